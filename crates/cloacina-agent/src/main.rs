@@ -47,7 +47,7 @@ use cloacina::fleet::{
     AgentResultResponse, EphemeralKeyEntry, GraphWorkPacket, RefusalReason, WorkPacket,
     AGENT_PROTOCOL_VERSION, AGENT_RECIPIENT_PREFIX, GRAPH_PACKET_KIND, WORK_PACKET_KIND,
 };
-use cloacina::security::{AgentKeyPool, InMemorySecretResolver};
+use cloacina::security::{AgentKeyPool, InMemorySecretResolver, SandboxMode, SecretRedactor};
 use futures_util::{SinkExt, StreamExt};
 use serde::Serialize;
 use tokio::sync::mpsc;
@@ -80,7 +80,8 @@ struct Args {
     /// Optional caller-chosen agent id; server assigns one if omitted.
     #[arg(long)]
     agent_id: Option<String>,
-    /// Max concurrent work packets this agent will accept.
+    /// Max concurrent work packets this agent will accept. Task memory
+    /// limits are only enforced when this is 1.
     #[arg(long, default_value_t = 4)]
     max_concurrency: u32,
     /// Free-form capability tags advertised at registration.
@@ -95,6 +96,22 @@ struct Args {
     /// packets to skip the REST fetch on cache hit.
     #[arg(long, env = "CLOACINA_AGENT_CACHE_DIR")]
     cache_dir: Option<std::path::PathBuf>,
+    /// Syscall sandbox for packaged task executions: `off`, `best-effort`
+    /// (apply what the kernel supports) or `enforce` (refuse packets that
+    /// cannot run fully sandboxed). Linux only; profiles come from the
+    /// package's granted capabilities.
+    #[arg(long, env = "CLOACINA_AGENT_SANDBOX", default_value_t = SandboxMode::Off)]
+    sandbox: SandboxMode,
+    /// PEM CA bundle trusted (in addition to the system roots) for the
+    /// server's certificate — REST and the substrate WS alike.
+    #[arg(long, env = "CLOACINA_TLS_CA_CERT")]
+    tls_ca_cert: Option<std::path::PathBuf>,
+    /// PEM client certificate presented to a server requiring mutual TLS.
+    #[arg(long, env = "CLOACINA_TLS_CLIENT_CERT", requires = "tls_client_key")]
+    tls_client_cert: Option<std::path::PathBuf>,
+    /// PEM private key for `--tls-client-cert`.
+    #[arg(long, env = "CLOACINA_TLS_CLIENT_KEY", requires = "tls_client_cert")]
+    tls_client_key: Option<std::path::PathBuf>,
 }
 
 #[tokio::main]
@@ -120,10 +137,25 @@ const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(30);
 
 async fn run(args: Args) -> Result<()> {
     let server_base = args.server.trim_end_matches('/').to_string();
-    let http = reqwest::Client::builder()
-        .user_agent(concat!("cloacina-agent/", env!("CARGO_PKG_VERSION")))
+    let tls = cloacina_client::TlsOptions {
+        ca_cert: args.tls_ca_cert.clone(),
+        client_cert: args.tls_client_cert.clone(),
+        client_key: args.tls_client_key.clone(),
+    };
+    let http = tls
+        .apply(
+            reqwest::Client::builder()
+                .user_agent(concat!("cloacina-agent/", env!("CARGO_PKG_VERSION"))),
+        )
+        .context("TLS configuration")?
         .build()
         .context("build HTTP client")?;
+    // The substrate WS must trust the same roots and present the same client
+    // certificate as REST; `None` keeps tungstenite's native-roots default.
+    let ws_connector = tls
+        .rustls_config()
+        .context("TLS configuration")?
+        .map(tokio_tungstenite::Connector::Rustls);
 
     // Install the PyO3 Python runtime so the agent can load Python-packaged
     // workflows (not just Rust cdylibs). Idempotent. (CLOACI-T-0716)
@@ -143,6 +175,15 @@ async fn run(args: Args) -> Result<()> {
     info!(cache_dir = ?cache_dir, "artifact cache directory");
     let cache_dir = Arc::new(cache_dir);
 
+    if args.sandbox != SandboxMode::Off && !cfg!(target_os = "linux") {
+        bail!(
+            "--sandbox {} requires Linux (Landlock/seccomp); use --sandbox off",
+            args.sandbox
+        );
+    }
+    let _ = SANDBOX_MODE.set(args.sandbox);
+    info!(sandbox = %args.sandbox, "packaged task sandbox mode");
+
     // Process-wide in-flight counter — survives across reconnect sessions so a
     // task still running when the WS drops is still counted on reconnect.
     let in_flight = Arc::new(AtomicU32::new(0));
@@ -163,6 +204,7 @@ async fn run(args: Args) -> Result<()> {
         match connect_and_serve(
             &args,
             &http,
+            ws_connector.as_ref(),
             &server_base,
             &target_triple,
             in_flight.clone(),
@@ -197,6 +239,7 @@ async fn run(args: Args) -> Result<()> {
 async fn connect_and_serve(
     args: &Args,
     http: &reqwest::Client,
+    ws_connector: Option<&tokio_tungstenite::Connector>,
     server_base: &str,
     target_triple: &str,
     in_flight: Arc<AtomicU32>,
@@ -257,9 +300,14 @@ async fn connect_and_serve(
             .context("mint WS ticket")?;
         let ws_url = ws_url_for(server_base, &agent_id, &ticket)?;
         info!(ws_url = %ws_url, "connecting to substrate delivery WS");
-        let (stream, _resp) = tokio_tungstenite::connect_async(&ws_url)
-            .await
-            .with_context(|| format!("WS connect to {}", ws_url))?;
+        let (stream, _resp) = tokio_tungstenite::connect_async_tls_with_config(
+            &ws_url,
+            None,
+            false,
+            ws_connector.cloned(),
+        )
+        .await
+        .with_context(|| format!("WS connect to {}", ws_url))?;
         info!(agent_id = %agent_id, "substrate WS connected");
         receive_loop(
             stream,
@@ -617,6 +665,7 @@ async fn handle_text_frame(
                 agent_id.to_string(),
                 target_triple.to_string(),
                 packet,
+                max_concurrency,
                 in_flight.clone(),
                 ack_tx.clone(),
                 push_id,
@@ -663,6 +712,34 @@ fn make_ack(id: i64) -> Result<String> {
     Ok(serde_json::to_string(&v)?)
 }
 
+/// The memory limit to enforce for a task on this agent.
+///
+/// Limits are measured as process-wide RSS growth, so with more than one
+/// packet in flight a leaky task would be charged to every guarded packet
+/// running next to it. They are only enforced when the agent runs one packet
+/// at a time (`--max-concurrency 1`); otherwise a warning is logged once and
+/// the task runs unguarded.
+fn enforceable_memory_limit(
+    task_id: &str,
+    limit_bytes: Option<u64>,
+    max_concurrency: u32,
+) -> Option<u64> {
+    static WARNED: std::sync::Once = std::sync::Once::new();
+    if limit_bytes.is_some() && max_concurrency > 1 {
+        WARNED.call_once(|| {
+            warn!(
+                task_id = %task_id,
+                max_concurrency,
+                "Task declares a memory limit, but RSS cannot be attributed to one task \
+                 while several packets run at once; memory limits are only enforced \
+                 with --max-concurrency 1"
+            );
+        });
+        return None;
+    }
+    limit_bytes
+}
+
 /// Spawn a worker that runs the full Tier-B path (triple check → fetch+cache
 /// artifact → dlopen via fidius → resolve task → execute with context →
 /// classify), POSTs the result, and acks.
@@ -674,6 +751,7 @@ fn spawn_packet_worker(
     agent_id: String,
     target_triple: String,
     packet: WorkPacket,
+    max_concurrency: u32,
     in_flight: Arc<AtomicU32>,
     ack_tx: mpsc::UnboundedSender<Message>,
     push_id: i64,
@@ -686,6 +764,7 @@ fn spawn_packet_worker(
         let outcome = process_work_packet(
             &packet,
             &target_triple,
+            max_concurrency,
             &http,
             &server,
             &api_key,
@@ -739,26 +818,24 @@ async fn execute_python_graph(
     {
         let mut imported = imported_py_graph_digests().lock().await;
         if !imported.contains(&digest) {
-            let archive = match fetch_source_archive(http, server, api_key, &digest).await {
-                Ok(a) => a,
-                Err(e) => {
-                    return cloacina::fleet::AgentOutcome::Refused {
-                        reason: RefusalReason::ArtifactFetchFailed,
-                        message: format!("python graph source fetch failed: {}", e),
-                    };
-                }
-            };
-            if let Err(e) = stage_agent_providers(http, server, api_key, &digest, cache_dir).await {
+            let staging = cache_dir.join(format!("pysrc-{}", digest));
+            if let Err(e) = std::fs::create_dir_all(&staging) {
                 return cloacina::fleet::AgentOutcome::Refused {
                     reason: RefusalReason::RuntimeLoadFailed,
-                    message: e,
+                    message: format!("create python staging dir {:?}: {}", staging, e),
                 };
             }
-            let staging = cache_dir.join(format!("pysrc-{}", digest));
-            if let Err(e) = std::fs::create_dir_all(&staging) {
+            let archive = staging.join("source.cloacina");
+            if let Err(e) = fetch_source_archive(http, server, api_key, &digest, &archive).await {
+                return cloacina::fleet::AgentOutcome::Refused {
+                    reason: RefusalReason::ArtifactFetchFailed,
+                    message: format!("python graph source fetch failed: {}", e),
+                };
+            }
+            if let Err(e) = stage_agent_providers(http, server, api_key, &digest, cache_dir).await {
                 return cloacina::fleet::AgentOutcome::Refused {
                     reason: RefusalReason::RuntimeLoadFailed,
-                    message: format!("create python staging dir {:?}: {}", staging, e),
+                    message: e,
                 };
             }
             let Some(py) = cloacina::python_runtime::python_runtime() else {
@@ -834,6 +911,7 @@ async fn execute_python_graph(
                 return cloacina::fleet::AgentOutcome::Failure {
                     message: format!("rebuild cache entry '{}': {}", source, e),
                     classification: cloacina::fleet::FailureClassification::TaskError,
+                    payload: None,
                 };
             }
         };
@@ -861,6 +939,7 @@ async fn execute_python_graph(
                 return cloacina::fleet::AgentOutcome::Failure {
                     message: format!("python graph '{}' execution failed: {}", graph_name, e),
                     classification: cloacina::fleet::FailureClassification::TaskError,
+                    payload: None,
                 };
             }
         }
@@ -1050,16 +1129,19 @@ async fn process_graph_packet(
                         .error
                         .unwrap_or_else(|| "unknown FFI graph execution error".to_string()),
                     classification: cloacina::fleet::FailureClassification::TaskError,
+                    payload: None,
                 }
             }
         }
         Ok(Err(e)) => cloacina::fleet::AgentOutcome::Failure {
             message: format!("execute_graph FFI call failed: {}", e),
             classification: cloacina::fleet::FailureClassification::TaskError,
+            payload: None,
         },
         Err(join_err) => cloacina::fleet::AgentOutcome::Failure {
             message: format!("execute_graph panicked: {}", join_err),
             classification: cloacina::fleet::FailureClassification::TaskError,
+            payload: None,
         },
     }
 }
@@ -1145,23 +1227,72 @@ fn loaded_runtimes(
     LOADED_RUNTIMES.get_or_init(|| tokio::sync::Mutex::new(std::collections::HashMap::new()))
 }
 
+/// Process-wide sandbox mode (`--sandbox`), set once at startup. Unset (in
+/// tests) reads as `off`.
+static SANDBOX_MODE: std::sync::OnceLock<SandboxMode> = std::sync::OnceLock::new();
+
+fn sandbox_mode() -> SandboxMode {
+    SANDBOX_MODE.get().copied().unwrap_or_default()
+}
+
+/// Whether an agent in `mode` can run `packet` at all. Under `enforce`, a
+/// packet that could only run unconfined is refused, so the server
+/// reschedules it to another agent: Python packages run in the shared
+/// interpreter, and a package without granted capabilities has no profile.
+fn sandbox_refusal(
+    mode: SandboxMode,
+    packet: &WorkPacket,
+    is_python: bool,
+) -> Option<cloacina::fleet::AgentOutcome> {
+    if mode != SandboxMode::Enforce {
+        return None;
+    }
+    let message = if is_python {
+        "agent enforces a syscall sandbox; Python packages cannot run sandboxed"
+    } else if packet.capabilities.is_none() {
+        "agent enforces a syscall sandbox; the package carries no granted capabilities to \
+         derive its profile from"
+    } else {
+        return None;
+    };
+    Some(cloacina::fleet::AgentOutcome::Refused {
+        reason: RefusalReason::SandboxRequired,
+        message: message.to_string(),
+    })
+}
+
 /// Fetch the package SOURCE archive (the uploaded `.cloacina`) by digest from
 /// `GET /v1/agent/source/{digest}` — used for Python packages, which have no
-/// cdylib (CLOACI-T-0716).
+/// cdylib (CLOACI-T-0716) — into `dest`.
 async fn fetch_source_archive(
     http: &reqwest::Client,
     server: &str,
     api_key: &str,
     digest: &str,
-) -> Result<Vec<u8>> {
+    dest: &std::path::Path,
+) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
     let url = format!("{}/v1/agent/source/{}", server, digest);
-    let resp = http
+    let mut resp = http
         .get(&url)
         .bearer_auth(api_key)
         .send()
         .await?
         .error_for_status()?;
-    Ok(resp.bytes().await?.to_vec())
+    // Written as it arrives: source archives can be large.
+    let mut file = tokio::fs::File::create(dest)
+        .await
+        .with_context(|| format!("create {:?}", dest))?;
+    while let Some(chunk) = resp.chunk().await? {
+        file.write_all(&chunk)
+            .await
+            .with_context(|| format!("write {:?}", dest))?;
+    }
+    file.flush()
+        .await
+        .with_context(|| format!("write {:?}", dest))?;
+    Ok(())
 }
 
 /// Load a Rust cdylib package into `runtime`: fetch + cache the artifact by
@@ -1186,21 +1317,24 @@ async fn load_rust_cdylib(
             reason: RefusalReason::ArtifactFetchFailed,
             message: format!("read cached artifact {:?}: {}", artifact_path, e),
         })?;
-    let registrar = cloacina::registry::loader::TaskRegistrar::new().map_err(|e| {
-        cloacina::fleet::AgentOutcome::Refused {
+    let registrar = cloacina::registry::loader::TaskRegistrar::new()
+        .map_err(|e| cloacina::fleet::AgentOutcome::Refused {
             reason: RefusalReason::RuntimeLoadFailed,
             message: format!("task registrar init: {}", e),
-        }
-    })?;
+        })?
+        .with_sandbox(sandbox_mode());
     let package_id = format!("agent_pkg_{}", packet.artifact.digest);
     let metadata = synthetic_package_metadata(&packet.artifact.digest);
+    // The granted capabilities gate secrets and env exactly as on the server,
+    // and — with `--sandbox` — confine each execution to them.
     let registered = registrar
-        .register_package_tasks(
+        .register_package_tasks_with_capabilities(
             &package_id,
             &cdylib_bytes,
             &metadata,
             packet.tenant_id.as_deref(),
             runtime,
+            packet.capabilities.clone(),
         )
         .await
         .map_err(|e| cloacina::fleet::AgentOutcome::Refused {
@@ -1256,6 +1390,7 @@ async fn load_rust_cdylib(
             cloacina::fleet::AgentOutcome::Failure {
                 message: format!("parse_namespace({}): {}", packet.task_name, e),
                 classification: cloacina::fleet::FailureClassification::Validation,
+                payload: None,
             }
         })?;
         resolve_agent_constructor_nodes(decls, &ns.tenant_id, &ns.package_name, runtime)
@@ -1414,7 +1549,13 @@ async fn load_python_package(
     cache_dir: &std::path::Path,
     runtime: &Arc<cloacina::Runtime>,
 ) -> std::result::Result<(), cloacina::fleet::AgentOutcome> {
-    let archive = fetch_source_archive(http, server, api_key, digest)
+    let staging = cache_dir.join(format!("pysrc-{}", digest));
+    std::fs::create_dir_all(&staging).map_err(|e| cloacina::fleet::AgentOutcome::Refused {
+        reason: RefusalReason::RuntimeLoadFailed,
+        message: format!("create python staging dir {:?}: {}", staging, e),
+    })?;
+    let archive = staging.join("source.cloacina");
+    fetch_source_archive(http, server, api_key, digest, &archive)
         .await
         .map_err(|e| cloacina::fleet::AgentOutcome::Refused {
             reason: RefusalReason::ArtifactFetchFailed,
@@ -1434,12 +1575,6 @@ async fn load_python_package(
             message: e,
         })?;
 
-    let staging = cache_dir.join(format!("pysrc-{}", digest));
-    std::fs::create_dir_all(&staging).map_err(|e| cloacina::fleet::AgentOutcome::Refused {
-        reason: RefusalReason::RuntimeLoadFailed,
-        message: format!("create python staging dir {:?}: {}", staging, e),
-    })?;
-
     let py = cloacina::python_runtime::python_runtime().ok_or_else(|| {
         cloacina::fleet::AgentOutcome::Refused {
             reason: RefusalReason::RuntimeLoadFailed,
@@ -1500,6 +1635,7 @@ async fn load_python_package(
 async fn process_work_packet(
     packet: &WorkPacket,
     agent_triple: &str,
+    max_concurrency: u32,
     http: &reqwest::Client,
     server: &str,
     api_key: &str,
@@ -1526,6 +1662,15 @@ async fn process_work_packet(
             ),
         };
     }
+    if let Some(refused) = sandbox_refusal(sandbox_mode(), packet, is_python) {
+        return refused;
+    }
+    if is_python && sandbox_mode() == SandboxMode::BestEffort {
+        warn!(
+            task = %packet.task_name,
+            "Python packages cannot run sandboxed; running unconfined (best-effort sandbox)"
+        );
+    }
 
     let runtime = {
         // The lock is held across a (one-time, per-digest) load so concurrent
@@ -1556,6 +1701,7 @@ async fn process_work_packet(
             return cloacina::fleet::AgentOutcome::Failure {
                 message: format!("parse_namespace({}): {}", packet.task_name, e),
                 classification: cloacina::fleet::FailureClassification::Validation,
+                payload: None,
             };
         }
     };
@@ -1571,6 +1717,7 @@ async fn process_work_packet(
                     .collect::<Vec<_>>()
             ),
             classification: cloacina::fleet::FailureClassification::Validation,
+            payload: None,
         };
     };
 
@@ -1581,9 +1728,13 @@ async fn process_work_packet(
             return cloacina::fleet::AgentOutcome::Failure {
                 message: format!("build context: {}", e),
                 classification: cloacina::fleet::FailureClassification::Validation,
+                payload: None,
             };
         }
     };
+    if let Some(params) = packet.params.clone() {
+        context.set_params(params);
+    }
 
     // 5b. CLOACI-T-0861 / D-5 — unwrap the HPKE-wrapped secrets with the ONE-TIME
     //     pooled private key the server named in `secret_key_id`. We `take` that
@@ -1592,6 +1743,8 @@ async fn process_work_packet(
     //     reads them via `ctx.secret(name)`. The plaintext lives only in this
     //     resolver, only for the run; it is never persisted or logged (NFR-001/
     //     NFR-003). The AAD is keyed by `task_execution_id`, matching the wrap.
+    //     A redactor in front of it strips the values from the outcome.
+    let mut secret_redactor = None;
     if !packet.wrapped_secrets.is_empty() {
         let Some(key_id) = packet.secret_key_id.as_deref() else {
             // Secrets present but no key id — a malformed dispatch. Fail closed
@@ -1599,6 +1752,7 @@ async fn process_work_packet(
             return cloacina::fleet::AgentOutcome::Failure {
                 message: "work packet carries wrapped_secrets but no secret_key_id".to_string(),
                 classification: cloacina::fleet::FailureClassification::Validation,
+                payload: None,
             };
         };
         // One-time discard: remove the key from the pool. An unknown key_id (e.g.
@@ -1613,6 +1767,7 @@ async fn process_work_packet(
                     key_id
                 ),
                 classification: cloacina::fleet::FailureClassification::Validation,
+                payload: None,
             };
         };
         match InMemorySecretResolver::from_wrapped(
@@ -1620,33 +1775,61 @@ async fn process_work_packet(
             &packet.wrapped_secrets,
             &packet.task_execution_id,
         ) {
-            Ok(resolver) => context.set_secret_resolver(resolver.into_arc()),
+            Ok(resolver) => {
+                let redactor = Arc::new(SecretRedactor::new(resolver.into_arc()));
+                context.set_secret_resolver(redactor.clone());
+                secret_redactor = Some(redactor);
+            }
             Err(e) => {
                 // Names only — `FleetSecretError` never renders a secret value.
                 return cloacina::fleet::AgentOutcome::Failure {
                     message: format!("unwrap secrets: {}", e),
                     classification: cloacina::fleet::FailureClassification::Validation,
+                    payload: None,
                 };
             }
         }
     }
 
-    // 6. Execute under the packet's timeout.
+    // 6. Execute under the packet's timeout and the task's memory limit. The
+    //    task runs in this process, not the server's, so its usage is measured
+    //    as RSS growth — which is only attributable to one task when this
+    //    agent runs a single packet at a time.
     let timeout = std::time::Duration::from_secs(packet.timeout_seconds.max(1) as u64);
-    match tokio::time::timeout(timeout, task.execute(context)).await {
-        Ok(Ok(output)) => {
-            let output_value = context_to_value(&output);
-            cloacina::fleet::AgentOutcome::Success {
-                context: output_value,
+    let task_scope = cloacina::context_scope::TaskScope::enter(task.as_ref(), &mut context);
+    let execution = cloacina::memory::guard_task(
+        task.id(),
+        enforceable_memory_limit(task.id(), task.memory_limit(), max_concurrency),
+        cloacina::memory::MemoryAccounting::ProcessRss,
+        task.execute(context),
+    );
+    let result = match tokio::time::timeout(timeout, execution).await {
+        Ok(result) => result.and_then(|output| {
+            cloacina::outputs::check_task_outputs(task.as_ref(), &output)?;
+            task_scope.leave(output)
+        }),
+        Err(_) => {
+            return cloacina::fleet::AgentOutcome::Failure {
+                message: format!("task exceeded timeout of {}s", packet.timeout_seconds),
+                classification: cloacina::fleet::FailureClassification::Timeout,
+                payload: None,
             }
         }
-        Ok(Err(e)) => cloacina::fleet::AgentOutcome::Failure {
+    };
+    let result = match &secret_redactor {
+        Some(redactor) => result
+            .map(|output| redactor.redact_context(output))
+            .map_err(|e| redactor.redact_error(e)),
+        None => result,
+    };
+    match result {
+        Ok(output) => cloacina::fleet::AgentOutcome::Success {
+            context: context_to_value(&output),
+        },
+        Err(e) => cloacina::fleet::AgentOutcome::Failure {
             message: e.to_string(),
             classification: cloacina::fleet::FailureClassification::TaskError,
-        },
-        Err(_) => cloacina::fleet::AgentOutcome::Failure {
-            message: format!("task exceeded timeout of {}s", packet.timeout_seconds),
-            classification: cloacina::fleet::FailureClassification::Timeout,
+            payload: e.payload().cloned(),
         },
     }
 }
@@ -1741,6 +1924,8 @@ fn synthetic_package_metadata(
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     }
 }
 
@@ -1749,16 +1934,11 @@ fn synthetic_package_metadata(
 fn build_context(
     value: &serde_json::Value,
 ) -> Result<cloacina::Context<serde_json::Value>, anyhow::Error> {
-    let mut ctx = cloacina::Context::<serde_json::Value>::new();
     match value {
         serde_json::Value::Object(map) => {
-            for (k, v) in map {
-                ctx.insert(k.as_str(), v.clone())
-                    .map_err(|e| anyhow!("insert {}: {}", k, e))?;
-            }
-            Ok(ctx)
+            cloacina::Context::from_map(map.clone()).map_err(|e| anyhow!("decode context: {}", e))
         }
-        serde_json::Value::Null => Ok(ctx),
+        serde_json::Value::Null => Ok(cloacina::Context::new()),
         other => bail!(
             "WorkPacket.context must be a JSON object or null, got {}",
             kind_of(other)
@@ -1781,12 +1961,7 @@ fn kind_of(v: &serde_json::Value) -> &'static str {
 /// server's `FleetExecutor` reconciliation can pass it through to the shared
 /// `TaskResultHandler::handle_outcome` Success branch.
 fn context_to_value(ctx: &cloacina::Context<serde_json::Value>) -> serde_json::Value {
-    let map: serde_json::Map<String, serde_json::Value> = ctx
-        .data()
-        .iter()
-        .map(|(k, v)| (k.clone(), v.clone()))
-        .collect();
-    serde_json::Value::Object(map)
+    serde_json::Value::Object(ctx.to_map().unwrap_or_default())
 }
 
 // ────────────────────────────────────────────────────────────────────
@@ -1848,6 +2023,8 @@ mod tests {
             language: Some("rust".into()),
             wrapped_secrets: Vec::new(),
             secret_key_id: None,
+            capabilities: None,
+            params: None,
         }
     }
 
@@ -1871,6 +2048,7 @@ mod tests {
         let outcome = process_work_packet(
             &packet,
             agent_triple,
+            1,
             &http,
             "http://nowhere.invalid:1",
             "ignored-key",
@@ -1897,6 +2075,7 @@ mod tests {
         let outcome = process_work_packet(
             &packet,
             &agent_triple,
+            1,
             &http,
             "http://127.0.0.1:1",
             "ignored",
@@ -1928,6 +2107,26 @@ mod tests {
             .to_string()
     }
 
+    #[test]
+    fn enforcing_agent_refuses_packets_it_cannot_sandbox() {
+        let ungated = pkt("x86_64-unknown-linux-gnu");
+        let mut gated = ungated.clone();
+        gated.capabilities = Some(Default::default());
+
+        for (packet, is_python) in [(&ungated, false), (&gated, true)] {
+            assert!(matches!(
+                sandbox_refusal(SandboxMode::Enforce, packet, is_python),
+                Some(AgentOutcome::Refused {
+                    reason: RefusalReason::SandboxRequired,
+                    ..
+                })
+            ));
+        }
+        assert!(sandbox_refusal(SandboxMode::Enforce, &gated, false).is_none());
+        assert!(sandbox_refusal(SandboxMode::BestEffort, &ungated, false).is_none());
+        assert!(sandbox_refusal(SandboxMode::Off, &ungated, true).is_none());
+    }
+
     #[test]
     fn build_context_accepts_object_or_null() {
         let v = serde_json::json!({"k1": 1, "k2": "x"});
@@ -1969,4 +2168,11 @@ mod tests {
     fn ws_url_for_rejects_unsupported_scheme() {
         assert!(ws_url_for("ftp://x", "y", "z").is_err());
     }
+
+    #[test]
+    fn memory_limits_are_only_enforced_one_packet_at_a_time() {
+        assert_eq!(enforceable_memory_limit("t", Some(1024), 1), Some(1024));
+        assert_eq!(enforceable_memory_limit("t", Some(1024), 4), None);
+        assert_eq!(enforceable_memory_limit("t", None, 1), None);
+    }
 }
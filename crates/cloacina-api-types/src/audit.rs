@@ -0,0 +1,83 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Audit chain types — reading and verifying the hash-chained audit log.
+
+use serde::{Deserialize, Serialize};
+
+/// Query string for `GET /audit/entries`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct ListAuditEntriesQuery {
+    /// Return entries with a sequence greater than this. Default 0.
+    pub after: Option<i64>,
+    /// Page size, 1..=1000. Default 100.
+    pub limit: Option<i64>,
+}
+
+/// One audit chain entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditEntry {
+    pub sequence: i64,
+    /// Audit event type, e.g. `key.exported`.
+    pub event_type: String,
+    /// The event's fields as captured.
+    pub payload: serde_json::Value,
+    /// Hex SHA-256 of the previous entry (all zeros for the first).
+    pub prev_hash: String,
+    /// Hex SHA-256 of this entry.
+    pub entry_hash: String,
+    /// RFC 3339.
+    pub created_at: String,
+}
+
+/// Query string for `GET /audit/verify`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct VerifyAuditChainQuery {
+    /// First sequence to verify. Default 1.
+    pub from: Option<i64>,
+    /// Last sequence to verify. Default: the head.
+    pub to: Option<i64>,
+}
+
+/// Where verification found the chain broken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditChainBreak {
+    pub sequence: i64,
+    pub reason: String,
+}
+
+/// Result of `GET /audit/verify`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditChainVerification {
+    /// `true` when every entry and anchor in range checked out.
+    pub valid: bool,
+    pub entries_checked: u64,
+    pub anchors_checked: u64,
+    pub first_sequence: Option<i64>,
+    pub last_sequence: Option<i64>,
+    /// Hash of the last verified entry.
+    pub head_hash: Option<String>,
+    /// Whether anchor signatures were checked (an anchor key is configured).
+    pub signatures_checked: bool,
+    pub failure: Option<AuditChainBreak>,
+}
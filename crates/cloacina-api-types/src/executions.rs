@@ -17,6 +17,7 @@
 //! Execution API types — trigger workflows and query execution status.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Request body for `POST /tenants/{tenant_id}/workflows/{name}/execute`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -25,6 +26,54 @@ pub struct ExecuteRequest {
     /// Optional JSON context to pass to the workflow.
     #[serde(default)]
     pub context: Option<serde_json::Value>,
+    /// Key/value labels to attach to the execution (`source=api`,
+    /// `customer=acme`). Stored outside the context and filterable on
+    /// `GET /executions?label=key=value`.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// Read-only run configuration (`iterations`, `failure_rate`). Tasks
+    /// read it with `context.params()` but cannot change it, and it is kept
+    /// out of the context.
+    #[serde(default)]
+    pub params: BTreeMap<String, serde_json::Value>,
+    /// Overrides of the runner configuration and task retry policies for
+    /// this execution only.
+    #[serde(default)]
+    pub options: Option<ExecuteOptions>,
+}
+
+/// Per-execution overrides for an execute request. Unset fields keep the
+/// runner's configuration and the tasks' retry policies.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecuteOptions {
+    /// Most tasks of this execution dispatched at once.
+    #[serde(default)]
+    pub max_parallelism: Option<u32>,
+    /// Dispatch order against other executions' ready tasks; higher first.
+    #[serde(default)]
+    pub priority: i32,
+    /// Replaces every task's `max_attempts`.
+    #[serde(default)]
+    pub max_attempts: Option<i32>,
+    /// Scales every task's retry delays (`0.1` retries ten times sooner).
+    #[serde(default)]
+    pub retry_delay_multiplier: Option<f64>,
+    /// Timeout in seconds for each of this execution's tasks.
+    #[serde(default)]
+    pub task_timeout_secs: Option<u64>,
+    /// RFC 3339 time by which the execution must finish. Tasks that would
+    /// not finish in time are not started and the execution fails with
+    /// `DEADLINE_EXCEEDED`.
+    #[serde(default)]
+    pub deadline: Option<String>,
+    /// Run id of the execution (`backfill__2026-01-01`); derived from the
+    /// logical date when unset.
+    #[serde(default)]
+    pub run_id: Option<String>,
+    /// RFC 3339 logical date the execution processes; now when unset.
+    #[serde(default)]
+    pub logical_date: Option<String>,
 }
 
 /// `202 Accepted` body for a scheduled workflow execution.
@@ -47,6 +96,11 @@ pub struct ExecuteResponse {
 pub struct ListExecutionsQuery {
     pub status: Option<String>,
     pub workflow: Option<String>,
+    /// Label selector: comma-separated `key=value` terms, all of which an
+    /// execution must carry (`source=api,customer=acme`).
+    pub label: Option<String>,
+    /// Exact run id.
+    pub run_id: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
@@ -68,6 +122,21 @@ pub struct ExecutionSummary {
     /// The UI marks manual runs with a "manual" pill.
     #[serde(default)]
     pub trigger_origin: Option<String>,
+    /// Stable run id (`scheduled__2026-01-01T00:00:00.000000Z`); `null` for
+    /// executions created before run ids existed.
+    #[serde(default)]
+    pub run_id: Option<String>,
+    /// RFC 3339 logical date the run processes; `null` for executions
+    /// created before logical dates existed.
+    #[serde(default)]
+    pub logical_date: Option<String>,
+    /// Labels attached at submission; empty when unlabelled.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// Current operator resolution (`investigating`, `resolved`, `rerun`,
+    /// `ignored`) from the latest annotation that set one; `null` when none.
+    #[serde(default)]
+    pub resolution: Option<String>,
 }
 
 /// `GET /tenants/{tenant_id}/executions/{id}` response.
@@ -77,6 +146,224 @@ pub struct ExecutionDetail {
     pub tenant_id: String,
     pub execution_id: String,
     pub status: String,
+    /// Labels attached at submission; empty when unlabelled.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// Params the execution was submitted with; empty when it had none.
+    #[serde(default)]
+    pub params: BTreeMap<String, serde_json::Value>,
+    /// Current operator resolution from the latest annotation that set one;
+    /// `null` when none.
+    #[serde(default)]
+    pub resolution: Option<String>,
+    /// Operator annotations, oldest first.
+    #[serde(default)]
+    pub annotations: Vec<ExecutionAnnotation>,
+}
+
+/// Request body for `POST /tenants/{tenant_id}/executions/{id}/annotations`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AnnotateExecutionRequest {
+    /// Free-text note ("known outage, reran manually at 14:00").
+    pub note: String,
+    /// Optional resolution status: `investigating`, `resolved`, `rerun` or
+    /// `ignored`.
+    #[serde(default)]
+    pub resolution: Option<String>,
+}
+
+/// One operator annotation on an execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionAnnotation {
+    /// Annotation UUID.
+    pub id: String,
+    /// Name of the API key or account that wrote the note.
+    pub author: String,
+    pub note: String,
+    /// Resolution status set by this annotation, if any.
+    pub resolution: Option<String>,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+}
+
+/// `GET /tenants/{tenant_id}/executions/{id}/annotations` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionAnnotationsResponse {
+    pub tenant_id: String,
+    pub execution_id: String,
+    pub annotations: Vec<ExecutionAnnotation>,
+}
+
+/// Request body for
+/// `POST /tenants/{tenant_id}/executions/{id}/tasks/{task}/resolve`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResolveTaskRequest {
+    /// Why the failure can be treated as a success ("side effect applied by
+    /// hand"). Recorded as an annotation on the execution.
+    pub note: String,
+}
+
+/// Result of marking a failed task successful.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResolveTaskResponse {
+    pub tenant_id: String,
+    pub execution_id: String,
+    /// Task execution UUID of the resolved task.
+    pub task_execution_id: String,
+    /// Fully qualified task name.
+    pub task_name: String,
+    /// Downstream tasks that had been skipped and are scheduled again.
+    pub reopened_tasks: Vec<String>,
+    /// `true` when the failed execution was set back to running.
+    pub execution_reopened: bool,
+    /// The audit annotation recorded for the resolution.
+    pub annotation: ExecutionAnnotation,
+}
+
+/// Context keys one task attempt read and wrote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskContextAccess {
+    /// Task execution UUID.
+    pub task_execution_id: String,
+    /// Fully qualified task name.
+    pub task_name: String,
+    pub attempt: i32,
+    /// Keys the task read, sorted.
+    pub reads: Vec<String>,
+    /// Keys the task inserted, updated or removed, sorted.
+    pub writes: Vec<String>,
+    /// RFC 3339 timestamp.
+    pub recorded_at: String,
+}
+
+/// A mismatch between traced context access and the workflow's dependencies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContextAccessFinding {
+    /// `missing_dependency` — `task` read `key`, written by `producer`, which
+    /// it does not depend on. `unused_dependency` — `task` read nothing its
+    /// data dependency `dependency` wrote.
+    pub kind: String,
+    /// Fully qualified task name.
+    pub task: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<String>,
+}
+
+/// `GET /tenants/{tenant_id}/executions/{id}/context-access` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContextAccessResponse {
+    pub tenant_id: String,
+    pub execution_id: String,
+    pub workflow_name: String,
+    /// One entry per traced task attempt, oldest first. Empty unless the
+    /// server runs with context access tracing enabled.
+    pub tasks: Vec<TaskContextAccess>,
+    /// Findings from each task's latest traced attempt.
+    pub findings: Vec<ContextAccessFinding>,
+}
+
+/// One top-level context key a task attempt changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContextKeyChange {
+    pub key: String,
+    /// `added`, `modified` or `removed`.
+    pub kind: String,
+    /// Value in the task's input; absent when added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<serde_json::Value>,
+    /// Value in the task's output; absent when removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+}
+
+/// Context keys one successful task attempt changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskContextChanges {
+    /// Task execution UUID.
+    pub task_execution_id: String,
+    /// Fully qualified task name.
+    pub task_name: String,
+    pub attempt: i32,
+    /// Changed keys, sorted.
+    pub changes: Vec<ContextKeyChange>,
+    /// RFC 3339 timestamp.
+    pub recorded_at: String,
+}
+
+/// One write of a context key by a task attempt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContextKeyWrite {
+    /// Fully qualified task name.
+    pub task_name: String,
+    pub attempt: i32,
+    /// `added`, `modified` or `removed`.
+    pub kind: String,
+    /// Value before the task ran; absent when added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<serde_json::Value>,
+    /// Value the task left; absent when removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+    /// RFC 3339 timestamp.
+    pub recorded_at: String,
+}
+
+/// `GET /tenants/{tenant_id}/executions/{id}/context-lineage` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContextLineageResponse {
+    pub tenant_id: String,
+    pub execution_id: String,
+    pub workflow_name: String,
+    /// One entry per recorded task attempt, oldest first. Empty unless the
+    /// server runs with context lineage tracking enabled.
+    pub tasks: Vec<TaskContextChanges>,
+    /// Every write of each key, oldest first. Keys no task wrote came from
+    /// the execution's input.
+    pub keys: BTreeMap<String, Vec<ContextKeyWrite>>,
+}
+
+/// One run artifact: a named file a task stored for its execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionArtifact {
+    /// Artifact UUID.
+    pub id: String,
+    /// File name, unique within the execution (`report.pdf`).
+    pub name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    /// Task that stored it.
+    pub task_name: String,
+    /// Task execution UUID; `null` once that row is gone.
+    pub task_execution_id: Option<String>,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+}
+
+/// `GET /tenants/{tenant_id}/executions/{id}/artifacts` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionArtifactsResponse {
+    pub tenant_id: String,
+    pub execution_id: String,
+    /// Oldest first.
+    pub artifacts: Vec<ExecutionArtifact>,
 }
 
 /// One row in the execution event log.
@@ -133,6 +420,26 @@ pub struct TaskExecutionDetail {
     pub last_error: Option<String>,
     /// Structured error details, when present.
     pub error_details: Option<String>,
+    /// Payload of a task that failed with a structured error, as the task
+    /// returned it.
+    #[serde(default)]
+    pub error_payload: Option<TaskErrorPayload>,
+}
+
+/// Structured error a task failed with (`TaskError::Structured`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskErrorPayload {
+    /// Machine-readable error code set by the task.
+    pub code: String,
+    /// One of `validation`, `not_found`, `permission_denied`, `conflict`,
+    /// `transient`, `external`, `internal`.
+    pub category: String,
+    /// Message meant for the people looking at the failed execution.
+    pub message: String,
+    /// Arbitrary JSON details; omitted when the task set none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
 }
 
 /// `GET /tenants/{tenant_id}/executions/{id}/tasks` response.
@@ -143,3 +450,200 @@ pub struct ExecutionTasksResponse {
     pub execution_id: String,
     pub tasks: Vec<TaskExecutionDetail>,
 }
+
+/// `GET /tenants/{tenant_id}/executions/{id}/recording` response: a
+/// replayable snapshot of the execution. Same shape as
+/// `cloacina::replay::ExecutionRecording`, so it can be saved and loaded
+/// straight into a local `ReplayRunner`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionRecording {
+    pub execution_id: String,
+    pub workflow_name: String,
+    pub workflow_version: String,
+    pub status: String,
+    /// Context the execution was started with.
+    pub initial_context: BTreeMap<String, serde_json::Value>,
+    /// One entry per task, ordered by start time.
+    pub steps: Vec<RecordedStep>,
+}
+
+/// One task of an [`ExecutionRecording`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RecordedStep {
+    /// Fully qualified task name.
+    pub task_name: String,
+    pub status: String,
+    pub attempt: i32,
+    /// RFC 3339 timestamp; `null` if the task never started.
+    pub started_at: Option<String>,
+    /// RFC 3339 timestamp; `null` if the task never finished.
+    pub completed_at: Option<String>,
+    /// Error message of the last failed attempt, when present.
+    pub error: Option<String>,
+    /// Structured error the task failed with, when present.
+    pub error_payload: Option<TaskErrorPayload>,
+    /// Output context the task persisted; `null` when it produced none.
+    pub output: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+/// `GET /tenants/{tenant_id}/executions/{id}/tree` response: the execution's
+/// task tree with per-attempt timings, retries, trigger outcomes and
+/// context ids. Same shape as `cloacina::execution_detail::ExecutionDetail`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionTree {
+    pub tenant_id: String,
+    pub execution_id: String,
+    pub workflow_name: String,
+    pub workflow_version: String,
+    pub status: String,
+    /// RFC 3339 timestamp.
+    pub started_at: String,
+    /// RFC 3339 timestamp; `null` while still running.
+    pub completed_at: Option<String>,
+    /// Wall-clock run time; `null` while still running.
+    pub duration_ms: Option<i64>,
+    pub error_details: Option<String>,
+    /// `"manual"` for operator runs; `null` for scheduled and triggered runs.
+    pub trigger_origin: Option<String>,
+    #[serde(default)]
+    pub run_id: Option<String>,
+    /// RFC 3339 logical date the run processes.
+    #[serde(default)]
+    pub logical_date: Option<String>,
+    /// RFC 3339 timestamp; `null` unless paused.
+    pub paused_at: Option<String>,
+    pub pause_reason: Option<String>,
+    pub recovery_attempts: i32,
+    /// UUID of the execution's context.
+    pub context_id: Option<String>,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// Tasks in start order; tasks that never started come last.
+    pub tasks: Vec<ExecutionTreeTask>,
+}
+
+/// One task of an [`ExecutionTree`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExecutionTreeTask {
+    /// Task execution UUID.
+    pub task_execution_id: String,
+    /// Fully qualified task name.
+    pub task_name: String,
+    pub status: String,
+    pub sub_status: Option<String>,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+    /// RFC 3339 timestamp; `null` until the task starts.
+    pub started_at: Option<String>,
+    /// RFC 3339 timestamp; `null` until the task finishes.
+    pub completed_at: Option<String>,
+    pub attempt: i32,
+    pub max_attempts: i32,
+    /// RFC 3339 timestamp of the next scheduled retry, when one is pending.
+    pub retry_at: Option<String>,
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub error_payload: Option<TaskErrorPayload>,
+    /// The task's trigger rules as stored.
+    pub trigger_rules: serde_json::Value,
+    /// How the trigger rules resolved; `null` until they were evaluated.
+    pub trigger_evaluation: Option<TriggerEvaluation>,
+    /// One entry per claimed run, oldest first.
+    pub attempts: Vec<TaskAttempt>,
+    /// UUID of the context the task saved as its output.
+    pub output_context_id: Option<String>,
+    /// Operator note recorded when a failed task was marked successful.
+    #[serde(default)]
+    pub resolution_note: Option<String>,
+}
+
+/// One claimed run of a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskAttempt {
+    pub attempt: i32,
+    /// RFC 3339 timestamp.
+    pub started_at: String,
+    /// RFC 3339 timestamp; `null` while running.
+    pub ended_at: Option<String>,
+    pub duration_ms: Option<i64>,
+    /// `running`, `completed`, `failed`, `abandoned`, `reset` or `resolved`.
+    pub outcome: String,
+    /// Worker or runner that claimed the attempt.
+    pub worker_id: Option<String>,
+    pub error: Option<String>,
+    /// RFC 3339 timestamp the following retry was scheduled for.
+    pub retry_at: Option<String>,
+    /// Loop iterations the attempt ran; `0` for tasks without `loop_until`.
+    #[serde(default)]
+    pub iterations: u32,
+}
+
+/// Result of evaluating a task's trigger rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerEvaluation {
+    /// `true` when the task was marked ready, `false` when it was skipped.
+    pub satisfied: bool,
+    /// RFC 3339 timestamp.
+    pub evaluated_at: String,
+    /// Skip reason, when not satisfied.
+    pub reason: Option<String>,
+}
+
+/// Query string for `GET /tenants/{tenant_id}/stats/queue`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct QueueStatsQuery {
+    /// Window for dispatch latency, in seconds (default 3600).
+    pub window_secs: Option<u64>,
+}
+
+/// `GET /tenants/{tenant_id}/stats/queue` response: claim queue depth,
+/// time-to-dispatch and scheduler loop latency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QueueStats {
+    pub tenant_id: String,
+    pub window_secs: u64,
+    /// Tasks a worker could claim right now.
+    pub ready: i64,
+    /// Ready tasks waiting for their retry time.
+    pub backing_off: i64,
+    /// RFC 3339 timestamp the longest-waiting claimable task became
+    /// claimable; `null` when nothing is waiting.
+    pub oldest_ready_at: Option<String>,
+    pub dispatch: DispatchLatency,
+    /// `null` when no scheduler for this tenant is running in this server.
+    pub scheduler_loop: Option<SchedulerLoop>,
+}
+
+/// Time from claimable to claimed for tasks claimed in the window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DispatchLatency {
+    pub dispatched: i64,
+    pub avg_ms: Option<i64>,
+    pub p95_ms: Option<i64>,
+    pub max_ms: Option<i64>,
+}
+
+/// Scheduler loop tick latency over the most recent ticks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SchedulerLoop {
+    pub poll_interval_ms: u64,
+    pub ticks: u64,
+    pub failed_ticks: u64,
+    /// RFC 3339 timestamp; `null` before the first tick.
+    pub last_tick_at: Option<String>,
+    pub last_ms: Option<u64>,
+    pub avg_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+    pub max_ms: Option<u64>,
+}
@@ -0,0 +1,76 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Multi-region federation API types.
+
+use serde::{Deserialize, Serialize};
+
+use crate::executions::ExecutionSummary;
+
+/// One execution in the federated list, tagged with the region that ran it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FederatedExecutionSummary {
+    /// Region whose server (and database) holds the execution.
+    pub region: String,
+    #[serde(flatten)]
+    pub execution: ExecutionSummary,
+}
+
+/// A region that could not be queried.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RegionError {
+    pub region: String,
+    pub error: String,
+}
+
+/// `GET /tenants/{tenant_id}/federation/executions` response.
+///
+/// Partial by design: a region that is down is reported in `unreachable`
+/// and the rest of the view is still returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FederatedExecutionsResponse {
+    pub tenant_id: String,
+    /// Executions from every reachable region, newest first.
+    pub items: Vec<FederatedExecutionSummary>,
+    #[serde(default)]
+    pub unreachable: Vec<RegionError>,
+}
+
+/// One region in the federation and whether this server can reach it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FederationRegion {
+    pub region: String,
+    /// Peer base URL; `null` for the region serving the request.
+    pub url: Option<String>,
+    /// `true` for the region serving the request.
+    pub local: bool,
+    pub reachable: bool,
+    /// Why the peer could not be reached; `null` when reachable.
+    pub error: Option<String>,
+}
+
+/// `GET /federation/regions` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FederationRegionsResponse {
+    /// Region served by this server.
+    pub region: String,
+    pub regions: Vec<FederationRegion>,
+}
@@ -101,6 +101,50 @@ impl InputSlot {
     }
 }
 
+/// Assemble a standalone JSON Schema (draft 2020-12) object document from a
+/// slot list, for clients that render or validate a whole input form rather
+/// than slot by slot.
+///
+/// Each slot becomes a property carrying its schema fragment, plus its
+/// `default` when one is declared. A slot is listed in `required` only when it
+/// is required *and* has no default, matching the execute-time validation.
+/// Encrypted slots are described by their `{"$secret": "name"}` reference
+/// shape and marked `writeOnly`, since a literal value is never accepted.
+/// Undeclared extra keys stay allowed (`additionalProperties: true`), as the
+/// execute endpoint accepts free-form context alongside declared params.
+pub fn slots_json_schema(title: &str, slots: &[InputSlot]) -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    for slot in slots {
+        let mut schema = if slot.encrypted {
+            serde_json::json!({
+                "type": "object",
+                "properties": { "$secret": { "type": "string" } },
+                "required": ["$secret"],
+                "additionalProperties": false,
+                "writeOnly": true,
+            })
+        } else {
+            slot.schema.clone()
+        };
+        if let (Some(default), Some(obj)) = (&slot.default, schema.as_object_mut()) {
+            obj.insert("default".to_string(), default.clone());
+        }
+        properties.insert(slot.name.clone(), schema);
+        if slot.required && slot.default.is_none() {
+            required.push(serde_json::Value::String(slot.name.clone()));
+        }
+    }
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": title,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": true,
+    })
+}
+
 /// A declared injectable surface other than the workflow itself — a computation
 /// graph, reactor, or accumulator (CLOACI-I-0128 Task D). Carries the surface's
 /// declared input slots so the server can validate operator injections
@@ -119,3 +163,44 @@ pub struct DeclaredSurface {
     /// The surface's declared input slots.
     pub slots: Vec<InputSlot>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn slots_json_schema_builds_object_document() {
+        let slots = vec![
+            InputSlot::required("date", json!({"type": "string"})),
+            InputSlot::optional("limit", json!({"type": "integer"}), Some(json!(10))),
+            InputSlot {
+                required: true,
+                default: Some(json!("eu")),
+                ..InputSlot::required("region", json!({"type": "string"}))
+            },
+            InputSlot::secret("db"),
+        ];
+        let schema = slots_json_schema("etl params", &slots);
+
+        assert_eq!(schema["title"], "etl params");
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"]["date"], json!({"type": "string"}));
+        assert_eq!(
+            schema["properties"]["limit"],
+            json!({"type": "integer", "default": 10})
+        );
+        assert_eq!(schema["properties"]["db"]["required"], json!(["$secret"]));
+        assert_eq!(schema["properties"]["db"]["writeOnly"], true);
+        // A default satisfies a required slot, as at execute time.
+        assert_eq!(schema["required"], json!(["date", "db"]));
+    }
+
+    #[test]
+    fn slots_json_schema_empty_is_free_form() {
+        let schema = slots_json_schema("none", &[]);
+        assert_eq!(schema["properties"], json!({}));
+        assert_eq!(schema["required"], json!([]));
+        assert_eq!(schema["additionalProperties"], true);
+    }
+}
@@ -31,15 +31,18 @@
 //!   byte-compatible with the ad-hoc `serde_json::json!` responses they
 //!   replaced. Changes here are API changes and must ride a release.
 
+pub mod audit;
 pub mod common;
 pub mod compiler;
 pub mod delivery;
 pub mod error;
 pub mod executions;
+pub mod federation;
 pub mod fleet;
 pub mod health;
 pub mod input_interface;
 pub mod keys;
+pub mod maintenance;
 pub mod operations;
 pub mod reactor;
 pub mod secrets;
@@ -47,23 +50,38 @@ pub mod tenants;
 pub mod triggers;
 pub mod workflows;
 
+pub use audit::{
+    AuditChainBreak, AuditChainVerification, AuditEntry, ListAuditEntriesQuery,
+    VerifyAuditChainQuery,
+};
 pub use common::{ListResponse, TenantListResponse};
 pub use compiler::CompilerStatus;
 pub use delivery::{ClientMessage, EnvelopeError, ServerMessage, DELIVERY_PROTOCOL_VERSION};
 pub use error::ErrorBody;
 pub use executions::{
-    ExecuteRequest, ExecuteResponse, ExecutionDetail, ExecutionEvent, ExecutionEventsResponse,
-    ExecutionSummary, ExecutionTasksResponse, ListExecutionsQuery, TaskExecutionDetail,
+    AnnotateExecutionRequest, ContextAccessFinding, ContextAccessResponse, ContextKeyChange,
+    ContextKeyWrite, ContextLineageResponse, DispatchLatency, ExecuteOptions, ExecuteRequest,
+    ExecuteResponse, ExecutionAnnotation, ExecutionAnnotationsResponse, ExecutionArtifact,
+    ExecutionArtifactsResponse, ExecutionDetail, ExecutionEvent, ExecutionEventsResponse,
+    ExecutionRecording, ExecutionSummary, ExecutionTasksResponse, ExecutionTree, ExecutionTreeTask,
+    ListExecutionsQuery, QueueStats, QueueStatsQuery, RecordedStep, ResolveTaskRequest,
+    ResolveTaskResponse, SchedulerLoop, TaskAttempt, TaskContextAccess, TaskContextChanges,
+    TaskErrorPayload, TaskExecutionDetail, TriggerEvaluation,
+};
+pub use federation::{
+    FederatedExecutionSummary, FederatedExecutionsResponse, FederationRegion,
+    FederationRegionsResponse, RegionError,
 };
 pub use fleet::AgentInfo;
 pub use health::{
     AccumulatorStatus, GraphStatus, GraphTopology, GraphTopologyEdge, GraphTopologyNode,
     ReactorFire, ReactorFireTimeseries, ReactorStatus,
 };
-pub use input_interface::{DeclaredSurface, InputSlot};
+pub use input_interface::{slots_json_schema, DeclaredSurface, InputSlot};
 pub use keys::{
     CreateKeyRequest, KeyCreatedResponse, KeyInfo, KeyRevokedResponse, KeyRole, WsTicketResponse,
 };
+pub use maintenance::{CleanupEventsRequest, CleanupEventsResponse};
 pub use operations::{OpsMetricsEvent, ReconcilerStatus, ServerHealthLite};
 pub use reactor::{
     FireMode, FireReactorRequest, FireReactorResponse, InjectAccumulatorRequest,
@@ -76,11 +94,16 @@ pub use tenants::{
     CreateTenantRequest, TenantCreatedResponse, TenantRemovedResponse, TenantSummary,
 };
 pub use triggers::{
-    FireTriggerRequest, FireTriggerResponse, FiredExecution, ListTriggersQuery,
-    TriggerDetailResponse, TriggerExecution, TriggerPauseResponse, TriggerScheduleInfo,
-    TriggerScheduleSummary,
+    CreateTriggerRequest, FireTriggerRequest, FireTriggerResponse, FiredExecution,
+    ListTriggersQuery, TriggerCatchupRequest, TriggerCatchupResponse, TriggerConditionRequest,
+    TriggerConditionResponse, TriggerDedupRequest, TriggerDedupResponse, TriggerDetailResponse,
+    TriggerDstPolicyRequest, TriggerDstPolicyResponse, TriggerExecution, TriggerPauseResponse,
+    TriggerScheduleInfo, TriggerScheduleSummary, TriggerThrottleRequest, TriggerThrottleResponse,
 };
 pub use workflows::{
-    WorkflowDeletedResponse, WorkflowDetail, WorkflowPauseResponse, WorkflowSourceFile,
-    WorkflowSourceResponse, WorkflowSummary, WorkflowTaskNode, WorkflowUploadedResponse,
+    BuildProvenance, WorkflowConsumersResponse, WorkflowDeletedResponse, WorkflowDeprecationInfo,
+    WorkflowDeprecationRequest, WorkflowDeprecationResponse, WorkflowDetail, WorkflowPauseResponse,
+    WorkflowRollbackResponse, WorkflowSchemaResponse, WorkflowSourceFile, WorkflowSourceResponse,
+    WorkflowSummary, WorkflowTaskNode, WorkflowUploadedResponse, WorkflowVersionSummary,
+    WorkflowVersionsResponse,
 };
@@ -0,0 +1,45 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Platform maintenance operations run by the server on an operator's behalf.
+//!
+//! These let `cloacinactl admin` verbs reach the control plane over REST, so
+//! operators need an admin API key rather than database credentials.
+
+use serde::{Deserialize, Serialize};
+
+/// Body of `POST /v1/maintenance/cleanup-events`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CleanupEventsRequest {
+    /// Delete execution events older than this many seconds. Must be > 0.
+    pub older_than_seconds: u64,
+    /// Only count matching events; delete nothing.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Result of an execution-event cleanup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CleanupEventsResponse {
+    /// RFC 3339 cutoff; events created before it matched.
+    pub cutoff: String,
+    /// Echo of the request's `dry_run`.
+    pub dry_run: bool,
+    /// Events deleted, or — on a dry run — events that would be deleted.
+    pub events: u64,
+}
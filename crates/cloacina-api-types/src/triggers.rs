@@ -56,6 +56,154 @@ pub struct TriggerScheduleSummary {
     pub paused_at: Option<String>,
 }
 
+/// `PUT /tenants/{tenant_id}/triggers/{name}/dedup` request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerDedupRequest {
+    /// Dedup window in seconds. Omit (or 0) to dedup only against in-flight
+    /// executions.
+    #[serde(default)]
+    pub window_secs: Option<i64>,
+    /// Top-level context keys to hash, e.g. `["path"]`. Empty hashes the
+    /// whole context.
+    #[serde(default)]
+    pub keys: Vec<String>,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/dedup` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerDedupResponse {
+    pub tenant_id: String,
+    /// Schedule UUID.
+    pub id: String,
+    /// The name the schedule was addressed by (trigger or workflow name).
+    pub name: String,
+    /// Effective dedup window in seconds; `None` when disabled.
+    pub window_secs: Option<i64>,
+    /// Effective dedup keys (normalized); empty = whole context.
+    pub keys: Vec<String>,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/catchup` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerCatchupRequest {
+    /// `skip`, `run_all` or `run_latest_only`.
+    pub policy: String,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/catchup` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerCatchupResponse {
+    pub tenant_id: String,
+    /// Schedule UUID.
+    pub id: String,
+    /// The name the schedule was addressed by (workflow name).
+    pub name: String,
+    /// The catchup policy now in effect.
+    pub policy: String,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/dst` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerDstPolicyRequest {
+    /// `skip`, `run_once` or `run_twice`.
+    pub policy: String,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/dst` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerDstPolicyResponse {
+    pub tenant_id: String,
+    /// Schedule UUID.
+    pub id: String,
+    /// The name the schedule was addressed by (workflow name).
+    pub name: String,
+    /// The DST policy now in effect.
+    pub policy: String,
+    /// RFC 3339 next fire time, recomputed under the policy.
+    pub next_run_at: String,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/throttle` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerThrottleRequest {
+    /// Minimum milliseconds between two fires; a sooner firing is suppressed
+    /// as `rate_limited`. Omit (or 0) for no rate limit.
+    #[serde(default)]
+    pub min_interval_between_fires_ms: Option<i64>,
+    /// Quiet milliseconds required before a held firing runs; later firings
+    /// replace the held one. Omit (or 0) to fire immediately.
+    #[serde(default)]
+    pub debounce_window_ms: Option<i64>,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/throttle` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerThrottleResponse {
+    pub tenant_id: String,
+    /// Schedule UUID.
+    pub id: String,
+    /// The name the schedule was addressed by (trigger or workflow name).
+    pub name: String,
+    /// Effective minimum interval between fires; `None` when disabled.
+    pub min_interval_between_fires_ms: Option<i64>,
+    /// Effective debounce window; `None` when disabled.
+    pub debounce_window_ms: Option<i64>,
+}
+
+/// `PUT /tenants/{tenant_id}/triggers/{name}/condition` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerConditionRequest {
+    /// CEL expression over `context`, `probes` and `trigger`; a firing runs
+    /// only when it is true. Omit (or null) to clear the condition and its
+    /// probes.
+    #[serde(default)]
+    pub condition: Option<String>,
+    /// Probe definitions, e.g. `{"name": "flag", "kind": "file", "path":
+    /// "/data/ready"}`. Kinds: `file`, `env`, `last_execution`, `http`.
+    #[serde(default)]
+    pub probes: Vec<serde_json::Value>,
+}
+
+/// `POST /tenants/{tenant_id}/triggers` request: a declarative trigger that
+/// fires whenever its condition holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateTriggerRequest {
+    /// Trigger name; must not already be taken.
+    pub name: String,
+    pub workflow_name: String,
+    /// How often the condition is evaluated, in milliseconds.
+    pub poll_interval_ms: i64,
+    /// CEL condition (see [`TriggerConditionRequest::condition`]).
+    pub condition: String,
+    #[serde(default)]
+    pub probes: Vec<serde_json::Value>,
+}
+
+/// Response of `PUT .../condition` and `POST /tenants/{tenant_id}/triggers`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TriggerConditionResponse {
+    pub tenant_id: String,
+    /// Schedule UUID.
+    pub id: String,
+    /// The name the schedule was addressed by (trigger or workflow name).
+    pub name: String,
+    pub workflow_name: String,
+    /// The condition now in effect; `None` when cleared.
+    pub condition: Option<String>,
+    pub probes: Vec<serde_json::Value>,
+}
+
 /// Schedule fields in the trigger detail response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -78,6 +226,43 @@ pub struct TriggerScheduleInfo {
     /// RFC 3339 timestamp of when it was paused, if paused.
     #[serde(default)]
     pub paused_at: Option<String>,
+    /// Who paused it (the API key name for server pauses), if recorded.
+    #[serde(default)]
+    pub paused_by: Option<String>,
+    /// Why it was paused, if a reason was given.
+    #[serde(default)]
+    pub pause_reason: Option<String>,
+    /// Cron daylight-saving policy (`skip`, `run_once`, `run_twice`);
+    /// `None` for trigger schedules.
+    #[serde(default)]
+    pub dst_policy: Option<String>,
+    /// Trigger dedup window in seconds: a firing whose context hash matches
+    /// a firing within the window is suppressed. `None` = only an in-flight
+    /// execution suppresses a duplicate.
+    #[serde(default)]
+    pub dedup_window_secs: Option<i64>,
+    /// Top-level context keys the dedup hash covers; empty = whole context.
+    #[serde(default)]
+    pub dedup_keys: Vec<String>,
+    /// What a cron schedule does with missed fires: `skip`, `run_all` or
+    /// `run_latest_only`. `None` for trigger schedules.
+    #[serde(default)]
+    pub catchup_policy: Option<String>,
+    /// Minimum milliseconds between two fires of a trigger; `None` = no
+    /// rate limit.
+    #[serde(default)]
+    pub min_fire_interval_ms: Option<i64>,
+    /// Quiet milliseconds a debounced trigger waits before its latest
+    /// firing runs; `None` = no debounce.
+    #[serde(default)]
+    pub debounce_window_ms: Option<i64>,
+    /// Declarative CEL condition gating (or, with no registered trigger,
+    /// driving) the trigger's firings; `None` = none.
+    #[serde(default)]
+    pub condition: Option<String>,
+    /// Probes the condition reads as `probes.<name>`.
+    #[serde(default)]
+    pub probes: Vec<serde_json::Value>,
 }
 
 /// `POST /tenants/{tenant_id}/triggers/{name}/pause` and `/resume` response
@@ -141,6 +326,23 @@ pub struct TriggerExecution {
     pub started_at: String,
     /// RFC 3339 timestamp.
     pub completed_at: Option<String>,
+    /// Dedup hash of the firing's context (trigger schedules only).
+    #[serde(default)]
+    pub context_hash: Option<String>,
+    /// Why the firing was suppressed instead of executed: `active` (an
+    /// execution with the same hash was in flight), `window` (a firing
+    /// with the same hash happened within the dedup window) or
+    /// `rate_limited` (the trigger fired within its minimum fire interval).
+    /// `None` for firings that ran.
+    #[serde(default)]
+    pub suppressed_reason: Option<String>,
+    /// Schedule-execution UUID of the firing this one duplicated.
+    #[serde(default)]
+    pub duplicate_of: Option<String>,
+    /// RFC 3339 timestamp of the latest suppression folded into this row;
+    /// repeat suppressions of the same firing share one row.
+    #[serde(default)]
+    pub last_suppressed_at: Option<String>,
 }
 
 /// `GET /tenants/{tenant_id}/triggers/{name}` response.
@@ -62,6 +62,51 @@ pub struct WorkflowDeletedResponse {
     pub version: String,
 }
 
+/// `POST /tenants/{tenant_id}/workflows/{name}/rollback` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowRollbackResponse {
+    pub tenant_id: String,
+    /// Package UUID of the re-activated version.
+    pub id: String,
+    pub package_name: String,
+    /// Version that is active after the rollback.
+    pub version: String,
+    /// Version that was active before it.
+    pub previous_version: String,
+}
+
+/// One registered version of a package
+/// (`GET /tenants/{tenant_id}/workflows/{name}/versions`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowVersionSummary {
+    /// Package UUID of this version.
+    pub id: String,
+    pub version: String,
+    /// SHA-256 of the uploaded archive.
+    pub content_hash: String,
+    /// `pending`, `building`, `success` or `failed`.
+    pub build_status: String,
+    pub build_error: Option<String>,
+    /// Whether this is the version currently loaded.
+    pub active: bool,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+    /// RFC 3339 timestamp of the finished build.
+    pub compiled_at: Option<String>,
+}
+
+/// `GET /tenants/{tenant_id}/workflows/{name}/versions` response. Versions
+/// are newest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowVersionsResponse {
+    pub tenant_id: String,
+    pub package_name: String,
+    pub versions: Vec<WorkflowVersionSummary>,
+}
+
 /// One node in a workflow's task dependency graph — a task plus the ids of the
 /// tasks it depends on. The UI renders these as a DAG. (CLOACI-T-0663)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +128,34 @@ pub struct WorkflowTaskNode {
     pub doc_why: Option<String>,
 }
 
+/// Where a package was built from, recorded by `cloacinactl package pack` into
+/// the archive's `provenance.json`. Every field is best-effort: a value the
+/// packing host could not determine (no git checkout, no `rustc` on `PATH`,
+/// no lockfile) is `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BuildProvenance {
+    /// `HEAD` commit of the git checkout the package was packed from.
+    #[serde(default)]
+    pub git_commit: Option<String>,
+    /// Whether the checkout had uncommitted changes when packed, in which
+    /// case `git_commit` alone does not identify the source.
+    #[serde(default)]
+    pub git_dirty: bool,
+    /// RFC 3339 timestamp of when the package was packed.
+    #[serde(default)]
+    pub built_at: Option<String>,
+    /// `rustc --version` of the packing toolchain (Rust packages only).
+    #[serde(default)]
+    pub rustc_version: Option<String>,
+    /// Lockfile the dependency hash was taken from (e.g. `"Cargo.lock"`).
+    #[serde(default)]
+    pub lockfile: Option<String>,
+    /// Hex SHA-256 of that lockfile.
+    #[serde(default)]
+    pub lockfile_sha256: Option<String>,
+}
+
 /// One source file from a workflow package's retained `.cloacina` archive,
 /// surfaced read-only for display (CLOACI-T-0750).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -153,6 +226,39 @@ pub struct WorkflowDetail {
     /// render a typed execute form and the server validate context.
     #[serde(default)]
     pub declared_params: Vec<crate::InputSlot>,
+    /// Declared outputs (named, JSON-Schema-typed final-context keys) the
+    /// workflow produces, from `#[workflow(outputs(...))]`. Empty when
+    /// undeclared.
+    #[serde(default)]
+    pub declared_outputs: Vec<crate::InputSlot>,
+    /// Build provenance recorded when the package was packed. `None` for
+    /// packages packed without it.
+    #[serde(default)]
+    pub provenance: Option<BuildProvenance>,
+}
+
+/// `GET /tenants/{tenant_id}/workflows/{name}/schema` response — the
+/// workflow's declared params and outputs as standalone JSON Schema documents,
+/// so UIs can render and validate an ad-hoc execute form without interpreting
+/// slot lists themselves. Both are empty object schemas when undeclared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowSchemaResponse {
+    pub tenant_id: String,
+    /// Package UUID.
+    pub id: String,
+    pub package_name: String,
+    /// Executable workflow name (see `WorkflowSummary::workflow_name`).
+    pub workflow_name: String,
+    pub version: String,
+    /// JSON Schema (draft 2020-12) of the execution context the workflow
+    /// accepts, built from its declared params.
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub params: serde_json::Value,
+    /// JSON Schema (draft 2020-12) of the declared outputs in the final
+    /// context.
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub outputs: serde_json::Value,
 }
 
 /// `POST /tenants/{tenant_id}/workflows/{name}/pause` and `/resume` response
@@ -170,3 +276,61 @@ pub struct WorkflowPauseResponse {
     /// Current paused state after the operation.
     pub paused: bool,
 }
+
+/// `PUT /tenants/{tenant_id}/workflows/{name}/deprecation` request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowDeprecationRequest {
+    /// RFC 3339 timestamp the workflow is due to be removed at.
+    #[serde(default)]
+    pub sunset_at: Option<String>,
+    /// Note for consumers, e.g. which workflow replaces this one.
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// A workflow's deprecation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowDeprecationInfo {
+    pub workflow_name: String,
+    pub message: Option<String>,
+    /// RFC 3339 timestamp.
+    pub sunset_at: Option<String>,
+    /// RFC 3339 timestamp of when the workflow was first deprecated.
+    pub deprecated_at: String,
+    /// Whether the sunset date has passed.
+    pub past_sunset: bool,
+}
+
+/// `PUT` and `DELETE /tenants/{tenant_id}/workflows/{name}/deprecation`
+/// response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowDeprecationResponse {
+    pub tenant_id: String,
+    pub name: String,
+    /// Current deprecation state after the operation.
+    pub deprecated: bool,
+    /// The deprecation now in effect; `None` once cleared.
+    pub deprecation: Option<WorkflowDeprecationInfo>,
+}
+
+/// `GET /tenants/{tenant_id}/workflows/{name}/consumers` response: what still
+/// starts the workflow.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkflowConsumersResponse {
+    pub tenant_id: String,
+    pub name: String,
+    /// The workflow's deprecation, if it is deprecated.
+    pub deprecation: Option<WorkflowDeprecationInfo>,
+    /// Cron and trigger schedules that start the workflow.
+    pub schedules: Vec<crate::TriggerScheduleSummary>,
+    /// Reactors whose firings start the workflow.
+    pub reactors: Vec<String>,
+    /// UUIDs of executions still pending or running.
+    pub active_executions: Vec<String>,
+    /// `true` when nothing starts the workflow and nothing is in flight.
+    pub safe_to_remove: bool,
+}
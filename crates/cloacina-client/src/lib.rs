@@ -45,11 +45,13 @@
 
 mod error;
 mod profile;
+mod tls;
 mod ws;
 
 pub use cloacina_api_types as types;
 pub use error::ClientError;
 pub use profile::resolve_api_key_scheme;
+pub use tls::{TlsOptions, TLS_CA_CERT_ENV, TLS_CLIENT_CERT_ENV, TLS_CLIENT_KEY_ENV};
 pub use ws::{DeliveryPush, SubscribeOptions, DELIVERY_PROTOCOL_VERSION};
 
 use std::sync::Arc;
@@ -60,15 +62,22 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 use cloacina_api_types::{
-    AccumulatorStatus, AgentInfo, CompilerStatus, CreateKeyRequest, CreateTenantRequest,
-    DeclaredSurface, ExecuteRequest, ExecuteResponse, ExecutionDetail, ExecutionEventsResponse,
-    ExecutionSummary, ExecutionTasksResponse, FireReactorRequest, FireReactorResponse,
-    FireTriggerRequest, FireTriggerResponse, GraphStatus, InjectAccumulatorRequest,
-    InjectAccumulatorResponse, KeyCreatedResponse, KeyInfo, KeyRevokedResponse, KeyRole,
-    ListResponse, ReactorFire, ReactorFireTimeseries, ReactorStatus, TenantCreatedResponse,
-    TenantListResponse, TenantRemovedResponse, TenantSummary, TriggerDetailResponse,
-    TriggerPauseResponse, TriggerScheduleSummary, WorkflowDeletedResponse, WorkflowDetail,
-    WorkflowPauseResponse, WorkflowSourceResponse, WorkflowSummary, WorkflowUploadedResponse,
+    AccumulatorStatus, AgentInfo, AnnotateExecutionRequest, AuditChainVerification, AuditEntry,
+    CleanupEventsRequest, CleanupEventsResponse, CompilerStatus, ContextAccessResponse,
+    ContextLineageResponse, CreateKeyRequest, CreateTenantRequest, DeclaredSurface, ExecuteOptions,
+    ExecuteRequest, ExecuteResponse, ExecutionAnnotation, ExecutionAnnotationsResponse,
+    ExecutionArtifactsResponse, ExecutionDetail, ExecutionEventsResponse, ExecutionSummary,
+    ExecutionTasksResponse, ExecutionTree, FederatedExecutionsResponse, FederationRegionsResponse,
+    FireReactorRequest, FireReactorResponse, FireTriggerRequest, FireTriggerResponse, GraphStatus,
+    InjectAccumulatorRequest, InjectAccumulatorResponse, KeyCreatedResponse, KeyInfo,
+    KeyRevokedResponse, KeyRole, ListResponse, QueueStats, ReactorFire, ReactorFireTimeseries,
+    ReactorStatus, ResolveTaskRequest, ResolveTaskResponse, TenantCreatedResponse,
+    TenantListResponse, TenantRemovedResponse, TenantSummary, TriggerCatchupRequest,
+    TriggerCatchupResponse, TriggerDedupRequest, TriggerDedupResponse, TriggerDetailResponse,
+    TriggerPauseResponse, TriggerScheduleSummary, WorkflowConsumersResponse,
+    WorkflowDeletedResponse, WorkflowDeprecationRequest, WorkflowDeprecationResponse,
+    WorkflowDetail, WorkflowPauseResponse, WorkflowRollbackResponse, WorkflowSchemaResponse,
+    WorkflowSourceResponse, WorkflowSummary, WorkflowUploadedResponse, WorkflowVersionsResponse,
     WsTicketResponse,
 };
 
@@ -80,6 +89,7 @@ pub struct ClientBuilder {
     tenant: Option<String>,
     connect_timeout: Option<Duration>,
     timeout: Option<Duration>,
+    tls: TlsOptions,
 }
 
 impl ClientBuilder {
@@ -127,21 +137,34 @@ impl ClientBuilder {
         self
     }
 
+    /// Trust a private CA and/or present a client certificate (mTLS) on
+    /// every REST and WebSocket connection.
+    pub fn tls(mut self, tls: TlsOptions) -> Self {
+        self.tls = tls;
+        self
+    }
+
     pub fn build(self) -> Result<Client, ClientError> {
         let api_key = self
             .api_key
             .ok_or_else(|| ClientError::Config("no API key configured".into()))?;
-        let http = reqwest::Client::builder()
-            .connect_timeout(self.connect_timeout.unwrap_or(Duration::from_secs(5)))
-            .timeout(self.timeout.unwrap_or(Duration::from_secs(30)))
+        let http = self
+            .tls
+            .apply(
+                reqwest::Client::builder()
+                    .connect_timeout(self.connect_timeout.unwrap_or(Duration::from_secs(5)))
+                    .timeout(self.timeout.unwrap_or(Duration::from_secs(30))),
+            )?
             .build()
             .map_err(ClientError::from_reqwest)?;
+        let ws_tls = self.tls.rustls_config()?;
         Ok(Client {
             inner: Arc::new(ClientInner {
                 server: self.server.trim_end_matches('/').to_string(),
                 api_key,
                 tenant: self.tenant,
                 http,
+                ws_tls,
             }),
         })
     }
@@ -152,6 +175,8 @@ struct ClientInner {
     api_key: String,
     tenant: Option<String>,
     http: reqwest::Client,
+    /// TLS config for WebSocket connections; `None` uses the system roots.
+    ws_tls: Option<Arc<rustls::ClientConfig>>,
 }
 
 /// Typed client for the cloacina-server REST API + delivery WebSocket.
@@ -173,6 +198,15 @@ impl Client {
         self.inner.tenant.as_deref().unwrap_or("public")
     }
 
+    /// Connector for WebSocket connections honouring the client's TLS
+    /// options; `None` uses the defaults.
+    pub(crate) fn ws_connector(&self) -> Option<tokio_tungstenite::Connector> {
+        self.inner
+            .ws_tls
+            .clone()
+            .map(tokio_tungstenite::Connector::Rustls)
+    }
+
     fn url(&self, path: &str) -> String {
         format!("{}/{}", self.inner.server, path.trim_start_matches('/'))
     }
@@ -309,6 +343,40 @@ impl Client {
         Self::parse(response).await
     }
 
+    pub async fn list_audit_entries(
+        &self,
+        after: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<ListResponse<AuditEntry>, ClientError> {
+        let mut path = "/v1/audit/entries".to_string();
+        let mut sep = '?';
+        if let Some(a) = after {
+            path.push_str(&format!("{sep}after={a}"));
+            sep = '&';
+        }
+        if let Some(l) = limit {
+            path.push_str(&format!("{sep}limit={l}"));
+        }
+        self.get_json(&path).await
+    }
+
+    pub async fn verify_audit_chain(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<AuditChainVerification, ClientError> {
+        let mut path = "/v1/audit/verify".to_string();
+        let mut sep = '?';
+        if let Some(f) = from {
+            path.push_str(&format!("{sep}from={f}"));
+            sep = '&';
+        }
+        if let Some(t) = to {
+            path.push_str(&format!("{sep}to={t}"));
+        }
+        self.get_json(&path).await
+    }
+
     pub async fn create_tenant_key(
         &self,
         name: &str,
@@ -488,13 +556,28 @@ impl Client {
         &self,
         package: Vec<u8>,
         tenant: Option<&str>,
+    ) -> Result<WorkflowUploadedResponse, ClientError> {
+        self.upload_workflow_with_config(package, None, tenant)
+            .await
+    }
+
+    /// Upload a `.cloacina` package with the configuration values its
+    /// `config_schema` requires.
+    pub async fn upload_workflow_with_config(
+        &self,
+        package: Vec<u8>,
+        config: Option<&serde_json::Value>,
+        tenant: Option<&str>,
     ) -> Result<WorkflowUploadedResponse, ClientError> {
         let t = self.tenant_of(tenant);
         let part = reqwest::multipart::Part::bytes(package)
             .file_name("package.cloacina")
             .mime_str("application/octet-stream")
             .map_err(ClientError::from_reqwest)?;
-        let form = reqwest::multipart::Form::new().part("file", part);
+        let mut form = reqwest::multipart::Form::new().part("file", part);
+        if let Some(config) = config {
+            form = form.text("config", config.to_string());
+        }
         let response = self
             .request(Method::POST, &format!("/v1/tenants/{t}/workflows"))
             .multipart(form)
@@ -583,6 +666,48 @@ impl Client {
             &format!("/v1/tenants/{t}/workflows/{name}/execute"),
             &ExecuteRequest {
                 context: Some(context),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Execute a workflow with labels attached to the execution.
+    pub async fn execute_workflow_with_labels(
+        &self,
+        name: &str,
+        context: Value,
+        labels: std::collections::BTreeMap<String, String>,
+    ) -> Result<ExecuteResponse, ClientError> {
+        let t = self.tenant_segment();
+        self.post_json(
+            &format!("/v1/tenants/{t}/workflows/{name}/execute"),
+            &ExecuteRequest {
+                context: Some(context),
+                labels,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Execute a workflow with labels and per-execution overrides of the
+    /// runner configuration and task retry policies.
+    pub async fn execute_workflow_with_options(
+        &self,
+        name: &str,
+        context: Value,
+        labels: std::collections::BTreeMap<String, String>,
+        options: ExecuteOptions,
+    ) -> Result<ExecuteResponse, ClientError> {
+        let t = self.tenant_segment();
+        self.post_json(
+            &format!("/v1/tenants/{t}/workflows/{name}/execute"),
+            &ExecuteRequest {
+                context: Some(context),
+                labels,
+                options: Some(options),
+                ..Default::default()
             },
         )
         .await
@@ -594,25 +719,31 @@ impl Client {
         tenant: Option<&str>,
     ) -> Result<TenantListResponse<ExecutionSummary>, ClientError> {
         let t = self.tenant_of(tenant);
-        let mut path = format!("/v1/tenants/{t}/executions");
-        let mut sep = '?';
-        let mut push = |k: &str, v: String| {
-            path.push_str(&format!("{sep}{k}={v}"));
-            sep = '&';
-        };
-        if let Some(s) = &query.status {
-            push("status", urlencoding::encode(s).into_owned());
-        }
-        if let Some(w) = &query.workflow {
-            push("workflow", urlencoding::encode(w).into_owned());
-        }
-        if let Some(l) = query.limit {
-            push("limit", l.to_string());
-        }
-        if let Some(o) = query.offset {
-            push("offset", o.to_string());
-        }
-        self.get_json(&path).await
+        self.get_json(&executions_path(
+            format!("/v1/tenants/{t}/executions"),
+            query,
+        ))
+        .await
+    }
+
+    /// Executions of one tenant across every region of a federation,
+    /// queried through whichever region this client points at.
+    pub async fn list_federated_executions(
+        &self,
+        query: &cloacina_api_types::ListExecutionsQuery,
+        tenant: Option<&str>,
+    ) -> Result<FederatedExecutionsResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&executions_path(
+            format!("/v1/tenants/{t}/federation/executions"),
+            query,
+        ))
+        .await
+    }
+
+    /// Regions in the server's federation and whether each is reachable.
+    pub async fn federation_regions(&self) -> Result<FederationRegionsResponse, ClientError> {
+        self.get_json("/v1/federation/regions").await
     }
 
     pub async fn get_execution(
@@ -635,6 +766,149 @@ impl Client {
             .await
     }
 
+    /// Record an operator note (and optional resolution status) on an
+    /// execution. The server attributes it to the calling key.
+    pub async fn annotate_execution(
+        &self,
+        exec_id: &str,
+        note: &str,
+        resolution: Option<&str>,
+        tenant: Option<&str>,
+    ) -> Result<ExecutionAnnotation, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.post_json(
+            &format!("/v1/tenants/{t}/executions/{exec_id}/annotations"),
+            &AnnotateExecutionRequest {
+                note: note.to_string(),
+                resolution: resolution.map(str::to_string),
+            },
+        )
+        .await
+    }
+
+    /// Mark a failed task successful without re-running it. Skipped
+    /// downstream tasks are scheduled again and `note` is recorded as a
+    /// `resolved` annotation attributed to the calling key.
+    pub async fn resolve_task(
+        &self,
+        exec_id: &str,
+        task: &str,
+        note: &str,
+        tenant: Option<&str>,
+    ) -> Result<ResolveTaskResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        let task = urlencoding::encode(task);
+        self.post_json(
+            &format!("/v1/tenants/{t}/executions/{exec_id}/tasks/{task}/resolve"),
+            &ResolveTaskRequest {
+                note: note.to_string(),
+            },
+        )
+        .await
+    }
+
+    pub async fn list_execution_annotations(
+        &self,
+        exec_id: &str,
+        tenant: Option<&str>,
+    ) -> Result<ExecutionAnnotationsResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!("/v1/tenants/{t}/executions/{exec_id}/annotations"))
+            .await
+    }
+
+    /// Context keys each task of an execution read and wrote, with findings
+    /// against the workflow's dependencies.
+    pub async fn get_context_access(
+        &self,
+        exec_id: &str,
+        tenant: Option<&str>,
+    ) -> Result<ContextAccessResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!(
+            "/v1/tenants/{t}/executions/{exec_id}/context-access"
+        ))
+        .await
+    }
+
+    /// Which task changed each context key of an execution, with previous
+    /// values.
+    pub async fn get_context_lineage(
+        &self,
+        exec_id: &str,
+        tenant: Option<&str>,
+    ) -> Result<ContextLineageResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!(
+            "/v1/tenants/{t}/executions/{exec_id}/context-lineage"
+        ))
+        .await
+    }
+
+    /// Files the tasks of an execution stored, oldest first.
+    pub async fn list_execution_artifacts(
+        &self,
+        exec_id: &str,
+        tenant: Option<&str>,
+    ) -> Result<ExecutionArtifactsResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!("/v1/tenants/{t}/executions/{exec_id}/artifacts"))
+            .await
+    }
+
+    /// Bytes of the artifact `name` of an execution.
+    pub async fn download_execution_artifact(
+        &self,
+        exec_id: &str,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<Vec<u8>, ClientError> {
+        let t = self.tenant_of(tenant);
+        let name = urlencoding::encode(name);
+        let response = self
+            .request(
+                Method::GET,
+                &format!("/v1/tenants/{t}/executions/{exec_id}/artifacts/{name}"),
+            )
+            .send()
+            .await
+            .map_err(ClientError::from_reqwest)?;
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            let body = response.json::<Value>().await.unwrap_or(Value::Null);
+            return Err(ClientError::from_status(status, body));
+        }
+        let bytes = response.bytes().await.map_err(ClientError::from_reqwest)?;
+        Ok(bytes.to_vec())
+    }
+
+    /// Full task tree of an execution: per-attempt timings, errors,
+    /// retries, trigger outcomes and context ids.
+    pub async fn get_execution_tree(
+        &self,
+        exec_id: &str,
+        tenant: Option<&str>,
+    ) -> Result<ExecutionTree, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!("/v1/tenants/{t}/executions/{exec_id}/tree"))
+            .await
+    }
+
+    /// Claim queue depth, time-to-dispatch over the last `window_secs`
+    /// (server default: one hour) and scheduler loop latency.
+    pub async fn queue_stats(
+        &self,
+        window_secs: Option<u64>,
+        tenant: Option<&str>,
+    ) -> Result<QueueStats, ClientError> {
+        let t = self.tenant_of(tenant);
+        let path = match window_secs {
+            Some(w) => format!("/v1/tenants/{t}/stats/queue?window_secs={w}"),
+            None => format!("/v1/tenants/{t}/stats/queue"),
+        };
+        self.get_json(&path).await
+    }
+
     pub async fn get_execution_tasks(
         &self,
         tenant_id: &str,
@@ -738,6 +1012,83 @@ impl Client {
         .await
     }
 
+    /// Re-activate the version of package `name` registered before the
+    /// active one.
+    pub async fn rollback_workflow(
+        &self,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<WorkflowRollbackResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.post_json(
+            &format!("/v1/tenants/{t}/workflows/{name}/rollback"),
+            &Value::Null,
+        )
+        .await
+    }
+
+    /// Every registered version of package `name`, newest first.
+    pub async fn list_workflow_versions(
+        &self,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<WorkflowVersionsResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!("/v1/tenants/{t}/workflows/{name}/versions"))
+            .await
+    }
+
+    /// Mark workflow `name` deprecated, with an optional RFC 3339 sunset date
+    /// and a note for its consumers.
+    pub async fn deprecate_workflow(
+        &self,
+        name: &str,
+        sunset_at: Option<&str>,
+        message: Option<&str>,
+        tenant: Option<&str>,
+    ) -> Result<WorkflowDeprecationResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        let request = WorkflowDeprecationRequest {
+            sunset_at: sunset_at.map(str::to_string),
+            message: message.map(str::to_string),
+        };
+        self.put_json(
+            &format!("/v1/tenants/{t}/workflows/{name}/deprecation"),
+            &request,
+        )
+        .await
+    }
+
+    /// Clear the deprecation of workflow `name`.
+    pub async fn undeprecate_workflow(
+        &self,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<WorkflowDeprecationResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        let response = self
+            .request(
+                Method::DELETE,
+                &format!("/v1/tenants/{t}/workflows/{name}/deprecation"),
+            )
+            .send()
+            .await
+            .map_err(ClientError::from_reqwest)?;
+        Self::parse(response).await
+    }
+
+    /// What still starts workflow `name`: schedules, reactor subscriptions
+    /// and in-flight executions.
+    pub async fn get_workflow_consumers(
+        &self,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<WorkflowConsumersResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!("/v1/tenants/{t}/workflows/{name}/consumers"))
+            .await
+    }
+
     pub async fn get_workflow_source(
         &self,
         name: &str,
@@ -748,6 +1099,41 @@ impl Client {
             .await
     }
 
+    /// The active `.cloacina` archive of package `name`, byte-for-byte as
+    /// uploaded.
+    pub async fn download_workflow_archive(
+        &self,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<Vec<u8>, ClientError> {
+        let t = self.tenant_of(tenant);
+        let response = self
+            .request(
+                Method::GET,
+                &format!("/v1/tenants/{t}/workflows/{name}/archive"),
+            )
+            .send()
+            .await
+            .map_err(ClientError::from_reqwest)?;
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            let body = response.json::<Value>().await.unwrap_or(Value::Null);
+            return Err(ClientError::from_status(status, body));
+        }
+        let bytes = response.bytes().await.map_err(ClientError::from_reqwest)?;
+        Ok(bytes.to_vec())
+    }
+
+    pub async fn get_workflow_schema(
+        &self,
+        name: &str,
+        tenant: Option<&str>,
+    ) -> Result<WorkflowSchemaResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.get_json(&format!("/v1/tenants/{t}/workflows/{name}/schema"))
+            .await
+    }
+
     pub async fn pause_trigger(
         &self,
         name: &str,
@@ -799,6 +1185,37 @@ impl Client {
         .await
     }
 
+    /// Configure how a trigger deduplicates firings (window + hashed keys).
+    pub async fn set_trigger_dedup(
+        &self,
+        name: &str,
+        request: &TriggerDedupRequest,
+        tenant: Option<&str>,
+    ) -> Result<TriggerDedupResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        self.put_json(&format!("/v1/tenants/{t}/triggers/{name}/dedup"), request)
+            .await
+    }
+
+    /// Set what a cron schedule does with missed fires
+    /// (`skip`, `run_all` or `run_latest_only`).
+    pub async fn set_trigger_catchup(
+        &self,
+        name: &str,
+        policy: &str,
+        tenant: Option<&str>,
+    ) -> Result<TriggerCatchupResponse, ClientError> {
+        let t = self.tenant_of(tenant);
+        let request = TriggerCatchupRequest {
+            policy: policy.to_string(),
+        };
+        self.put_json(
+            &format!("/v1/tenants/{t}/triggers/{name}/catchup"),
+            &request,
+        )
+        .await
+    }
+
     // ---- fleet / compiler ----
 
     pub async fn list_agents(&self) -> Result<ListResponse<AgentInfo>, ClientError> {
@@ -809,6 +1226,25 @@ impl Client {
         self.get_json("/v1/compiler/status").await
     }
 
+    // ---- maintenance (admin) ----
+
+    /// Prune execution events older than `older_than_seconds`; with
+    /// `dry_run`, only count them.
+    pub async fn cleanup_events(
+        &self,
+        older_than_seconds: u64,
+        dry_run: bool,
+    ) -> Result<CleanupEventsResponse, ClientError> {
+        self.post_json(
+            "/v1/maintenance/cleanup-events",
+            &CleanupEventsRequest {
+                older_than_seconds,
+                dry_run,
+            },
+        )
+        .await
+    }
+
     // ---- WebSocket (substrate delivery) ----
 
     /// Subscribe to the substrate delivery stream for a recipient. Yields
@@ -842,3 +1278,31 @@ impl Client {
         ws::follow_execution_events(self.clone(), execution_id.to_string(), options)
     }
 }
+
+/// Append a `ListExecutionsQuery` to an executions route as a query string.
+fn executions_path(mut path: String, query: &cloacina_api_types::ListExecutionsQuery) -> String {
+    let mut sep = '?';
+    let mut push = |k: &str, v: String| {
+        path.push_str(&format!("{sep}{k}={v}"));
+        sep = '&';
+    };
+    if let Some(s) = &query.status {
+        push("status", urlencoding::encode(s).into_owned());
+    }
+    if let Some(w) = &query.workflow {
+        push("workflow", urlencoding::encode(w).into_owned());
+    }
+    if let Some(l) = &query.label {
+        push("label", urlencoding::encode(l).into_owned());
+    }
+    if let Some(r) = &query.run_id {
+        push("run_id", urlencoding::encode(r).into_owned());
+    }
+    if let Some(l) = query.limit {
+        push("limit", l.to_string());
+    }
+    if let Some(o) = query.offset {
+        push("offset", o.to_string());
+    }
+    path
+}
@@ -24,7 +24,7 @@ use std::path::Path;
 use serde::Deserialize;
 
 use crate::error::ClientError;
-use crate::ClientBuilder;
+use crate::{ClientBuilder, TlsOptions};
 
 #[derive(Debug, Default, Deserialize)]
 struct CloacinactlConfig {
@@ -38,6 +38,8 @@ struct CloacinactlConfig {
 struct Profile {
     server: String,
     api_key: String,
+    #[serde(default)]
+    tls: Option<TlsOptions>,
 }
 
 pub(crate) fn builder_from_profile(
@@ -74,7 +76,11 @@ pub(crate) fn builder_from_profile(
         ))
     })?;
 
-    Ok(ClientBuilder::new(&p.server).api_key(resolve_api_key_scheme(&p.api_key)?))
+    // Profile TLS settings win field by field over the CLOACINA_TLS_* env vars.
+    let tls = p.tls.clone().unwrap_or_default().or(TlsOptions::from_env());
+    Ok(ClientBuilder::new(&p.server)
+        .api_key(resolve_api_key_scheme(&p.api_key)?)
+        .tls(tls))
 }
 
 /// Resolve an api-key value that may carry a scheme prefix —
@@ -0,0 +1,235 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Client-side TLS for servers behind a private CA or requiring client
+//! certificates (mTLS). Applied to both the REST client and the WebSocket
+//! connections, so every transport to the server trusts the same roots and
+//! presents the same identity.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ClientError;
+
+/// Env var naming a PEM CA bundle to trust (see [`TlsOptions::from_env`]).
+pub const TLS_CA_CERT_ENV: &str = "CLOACINA_TLS_CA_CERT";
+/// Env var naming the PEM client certificate chain for mTLS.
+pub const TLS_CLIENT_CERT_ENV: &str = "CLOACINA_TLS_CLIENT_CERT";
+/// Env var naming the PEM private key for the client certificate.
+pub const TLS_CLIENT_KEY_ENV: &str = "CLOACINA_TLS_CLIENT_KEY";
+
+/// TLS settings for connections to `cloacina-server`. The default trusts the
+/// system roots and presents no client certificate. Also the `tls` table of a
+/// `cloacinactl` profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsOptions {
+    /// PEM bundle of CAs trusted in addition to the system roots.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate chain, leaf first. Requires `client_key`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for `client_cert`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+}
+
+impl TlsOptions {
+    /// Read [`TLS_CA_CERT_ENV`], [`TLS_CLIENT_CERT_ENV`] and
+    /// [`TLS_CLIENT_KEY_ENV`]; unset or empty variables leave the field unset.
+    pub fn from_env() -> Self {
+        let path = |var: &str| {
+            std::env::var(var)
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .map(PathBuf::from)
+        };
+        Self {
+            ca_cert: path(TLS_CA_CERT_ENV),
+            client_cert: path(TLS_CLIENT_CERT_ENV),
+            client_key: path(TLS_CLIENT_KEY_ENV),
+        }
+    }
+
+    /// Fill every field left unset here from `fallback`.
+    pub fn or(self, fallback: TlsOptions) -> Self {
+        Self {
+            ca_cert: self.ca_cert.or(fallback.ca_cert),
+            client_cert: self.client_cert.or(fallback.client_cert),
+            client_key: self.client_key.or(fallback.client_key),
+        }
+    }
+
+    /// Whether these options change anything from the defaults.
+    pub fn is_empty(&self) -> bool {
+        self.ca_cert.is_none() && self.client_cert.is_none() && self.client_key.is_none()
+    }
+
+    fn identity_paths(&self) -> Result<Option<(&Path, &Path)>, ClientError> {
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => Ok(Some((cert, key))),
+            (None, None) => Ok(None),
+            _ => Err(ClientError::Config(
+                "a TLS client certificate and key must be configured together".into(),
+            )),
+        }
+    }
+
+    /// Apply the options to a REST client builder.
+    pub fn apply(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, ClientError> {
+        if let Some(ca) = &self.ca_cert {
+            let pem = read(ca)?;
+            let certs = reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| {
+                ClientError::Config(format!("invalid CA bundle {}: {e}", ca.display()))
+            })?;
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+        if let Some((cert, key)) = self.identity_paths()? {
+            let mut pem = read(cert)?;
+            pem.push(b'\n');
+            pem.extend(read(key)?);
+            let identity = reqwest::Identity::from_pem(&pem).map_err(|e| {
+                ClientError::Config(format!(
+                    "invalid client certificate {} / key {}: {e}",
+                    cert.display(),
+                    key.display()
+                ))
+            })?;
+            builder = builder.identity(identity);
+        }
+        Ok(builder)
+    }
+
+    /// rustls client config for WebSocket connections, or `None` when the
+    /// defaults apply.
+    pub fn rustls_config(&self) -> Result<Option<Arc<rustls::ClientConfig>>, ClientError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add_parsable_certificates(rustls_native_certs::load_native_certs().certs);
+        if let Some(ca) = &self.ca_cert {
+            for cert in load_certs(ca)? {
+                roots.add(cert).map_err(|e| {
+                    ClientError::Config(format!("invalid CA certificate in {}: {e}", ca.display()))
+                })?;
+            }
+        }
+
+        let builder = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| ClientError::Config(format!("TLS protocol versions: {e}")))?
+        .with_root_certificates(roots);
+        let config = match self.identity_paths()? {
+            Some((cert, key)) => {
+                let key_der = PrivateKeyDer::from_pem_file(key).map_err(|e| {
+                    ClientError::Config(format!("invalid private key {}: {e}", key.display()))
+                })?;
+                builder
+                    .with_client_auth_cert(load_certs(cert)?, key_der)
+                    .map_err(|e| {
+                        ClientError::Config(format!(
+                            "invalid client certificate {}: {e}",
+                            cert.display()
+                        ))
+                    })?
+            }
+            None => builder.with_no_client_auth(),
+        };
+        Ok(Some(Arc::new(config)))
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, ClientError> {
+    std::fs::read(path)
+        .map_err(|e| ClientError::Config(format!("failed to read {}: {e}", path.display())))
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>, ClientError> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .and_then(|iter| iter.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| {
+            ClientError::Config(format!("invalid PEM certificates {}: {e}", path.display()))
+        })?;
+    if certs.is_empty() {
+        return Err(ClientError::Config(format!(
+            "no certificates found in {}",
+            path.display()
+        )));
+    }
+    Ok(certs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_need_no_custom_config() {
+        assert!(TlsOptions::default().rustls_config().unwrap().is_none());
+    }
+
+    #[test]
+    fn client_cert_without_key_is_rejected() {
+        let options = TlsOptions {
+            client_cert: Some("/etc/tls/client.pem".into()),
+            ..Default::default()
+        };
+        let err = options.apply(reqwest::Client::builder()).unwrap_err();
+        assert!(err.to_string().contains("configured together"));
+    }
+
+    #[test]
+    fn unset_fields_fall_back() {
+        let explicit = TlsOptions {
+            ca_cert: Some("/profile/ca.pem".into()),
+            ..Default::default()
+        };
+        let env = TlsOptions {
+            ca_cert: Some("/env/ca.pem".into()),
+            client_cert: Some("/env/client.pem".into()),
+            client_key: Some("/env/client.key".into()),
+        };
+        let merged = explicit.or(env);
+        assert_eq!(merged.ca_cert, Some("/profile/ca.pem".into()));
+        assert_eq!(merged.client_cert, Some("/env/client.pem".into()));
+        assert_eq!(merged.client_key, Some("/env/client.key".into()));
+    }
+
+    #[test]
+    fn missing_ca_bundle_is_a_config_error() {
+        let options = TlsOptions {
+            ca_cert: Some("/nonexistent/ca.pem".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            options.apply(reqwest::Client::builder()),
+            Err(ClientError::Config(_))
+        ));
+    }
+}
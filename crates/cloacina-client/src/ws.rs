@@ -99,9 +99,14 @@ pub(crate) fn subscribe_delivery(
                 urlencoding::encode(&ticket),
             );
 
-            let (mut socket, _resp) = tokio_tungstenite::connect_async(&url)
-                .await
-                .map_err(|e| ClientError::Ws(format!("connect failed for {url}: {e}")))?;
+            let (mut socket, _resp) = tokio_tungstenite::connect_async_tls_with_config(
+                &url,
+                None,
+                false,
+                client.ws_connector(),
+            )
+            .await
+            .map_err(|e| ClientError::Ws(format!("connect failed for {url}: {e}")))?;
 
             // Declare our protocol version; an incompatible server closes
             // with 4426, which we surface as a terminal error below.
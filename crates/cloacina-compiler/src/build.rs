@@ -276,7 +276,14 @@ async fn run_build(
                 exit_status: Some(0),
             })
         }
-        _ => cargo_build(package_id, &source_dir, config).await,
+        _ => match bundled_artifact(&source_dir, config) {
+            Ok(Some(artifact)) => Ok(CargoBuildSuccess {
+                artifact,
+                exit_status: Some(0),
+            }),
+            Ok(None) => cargo_build(package_id, &source_dir, config).await,
+            Err(reason) => Err(BuildError::internal(reason)),
+        },
     };
 
     // Single emit-finished point: classify outcome, compute wall-clock,
@@ -538,6 +545,38 @@ async fn run_build(
     final_result
 }
 
+/// Multi-arch bundles: the prebuilt cdylib the package ships under
+/// `targets/<triple>/` for the triple this compiler produces
+/// (`config.build_target`, else the host's), used instead of running cargo.
+/// `Ok(None)` means "build from source". A package that bundles binaries but
+/// none for this triple and carries no `Cargo.toml` cannot be built here and
+/// is an error.
+fn bundled_artifact(source_dir: &Path, config: &CompilerConfig) -> Result<Option<Vec<u8>>, String> {
+    let triple = match config.build_target.as_deref() {
+        Some(target) => cloacina::packaging::normalize_target_triple(target)
+            .unwrap_or_else(|| target.to_string()),
+        None => cloacina::fleet::protocol::host_target_triple(),
+    };
+    if let Some(artifact) = cloacina::packaging::read_bundled_binary(source_dir, &triple)? {
+        info!(
+            %triple,
+            bytes = artifact.len(),
+            "using bundled binary — skipping cargo build"
+        );
+        return Ok(Some(artifact));
+    }
+    let bundled = cloacina::packaging::bundled_targets(source_dir);
+    if !bundled.is_empty() && !source_dir.join("Cargo.toml").exists() {
+        return Err(format!(
+            "package bundles binaries for [{}] but none for '{}', and has no Cargo.toml \
+             to build from",
+            bundled.join(", "),
+            triple
+        ));
+    }
+    Ok(None)
+}
+
 fn load_manifest(source_dir: &Path) -> Result<toml::Value, String> {
     let manifest_path = source_dir.join("package.toml");
     let raw = std::fs::read_to_string(&manifest_path)
@@ -1364,7 +1364,7 @@ fn parse_param_required(attr: &syn::Attribute) -> SynResult<bool> {
 }
 
 /// If `ty` is `Option<T>`, return `T`.
-fn option_inner(ty: &Type) -> Option<Type> {
+pub(crate) fn option_inner(ty: &Type) -> Option<Type> {
     if let Type::Path(tp) = ty {
         let seg = tp.path.segments.last()?;
         if seg.ident == "Option" {
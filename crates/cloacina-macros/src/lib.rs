@@ -71,6 +71,11 @@ pub fn task(args: TokenStream, input: TokenStream) -> TokenStream {
 ///   `cloacina::Runtime::seed_from_inventory`
 /// - **Packaged** (`features = ["packaged"]`): FFI exports for `.cloacina` packages
 ///
+/// An embedded workflow may inline another registered workflow (such as one
+/// loaded from a package) as a sub-DAG with
+/// `embed_workflow!(prefix = "vendor", workflow = "vendor_ingest", dependencies = ["fetch"]);`
+/// inside the module; tasks depend on the whole sub-DAG through its prefix.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -32,7 +32,7 @@ use crate::registry::{get_registry, TaskInfo};
 /// # Fields
 ///
 /// * `id` - Unique identifier for the task (required)
-/// * `dependencies` - List of task IDs this task depends on
+/// * `dependencies` - List of task IDs this task depends on; `soft("id")` marks an ordering-only edge
 /// * `retry_attempts` - Maximum number of retry attempts (default: 3)
 /// * `retry_backoff` - Backoff strategy: "fixed", "linear", or "exponential" (default: "exponential")
 /// * `retry_delay_ms` - Initial delay between retries in milliseconds (default: 1000)
@@ -40,12 +40,29 @@ use crate::registry::{get_registry, TaskInfo};
 /// * `retry_condition` - Condition for retrying: "never", "all", "transient", or error patterns
 /// * `retry_jitter` - Whether to add random jitter to retry delays (default: true)
 /// * `trigger_rules` - Rules that determine when the task should be executed
+/// * `short_circuit` - Output key; when empty after success, descendants are skipped
+/// * `branch_key` - Output key holding the branch name this decider task selects
+/// * `branch` - Branch this task heads; it runs only when its decider selects it
+/// * `loop_until` - Context condition (trigger-rule syntax) ending the task's loop
+/// * `max_iterations` - Most iterations of a `loop_until` loop per attempt
+/// * `memory_limit_mb` - Memory limit in MiB; the task fails once it uses more
+/// * `timeout_ms` - How long one attempt may run before it fails with a timeout
+/// * `expected_duration_ms` - How long one attempt is expected to run, checked against execution deadlines
+/// * `approval` - When `true`, the task waits for a human approval before it runs
+/// * `workload` - Workload class: "cpu", "io" or "memory" (long forms such as "cpu_bound" accepted)
+/// * `resources` - Named resources the task uses: ["gpu", "db_connection"]; each takes a slot of the runner's pool of that name
+/// * `produces` - Context keys the task writes, with JSON types: ["processed_data: object", "count: number"]
+/// * `scoped` - When `true`, keys the task adds or changes are moved under its id
+/// * `reads` - Context keys the task sees: ["rows", "settings"]; the rest pass through to its output
+/// * `config` - JSON object literal; strings may embed `{{ … }}` templates rendered at schedule time
 /// * `on_success` - Function to call on successful task completion: `async fn(&str, &Context<Value>)`
 /// * `on_failure` - Function to call on task failure: `async fn(&str, &TaskError, &Context<Value>)`
 #[derive(Default)]
 pub struct TaskAttributes {
     pub id: String,
     pub dependencies: Vec<String>, // Will need to convert to TaskNamespace during code generation
+    /// Subset of `dependencies` declared as `soft("id")`: ordering-only edges.
+    pub soft_dependencies: Vec<String>,
     pub retry_attempts: Option<i32>,
     pub retry_backoff: Option<String>,
     pub retry_delay_ms: Option<i32>,
@@ -53,6 +70,27 @@ pub struct TaskAttributes {
     pub retry_condition: Option<String>,
     pub retry_jitter: Option<bool>,
     pub trigger_rules: Option<Expr>,
+    pub short_circuit: Option<String>,
+    pub branch_key: Option<String>,
+    pub branch: Option<String>,
+    /// `loop_until` condition, already lowered to trigger-rule JSON.
+    pub loop_until: Option<String>,
+    pub max_iterations: Option<u32>,
+    pub memory_limit_mb: Option<u64>,
+    pub timeout_ms: Option<u64>,
+    pub expected_duration_ms: Option<u64>,
+    pub approval: bool,
+    /// Workload class name, validated against `WorkloadClass::from_str`'s forms.
+    pub workload: Option<String>,
+    /// Named resources, each limited by the runner's pool of that name.
+    pub resources: Vec<String>,
+    /// Declared outputs as `(key, OutputKind variant)` pairs.
+    pub produces: Vec<(String, String)>,
+    pub scoped: bool,
+    /// Declared reads; `None` when the task sees the whole context.
+    pub reads: Option<Vec<String>>,
+    /// Declarative task configuration, validated as a JSON object at compile time.
+    pub config: Option<String>,
     pub on_success: Option<Expr>,
     pub on_failure: Option<Expr>,
     /// Optional `invokes = computation_graph("name")` clause. Set when the
@@ -76,6 +114,7 @@ impl Parse for TaskAttributes {
     fn parse(input: ParseStream) -> SynResult<Self> {
         let mut id = None;
         let mut dependencies = Vec::new();
+        let mut soft_dependencies = Vec::new();
         let mut retry_attempts = None;
         let mut retry_backoff = None;
         let mut retry_delay_ms = None;
@@ -83,6 +122,21 @@ impl Parse for TaskAttributes {
         let mut retry_condition = None;
         let mut retry_jitter = None;
         let mut trigger_rules = None;
+        let mut short_circuit = None;
+        let mut branch_key = None;
+        let mut branch = None;
+        let mut loop_until = None;
+        let mut max_iterations = None;
+        let mut memory_limit_mb = None;
+        let mut timeout_ms = None;
+        let mut expected_duration_ms = None;
+        let mut approval = false;
+        let mut workload = None;
+        let mut resources = Vec::new();
+        let mut produces: Vec<(String, String)> = Vec::new();
+        let mut scoped = false;
+        let mut reads: Option<Vec<String>> = None;
+        let mut config = None;
         let mut on_success = None;
         let mut on_failure = None;
         let mut invokes_computation_graph: Option<String> = None;
@@ -98,13 +152,28 @@ impl Parse for TaskAttributes {
                     id = Some(lit.value());
                 }
                 "dependencies" => {
-                    // Parse array of strings: ["dep1", "dep2"]
+                    // Parse array of strings: ["dep1", "dep2", soft("dep3")]
                     let content;
                     syn::bracketed!(content in input);
 
                     while !content.is_empty() {
-                        let lit: LitStr = content.parse()?;
-                        dependencies.push(lit.value());
+                        if content.peek(Ident) {
+                            let marker: Ident = content.parse()?;
+                            if marker != "soft" {
+                                return Err(syn::Error::new(
+                                    marker.span(),
+                                    "expected a task id string or soft(\"task_id\")",
+                                ));
+                            }
+                            let inner;
+                            syn::parenthesized!(inner in content);
+                            let lit: LitStr = inner.parse()?;
+                            soft_dependencies.push(lit.value());
+                            dependencies.push(lit.value());
+                        } else {
+                            let lit: LitStr = content.parse()?;
+                            dependencies.push(lit.value());
+                        }
 
                         if !content.is_empty() {
                             content.parse::<Token![,]>()?;
@@ -139,6 +208,206 @@ impl Parse for TaskAttributes {
                     let expr: Expr = input.parse()?;
                     trigger_rules = Some(expr);
                 }
+                "short_circuit" => {
+                    let lit: LitStr = input.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "short_circuit expects a non-empty context key",
+                        ));
+                    }
+                    short_circuit = Some(lit.value());
+                }
+                "branch_key" => {
+                    let lit: LitStr = input.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "branch_key expects a non-empty context key",
+                        ));
+                    }
+                    branch_key = Some(lit.value());
+                }
+                "branch" => {
+                    let lit: LitStr = input.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "branch expects a non-empty branch name",
+                        ));
+                    }
+                    branch = Some(lit.value());
+                }
+                "loop_until" => {
+                    let expr: Expr = input.parse()?;
+                    let rule = parse_trigger_rules_expr(&expr)
+                        .and_then(|rule| validate_loop_condition(&rule).map(|()| rule))
+                        .map_err(|e| {
+                            syn::Error::new_spanned(&expr, format!("Invalid loop_until: {}", e))
+                        })?;
+                    loop_until = Some(rule.to_string());
+                }
+                "max_iterations" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    let value: u32 = lit.base10_parse()?;
+                    if value == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "max_iterations must be at least 1",
+                        ));
+                    }
+                    max_iterations = Some(value);
+                }
+                "memory_limit_mb" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    let mb: u64 = lit.base10_parse()?;
+                    if mb == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "memory_limit_mb must be greater than zero",
+                        ));
+                    }
+                    memory_limit_mb = Some(mb);
+                }
+                "timeout_ms" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    let ms: u64 = lit.base10_parse()?;
+                    if ms == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "timeout_ms must be greater than zero",
+                        ));
+                    }
+                    timeout_ms = Some(ms);
+                }
+                "expected_duration_ms" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    let ms: u64 = lit.base10_parse()?;
+                    if ms == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "expected_duration_ms must be greater than zero",
+                        ));
+                    }
+                    expected_duration_ms = Some(ms);
+                }
+                "approval" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    approval = lit.value;
+                }
+                "workload" => {
+                    let lit: LitStr = input.parse()?;
+                    let class = match lit.value().replace('-', "_").as_str() {
+                        "cpu" | "cpu_bound" => "Cpu",
+                        "io" | "io_bound" => "Io",
+                        "memory" | "memory_heavy" => "Memory",
+                        other => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                format!(
+                                    "unknown workload class '{}', expected \"cpu\", \"io\" or \"memory\"",
+                                    other
+                                ),
+                            ))
+                        }
+                    };
+                    workload = Some(class.to_string());
+                }
+                "resources" => {
+                    // Parse array of strings: ["gpu", "db_connection"]
+                    let content;
+                    syn::bracketed!(content in input);
+
+                    while !content.is_empty() {
+                        let lit: LitStr = content.parse()?;
+                        if lit.value().trim().is_empty() {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "resource names may not be empty",
+                            ));
+                        }
+                        if !resources.contains(&lit.value()) {
+                            resources.push(lit.value());
+                        }
+
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                }
+                "produces" => {
+                    // Parse array of declarations: ["processed_data: object", "count: number"]
+                    let content;
+                    syn::bracketed!(content in input);
+
+                    while !content.is_empty() {
+                        let lit: LitStr = content.parse()?;
+                        let (key, kind) = parse_produced_output(&lit.value())
+                            .map_err(|message| syn::Error::new(lit.span(), message))?;
+                        if produces.iter().any(|(existing, _)| *existing == key) {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                format!("output '{}' is declared more than once", key),
+                            ));
+                        }
+                        produces.push((key, kind));
+
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                }
+                "scoped" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    scoped = lit.value;
+                }
+                "reads" => {
+                    // Parse array of keys: ["rows", "settings"]
+                    let content;
+                    syn::bracketed!(content in input);
+                    let mut keys = Vec::new();
+
+                    while !content.is_empty() {
+                        let lit: LitStr = content.parse()?;
+                        if lit.value().trim().is_empty() {
+                            return Err(syn::Error::new(lit.span(), "read keys may not be empty"));
+                        }
+                        if keys.contains(&lit.value()) {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                format!("key '{}' is declared more than once", lit.value()),
+                            ));
+                        }
+                        keys.push(lit.value());
+
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                    if keys.is_empty() {
+                        return Err(syn::Error::new(
+                            name.span(),
+                            "reads must name at least one key",
+                        ));
+                    }
+                    reads = Some(keys);
+                }
+                "config" => {
+                    let lit: LitStr = input.parse()?;
+                    match serde_json::from_str::<serde_json::Value>(&lit.value()) {
+                        Ok(serde_json::Value::Object(_)) => {}
+                        Ok(_) => {
+                            return Err(syn::Error::new(lit.span(), "config expects a JSON object"))
+                        }
+                        Err(e) => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                format!("config is not valid JSON: {}", e),
+                            ))
+                        }
+                    }
+                    config = Some(lit.value());
+                }
                 "on_success" => {
                     let expr: Expr = input.parse()?;
                     on_success = Some(expr);
@@ -209,9 +478,17 @@ impl Parse for TaskAttributes {
         // ident is available (an empty string here is the "not provided" sentinel).
         let id = id.unwrap_or_default();
 
+        if loop_until.is_some() != max_iterations.is_some() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "loop_until and max_iterations must be declared together",
+            ));
+        }
+
         Ok(TaskAttributes {
             id,
             dependencies,
+            soft_dependencies,
             retry_attempts,
             retry_backoff,
             retry_delay_ms,
@@ -219,6 +496,21 @@ impl Parse for TaskAttributes {
             retry_condition,
             retry_jitter,
             trigger_rules,
+            short_circuit,
+            branch_key,
+            branch,
+            loop_until,
+            max_iterations,
+            memory_limit_mb,
+            timeout_ms,
+            expected_duration_ms,
+            approval,
+            workload,
+            resources,
+            produces,
+            scoped,
+            reads,
+            config,
             on_success,
             on_failure,
             invokes_computation_graph,
@@ -227,6 +519,37 @@ impl Parse for TaskAttributes {
     }
 }
 
+/// Parses one `produces` declaration (`"key: kind"`) into the key and the
+/// name of its `OutputKind` variant. Mirrors `ProducedOutput::parse`.
+fn parse_produced_output(declaration: &str) -> Result<(String, String), String> {
+    let (key, kind) = declaration.split_once(':').ok_or_else(|| {
+        format!(
+            "output declaration '{}' must have the form \"key: type\"",
+            declaration
+        )
+    })?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("output keys may not be empty".to_string());
+    }
+    let variant = match kind.trim() {
+        "object" => "Object",
+        "array" => "Array",
+        "string" => "String",
+        "number" => "Number",
+        "integer" => "Integer",
+        "boolean" | "bool" => "Boolean",
+        "any" => "Any",
+        other => {
+            return Err(format!(
+                "unknown output type '{}', expected object, array, string, number, integer, boolean or any",
+                other
+            ))
+        }
+    };
+    Ok((key.to_string(), variant.to_string()))
+}
+
 /// Calculate code fingerprint from function
 ///
 /// Generates a unique hash based on the function's signature and body.
@@ -473,6 +796,21 @@ pub fn parse_trigger_rules_expr(expr: &Expr) -> Result<serde_json::Value, String
     }
 }
 
+/// Check that a parsed `loop_until` rule only tests context values: task
+/// status conditions have no meaning inside a single task's loop.
+fn validate_loop_condition(rule: &serde_json::Value) -> Result<(), String> {
+    let conditions = rule
+        .get("conditions")
+        .and_then(|c| c.as_array())
+        .ok_or_else(|| "expected a context_value() condition".to_string())?;
+    for condition in conditions {
+        if condition.get("type").and_then(|t| t.as_str()) != Some("ContextValue") {
+            return Err("only context_value() conditions are supported".to_string());
+        }
+    }
+    Ok(())
+}
+
 /// Parse a list of trigger conditions from function arguments
 fn parse_condition_list(
     args: &syn::punctuated::Punctuated<Expr, syn::Token![,]>,
@@ -728,6 +1066,7 @@ pub fn generate_task_impl(attrs: TaskAttributes, input: ItemFn) -> TokenStream2
 
     let task_id = &attrs.id;
     let dependencies = &attrs.dependencies;
+    let soft_dependencies = &attrs.soft_dependencies;
 
     // Generate retry policy creation code
     let generate_retry_policy = generate_retry_policy_code(&attrs);
@@ -735,6 +1074,78 @@ pub fn generate_task_impl(attrs: TaskAttributes, input: ItemFn) -> TokenStream2
     // Generate trigger rules JSON code
     let generate_trigger_rules = generate_trigger_rules_code(&attrs);
 
+    let generate_short_circuit = match &attrs.short_circuit {
+        Some(key) => quote! { Some(#key.to_string()) },
+        None => quote! { None },
+    };
+
+    let generate_branch_key = match &attrs.branch_key {
+        Some(key) => quote! { Some(#key.to_string()) },
+        None => quote! { None },
+    };
+
+    let generate_branch = match &attrs.branch {
+        Some(name) => quote! { Some(#name.to_string()) },
+        None => quote! { None },
+    };
+
+    let generate_loop_until = match &attrs.loop_until {
+        Some(json) => quote! { Some(serde_json::from_str(#json).unwrap()) },
+        None => quote! { None },
+    };
+
+    let generate_max_iterations = match attrs.max_iterations {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    };
+
+    let generate_memory_limit = match attrs.memory_limit_mb {
+        Some(mb) => {
+            let bytes = mb.saturating_mul(1024 * 1024);
+            quote! { Some(#bytes) }
+        }
+        None => quote! { None },
+    };
+
+    let generate_timeout = match attrs.timeout_ms {
+        Some(ms) => quote! { Some(::std::time::Duration::from_millis(#ms)) },
+        None => quote! { None },
+    };
+
+    let generate_expected_duration = match attrs.expected_duration_ms {
+        Some(ms) => quote! { Some(::std::time::Duration::from_millis(#ms)) },
+        None => quote! { None },
+    };
+
+    let approval = attrs.approval;
+
+    let generate_workload_class = match &attrs.workload {
+        Some(class) => {
+            let variant = syn::Ident::new(class, Span::call_site());
+            quote! { Some(::cloacina_workflow::WorkloadClass::#variant) }
+        }
+        None => quote! { None },
+    };
+
+    let resources = &attrs.resources;
+
+    let produced_keys = attrs.produces.iter().map(|(key, _)| key);
+    let produced_kinds = attrs
+        .produces
+        .iter()
+        .map(|(_, kind)| syn::Ident::new(kind, Span::call_site()));
+
+    let scoped = attrs.scoped;
+    let generate_reads = match &attrs.reads {
+        Some(keys) => quote! { Some(vec![#(#keys.to_string()),*]) },
+        None => quote! { None },
+    };
+
+    let generate_configuration = match &attrs.config {
+        Some(json) => quote! { Some(serde_json::from_str(#json).unwrap()) },
+        None => quote! { None },
+    };
+
     let execute_body = match (fn_asyncness.is_some(), has_handle_param) {
         (true, true) => quote! {
             {
@@ -941,6 +1352,11 @@ pub fn generate_task_impl(attrs: TaskAttributes, input: ItemFn) -> TokenStream2
                 &[#(#dependencies),*]
             }
 
+            /// IDs of the dependencies declared ordering-only with `soft(...)`.
+            pub fn soft_dependency_task_ids() -> &'static [&'static str] {
+                &[#(#soft_dependencies),*]
+            }
+
             /// Get the code fingerprint for this task
             pub fn code_fingerprint() -> &'static str {
                 #code_fingerprint
@@ -993,6 +1409,10 @@ pub fn generate_task_impl(attrs: TaskAttributes, input: ItemFn) -> TokenStream2
                 &self.dependencies
             }
 
+            fn is_soft_dependency(&self, dependency: &::cloacina_workflow::TaskNamespace) -> bool {
+                Self::soft_dependency_task_ids().contains(&dependency.task_id.as_str())
+            }
+
             fn retry_policy(&self) -> ::cloacina_workflow::RetryPolicy {
                 self.create_retry_policy()
             }
@@ -1001,6 +1421,69 @@ pub fn generate_task_impl(attrs: TaskAttributes, input: ItemFn) -> TokenStream2
                 self.trigger_rules()
             }
 
+            fn short_circuit(&self) -> Option<String> {
+                #generate_short_circuit
+            }
+
+            fn branch_key(&self) -> Option<String> {
+                #generate_branch_key
+            }
+
+            fn branch(&self) -> Option<String> {
+                #generate_branch
+            }
+
+            fn loop_until(&self) -> Option<serde_json::Value> {
+                #generate_loop_until
+            }
+
+            fn max_iterations(&self) -> Option<u32> {
+                #generate_max_iterations
+            }
+
+            fn memory_limit(&self) -> Option<u64> {
+                #generate_memory_limit
+            }
+
+            fn timeout(&self) -> Option<::std::time::Duration> {
+                #generate_timeout
+            }
+
+            fn expected_duration(&self) -> Option<::std::time::Duration> {
+                #generate_expected_duration
+            }
+
+            fn requires_approval(&self) -> bool {
+                #approval
+            }
+
+            fn workload_class(&self) -> Option<::cloacina_workflow::WorkloadClass> {
+                #generate_workload_class
+            }
+
+            fn resources(&self) -> Vec<String> {
+                vec![#(#resources.to_string()),*]
+            }
+
+            fn produces(&self) -> Vec<::cloacina_workflow::ProducedOutput> {
+                vec![#(::cloacina_workflow::ProducedOutput::new(
+                    #produced_keys,
+                    ::cloacina_workflow::OutputKind::#produced_kinds,
+                )),*]
+            }
+
+            fn scoped(&self) -> bool {
+                #scoped
+            }
+
+            fn reads(&self) -> Option<Vec<String>> {
+                #generate_reads
+            }
+
+            fn configuration(&self) -> Option<serde_json::Value> {
+                #generate_configuration
+            }
+
             fn code_fingerprint(&self) -> Option<String> {
                 Some(Self::code_fingerprint().to_string())
             }
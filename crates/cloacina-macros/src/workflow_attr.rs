@@ -30,7 +30,7 @@ use std::collections::{hash_map::DefaultHasher, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use syn::{
     parse::{Parse, ParseStream},
-    Expr, Ident, ItemMod, LitStr, Result as SynResult, Token,
+    Expr, Ident, ItemMod, LitInt, LitStr, Result as SynResult, Token,
 };
 
 use crate::packaged_workflow::{
@@ -47,6 +47,12 @@ use crate::tasks::TaskAttributes;
 /// * `tenant` - Tenant identifier (optional, defaults to "public")
 /// * `description` - Optional description
 /// * `author` - Optional author information
+/// * `health_check` - Optional `async fn` (inside the workflow module) run by
+///   the registry after a packaged load
+/// * `max_active_runs` - Optional cap on concurrently running executions
+/// * `retry_attempts` / `retry_delay_ms` - Optional re-runs of the whole
+///   workflow after a failed execution, and the delay before the first one
+/// * `timeout_ms` - Optional limit on how long an execution may run
 pub struct UnifiedWorkflowAttributes {
     pub name: String,
     pub tenant: String,
@@ -66,6 +72,33 @@ pub struct UnifiedWorkflowAttributes {
     /// the manifest/FFI metadata carries which secrets the workflow requires. A
     /// secret is bound per-instance via a `{"$secret": "name"}` reference.
     pub secrets: Vec<String>,
+    /// Declared outputs from `#[workflow(outputs( name: Type, … ))]`. Generates
+    /// a typed `Outputs` struct in the workflow module and is surfaced as
+    /// `"workflow_outputs"` slots via the input-interface FFI entrypoint.
+    pub outputs: Vec<WorkflowOutput>,
+    /// `#[workflow(health_check = some_fn)]`: an `async fn() -> Result<(), E>`
+    /// (`E: Display`) resolved inside the workflow module. The registry runs it
+    /// after loading the package; an `Err` marks the package Degraded.
+    pub health_check: Option<syn::Path>,
+    /// `#[workflow(max_active_runs = N)]`: at most `N` executions of the
+    /// workflow run at once; the scheduler queues the rest.
+    pub max_active_runs: Option<usize>,
+    /// `#[workflow(retry_attempts = N)]`: re-run the whole workflow up to
+    /// `N` times after it fails.
+    pub retry_attempts: Option<i32>,
+    /// `#[workflow(retry_delay_ms = N)]`: delay before the first re-run,
+    /// doubling for each one after.
+    pub retry_delay_ms: Option<u64>,
+    /// `#[workflow(timeout_ms = N)]`: fail executions still unfinished `N`
+    /// milliseconds after they were created.
+    pub timeout_ms: Option<u64>,
+}
+
+/// One declared workflow output: a final-context key and the type it is read
+/// back as.
+pub struct WorkflowOutput {
+    pub name: Ident,
+    pub ty: syn::Type,
 }
 
 /// One declared workflow parameter (CLOACI-I-0128). `default = None` means the
@@ -232,6 +265,96 @@ fn is_constructor_macro(item: &syn::Item) -> bool {
     matches!(item, syn::Item::Macro(m) if m.mac.path.is_ident("constructor"))
 }
 
+/// One `embed_workflow!(...)` declaration inside a `#[workflow]` module: another
+/// registered workflow (typically a packaged one) inlined as a sub-DAG.
+///
+/// ```rust,ignore
+/// embed_workflow!(
+///     prefix = "vendor",            // inlined tasks become `vendor.<task id>`
+///     workflow = "vendor_ingest",   // registered name of the embedded workflow
+///     dependencies = ["prepare"],   // what the sub-DAG's root tasks wait for
+/// );
+/// ```
+///
+/// Tasks depend on the whole sub-DAG through its prefix. The embedded workflow
+/// is looked up and inlined by the runtime when the workflow is resolved.
+struct EmbedDecl {
+    prefix: String,
+    workflow: String,
+    dependencies: Vec<String>,
+}
+
+/// True if `item` is an `embed_workflow!(...)` invocation (lowered + stripped
+/// like `constructor!`).
+fn is_embed_macro(item: &syn::Item) -> bool {
+    matches!(item, syn::Item::Macro(m) if m.mac.path.is_ident("embed_workflow"))
+}
+
+impl Parse for EmbedDecl {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut prefix: Option<LitStr> = None;
+        let mut workflow: Option<String> = None;
+        let mut dependencies: Vec<String> = Vec::new();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "prefix" => prefix = Some(input.parse::<LitStr>()?),
+                "workflow" => workflow = Some(input.parse::<LitStr>()?.value()),
+                "dependencies" => {
+                    let content;
+                    syn::bracketed!(content in input);
+                    while !content.is_empty() {
+                        dependencies.push(content.parse::<LitStr>()?.value());
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown embed_workflow! field '{}'. Valid fields: prefix, \
+                             workflow, dependencies",
+                            other
+                        ),
+                    ));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let prefix = prefix.ok_or_else(|| {
+            syn::Error::new(Span::call_site(), "embed_workflow! requires a `prefix`")
+        })?;
+        if prefix.value().is_empty()
+            || prefix.value().contains("::")
+            || prefix.value().contains('.')
+        {
+            return Err(syn::Error::new(
+                prefix.span(),
+                "embed_workflow! `prefix` must be non-empty and contain no '::' or '.'",
+            ));
+        }
+        let workflow = workflow.ok_or_else(|| {
+            syn::Error::new(
+                Span::call_site(),
+                "embed_workflow! requires a `workflow` name",
+            )
+        })?;
+
+        Ok(EmbedDecl {
+            prefix: prefix.value(),
+            workflow,
+            dependencies,
+        })
+    }
+}
+
 impl Parse for UnifiedWorkflowAttributes {
     fn parse(input: ParseStream) -> SynResult<Self> {
         let mut name = None;
@@ -241,6 +364,12 @@ impl Parse for UnifiedWorkflowAttributes {
         let mut triggers: Vec<String> = Vec::new();
         let mut params: Vec<WorkflowParam> = Vec::new();
         let mut secrets: Vec<String> = Vec::new();
+        let mut outputs: Vec<WorkflowOutput> = Vec::new();
+        let mut health_check: Option<syn::Path> = None;
+        let mut max_active_runs: Option<usize> = None;
+        let mut retry_attempts: Option<i32> = None;
+        let mut retry_delay_ms: Option<u64> = None;
+        let mut timeout_ms: Option<u64> = None;
 
         while !input.is_empty() {
             let field_name: Ident = input.parse()?;
@@ -319,6 +448,32 @@ impl Parse for UnifiedWorkflowAttributes {
                 continue;
             }
 
+            // `outputs( name: Type, … )` — call syntax like `params`. Each entry
+            // becomes a field of the generated `Outputs` struct.
+            if field_name == "outputs" {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let oname: Ident = content.parse()?;
+                    content.parse::<Token![:]>()?;
+                    let ty: syn::Type = content.parse()?;
+                    if outputs.iter().any(|o| o.name == oname) {
+                        return Err(syn::Error::new(
+                            oname.span(),
+                            format!("duplicate workflow output: '{}'", oname),
+                        ));
+                    }
+                    outputs.push(WorkflowOutput { name: oname, ty });
+                    if !content.is_empty() {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+
             input.parse::<Token![=]>()?;
 
             match field_name.to_string().as_str() {
@@ -338,6 +493,39 @@ impl Parse for UnifiedWorkflowAttributes {
                     let lit: LitStr = input.parse()?;
                     author = Some(lit.value());
                 }
+                "health_check" => {
+                    health_check = Some(input.parse()?);
+                }
+                "max_active_runs" => {
+                    let lit: LitInt = input.parse()?;
+                    let value: usize = lit.base10_parse()?;
+                    if value == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "max_active_runs must be at least 1",
+                        ));
+                    }
+                    max_active_runs = Some(value);
+                }
+                "retry_attempts" => {
+                    let lit: LitInt = input.parse()?;
+                    retry_attempts = Some(lit.base10_parse()?);
+                }
+                "retry_delay_ms" => {
+                    let lit: LitInt = input.parse()?;
+                    retry_delay_ms = Some(lit.base10_parse()?);
+                }
+                "timeout_ms" => {
+                    let lit: LitInt = input.parse()?;
+                    let value: u64 = lit.base10_parse()?;
+                    if value == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "timeout_ms must be greater than zero",
+                        ));
+                    }
+                    timeout_ms = Some(value);
+                }
                 "triggers" => {
                     // Array of string literals: triggers = ["t1", "t2"]
                     let content;
@@ -354,7 +542,7 @@ impl Parse for UnifiedWorkflowAttributes {
                     return Err(syn::Error::new(
                         field_name.span(),
                         format!(
-                            "Unknown attribute: '{}'. Valid attributes: name, tenant, description, author, triggers, params, secrets",
+                            "Unknown attribute: '{}'. Valid attributes: name, tenant, description, author, triggers, health_check, max_active_runs, retry_attempts, retry_delay_ms, timeout_ms, params, secrets, outputs",
                             field_name
                         ),
                     ));
@@ -369,6 +557,12 @@ impl Parse for UnifiedWorkflowAttributes {
         let name = name.ok_or_else(|| {
             syn::Error::new(Span::call_site(), "#[workflow] requires 'name' attribute")
         })?;
+        if retry_delay_ms.is_some() && retry_attempts.is_none() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "#[workflow] 'retry_delay_ms' requires 'retry_attempts'",
+            ));
+        }
 
         Ok(UnifiedWorkflowAttributes {
             name,
@@ -378,6 +572,12 @@ impl Parse for UnifiedWorkflowAttributes {
             triggers,
             params,
             secrets,
+            outputs,
+            health_check,
+            max_active_runs,
+            retry_attempts,
+            retry_delay_ms,
+            timeout_ms,
         })
     }
 }
@@ -425,11 +625,25 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
     // Scan module for #[task] functions
     let mut detected_tasks: HashMap<String, syn::Ident> = HashMap::new();
     let mut task_dependencies: HashMap<String, Vec<String>> = HashMap::new();
+    // Branch constructs: ids of decider tasks (`branch_key`) and, per branch
+    // task, the branch it heads plus its data dependencies.
+    let mut branch_deciders: HashSet<String> = HashSet::new();
+    let mut branch_tasks: Vec<(String, String, Vec<String>)> = Vec::new();
     // CLOACI-T-0829: scan for `constructor!(...)` consumer declarations.
     let mut constructor_nodes: Vec<ConstructorNodeDecl> = Vec::new();
+    let mut embeds: Vec<EmbedDecl> = Vec::new();
 
     if let Some((_, items)) = mod_content {
         for item in items {
+            if is_embed_macro(item) {
+                if let syn::Item::Macro(item_macro) = item {
+                    match syn::parse2::<EmbedDecl>(item_macro.mac.tokens.clone()) {
+                        Ok(decl) => embeds.push(decl),
+                        Err(e) => return e.to_compile_error(),
+                    }
+                }
+                continue;
+            }
             if is_constructor_macro(item) {
                 if let syn::Item::Macro(item_macro) = item {
                     match syn::parse2::<ConstructorNodeDecl>(item_macro.mac.tokens.clone()) {
@@ -467,6 +681,18 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
                         } else {
                             task_attrs.id.clone()
                         };
+                        if task_attrs.branch_key.is_some() {
+                            branch_deciders.insert(task_id.clone());
+                        }
+                        if let Some(branch) = &task_attrs.branch {
+                            let data_deps = task_attrs
+                                .dependencies
+                                .iter()
+                                .filter(|dep| !task_attrs.soft_dependencies.contains(dep))
+                                .cloned()
+                                .collect();
+                            branch_tasks.push((task_id.clone(), branch.clone(), data_deps));
+                        }
                         detected_tasks.insert(task_id.clone(), fn_name.clone());
                         task_dependencies.insert(task_id, task_attrs.dependencies.clone());
                         break;
@@ -476,10 +702,11 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
         }
     }
 
-    if detected_tasks.is_empty() && constructor_nodes.is_empty() {
+    if detected_tasks.is_empty() && constructor_nodes.is_empty() && embeds.is_empty() {
         return syn::Error::new(
             mod_name.span(),
-            "#[workflow] module must contain at least one #[task] function or constructor!(…) node",
+            "#[workflow] module must contain at least one #[task] function, constructor!(…) node \
+             or embed_workflow!(…)",
         )
         .to_compile_error();
     }
@@ -503,6 +730,22 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
         available_ids.insert(decl.id.clone());
         combined_deps.insert(decl.id.clone(), decl.dependencies.clone());
     }
+    // An embed's prefix is a DAG node like any other until the runtime inlines
+    // the embedded workflow's tasks under it.
+    for embed in &embeds {
+        if available_ids.contains(&embed.prefix) {
+            return syn::Error::new(
+                mod_name.span(),
+                format!(
+                    "embed_workflow!(prefix = \"{}\") collides with another node id in workflow '{}'",
+                    embed.prefix, workflow_name
+                ),
+            )
+            .to_compile_error();
+        }
+        available_ids.insert(embed.prefix.clone());
+        combined_deps.insert(embed.prefix.clone(), embed.dependencies.clone());
+    }
 
     // Validate dependencies
     let validation_error = validate_dependencies(workflow_name, &available_ids, &combined_deps);
@@ -520,6 +763,21 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
         return quote! { compile_error!(#error_msg); };
     }
 
+    // A branch task must depend directly on the decider that selects it.
+    for (task_id, branch, data_deps) in &branch_tasks {
+        if !data_deps.iter().any(|dep| branch_deciders.contains(dep)) {
+            return syn::Error::new(
+                mod_name.span(),
+                format!(
+                    "task '{}' declares branch = \"{}\" but depends on no task with `branch_key` \
+                     in workflow '{}'",
+                    task_id, branch, workflow_name
+                ),
+            )
+            .to_compile_error();
+        }
+    }
+
     // Generate fingerprint
     let mut hasher = DefaultHasher::new();
     workflow_name.hash(&mut hasher);
@@ -548,7 +806,7 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
     let module_items = if let Some((_, items)) = mod_content {
         let kept: Vec<&syn::Item> = items
             .iter()
-            .filter(|it| !is_constructor_macro(it))
+            .filter(|it| !is_constructor_macro(it) && !is_embed_macro(it))
             .collect();
         quote! { #(#kept)* }
     } else {
@@ -624,6 +882,42 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
     let params_fn_embedded = make_params_fn(quote! { ::cloacina::input_interface });
     let params_fn_packaged = make_params_fn(quote! { ::cloacina_workflow::input_interface });
 
+    // Declared outputs: a typed `Outputs` struct generated inside the workflow
+    // module (so field types resolve in the same scope as the tasks writing
+    // them), plus a hidden slot-list fn the descriptor entry points at. Output
+    // types don't need `JsonSchema` — the probe degrades to an untyped slot.
+    let output_items = build_output_items(&attrs.outputs);
+    let outputs_fn = if attrs.outputs.is_empty() {
+        quote! { || ::std::string::String::from("[]") }
+    } else {
+        quote! { #mod_name::Outputs::__output_slots }
+    };
+
+    // `health_check = f`: a hidden wrapper emitted INSIDE the workflow module
+    // (so `f` resolves — and may stay private — in the author's scope) that
+    // boxes the future and flattens the error to a string. The descriptor
+    // points at the wrapper.
+    let (health_check_items, health_check_fn) = match &attrs.health_check {
+        Some(path) => (
+            quote! {
+                #[doc(hidden)]
+                pub fn __cloacina_health_check() -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<(), ::std::string::String>,
+                            > + ::std::marker::Send,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async {
+                        #path().await.map_err(|e| e.to_string())
+                    })
+                }
+            },
+            quote! { ::core::option::Option::Some(#mod_name::__cloacina_health_check) },
+        ),
+        None => (quote! {}, quote! { ::core::option::Option::None }),
+    };
+
     let workflow_descriptor_entry = quote! {
         #[cfg(not(feature = "packaged"))]
         ::cloacina::cloacina_workflow_plugin::inventory::submit! {
@@ -635,6 +929,8 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
                 graph_data_json: #graph_data_json,
                 triggers: || vec![#(#triggers_vec.to_string()),*],
                 params: #params_fn_embedded,
+                outputs: #outputs_fn,
+                health_check: #health_check_fn,
             }
         }
 
@@ -648,6 +944,8 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
                 graph_data_json: #graph_data_json,
                 triggers: || vec![#(#triggers_vec.to_string()),*],
                 params: #params_fn_packaged,
+                outputs: #outputs_fn,
+                health_check: #health_check_fn,
             }
         }
     };
@@ -659,12 +957,28 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
         tenant,
         &description,
         &author,
+        attrs.max_active_runs,
+        attrs.retry_attempts,
+        attrs.retry_delay_ms,
+        attrs.timeout_ms,
         &fingerprint,
         &detected_tasks,
         &task_dependencies,
         &constructor_nodes,
+        &embeds,
     );
 
+    // Embedded workflows are resolved through the host's runtime, which a
+    // packaged (cdylib) build has no access to.
+    let packaged_embed_error = if embeds.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            #[cfg(feature = "packaged")]
+            compile_error!("embed_workflow!(…) is only supported in embedded (non-packaged) workflows");
+        }
+    };
+
     // Generate packaged FFI exports (when `packaged` feature IS active)
     let packaged_registration = generate_packaged_registration(
         mod_name,
@@ -688,6 +1002,10 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
         #(#mod_attrs)*
         #mod_vis mod #mod_name {
             #module_items
+
+            #output_items
+
+            #health_check_items
         }
 
         // I-0102 / T-C: TaskEntry + WorkflowDescriptorEntry inventory
@@ -700,6 +1018,8 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
 
         #workflow_descriptor_entry
 
+        #packaged_embed_error
+
         #[cfg(not(feature = "packaged"))]
         const _: () = {
             #embedded_registration
@@ -707,6 +1027,104 @@ fn generate_workflow_attr(attrs: UnifiedWorkflowAttributes, input: ItemMod) -> T
     }
 }
 
+/// Build the `Outputs` struct, its `WorkflowOutputs` impl, and the hidden
+/// `__output_slots` descriptor fn for `#[workflow(outputs(...))]`. Emits nothing
+/// when no outputs are declared. Both trait impls are cfg-gated on `packaged`
+/// so they resolve through whichever crate the user depends on.
+fn build_output_items(outputs: &[WorkflowOutput]) -> TokenStream2 {
+    if outputs.is_empty() {
+        return quote! {};
+    }
+
+    let fields: Vec<TokenStream2> = outputs
+        .iter()
+        .map(|o| {
+            let name = &o.name;
+            let ty = &o.ty;
+            quote! { pub #name: #ty }
+        })
+        .collect();
+    let keys: Vec<String> = outputs.iter().map(|o| o.name.to_string()).collect();
+
+    let make_impl = |outputs_path: TokenStream2, iface_path: TokenStream2| -> TokenStream2 {
+        let extracts = outputs.iter().map(|o| {
+            let name = &o.name;
+            let key = name.to_string();
+            quote! { #name: #outputs_path::extract_output(context, #key)? }
+        });
+        // An `Option<T>` output may be absent from the final context, so it is
+        // declared as an optional slot described by `T`'s schema.
+        let slots = outputs.iter().map(|o| {
+            let key = o.name.to_string();
+            let probe = |ty: &syn::Type| {
+                quote! {{
+                    use #iface_path::{ProbeFallback as _, ProbeTyped as _};
+                    (&#iface_path::SchemaProbe::<#ty>::new()).probe_input_schema()
+                }}
+            };
+            match crate::constructor_attr::option_inner(&o.ty) {
+                Some(inner) => {
+                    let schema = probe(&inner);
+                    quote! {
+                        #iface_path::InputSlot::optional(#key, #schema, ::std::option::Option::None)
+                    }
+                }
+                None => {
+                    let schema = probe(&o.ty);
+                    quote! { #iface_path::InputSlot::required(#key, #schema) }
+                }
+            }
+        });
+        quote! {
+            impl #outputs_path::WorkflowOutputs for Outputs {
+                const KEYS: &'static [&'static str] = &[#(#keys),*];
+
+                fn from_context(
+                    context: &#outputs_path::OutputContext,
+                ) -> ::core::result::Result<Self, #outputs_path::OutputsError> {
+                    ::core::result::Result::Ok(Self { #(#extracts),* })
+                }
+            }
+
+            impl Outputs {
+                #[doc(hidden)]
+                pub fn __output_slots() -> ::std::string::String {
+                    let slots: ::std::vec::Vec<#iface_path::InputSlot> =
+                        ::std::vec![ #(#slots),* ];
+                    #iface_path::slots_to_json(&slots)
+                }
+            }
+        }
+    };
+    let embedded_impl = make_impl(
+        quote! { ::cloacina::outputs },
+        quote! { ::cloacina::input_interface },
+    );
+    let packaged_impl = make_impl(
+        quote! { ::cloacina_workflow::outputs },
+        quote! { ::cloacina_workflow::input_interface },
+    );
+
+    quote! {
+        /// Typed view over this workflow's declared outputs. Read it from a
+        /// finished run with `result.outputs::<Outputs>()`.
+        #[derive(Debug)]
+        pub struct Outputs {
+            #(#fields),*
+        }
+
+        #[cfg(not(feature = "packaged"))]
+        const _: () = {
+            #embedded_impl
+        };
+
+        #[cfg(feature = "packaged")]
+        const _: () = {
+            #packaged_impl
+        };
+    }
+}
+
 /// Validate task dependencies within the module. `available_ids` is the combined
 /// set of `#[task]` ids + `constructor!(…)` node ids declared in this module
 /// (CLOACI-T-0829), so a dependency may point at either node kind.
@@ -777,10 +1195,15 @@ fn generate_embedded_registration(
     tenant: &str,
     description: &str,
     author: &str,
+    max_active_runs: Option<usize>,
+    retry_attempts: Option<i32>,
+    retry_delay_ms: Option<u64>,
+    timeout_ms: Option<u64>,
     _fingerprint: &str,
     detected_tasks: &HashMap<String, syn::Ident>,
     _task_dependencies: &HashMap<String, Vec<String>>,
     constructor_nodes: &[ConstructorNodeDecl],
+    embeds: &[EmbedDecl],
 ) -> TokenStream2 {
     let mod_path_prefix = quote! { #mod_name };
 
@@ -799,6 +1222,32 @@ fn generate_embedded_registration(
         })
         .collect();
 
+    // Declare each embed_workflow!(…); the runtime inlines the embedded
+    // workflow's tasks when it resolves this workflow.
+    let embed_declaration_code: Vec<TokenStream2> = embeds
+        .iter()
+        .map(|embed| {
+            let prefix = &embed.prefix;
+            let embedded = &embed.workflow;
+            let deps = &embed.dependencies;
+            quote! {
+                workflow
+                    .embed_workflow(
+                        #prefix,
+                        #embedded,
+                        ::std::vec![
+                            #(
+                                cloacina_workflow::TaskNamespace::new(
+                                    #tenant, pkg_name, #workflow_name, #deps,
+                                )
+                            ),*
+                        ],
+                    )
+                    .expect("Failed to embed workflow");
+            }
+        })
+        .collect();
+
     // Generate workflow constructor
     let task_addition_code: Vec<TokenStream2> = detected_tasks.values().map(|fn_name| {
             let constructor_name = syn::Ident::new(
@@ -854,8 +1303,24 @@ fn generate_embedded_registration(
                         }
                         fn id(&self) -> &str { self.inner.id() }
                         fn dependencies(&self) -> &[cloacina_workflow::TaskNamespace] { self.inner.dependencies() }
+                        fn is_soft_dependency(&self, dependency: &cloacina_workflow::TaskNamespace) -> bool { self.inner.is_soft_dependency(dependency) }
                         fn retry_policy(&self) -> cloacina_workflow::retry::RetryPolicy { self.inner.retry_policy() }
                         fn trigger_rules(&self) -> serde_json::Value { self.rewritten_trigger_rules.clone() }
+                        fn short_circuit(&self) -> Option<String> { self.inner.short_circuit() }
+                        fn branch_key(&self) -> Option<String> { self.inner.branch_key() }
+                        fn branch(&self) -> Option<String> { self.inner.branch() }
+                        fn loop_until(&self) -> Option<serde_json::Value> { self.inner.loop_until() }
+                        fn max_iterations(&self) -> Option<u32> { self.inner.max_iterations() }
+                        fn memory_limit(&self) -> Option<u64> { self.inner.memory_limit() }
+                        fn timeout(&self) -> Option<::std::time::Duration> { self.inner.timeout() }
+                        fn expected_duration(&self) -> Option<::std::time::Duration> { self.inner.expected_duration() }
+                        fn requires_approval(&self) -> bool { self.inner.requires_approval() }
+                        fn workload_class(&self) -> Option<cloacina_workflow::WorkloadClass> { self.inner.workload_class() }
+                        fn resources(&self) -> Vec<String> { self.inner.resources() }
+                        fn produces(&self) -> Vec<cloacina_workflow::ProducedOutput> { self.inner.produces() }
+                        fn scoped(&self) -> bool { self.inner.scoped() }
+                        fn reads(&self) -> Option<Vec<String>> { self.inner.reads() }
+                        fn configuration(&self) -> Option<serde_json::Value> { self.inner.configuration() }
                         fn code_fingerprint(&self) -> Option<String> { self.inner.code_fingerprint() }
                         fn requires_handle(&self) -> bool { self.inner.requires_handle() }
                     }
@@ -887,6 +1352,35 @@ fn generate_embedded_registration(
         quote! {}
     };
 
+    let max_active_runs_field = match max_active_runs {
+        Some(limit) => quote! { workflow.set_max_active_runs(Some(#limit)); },
+        None => quote! {},
+    };
+
+    let retry_policy_field = match retry_attempts {
+        Some(attempts) => {
+            let delay = retry_delay_ms.map(|ms| {
+                quote! { .initial_delay(::std::time::Duration::from_millis(#ms)) }
+            });
+            quote! {
+                workflow.set_retry_policy(Some(
+                    cloacina::retry::RetryPolicy::builder()
+                        .max_attempts(#attempts)
+                        #delay
+                        .build(),
+                ));
+            }
+        }
+        None => quote! {},
+    };
+
+    let timeout_field = match timeout_ms {
+        Some(ms) => quote! {
+            workflow.set_timeout(Some(::std::time::Duration::from_millis(#ms)));
+        },
+        None => quote! {},
+    };
+
     quote! {
         fn #workflow_constructor_name() -> cloacina::Workflow {
             let pkg_name = env!("CARGO_PKG_NAME");
@@ -896,6 +1390,9 @@ fn generate_embedded_registration(
             workflow.set_package(pkg_name);
             #description_field
             #author_field
+            #max_active_runs_field
+            #retry_policy_field
+            #timeout_field
 
             // Add tasks
             #(#task_addition_code)*
@@ -903,6 +1400,9 @@ fn generate_embedded_registration(
             // Add constructor!(…) DAG nodes (CLOACI-T-0829)
             #(#constructor_addition_code)*
 
+            // Declare embed_workflow!(…) sub-DAGs
+            #(#embed_declaration_code)*
+
             workflow.validate().expect("Workflow validation failed");
             workflow.finalize()
         }
@@ -1146,12 +1646,60 @@ fn build_task_inventory_entries(
                             fn dependencies(&self) -> &[cloacina_workflow::TaskNamespace] {
                                 self.inner.dependencies()
                             }
+                            fn is_soft_dependency(&self, dependency: &cloacina_workflow::TaskNamespace) -> bool {
+                                self.inner.is_soft_dependency(dependency)
+                            }
                             fn retry_policy(&self) -> cloacina_workflow::retry::RetryPolicy {
                                 self.inner.retry_policy()
                             }
                             fn trigger_rules(&self) -> serde_json::Value {
                                 self.rewritten_trigger_rules.clone()
                             }
+                            fn short_circuit(&self) -> Option<String> {
+                                self.inner.short_circuit()
+                            }
+                            fn branch_key(&self) -> Option<String> {
+                                self.inner.branch_key()
+                            }
+                            fn branch(&self) -> Option<String> {
+                                self.inner.branch()
+                            }
+                            fn loop_until(&self) -> Option<serde_json::Value> {
+                                self.inner.loop_until()
+                            }
+                            fn max_iterations(&self) -> Option<u32> {
+                                self.inner.max_iterations()
+                            }
+                            fn memory_limit(&self) -> Option<u64> {
+                                self.inner.memory_limit()
+                            }
+                            fn timeout(&self) -> Option<::std::time::Duration> {
+                                self.inner.timeout()
+                            }
+                            fn expected_duration(&self) -> Option<::std::time::Duration> {
+                                self.inner.expected_duration()
+                            }
+                            fn requires_approval(&self) -> bool {
+                                self.inner.requires_approval()
+                            }
+                            fn workload_class(&self) -> Option<cloacina_workflow::WorkloadClass> {
+                                self.inner.workload_class()
+                            }
+                            fn resources(&self) -> Vec<String> {
+                                self.inner.resources()
+                            }
+                            fn produces(&self) -> Vec<cloacina_workflow::ProducedOutput> {
+                                self.inner.produces()
+                            }
+                            fn scoped(&self) -> bool {
+                                self.inner.scoped()
+                            }
+                            fn reads(&self) -> Option<Vec<String>> {
+                                self.inner.reads()
+                            }
+                            fn configuration(&self) -> Option<serde_json::Value> {
+                                self.inner.configuration()
+                            }
                             fn code_fingerprint(&self) -> Option<String> {
                                 self.inner.code_fingerprint()
                             }
@@ -0,0 +1,344 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Kafka consumer-group source (`kafka` feature).
+//!
+//! Auto-commit is disabled. Each partition's committed offset advances past
+//! a message only once it and every earlier message received from that
+//! partition have been acked. Kafka has no per-message nack, so a nack seeks
+//! the partition back to the failed offset: that message and everything
+//! received after it are consumed again, and the committed offset stays
+//! behind it until the redelivered copy is acked.
+
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cloacina::trigger::{MessageEnvelope, MessageSource, MessageSourceError};
+use parking_lot::Mutex;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{Headers, Message};
+use rdkafka::{Offset, TopicPartitionList};
+use tracing::debug;
+
+use crate::source_error;
+
+/// Handle of a received Kafka message.
+#[derive(Debug, Clone)]
+pub struct KafkaDelivery {
+    topic: String,
+    partition: i32,
+    offset: i64,
+}
+
+/// Received and settled offsets of one partition.
+#[derive(Debug)]
+struct PartitionOffsets {
+    in_flight: BTreeSet<i64>,
+    /// One past the highest offset received
+    next: i64,
+    committed: i64,
+}
+
+impl PartitionOffsets {
+    fn new(first: i64) -> Self {
+        Self {
+            in_flight: BTreeSet::new(),
+            next: first,
+            committed: first,
+        }
+    }
+
+    fn received(&mut self, offset: i64) {
+        self.in_flight.insert(offset);
+        self.next = self.next.max(offset + 1);
+    }
+
+    /// Mark `offset` acked; returns the offset to commit when the committed
+    /// position moves forward.
+    fn acked(&mut self, offset: i64) -> Option<i64> {
+        self.in_flight.remove(&offset);
+        let commit = self.in_flight.first().copied().unwrap_or(self.next);
+        if commit > self.committed {
+            self.committed = commit;
+            Some(commit)
+        } else {
+            None
+        }
+    }
+
+    /// Mark `offset` nacked. Returns whether the consumer must seek back to
+    /// it; everything from `offset` on is then received again, so it stops
+    /// counting as in flight.
+    fn nacked(&mut self, offset: i64) -> bool {
+        // Already rewound to or before this offset by an earlier nack.
+        if offset >= self.next {
+            return false;
+        }
+        self.in_flight.split_off(&offset);
+        self.next = offset;
+        true
+    }
+}
+
+/// How long a nack waits for the consumer to seek.
+const SEEK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Builder for a [`KafkaSource`].
+pub struct KafkaSourceBuilder {
+    config: ClientConfig,
+    group_id: String,
+    topics: Vec<String>,
+}
+
+impl KafkaSourceBuilder {
+    /// Subscribe to `topic`. Repeatable.
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topics.push(topic.into());
+        self
+    }
+
+    /// Set a librdkafka consumer property, e.g. `security.protocol` or
+    /// `auto.offset.reset` (default: `earliest`).
+    pub fn set(mut self, key: &str, value: &str) -> Self {
+        self.config.set(key, value);
+        self
+    }
+
+    /// Create the consumer and subscribe to the topics.
+    pub fn build(mut self) -> Result<KafkaSource, MessageSourceError> {
+        if self.topics.is_empty() {
+            return Err(MessageSourceError(
+                "kafka source needs at least one topic".to_string(),
+            ));
+        }
+        // Offsets are committed by the source, never on receipt.
+        self.config.set("enable.auto.commit", "false");
+        let consumer: StreamConsumer = self
+            .config
+            .create()
+            .map_err(|e| source_error("cannot create kafka consumer", e))?;
+        let topics: Vec<&str> = self.topics.iter().map(String::as_str).collect();
+        consumer
+            .subscribe(&topics)
+            .map_err(|e| source_error("cannot subscribe", e))?;
+
+        Ok(KafkaSource {
+            consumer,
+            group_id: self.group_id,
+            topics: self.topics,
+            offsets: Mutex::new(HashMap::new()),
+        })
+    }
+}
+
+/// Kafka consumer-group [`MessageSource`].
+pub struct KafkaSource {
+    consumer: StreamConsumer,
+    group_id: String,
+    topics: Vec<String>,
+    offsets: Mutex<HashMap<(String, i32), PartitionOffsets>>,
+}
+
+impl KafkaSource {
+    /// Start building a source consuming as `group_id` from the cluster at
+    /// `brokers` (comma-separated `host:port` list).
+    pub fn builder(brokers: &str, group_id: &str) -> KafkaSourceBuilder {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", brokers)
+            .set("group.id", group_id)
+            .set("auto.offset.reset", "earliest");
+        KafkaSourceBuilder {
+            config,
+            group_id: group_id.to_string(),
+            topics: Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl MessageSource for KafkaSource {
+    type Delivery = KafkaDelivery;
+
+    async fn receive(
+        &self,
+        max: usize,
+        wait: Duration,
+    ) -> Result<Vec<(MessageEnvelope, KafkaDelivery)>, MessageSourceError> {
+        let mut received = Vec::new();
+        while received.len() < max {
+            // Only the first message is waited for; the rest of a batch is
+            // whatever is already buffered.
+            let timeout = if received.is_empty() {
+                wait
+            } else {
+                Duration::from_millis(1)
+            };
+            let message = match tokio::time::timeout(timeout, self.consumer.recv()).await {
+                Err(_) => break,
+                Ok(Err(e)) if received.is_empty() => {
+                    return Err(source_error("kafka receive failed", e))
+                }
+                Ok(Err(_)) => break,
+                Ok(Ok(message)) => message,
+            };
+
+            let topic = message.topic().to_string();
+            let (partition, offset) = (message.partition(), message.offset());
+            let headers = message
+                .headers()
+                .map(|headers| {
+                    headers
+                        .iter()
+                        .filter_map(|h| {
+                            Some((
+                                h.key.to_string(),
+                                String::from_utf8_lossy(h.value?).into_owned(),
+                            ))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let envelope = MessageEnvelope {
+                payload: message.payload().unwrap_or_default().to_vec(),
+                source: topic.clone(),
+                key: message
+                    .key()
+                    .map(|k| String::from_utf8_lossy(k).into_owned()),
+                position: Some(format!("{}:{}", partition, offset)),
+                headers,
+            };
+
+            self.offsets
+                .lock()
+                .entry((topic.clone(), partition))
+                .or_insert_with(|| PartitionOffsets::new(offset))
+                .received(offset);
+            received.push((
+                envelope,
+                KafkaDelivery {
+                    topic,
+                    partition,
+                    offset,
+                },
+            ));
+        }
+        Ok(received)
+    }
+
+    async fn ack(&self, delivery: KafkaDelivery) -> Result<(), MessageSourceError> {
+        let commit = self
+            .offsets
+            .lock()
+            .get_mut(&(delivery.topic.clone(), delivery.partition))
+            .and_then(|offsets| offsets.acked(delivery.offset));
+        let Some(commit) = commit else {
+            return Ok(());
+        };
+
+        let mut partitions = TopicPartitionList::new();
+        partitions
+            .add_partition_offset(&delivery.topic, delivery.partition, Offset::Offset(commit))
+            .map_err(|e| source_error("invalid commit offset", e))?;
+        self.consumer
+            .commit(&partitions, CommitMode::Async)
+            .map_err(|e| source_error("kafka commit failed", e))
+    }
+
+    async fn nack(&self, delivery: KafkaDelivery) -> Result<(), MessageSourceError> {
+        let rewind = self
+            .offsets
+            .lock()
+            .get_mut(&(delivery.topic.clone(), delivery.partition))
+            .is_some_and(|offsets| offsets.nacked(delivery.offset));
+        if !rewind {
+            return Ok(());
+        }
+
+        debug!(
+            "Kafka message {}:{}:{} failed; seeking back to it",
+            delivery.topic, delivery.partition, delivery.offset
+        );
+        self.consumer
+            .seek(
+                &delivery.topic,
+                delivery.partition,
+                Offset::Offset(delivery.offset),
+                SEEK_TIMEOUT,
+            )
+            .map_err(|e| source_error("kafka seek failed", e))
+    }
+
+    fn describe(&self) -> String {
+        format!("kafka {}@{}", self.topics.join(","), self.group_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_waits_for_earlier_offsets() {
+        let mut offsets = PartitionOffsets::new(10);
+        for offset in [10, 11, 13] {
+            offsets.received(offset);
+        }
+
+        // 11 is done, but 10 is not: nothing to commit yet.
+        assert_eq!(offsets.acked(11), None);
+        // The commit moves up to 13, still in flight; 12 was never received.
+        assert_eq!(offsets.acked(10), Some(13));
+        assert_eq!(offsets.acked(13), Some(14));
+    }
+
+    #[test]
+    fn test_nacked_offset_holds_the_commit() {
+        let mut offsets = PartitionOffsets::new(0);
+        for offset in 0..3 {
+            offsets.received(offset);
+        }
+        // 0 was nacked and stays in flight.
+        assert_eq!(offsets.acked(1), None);
+        assert_eq!(offsets.acked(2), None);
+    }
+
+    #[test]
+    fn test_nack_rewinds_to_the_failed_offset() {
+        let mut offsets = PartitionOffsets::new(0);
+        for offset in 0..4 {
+            offsets.received(offset);
+        }
+        assert_eq!(offsets.acked(0), Some(1));
+
+        // 2 fails: seek back to it. 1 came before it and commits up to 2;
+        // 3 will come again.
+        assert!(offsets.nacked(2));
+        assert_eq!(offsets.acked(1), Some(2));
+        // The stale copy of 3 is settled, but 2 has not been redelivered yet.
+        assert_eq!(offsets.acked(3), None);
+        // A nack of 3 from before the rewind does not seek forward again.
+        assert!(!offsets.nacked(3));
+
+        for offset in 2..4 {
+            offsets.received(offset);
+        }
+        assert_eq!(offsets.acked(3), None);
+        assert_eq!(offsets.acked(2), Some(4));
+    }
+}
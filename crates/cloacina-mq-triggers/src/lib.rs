@@ -0,0 +1,61 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! # Cloacina message queue sources
+//!
+//! Broker-backed [`MessageSource`](cloacina::trigger::MessageSource)
+//! implementations for [`MessageTrigger`](cloacina::trigger::MessageTrigger),
+//! each behind a cargo feature:
+//!
+//! | Feature | Source | Client |
+//! |---|---|---|
+//! | `kafka` | [`KafkaSource`] | `rdkafka` |
+//! | `nats` | [`NatsSource`] (JetStream pull consumer) | `async-nats` |
+//! | `amqp` | [`AmqpSource`] (RabbitMQ and other AMQP 0.9.1 brokers) | `lapin` |
+//!
+//! Every source leaves messages unacknowledged until the workflow they
+//! started finishes, so register the trigger with
+//! [`DefaultRunner::register_message_trigger`](cloacina::runner::DefaultRunner::register_message_trigger):
+//!
+//! ```rust,ignore
+//! use cloacina::trigger::MessageTrigger;
+//! use cloacina_mq_triggers::KafkaSource;
+//!
+//! let source = KafkaSource::builder("localhost:9092", "order-ingest")
+//!     .topic("orders")
+//!     .build()?;
+//! let trigger = Arc::new(MessageTrigger::builder("orders", source).build());
+//! runner.register_message_trigger(trigger, "ingest_order").await?;
+//! ```
+
+#[cfg(feature = "amqp")]
+mod amqp;
+#[cfg(feature = "kafka")]
+mod kafka;
+#[cfg(feature = "nats")]
+mod nats;
+
+#[cfg(feature = "amqp")]
+pub use amqp::{AmqpDelivery, AmqpSource};
+#[cfg(feature = "kafka")]
+pub use kafka::{KafkaDelivery, KafkaSource, KafkaSourceBuilder};
+#[cfg(feature = "nats")]
+pub use nats::{NatsDelivery, NatsSource};
+
+#[cfg(any(feature = "kafka", feature = "nats", feature = "amqp"))]
+fn source_error(context: &str, e: impl std::fmt::Display) -> cloacina::trigger::MessageSourceError {
+    cloacina::trigger::MessageSourceError(format!("{}: {}", context, e))
+}
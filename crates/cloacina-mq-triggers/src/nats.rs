@@ -0,0 +1,146 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! NATS JetStream source (`nats` feature).
+//!
+//! Pulls from an existing durable pull consumer. Acks are explicit; a nack
+//! asks the server to redeliver right away, and a message left unacked is
+//! redelivered once the consumer's `ack_wait` passes, so set `ack_wait`
+//! longer than the workflow takes.
+
+use std::time::Duration;
+
+use async_nats::jetstream::consumer::PullConsumer;
+use async_nats::jetstream::{self, AckKind};
+use async_trait::async_trait;
+use cloacina::trigger::{MessageEnvelope, MessageSource, MessageSourceError};
+use futures::StreamExt;
+
+use crate::source_error;
+
+/// Handle of a received JetStream message.
+pub struct NatsDelivery(jetstream::Message);
+
+impl std::fmt::Debug for NatsDelivery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NatsDelivery")
+            .field(&self.0.subject.as_str())
+            .finish()
+    }
+}
+
+/// NATS JetStream pull-consumer [`MessageSource`].
+pub struct NatsSource {
+    consumer: PullConsumer,
+    stream: String,
+    consumer_name: String,
+}
+
+impl NatsSource {
+    /// Connect to the server at `url` and bind to the durable pull consumer
+    /// `consumer` of `stream`. Both must already exist.
+    pub async fn connect(
+        url: &str,
+        stream: &str,
+        consumer: &str,
+    ) -> Result<Self, MessageSourceError> {
+        let client = async_nats::connect(url)
+            .await
+            .map_err(|e| source_error("cannot connect to nats", e))?;
+        let pull: PullConsumer = jetstream::new(client)
+            .get_stream(stream)
+            .await
+            .map_err(|e| source_error("cannot open stream", e))?
+            .get_consumer(consumer)
+            .await
+            .map_err(|e| source_error("cannot open consumer", e))?;
+        Ok(Self {
+            consumer: pull,
+            stream: stream.to_string(),
+            consumer_name: consumer.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl MessageSource for NatsSource {
+    type Delivery = NatsDelivery;
+
+    async fn receive(
+        &self,
+        max: usize,
+        wait: Duration,
+    ) -> Result<Vec<(MessageEnvelope, NatsDelivery)>, MessageSourceError> {
+        let mut batch = self
+            .consumer
+            .batch()
+            .max_messages(max)
+            .expires(wait)
+            .messages()
+            .await
+            .map_err(|e| source_error("nats fetch failed", e))?;
+
+        let mut received = Vec::new();
+        while let Some(message) = batch.next().await {
+            let message = message.map_err(|e| source_error("nats receive failed", e))?;
+            let position = message
+                .info()
+                .ok()
+                .map(|info| info.stream_sequence.to_string());
+            let headers = message
+                .headers
+                .as_ref()
+                .map(|headers| {
+                    headers
+                        .iter()
+                        .filter_map(|(name, values)| {
+                            Some((name.to_string(), values.first()?.as_str().to_string()))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let envelope = MessageEnvelope {
+                payload: message.payload.to_vec(),
+                source: message.subject.to_string(),
+                key: None,
+                position,
+                headers,
+            };
+            received.push((envelope, NatsDelivery(message)));
+        }
+        Ok(received)
+    }
+
+    async fn ack(&self, delivery: NatsDelivery) -> Result<(), MessageSourceError> {
+        delivery
+            .0
+            .ack()
+            .await
+            .map_err(|e| source_error("nats ack failed", e))
+    }
+
+    async fn nack(&self, delivery: NatsDelivery) -> Result<(), MessageSourceError> {
+        delivery
+            .0
+            .ack_with(AckKind::Nak(None))
+            .await
+            .map_err(|e| source_error("nats nak failed", e))
+    }
+
+    fn describe(&self) -> String {
+        format!("nats {}/{}", self.stream, self.consumer_name)
+    }
+}
@@ -94,6 +94,12 @@ impl PyTenantCredentials {
         self.inner.connection_string.clone()
     }
 
+    /// Connection string with the password replaced by `****`
+    #[getter]
+    pub fn masked_connection_string(&self) -> String {
+        self.inner.masked_connection_string()
+    }
+
     pub fn __repr__(&self) -> String {
         format!(
             "TenantCredentials(username='{}', schema_name='{}', password='***', connection_string='***')",
@@ -204,6 +210,36 @@ impl PyDatabaseAdmin {
         Ok(())
     }
 
+    /// Set a new password for a tenant's database user; generated when omitted
+    #[pyo3(signature = (schema_name, username, password=None))]
+    pub fn rotate_tenant_credentials(
+        &self,
+        py: Python<'_>,
+        schema_name: String,
+        username: String,
+        password: Option<String>,
+    ) -> PyResult<PyTenantCredentials> {
+        let password = password.unwrap_or_default();
+        // CLOACI-I-0136: GIL-safe bridge (see create_tenant).
+        let credentials = crate::gil::py_block_on(py, async {
+            self.inner
+                .rotate_tenant_credentials(&schema_name, &username, &password)
+                .await
+        })
+        .map_err(|e: AdminError| {
+            PyRuntimeError::new_err(format!("Failed to rotate tenant credentials: {}", e))
+        })?;
+
+        Ok(PyTenantCredentials { inner: credentials })
+    }
+
+    /// Names of all tenant schemas
+    pub fn list_tenant_schemas(&self, py: Python<'_>) -> PyResult<Vec<String>> {
+        crate::gil::py_block_on(py, async { self.inner.list_tenant_schemas().await }).map_err(
+            |e: AdminError| PyRuntimeError::new_err(format!("Failed to list tenants: {}", e)),
+        )
+    }
+
     pub fn __repr__(&self) -> String {
         "DatabaseAdmin()".to_string()
     }
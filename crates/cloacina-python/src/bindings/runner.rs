@@ -106,6 +106,13 @@ enum RuntimeMessage {
         timezone: String,
         response_tx: oneshot::Sender<Result<(), cloacina::executor::WorkflowExecutionError>>,
     },
+    GetCronScheduleFireTimes {
+        schedule_id: String,
+        count: usize,
+        response_tx: oneshot::Sender<
+            Result<Vec<chrono::DateTime<chrono::Utc>>, cloacina::executor::WorkflowExecutionError>,
+        >,
+    },
     GetCronExecutionHistory {
         schedule_id: String,
         limit: i64,
@@ -582,6 +589,20 @@ async fn run_event_loop(
                     let _ = response_tx.send(result);
                 });
             }
+            RuntimeMessage::GetCronScheduleFireTimes {
+                schedule_id,
+                count,
+                response_tx,
+            } => {
+                let runner = runner.clone();
+                tokio::spawn(async move {
+                    let result = match parse_schedule_id(&schedule_id) {
+                        Ok(id) => runner.get_cron_schedule_fire_times(id, count).await,
+                        Err(e) => Err(e),
+                    };
+                    let _ = response_tx.send(result);
+                });
+            }
             RuntimeMessage::GetCronExecutionHistory {
                 schedule_id,
                 limit,
@@ -1142,6 +1163,29 @@ impl PyDefaultRunner {
         self.send_and_recv(message, response_rx, py, "Failed to update cron schedule")
     }
 
+    /// Next fire times of a cron schedule as RFC 3339 strings (default 10, at most 100)
+    #[pyo3(signature = (schedule_id, count=None))]
+    pub fn get_cron_schedule_fire_times(
+        &self,
+        schedule_id: String,
+        count: Option<usize>,
+        py: Python,
+    ) -> PyResult<Vec<String>> {
+        let (response_tx, response_rx) = oneshot::channel();
+        let message = RuntimeMessage::GetCronScheduleFireTimes {
+            schedule_id,
+            count: count.unwrap_or(10),
+            response_tx,
+        };
+        let times = self.send_and_recv(
+            message,
+            response_rx,
+            py,
+            "Failed to get cron schedule fire times",
+        )?;
+        Ok(times.into_iter().map(|t| t.to_rfc3339()).collect())
+    }
+
     /// Get execution history for a specific cron schedule
     pub fn get_cron_execution_history(
         &self,
@@ -133,7 +133,17 @@ pub fn extract_python_package(
         path: archive_path.display().to_string(),
         error: e.to_string(),
     })?;
+    extract_python_package_file(&archive_path, staging_dir)
+}
 
+/// Extract a Python workflow package from the `.cloacina` source archive at
+/// *archive_path*, which is read from disk rather than held in memory.
+///
+/// Otherwise identical to [`extract_python_package`].
+pub fn extract_python_package_file(
+    archive_path: &Path,
+    staging_dir: &Path,
+) -> Result<ExtractedPythonPackage, LoaderError> {
     // Unpack via fidius
     let extract_dir = staging_dir.join(uuid::Uuid::new_v4().to_string());
     std::fs::create_dir_all(&extract_dir).map_err(|e| LoaderError::FileSystem {
@@ -142,7 +152,7 @@ pub fn extract_python_package(
     })?;
 
     let source_dir =
-        fidius_core::package::unpack_package(&archive_path, &extract_dir).map_err(|e| {
+        fidius_core::package::unpack_package(archive_path, &extract_dir).map_err(|e| {
             LoaderError::FileSystem {
                 path: archive_path.display().to_string(),
                 error: format!("Failed to unpack source archive: {e}"),
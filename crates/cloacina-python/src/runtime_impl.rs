@@ -36,7 +36,7 @@ pub struct CloacinaPythonRuntime;
 impl PythonRuntime for CloacinaPythonRuntime {
     fn load_workflow_package(
         &self,
-        archive_data: &[u8],
+        archive_path: &Path,
         staging_dir: &Path,
         tenant_id: &str,
         runtime: &Arc<Runtime>,
@@ -44,8 +44,9 @@ impl PythonRuntime for CloacinaPythonRuntime {
         std::fs::create_dir_all(staging_dir)
             .map_err(|e| format!("Failed to create Python staging dir: {}", e))?;
 
-        let extracted = crate::package_loader::extract_python_package(archive_data, staging_dir)
-            .map_err(|e| format!("Failed to extract Python package: {}", e))?;
+        let extracted =
+            crate::package_loader::extract_python_package_file(archive_path, staging_dir)
+                .map_err(|e| format!("Failed to extract Python package: {}", e))?;
 
         pyo3::prepare_freethreaded_python();
         let task_namespaces: Vec<TaskNamespace> =
@@ -93,7 +94,7 @@ impl PythonRuntime for CloacinaPythonRuntime {
 
     fn load_cg_package(
         &self,
-        archive_data: &[u8],
+        archive_path: &Path,
         staging_dir: &Path,
         tenant_id: &str,
         graph_name: &str,
@@ -104,8 +105,9 @@ impl PythonRuntime for CloacinaPythonRuntime {
         std::fs::create_dir_all(staging_dir)
             .map_err(|e| format!("Failed to create Python CG staging dir: {}", e))?;
 
-        let extracted = crate::package_loader::extract_python_package(archive_data, staging_dir)
-            .map_err(|e| format!("Failed to extract Python CG package: {}", e))?;
+        let extracted =
+            crate::package_loader::extract_python_package_file(archive_path, staging_dir)
+                .map_err(|e| format!("Failed to extract Python CG package: {}", e))?;
 
         pyo3::prepare_freethreaded_python();
         crate::loader::import_python_computation_graph(
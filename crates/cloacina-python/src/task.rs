@@ -222,6 +222,7 @@ impl cloacina::Task for PythonTaskWrapper {
         let (mut final_context, returned_handle) = tokio::task::spawn_blocking(move || {
             Python::with_gil(|py| {
                 let original_data = context.data().clone();
+                let original_bytes = context.binary().clone();
                 let py_context = PyContext::from_rust_context(context);
 
                 let (result, recovered_handle) = if let Some(handle) = task_handle {
@@ -248,11 +249,7 @@ impl cloacina::Task for PythonTaskWrapper {
                 match result {
                     Ok(returned) => {
                         let final_context = if returned.is_none(py) {
-                            let mut new_context = cloacina::Context::new();
-                            for (key, value) in original_data.iter() {
-                                new_context.insert(key.clone(), value.clone()).unwrap();
-                            }
-                            new_context
+                            cloacina::Context::from_parts(original_data, original_bytes)
                         } else {
                             let returned_context: PyContext =
                                 returned.extract(py).map_err(|e| {
@@ -379,18 +376,12 @@ impl cloacina::Task for PythonTaskWrapper {
             //    updated context so the mutation survives the spawn_blocking hop.
             if let Some(post) = invocation.post_invocation.as_ref() {
                 let post = Python::with_gil(|py| post.clone_ref(py));
-                let post_data: Vec<(String, serde_json::Value)> = final_context
-                    .data()
-                    .iter()
-                    .map(|(k, v)| (k.clone(), v.clone()))
-                    .collect();
+                // Binary values ride along so the callback's context keeps them.
+                let post_data = final_context.to_map().unwrap_or_default();
                 let post_result = tokio::task::spawn_blocking(move || {
                     Python::with_gil(
                         |py| -> PyResult<Option<cloacina::Context<serde_json::Value>>> {
-                            let mut ctx = cloacina::Context::new();
-                            for (k, v) in post_data {
-                                ctx.insert(k, v).ok();
-                            }
+                            let ctx = cloacina::Context::from_map(post_data).unwrap_or_default();
                             let py_ctx = PyContext::from_rust_context(ctx);
                             let returned = post.call1(py, (py_ctx,))?;
                             if !returned.is_none(py) {
@@ -71,6 +71,18 @@ struct AdvisoryLockRow {
 /// follower replicas.
 #[cfg(feature = "postgres")]
 pub async fn with_fleet_leadership<F, Fut, T>(db: &Database, work: F) -> Option<T>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = T>,
+{
+    with_advisory_leadership(db, FLEET_CONTROL_LOCK_KEY, work).await
+}
+
+/// [`with_fleet_leadership`] for an arbitrary advisory-lock key, so other
+/// single-writer loops (federation chain forwarding) can elect their own
+/// leader without contending with the fleet control loop.
+#[cfg(feature = "postgres")]
+pub async fn with_advisory_leadership<F, Fut, T>(db: &Database, key: i64, work: F) -> Option<T>
 where
     F: FnOnce() -> Fut,
     Fut: Future<Output = T>,
@@ -80,14 +92,14 @@ where
     let conn = match db.get_postgres_connection().await {
         Ok(c) => c,
         Err(e) => {
-            warn!(error = %e, "fleet leadership: failed to acquire a connection; skipping tick");
+            warn!(lock_key = key, error = %e, "leadership: failed to acquire a connection; skipping tick");
             return None;
         }
     };
 
     // Try to take the lock. A non-blocking try: if another replica holds it we
     // get `false` and bow out rather than queueing.
-    let lock_sql = format!("SELECT pg_try_advisory_lock({FLEET_CONTROL_LOCK_KEY}) AS locked");
+    let lock_sql = format!("SELECT pg_try_advisory_lock({key}) AS locked");
     let acquired = match conn
         .interact(move |conn| {
             use diesel::RunQueryDsl;
@@ -97,11 +109,11 @@ where
     {
         Ok(Ok(row)) => row.locked,
         Ok(Err(e)) => {
-            warn!(error = %e, "fleet leadership: pg_try_advisory_lock query failed; skipping tick");
+            warn!(lock_key = key, error = %e, "leadership: pg_try_advisory_lock query failed; skipping tick");
             return None;
         }
         Err(e) => {
-            warn!(error = %e, "fleet leadership: interact failed during lock; skipping tick");
+            warn!(lock_key = key, error = %e, "leadership: interact failed during lock; skipping tick");
             return None;
         }
     };
@@ -114,7 +126,7 @@ where
     // We are the leader. Run the work, then ALWAYS unlock.
     let out = work().await;
 
-    let unlock_sql = format!("SELECT pg_advisory_unlock({FLEET_CONTROL_LOCK_KEY}) AS locked");
+    let unlock_sql = format!("SELECT pg_advisory_unlock({key}) AS locked");
     match conn
         .interact(move |conn| {
             use diesel::RunQueryDsl;
@@ -124,11 +136,14 @@ where
     {
         Ok(Ok(row)) if row.locked => {}
         Ok(Ok(_)) => warn!(
-            "fleet leadership: pg_advisory_unlock returned false (lock was not held on this \
+            lock_key = key,
+            "leadership: pg_advisory_unlock returned false (lock was not held on this \
              session); the lock will free when this connection is recycled"
         ),
-        Ok(Err(e)) => warn!(error = %e, "fleet leadership: pg_advisory_unlock query failed"),
-        Err(e) => warn!(error = %e, "fleet leadership: interact failed during unlock"),
+        Ok(Err(e)) => {
+            warn!(lock_key = key, error = %e, "leadership: pg_advisory_unlock query failed")
+        }
+        Err(e) => warn!(lock_key = key, error = %e, "leadership: interact failed during unlock"),
     }
 
     Some(out)
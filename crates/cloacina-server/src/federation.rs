@@ -0,0 +1,691 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Multi-region federation.
+//!
+//! Deployments whose data has to stay in-region run one cluster per region,
+//! each with its own database. Federation links those clusters loosely: each
+//! server lists its peers in a federation config file and talks to them over
+//! their public REST API with a peer API key. Nothing is replicated — a
+//! region's executions, contexts and packages stay in its own database.
+//!
+//! Two things cross the link:
+//!
+//! - **Unified query view.** `GET /v1/tenants/{tenant_id}/federation/executions`
+//!   returns the tenant's executions from this region and every peer, each
+//!   tagged with its region. A peer that cannot be reached is reported next to
+//!   the partial result instead of failing the request.
+//! - **Cross-region chaining.** A chain rule starts a workflow in a peer region
+//!   whenever a workflow completes in this one. Only the context keys listed in
+//!   the rule's `forward_keys` leave the region. The forwarded execution is
+//!   labelled with its source region and execution ID; the chain loop checks
+//!   for that label before forwarding, which keeps forwarding idempotent across
+//!   restarts. The loop is leader-gated so replicas never race each other, and
+//!   resumes from a per-rule `(completed_at, id)` cursor stored in the tenant
+//!   database.
+//!
+//! ```toml
+//! region = "us-east"
+//!
+//! [[peers]]
+//! region = "eu-west"
+//! url = "https://cloacina.eu-west.example.com"
+//! api_key = "env:CLOACINA_PEER_EU_WEST_KEY"
+//!
+//! [[chains]]
+//! tenant = "acme"
+//! workflow = "extract_orders"
+//! region = "eu-west"
+//! target_workflow = "load_orders"
+//! forward_keys = ["batch_id", "row_count"]
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{bail, Context as _, Result};
+use serde::Deserialize;
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use cloacina::dal::DAL;
+use cloacina::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use cloacina::database::Database;
+use cloacina_api_types::{
+    ExecuteRequest, ExecuteResponse, ExecutionSummary, FederatedExecutionSummary, FederationRegion,
+    ListExecutionsQuery, RegionError,
+};
+use cloacina_client::{Client, ClientBuilder};
+
+use crate::TenantDatabaseCache;
+
+/// Label carrying the region a chained execution was forwarded from.
+pub const SOURCE_REGION_LABEL: &str = "federation/source-region";
+/// Label carrying the ID of the execution that triggered a chained one.
+pub const SOURCE_EXECUTION_LABEL: &str = "federation/source-execution";
+
+/// Advisory-lock key for the chain loop. Distinct from the fleet control
+/// key so the two loops elect leaders independently.
+pub const FEDERATION_CHAIN_LOCK_KEY: i64 = 44_510_001;
+
+/// Default seconds between chain-loop passes.
+pub const DEFAULT_CHAIN_INTERVAL_S: u64 = 15;
+
+/// Request timeout for calls to a peer region.
+const PEER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How far back the chain loop looks for completed source executions.
+/// Bounds the re-check work after a restart or a long peer outage.
+const CHAIN_LOOKBACK_HOURS: i64 = 24;
+
+/// Completed source executions read per page; a pass pages until it has
+/// caught up with the rule's cursor.
+const CHAIN_BATCH: i64 = 100;
+
+/// Completions newer than this are left for the next pass, so a row whose
+/// transaction commits after a later-stamped one is not stepped over by the
+/// cursor.
+const CHAIN_SETTLE_SECS: i64 = 5;
+
+/// Federation config file (`--federation-config`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct FederationConfig {
+    /// Name of the region this server runs in.
+    pub region: String,
+    #[serde(default)]
+    pub peers: Vec<PeerConfig>,
+    #[serde(default)]
+    pub chains: Vec<ChainRule>,
+    /// Seconds between chain-loop passes.
+    #[serde(default = "default_chain_interval_s")]
+    pub chain_interval_s: u64,
+}
+
+fn default_chain_interval_s() -> u64 {
+    DEFAULT_CHAIN_INTERVAL_S
+}
+
+/// One peer region.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PeerConfig {
+    pub region: String,
+    /// Base URL of the peer's cloacina-server.
+    pub url: String,
+    /// API key for the peer — `env:VAR`, `file:/path`, or a literal key,
+    /// resolved the same way as a `cloacinactl` profile key. Needs read
+    /// access to every federated tenant and write access to chain targets.
+    pub api_key: String,
+}
+
+/// Start `target_workflow` in `region` whenever `workflow` completes here.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainRule {
+    pub tenant: String,
+    pub workflow: String,
+    /// Peer region the chained workflow runs in.
+    pub region: String,
+    /// Workflow to start in the peer region; defaults to `workflow`.
+    #[serde(default)]
+    pub target_workflow: Option<String>,
+    /// Final-context keys passed to the chained execution. Nothing else
+    /// leaves the region; an empty list forwards an empty context.
+    #[serde(default)]
+    pub forward_keys: Vec<String>,
+}
+
+impl ChainRule {
+    /// Workflow started in the peer region.
+    pub fn target_workflow(&self) -> &str {
+        self.target_workflow.as_deref().unwrap_or(&self.workflow)
+    }
+
+    /// Key the rule's progress is stored under in the tenant database.
+    fn cursor_key(&self) -> String {
+        format!(
+            "{}->{}/{}",
+            self.workflow,
+            self.region,
+            self.target_workflow()
+        )
+    }
+}
+
+impl FederationConfig {
+    /// Read and validate a federation config file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read federation config {}", path.display()))?;
+        let config: FederationConfig = toml::from_str(&raw)
+            .with_context(|| format!("invalid federation config {}", path.display()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Reject configs the server could not act on: duplicate or self-named
+    /// peers, and chains pointing at unknown regions.
+    pub fn validate(&self) -> Result<()> {
+        if self.region.trim().is_empty() {
+            bail!("federation config: `region` must not be empty");
+        }
+        if self.chain_interval_s == 0 {
+            bail!("federation config: `chain_interval_s` must be at least 1");
+        }
+        let mut seen = HashSet::new();
+        for peer in &self.peers {
+            if peer.region == self.region {
+                bail!(
+                    "federation config: peer '{}' has the same name as this region",
+                    peer.region
+                );
+            }
+            if !seen.insert(peer.region.as_str()) {
+                bail!("federation config: peer '{}' listed twice", peer.region);
+            }
+        }
+        for chain in &self.chains {
+            if !seen.contains(chain.region.as_str()) {
+                bail!(
+                    "federation config: chain for '{}' targets unknown region '{}'",
+                    chain.workflow,
+                    chain.region
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Peer {
+    url: String,
+    client: Client,
+}
+
+/// Live federation state: this region's name, a client per peer, and the
+/// chain rules.
+pub struct Federation {
+    region: String,
+    peers: BTreeMap<String, Peer>,
+    chains: Vec<ChainRule>,
+    chain_interval: Duration,
+}
+
+impl Federation {
+    /// Build peer clients from a validated config. Fails if a peer's API key
+    /// cannot be resolved.
+    pub fn from_config(config: FederationConfig) -> Result<Self> {
+        let mut peers = BTreeMap::new();
+        for peer in config.peers {
+            let api_key = cloacina_client::resolve_api_key_scheme(&peer.api_key)
+                .with_context(|| format!("federation peer '{}'", peer.region))?;
+            // Peers behind a private CA or requiring mTLS use the same
+            // CLOACINA_TLS_* client settings as cloacinactl.
+            let client = ClientBuilder::new(&peer.url)
+                .api_key(api_key)
+                .tls(cloacina_client::TlsOptions::from_env())
+                .timeout(PEER_TIMEOUT)
+                .build()
+                .with_context(|| format!("federation peer '{}'", peer.region))?;
+            peers.insert(
+                peer.region,
+                Peer {
+                    url: peer.url,
+                    client,
+                },
+            );
+        }
+        Ok(Self {
+            region: config.region,
+            peers,
+            chains: config.chains,
+            chain_interval: Duration::from_secs(config.chain_interval_s),
+        })
+    }
+
+    /// Region this server runs in.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Configured chain rules.
+    pub fn chains(&self) -> &[ChainRule] {
+        &self.chains
+    }
+
+    /// This region plus every peer, with a health probe of each peer.
+    pub async fn regions(&self) -> Vec<FederationRegion> {
+        let mut probes = tokio::task::JoinSet::new();
+        for (region, peer) in &self.peers {
+            let (region, url, client) = (region.clone(), peer.url.clone(), peer.client.clone());
+            probes.spawn(async move {
+                let error = client.health().await.err().map(|e| e.to_string());
+                FederationRegion {
+                    region,
+                    url: Some(url),
+                    local: false,
+                    reachable: error.is_none(),
+                    error,
+                }
+            });
+        }
+        let mut regions = vec![FederationRegion {
+            region: self.region.clone(),
+            url: None,
+            local: true,
+            reachable: true,
+            error: None,
+        }];
+        while let Some(probe) = probes.join_next().await {
+            match probe {
+                Ok(region) => regions.push(region),
+                Err(e) => warn!("federation health probe panicked: {}", e),
+            }
+        }
+        regions[1..].sort_by(|a, b| a.region.cmp(&b.region));
+        regions
+    }
+
+    /// Query every peer's executions for `tenant_id` concurrently. Returns
+    /// the region-tagged rows and the peers that failed.
+    pub async fn list_peer_executions(
+        &self,
+        tenant_id: &str,
+        query: &ListExecutionsQuery,
+    ) -> (Vec<FederatedExecutionSummary>, Vec<RegionError>) {
+        let mut calls = tokio::task::JoinSet::new();
+        for (region, peer) in &self.peers {
+            let (region, client) = (region.clone(), peer.client.clone());
+            let (tenant_id, query) = (tenant_id.to_string(), query.clone());
+            calls.spawn(async move {
+                let result = client.list_executions(&query, Some(&tenant_id)).await;
+                (region, result)
+            });
+        }
+        let mut items = Vec::new();
+        let mut unreachable = Vec::new();
+        while let Some(call) = calls.join_next().await {
+            match call {
+                Ok((region, Ok(page))) => {
+                    items.extend(page.items.into_iter().map(|execution| {
+                        FederatedExecutionSummary {
+                            region: region.clone(),
+                            execution,
+                        }
+                    }));
+                }
+                Ok((region, Err(e))) => {
+                    warn!(region = %region, "federated execution query failed: {}", e);
+                    unreachable.push(RegionError {
+                        region,
+                        error: e.to_string(),
+                    });
+                }
+                Err(e) => warn!("federated execution query panicked: {}", e),
+            }
+        }
+        unreachable.sort_by(|a, b| a.region.cmp(&b.region));
+        (items, unreachable)
+    }
+
+    /// Run the chain loop until shutdown. Every replica runs it; each pass
+    /// only does work on the replica holding the chain advisory lock.
+    pub async fn run_chain_loop(
+        self: Arc<Self>,
+        admin_db: Database,
+        tenant_databases: Arc<TenantDatabaseCache>,
+        mut shutdown: watch::Receiver<bool>,
+    ) {
+        info!(
+            region = %self.region,
+            rules = self.chains.len(),
+            interval_s = self.chain_interval.as_secs(),
+            "federation chain loop started (leader-gated)"
+        );
+        let mut ticker = tokio::time::interval(self.chain_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    crate::autoscaler::leader::with_advisory_leadership(
+                        &admin_db,
+                        FEDERATION_CHAIN_LOCK_KEY,
+                        || self.chain_pass(&admin_db, &tenant_databases),
+                    )
+                    .await;
+                }
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        info!("federation chain loop shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn chain_pass(&self, admin_db: &Database, tenant_databases: &TenantDatabaseCache) {
+        let now = chrono::Utc::now();
+        let since = UniversalTimestamp(now - chrono::Duration::hours(CHAIN_LOOKBACK_HOURS));
+        let until = UniversalTimestamp(now - chrono::Duration::seconds(CHAIN_SETTLE_SECS));
+        for rule in &self.chains {
+            let db = match tenant_databases.resolve(&rule.tenant, admin_db).await {
+                Ok(db) => db,
+                Err(e) => {
+                    warn!(tenant = %rule.tenant, "federation chain: tenant database error: {}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = self.chain_rule(rule, &DAL::new(db), since, until).await {
+                warn!(
+                    tenant = %rule.tenant,
+                    workflow = %rule.workflow,
+                    "federation chain: {:#}",
+                    e
+                );
+            }
+        }
+    }
+
+    /// Forward every completion of the rule's workflow past its persisted
+    /// `(completed_at, id)` cursor, a page at a time, advancing the cursor
+    /// after each one. A failed forward stops the rule for this pass so the
+    /// same execution is retried first on the next one.
+    async fn chain_rule(
+        &self,
+        rule: &ChainRule,
+        dal: &DAL,
+        since: UniversalTimestamp,
+        until: UniversalTimestamp,
+    ) -> Result<()> {
+        let key = rule.cursor_key();
+        let mut cursor = dal
+            .federation_chain_cursor()
+            .get(&key)
+            .await
+            .context("failed to read chain cursor")?;
+        loop {
+            let page = dal
+                .workflow_execution()
+                .list_completed_after(&rule.workflow, since, until, cursor, CHAIN_BATCH)
+                .await
+                .context("failed to list completed executions")?;
+            let more = page.len() as i64 == CHAIN_BATCH;
+
+            for execution in page {
+                let Some(completed_at) = execution.completed_at else {
+                    continue;
+                };
+                match self
+                    .forward(rule, dal, execution.id, execution.context_id)
+                    .await
+                {
+                    Ok(Some(chained)) => {
+                        metrics::counter!("cloacina_federation_chain_forwards_total", "outcome" => "forwarded")
+                            .increment(1);
+                        info!(
+                            tenant = %rule.tenant,
+                            source_execution = %execution.id,
+                            region = %rule.region,
+                            workflow = %chained.workflow_name,
+                            execution_id = %chained.execution_id,
+                            "federation chain: started workflow in peer region"
+                        );
+                    }
+                    Ok(None) => {
+                        debug!(
+                            source_execution = %execution.id,
+                            region = %rule.region,
+                            "federation chain: already forwarded"
+                        );
+                    }
+                    Err(e) => {
+                        metrics::counter!("cloacina_federation_chain_forwards_total", "outcome" => "failed")
+                            .increment(1);
+                        warn!(
+                            tenant = %rule.tenant,
+                            source_execution = %execution.id,
+                            region = %rule.region,
+                            "federation chain: forward failed, retrying next pass: {:#}",
+                            e
+                        );
+                        return Ok(());
+                    }
+                }
+                dal.federation_chain_cursor()
+                    .advance(&key, completed_at, execution.id)
+                    .await
+                    .context("failed to advance chain cursor")?;
+                cursor = Some((completed_at, execution.id));
+            }
+
+            if !more {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Start the chained execution for one completed source execution.
+    /// Returns `None` when the peer already has it.
+    async fn forward(
+        &self,
+        rule: &ChainRule,
+        dal: &DAL,
+        source_id: UniversalUuid,
+        context_id: Option<UniversalUuid>,
+    ) -> Result<Option<ExecuteResponse>> {
+        let peer = self
+            .peers
+            .get(&rule.region)
+            .with_context(|| format!("unknown peer region '{}'", rule.region))?;
+
+        let existing = peer
+            .client
+            .list_executions(
+                &ListExecutionsQuery {
+                    label: Some(format!("{SOURCE_EXECUTION_LABEL}={source_id}")),
+                    limit: Some(1),
+                    ..Default::default()
+                },
+                Some(&rule.tenant),
+            )
+            .await
+            .context("checking peer for an earlier forward")?;
+        if !existing.items.is_empty() {
+            return Ok(None);
+        }
+
+        // A context purged by retention forwards as empty.
+        let data = match context_id {
+            Some(id) => dal
+                .context()
+                .read::<serde_json::Value>(id)
+                .await
+                .context("reading source execution context")?
+                .to_map()?
+                .into_iter()
+                .collect(),
+            None => HashMap::new(),
+        };
+        let labels = BTreeMap::from([
+            (SOURCE_REGION_LABEL.to_string(), self.region.clone()),
+            (SOURCE_EXECUTION_LABEL.to_string(), source_id.to_string()),
+        ]);
+        let request = ExecuteRequest {
+            context: Some(serde_json::Value::Object(forwarded_context(
+                &data,
+                &rule.forward_keys,
+            ))),
+            labels,
+            ..Default::default()
+        };
+        let response = peer
+            .client
+            .post_json(
+                &format!(
+                    "/v1/tenants/{}/workflows/{}/execute",
+                    rule.tenant,
+                    rule.target_workflow()
+                ),
+                &request,
+            )
+            .await
+            .context("starting chained execution on peer")?;
+        Ok(Some(response))
+    }
+}
+
+/// The part of a final context allowed to leave the region: the listed keys
+/// that are present, nothing else.
+pub fn forwarded_context(
+    data: &HashMap<String, serde_json::Value>,
+    forward_keys: &[String],
+) -> serde_json::Map<String, serde_json::Value> {
+    forward_keys
+        .iter()
+        .filter_map(|key| data.get(key).map(|value| (key.clone(), value.clone())))
+        .collect()
+}
+
+/// Merge this region's executions with the peers' into one newest-first
+/// page of at most `limit` rows.
+pub fn merge_federated(
+    region: &str,
+    local: Vec<ExecutionSummary>,
+    peers: Vec<FederatedExecutionSummary>,
+    limit: usize,
+) -> Vec<FederatedExecutionSummary> {
+    let mut items: Vec<FederatedExecutionSummary> = local
+        .into_iter()
+        .map(|execution| FederatedExecutionSummary {
+            region: region.to_string(),
+            execution,
+        })
+        .chain(peers)
+        .collect();
+    // Regions format timestamps identically, but parse anyway so a peer with
+    // a different offset still sorts correctly.
+    items.sort_by_cached_key(|item| {
+        std::cmp::Reverse(
+            chrono::DateTime::parse_from_rfc3339(&item.execution.started_at)
+                .map(|t| t.timestamp_micros())
+                .unwrap_or(i64::MIN),
+        )
+    });
+    items.truncate(limit);
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(raw: &str) -> Result<FederationConfig> {
+        let config: FederationConfig = toml::from_str(raw)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn summary(id: &str, started_at: &str) -> ExecutionSummary {
+        ExecutionSummary {
+            id: id.to_string(),
+            workflow_name: "wf".to_string(),
+            status: "Completed".to_string(),
+            started_at: started_at.to_string(),
+            completed_at: None,
+            trigger_origin: None,
+            run_id: None,
+            logical_date: None,
+            labels: BTreeMap::new(),
+            resolution: None,
+        }
+    }
+
+    #[test]
+    fn config_parses_and_defaults() {
+        let config = parse(
+            r#"
+            region = "us-east"
+            [[peers]]
+            region = "eu-west"
+            url = "https://eu.example.com"
+            api_key = "env:EU_KEY"
+            [[chains]]
+            tenant = "acme"
+            workflow = "extract"
+            region = "eu-west"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.chain_interval_s, DEFAULT_CHAIN_INTERVAL_S);
+        assert_eq!(config.chains[0].target_workflow(), "extract");
+        assert!(config.chains[0].forward_keys.is_empty());
+    }
+
+    #[test]
+    fn config_rejects_bad_topology() {
+        let self_peer = r#"
+            region = "us-east"
+            [[peers]]
+            region = "us-east"
+            url = "http://x"
+            api_key = "k"
+        "#;
+        assert!(parse(self_peer).is_err());
+
+        let unknown_target = r#"
+            region = "us-east"
+            [[chains]]
+            tenant = "acme"
+            workflow = "extract"
+            region = "eu-west"
+        "#;
+        assert!(parse(unknown_target).is_err());
+    }
+
+    #[test]
+    fn only_listed_keys_are_forwarded() {
+        let data = HashMap::from([
+            ("batch_id".to_string(), serde_json::json!("b-1")),
+            ("customer_email".to_string(), serde_json::json!("a@b.c")),
+        ]);
+        let forwarded = forwarded_context(&data, &["batch_id".to_string(), "missing".to_string()]);
+        assert_eq!(forwarded.len(), 1);
+        assert_eq!(forwarded["batch_id"], "b-1");
+    }
+
+    #[test]
+    fn merge_orders_newest_first_across_regions() {
+        let local = vec![summary("a", "2026-01-01T10:00:00+00:00")];
+        let peers = vec![FederatedExecutionSummary {
+            region: "eu-west".to_string(),
+            // 10:30 UTC, written with a non-UTC offset.
+            execution: summary("b", "2026-01-01T11:30:00+01:00"),
+        }];
+        let merged = merge_federated("us-east", local, peers, 10);
+        assert_eq!(merged[0].execution.id, "b");
+        assert_eq!(merged[0].region, "eu-west");
+        assert_eq!(merged[1].region, "us-east");
+
+        let merged = merge_federated(
+            "us-east",
+            vec![summary("a", "2026-01-01T10:00:00+00:00")],
+            Vec::new(),
+            0,
+        );
+        assert!(merged.is_empty());
+    }
+}
@@ -44,8 +44,8 @@ use cloacina::error::{ExecutorError, TaskError};
 use cloacina::executor::types::ClaimedTask;
 use cloacina::executor::{TaskContextBuilder, TaskResultHandler};
 use cloacina::fleet::{
-    host_target_triple, AgentOutcome, AgentResultRequest, ArtifactRef, WorkPacket, WrappedSecret,
-    AGENT_PROTOCOL_VERSION, AGENT_RECIPIENT_PREFIX, WORK_PACKET_KIND,
+    host_target_triple, AgentOutcome, AgentResultRequest, ArtifactRef, FailureClassification,
+    WorkPacket, WrappedSecret, AGENT_PROTOCOL_VERSION, AGENT_RECIPIENT_PREFIX, WORK_PACKET_KIND,
 };
 use cloacina::models::delivery_outbox::NewDeliveryOutbox;
 use cloacina::retry::RetryPolicy;
@@ -63,6 +63,13 @@ use crate::fleet_coordinator::FleetCoordinator;
 /// dispatcher slot doesn't pin forever on a silent agent.
 const RESULT_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
 
+/// Execution timeout sent to the agent for tasks that declare none.
+const DEFAULT_PACKET_TIMEOUT_SECS: u32 = 300;
+
+/// Slack on top of a task's own timeout before the server stops waiting, so
+/// the agent's timeout failure arrives before the server gives up.
+const RESULT_WAIT_GRACE: Duration = Duration::from_secs(30);
+
 /// Baseline advertised capacity so an empty fleet still reports a value the
 /// dispatcher can throttle against.
 const MIN_ADVERTISED_CAPACITY: usize = 0;
@@ -307,7 +314,8 @@ impl TaskExecutor for FleetExecutor {
 
         // Heartbeat the claim for the lifetime of the dispatch so the stale-claim
         // sweeper (60s) doesn't reclaim a task while the agent is still running it
-        // (the result wait can be up to RESULT_WAIT_TIMEOUT).
+        // (the result wait can be up to RESULT_WAIT_TIMEOUT, or longer for
+        // tasks with a longer timeout).
         let heartbeat = if claimed {
             let dal = self.dal.clone();
             let runner_id = self.instance_id;
@@ -466,26 +474,35 @@ impl TaskExecutor for FleetExecutor {
             // ── 3. Resolve the task's dependencies from the server Runtime, then
             //       build the merged dependency context via the SHARED
             //       TaskContextBuilder so the agent receives exactly the input
-            //       context a thread run would produce.
-            let dependencies: Vec<cloacina::task::TaskNamespace> =
-                match self.runtime.get_task(&namespace) {
-                    Some(task) => task.dependencies().to_vec(),
-                    None => {
-                        return Ok(self
-                            .reconcile_error(
-                                &event,
-                                &claimed_task,
-                                &retry_policy,
-                                start.elapsed(),
-                                format!(
-                                    "task `{}` not loaded in server runtime — cannot resolve \
+            //       context a thread run would produce. The task's granted
+            //       capabilities travel with the packet so a sandboxing agent
+            //       can confine the execution to them, and so does its timeout.
+            let (dependencies, capabilities, task_timeout): (
+                Vec<cloacina::task::TaskNamespace>,
+                _,
+                _,
+            ) = match self.runtime.get_task(&namespace) {
+                Some(task) => (
+                    task.data_dependencies(),
+                    task.capabilities(),
+                    task.timeout(),
+                ),
+                None => {
+                    return Ok(self
+                        .reconcile_error(
+                            &event,
+                            &claimed_task,
+                            &retry_policy,
+                            start.elapsed(),
+                            format!(
+                                "task `{}` not loaded in server runtime — cannot resolve \
                                  dependency context for fleet dispatch",
-                                    event.task_name
-                                ),
-                            )
-                            .await);
-                    }
-                };
+                                event.task_name
+                            ),
+                        )
+                        .await);
+                }
+            };
             let context = match self
                 .context_builder
                 .build(&claimed_task, &dependencies)
@@ -505,6 +522,7 @@ impl TaskExecutor for FleetExecutor {
                 }
             };
             let context_value = context_to_json(&context);
+            let params = context.params().cloned();
 
             // ── 3b. CLOACI-T-0780 (multi-arch): resolve the cdylib digest + the
             //        triple to stamp. An INTERPRETED package runs on the selected
@@ -570,6 +588,11 @@ impl TaskExecutor for FleetExecutor {
             };
 
             // ── 4. Build the work packet with real context + real artifact ref.
+            let timeout_seconds = task_timeout.map_or(DEFAULT_PACKET_TIMEOUT_SECS, |timeout| {
+                timeout.as_secs().clamp(1, u64::from(u32::MAX)) as u32
+            });
+            let result_wait = RESULT_WAIT_TIMEOUT
+                .max(Duration::from_secs(u64::from(timeout_seconds)) + RESULT_WAIT_GRACE);
             let packet = WorkPacket {
                 protocol_version: AGENT_PROTOCOL_VERSION,
                 task_execution_id: event.task_execution_id.0.to_string(),
@@ -582,7 +605,7 @@ impl TaskExecutor for FleetExecutor {
                     digest,
                     build_target_triple,
                 },
-                timeout_seconds: 300,
+                timeout_seconds,
                 tenant_id: tenant_id.clone(),
                 language: Some(language),
                 // CLOACI-T-0861 / D-5 — secrets resolved + HPKE-wrapped to a
@@ -591,6 +614,8 @@ impl TaskExecutor for FleetExecutor {
                 // Both empty/None when the task references no secrets.
                 wrapped_secrets,
                 secret_key_id,
+                capabilities,
+                params,
             };
             let payload_bytes = match serde_json::to_vec(&packet) {
                 Ok(b) => b,
@@ -658,47 +683,46 @@ impl TaskExecutor for FleetExecutor {
             );
 
             // ── 7. Await the agent's result.
-            let result_req: AgentResultRequest =
-                match tokio::time::timeout(RESULT_WAIT_TIMEOUT, rx).await {
-                    Ok(Ok(req)) => req,
-                    Ok(Err(_)) => {
-                        // Sender dropped — coordinator cleared (e.g. server shutdown).
-                        warn!(
-                            task_id = %event.task_execution_id,
-                            "fleet: result rendezvous canceled before agent reported"
-                        );
-                        return Ok(self
-                            .reconcile_error(
-                                &event,
-                                &claimed_task,
-                                &retry_policy,
-                                start.elapsed(),
-                                "fleet result rendezvous canceled".to_string(),
-                            )
-                            .await);
-                    }
-                    Err(_) => {
-                        // Timeout waiting for the agent. Cancel the rendezvous so a
-                        // late report is reported as orphan + dropped.
-                        self.coordinator.cancel(event.task_execution_id);
-                        warn!(
-                            task_id = %event.task_execution_id,
-                            agent_id = %agent_id,
-                            elapsed_s = ?RESULT_WAIT_TIMEOUT.as_secs(),
-                            "fleet: agent result wait exceeded server-side timeout"
-                        );
-                        return Ok(self
-                            .result_handler
-                            .handle_outcome(
-                                &event,
-                                &claimed_task,
-                                Err(ExecutorError::TaskTimeout),
-                                &retry_policy,
-                                start.elapsed(),
-                            )
-                            .await);
-                    }
-                };
+            let result_req: AgentResultRequest = match tokio::time::timeout(result_wait, rx).await {
+                Ok(Ok(req)) => req,
+                Ok(Err(_)) => {
+                    // Sender dropped — coordinator cleared (e.g. server shutdown).
+                    warn!(
+                        task_id = %event.task_execution_id,
+                        "fleet: result rendezvous canceled before agent reported"
+                    );
+                    return Ok(self
+                        .reconcile_error(
+                            &event,
+                            &claimed_task,
+                            &retry_policy,
+                            start.elapsed(),
+                            "fleet result rendezvous canceled".to_string(),
+                        )
+                        .await);
+                }
+                Err(_) => {
+                    // Timeout waiting for the agent. Cancel the rendezvous so a
+                    // late report is reported as orphan + dropped.
+                    self.coordinator.cancel(event.task_execution_id);
+                    warn!(
+                        task_id = %event.task_execution_id,
+                        agent_id = %agent_id,
+                        elapsed_s = ?result_wait.as_secs(),
+                        "fleet: agent result wait exceeded server-side timeout"
+                    );
+                    return Ok(self
+                        .result_handler
+                        .handle_outcome(
+                            &event,
+                            &claimed_task,
+                            Err(ExecutorError::TaskTimeout),
+                            &retry_policy,
+                            start.elapsed(),
+                        )
+                        .await);
+                }
+            };
 
             // ── 8. Map AgentOutcome → Result<Context, ExecutorError>.
             // CLOACI-T-0780: a refusal is an expected fail-closed reschedule, not a
@@ -714,9 +738,24 @@ impl TaskExecutor for FleetExecutor {
                             timestamp: chrono::Utc::now(),
                         })),
                     },
+                    AgentOutcome::Failure {
+                        payload: Some(payload),
+                        ..
+                    } => Err(ExecutorError::TaskExecution(TaskError::structured(
+                        event.task_name.clone(),
+                        payload,
+                    ))),
+                    // A timeout stays a timeout so it is recorded the same way
+                    // a thread-executor timeout is.
+                    AgentOutcome::Failure {
+                        classification: FailureClassification::Timeout,
+                        payload: None,
+                        ..
+                    } => Err(ExecutorError::TaskTimeout),
                     AgentOutcome::Failure {
                         message,
                         classification,
+                        payload: None,
                     } => Err(ExecutorError::TaskExecution(TaskError::ExecutionFailed {
                         message: format!("agent failure ({:?}): {}", classification, message),
                         task_id: event.task_name.clone(),
@@ -812,16 +851,11 @@ impl TaskExecutor for FleetExecutor {
 /// the shared `TaskResultHandler` Success path.
 fn value_to_context(value: serde_json::Value) -> Result<Context<serde_json::Value>, anyhow::Error> {
     use anyhow::{anyhow, bail};
-    let mut ctx = Context::<serde_json::Value>::new();
     match value {
         serde_json::Value::Object(map) => {
-            for (k, v) in map {
-                ctx.insert(k.as_str(), v)
-                    .map_err(|e| anyhow!("insert {}: {}", k, e))?;
-            }
-            Ok(ctx)
+            Context::from_map(map).map_err(|e| anyhow!("decode context: {}", e))
         }
-        serde_json::Value::Null => Ok(ctx),
+        serde_json::Value::Null => Ok(Context::new()),
         other => bail!(
             "AgentOutcome::Success.context must be a JSON object or null, got {}",
             kind_of(&other)
@@ -832,12 +866,7 @@ fn value_to_context(value: serde_json::Value) -> Result<Context<serde_json::Valu
 /// Materialize a `Context<serde_json::Value>` into a JSON object for the work
 /// packet's `context` field (inverse of `value_to_context`).
 fn context_to_json(ctx: &Context<serde_json::Value>) -> serde_json::Value {
-    serde_json::Value::Object(
-        ctx.data()
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect(),
-    )
+    serde_json::Value::Object(ctx.to_map().unwrap_or_default())
 }
 
 fn kind_of(v: &serde_json::Value) -> &'static str {
@@ -256,13 +256,12 @@ impl FleetGraphExecutor {
                     );
                     Ok(GraphResult::completed_with_json(outputs, outputs_json))
                 }
-                AgentOutcome::Failure {
-                    message,
-                    classification: _,
-                } => Ok(GraphResult::error(GraphError::NodeExecution(format!(
-                    "graph firing failed on agent {}: {}",
-                    result.agent_id, message
-                )))),
+                AgentOutcome::Failure { message, .. } => {
+                    Ok(GraphResult::error(GraphError::NodeExecution(format!(
+                        "graph firing failed on agent {}: {}",
+                        result.agent_id, message
+                    ))))
+                }
                 AgentOutcome::Refused { reason, message } => {
                     // The agent did NOT run the graph — safe to fall back.
                     Err(format!(
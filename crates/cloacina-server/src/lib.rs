@@ -24,6 +24,7 @@ pub mod actuator;
 pub mod agent_registry;
 pub mod autoscaler;
 pub mod delivery_sink;
+pub mod federation;
 pub mod fleet_coordinator;
 pub mod fleet_executor;
 pub mod fleet_graph_executor;
@@ -31,9 +32,11 @@ pub mod identity;
 pub mod oidc;
 pub mod openapi;
 pub mod ops_metrics;
+pub mod registry_webhooks;
 pub mod routes;
 pub mod secrets;
 pub mod tenant_runner_cache;
+pub mod tls;
 
 /// CORS configuration (CLOACI-T-0643 / REQ-009). Disabled unless at least
 /// one allowed origin is configured — browser consumers (the TS SDK / UI)
@@ -94,6 +97,7 @@ impl CorsConfig {
             vec![
                 axum::http::header::AUTHORIZATION,
                 axum::http::header::CONTENT_TYPE,
+                axum::http::HeaderName::from_static(crate::routes::auth::TENANT_HEADER),
             ]
         } else {
             self.allowed_headers
@@ -135,6 +139,7 @@ use cloacina::security::SecurityConfig;
 pub struct TenantDatabaseCache {
     databases: tokio::sync::RwLock<std::collections::HashMap<String, Database>>,
     database_url: String,
+    at_rest: Option<Arc<cloacina::crypto::AtRestEncryption>>,
 }
 
 impl TenantDatabaseCache {
@@ -142,9 +147,19 @@ impl TenantDatabaseCache {
         Self {
             databases: tokio::sync::RwLock::new(std::collections::HashMap::new()),
             database_url,
+            at_rest: None,
         }
     }
 
+    /// Attach at-rest encryption to every tenant database this cache opens.
+    pub fn with_at_rest_encryption(
+        mut self,
+        encryption: Option<Arc<cloacina::crypto::AtRestEncryption>>,
+    ) -> Self {
+        self.at_rest = encryption;
+        self
+    }
+
     /// Get or create a schema-scoped Database for the given tenant.
     ///
     /// Returns the admin (public schema) database if tenant_id is "public".
@@ -166,12 +181,15 @@ impl TenantDatabaseCache {
         }
 
         // Slow path: create and cache
-        let db = Database::try_new_with_schema(
+        let mut db = Database::try_new_with_schema(
             &self.database_url,
             "cloacina",
             2, // small pool per tenant
             Some(tenant_id),
         )?;
+        if let Some(encryption) = &self.at_rest {
+            db = db.with_at_rest_encryption(encryption.clone());
+        }
 
         let mut cache = self.databases.write().await;
         // Double-check after acquiring write lock
@@ -262,12 +280,45 @@ pub struct AppState {
     pub oidc_policy: Arc<crate::oidc::MappingPolicy>,
     /// CLOACI-T-0790: short-lived in-flight OIDC login state (state/nonce/PKCE).
     pub oidc_login: Arc<crate::oidc::LoginFlowStore>,
+    /// Verifier for IdP-issued bearer JWTs (`CLOACINA_OIDC_ISSUER` +
+    /// `CLOACINA_OIDC_AUDIENCE`); `None` → only API keys are accepted.
+    pub oidc_bearer: Option<Arc<crate::oidc::OidcBearerVerifier>>,
+    /// Public key audit chain anchors are signed with (`CLOACINA_AUDIT_ANCHOR_KEY`);
+    /// `None` → anchors are unsigned and verification checks hashes only.
+    pub audit_anchor_key: Option<Vec<u8>>,
     /// CLOACI-T-0810: fleet actuator that reconciles each tenant's running agent
     /// count toward its `desired_count`. Selected by `CLOACINA_FLEET_ACTUATOR`
     /// and validated fail-closed at boot by the substrate guard. `NoopActuator`
     /// (kind `"none"`) when actuation is off — in which case the reconcile loop
     /// does not run.
     pub fleet_actuator: Arc<dyn crate::actuator::FleetActuator>,
+    /// Multi-region federation (peers + chain rules), if `--federation-config`
+    /// was given; `None` → the federation routes answer 404.
+    pub federation: Option<Arc<crate::federation::Federation>>,
+    /// Outbound webhooks for package lifecycle events, if
+    /// `--registry-webhook-url` was given.
+    pub registry_webhooks: Option<Arc<crate::registry_webhooks::RegistryWebhooks>>,
+}
+
+impl AppState {
+    /// Registry for `tenant_db`, with registry webhooks (if configured)
+    /// attached as an event listener. Routes that register, roll back or
+    /// remove packages build their registry through this.
+    pub(crate) fn tenant_registry(
+        &self,
+        tenant_id: &str,
+        tenant_db: cloacina::database::Database,
+    ) -> Result<
+        cloacina::registry::WorkflowRegistryImpl<cloacina::dal::UnifiedRegistryStorage>,
+        cloacina::registry::RegistryError,
+    > {
+        let storage = cloacina::dal::UnifiedRegistryStorage::new(tenant_db.clone());
+        let registry = cloacina::registry::WorkflowRegistryImpl::new(storage, tenant_db)?;
+        Ok(match &self.registry_webhooks {
+            Some(webhooks) => registry.with_event_listener(webhooks.listener(tenant_id)),
+            None => registry,
+        })
+    }
 }
 
 /// CLOACI-T-0580: build the base `DefaultRunnerConfig` used by every
@@ -276,17 +327,43 @@ pub struct AppState {
 /// honors the operator override).
 fn runner_config_for_tenant_cache(
     reconcile_interval: Option<std::time::Duration>,
+    context_retention: Option<std::time::Duration>,
+    registry_gc: Option<cloacina::registry::RegistryGcPolicy>,
+    registry_gc_dry_run: bool,
+    trace_context_access: bool,
+    track_context_lineage: bool,
     default_executor: &str,
+    security: &SecurityConfig,
+    signature_trust_store: Option<cloacina::Database>,
 ) -> cloacina::DefaultRunnerConfig {
     let mut builder = cloacina::DefaultRunnerConfig::builder();
     builder = builder.registry_storage_backend("database");
     if let Some(interval) = reconcile_interval {
         builder = builder.registry_reconcile_interval(interval);
     }
+    // Each tenant's executions live in the tenant schema, so the retention
+    // sweeper has to run on the per-tenant runners to reach them.
+    builder = builder.context_retention(context_retention);
+    // Same for registry GC: each tenant's packages live in its own schema.
+    builder = builder
+        .registry_gc(registry_gc)
+        .registry_gc_dry_run(registry_gc_dry_run);
+    builder = builder
+        .trace_context_access(trace_context_access)
+        .track_context_lineage(track_context_lineage);
     // CLOACI-T-0640: carry the configured default executor onto per-tenant
     // runners so their dispatcher sends tenant tasks to the same executor (and
     // a dispatcher exists at all, which `register_executor` requires).
     builder = builder.default_executor(default_executor);
+    // Tenant reconcilers enforce the same signature policy as the upload
+    // route. Signatures and trust lists live in the admin database, which a
+    // tenant runner's schema-scoped connection cannot see, so verification
+    // runs against `signature_trust_store`.
+    builder = builder
+        .require_signatures(security.require_signatures)
+        .verification_org_id(security.verification_org_id)
+        .tenant_trust_orgs(security.tenant_trust_orgs.clone())
+        .signature_trust_store(signature_trust_store);
     builder
         .build()
         .expect("default tenant runner config builds cleanly")
@@ -296,16 +373,19 @@ fn runner_config_for_tenant_cache(
 ///
 /// Extracted from `run()` so it's unit-testable without spinning up the
 /// full server. Currently enforces: `--require-signatures` is only meaningful
-/// paired with `--verification-org-id`; reject the combo at boot rather than
-/// surface a 403 on first upload (CLOACI-I-0103 / T-0567).
+/// paired with `--verification-org-id` or at least one `--tenant-trust-org`;
+/// reject the combo at boot rather than surface a 403 on first upload
+/// (CLOACI-I-0103 / T-0567).
 fn validate_security_args(
     require_signatures: bool,
     verification_org_id: Option<&uuid::Uuid>,
+    tenant_trust_orgs: &std::collections::HashMap<String, cloacina::UniversalUuid>,
 ) -> Result<()> {
-    if require_signatures && verification_org_id.is_none() {
+    if require_signatures && verification_org_id.is_none() && tenant_trust_orgs.is_empty() {
         anyhow::bail!(
             "--require-signatures requires --verification-org-id <UUID> \
-             (or set CLOACINA_VERIFICATION_ORG_ID env var). Without a trusted \
+             (or set CLOACINA_VERIFICATION_ORG_ID env var), or per-tenant \
+             --tenant-trust-org TENANT=UUID entries. Without a trusted \
              org_id the server has no way to verify uploaded signatures."
         );
     }
@@ -326,10 +406,38 @@ pub async fn run(
     bootstrap_key: Option<String>,
     require_signatures: bool,
     verification_org_id: Option<uuid::Uuid>,
+    // Per-tenant signature trust lists (tenant → org whose trusted keys its
+    // packages must be signed by); tenants not listed use
+    // `verification_org_id`.
+    tenant_trust_orgs: std::collections::HashMap<String, cloacina::UniversalUuid>,
     reconcile_interval: Option<std::time::Duration>,
     tenant_runner_cache_size: usize,
     tenant_deletion_drain_timeout: std::time::Duration,
     log_retention_days: u64,
+    // How long finished executions keep their contexts before the retention
+    // sweeper purges them; `None` keeps them forever. Metadata is never purged.
+    context_retention: Option<std::time::Duration>,
+    // Which superseded package versions registry GC keeps; `None` keeps them
+    // all. With `registry_gc_dry_run` it only logs what it would delete.
+    registry_gc: Option<cloacina::registry::RegistryGcPolicy>,
+    registry_gc_dry_run: bool,
+    // Record the context keys each task reads and writes, for the
+    // context-access report.
+    trace_context_access: bool,
+    // Record which task changed each context key, for the context-lineage
+    // report.
+    track_context_lineage: bool,
+    // Blue/green handoff. With `takeover` this server asks every live server
+    // on another version to drain at startup; any server that is asked to
+    // drain waits up to `handoff_drain_timeout` for in-flight tasks and exits.
+    takeover: bool,
+    handoff_drain_timeout: std::time::Duration,
+    // Multi-region federation config file (peers, chain rules). `None` runs
+    // the server standalone.
+    federation_config: Option<std::path::PathBuf>,
+    // Where package lifecycle events (registered/updated/rolled back/removed)
+    // are POSTed. No URLs disables webhooks.
+    registry_webhooks: crate::registry_webhooks::RegistryWebhookConfig,
     // Executor every task is dispatched to (CLOACI-T-0640). `"default"` runs all
     // work on the in-process thread executor; `"fleet"` sends it to the
     // execution-agent fleet. Validated against the registered executor keys at
@@ -345,11 +453,42 @@ pub async fn run(
     // CORS opt-in (CLOACI-T-0643 / REQ-009). Disabled unless at least one
     // allowed origin is configured.
     cors: CorsConfig,
+    // Serve HTTPS (optionally with client-certificate auth) instead of plain
+    // HTTP. `None` serves plain HTTP.
+    tls: Option<crate::tls::TlsConfig>,
 ) -> Result<()> {
     // Validate CORS config before any heavy startup work.
     let cors_layer = cors.layer()?;
+    // Unreadable or mismatched certificates also fail before startup work.
+    let tls_server_config = tls
+        .as_ref()
+        .map(|tls| tls.server_config())
+        .transpose()
+        .context("invalid TLS configuration")?;
+    secrets::init_cloud_credentials().context("invalid cloud credentials configuration")?;
     // Fail fast at boot rather than 403 at first upload (CLOACI-I-0103 / T-0567).
-    validate_security_args(require_signatures, verification_org_id.as_ref())?;
+    validate_security_args(
+        require_signatures,
+        verification_org_id.as_ref(),
+        &tenant_trust_orgs,
+    )?;
+    let security_config = SecurityConfig {
+        require_signatures,
+        verification_org_id: verification_org_id.map(cloacina::UniversalUuid::from),
+        tenant_trust_orgs,
+        ..SecurityConfig::default()
+    };
+    // Bad peer lists and unresolvable peer keys also fail at boot.
+    let federation = federation_config
+        .as_deref()
+        .map(|path| {
+            crate::federation::FederationConfig::load(path)
+                .and_then(crate::federation::Federation::from_config)
+        })
+        .transpose()?
+        .map(Arc::new);
+    let registry_webhooks =
+        crate::registry_webhooks::RegistryWebhooks::from_config(registry_webhooks)?.map(Arc::new);
 
     // CLOACI-T-0582: enable strict search_path checking on the server.
     // Adds a `current_schema()` round-trip on every tenant-scoped
@@ -371,10 +510,13 @@ pub async fn run(
     std::fs::create_dir_all(&logs_dir)
         .with_context(|| format!("Failed to create logs dir: {}", logs_dir.display()))?;
 
-    let filter = if verbose {
-        EnvFilter::new("debug")
-    } else {
-        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"))
+    // Built per layer: the audit chain layer below must not be subject to it.
+    let make_filter = || {
+        if verbose {
+            EnvFilter::new("debug")
+        } else {
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"))
+        }
     };
 
     // Daily-rotated file appender with optional retention via
@@ -396,11 +538,23 @@ pub async fn run(
     })?;
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
 
-    // Build the base subscriber with stderr + file layers
+    // Build the base subscriber with stderr + file layers. Audit events are
+    // also captured, whatever the log level, for the hash-chained audit log;
+    // the writer that appends them starts once the database is up.
+    let (audit_chain_layer, audit_chain_events) =
+        cloacina::security::audit_chain::audit_chain_layer();
     let subscriber = tracing_subscriber::registry()
-        .with(filter)
-        .with(fmt::layer().with_writer(std::io::stderr))
-        .with(fmt::layer().json().with_writer(non_blocking));
+        .with(
+            fmt::layer()
+                .with_writer(std::io::stderr)
+                .and_then(fmt::layer().json().with_writer(non_blocking))
+                .with_filter(make_filter()),
+        )
+        .with(
+            audit_chain_layer.with_filter(tracing_subscriber::filter::filter_fn(
+                cloacina::security::audit_chain::is_audit_event,
+            )),
+        );
 
     // Conditionally add OpenTelemetry tracing layer
     #[cfg(feature = "telemetry")]
@@ -426,7 +580,9 @@ pub async fn run(
                 .build();
 
             let tracer = provider.tracer("cloacina");
-            let otel_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+            let otel_layer = tracing_opentelemetry::layer()
+                .with_tracer(tracer)
+                .with_filter(make_filter());
 
             subscriber.with(otel_layer).init();
             // Provider is kept alive by the global registry
@@ -659,6 +815,29 @@ pub async fn run(
          (Context API rejected an insert/update — counted but does not \
          fail the task). Closes COR-11."
     );
+    metrics::describe_counter!(
+        "cloacina_execution_contexts_purged_total",
+        "Finished workflow executions whose contexts were purged by the \
+         context retention sweeper (`--context-retention-hours`). Execution \
+         metadata stays; only the input and per-task output payloads go."
+    );
+    metrics::describe_counter!(
+        "cloacina_registry_versions_collected_total",
+        "Superseded package versions deleted by registry garbage collection \
+         (`--registry-gc-keep-last`), with their archives and per-target \
+         artifacts."
+    );
+    metrics::describe_counter!(
+        "cloacina_federation_chain_forwards_total",
+        "Cross-region chain forwards by the federation chain loop. `outcome` \
+         is `forwarded` (chained workflow started in the peer region) or \
+         `failed` (retried on the next pass)."
+    );
+    metrics::describe_counter!(
+        "cloacina_registry_webhooks_total",
+        "Package lifecycle webhook deliveries (`--registry-webhook-url`). \
+         `outcome` is `delivered` (2xx) or `dropped` (failed every attempt)."
+    );
 
     // Connect to Postgres with DB-backed registry (so uploaded packages get compiled + loaded)
     let mut runner_builder = DefaultRunnerConfig::builder();
@@ -666,13 +845,26 @@ pub async fn run(
     if let Some(interval) = reconcile_interval {
         runner_builder = runner_builder.registry_reconcile_interval(interval);
     }
+    runner_builder = runner_builder.context_retention(context_retention);
+    runner_builder = runner_builder
+        .registry_gc(registry_gc.clone())
+        .registry_gc_dry_run(registry_gc_dry_run);
+    runner_builder = runner_builder
+        .trace_context_access(trace_context_access)
+        .track_context_lineage(track_context_lineage);
+    // The admin runner stands for the whole server process in the handoff
+    // registry; per-tenant runners stop with it.
+    runner_builder = runner_builder
+        .enable_handoff(true)
+        .handoff_takeover(takeover);
     // CLOACI-T-0571: forward the verification config into the runner so the
     // reconciler's defense-in-depth signature-existence check fires even
     // when packages reach `workflow_packages` via paths other than the
     // upload route.
     runner_builder = runner_builder
         .require_signatures(require_signatures)
-        .verification_org_id(verification_org_id.map(cloacina::UniversalUuid::from));
+        .verification_org_id(verification_org_id.map(cloacina::UniversalUuid::from))
+        .tenant_trust_orgs(security_config.tenant_trust_orgs.clone());
     // Default executor (CLOACI-T-0640): every task dispatches to this one
     // executor key. `"default"` keeps all work on the in-process thread
     // executor; `"fleet"` sends it to the execution-agent fleet. Applied to BOTH
@@ -694,8 +886,19 @@ pub async fn run(
     // can then resolve `{"$secret": …}` bindings via `context.secret(...)`.
     // Without `CLOACINA_SECRET_KEK` the resolver is None and secret resolution
     // fails closed with a clear error.
-    let admin_database =
+    //
+    // With `CLOACINA_AT_REST_KEYS` set, contexts and package archives are
+    // envelope-encrypted on every database the server opens.
+    let at_rest =
+        cloacina::crypto::AtRestEncryption::from_env().context("invalid CLOACINA_AT_REST_KEYS")?;
+    if let Some(encryption) = &at_rest {
+        info!(key_id = %encryption.current_key_id(), "At-rest encryption enabled");
+    }
+    let mut admin_database =
         cloacina::Database::new(&database_url, "cloacina", runner_config.db_pool_size());
+    if let Some(encryption) = &at_rest {
+        admin_database = admin_database.with_at_rest_encryption(encryption.clone());
+    }
     admin_database
         .run_migrations()
         .await
@@ -712,6 +915,39 @@ pub async fn run(
 
     info!("Connected to Postgres, migrations applied");
 
+    // Tamper-evident audit chain: append captured audit events and
+    // periodically anchor the head, signed when an anchor key is configured.
+    let audit_anchor_signer = cloacina::security::audit_chain::AnchorSigner::from_env()
+        .context("invalid CLOACINA_AUDIT_ANCHOR_KEY")?;
+    let audit_anchor_key = audit_anchor_signer.as_ref().map(|s| {
+        info!(public_key = %s.public_key_hex(), "Audit chain anchors will be signed");
+        s.public_key_bytes()
+    });
+    cloacina::security::audit_chain::spawn_audit_chain_writer(
+        runner.database().clone(),
+        audit_chain_events,
+    );
+    let audit_anchor_interval = std::env::var("CLOACINA_AUDIT_ANCHOR_INTERVAL_S")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(3600);
+    if audit_anchor_interval > 0 {
+        let dal = cloacina::dal::unified::DAL::new(runner.database().clone());
+        tokio::spawn(async move {
+            let mut ticker =
+                tokio::time::interval(std::time::Duration::from_secs(audit_anchor_interval));
+            loop {
+                ticker.tick().await;
+                if let Err(e) =
+                    cloacina::security::audit_chain::anchor_head(&dal, audit_anchor_signer.as_ref())
+                        .await
+                {
+                    warn!("Failed to anchor the audit chain: {}", e);
+                }
+            }
+        });
+    }
+
     let endpoint_registry = EndpointRegistry::new();
     let unified_dal = cloacina::dal::unified::DAL::new(runner.database().clone());
     let graph_scheduler = Arc::new(ComputationGraphScheduler::with_dal(
@@ -759,7 +995,9 @@ pub async fn run(
     // the fleet secret resolver factory share ONE instance — they must resolve
     // the same tenant schemas so a secret written over HTTP is the one a fleet
     // task resolves.
-    let tenant_database_cache = Arc::new(TenantDatabaseCache::new(database_url.clone()));
+    let tenant_database_cache = Arc::new(
+        TenantDatabaseCache::new(database_url.clone()).with_at_rest_encryption(at_rest.clone()),
+    );
 
     // CLOACI-T-0862 / T-0861 handoff: the concrete fleet secret resolver factory.
     // Activates the `FleetExecutor` secret seam so a `$secret`-referencing fleet
@@ -781,7 +1019,17 @@ pub async fn run(
         let cache = crate::tenant_runner_cache::TenantRunnerCache::new(
             std::num::NonZeroUsize::new(tenant_runner_cache_size.max(1))
                 .expect("max(1) is non-zero"),
-            runner_config_for_tenant_cache(reconcile_interval, &default_executor),
+            runner_config_for_tenant_cache(
+                reconcile_interval,
+                context_retention,
+                registry_gc,
+                registry_gc_dry_run,
+                trace_context_access,
+                track_context_lineage,
+                &default_executor,
+                &security_config,
+                Some(runner.database().clone()),
+            ),
         )
         // CLOACI-T-0581 follow-up: per-tenant runners share the global graph
         // scheduler so their reconcilers can route packaged CGs. The scheduler
@@ -847,6 +1095,18 @@ pub async fn run(
         },
         None => None,
     };
+    // Bearer JWTs from the same issuer. Unlike login, a discovery failure is
+    // fatal: clients configured to send tokens would otherwise all get 401s.
+    let oidc_bearer = match crate::oidc::OidcBearerConfig::from_env() {
+        Some(cfg) => {
+            let verifier = crate::oidc::OidcBearerVerifier::discover(cfg)
+                .await
+                .map_err(|e| anyhow::anyhow!("OIDC bearer token validation: {e}"))?;
+            info!(issuer = %verifier.issuer(), "OIDC bearer token validation enabled");
+            Some(verifier)
+        }
+        None => None,
+    };
     let oidc_policy = Arc::new(crate::oidc::MappingPolicy::from_env());
     // CLOACI-T-0801: Postgres-backed login-flow state (multi-replica safe).
     let oidc_login = Arc::new(crate::oidc::LoginFlowStore::with_db(
@@ -906,11 +1166,7 @@ pub async fn run(
         delivery_wake: delivery_wake.clone(),
         agent_registry: agent_registry.clone(),
         fleet_coordinator: fleet_coordinator.clone(),
-        security_config: SecurityConfig {
-            require_signatures,
-            verification_org_id: verification_org_id.map(cloacina::UniversalUuid::from),
-            ..SecurityConfig::default()
-        },
+        security_config,
         ws_tickets: Arc::new(crate::routes::auth::WsTicketStore::new(
             std::time::Duration::from_secs(60),
         )),
@@ -929,7 +1185,11 @@ pub async fn run(
         oidc: oidc_provider,
         oidc_policy,
         oidc_login,
+        oidc_bearer,
+        audit_anchor_key,
         fleet_actuator: fleet_actuator.clone(),
+        federation: federation.clone(),
+        registry_webhooks,
     };
 
     // Bootstrap: create initial admin key if none exist
@@ -946,6 +1206,22 @@ pub async fn run(
     // is subscribed.
     crate::ops_metrics::spawn(state.clone(), substrate_shutdown_rx.clone());
 
+    // Cross-region chaining: forward completed workflows to peer regions per
+    // the federation chain rules. Leader-gated like the fleet control loop.
+    if let Some(federation) = federation.as_ref() {
+        info!(
+            region = %federation.region(),
+            "Multi-region federation enabled"
+        );
+        if !federation.chains().is_empty() {
+            tokio::spawn(federation.clone().run_chain_loop(
+                state.database.clone(),
+                state.tenant_databases.clone(),
+                substrate_shutdown_rx.clone(),
+            ));
+        }
+    }
+
     // Fleet executor registration (CLOACI-I-0114 / T-0633, T-0640). Only wired
     // up when the operator opts into the fleet via `--default-executor fleet`;
     // otherwise all work stays on the in-process thread executor and there's no
@@ -1341,8 +1617,20 @@ pub async fn run(
         .await
         .with_context(|| format!("Failed to bind to {}", bind))?;
 
+    let scheme = if tls_server_config.is_some() {
+        "https"
+    } else {
+        "http"
+    };
     info!("");
-    info!("API server is running on http://{}", bind);
+    info!("API server is running on {}://{}", scheme, bind);
+    if let Some(tls) = &tls {
+        info!(
+            sni_certificates = tls.sni_certs.len(),
+            mutual_tls = tls.mutual(),
+            "TLS enabled"
+        );
+    }
     info!("  GET  /health     — liveness check");
     info!("  GET  /ready      — readiness check");
     info!("  GET  /metrics    — Prometheus metrics");
@@ -1369,29 +1657,56 @@ pub async fn run(
         })
     };
 
-    axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
-    )
-    .with_graceful_shutdown(async move {
-        shutdown_signal().await;
+    let graceful_shutdown = async move {
+        let handoff = tokio::select! {
+            _ = shutdown_signal() => false,
+            _ = runner_for_shutdown.handoff_requested() => {
+                info!("Successor server took over — draining");
+                true
+            }
+        };
         // Signal the graph scheduler to shut down first
         let _ = shutdown_tx.send(true);
         // Wait for graph scheduler to finish flushing/persisting
         let _ = scheduler_handle.await;
         // Shut down the workflow runner (scheduler loop, executor, stale claim sweeper)
         info!("Shutting down workflow runner...");
-        match tokio::time::timeout(
-            std::time::Duration::from_secs(30),
-            runner_for_shutdown.shutdown(),
-        )
-        .await
-        {
-            Ok(Ok(())) => info!("Workflow runner shutdown complete"),
-            Ok(Err(e)) => warn!("Workflow runner shutdown error: {}", e),
-            Err(_) => warn!("Workflow runner shutdown timed out after 30s"),
+        if handoff {
+            match runner_for_shutdown.drain(handoff_drain_timeout).await {
+                Ok(true) => info!("Workflow runner drained"),
+                Ok(false) => warn!(
+                    "Workflow runner drain timed out after {}s; the successor will reclaim the rest",
+                    handoff_drain_timeout.as_secs()
+                ),
+                Err(e) => warn!("Workflow runner drain error: {}", e),
+            }
+        } else {
+            match tokio::time::timeout(
+                std::time::Duration::from_secs(30),
+                runner_for_shutdown.shutdown(),
+            )
+            .await
+            {
+                Ok(Ok(())) => info!("Workflow runner shutdown complete"),
+                Ok(Err(e)) => warn!("Workflow runner shutdown error: {}", e),
+                Err(_) => warn!("Workflow runner shutdown timed out after 30s"),
+            }
         }
         // CLOACI-T-0580: shut down every cached per-tenant runner.
+        if handoff {
+            info!("Draining tenant runner cache...");
+            let results = tenant_runners_for_shutdown
+                .drain_all(handoff_drain_timeout)
+                .await;
+            for (tenant, result) in results {
+                match result {
+                    Ok(true) => {}
+                    Ok(false) => warn!(tenant_id = %tenant, "tenant runner drain timed out"),
+                    Err(e) => warn!(tenant_id = %tenant, error = %e, "tenant runner drain failed"),
+                }
+            }
+            return;
+        }
         info!("Shutting down tenant runner cache...");
         let results = tenant_runners_for_shutdown.shutdown_all().await;
         let total = results.len();
@@ -1412,8 +1727,24 @@ pub async fn run(
                 }
             }
         }
-    })
-    .await
+    };
+
+    let make_service = app.into_make_service_with_connect_info::<std::net::SocketAddr>();
+    match tls_server_config {
+        Some(config) => {
+            let listener = crate::tls::TlsListener::new(listener, config)
+                .context("Failed to start TLS listener")?
+                .with_connect_info();
+            axum::serve(listener, make_service)
+                .with_graceful_shutdown(graceful_shutdown)
+                .await
+        }
+        None => {
+            axum::serve(listener, make_service)
+                .with_graceful_shutdown(graceful_shutdown)
+                .await
+        }
+    }
     .context("Server error")?;
 
     info!("API server shutdown complete");
@@ -1579,6 +1910,14 @@ fn build_router(state: AppState) -> Router {
     // Authenticated routes — behind auth middleware
     let auth_routes = Router::new()
         // Key management
+        .route(
+            "/audit/entries",
+            get(crate::routes::audit::list_audit_entries),
+        )
+        .route(
+            "/audit/verify",
+            get(crate::routes::audit::verify_audit_chain),
+        )
         .route("/auth/keys", post(crate::routes::keys::create_key))
         .route("/auth/keys", get(crate::routes::keys::list_keys))
         .route(
@@ -1597,11 +1936,21 @@ fn build_router(state: AppState) -> Router {
         .route("/auth/whoami", get(crate::routes::session::whoami))
         // Execution-agent fleet roster (admin, operator-facing) — CLOACI-I-0124
         .route("/agents", get(crate::routes::agent::list_agents))
+        // Multi-region federation roster (peers + reachability)
+        .route(
+            "/federation/regions",
+            get(crate::routes::federation::list_regions),
+        )
         // Compiler / build-pipeline status (admin) — CLOACI-I-0124
         .route(
             "/compiler/status",
             get(crate::routes::compiler::compiler_status),
         )
+        // Platform maintenance (admin) — remote counterparts of `cloacinactl admin`
+        .route(
+            "/maintenance/cleanup-events",
+            post(crate::routes::maintenance::cleanup_events),
+        )
         // Tenant management
         .route("/tenants", post(crate::routes::tenants::create_tenant))
         .route("/tenants", get(crate::routes::tenants::list_tenants))
@@ -1705,6 +2054,14 @@ fn build_router(state: AppState) -> Router {
             "/tenants/{tenant_id}/workflows/{name}/source",
             get(crate::routes::workflows::get_workflow_source),
         )
+        .route(
+            "/tenants/{tenant_id}/workflows/{name}/archive",
+            get(crate::routes::workflows::get_workflow_archive),
+        )
+        .route(
+            "/tenants/{tenant_id}/workflows/{name}/schema",
+            get(crate::routes::workflows::get_workflow_schema),
+        )
         // Pause / resume a workflow (block new executions) — CLOACI-T-0749.
         .route(
             "/tenants/{tenant_id}/workflows/{name}/pause",
@@ -1714,14 +2071,32 @@ fn build_router(state: AppState) -> Router {
             "/tenants/{tenant_id}/workflows/{name}/resume",
             post(crate::routes::workflows::resume_workflow),
         )
+        .route(
+            "/tenants/{tenant_id}/workflows/{name}/rollback",
+            post(crate::routes::workflows::rollback_workflow),
+        )
+        .route(
+            "/tenants/{tenant_id}/workflows/{name}/versions",
+            get(crate::routes::workflows::list_workflow_versions),
+        )
+        .route(
+            "/tenants/{tenant_id}/workflows/{name}/deprecation",
+            put(crate::routes::workflows::deprecate_workflow)
+                .delete(crate::routes::workflows::undeprecate_workflow),
+        )
+        .route(
+            "/tenants/{tenant_id}/workflows/{name}/consumers",
+            get(crate::routes::workflows::get_workflow_consumers),
+        )
         .route(
             "/tenants/{tenant_id}/workflows/{name}/{version}",
             delete(crate::routes::workflows::delete_workflow),
         )
-        // Trigger schedules (tenant-scoped, read-only)
+        // Trigger schedules (tenant-scoped)
         .route(
             "/tenants/{tenant_id}/triggers",
-            get(crate::routes::triggers::list_triggers),
+            get(crate::routes::triggers::list_triggers)
+                .post(crate::routes::triggers::create_trigger),
         )
         .route(
             "/tenants/{tenant_id}/triggers/{name}",
@@ -1736,6 +2111,26 @@ fn build_router(state: AppState) -> Router {
             "/tenants/{tenant_id}/triggers/{name}/resume",
             post(crate::routes::triggers::resume_trigger),
         )
+        .route(
+            "/tenants/{tenant_id}/triggers/{name}/dedup",
+            put(crate::routes::triggers::set_trigger_dedup),
+        )
+        .route(
+            "/tenants/{tenant_id}/triggers/{name}/catchup",
+            put(crate::routes::triggers::set_trigger_catchup),
+        )
+        .route(
+            "/tenants/{tenant_id}/triggers/{name}/throttle",
+            put(crate::routes::triggers::set_trigger_throttle),
+        )
+        .route(
+            "/tenants/{tenant_id}/triggers/{name}/dst",
+            put(crate::routes::triggers::set_trigger_dst_policy),
+        )
+        .route(
+            "/tenants/{tenant_id}/triggers/{name}/condition",
+            put(crate::routes::triggers::set_trigger_condition),
+        )
         .route(
             "/tenants/{tenant_id}/triggers/{name}/fire",
             post(crate::routes::triggers::fire_trigger),
@@ -1753,6 +2148,10 @@ fn build_router(state: AppState) -> Router {
             "/tenants/{tenant_id}/executions",
             get(crate::routes::executions::list_executions),
         )
+        .route(
+            "/tenants/{tenant_id}/federation/executions",
+            get(crate::routes::federation::list_federated_executions),
+        )
         .route(
             "/tenants/{tenant_id}/executions/{exec_id}",
             get(crate::routes::executions::get_execution),
@@ -1765,6 +2164,46 @@ fn build_router(state: AppState) -> Router {
             "/tenants/{tenant_id}/executions/{exec_id}/tasks",
             get(crate::routes::executions::get_execution_tasks),
         )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/recording",
+            get(crate::routes::executions::get_execution_recording),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/tree",
+            get(crate::routes::executions::get_execution_tree),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/annotations",
+            get(crate::routes::executions::list_execution_annotations),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/annotations",
+            post(crate::routes::executions::annotate_execution),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/tasks/{task}/resolve",
+            post(crate::routes::executions::resolve_task),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/context-access",
+            get(crate::routes::executions::get_context_access),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/context-lineage",
+            get(crate::routes::executions::get_context_lineage),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/artifacts",
+            get(crate::routes::executions::list_artifacts),
+        )
+        .route(
+            "/tenants/{tenant_id}/executions/{exec_id}/artifacts/{name}",
+            get(crate::routes::executions::download_artifact),
+        )
+        .route(
+            "/tenants/{tenant_id}/stats/queue",
+            get(crate::routes::executions::get_queue_stats),
+        )
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             crate::routes::authz::authz_mw,
@@ -2303,7 +2742,17 @@ mod tests {
             tenant_databases: Arc::new(TenantDatabaseCache::new(TEST_DB_URL.to_string())),
             tenant_runners: Arc::new(crate::tenant_runner_cache::TenantRunnerCache::new(
                 std::num::NonZeroUsize::new(8).expect("test cap"),
-                runner_config_for_tenant_cache(None, "default"),
+                runner_config_for_tenant_cache(
+                    None,
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    "default",
+                    &SecurityConfig::default(),
+                    None,
+                ),
             )),
             tenant_deletion_drain_timeout: std::time::Duration::from_secs(5),
             agent_heartbeat_interval_seconds: cloacina::fleet::DEFAULT_HEARTBEAT_INTERVAL_SECONDS,
@@ -2315,9 +2764,13 @@ mod tests {
             oidc_login: Arc::new(crate::oidc::LoginFlowStore::new(
                 std::time::Duration::from_secs(600),
             )),
+            oidc_bearer: None,
+            audit_anchor_key: None,
             // CLOACI-T-0810: tests don't actuate — a Noop actuator keeps handlers
             // that read `fleet_actuator` constructible without a Docker daemon.
             fleet_actuator: Arc::new(crate::actuator::NoopActuator),
+            federation: None,
+            registry_webhooks: None,
         }
     }
 
@@ -4238,7 +4691,17 @@ mod tests {
         // Override the cache with a small cap for this test.
         state.tenant_runners = Arc::new(crate::tenant_runner_cache::TenantRunnerCache::new(
             std::num::NonZeroUsize::new(2).expect("cap=2"),
-            runner_config_for_tenant_cache(None, "default"),
+            runner_config_for_tenant_cache(
+                None,
+                None,
+                None,
+                false,
+                false,
+                false,
+                "default",
+                &SecurityConfig::default(),
+                None,
+            ),
         ));
 
         let schema_a = format!(
@@ -4789,6 +5252,76 @@ mod tests {
         assert!(body["items"].as_array().is_some());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_federation_routes_404_when_not_configured() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        for uri in [
+            "/v1/federation/regions",
+            "/v1/tenants/public/federation/executions",
+        ] {
+            let req = axum::http::Request::builder()
+                .uri(uri)
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap();
+            let (status, body) = send_request(app.clone(), req).await;
+            assert_eq!(status, StatusCode::NOT_FOUND, "{uri}");
+            assert_eq!(body["code"], "federation_not_configured");
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_federated_executions_report_unreachable_peer() {
+        let mut state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let federation =
+            crate::federation::Federation::from_config(crate::federation::FederationConfig {
+                region: "us-east".to_string(),
+                peers: vec![crate::federation::PeerConfig {
+                    region: "eu-west".to_string(),
+                    // Nothing listens on port 1.
+                    url: "http://127.0.0.1:1".to_string(),
+                    api_key: "peer-key".to_string(),
+                }],
+                chains: Vec::new(),
+                chain_interval_s: crate::federation::DEFAULT_CHAIN_INTERVAL_S,
+            })
+            .unwrap();
+        state.federation = Some(Arc::new(federation));
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .uri("/v1/tenants/public/federation/executions")
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send_request(app.clone(), req).await;
+        assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+        assert!(body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|item| item["region"] == "us-east"));
+        assert_eq!(body["unreachable"][0]["region"], "eu-west");
+
+        let req = axum::http::Request::builder()
+            .uri("/v1/federation/regions")
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["region"], "us-east");
+        assert_eq!(body["regions"][0]["local"], true);
+        assert_eq!(body["regions"][1]["region"], "eu-west");
+        assert_eq!(body["regions"][1]["reachable"], false);
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_get_execution_invalid_uuid_returns_400() {
@@ -4841,6 +5374,86 @@ mod tests {
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_get_context_access_invalid_uuid_returns_400() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .uri("/v1/tenants/public/executions/not-a-uuid/context-access")
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_context_access_nonexistent_returns_404() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .uri(format!(
+                "/v1/tenants/public/executions/{}/context-access",
+                uuid::Uuid::new_v4()
+            ))
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let (status, body) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "execution_not_found");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_context_lineage_nonexistent_returns_404() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .uri(format!(
+                "/v1/tenants/public/executions/{}/context-lineage",
+                uuid::Uuid::new_v4()
+            ))
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let (status, body) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "execution_not_found");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_list_artifacts_nonexistent_returns_404() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .uri(format!(
+                "/v1/tenants/public/executions/{}/artifacts",
+                uuid::Uuid::new_v4()
+            ))
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let (status, body) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "execution_not_found");
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_execute_nonexistent_workflow_returns_error() {
@@ -4919,6 +5532,164 @@ mod tests {
         assert_eq!(status, StatusCode::NOT_FOUND);
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_dedup_rejects_negative_window() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_trigger/dedup")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{"window_secs": -1}"#))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_dedup_nonexistent_returns_404() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_trigger/dedup")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{"window_secs": 86400, "keys": ["path"]}"#))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_throttle_rejects_negative_interval() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_trigger/throttle")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{"debounce_window_ms": -1}"#))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_throttle_nonexistent_returns_404() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_trigger/throttle")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(
+                r#"{"min_interval_between_fires_ms": 60000, "debounce_window_ms": 5000}"#,
+            ))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_dst_policy_rejects_unknown_policy() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_workflow/dst")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{"policy": "sometimes"}"#))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_condition_rejects_invalid_expression() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_trigger/condition")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{"condition": "probes.flag.exists &&"}"#))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_trigger_condition_nonexistent_returns_404() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("PUT")
+            .uri("/v1/tenants/public/triggers/nonexistent_trigger/condition")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(
+                r#"{"condition": "probes.flag.exists", "probes": [{"name": "flag", "kind": "file", "path": "/tmp/ready"}]}"#,
+            ))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_create_trigger_rejects_zero_poll_interval() {
+        let state = test_state().await;
+        let token = create_test_api_key(&state).await;
+        let app = build_router(state);
+
+        let req = axum::http::Request::builder()
+            .method("POST")
+            .uri("/v1/tenants/public/triggers")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(
+                r#"{"name": "ready", "workflow_name": "wf", "poll_interval_ms": 0, "condition": "true"}"#,
+            ))
+            .unwrap();
+
+        let (status, _) = send_request(app, req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
     // ── Fallback / 404 ──────────────────────────────────────────────
 
     #[tokio::test]
@@ -5082,7 +5853,7 @@ mod tests {
     #[test]
     fn validate_security_args_default_passes() {
         // Default config: no signature requirement, no org_id. Most common deployment.
-        assert!(validate_security_args(false, None).is_ok());
+        assert!(validate_security_args(false, None, &std::collections::HashMap::new()).is_ok());
     }
 
     #[test]
@@ -5090,20 +5861,24 @@ mod tests {
         // Configuring an org_id without enabling require_signatures is allowed
         // (operator may want it pre-staged before flipping the flag).
         let uuid = uuid::Uuid::new_v4();
-        assert!(validate_security_args(false, Some(&uuid)).is_ok());
+        assert!(
+            validate_security_args(false, Some(&uuid), &std::collections::HashMap::new()).is_ok()
+        );
     }
 
     #[test]
     fn validate_security_args_require_with_org_passes() {
         // The fully-configured opt-in posture.
         let uuid = uuid::Uuid::new_v4();
-        assert!(validate_security_args(true, Some(&uuid)).is_ok());
+        assert!(
+            validate_security_args(true, Some(&uuid), &std::collections::HashMap::new()).is_ok()
+        );
     }
 
     #[test]
     fn validate_security_args_require_without_org_fails() {
         // The bad combo we want to catch at boot, not at first upload.
-        let result = validate_security_args(true, None);
+        let result = validate_security_args(true, None, &std::collections::HashMap::new());
         let err = result.expect_err("expected validation failure");
         let msg = err.to_string();
         assert!(
@@ -5118,6 +5893,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_security_args_require_with_tenant_trust_orgs_passes() {
+        // Per-tenant trust lists alone are enough; tenants without one are
+        // rejected at upload with `signature_verification_unconfigured`.
+        let orgs = std::collections::HashMap::from([(
+            "acme".to_string(),
+            cloacina::UniversalUuid::new_v4(),
+        )]);
+        assert!(validate_security_args(true, None, &orgs).is_ok());
+    }
+
     /// CLOACI-T-0811: leader election via a Postgres advisory lock.
     ///
     /// Placed here (a `#[serial]` server lib test over `test_state()`'s real
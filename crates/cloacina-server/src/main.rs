@@ -21,6 +21,7 @@ use anyhow::Result;
 use clap::Parser;
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// cloacina-server — HTTP API for Cloacina, backed by Postgres.
@@ -45,11 +46,18 @@ struct Cli {
     #[arg(long, env = "DATABASE_URL")]
     database_url: Option<String>,
 
+    /// Secret holding the database `username`/`password`, filled into
+    /// --database-url at startup. Read from the backend named by
+    /// CLOACINA_SECRETS_BACKEND (vault, aws or gcp with the matching
+    /// `secrets-*` feature, env or file). Leased credentials are renewed in the background.
+    #[arg(long, env = "CLOACINA_DATABASE_CREDENTIALS_SECRET")]
+    database_credentials_secret: Option<String>,
+
     /// Bootstrap API key (used instead of auto-generating on first startup).
     #[arg(long, env = "CLOACINA_BOOTSTRAP_KEY")]
     bootstrap_key: Option<String>,
 
-    /// Require package signatures for workflow uploads.
+    /// Require package signatures for workflow uploads and package loads.
     #[arg(long, env = "CLOACINA_REQUIRE_SIGNATURES")]
     require_signatures: bool,
 
@@ -59,6 +67,12 @@ struct Cli {
     #[arg(long, env = "CLOACINA_VERIFICATION_ORG_ID")]
     verification_org_id: Option<Uuid>,
 
+    /// Per-tenant signature trust list as `TENANT=ORG_UUID`: that tenant's
+    /// packages must be signed by a key trusted by ORG_UUID instead of
+    /// `--verification-org-id`. Repeat for each tenant.
+    #[arg(long, value_delimiter = ',', env = "CLOACINA_TENANT_TRUST_ORGS")]
+    tenant_trust_org: Vec<String>,
+
     /// Interval (seconds) between reconciler passes that sync the in-runner
     /// workflow registry with the DB. Default matches the cloacina runtime
     /// default; override upward for quiet prod, downward for fast e2e.
@@ -89,6 +103,73 @@ struct Cli {
     #[arg(long, default_value_t = 14)]
     log_retention_days: u64,
 
+    /// Hours after a workflow execution finishes before its contexts (input
+    /// and per-task outputs) are purged. Execution metadata — status, timings,
+    /// task rows, events, labels, annotations — is kept. Unset keeps contexts
+    /// forever.
+    #[arg(long, env = "CLOACINA_CONTEXT_RETENTION_HOURS")]
+    context_retention_hours: Option<u64>,
+
+    /// Garbage-collect superseded package versions, keeping this many per
+    /// package (counting the active one). The active version is never
+    /// deleted. Unset keeps every version.
+    #[arg(long, env = "CLOACINA_REGISTRY_GC_KEEP_LAST")]
+    registry_gc_keep_last: Option<usize>,
+
+    /// Package versions (`name@version`) registry GC never deletes. Repeat
+    /// the flag or comma-separate values.
+    #[arg(long, value_delimiter = ',', env = "CLOACINA_REGISTRY_GC_PIN")]
+    registry_gc_pin: Vec<String>,
+
+    /// Only log which package versions registry GC would delete.
+    #[arg(long, env = "CLOACINA_REGISTRY_GC_DRY_RUN")]
+    registry_gc_dry_run: bool,
+
+    /// Record the context keys each task reads and writes, so the
+    /// context-access endpoint can flag hidden data couplings between tasks.
+    #[arg(long, env = "CLOACINA_TRACE_CONTEXT_ACCESS")]
+    trace_context_access: bool,
+
+    /// Record which task changed each context key, with the previous value,
+    /// for the context-lineage endpoint.
+    #[arg(long, env = "CLOACINA_TRACK_CONTEXT_LINEAGE")]
+    track_context_lineage: bool,
+
+    /// Blue/green upgrade: on startup, ask every live server on this
+    /// database running a different version to drain. The old server stops
+    /// firing schedules and claiming tasks, finishes what it has in flight,
+    /// and exits; this one picks up its schedules and queued executions.
+    #[arg(long, env = "CLOACINA_TAKEOVER")]
+    takeover: bool,
+
+    /// Max seconds a server asked to drain by a successor waits for its
+    /// in-flight tasks before exiting anyway. Unfinished tasks are reclaimed
+    /// by the successor's stale-claim sweeper. Default 300.
+    #[arg(long, env = "CLOACINA_HANDOFF_DRAIN_TIMEOUT_S", default_value_t = 300)]
+    handoff_drain_timeout_s: u64,
+
+    /// Multi-region federation config (TOML): this server's region, its peer
+    /// regions, and cross-region chain rules. Enables the federated
+    /// execution view and chain forwarding. Unset runs standalone.
+    #[arg(long, env = "CLOACINA_FEDERATION_CONFIG")]
+    federation_config: Option<PathBuf>,
+
+    /// Webhook URL(s) notified when a package is registered, updated, rolled
+    /// back or removed. Repeat the flag or comma-separate values. Unset
+    /// disables registry webhooks.
+    #[arg(long, value_delimiter = ',', env = "CLOACINA_REGISTRY_WEBHOOK_URL")]
+    registry_webhook_url: Vec<String>,
+
+    /// Secret for signing registry webhook bodies (HMAC-SHA256, sent as
+    /// `X-Cloacina-Signature: sha256=<hex>`). Unset sends them unsigned.
+    #[arg(long, env = "CLOACINA_REGISTRY_WEBHOOK_SECRET", hide_env_values = true)]
+    registry_webhook_secret: Option<String>,
+
+    /// Registry webhook event kinds to deliver, comma-separated
+    /// (`registered`, `updated`, `rolled_back`, `removed`). Default: all.
+    #[arg(long, value_delimiter = ',', env = "CLOACINA_REGISTRY_WEBHOOK_EVENTS")]
+    registry_webhook_events: Vec<String>,
+
     /// Executor every task is dispatched to (CLOACI-T-0640). `default` (the
     /// in-process thread executor) unless set to another registered key —
     /// notably `fleet` to send all work to the execution-agent fleet. The key
@@ -149,6 +230,41 @@ struct Cli {
     )]
     cors_allowed_headers: Vec<String>,
 
+    /// PEM certificate chain to serve the API over HTTPS with. Requires
+    /// `--tls-key`. Unset serves plain HTTP.
+    #[arg(long, env = "CLOACINA_TLS_CERT", requires = "tls_key")]
+    tls_cert: Option<PathBuf>,
+
+    /// PEM private key for `--tls-cert`.
+    #[arg(long, env = "CLOACINA_TLS_KEY", requires = "tls_cert")]
+    tls_key: Option<PathBuf>,
+
+    /// Extra certificate served to clients that ask for a given hostname via
+    /// SNI, as `host=cert.pem:key.pem`. Repeat the flag or comma-separate
+    /// values. Other clients get `--tls-cert`.
+    #[arg(
+        long,
+        env = "CLOACINA_TLS_SNI_CERTS",
+        value_delimiter = ',',
+        requires = "tls_cert"
+    )]
+    tls_sni_cert: Vec<cloacina_server::tls::SniCert>,
+
+    /// PEM bundle of CAs that issue client certificates. Turns on mutual
+    /// TLS: clients must present a certificate from one of these CAs.
+    #[arg(long, env = "CLOACINA_TLS_CLIENT_CA", requires = "tls_cert")]
+    tls_client_ca: Option<PathBuf>,
+
+    /// Client certificate requirement with `--tls-client-ca`: `required`
+    /// (default), `optional` (verify one if presented) or `none`.
+    #[arg(
+        long,
+        env = "CLOACINA_TLS_CLIENT_AUTH",
+        default_value = "required",
+        requires = "tls_client_ca"
+    )]
+    tls_client_auth: cloacina_server::tls::ClientAuth,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -184,6 +300,43 @@ async fn main() -> Result<()> {
         )
     })?;
 
+    // Held for the life of the server: lease renewal stops when it drops.
+    let (database_url, _database_credentials) = match &cli.database_credentials_secret {
+        Some(secret) => {
+            let backend = cloacina::security::secret_backend_from_env()?.ok_or_else(|| {
+                anyhow::anyhow!("--database-credentials-secret requires CLOACINA_SECRETS_BACKEND")
+            })?;
+            let resolver = Arc::new(cloacina::security::CachedSecretResolver::new(backend));
+            let url = cloacina::security::database_url_with_credentials(
+                &database_url,
+                resolver.as_ref(),
+                secret,
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to resolve database credentials: {}", e))?;
+            resolver.spawn_lease_renewal(std::time::Duration::from_secs(60));
+            (url, Some(resolver))
+        }
+        None => (database_url, None),
+    };
+
+    let registry_gc = match cli.registry_gc_keep_last {
+        Some(keep_last) => {
+            let mut policy = cloacina::registry::RegistryGcPolicy::keep_last(keep_last);
+            for pin in &cli.registry_gc_pin {
+                let (name, version) = pin.rsplit_once('@').ok_or_else(|| {
+                    anyhow::anyhow!("--registry-gc-pin expects name@version, got '{}'", pin)
+                })?;
+                policy = policy.pin(name, version);
+            }
+            Some(policy)
+        }
+        None => None,
+    };
+
+    let tenant_trust_orgs = cloacina::security::parse_tenant_trust_orgs(&cli.tenant_trust_org)
+        .map_err(|e| anyhow::anyhow!("invalid --tenant-trust-org: {}", e))?;
+
     cloacina_server::run(
         cli.home,
         cli.bind,
@@ -192,10 +345,25 @@ async fn main() -> Result<()> {
         cli.bootstrap_key,
         cli.require_signatures,
         cli.verification_org_id,
+        tenant_trust_orgs,
         cli.reconcile_interval_s.map(std::time::Duration::from_secs),
         cli.tenant_runner_cache_size,
         std::time::Duration::from_secs(cli.tenant_deletion_drain_timeout_s),
         cli.log_retention_days,
+        cli.context_retention_hours
+            .map(|h| std::time::Duration::from_secs(h * 60 * 60)),
+        registry_gc,
+        cli.registry_gc_dry_run,
+        cli.trace_context_access,
+        cli.track_context_lineage,
+        cli.takeover,
+        std::time::Duration::from_secs(cli.handoff_drain_timeout_s),
+        cli.federation_config,
+        cloacina_server::registry_webhooks::RegistryWebhookConfig {
+            urls: cli.registry_webhook_url,
+            secret: cli.registry_webhook_secret,
+            events: cli.registry_webhook_events,
+        },
         cli.default_executor,
         cli.agent_heartbeat_interval_s,
         cli.agent_liveness_misses,
@@ -204,6 +372,15 @@ async fn main() -> Result<()> {
             allowed_methods: cli.cors_allowed_methods,
             allowed_headers: cli.cors_allowed_headers,
         },
+        cli.tls_cert
+            .zip(cli.tls_key)
+            .map(|(cert, key)| cloacina_server::tls::TlsConfig {
+                cert,
+                key,
+                sni_certs: cli.tls_sni_cert,
+                client_ca: cli.tls_client_ca,
+                client_auth: cli.tls_client_auth,
+            }),
     )
     .await
 }
@@ -229,10 +229,13 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use openidconnect::core::{CoreAuthenticationFlow, CoreClient, CoreProviderMetadata};
+use openidconnect::core::{
+    CoreAuthenticationFlow, CoreClient, CoreJsonWebKeySet, CoreJwsSigningAlgorithm,
+    CoreProviderMetadata,
+};
 use openidconnect::{
-    AuthorizationCode, ClientId, ClientSecret, CsrfToken, IssuerUrl, Nonce, PkceCodeChallenge,
-    PkceCodeVerifier, RedirectUrl, Scope, TokenResponse,
+    AuthorizationCode, ClientId, ClientSecret, CsrfToken, IssuerUrl, JsonWebKey, JsonWebKeySetUrl,
+    Nonce, PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, TokenResponse,
 };
 
 /// A discovered OIDC relying party. Holds the cached provider metadata (which
@@ -451,6 +454,308 @@ impl LoginFlowStore {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Bearer JWTs — API clients (CI jobs, service accounts) present an IdP-issued
+// token directly instead of going through the browser login flow. The token is
+// verified locally against the issuer's JWKS and mapped through the same
+// `MappingPolicy` as a login; no key is minted.
+// ---------------------------------------------------------------------------
+
+/// Minimum spacing between JWKS refetches triggered by unknown `kid`s, so a
+/// flood of forged tokens cannot turn into a flood of IdP requests.
+const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+/// Clock skew tolerated on `exp` / `nbf`.
+const CLOCK_SKEW_SECS: i64 = 60;
+/// Verified tokens remembered so repeat requests skip signature checks.
+const VERIFIED_TOKEN_CACHE: usize = 1024;
+
+/// Configuration for accepting IdP-issued JWTs as bearer credentials.
+#[derive(Debug, Clone)]
+pub struct OidcBearerConfig {
+    pub issuer_url: String,
+    /// Accepted `aud` values; a token must name at least one.
+    pub audiences: Vec<String>,
+}
+
+impl OidcBearerConfig {
+    /// Build from `CLOACINA_OIDC_ISSUER` + `CLOACINA_OIDC_AUDIENCE`
+    /// (comma-separated); `None` unless both are set.
+    pub fn from_env() -> Option<OidcBearerConfig> {
+        let issuer_url = std::env::var("CLOACINA_OIDC_ISSUER").ok()?;
+        let audiences: Vec<String> = std::env::var("CLOACINA_OIDC_AUDIENCE")
+            .ok()?
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if audiences.is_empty() {
+            return None;
+        }
+        Some(OidcBearerConfig {
+            issuer_url,
+            audiences,
+        })
+    }
+}
+
+/// `aud` is either a single string or an array.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum AudienceClaim {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(serde::Deserialize)]
+struct BearerClaims {
+    iss: String,
+    sub: String,
+    aud: AudienceClaim,
+    exp: i64,
+    #[serde(default)]
+    nbf: Option<i64>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    groups: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct JwtHeader {
+    alg: String,
+    #[serde(default)]
+    kid: Option<String>,
+}
+
+struct VerifiedToken {
+    claims: IdentityClaims,
+    expires_at: i64,
+}
+
+/// Verifies IdP-issued bearer JWTs against the issuer's published keys.
+pub struct OidcBearerVerifier {
+    issuer: String,
+    audiences: Vec<String>,
+    jwks_uri: JsonWebKeySetUrl,
+    http: reqwest::Client,
+    jwks: tokio::sync::RwLock<CoreJsonWebKeySet>,
+    last_refresh: tokio::sync::Mutex<Instant>,
+    verified: tokio::sync::Mutex<lru::LruCache<String, VerifiedToken>>,
+}
+
+impl OidcBearerVerifier {
+    /// Discover the issuer's JWKS. Called once at startup when
+    /// `OidcBearerConfig::from_env()` is `Some`.
+    pub async fn discover(config: OidcBearerConfig) -> Result<Arc<OidcBearerVerifier>, String> {
+        let http = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| format!("oidc http client: {e}"))?;
+        let issuer = IssuerUrl::new(config.issuer_url.clone())
+            .map_err(|e| format!("oidc issuer url: {e}"))?;
+        let metadata = CoreProviderMetadata::discover_async(issuer, &http)
+            .await
+            .map_err(|e| format!("oidc discovery failed: {e}"))?;
+        Ok(Arc::new(OidcBearerVerifier {
+            issuer: metadata.issuer().as_str().to_string(),
+            audiences: config.audiences,
+            jwks_uri: metadata.jwks_uri().clone(),
+            http,
+            jwks: tokio::sync::RwLock::new(metadata.jwks().clone()),
+            last_refresh: tokio::sync::Mutex::new(Instant::now()),
+            verified: tokio::sync::Mutex::new(lru::LruCache::new(
+                std::num::NonZeroUsize::new(VERIFIED_TOKEN_CACHE).expect("non-zero"),
+            )),
+        }))
+    }
+
+    /// The issuer recorded in principal provenance (`oidc:<issuer>:<sub>`).
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Whether `token` is shaped like a JWT (three dot-separated segments)
+    /// rather than a cloacina API key.
+    pub fn looks_like_jwt(token: &str) -> bool {
+        !token.starts_with("clk_") && token.split('.').count() == 3
+    }
+
+    /// Verify `token` and return its identity, plus whether this is the first
+    /// time the token was seen (callers audit only first use).
+    pub async fn verify(&self, token: &str) -> Result<(IdentityClaims, bool), String> {
+        let hash = cloacina::security::api_keys::hash_api_key(token);
+        let now = chrono::Utc::now().timestamp();
+        {
+            let mut verified = self.verified.lock().await;
+            if let Some(entry) = verified.get(&hash) {
+                if entry.expires_at + CLOCK_SKEW_SECS > now {
+                    return Ok((entry.claims.clone(), false));
+                }
+                verified.pop(&hash);
+            }
+        }
+
+        let SplitJwt {
+            header,
+            signing_input,
+            signature,
+            payload,
+        } = split_jwt(token)?;
+        let alg = signing_alg(&header.alg)?;
+        if !self
+            .verify_signature(&header, &alg, signing_input, &signature)
+            .await?
+        {
+            // The IdP may have rotated its signing key since we last fetched.
+            if !self.refresh_jwks().await
+                || !self
+                    .verify_signature(&header, &alg, signing_input, &signature)
+                    .await?
+            {
+                return Err("no signing key matches the token".to_string());
+            }
+        }
+
+        let claims: BearerClaims =
+            serde_json::from_slice(&payload).map_err(|e| format!("invalid token claims: {e}"))?;
+        let expires_at = claims.exp;
+        let identity = check_claims(claims, &self.issuer, &self.audiences, now)?;
+        self.verified.lock().await.put(
+            hash,
+            VerifiedToken {
+                claims: identity.clone(),
+                expires_at,
+            },
+        );
+        Ok((identity, true))
+    }
+
+    /// `Ok(false)` when no key in the current JWKS matches the header;
+    /// `Err` when a matching key rejects the signature.
+    async fn verify_signature(
+        &self,
+        header: &JwtHeader,
+        alg: &CoreJwsSigningAlgorithm,
+        signing_input: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, String> {
+        let jwks = self.jwks.read().await;
+        let mut last_error = None;
+        for key in jwks.keys().iter().filter(|k| match &header.kid {
+            Some(kid) => k.key_id().map(|id| id.as_str() == kid).unwrap_or(false),
+            None => true,
+        }) {
+            match key.verify_signature(alg, signing_input, signature) {
+                Ok(()) => return Ok(true),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match (last_error, &header.kid) {
+            (Some(e), Some(_)) => Err(format!("signature verification failed: {e}")),
+            _ => Ok(false),
+        }
+    }
+
+    /// Refetch the JWKS unless it was refreshed recently. Returns whether
+    /// the key set was replaced.
+    async fn refresh_jwks(&self) -> bool {
+        let mut last = self.last_refresh.lock().await;
+        if last.elapsed() < JWKS_REFRESH_INTERVAL {
+            return false;
+        }
+        *last = Instant::now();
+        match CoreJsonWebKeySet::fetch_async(&self.jwks_uri, &self.http).await {
+            Ok(keys) => {
+                *self.jwks.write().await = keys;
+                true
+            }
+            Err(e) => {
+                tracing::warn!("oidc JWKS refresh failed: {e}");
+                false
+            }
+        }
+    }
+}
+
+/// The parts of a compact JWS.
+struct SplitJwt<'a> {
+    header: JwtHeader,
+    /// `header.payload` as sent, which the signature covers.
+    signing_input: &'a [u8],
+    signature: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+/// Split a compact JWS into its header, signing input, signature and payload.
+fn split_jwt(token: &str) -> Result<SplitJwt<'_>, String> {
+    use base64::Engine;
+    let b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let (signing_input, signature) = token
+        .rsplit_once('.')
+        .ok_or_else(|| "malformed token".to_string())?;
+    let (header, payload) = signing_input
+        .split_once('.')
+        .ok_or_else(|| "malformed token".to_string())?;
+    let header: JwtHeader = b64
+        .decode(header)
+        .ok()
+        .and_then(|h| serde_json::from_slice(&h).ok())
+        .ok_or_else(|| "malformed token header".to_string())?;
+    let payload = b64
+        .decode(payload)
+        .map_err(|_| "malformed token payload".to_string())?;
+    let signature = b64
+        .decode(signature)
+        .map_err(|_| "malformed token signature".to_string())?;
+    Ok(SplitJwt {
+        header,
+        signing_input: signing_input.as_bytes(),
+        signature,
+        payload,
+    })
+}
+
+/// Asymmetric algorithms only: `none` and the HMAC family would let anyone
+/// holding the (public) client id forge tokens.
+fn signing_alg(alg: &str) -> Result<CoreJwsSigningAlgorithm, String> {
+    if !matches!(alg.get(..2), Some("RS" | "PS" | "ES")) && alg != "EdDSA" {
+        return Err(format!("unsupported token algorithm '{alg}'"));
+    }
+    serde_json::from_value(serde_json::Value::String(alg.to_string()))
+        .map_err(|_| format!("unsupported token algorithm '{alg}'"))
+}
+
+/// Check issuer, audience and validity window, and extract the identity.
+fn check_claims(
+    claims: BearerClaims,
+    issuer: &str,
+    audiences: &[String],
+    now: i64,
+) -> Result<IdentityClaims, String> {
+    if claims.iss.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        return Err(format!("unexpected issuer '{}'", claims.iss));
+    }
+    let aud_ok = match &claims.aud {
+        AudienceClaim::One(a) => audiences.contains(a),
+        AudienceClaim::Many(all) => all.iter().any(|a| audiences.contains(a)),
+    };
+    if !aud_ok {
+        return Err("token audience not accepted".to_string());
+    }
+    if claims.exp + CLOCK_SKEW_SECS <= now {
+        return Err("token expired".to_string());
+    }
+    if claims.nbf.is_some_and(|nbf| nbf - CLOCK_SKEW_SECS > now) {
+        return Err("token not yet valid".to_string());
+    }
+    Ok(IdentityClaims {
+        subject: claims.sub,
+        email: claims.email,
+        groups: claims.groups,
+    })
+}
+
 /// Extract the `groups` claim from a (already-validated) JWT's payload.
 fn extract_groups(jwt: &str) -> Vec<String> {
     use base64::Engine;
@@ -602,6 +907,99 @@ mod tests {
             .is_empty());
     }
 
+    fn bearer_claims(iss: &str, aud: serde_json::Value, exp: i64) -> BearerClaims {
+        serde_json::from_value(serde_json::json!({
+            "iss": iss,
+            "sub": "svc-ci",
+            "aud": aud,
+            "exp": exp,
+            "email": "ci@acme.com",
+            "groups": ["acme-deployers"],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn bearer_claims_accept_matching_issuer_and_audience() {
+        let auds = vec!["cloacina-api".to_string()];
+        let id = check_claims(
+            bearer_claims(
+                "https://idp.example/",
+                serde_json::json!("cloacina-api"),
+                2_000,
+            ),
+            "https://idp.example",
+            &auds,
+            1_000,
+        )
+        .unwrap();
+        assert_eq!(id.subject, "svc-ci");
+        assert_eq!(id.groups, vec!["acme-deployers".to_string()]);
+
+        // Array audiences need only one accepted entry.
+        assert!(check_claims(
+            bearer_claims(
+                "https://idp.example",
+                serde_json::json!(["account", "cloacina-api"]),
+                2_000
+            ),
+            "https://idp.example",
+            &auds,
+            1_000,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn bearer_claims_reject_wrong_issuer_audience_or_expiry() {
+        let auds = vec!["cloacina-api".to_string()];
+        let iss = "https://idp.example";
+        assert!(check_claims(
+            bearer_claims(
+                "https://evil.example",
+                serde_json::json!("cloacina-api"),
+                2_000
+            ),
+            iss,
+            &auds,
+            1_000
+        )
+        .is_err());
+        assert!(check_claims(
+            bearer_claims(iss, serde_json::json!("other-app"), 2_000),
+            iss,
+            &auds,
+            1_000
+        )
+        .is_err());
+        assert_eq!(
+            check_claims(
+                bearer_claims(iss, serde_json::json!("cloacina-api"), 1_000),
+                iss,
+                &auds,
+                1_000 + CLOCK_SKEW_SECS
+            )
+            .unwrap_err(),
+            "token expired"
+        );
+    }
+
+    #[test]
+    fn bearer_tokens_require_asymmetric_algorithms() {
+        assert!(signing_alg("RS256").is_ok());
+        assert!(signing_alg("ES256").is_ok());
+        assert!(signing_alg("none").is_err());
+        assert!(signing_alg("HS256").is_err());
+    }
+
+    #[test]
+    fn jwt_shape_is_distinguished_from_api_keys() {
+        assert!(OidcBearerVerifier::looks_like_jwt("eyJh.eyJz.c2ln"));
+        assert!(!OidcBearerVerifier::looks_like_jwt("clk_abc.def.ghi"));
+        assert!(!OidcBearerVerifier::looks_like_jwt("clk_abcdef"));
+        assert!(split_jwt("eyJh.eyJz").is_err());
+    }
+
     /// Live discovery against the Dex sidecar. Ignored by default — run with
     /// `docker compose -f docker/docker-compose.demo.yml up -d dex` then
     /// `cargo test -p cloacina-server --lib oidc -- --ignored`.
@@ -29,18 +29,32 @@
 //! OpenAPI cannot describe WS message flows.
 
 use cloacina_api_types::{
-    AccumulatorStatus, AgentInfo, CompilerStatus, CreateKeyRequest, CreateTenantRequest,
-    DeclaredSurface, ErrorBody, ExecuteRequest, ExecuteResponse, ExecutionDetail, ExecutionEvent,
-    ExecutionEventsResponse, ExecutionSummary, ExecutionTasksResponse, FireMode,
+    AccumulatorStatus, AgentInfo, AnnotateExecutionRequest, AuditChainBreak,
+    AuditChainVerification, AuditEntry, BuildProvenance, CleanupEventsRequest,
+    CleanupEventsResponse, CompilerStatus, ContextAccessFinding, ContextAccessResponse,
+    ContextKeyChange, ContextKeyWrite, ContextLineageResponse, CreateKeyRequest,
+    CreateTenantRequest, CreateTriggerRequest, DeclaredSurface, DispatchLatency, ErrorBody,
+    ExecuteOptions, ExecuteRequest, ExecuteResponse, ExecutionAnnotation,
+    ExecutionAnnotationsResponse, ExecutionArtifact, ExecutionArtifactsResponse, ExecutionDetail,
+    ExecutionEvent, ExecutionEventsResponse, ExecutionRecording, ExecutionSummary,
+    ExecutionTasksResponse, ExecutionTree, ExecutionTreeTask, FederatedExecutionSummary,
+    FederatedExecutionsResponse, FederationRegion, FederationRegionsResponse, FireMode,
     FireReactorRequest, FireReactorResponse, GraphStatus, GraphTopology, GraphTopologyEdge,
     GraphTopologyNode, InjectAccumulatorRequest, InjectAccumulatorResponse, InputSlot,
-    KeyCreatedResponse, KeyInfo, KeyRevokedResponse, KeyRole, ListResponse, ReactorFire,
-    ReactorFireTimeseries, ReactorStatus, SecretDeletedResponse, SecretMetadataResponse,
+    KeyCreatedResponse, KeyInfo, KeyRevokedResponse, KeyRole, ListResponse, QueueStats,
+    ReactorFire, ReactorFireTimeseries, ReactorStatus, RecordedStep, RegionError,
+    ResolveTaskRequest, ResolveTaskResponse, SchedulerLoop, SecretDeletedResponse,
+    SecretMetadataResponse, TaskAttempt, TaskContextAccess, TaskContextChanges, TaskErrorPayload,
     TaskExecutionDetail, TenantCreatedResponse, TenantListResponse, TenantRemovedResponse,
-    TenantSummary, TriggerDetailResponse, TriggerExecution, TriggerPauseResponse,
-    TriggerScheduleInfo, TriggerScheduleSummary, WorkflowDeletedResponse, WorkflowDetail,
-    WorkflowPauseResponse, WorkflowSourceFile, WorkflowSourceResponse, WorkflowSummary,
-    WorkflowTaskNode, WorkflowUploadedResponse, WsTicketResponse,
+    TenantSummary, TriggerCatchupRequest, TriggerCatchupResponse, TriggerConditionRequest,
+    TriggerConditionResponse, TriggerDedupRequest, TriggerDedupResponse, TriggerDetailResponse,
+    TriggerDstPolicyRequest, TriggerDstPolicyResponse, TriggerEvaluation, TriggerExecution,
+    TriggerPauseResponse, TriggerScheduleInfo, TriggerScheduleSummary, TriggerThrottleRequest,
+    TriggerThrottleResponse, WorkflowConsumersResponse, WorkflowDeletedResponse,
+    WorkflowDeprecationInfo, WorkflowDeprecationRequest, WorkflowDeprecationResponse,
+    WorkflowDetail, WorkflowPauseResponse, WorkflowRollbackResponse, WorkflowSchemaResponse,
+    WorkflowSourceFile, WorkflowSourceResponse, WorkflowSummary, WorkflowTaskNode,
+    WorkflowUploadedResponse, WorkflowVersionSummary, WorkflowVersionsResponse, WsTicketResponse,
 };
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi, ToSchema};
@@ -54,6 +68,9 @@ pub struct PackageUploadForm {
     /// The `.cloacina` package archive.
     #[schema(format = Binary, content_media_type = "application/octet-stream")]
     pub file: String,
+    /// JSON configuration values for a package that declares a
+    /// `config_schema`; validated against it at registration.
+    pub config: Option<String>,
 }
 
 /// Adds the bearer API-key security scheme referenced by every
@@ -71,7 +88,10 @@ impl Modify for SecurityAddon {
                     .description(Some(
                         "Cloacina API key, sent as `Authorization: Bearer <key>`. \
                          Tenant scope is carried by the key itself; tenant-scoped \
-                         routes additionally name the tenant in the path.",
+                         routes additionally name the tenant in the path. When the \
+                         server accepts OIDC bearer tokens, an IdP-issued JWT may be \
+                         sent instead; `X-Cloacina-Tenant` selects the tenant when \
+                         its identity maps to several.",
                     ))
                     .build(),
             ),
@@ -92,6 +112,8 @@ impl Modify for SecurityAddon {
     paths(
         crate::health,
         crate::ready,
+        crate::routes::audit::list_audit_entries,
+        crate::routes::audit::verify_audit_chain,
         crate::routes::keys::create_key,
         crate::routes::keys::list_keys,
         crate::routes::keys::revoke_key,
@@ -119,13 +141,26 @@ impl Modify for SecurityAddon {
         crate::routes::workflows::list_workflows,
         crate::routes::workflows::get_workflow,
         crate::routes::workflows::get_workflow_source,
+        crate::routes::workflows::get_workflow_archive,
+        crate::routes::workflows::get_workflow_schema,
         crate::routes::workflows::pause_workflow,
         crate::routes::workflows::resume_workflow,
+        crate::routes::workflows::rollback_workflow,
+        crate::routes::workflows::list_workflow_versions,
+        crate::routes::workflows::deprecate_workflow,
+        crate::routes::workflows::undeprecate_workflow,
+        crate::routes::workflows::get_workflow_consumers,
         crate::routes::workflows::delete_workflow,
         crate::routes::triggers::list_triggers,
         crate::routes::triggers::get_trigger,
         crate::routes::triggers::pause_trigger,
         crate::routes::triggers::resume_trigger,
+        crate::routes::triggers::set_trigger_dedup,
+        crate::routes::triggers::set_trigger_catchup,
+        crate::routes::triggers::set_trigger_throttle,
+        crate::routes::triggers::set_trigger_dst_policy,
+        crate::routes::triggers::set_trigger_condition,
+        crate::routes::triggers::create_trigger,
         crate::routes::triggers::fire_trigger,
         crate::routes::triggers::get_trigger_interface,
         crate::routes::executions::execute_workflow,
@@ -133,8 +168,21 @@ impl Modify for SecurityAddon {
         crate::routes::executions::get_execution,
         crate::routes::executions::get_execution_events,
         crate::routes::executions::get_execution_tasks,
+        crate::routes::executions::get_execution_recording,
+        crate::routes::executions::get_execution_tree,
+        crate::routes::executions::list_execution_annotations,
+        crate::routes::executions::annotate_execution,
+        crate::routes::executions::resolve_task,
+        crate::routes::executions::get_context_access,
+        crate::routes::executions::get_context_lineage,
+        crate::routes::executions::list_artifacts,
+        crate::routes::executions::download_artifact,
+        crate::routes::executions::get_queue_stats,
+        crate::routes::federation::list_federated_executions,
+        crate::routes::federation::list_regions,
         crate::routes::agent::list_agents,
         crate::routes::compiler::compiler_status,
+        crate::routes::maintenance::cleanup_events,
         crate::routes::health_graphs::list_accumulators,
         crate::routes::health_graphs::list_reactors,
         crate::routes::health_graphs::fire_reactor,
@@ -168,6 +216,10 @@ impl Modify for SecurityAddon {
         KeyRevokedResponse,
         WsTicketResponse,
         ListResponse<KeyInfo>,
+        AuditEntry,
+        ListResponse<AuditEntry>,
+        AuditChainBreak,
+        AuditChainVerification,
         CreateTenantRequest,
         TenantCreatedResponse,
         TenantRemovedResponse,
@@ -177,20 +229,41 @@ impl Modify for SecurityAddon {
         WorkflowUploadedResponse,
         WorkflowSummary,
         WorkflowDetail,
+        BuildProvenance,
         WorkflowTaskNode,
         WorkflowSourceResponse,
         WorkflowSourceFile,
+        WorkflowSchemaResponse,
         WorkflowPauseResponse,
         InputSlot,
         DeclaredSurface,
         WorkflowDeletedResponse,
+        WorkflowRollbackResponse,
+        WorkflowVersionSummary,
+        WorkflowVersionsResponse,
+        WorkflowDeprecationRequest,
+        WorkflowDeprecationInfo,
+        WorkflowDeprecationResponse,
+        WorkflowConsumersResponse,
         TenantListResponse<WorkflowSummary>,
         TriggerScheduleSummary,
         TriggerScheduleInfo,
         TriggerExecution,
         TriggerDetailResponse,
         TriggerPauseResponse,
+        TriggerDedupRequest,
+        TriggerDedupResponse,
+        TriggerCatchupRequest,
+        TriggerCatchupResponse,
+        TriggerThrottleRequest,
+        TriggerThrottleResponse,
+        TriggerConditionRequest,
+        TriggerConditionResponse,
+        CreateTriggerRequest,
+        TriggerDstPolicyRequest,
+        TriggerDstPolicyResponse,
         TenantListResponse<TriggerScheduleSummary>,
+        ExecuteOptions,
         ExecuteRequest,
         ExecuteResponse,
         ExecutionSummary,
@@ -198,11 +271,42 @@ impl Modify for SecurityAddon {
         ExecutionEvent,
         ExecutionEventsResponse,
         TaskExecutionDetail,
+        TaskErrorPayload,
         ExecutionTasksResponse,
+        ExecutionRecording,
+        RecordedStep,
+        ExecutionTree,
+        ExecutionTreeTask,
+        TaskAttempt,
+        TriggerEvaluation,
+        AnnotateExecutionRequest,
+        ExecutionAnnotation,
+        ExecutionAnnotationsResponse,
+        ResolveTaskRequest,
+        ResolveTaskResponse,
+        TaskContextAccess,
+        ContextAccessFinding,
+        ContextAccessResponse,
+        ContextKeyChange,
+        TaskContextChanges,
+        ContextKeyWrite,
+        ContextLineageResponse,
+        ExecutionArtifact,
+        ExecutionArtifactsResponse,
+        QueueStats,
+        DispatchLatency,
+        SchedulerLoop,
         TenantListResponse<ExecutionSummary>,
+        FederatedExecutionSummary,
+        FederatedExecutionsResponse,
+        RegionError,
+        FederationRegion,
+        FederationRegionsResponse,
         AgentInfo,
         ListResponse<AgentInfo>,
         CompilerStatus,
+        CleanupEventsRequest,
+        CleanupEventsResponse,
         AccumulatorStatus,
         GraphStatus,
         GraphTopology,
@@ -225,12 +329,15 @@ impl Modify for SecurityAddon {
     tags(
         (name = "operational", description = "Liveness/readiness (no auth)"),
         (name = "keys", description = "API key management"),
+        (name = "audit", description = "Tamper-evident audit chain (admin)"),
         (name = "tenants", description = "Tenant lifecycle (admin)"),
         (name = "workflows", description = "Workflow package registry"),
         (name = "triggers", description = "Cron + trigger schedules (read-only)"),
         (name = "executions", description = "Workflow execution + event log"),
+        (name = "federation", description = "Multi-region federation"),
         (name = "fleet", description = "Execution-agent fleet roster (admin)"),
         (name = "compiler", description = "Compiler / build-pipeline status (admin)"),
+        (name = "maintenance", description = "Platform maintenance operations (admin)"),
         (name = "graph-health", description = "Computation-graph health"),
     )
 )]
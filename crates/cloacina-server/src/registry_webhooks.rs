@@ -0,0 +1,289 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Outbound webhooks for package lifecycle events.
+//!
+//! When `--registry-webhook-url` is set, every package the API registers,
+//! updates, rolls back or removes is POSTed as JSON to each configured URL:
+//!
+//! ```json
+//! {
+//!   "tenant_id": "acme",
+//!   "kind": "updated",
+//!   "package_id": "…",
+//!   "package_name": "etl",
+//!   "version": "1.1.0",
+//!   "previous_version": "1.0.0",
+//!   "content_hash": "…",
+//!   "occurred_at": "2026-01-01T00:00:00Z"
+//! }
+//! ```
+//!
+//! The `X-Cloacina-Event` header carries the dotted event name
+//! (`package.updated`). With `--registry-webhook-secret`, the body is signed
+//! with HMAC-SHA256 and the hex digest sent as
+//! `X-Cloacina-Signature: sha256=<hex>`.
+//!
+//! Delivery is best-effort and off the request path: each event is sent from
+//! its own task, retried up to [`MAX_ATTEMPTS`] times on connection errors
+//! and non-2xx responses, then dropped with a warning. Receivers that cannot
+//! miss an event should reconcile against `GET /workflows` periodically.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context as _, Result};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use tracing::{debug, warn};
+
+use cloacina::registry::{RegistryEvent, RegistryEventKind, RegistryEventListener};
+
+/// Delivery attempts per event and URL before it is dropped.
+pub const MAX_ATTEMPTS: u32 = 3;
+/// Per-attempt HTTP timeout.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Operator configuration for registry webhooks.
+#[derive(Debug, Clone, Default)]
+pub struct RegistryWebhookConfig {
+    /// Endpoints every matching event is POSTed to.
+    pub urls: Vec<String>,
+    /// HMAC-SHA256 key for `X-Cloacina-Signature`; unsigned when `None`.
+    pub secret: Option<String>,
+    /// Event kinds to deliver (`registered`, `updated`, `rolled_back`,
+    /// `removed`); empty delivers all of them.
+    pub events: Vec<String>,
+}
+
+/// Delivers registry events to the configured webhook URLs.
+pub struct RegistryWebhooks {
+    urls: Vec<reqwest::Url>,
+    secret: Option<Vec<u8>>,
+    events: HashSet<RegistryEventKind>,
+    http: reqwest::Client,
+}
+
+impl RegistryWebhooks {
+    /// Build the dispatcher, or `None` when no URL is configured. Malformed
+    /// URLs and unknown event kinds fail at boot.
+    pub fn from_config(config: RegistryWebhookConfig) -> Result<Option<Self>> {
+        if config.urls.is_empty() {
+            return Ok(None);
+        }
+        let urls = config
+            .urls
+            .iter()
+            .map(|u| {
+                reqwest::Url::parse(u)
+                    .with_context(|| format!("invalid --registry-webhook-url '{}'", u))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let events = config
+            .events
+            .iter()
+            .map(|e| {
+                serde_json::from_value::<RegistryEventKind>(serde_json::Value::String(
+                    e.trim().to_string(),
+                ))
+                .with_context(|| {
+                    format!(
+                        "unknown --registry-webhook-events kind '{}' \
+                         (expected registered, updated, rolled_back or removed)",
+                        e
+                    )
+                })
+            })
+            .collect::<Result<HashSet<_>>>()?;
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("failed to build registry webhook HTTP client")?;
+        Ok(Some(Self {
+            urls,
+            secret: config.secret.map(String::into_bytes),
+            events,
+            http,
+        }))
+    }
+
+    /// A listener that delivers the events of `tenant_id`'s registry.
+    pub fn listener(self: &Arc<Self>, tenant_id: &str) -> Arc<dyn RegistryEventListener> {
+        Arc::new(TenantWebhookListener {
+            webhooks: self.clone(),
+            tenant_id: tenant_id.to_string(),
+        })
+    }
+
+    fn wants(&self, kind: RegistryEventKind) -> bool {
+        self.events.is_empty() || self.events.contains(&kind)
+    }
+
+    fn signature(&self, body: &[u8]) -> Option<String> {
+        let secret = self.secret.as_ref()?;
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+        mac.update(body);
+        let digest = mac.finalize().into_bytes();
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        Some(format!("sha256={}", hex))
+    }
+
+    async fn deliver(&self, url: reqwest::Url, kind: RegistryEventKind, body: Vec<u8>) {
+        let signature = self.signature(&body);
+        for attempt in 1..=MAX_ATTEMPTS {
+            let mut request = self
+                .http
+                .post(url.clone())
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header("X-Cloacina-Event", kind.as_str())
+                .body(body.clone());
+            if let Some(signature) = &signature {
+                request = request.header("X-Cloacina-Signature", signature);
+            }
+            let error = match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    debug!(%url, event = %kind, "registry webhook delivered");
+                    metrics::counter!("cloacina_registry_webhooks_total", "outcome" => "delivered")
+                        .increment(1);
+                    return;
+                }
+                Ok(response) => format!("HTTP {}", response.status()),
+                Err(e) => e.to_string(),
+            };
+            if attempt == MAX_ATTEMPTS {
+                warn!(%url, event = %kind, %error, "registry webhook dropped after {} attempts", MAX_ATTEMPTS);
+                metrics::counter!("cloacina_registry_webhooks_total", "outcome" => "dropped")
+                    .increment(1);
+                return;
+            }
+            debug!(%url, event = %kind, %error, attempt, "registry webhook failed; retrying");
+            tokio::time::sleep(Duration::from_secs(1 << (attempt - 1))).await;
+        }
+    }
+}
+
+/// Webhook payload: the registry event plus the tenant it happened in.
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    tenant_id: &'a str,
+    #[serde(flatten)]
+    event: &'a RegistryEvent,
+}
+
+struct TenantWebhookListener {
+    webhooks: Arc<RegistryWebhooks>,
+    tenant_id: String,
+}
+
+impl RegistryEventListener for TenantWebhookListener {
+    fn on_event(&self, event: &RegistryEvent) {
+        if !self.webhooks.wants(event.kind) {
+            return;
+        }
+        let body = match serde_json::to_vec(&WebhookPayload {
+            tenant_id: &self.tenant_id,
+            event,
+        }) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!(error = %e, "failed to serialize registry webhook payload");
+                return;
+            }
+        };
+        for url in &self.webhooks.urls {
+            let webhooks = self.webhooks.clone();
+            let url = url.clone();
+            let body = body.clone();
+            let kind = event.kind;
+            tokio::spawn(async move { webhooks.deliver(url, kind, body).await });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(urls: &[&str], events: &[&str]) -> RegistryWebhookConfig {
+        RegistryWebhookConfig {
+            urls: urls.iter().map(|u| u.to_string()).collect(),
+            secret: Some("s3cret".to_string()),
+            events: events.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn no_urls_disables_webhooks() {
+        assert!(
+            RegistryWebhooks::from_config(RegistryWebhookConfig::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn bad_urls_and_event_kinds_fail_at_boot() {
+        assert!(RegistryWebhooks::from_config(config(&["not a url"], &[])).is_err());
+        assert!(RegistryWebhooks::from_config(config(
+            &["https://ci.example.com/hook"],
+            &["deleted"]
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn event_filter_and_signature() {
+        let webhooks = RegistryWebhooks::from_config(config(
+            &["https://ci.example.com/hook"],
+            &["registered", "rolled_back"],
+        ))
+        .unwrap()
+        .unwrap();
+        assert!(webhooks.wants(RegistryEventKind::RolledBack));
+        assert!(!webhooks.wants(RegistryEventKind::Removed));
+
+        // Deterministic hex HMAC-SHA256 over the exact body bytes.
+        let signature = webhooks.signature(b"{}").unwrap();
+        assert!(signature.starts_with("sha256="));
+        assert_eq!(signature.len(), "sha256=".len() + 64);
+        assert_eq!(webhooks.signature(b"{}").unwrap(), signature);
+        assert_ne!(webhooks.signature(b"[]").unwrap(), signature);
+    }
+
+    #[test]
+    fn payload_carries_tenant_and_flattened_event() {
+        let event = RegistryEvent {
+            kind: RegistryEventKind::Updated,
+            package_id: uuid::Uuid::nil(),
+            package_name: "etl".to_string(),
+            version: "1.1.0".to_string(),
+            previous_version: Some("1.0.0".to_string()),
+            content_hash: Some("abc".to_string()),
+            occurred_at: chrono::Utc::now(),
+        };
+        let json = serde_json::to_value(WebhookPayload {
+            tenant_id: "acme",
+            event: &event,
+        })
+        .unwrap();
+        assert_eq!(json["tenant_id"], "acme");
+        assert_eq!(json["kind"], "updated");
+        assert_eq!(json["previous_version"], "1.0.0");
+    }
+}
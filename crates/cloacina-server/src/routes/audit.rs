@@ -0,0 +1,145 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Audit chain endpoints — page through the hash-chained audit log and
+//! verify it. Platform admin only.
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Extension, Json,
+};
+use tracing::warn;
+
+use cloacina_api_types::{
+    AuditChainBreak, AuditChainVerification, AuditEntry, ListAuditEntriesQuery, ListResponse,
+    VerifyAuditChainQuery,
+};
+
+use crate::routes::auth::AuthenticatedKey;
+use crate::routes::error::ApiError;
+use crate::AppState;
+
+const DEFAULT_AUDIT_ENTRIES_LIMIT: i64 = 100;
+const MAX_AUDIT_ENTRIES_LIMIT: i64 = 1000;
+
+/// GET /audit/entries — a page of audit chain entries in sequence order.
+#[utoipa::path(
+    get,
+    path = "/v1/audit/entries",
+    tag = "audit",
+    params(ListAuditEntriesQuery),
+    responses(
+        (status = 200, description = "Audit entries after `after`", body = ListResponse<AuditEntry>),
+        (status = 400, description = "Invalid pagination", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Platform admin required", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_audit_entries(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Query(q): Query<ListAuditEntriesQuery>,
+) -> impl IntoResponse {
+    let limit = q.limit.unwrap_or(DEFAULT_AUDIT_ENTRIES_LIMIT);
+    if !(1..=MAX_AUDIT_ENTRIES_LIMIT).contains(&limit) {
+        return ApiError::bad_request(
+            "invalid_pagination",
+            format!("limit must be 1..={}", MAX_AUDIT_ENTRIES_LIMIT),
+        )
+        .into_response();
+    }
+    let after = q.after.unwrap_or(0).max(0);
+
+    let dal = cloacina::dal::DAL::new(state.database.clone());
+    match dal.audit_log().list(after + 1, None, limit).await {
+        Ok(entries) => {
+            let items: Vec<AuditEntry> = entries
+                .into_iter()
+                .map(|e| AuditEntry {
+                    sequence: e.sequence,
+                    event_type: e.event_type,
+                    payload: serde_json::from_str(&e.payload)
+                        .unwrap_or(serde_json::Value::String(e.payload)),
+                    prev_hash: e.prev_hash,
+                    entry_hash: e.entry_hash,
+                    created_at: e.created_at.to_rfc3339(),
+                })
+                .collect();
+            Json(ListResponse::new(items)).into_response()
+        }
+        Err(e) => {
+            warn!("Failed to list audit entries: {}", e);
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
+/// GET /audit/verify — recompute the chain over a range and check it
+/// against the stored hashes and anchors.
+///
+/// A broken chain is still a 200; `valid` is false and `failure` says where.
+#[utoipa::path(
+    get,
+    path = "/v1/audit/verify",
+    tag = "audit",
+    params(VerifyAuditChainQuery),
+    responses(
+        (status = 200, description = "Verification result", body = AuditChainVerification),
+        (status = 400, description = "Invalid range", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Platform admin required", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn verify_audit_chain(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Query(q): Query<VerifyAuditChainQuery>,
+) -> impl IntoResponse {
+    if let (Some(from), Some(to)) = (q.from, q.to) {
+        if from > to {
+            return ApiError::bad_request("invalid_range", "from must be <= to".to_string())
+                .into_response();
+        }
+    }
+
+    let dal = cloacina::dal::DAL::new(state.database.clone());
+    let anchor_key = state.audit_anchor_key.as_deref();
+    match cloacina::security::audit_chain::verify_chain(&dal, q.from, q.to, anchor_key).await {
+        Ok(result) => Json(AuditChainVerification {
+            valid: result.is_valid(),
+            entries_checked: result.entries_checked,
+            anchors_checked: result.anchors_checked,
+            first_sequence: result.first_sequence,
+            last_sequence: result.last_sequence,
+            head_hash: result.head_hash,
+            signatures_checked: anchor_key.is_some(),
+            failure: result.failure.map(|f| AuditChainBreak {
+                sequence: f.sequence,
+                reason: f.reason,
+            }),
+        })
+        .into_response(),
+        Err(e) => {
+            warn!("Failed to verify the audit chain: {}", e);
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
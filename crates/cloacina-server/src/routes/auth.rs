@@ -19,6 +19,10 @@
 //! Extracts `Authorization: Bearer <key>` headers, hashes the token,
 //! checks an LRU cache (30s TTL) before falling back to the DAL.
 //! Applied via `route_layer` so unauthenticated routes still 404 correctly.
+//!
+//! When `CLOACINA_OIDC_AUDIENCE` is configured, a JWT-shaped bearer token is
+//! instead verified against the issuer's JWKS and mapped to a principal by the
+//! OIDC allowlist, so API clients can authenticate with IdP-issued tokens.
 
 use axum::{
     extract::{Request, State},
@@ -33,6 +37,7 @@ use tokio::sync::Mutex;
 use tracing::warn;
 
 use cloacina::dal::unified::api_keys::ApiKeyInfo;
+use cloacina::security::audit;
 
 use crate::routes::error::ApiError;
 use crate::AppState;
@@ -120,6 +125,27 @@ impl KeyCache {
 /// Shared logic used by both the HTTP middleware and WebSocket handlers.
 /// Checks the LRU cache first, then falls back to the DAL.
 pub async fn validate_token(state: &AppState, token: &str) -> Result<AuthenticatedKey, ApiError> {
+    validate_token_for_tenant(state, token, None).await
+}
+
+/// [`validate_token`], with the tenant a bearer JWT should act in.
+///
+/// API keys carry their own scope and ignore `tenant`. An IdP-issued JWT
+/// (accepted when `CLOACINA_OIDC_AUDIENCE` is set) is mapped through the OIDC
+/// allowlist; when the identity maps to several tenants, `tenant` (from the
+/// `X-Cloacina-Tenant` header) picks one, otherwise the first matching rule
+/// wins.
+pub async fn validate_token_for_tenant(
+    state: &AppState,
+    token: &str,
+    tenant: Option<&str>,
+) -> Result<AuthenticatedKey, ApiError> {
+    if let Some(verifier) = &state.oidc_bearer {
+        if crate::oidc::OidcBearerVerifier::looks_like_jwt(token) {
+            return validate_jwt(state, verifier, token, tenant).await;
+        }
+    }
+
     let hash = cloacina::security::api_keys::hash_api_key(token);
 
     // Check cache first (avoids DB hit)
@@ -148,7 +174,7 @@ pub async fn validate_token(state: &AppState, token: &str) -> Result<Authenticat
             Ok(auth)
         }
         Ok(None) => {
-            warn!("API key validation failed — unknown or revoked key");
+            audit::log_authentication_failed("api_key", "unknown or revoked key");
             // CLOACI-T-0595 / API-06: canonical ApiError envelope.
             Err(ApiError::unauthorized("invalid or revoked API key"))
         }
@@ -161,6 +187,63 @@ pub async fn validate_token(state: &AppState, token: &str) -> Result<Authenticat
     }
 }
 
+/// Verify an IdP-issued JWT and map its identity to a principal. The
+/// principal is never god-mode, exactly like a key minted by OIDC login.
+async fn validate_jwt(
+    state: &AppState,
+    verifier: &crate::oidc::OidcBearerVerifier,
+    token: &str,
+    tenant: Option<&str>,
+) -> Result<AuthenticatedKey, ApiError> {
+    let (claims, first_use) = verifier.verify(token).await.map_err(|reason| {
+        audit::log_authentication_failed("oidc_jwt", &reason);
+        ApiError::unauthorized("invalid bearer token")
+    })?;
+    let principals = state.oidc_policy.resolve_all(&claims, verifier.issuer());
+    let principal = match tenant {
+        Some(t) => principals
+            .into_iter()
+            .find(|p| p.tenant.as_deref() == Some(t)),
+        None => principals.into_iter().next(),
+    };
+    let Some(principal) = principal else {
+        audit::log_authentication_failed("oidc_jwt", "identity matches no mapping rule");
+        return Err(ApiError::forbidden(
+            "identity_not_mapped",
+            "identity is not mapped to a principal",
+        ));
+    };
+    if first_use {
+        audit::log_token_authenticated(
+            &principal.provenance,
+            principal.tenant.as_deref(),
+            &principal.role,
+        );
+    }
+    Ok(AuthenticatedKey {
+        key_id: principal_key_id(&principal.provenance),
+        name: principal.provenance,
+        permissions: principal.role,
+        tenant_id: principal.tenant,
+        is_admin: false,
+    })
+}
+
+/// Stable id for a token principal, so logs and spans group one caller's
+/// requests. Derived from the provenance; never collides with a stored key
+/// in practice, and key-management routes find no row for it.
+fn principal_key_id(provenance: &str) -> uuid::Uuid {
+    let hash = cloacina::security::api_keys::hash_api_key(provenance);
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hash[i * 2..i * 2 + 2], 16).unwrap_or(0);
+    }
+    uuid::Uuid::from_bytes(bytes)
+}
+
+/// Header selecting the tenant a bearer JWT acts in.
+pub const TENANT_HEADER: &str = "x-cloacina-tenant";
+
 /// Auth middleware — validates Bearer token against cache then DAL.
 ///
 /// On success, inserts `AuthenticatedKey` into request extensions.
@@ -178,7 +261,13 @@ pub async fn require_auth(
         }
     };
 
-    match validate_token(&state, &token).await {
+    let tenant = request
+        .headers()
+        .get(TENANT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    match validate_token_for_tenant(&state, &token, tenant.as_deref()).await {
         Ok(auth) => {
             record_auth_span_fields(&tracing::Span::current(), &auth);
             request.extensions_mut().insert(auth);
@@ -257,9 +257,16 @@ pub fn build_authz_table() -> AuthzTable {
         "/compiler/status",
         Access::platform(Level::Admin),
     );
+    add(
+        Method::POST,
+        "/maintenance/cleanup-events",
+        Access::platform(Level::Admin),
+    );
     // CLOACI-T-0785: tenant-admin (was Platform); the handler filters the
     // roster to the caller's tenant (god sees all).
     add(Method::GET, "/agents", Access::any(Level::Admin));
+    // Federation roster: region names and peer URLs, no tenant data.
+    add(Method::GET, "/federation/regions", Access::any(Level::Read));
 
     // ----- Tenant + Admin: tenant-admin key self-service (CLOACI-T-0784).
     //       POST lowered from Platform; GET/DELETE are new. The DELETE handler
@@ -383,6 +390,14 @@ pub fn build_authz_table() -> AuthzTable {
         Access::platform(Level::Admin),
     );
 
+    // ----- Platform + Admin: the tamper-evident audit chain. -----
+    add(
+        Method::GET,
+        "/audit/entries",
+        Access::platform(Level::Admin),
+    );
+    add(Method::GET, "/audit/verify", Access::platform(Level::Admin));
+
     // ----- Tenant + Read -----
     add(
         Method::GET,
@@ -399,6 +414,16 @@ pub fn build_authz_table() -> AuthzTable {
         "/tenants/{tenant_id}/workflows/{name}/source",
         Access::tenant(Level::Read),
     );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/workflows/{name}/archive",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/workflows/{name}/schema",
+        Access::tenant(Level::Read),
+    );
     add(
         Method::GET,
         "/tenants/{tenant_id}/triggers",
@@ -419,6 +444,11 @@ pub fn build_authz_table() -> AuthzTable {
         "/tenants/{tenant_id}/executions",
         Access::tenant(Level::Read),
     );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/federation/executions",
+        Access::tenant(Level::Read),
+    );
     add(
         Method::GET,
         "/tenants/{tenant_id}/executions/{exec_id}",
@@ -434,6 +464,46 @@ pub fn build_authz_table() -> AuthzTable {
         "/tenants/{tenant_id}/executions/{exec_id}/tasks",
         Access::tenant(Level::Read),
     );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/recording",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/tree",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/annotations",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/context-access",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/context-lineage",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/artifacts",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/executions/{exec_id}/artifacts/{name}",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/stats/queue",
+        Access::tenant(Level::Read),
+    );
 
     // ----- Tenant + Write -----
     add(
@@ -451,6 +521,31 @@ pub fn build_authz_table() -> AuthzTable {
         "/tenants/{tenant_id}/workflows/{name}/resume",
         Access::tenant(Level::Write),
     );
+    add(
+        Method::POST,
+        "/tenants/{tenant_id}/workflows/{name}/rollback",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/workflows/{name}/versions",
+        Access::tenant(Level::Read),
+    );
+    add(
+        Method::PUT,
+        "/tenants/{tenant_id}/workflows/{name}/deprecation",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::DELETE,
+        "/tenants/{tenant_id}/workflows/{name}/deprecation",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::GET,
+        "/tenants/{tenant_id}/workflows/{name}/consumers",
+        Access::tenant(Level::Read),
+    );
     add(
         Method::DELETE,
         "/tenants/{tenant_id}/workflows/{name}/{version}",
@@ -461,6 +556,16 @@ pub fn build_authz_table() -> AuthzTable {
         "/tenants/{tenant_id}/workflows/{name}/execute",
         Access::tenant(Level::Write),
     );
+    add(
+        Method::POST,
+        "/tenants/{tenant_id}/executions/{exec_id}/annotations",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::POST,
+        "/tenants/{tenant_id}/executions/{exec_id}/tasks/{task}/resolve",
+        Access::tenant(Level::Write),
+    );
     add(
         Method::POST,
         "/tenants/{tenant_id}/triggers/{name}/pause",
@@ -476,6 +581,36 @@ pub fn build_authz_table() -> AuthzTable {
         "/tenants/{tenant_id}/triggers/{name}/fire",
         Access::tenant(Level::Write),
     );
+    add(
+        Method::PUT,
+        "/tenants/{tenant_id}/triggers/{name}/dedup",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::PUT,
+        "/tenants/{tenant_id}/triggers/{name}/catchup",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::PUT,
+        "/tenants/{tenant_id}/triggers/{name}/throttle",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::PUT,
+        "/tenants/{tenant_id}/triggers/{name}/dst",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::PUT,
+        "/tenants/{tenant_id}/triggers/{name}/condition",
+        Access::tenant(Level::Write),
+    );
+    add(
+        Method::POST,
+        "/tenants/{tenant_id}/triggers",
+        Access::tenant(Level::Write),
+    );
 
     // ----- Any + Read (today: authenticated; data-scoping stays in handler) -----
     add(Method::POST, "/auth/ws-ticket", Access::any(Level::Read));
@@ -856,7 +991,7 @@ mod tests {
         let t = build_authz_table();
         assert_eq!(
             t.len(),
-            64,
+            92,
             "authz table size changed — a route was added/removed without updating the table"
         );
 
@@ -945,6 +1080,10 @@ mod tests {
             get(Method::GET, "/compiler/status"),
             Some(Access::platform(Level::Admin))
         );
+        assert_eq!(
+            get(Method::POST, "/maintenance/cleanup-events"),
+            Some(Access::platform(Level::Admin))
+        );
         assert_eq!(
             get(Method::POST, "/tenants/{tenant_id}/workflows"),
             Some(Access::tenant(Level::Write))
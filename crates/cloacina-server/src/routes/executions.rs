@@ -25,13 +25,17 @@ use axum::{
 use tracing::{info, warn};
 
 use cloacina::dal::UnifiedRegistryStorage;
-use cloacina::executor::WorkflowExecutor;
 use cloacina::registry::workflow_registry::WorkflowRegistryImpl;
 use cloacina::Context;
 use cloacina_api_types::{
-    ExecuteRequest, ExecuteResponse, ExecutionDetail, ExecutionEvent, ExecutionEventsResponse,
-    ExecutionSummary, ExecutionTasksResponse, ListExecutionsQuery, TaskExecutionDetail,
-    TenantListResponse,
+    AnnotateExecutionRequest, ContextAccessFinding, ContextAccessResponse, ContextKeyChange,
+    ContextKeyWrite, ContextLineageResponse, DispatchLatency, ExecuteOptions, ExecuteRequest,
+    ExecuteResponse, ExecutionAnnotation, ExecutionAnnotationsResponse, ExecutionArtifact,
+    ExecutionArtifactsResponse, ExecutionDetail, ExecutionEvent, ExecutionEventsResponse,
+    ExecutionRecording, ExecutionSummary, ExecutionTasksResponse, ExecutionTree, ExecutionTreeTask,
+    ListExecutionsQuery, QueueStats, QueueStatsQuery, RecordedStep, ResolveTaskRequest,
+    ResolveTaskResponse, SchedulerLoop, TaskAttempt, TaskContextAccess, TaskContextChanges,
+    TaskErrorPayload, TaskExecutionDetail, TenantListResponse, TriggerEvaluation,
 };
 
 use crate::routes::auth::AuthenticatedKey;
@@ -75,6 +79,21 @@ pub async fn execute_workflow(
     let provided_ctx: Option<serde_json::Map<String, serde_json::Value>> =
         body.context.as_ref().and_then(|v| v.as_object()).cloned();
 
+    // Reject malformed labels before anything is scheduled.
+    if let Err(e) = cloacina::models::workflow_execution::validate_labels(&body.labels) {
+        return ApiError::bad_request("invalid_labels", e.to_string()).into_response();
+    }
+    let mut options = match body.options.map(execution_options).transpose() {
+        Ok(options) => options.unwrap_or_default(),
+        Err(e) => return ApiError::bad_request("invalid_options", e).into_response(),
+    };
+    if !body.params.is_empty() {
+        options.params = Some(body.params.into_iter().collect());
+    }
+    if let Err(e) = options.validate() {
+        return ApiError::bad_request("invalid_options", e.to_string()).into_response();
+    }
+
     // Merge provided context if any. Route through the SAME merge the
     // instance fire path uses (CLOACI-T-0859): a `{"$secret": "name"}` value
     // is routed AWAY from the plaintext context into the reserved alias map
@@ -206,7 +225,10 @@ pub async fn execute_workflow(
         }
     };
 
-    match tenant_runner.execute_async(&name, context).await {
+    match tenant_runner
+        .execute_async_with_options(&name, context, &body.labels, &options)
+        .await
+    {
         Ok(execution) => {
             info!(
                 "Executed workflow '{}' for tenant '{}': {}",
@@ -220,14 +242,13 @@ pub async fn execute_workflow(
                 .resolve(&tenant_id, &state.database)
                 .await
             {
-                if let Err(e) = cloacina::dal::DAL::new(db)
+                let dal = cloacina::dal::DAL::new(db);
+                let execution_id = cloacina::database::universal_types::UniversalUuid::from(
+                    execution.execution_id,
+                );
+                if let Err(e) = dal
                     .workflow_execution()
-                    .set_trigger_origin(
-                        cloacina::database::universal_types::UniversalUuid::from(
-                            execution.execution_id,
-                        ),
-                        "manual",
-                    )
+                    .set_trigger_origin(execution_id, "manual")
                     .await
                 {
                     warn!(
@@ -260,7 +281,7 @@ pub async fn execute_workflow(
 /// Default page size for `list_executions` when the client doesn't
 /// specify `?limit=`. Bounded so the response stays small enough for
 /// CLI rendering.
-const DEFAULT_EXECUTIONS_LIMIT: i64 = 100;
+pub(crate) const DEFAULT_EXECUTIONS_LIMIT: i64 = 100;
 /// Hard ceiling on `?limit=` to keep a single response from pulling
 /// the entire `workflow_executions` table.
 const MAX_EXECUTIONS_LIMIT: i64 = 1000;
@@ -270,6 +291,7 @@ const MAX_EXECUTIONS_LIMIT: i64 = 1000;
 /// **CLOACI-T-0594 / API-02:** accepts `?status=Failed` and
 /// `?workflow_name=foo` and `?limit=N&offset=M`. Previously these
 /// query params were silently discarded.
+/// `?label=key=value,…` narrows to executions carrying every listed label.
 #[utoipa::path(
     get,
     path = "/v1/tenants/{tenant_id}/executions",
@@ -280,7 +302,7 @@ const MAX_EXECUTIONS_LIMIT: i64 = 1000;
     ),
     responses(
         (status = 200, description = "Executions page", body = TenantListResponse<ExecutionSummary>),
-        (status = 400, description = "Invalid pagination", body = cloacina_api_types::ErrorBody),
+        (status = 400, description = "Invalid pagination or label selector", body = cloacina_api_types::ErrorBody),
         (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
         (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
         (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
@@ -293,65 +315,102 @@ pub async fn list_executions(
     Path(tenant_id): Path<String>,
     Query(q): Query<ListExecutionsQuery>,
 ) -> impl IntoResponse {
+    match load_execution_summaries(&state, &tenant_id, q).await {
+        // CLOACI-T-0594 / API-03: unified `{items, total}` envelope.
+        // `total` is best-effort — equals the returned page size when
+        // we don't run a separate COUNT (high-cardinality table).
+        Ok(items) => Json(TenantListResponse::new(tenant_id, items)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Validate a list query and load one page of the tenant's executions.
+/// Shared by the tenant list and the federated list.
+pub(crate) async fn load_execution_summaries(
+    state: &AppState,
+    tenant_id: &str,
+    q: ListExecutionsQuery,
+) -> Result<Vec<ExecutionSummary>, ApiError> {
     let limit = q.limit.unwrap_or(DEFAULT_EXECUTIONS_LIMIT);
     if !(1..=MAX_EXECUTIONS_LIMIT).contains(&limit) {
-        return ApiError::bad_request(
+        return Err(ApiError::bad_request(
             "invalid_pagination",
             format!("limit must be 1..={}", MAX_EXECUTIONS_LIMIT),
-        )
-        .into_response();
+        ));
     }
     let offset = q.offset.unwrap_or(0);
     if offset < 0 {
-        return ApiError::bad_request("invalid_pagination", "offset must be >= 0".to_string())
-            .into_response();
+        return Err(ApiError::bad_request(
+            "invalid_pagination",
+            "offset must be >= 0".to_string(),
+        ));
     }
 
-    let tenant_db = match state
+    let tenant_db = state
         .tenant_databases
-        .resolve(&tenant_id, &state.database)
+        .resolve(tenant_id, &state.database)
         .await
-    {
-        Ok(db) => db,
-        Err(e) => {
-            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
-        }
-    };
+        .map_err(|e| ApiError::internal(format!("tenant database error: {}", e)))?;
     let dal = cloacina::dal::DAL::new(tenant_db);
 
+    let labels = match q.label.as_deref() {
+        Some(raw) => cloacina::models::workflow_execution::parse_label_selector(raw)
+            .map_err(|e| ApiError::bad_request("invalid_labels", e.to_string()))?,
+        None => Vec::new(),
+    };
+
     let filter = cloacina::dal::unified::workflow_execution::ExecutionListFilter {
         status: q.status,
         workflow_name: q.workflow,
+        run_id: q.run_id,
+        labels,
         limit,
         offset,
     };
 
-    match dal.workflow_execution().list_filtered(filter).await {
-        Ok(executions) => {
-            let items: Vec<ExecutionSummary> = executions
-                .into_iter()
-                .map(|e| ExecutionSummary {
-                    id: e.id.0.to_string(),
-                    workflow_name: e.workflow_name,
-                    status: e.status,
-                    started_at: e.started_at.0.to_rfc3339(),
-                    completed_at: e.completed_at.map(|t| t.0.to_rfc3339()),
-                    trigger_origin: e.trigger_origin,
-                })
-                .collect();
-            // CLOACI-T-0594 / API-03: unified `{items, total}` envelope.
-            // `total` is best-effort — equals the returned page size when
-            // we don't run a separate COUNT (high-cardinality table).
-            Json(TenantListResponse::new(tenant_id, items)).into_response()
-        }
-        Err(e) => {
+    let executions = dal
+        .workflow_execution()
+        .list_filtered(filter)
+        .await
+        .map_err(|e| {
             warn!(
                 "Failed to list executions for tenant '{}': {}",
                 tenant_id, e
             );
-            ApiError::internal(format!("{}", e)).into_response()
-        }
-    }
+            ApiError::internal(format!("{}", e))
+        })?;
+    let ids: Vec<_> = executions.iter().map(|e| e.id).collect();
+    let mut labels = dal
+        .workflow_execution()
+        .get_labels_for(&ids)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to load execution labels: {}", e);
+            Default::default()
+        });
+    let mut resolutions = dal
+        .workflow_execution()
+        .get_resolutions_for(&ids)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to load execution resolutions: {}", e);
+            Default::default()
+        });
+    Ok(executions
+        .into_iter()
+        .map(|e| ExecutionSummary {
+            id: e.id.0.to_string(),
+            labels: labels.remove(&e.id).unwrap_or_default(),
+            resolution: resolutions.remove(&e.id),
+            workflow_name: e.workflow_name,
+            status: e.status,
+            started_at: e.started_at.0.to_rfc3339(),
+            completed_at: e.completed_at.map(|t| t.0.to_rfc3339()),
+            trigger_origin: e.trigger_origin,
+            run_id: e.run_id,
+            logical_date: e.logical_date.map(|t| t.0.to_rfc3339()),
+        })
+        .collect())
 }
 
 /// GET /tenants/:tenant_id/executions/:id — get execution details.
@@ -399,6 +458,34 @@ pub async fn get_execution(
 
     match dal.workflow_execution().get_by_id(universal_id).await {
         Ok(execution) => {
+            let labels = dal
+                .workflow_execution()
+                .get_labels(universal_id)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Failed to load labels for execution {}: {}", exec_id, e);
+                    Default::default()
+                });
+            let params = dal
+                .workflow_execution()
+                .get_params(universal_id)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Failed to load params for execution {}: {}", exec_id, e);
+                    Default::default()
+                });
+            let annotations = dal
+                .workflow_execution()
+                .list_annotations(universal_id)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "Failed to load annotations for execution {}: {}",
+                        exec_id, e
+                    );
+                    Default::default()
+                });
+            let resolution = annotations.iter().rev().find_map(|a| a.resolution.clone());
             // Pass through the stored status string verbatim. Earlier code
             // had a per-variant match that returned the same value, which
             // was redundant. Trust the producer to write a valid status.
@@ -406,6 +493,10 @@ pub async fn get_execution(
                 tenant_id,
                 execution_id: exec_id,
                 status: execution.status.as_str().to_string(),
+                labels,
+                params: params.into_iter().collect(),
+                resolution,
+                annotations: annotations.into_iter().map(to_annotation_dto).collect(),
             })
             .into_response()
         }
@@ -558,6 +649,7 @@ pub async fn get_execution_tasks(
                     sub_status: t.sub_status,
                     last_error: t.last_error,
                     error_details: t.error_details,
+                    error_payload: t.error_payload.map(to_error_payload_dto),
                 })
                 .collect();
             Json(ExecutionTasksResponse {
@@ -571,154 +663,1224 @@ pub async fn get_execution_tasks(
     }
 }
 
-/// Validate a provided execution context against a workflow's declared input
-/// params (CLOACI-T-0757 / I-0128). v1 checks required-presence and a top-level
-/// JSON-Schema `type` match; returns human-readable error strings (empty =
-/// valid). Full nested JSON-Schema validation is a follow-up — when a slot's
-/// schema has no simple top-level `type` (enums/oneOf/etc.) the value is
-/// accepted rather than rejected.
-pub(crate) fn validate_declared_params(
-    params: &[cloacina_api_types::InputSlot],
-    provided: Option<&serde_json::Map<String, serde_json::Value>>,
-) -> Vec<String> {
-    let mut errors = Vec::new();
-    for p in params {
-        match provided.and_then(|m| m.get(&p.name)) {
-            None => {
-                if p.required && p.default.is_none() {
-                    errors.push(format!("missing required param '{}'", p.name));
-                }
-            }
-            Some(v) => {
-                // CLOACI-T-0859: an encrypted slot (declared via
-                // `secrets(...)`) is bound with a `{"$secret": "name"}`
-                // reference — never a literal value (which would land the
-                // plaintext in the durable context).
-                if p.encrypted {
-                    let is_secret_ref = v
-                        .as_object()
-                        .map(|o| o.len() == 1 && o.get("$secret").is_some_and(|s| s.is_string()))
-                        .unwrap_or(false);
-                    if !is_secret_ref {
-                        errors.push(format!(
-                            "secret '{}' must be bound via {{\"$secret\": \"name\"}}, \
-                             not a literal value",
-                            p.name
-                        ));
-                    }
-                    continue;
-                }
-                if let Some(expected) = p.schema.get("type").and_then(|t| t.as_str()) {
-                    if !json_value_matches_type(v, expected) {
-                        errors.push(format!(
-                            "param '{}' expects type '{}', got {}",
-                            p.name,
-                            expected,
-                            json_type_name(v)
-                        ));
-                    }
-                }
-            }
+/// GET /tenants/:tenant_id/executions/:id/recording — a replayable snapshot
+/// of the execution: its initial context plus each task's status and output
+/// context. Saved to a file, it loads straight into a local
+/// `cloacina::replay::ReplayRunner` for post-mortem debugging.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/recording",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    responses(
+        (status = 200, description = "Execution recording", body = ExecutionRecording),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_execution_recording(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
         }
-    }
-    errors
-}
+    };
 
-/// Validate a single JSON value against one slot's schema (CLOACI-I-0128 T-0759).
-/// Used for surfaces that accept a single value (accumulator inject) rather than
-/// a named map. Returns an error message, or `None` when valid / untyped.
-pub(crate) fn validate_value_against_schema(
-    value: &serde_json::Value,
-    schema: &serde_json::Value,
-) -> Option<String> {
-    if let Some(expected) = schema.get("type").and_then(|t| t.as_str()) {
-        if !json_value_matches_type(value, expected) {
-            return Some(format!(
-                "expects type '{}', got {}",
-                expected,
-                json_type_name(value)
-            ));
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    match cloacina::replay::ExecutionRecording::load(&dal, universal_id).await {
+        Ok(recording) => Json(to_recording_dto(recording)).into_response(),
+        Err(cloacina::replay::ReplayError::Load(e)) => {
+            ApiError::not_found("execution_not_found", format!("{}", e)).into_response()
         }
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
     }
-    None
 }
 
-pub(crate) fn json_value_matches_type(v: &serde_json::Value, expected: &str) -> bool {
-    match expected {
-        "string" => v.is_string(),
-        "integer" => v.is_i64() || v.is_u64(),
-        "number" => v.is_number(),
-        "boolean" => v.is_boolean(),
-        "array" => v.is_array(),
-        "object" => v.is_object(),
-        "null" => v.is_null(),
-        // Unknown/compound schema type — don't reject (v1 limitation).
-        _ => true,
+fn execution_options(o: ExecuteOptions) -> Result<cloacina::runner::ExecutionOptions, String> {
+    let logical_date = o
+        .logical_date
+        .map(|raw| {
+            chrono::DateTime::parse_from_rfc3339(&raw)
+                .map(|d| d.with_timezone(&chrono::Utc))
+                .map_err(|e| format!("logical_date '{}' is not RFC 3339: {}", raw, e))
+        })
+        .transpose()?;
+    let deadline = o
+        .deadline
+        .map(|raw| {
+            chrono::DateTime::parse_from_rfc3339(&raw)
+                .map(|d| d.with_timezone(&chrono::Utc))
+                .map_err(|e| format!("deadline '{}' is not RFC 3339: {}", raw, e))
+        })
+        .transpose()?;
+    Ok(cloacina::runner::ExecutionOptions {
+        max_parallelism: o.max_parallelism.map(|n| n as usize),
+        priority: o.priority,
+        max_attempts: o.max_attempts,
+        retry_delay_multiplier: o.retry_delay_multiplier,
+        task_timeout: o.task_timeout_secs.map(std::time::Duration::from_secs),
+        // The endpoint returns at once; nothing waits on the execution.
+        workflow_timeout: None,
+        deadline,
+        run_id: o.run_id,
+        logical_date,
+        // Set from the request's `params`, not its options.
+        params: None,
+    })
+}
+
+fn to_recording_dto(r: cloacina::replay::ExecutionRecording) -> ExecutionRecording {
+    ExecutionRecording {
+        execution_id: r.execution_id,
+        workflow_name: r.workflow_name,
+        workflow_version: r.workflow_version,
+        status: r.status,
+        initial_context: r.initial_context,
+        steps: r
+            .steps
+            .into_iter()
+            .map(|s| RecordedStep {
+                task_name: s.task_name,
+                status: s.status,
+                attempt: s.attempt,
+                started_at: s.started_at.map(|ts| ts.to_rfc3339()),
+                completed_at: s.completed_at.map(|ts| ts.to_rfc3339()),
+                error: s.error,
+                error_payload: s.error_payload.map(to_error_payload_dto),
+                output: s.output,
+            })
+            .collect(),
     }
 }
 
-pub(crate) fn json_type_name(v: &serde_json::Value) -> &'static str {
-    match v {
-        serde_json::Value::Null => "null",
-        serde_json::Value::Bool(_) => "boolean",
-        serde_json::Value::Number(_) => "number",
-        serde_json::Value::String(_) => "string",
-        serde_json::Value::Array(_) => "array",
-        serde_json::Value::Object(_) => "object",
+fn to_error_payload_dto(p: cloacina::TaskErrorPayload) -> TaskErrorPayload {
+    TaskErrorPayload {
+        code: p.code,
+        category: p.category.as_str().to_string(),
+        message: p.message,
+        details: (!p.details.is_null()).then_some(p.details),
     }
 }
 
-#[cfg(test)]
-mod input_validation_tests {
-    use super::*;
-    use cloacina::input_interface::schema_for;
-    use cloacina_api_types::InputSlot;
+/// GET /tenants/:tenant_id/executions/:id/tree — the execution's task tree
+/// with per-attempt timings, errors, retries, trigger outcomes and context
+/// ids. One call gives UIs and `cloacinactl execution show` everything they
+/// render.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/tree",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    responses(
+        (status = 200, description = "Execution task tree", body = ExecutionTree),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_execution_tree(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
+        }
+    };
 
-    fn obj(pairs: &[(&str, serde_json::Value)]) -> serde_json::Map<String, serde_json::Value> {
-        pairs
-            .iter()
-            .map(|(k, v)| (k.to_string(), v.clone()))
-            .collect()
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    match cloacina::execution_detail::ExecutionDetail::load(&dal, universal_id).await {
+        Ok(detail) => Json(to_tree_dto(tenant_id, detail)).into_response(),
+        Err(e) => execution_lookup_error(e).into_response(),
     }
+}
 
-    #[test]
-    fn missing_required_param_errors() {
-        let params = vec![InputSlot::required("order_id", schema_for::<String>())];
-        let errors = validate_declared_params(&params, Some(&obj(&[])));
-        assert_eq!(errors.len(), 1);
-        assert!(errors[0].contains("order_id"));
+fn to_tree_dto(tenant_id: String, d: cloacina::execution_detail::ExecutionDetail) -> ExecutionTree {
+    let rfc3339 = |ts: chrono::DateTime<chrono::Utc>| ts.to_rfc3339();
+    ExecutionTree {
+        tenant_id,
+        execution_id: d.execution_id.to_string(),
+        workflow_name: d.workflow_name,
+        workflow_version: d.workflow_version,
+        status: d.status,
+        started_at: rfc3339(d.started_at),
+        completed_at: d.completed_at.map(rfc3339),
+        duration_ms: d.duration_ms,
+        error_details: d.error_details,
+        trigger_origin: d.trigger_origin,
+        run_id: d.run_id,
+        logical_date: d.logical_date.map(rfc3339),
+        paused_at: d.paused_at.map(rfc3339),
+        pause_reason: d.pause_reason,
+        recovery_attempts: d.recovery_attempts,
+        context_id: d.context_id.map(|id| id.to_string()),
+        labels: d.labels,
+        tasks: d
+            .tasks
+            .into_iter()
+            .map(|t| ExecutionTreeTask {
+                task_execution_id: t.task_execution_id.to_string(),
+                task_name: t.task_name,
+                status: t.status,
+                sub_status: t.sub_status,
+                created_at: rfc3339(t.created_at),
+                started_at: t.started_at.map(rfc3339),
+                completed_at: t.completed_at.map(rfc3339),
+                attempt: t.attempt,
+                max_attempts: t.max_attempts,
+                retry_at: t.retry_at.map(rfc3339),
+                last_error: t.last_error,
+                error_payload: t.error_payload.map(to_error_payload_dto),
+                trigger_rules: t.trigger_rules,
+                trigger_evaluation: t.trigger_evaluation.map(|e| TriggerEvaluation {
+                    satisfied: e.satisfied,
+                    evaluated_at: rfc3339(e.evaluated_at),
+                    reason: e.reason,
+                }),
+                attempts: t
+                    .attempts
+                    .into_iter()
+                    .map(|a| TaskAttempt {
+                        attempt: a.attempt,
+                        started_at: rfc3339(a.started_at),
+                        ended_at: a.ended_at.map(rfc3339),
+                        duration_ms: a.duration_ms,
+                        outcome: a.outcome.as_str().to_string(),
+                        worker_id: a.worker_id,
+                        error: a.error,
+                        retry_at: a.retry_at.map(rfc3339),
+                        iterations: a.iterations,
+                    })
+                    .collect(),
+                output_context_id: t.output_context_id.map(|id| id.to_string()),
+                resolution_note: t.resolution_note,
+            })
+            .collect(),
     }
+}
 
-    #[test]
-    fn optional_with_default_omitted_is_ok() {
-        let params = vec![InputSlot::optional(
-            "limit",
-            schema_for::<u32>(),
-            Some(serde_json::json!(100)),
-        )];
-        assert!(validate_declared_params(&params, Some(&obj(&[]))).is_empty());
+/// Maps a failed execution lookup onto a response: 404 only when the row
+/// does not exist, 500 for anything else (pool, query, or decode errors).
+fn execution_lookup_error(e: cloacina::error::ValidationError) -> ApiError {
+    match e {
+        cloacina::error::ValidationError::Database(diesel::result::Error::NotFound) => {
+            ApiError::not_found("execution_not_found", "execution not found")
+        }
+        other => ApiError::internal(format!("{}", other)),
     }
+}
 
-    #[test]
-    fn wrong_type_errors() {
-        let params = vec![InputSlot::required("order_id", schema_for::<String>())];
-        let provided = obj(&[("order_id", serde_json::json!(42))]);
-        let errors = validate_declared_params(&params, Some(&provided));
-        assert_eq!(errors.len(), 1, "{:?}", errors);
-        assert!(errors[0].contains("string"));
+fn to_artifact_dto(
+    a: cloacina::models::workflow_execution::ExecutionArtifact,
+) -> ExecutionArtifact {
+    ExecutionArtifact {
+        id: a.id.0.to_string(),
+        name: a.name,
+        content_type: a.content_type,
+        size_bytes: a.size_bytes,
+        task_name: a.task_name,
+        task_execution_id: a.task_execution_id.map(|id| id.0.to_string()),
+        created_at: a.created_at.0.to_rfc3339(),
     }
+}
 
-    #[test]
-    fn correct_input_passes() {
-        let params = vec![
-            InputSlot::required("order_id", schema_for::<String>()),
-            InputSlot::optional("limit", schema_for::<u32>(), Some(serde_json::json!(100))),
-        ];
-        let provided = obj(&[
-            ("order_id", serde_json::json!("A-1")),
-            ("limit", serde_json::json!(5)),
-        ]);
-        assert!(validate_declared_params(&params, Some(&provided)).is_empty());
+fn to_annotation_dto(
+    a: cloacina::models::workflow_execution::ExecutionAnnotation,
+) -> ExecutionAnnotation {
+    ExecutionAnnotation {
+        id: a.id.0.to_string(),
+        author: a.author,
+        note: a.note,
+        resolution: a.resolution,
+        created_at: a.created_at.0.to_rfc3339(),
+    }
+}
+
+/// POST /tenants/:tenant_id/executions/:id/annotations — record an operator
+/// note (and optionally a resolution status) on an execution.
+///
+/// The author is the name of the calling API key, so incident context stays
+/// attributable without the client asserting who it is.
+#[utoipa::path(
+    post,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/annotations",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    request_body = AnnotateExecutionRequest,
+    responses(
+        (status = 201, description = "Annotation recorded", body = ExecutionAnnotation),
+        (status = 400, description = "Invalid execution ID, note, or resolution", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn annotate_execution(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+    Json(body): Json<AnnotateExecutionRequest>,
+) -> impl IntoResponse {
+    use cloacina::models::workflow_execution::{ExecutionResolution, NewExecutionAnnotation};
+
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
+        }
+    };
+
+    let mut annotation = NewExecutionAnnotation::new(auth.name, body.note);
+    if let Some(raw) = body.resolution.as_deref() {
+        match ExecutionResolution::from_str(raw) {
+            Some(resolution) => annotation = annotation.with_resolution(resolution),
+            None => {
+                let message = format!(
+                    "unknown resolution '{}' (expected investigating, resolved, rerun or ignored)",
+                    raw
+                );
+                return ApiError::bad_request("invalid_annotation", message).into_response();
+            }
+        }
+    }
+    if let Err(e) = annotation.validate() {
+        return ApiError::bad_request("invalid_annotation", e.to_string()).into_response();
+    }
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    if let Err(e) = dal.workflow_execution().get_by_id(universal_id).await {
+        return execution_lookup_error(e).into_response();
+    }
+
+    match dal
+        .workflow_execution()
+        .add_annotation(universal_id, annotation)
+        .await
+    {
+        Ok(a) => {
+            info!(
+                "Annotated execution {} for tenant '{}' (resolution: {:?})",
+                exec_id, tenant_id, a.resolution
+            );
+            (StatusCode::CREATED, Json(to_annotation_dto(a))).into_response()
+        }
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
+
+/// GET /tenants/:tenant_id/executions/:id/annotations — operator notes on an
+/// execution, oldest first.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/annotations",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    responses(
+        (status = 200, description = "Execution annotations", body = ExecutionAnnotationsResponse),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_execution_annotations(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
+        }
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    match dal
+        .workflow_execution()
+        .list_annotations(universal_id)
+        .await
+    {
+        Ok(annotations) => Json(ExecutionAnnotationsResponse {
+            tenant_id,
+            execution_id: exec_id,
+            annotations: annotations.into_iter().map(to_annotation_dto).collect(),
+        })
+        .into_response(),
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
+
+/// POST /tenants/:tenant_id/executions/:id/tasks/:task/resolve — mark a
+/// failed task successful without re-running it.
+///
+/// Downstream tasks that were skipped because of the failure are scheduled
+/// again and a failed execution is set back to running. The note is recorded
+/// as a `resolved` annotation authored by the calling API key, so the
+/// override stays auditable. `task` is the task's local or fully qualified
+/// name.
+#[utoipa::path(
+    post,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/tasks/{task}/resolve",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+        ("task" = String, Path, description = "Task name (local or fully qualified)"),
+    ),
+    request_body = ResolveTaskRequest,
+    responses(
+        (status = 200, description = "Task marked successful", body = ResolveTaskResponse),
+        (status = 400, description = "Invalid execution ID or note", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+        (status = 409, description = "Task is not failed or cannot be resolved", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn resolve_task(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id, task)): Path<(String, String, String)>,
+    Json(body): Json<ResolveTaskRequest>,
+) -> impl IntoResponse {
+    use cloacina::models::workflow_execution::NewExecutionAnnotation;
+    use cloacina::WorkflowExecutionError;
+
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
+        }
+    };
+
+    let annotation = NewExecutionAnnotation::new(auth.name, body.note);
+    if let Err(e) = annotation.validate() {
+        return ApiError::bad_request("invalid_annotation", e.to_string()).into_response();
+    }
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    let dal = cloacina::dal::DAL::new(tenant_db.clone());
+    if let Err(e) = dal.workflow_execution().get_by_id(universal_id).await {
+        return execution_lookup_error(e).into_response();
+    }
+
+    // Same runner selection as execute_workflow: the global runner owns the
+    // public schema, other tenants use their cached schema-scoped runner.
+    let runner = if tenant_id == "public" {
+        state.runner.clone()
+    } else {
+        match state
+            .tenant_runners
+            .get_or_create(&tenant_id, tenant_db)
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("Failed to acquire tenant runner for '{}': {}", tenant_id, e);
+                return ApiError::internal(format!("tenant runner unavailable: {}", e))
+                    .into_response();
+            }
+        }
+    };
+
+    match runner
+        .resolve_failed_task(universal_id, &task, annotation)
+        .await
+    {
+        Ok(r) => {
+            info!(
+                "Resolved task '{}' of execution {} for tenant '{}' ({} task(s) reopened)",
+                r.task_name,
+                exec_id,
+                tenant_id,
+                r.reopened_tasks.len()
+            );
+            Json(ResolveTaskResponse {
+                tenant_id,
+                execution_id: exec_id,
+                task_execution_id: r.task_execution_id.to_string(),
+                task_name: r.task_name,
+                reopened_tasks: r.reopened_tasks,
+                execution_reopened: r.execution_reopened,
+                annotation: to_annotation_dto(r.annotation),
+            })
+            .into_response()
+        }
+        Err(WorkflowExecutionError::Configuration { message }) => {
+            ApiError::new(StatusCode::CONFLICT, "task_not_resolvable", message).into_response()
+        }
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
+
+/// GET /tenants/:tenant_id/executions/:id/context-access — context keys each
+/// task read and wrote, with findings against the workflow's dependencies.
+///
+/// Records exist only for attempts run while the server had
+/// `--trace-context-access` enabled. Findings need the workflow loaded in the
+/// tenant's runner and are empty otherwise.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/context-access",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    responses(
+        (status = 200, description = "Context access report", body = ContextAccessResponse),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_context_access(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    use cloacina::runner::ContextAccessFinding as Finding;
+
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
+        }
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    let dal = cloacina::dal::DAL::new(tenant_db.clone());
+    if let Err(e) = dal.workflow_execution().get_by_id(universal_id).await {
+        return execution_lookup_error(e).into_response();
+    }
+
+    // Same runner selection as execute_workflow.
+    let runner = if tenant_id == "public" {
+        state.runner.clone()
+    } else {
+        match state
+            .tenant_runners
+            .get_or_create(&tenant_id, tenant_db)
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("Failed to acquire tenant runner for '{}': {}", tenant_id, e);
+                return ApiError::internal(format!("tenant runner unavailable: {}", e))
+                    .into_response();
+            }
+        }
+    };
+
+    match runner.get_context_access(universal_id).await {
+        Ok(report) => Json(ContextAccessResponse {
+            tenant_id,
+            execution_id: exec_id,
+            workflow_name: report.workflow_name,
+            tasks: report
+                .tasks
+                .into_iter()
+                .map(|t| TaskContextAccess {
+                    task_execution_id: t.task_execution_id.to_string(),
+                    task_name: t.task_name,
+                    attempt: t.attempt,
+                    reads: t.reads,
+                    writes: t.writes,
+                    recorded_at: t.recorded_at.0.to_rfc3339(),
+                })
+                .collect(),
+            findings: report
+                .findings
+                .into_iter()
+                .map(|f| match f {
+                    Finding::MissingDependency {
+                        task,
+                        key,
+                        producer,
+                    } => ContextAccessFinding {
+                        kind: "missing_dependency".to_string(),
+                        task,
+                        key: Some(key),
+                        producer: Some(producer),
+                        dependency: None,
+                    },
+                    Finding::UnusedDependency { task, dependency } => ContextAccessFinding {
+                        kind: "unused_dependency".to_string(),
+                        task,
+                        key: None,
+                        producer: None,
+                        dependency: Some(dependency),
+                    },
+                })
+                .collect(),
+        })
+        .into_response(),
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
+
+/// GET /tenants/:tenant_id/executions/:id/context-lineage — which task
+/// changed each context key, with previous and new values.
+///
+/// Records exist only for attempts run while the server had
+/// `--track-context-lineage` enabled.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/context-lineage",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    responses(
+        (status = 200, description = "Context lineage", body = ContextLineageResponse),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_context_lineage(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    use cloacina::context_lineage::ContextChangeKind;
+
+    let id = match uuid::Uuid::parse_str(&exec_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ApiError::bad_request("invalid_request", "invalid execution ID").into_response()
+        }
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    let dal = cloacina::dal::DAL::new(tenant_db.clone());
+    if let Err(e) = dal.workflow_execution().get_by_id(universal_id).await {
+        return execution_lookup_error(e).into_response();
+    }
+
+    // Same runner selection as execute_workflow.
+    let runner = if tenant_id == "public" {
+        state.runner.clone()
+    } else {
+        match state
+            .tenant_runners
+            .get_or_create(&tenant_id, tenant_db)
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("Failed to acquire tenant runner for '{}': {}", tenant_id, e);
+                return ApiError::internal(format!("tenant runner unavailable: {}", e))
+                    .into_response();
+            }
+        }
+    };
+
+    let kind = |kind: ContextChangeKind| {
+        match kind {
+            ContextChangeKind::Added => "added",
+            ContextChangeKind::Modified => "modified",
+            ContextChangeKind::Removed => "removed",
+        }
+        .to_string()
+    };
+
+    match runner.get_context_lineage(universal_id).await {
+        Ok(lineage) => Json(ContextLineageResponse {
+            tenant_id,
+            execution_id: exec_id,
+            workflow_name: lineage.workflow_name,
+            tasks: lineage
+                .tasks
+                .into_iter()
+                .map(|t| TaskContextChanges {
+                    task_execution_id: t.task_execution_id.to_string(),
+                    task_name: t.task_name,
+                    attempt: t.attempt,
+                    changes: t
+                        .changes
+                        .into_iter()
+                        .map(|c| ContextKeyChange {
+                            key: c.key,
+                            kind: kind(c.kind),
+                            previous: c.previous,
+                            value: c.value,
+                        })
+                        .collect(),
+                    recorded_at: t.recorded_at.0.to_rfc3339(),
+                })
+                .collect(),
+            keys: lineage
+                .keys
+                .into_iter()
+                .map(|(key, writes)| {
+                    let writes = writes
+                        .into_iter()
+                        .map(|w| ContextKeyWrite {
+                            task_name: w.task_name,
+                            attempt: w.attempt,
+                            kind: kind(w.kind),
+                            previous: w.previous,
+                            value: w.value,
+                            recorded_at: w.recorded_at.0.to_rfc3339(),
+                        })
+                        .collect();
+                    (key, writes)
+                })
+                .collect(),
+        })
+        .into_response(),
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
+
+/// The runner serving `tenant_id` and the id of its execution `exec_id`,
+/// which must exist. Same runner selection as execute_workflow: the runner's
+/// database carries the artifact store it was configured with.
+async fn execution_runner(
+    state: &AppState,
+    tenant_id: &str,
+    exec_id: &str,
+) -> Result<
+    (
+        std::sync::Arc<cloacina::runner::DefaultRunner>,
+        cloacina::database::universal_types::UniversalUuid,
+    ),
+    ApiError,
+> {
+    let id = uuid::Uuid::parse_str(exec_id)
+        .map_err(|_| ApiError::bad_request("invalid_request", "invalid execution ID"))?;
+    let tenant_db = state
+        .tenant_databases
+        .resolve(tenant_id, &state.database)
+        .await
+        .map_err(|e| ApiError::internal(format!("tenant database error: {}", e)))?;
+    let universal_id = cloacina::database::universal_types::UniversalUuid(id);
+
+    let dal = cloacina::dal::DAL::new(tenant_db.clone());
+    dal.workflow_execution()
+        .get_by_id(universal_id)
+        .await
+        .map_err(execution_lookup_error)?;
+
+    let runner = if tenant_id == "public" {
+        state.runner.clone()
+    } else {
+        state
+            .tenant_runners
+            .get_or_create(tenant_id, tenant_db)
+            .await
+            .map_err(|e| {
+                warn!("Failed to acquire tenant runner for '{}': {}", tenant_id, e);
+                ApiError::internal(format!("tenant runner unavailable: {}", e))
+            })?
+    };
+    Ok((runner, universal_id))
+}
+
+/// GET /tenants/:tenant_id/executions/:id/artifacts — files the
+/// execution's tasks stored.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/artifacts",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+    ),
+    responses(
+        (status = 200, description = "Execution artifacts", body = ExecutionArtifactsResponse),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_artifacts(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let (runner, universal_id) = match execution_runner(&state, &tenant_id, &exec_id).await {
+        Ok(found) => found,
+        Err(e) => return e.into_response(),
+    };
+
+    match runner.get_execution_artifacts(universal_id).await {
+        Ok(artifacts) => Json(ExecutionArtifactsResponse {
+            tenant_id,
+            execution_id: exec_id,
+            artifacts: artifacts.into_iter().map(to_artifact_dto).collect(),
+        })
+        .into_response(),
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
+
+/// GET /tenants/:tenant_id/executions/:id/artifacts/:name — download one
+/// artifact, served with the content type its task stored it with.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/executions/{exec_id}/artifacts/{name}",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("exec_id" = String, Path, description = "Execution UUID"),
+        ("name" = String, Path, description = "Artifact name"),
+    ),
+    responses(
+        (status = 200, description = "Artifact bytes", body = Vec<u8>, content_type = "application/octet-stream"),
+        (status = 400, description = "Invalid execution ID", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Execution or artifact not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn download_artifact(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, exec_id, name)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    use axum::http::header;
+
+    let (runner, universal_id) = match execution_runner(&state, &tenant_id, &exec_id).await {
+        Ok(found) => found,
+        Err(e) => return e.into_response(),
+    };
+
+    let (artifact, bytes) = match runner.read_execution_artifact(universal_id, &name).await {
+        Ok(Some(found)) => found,
+        Ok(None) => {
+            return ApiError::not_found(
+                "artifact_not_found",
+                format!("execution has no artifact '{}'", name),
+            )
+            .into_response()
+        }
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+    // Non-ASCII names cannot go in a plain header; the client names the file.
+    let disposition = if artifact.name.is_ascii() {
+        format!(
+            "attachment; filename=\"{}\"",
+            artifact.name.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    } else {
+        "attachment".to_string()
+    };
+    axum::response::Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, artifact.content_type)
+        .header(header::CONTENT_DISPOSITION, disposition)
+        .body(axum::body::Body::from(bytes))
+        .unwrap_or_else(|e| {
+            ApiError::internal(format!("build artifact response: {}", e)).into_response()
+        })
+}
+
+/// Default `?window_secs=` for queue stats: one hour.
+const DEFAULT_QUEUE_STATS_WINDOW_SECS: u64 = 3600;
+/// Hard ceiling on `?window_secs=`: 30 days of claim events.
+const MAX_QUEUE_STATS_WINDOW_SECS: u64 = 30 * 24 * 3600;
+
+/// GET /tenants/:tenant_id/stats/queue — claim queue depth, time-to-dispatch
+/// over `?window_secs=` and scheduler loop latency.
+///
+/// Queue depth and dispatch latency are read from the tenant's database.
+/// Loop latency is only known for a scheduler running in this server: the
+/// global runner for `public`, or an already-cached tenant runner. Reading
+/// stats never starts a tenant runner.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/stats/queue",
+    tag = "executions",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        QueueStatsQuery,
+    ),
+    responses(
+        (status = 200, description = "Queue stats", body = QueueStats),
+        (status = 400, description = "Invalid window", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_queue_stats(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path(tenant_id): Path<String>,
+    Query(q): Query<QueueStatsQuery>,
+) -> impl IntoResponse {
+    let window_secs = q.window_secs.unwrap_or(DEFAULT_QUEUE_STATS_WINDOW_SECS);
+    if !(1..=MAX_QUEUE_STATS_WINDOW_SECS).contains(&window_secs) {
+        return ApiError::bad_request(
+            "invalid_window",
+            format!("window_secs must be 1..={}", MAX_QUEUE_STATS_WINDOW_SECS),
+        )
+        .into_response();
+    }
+    let window = std::time::Duration::from_secs(window_secs);
+
+    let runner = if tenant_id == "public" {
+        Some(state.runner.clone())
+    } else {
+        state.tenant_runners.get(&tenant_id).await
+    };
+    let stats = match runner {
+        Some(runner) => runner
+            .queue_stats(window)
+            .await
+            .map(QueueStatsParts::from)
+            .map_err(|e| e.to_string()),
+        None => load_queue_stats(&state, &tenant_id, window).await,
+    };
+
+    match stats {
+        Ok(stats) => Json(to_queue_stats_dto(tenant_id, window_secs, stats)).into_response(),
+        Err(e) => ApiError::internal(e).into_response(),
+    }
+}
+
+/// Database-only queue stats for a tenant without a local scheduler.
+async fn load_queue_stats(
+    state: &AppState,
+    tenant_id: &str,
+    window: std::time::Duration,
+) -> Result<QueueStatsParts, String> {
+    let tenant_db = state
+        .tenant_databases
+        .resolve(tenant_id, &state.database)
+        .await
+        .map_err(|e| format!("tenant database error: {}", e))?;
+    let dal = cloacina::dal::DAL::new(tenant_db);
+    let stats = dal.execution_stats();
+    let since = chrono::Utc::now() - chrono::Duration::from_std(window).unwrap_or_default();
+    Ok(QueueStatsParts {
+        ready_queue: stats.ready_queue().await.map_err(|e| e.to_string())?,
+        dispatch_latency: stats
+            .dispatch_latency(since)
+            .await
+            .map_err(|e| e.to_string())?,
+        scheduler_loop: None,
+    })
+}
+
+/// Queue stats with loop latency optional, so both sources map the same way.
+struct QueueStatsParts {
+    ready_queue: cloacina::dal::ReadyQueueStats,
+    dispatch_latency: cloacina::dal::DispatchLatencyStats,
+    scheduler_loop: Option<cloacina::execution_planner::SchedulerLoopStats>,
+}
+
+impl From<cloacina::runner::QueueStats> for QueueStatsParts {
+    fn from(s: cloacina::runner::QueueStats) -> Self {
+        Self {
+            ready_queue: s.ready_queue,
+            dispatch_latency: s.dispatch_latency,
+            scheduler_loop: Some(s.scheduler_loop),
+        }
+    }
+}
+
+fn to_queue_stats_dto(tenant_id: String, window_secs: u64, s: QueueStatsParts) -> QueueStats {
+    let ms = |d: std::time::Duration| d.as_millis() as u64;
+    QueueStats {
+        tenant_id,
+        window_secs,
+        ready: s.ready_queue.ready,
+        backing_off: s.ready_queue.backing_off,
+        oldest_ready_at: s.ready_queue.oldest_ready_at.map(|t| t.to_rfc3339()),
+        dispatch: DispatchLatency {
+            dispatched: s.dispatch_latency.dispatched,
+            avg_ms: s.dispatch_latency.avg_ms,
+            p95_ms: s.dispatch_latency.p95_ms,
+            max_ms: s.dispatch_latency.max_ms,
+        },
+        scheduler_loop: s.scheduler_loop.map(|l| SchedulerLoop {
+            poll_interval_ms: ms(l.poll_interval),
+            ticks: l.ticks,
+            failed_ticks: l.failed_ticks,
+            last_tick_at: l.last_tick_at.map(|t| t.to_rfc3339()),
+            last_ms: l.last_duration.map(ms),
+            avg_ms: l.avg_duration.map(ms),
+            p95_ms: l.p95_duration.map(ms),
+            max_ms: l.max_duration.map(ms),
+        }),
+    }
+}
+
+/// Validate a provided execution context against a workflow's declared input
+/// params (CLOACI-T-0757 / I-0128). v1 checks required-presence and a top-level
+/// JSON-Schema `type` match; returns human-readable error strings (empty =
+/// valid). Full nested JSON-Schema validation is a follow-up — when a slot's
+/// schema has no simple top-level `type` (enums/oneOf/etc.) the value is
+/// accepted rather than rejected.
+pub(crate) fn validate_declared_params(
+    params: &[cloacina_api_types::InputSlot],
+    provided: Option<&serde_json::Map<String, serde_json::Value>>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for p in params {
+        match provided.and_then(|m| m.get(&p.name)) {
+            None => {
+                if p.required && p.default.is_none() {
+                    errors.push(format!("missing required param '{}'", p.name));
+                }
+            }
+            Some(v) => {
+                // CLOACI-T-0859: an encrypted slot (declared via
+                // `secrets(...)`) is bound with a `{"$secret": "name"}`
+                // reference — never a literal value (which would land the
+                // plaintext in the durable context).
+                if p.encrypted {
+                    let is_secret_ref = v
+                        .as_object()
+                        .map(|o| o.len() == 1 && o.get("$secret").is_some_and(|s| s.is_string()))
+                        .unwrap_or(false);
+                    if !is_secret_ref {
+                        errors.push(format!(
+                            "secret '{}' must be bound via {{\"$secret\": \"name\"}}, \
+                             not a literal value",
+                            p.name
+                        ));
+                    }
+                    continue;
+                }
+                if let Some(expected) = p.schema.get("type").and_then(|t| t.as_str()) {
+                    if !json_value_matches_type(v, expected) {
+                        errors.push(format!(
+                            "param '{}' expects type '{}', got {}",
+                            p.name,
+                            expected,
+                            json_type_name(v)
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// Validate a single JSON value against one slot's schema (CLOACI-I-0128 T-0759).
+/// Used for surfaces that accept a single value (accumulator inject) rather than
+/// a named map. Returns an error message, or `None` when valid / untyped.
+pub(crate) fn validate_value_against_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+) -> Option<String> {
+    if let Some(expected) = schema.get("type").and_then(|t| t.as_str()) {
+        if !json_value_matches_type(value, expected) {
+            return Some(format!(
+                "expects type '{}', got {}",
+                expected,
+                json_type_name(value)
+            ));
+        }
+    }
+    None
+}
+
+pub(crate) fn json_value_matches_type(v: &serde_json::Value, expected: &str) -> bool {
+    match expected {
+        "string" => v.is_string(),
+        "integer" => v.is_i64() || v.is_u64(),
+        "number" => v.is_number(),
+        "boolean" => v.is_boolean(),
+        "array" => v.is_array(),
+        "object" => v.is_object(),
+        "null" => v.is_null(),
+        // Unknown/compound schema type — don't reject (v1 limitation).
+        _ => true,
+    }
+}
+
+pub(crate) fn json_type_name(v: &serde_json::Value) -> &'static str {
+    match v {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod input_validation_tests {
+    use super::*;
+    use cloacina::input_interface::schema_for;
+    use cloacina_api_types::InputSlot;
+
+    fn obj(pairs: &[(&str, serde_json::Value)]) -> serde_json::Map<String, serde_json::Value> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn missing_required_param_errors() {
+        let params = vec![InputSlot::required("order_id", schema_for::<String>())];
+        let errors = validate_declared_params(&params, Some(&obj(&[])));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("order_id"));
+    }
+
+    #[test]
+    fn optional_with_default_omitted_is_ok() {
+        let params = vec![InputSlot::optional(
+            "limit",
+            schema_for::<u32>(),
+            Some(serde_json::json!(100)),
+        )];
+        assert!(validate_declared_params(&params, Some(&obj(&[]))).is_empty());
+    }
+
+    #[test]
+    fn wrong_type_errors() {
+        let params = vec![InputSlot::required("order_id", schema_for::<String>())];
+        let provided = obj(&[("order_id", serde_json::json!(42))]);
+        let errors = validate_declared_params(&params, Some(&provided));
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(errors[0].contains("string"));
+    }
+
+    #[test]
+    fn correct_input_passes() {
+        let params = vec![
+            InputSlot::required("order_id", schema_for::<String>()),
+            InputSlot::optional("limit", schema_for::<u32>(), Some(serde_json::json!(100))),
+        ];
+        let provided = obj(&[
+            ("order_id", serde_json::json!("A-1")),
+            ("limit", serde_json::json!(5)),
+        ]);
+        assert!(validate_declared_params(&params, Some(&provided)).is_empty());
     }
 
     #[test]
@@ -743,4 +1905,16 @@ mod input_validation_tests {
         assert!(validate_value_against_schema(&serde_json::json!(42), &any).is_none());
         assert!(validate_value_against_schema(&serde_json::json!("x"), &any).is_none());
     }
+
+    #[test]
+    fn execution_lookup_only_maps_missing_rows_to_not_found() {
+        use cloacina::error::ValidationError;
+
+        let missing =
+            execution_lookup_error(ValidationError::Database(diesel::result::Error::NotFound));
+        assert_eq!(missing.status, axum::http::StatusCode::NOT_FOUND);
+
+        let pool = execution_lookup_error(ValidationError::ConnectionPool("timed out".into()));
+        assert_eq!(pool.status, axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
 }
@@ -0,0 +1,125 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Multi-region federation routes — the unified execution view and the
+//! region roster. See [`crate::federation`] for the model.
+//!
+//! Both routes answer 404 `federation_not_configured` when the server was
+//! started without `--federation-config`.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Extension, Json,
+};
+
+use cloacina_api_types::{
+    FederatedExecutionsResponse, FederationRegionsResponse, ListExecutionsQuery,
+};
+
+use crate::federation::{merge_federated, Federation};
+use crate::routes::auth::AuthenticatedKey;
+use crate::routes::error::ApiError;
+use crate::routes::executions::{load_execution_summaries, DEFAULT_EXECUTIONS_LIMIT};
+use crate::AppState;
+
+fn federation(state: &AppState) -> Result<Arc<Federation>, ApiError> {
+    state.federation.clone().ok_or_else(|| {
+        ApiError::not_found(
+            "federation_not_configured",
+            "this server is not part of a federation (no --federation-config)",
+        )
+    })
+}
+
+/// GET /tenants/:tenant_id/federation/executions — executions across regions.
+///
+/// Takes the same filters as the tenant executions list. Each region applies
+/// them (including `offset`) to its own executions; the merged result is
+/// newest-first and capped at `limit`. Peers that fail are listed in
+/// `unreachable` rather than failing the request.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/federation/executions",
+    tag = "federation",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ListExecutionsQuery,
+    ),
+    responses(
+        (status = 200, description = "Executions from every reachable region", body = FederatedExecutionsResponse),
+        (status = 400, description = "Invalid pagination or label selector", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Federation not configured", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_federated_executions(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path(tenant_id): Path<String>,
+    Query(q): Query<ListExecutionsQuery>,
+) -> impl IntoResponse {
+    let federation = match federation(&state) {
+        Ok(f) => f,
+        Err(e) => return e.into_response(),
+    };
+    let limit = q.limit.unwrap_or(DEFAULT_EXECUTIONS_LIMIT);
+    // Local first: it validates the query before anything goes to a peer.
+    let local = match load_execution_summaries(&state, &tenant_id, q.clone()).await {
+        Ok(items) => items,
+        Err(e) => return e.into_response(),
+    };
+    let (peers, unreachable) = federation.list_peer_executions(&tenant_id, &q).await;
+    let items = merge_federated(federation.region(), local, peers, limit as usize);
+    Json(FederatedExecutionsResponse {
+        tenant_id,
+        items,
+        unreachable,
+    })
+    .into_response()
+}
+
+/// GET /federation/regions — this region and its peers, with reachability.
+#[utoipa::path(
+    get,
+    path = "/v1/federation/regions",
+    tag = "federation",
+    responses(
+        (status = 200, description = "Federation regions", body = FederationRegionsResponse),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Federation not configured", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_regions(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+) -> impl IntoResponse {
+    let federation = match federation(&state) {
+        Ok(f) => f,
+        Err(e) => return e.into_response(),
+    };
+    Json(FederationRegionsResponse {
+        region: federation.region().to_string(),
+        regions: federation.regions().await,
+    })
+    .into_response()
+}
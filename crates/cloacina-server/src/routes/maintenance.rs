@@ -0,0 +1,105 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Platform maintenance operations (admin).
+//!
+//! Server-side counterparts of the `cloacinactl admin` verbs that otherwise
+//! need direct database access, so operators can run them remotely with a
+//! god-mode API key instead of DB credentials.
+
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use chrono::{Duration, Utc};
+use tracing::info;
+
+use cloacina::database::universal_types::UniversalTimestamp;
+use cloacina_api_types::{CleanupEventsRequest, CleanupEventsResponse};
+
+use crate::routes::auth::AuthenticatedKey;
+use crate::routes::error::ApiError;
+use crate::AppState;
+
+/// `POST /v1/maintenance/cleanup-events` — prune old execution events from
+/// the public schema (admin only).
+#[utoipa::path(
+    post,
+    path = "/v1/maintenance/cleanup-events",
+    tag = "maintenance",
+    request_body = CleanupEventsRequest,
+    responses(
+        (status = 200, description = "Events deleted (or counted on a dry run)", body = CleanupEventsResponse),
+        (status = 400, description = "Invalid retention window", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Admin required", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn cleanup_events(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthenticatedKey>,
+    Json(req): Json<CleanupEventsRequest>,
+) -> impl IntoResponse {
+    let seconds = match i64::try_from(req.older_than_seconds) {
+        Ok(s) if s > 0 => s,
+        _ => {
+            return ApiError::bad_request(
+                "invalid_request",
+                "older_than_seconds must be a positive number of seconds",
+            )
+            .into_response()
+        }
+    };
+    let Some(cutoff) =
+        Duration::try_seconds(seconds).and_then(|d| Utc::now().checked_sub_signed(d))
+    else {
+        return ApiError::bad_request("invalid_request", "older_than_seconds is out of range")
+            .into_response();
+    };
+
+    let dal = cloacina::dal::DAL::new(state.database.clone());
+    let events = if req.dry_run {
+        dal.execution_event()
+            .count_older_than(UniversalTimestamp(cutoff))
+            .await
+            .map(|n| n.max(0) as u64)
+    } else {
+        dal.execution_event()
+            .delete_older_than(UniversalTimestamp(cutoff))
+            .await
+            .map(|n| n as u64)
+    };
+
+    match events {
+        Ok(events) => {
+            info!(
+                key_id = %auth.key_id,
+                dry_run = req.dry_run,
+                events,
+                cutoff = %cutoff,
+                "execution-event cleanup"
+            );
+            Json(CleanupEventsResponse {
+                cutoff: cutoff.to_rfc3339(),
+                dry_run: req.dry_run,
+                events,
+            })
+            .into_response()
+        }
+        Err(e) => ApiError::internal(format!("{}", e)).into_response(),
+    }
+}
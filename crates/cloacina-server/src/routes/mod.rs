@@ -17,17 +17,20 @@
 //! API server route handlers and middleware.
 
 pub mod agent;
+pub mod audit;
 pub mod auth;
 pub mod authz;
 pub mod compiler;
 pub mod delivery_ws;
 pub mod error;
 pub mod executions;
+pub mod federation;
 pub mod fleet;
 pub mod health_graphs;
 pub mod keys;
 pub mod limits;
 pub mod local_auth;
+pub mod maintenance;
 pub mod oidc_auth;
 pub mod secrets;
 pub mod session;
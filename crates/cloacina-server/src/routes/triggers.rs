@@ -18,6 +18,7 @@
 
 use axum::{
     extract::{Path, State},
+    http::StatusCode,
     response::IntoResponse,
     Extension, Json,
 };
@@ -26,9 +27,12 @@ use tracing::warn;
 use axum::extract::Query;
 
 use cloacina_api_types::{
-    DeclaredSurface, FireTriggerRequest, FireTriggerResponse, FiredExecution, InputSlot,
-    ListTriggersQuery, TenantListResponse, TriggerDetailResponse, TriggerExecution,
-    TriggerPauseResponse, TriggerScheduleInfo, TriggerScheduleSummary,
+    CreateTriggerRequest, DeclaredSurface, FireTriggerRequest, FireTriggerResponse, FiredExecution,
+    InputSlot, ListTriggersQuery, TenantListResponse, TriggerCatchupRequest,
+    TriggerCatchupResponse, TriggerConditionRequest, TriggerConditionResponse, TriggerDedupRequest,
+    TriggerDedupResponse, TriggerDetailResponse, TriggerDstPolicyRequest, TriggerDstPolicyResponse,
+    TriggerExecution, TriggerPauseResponse, TriggerScheduleInfo, TriggerScheduleSummary,
+    TriggerThrottleRequest, TriggerThrottleResponse,
 };
 
 use cloacina::dal::UnifiedRegistryStorage;
@@ -107,23 +111,8 @@ pub async fn list_triggers(
 
     match dal.schedule().list(None, false, limit, offset).await {
         Ok(schedules) => {
-            let items: Vec<TriggerScheduleSummary> = schedules
-                .into_iter()
-                .map(|s| TriggerScheduleSummary {
-                    id: s.id.0.to_string(),
-                    schedule_type: s.schedule_type,
-                    workflow_name: s.workflow_name,
-                    enabled: s.enabled.is_true(),
-                    cron_expression: s.cron_expression,
-                    trigger_name: s.trigger_name,
-                    poll_interval_ms: s.poll_interval_ms.map(i64::from),
-                    next_run_at: s.next_run_at.map(|t| t.0.to_rfc3339()),
-                    last_run_at: s.last_run_at.map(|t| t.0.to_rfc3339()),
-                    created_at: s.created_at.0.to_rfc3339(),
-                    paused: s.paused.is_true(),
-                    paused_at: s.paused_at.map(|t| t.0.to_rfc3339()),
-                })
-                .collect();
+            let items: Vec<TriggerScheduleSummary> =
+                schedules.into_iter().map(schedule_summary).collect();
             // CLOACI-T-0594 / API-03: unified `{items, total}` envelope.
             // tenant_id retained at the top level for backward compatibility
             // with operator dashboards that key off it.
@@ -136,6 +125,24 @@ pub async fn list_triggers(
     }
 }
 
+/// The list-row view of a schedule.
+pub(crate) fn schedule_summary(s: cloacina::models::schedule::Schedule) -> TriggerScheduleSummary {
+    TriggerScheduleSummary {
+        id: s.id.0.to_string(),
+        schedule_type: s.schedule_type,
+        workflow_name: s.workflow_name,
+        enabled: s.enabled.is_true(),
+        cron_expression: s.cron_expression,
+        trigger_name: s.trigger_name,
+        poll_interval_ms: s.poll_interval_ms.map(i64::from),
+        next_run_at: s.next_run_at.map(|t| t.0.to_rfc3339()),
+        last_run_at: s.last_run_at.map(|t| t.0.to_rfc3339()),
+        created_at: s.created_at.0.to_rfc3339(),
+        paused: s.paused.is_true(),
+        paused_at: s.paused_at.map(|t| t.0.to_rfc3339()),
+    }
+}
+
 /// GET /tenants/:tenant_id/triggers/:name — trigger details + recent executions.
 ///
 /// CLOACI-T-0579: routed through the tenant-scoped `Database`. A request
@@ -207,9 +214,18 @@ pub async fn get_trigger(
                     scheduled_time: e.scheduled_time.map(|t| t.0.to_rfc3339()),
                     started_at: e.started_at.0.to_rfc3339(),
                     completed_at: e.completed_at.map(|t| t.0.to_rfc3339()),
+                    last_suppressed_at: e.is_suppressed().then(|| e.updated_at.0.to_rfc3339()),
+                    context_hash: e.context_hash,
+                    suppressed_reason: e.suppressed_reason,
+                    duplicate_of: e.duplicate_of.map(|id| id.0.to_string()),
                 })
                 .collect();
 
+            let dst_policy = schedule
+                .is_cron()
+                .then(|| schedule.dst_policy().to_string());
+            let dedup_keys = schedule.dedup_key_list();
+
             Json(TriggerDetailResponse {
                 tenant_id,
                 schedule: TriggerScheduleInfo {
@@ -222,6 +238,20 @@ pub async fn get_trigger(
                     poll_interval_ms: schedule.poll_interval_ms.map(i64::from),
                     paused: schedule.paused.is_true(),
                     paused_at: schedule.paused_at.map(|t| t.0.to_rfc3339()),
+                    paused_by: schedule.paused_by,
+                    pause_reason: schedule.pause_reason,
+                    dst_policy,
+                    dedup_window_secs: schedule.dedup_window_secs.map(i64::from),
+                    dedup_keys,
+                    catchup_policy: schedule.catchup_policy,
+                    min_fire_interval_ms: schedule.min_fire_interval_ms.map(i64::from),
+                    debounce_window_ms: schedule.debounce_window_ms.map(i64::from),
+                    probes: schedule
+                        .trigger_probes
+                        .as_deref()
+                        .and_then(|p| serde_json::from_str(p).ok())
+                        .unwrap_or_default(),
+                    condition: schedule.trigger_condition,
                 },
                 recent_executions: exec_items,
             })
@@ -291,10 +321,728 @@ pub async fn resume_trigger(
     set_trigger_paused(state, _auth, tenant_id, name, false).await
 }
 
+/// PUT /tenants/:tenant_id/triggers/:name/dedup — configure how a trigger
+/// schedule deduplicates firings.
+///
+/// `window_secs` suppresses a firing whose context hash matches any firing
+/// within the window; `keys` restricts the hash to those top-level context
+/// keys. Replaces the previous configuration. Suppressed firings show up in
+/// the trigger's `recent_executions` with their reason.
+#[utoipa::path(
+    put,
+    path = "/v1/tenants/{tenant_id}/triggers/{name}/dedup",
+    tag = "triggers",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Trigger or workflow name"),
+    ),
+    request_body = TriggerDedupRequest,
+    responses(
+        (status = 200, description = "Dedup configuration applied", body = TriggerDedupResponse),
+        (status = 400, description = "Negative window or a cron schedule", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Trigger not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn set_trigger_dedup(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+    Json(request): Json<TriggerDedupRequest>,
+) -> impl IntoResponse {
+    let window_secs = match request.window_secs {
+        Some(secs) if secs < 0 => {
+            return ApiError::bad_request("invalid_window", "window_secs must not be negative")
+                .into_response()
+        }
+        Some(0) | None => None,
+        Some(secs) => Some(i32::try_from(secs).unwrap_or(i32::MAX)),
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            warn!(
+                "Failed to resolve tenant database for '{}': {}",
+                tenant_id, e
+            );
+            return ApiError::internal(format!("tenant database unavailable: {}", e))
+                .into_response();
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+
+    // Resolve schedule by trigger name or workflow name (mirrors get_trigger).
+    let schedules = match dal.schedule().list(None, false, 1000, 0).await {
+        Ok(s) => s,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+    let schedule = match schedules
+        .into_iter()
+        .find(|s| s.trigger_name.as_deref() == Some(&name) || s.workflow_name == name)
+    {
+        Some(s) => s,
+        None => {
+            return ApiError::not_found(
+                "trigger_not_found",
+                format!("trigger '{}' not found", name),
+            )
+            .into_response()
+        }
+    };
+    if !schedule.is_trigger() {
+        return ApiError::bad_request(
+            "not_a_trigger",
+            format!(
+                "'{}' is a cron schedule; dedup applies to triggers only",
+                name
+            ),
+        )
+        .into_response();
+    }
+
+    if let Err(e) = dal
+        .schedule()
+        .set_dedup(schedule.id, window_secs, &request.keys)
+        .await
+    {
+        warn!(
+            "Failed to set dedup for trigger '{}' in tenant '{}': {}",
+            name, tenant_id, e
+        );
+        return ApiError::internal(format!("{}", e)).into_response();
+    }
+
+    Json(TriggerDedupResponse {
+        tenant_id,
+        id: schedule.id.0.to_string(),
+        name,
+        window_secs: window_secs.map(i64::from),
+        keys: cloacina::models::schedule::parse_tags(
+            cloacina::models::schedule::encode_tags(&request.keys).as_deref(),
+        ),
+    })
+    .into_response()
+}
+
+/// PUT /tenants/:tenant_id/triggers/:name/catchup — set what a cron schedule
+/// does with fires it missed.
+///
+/// `skip` drops them, `run_latest_only` runs once for the most recent one and
+/// `run_all` runs every missed fire (bounded by the scheduler's max catchup).
+#[utoipa::path(
+    put,
+    path = "/v1/tenants/{tenant_id}/triggers/{name}/catchup",
+    tag = "triggers",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Workflow name of the cron schedule"),
+    ),
+    request_body = TriggerCatchupRequest,
+    responses(
+        (status = 200, description = "Catchup policy applied", body = TriggerCatchupResponse),
+        (status = 400, description = "Unknown policy or a trigger schedule", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Schedule not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn set_trigger_catchup(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+    Json(request): Json<TriggerCatchupRequest>,
+) -> impl IntoResponse {
+    use cloacina::models::schedule::CatchupPolicy;
+
+    let Some(policy) = CatchupPolicy::from_str(&request.policy) else {
+        return ApiError::bad_request(
+            "invalid_policy",
+            "policy must be one of skip, run_all, run_latest_only",
+        )
+        .into_response();
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            warn!(
+                "Failed to resolve tenant database for '{}': {}",
+                tenant_id, e
+            );
+            return ApiError::internal(format!("tenant database unavailable: {}", e))
+                .into_response();
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+
+    // Resolve schedule by trigger name or workflow name (mirrors get_trigger).
+    let schedules = match dal.schedule().list(None, false, 1000, 0).await {
+        Ok(s) => s,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+    let schedule = match schedules
+        .into_iter()
+        .find(|s| s.trigger_name.as_deref() == Some(&name) || s.workflow_name == name)
+    {
+        Some(s) => s,
+        None => {
+            return ApiError::not_found(
+                "trigger_not_found",
+                format!("trigger '{}' not found", name),
+            )
+            .into_response()
+        }
+    };
+    if schedule.is_trigger() {
+        return ApiError::bad_request(
+            "not_a_cron_schedule",
+            format!(
+                "'{}' is a trigger schedule; catchup applies to cron schedules only",
+                name
+            ),
+        )
+        .into_response();
+    }
+
+    if let Err(e) = dal.schedule().set_catchup_policy(schedule.id, policy).await {
+        warn!(
+            "Failed to set catchup policy for schedule '{}' in tenant '{}': {}",
+            name, tenant_id, e
+        );
+        return ApiError::internal(format!("{}", e)).into_response();
+    }
+
+    Json(TriggerCatchupResponse {
+        tenant_id,
+        id: schedule.id.0.to_string(),
+        name,
+        policy: policy.to_string(),
+    })
+    .into_response()
+}
+
+/// PUT /tenants/:tenant_id/triggers/:name/dst — set how a cron schedule
+/// handles daylight-saving transitions.
+///
+/// `skip` drops a fire whose wall-clock time does not exist, `run_once` (the
+/// default) moves it past the gap, and `run_twice` also fires a repeated
+/// wall-clock time at both instants. The next run is recomputed.
+#[utoipa::path(
+    put,
+    path = "/v1/tenants/{tenant_id}/triggers/{name}/dst",
+    tag = "triggers",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Workflow name of the cron schedule"),
+    ),
+    request_body = TriggerDstPolicyRequest,
+    responses(
+        (status = 200, description = "DST policy applied", body = TriggerDstPolicyResponse),
+        (status = 400, description = "Unknown policy, or a trigger or interval schedule", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Schedule not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn set_trigger_dst_policy(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+    Json(request): Json<TriggerDstPolicyRequest>,
+) -> impl IntoResponse {
+    let Ok(policy) = request.policy.parse::<cloacina::DstPolicy>() else {
+        return ApiError::bad_request(
+            "invalid_policy",
+            "policy must be one of skip, run_once, run_twice",
+        )
+        .into_response();
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            warn!(
+                "Failed to resolve tenant database for '{}': {}",
+                tenant_id, e
+            );
+            return ApiError::internal(format!("tenant database unavailable: {}", e))
+                .into_response();
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+
+    // Resolve schedule by trigger name or workflow name (mirrors get_trigger).
+    let schedules = match dal.schedule().list(None, false, 1000, 0).await {
+        Ok(s) => s,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+    let schedule = match schedules
+        .into_iter()
+        .find(|s| s.trigger_name.as_deref() == Some(&name) || s.workflow_name == name)
+    {
+        Some(s) => s,
+        None => {
+            return ApiError::not_found(
+                "trigger_not_found",
+                format!("trigger '{}' not found", name),
+            )
+            .into_response()
+        }
+    };
+    if schedule.is_trigger() || schedule.is_interval() {
+        return ApiError::bad_request(
+            "not_a_cron_schedule",
+            format!(
+                "'{}' has no cron expression; DST policies apply to cron schedules only",
+                name
+            ),
+        )
+        .into_response();
+    }
+
+    let expression = schedule.cron_expression.as_deref().unwrap_or("* * * * *");
+    let timezone = schedule.timezone.as_deref().unwrap_or("UTC");
+    let delay = schedule.fire_delay();
+    let next_run = match cloacina::CronEvaluator::new(expression, timezone).and_then(|evaluator| {
+        evaluator
+            .with_dst_policy(policy)
+            .next_execution(chrono::Utc::now() - delay)
+    }) {
+        Ok(next) => next + delay,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+
+    if let Err(e) = dal
+        .schedule()
+        .set_dst_policy(schedule.id, policy, next_run)
+        .await
+    {
+        warn!(
+            "Failed to set DST policy for schedule '{}' in tenant '{}': {}",
+            name, tenant_id, e
+        );
+        return ApiError::internal(format!("{}", e)).into_response();
+    }
+
+    Json(TriggerDstPolicyResponse {
+        tenant_id,
+        id: schedule.id.0.to_string(),
+        name,
+        policy: policy.to_string(),
+        next_run_at: next_run.to_rfc3339(),
+    })
+    .into_response()
+}
+
+/// PUT /tenants/:tenant_id/triggers/:name/throttle — configure trigger rate
+/// limiting and debouncing.
+///
+/// `min_interval_between_fires_ms` suppresses a firing sooner than that after
+/// the previous fire (recorded as `rate_limited`); `debounce_window_ms` holds
+/// a firing until the trigger has been quiet for the window, keeping only the
+/// latest. Replaces the previous configuration; omit (or 0) to disable.
+#[utoipa::path(
+    put,
+    path = "/v1/tenants/{tenant_id}/triggers/{name}/throttle",
+    tag = "triggers",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Trigger or workflow name"),
+    ),
+    request_body = TriggerThrottleRequest,
+    responses(
+        (status = 200, description = "Throttle configuration applied", body = TriggerThrottleResponse),
+        (status = 400, description = "Negative interval or a cron schedule", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Trigger not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn set_trigger_throttle(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+    Json(request): Json<TriggerThrottleRequest>,
+) -> impl IntoResponse {
+    let min_fire_interval_ms = match throttle_millis(
+        "min_interval_between_fires_ms",
+        request.min_interval_between_fires_ms,
+    ) {
+        Ok(ms) => ms,
+        Err(e) => return e.into_response(),
+    };
+    let debounce_window_ms = match throttle_millis("debounce_window_ms", request.debounce_window_ms)
+    {
+        Ok(ms) => ms,
+        Err(e) => return e.into_response(),
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            warn!(
+                "Failed to resolve tenant database for '{}': {}",
+                tenant_id, e
+            );
+            return ApiError::internal(format!("tenant database unavailable: {}", e))
+                .into_response();
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+
+    // Resolve schedule by trigger name or workflow name (mirrors get_trigger).
+    let schedules = match dal.schedule().list(None, false, 1000, 0).await {
+        Ok(s) => s,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+    let schedule = match schedules
+        .into_iter()
+        .find(|s| s.trigger_name.as_deref() == Some(&name) || s.workflow_name == name)
+    {
+        Some(s) => s,
+        None => {
+            return ApiError::not_found(
+                "trigger_not_found",
+                format!("trigger '{}' not found", name),
+            )
+            .into_response()
+        }
+    };
+    if !schedule.is_trigger() {
+        return ApiError::bad_request(
+            "not_a_trigger",
+            format!(
+                "'{}' is a cron schedule; throttling applies to triggers only",
+                name
+            ),
+        )
+        .into_response();
+    }
+
+    if let Err(e) = dal
+        .schedule()
+        .set_throttle(schedule.id, min_fire_interval_ms, debounce_window_ms)
+        .await
+    {
+        warn!(
+            "Failed to set throttle for trigger '{}' in tenant '{}': {}",
+            name, tenant_id, e
+        );
+        return ApiError::internal(format!("{}", e)).into_response();
+    }
+
+    Json(TriggerThrottleResponse {
+        tenant_id,
+        id: schedule.id.0.to_string(),
+        name,
+        min_interval_between_fires_ms: min_fire_interval_ms.map(i64::from),
+        debounce_window_ms: debounce_window_ms.map(i64::from),
+    })
+    .into_response()
+}
+
+/// Validate a throttle interval from a request: negative is rejected, zero
+/// or absent disables it.
+fn throttle_millis(field: &str, value: Option<i64>) -> Result<Option<i32>, ApiError> {
+    match value {
+        Some(ms) if ms < 0 => Err(ApiError::bad_request(
+            "invalid_interval",
+            format!("{} must not be negative", field),
+        )),
+        Some(0) | None => Ok(None),
+        Some(ms) => Ok(Some(i32::try_from(ms).unwrap_or(i32::MAX))),
+    }
+}
+
+/// PUT /tenants/:tenant_id/triggers/:name/condition — set or clear a
+/// trigger's declarative condition.
+///
+/// The CEL `condition` is evaluated after each poll that would fire, over the
+/// trigger's `context`, the results of its `probes` and `trigger`; a firing
+/// runs only when it is true. A trigger schedule with a condition but no
+/// registered trigger fires on every poll where the condition holds.
+/// Omitting `condition` clears it along with the probes.
+#[utoipa::path(
+    put,
+    path = "/v1/tenants/{tenant_id}/triggers/{name}/condition",
+    tag = "triggers",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Trigger or workflow name"),
+    ),
+    request_body = TriggerConditionRequest,
+    responses(
+        (status = 200, description = "Condition applied", body = TriggerConditionResponse),
+        (status = 400, description = "Invalid condition or probes, or a cron schedule", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Trigger not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn set_trigger_condition(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+    Json(request): Json<TriggerConditionRequest>,
+) -> impl IntoResponse {
+    let probes = match request.condition.as_deref() {
+        Some(condition) => match parse_condition(condition, &request.probes) {
+            Ok(probes) => probes,
+            Err(e) => return e.into_response(),
+        },
+        None => Vec::new(),
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            warn!(
+                "Failed to resolve tenant database for '{}': {}",
+                tenant_id, e
+            );
+            return ApiError::internal(format!("tenant database unavailable: {}", e))
+                .into_response();
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+
+    // Resolve schedule by trigger name or workflow name (mirrors get_trigger).
+    let schedules = match dal.schedule().list(None, false, 1000, 0).await {
+        Ok(s) => s,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+    let schedule = match schedules
+        .into_iter()
+        .find(|s| s.trigger_name.as_deref() == Some(&name) || s.workflow_name == name)
+    {
+        Some(s) => s,
+        None => {
+            return ApiError::not_found(
+                "trigger_not_found",
+                format!("trigger '{}' not found", name),
+            )
+            .into_response()
+        }
+    };
+    if !schedule.is_trigger() {
+        return ApiError::bad_request(
+            "not_a_trigger",
+            format!(
+                "'{}' is a cron schedule; conditions apply to triggers only",
+                name
+            ),
+        )
+        .into_response();
+    }
+
+    if let Err(e) = dal
+        .schedule()
+        .set_condition(
+            schedule.id,
+            request.condition.clone(),
+            cloacina::trigger::trigger_probes_json(&probes),
+        )
+        .await
+    {
+        warn!(
+            "Failed to set condition for trigger '{}' in tenant '{}': {}",
+            name, tenant_id, e
+        );
+        return ApiError::internal(format!("{}", e)).into_response();
+    }
+
+    Json(TriggerConditionResponse {
+        tenant_id,
+        id: schedule.id.0.to_string(),
+        name,
+        workflow_name: schedule.workflow_name,
+        condition: request.condition,
+        probes: if probes.is_empty() {
+            Vec::new()
+        } else {
+            request.probes
+        },
+    })
+    .into_response()
+}
+
+/// POST /tenants/:tenant_id/triggers — create a declarative trigger.
+///
+/// The trigger has no code of its own: every `poll_interval_ms` the scheduler
+/// runs its probes and fires `workflow_name` when `condition` is true. Pair
+/// it with a dedup window or throttle to avoid firing on every poll while the
+/// condition stays true.
+#[utoipa::path(
+    post,
+    path = "/v1/tenants/{tenant_id}/triggers",
+    tag = "triggers",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+    ),
+    request_body = CreateTriggerRequest,
+    responses(
+        (status = 201, description = "Trigger created", body = TriggerConditionResponse),
+        (status = 400, description = "Invalid interval, condition or probes", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 409, description = "A trigger with this name already exists", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn create_trigger(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path(tenant_id): Path<String>,
+    Json(request): Json<CreateTriggerRequest>,
+) -> impl IntoResponse {
+    if request.name.trim().is_empty() || request.workflow_name.trim().is_empty() {
+        return ApiError::bad_request(
+            "invalid_trigger",
+            "name and workflow_name must not be empty",
+        )
+        .into_response();
+    }
+    if request.poll_interval_ms <= 0 {
+        return ApiError::bad_request("invalid_interval", "poll_interval_ms must be positive")
+            .into_response();
+    }
+    let probes = match parse_condition(&request.condition, &request.probes) {
+        Ok(probes) => probes,
+        Err(e) => return e.into_response(),
+    };
+
+    let tenant_db = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            warn!(
+                "Failed to resolve tenant database for '{}': {}",
+                tenant_id, e
+            );
+            return ApiError::internal(format!("tenant database unavailable: {}", e))
+                .into_response();
+        }
+    };
+    let dal = cloacina::dal::DAL::new(tenant_db);
+
+    match dal.schedule().get_by_trigger_name(&request.name).await {
+        Ok(None) => {}
+        Ok(Some(_)) => {
+            return ApiError::new(
+                StatusCode::CONFLICT,
+                "trigger_exists",
+                format!("trigger '{}' already exists", request.name),
+            )
+            .into_response()
+        }
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    }
+
+    let poll_interval = std::time::Duration::from_millis(request.poll_interval_ms as u64);
+    let schedule = match dal
+        .schedule()
+        .upsert_trigger(cloacina::models::schedule::NewSchedule::trigger(
+            &request.name,
+            &request.workflow_name,
+            poll_interval,
+        ))
+        .await
+    {
+        Ok(s) => s,
+        Err(e) => {
+            warn!(
+                "Failed to create trigger '{}' in tenant '{}': {}",
+                request.name, tenant_id, e
+            );
+            return ApiError::internal(format!("{}", e)).into_response();
+        }
+    };
+    if let Err(e) = dal
+        .schedule()
+        .set_condition(
+            schedule.id,
+            Some(request.condition.clone()),
+            cloacina::trigger::trigger_probes_json(&probes),
+        )
+        .await
+    {
+        warn!(
+            "Failed to set condition for trigger '{}' in tenant '{}': {}",
+            request.name, tenant_id, e
+        );
+        return ApiError::internal(format!("{}", e)).into_response();
+    }
+
+    (
+        StatusCode::CREATED,
+        Json(TriggerConditionResponse {
+            tenant_id,
+            id: schedule.id.0.to_string(),
+            name: request.name,
+            workflow_name: schedule.workflow_name,
+            condition: Some(request.condition),
+            probes: request.probes,
+        }),
+    )
+        .into_response()
+}
+
+/// Parse and validate a condition and its probe definitions from a request.
+fn parse_condition(
+    condition: &str,
+    probes: &[serde_json::Value],
+) -> Result<Vec<cloacina::trigger::TriggerProbe>, ApiError> {
+    let probes = probes
+        .iter()
+        .cloned()
+        .map(serde_json::from_value::<cloacina::trigger::TriggerProbe>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ApiError::bad_request("invalid_condition", format!("invalid probe: {}", e)))?;
+    cloacina::trigger::validate_trigger_condition(condition, &probes)
+        .map_err(|e| ApiError::bad_request("invalid_condition", e.to_string()))?;
+    Ok(probes)
+}
+
 /// Shared pause/resume implementation for trigger and cron schedules.
 async fn set_trigger_paused(
     state: AppState,
-    _auth: AuthenticatedKey,
+    auth: AuthenticatedKey,
     tenant_id: String,
     name: String,
     pause: bool,
@@ -337,7 +1085,9 @@ async fn set_trigger_paused(
     };
 
     let result = if pause {
-        dal.schedule().pause(schedule.id).await
+        dal.schedule()
+            .pause(schedule.id, Some(&auth.name), None)
+            .await
     } else {
         dal.schedule().resume(schedule.id).await
     };
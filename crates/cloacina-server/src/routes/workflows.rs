@@ -18,7 +18,7 @@
 
 use axum::{
     extract::{Multipart, Path, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Extension, Json,
 };
@@ -27,11 +27,12 @@ use tracing::{info, warn};
 use cloacina::dal::UnifiedRegistryStorage;
 use cloacina::registry::traits::WorkflowRegistry;
 use cloacina::registry::workflow_registry::WorkflowRegistryImpl;
-use cloacina::security::audit;
 use cloacina_api_types::{
-    TenantListResponse, WorkflowDeletedResponse, WorkflowDetail, WorkflowPauseResponse,
+    TenantListResponse, WorkflowConsumersResponse, WorkflowDeletedResponse,
+    WorkflowDeprecationInfo, WorkflowDeprecationRequest, WorkflowDeprecationResponse,
+    WorkflowDetail, WorkflowPauseResponse, WorkflowRollbackResponse, WorkflowSchemaResponse,
     WorkflowSourceFile, WorkflowSourceResponse, WorkflowSummary, WorkflowTaskNode,
-    WorkflowUploadedResponse,
+    WorkflowUploadedResponse, WorkflowVersionSummary, WorkflowVersionsResponse,
 };
 
 use crate::routes::auth::AuthenticatedKey;
@@ -47,7 +48,8 @@ use crate::AppState;
     request_body(
         content = crate::openapi::PackageUploadForm,
         content_type = "multipart/form-data",
-        description = "The first file field is taken as the .cloacina package"
+        description = "The first `file` field is taken as the .cloacina package; an optional \
+                       `config` field carries its JSON configuration values"
     ),
     responses(
         (status = 201, description = "Package registered", body = WorkflowUploadedResponse),
@@ -64,98 +66,89 @@ pub async fn upload_workflow(
     Path(tenant_id): Path<String>,
     mut multipart: Multipart,
 ) -> impl IntoResponse {
-    // Extract file from multipart
-    let package_data = match extract_file_field(&mut multipart).await {
-        Ok(data) => data,
+    // Spool the file from multipart to disk; the archive is never held in
+    // memory whole.
+    let (package_file, config) = match extract_upload(&mut multipart).await {
+        Ok(upload) => upload,
         Err(msg) => return ApiError::bad_request("invalid_request", msg).into_response(),
     };
 
-    if package_data.is_empty() {
+    if package_file
+        .as_file()
+        .metadata()
+        .map(|m| m.len())
+        .unwrap_or(0)
+        == 0
+    {
         return ApiError::bad_request("invalid_request", "empty package file").into_response();
     }
 
     // T-0557 Bug 2: signature verification at upload time.
     //
-    // When `require_signatures` is enabled AND a `verification_org_id`
-    // is configured, run real verification against the trusted-key list
-    // for that org. The signature is looked up by package hash from the
-    // `package_signatures` table (`SignatureSource::Database`); the
+    // When `require_signatures` is enabled, the package must be signed by a
+    // key on the tenant's trust list: the org from `--tenant-trust-org` for
+    // this tenant, else the default `verification_org_id`. The signature is
+    // looked up by package hash from the `package_signatures` table; the
     // signing flow (`cloacinactl pack`/`publish` + the future T-0514
-    // sidecar) is responsible for inserting the row before upload.
+    // sidecar) is responsible for inserting the row before upload. The
+    // reconciler applies the same policy before loading, so packages that
+    // reach the registry another way are held to it too.
+    //
+    // A tenant with no trust list fails safe with
+    // `signature_verification_unconfigured` — the operator knows
+    // verification is enabled but unwired for that tenant.
     //
-    // When `require_signatures` is enabled but no org is configured
-    // we fail-safe with a clearer error than the old "TODO" stub —
-    // the operator knows verification is enabled but unwired, not
-    // that signing is required for the upload itself.
+    // CLOACI-I-0103 / T-0568: both success and failure go through the
+    // structured audit log so deployments with a centralised log pipeline
+    // get a single, parseable record per upload.
     if state.security_config.require_signatures {
-        let Some(org_id) = state.security_config.verification_org_id else {
-            warn!(
-                "Package upload rejected: require_signatures=true but no \
-                 verification_org_id configured. Set SecurityConfig::verification_org_id \
-                 before enabling signature requirements."
-            );
-            return ApiError::forbidden(
-                "signature_verification_unconfigured",
-                "signature verification is required but server is not configured \
-                 with a verification_org_id; contact the server operator",
-            )
-            .into_response();
-        };
-        let dal = cloacina::dal::DAL::new(state.database.clone());
-        let package_signer = cloacina::security::DbPackageSigner::new(dal.clone());
-        let key_manager = cloacina::security::DbKeyManager::new(dal);
-        // CLOACI-I-0103 / T-0568: route both success and failure through the
-        // structured audit log so deployments with a centralised log pipeline
-        // get a single, parseable record per upload. The human-readable
-        // info!/warn! lines stay because they carry message-level context
-        // operators tail in real time.
         let audit_path = format!("upload:tenant={}", tenant_id);
-        match cloacina::security::verify_package_bytes(
-            &package_data,
-            org_id,
-            cloacina::security::SignatureSource::Database,
-            &package_signer,
-            &key_manager,
+        match cloacina::security::enforce_signature_policy(
+            &state.security_config,
+            &tenant_id,
+            package_file.path(),
+            &state.database,
+            &audit_path,
         )
         .await
         {
             Ok(result) => {
-                info!(
-                    "Package signature verified: hash={} signer={}",
-                    result.package_hash, result.signer_fingerprint
-                );
-                audit::log_package_load_success(
-                    org_id,
-                    &audit_path,
-                    &result.package_hash,
-                    Some(&result.signer_fingerprint),
-                    /* signature_verified */ true,
-                );
+                if let Some(result) = result {
+                    info!(
+                        "Package signature verified: hash={} signer={}",
+                        result.package_hash, result.signer_fingerprint
+                    );
+                }
             }
             Err(e) => {
-                warn!("Package signature verification failed: {}", e);
-                let (code, msg) = match &e {
-                    cloacina::security::VerificationError::TamperedPackage { .. } => (
-                        "package_tampered",
-                        "package contents do not match the signed hash".to_string(),
-                    ),
-                    cloacina::security::VerificationError::UntrustedSigner { fingerprint } => (
-                        "untrusted_signer",
-                        format!("package signed by untrusted key: {}", fingerprint),
-                    ),
-                    cloacina::security::VerificationError::InvalidSignature => (
-                        "invalid_signature",
-                        "cryptographic signature verification failed".to_string(),
-                    ),
-                    cloacina::security::VerificationError::SignatureNotFound { .. } => (
-                        "signature_not_found",
-                        "no signature row found for this package; sign before uploading"
-                            .to_string(),
+                warn!(
+                    "Package upload for tenant '{}' rejected by signature policy: {}",
+                    tenant_id, e
+                );
+                let msg = match &e {
+                    cloacina::security::VerificationError::TamperedPackage { .. } => {
+                        "package contents do not match the signed hash".to_string()
+                    }
+                    cloacina::security::VerificationError::UntrustedSigner { fingerprint } => {
+                        format!(
+                            "package signed by key {} which is not on tenant '{}''s trust list",
+                            fingerprint, tenant_id
+                        )
+                    }
+                    cloacina::security::VerificationError::InvalidSignature => {
+                        "cryptographic signature verification failed".to_string()
+                    }
+                    cloacina::security::VerificationError::SignatureNotFound { .. } => {
+                        "no signature row found for this package; sign before uploading".to_string()
+                    }
+                    cloacina::security::VerificationError::NoTrustList { .. } => format!(
+                        "signature verification is required but tenant '{}' has no trust \
+                         list configured; contact the server operator",
+                        tenant_id
                     ),
-                    _ => ("signature_verification_error", format!("{}", e)),
+                    _ => format!("{}", e),
                 };
-                audit::log_package_load_failure(org_id, &audit_path, &e.to_string(), code);
-                return ApiError::forbidden(code, msg).into_response();
+                return ApiError::forbidden(e.code(), msg).into_response();
             }
         }
     }
@@ -171,8 +164,7 @@ pub async fn upload_workflow(
             return ApiError::internal(format!("tenant database error: {}", e)).into_response()
         }
     };
-    let storage = UnifiedRegistryStorage::new(tenant_db.clone());
-    let mut registry = match WorkflowRegistryImpl::new(storage, tenant_db) {
+    let mut registry = match state.tenant_registry(&tenant_id, tenant_db) {
         Ok(r) => r,
         Err(e) => {
             warn!("Failed to create registry: {}", e);
@@ -180,7 +172,10 @@ pub async fn upload_workflow(
         }
     };
 
-    match registry.register_workflow_package(package_data).await {
+    match registry
+        .register_workflow_package_file(package_file.path(), config)
+        .await
+    {
         Ok(package_id) => {
             info!(
                 "Uploaded workflow package for tenant '{}': {}",
@@ -310,6 +305,7 @@ pub async fn get_workflow(
     if let Ok(pkg_id) = uuid::Uuid::parse_str(&name) {
         match registry.inspect_package_by_id(pkg_id).await {
             Ok(Some(ins)) => {
+                let declared_outputs = ins.metadata.declared_outputs();
                 return Json(WorkflowDetail {
                     tenant_id,
                     id: ins.metadata.id.to_string(),
@@ -335,6 +331,8 @@ pub async fn get_workflow(
                     build_error: ins.build_error,
                     paused: ins.metadata.paused,
                     declared_params: ins.metadata.declared_params.clone(),
+                    declared_outputs,
+                    provenance: ins.metadata.provenance,
                 })
                 .into_response();
             }
@@ -361,33 +359,38 @@ pub async fn get_workflow(
                     // real build state (pending/building/failed in
                     // addition to success).
                     match registry.inspect_package_by_id(w.id).await {
-                        Ok(Some(ins)) => Json(WorkflowDetail {
-                            tenant_id,
-                            id: ins.metadata.id.to_string(),
-                            package_name: ins.metadata.package_name,
-                            workflow_name: ins.metadata.workflow_name,
-                            version: ins.metadata.version,
-                            description: ins.metadata.description,
-                            tasks: ins.metadata.tasks,
-                            task_graph: ins
-                                .metadata
-                                .task_graph
-                                .into_iter()
-                                .map(|n| WorkflowTaskNode {
-                                    id: n.id,
-                                    dependencies: n.dependencies,
-                                    description: n.description,
-                                    doc_what: n.doc_what,
-                                    doc_why: n.doc_why,
-                                })
-                                .collect(),
-                            created_at: ins.metadata.created_at.to_rfc3339(),
-                            build_status: ins.build_status,
-                            build_error: ins.build_error,
-                            paused: ins.metadata.paused,
-                            declared_params: ins.metadata.declared_params.clone(),
-                        })
-                        .into_response(),
+                        Ok(Some(ins)) => {
+                            let declared_outputs = ins.metadata.declared_outputs();
+                            Json(WorkflowDetail {
+                                tenant_id,
+                                id: ins.metadata.id.to_string(),
+                                package_name: ins.metadata.package_name,
+                                workflow_name: ins.metadata.workflow_name,
+                                version: ins.metadata.version,
+                                description: ins.metadata.description,
+                                tasks: ins.metadata.tasks,
+                                task_graph: ins
+                                    .metadata
+                                    .task_graph
+                                    .into_iter()
+                                    .map(|n| WorkflowTaskNode {
+                                        id: n.id,
+                                        dependencies: n.dependencies,
+                                        description: n.description,
+                                        doc_what: n.doc_what,
+                                        doc_why: n.doc_why,
+                                    })
+                                    .collect(),
+                                created_at: ins.metadata.created_at.to_rfc3339(),
+                                build_status: ins.build_status,
+                                build_error: ins.build_error,
+                                paused: ins.metadata.paused,
+                                declared_params: ins.metadata.declared_params.clone(),
+                                declared_outputs,
+                                provenance: ins.metadata.provenance,
+                            })
+                            .into_response()
+                        }
                         Ok(None) => ApiError::not_found(
                             "workflow_not_found",
                             format!("workflow '{}' not found", name),
@@ -503,6 +506,172 @@ pub async fn get_workflow_source(
     }
 }
 
+/// GET /tenants/:tenant_id/workflows/:name/archive — download the active
+/// package's `.cloacina` archive.
+///
+/// Returns the exact bytes that were uploaded, so the package can be
+/// re-uploaded to another server or tenant (`cloacinactl bundle export`).
+/// `name` may be a package name or a package UUID, matching `get_workflow`.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/archive",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Package name, or package UUID"),
+    ),
+    responses(
+        (status = 200, description = "The `.cloacina` package archive", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Workflow not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_workflow_archive(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let tenant_db: cloacina::database::Database = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let storage = UnifiedRegistryStorage::new(tenant_db.clone());
+    let registry = match WorkflowRegistryImpl::new(storage, tenant_db) {
+        Ok(r) => r,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+
+    let package_id = if let Ok(pkg_id) = uuid::Uuid::parse_str(&name) {
+        pkg_id
+    } else {
+        match registry.list_workflows().await {
+            Ok(workflows) => match workflows.into_iter().find(|w| w.package_name == name) {
+                Some(w) => w.id,
+                None => {
+                    return ApiError::not_found(
+                        "workflow_not_found",
+                        format!("workflow '{}' not found", name),
+                    )
+                    .into_response();
+                }
+            },
+            Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+        }
+    };
+
+    match registry.get_source_for_build(package_id).await {
+        Ok(Some((metadata, bytes))) => {
+            let disposition = format!(
+                "attachment; filename=\"{}-{}.cloacina\"",
+                metadata.package_name, metadata.version
+            );
+            (
+                [
+                    (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+                    (header::CONTENT_DISPOSITION, disposition),
+                ],
+                bytes,
+            )
+                .into_response()
+        }
+        Ok(None) => ApiError::not_found(
+            "workflow_not_found",
+            format!("workflow '{}' not found", name),
+        )
+        .into_response(),
+        Err(e) => {
+            warn!(
+                "Failed to read archive for workflow '{}' (tenant '{}'): {}",
+                name, tenant_id, e
+            );
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
+/// GET /tenants/:tenant_id/workflows/:name/schema — JSON Schema export of the
+/// workflow's declared params and outputs.
+///
+/// `name` may be a package name or a package UUID, matching `get_workflow`.
+/// Undeclared workflows return empty, free-form object schemas.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/schema",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Package name, or package UUID"),
+    ),
+    responses(
+        (status = 200, description = "Param and output JSON Schemas", body = WorkflowSchemaResponse),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Workflow not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_workflow_schema(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let tenant_db: cloacina::database::Database = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let storage = UnifiedRegistryStorage::new(tenant_db.clone());
+    let registry = match WorkflowRegistryImpl::new(storage, tenant_db) {
+        Ok(r) => r,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+
+    let metadata = if let Ok(pkg_id) = uuid::Uuid::parse_str(&name) {
+        match registry.inspect_package_by_id(pkg_id).await {
+            Ok(ins) => ins.map(|ins| ins.metadata),
+            Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+        }
+    } else {
+        match registry.list_workflows().await {
+            Ok(workflows) => workflows.into_iter().find(|w| w.package_name == name),
+            Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+        }
+    };
+
+    match metadata {
+        Some(metadata) => Json(WorkflowSchemaResponse {
+            tenant_id,
+            id: metadata.id.to_string(),
+            params: metadata.params_json_schema(),
+            outputs: metadata.outputs_json_schema(),
+            package_name: metadata.package_name,
+            workflow_name: metadata.workflow_name,
+            version: metadata.version,
+        })
+        .into_response(),
+        None => ApiError::not_found(
+            "workflow_not_found",
+            format!("workflow '{}' not found", name),
+        )
+        .into_response(),
+    }
+}
+
 /// Best-effort language id from a file extension, for client-side syntax
 /// highlighting. `None` when the extension is unknown.
 fn detect_source_language(path: &str) -> Option<String> {
@@ -557,8 +726,7 @@ pub async fn delete_workflow(
             return ApiError::internal(format!("tenant database error: {}", e)).into_response()
         }
     };
-    let storage = UnifiedRegistryStorage::new(tenant_db.clone());
-    let mut registry = match WorkflowRegistryImpl::new(storage, tenant_db) {
+    let mut registry = match state.tenant_registry(&tenant_id, tenant_db) {
         Ok(r) => r,
         Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
     };
@@ -589,6 +757,155 @@ pub async fn delete_workflow(
     }
 }
 
+/// POST /tenants/:tenant_id/workflows/:name/rollback — re-activate the previous
+/// version of a package.
+///
+/// The newest successfully-built version registered before the active one
+/// becomes active again; the active version is superseded, not deleted.
+/// `name` is the package name.
+#[utoipa::path(
+    post,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/rollback",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Package name"),
+    ),
+    responses(
+        (status = 200, description = "Previous version re-activated", body = WorkflowRollbackResponse),
+        (status = 400, description = "No earlier built version to roll back to", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn rollback_workflow(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let tenant_db: cloacina::database::Database = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let mut registry = match state.tenant_registry(&tenant_id, tenant_db) {
+        Ok(r) => r,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+
+    match registry.rollback_workflow_package(&name).await {
+        Ok(event) => {
+            let previous_version = event.previous_version.unwrap_or_default();
+            info!(
+                "Rolled back workflow '{}' v{} -> v{} for tenant '{}'",
+                name, previous_version, event.version, tenant_id
+            );
+            Json(WorkflowRollbackResponse {
+                tenant_id,
+                id: event.package_id.to_string(),
+                package_name: name,
+                version: event.version,
+                previous_version,
+            })
+            .into_response()
+        }
+        Err(cloacina::registry::RegistryError::ValidationError { reason }) => {
+            ApiError::bad_request("no_previous_version", reason).into_response()
+        }
+        Err(e) => {
+            warn!(
+                "Failed to roll back workflow '{}' for tenant '{}': {}",
+                name, tenant_id, e
+            );
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
+/// GET /tenants/:tenant_id/workflows/:name/versions — version history of a
+/// package.
+///
+/// Lists every registered version, newest first, including superseded ones
+/// and ones still building or failed, so operators can pick a rollback
+/// target. `name` is the package name.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/versions",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Package name"),
+    ),
+    responses(
+        (status = 200, description = "Package versions, newest first", body = WorkflowVersionsResponse),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Package not found", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_workflow_versions(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let tenant_db: cloacina::database::Database = match state
+        .tenant_databases
+        .resolve(&tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            return ApiError::internal(format!("tenant database error: {}", e)).into_response()
+        }
+    };
+    let registry = match state.tenant_registry(&tenant_id, tenant_db) {
+        Ok(r) => r,
+        Err(e) => return ApiError::internal(format!("{}", e)).into_response(),
+    };
+
+    match registry.list_package_versions(&name).await {
+        Ok(versions) if versions.is_empty() => ApiError::not_found(
+            "workflow_not_found",
+            format!("package '{}' not found", name),
+        )
+        .into_response(),
+        Ok(versions) => Json(WorkflowVersionsResponse {
+            tenant_id,
+            package_name: name,
+            versions: versions
+                .into_iter()
+                .map(|v| WorkflowVersionSummary {
+                    id: v.id.to_string(),
+                    version: v.version,
+                    content_hash: v.content_hash,
+                    build_status: v.build_status,
+                    build_error: v.build_error,
+                    active: v.active,
+                    created_at: v.created_at.to_rfc3339(),
+                    compiled_at: v.compiled_at.map(|t| t.to_rfc3339()),
+                })
+                .collect(),
+        })
+        .into_response(),
+        Err(e) => {
+            warn!(
+                "Failed to list versions of workflow '{}' for tenant '{}': {}",
+                name, tenant_id, e
+            );
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
 /// POST /tenants/:tenant_id/workflows/:name/pause — pause a workflow (CLOACI-T-0749).
 ///
 /// Blocks new executions of the workflow (manual and triggered) until resumed.
@@ -705,16 +1022,278 @@ async fn set_workflow_paused(
     }
 }
 
-/// Extract the first file field from a multipart request.
-async fn extract_file_field(multipart: &mut Multipart) -> Result<Vec<u8>, String> {
-    while let Ok(Some(field)) = multipart.next_field().await {
-        if field.name() == Some("file") {
-            let data = field
-                .bytes()
-                .await
-                .map_err(|e| format!("failed to read file: {}", e))?;
-            return Ok(data.to_vec());
+/// PUT /tenants/:tenant_id/workflows/:name/deprecation — mark a workflow
+/// deprecated.
+///
+/// The workflow keeps running, but every execution logs a warning with the
+/// sunset date and message, and the runner refuses new cron schedules against
+/// it. Re-deprecating replaces the sunset date and message.
+#[utoipa::path(
+    put,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/deprecation",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Workflow name"),
+    ),
+    request_body = WorkflowDeprecationRequest,
+    responses(
+        (status = 200, description = "Workflow deprecated", body = WorkflowDeprecationResponse),
+        (status = 400, description = "Invalid sunset_at", body = cloacina_api_types::ErrorBody),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn deprecate_workflow(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+    Json(request): Json<WorkflowDeprecationRequest>,
+) -> impl IntoResponse {
+    let sunset_at = match request
+        .sunset_at
+        .as_deref()
+        .map(chrono::DateTime::parse_from_rfc3339)
+    {
+        None => None,
+        Some(Ok(t)) => Some(cloacina::UniversalTimestamp(t.with_timezone(&chrono::Utc))),
+        Some(Err(e)) => {
+            return ApiError::bad_request(
+                "invalid_sunset",
+                format!("sunset_at must be an RFC 3339 timestamp: {}", e),
+            )
+            .into_response()
+        }
+    };
+    let dal = match tenant_dal(&state, &tenant_id).await {
+        Ok(dal) => dal,
+        Err(resp) => return resp,
+    };
+
+    match dal
+        .workflow_deprecation()
+        .deprecate(&name, request.message, sunset_at)
+        .await
+    {
+        Ok(deprecation) => {
+            info!("Deprecated workflow '{}' for tenant '{}'", name, tenant_id);
+            Json(WorkflowDeprecationResponse {
+                tenant_id,
+                name,
+                deprecated: true,
+                deprecation: Some(deprecation_info(&deprecation)),
+            })
+            .into_response()
+        }
+        Err(e) => {
+            warn!(
+                "Failed to deprecate workflow '{}' for tenant '{}': {}",
+                name, tenant_id, e
+            );
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
+/// DELETE /tenants/:tenant_id/workflows/:name/deprecation — clear a
+/// workflow's deprecation. New schedules are accepted again.
+#[utoipa::path(
+    delete,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/deprecation",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Workflow name"),
+    ),
+    responses(
+        (status = 200, description = "Deprecation cleared", body = WorkflowDeprecationResponse),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access or role denied", body = cloacina_api_types::ErrorBody),
+        (status = 404, description = "Workflow is not deprecated", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn undeprecate_workflow(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let dal = match tenant_dal(&state, &tenant_id).await {
+        Ok(dal) => dal,
+        Err(resp) => return resp,
+    };
+
+    match dal.workflow_deprecation().remove(&name).await {
+        Ok(true) => {
+            info!(
+                "Cleared deprecation of workflow '{}' for tenant '{}'",
+                name, tenant_id
+            );
+            Json(WorkflowDeprecationResponse {
+                tenant_id,
+                name,
+                deprecated: false,
+                deprecation: None,
+            })
+            .into_response()
+        }
+        Ok(false) => ApiError::not_found(
+            "not_deprecated",
+            format!("workflow '{}' is not deprecated", name),
+        )
+        .into_response(),
+        Err(e) => {
+            warn!(
+                "Failed to clear deprecation of workflow '{}' for tenant '{}': {}",
+                name, tenant_id, e
+            );
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
+/// GET /tenants/:tenant_id/workflows/:name/consumers — what still starts a
+/// workflow.
+///
+/// Lists the schedules and reactor subscriptions that start it and its
+/// in-flight executions; `safe_to_remove` is `true` once all are empty.
+#[utoipa::path(
+    get,
+    path = "/v1/tenants/{tenant_id}/workflows/{name}/consumers",
+    tag = "workflows",
+    params(
+        ("tenant_id" = String, Path, description = "Tenant identifier"),
+        ("name" = String, Path, description = "Workflow name"),
+    ),
+    responses(
+        (status = 200, description = "Remaining consumers", body = WorkflowConsumersResponse),
+        (status = 401, description = "Missing or invalid API key", body = cloacina_api_types::ErrorBody),
+        (status = 403, description = "Tenant access denied", body = cloacina_api_types::ErrorBody),
+        (status = 500, description = "Internal error", body = cloacina_api_types::ErrorBody),
+    ),
+    security(("api_key" = []))
+)]
+pub async fn get_workflow_consumers(
+    State(state): State<AppState>,
+    Extension(_auth): Extension<AuthenticatedKey>,
+    Path((tenant_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let dal = match tenant_dal(&state, &tenant_id).await {
+        Ok(dal) => dal,
+        Err(resp) => return resp,
+    };
+
+    match cloacina::runner::WorkflowConsumers::load(&dal, &name).await {
+        Ok(consumers) => {
+            let safe_to_remove = consumers.is_empty();
+            Json(WorkflowConsumersResponse {
+                tenant_id,
+                name,
+                deprecation: consumers.deprecation.as_ref().map(deprecation_info),
+                schedules: consumers
+                    .schedules
+                    .into_iter()
+                    .map(crate::routes::triggers::schedule_summary)
+                    .collect(),
+                reactors: consumers.reactors,
+                active_executions: consumers
+                    .active_executions
+                    .into_iter()
+                    .map(|id| id.0.to_string())
+                    .collect(),
+                safe_to_remove,
+            })
+            .into_response()
+        }
+        Err(e) => {
+            warn!(
+                "Failed to list consumers of workflow '{}' for tenant '{}': {}",
+                name, tenant_id, e
+            );
+            ApiError::internal(format!("{}", e)).into_response()
+        }
+    }
+}
+
+/// The tenant's DAL, or the error response to return.
+async fn tenant_dal(
+    state: &AppState,
+    tenant_id: &str,
+) -> Result<cloacina::dal::DAL, axum::response::Response> {
+    match state
+        .tenant_databases
+        .resolve(tenant_id, &state.database)
+        .await
+    {
+        Ok(db) => Ok(cloacina::dal::DAL::new(db)),
+        Err(e) => Err(ApiError::internal(format!("tenant database error: {}", e)).into_response()),
+    }
+}
+
+fn deprecation_info(
+    d: &cloacina::models::workflow_deprecation::WorkflowDeprecation,
+) -> WorkflowDeprecationInfo {
+    WorkflowDeprecationInfo {
+        workflow_name: d.workflow_name.clone(),
+        message: d.message.clone(),
+        sunset_at: d.sunset_at.map(|t| t.0.to_rfc3339()),
+        deprecated_at: d.deprecated_at.0.to_rfc3339(),
+        past_sunset: d.is_past_sunset(chrono::Utc::now()),
+    }
+}
+
+/// Read a package upload: spool the first `file` field to a temp file, chunk
+/// by chunk, and parse the optional `config` field as JSON. The file is
+/// removed when the returned handle drops.
+async fn extract_upload(
+    multipart: &mut Multipart,
+) -> Result<(tempfile::NamedTempFile, Option<serde_json::Value>), String> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut package = None;
+    let mut config = None;
+    while let Ok(Some(mut field)) = multipart.next_field().await {
+        match field.name() {
+            Some("file") if package.is_none() => {
+                let spool = tempfile::Builder::new()
+                    .suffix(".cloacina")
+                    .tempfile()
+                    .map_err(|e| format!("failed to spool upload: {}", e))?;
+                let mut file = tokio::fs::File::from_std(
+                    spool
+                        .reopen()
+                        .map_err(|e| format!("failed to spool upload: {}", e))?,
+                );
+                while let Some(chunk) = field
+                    .chunk()
+                    .await
+                    .map_err(|e| format!("failed to read file: {}", e))?
+                {
+                    file.write_all(&chunk)
+                        .await
+                        .map_err(|e| format!("failed to spool upload: {}", e))?;
+                }
+                file.flush()
+                    .await
+                    .map_err(|e| format!("failed to spool upload: {}", e))?;
+                package = Some(spool);
+            }
+            Some("config") => {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|e| format!("failed to read config: {}", e))?;
+                config = Some(
+                    serde_json::from_str(&text)
+                        .map_err(|e| format!("config is not valid JSON: {}", e))?,
+                );
+            }
+            _ => {}
         }
     }
-    Err("no 'file' field in multipart request".to_string())
+    let package = package.ok_or_else(|| "no 'file' field in multipart request".to_string())?;
+    Ok((package, config))
 }
@@ -34,14 +34,19 @@
 //!    [`SecretStoreResolver`] over the tenant's schema + derived `org_id` + the
 //!    server KEK, so a `$secret`-referencing fleet task actually resolves (it
 //!    failed closed while the factory was `None`).
+//!
+//! Both resolvers also issue short-lived cloud credentials (`aws-role:…`,
+//! `gcp-sa:…`) when `CLOACINA_CLOUD_CREDENTIALS` is set, limited to the
+//! identities `CLOACINA_CLOUD_IDENTITY_MAP` grants the tenant. See
+//! [`init_cloud_credentials`].
 
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use async_trait::async_trait;
 
 use cloacina::database::universal_types::UniversalUuid;
 use cloacina::database::Database;
-use cloacina::security::{SecretStore, SecretStoreResolver};
+use cloacina::security::{CloudCredentials, SecretStore, SecretStoreResolver};
 use cloacina::SecretResolver;
 
 use crate::fleet_executor::FleetSecretResolverFactory;
@@ -53,6 +58,36 @@ use crate::TenantDatabaseCache;
 const TENANT_ORG_NAMESPACE: uuid::Uuid =
     uuid::Uuid::from_u128(0xC10AC1A5_0133_5EC0_BABE_000000000000u128);
 
+/// Process-wide cloud credential issuers, set once at startup by
+/// [`init_cloud_credentials`]. Shared by every tenant so a granted role's
+/// session is cached once.
+static CLOUD_CREDENTIALS: OnceLock<Option<Arc<CloudCredentials>>> = OnceLock::new();
+
+/// Build the cloud credential issuers from the environment. Called once from
+/// `run()`; a malformed configuration fails startup rather than surfacing on
+/// the first task that needs credentials.
+pub fn init_cloud_credentials() -> anyhow::Result<()> {
+    let credentials = CloudCredentials::from_env()?.map(Arc::new);
+    if let Some(credentials) = &credentials {
+        tracing::info!(?credentials, "cloud credential issuers enabled");
+    }
+    let _ = CLOUD_CREDENTIALS.set(credentials);
+    Ok(())
+}
+
+/// Layer the cloud credential issuers, scoped to `tenant`'s grants, over a
+/// tenant's secret resolver. Passes `resolver` through when no issuer is
+/// configured.
+fn with_cloud_credentials(
+    tenant: &str,
+    resolver: Option<Arc<dyn SecretResolver>>,
+) -> Option<Arc<dyn SecretResolver>> {
+    match CLOUD_CREDENTIALS.get().and_then(Option::as_ref) {
+        Some(credentials) => Some(credentials.tenant_resolver(tenant, resolver)),
+        None => resolver,
+    }
+}
+
 /// Map a tenant's schema-name to its stable `org_id`.
 ///
 /// Deterministic (UUIDv5 over [`TENANT_ORG_NAMESPACE`] + the schema name): the
@@ -105,20 +140,24 @@ impl ServerFleetSecretResolverFactory {
 /// Tenant-scoped secret resolver for a runner's IN-PROCESS thread executor
 /// (CLOACI-T-0890): threaded at runner construction so `context.secret(...)`
 /// resolves when tasks execute locally (the `"default"` executor) rather than
-/// on the fleet. Returns `None` when `CLOACINA_SECRET_KEK` is unset — the
-/// executor then fails closed with the clear "secrets backend not configured"
-/// error. Same composition as the fleet factory: tenant DB → SecretStore →
-/// tenant-org-scoped SecretStoreResolver.
+/// on the fleet. Returns `None` when `CLOACINA_SECRET_KEK` is unset and no
+/// cloud credential issuer is configured — the executor then fails closed
+/// with the clear "secrets backend not configured" error. Same composition as
+/// the fleet factory: tenant DB → SecretStore → tenant-org-scoped
+/// SecretStoreResolver, under the tenant's cloud credential grants.
 pub fn runner_secret_resolver(tenant: &str, db: &Database) -> Option<Arc<dyn SecretResolver>> {
-    let kek = SecretStoreResolver::kek_from_env().ok()?;
-    let store = SecretStore::new(cloacina::dal::DAL::new(db.clone()));
-    Some(SecretStoreResolver::new(store, tenant_org_id(tenant), kek).into_arc())
+    let store_resolver = SecretStoreResolver::kek_from_env().ok().map(|kek| {
+        let store = SecretStore::new(cloacina::dal::DAL::new(db.clone()));
+        SecretStoreResolver::new(store, tenant_org_id(tenant), kek).into_arc()
+    });
+    with_cloud_credentials(tenant, store_resolver)
 }
 
 #[async_trait]
 impl FleetSecretResolverFactory for ServerFleetSecretResolverFactory {
     async fn resolver_for(&self, tenant: &str, package: &str) -> Option<Arc<dyn SecretResolver>> {
-        // No KEK ⇒ secrets are not configured on this deployment → fail closed.
+        // No KEK ⇒ stored secrets are not configured on this deployment → fail
+        // closed for them. Cloud credentials, when configured, still resolve.
         let kek = match SecretStoreResolver::kek_from_env() {
             Ok(kek) => kek,
             Err(e) => {
@@ -126,10 +165,10 @@ impl FleetSecretResolverFactory for ServerFleetSecretResolverFactory {
                     tenant = %tenant,
                     package = %package,
                     error = %e,
-                    "fleet secret resolver: server KEK unavailable — cannot resolve secrets \
-                     for this dispatch (failing closed, no plaintext fallback)"
+                    "fleet secret resolver: server KEK unavailable — cannot resolve stored \
+                     secrets for this dispatch (failing closed, no plaintext fallback)"
                 );
-                return None;
+                return with_cloud_credentials(tenant, None);
             }
         };
 
@@ -150,7 +189,7 @@ impl FleetSecretResolverFactory for ServerFleetSecretResolverFactory {
         let org_id = tenant_org_id(tenant);
         // Tenant-scoped resolver (see the grant-scope note on the struct).
         let resolver = SecretStoreResolver::new(store, org_id, kek);
-        Some(resolver.into_arc())
+        with_cloud_credentials(tenant, Some(resolver.into_arc()))
     }
 }
 
@@ -241,6 +241,13 @@ impl TenantRunnerCache {
         Ok(runner)
     }
 
+    /// The tenant's cached runner, if any. Unlike `get_or_create` this
+    /// never constructs a runner and does not refresh its LRU position,
+    /// so read-only callers (stats) don't keep idle tenants alive.
+    pub async fn get(&self, tenant_id: &str) -> Option<Arc<DefaultRunner>> {
+        self.cache.lock().await.peek(tenant_id).cloned()
+    }
+
     /// Explicitly evict a tenant's runner from the cache, awaiting its
     /// graceful shutdown. Used by tenant-deletion teardown (CLOACI-T-0581).
     /// Returns `Ok(true)` if a runner was evicted, `Ok(false)` if none
@@ -329,6 +336,30 @@ impl TenantRunnerCache {
         results
     }
 
+    /// Drain every cached runner for a blue/green handoff. Like
+    /// [`shutdown_all`](Self::shutdown_all), but each runner gets up to
+    /// `timeout` to finish its in-flight tasks. The result is `Ok(true)`
+    /// when a runner drained fully.
+    pub async fn drain_all(
+        &self,
+        timeout: std::time::Duration,
+    ) -> HashMap<String, Result<bool, String>> {
+        let runners: Vec<(String, Arc<DefaultRunner>)> = {
+            let mut cache = self.cache.lock().await;
+            let mut drained = Vec::with_capacity(cache.len());
+            while let Some((k, v)) = cache.pop_lru() {
+                drained.push((k, v));
+            }
+            drained
+        };
+        let mut results = HashMap::with_capacity(runners.len());
+        for (tenant_id, runner) in runners {
+            let r = runner.drain(timeout).await.map_err(|e| e.to_string());
+            results.insert(tenant_id, r);
+        }
+        results
+    }
+
     /// Current number of cached runners. Test/observability helper.
     pub async fn len(&self) -> usize {
         self.cache.lock().await.len()
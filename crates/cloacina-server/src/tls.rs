@@ -0,0 +1,440 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! TLS termination for the API listener (REST, agent and delivery
+//! WebSockets).
+//!
+//! With `--tls-cert`/`--tls-key` the server speaks HTTPS only. Additional
+//! certificates can be served by SNI hostname (`--tls-sni-cert
+//! host=cert.pem:key.pem`); a client that sends no SNI, or an unknown name,
+//! gets the default certificate. `--tls-client-ca` turns on mutual TLS:
+//! clients must present a certificate issued by one of those CAs (or, with
+//! `--tls-client-auth optional`, may present none). Client certificates gate
+//! the connection only — every request still authenticates with an API key
+//! or bearer token.
+//!
+//! Handshakes run off the accept loop, each bounded by
+//! [`HANDSHAKE_TIMEOUT`], so a slow or stalled client cannot hold up others.
+//! Certificates are read once at startup; restart the server to rotate them.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier};
+use rustls::sign::CertifiedKey;
+use rustls::{RootCertStore, ServerConfig};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, warn};
+
+/// Upper bound on a single TLS handshake.
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Completed handshakes waiting for the HTTP server to pick them up.
+const ACCEPT_BACKLOG: usize = 1024;
+
+/// Whether clients must present a certificate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClientAuth {
+    /// Client certificates are neither requested nor checked.
+    None,
+    /// A presented certificate must chain to `client_ca`; clients may
+    /// present none.
+    Optional,
+    /// Every client must present a certificate chaining to `client_ca`.
+    #[default]
+    Required,
+}
+
+impl FromStr for ClientAuth {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(ClientAuth::None),
+            "optional" => Ok(ClientAuth::Optional),
+            "required" => Ok(ClientAuth::Required),
+            other => Err(format!(
+                "unknown client auth mode '{}' (expected none, optional or required)",
+                other
+            )),
+        }
+    }
+}
+
+/// A certificate served to clients that ask for `hostname` via SNI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SniCert {
+    pub hostname: String,
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+impl FromStr for SniCert {
+    type Err = String;
+
+    /// Parse `hostname=cert.pem:key.pem`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (hostname, paths) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected host=cert.pem:key.pem, got '{}'", s))?;
+        let (cert, key) = paths
+            .split_once(':')
+            .ok_or_else(|| format!("expected host=cert.pem:key.pem, got '{}'", s))?;
+        let hostname = hostname.trim().to_ascii_lowercase();
+        if hostname.is_empty() || cert.is_empty() || key.is_empty() {
+            return Err(format!("expected host=cert.pem:key.pem, got '{}'", s));
+        }
+        Ok(Self {
+            hostname,
+            cert: PathBuf::from(cert),
+            key: PathBuf::from(key),
+        })
+    }
+}
+
+/// Operator configuration for serving the API over TLS.
+#[derive(Debug, Clone, Default)]
+pub struct TlsConfig {
+    /// PEM certificate chain served by default.
+    pub cert: PathBuf,
+    /// PEM private key for `cert`.
+    pub key: PathBuf,
+    /// Extra certificates selected by SNI hostname.
+    pub sni_certs: Vec<SniCert>,
+    /// PEM bundle of CAs that issue client certificates. Enables mTLS.
+    pub client_ca: Option<PathBuf>,
+    /// How strictly client certificates are required when `client_ca` is set.
+    pub client_auth: ClientAuth,
+}
+
+impl TlsConfig {
+    /// Build the rustls server config. Unreadable or mismatched certificates
+    /// and keys fail at boot.
+    pub fn server_config(&self) -> Result<Arc<ServerConfig>> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+        let default = load_certified_key(&provider, &self.cert, &self.key)?;
+        let mut by_name = HashMap::new();
+        for sni in &self.sni_certs {
+            let key = load_certified_key(&provider, &sni.cert, &sni.key)
+                .with_context(|| format!("TLS certificate for SNI host '{}'", sni.hostname))?;
+            by_name.insert(sni.hostname.clone(), key);
+        }
+        let resolver = Arc::new(SniResolver { by_name, default });
+
+        let builder = ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .context("TLS protocol versions")?;
+        let builder = match (&self.client_ca, self.client_auth) {
+            (Some(ca), ClientAuth::Optional | ClientAuth::Required) => {
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(ca)? {
+                    roots
+                        .add(cert)
+                        .with_context(|| format!("invalid client CA in {}", ca.display()))?;
+                }
+                let mut verifier =
+                    WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+                if self.client_auth == ClientAuth::Optional {
+                    verifier = verifier.allow_unauthenticated();
+                }
+                let verifier = verifier
+                    .build()
+                    .map_err(|e| anyhow!("client certificate verifier: {}", e))?;
+                builder.with_client_cert_verifier(verifier)
+            }
+            (None, ClientAuth::Optional) => {
+                bail!("--tls-client-auth optional requires --tls-client-ca")
+            }
+            _ => builder.with_no_client_auth(),
+        };
+
+        let mut config = builder.with_cert_resolver(resolver);
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        Ok(Arc::new(config))
+    }
+
+    /// Whether the config verifies client certificates.
+    pub fn mutual(&self) -> bool {
+        self.client_ca.is_some() && self.client_auth != ClientAuth::None
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .with_context(|| format!("failed to read certificates from {}", path.display()))?
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .with_context(|| format!("invalid PEM certificate in {}", path.display()))?;
+    if certs.is_empty() {
+        bail!("no certificates found in {}", path.display());
+    }
+    Ok(certs)
+}
+
+fn load_certified_key(
+    provider: &CryptoProvider,
+    cert: &Path,
+    key: &Path,
+) -> Result<Arc<CertifiedKey>> {
+    let certs = load_certs(cert)?;
+    let key_der = PrivateKeyDer::from_pem_file(key)
+        .with_context(|| format!("failed to read private key from {}", key.display()))?;
+    let signing_key = provider
+        .key_provider
+        .load_private_key(key_der)
+        .map_err(|e| anyhow!("unsupported private key in {}: {}", key.display(), e))?;
+    let certified = CertifiedKey::new(certs, signing_key);
+    // Keys whose public half the provider cannot derive are let through;
+    // only a definite mismatch fails.
+    if let Err(rustls::Error::InconsistentKeys(rustls::InconsistentKeys::KeyMismatch)) =
+        certified.keys_match()
+    {
+        bail!(
+            "private key {} does not match certificate {}",
+            key.display(),
+            cert.display()
+        );
+    }
+    Ok(Arc::new(certified))
+}
+
+/// Serves the certificate registered for the client's SNI name, falling back
+/// to the default one.
+#[derive(Debug)]
+struct SniResolver {
+    by_name: HashMap<String, Arc<CertifiedKey>>,
+    default: Arc<CertifiedKey>,
+}
+
+impl ResolvesServerCert for SniResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let key = client_hello
+            .server_name()
+            .and_then(|name| self.by_name.get(&name.to_ascii_lowercase()))
+            .unwrap_or(&self.default);
+        Some(key.clone())
+    }
+}
+
+/// A TCP listener that hands the HTTP server only connections that completed
+/// a TLS handshake.
+pub struct TlsListener {
+    local_addr: SocketAddr,
+    accepted: mpsc::Receiver<(TlsStream<TcpStream>, SocketAddr)>,
+}
+
+impl TlsListener {
+    /// Start accepting on `listener`, terminating TLS with `config`.
+    pub fn new(listener: TcpListener, config: Arc<ServerConfig>) -> std::io::Result<Self> {
+        let local_addr = listener.local_addr()?;
+        let acceptor = TlsAcceptor::from(config);
+        let (tx, accepted) = mpsc::channel(ACCEPT_BACKLOG);
+        tokio::spawn(accept_loop(listener, acceptor, tx));
+        Ok(Self {
+            local_addr,
+            accepted,
+        })
+    }
+
+    /// This listener in the form `axum::serve` needs to hand handlers
+    /// `ConnectInfo<SocketAddr>`. axum only implements `Connected` for its
+    /// own listener types, `TapIo` among them, so a no-op tap carries the
+    /// peer address through.
+    pub fn with_connect_info(
+        self,
+    ) -> axum::serve::TapIo<Self, impl FnMut(&mut TlsStream<TcpStream>) + Send + 'static> {
+        axum::serve::ListenerExt::tap_io(self, |_| {})
+    }
+}
+
+async fn accept_loop(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    tx: mpsc::Sender<(TlsStream<TcpStream>, SocketAddr)>,
+) {
+    loop {
+        let (tcp, peer) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(conn) => conn,
+                Err(e) => {
+                    // Usually EMFILE/ENFILE; back off instead of spinning.
+                    warn!(error = %e, "TLS listener accept failed");
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    continue;
+                }
+            },
+            _ = tx.closed() => return,
+        };
+        let acceptor = acceptor.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(tcp)).await {
+                Ok(Ok(stream)) => {
+                    let _ = tx.send((stream, peer)).await;
+                }
+                Ok(Err(e)) => debug!(peer = %peer, error = %e, "TLS handshake failed"),
+                Err(_) => debug!(peer = %peer, "TLS handshake timed out"),
+            }
+        });
+    }
+}
+
+impl axum::serve::Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        match self.accepted.recv().await {
+            Some(conn) => conn,
+            // The accept loop only stops once this receiver is gone.
+            None => std::future::pending().await,
+        }
+    }
+
+    fn local_addr(&self) -> std::io::Result<Self::Addr> {
+        Ok(self.local_addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sni_cert_parses() {
+        let sni: SniCert = "API.example.com=/etc/tls/api.crt:/etc/tls/api.key"
+            .parse()
+            .unwrap();
+        assert_eq!(sni.hostname, "api.example.com");
+        assert_eq!(sni.cert, PathBuf::from("/etc/tls/api.crt"));
+        assert_eq!(sni.key, PathBuf::from("/etc/tls/api.key"));
+
+        for bad in [
+            "api.example.com",
+            "api.example.com=/a.crt",
+            "=/a.crt:/a.key",
+        ] {
+            assert!(bad.parse::<SniCert>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn client_auth_parses() {
+        assert_eq!("none".parse::<ClientAuth>().unwrap(), ClientAuth::None);
+        assert_eq!(
+            "Optional".parse::<ClientAuth>().unwrap(),
+            ClientAuth::Optional
+        );
+        assert_eq!(
+            "required".parse::<ClientAuth>().unwrap(),
+            ClientAuth::Required
+        );
+        assert!("strict".parse::<ClientAuth>().is_err());
+    }
+
+    #[test]
+    fn missing_certificate_fails_at_boot() {
+        let config = TlsConfig {
+            cert: PathBuf::from("/nonexistent/cert.pem"),
+            key: PathBuf::from("/nonexistent/key.pem"),
+            ..Default::default()
+        };
+        let err = config.server_config().unwrap_err();
+        assert!(format!("{:#}", err).contains("/nonexistent/cert.pem"));
+    }
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test-fixtures/tls")
+            .join(name)
+    }
+
+    #[tokio::test]
+    async fn serves_https_with_peer_address() {
+        use axum::extract::ConnectInfo;
+        use rustls::pki_types::ServerName;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let config = TlsConfig {
+            cert: fixture("server.crt"),
+            key: fixture("server.key"),
+            ..Default::default()
+        };
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let listener = TlsListener::new(listener, config.server_config().unwrap()).unwrap();
+        let addr = axum::serve::Listener::local_addr(&listener).unwrap();
+        let listener = listener.with_connect_info();
+        let app = axum::Router::new().route(
+            "/peer",
+            axum::routing::get(|ConnectInfo(peer): ConnectInfo<SocketAddr>| async move {
+                peer.to_string()
+            }),
+        );
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+        });
+
+        let mut roots = RootCertStore::empty();
+        for cert in load_certs(&fixture("server.crt")).unwrap() {
+            roots.add(cert).unwrap();
+        }
+        let client = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+        let tcp = TcpStream::connect(addr).await.unwrap();
+        let local = tcp.local_addr().unwrap();
+        let mut tls = tokio_rustls::TlsConnector::from(Arc::new(client))
+            .connect(ServerName::try_from("localhost").unwrap(), tcp)
+            .await
+            .unwrap();
+        tls.write_all(b"GET /peer HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        tls.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert!(response.ends_with(&local.to_string()), "{}", response);
+    }
+
+    #[test]
+    fn mutual_needs_a_client_ca() {
+        let mut config = TlsConfig::default();
+        assert!(!config.mutual());
+        config.client_ca = Some(PathBuf::from("/etc/tls/clients.pem"));
+        assert!(config.mutual());
+        config.client_auth = ClientAuth::None;
+        assert!(!config.mutual());
+    }
+}
@@ -169,9 +169,28 @@ pub struct WorkflowDescriptorEntry {
     /// `#[workflow(params(...))]` macro via `cloacina_workflow::schema_for`.
     /// Returns `"[]"` for workflows that declare no params.
     pub params: fn() -> ::std::string::String,
+    /// Declared outputs from `#[workflow(outputs(...))]`, as a JSON array of
+    /// `InputSlot` (one slot per output key; `Option<T>` outputs are optional).
+    /// Returns `"[]"` for
+    /// workflows that declare no outputs.
+    pub outputs: fn() -> ::std::string::String,
+    /// Optional load-time health check from
+    /// `#[workflow(health_check = some_fn)]`. The registry runs it through
+    /// `run_health_checks` after dlopen; a failing check marks the package
+    /// Degraded and keeps it out of scheduling. `None` when not declared.
+    pub health_check: Option<WorkflowHealthCheckFn>,
 }
 inventory::collect!(WorkflowDescriptorEntry);
 
+/// Future returned by a workflow health check: `Err` carries the reason the
+/// package should be considered Degraded.
+pub type WorkflowHealthCheckFuture =
+    Pin<Box<dyn Future<Output = Result<(), ::std::string::String>> + Send>>;
+
+/// Entry point emitted by `#[workflow(health_check = ...)]`, wrapping the
+/// author's `async fn` so its error type is flattened to a string.
+pub type WorkflowHealthCheckFn = fn() -> WorkflowHealthCheckFuture;
+
 /// Computation graph entry emitted by `#[computation_graph]` for the
 /// reactor-triggered (split) form. The `package!()` shell walks
 /// `inventory::iter::<ComputationGraphEntry>` to build the metadata
@@ -45,17 +45,18 @@ pub mod types;
 pub use inventory_entries::{
     AccumulatorEntry, ComputationGraphEntry, ConstructorEntry, ReactorEntry, TaskEntry,
     TriggerEntry, TriggerlessGraph, TriggerlessGraphEntry, TriggerlessGraphFn,
-    TriggerlessGraphRegistration, WorkflowDescriptorEntry,
+    TriggerlessGraphRegistration, WorkflowDescriptorEntry, WorkflowHealthCheckFn,
+    WorkflowHealthCheckFuture,
 };
 
 // Re-export the interface types for convenience
 pub use types::{
-    AccumulatorDeclarationEntry, CloacinaMetadata, ConstructorPackageMetadata,
-    GraphExecutionRequest, GraphExecutionResult, GraphPackageMetadata, InputInterfaceDescriptor,
-    InputInterfaceEntry, PackageTasksMetadata, ProviderDep, ReactorPackageMetadata,
-    TaskExecutionRequest, TaskExecutionResult, TaskMetadataEntry, TriggerInvokeRequest,
-    TriggerInvokeResult, TriggerPackageMetadata, TriggerlessGraphInvokeRequest,
-    TriggerlessGraphInvokeResult, TriggerlessGraphMetadataEntry,
+    AccumulatorDeclarationEntry, CapabilityManifest, CloacinaMetadata, ConstructorPackageMetadata,
+    GraphExecutionRequest, GraphExecutionResult, GraphPackageMetadata, HealthCheckReport,
+    HealthCheckRequest, InputInterfaceDescriptor, InputInterfaceEntry, PackageTasksMetadata,
+    ProviderDep, ReactorPackageMetadata, TaskExecutionRequest, TaskExecutionResult,
+    TaskMetadataEntry, TriggerInvokeRequest, TriggerInvokeResult, TriggerPackageMetadata,
+    TriggerlessGraphInvokeRequest, TriggerlessGraphInvokeResult, TriggerlessGraphMetadataEntry,
 };
 
 // Re-export fidius crates so generated code can reference them
@@ -160,6 +161,12 @@ macro_rules! package {
                                 .iter()
                                 .map(|n| n.task_id.clone())
                                 .collect();
+                        let soft_dependencies: ::std::vec::Vec<::std::string::String> =
+                            cloacina_workflow::Task::dependencies(&*task)
+                                .iter()
+                                .filter(|n| cloacina_workflow::Task::is_soft_dependency(&*task, n))
+                                .map(|n| n.task_id.clone())
+                                .collect();
                         tasks.push($crate::TaskMetadataEntry {
                             index: idx as u32,
                             id: cloacina_workflow::Task::id(&*task).to_string(),
@@ -172,6 +179,30 @@ macro_rules! package {
                             source_location: format!("{}/lib.rs", env!("CARGO_PKG_NAME")),
                             trigger_rules: cloacina_workflow::Task::trigger_rules(&*task)
                                 .to_string(),
+                            short_circuit: cloacina_workflow::Task::short_circuit(&*task),
+                            branch_key: cloacina_workflow::Task::branch_key(&*task),
+                            branch: cloacina_workflow::Task::branch(&*task),
+                            loop_until: cloacina_workflow::Task::loop_until(&*task)
+                                .map(|rule| rule.to_string()),
+                            max_iterations: cloacina_workflow::Task::max_iterations(&*task),
+                            soft_dependencies,
+                            memory_limit: cloacina_workflow::Task::memory_limit(&*task),
+                            timeout_ms: cloacina_workflow::Task::timeout(&*task)
+                                .map(|timeout| timeout.as_millis() as u64),
+                            requires_approval: cloacina_workflow::Task::requires_approval(
+                                &*task,
+                            ),
+                            workload_class: cloacina_workflow::Task::workload_class(&*task)
+                                .map(|class| class.as_str().to_string()),
+                            resources: cloacina_workflow::Task::resources(&*task),
+                            produces: cloacina_workflow::Task::produces(&*task)
+                                .iter()
+                                .map(|output| output.to_string())
+                                .collect(),
+                            scoped: cloacina_workflow::Task::scoped(&*task),
+                            reads: cloacina_workflow::Task::reads(&*task),
+                            configuration: cloacina_workflow::Task::configuration(&*task)
+                                .map(|config| config.to_string()),
                         });
                     }
                     // Look up WorkflowDescriptorEntry for description / author /
@@ -281,6 +312,44 @@ macro_rules! package {
                         ));
                     }
 
+                    // Capability manifest: when the host granted an explicit env
+                    // set, `context.env_var(...)` sees exactly that set.
+                    if let ::core::option::Option::Some(env) = request.granted_env {
+                        context.set_granted_env(env);
+                    }
+
+                    // Package configuration: the values the package was
+                    // registered with, read through `context.package_config()`.
+                    if let ::core::option::Option::Some(config_json) = request.package_config_json {
+                        match ::serde_json::from_str::<::serde_json::Value>(&config_json) {
+                            Ok(config) => context.set_package_config(config),
+                            Err(e) => {
+                                return Err($crate::PluginError {
+                                    code: "CONTEXT_ERROR".to_string(),
+                                    message: format!("Failed to parse package config: {}", e),
+                                    details: None,
+                                });
+                            }
+                        }
+                    }
+
+                    // Execution parameters, read through `context.params()`.
+                    if let ::core::option::Option::Some(params_json) = request.params_json {
+                        match ::serde_json::from_str::<
+                            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+                        >(&params_json)
+                        {
+                            Ok(params) => context.set_params(params),
+                            Err(e) => {
+                                return Err($crate::PluginError {
+                                    code: "CONTEXT_ERROR".to_string(),
+                                    message: format!("Failed to parse execution params: {}", e),
+                                    details: None,
+                                });
+                            }
+                        }
+                    }
+
                     let result = rt.block_on(async move {
                         cloacina_workflow::Task::execute(&*task, context).await
                     });
@@ -751,6 +820,17 @@ macro_rules! package {
                             surface_name: d.name.to_string(),
                             slots_json: (d.params)(),
                         });
+                        // Declared outputs ride the same descriptor under their
+                        // own surface kind, so no ABI change is needed.
+                        let outputs_json = (d.outputs)();
+                        if outputs_json != "[]" {
+                            entries.push($crate::InputInterfaceEntry {
+                                surface_kind: ::cloacina_workflow::outputs::OUTPUTS_SURFACE_KIND
+                                    .to_string(),
+                                surface_name: d.name.to_string(),
+                                slots_json: outputs_json,
+                            });
+                        }
                     }
 
                     // CLOACI-I-0128 (T-0758): computation-graph surfaces. Each CG
@@ -843,6 +923,62 @@ macro_rules! package {
                     }
                     Ok(out)
                 }
+
+                fn run_health_checks(
+                    &self,
+                    request: $crate::HealthCheckRequest,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<$crate::HealthCheckReport>,
+                    $crate::PluginError,
+                > {
+                    static CDYLIB_HEALTH_RUNTIME: ::std::sync::OnceLock<
+                        cloacina_workflow::__private::tokio::runtime::Runtime,
+                    > = ::std::sync::OnceLock::new();
+                    let rt = CDYLIB_HEALTH_RUNTIME.get_or_init(|| {
+                        cloacina_workflow::__private::tokio::runtime::Builder::new_multi_thread()
+                            .enable_all()
+                            .worker_threads(1)
+                            .thread_name("package-shell-health-worker")
+                            .build()
+                            .expect("Failed to create cdylib health-check tokio runtime")
+                    });
+                    let deadline = ::std::time::Duration::from_millis(request.timeout_ms);
+
+                    let mut out: ::std::vec::Vec<$crate::HealthCheckReport> =
+                        ::std::vec::Vec::new();
+                    for d in $crate::inventory::iter::<$crate::WorkflowDescriptorEntry> {
+                        let ::core::option::Option::Some(check) = d.health_check else {
+                            continue;
+                        };
+                        // Spawned (not block_on'd directly) so a panicking check
+                        // surfaces as a JoinError instead of unwinding across FFI.
+                        let outcome = rt.block_on(async move {
+                            cloacina_workflow::__private::tokio::time::timeout(
+                                deadline,
+                                cloacina_workflow::__private::tokio::spawn(check()),
+                            )
+                            .await
+                        });
+                        let message = match outcome {
+                            Ok(Ok(Ok(()))) => ::core::option::Option::None,
+                            Ok(Ok(Err(e))) => ::core::option::Option::Some(e),
+                            Ok(Err(join)) => ::core::option::Option::Some(format!(
+                                "health check panicked: {}",
+                                join
+                            )),
+                            Err(_) => ::core::option::Option::Some(format!(
+                                "health check timed out after {}ms",
+                                request.timeout_ms
+                            )),
+                        };
+                        out.push($crate::HealthCheckReport {
+                            workflow: d.name.to_string(),
+                            healthy: message.is_none(),
+                            message,
+                        });
+                    }
+                    Ok(out)
+                }
             }
 
             $crate::fidius_plugin_registry!();
@@ -880,6 +1016,9 @@ pub const METHOD_GET_INPUT_INTERFACE: usize = 9;
 /// See [`METHOD_GET_TASK_METADATA`]. Packaged `constructor!(...)` node declarations
 /// (CLOACI-T-0832), optional since interface version 4.
 pub const METHOD_GET_CONSTRUCTOR_METADATA: usize = 10;
+/// See [`METHOD_GET_TASK_METADATA`]. Load-time workflow health checks,
+/// optional since interface version 6.
+pub const METHOD_RUN_HEALTH_CHECKS: usize = 11;
 
 /// The plugin interface for cloacina workflow packages.
 ///
@@ -896,7 +1035,12 @@ pub const METHOD_GET_CONSTRUCTOR_METADATA: usize = 10;
 // version 4 → 5 (CLOACI-T-0895): `TaskExecutionRequest` gained the
 // `resolved_secrets` wire field — a bincode layout change, so stale artifacts
 // must fail the version gate at load rather than mis-decode.
-#[fidius::plugin_interface(version = 5, buffer = PluginAllocated)]
+// version 5 → 6: optional `run_health_checks` appended (no layout change).
+// version 6 → 7: `TaskExecutionRequest` gained `granted_env` (layout change).
+// version 7 → 8: `TaskExecutionRequest` gained `package_config_json` (layout
+// change).
+// version 8 → 9: `TaskExecutionRequest` gained `params_json` (layout change).
+#[fidius::plugin_interface(version = 9, buffer = PluginAllocated)]
 pub trait CloacinaPlugin: Send + Sync {
     /// Returns metadata about all tasks in this workflow package.
     /// Method index 0.
@@ -1014,4 +1158,17 @@ pub trait CloacinaPlugin: Send + Sync {
     /// `ConstructorPackageMetadata`. (CLOACI-T-0832)
     #[optional(since = 4)]
     fn get_constructor_metadata(&self) -> Result<Vec<ConstructorPackageMetadata>, PluginError>;
+
+    /// Runs every `#[workflow(health_check = ...)]` entry point in this
+    /// package and reports one `HealthCheckReport` per declared check. Method
+    /// index 11. Optional (since version 6): plugins built against older hosts
+    /// return `CallError::NotImplemented`, which the host treats as "no checks
+    /// declared" (healthy). The unified `cloacina::package!()` shell walks
+    /// `inventory::iter::<WorkflowDescriptorEntry>` and drives each check on a
+    /// dedicated cdylib tokio runtime under `request.timeout_ms`.
+    #[optional(since = 6)]
+    fn run_health_checks(
+        &self,
+        request: HealthCheckRequest,
+    ) -> Result<Vec<HealthCheckReport>, PluginError>;
 }
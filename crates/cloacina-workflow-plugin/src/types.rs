@@ -47,6 +47,61 @@ pub struct TaskMetadataEntry {
     /// `{"type":"Always"}` for packages built before this field existed.
     #[serde(default = "default_trigger_rules")]
     pub trigger_rules: String,
+    /// Output key whose emptiness short-circuits the task's descendants
+    /// (`Task::short_circuit`). `None` when the task never short-circuits.
+    #[serde(default)]
+    pub short_circuit: Option<String>,
+    /// Output key holding the branch name this decider selects
+    /// (`Task::branch_key`). `None` when the task is not a branch decider.
+    #[serde(default)]
+    pub branch_key: Option<String>,
+    /// Branch the task heads (`Task::branch`). `None` when the task runs
+    /// regardless of any decider's choice.
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// Serialized `loop_until` condition JSON (`Task::loop_until`). `None`
+    /// when the task body runs once.
+    #[serde(default)]
+    pub loop_until: Option<String>,
+    /// Most iterations of the task's loop (`Task::max_iterations`).
+    #[serde(default)]
+    pub max_iterations: Option<u32>,
+    /// Local IDs of the dependencies the task treats as soft
+    /// (`Task::is_soft_dependency`); a subset of `dependencies`.
+    #[serde(default)]
+    pub soft_dependencies: Vec<String>,
+    /// RSS growth limit in bytes (`Task::memory_limit`). `None` is unlimited.
+    #[serde(default)]
+    pub memory_limit: Option<u64>,
+    /// Execution timeout in milliseconds (`Task::timeout`). `None` uses the
+    /// runner default.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Whether the task waits for a human approval before it runs
+    /// (`Task::requires_approval`).
+    #[serde(default)]
+    pub requires_approval: bool,
+    /// Workload class name (`Task::workload_class`, e.g. `"cpu"`). `None`
+    /// when the task is unclassified.
+    #[serde(default)]
+    pub workload_class: Option<String>,
+    /// Named resources the task uses (`Task::resources`).
+    #[serde(default)]
+    pub resources: Vec<String>,
+    /// Declared outputs in `"key: kind"` form (`Task::produces`).
+    #[serde(default)]
+    pub produces: Vec<String>,
+    /// Whether the task's writes move under its ID (`Task::scoped`).
+    #[serde(default)]
+    pub scoped: bool,
+    /// Context keys the task reads (`Task::reads`). `None` when it sees the
+    /// whole context.
+    #[serde(default)]
+    pub reads: Option<Vec<String>>,
+    /// Serialized declarative configuration JSON (`Task::configuration`).
+    /// `None` when the task declares no config.
+    #[serde(default)]
+    pub configuration: Option<String>,
 }
 
 /// Default trigger-rules JSON (`Always`) for back-compat deserialization of
@@ -118,6 +173,21 @@ pub struct TaskExecutionRequest {
     /// durable context.
     pub resolved_secrets:
         std::collections::BTreeMap<String, std::collections::BTreeMap<String, String>>,
+    /// Environment variables the package was granted through its capability
+    /// manifest, read host-side and shipped by value. `Some` switches
+    /// `context.env_var(...)` inside the package to this map only; `None`
+    /// (a package that declares no capabilities under an unrestricted policy)
+    /// leaves it reading the process environment.
+    pub granted_env: Option<std::collections::BTreeMap<String, String>>,
+    /// JSON-encoded configuration values the package was registered with,
+    /// already validated against its `config_schema`. Exposed inside the
+    /// package as `context.package_config()`; `None` when the package was
+    /// registered without configuration.
+    pub package_config_json: Option<String>,
+    /// JSON object of the parameters the execution was started with.
+    /// Exposed inside the package as `context.params()`; `None` when the
+    /// execution has none.
+    pub params_json: Option<String>,
 }
 
 // Secret values must never appear in logs — a manual Debug keeps names only.
@@ -130,6 +200,15 @@ impl std::fmt::Debug for TaskExecutionRequest {
                 "resolved_secrets",
                 &self.resolved_secrets.keys().collect::<Vec<_>>(),
             )
+            .field(
+                "granted_env",
+                &self
+                    .granted_env
+                    .as_ref()
+                    .map(|env| env.keys().collect::<Vec<_>>()),
+            )
+            .field("package_config_json", &self.package_config_json)
+            .field("params_json", &self.params_json)
             .finish()
     }
 }
@@ -337,6 +416,31 @@ pub struct TriggerInvokeResult {
     pub error: Option<String>,
 }
 
+/// Request to run the package's load-time health checks, sent by the
+/// reconciler right after it dlopens a package and before it registers
+/// anything from it. `timeout_ms` bounds each workflow's check; a check that
+/// overruns is reported unhealthy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheckRequest {
+    /// Per-check deadline in milliseconds.
+    pub timeout_ms: u64,
+}
+
+/// Outcome of one workflow's `#[workflow(health_check = ...)]` entry point,
+/// returned by `run_health_checks()`. Workflows that declare no check are
+/// omitted. Any entry with `healthy == false` marks the whole package
+/// Degraded on the host.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheckReport {
+    /// Workflow the check belongs to.
+    pub workflow: String,
+    /// `true` when the check returned `Ok(())` within the deadline.
+    pub healthy: bool,
+    /// Failure description (the check's error, a timeout, or a panic).
+    /// `None` when healthy.
+    pub message: Option<String>,
+}
+
 /// Metadata for a single trigger declared by this package, returned by
 /// `get_trigger_metadata()`. The reconciler routes cron-shaped triggers
 /// (`cron_expression.is_some()`) to the cron scheduler and custom-poll
@@ -429,8 +533,22 @@ pub struct CloacinaMetadata {
     /// packages don't need it (their Cargo.toml is the source of truth).
     #[serde(default)]
     pub providers: std::collections::HashMap<String, ProviderDep>,
+    /// `[metadata.capabilities]` — what the package needs from the host.
+    /// Validated against the runner's deployment policy at registration.
+    /// `None` when the table is absent.
+    #[serde(default)]
+    pub capabilities: Option<CapabilityManifest>,
+    /// Path, relative to the package root, of a JSON Schema document the
+    /// package's configuration values must satisfy. Registering a package
+    /// that declares one requires config values that validate against it;
+    /// the values reach its tasks through `context.package_config()`.
+    #[serde(default)]
+    pub config_schema: Option<String>,
 }
 
+/// Re-exported from `cloacina-workflow`, where the task trait can name it.
+pub use cloacina_workflow::CapabilityManifest;
+
 /// One `[metadata.providers]` dependency spec — a Cargo-style dependency
 /// expression for a constructor provider (CLOACI-T-0831).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -549,12 +667,44 @@ mod tests {
             description: "Extract data from sources".to_string(),
             source_location: "src/lib.rs".to_string(),
             trigger_rules: "{\"type\":\"Always\"}".to_string(),
+            short_circuit: Some("rows".to_string()),
+            branch_key: Some("route".to_string()),
+            branch: Some("fast".to_string()),
+            loop_until: Some("{\"type\":\"Always\"}".to_string()),
+            max_iterations: Some(10),
+            soft_dependencies: vec!["load_cache".to_string()],
+            memory_limit: Some(64 * 1024 * 1024),
+            timeout_ms: Some(60_000),
+            requires_approval: true,
+            workload_class: Some("cpu".to_string()),
+            resources: vec!["gpu".to_string()],
+            produces: vec!["count: number".to_string()],
+            scoped: true,
+            reads: Some(vec!["rows".to_string()]),
+            configuration: Some("{\"bucket\":\"{{ ds }}\"}".to_string()),
         };
 
         let json = serde_json::to_string(&entry).unwrap();
         let roundtrip: TaskMetadataEntry = serde_json::from_str(&json).unwrap();
         assert_eq!(roundtrip.id, "extract_data");
         assert_eq!(roundtrip.index, 0);
+        assert_eq!(roundtrip.short_circuit.as_deref(), Some("rows"));
+        assert_eq!(roundtrip.branch_key.as_deref(), Some("route"));
+        assert_eq!(roundtrip.branch.as_deref(), Some("fast"));
+        assert_eq!(roundtrip.max_iterations, Some(10));
+        assert_eq!(roundtrip.soft_dependencies, vec!["load_cache".to_string()]);
+        assert_eq!(roundtrip.memory_limit, Some(64 * 1024 * 1024));
+        assert_eq!(roundtrip.timeout_ms, Some(60_000));
+        assert!(roundtrip.requires_approval);
+        assert_eq!(roundtrip.workload_class.as_deref(), Some("cpu"));
+        assert_eq!(roundtrip.resources, vec!["gpu".to_string()]);
+        assert_eq!(roundtrip.produces, vec!["count: number".to_string()]);
+        assert!(roundtrip.scoped);
+        assert_eq!(roundtrip.reads, Some(vec!["rows".to_string()]));
+        assert_eq!(
+            roundtrip.configuration.as_deref(),
+            Some("{\"bucket\":\"{{ ds }}\"}")
+        );
     }
 
     #[test]
@@ -574,6 +724,21 @@ mod tests {
                 description: "First step".to_string(),
                 source_location: "src/lib.rs".to_string(),
                 trigger_rules: "{\"type\":\"Always\"}".to_string(),
+                short_circuit: None,
+                branch_key: None,
+                branch: None,
+                loop_until: None,
+                max_iterations: None,
+                soft_dependencies: vec![],
+                memory_limit: None,
+                timeout_ms: None,
+                requires_approval: false,
+                workload_class: None,
+                resources: Vec::new(),
+                produces: Vec::new(),
+                scoped: false,
+                reads: None,
+                configuration: None,
             }],
             triggers: Vec::new(),
         };
@@ -595,6 +760,12 @@ mod tests {
             task_name: "extract_data".to_string(),
             context_json: r#"{"key": "value"}"#.to_string(),
             resolved_secrets: secrets,
+            granted_env: Some(std::collections::BTreeMap::from([(
+                "REGION".to_string(),
+                "eu-west-1".to_string(),
+            )])),
+            package_config_json: Some(r#"{"batch_size":500}"#.to_string()),
+            params_json: Some(r#"{"iterations":3}"#.to_string()),
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -608,6 +779,17 @@ mod tests {
         let dbg = format!("{request:?}");
         assert!(dbg.contains("oncall_api"));
         assert!(!dbg.contains("\"v\""));
+        assert_eq!(roundtrip.granted_env.unwrap()["REGION"], "eu-west-1");
+        assert!(dbg.contains("REGION"));
+        assert!(!dbg.contains("eu-west-1"));
+        assert_eq!(
+            roundtrip.package_config_json.as_deref(),
+            Some(r#"{"batch_size":500}"#)
+        );
+        assert_eq!(
+            roundtrip.params_json.as_deref(),
+            Some(r#"{"iterations":3}"#)
+        );
     }
 
     #[test]
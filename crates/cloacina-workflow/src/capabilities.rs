@@ -0,0 +1,61 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Package capability manifests — what a packaged workflow needs from the
+//! host. Packages declare one; runners grant it against their deployment
+//! policy and enforce it at execution.
+
+use serde::{Deserialize, Serialize};
+
+/// A package's `[metadata.capabilities]` table: the host capabilities its
+/// workflows require. The runner checks every entry against its deployment
+/// policy when the package is registered (any entry the policy does not allow
+/// rejects the package) and, at execution, hands the package only what it
+/// declared here.
+///
+/// ```toml
+/// [metadata.capabilities]
+/// network = ["api.example.com:443", "*.internal.example.com"]
+/// filesystem = ["ro:/etc/ssl/certs", "rw:/var/lib/etl"]
+/// secrets = ["warehouse_db"]
+/// env = ["AWS_REGION"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CapabilityManifest {
+    /// Network egress: `host`, `host:port`, or a `*` glob over either.
+    #[serde(default)]
+    pub network: Vec<String>,
+    /// Filesystem access, each `ro:<absolute path>` or `rw:<absolute path>`.
+    #[serde(default)]
+    pub filesystem: Vec<String>,
+    /// Secret names the package's tasks may resolve.
+    #[serde(default)]
+    pub secrets: Vec<String>,
+    /// Environment variable names passed through to the package's tasks.
+    #[serde(default)]
+    pub env: Vec<String>,
+}
+
+impl CapabilityManifest {
+    /// Whether the manifest requests nothing at all.
+    pub fn is_empty(&self) -> bool {
+        self.network.is_empty()
+            && self.filesystem.is_empty()
+            && self.secrets.is_empty()
+            && self.env.is_empty()
+    }
+}
@@ -20,6 +20,7 @@
 //! It contains only the core data operations without runtime-specific features
 //! like database persistence or dependency loading.
 
+use crate::context_access::ContextAccessLog;
 use crate::error::ContextError;
 use crate::secret::{SecretAccessError, SecretResolver, SecretResolverError};
 use serde::{Deserialize, Serialize};
@@ -57,6 +58,11 @@ where
 {
     data: HashMap<String, T>,
 
+    /// Binary values ([`Context::insert_bytes`]), held as raw bytes. They
+    /// share the key space of `data`; serialization writes each as a
+    /// [`BYTES_MARKER`] object.
+    bytes: HashMap<String, Vec<u8>>,
+
     /// Secret resolution side channel (CLOACI-I-0133 / T-0858, design D-1).
     ///
     /// A runtime-only handle used by [`Context::secret`]. It is **never**
@@ -66,6 +72,27 @@ where
     /// the durable context / `schedules.params` / fires log (NFR-001). It is
     /// likewise redacted from [`Debug`] below so it cannot leak through logs.
     secrets: Option<Arc<dyn SecretResolver>>,
+
+    /// Environment granted to this scope by a package capability manifest.
+    /// Runtime-only like `secrets`: never serialized, values redacted from
+    /// [`Debug`]. `None` means [`Context::env_var`] reads the process
+    /// environment.
+    env: Option<Arc<BTreeMap<String, String>>>,
+
+    /// Configuration values the running package was registered with,
+    /// validated against its `config_schema`. Runtime-only: never serialized,
+    /// so they stay out of the durable context.
+    package_config: Option<Arc<serde_json::Value>>,
+
+    /// Parameters the execution was started with. Runtime-only like
+    /// `package_config`, and read-only: there is no way to change them from
+    /// a task, so every task of the execution sees the same values.
+    params: Option<Arc<serde_json::Map<String, serde_json::Value>>>,
+
+    /// Key-level access tracing. Runtime-only like `secrets`: the executor
+    /// attaches a log for the duration of one task body; `None` records
+    /// nothing.
+    access_log: Option<Arc<ContextAccessLog>>,
 }
 
 // Manual `Debug` (the struct can no longer derive it because
@@ -78,10 +105,25 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Context")
             .field("data", &self.data)
+            .field(
+                "bytes",
+                &self
+                    .bytes
+                    .iter()
+                    .map(|(key, bytes)| (key, bytes.len()))
+                    .collect::<HashMap<_, _>>(),
+            )
             .field(
                 "secrets",
                 &self.secrets.as_ref().map(|_| "<redacted resolver>"),
             )
+            .field(
+                "env",
+                &self.env.as_ref().map(|env| env.keys().collect::<Vec<_>>()),
+            )
+            .field("package_config", &self.package_config)
+            .field("params", &self.params)
+            .field("access_log", &self.access_log.is_some())
             .finish()
     }
 }
@@ -104,7 +146,12 @@ where
         debug!("Creating new empty context");
         Self {
             data: HashMap::new(),
+            bytes: HashMap::new(),
             secrets: None,
+            env: None,
+            package_config: None,
+            params: None,
+            access_log: None,
         }
     }
 
@@ -121,9 +168,14 @@ where
         debug!("Cloning context data");
         Self {
             data: self.data.clone(),
+            bytes: self.bytes.clone(),
             // Carry the resolver handle (cheap Arc clone) so a cloned execution
             // scope can still resolve secrets.
             secrets: self.secrets.clone(),
+            env: self.env.clone(),
+            package_config: self.package_config.clone(),
+            params: self.params.clone(),
+            access_log: self.access_log.clone(),
         }
     }
 
@@ -154,11 +206,12 @@ where
     /// ```
     pub fn insert(&mut self, key: impl Into<String>, value: T) -> Result<(), ContextError> {
         let key = key.into();
-        if self.data.contains_key(&key) {
+        if self.holds(&key) {
             warn!("Attempted to insert duplicate key: {}", key);
             return Err(ContextError::KeyExists(key));
         }
         debug!("Inserting value for key: {}", key);
+        self.record_write(&key);
         self.data.insert(key, value);
         Ok(())
     }
@@ -192,11 +245,13 @@ where
     /// ```
     pub fn update(&mut self, key: impl Into<String>, value: T) -> Result<(), ContextError> {
         let key = key.into();
-        if !self.data.contains_key(&key) {
+        if !self.holds(&key) {
             warn!("Attempted to update non-existent key: {}", key);
             return Err(ContextError::KeyNotFound(key));
         }
         debug!("Updating value for key: {}", key);
+        self.record_write(&key);
+        self.bytes.remove(&key);
         self.data.insert(key, value);
         Ok(())
     }
@@ -210,7 +265,7 @@ where
     /// # Returns
     ///
     /// * `Some(&T)` - If the key exists
-    /// * `None` - If the key doesn't exist
+    /// * `None` - If the key doesn't exist or holds a binary value
     ///
     /// # Examples
     ///
@@ -225,6 +280,7 @@ where
     /// ```
     pub fn get(&self, key: &str) -> Option<&T> {
         debug!("Getting value for key: {}", key);
+        self.record_read(key);
         self.data.get(key)
     }
 
@@ -237,7 +293,8 @@ where
     /// # Returns
     ///
     /// * `Some(T)` - If the key existed and was removed
-    /// * `None` - If the key didn't exist
+    /// * `None` - If the key didn't exist or held a binary value, which is
+    ///   removed all the same
     ///
     /// # Examples
     ///
@@ -253,13 +310,49 @@ where
     /// ```
     pub fn remove(&mut self, key: &str) -> Option<T> {
         debug!("Removing value for key: {}", key);
+        self.record_write(key);
+        self.bytes.remove(key);
         self.data.remove(key)
     }
 
+    /// Whether `key` holds a value or a binary value.
+    fn holds(&self, key: &str) -> bool {
+        self.data.contains_key(key) || self.bytes.contains_key(key)
+    }
+
+    /// Attaches a key-level access log (see [`crate::context_access`]).
+    /// Subsequent reads and writes through this context are recorded in it.
+    pub fn set_access_log(&mut self, log: Arc<ContextAccessLog>) {
+        self.access_log = Some(log);
+    }
+
+    /// Returns the attached access log, if any.
+    pub fn access_log(&self) -> Option<&Arc<ContextAccessLog>> {
+        self.access_log.as_ref()
+    }
+
+    /// Detaches and returns the access log so later accesses go unrecorded.
+    pub fn take_access_log(&mut self) -> Option<Arc<ContextAccessLog>> {
+        self.access_log.take()
+    }
+
+    fn record_read(&self, key: &str) {
+        if let Some(log) = &self.access_log {
+            log.record_read(key);
+        }
+    }
+
+    fn record_write(&self, key: &str) {
+        if let Some(log) = &self.access_log {
+            log.record_write(key);
+        }
+    }
+
     /// Gets a reference to the underlying data HashMap.
     ///
     /// This method provides direct access to the internal data structure
     /// for advanced use cases that need to iterate over all key-value pairs.
+    /// Binary values are not in it; see [`binary`](Self::binary).
     ///
     /// # Returns
     ///
@@ -284,6 +377,8 @@ where
 
     /// Consumes the context and returns the underlying data HashMap.
     ///
+    /// Binary values are dropped; use [`to_map`](Self::to_map) to keep them.
+    ///
     /// # Returns
     ///
     /// The HashMap containing all context data
@@ -291,6 +386,26 @@ where
         self.data
     }
 
+    /// The binary values in this context, by key.
+    pub fn binary(&self) -> &HashMap<String, Vec<u8>> {
+        &self.bytes
+    }
+
+    /// Consumes the context and returns its values and its binary values.
+    pub fn into_parts(self) -> (HashMap<String, T>, HashMap<String, Vec<u8>>) {
+        (self.data, self.bytes)
+    }
+
+    /// Creates a Context from values and binary values, as returned by
+    /// [`into_parts`](Self::into_parts). A key present in both keeps its
+    /// binary value.
+    pub fn from_parts(mut data: HashMap<String, T>, bytes: HashMap<String, Vec<u8>>) -> Self {
+        data.retain(|key, _| !bytes.contains_key(key));
+        let mut context = Self::from_data(data);
+        context.bytes = bytes;
+        context
+    }
+
     /// Creates a Context from a HashMap.
     ///
     /// # Arguments
@@ -303,7 +418,12 @@ where
     pub fn from_data(data: HashMap<String, T>) -> Self {
         Self {
             data,
+            bytes: HashMap::new(),
             secrets: None,
+            env: None,
+            package_config: None,
+            params: None,
+            access_log: None,
         }
     }
 
@@ -315,7 +435,7 @@ where
     /// * `Err(ContextError)` - If serialization fails
     pub fn to_json(&self) -> Result<String, ContextError> {
         debug!("Serializing context to JSON");
-        let json = serde_json::to_string(&self.data)?;
+        let json = serde_json::to_string(&self.to_map()?)?;
         debug!("Context serialized successfully");
         Ok(json)
     }
@@ -332,14 +452,43 @@ where
     /// * `Err(ContextError)` - If deserialization fails
     pub fn from_json(json: String) -> Result<Self, ContextError> {
         debug!("Deserializing context from JSON");
-        let data = serde_json::from_str(&json)?;
+        let context = Self::from_map(serde_json::from_str(&json)?)?;
         debug!("Context deserialized successfully");
         // A deserialized context is a durable snapshot: it never carries a
         // resolver. The runtime re-attaches one at fire time if needed.
-        Ok(Self {
-            data,
-            secrets: None,
-        })
+        Ok(context)
+    }
+
+    /// The context as a JSON object, each binary value written as a
+    /// [`BYTES_MARKER`] object. This is the form contexts are stored and
+    /// sent in.
+    pub fn to_map(&self) -> Result<serde_json::Map<String, serde_json::Value>, ContextError> {
+        let mut map = serde_json::Map::new();
+        for (key, value) in &self.data {
+            map.insert(key.clone(), serde_json::to_value(value)?);
+        }
+        for (key, bytes) in &self.bytes {
+            map.insert(key.clone(), bytes_value(bytes));
+        }
+        Ok(map)
+    }
+
+    /// Builds a context from the form [`to_map`](Self::to_map) writes:
+    /// top-level [`BYTES_MARKER`] objects become binary values again.
+    pub fn from_map(map: serde_json::Map<String, serde_json::Value>) -> Result<Self, ContextError> {
+        let mut data = HashMap::with_capacity(map.len());
+        let mut bytes = HashMap::new();
+        for (key, value) in map {
+            match value_bytes(&value) {
+                Some(raw) => {
+                    bytes.insert(key, raw);
+                }
+                None => {
+                    data.insert(key, serde_json::from_value(value)?);
+                }
+            }
+        }
+        Ok(Self::from_parts(data, bytes))
     }
 
     // ── Secret resolution side channel (CLOACI-I-0133 / T-0858, D-1) ─────────
@@ -427,6 +576,132 @@ where
                 field: field.to_string(),
             })
     }
+
+    /// Resolve a single-valued secret, such as an API key.
+    ///
+    /// Returns the secret's `value` field, which is what backends name a
+    /// plain (non-JSON-object) secret, or its only field. A secret with
+    /// several fields and no `value` errors with
+    /// [`SecretAccessError::FieldNotFound`]; read it with
+    /// [`Context::secret_field`] instead.
+    pub async fn get_secret(&self, name: &str) -> Result<String, SecretAccessError> {
+        let mut fields = self.secret(name).await?;
+        if let Some(value) = fields.remove("value") {
+            return Ok(value);
+        }
+        match fields.len() {
+            1 => Ok(fields.into_values().next().unwrap_or_default()),
+            _ => Err(SecretAccessError::FieldNotFound {
+                secret: name.to_string(),
+                field: "value".to_string(),
+            }),
+        }
+    }
+
+    /// Restrict [`Context::env_var`] to `env`. The runner sets this from a
+    /// package's capability manifest so a task sees only the variables its
+    /// package was granted.
+    pub fn set_granted_env(&mut self, env: BTreeMap<String, String>) {
+        self.env = Some(Arc::new(env));
+    }
+
+    /// Read an environment variable.
+    ///
+    /// Inside a package that declares `[metadata.capabilities]` this returns
+    /// only granted variables (`None` for anything else); otherwise it reads
+    /// the process environment.
+    pub fn env_var(&self, name: &str) -> Option<String> {
+        match &self.env {
+            Some(env) => env.get(name).cloned(),
+            None => std::env::var(name).ok(),
+        }
+    }
+
+    /// Attach the package configuration values. The runner sets this from
+    /// the values a package was registered with.
+    pub fn set_package_config(&mut self, config: serde_json::Value) {
+        self.package_config = Some(Arc::new(config));
+    }
+
+    /// The configuration values the running package was registered with, or
+    /// `None` outside a package that declares a `config_schema`.
+    pub fn package_config(&self) -> Option<&serde_json::Value> {
+        self.package_config.as_deref()
+    }
+
+    /// Deserialize the package configuration into `C`.
+    ///
+    /// Returns [`ContextError::KeyNotFound`] when no configuration is
+    /// attached.
+    pub fn package_config_as<C: serde::de::DeserializeOwned>(&self) -> Result<C, ContextError> {
+        let config = self
+            .package_config
+            .as_deref()
+            .ok_or_else(|| ContextError::KeyNotFound("package_config".to_string()))?;
+        Ok(serde_json::from_value(config.clone())?)
+    }
+
+    /// Attach the execution's parameters. The runner sets this from the
+    /// parameters the execution was started with.
+    pub fn set_params(&mut self, params: serde_json::Map<String, serde_json::Value>) {
+        self.params = Some(Arc::new(params));
+    }
+
+    /// The parameters the execution was started with, or `None` when it was
+    /// started without any. Unlike context values they are read-only and do
+    /// not travel between tasks through their outputs.
+    pub fn params(&self) -> Option<&serde_json::Map<String, serde_json::Value>> {
+        self.params.as_deref()
+    }
+
+    /// Deserialize execution parameter `name` into `V`.
+    ///
+    /// Returns [`ContextError::KeyNotFound`] when the execution has no such
+    /// parameter.
+    pub fn param<V: serde::de::DeserializeOwned>(&self, name: &str) -> Result<V, ContextError> {
+        let value = self
+            .params
+            .as_deref()
+            .and_then(|params| params.get(name))
+            .ok_or_else(|| ContextError::KeyNotFound(format!("param '{}'", name)))?;
+        Ok(serde_json::from_value(value.clone())?)
+    }
+}
+
+/// Reserved `Context` data key holding the running task's rendered declarative
+/// configuration ([`Task::configuration`](crate::Task::configuration)).
+///
+/// The executor sets it on the context it hands a task and strips it from the
+/// context the task produces, so one task's config never reaches another.
+pub const TASK_CONFIG_KEY: &str = "__cloacina_task_config__";
+
+/// Key of the object that stands for a binary value in a serialized context.
+///
+/// A context holds the values written with [`Context::insert_bytes`] as raw
+/// bytes. JSON has no byte strings, so [`Context::to_json`] and
+/// [`Context::to_map`] write each as `{"$cloacina_bytes": "<base64>"}` and
+/// [`Context::from_json`] and [`Context::from_map`] read it back. Storage
+/// that can keep raw bytes (the binary context formats, the context offload
+/// store) unwraps it again.
+pub const BYTES_MARKER: &str = "$cloacina_bytes";
+
+/// The context value standing for `bytes`.
+pub fn bytes_value(bytes: &[u8]) -> serde_json::Value {
+    use base64::Engine as _;
+    serde_json::json!({ BYTES_MARKER: base64::engine::general_purpose::STANDARD.encode(bytes) })
+}
+
+/// The bytes a context value stands for, if it is a binary value.
+pub fn value_bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
+    use base64::Engine as _;
+    let object = value.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+    let encoded = object.get(BYTES_MARKER)?.as_str()?;
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()
 }
 
 /// Typed accessors for the task context (`Context<serde_json::Value>`).
@@ -462,6 +737,7 @@ impl Context<serde_json::Value> {
     where
         V: serde::de::DeserializeOwned,
     {
+        self.record_read(key);
         match self.data.get(key) {
             None => Ok(None),
             Some(value) => serde_json::from_value(value.clone())
@@ -510,6 +786,29 @@ impl Context<serde_json::Value> {
         }
     }
 
+    /// Read the running task's rendered declarative configuration and
+    /// deserialize it into `V`.
+    ///
+    /// Returns `Ok(None)` when the task declares no `config`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloacina_workflow::context::TASK_CONFIG_KEY;
+    /// use cloacina_workflow::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.insert(TASK_CONFIG_KEY, serde_json::json!({"bucket": "b-2026-03-09"})).unwrap();
+    /// let config: serde_json::Value = ctx.task_config().unwrap().unwrap();
+    /// assert_eq!(config["bucket"], "b-2026-03-09");
+    /// ```
+    pub fn task_config<V>(&self) -> Result<Option<V>, crate::error::TaskError>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        self.get_as(TASK_CONFIG_KEY)
+    }
+
     /// Serialize a value and write it under `key`, **upserting** (insert or
     /// overwrite).
     ///
@@ -546,9 +845,283 @@ impl Context<serde_json::Value> {
                 message: format!("context key '{}' could not be serialized: {}", key, e),
             })?;
         // Upsert: overwrite if present, insert otherwise.
+        self.record_write(&key);
         self.data.insert(key, json);
         Ok(())
     }
+
+    /// Get a value by key and deserialize it into `V`, reporting exactly
+    /// what went wrong.
+    ///
+    /// Unlike [`get_as`](Self::get_as), the error is a [`ContextError`] that
+    /// tells a missing key ([`ContextError::KeyNotFound`]) apart from a value
+    /// of the wrong shape ([`ContextError::Deserialization`], whose `path`
+    /// points at the offending field, e.g. `records[2].id`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloacina_workflow::{Context, ContextError};
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.insert("records", serde_json::json!([{"id": 1}, {"id": "two"}])).unwrap();
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Record { id: u32 }
+    ///
+    /// match ctx.get_typed::<Vec<Record>>("records").unwrap_err() {
+    ///     ContextError::Deserialization { path, .. } => assert_eq!(path, "records[1].id"),
+    ///     other => panic!("unexpected error: {other}"),
+    /// }
+    /// assert!(matches!(
+    ///     ctx.get_typed::<u32>("missing"),
+    ///     Err(ContextError::KeyNotFound(_))
+    /// ));
+    /// ```
+    pub fn get_typed<V>(&self, key: &str) -> Result<V, ContextError>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        self.record_read(key);
+        let value = self
+            .data
+            .get(key)
+            .ok_or_else(|| ContextError::KeyNotFound(key.to_string()))?;
+        deserialize_at(value, key)
+    }
+
+    /// Serialize `value` and insert it under `key`.
+    ///
+    /// The typed counterpart of [`insert`](Context::insert): it errors with
+    /// [`ContextError::KeyExists`] if the key is already present and with
+    /// [`ContextError::Serialization`] if the value cannot be serialized.
+    /// Use [`insert_as`](Self::insert_as) to overwrite instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloacina_workflow::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.insert_typed("ids", &vec![1u32, 2, 3]).unwrap();
+    /// assert_eq!(ctx.get_typed::<Vec<u32>>("ids").unwrap(), vec![1, 2, 3]);
+    /// assert!(ctx.insert_typed("ids", &vec![4u32]).is_err());
+    /// ```
+    pub fn insert_typed<V>(&mut self, key: impl Into<String>, value: &V) -> Result<(), ContextError>
+    where
+        V: serde::Serialize + ?Sized,
+    {
+        self.insert(key, serde_json::to_value(value)?)
+    }
+
+    /// Insert a binary value under `key`.
+    ///
+    /// Images, parquet buffers or compressed artifacts are held as they are;
+    /// see [`BYTES_MARKER`] for how they are serialized. Errors with
+    /// [`ContextError::KeyExists`] if the key is already present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloacina_workflow::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.insert_bytes("thumbnail", [0x89, b'P', b'N', b'G']).unwrap();
+    /// assert_eq!(ctx.get_bytes("thumbnail").unwrap(), vec![0x89, b'P', b'N', b'G']);
+    /// ```
+    pub fn insert_bytes(
+        &mut self,
+        key: impl Into<String>,
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<(), ContextError> {
+        let key = key.into();
+        if self.holds(&key) {
+            warn!("Attempted to insert duplicate key: {}", key);
+            return Err(ContextError::KeyExists(key));
+        }
+        debug!("Inserting binary value for key: {}", key);
+        self.record_write(&key);
+        self.bytes.insert(key, bytes.as_ref().to_vec());
+        Ok(())
+    }
+
+    /// Get the binary value stored under `key`.
+    ///
+    /// Errors with [`ContextError::KeyNotFound`] if the key is absent and
+    /// [`ContextError::TypeMismatch`] if it holds anything but a value
+    /// written with [`insert_bytes`](Self::insert_bytes).
+    pub fn get_bytes(&self, key: &str) -> Result<Vec<u8>, ContextError> {
+        self.record_read(key);
+        if let Some(bytes) = self.bytes.get(key) {
+            return Ok(bytes.clone());
+        }
+        let value = self
+            .data
+            .get(key)
+            .ok_or_else(|| ContextError::KeyNotFound(key.to_string()))?;
+        value_bytes(value).ok_or_else(|| ContextError::TypeMismatch(key.to_string()))
+    }
+
+    /// Remove and return the binary value stored under `key`, if it holds
+    /// one.
+    pub fn remove_bytes(&mut self, key: &str) -> Option<Vec<u8>> {
+        let bytes = self.bytes.remove(key)?;
+        debug!("Removing binary value for key: {}", key);
+        self.record_write(key);
+        Some(bytes)
+    }
+
+    /// A view of this context that reads and writes inside the object
+    /// stored under `scope`.
+    ///
+    /// Tasks running in parallel can each write into their own scope
+    /// without their keys colliding when a downstream task merges their
+    /// outputs. The scope object is created by the first write; a `scope`
+    /// key holding anything but an object is a
+    /// [`ContextError::TypeMismatch`]. Tasks declared with
+    /// `#[task(scoped = true)]` get a scope named after their id without
+    /// calling this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloacina_workflow::Context;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.scoped("partition_1").insert("rows", serde_json::json!(10)).unwrap();
+    /// ctx.scoped("partition_2").insert("rows", serde_json::json!(12)).unwrap();
+    ///
+    /// assert_eq!(ctx.get("partition_1"), Some(&serde_json::json!({"rows": 10})));
+    /// assert_eq!(ctx.scoped("partition_2").get_typed::<u32>("rows").unwrap(), 12);
+    /// ```
+    pub fn scoped(&mut self, scope: impl Into<String>) -> ScopedContext<'_> {
+        ScopedContext {
+            context: self,
+            scope: scope.into(),
+        }
+    }
+}
+
+/// Deserialize the value stored under `key` into `V`, reporting the path of
+/// the field that failed.
+fn deserialize_at<V>(value: &serde_json::Value, key: &str) -> Result<V, ContextError>
+where
+    V: serde::de::DeserializeOwned,
+{
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let path = match e.path().to_string().as_str() {
+            "." => key.to_string(),
+            field if field.starts_with('[') => format!("{}{}", key, field),
+            field => format!("{}.{}", key, field),
+        };
+        ContextError::Deserialization {
+            key: key.to_string(),
+            path,
+            type_name: std::any::type_name::<V>().to_string(),
+            message: e.into_inner().to_string(),
+        }
+    })
+}
+
+/// A view of a [`Context`] confined to one scope; see [`Context::scoped`].
+///
+/// Keys in errors are reported as `scope.key`.
+pub struct ScopedContext<'a> {
+    context: &'a mut Context<serde_json::Value>,
+    scope: String,
+}
+
+impl ScopedContext<'_> {
+    /// The name of the scope.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Gets a value from the scope.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.context.get(&self.scope)?.as_object()?.get(key)
+    }
+
+    /// Get a value from the scope and deserialize it into `V`; see
+    /// [`Context::get_typed`].
+    pub fn get_typed<V>(&self, key: &str) -> Result<V, ContextError>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        let value = self
+            .get(key)
+            .ok_or_else(|| ContextError::KeyNotFound(self.path(key)))?;
+        deserialize_at(value, &self.path(key))
+    }
+
+    /// Inserts a value into the scope, failing with
+    /// [`ContextError::KeyExists`] if the key is already there.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: serde_json::Value,
+    ) -> Result<(), ContextError> {
+        let key = key.into();
+        let path = self.path(&key);
+        let object = self.object_mut()?;
+        if object.contains_key(&key) {
+            return Err(ContextError::KeyExists(path));
+        }
+        object.insert(key, value);
+        Ok(())
+    }
+
+    /// Serialize `value` and insert it into the scope; see
+    /// [`Context::insert_typed`].
+    pub fn insert_typed<V>(&mut self, key: impl Into<String>, value: &V) -> Result<(), ContextError>
+    where
+        V: serde::Serialize + ?Sized,
+    {
+        self.insert(key, serde_json::to_value(value)?)
+    }
+
+    /// Updates a value in the scope, failing with
+    /// [`ContextError::KeyNotFound`] if the key is not there.
+    pub fn update(
+        &mut self,
+        key: impl Into<String>,
+        value: serde_json::Value,
+    ) -> Result<(), ContextError> {
+        let key = key.into();
+        let path = self.path(&key);
+        let object = self.object_mut()?;
+        if !object.contains_key(&key) {
+            return Err(ContextError::KeyNotFound(path));
+        }
+        object.insert(key, value);
+        Ok(())
+    }
+
+    /// Removes and returns a value from the scope.
+    pub fn remove(&mut self, key: &str) -> Option<serde_json::Value> {
+        self.context.record_write(&self.scope);
+        self.context
+            .data
+            .get_mut(&self.scope)?
+            .as_object_mut()?
+            .remove(key)
+    }
+
+    fn path(&self, key: &str) -> String {
+        format!("{}.{}", self.scope, key)
+    }
+
+    fn object_mut(
+        &mut self,
+    ) -> Result<&mut serde_json::Map<String, serde_json::Value>, ContextError> {
+        self.context.record_write(&self.scope);
+        self.context
+            .data
+            .entry(self.scope.clone())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| ContextError::TypeMismatch(self.scope.clone()))
+    }
 }
 
 impl<T> Default for Context<T>
@@ -713,6 +1286,88 @@ mod tests {
         assert!(!restored.has_secret_resolver());
     }
 
+    #[test]
+    fn test_package_config_is_typed_and_not_serialized() {
+        #[derive(Deserialize)]
+        struct Config {
+            batch_size: u32,
+        }
+
+        let mut ctx = Context::<serde_json::Value>::new();
+        assert!(ctx.package_config().is_none());
+        assert!(matches!(
+            ctx.package_config_as::<Config>(),
+            Err(ContextError::KeyNotFound(_))
+        ));
+
+        ctx.set_package_config(serde_json::json!({"batch_size": 500}));
+        assert_eq!(ctx.package_config_as::<Config>().unwrap().batch_size, 500);
+        assert_eq!(
+            ctx.clone_data().package_config(),
+            Some(&serde_json::json!({"batch_size": 500}))
+        );
+
+        let json = ctx.to_json().unwrap();
+        assert!(!json.contains("batch_size"));
+        let restored = Context::<serde_json::Value>::from_json(json).unwrap();
+        assert!(restored.package_config().is_none());
+    }
+
+    #[test]
+    fn test_params_are_read_only_and_not_serialized() {
+        let mut ctx = Context::<serde_json::Value>::new();
+        assert!(ctx.params().is_none());
+        assert!(matches!(
+            ctx.param::<u32>("iterations"),
+            Err(ContextError::KeyNotFound(_))
+        ));
+
+        let mut params = serde_json::Map::new();
+        params.insert("iterations".to_string(), serde_json::json!(3));
+        ctx.set_params(params);
+        assert_eq!(ctx.param::<u32>("iterations").unwrap(), 3);
+        assert_eq!(ctx.clone_data().param::<u32>("iterations").unwrap(), 3);
+
+        // Params are not context data.
+        assert!(ctx.get("iterations").is_none());
+        ctx.insert("iterations", serde_json::json!(10)).unwrap();
+        assert_eq!(ctx.param::<u32>("iterations").unwrap(), 3);
+
+        let json = ctx.to_json().unwrap();
+        let restored = Context::<serde_json::Value>::from_json(json).unwrap();
+        assert!(restored.params().is_none());
+    }
+
+    #[test]
+    fn test_granted_env_restricts_env_var_and_is_not_serialized() {
+        let mut ctx = Context::<serde_json::Value>::new();
+        // No grant attached: the process environment is visible.
+        assert!(ctx.env_var("PATH").is_some());
+
+        ctx.set_granted_env(BTreeMap::from([(
+            "REGION".to_string(),
+            "eu-west-1".to_string(),
+        )]));
+        assert_eq!(ctx.env_var("REGION").as_deref(), Some("eu-west-1"));
+        assert_eq!(ctx.env_var("PATH"), None);
+        assert_eq!(
+            ctx.clone_data().env_var("REGION").as_deref(),
+            Some("eu-west-1")
+        );
+
+        let dbg = format!("{:?}", ctx);
+        assert!(dbg.contains("REGION"));
+        assert!(
+            !dbg.contains("eu-west-1"),
+            "env value leaked into Debug: {dbg}"
+        );
+
+        let json = ctx.to_json().unwrap();
+        assert!(!json.contains("eu-west-1"));
+        let restored = Context::<serde_json::Value>::from_json(json).unwrap();
+        assert!(restored.env_var("PATH").is_some());
+    }
+
     #[test]
     fn test_debug_redacts_resolver_and_never_prints_plaintext() {
         let mut ctx = Context::<serde_json::Value>::new();
@@ -799,6 +1454,29 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_get_secret_reads_single_valued_secrets() {
+        let mut secrets = HashMap::new();
+        secrets.insert(
+            "api_key".to_string(),
+            BTreeMap::from([("value".to_string(), "k-123".to_string())]),
+        );
+        secrets.insert(
+            "token".to_string(),
+            BTreeMap::from([("bearer".to_string(), "t-456".to_string())]),
+        );
+        let ctx = Context::<serde_json::Value>::new()
+            .with_secret_resolver(Arc::new(StubResolver { secrets }));
+        assert_eq!(ctx.get_secret("api_key").await.unwrap(), "k-123");
+        assert_eq!(ctx.get_secret("token").await.unwrap(), "t-456");
+
+        let ctx = ctx.with_secret_resolver(stub_resolver());
+        assert!(matches!(
+            ctx.get_secret("db_prod").await.unwrap_err(),
+            SecretAccessError::FieldNotFound { ref field, .. } if field == "value"
+        ));
+    }
+
     #[test]
     fn test_typed_accessor_errors_are_actionable() {
         let mut ctx = Context::new();
@@ -815,4 +1493,153 @@ mod tests {
         let msg = err.to_string();
         assert!(msg.contains("absent"), "msg should name the key: {msg}");
     }
+
+    #[test]
+    fn test_get_typed_distinguishes_missing_from_malformed() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Batch {
+            id: u32,
+            rows: Vec<u32>,
+        }
+
+        let mut ctx = Context::new();
+        let batch = Batch {
+            id: 1,
+            rows: vec![10, 20],
+        };
+        ctx.insert_typed("batch", &batch).unwrap();
+        assert_eq!(ctx.get_typed::<Batch>("batch").unwrap(), batch);
+        assert!(matches!(
+            ctx.insert_typed("batch", &batch),
+            Err(ContextError::KeyExists(_))
+        ));
+
+        assert!(matches!(
+            ctx.get_typed::<Batch>("absent"),
+            Err(ContextError::KeyNotFound(ref key)) if key == "absent"
+        ));
+
+        ctx.insert("bad", serde_json::json!({"id": 2, "rows": [1, "x"]}))
+            .unwrap();
+        match ctx.get_typed::<Batch>("bad").unwrap_err() {
+            ContextError::Deserialization {
+                key,
+                path,
+                type_name,
+                ..
+            } => {
+                assert_eq!(key, "bad");
+                assert_eq!(path, "bad.rows[1]");
+                assert!(type_name.ends_with("Batch"), "type_name: {type_name}");
+            }
+            other => panic!("expected a deserialization error, got {other}"),
+        }
+
+        ctx.insert("scalar", serde_json::json!("seven")).unwrap();
+        match ctx.get_typed::<u32>("scalar").unwrap_err() {
+            ContextError::Deserialization { path, .. } => assert_eq!(path, "scalar"),
+            other => panic!("expected a deserialization error, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_scoped_writes_stay_inside_their_scope() {
+        let mut ctx = Context::new();
+        ctx.insert("rows", serde_json::json!(1)).unwrap();
+        ctx.scoped("left")
+            .insert("rows", serde_json::json!(2))
+            .unwrap();
+        ctx.scoped("right")
+            .insert_typed("rows", &vec![3, 4])
+            .unwrap();
+
+        assert_eq!(ctx.get("rows"), Some(&serde_json::json!(1)));
+        assert_eq!(ctx.scoped("left").get("rows"), Some(&serde_json::json!(2)));
+        assert_eq!(
+            ctx.scoped("right").get_typed::<Vec<u32>>("rows").unwrap(),
+            vec![3, 4]
+        );
+        assert!(matches!(
+            ctx.scoped("left").insert("rows", serde_json::json!(5)),
+            Err(ContextError::KeyExists(ref key)) if key == "left.rows"
+        ));
+        assert!(matches!(
+            ctx.scoped("left").update("missing", serde_json::json!(5)),
+            Err(ContextError::KeyNotFound(ref key)) if key == "left.missing"
+        ));
+        match ctx
+            .scoped("right")
+            .get_typed::<Vec<String>>("rows")
+            .unwrap_err()
+        {
+            ContextError::Deserialization { path, .. } => assert_eq!(path, "right.rows[0]"),
+            other => panic!("expected a deserialization error, got {other}"),
+        }
+
+        ctx.scoped("left")
+            .update("rows", serde_json::json!(6))
+            .unwrap();
+        assert_eq!(ctx.get("left"), Some(&serde_json::json!({"rows": 6})));
+        assert_eq!(
+            ctx.scoped("left").remove("rows"),
+            Some(serde_json::json!(6))
+        );
+        assert_eq!(ctx.scoped("absent").remove("rows"), None);
+        assert!(ctx.get("absent").is_none());
+
+        // A scope name already holding a non-object is a type mismatch.
+        assert!(matches!(
+            ctx.scoped("rows").insert("x", serde_json::json!(0)),
+            Err(ContextError::TypeMismatch(ref key)) if key == "rows"
+        ));
+    }
+
+    #[test]
+    fn test_bytes_round_trip_and_reject_other_values() {
+        let mut ctx = Context::new();
+        let payload: Vec<u8> = (0..=255).collect();
+        ctx.insert_bytes("blob", &payload).unwrap();
+        ctx.insert("text", serde_json::json!("not bytes")).unwrap();
+
+        assert_eq!(ctx.get_bytes("blob").unwrap(), payload);
+        assert!(ctx.get("blob").is_none());
+        assert_eq!(ctx.binary()["blob"], payload);
+        assert!(matches!(
+            ctx.get_bytes("text"),
+            Err(ContextError::TypeMismatch(ref key)) if key == "text"
+        ));
+        assert!(matches!(
+            ctx.get_bytes("missing"),
+            Err(ContextError::KeyNotFound(_))
+        ));
+        assert!(matches!(
+            ctx.insert_bytes("blob", b"again"),
+            Err(ContextError::KeyExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_bytes_are_base64_only_in_json() {
+        let mut ctx = Context::new();
+        ctx.insert_bytes("blob", b"\x00\xffraw").unwrap();
+        ctx.insert("n", serde_json::json!(1)).unwrap();
+
+        let json = ctx.to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["blob"], bytes_value(b"\x00\xffraw"));
+
+        let read = Context::<serde_json::Value>::from_json(json).unwrap();
+        assert_eq!(read.binary()["blob"], b"\x00\xffraw".to_vec());
+        assert_eq!(read.get("n"), Some(&serde_json::json!(1)));
+
+        let mut ctx = read;
+        ctx.update("blob", serde_json::json!("text")).unwrap();
+        assert!(ctx.binary().is_empty());
+        ctx.insert_bytes("other", b"x").unwrap();
+        assert_eq!(ctx.remove("other"), None);
+        assert!(matches!(
+            ctx.get_bytes("other"),
+            Err(ContextError::KeyNotFound(_))
+        ));
+    }
 }
@@ -0,0 +1,120 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Runtime tracing of which context keys a task reads and writes.
+//!
+//! The executor attaches a [`ContextAccessLog`] to the context it hands a
+//! task and reads it back once the task returns. Keys the task reads through
+//! [`Context::get`](crate::Context::get) / `get_as` / `get_required` and
+//! writes through `insert` / `update` / `insert_as` / `remove` are recorded,
+//! so hidden data couplings — a task silently depending on another task's
+//! output without declaring the dependency — can be surfaced after the fact.
+//!
+//! Bulk access through [`Context::data`](crate::Context::data) is not
+//! attributed to individual keys.
+
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+/// Keys read and written through one context while a log is attached.
+#[derive(Debug, Default)]
+pub struct ContextAccessLog {
+    reads: Mutex<BTreeSet<String>>,
+    writes: Mutex<BTreeSet<String>>,
+}
+
+impl ContextAccessLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a read of `key`, whether or not the key was present.
+    pub fn record_read(&self, key: &str) {
+        if let Ok(mut reads) = self.reads.lock() {
+            if !reads.contains(key) {
+                reads.insert(key.to_string());
+            }
+        }
+    }
+
+    /// Records a write (insert, update or removal) of `key`.
+    pub fn record_write(&self, key: &str) {
+        if let Ok(mut writes) = self.writes.lock() {
+            if !writes.contains(key) {
+                writes.insert(key.to_string());
+            }
+        }
+    }
+
+    /// Keys read so far, sorted.
+    pub fn reads(&self) -> Vec<String> {
+        self.reads
+            .lock()
+            .map(|reads| reads.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Keys written so far, sorted.
+    pub fn writes(&self) -> Vec<String> {
+        self.writes
+            .lock()
+            .map(|writes| writes.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_access_log_records_reads_and_writes() {
+        let log = Arc::new(ContextAccessLog::new());
+        let mut ctx = Context::new();
+        ctx.insert("seed", serde_json::json!(1)).unwrap();
+        ctx.set_access_log(log.clone());
+
+        let _ = ctx.get("seed");
+        let _ = ctx.get_as::<i64>("missing");
+        ctx.insert("out", serde_json::json!(2)).unwrap();
+        ctx.insert_as("total", 3u32).unwrap();
+        ctx.remove("seed");
+
+        assert_eq!(log.reads(), vec!["missing".to_string(), "seed".to_string()]);
+        assert_eq!(
+            log.writes(),
+            vec!["out".to_string(), "seed".to_string(), "total".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_untraced_context_records_nothing() {
+        let log = Arc::new(ContextAccessLog::new());
+        let mut ctx = Context::new();
+        ctx.insert("a", serde_json::json!(1)).unwrap();
+        let _ = ctx.get("a");
+        assert!(ctx.access_log().is_none());
+
+        ctx.set_access_log(log.clone());
+        let detached = ctx.take_access_log();
+        assert!(detached.is_some());
+        let _ = ctx.get("a");
+        assert!(log.reads().is_empty());
+    }
+}
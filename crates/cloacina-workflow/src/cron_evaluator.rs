@@ -39,7 +39,7 @@
 //! # }
 //! ```
 
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
 use chrono_tz::Tz;
 use croner::Cron;
 use serde::{Deserialize, Serialize};
@@ -64,8 +64,71 @@ pub enum CronError {
     /// Error from the croner crate.
     #[error("Cron parsing error: {0}")]
     CronParsingError(String),
+
+    /// A preset or natural-language schedule that could mean more than one
+    /// cron expression (e.g. "every day at 9" — morning or evening?).
+    #[error("Ambiguous schedule expression: {0}")]
+    AmbiguousExpression(String),
+}
+
+/// What a cron schedule does when a daylight-saving transition makes its
+/// wall-clock fire time missing (clocks jump forward) or repeated (clocks
+/// turn back).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DstPolicy {
+    /// Drop a fire whose wall-clock time does not exist; fire a repeated
+    /// time once.
+    Skip,
+    /// Move a fire whose wall-clock time does not exist to the first valid
+    /// time after the gap; fire a repeated time once.
+    #[default]
+    RunOnce,
+    /// Move a missing fire like [`DstPolicy::RunOnce`]; fire a repeated
+    /// wall-clock time at both instants.
+    RunTwice,
+}
+
+impl DstPolicy {
+    /// Returns the string representation stored on a schedule.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DstPolicy::Skip => "skip",
+            DstPolicy::RunOnce => "run_once",
+            DstPolicy::RunTwice => "run_twice",
+        }
+    }
+}
+
+impl std::fmt::Display for DstPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for DstPolicy {
+    type Err = CronError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(DstPolicy::Skip),
+            "run_once" => Ok(DstPolicy::RunOnce),
+            "run_twice" => Ok(DstPolicy::RunTwice),
+            other => Err(CronError::InvalidExpression(format!(
+                "unknown DST policy '{}' (expected skip, run_once or run_twice)",
+                other
+            ))),
+        }
+    }
 }
 
+/// How far around a fire time to look for a repeated wall-clock time.
+/// Covers the largest DST shift in use (two hours) with margin.
+const DST_SCAN: Duration = Duration::hours(3);
+
+/// Most consecutive fire times [`DstPolicy::Skip`] will drop before giving up.
+const MAX_SKIPPED_FIRES: usize = 64;
+
 /// Timezone-aware cron expression evaluator.
 ///
 /// This struct provides methods for evaluating cron expressions in specific timezones,
@@ -98,6 +161,8 @@ pub struct CronEvaluator {
     expression: String,
     /// Original timezone string for debugging
     timezone_str: String,
+    /// Behavior across daylight-saving transitions
+    dst_policy: DstPolicy,
 }
 
 impl CronEvaluator {
@@ -143,13 +208,28 @@ impl CronEvaluator {
             timezone,
             expression: cron_expr.to_string(),
             timezone_str: timezone_str.to_string(),
+            dst_policy: DstPolicy::default(),
         })
     }
 
+    /// Sets how fire times falling in a daylight-saving transition are
+    /// handled; [`DstPolicy::RunOnce`] by default.
+    pub fn with_dst_policy(mut self, policy: DstPolicy) -> Self {
+        self.dst_policy = policy;
+        self
+    }
+
+    /// Returns the daylight-saving policy.
+    pub fn dst_policy(&self) -> DstPolicy {
+        self.dst_policy
+    }
+
     /// Finds the next execution time after the given timestamp.
     ///
     /// This method converts the UTC timestamp to the evaluator's timezone,
     /// finds the next cron match in that timezone, then converts back to UTC.
+    /// Fire times in a daylight-saving transition follow the evaluator's
+    /// [`DstPolicy`].
     ///
     /// # Arguments
     /// * `after` - UTC timestamp to find the next execution after
@@ -174,6 +254,29 @@ impl CronEvaluator {
     /// # }
     /// ```
     pub fn next_execution(&self, after: DateTime<Utc>) -> Result<DateTime<Utc>, CronError> {
+        match self.dst_policy {
+            DstPolicy::RunOnce => self.next_local_match(after),
+            DstPolicy::Skip => {
+                let mut cursor = after;
+                for _ in 0..MAX_SKIPPED_FIRES {
+                    let next = self.next_local_match(cursor)?;
+                    if !self.is_shifted(next) {
+                        return Ok(next);
+                    }
+                    cursor = next;
+                }
+                Err(CronError::NoNextExecution)
+            }
+            DstPolicy::RunTwice => {
+                let next = self.next_local_match(after)?;
+                Ok(self.first_repeat_between(after, next).unwrap_or(next))
+            }
+        }
+    }
+
+    /// The next cron match in the evaluator's timezone, leaving any
+    /// daylight-saving gap or overlap to the cron library.
+    fn next_local_match(&self, after: DateTime<Utc>) -> Result<DateTime<Utc>, CronError> {
         // Convert UTC time to the target timezone
         let local_time = self.timezone.from_utc_datetime(&after.naive_utc());
 
@@ -187,6 +290,65 @@ impl CronEvaluator {
         Ok(next_local.with_timezone(&Utc))
     }
 
+    /// True when `at` was moved out of a daylight-saving gap: its wall-clock
+    /// time does not match the expression.
+    fn is_shifted(&self, at: DateTime<Utc>) -> bool {
+        !self
+            .cron
+            .is_time_matching(&at.with_timezone(&self.timezone))
+            .unwrap_or(true)
+    }
+
+    /// The earliest instant in `(after, before)` of a repeated wall-clock
+    /// time matching the expression, if the interval (give or take
+    /// [`DST_SCAN`]) contains a daylight-saving overlap.
+    fn first_repeat_between(
+        &self,
+        after: DateTime<Utc>,
+        before: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let offset_at = |at: DateTime<Utc>| {
+            self.timezone
+                .offset_from_utc_datetime(&at.naive_utc())
+                .fix()
+                .local_minus_utc()
+        };
+        // An overlap needs the offset to change; transitions are months
+        // apart, so equal offsets at both ends of a short window rule it out.
+        if before - after <= Duration::days(7)
+            && offset_at(after - DST_SCAN) == offset_at(before + DST_SCAN)
+        {
+            return None;
+        }
+
+        // Walk matching wall-clock times (evaluated as naive times, where
+        // there are no transitions) and resolve each in the timezone.
+        let local = |at: DateTime<Utc>| at.with_timezone(&self.timezone).naive_local();
+        let end = local(before) + DST_SCAN;
+        let mut cursor: NaiveDateTime = local(after) - DST_SCAN;
+        let mut earliest: Option<DateTime<Utc>> = None;
+        loop {
+            let naive = self
+                .cron
+                .find_next_occurrence(&Utc.from_utc_datetime(&cursor), false)
+                .ok()?
+                .naive_utc();
+            if naive > end {
+                break;
+            }
+            if let LocalResult::Ambiguous(first, second) = self.timezone.from_local_datetime(&naive)
+            {
+                for at in [first.with_timezone(&Utc), second.with_timezone(&Utc)] {
+                    if at > after && at < before && earliest.is_none_or(|e| at < e) {
+                        earliest = Some(at);
+                    }
+                }
+            }
+            cursor = naive;
+        }
+        earliest
+    }
+
     /// Finds multiple next execution times after the given timestamp.
     ///
     /// This is useful for catchup policies that need to run multiple missed executions.
@@ -349,6 +511,119 @@ impl CronEvaluator {
     }
 }
 
+/// How a simulated fire time relates to a daylight-saving transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DstEdge {
+    /// The scheduled wall-clock time does not exist (clocks jumped forward);
+    /// the fire moved to the first valid time after the gap.
+    Shifted,
+    /// The wall-clock time occurs twice (clocks turned back); the schedule
+    /// fires once, at this instant.
+    Ambiguous,
+    /// The UTC offset differs from the previous fire time (or from the
+    /// simulation start, for the first one).
+    OffsetChanged {
+        from_offset_seconds: i32,
+        to_offset_seconds: i32,
+    },
+}
+
+/// One fire time from [`CronEvaluator::simulate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CronFireTime {
+    /// Fire instant in UTC
+    pub at: DateTime<Utc>,
+    /// The same instant as wall-clock time in the evaluator's timezone
+    pub local: DateTime<FixedOffset>,
+    /// DST transitions affecting this fire; empty for ordinary fires
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dst_edges: Vec<DstEdge>,
+}
+
+impl CronEvaluator {
+    /// Finds the next `limit` fire times after `after`, annotated with how
+    /// each one is affected by daylight-saving transitions.
+    ///
+    /// # Arguments
+    /// * `after` - UTC timestamp to simulate from
+    /// * `limit` - Maximum number of fire times to return
+    ///
+    /// # Returns
+    /// * `Result<Vec<CronFireTime>, CronError>` - Fire times in order
+    pub fn simulate(
+        &self,
+        after: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<CronFireTime>, CronError> {
+        let mut previous_offset = self
+            .timezone
+            .offset_from_utc_datetime(&after.naive_utc())
+            .fix()
+            .local_minus_utc();
+
+        let mut fires = Vec::with_capacity(limit);
+        for at in self.next_executions(after, limit)? {
+            let local = at.with_timezone(&self.timezone);
+            let offset = local.offset().fix().local_minus_utc();
+
+            let mut dst_edges = Vec::new();
+            if !self.cron.is_time_matching(&local).unwrap_or(true) {
+                dst_edges.push(DstEdge::Shifted);
+            }
+            if let LocalResult::Ambiguous(..) =
+                self.timezone.from_local_datetime(&local.naive_local())
+            {
+                dst_edges.push(DstEdge::Ambiguous);
+            }
+            if offset != previous_offset {
+                dst_edges.push(DstEdge::OffsetChanged {
+                    from_offset_seconds: previous_offset,
+                    to_offset_seconds: offset,
+                });
+            }
+            previous_offset = offset;
+
+            fires.push(CronFireTime {
+                at,
+                local: local.fixed_offset(),
+                dst_edges,
+            });
+        }
+        Ok(fires)
+    }
+}
+
+/// Validates a cron expression and timezone and simulates the next `count`
+/// fire times after `after`.
+///
+/// Unlike [`CronEvaluator::validate`], this rejects expressions that parse
+/// but never fire (e.g. `0 0 30 2 *`, February 30th). Schedule registration
+/// validates through it with a `count` of 1.
+///
+/// # Arguments
+/// * `cron_expr` - Cron expression to simulate
+/// * `timezone_str` - IANA timezone name
+/// * `after` - UTC timestamp to simulate from
+/// * `count` - Number of fire times to return
+///
+/// # Returns
+/// * `Result<Vec<CronFireTime>, CronError>` - Fire times, or the validation error
+pub fn simulate_cron(
+    cron_expr: &str,
+    timezone_str: &str,
+    after: DateTime<Utc>,
+    count: usize,
+) -> Result<Vec<CronFireTime>, CronError> {
+    let evaluator = CronEvaluator::new(cron_expr, timezone_str)?;
+    let mut fires = evaluator.simulate(after, count.max(1))?;
+    if fires.is_empty() {
+        return Err(CronError::NoNextExecution);
+    }
+    fires.truncate(count);
+    Ok(fires)
+}
+
 impl FromStr for CronEvaluator {
     type Err = CronError;
 
@@ -528,4 +803,104 @@ mod tests {
         assert_eq!(executions.len(), 1);
         assert_eq!(executions[0].hour(), 14, "9 AM EST = 2 PM UTC");
     }
+
+    #[test]
+    fn test_simulate_flags_offset_change() {
+        // Europe/Berlin springs forward on 2026-03-29 (UTC+1 -> UTC+2).
+        let evaluator = CronEvaluator::new("0 12 * * *", "Europe/Berlin").unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 3, 27, 12, 0, 0).unwrap();
+
+        let fires = evaluator.simulate(after, 3).unwrap();
+        assert_eq!(fires.len(), 3);
+        assert!(fires[0].dst_edges.is_empty(), "2026-03-28 is still CET");
+        assert_eq!(
+            fires[1].dst_edges,
+            vec![DstEdge::OffsetChanged {
+                from_offset_seconds: 3600,
+                to_offset_seconds: 7200,
+            }]
+        );
+        assert_eq!(fires[1].at.hour(), 10, "noon CEST = 10:00 UTC");
+        assert_eq!(fires[1].local.hour(), 12);
+        assert!(fires[2].dst_edges.is_empty());
+    }
+
+    #[test]
+    fn test_simulate_flags_ambiguous_wall_time() {
+        // Europe/Berlin falls back on 2026-10-25; 02:30 happens twice.
+        let evaluator = CronEvaluator::new("30 2 * * *", "Europe/Berlin").unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 10, 24, 12, 0, 0).unwrap();
+
+        let fires = evaluator.simulate(after, 2).unwrap();
+        let transition_day = fires
+            .iter()
+            .find(|f| f.local.day() == 25)
+            .expect("fires on the transition day");
+        assert!(transition_day.dst_edges.contains(&DstEdge::Ambiguous));
+    }
+
+    #[test]
+    fn test_dst_policy_skip_drops_missing_wall_time() {
+        // Europe/Berlin springs forward on 2026-03-29; 02:30 does not exist.
+        let after = Utc.with_ymd_and_hms(2026, 3, 27, 12, 0, 0).unwrap();
+        let run_once = CronEvaluator::new("30 2 * * *", "Europe/Berlin").unwrap();
+        let skip = run_once.clone().with_dst_policy(DstPolicy::Skip);
+
+        let days = |evaluator: &CronEvaluator| -> Vec<u32> {
+            evaluator
+                .next_executions(after, 3)
+                .unwrap()
+                .iter()
+                .map(|at| at.with_timezone(&evaluator.timezone()).day())
+                .collect()
+        };
+        assert_eq!(days(&run_once), vec![28, 29, 30]);
+        assert_eq!(days(&skip), vec![28, 30, 31]);
+    }
+
+    #[test]
+    fn test_dst_policy_run_twice_fires_repeated_wall_time_twice() {
+        // Europe/Berlin falls back on 2026-10-25; 02:30 happens twice.
+        let after = Utc.with_ymd_and_hms(2026, 10, 24, 12, 0, 0).unwrap();
+        let evaluator = CronEvaluator::new("30 2 * * *", "Europe/Berlin")
+            .unwrap()
+            .with_dst_policy(DstPolicy::RunTwice);
+
+        let fires = evaluator.next_executions(after, 3).unwrap();
+        assert_eq!(
+            fires,
+            vec![
+                Utc.with_ymd_and_hms(2026, 10, 25, 0, 30, 0).unwrap(), // 02:30 CEST
+                Utc.with_ymd_and_hms(2026, 10, 25, 1, 30, 0).unwrap(), // 02:30 CET
+                Utc.with_ymd_and_hms(2026, 10, 26, 1, 30, 0).unwrap(),
+            ]
+        );
+
+        let once = evaluator.clone().with_dst_policy(DstPolicy::RunOnce);
+        let fires = once.next_executions(after, 2).unwrap();
+        assert_eq!(fires[1].with_timezone(&once.timezone()).day(), 26);
+    }
+
+    #[test]
+    fn test_dst_policy_round_trips() {
+        for policy in [DstPolicy::Skip, DstPolicy::RunOnce, DstPolicy::RunTwice] {
+            assert_eq!(policy.as_str().parse::<DstPolicy>().unwrap(), policy);
+        }
+        assert!("sometimes".parse::<DstPolicy>().is_err());
+        assert_eq!(DstPolicy::default(), DstPolicy::RunOnce);
+    }
+
+    #[test]
+    fn test_simulate_cron_validates() {
+        assert!(matches!(
+            simulate_cron("0 9 * * *", "Mars/Olympus", Utc::now(), 5),
+            Err(CronError::InvalidTimezone(_))
+        ));
+        assert!(simulate_cron("not cron", "UTC", Utc::now(), 5).is_err());
+
+        let after = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let fires = simulate_cron("0 9 * * *", "UTC", after, 2).unwrap();
+        assert_eq!(fires.len(), 2);
+        assert_eq!(fires[0].at.hour(), 9);
+    }
 }
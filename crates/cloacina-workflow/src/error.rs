@@ -24,9 +24,12 @@
 //!
 //! - [`ContextError`]: Errors related to context operations
 //! - [`TaskError`]: Errors that occur during task execution
+//! - [`TaskErrorPayload`]: Structured, serializable error details a task can
+//!   fail with
 //! - [`CheckpointError`]: Errors in task checkpointing
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Errors that can occur during context operations.
@@ -47,6 +50,17 @@ pub enum ContextError {
     #[error("Type mismatch for key {0}")]
     TypeMismatch(String),
 
+    /// A stored value did not deserialize into the requested type. `path`
+    /// locates the offending field, starting with the key (e.g.
+    /// `records[2].id`).
+    #[error("Cannot read '{path}' as {type_name}: {message}")]
+    Deserialization {
+        key: String,
+        path: String,
+        type_name: String,
+        message: String,
+    },
+
     /// Key already exists when inserting
     #[error("Key already exists: {0}")]
     KeyExists(String),
@@ -107,6 +121,137 @@ pub enum TaskError {
     /// Trigger rule evaluation failed
     #[error("Trigger rule evaluation failed: {task_id}")]
     TriggerRuleFailed { task_id: String },
+
+    /// Task failed with a structured payload. The payload is stored as-is on
+    /// the task execution record and returned by the APIs.
+    #[error("Task {task_id} failed [{}]: {}", payload.code, payload.message)]
+    Structured {
+        task_id: String,
+        payload: TaskErrorPayload,
+    },
+
+    /// Task used more memory than its declared limit
+    #[error(
+        "Task {task_id} exceeded its memory limit: used {observed_bytes} bytes, limit {limit_bytes} bytes"
+    )]
+    MemoryLimitExceeded {
+        task_id: String,
+        limit_bytes: u64,
+        observed_bytes: u64,
+    },
+}
+
+impl TaskError {
+    /// Creates a [`TaskError::Structured`] for `task_id`.
+    pub fn structured(task_id: impl Into<String>, payload: TaskErrorPayload) -> Self {
+        TaskError::Structured {
+            task_id: task_id.into(),
+            payload,
+        }
+    }
+
+    /// Returns the structured payload, if this error carries one.
+    pub fn payload(&self) -> Option<&TaskErrorPayload> {
+        match self {
+            TaskError::Structured { payload, .. } => Some(payload),
+            _ => None,
+        }
+    }
+}
+
+/// Broad class of a structured task failure.
+///
+/// The category is part of the stored payload, and [`ErrorCategory::Transient`]
+/// also marks the error as transient for `RetryCondition::TransientOnly`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// The task's input was invalid.
+    Validation,
+    /// Something the task needed does not exist.
+    NotFound,
+    /// The task was not allowed to do what it tried.
+    PermissionDenied,
+    /// The task's work conflicts with existing state.
+    Conflict,
+    /// A temporary failure (e.g. an upstream outage); retrying may succeed.
+    Transient,
+    /// An external system returned an error.
+    External,
+    /// A failure in the task itself.
+    #[default]
+    Internal,
+}
+
+impl ErrorCategory {
+    /// The category's `snake_case` name, as serialized.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Validation => "validation",
+            ErrorCategory::NotFound => "not_found",
+            ErrorCategory::PermissionDenied => "permission_denied",
+            ErrorCategory::Conflict => "conflict",
+            ErrorCategory::Transient => "transient",
+            ErrorCategory::External => "external",
+            ErrorCategory::Internal => "internal",
+        }
+    }
+}
+
+/// Structured error details for a failed task.
+///
+/// Return one via [`TaskError::Structured`] to have the code, category,
+/// message and details kept verbatim on the task execution record instead of
+/// a formatted string:
+///
+/// ```rust
+/// use cloacina_workflow::{ErrorCategory, TaskError, TaskErrorPayload};
+///
+/// let error = TaskError::structured(
+///     "charge_card",
+///     TaskErrorPayload::new("CARD_DECLINED", "The card was declined")
+///         .with_category(ErrorCategory::External)
+///         .with_details(serde_json::json!({ "decline_code": "insufficient_funds" })),
+/// );
+/// assert_eq!(error.payload().unwrap().code, "CARD_DECLINED");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskErrorPayload {
+    /// Machine-readable error code, e.g. `CARD_DECLINED`.
+    pub code: String,
+    /// Broad class of the failure.
+    #[serde(default)]
+    pub category: ErrorCategory,
+    /// Message meant for the people looking at the failed execution.
+    pub message: String,
+    /// Arbitrary JSON details; `null` when there are none.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub details: serde_json::Value,
+}
+
+impl TaskErrorPayload {
+    /// Creates a payload with the [`ErrorCategory::Internal`] category and no
+    /// details.
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            category: ErrorCategory::default(),
+            message: message.into(),
+            details: serde_json::Value::Null,
+        }
+    }
+
+    /// Sets the category.
+    pub fn with_category(mut self, category: ErrorCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Sets the details.
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = details;
+        self
+    }
 }
 
 impl From<ContextError> for TaskError {
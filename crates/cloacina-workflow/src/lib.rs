@@ -65,25 +65,32 @@
 //! }
 //! ```
 
+pub mod capabilities;
 pub mod context;
+pub mod context_access;
 pub mod cron_evaluator;
 pub mod error;
 pub mod input_interface;
 pub mod namespace;
+pub mod outputs;
 pub mod retry;
+pub mod schedule_expression;
 pub mod secret;
 pub mod task;
 pub mod trigger;
 
 // Re-export primary types at crate root for convenience
-pub use context::Context;
-pub use error::{CheckpointError, ContextError, TaskError};
+pub use capabilities::CapabilityManifest;
+pub use context::{Context, ScopedContext};
+pub use context_access::ContextAccessLog;
+pub use error::{CheckpointError, ContextError, ErrorCategory, TaskError, TaskErrorPayload};
 pub use input_interface::{schema_for, InputSlot};
 pub use namespace::{parse_namespace, TaskNamespace};
+pub use outputs::{OutputKind, OutputsError, ProducedOutput, WorkflowOutputs};
 pub use retry::{BackoffStrategy, RetryCondition, RetryPolicy, RetryPolicyBuilder};
 pub use secret::{SecretAccessError, SecretResolver, SecretResolverError};
-pub use task::{Task, TaskState};
-pub use trigger::{Trigger, TriggerError, TriggerResult};
+pub use task::{Task, TaskState, WorkloadClass};
+pub use trigger::{Trigger, TriggerBatch, TriggerError, TriggerResult};
 
 // Re-export macros when the feature is enabled
 #[cfg(feature = "macros")]
@@ -0,0 +1,333 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Declared workflow outputs — typed extraction from a final context.
+//!
+//! `#[workflow(outputs( name: Type, … ))]` generates an `Outputs` struct inside
+//! the workflow module and implements [`WorkflowOutputs`] for it, so callers
+//! read `result.outputs::<my_workflow::Outputs>()?` instead of poking at the
+//! final context JSON. The same declaration is surfaced as a list of
+//! [`InputSlot`](crate::input_interface::InputSlot)s (kind `"workflow_outputs"`)
+//! so the REST API can document what each workflow returns.
+//!
+//! Tasks declare the keys they write the same way, one level down:
+//! `#[task(produces = ["count: number"])]` yields [`ProducedOutput`]s, and
+//! executors run [`check_produced`] on each successful task's output context so
+//! a missing or mistyped key fails the producing task instead of whichever
+//! downstream task reads it first.
+//!
+//! Lives in `cloacina-workflow` for the same reason as
+//! [`input_interface`](crate::input_interface): the macro emits references to it
+//! into packaged cdylibs, which don't depend on core `cloacina`.
+
+use crate::context::Context;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Surface kind under which declared outputs ride the input-interface
+/// descriptor (`InputInterfaceEntry::surface_kind`).
+pub const OUTPUTS_SURFACE_KIND: &str = "workflow_outputs";
+
+/// The context type outputs are extracted from. Named here so generated code in
+/// packaged cdylibs needn't depend on `serde_json` directly.
+pub type OutputContext = Context<serde_json::Value>;
+
+/// Errors from extracting declared outputs out of a final context.
+#[derive(Debug, Error)]
+pub enum OutputsError {
+    /// A declared output key is absent from the context and its type has no
+    /// "absent" representation (i.e. it is not an `Option`).
+    #[error("declared output '{key}' is missing from the final context")]
+    Missing { key: String },
+
+    /// A declared output key is present but does not deserialize into the
+    /// declared type.
+    #[error("declared output '{key}' has an unexpected shape: {message}")]
+    InvalidType { key: String, message: String },
+}
+
+/// A typed view over a workflow's declared outputs.
+///
+/// Implemented by the `Outputs` struct that `#[workflow(outputs(...))]`
+/// generates; hand-written implementations are possible but unusual.
+pub trait WorkflowOutputs: Sized {
+    /// Declared output keys, in declaration order.
+    const KEYS: &'static [&'static str];
+
+    /// Extract every declared output from `context`.
+    fn from_context(context: &OutputContext) -> Result<Self, OutputsError>;
+}
+
+/// Read one declared output from `context` as `T`.
+///
+/// A missing key is read as `null`, so `Option<_>` outputs come back as `None`
+/// while any other type reports [`OutputsError::Missing`].
+pub fn extract_output<T: DeserializeOwned>(
+    context: &OutputContext,
+    key: &str,
+) -> Result<T, OutputsError> {
+    match context.get(key) {
+        Some(value) => {
+            serde_json::from_value(value.clone()).map_err(|e| OutputsError::InvalidType {
+                key: key.to_string(),
+                message: e.to_string(),
+            })
+        }
+        None => {
+            serde_json::from_value(serde_json::Value::Null).map_err(|_| OutputsError::Missing {
+                key: key.to_string(),
+            })
+        }
+    }
+}
+
+/// JSON type of one output a task declares with `produces`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputKind {
+    /// A JSON object
+    Object,
+    /// A JSON array
+    Array,
+    /// A JSON string
+    String,
+    /// Any JSON number
+    Number,
+    /// A JSON number without a fractional part
+    Integer,
+    /// `true` or `false`
+    Boolean,
+    /// Any value, including `null`; only presence is checked
+    Any,
+}
+
+impl OutputKind {
+    /// Returns the string representation of the kind.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputKind::Object => "object",
+            OutputKind::Array => "array",
+            OutputKind::String => "string",
+            OutputKind::Number => "number",
+            OutputKind::Integer => "integer",
+            OutputKind::Boolean => "boolean",
+            OutputKind::Any => "any",
+        }
+    }
+
+    /// Parses a kind from its string representation; `bool` is accepted for
+    /// `boolean`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "object" => Some(OutputKind::Object),
+            "array" => Some(OutputKind::Array),
+            "string" => Some(OutputKind::String),
+            "number" => Some(OutputKind::Number),
+            "integer" => Some(OutputKind::Integer),
+            "boolean" | "bool" => Some(OutputKind::Boolean),
+            "any" => Some(OutputKind::Any),
+            _ => None,
+        }
+    }
+
+    /// Returns whether `value` is of this kind.
+    pub fn matches(&self, value: &serde_json::Value) -> bool {
+        match self {
+            OutputKind::Object => value.is_object(),
+            OutputKind::Array => value.is_array(),
+            OutputKind::String => value.is_string(),
+            OutputKind::Number => value.is_number(),
+            OutputKind::Integer => value.is_i64() || value.is_u64(),
+            OutputKind::Boolean => value.is_boolean(),
+            OutputKind::Any => true,
+        }
+    }
+}
+
+impl std::fmt::Display for OutputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One context key a task promises to write, and its JSON type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProducedOutput {
+    /// Context key the task writes.
+    pub key: String,
+    /// JSON type the value must have.
+    pub kind: OutputKind,
+}
+
+impl ProducedOutput {
+    /// Creates a declaration for `key` of type `kind`.
+    pub fn new(key: impl Into<String>, kind: OutputKind) -> Self {
+        Self {
+            key: key.into(),
+            kind,
+        }
+    }
+
+    /// Parses a `"key: kind"` declaration, the form `produces` takes.
+    pub fn parse(declaration: &str) -> Option<Self> {
+        let (key, kind) = declaration.split_once(':')?;
+        let key = key.trim();
+        if key.is_empty() {
+            return None;
+        }
+        Some(Self::new(key, OutputKind::from_str(kind.trim())?))
+    }
+}
+
+impl std::fmt::Display for ProducedOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.key, self.kind)
+    }
+}
+
+/// A declared output a task's context does not satisfy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputViolation {
+    /// The declared key.
+    pub key: String,
+    /// The declared type.
+    pub expected: OutputKind,
+    /// JSON type of the value found, or `None` when the key is missing.
+    pub found: Option<String>,
+}
+
+impl std::fmt::Display for OutputViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.found {
+            Some(found) => write!(f, "'{}' is {}, expected {}", self.key, found, self.expected),
+            None => write!(f, "'{}' is missing, expected {}", self.key, self.expected),
+        }
+    }
+}
+
+/// Checks `context` against a task's declared outputs, returning every
+/// declaration it does not satisfy (empty when all hold).
+pub fn check_produced(
+    context: &OutputContext,
+    produced: &[ProducedOutput],
+) -> Vec<OutputViolation> {
+    produced
+        .iter()
+        .filter_map(|output| {
+            let found = match context.get(&output.key) {
+                Some(value) if output.kind.matches(value) => return None,
+                Some(value) => Some(json_type_name(value).to_string()),
+                None => None,
+            };
+            Some(OutputViolation {
+                key: output.key.clone(),
+                expected: output.kind,
+                found,
+            })
+        })
+        .collect()
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn extracts_present_value() {
+        let mut ctx = Context::new();
+        ctx.insert("count", json!(3)).unwrap();
+        let count: u32 = extract_output(&ctx, "count").unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn missing_optional_is_none() {
+        let ctx = Context::new();
+        let v: Option<String> = extract_output(&ctx, "summary").unwrap();
+        assert!(v.is_none());
+    }
+
+    #[test]
+    fn missing_required_is_error() {
+        let ctx = Context::new();
+        let err = extract_output::<u32>(&ctx, "count").unwrap_err();
+        assert!(matches!(err, OutputsError::Missing { ref key } if key == "count"));
+    }
+
+    #[test]
+    fn wrong_shape_is_invalid_type() {
+        let mut ctx = Context::new();
+        ctx.insert("count", json!("three")).unwrap();
+        let err = extract_output::<u32>(&ctx, "count").unwrap_err();
+        assert!(matches!(err, OutputsError::InvalidType { ref key, .. } if key == "count"));
+    }
+
+    #[test]
+    fn parses_produced_declarations() {
+        let output = ProducedOutput::parse("processed_data : object").unwrap();
+        assert_eq!(
+            output,
+            ProducedOutput::new("processed_data", OutputKind::Object)
+        );
+        assert_eq!(output.to_string(), "processed_data: object");
+        assert!(ProducedOutput::parse("count").is_none());
+        assert!(ProducedOutput::parse(": number").is_none());
+        assert!(ProducedOutput::parse("count: float").is_none());
+    }
+
+    #[test]
+    fn check_produced_reports_missing_and_mistyped_keys() {
+        let mut ctx = Context::new();
+        ctx.insert("processed_data", json!({"rows": 2})).unwrap();
+        ctx.insert("count", json!("two")).unwrap();
+        ctx.insert("ratio", json!(0.5)).unwrap();
+        let produced = [
+            ProducedOutput::new("processed_data", OutputKind::Object),
+            ProducedOutput::new("count", OutputKind::Number),
+            ProducedOutput::new("ratio", OutputKind::Integer),
+            ProducedOutput::new("summary", OutputKind::Any),
+        ];
+
+        let violations = check_produced(&ctx, &produced);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(
+            violations[0].to_string(),
+            "'count' is string, expected number"
+        );
+        assert_eq!(
+            violations[1].to_string(),
+            "'ratio' is number, expected integer"
+        );
+        assert_eq!(
+            violations[2].to_string(),
+            "'summary' is missing, expected any"
+        );
+    }
+}
@@ -46,7 +46,7 @@
 //!     .build();
 //! ```
 
-use crate::error::TaskError;
+use crate::error::{ErrorCategory, TaskError};
 use chrono::NaiveDateTime;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
@@ -265,6 +265,7 @@ impl RetryPolicy {
     fn is_transient_error(&self, error: &TaskError) -> bool {
         match error {
             TaskError::Timeout { .. } => true,
+            TaskError::Structured { payload, .. } => payload.category == ErrorCategory::Transient,
             TaskError::ExecutionFailed { message, .. } | TaskError::Unknown { message, .. } => {
                 Self::message_matches_transient_patterns(message)
             }
@@ -499,6 +500,25 @@ mod tests {
         assert!(policy.is_transient_error(&make_unknown_error("TIMEOUT waiting for response")));
     }
 
+    #[test]
+    fn test_structured_error_is_transient_by_category() {
+        let policy = RetryPolicy::default();
+        let transient = TaskError::structured(
+            "t",
+            crate::error::TaskErrorPayload::new("UPSTREAM_DOWN", "invalid response")
+                .with_category(ErrorCategory::Transient),
+        );
+        assert!(policy.is_transient_error(&transient));
+
+        // The message is not pattern-matched: the category decides.
+        let permanent = TaskError::structured(
+            "t",
+            crate::error::TaskErrorPayload::new("BAD_ROW", "connection field missing")
+                .with_category(ErrorCategory::Validation),
+        );
+        assert!(!policy.is_transient_error(&permanent));
+    }
+
     #[test]
     fn test_permanent_errors_are_not_transient() {
         let policy = RetryPolicy::default();
@@ -531,6 +551,11 @@ mod tests {
         assert!(!policy.is_transient_error(&TaskError::TriggerRuleFailed {
             task_id: "t".to_string(),
         }));
+        assert!(!policy.is_transient_error(&TaskError::MemoryLimitExceeded {
+            task_id: "t".to_string(),
+            limit_bytes: 1024,
+            observed_bytes: 2048,
+        }));
     }
 
     #[test]
@@ -0,0 +1,520 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Preset and natural-language schedule expressions
+//!
+//! Schedules are always stored as canonical 5-field cron. This module turns
+//! the friendlier spellings users reach for into that canonical form:
+//!
+//! - Presets: `@yearly`/`@annually`, `@monthly`, `@weekly`, `@daily`/`@midnight`,
+//!   `@hourly`
+//! - A small natural-language grammar:
+//!   - `every minute`, `every 15 minutes`
+//!   - `every hour`, `every 2 hours`, `every hour at :30`
+//!   - `every day at 9am`, `daily at 17:30`
+//!   - `every weekday at 9am`, `every weekend at noon`
+//!   - `every monday and thursday at 8:15pm`
+//!   - `every month on the 1st at midnight`
+//!
+//! Anything that already parses as cron is passed through untouched. Inputs
+//! that could mean more than one schedule (`every day at 9` — morning or
+//! evening?) are rejected with [`CronError::AmbiguousExpression`] rather than
+//! guessed at.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloacina_workflow::schedule_expression::normalize_cron_expression;
+//!
+//! assert_eq!(normalize_cron_expression("@hourly").unwrap(), "0 * * * *");
+//! assert_eq!(
+//!     normalize_cron_expression("every weekday at 9am").unwrap(),
+//!     "0 9 * * 1-5"
+//! );
+//! assert_eq!(normalize_cron_expression("*/5 * * * *").unwrap(), "*/5 * * * *");
+//! assert!(normalize_cron_expression("every day at 9").is_err());
+//! ```
+
+use crate::cron_evaluator::{CronError, CronEvaluator};
+
+/// Normalize a preset, natural-language, or cron expression to canonical cron.
+///
+/// # Arguments
+/// * `input` - The schedule expression as written by the user
+///
+/// # Returns
+/// * `Result<String, CronError>` - Canonical cron expression or a descriptive error
+pub fn normalize_cron_expression(input: &str) -> Result<String, CronError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(CronError::InvalidExpression(
+            "schedule expression is empty".to_string(),
+        ));
+    }
+
+    if let Some(preset) = trimmed.strip_prefix('@') {
+        return preset_to_cron(preset).map(str::to_string);
+    }
+
+    let lowered = trimmed.to_ascii_lowercase();
+    let words: Vec<&str> = lowered
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if is_natural_language(&words) {
+        let cron = parse_natural(&words, trimmed)?;
+        // The grammar only emits well-formed cron, but keep the invariant
+        // that everything we return is accepted by the evaluator.
+        CronEvaluator::validate_expression(&cron)?;
+        return Ok(cron);
+    }
+
+    CronEvaluator::validate_expression(trimmed)?;
+    Ok(trimmed.to_string())
+}
+
+fn preset_to_cron(preset: &str) -> Result<&'static str, CronError> {
+    match preset.to_ascii_lowercase().as_str() {
+        "yearly" | "annually" => Ok("0 0 1 1 *"),
+        "monthly" => Ok("0 0 1 * *"),
+        "weekly" => Ok("0 0 * * 0"),
+        "daily" | "midnight" => Ok("0 0 * * *"),
+        "hourly" => Ok("0 * * * *"),
+        "reboot" => Err(CronError::InvalidExpression(
+            "@reboot is not supported: schedules fire on wall-clock time, not process start"
+                .to_string(),
+        )),
+        other => Err(CronError::InvalidExpression(format!(
+            "unknown preset '@{}' (expected one of @yearly, @annually, @monthly, @weekly, @daily, @midnight, @hourly)",
+            other
+        ))),
+    }
+}
+
+fn is_natural_language(words: &[&str]) -> bool {
+    matches!(
+        words.first(),
+        Some(&"every") | Some(&"daily") | Some(&"hourly") | Some(&"weekly") | Some(&"monthly")
+    )
+}
+
+/// A wall-clock time of day resolved from phrases like `9am` or `17:30`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimeOfDay {
+    hour: u32,
+    minute: u32,
+}
+
+fn parse_natural(words: &[&str], original: &str) -> Result<String, CronError> {
+    // Split off a trailing `at <time>[ and <time>...]` clause, if present.
+    let (head, times) = match words.iter().position(|w| *w == "at") {
+        Some(idx) => (&words[..idx], Some(&words[idx + 1..])),
+        None => (words, None),
+    };
+
+    let head: Vec<&str> = head.iter().copied().filter(|w| *w != "every").collect();
+
+    match head.as_slice() {
+        ["minute"] => no_time(times, "every minute").map(|_| "* * * * *".to_string()),
+        [n, "minutes"] => {
+            no_time(times, "every N minutes")?;
+            let step = parse_step(n, 60, "minutes")?;
+            Ok(format!("*/{} * * * *", step))
+        }
+        ["hour"] | ["hourly"] => {
+            let minute = minute_past_the_hour(times)?;
+            Ok(format!("{} * * * *", minute))
+        }
+        [n, "hours"] => {
+            let minute = minute_past_the_hour(times)?;
+            let step = parse_step(n, 24, "hours")?;
+            Ok(format!("{} */{} * * *", minute, step))
+        }
+        ["day"] | ["daily"] => {
+            let (minute, hours) = required_times(times, original)?;
+            Ok(format!("{} {} * * *", minute, hours))
+        }
+        ["weekday"] | ["weekdays"] => {
+            let (minute, hours) = required_times(times, original)?;
+            Ok(format!("{} {} * * 1-5", minute, hours))
+        }
+        ["weekend"] | ["weekends"] => {
+            let (minute, hours) = required_times(times, original)?;
+            Ok(format!("{} {} * * 0,6", minute, hours))
+        }
+        ["week"] | ["weekly"] => Err(CronError::AmbiguousExpression(format!(
+            "'{}' does not say which day of the week; use e.g. 'every monday at 9am' or @weekly",
+            original
+        ))),
+        ["month"] | ["monthly"] => Err(CronError::AmbiguousExpression(format!(
+            "'{}' does not say which day of the month; use e.g. 'every month on the 1st at 9am' or @monthly",
+            original
+        ))),
+        ["month", "on", rest @ ..] | ["monthly", "on", rest @ ..] => {
+            let day = parse_day_of_month(rest, original)?;
+            let (minute, hours) = required_times(times, original)?;
+            Ok(format!("{} {} {} * *", minute, hours, day))
+        }
+        days if !days.is_empty() => {
+            let dow = parse_days_of_week(days, original)?;
+            let (minute, hours) = required_times(times, original)?;
+            Ok(format!("{} {} * * {}", minute, hours, dow))
+        }
+        _ => Err(unrecognized(original)),
+    }
+}
+
+fn unrecognized(original: &str) -> CronError {
+    CronError::InvalidExpression(format!(
+        "could not understand schedule '{}'; use cron syntax, a preset such as @daily, or a phrase like 'every weekday at 9am'",
+        original
+    ))
+}
+
+fn no_time(times: Option<&[&str]>, phrase: &str) -> Result<(), CronError> {
+    match times {
+        None => Ok(()),
+        Some(_) => Err(CronError::InvalidExpression(format!(
+            "'{}' cannot be combined with a time of day",
+            phrase
+        ))),
+    }
+}
+
+fn parse_step(raw: &str, unit_max: u32, unit: &str) -> Result<u32, CronError> {
+    let n: u32 = raw.parse().map_err(|_| {
+        CronError::InvalidExpression(format!("'{}' is not a number of {}", raw, unit))
+    })?;
+    if n == 0 || n >= unit_max {
+        return Err(CronError::InvalidExpression(format!(
+            "every {} {} is out of range (1-{})",
+            n,
+            unit,
+            unit_max - 1
+        )));
+    }
+    // Cron steps restart at each boundary, so a step that does not divide the
+    // unit evenly produces an irregular gap (e.g. every 7 minutes fires at
+    // :56 and then :00). Refuse rather than silently schedule something else.
+    if !unit_max.is_multiple_of(n) {
+        return Err(CronError::AmbiguousExpression(format!(
+            "every {} {} cannot be expressed evenly in cron ({} does not divide {}); choose a divisor of {}",
+            n, unit, n, unit_max, unit_max
+        )));
+    }
+    Ok(n)
+}
+
+/// Resolve the optional `at :MM` / `at MM minutes past` clause of an hourly phrase.
+fn minute_past_the_hour(times: Option<&[&str]>) -> Result<u32, CronError> {
+    let Some(words) = times else {
+        return Ok(0);
+    };
+    let raw: &str = match words {
+        [m] => m.trim_start_matches(':'),
+        [m, "past"] | [m, "minutes", "past"] | [m, "past", "the", "hour"] => m,
+        [m, "minutes", "past", "the", "hour"] => m,
+        _ => {
+            return Err(CronError::InvalidExpression(format!(
+                "expected a minute past the hour such as 'at :15', got 'at {}'",
+                words.join(" ")
+            )))
+        }
+    };
+    let minute: u32 = raw.parse().map_err(|_| {
+        CronError::InvalidExpression(format!("'{}' is not a minute past the hour", raw))
+    })?;
+    if minute > 59 {
+        return Err(CronError::InvalidExpression(format!(
+            "minute {} is out of range (0-59)",
+            minute
+        )));
+    }
+    Ok(minute)
+}
+
+/// Resolve a required `at <time>[ and <time>...]` clause into cron minute and
+/// hour fields. Several times are allowed only when they share a minute,
+/// because a single cron line cannot pair distinct minutes with distinct hours.
+fn required_times(times: Option<&[&str]>, original: &str) -> Result<(u32, String), CronError> {
+    let Some(words) = times else {
+        return Err(CronError::AmbiguousExpression(format!(
+            "'{}' needs a time of day, e.g. '{} at 9am'",
+            original, original
+        )));
+    };
+    let parsed = parse_times(words)?;
+    let minute = parsed[0].minute;
+    if parsed.iter().any(|t| t.minute != minute) {
+        return Err(CronError::AmbiguousExpression(format!(
+            "times in '{}' use different minutes; a single schedule can only combine times on the same minute",
+            original
+        )));
+    }
+    let mut hours: Vec<u32> = parsed.iter().map(|t| t.hour).collect();
+    hours.sort_unstable();
+    hours.dedup();
+    let hours = hours
+        .iter()
+        .map(|h| h.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    Ok((minute, hours))
+}
+
+fn parse_times(words: &[&str]) -> Result<Vec<TimeOfDay>, CronError> {
+    if words.is_empty() {
+        return Err(CronError::InvalidExpression(
+            "expected a time of day after 'at'".to_string(),
+        ));
+    }
+    let mut times = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i];
+        if word == "and" {
+            i += 1;
+            continue;
+        }
+        // Allow a detached meridiem: `9 am`, `9:30 pm`.
+        let (token, consumed) = match words.get(i + 1) {
+            Some(next) if *next == "am" || *next == "pm" => (format!("{}{}", word, next), 2),
+            _ => (word.to_string(), 1),
+        };
+        times.push(parse_time_of_day(&token)?);
+        i += consumed;
+    }
+    Ok(times)
+}
+
+fn parse_time_of_day(token: &str) -> Result<TimeOfDay, CronError> {
+    match token {
+        "noon" | "midday" => {
+            return Ok(TimeOfDay {
+                hour: 12,
+                minute: 0,
+            })
+        }
+        "midnight" => return Ok(TimeOfDay { hour: 0, minute: 0 }),
+        _ => {}
+    }
+
+    let (clock, meridiem) = if let Some(c) = token.strip_suffix("am") {
+        (c, Some(false))
+    } else if let Some(c) = token.strip_suffix("pm") {
+        (c, Some(true))
+    } else {
+        (token, None)
+    };
+
+    let (hour_raw, minute_raw) = match clock.split_once(':') {
+        Some((h, m)) => (h, Some(m)),
+        None => (clock, None),
+    };
+    let invalid = || CronError::InvalidExpression(format!("'{}' is not a time of day", token));
+    let hour: u32 = hour_raw.parse().map_err(|_| invalid())?;
+    let minute: u32 = match minute_raw {
+        Some(m) if m.len() == 2 => m.parse().map_err(|_| invalid())?,
+        Some(_) => return Err(invalid()),
+        None => 0,
+    };
+    if minute > 59 {
+        return Err(invalid());
+    }
+
+    let hour = match meridiem {
+        Some(pm) => {
+            if hour == 0 || hour > 12 {
+                return Err(CronError::InvalidExpression(format!(
+                    "'{}' is not a valid 12-hour time",
+                    token
+                )));
+            }
+            match (hour, pm) {
+                (12, false) => 0,
+                (12, true) => 12,
+                (h, false) => h,
+                (h, true) => h + 12,
+            }
+        }
+        // `17:30` / `09:00` are unambiguous 24-hour times; a bare `9` is not.
+        None if minute_raw.is_some() => {
+            if hour > 23 {
+                return Err(invalid());
+            }
+            hour
+        }
+        None => {
+            return Err(CronError::AmbiguousExpression(format!(
+                "'{}' could be morning or evening; write {}am, {}pm, or {:02}:00",
+                token, hour, hour, hour
+            )))
+        }
+    };
+
+    Ok(TimeOfDay { hour, minute })
+}
+
+fn parse_day_of_month(words: &[&str], original: &str) -> Result<u32, CronError> {
+    let raw = match words {
+        ["the", d] | [d] => *d,
+        ["day", d] => *d,
+        _ => return Err(unrecognized(original)),
+    };
+    let digits = raw
+        .trim_end_matches("st")
+        .trim_end_matches("nd")
+        .trim_end_matches("rd")
+        .trim_end_matches("th");
+    let day: u32 = digits.parse().map_err(|_| {
+        CronError::InvalidExpression(format!("'{}' is not a day of the month", raw))
+    })?;
+    if day == 0 || day > 31 {
+        return Err(CronError::InvalidExpression(format!(
+            "day of month {} is out of range (1-31)",
+            day
+        )));
+    }
+    if day > 28 {
+        // Cron silently skips months without the day, which is rarely what a
+        // "monthly" schedule intends.
+        return Err(CronError::AmbiguousExpression(format!(
+            "day {} does not exist in every month, so '{}' would skip some months; use a day between 1 and 28",
+            day, original
+        )));
+    }
+    Ok(day)
+}
+
+fn parse_days_of_week(words: &[&str], original: &str) -> Result<String, CronError> {
+    let mut days: Vec<u32> = Vec::new();
+    for word in words {
+        if *word == "and" {
+            continue;
+        }
+        let day = match word.trim_end_matches('s') {
+            "sunday" | "sun" => 0,
+            "monday" | "mon" => 1,
+            "tuesday" | "tue" | "tues" => 2,
+            "wednesday" | "wed" => 3,
+            "thursday" | "thu" | "thur" | "thurs" => 4,
+            "friday" | "fri" => 5,
+            "saturday" | "sat" => 6,
+            _ => return Err(unrecognized(original)),
+        };
+        if !days.contains(&day) {
+            days.push(day);
+        }
+    }
+    if days.is_empty() {
+        return Err(unrecognized(original));
+    }
+    days.sort_unstable();
+    Ok(days
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(input: &str) -> String {
+        normalize_cron_expression(input)
+            .unwrap_or_else(|e| panic!("'{}' should normalize: {}", input, e))
+    }
+
+    fn ambiguous(input: &str) {
+        match normalize_cron_expression(input) {
+            Err(CronError::AmbiguousExpression(_)) => {}
+            other => panic!("'{}' should be ambiguous, got {:?}", input, other),
+        }
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(ok("@hourly"), "0 * * * *");
+        assert_eq!(ok("@daily"), "0 0 * * *");
+        assert_eq!(ok("@midnight"), "0 0 * * *");
+        assert_eq!(ok("@weekly"), "0 0 * * 0");
+        assert_eq!(ok("@monthly"), "0 0 1 * *");
+        assert_eq!(ok("@yearly"), "0 0 1 1 *");
+        assert_eq!(ok("@ANNUALLY"), "0 0 1 1 *");
+        assert!(normalize_cron_expression("@reboot").is_err());
+        assert!(normalize_cron_expression("@fortnightly").is_err());
+    }
+
+    #[test]
+    fn test_cron_passthrough() {
+        assert_eq!(ok("0 9 * * 1-5"), "0 9 * * 1-5");
+        assert_eq!(ok("  */15 * * * *  "), "*/15 * * * *");
+        assert!(normalize_cron_expression("not a schedule").is_err());
+        assert!(normalize_cron_expression("").is_err());
+    }
+
+    #[test]
+    fn test_minute_and_hour_intervals() {
+        assert_eq!(ok("every minute"), "* * * * *");
+        assert_eq!(ok("every 15 minutes"), "*/15 * * * *");
+        assert_eq!(ok("every hour"), "0 * * * *");
+        assert_eq!(ok("hourly"), "0 * * * *");
+        assert_eq!(ok("every hour at :30"), "30 * * * *");
+        assert_eq!(ok("every 2 hours"), "0 */2 * * *");
+        assert_eq!(ok("every 6 hours at 15 minutes past"), "15 */6 * * *");
+        ambiguous("every 7 minutes");
+        ambiguous("every 5 hours");
+        assert!(normalize_cron_expression("every 0 minutes").is_err());
+        assert!(normalize_cron_expression("every minute at 9am").is_err());
+    }
+
+    #[test]
+    fn test_daily_and_weekly_phrases() {
+        assert_eq!(ok("every day at 9am"), "0 9 * * *");
+        assert_eq!(ok("daily at 17:30"), "30 17 * * *");
+        assert_eq!(ok("Every Weekday at 9am"), "0 9 * * 1-5");
+        assert_eq!(ok("every weekend at noon"), "0 12 * * 0,6");
+        assert_eq!(ok("every monday at 8:15 pm"), "15 20 * * 1");
+        assert_eq!(
+            ok("every monday, wednesday and friday at 6am"),
+            "0 6 * * 1,3,5"
+        );
+        assert_eq!(ok("every day at 9am and 5pm"), "0 9,17 * * *");
+        assert_eq!(ok("every day at midnight"), "0 0 * * *");
+        assert_eq!(ok("every day at 12am"), "0 0 * * *");
+        assert_eq!(ok("every day at 12pm"), "0 12 * * *");
+    }
+
+    #[test]
+    fn test_monthly_phrases() {
+        assert_eq!(ok("every month on the 1st at 9am"), "0 9 1 * *");
+        assert_eq!(ok("monthly on the 15th at 06:00"), "0 6 15 * *");
+        ambiguous("every month on the 31st at 9am");
+        ambiguous("every month");
+    }
+
+    #[test]
+    fn test_ambiguous_phrases_are_rejected() {
+        ambiguous("every day at 9");
+        ambiguous("every weekday");
+        ambiguous("every week");
+        ambiguous("every day at 9am and 5:30pm");
+        assert!(normalize_cron_expression("every day at 13pm").is_err());
+        assert!(normalize_cron_expression("every blursday at 9am").is_err());
+    }
+}
@@ -19,9 +19,11 @@
 //! This module provides the core `Task` trait and `TaskState` enum for defining
 //! executable tasks in Cloacina workflows.
 
+use crate::capabilities::CapabilityManifest;
 use crate::context::Context;
 use crate::error::{CheckpointError, TaskError};
 use crate::namespace::TaskNamespace;
+use crate::outputs::ProducedOutput;
 use crate::retry::RetryPolicy;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -88,6 +90,59 @@ impl TaskState {
     }
 }
 
+/// The resource a task mostly spends its time on.
+///
+/// Runners can cap how many tasks of each class run at once, and place a
+/// task according to its class: [`WorkloadClass::Cpu`] tasks run on the
+/// blocking thread pool so they never stall the async workers that drive
+/// I/O-bound tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkloadClass {
+    /// Compute-bound work; runs on the blocking thread pool
+    Cpu,
+    /// Work that mostly waits on the network, disk or other services
+    Io,
+    /// Work that holds a large amount of memory while it runs
+    Memory,
+}
+
+impl WorkloadClass {
+    /// Returns the string representation of the class.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkloadClass::Cpu => "cpu",
+            WorkloadClass::Io => "io",
+            WorkloadClass::Memory => "memory",
+        }
+    }
+
+    /// Parses a class from its string representation. The long forms
+    /// `cpu_bound`, `io_bound` and `memory_heavy` (with `_` or `-`) are
+    /// accepted too.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.replace('-', "_").as_str() {
+            "cpu" | "cpu_bound" => Some(WorkloadClass::Cpu),
+            "io" | "io_bound" => Some(WorkloadClass::Io),
+            "memory" | "memory_heavy" => Some(WorkloadClass::Memory),
+            _ => None,
+        }
+    }
+
+    /// Returns whether tasks of this class run on the blocking thread pool
+    /// instead of an async worker.
+    pub fn runs_blocking(&self) -> bool {
+        matches!(self, WorkloadClass::Cpu)
+    }
+}
+
+impl std::fmt::Display for WorkloadClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Core trait that defines an executable task in a pipeline.
 ///
 /// Tasks are the fundamental units of work in Cloacina. Most users should use the
@@ -147,6 +202,31 @@ pub trait Task: Send + Sync {
     /// execute after all its dependencies have completed successfully.
     fn dependencies(&self) -> &[TaskNamespace];
 
+    /// Returns whether `dependency` is an ordering-only ("soft") dependency.
+    ///
+    /// A soft dependency still has to reach a terminal state before this task
+    /// runs, but it is not a data dependency: its output context is not merged
+    /// into this task's input, and its outcome never skips this task (an
+    /// upstream short-circuit does not propagate across a soft edge). Use it
+    /// for "run after, regardless of outcome" relationships such as cleanup.
+    ///
+    /// The default implementation returns `false` (every dependency is a data
+    /// dependency).
+    fn is_soft_dependency(&self, _dependency: &TaskNamespace) -> bool {
+        false
+    }
+
+    /// Returns the dependencies whose output feeds this task — every entry of
+    /// [`dependencies`](Self::dependencies) that is not
+    /// [soft](Self::is_soft_dependency).
+    fn data_dependencies(&self) -> Vec<TaskNamespace> {
+        self.dependencies()
+            .iter()
+            .filter(|dependency| !self.is_soft_dependency(dependency))
+            .cloned()
+            .collect()
+    }
+
     /// Saves a checkpoint for this task.
     ///
     /// This method is called to save intermediate state during task execution.
@@ -192,6 +272,209 @@ pub trait Task: Send + Sync {
         serde_json::json!({"type": "Always"})
     }
 
+    /// Returns the output key whose emptiness short-circuits this task's
+    /// descendants.
+    ///
+    /// When a task declares a short-circuit key and completes with that key
+    /// missing, `null`, `false`, `""`, `[]` or `{}` in its output context, every
+    /// downstream task that uses the default `Always` trigger rule is marked
+    /// Skipped with a reason naming this task — no mirrored `trigger_rules` on
+    /// each descendant required. Tasks with explicit trigger rules keep
+    /// evaluating their own rules.
+    ///
+    /// The default implementation returns `None` (never short-circuits).
+    fn short_circuit(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the output key under which this task, as a branch decider,
+    /// writes the name of the branch to take.
+    ///
+    /// When the task completes, each direct dependent that declares a
+    /// [`branch`](Task::branch) runs only if its branch name equals the string
+    /// stored under this key; the others are marked Skipped with a
+    /// `BranchNotTaken` reason, and so is everything downstream of them whose
+    /// data dependencies were all left out. A missing or non-string value
+    /// selects no branch.
+    ///
+    /// The default implementation returns `None` (not a decider).
+    fn branch_key(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the name of the branch this task heads, when it directly
+    /// depends on a branch decider (see [`branch_key`](Task::branch_key)).
+    ///
+    /// The default implementation returns `None` (the task runs regardless of
+    /// any decider's choice).
+    fn branch(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the condition that ends this task's loop, in trigger-rule form
+    /// restricted to context conditions.
+    ///
+    /// When set, the executor runs the task body again — feeding each
+    /// iteration the previous one's output context — until the condition
+    /// holds on the output, up to [`max_iterations`](Task::max_iterations)
+    /// times. Every iteration is recorded as a `task_iterated`
+    /// execution event; running out of iterations fails the attempt with the
+    /// `LOOP_LIMIT_EXCEEDED` error code.
+    ///
+    /// The default implementation returns `None` (the body runs once).
+    fn loop_until(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Returns the most iterations one attempt of a looping task may run.
+    ///
+    /// Only meaningful with [`loop_until`](Task::loop_until); a loop without
+    /// a bound runs once.
+    ///
+    /// The default implementation returns `None`.
+    fn max_iterations(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns the memory limit for a single execution of this task, in bytes.
+    ///
+    /// When set, the executor tracks the task's memory while it runs and fails
+    /// it with [`TaskError::MemoryLimitExceeded`] once usage passes the limit.
+    /// In-process executors count the task's own allocations (an estimate that
+    /// needs the host binary to install `cloacina::memory::TrackingAllocator`);
+    /// execution agents, which run tasks in their own process, measure resident
+    /// set size growth instead.
+    ///
+    /// The default implementation returns `None` (no limit).
+    fn memory_limit(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns how long a single attempt of this task may run.
+    ///
+    /// An attempt still running after this long is failed with a timeout,
+    /// which frees its concurrency slot and is retried like any other
+    /// transient failure. Overrides the runner's default task timeout; a
+    /// per-execution `task_timeout` override still takes precedence.
+    ///
+    /// The default implementation returns `None` (the runner's default).
+    fn timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+
+    /// Returns how long one attempt of this task is expected to run.
+    ///
+    /// Used against an execution's deadline: the executor does not start a
+    /// task whose expected duration is longer than the time left before the
+    /// deadline, and fails the execution with `DEADLINE_EXCEEDED` instead.
+    ///
+    /// The default implementation returns `None` (no estimate; the task
+    /// starts as long as the deadline has not passed).
+    fn expected_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+
+    /// Returns whether this task waits for a human approval before it runs.
+    ///
+    /// Once its dependencies and trigger rules are satisfied, a gated task
+    /// is parked as `AwaitingApproval` until an approver approves it (it then
+    /// runs normally) or rejects it (it fails without running).
+    ///
+    /// The default implementation returns `false`.
+    fn requires_approval(&self) -> bool {
+        false
+    }
+
+    /// Returns the workload class of this task.
+    ///
+    /// The executor applies the runner's per-class concurrency limit to the
+    /// task and, for [`WorkloadClass::Cpu`], runs it on the blocking thread
+    /// pool.
+    ///
+    /// The default implementation returns `None` (unclassified: only the
+    /// runner's overall concurrency limit applies).
+    fn workload_class(&self) -> Option<WorkloadClass> {
+        None
+    }
+
+    /// Returns the named resources this task uses, e.g. `["gpu",
+    /// "db_connection"]`.
+    ///
+    /// For every resource the runner defines a pool for, the task holds one
+    /// slot of that pool while it runs, so a pool's capacity caps how many
+    /// tasks use the resource at once. Resources without a pool are not
+    /// limited.
+    ///
+    /// The default implementation returns no resources.
+    fn resources(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the context keys this task promises to write, with their JSON
+    /// types, as declared by `#[task(produces = ["count: number"])]`.
+    ///
+    /// Executors check each successful attempt's output context against
+    /// them: a missing or mistyped key fails the attempt with the
+    /// `OUTPUT_SCHEMA_VIOLATION` error code rather than leaving downstream
+    /// tasks to trip over it.
+    ///
+    /// The default implementation declares no outputs (nothing is checked).
+    fn produces(&self) -> Vec<ProducedOutput> {
+        Vec::new()
+    }
+
+    /// Returns whether this task's writes go into a scope named after its
+    /// id (see [`Context::scoped`](crate::Context::scoped)) instead of the
+    /// shared top level of the context.
+    ///
+    /// After each successful attempt, executors move every key the task
+    /// added or changed under `<id>` and leave the keys it received as it
+    /// found them, so parallel tasks writing the same key name don't
+    /// overwrite each other when a downstream task merges their outputs.
+    ///
+    /// The default implementation returns `false`.
+    fn scoped(&self) -> bool {
+        false
+    }
+
+    /// Returns the upstream context keys this task reads, as declared by
+    /// `#[task(reads = ["config", "extract"])]`.
+    ///
+    /// A task that declares them sees only those keys. The other keys are
+    /// not visible to it and pass through to its output unchanged. Naming
+    /// a scope admits the whole scope object.
+    ///
+    /// The default implementation returns `None` (the task sees the whole
+    /// context).
+    fn reads(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    /// Returns the host capabilities this task's package was granted, when
+    /// the package is capability-gated.
+    ///
+    /// Executors use them to restrict what the task can reach: execution
+    /// agents derive the task's syscall sandbox (filesystem paths, network
+    /// ports) from them.
+    ///
+    /// The default implementation returns `None` (not capability-gated).
+    fn capabilities(&self) -> Option<CapabilityManifest> {
+        None
+    }
+
+    /// Returns the task's declarative configuration, if it declares one.
+    ///
+    /// The configuration is a JSON value whose strings may embed templates
+    /// such as `"s3://bucket/{{ ds }}/{{ workflow.name }}.parquet"`. It is
+    /// rendered once when the task is scheduled, from the execution's fire
+    /// metadata and input context, and handed to the task through
+    /// [`Context::task_config`](crate::Context::task_config).
+    ///
+    /// The default implementation returns `None` (no configuration).
+    fn configuration(&self) -> Option<serde_json::Value> {
+        None
+    }
+
     /// Returns a code fingerprint for content-based versioning.
     ///
     /// This method should return a hash of the task's implementation code,
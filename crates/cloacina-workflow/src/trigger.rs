@@ -62,13 +62,94 @@ impl TriggerResult {
             TriggerResult::Fire(None) => "fire_no_context".to_string(),
             TriggerResult::Fire(Some(ctx)) => {
                 let mut hasher = DefaultHasher::new();
-                if let Ok(serialized) = serde_json::to_string(ctx.data()) {
+                if let Ok(serialized) = ctx.to_json() {
                     serialized.hash(&mut hasher);
                 }
                 format!("{:016x}", hasher.finish())
             }
         }
     }
+
+    /// Computes the deduplication hash over a subset of top-level context
+    /// keys, e.g. only `path` so a file re-dropped with a new mtime still
+    /// counts as a duplicate. Missing keys hash as `null`. An empty key list
+    /// is the same as [`context_hash`](Self::context_hash).
+    pub fn context_hash_for_keys(&self, keys: &[String]) -> String {
+        let ctx = match self {
+            TriggerResult::Fire(Some(ctx)) if !keys.is_empty() => ctx,
+            _ => return self.context_hash(),
+        };
+        let selected: std::collections::BTreeMap<&str, Option<&serde_json::Value>> = keys
+            .iter()
+            .map(|key| (key.as_str(), ctx.get(key)))
+            .collect();
+        let mut hasher = DefaultHasher::new();
+        if let Ok(serialized) = serde_json::to_string(&selected) {
+            serialized.hash(&mut hasher);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// Context key holding the aggregated payload list of a batched firing.
+pub const BATCH_EVENTS_KEY: &str = "trigger_events";
+
+/// Context key holding the number of events in a batched firing.
+pub const BATCH_COUNT_KEY: &str = "trigger_event_count";
+
+/// Batching policy for a high-rate trigger.
+///
+/// Instead of one workflow execution per `Fire`, the scheduler buffers fired
+/// payloads and launches a single execution once `max_events` have
+/// accumulated or `window` has elapsed since the first buffered event,
+/// whichever comes first. The execution context carries the payloads, in
+/// arrival order, as a list under [`BATCH_EVENTS_KEY`] and their count under
+/// [`BATCH_COUNT_KEY`].
+///
+/// The fields are private so every policy has at least one flush condition;
+/// build one with [`TriggerBatch::new`], [`TriggerBatch::events`] or
+/// [`TriggerBatch::window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerBatch {
+    max_events: Option<usize>,
+    window: Option<Duration>,
+}
+
+impl TriggerBatch {
+    /// Flush every `max_events` events or every `window`, whichever first.
+    /// A `max_events` of zero is treated as one.
+    pub fn new(max_events: usize, window: Duration) -> Self {
+        Self {
+            max_events: Some(max_events.max(1)),
+            window: Some(window),
+        }
+    }
+
+    /// Flush only on count. A `max_events` of zero is treated as one.
+    pub fn events(max_events: usize) -> Self {
+        Self {
+            max_events: Some(max_events.max(1)),
+            window: None,
+        }
+    }
+
+    /// Flush only on elapsed time.
+    pub fn window(window: Duration) -> Self {
+        Self {
+            max_events: None,
+            window: Some(window),
+        }
+    }
+
+    /// Flush once this many events are buffered.
+    pub fn max_events(&self) -> Option<usize> {
+        self.max_events
+    }
+
+    /// Flush once the oldest buffered event is this old.
+    pub fn window_duration(&self) -> Option<Duration> {
+        self.window
+    }
 }
 
 /// Errors that can occur during trigger polling.
@@ -125,4 +206,18 @@ pub trait Trigger: Send + Sync + fmt::Debug {
     fn workflow_name(&self) -> &str {
         ""
     }
+
+    /// Returns this trigger's batching policy, if any. When `Some`, fired
+    /// payloads are accumulated and launched as one execution per batch (see
+    /// [`TriggerBatch`]). Default `None`: one execution per `Fire`.
+    fn batch(&self) -> Option<TriggerBatch> {
+        None
+    }
+
+    /// Hands back a fired context that will not start an execution: the
+    /// schedule's condition was false, a later firing replaced it while
+    /// debouncing, it was suppressed as a duplicate or rate limited, or the
+    /// handoff failed. Triggers that hold something per firing (a message
+    /// delivery awaiting its ack) release it here. Default: nothing.
+    async fn discard(&self, _context: &Context<serde_json::Value>) {}
 }
@@ -0,0 +1,221 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Run artifacts: named files a workflow execution produces.
+//!
+//! Reports, exports and images are too large for a context, which is one
+//! database row read by every downstream task. A task stores them as
+//! artifacts of its execution instead:
+//!
+//! ```rust,ignore
+//! #[task(id = "render_report")]
+//! async fn render_report(
+//!     context: &mut Context<Value>,
+//!     handle: &TaskHandle,
+//! ) -> Result<(), TaskError> {
+//!     let pdf = render(context)?;
+//!     handle.store_artifact("report.pdf", pdf, "application/pdf").await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The bytes go to an [`ArtifactStore`], any [`RegistryStorage`] backend (the
+//! database, a directory, or one an extension registers, e.g. S3), and a row
+//! in `workflow_execution_artifacts` records the name, content type, size and
+//! the task that stored it. Names are unique within an execution: storing a
+//! name again, as a retried task does, replaces the earlier artifact.
+//!
+//! `DAL::execution_artifact()` lists and reads them; the runner and the
+//! server expose the same per execution. Blobs are sealed with the
+//! database's at-rest keys when those are configured.
+//!
+//! Attach a store with
+//! [`Database::with_artifact_store`](crate::Database::with_artifact_store),
+//! or configure it on the runner with `artifact_backend`; without one,
+//! artifacts are stored in the database.
+
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::crypto::AtRestEncryption;
+use crate::database::universal_types::UniversalUuid;
+use crate::error::ValidationError;
+use crate::models::workflow_packages::StorageType;
+use crate::registry::{RegistryStorage, StorageError};
+
+/// Longest artifact name.
+pub const MAX_ARTIFACT_NAME_LEN: usize = 255;
+
+/// Longest artifact content type.
+pub const MAX_CONTENT_TYPE_LEN: usize = 255;
+
+/// Blob store artifacts are written to.
+pub struct ArtifactStore {
+    store: Mutex<Box<dyn RegistryStorage>>,
+}
+
+impl std::fmt::Debug for ArtifactStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArtifactStore").finish_non_exhaustive()
+    }
+}
+
+impl ArtifactStore {
+    pub fn new(store: Box<dyn RegistryStorage>) -> Self {
+        Self {
+            store: Mutex::new(store),
+        }
+    }
+
+    /// Write the bytes of artifact `artifact_id`, returning their storage
+    /// id.
+    ///
+    /// They are sealed with `encryption` unless the store is the database,
+    /// which applies it itself.
+    pub(crate) async fn put(
+        &self,
+        bytes: Vec<u8>,
+        artifact_id: UniversalUuid,
+        encryption: Option<&AtRestEncryption>,
+    ) -> Result<String, StorageError> {
+        let mut store = self.store.lock().await;
+        let bytes = match encryption.filter(|_| store.storage_type() != StorageType::Database) {
+            Some(encryption) => encryption
+                .encrypt(&bytes, artifact_id.0.as_bytes())
+                .await
+                .map_err(|e| StorageError::Backend(format!("Encryption error: {}", e)))?,
+            None => bytes,
+        };
+        store.store_binary(bytes).await
+    }
+
+    /// Read the bytes of artifact `artifact_id` from `storage_id`.
+    pub(crate) async fn get(
+        &self,
+        storage_id: &str,
+        artifact_id: UniversalUuid,
+        encryption: Option<&AtRestEncryption>,
+    ) -> Result<Vec<u8>, StorageError> {
+        let bytes = self
+            .store
+            .lock()
+            .await
+            .retrieve_binary(storage_id)
+            .await?
+            .ok_or_else(|| StorageError::DataCorruption {
+                id: storage_id.to_string(),
+                reason: format!("bytes of artifact {} are missing", artifact_id),
+            })?;
+        if !AtRestEncryption::is_encrypted(&bytes) {
+            return Ok(bytes);
+        }
+        let encryption = encryption.ok_or_else(|| {
+            StorageError::Backend(
+                "artifact is encrypted but no at-rest keys are configured".to_string(),
+            )
+        })?;
+        encryption
+            .decrypt(&bytes, artifact_id.0.as_bytes())
+            .await
+            .map_err(|e| StorageError::Backend(format!("Decryption error: {}", e)))
+    }
+
+    /// Delete the bytes at `storage_id`. Failures are logged, not returned:
+    /// a leftover blob is unreachable, not wrong.
+    pub(crate) async fn discard(&self, storage_id: &str) {
+        if let Err(e) = self.store.lock().await.delete_binary(storage_id).await {
+            warn!("Failed to delete artifact bytes {}: {}", storage_id, e);
+        }
+    }
+}
+
+/// Check an artifact name and content type. A name is one path component
+/// (`report.pdf`, not `out/report.pdf`), so it is safe as a download file
+/// name.
+pub fn validate_artifact(name: &str, content_type: &str) -> Result<(), ValidationError> {
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(ValidationError::InvalidArtifact(format!(
+            "artifact name '{}' is not a file name",
+            name
+        )));
+    }
+    if name.len() > MAX_ARTIFACT_NAME_LEN {
+        return Err(ValidationError::InvalidArtifact(format!(
+            "artifact name is longer than {} characters",
+            MAX_ARTIFACT_NAME_LEN
+        )));
+    }
+    if name.contains(['/', '\\']) || name.chars().any(char::is_control) {
+        return Err(ValidationError::InvalidArtifact(format!(
+            "artifact name '{}' may not contain path separators or control characters",
+            name.escape_default()
+        )));
+    }
+    // Served back as a `Content-Type` header, so printable ASCII only.
+    if content_type.is_empty()
+        || content_type.len() > MAX_CONTENT_TYPE_LEN
+        || !content_type
+            .chars()
+            .all(|c| c.is_ascii_graphic() || c == ' ')
+    {
+        return Err(ValidationError::InvalidArtifact(format!(
+            "content type '{}' of artifact '{}' is invalid",
+            content_type.escape_default(),
+            name
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::FilesystemRegistryStorage;
+
+    #[tokio::test]
+    async fn test_artifact_bytes_round_trip_and_discard() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ArtifactStore::new(Box::new(
+            FilesystemRegistryStorage::new(dir.path()).unwrap(),
+        ));
+        let id = UniversalUuid::new_v4();
+        let pdf = b"%PDF-1.7 report".to_vec();
+
+        let storage_id = store.put(pdf.clone(), id, None).await.unwrap();
+        assert_eq!(store.get(&storage_id, id, None).await.unwrap(), pdf);
+
+        store.discard(&storage_id).await;
+        assert!(matches!(
+            store.get(&storage_id, id, None).await,
+            Err(StorageError::DataCorruption { .. })
+        ));
+    }
+
+    #[test]
+    fn test_artifact_names_are_single_file_names() {
+        assert!(validate_artifact("report.pdf", "application/pdf").is_ok());
+        for name in ["", "..", "out/report.pdf", "a\\b", "line\nbreak"] {
+            assert!(
+                validate_artifact(name, "text/plain").is_err(),
+                "{name:?} should be rejected"
+            );
+        }
+        assert!(validate_artifact(&"a".repeat(MAX_ARTIFACT_NAME_LEN + 1), "text/plain").is_err());
+        assert!(validate_artifact("report.pdf", "").is_err());
+        assert!(validate_artifact("report.pdf", "text/plain; charset=utf-8").is_ok());
+        assert!(validate_artifact("report.pdf", "text/plain\r\nX-Injected: 1").is_err());
+    }
+}
@@ -0,0 +1,310 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Workflow catalog introspection.
+//!
+//! A [`WorkflowCatalog`] lists every workflow a [`Runtime`] can run: its
+//! version, tasks, dependency edges, retry policies, trigger rules and
+//! declared input params. Embedded workflows are described from the runtime
+//! and the `#[workflow]` descriptor entries; [`WorkflowCatalog::merge_packages`]
+//! overlays what the workflow registry knows about loaded packages (package
+//! version, declared params, trigger subscriptions, pause state).
+//!
+//! ```rust,ignore
+//! let catalog = runner.workflow_catalog().await?;
+//! for workflow in &catalog.workflows {
+//!     println!("{} ({} tasks)", workflow.name, workflow.tasks.len());
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::input_interface::InputSlot;
+use crate::registry::WorkflowMetadata as PackageMetadata;
+use crate::retry::RetryPolicy;
+use crate::runtime::Runtime;
+use crate::task::TaskNamespace;
+use crate::workflow::Workflow;
+
+/// Where a catalog workflow came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatalogSource {
+    /// Compiled into this binary with `#[workflow]`
+    Embedded,
+    /// Loaded from a workflow package through the registry
+    Package,
+}
+
+/// One task of a catalog workflow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatalogTask {
+    /// Fully qualified task namespace
+    pub name: String,
+    /// Task id within the workflow
+    pub id: String,
+    /// Fully qualified namespaces of upstream tasks
+    pub dependencies: Vec<String>,
+    pub retry_policy: RetryPolicy,
+    pub trigger_rules: Value,
+    /// Static task configuration, when the task declares one
+    pub configuration: Option<Value>,
+}
+
+/// One workflow in the catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatalogWorkflow {
+    pub name: String,
+    /// Content hash of the workflow definition
+    pub version: String,
+    pub description: Option<String>,
+    pub tags: BTreeMap<String, String>,
+    pub tenant: String,
+    pub package: String,
+    pub source: CatalogSource,
+    /// Semantic version of the package, for packaged workflows
+    pub package_version: Option<String>,
+    /// Tasks in topological order
+    pub tasks: Vec<CatalogTask>,
+    /// Declared input params (`#[workflow(params(...))]`)
+    pub params: Vec<InputSlot>,
+    /// Trigger names the workflow subscribes to
+    pub triggers: Vec<String>,
+    pub paused: bool,
+}
+
+/// Every workflow a runtime can run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowCatalog {
+    /// Workflows sorted by name
+    pub workflows: Vec<CatalogWorkflow>,
+}
+
+impl WorkflowCatalog {
+    /// Describe the workflows registered in `runtime`.
+    ///
+    /// Params and trigger subscriptions come from the `#[workflow]`
+    /// descriptor entries linked into the process; workflows registered by
+    /// hand have none.
+    pub fn from_runtime(runtime: &Runtime) -> Self {
+        let mut workflows: Vec<CatalogWorkflow> = runtime
+            .workflow_names()
+            .into_iter()
+            .filter_map(|name| runtime.get_workflow(&name))
+            .map(|workflow| describe_workflow(&workflow))
+            .collect();
+
+        for entry in inventory::iter::<cloacina_workflow_plugin::WorkflowDescriptorEntry> {
+            let Some(workflow) = workflows.iter_mut().find(|w| w.name == entry.name) else {
+                continue;
+            };
+            workflow.params = parse_slots(entry.name, &(entry.params)());
+            workflow.triggers = (entry.triggers)();
+        }
+
+        workflows.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { workflows }
+    }
+
+    /// Overlay registry metadata for workflows loaded from packages.
+    ///
+    /// Packages whose workflow is not (yet) registered in the runtime are
+    /// skipped: the catalog lists what can run, not what is stored.
+    pub fn merge_packages(&mut self, packages: &[PackageMetadata]) {
+        for package in packages {
+            let Some(workflow) = self
+                .workflows
+                .iter_mut()
+                .find(|w| w.name == package.workflow_name)
+            else {
+                continue;
+            };
+            workflow.source = CatalogSource::Package;
+            workflow.package_version = Some(package.version.clone());
+            if workflow.description.is_none() {
+                workflow.description = package.description.clone();
+            }
+            if !package.declared_params.is_empty() {
+                workflow.params = package.declared_params.clone();
+            }
+            if !package.workflow_triggers.is_empty() {
+                workflow.triggers = package.workflow_triggers.clone();
+            }
+            workflow.paused = package.paused;
+        }
+    }
+
+    /// The workflow named `name`, if the catalog has one.
+    pub fn workflow(&self, name: &str) -> Option<&CatalogWorkflow> {
+        self.workflows.iter().find(|w| w.name == name)
+    }
+}
+
+fn describe_workflow(workflow: &Workflow) -> CatalogWorkflow {
+    let order = workflow
+        .topological_sort()
+        .unwrap_or_else(|_| workflow.get_task_ids());
+    let tasks = order
+        .iter()
+        .filter_map(|namespace| {
+            let task = workflow.get_task(namespace).ok()?;
+            Some(CatalogTask {
+                name: namespace.to_string(),
+                id: task.id().to_string(),
+                dependencies: workflow
+                    .get_dependencies(namespace)
+                    .map(|deps| deps.iter().map(TaskNamespace::to_string).collect())
+                    .unwrap_or_default(),
+                retry_policy: task.retry_policy(),
+                trigger_rules: task.trigger_rules(),
+                configuration: task.configuration(),
+            })
+        })
+        .collect();
+    let metadata = workflow.metadata();
+
+    CatalogWorkflow {
+        name: workflow.name().to_string(),
+        version: metadata.version.clone(),
+        description: metadata.description.clone(),
+        tags: metadata
+            .tags
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+        tenant: workflow.tenant().to_string(),
+        package: workflow.package().to_string(),
+        source: CatalogSource::Embedded,
+        package_version: None,
+        tasks,
+        params: Vec::new(),
+        triggers: Vec::new(),
+        paused: false,
+    }
+}
+
+/// Parses a descriptor's slot JSON, logging and dropping malformed lists.
+fn parse_slots(workflow: &str, json: &str) -> Vec<InputSlot> {
+    serde_json::from_str(json).unwrap_or_else(|e| {
+        warn!(
+            "Ignoring malformed param descriptor of workflow '{}': {}",
+            workflow, e
+        );
+        Vec::new()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::error::TaskError;
+    use crate::retry::RetryPolicyBuilder;
+    use crate::task::Task;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    struct CatalogTestTask {
+        id: String,
+        dependencies: Vec<TaskNamespace>,
+        max_attempts: i32,
+    }
+
+    #[async_trait]
+    impl Task for CatalogTestTask {
+        async fn execute(
+            &self,
+            context: Context<serde_json::Value>,
+        ) -> Result<Context<serde_json::Value>, TaskError> {
+            Ok(context)
+        }
+
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn dependencies(&self) -> &[TaskNamespace] {
+            &self.dependencies
+        }
+
+        fn retry_policy(&self) -> RetryPolicy {
+            RetryPolicyBuilder::new()
+                .max_attempts(self.max_attempts)
+                .build()
+        }
+    }
+
+    fn namespace(task: &str) -> TaskNamespace {
+        TaskNamespace::new("public", "embedded", "catalog_wf", task)
+    }
+
+    fn runtime() -> Runtime {
+        let runtime = Runtime::empty();
+        runtime.register_workflow("catalog_wf".to_string(), || {
+            let mut workflow = Workflow::new("catalog_wf");
+            workflow.set_description("Catalog test workflow");
+            workflow
+                .add_task(Arc::new(CatalogTestTask {
+                    id: "extract".to_string(),
+                    dependencies: vec![],
+                    max_attempts: 1,
+                }))
+                .unwrap();
+            workflow
+                .add_task(Arc::new(CatalogTestTask {
+                    id: "load".to_string(),
+                    dependencies: vec![namespace("extract")],
+                    max_attempts: 5,
+                }))
+                .unwrap();
+            workflow.finalize()
+        });
+        runtime
+    }
+
+    #[test]
+    fn test_catalog_describes_tasks_in_order() {
+        let catalog = WorkflowCatalog::from_runtime(&runtime());
+        let workflow = catalog.workflow("catalog_wf").unwrap();
+        assert_eq!(workflow.source, CatalogSource::Embedded);
+        assert_eq!(
+            workflow.description.as_deref(),
+            Some("Catalog test workflow")
+        );
+        assert!(!workflow.version.is_empty());
+
+        let ids: Vec<&str> = workflow.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["extract", "load"]);
+        let load = &workflow.tasks[1];
+        assert_eq!(load.dependencies, vec![namespace("extract").to_string()]);
+        assert_eq!(load.retry_policy.max_attempts, 5);
+    }
+
+    #[test]
+    fn test_malformed_slots_are_dropped() {
+        assert!(parse_slots("wf", "not json").is_empty());
+        let slots = parse_slots(
+            "wf",
+            r#"[{"name":"date","schema":{"type":"string"},"required":true}]"#,
+        );
+        assert_eq!(slots.len(), 1);
+        assert!(slots[0].required);
+    }
+}
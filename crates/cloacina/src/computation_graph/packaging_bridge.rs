@@ -118,7 +118,7 @@ pub use cloacina_workflow_plugin::{
     METHOD_EXECUTE_GRAPH, METHOD_EXECUTE_TASK, METHOD_GET_CONSTRUCTOR_METADATA,
     METHOD_GET_GRAPH_METADATA, METHOD_GET_REACTOR_METADATA, METHOD_GET_TASK_METADATA,
     METHOD_GET_TRIGGERLESS_GRAPH_METADATA, METHOD_GET_TRIGGER_METADATA,
-    METHOD_INVOKE_TRIGGERLESS_GRAPH, METHOD_INVOKE_TRIGGER_POLL,
+    METHOD_INVOKE_TRIGGERLESS_GRAPH, METHOD_INVOKE_TRIGGER_POLL, METHOD_RUN_HEALTH_CHECKS,
 };
 
 /// Call `get_reactor_metadata` (method index 4) on a loaded fidius plugin.
@@ -160,6 +160,27 @@ pub fn call_get_constructor_metadata(
     }
 }
 
+/// Call `run_health_checks` (method index 11) on a loaded fidius plugin.
+/// Returns one report per `#[workflow(health_check = ...)]` entry point.
+/// Plugins built before trait v6 return `CallError::NotImplemented` →
+/// `Ok(vec![])` ("no checks declared"), so older packages load as healthy.
+pub fn call_run_health_checks(
+    handle: &fidius_host::PluginHandle,
+    timeout: std::time::Duration,
+) -> Result<Vec<cloacina_workflow_plugin::HealthCheckReport>, String> {
+    let request = cloacina_workflow_plugin::HealthCheckRequest {
+        timeout_ms: timeout.as_millis() as u64,
+    };
+    match handle.call_method::<_, Vec<cloacina_workflow_plugin::HealthCheckReport>>(
+        METHOD_RUN_HEALTH_CHECKS,
+        &(request,),
+    ) {
+        Ok(reports) => Ok(reports),
+        Err(fidius_host::CallError::NotImplemented { .. }) => Ok(Vec::new()),
+        Err(e) => Err(format!("run_health_checks FFI call failed: {}", e)),
+    }
+}
+
 /// Call `get_trigger_metadata` (method index 5) on a loaded fidius plugin.
 ///
 /// I-0102 / T-B: same NotImplemented fallback as `call_get_reactor_metadata`.
@@ -106,7 +106,7 @@
 
 // Re-export the core Context type from cloacina_workflow
 // This ensures type compatibility between macro-generated code and runtime
-pub use cloacina_workflow::Context;
+pub use cloacina_workflow::{Context, ScopedContext};
 
 use crate::error::ContextError;
 use crate::models::context::{DbContext, NewDbContext};
@@ -0,0 +1,376 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Storage formats for contexts.
+//!
+//! Contexts are stored as JSON text in `contexts.value` by default. A
+//! database configured with a binary [`ContextFormat`] (MessagePack or CBOR)
+//! writes the encoded context to `contexts.payload` instead and keeps the
+//! format's name in `value`:
+//!
+//! | Format | `value` | `payload` |
+//! |---|---|---|
+//! | JSON | the context as JSON | `NULL` |
+//! | MessagePack | `msgpack` | the context as MessagePack |
+//! | CBOR | `cbor` | the context as CBOR |
+//!
+//! The binary formats encode the context map directly, and a binary value
+//! ([`Context::insert_bytes`](crate::Context::insert_bytes)) becomes a native
+//! MessagePack `bin` / CBOR byte string rather than its base64 JSON form.
+//! JSON has no byte strings, so under the JSON format a context holding a
+//! binary value is written as MessagePack (see [`ContextFormat::for_context`]);
+//! base64 is left for contexts handed around as JSON text.
+//!
+//! Every row says how it was written, so rows in different formats are read
+//! side by side and changing the format needs no downtime: new writes use the
+//! new format and `DAL::context().convert_format(..)` (`cloacinactl admin
+//! convert-contexts`) rewrites the existing rows.
+//!
+//! Offloading and at-rest encryption apply to every format: large values are
+//! offloaded before encoding and the encoded bytes are sealed.
+//!
+//! Attach a format with
+//! [`Database::with_context_format`](crate::Database::with_context_format),
+//! or configure it on the runner with `context_format`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use cloacina_workflow::context::{bytes_value, value_bytes};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::error::ContextError;
+
+/// How contexts are encoded in the `contexts` table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextFormat {
+    /// JSON text in `contexts.value`.
+    #[default]
+    Json,
+    /// MessagePack in `contexts.payload`.
+    #[serde(rename = "msgpack")]
+    MessagePack,
+    /// CBOR in `contexts.payload`.
+    Cbor,
+}
+
+impl ContextFormat {
+    /// The format's name, as configured and as stored in `contexts.value`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContextFormat::Json => "json",
+            ContextFormat::MessagePack => "msgpack",
+            ContextFormat::Cbor => "cbor",
+        }
+    }
+
+    /// Whether the format is stored in `contexts.payload`.
+    pub fn is_binary(&self) -> bool {
+        *self != ContextFormat::Json
+    }
+
+    /// The format `context` is written in when this format is configured:
+    /// this one, except that JSON writes a context holding a binary value as
+    /// MessagePack, so the bytes land in `contexts.payload` as they are
+    /// rather than as base64 text.
+    pub fn for_context(&self, context: &Map<String, Value>) -> ContextFormat {
+        if *self == ContextFormat::Json && context.values().any(holds_bytes) {
+            ContextFormat::MessagePack
+        } else {
+            *self
+        }
+    }
+
+    /// Encode the context map `context` in this format.
+    pub fn encode(&self, context: &Map<String, Value>) -> Result<Vec<u8>, ContextError> {
+        let context = EncodedMap(context);
+        let encoded = match self {
+            ContextFormat::Json => return Ok(serde_json::to_vec(context.0)?),
+            ContextFormat::MessagePack => {
+                rmp_serde::to_vec_named(&context).map_err(|e| e.to_string())
+            }
+            ContextFormat::Cbor => {
+                let mut buffer = Vec::new();
+                ciborium::into_writer(&context, &mut buffer)
+                    .map(|()| buffer)
+                    .map_err(|e| e.to_string())
+            }
+        };
+        encoded.map_err(|message| self.error(message))
+    }
+
+    /// Decode a context encoded in this format back to its map.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Map<String, Value>, ContextError> {
+        let decoded: Decoded = match self {
+            ContextFormat::Json => return Ok(serde_json::from_slice(bytes)?),
+            ContextFormat::MessagePack => {
+                rmp_serde::from_slice(bytes).map_err(|e| self.error(e.to_string()))?
+            }
+            ContextFormat::Cbor => {
+                ciborium::from_reader(bytes).map_err(|e| self.error(e.to_string()))?
+            }
+        };
+        match decoded.0 {
+            Value::Object(context) => Ok(context),
+            other => Err(self.error(format!("expected a map, found {}", other))),
+        }
+    }
+
+    fn error(&self, message: String) -> ContextError {
+        ContextError::Encoding {
+            format: *self,
+            message,
+        }
+    }
+}
+
+/// Whether `value` is or contains a binary value.
+fn holds_bytes(value: &Value) -> bool {
+    match value {
+        Value::Array(items) => items.iter().any(holds_bytes),
+        Value::Object(fields) => value_bytes(value).is_some() || fields.values().any(holds_bytes),
+        _ => false,
+    }
+}
+
+/// A context map as the binary formats write it (see [`Encoded`]).
+struct EncodedMap<'a>(&'a Map<String, Value>);
+
+impl Serialize for EncodedMap<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(k, v)| (k, Encoded(v))))
+    }
+}
+
+/// A context value as the binary formats write it: binary values as native
+/// byte strings, everything else as is.
+struct Encoded<'a>(&'a Value);
+
+impl Serialize for Encoded<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(bytes) = value_bytes(self.0) {
+            return serializer.serialize_bytes(&bytes);
+        }
+        match self.0 {
+            Value::Array(items) => serializer.collect_seq(items.iter().map(Encoded)),
+            Value::Object(fields) => EncodedMap(fields).serialize(serializer),
+            other => other.serialize(serializer),
+        }
+    }
+}
+
+/// A context value read back from a binary format: byte strings become
+/// binary values again.
+struct Decoded(Value);
+
+impl<'de> Deserialize<'de> for Decoded {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DecodedVisitor)
+    }
+}
+
+struct DecodedVisitor;
+
+impl<'de> Visitor<'de> for DecodedVisitor {
+    type Value = Decoded;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a context value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Decoded, E> {
+        Ok(Decoded(Value::Bool(v)))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Decoded, E> {
+        Ok(Decoded(Value::from(v)))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Decoded, E> {
+        Ok(Decoded(Value::from(v)))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Decoded, E> {
+        i64::try_from(v)
+            .map(Value::from)
+            .or_else(|_| u64::try_from(v).map(Value::from))
+            .map(Decoded)
+            .map_err(|_| E::custom(format!("integer {} is out of range", v)))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Decoded, E> {
+        u64::try_from(v)
+            .map(|v| Decoded(Value::from(v)))
+            .map_err(|_| E::custom(format!("integer {} is out of range", v)))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Decoded, E> {
+        Ok(Decoded(Value::from(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Decoded, E> {
+        Ok(Decoded(Value::String(v.to_string())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Decoded, E> {
+        Ok(Decoded(Value::String(v)))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Decoded, E> {
+        Ok(Decoded(bytes_value(v)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Decoded, E> {
+        Ok(Decoded(Value::Null))
+    }
+
+    fn visit_none<E>(self) -> Result<Decoded, E> {
+        Ok(Decoded(Value::Null))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Decoded, D::Error> {
+        Decoded::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Decoded, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(Decoded(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Decoded(Value::Array(items)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Decoded, A::Error> {
+        let mut fields = Map::new();
+        while let Some((key, Decoded(value))) = map.next_entry::<String, Decoded>()? {
+            fields.insert(key, value);
+        }
+        Ok(Decoded(Value::Object(fields)))
+    }
+}
+
+impl fmt::Display for ContextFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContextFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(ContextFormat::Json),
+            "msgpack" | "messagepack" => Ok(ContextFormat::MessagePack),
+            "cbor" => Ok(ContextFormat::Cbor),
+            other => Err(format!(
+                "unknown context format '{}' (expected json, msgpack or cbor)",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_formats_round_trip_and_shrink() {
+        let context = serde_json::json!({
+            "rows": (0..200).map(|i| serde_json::json!({ "id": i, "ok": i % 2 == 0 })).collect::<Vec<_>>(),
+            "name": "batch",
+            "ratio": 0.25,
+            "missing": null,
+        });
+        let Value::Object(context) = context else {
+            unreachable!()
+        };
+        let json = ContextFormat::Json.encode(&context).unwrap();
+        assert_eq!(ContextFormat::Json.decode(&json).unwrap(), context);
+
+        for format in [ContextFormat::MessagePack, ContextFormat::Cbor] {
+            let encoded = format.encode(&context).unwrap();
+            assert!(encoded.len() < json.len(), "{format} should be smaller");
+            assert_eq!(format.decode(&encoded).unwrap(), context);
+        }
+    }
+
+    #[test]
+    fn test_binary_values_are_stored_as_native_bytes() {
+        let blob: Vec<u8> = (0..=255).collect();
+        let mut context = Map::new();
+        context.insert("blob".into(), bytes_value(&blob));
+        context.insert(
+            "nested".into(),
+            serde_json::json!({ "parts": [bytes_value(b"abc")] }),
+        );
+        // Looks like a binary value but is not base64: kept as an object.
+        context.insert(
+            "lookalike".into(),
+            serde_json::json!({ "$cloacina_bytes": "not base64!" }),
+        );
+
+        // JSON would need base64, so such a context is written as MessagePack.
+        assert_eq!(
+            ContextFormat::Json.for_context(&context),
+            ContextFormat::MessagePack
+        );
+        assert_eq!(
+            ContextFormat::Cbor.for_context(&context),
+            ContextFormat::Cbor
+        );
+        context.remove("blob");
+        context.remove("nested");
+        assert_eq!(
+            ContextFormat::Json.for_context(&context),
+            ContextFormat::Json
+        );
+        context.insert("blob".into(), bytes_value(&blob));
+        context.insert(
+            "nested".into(),
+            serde_json::json!({ "parts": [bytes_value(b"abc")] }),
+        );
+
+        let json = ContextFormat::Json.encode(&context).unwrap();
+        for format in [ContextFormat::MessagePack, ContextFormat::Cbor] {
+            let encoded = format.encode(&context).unwrap();
+            // The raw bytes are in the encoding; base64 would inflate them.
+            assert!(encoded.windows(blob.len()).any(|w| w == blob.as_slice()));
+            assert!(encoded.len() < json.len(), "{format} should be smaller");
+            assert_eq!(format.decode(&encoded).unwrap(), context);
+        }
+    }
+
+    #[test]
+    fn test_formats_parse_from_their_names() {
+        for format in [
+            ContextFormat::Json,
+            ContextFormat::MessagePack,
+            ContextFormat::Cbor,
+        ] {
+            assert_eq!(format.as_str().parse::<ContextFormat>(), Ok(format));
+        }
+        assert!("yaml".parse::<ContextFormat>().is_err());
+        assert!(matches!(
+            ContextFormat::Cbor.decode(b"\xff\xff"),
+            Err(ContextError::Encoding { .. })
+        ));
+    }
+}
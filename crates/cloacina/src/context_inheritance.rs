@@ -0,0 +1,335 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Parent → child context inheritance policies.
+//!
+//! When one workflow launches another (a child run via
+//! [`DefaultRunner::execute_child`](crate::runner::DefaultRunner::execute_child),
+//! or a chain where one run's final context seeds the next) the default used to
+//! be all-or-nothing: copy every key in, copy nothing back. A
+//! [`ContextInheritance`] makes both directions explicit:
+//!
+//! - **inbound** — which parent keys the child starts with;
+//! - **outbound** — which child keys are written back into the parent.
+//!
+//! Each direction is a [`KeyPolicy`]: a selection (all / none / allowlist /
+//! denylist) plus optional renames applied to the selected keys.
+//!
+//! ```rust,ignore
+//! use cloacina::context_inheritance::{ContextInheritance, KeyPolicy};
+//!
+//! let policy = ContextInheritance::new()
+//!     .inbound(KeyPolicy::allow(["account_id", "run_date"]).rename("run_date", "ds"))
+//!     .outbound(KeyPolicy::allow(["row_count"]).rename("row_count", "child_row_count"));
+//! ```
+
+use crate::error::ContextError;
+use crate::Context;
+
+/// Which keys a [`KeyPolicy`] lets through, before renames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySelection {
+    /// Every key.
+    All,
+    /// No keys.
+    None,
+    /// Only the listed keys.
+    Allow(Vec<String>),
+    /// Every key except the listed ones.
+    Deny(Vec<String>),
+}
+
+impl KeySelection {
+    fn admits(&self, key: &str) -> bool {
+        match self {
+            KeySelection::All => true,
+            KeySelection::None => false,
+            KeySelection::Allow(keys) => keys.iter().any(|k| k == key),
+            KeySelection::Deny(keys) => !keys.iter().any(|k| k == key),
+        }
+    }
+}
+
+/// One direction of context flow: a key selection plus renames.
+///
+/// Renames apply only to keys the selection admits; renaming a key that isn't
+/// selected is a no-op.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPolicy {
+    selection: KeySelection,
+    renames: Vec<(String, String)>,
+}
+
+impl KeyPolicy {
+    /// Pass every key through unchanged.
+    pub fn all() -> Self {
+        Self::from_selection(KeySelection::All)
+    }
+
+    /// Pass nothing through.
+    pub fn none() -> Self {
+        Self::from_selection(KeySelection::None)
+    }
+
+    /// Pass only `keys` through.
+    pub fn allow<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::from_selection(KeySelection::Allow(
+            keys.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Pass every key except `keys` through.
+    pub fn deny<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::from_selection(KeySelection::Deny(
+            keys.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    fn from_selection(selection: KeySelection) -> Self {
+        Self {
+            selection,
+            renames: Vec::new(),
+        }
+    }
+
+    /// Write the selected key `from` under the name `to` on the other side.
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.renames.push((from.into(), to.into()));
+        self
+    }
+
+    /// The key selection this policy applies.
+    pub fn selection(&self) -> &KeySelection {
+        &self.selection
+    }
+
+    /// The target name for `key`, or `None` if the policy drops it.
+    pub fn target_key<'a>(&'a self, key: &'a str) -> Option<&'a str> {
+        if !self.selection.admits(key) {
+            return None;
+        }
+        Some(
+            self.renames
+                .iter()
+                .find(|(from, _)| from == key)
+                .map(|(_, to)| to.as_str())
+                .unwrap_or(key),
+        )
+    }
+
+    /// Build a fresh context holding the keys of `source` this policy admits,
+    /// under their (possibly renamed) target names.
+    ///
+    /// Returns [`ContextError::KeyExists`] when two source keys land on the same
+    /// target name (e.g. a rename onto a key that is also passed through).
+    pub fn project(
+        &self,
+        source: &Context<serde_json::Value>,
+    ) -> Result<Context<serde_json::Value>, ContextError> {
+        let mut out = Context::new();
+        // Sort for a deterministic collision report.
+        let mut keys: Vec<&String> = source.data().keys().chain(source.binary().keys()).collect();
+        keys.sort();
+        for key in keys {
+            if let Some(target) = self.target_key(key) {
+                match source.binary().get(key) {
+                    Some(bytes) => out.insert_bytes(target, bytes)?,
+                    None => out.insert(target, source.data()[key].clone())?,
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Write the keys of `source` this policy admits into `target`,
+    /// overwriting any existing values under the same (renamed) names.
+    pub fn merge_into(
+        &self,
+        source: &Context<serde_json::Value>,
+        target: &mut Context<serde_json::Value>,
+    ) -> Result<(), ContextError> {
+        let (data, bytes) = self.project(source)?.into_parts();
+        for (key, value) in data {
+            if target.get(&key).is_some() || target.binary().contains_key(&key) {
+                target.update(key, value)?;
+            } else {
+                target.insert(key, value)?;
+            }
+        }
+        for (key, raw) in bytes {
+            target.remove(&key);
+            target.insert_bytes(key, raw)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for KeyPolicy {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Inbound and outbound [`KeyPolicy`] for a parent/child workflow pair.
+///
+/// The default reproduces the historical behaviour: the child sees the whole
+/// parent context and nothing flows back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextInheritance {
+    inbound: KeyPolicy,
+    outbound: KeyPolicy,
+}
+
+impl ContextInheritance {
+    /// Copy everything into the child; copy nothing back.
+    pub fn new() -> Self {
+        Self {
+            inbound: KeyPolicy::all(),
+            outbound: KeyPolicy::none(),
+        }
+    }
+
+    /// Set the parent → child policy.
+    pub fn inbound(mut self, policy: KeyPolicy) -> Self {
+        self.inbound = policy;
+        self
+    }
+
+    /// Set the child → parent policy.
+    pub fn outbound(mut self, policy: KeyPolicy) -> Self {
+        self.outbound = policy;
+        self
+    }
+
+    /// The parent → child policy.
+    pub fn inbound_policy(&self) -> &KeyPolicy {
+        &self.inbound
+    }
+
+    /// The child → parent policy.
+    pub fn outbound_policy(&self) -> &KeyPolicy {
+        &self.outbound
+    }
+
+    /// The initial context for a child launched from `parent`.
+    pub fn child_context(
+        &self,
+        parent: &Context<serde_json::Value>,
+    ) -> Result<Context<serde_json::Value>, ContextError> {
+        self.inbound.project(parent)
+    }
+
+    /// Write the outbound keys of a finished child's context back into `parent`.
+    pub fn merge_back(
+        &self,
+        child: &Context<serde_json::Value>,
+        parent: &mut Context<serde_json::Value>,
+    ) -> Result<(), ContextError> {
+        self.outbound.merge_into(child, parent)
+    }
+}
+
+impl Default for ContextInheritance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ctx(pairs: &[(&str, serde_json::Value)]) -> Context<serde_json::Value> {
+        let mut c = Context::new();
+        for (k, v) in pairs {
+            c.insert(*k, v.clone()).unwrap();
+        }
+        c
+    }
+
+    #[test]
+    fn default_copies_in_and_nothing_out() {
+        let policy = ContextInheritance::default();
+        let parent = ctx(&[("a", json!(1)), ("b", json!(2))]);
+        let child = policy.child_context(&parent).unwrap();
+        assert_eq!(child.data().len(), 2);
+
+        let mut parent = parent;
+        let finished = ctx(&[("c", json!(3))]);
+        policy.merge_back(&finished, &mut parent).unwrap();
+        assert!(parent.get("c").is_none());
+    }
+
+    #[test]
+    fn allowlist_with_rename() {
+        let policy = KeyPolicy::allow(["run_date", "account"]).rename("run_date", "ds");
+        let parent = ctx(&[
+            ("run_date", json!("2026-01-01")),
+            ("account", json!("acme")),
+            ("token", json!("x")),
+        ]);
+        let child = policy.project(&parent).unwrap();
+        assert_eq!(child.get("ds"), Some(&json!("2026-01-01")));
+        assert_eq!(child.get("account"), Some(&json!("acme")));
+        assert!(child.get("run_date").is_none());
+        assert!(child.get("token").is_none());
+    }
+
+    #[test]
+    fn denylist_drops_listed_keys() {
+        let policy = KeyPolicy::deny(["token"]);
+        let parent = ctx(&[("token", json!("x")), ("a", json!(1))]);
+        let child = policy.project(&parent).unwrap();
+        assert!(child.get("token").is_none());
+        assert_eq!(child.get("a"), Some(&json!(1)));
+    }
+
+    #[test]
+    fn rename_of_unselected_key_is_noop() {
+        let policy = KeyPolicy::deny(["a"]).rename("a", "b");
+        assert_eq!(policy.target_key("a"), None);
+    }
+
+    #[test]
+    fn rename_collision_is_an_error() {
+        let policy = KeyPolicy::all().rename("a", "b");
+        let parent = ctx(&[("a", json!(1)), ("b", json!(2))]);
+        assert!(matches!(
+            policy.project(&parent),
+            Err(ContextError::KeyExists(_))
+        ));
+    }
+
+    #[test]
+    fn merge_back_overwrites_existing_parent_keys() {
+        let policy =
+            ContextInheritance::new().outbound(KeyPolicy::allow(["count"]).rename("count", "n"));
+        let mut parent = ctx(&[("n", json!(0))]);
+        let finished = ctx(&[("count", json!(7)), ("scratch", json!(true))]);
+        policy.merge_back(&finished, &mut parent).unwrap();
+        assert_eq!(parent.get("n"), Some(&json!(7)));
+        assert!(parent.get("scratch").is_none());
+    }
+}
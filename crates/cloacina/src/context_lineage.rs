@@ -0,0 +1,154 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context lineage — which task wrote each context key.
+//!
+//! With [`track_context_lineage`](crate::runner::DefaultRunnerConfigBuilder::track_context_lineage)
+//! enabled, the executor compares each successful task attempt's output
+//! context with the context it was given and records the top-level keys the
+//! attempt added, changed or removed, with the values before and after.
+//! [`DefaultRunner::get_context_lineage`](crate::runner::DefaultRunner::get_context_lineage)
+//! returns those records for one execution, grouped by key.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Context;
+
+/// Prefix of keys the runtime itself places in the context.
+const RESERVED_KEY_PREFIX: &str = "__cloacina";
+
+/// How a task attempt changed one context key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextChangeKind {
+    /// The key was absent from the task's input
+    Added,
+    /// The key held a different value in the task's input
+    Modified,
+    /// The key was in the task's input but not in its output
+    Removed,
+}
+
+/// One top-level context key a task attempt changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextChange {
+    pub key: String,
+    pub kind: ContextChangeKind,
+    /// Value in the task's input; `None` when added
+    pub previous: Option<Value>,
+    /// Value in the task's output; `None` when removed
+    pub value: Option<Value>,
+}
+
+/// The keys of `context` as lineage records them, binary values as
+/// marker objects.
+pub fn lineage_view(context: &Context<Value>) -> HashMap<String, Value> {
+    context.to_map().unwrap_or_default().into_iter().collect()
+}
+
+/// The top-level keys that differ between a task's `input` and `output`
+/// contexts, sorted by key. Reserved runtime keys are ignored.
+pub fn diff_contexts(
+    input: &HashMap<String, Value>,
+    output: &HashMap<String, Value>,
+) -> Vec<ContextChange> {
+    let mut changes: Vec<ContextChange> = output
+        .iter()
+        .filter_map(|(key, value)| match input.get(key) {
+            None => Some(ContextChange {
+                key: key.clone(),
+                kind: ContextChangeKind::Added,
+                previous: None,
+                value: Some(value.clone()),
+            }),
+            Some(previous) if previous != value => Some(ContextChange {
+                key: key.clone(),
+                kind: ContextChangeKind::Modified,
+                previous: Some(previous.clone()),
+                value: Some(value.clone()),
+            }),
+            Some(_) => None,
+        })
+        .chain(
+            input
+                .iter()
+                .filter(|(key, _)| !output.contains_key(*key))
+                .map(|(key, previous)| ContextChange {
+                    key: key.clone(),
+                    kind: ContextChangeKind::Removed,
+                    previous: Some(previous.clone()),
+                    value: None,
+                }),
+        )
+        .filter(|change| !change.key.starts_with(RESERVED_KEY_PREFIX))
+        .collect();
+    changes.sort_by(|a, b| a.key.cmp(&b.key));
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn map(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_diff_reports_added_modified_and_removed_keys() {
+        let input = map(json!({
+            "kept": 1,
+            "raw_data": [1, 2],
+            "dropped": true,
+            "__cloacina_task_config__": {},
+        }));
+        let output = map(json!({ "kept": 1, "raw_data": [], "summary": "ok" }));
+
+        assert_eq!(
+            diff_contexts(&input, &output),
+            vec![
+                ContextChange {
+                    key: "dropped".into(),
+                    kind: ContextChangeKind::Removed,
+                    previous: Some(json!(true)),
+                    value: None,
+                },
+                ContextChange {
+                    key: "raw_data".into(),
+                    kind: ContextChangeKind::Modified,
+                    previous: Some(json!([1, 2])),
+                    value: Some(json!([])),
+                },
+                ContextChange {
+                    key: "summary".into(),
+                    kind: ContextChangeKind::Added,
+                    previous: None,
+                    value: Some(json!("ok")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unchanged_context_has_no_changes() {
+        let context = map(json!({ "a": { "b": 1 } }));
+        assert!(diff_contexts(&context, &context).is_empty());
+    }
+}
@@ -0,0 +1,396 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context size limits and large-value offloading.
+//!
+//! Every context a runner stores (an execution's input and each task's
+//! output) is one row in `contexts`. A task that puts a large payload in its
+//! context, say ten thousand records, makes that row and every read of it
+//! large. With offloading, each top-level value whose JSON form is bigger
+//! than a threshold is written to a blob store and the row keeps a reference
+//! in its place:
+//!
+//! ```json
+//! {"products": {"$cloacina_offloaded": {"id": "4b1c…", "bytes": 1843021}}}
+//! ```
+//!
+//! Binary values ([`Context::insert_bytes`](crate::Context::insert_bytes))
+//! are offloaded as their raw bytes rather than their base64 JSON form, and
+//! their reference says so with `"binary": true`.
+//!
+//! Reads put the values back, so tasks, dependency loading and the
+//! execution APIs see the context as it was written. The blob store is any
+//! [`RegistryStorage`] backend: the database, a directory, or one an
+//! extension registers (e.g. S3).
+//!
+//! A size limit caps what stays in the row after offloading; saving a
+//! larger context fails with [`ContextError::TooLarge`], which fails the
+//! task that produced it.
+//!
+//! Attach a [`ContextOffload`] with
+//! [`Database::with_context_offload`](crate::Database::with_context_offload),
+//! or configure it on the runner with `context_offload_threshold` and
+//! `max_context_bytes`.
+
+use cloacina_workflow::context::{bytes_value, value_bytes};
+use serde_json::{Map, Value};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::crypto::AtRestEncryption;
+use crate::database::universal_types::UniversalUuid;
+use crate::error::ContextError;
+use crate::models::workflow_packages::StorageType;
+use crate::registry::{RegistryStorage, StorageError};
+
+/// Key of the object that stands in for an offloaded value.
+pub const OFFLOADED_MARKER: &str = "$cloacina_offloaded";
+
+/// Size limit and offload store applied to every context stored through a
+/// database.
+pub struct ContextOffload {
+    store: Option<Mutex<Box<dyn RegistryStorage>>>,
+    threshold_bytes: usize,
+    max_context_bytes: Option<usize>,
+}
+
+impl std::fmt::Debug for ContextOffload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextOffload")
+            .field("offloading", &self.store.is_some())
+            .field("threshold_bytes", &self.threshold_bytes)
+            .field("max_context_bytes", &self.max_context_bytes)
+            .finish()
+    }
+}
+
+impl Default for ContextOffload {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContextOffload {
+    /// Neither offloads nor limits anything; add either with the `with_`
+    /// methods.
+    pub fn new() -> Self {
+        Self {
+            store: None,
+            threshold_bytes: usize::MAX,
+            max_context_bytes: None,
+        }
+    }
+
+    /// Write top-level values larger than `threshold_bytes` (as JSON) to
+    /// `store`.
+    pub fn with_store(mut self, store: Box<dyn RegistryStorage>, threshold_bytes: usize) -> Self {
+        self.store = Some(Mutex::new(store));
+        self.threshold_bytes = threshold_bytes;
+        self
+    }
+
+    /// Reject contexts whose stored JSON, after offloading, is larger than
+    /// `limit` bytes.
+    pub fn with_max_context_bytes(mut self, limit: usize) -> Self {
+        self.max_context_bytes = Some(limit);
+        self
+    }
+
+    /// Values larger than this many bytes are offloaded, when a store is set.
+    pub fn threshold_bytes(&self) -> Option<usize> {
+        self.store.as_ref().map(|_| self.threshold_bytes)
+    }
+
+    /// The largest context that may be stored, if limited.
+    pub fn max_context_bytes(&self) -> Option<usize> {
+        self.max_context_bytes
+    }
+
+    /// Offload the large values of context `map`, stored as row
+    /// `context_id`, and enforce the size limit on what remains.
+    ///
+    /// Blobs are sealed with `encryption` unless the store is the database,
+    /// which applies it itself.
+    pub(crate) async fn offload(
+        &self,
+        mut map: Map<String, Value>,
+        context_id: UniversalUuid,
+        encryption: Option<&AtRestEncryption>,
+    ) -> Result<Map<String, Value>, ContextError> {
+        let Some(store) = self.store.as_ref() else {
+            self.check_size(&map)?;
+            return Ok(map);
+        };
+        let mut store = store.lock().await;
+        let seal = encryption.filter(|_| store.storage_type() != StorageType::Database);
+        let mut stored = Vec::new();
+        for (key, entry) in map.iter_mut() {
+            let bytes = serde_json::to_vec(&*entry)?;
+            if bytes.len() <= self.threshold_bytes {
+                continue;
+            }
+            let (bytes, binary) = match value_bytes(entry) {
+                Some(raw) => (raw, true),
+                None => (bytes, false),
+            };
+            let size = bytes.len();
+            let result = match seal {
+                Some(encryption) => {
+                    match encryption.encrypt(&bytes, &blob_aad(context_id, key)).await {
+                        Ok(sealed) => store.store_binary(sealed).await,
+                        Err(e) => Err(StorageError::Backend(format!("Encryption error: {}", e))),
+                    }
+                }
+                None => store.store_binary(bytes).await,
+            };
+            match result {
+                Ok(id) => {
+                    *entry = reference(&id, size, binary);
+                    stored.push(id);
+                }
+                Err(e) => {
+                    Self::discard(&mut **store, &stored).await;
+                    return Err(e.into());
+                }
+            }
+        }
+        if let Err(e) = self.check_size(&map) {
+            Self::discard(&mut **store, &stored).await;
+            return Err(e);
+        }
+        Ok(map)
+    }
+
+    /// Put the offloaded values of stored context `map` (row `context_id`)
+    /// back. Contexts without references pass through.
+    pub(crate) async fn restore(
+        &self,
+        mut map: Map<String, Value>,
+        context_id: UniversalUuid,
+        encryption: Option<&AtRestEncryption>,
+    ) -> Result<Map<String, Value>, ContextError> {
+        if !map.values().any(|entry| reference_id(entry).is_some()) {
+            return Ok(map);
+        }
+        let store = self.store.as_ref().ok_or_else(|| {
+            StorageError::Backend(
+                "context has offloaded values but no offload store is configured".to_string(),
+            )
+        })?;
+        let store = store.lock().await;
+        for (key, entry) in map.iter_mut() {
+            let Some(id) = reference_id(entry) else {
+                continue;
+            };
+            let binary = reference_is_binary(entry);
+            let bytes =
+                store
+                    .retrieve_binary(id)
+                    .await?
+                    .ok_or_else(|| StorageError::DataCorruption {
+                        id: id.to_string(),
+                        reason: format!("offloaded value of '{}' is missing", key),
+                    })?;
+            let bytes = if AtRestEncryption::is_encrypted(&bytes) {
+                let encryption = encryption.ok_or_else(|| {
+                    crate::crypto::AtRestError::Config(
+                        "offloaded value is encrypted but no at-rest keys are configured"
+                            .to_string(),
+                    )
+                })?;
+                encryption
+                    .decrypt(&bytes, &blob_aad(context_id, key))
+                    .await?
+            } else {
+                bytes
+            };
+            *entry = if binary {
+                bytes_value(&bytes)
+            } else {
+                serde_json::from_slice(&bytes)?
+            };
+        }
+        Ok(map)
+    }
+
+    /// Delete the blobs stored context `map` refers to. Failures are
+    /// logged, not returned: a leftover blob is unreachable, not wrong.
+    pub(crate) async fn release(&self, map: &Map<String, Value>) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        let ids = referenced_blobs(map);
+        if ids.is_empty() {
+            return;
+        }
+        Self::discard(&mut **store.lock().await, &ids).await;
+    }
+
+    async fn discard(store: &mut dyn RegistryStorage, ids: &[String]) {
+        for id in ids {
+            if let Err(e) = store.delete_binary(id).await {
+                warn!("Failed to delete offloaded context value {}: {}", id, e);
+            }
+        }
+    }
+
+    fn check_size(&self, map: &Map<String, Value>) -> Result<(), ContextError> {
+        let Some(limit) = self.max_context_bytes else {
+            return Ok(());
+        };
+        let size = serde_json::to_vec(map)?.len();
+        if size > limit {
+            return Err(ContextError::TooLarge { size, limit });
+        }
+        Ok(())
+    }
+}
+
+/// The blob ids referenced by the top-level values of stored context `map`.
+pub fn referenced_blobs(map: &Map<String, Value>) -> Vec<String> {
+    map.values()
+        .filter_map(reference_id)
+        .map(str::to_string)
+        .collect()
+}
+
+fn reference(id: &str, bytes: usize, binary: bool) -> Value {
+    if binary {
+        serde_json::json!({ OFFLOADED_MARKER: { "id": id, "bytes": bytes, "binary": true } })
+    } else {
+        serde_json::json!({ OFFLOADED_MARKER: { "id": id, "bytes": bytes } })
+    }
+}
+
+fn reference_id(value: &Value) -> Option<&str> {
+    let object = value.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+    object.get(OFFLOADED_MARKER)?.get("id")?.as_str()
+}
+
+fn reference_is_binary(value: &Value) -> bool {
+    value[OFFLOADED_MARKER]["binary"].as_bool().unwrap_or(false)
+}
+
+/// Associated data binding a sealed blob to the context row and key it was
+/// offloaded from.
+fn blob_aad(context_id: UniversalUuid, key: &str) -> Vec<u8> {
+    [&context_id.0.as_bytes()[..], key.as_bytes()].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::FilesystemRegistryStorage;
+
+    fn map(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            other => panic!("expected an object, got {}", other),
+        }
+    }
+
+    fn offload(dir: &std::path::Path, threshold: usize) -> ContextOffload {
+        ContextOffload::new().with_store(
+            Box::new(FilesystemRegistryStorage::new(dir).unwrap()),
+            threshold,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_large_values_round_trip_through_the_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let offload = offload(dir.path(), 64);
+        let id = UniversalUuid::new_v4();
+        let original = map(serde_json::json!({
+            "small": 1,
+            "rows": (0..100).collect::<Vec<_>>(),
+        }));
+
+        let stored = offload.offload(original.clone(), id, None).await.unwrap();
+        assert_eq!(stored["small"], 1);
+        assert!(reference_id(&stored["rows"]).is_some());
+        assert_eq!(referenced_blobs(&stored).len(), 1);
+
+        let restored = offload.restore(stored, id, None).await.unwrap();
+        assert_eq!(restored, original);
+    }
+
+    #[tokio::test]
+    async fn test_binary_values_are_stored_as_raw_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let offload = offload(dir.path(), 64);
+        let id = UniversalUuid::new_v4();
+        let payload: Vec<u8> = (0..=255).collect();
+        let original = map(serde_json::json!({ "image": bytes_value(&payload) }));
+
+        let stored = offload.offload(original, id, None).await.unwrap();
+        assert_eq!(stored["image"][OFFLOADED_MARKER]["binary"], true);
+        assert_eq!(stored["image"][OFFLOADED_MARKER]["bytes"], 256);
+
+        let restored = offload.restore(stored, id, None).await.unwrap();
+        assert_eq!(value_bytes(&restored["image"]), Some(payload));
+    }
+
+    #[tokio::test]
+    async fn test_context_over_limit_is_rejected_and_blobs_discarded() {
+        let dir = tempfile::tempdir().unwrap();
+        let offload = offload(dir.path(), 64).with_max_context_bytes(40);
+        let value = map(serde_json::json!({
+            "a": "x".repeat(30),
+            "b": "y".repeat(30),
+            "big": "z".repeat(200),
+        }));
+
+        let err = offload
+            .offload(value, UniversalUuid::new_v4(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ContextError::TooLarge { limit: 40, .. }));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_limit_applies_without_a_store() {
+        let offload = ContextOffload::new().with_max_context_bytes(10);
+        let id = UniversalUuid::new_v4();
+        assert!(offload
+            .offload(map(serde_json::json!({"a": 1})), id, None)
+            .await
+            .is_ok());
+        assert!(matches!(
+            offload
+                .offload(map(serde_json::json!({"a": "long value"})), id, None)
+                .await,
+            Err(ContextError::TooLarge {
+                size: 18,
+                limit: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn test_only_marker_objects_are_references() {
+        let value = serde_json::json!({
+            "a": { OFFLOADED_MARKER: { "id": "one", "bytes": 5 } },
+            "b": { OFFLOADED_MARKER: { "id": "two" }, "other": 1 },
+            "c": OFFLOADED_MARKER,
+        });
+        assert_eq!(referenced_blobs(&map(value)), vec!["one".to_string()]);
+    }
+}
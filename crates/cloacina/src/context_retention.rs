@@ -0,0 +1,166 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context retention — background service that expires context payloads.
+//!
+//! Execution metadata (status, timings, task rows, events, labels,
+//! annotations) is small and worth keeping for a long time; the contexts an
+//! execution carries (its input and each task's output) can be large. This
+//! sweeper drops the context payloads of executions that finished more than
+//! `retention` ago and leaves everything else in place, so the two age out on
+//! separate schedules.
+//!
+//! Only finished executions (those with `completed_at` set) are touched;
+//! running and paused executions keep their contexts regardless of age.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::dal::DAL;
+use crate::database::universal_types::UniversalTimestamp;
+
+/// Configuration for the context retention sweeper.
+#[derive(Debug, Clone)]
+pub struct ContextRetentionConfig {
+    /// How often to run the sweep (default 1h).
+    pub sweep_interval: Duration,
+    /// How long after an execution finishes its contexts are kept
+    /// (default 7 days).
+    pub retention: Duration,
+    /// Executions purged per transaction (default 500).
+    pub batch_size: i64,
+}
+
+impl Default for ContextRetentionConfig {
+    fn default() -> Self {
+        Self {
+            sweep_interval: Duration::from_secs(60 * 60),
+            retention: Duration::from_secs(7 * 24 * 60 * 60),
+            batch_size: 500,
+        }
+    }
+}
+
+/// Background service that purges expired execution contexts.
+pub struct ContextRetentionSweeper {
+    dal: Arc<DAL>,
+    config: ContextRetentionConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl ContextRetentionSweeper {
+    /// Create a new context retention sweeper.
+    pub fn new(
+        dal: Arc<DAL>,
+        config: ContextRetentionConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            dal,
+            config,
+            shutdown_rx,
+        }
+    }
+
+    /// Run the sweep loop.
+    pub async fn run(&mut self) {
+        info!(
+            "Starting context retention sweeper (interval: {}s, retention: {}s)",
+            self.config.sweep_interval.as_secs(),
+            self.config.retention.as_secs(),
+        );
+
+        let mut interval = tokio::time::interval(self.config.sweep_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.sweep().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    if *self.shutdown_rx.borrow() {
+                        info!("Context retention sweeper shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Perform a single sweep pass. Returns the number of executions whose
+    /// contexts were purged.
+    pub async fn sweep(&self) -> usize {
+        let cutoff = match chrono::Duration::from_std(self.config.retention) {
+            Ok(retention) => UniversalTimestamp(chrono::Utc::now() - retention),
+            Err(e) => {
+                warn!("Context retention sweep skipped: invalid retention: {}", e);
+                return 0;
+            }
+        };
+
+        let mut total = 0;
+        loop {
+            match self
+                .dal
+                .context()
+                .purge_for_executions_completed_before(cutoff, self.config.batch_size)
+                .await
+            {
+                Ok(n) => {
+                    total += n;
+                    if (n as i64) < self.config.batch_size {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    warn!("Context retention sweep failed: {}", e);
+                    break;
+                }
+            }
+        }
+
+        if total == 0 {
+            debug!(
+                "Context retention sweep: no executions older than {}",
+                cutoff
+            );
+        } else {
+            metrics::counter!("cloacina_execution_contexts_purged_total").increment(total as u64);
+            info!(
+                "Context retention sweep: purged contexts of {} execution(s) finished before {}",
+                total, cutoff
+            );
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults() {
+        let config = ContextRetentionConfig::default();
+        assert_eq!(config.sweep_interval, Duration::from_secs(3600));
+        assert_eq!(config.retention, Duration::from_secs(7 * 24 * 3600));
+        assert_eq!(config.batch_size, 500);
+    }
+}
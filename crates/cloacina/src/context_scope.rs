@@ -0,0 +1,255 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Per-task context scoping and declared reads.
+//!
+//! A task normally sees, and writes into, one shared namespace: the merged
+//! outputs of all its dependencies. Two task declarations narrow that:
+//!
+//! - [`reads`](Task::reads): the task sees only the listed keys. The rest
+//!   bypass it and reach its output unchanged.
+//! - [`scoped`](Task::scoped): whatever the task adds or changes ends up
+//!   under a key named after the task, so parallel branches that both write
+//!   `stats` produce `branch_a.stats` and `branch_b.stats` instead of one
+//!   overwriting the other when a downstream task merges them.
+//!
+//! Executors call [`TaskScope::enter`] on the built input context and
+//! [`TaskScope::leave`] on each successful output.
+
+use std::collections::HashMap;
+
+use cloacina_workflow::context::{bytes_value, TASK_CONFIG_KEY};
+use serde_json::{Map, Value};
+
+use crate::context::Context;
+use crate::error::TaskError;
+use crate::task::Task;
+
+/// What a task was shown, kept to put its output back together.
+#[derive(Debug)]
+pub struct TaskScope {
+    task_id: String,
+    /// The scope key, for scoped tasks.
+    scope: Option<String>,
+    /// The input as the task saw it, for scoped tasks.
+    visible: HashMap<String, Value>,
+    visible_bytes: HashMap<String, Vec<u8>>,
+    /// Input keys the task does not read.
+    carried: HashMap<String, Value>,
+    carried_bytes: HashMap<String, Vec<u8>>,
+}
+
+impl TaskScope {
+    /// Narrows `context` to the keys `task` reads.
+    ///
+    /// The task configuration key is always kept.
+    pub fn enter(task: &dyn Task, context: &mut Context<Value>) -> Self {
+        let mut carried = HashMap::new();
+        let mut carried_bytes = HashMap::new();
+        if let Some(reads) = task.reads() {
+            let hidden: Vec<String> = context
+                .data()
+                .keys()
+                .chain(context.binary().keys())
+                .filter(|key| key.as_str() != TASK_CONFIG_KEY && !reads.contains(key))
+                .cloned()
+                .collect();
+            for key in hidden {
+                if let Some(bytes) = context.remove_bytes(&key) {
+                    carried_bytes.insert(key, bytes);
+                } else if let Some(value) = context.remove(&key) {
+                    carried.insert(key, value);
+                }
+            }
+        }
+        let scope = task.scoped().then(|| task.id().to_string());
+        let (visible, visible_bytes) = if scope.is_some() {
+            (context.data().clone(), context.binary().clone())
+        } else {
+            (HashMap::new(), HashMap::new())
+        };
+        Self {
+            task_id: task.id().to_string(),
+            scope,
+            visible,
+            visible_bytes,
+            carried,
+            carried_bytes,
+        }
+    }
+
+    /// Builds the context the task hands downstream from its `output`.
+    ///
+    /// For a scoped task, keys it added or changed move under its scope and
+    /// the keys it received stay as they were; keys it did not read are put
+    /// back in either case. Fails if the scope key holds something other
+    /// than an object.
+    pub fn leave(self, output: Context<Value>) -> Result<Context<Value>, TaskError> {
+        let Self {
+            task_id,
+            scope,
+            visible,
+            visible_bytes,
+            carried,
+            carried_bytes,
+        } = self;
+        if scope.is_none() && carried.is_empty() && carried_bytes.is_empty() {
+            return Ok(output);
+        }
+        let (mut data, mut bytes) = output.into_parts();
+        if let Some(scope) = scope {
+            let mut scoped = match data.remove(&scope).or_else(|| visible.get(&scope).cloned()) {
+                None => Map::new(),
+                Some(Value::Object(object)) => object,
+                Some(_) => {
+                    return Err(TaskError::ContextError {
+                        task_id,
+                        error: cloacina_workflow::ContextError::TypeMismatch(scope),
+                    })
+                }
+            };
+            for (key, value) in data {
+                if visible.get(&key) != Some(&value) {
+                    scoped.insert(key, value);
+                }
+            }
+            // Inside the scope object a binary value can only be a marker.
+            for (key, raw) in bytes {
+                if visible_bytes.get(&key) != Some(&raw) {
+                    scoped.insert(key, bytes_value(&raw));
+                }
+            }
+            data = visible;
+            bytes = visible_bytes;
+            data.remove(&scope);
+            if !scoped.is_empty() {
+                data.insert(scope, Value::Object(scoped));
+            }
+        }
+        for (key, value) in carried {
+            if !bytes.contains_key(&key) {
+                data.entry(key).or_insert(value);
+            }
+        }
+        for (key, raw) in carried_bytes {
+            if !data.contains_key(&key) {
+                bytes.entry(key).or_insert(raw);
+            }
+        }
+        Ok(Context::from_parts(data, bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::FnTask;
+    use serde_json::json;
+
+    fn context(value: Value) -> Context<Value> {
+        let Value::Object(map) = value else {
+            unreachable!()
+        };
+        Context::from_map(map).unwrap()
+    }
+
+    fn data(context: Context<Value>) -> Value {
+        Value::Object(context.to_map().unwrap())
+    }
+
+    #[test]
+    fn test_unscoped_task_without_reads_is_untouched() {
+        let task = FnTask::new("plain", |ctx| async move { Ok(ctx) });
+        let mut input = context(json!({ "a": 1 }));
+        let scope = TaskScope::enter(&task, &mut input);
+        assert_eq!(input.data().len(), 1);
+
+        let output = scope.leave(context(json!({ "a": 2 }))).unwrap();
+        assert_eq!(data(output), json!({ "a": 2 }));
+    }
+
+    #[test]
+    fn test_scoped_writes_move_under_the_task_id() {
+        let task = FnTask::new("branch_a", |ctx| async move { Ok(ctx) }).scoped(true);
+        let mut input = context(json!({ "shared": 1, "stats": 0 }));
+        let scope = TaskScope::enter(&task, &mut input);
+
+        let output = scope
+            .leave(context(json!({ "shared": 1, "stats": 5, "rows": [1] })))
+            .unwrap();
+        assert_eq!(
+            data(output),
+            json!({
+                "shared": 1,
+                "stats": 0,
+                "branch_a": { "stats": 5, "rows": [1] },
+            })
+        );
+    }
+
+    #[test]
+    fn test_undeclared_reads_bypass_the_task() {
+        let task = FnTask::new("reader", |ctx| async move { Ok(ctx) }).reads(["a"]);
+        let mut input = context(json!({ "a": 1, "b": 2, TASK_CONFIG_KEY: {} }));
+        let scope = TaskScope::enter(&task, &mut input);
+        assert!(input.data().contains_key("a"));
+        assert!(input.data().contains_key(TASK_CONFIG_KEY));
+        assert!(!input.data().contains_key("b"));
+
+        let output = scope.leave(context(json!({ "a": 1, "c": 3 }))).unwrap();
+        assert_eq!(data(output), json!({ "a": 1, "b": 2, "c": 3 }));
+    }
+
+    #[test]
+    fn test_binary_values_are_carried_and_scoped() {
+        let reader = FnTask::new("reader", |ctx| async move { Ok(ctx) }).reads(["a"]);
+        let mut input = context(json!({ "a": 1, "image": bytes_value(b"png") }));
+        let scope = TaskScope::enter(&reader, &mut input);
+        assert!(input.binary().is_empty());
+
+        let output = scope.leave(context(json!({ "a": 2 }))).unwrap();
+        assert_eq!(output.get_bytes("image").unwrap(), b"png");
+        assert_eq!(output.get("a"), Some(&json!(2)));
+
+        let task = FnTask::new("branch_a", |ctx| async move { Ok(ctx) }).scoped(true);
+        let mut input = context(json!({ "image": bytes_value(b"png") }));
+        let scope = TaskScope::enter(&task, &mut input);
+        let output = scope
+            .leave(context(json!({
+                "image": bytes_value(b"png"),
+                "thumb": bytes_value(b"small"),
+            })))
+            .unwrap();
+        assert_eq!(output.get_bytes("image").unwrap(), b"png");
+        assert_eq!(
+            data(output),
+            json!({
+                "image": bytes_value(b"png"),
+                "branch_a": { "thumb": bytes_value(b"small") },
+            })
+        );
+    }
+
+    #[test]
+    fn test_non_object_scope_key_is_rejected() {
+        let task = FnTask::new("branch_a", |ctx| async move { Ok(ctx) }).scoped(true);
+        let mut input = context(json!({}));
+        let scope = TaskScope::enter(&task, &mut input);
+
+        let err = scope.leave(context(json!({ "branch_a": 1 }))).unwrap_err();
+        assert!(matches!(err, TaskError::ContextError { .. }));
+    }
+}
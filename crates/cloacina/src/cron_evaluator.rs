@@ -19,4 +19,7 @@
 //! `cloacina-workflow` but not on `cloacina`) can use it from the
 //! `#[trigger(cron = "...")]` macro emission. Engine paths re-export.
 
-pub use cloacina_workflow::cron_evaluator::{CronError, CronEvaluator};
+pub use cloacina_workflow::cron_evaluator::{
+    simulate_cron, CronError, CronEvaluator, CronFireTime, DstEdge, DstPolicy,
+};
+pub use cloacina_workflow::schedule_expression::normalize_cron_expression;
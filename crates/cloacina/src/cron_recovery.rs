@@ -272,11 +272,6 @@ impl CronRecoveryService {
         let mut context = Context::new();
 
         // Add recovery metadata
-        context
-            .insert("is_recovery", serde_json::json!(true))
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
         context
             .insert("recovery_attempt", serde_json::json!(attempt_count))
             .map_err(|e| WorkflowExecutionError::ExecutionFailed {
@@ -291,36 +286,14 @@ impl CronRecoveryService {
                 message: format!("Context error: {}", e),
             })?;
 
-        // Add original scheduling metadata
-        context
-            .insert(
-                "scheduled_time",
-                serde_json::json!(scheduled_time.to_rfc3339()),
-            )
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
-        context
-            .insert("schedule_id", serde_json::json!(schedule.id.to_string()))
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
-        context
-            .insert(
-                "schedule_timezone",
-                serde_json::json!(schedule.timezone.as_deref().unwrap_or("UTC")),
-            )
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
-        context
-            .insert(
-                "schedule_expression",
-                serde_json::json!(schedule.cron_expression.as_deref().unwrap_or("")),
-            )
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
+        // Add original scheduling metadata; the recovered run keeps the
+        // original fire's scheduled_time and so its logical date
+        crate::cron_trigger_scheduler::stamp_cron_fire_keys(
+            &mut context,
+            &schedule,
+            scheduled_time,
+            true,
+        )?;
 
         // Execute the workflow
         info!(
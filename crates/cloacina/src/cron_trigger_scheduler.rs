@@ -26,8 +26,14 @@
 //! - **Single Run Loop**: One tick drives both cron and trigger checks
 //! - **Atomic Claiming**: Prevents duplicate cron executions across instances
 //! - **Per-trigger Poll Intervals**: Each trigger retains its own polling frequency
+//! - **Trigger Leases**: Optionally, runners sharing a database split trigger
+//!   polling by leasing individual triggers, with failover on lease expiry
 //! - **Context-based Deduplication**: Prevents duplicate trigger executions
+//! - **Trigger Throttling**: Optional minimum interval between trigger fires
+//!   and debouncing of noisy triggers
 //! - **Catchup Policies**: Configurable handling of missed cron executions
+//! - **Delayed Executions**: One-shot runs requested with `execute_at` /
+//!   `execute_after` ride the cron timer and are claimed like cron fires
 //! - **Audit Trail**: Records every handoff via `schedule_executions`
 //! - **Saga Pattern**: Clean separation between scheduling and execution
 //!
@@ -45,23 +51,38 @@
 //! ```
 
 use crate::context::Context;
-use crate::cron_evaluator::CronEvaluator;
+use crate::cron_evaluator::{CronError, CronEvaluator};
 use crate::dal::UnifiedRegistryStorage;
 use crate::dal::DAL;
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
 use crate::error::ValidationError;
 use crate::executor::{WorkflowExecutionError, WorkflowExecutor};
-use crate::models::schedule::{CatchupPolicy, NewSchedule, NewScheduleExecution, Schedule};
+use crate::models::delayed_execution::DELAYED_EXECUTION_ID_KEY;
+use crate::models::schedule::{
+    CatchupPolicy, IntervalSchedule, NewSchedule, NewScheduleExecution, Schedule, SuppressionReason,
+};
 use crate::registry::workflow_registry::WorkflowRegistryImpl;
 use crate::runtime::Runtime;
-use crate::trigger::{Trigger, TriggerError};
+use crate::trigger::{
+    eval_trigger_condition, parse_trigger_probes, run_probes, PendingBatch, PendingDebounce,
+    Trigger, TriggerError, TriggerResult, PROBES_KEY,
+};
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{watch, Notify};
 use tracing::{debug, error, info, warn};
 
+/// Due delayed executions handed off per cron wake-up; the rest are picked
+/// up on the next, immediate, wake-up.
+const DELAYED_EXECUTION_BATCH: i64 = 100;
+
+/// How long a delayed execution's claim may go without a recorded hand-off
+/// before another wake-up claims it again, e.g. after the runner holding it
+/// stopped between the claim and the hand-off.
+const DELAYED_EXECUTION_CLAIM_TIMEOUT_SECS: i64 = 300;
+
 /// Configuration for the unified scheduler.
 #[derive(Debug, Clone)]
 pub struct SchedulerConfig {
@@ -95,6 +116,13 @@ pub struct SchedulerConfig {
     /// Defaults to 7 days. Subscriptions whose watermark predates the
     /// retention window will miss firings — documented gotcha.
     pub reactor_firings_retention: Duration,
+    /// Lease duration for shared trigger polling. When set, this scheduler
+    /// polls only the triggers it holds a lease on, renews its leases every
+    /// third of this duration, and balances them with the other runners on
+    /// the same database; a runner that stops renewing loses its triggers to
+    /// the others once the leases expire. `None` (the default) polls every
+    /// trigger, for single-runner deployments.
+    pub trigger_lease_ttl: Option<Duration>,
 }
 
 impl Default for SchedulerConfig {
@@ -109,6 +137,7 @@ impl Default for SchedulerConfig {
             reactor_poll_batch_limit: 100,
             reactor_firings_prune_interval: Duration::from_secs(60 * 60),
             reactor_firings_retention: Duration::from_secs(7 * 24 * 60 * 60),
+            trigger_lease_ttl: None,
         }
     }
 }
@@ -147,6 +176,10 @@ pub struct Scheduler {
     runtime: Arc<Runtime>,
     /// Tracks when each trigger was last polled (by trigger name).
     last_poll_times: HashMap<String, Instant>,
+    /// Fired payloads buffered by batching triggers, keyed by trigger name.
+    pending_batches: HashMap<String, PendingBatch>,
+    /// Firings held by debounced triggers, keyed by trigger name.
+    pending_debounces: HashMap<String, PendingDebounce>,
     /// Wakes the timer-driven cron loop when schedules change (registered,
     /// enabled/disabled, deleted) so a new schedule fires on time instead of
     /// waiting for the backstop (CLOACI-T-0743). Shared with the cron registrar
@@ -158,12 +191,21 @@ pub struct Scheduler {
     /// Tracks when the `reactor_firings` TTL prune last ran
     /// (CLOACI-I-0100 / T-0601).
     last_reactor_prune: Option<Instant>,
-    /// Per-subscription compiled CEL predicate cache (CLOACI-T-0602).
-    /// Key is the subscription id; value is `(expression_string, program)`
+    /// Per-subscription compiled CEL predicate cache (CLOACI-T-0602), also
+    /// holding trigger schedules' declarative conditions.
+    /// Key is the subscription (or schedule) id; value is `(expression_string, program)`
     /// so we can invalidate on expression-text change without restart.
     /// Arc<Mutex> for shared interior mutability across Scheduler clones
     /// (the active poller is single-threaded, but Clone is on the type).
     predicate_cache: PredicateCache,
+    /// Owner id this scheduler leases triggers under.
+    lease_owner: UniversalUuid,
+    /// Trigger schedules this scheduler currently holds a lease on.
+    trigger_leases: HashSet<UniversalUuid>,
+    /// When the held leases run out unless renewed.
+    trigger_leases_valid_until: Option<Instant>,
+    /// When the trigger leases were last renewed and rebalanced.
+    last_lease_refresh: Option<Instant>,
 }
 
 /// CLOACI-T-0602 — alias to satisfy clippy::type_complexity on the
@@ -214,12 +256,26 @@ impl Scheduler {
             runtime,
             cron_change,
             last_poll_times: HashMap::new(),
+            pending_batches: HashMap::new(),
+            pending_debounces: HashMap::new(),
             last_reactor_poll: None,
             last_reactor_prune: None,
             predicate_cache: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            lease_owner: UniversalUuid::new_v4(),
+            trigger_leases: HashSet::new(),
+            trigger_leases_valid_until: None,
+            last_lease_refresh: None,
         }
     }
 
+    /// Sets the owner id trigger leases are held under (defaults to a random
+    /// id). Runners pass their instance id so leases show which runner polls
+    /// each trigger.
+    pub fn with_lease_owner(mut self, owner: UniversalUuid) -> Self {
+        self.lease_owner = owner;
+        self
+    }
+
     /// Creates a new unified scheduler with default configuration.
     pub fn with_defaults(
         dal: Arc<DAL>,
@@ -306,6 +362,9 @@ impl Scheduler {
                     if let Err(e) = self.check_and_execute_cron_schedules().await {
                         error!("Error processing cron schedules: {}", e);
                     }
+                    if let Err(e) = self.dispatch_delayed_executions().await {
+                        error!("Error dispatching delayed executions: {}", e);
+                    }
                     next_cron_due = self.query_next_cron_due().await;
                 }
                 // --- Cron schedule changed (registered/enabled/deleted): re-arm ---
@@ -322,21 +381,34 @@ impl Scheduler {
             }
         }
 
+        if let Err(e) = self.release_trigger_leases().await {
+            warn!("Failed to release trigger leases: {}", e);
+        }
+
         info!("Unified scheduler polling loop stopped");
         Ok(())
     }
 
-    /// Query the earliest `next_run_at` over enabled cron schedules. Errors are
+    /// Query the earliest `next_run_at` over enabled cron schedules, or the
+    /// earliest pending delayed execution if that is sooner. Errors are
     /// logged and treated as "unknown" (`None`) so a transient DB hiccup falls
     /// back to the backstop rather than stalling the loop (CLOACI-T-0743).
     async fn query_next_cron_due(&self) -> Option<DateTime<Utc>> {
-        match self.dal.schedule().next_cron_due_time().await {
+        let cron_due = match self.dal.schedule().next_cron_due_time().await {
             Ok(due) => due,
             Err(e) => {
                 warn!("Failed to query next cron due time: {}", e);
                 None
             }
-        }
+        };
+        let delayed_due = match self.dal.delayed_execution().next_due_time().await {
+            Ok(due) => due,
+            Err(e) => {
+                warn!("Failed to query next delayed execution time: {}", e);
+                None
+            }
+        };
+        cron_due.into_iter().chain(delayed_due).min()
     }
 
     /// How long to sleep before the next cron check, given the cached next-due
@@ -414,15 +486,10 @@ impl Scheduler {
             return Ok(());
         }
 
-        // Calculate execution times based on catchup policy
-        let execution_times = self.calculate_execution_times(schedule, now)?;
-        if execution_times.is_empty() {
-            debug!(
-                "No execution times calculated for cron schedule {}",
-                schedule.id
-            );
-            return Ok(());
-        }
+        // Calculate execution times based on catchup policy. An empty set
+        // (missed fires dropped by `Skip`) still claims the schedule below,
+        // so next_run_at moves past the fires it skipped.
+        let execution_times = Self::calculate_execution_times(&self.config, schedule, now)?;
 
         // Calculate next run time
         let next_run = self.calculate_next_run(schedule, now)?;
@@ -445,6 +512,16 @@ impl Scheduler {
             return Ok(());
         }
 
+        if execution_times.is_empty() {
+            info!(
+                "No fires to run for cron schedule {} (policy: {}); next run at {}",
+                schedule.id,
+                schedule.catchup_policy.as_deref().unwrap_or("skip"),
+                next_run
+            );
+            return Ok(());
+        }
+
         info!(
             "Successfully claimed cron schedule {} for {} execution(s)",
             schedule.id,
@@ -544,45 +621,59 @@ impl Scheduler {
     }
 
     /// Calculates execution times based on the schedule's catchup policy.
+    ///
+    /// The due fire is the schedule's `next_run_at`; fires after it up to
+    /// `now` were missed. `Skip` runs only a fire at most one cron poll
+    /// interval late, `RunLatestOnly` runs the most recent due fire and
+    /// `RunAll` runs every fire since the last run. A schedule with a fire
+    /// delay is evaluated that much in the past, so the returned times are
+    /// the cron times themselves.
     fn calculate_execution_times(
-        &self,
+        config: &SchedulerConfig,
         schedule: &Schedule,
         now: DateTime<Utc>,
     ) -> Result<Vec<DateTime<Utc>>, WorkflowExecutionError> {
         let policy_str = schedule.catchup_policy.as_deref().unwrap_or("skip");
         let policy = CatchupPolicy::from(policy_str.to_string());
+        let delay = schedule.fire_delay();
+        let now = now - delay;
+
+        let evaluator = FireTimes::for_schedule(schedule)?;
+        let due = schedule.next_run_at.map(|t| t.0 - delay).unwrap_or(now);
 
         match policy {
             CatchupPolicy::Skip => {
-                // Just return the current next_run_at
-                let next_run = schedule.next_run_at.map(|t| t.0).unwrap_or(now);
-                Ok(vec![next_run])
+                let latest = latest_due_fire(&evaluator, due, now)?;
+                let grace = chrono::Duration::from_std(config.cron_poll_interval)
+                    .unwrap_or(chrono::Duration::MAX);
+                if now - latest > grace {
+                    debug!(
+                        "Cron schedule {} missed its fires up to {} (policy: Skip)",
+                        schedule.id, latest
+                    );
+                    Ok(Vec::new())
+                } else {
+                    Ok(vec![latest])
+                }
             }
+            CatchupPolicy::RunLatestOnly => Ok(vec![latest_due_fire(&evaluator, due, now)?]),
             CatchupPolicy::RunAll => {
-                let cron_expr = schedule.cron_expression.as_deref().unwrap_or("* * * * *");
-                let tz = schedule.timezone.as_deref().unwrap_or("UTC");
-
-                let evaluator = CronEvaluator::new(cron_expr, tz).map_err(|e| {
-                    WorkflowExecutionError::ExecutionFailed {
-                        message: format!("Cron evaluation error: {}", e),
-                    }
-                })?;
-
                 let start_time = schedule
                     .last_run_at
                     .map(|t| t.0)
-                    .unwrap_or(schedule.created_at.0);
+                    .unwrap_or(schedule.created_at.0)
+                    - delay;
 
                 let missed_executions = evaluator
-                    .executions_between(start_time, now, self.config.max_catchup_executions)
+                    .executions_between(start_time, now, config.max_catchup_executions)
                     .map_err(|e| WorkflowExecutionError::ExecutionFailed {
                         message: format!("Cron evaluation error: {}", e),
                     })?;
 
-                if missed_executions.len() >= self.config.max_catchup_executions {
+                if missed_executions.len() >= config.max_catchup_executions {
                     warn!(
                         "Limited catchup executions to {} for cron schedule {} (policy: RunAll)",
-                        self.config.max_catchup_executions, schedule.id
+                        config.max_catchup_executions, schedule.id
                     );
                 }
 
@@ -591,23 +682,18 @@ impl Scheduler {
         }
     }
 
-    /// Calculates the next run time for a cron schedule.
+    /// Calculates the next run time for a cron schedule: its next cron time
+    /// whose delayed run falls after `after`, plus the delay.
     fn calculate_next_run(
         &self,
         schedule: &Schedule,
         after: DateTime<Utc>,
     ) -> Result<DateTime<Utc>, WorkflowExecutionError> {
-        let cron_expr = schedule.cron_expression.as_deref().unwrap_or("* * * * *");
-        let tz = schedule.timezone.as_deref().unwrap_or("UTC");
-
-        let evaluator = CronEvaluator::new(cron_expr, tz).map_err(|e| {
-            WorkflowExecutionError::ExecutionFailed {
-                message: format!("Cron evaluation error: {}", e),
-            }
-        })?;
-
+        let evaluator = FireTimes::for_schedule(schedule)?;
+        let delay = schedule.fire_delay();
         evaluator
-            .next_execution(after)
+            .next_execution(after - delay)
+            .map(|next| next + delay)
             .map_err(|e| WorkflowExecutionError::ExecutionFailed {
                 message: format!("Cron evaluation error: {}", e),
             })
@@ -623,42 +709,26 @@ impl Scheduler {
         // CLOACI-I-0116: a named instance's bound params are delivered as
         // flat context keys; the reserved scheduler keys below are stamped
         // AFTER (merge skips them), so a binding can never spoof them.
+        // `{"$template": …}` params are rendered against the fire first.
         if let Some(ref params_json) = schedule.params {
-            crate::workflow_instance::merge_instance_params(&mut context, params_json).map_err(
+            let meta = crate::templating::FireMetadata {
+                fire_time: scheduled_time,
+                workflow_name: schedule.workflow_name.clone(),
+                schedule_id: Some(schedule.id.to_string()),
+                schedule_timezone: schedule.timezone.clone(),
+                trigger_name: None,
+            };
+            let params_json = crate::templating::render_params(params_json, &meta, &context)
+                .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                    message: format!("instance param template: {}", e),
+                })?;
+            crate::workflow_instance::merge_instance_params(&mut context, &params_json).map_err(
                 |e| WorkflowExecutionError::ExecutionFailed {
                     message: format!("instance params merge: {}", e),
                 },
             )?;
         }
-        context
-            .insert(
-                "scheduled_time",
-                serde_json::json!(scheduled_time.to_rfc3339()),
-            )
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
-        context
-            .insert("schedule_id", serde_json::json!(schedule.id.to_string()))
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
-        context
-            .insert(
-                "schedule_timezone",
-                serde_json::json!(schedule.timezone.as_deref().unwrap_or("UTC")),
-            )
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
-        context
-            .insert(
-                "schedule_expression",
-                serde_json::json!(schedule.cron_expression.as_deref().unwrap_or("")),
-            )
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Context error: {}", e),
-            })?;
+        stamp_cron_fire_keys(&mut context, schedule, scheduled_time, false)?;
 
         info!(
             "Executing workflow '{}' for cron schedule {} (scheduled time: {})",
@@ -702,10 +772,149 @@ impl Scheduler {
         Ok(audit_record.id)
     }
 
+    // -----------------------------------------------------------------------
+    // Delayed executions
+    // -----------------------------------------------------------------------
+
+    /// Hands every due delayed execution to the executor. Each one is claimed
+    /// first, so only one runner starts it; the workflow execution it started,
+    /// or why it could not start, is recorded on the row. A claim left without
+    /// an outcome for [`DELAYED_EXECUTION_CLAIM_TIMEOUT_SECS`] is claimed again, so
+    /// a runner stopping mid hand-off does not strand the run; when that
+    /// runner had already started the execution, the re-claim records it
+    /// instead of starting another.
+    async fn dispatch_delayed_executions(&self) -> Result<(), WorkflowExecutionError> {
+        let now = Utc::now();
+        let stale_before = now - chrono::Duration::seconds(DELAYED_EXECUTION_CLAIM_TIMEOUT_SECS);
+        let due = self
+            .dal
+            .delayed_execution()
+            .get_due(now, stale_before, DELAYED_EXECUTION_BATCH)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: e.to_string(),
+            })?;
+
+        for delayed in due {
+            let claimed = self
+                .dal
+                .delayed_execution()
+                .claim(delayed.id, now, stale_before)
+                .await
+                .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                    message: e.to_string(),
+                })?;
+            if !claimed {
+                debug!(
+                    "Delayed execution {} was already claimed or cancelled",
+                    delayed.id
+                );
+                continue;
+            }
+            if delayed.claimed_at.is_some() {
+                warn!(
+                    "Delayed execution {} was claimed without a hand-off; claiming it again",
+                    delayed.id
+                );
+                let started = self
+                    .dal
+                    .delayed_execution()
+                    .started_execution(&delayed)
+                    .await
+                    .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                        message: e.to_string(),
+                    })?;
+                if let Some(execution_id) = started {
+                    info!(
+                        "Delayed execution {} already started execution {}; recording it",
+                        delayed.id, execution_id
+                    );
+                    if let Err(e) = self
+                        .dal
+                        .delayed_execution()
+                        .record_outcome(delayed.id, Some(execution_id), None)
+                        .await
+                    {
+                        warn!(
+                            "Failed to record the outcome of delayed execution {}: {}",
+                            delayed.id, e
+                        );
+                    }
+                    continue;
+                }
+            }
+
+            let started = match Context::from_json(delayed.context.clone()) {
+                Ok(mut context) => {
+                    context.remove(DELAYED_EXECUTION_ID_KEY);
+                    match context.insert(
+                        DELAYED_EXECUTION_ID_KEY,
+                        serde_json::json!(delayed.id.to_string()),
+                    ) {
+                        Ok(()) => self
+                            .executor
+                            .execute_async(&delayed.workflow_name, context)
+                            .await
+                            .map(|execution| UniversalUuid(execution.execution_id)),
+                        Err(e) => Err(WorkflowExecutionError::ExecutionFailed {
+                            message: format!("Context error: {}", e),
+                        }),
+                    }
+                }
+                Err(e) => Err(WorkflowExecutionError::ExecutionFailed {
+                    message: format!("Invalid delayed execution context: {}", e),
+                }),
+            };
+            let (execution_id, error) = match started {
+                Ok(execution_id) => {
+                    info!(
+                        "Started workflow '{}' for delayed execution {} (due: {}, execution_id: {})",
+                        delayed.workflow_name, delayed.id, delayed.run_at.0, execution_id
+                    );
+                    (Some(execution_id), None)
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to start workflow '{}' for delayed execution {}: {}",
+                        delayed.workflow_name, delayed.id, e
+                    );
+                    (None, Some(e.to_string()))
+                }
+            };
+            if let Err(e) = self
+                .dal
+                .delayed_execution()
+                .record_outcome(delayed.id, execution_id, error)
+                .await
+            {
+                warn!(
+                    "Failed to record the outcome of delayed execution {}: {}",
+                    delayed.id, e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Trigger schedule processing
     // -----------------------------------------------------------------------
 
+    /// Run one pass over due delayed executions. Exposed publicly so
+    /// integration tests can drive the hand-off deterministically without
+    /// waiting on the background tick.
+    pub async fn dispatch_delayed_executions_once(&self) -> Result<(), WorkflowExecutionError> {
+        self.dispatch_delayed_executions().await
+    }
+
+    /// Run one pass over enabled triggers, polling those that are due.
+    /// Exposed publicly so integration tests can drive trigger polling
+    /// deterministically without waiting on the background tick.
+    pub async fn poll_triggers_once(&mut self) -> Result<(), WorkflowExecutionError> {
+        self.check_and_process_triggers().await
+    }
+
     /// Checks all enabled triggers and processes those that are due.
     async fn check_and_process_triggers(&mut self) -> Result<(), WorkflowExecutionError> {
         debug!("Checking trigger schedules");
@@ -724,9 +933,18 @@ impl Scheduler {
             return Ok(());
         }
 
+        let leasing = self.config.trigger_lease_ttl.is_some();
+        if let Some(ttl) = self.config.trigger_lease_ttl {
+            self.refresh_trigger_leases(&schedules, ttl).await;
+        }
+
         let now = Instant::now();
 
         for schedule in schedules {
+            if leasing && !self.holds_trigger_lease(schedule.id, now) {
+                continue;
+            }
+
             let trigger_name = schedule
                 .trigger_name
                 .as_deref()
@@ -739,8 +957,15 @@ impl Scheduler {
                 .unwrap_or(self.config.trigger_base_poll_interval);
             let last_poll = self.last_poll_times.get(&trigger_name);
 
+            // A trigger just leased from another runner keeps the cadence
+            // recorded in `last_poll_at`.
             let should_poll = match last_poll {
                 Some(last) => now.duration_since(*last) >= poll_interval,
+                None if leasing => schedule.last_poll_at.is_none_or(|at| {
+                    (Utc::now() - at.0)
+                        .to_std()
+                        .is_ok_and(|since| since >= poll_interval)
+                }),
                 None => true,
             };
 
@@ -749,9 +974,27 @@ impl Scheduler {
             }
 
             // Process this trigger
-            if let Err(e) = self.process_trigger(&schedule).await {
+            let mut batch = self
+                .pending_batches
+                .remove(&trigger_name)
+                .unwrap_or_default();
+            let mut debounce = self
+                .pending_debounces
+                .remove(&trigger_name)
+                .unwrap_or_default();
+            if let Err(e) = self
+                .process_trigger(&schedule, &mut batch, &mut debounce)
+                .await
+            {
                 error!("Failed to process trigger '{}': {}", trigger_name, e);
             }
+            if !batch.is_empty() {
+                self.pending_batches.insert(trigger_name.clone(), batch);
+            }
+            if !debounce.is_empty() {
+                self.pending_debounces
+                    .insert(trigger_name.clone(), debounce);
+            }
 
             // Update last poll time
             self.last_poll_times.insert(trigger_name, now);
@@ -760,8 +1003,143 @@ impl Scheduler {
         Ok(())
     }
 
+    /// Whether this scheduler holds an unexpired lease on a trigger.
+    fn holds_trigger_lease(&self, schedule_id: UniversalUuid, now: Instant) -> bool {
+        self.trigger_leases.contains(&schedule_id)
+            && self
+                .trigger_leases_valid_until
+                .is_some_and(|until| now < until)
+    }
+
+    /// Renews this scheduler's trigger leases and rebalances them, at most
+    /// every third of the lease duration. On failure the held leases stay
+    /// in use until they run out.
+    async fn refresh_trigger_leases(&mut self, schedules: &[Schedule], ttl: Duration) {
+        let started = Instant::now();
+        if self
+            .last_lease_refresh
+            .is_some_and(|last| started.duration_since(last) < ttl / 3)
+        {
+            return;
+        }
+        self.last_lease_refresh = Some(started);
+
+        match self.rebalance_trigger_leases(schedules, ttl).await {
+            Ok(leases) => {
+                self.trigger_leases = leases;
+                self.trigger_leases_valid_until = Some(started + ttl);
+            }
+            Err(e) => warn!("Failed to refresh trigger leases: {}", e),
+        }
+    }
+
+    /// Moves this scheduler towards a fair share of the enabled triggers:
+    /// renews the leases it holds, hands surplus ones back (keeping triggers
+    /// with buffered batch events where it can), and takes free or expired
+    /// leases until it holds its share. Returns the leased schedule ids.
+    async fn rebalance_trigger_leases(
+        &self,
+        schedules: &[Schedule],
+        ttl: Duration,
+    ) -> Result<HashSet<UniversalUuid>, ValidationError> {
+        let schedule_dal = self.dal.schedule();
+        let now = Utc::now();
+        let ttl = chrono::Duration::from_std(ttl).unwrap_or_default();
+        let expires_at = now + ttl;
+
+        let pollers = schedule_dal
+            .heartbeat_trigger_poller(self.lease_owner, now - ttl)
+            .await?;
+        let fair_share = schedules.len().div_ceil(pollers.max(1));
+
+        let renewed: HashSet<UniversalUuid> = schedule_dal
+            .renew_trigger_leases(self.lease_owner, expires_at)
+            .await?
+            .into_iter()
+            .collect();
+        let mut held: Vec<&Schedule> = schedules
+            .iter()
+            .filter(|s| renewed.contains(&s.id))
+            .collect();
+
+        held.sort_by_key(|s| {
+            let name = s.trigger_name.as_deref().unwrap_or("unknown");
+            !(self.pending_batches.contains_key(name) || self.pending_debounces.contains_key(name))
+        });
+        while held.len() > fair_share {
+            let Some(surplus) = held.pop() else { break };
+            schedule_dal
+                .release_trigger_lease(surplus.id, self.lease_owner)
+                .await?;
+            debug!(
+                "Released lease on trigger '{}' to rebalance ({} runners polling)",
+                surplus.trigger_name.as_deref().unwrap_or("unknown"),
+                pollers
+            );
+        }
+
+        for schedule in schedules {
+            if held.len() >= fair_share {
+                break;
+            }
+            let free = match (schedule.lease_owner, schedule.lease_expires_at) {
+                (Some(_), Some(expires)) => expires.0 < now,
+                _ => true,
+            };
+            if !free || renewed.contains(&schedule.id) {
+                continue;
+            }
+            if schedule_dal
+                .acquire_trigger_lease(schedule.id, self.lease_owner, now, expires_at)
+                .await?
+            {
+                debug!(
+                    "Leased trigger '{}'",
+                    schedule.trigger_name.as_deref().unwrap_or("unknown")
+                );
+                held.push(schedule);
+            }
+        }
+
+        Ok(held.into_iter().map(|s| s.id).collect())
+    }
+
+    /// Hands back every trigger lease this scheduler holds and leaves the
+    /// set of trigger pollers, so the other runners take its triggers over
+    /// without waiting for the leases to expire. The polling loop calls this
+    /// when it stops. Does nothing unless trigger leasing is enabled.
+    pub async fn release_trigger_leases(&mut self) -> Result<(), ValidationError> {
+        if self.config.trigger_lease_ttl.is_none() {
+            return Ok(());
+        }
+        self.trigger_leases.clear();
+        self.trigger_leases_valid_until = None;
+        self.last_lease_refresh = None;
+        self.dal
+            .schedule()
+            .remove_trigger_poller(self.lease_owner)
+            .await
+    }
+
     /// Processes a single trigger schedule.
-    async fn process_trigger(&self, schedule: &Schedule) -> Result<(), TriggerError> {
+    ///
+    /// For a batching trigger, fired payloads are buffered in `batch` and a
+    /// single execution is launched only once the batch policy is met. The
+    /// buffer is cleared only after the workflow has been handed off.
+    ///
+    /// For a debounced (unbatched) trigger, the latest firing is held in
+    /// `debounce` and runs once the trigger has not fired for the debounce
+    /// window; like a batch, it is cleared only after the handoff.
+    ///
+    /// A fired context that will not run — its condition is false, a later
+    /// firing replaces it, it is suppressed, the handoff fails without a
+    /// buffer to keep it — is handed back through [`Trigger::discard`].
+    async fn process_trigger(
+        &self,
+        schedule: &Schedule,
+        batch: &mut PendingBatch,
+        debounce: &mut PendingDebounce,
+    ) -> Result<(), TriggerError> {
         let trigger_name = schedule.trigger_name.as_deref().unwrap_or("unknown");
 
         debug!(
@@ -769,26 +1147,46 @@ impl Scheduler {
             trigger_name, schedule.workflow_name
         );
 
-        // Get the trigger instance from the scoped runtime
-        let trigger = self.runtime.get_trigger(trigger_name).ok_or_else(|| {
-            TriggerError::TriggerNotFound {
+        // Get the trigger instance from the scoped runtime. A schedule with
+        // a declarative condition needs none: it fires whenever the
+        // condition holds.
+        let trigger = self.runtime.get_trigger(trigger_name);
+        let condition = schedule.trigger_condition.as_deref();
+        if trigger.is_none() && condition.is_none() {
+            return Err(TriggerError::TriggerNotFound {
                 name: trigger_name.to_string(),
-            }
-        })?;
+            });
+        }
 
         // Poll the trigger with timeout
-        let poll_result = tokio::time::timeout(self.config.trigger_poll_timeout, trigger.poll())
-            .await
-            .map_err(|_| TriggerError::PollError {
-                message: format!(
-                    "Trigger '{}' poll timed out after {:?}",
-                    trigger_name, self.config.trigger_poll_timeout
-                ),
-            })?
-            .map_err(|e| {
-                error!("Trigger '{}' poll error: {}", trigger_name, e);
-                e
-            })?;
+        let poll_result = match &trigger {
+            Some(trigger) => tokio::time::timeout(self.config.trigger_poll_timeout, trigger.poll())
+                .await
+                .map_err(|_| TriggerError::PollError {
+                    message: format!(
+                        "Trigger '{}' poll timed out after {:?}",
+                        trigger_name, self.config.trigger_poll_timeout
+                    ),
+                })?
+                .map_err(|e| {
+                    error!("Trigger '{}' poll error: {}", trigger_name, e);
+                    e
+                })?,
+            None => TriggerResult::Fire(None),
+        };
+        let poll_result = match condition {
+            Some(expression) if poll_result.should_fire() => {
+                let polled = trigger.as_ref().and_then(|_| fired_copy(&poll_result));
+                let gated = self
+                    .apply_trigger_condition(schedule, expression, poll_result)
+                    .await;
+                if !matches!(gated, Ok(TriggerResult::Fire(_))) {
+                    discard_firings(trigger.as_ref(), polled).await;
+                }
+                gated?
+            }
+            _ => poll_result,
+        };
 
         // Update last poll time in database
         let now = Utc::now();
@@ -799,31 +1197,109 @@ impl Scheduler {
             );
         }
 
+        let batch_policy = trigger.as_ref().and_then(|t| t.batch());
+        let buffered = batch_policy.is_some() || schedule.debounce_window().is_some();
+        let poll_result = match batch_policy {
+            Some(policy) => {
+                if poll_result.should_fire() {
+                    batch.push(poll_result.into_context(), Instant::now());
+                    debug!(
+                        "Trigger '{}' buffered event ({} pending)",
+                        trigger_name,
+                        batch.len()
+                    );
+                }
+                if !batch.is_ready(&policy, Instant::now()) {
+                    return Ok(());
+                }
+                // Leave the buffer intact until the handoff succeeds: a skip or
+                // failure below keeps the events for the next poll.
+                TriggerResult::Fire(Some(batch.to_context()?))
+            }
+            None => match schedule.debounce_window() {
+                Some(window) => {
+                    if poll_result.should_fire() {
+                        let replaced = debounce.hold(poll_result.into_context(), Instant::now());
+                        discard_firings(trigger.as_ref(), replaced).await;
+                        debug!(
+                            "Trigger '{}' fired; holding it for {:?} of quiet",
+                            trigger_name, window
+                        );
+                        return Ok(());
+                    }
+                    if !debounce.is_settled(window, Instant::now()) {
+                        return Ok(());
+                    }
+                    debounce.to_result()
+                }
+                None => poll_result,
+            },
+        };
+
         // Check if trigger should fire
         if !poll_result.should_fire() {
             debug!("Trigger '{}' returned Skip", trigger_name);
             return Ok(());
         }
 
-        // Compute context hash for deduplication
-        let context_hash = poll_result.context_hash();
+        // A buffered firing stays held for the next poll when it does not
+        // run; an unbuffered one is lost, so keep a copy to hand back to the
+        // trigger then.
+        let spare = match &trigger {
+            Some(_) if !buffered => fired_copy(&poll_result),
+            _ => None,
+        };
+        let handed_off = self
+            .hand_off_firing(schedule, trigger.as_ref(), poll_result, batch, debounce)
+            .await;
+        if !matches!(handed_off, Ok(true)) {
+            discard_firings(trigger.as_ref(), spare).await;
+        }
+        handed_off.map(|_| ())
+    }
 
-        // Check for duplicate active execution (unless allow_concurrent)
-        if !schedule.allows_concurrent() {
-            let has_active = self
+    /// Hands a firing that passed its condition to the workflow executor,
+    /// unless it is suppressed as a duplicate. Returns whether an execution
+    /// was started. A window duplicate also drops the buffered or held
+    /// firings, handing them back to `trigger`.
+    async fn hand_off_firing(
+        &self,
+        schedule: &Schedule,
+        trigger: Option<&Arc<dyn Trigger>>,
+        poll_result: TriggerResult,
+        batch: &mut PendingBatch,
+        debounce: &mut PendingDebounce,
+    ) -> Result<bool, TriggerError> {
+        let trigger_name = schedule.trigger_name.as_deref().unwrap_or("unknown");
+
+        // Compute context hash for deduplication, over the schedule's
+        // configured dedup keys (whole context when none are set)
+        let context_hash = poll_result.context_hash_for_keys(&schedule.dedup_key_list());
+
+        if let Some((reason, duplicate_of)) = self.find_duplicate(schedule, &context_hash).await? {
+            info!(
+                "Trigger '{}' suppressed duplicate firing ({}; duplicate of {})",
+                trigger_name, reason, duplicate_of
+            );
+            if let Err(e) = self
                 .dal
                 .schedule_execution()
-                .has_active_execution(schedule.id, &context_hash)
+                .record_suppressed(schedule.id, &context_hash, reason, duplicate_of)
                 .await
-                .map_err(|e| TriggerError::ConnectionPool(e.to_string()))?;
-
-            if has_active {
-                debug!(
-                    "Trigger '{}' has active execution with same context hash, skipping",
-                    trigger_name
+            {
+                warn!(
+                    "Failed to record suppressed firing for trigger '{}': {}",
+                    trigger_name, e
                 );
-                return Ok(());
             }
+            // A window duplicate is dropped for good; an in-flight or rate
+            // limited one keeps its buffered or held firing for the next
+            // poll, as before.
+            if reason == SuppressionReason::Window {
+                discard_firings(trigger, batch.drain()).await;
+                discard_firings(trigger, debounce.take()).await;
+            }
+            return Ok(false);
         }
 
         info!(
@@ -837,11 +1313,14 @@ impl Scheduler {
             .await?;
 
         // Extract context from result
-        let context = poll_result.into_context().unwrap_or_else(Context::new);
+        let context = poll_result.into_context().unwrap_or_default();
 
         // Hand off to workflow executor
         match self.execute_trigger_workflow(schedule, context).await {
             Ok(workflow_execution_id) => {
+                batch.clear();
+                debounce.clear();
+
                 // Link the execution to the workflow execution
                 if let Err(e) = self
                     .dal
@@ -884,7 +1363,111 @@ impl Scheduler {
             }
         }
 
-        Ok(())
+        Ok(true)
+    }
+
+    /// Gates a firing on the schedule's declarative condition: runs its
+    /// probes, evaluates the condition, and on success adds the probe
+    /// results to the firing's context under `probes`. A false condition
+    /// turns the firing into a skip.
+    async fn apply_trigger_condition(
+        &self,
+        schedule: &Schedule,
+        expression: &str,
+        poll_result: TriggerResult,
+    ) -> Result<TriggerResult, TriggerError> {
+        let trigger_name = schedule.trigger_name.as_deref().unwrap_or("unknown");
+        let probes = parse_trigger_probes(schedule.trigger_probes.as_deref())?;
+        let results = tokio::time::timeout(
+            self.config.trigger_poll_timeout,
+            run_probes(&self.dal, &probes),
+        )
+        .await
+        .map_err(|_| TriggerError::PollError {
+            message: format!(
+                "Trigger '{}' probes timed out after {:?}",
+                trigger_name, self.config.trigger_poll_timeout
+            ),
+        })?;
+
+        let program = self
+            .cached_program(schedule.id, expression)
+            .map_err(|message| TriggerError::InvalidCondition { message })?;
+        let mut context = poll_result.into_context().unwrap_or_default();
+        let holds = eval_trigger_condition(&program, trigger_name, &context, &results)
+            .map_err(|message| TriggerError::InvalidCondition { message })?;
+        if !holds {
+            debug!("Trigger '{}' condition is false", trigger_name);
+            return Ok(TriggerResult::Skip);
+        }
+
+        if !results.is_empty() {
+            let _ = context.remove(PROBES_KEY);
+            context
+                .insert(PROBES_KEY, serde_json::Value::Object(results))
+                .map_err(crate::error::ContextError::from)?;
+        }
+        Ok(TriggerResult::Fire(Some(context)))
+    }
+
+    /// Decides whether a firing with `context_hash` duplicates an earlier one.
+    ///
+    /// An in-flight execution with the same hash suppresses the firing unless
+    /// the schedule allows concurrency; a configured dedup window additionally
+    /// suppresses it when any firing with the same hash started within the
+    /// window. Last, a minimum fire interval suppresses any firing sooner
+    /// than that after the previous fire. Returns the reason and the firing
+    /// it duplicates (the previous fire, when rate limited).
+    async fn find_duplicate(
+        &self,
+        schedule: &Schedule,
+        context_hash: &str,
+    ) -> Result<Option<(SuppressionReason, UniversalUuid)>, TriggerError> {
+        if !schedule.allows_concurrent() {
+            let active = self
+                .dal
+                .schedule_execution()
+                .find_active_by_hash(schedule.id, context_hash)
+                .await
+                .map_err(|e| TriggerError::ConnectionPool(e.to_string()))?;
+            if let Some(active) = active {
+                return Ok(Some((SuppressionReason::Active, active.id)));
+            }
+        }
+
+        if let Some(window) = schedule.dedup_window() {
+            let since = chrono::Duration::from_std(window)
+                .ok()
+                .and_then(|window| Utc::now().checked_sub_signed(window))
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            let recent = self
+                .dal
+                .schedule_execution()
+                .find_recent_by_hash(schedule.id, context_hash, since)
+                .await
+                .map_err(|e| TriggerError::ConnectionPool(e.to_string()))?;
+            if let Some(recent) = recent {
+                return Ok(Some((SuppressionReason::Window, recent.id)));
+            }
+        }
+
+        if let Some(min_interval) = schedule.min_fire_interval() {
+            let since = chrono::Duration::from_std(min_interval)
+                .ok()
+                .and_then(|interval| Utc::now().checked_sub_signed(interval))
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            let last = self
+                .dal
+                .schedule_execution()
+                .find_last_fired_since(schedule.id, since)
+                .await
+                .map_err(|e| TriggerError::ConnectionPool(e.to_string()))?;
+            if let Some(last) = last {
+                return Ok(Some((SuppressionReason::RateLimited, last.id)));
+            }
+        }
+
+        Ok(None)
     }
 
     /// Creates an audit record for a trigger execution.
@@ -924,10 +1507,24 @@ impl Scheduler {
     ) -> Result<UniversalUuid, WorkflowExecutionError> {
         let trigger_name = schedule.trigger_name.as_deref().unwrap_or("unknown");
 
+        let triggered_at = Utc::now();
+
         // CLOACI-I-0116: bound instance params override same-named keys in
         // the trigger-produced payload (OQ-3); reserved keys stamped after.
+        // Only `{"$template": …}` params are rendered; the payload never is.
         if let Some(ref params_json) = schedule.params {
-            crate::workflow_instance::merge_instance_params(&mut context, params_json).map_err(
+            let meta = crate::templating::FireMetadata {
+                fire_time: triggered_at,
+                workflow_name: schedule.workflow_name.clone(),
+                schedule_id: Some(schedule.id.to_string()),
+                schedule_timezone: schedule.timezone.clone(),
+                trigger_name: Some(trigger_name.to_string()),
+            };
+            let params_json = crate::templating::render_params(params_json, &meta, &context)
+                .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                    message: format!("instance param template: {}", e),
+                })?;
+            crate::workflow_instance::merge_instance_params(&mut context, &params_json).map_err(
                 |e| WorkflowExecutionError::ExecutionFailed {
                     message: format!("instance params merge: {}", e),
                 },
@@ -940,7 +1537,7 @@ impl Scheduler {
                 message: format!("Context error: {}", e),
             })?;
         context
-            .insert("triggered_at", serde_json::json!(Utc::now().to_rfc3339()))
+            .insert("triggered_at", serde_json::json!(triggered_at.to_rfc3339()))
             .map_err(|e| WorkflowExecutionError::ExecutionFailed {
                 message: format!("Context error: {}", e),
             })?;
@@ -1264,24 +1861,33 @@ impl Scheduler {
         expr: &str,
         context: &Context<serde_json::Value>,
     ) -> Result<bool, String> {
+        let program = self.cached_program(sub_id, expr)?;
+        eval_cel_predicate_program(&program, context)
+    }
+
+    /// Compiled CEL program for `expr`, cached under a subscription or
+    /// schedule id.
+    fn cached_program(
+        &self,
+        id: UniversalUuid,
+        expr: &str,
+    ) -> Result<Arc<cel_interpreter::Program>, String> {
         // Cache lookup. Re-compile only when the stored expression
         // string doesn't match — handles "subscriber upserted with a
-        // new `when=`" without an explicit invalidation API.
-        let program = {
-            let mut cache = self.predicate_cache.lock();
-            match cache.get(&sub_id) {
-                Some((cached_expr, prog)) if cached_expr == expr => prog.clone(),
-                _ => {
-                    let prog = Arc::new(
-                        cel_interpreter::Program::compile(expr)
-                            .map_err(|e| format!("compile error: {}", e))?,
-                    );
-                    cache.insert(sub_id, (expr.to_string(), prog.clone()));
-                    prog
-                }
+        // new `when=`" (or a changed trigger condition) without an
+        // explicit invalidation API.
+        let mut cache = self.predicate_cache.lock();
+        match cache.get(&id) {
+            Some((cached_expr, prog)) if cached_expr == expr => Ok(prog.clone()),
+            _ => {
+                let prog = Arc::new(
+                    cel_interpreter::Program::compile(expr)
+                        .map_err(|e| format!("compile error: {}", e))?,
+                );
+                cache.insert(id, (expr.to_string(), prog.clone()));
+                Ok(prog)
             }
-        };
-        eval_cel_predicate_program(&program, context)
+        }
     }
 
     /// TTL prune of `reactor_firings` (CLOACI-I-0100 / T-0601).
@@ -1411,6 +2017,145 @@ fn eval_cel_predicate_program(
     }
 }
 
+/// What a cron schedule fires on: its cron expression, or its interval for
+/// interval schedules.
+enum FireTimes {
+    Cron(Box<CronEvaluator>),
+    Interval(IntervalSchedule),
+}
+
+impl FireTimes {
+    fn for_schedule(schedule: &Schedule) -> Result<Self, WorkflowExecutionError> {
+        if let Some(interval) = schedule.interval_schedule() {
+            return Ok(FireTimes::Interval(interval));
+        }
+        let cron_expr = schedule.cron_expression.as_deref().unwrap_or("* * * * *");
+        let tz = schedule.timezone.as_deref().unwrap_or("UTC");
+        CronEvaluator::new(cron_expr, tz)
+            .map(|evaluator| {
+                FireTimes::Cron(Box::new(evaluator.with_dst_policy(schedule.dst_policy())))
+            })
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Cron evaluation error: {}", e),
+            })
+    }
+
+    fn next_execution(&self, after: DateTime<Utc>) -> Result<DateTime<Utc>, CronError> {
+        match self {
+            FireTimes::Cron(evaluator) => evaluator.next_execution(after),
+            FireTimes::Interval(interval) => Ok(interval.next_execution(after)),
+        }
+    }
+
+    fn executions_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        max_executions: usize,
+    ) -> Result<Vec<DateTime<Utc>>, CronError> {
+        match self {
+            FireTimes::Cron(evaluator) => evaluator.executions_between(start, end, max_executions),
+            FireTimes::Interval(interval) => {
+                Ok(interval.executions_between(start, end, max_executions))
+            }
+        }
+    }
+}
+
+/// A copy of a fired context, to hand back to its trigger if the firing is
+/// dropped.
+fn fired_copy(result: &TriggerResult) -> Option<Context<serde_json::Value>> {
+    match result {
+        TriggerResult::Fire(Some(ctx)) => Some(ctx.clone_data()),
+        _ => None,
+    }
+}
+
+/// Hands fired contexts that will not start an execution back to `trigger`
+/// (see [`Trigger::discard`]).
+async fn discard_firings(
+    trigger: Option<&Arc<dyn Trigger>>,
+    contexts: impl IntoIterator<Item = Context<serde_json::Value>>,
+) {
+    if let Some(trigger) = trigger {
+        for context in contexts {
+            trigger.discard(&context).await;
+        }
+    }
+}
+
+/// The most recent fire of `evaluator` at or before `now`, or `due` (the
+/// schedule's due fire) when no later fire has passed yet. Looks back from
+/// `now` in growing windows, so a long outage of a frequent schedule does not
+/// walk every fire it missed.
+fn latest_due_fire(
+    evaluator: &FireTimes,
+    due: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, WorkflowExecutionError> {
+    let cron_error = |e| WorkflowExecutionError::ExecutionFailed {
+        message: format!("Cron evaluation error: {}", e),
+    };
+    let mut lookback = chrono::Duration::minutes(1);
+    loop {
+        let from = (now - lookback).max(due);
+        let mut latest = None;
+        let mut cursor = from;
+        loop {
+            match evaluator.next_execution(cursor) {
+                Ok(next) if next <= now => {
+                    latest = Some(next);
+                    cursor = next;
+                }
+                Ok(_) | Err(CronError::NoNextExecution) => break,
+                Err(e) => return Err(cron_error(e)),
+            }
+        }
+        match latest {
+            Some(fire) => return Ok(fire),
+            None if from == due => return Ok(due),
+            None => lookback = lookback * 8,
+        }
+    }
+}
+
+/// Stamps the reserved keys of a cron fire into `context`: the fire's
+/// `scheduled_time` (the logical time, not the wall clock it ran at),
+/// `schedule_id`, `schedule_timezone`, `schedule_expression` and
+/// `is_recovery`. The planner derives `logical_date` and `run_id` from
+/// `scheduled_time`, so a recovered or late fire computes the same data
+/// window as an on-time one.
+pub(crate) fn stamp_cron_fire_keys(
+    context: &mut Context<serde_json::Value>,
+    schedule: &Schedule,
+    scheduled_time: DateTime<Utc>,
+    is_recovery: bool,
+) -> Result<(), WorkflowExecutionError> {
+    for (key, value) in [
+        (
+            "scheduled_time",
+            serde_json::json!(scheduled_time.to_rfc3339()),
+        ),
+        ("schedule_id", serde_json::json!(schedule.id.to_string())),
+        (
+            "schedule_timezone",
+            serde_json::json!(schedule.timezone.as_deref().unwrap_or("UTC")),
+        ),
+        (
+            "schedule_expression",
+            serde_json::json!(schedule.cron_expression.as_deref().unwrap_or("")),
+        ),
+        ("is_recovery", serde_json::json!(is_recovery)),
+    ] {
+        context
+            .insert(key, value)
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Context error: {}", e),
+            })?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1440,6 +2185,22 @@ mod tests {
             paused_at: None,
             params: None,
             instance_name: None,
+            tags: None,
+            dedup_window_secs: None,
+            dedup_keys: None,
+            lease_owner: None,
+            lease_expires_at: None,
+            fire_offset_secs: None,
+            fire_jitter_secs: None,
+            interval_secs: None,
+            interval_anchor: None,
+            min_fire_interval_ms: None,
+            debounce_window_ms: None,
+            trigger_condition: None,
+            trigger_probes: None,
+            paused_by: None,
+            pause_reason: None,
+            dst_policy: None,
         }
     }
 
@@ -1467,9 +2228,61 @@ mod tests {
             paused_at: None,
             params: None,
             instance_name: None,
+            tags: None,
+            dedup_window_secs: None,
+            dedup_keys: None,
+            lease_owner: None,
+            lease_expires_at: None,
+            fire_offset_secs: None,
+            fire_jitter_secs: None,
+            interval_secs: None,
+            interval_anchor: None,
+            min_fire_interval_ms: None,
+            debounce_window_ms: None,
+            trigger_condition: None,
+            trigger_probes: None,
+            paused_by: None,
+            pause_reason: None,
+            dst_policy: None,
         }
     }
 
+    #[test]
+    fn test_stamp_cron_fire_keys() {
+        let schedule = create_test_cron_schedule("0 9 * * *", "Europe/London");
+        let fire = DateTime::parse_from_rfc3339("2026-03-01T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut context = Context::new();
+        stamp_cron_fire_keys(&mut context, &schedule, fire, false).unwrap();
+        assert_eq!(
+            context.get("scheduled_time"),
+            Some(&serde_json::json!("2026-03-01T09:00:00+00:00"))
+        );
+        assert_eq!(
+            context.get("schedule_id"),
+            Some(&serde_json::json!(schedule.id.to_string()))
+        );
+        assert_eq!(
+            context.get("schedule_timezone"),
+            Some(&serde_json::json!("Europe/London"))
+        );
+        assert_eq!(
+            context.get("schedule_expression"),
+            Some(&serde_json::json!("0 9 * * *"))
+        );
+        assert_eq!(context.get("is_recovery"), Some(&serde_json::json!(false)));
+
+        let mut recovered = Context::new();
+        stamp_cron_fire_keys(&mut recovered, &schedule, fire, true).unwrap();
+        assert_eq!(recovered.get("is_recovery"), Some(&serde_json::json!(true)));
+        assert_eq!(
+            recovered.get("scheduled_time"),
+            context.get("scheduled_time")
+        );
+    }
+
     #[test]
     fn test_scheduler_config_default() {
         let config = SchedulerConfig::default();
@@ -1488,6 +2301,7 @@ mod tests {
             config.reactor_firings_retention,
             Duration::from_secs(7 * 86_400)
         );
+        assert_eq!(config.trigger_lease_ttl, None);
     }
 
     #[test]
@@ -1547,6 +2361,161 @@ mod tests {
         assert_eq!(policy, CatchupPolicy::Skip);
     }
 
+    fn at(ts: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(ts)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_latest_due_fire() {
+        let hourly = FireTimes::Cron(Box::new(CronEvaluator::new("0 * * * *", "UTC").unwrap()));
+        // Nothing missed: the due fire itself
+        assert_eq!(
+            latest_due_fire(
+                &hourly,
+                at("2026-03-01T09:00:00Z"),
+                at("2026-03-01T09:00:10Z")
+            )
+            .unwrap(),
+            at("2026-03-01T09:00:00Z")
+        );
+        // Down for two days: the most recent hour
+        assert_eq!(
+            latest_due_fire(
+                &hourly,
+                at("2026-03-01T09:00:00Z"),
+                at("2026-03-03T14:20:00Z")
+            )
+            .unwrap(),
+            at("2026-03-03T14:00:00Z")
+        );
+
+        let every_second =
+            FireTimes::Cron(Box::new(CronEvaluator::new("* * * * * *", "UTC").unwrap()));
+        assert_eq!(
+            latest_due_fire(
+                &every_second,
+                at("2026-01-01T00:00:00Z"),
+                at("2026-03-01T09:00:00Z")
+            )
+            .unwrap(),
+            at("2026-03-01T09:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_calculate_execution_times_per_policy() {
+        let config = SchedulerConfig::default();
+        let mut schedule = create_test_cron_schedule("0 * * * *", "UTC");
+        schedule.next_run_at = Some(UniversalTimestamp(at("2026-03-01T09:00:00Z")));
+        schedule.last_run_at = Some(UniversalTimestamp(at("2026-03-01T08:00:00Z")));
+        let on_time = at("2026-03-01T09:00:05Z");
+        let after_outage = at("2026-03-01T12:30:00Z");
+
+        for policy in ["skip", "run_latest_only", "run_all"] {
+            schedule.catchup_policy = Some(policy.to_string());
+            assert_eq!(
+                Scheduler::calculate_execution_times(&config, &schedule, on_time).unwrap(),
+                vec![at("2026-03-01T09:00:00Z")],
+                "{} on time",
+                policy
+            );
+        }
+
+        schedule.catchup_policy = Some("skip".to_string());
+        assert!(
+            Scheduler::calculate_execution_times(&config, &schedule, after_outage)
+                .unwrap()
+                .is_empty()
+        );
+
+        schedule.catchup_policy = Some("run_latest_only".to_string());
+        assert_eq!(
+            Scheduler::calculate_execution_times(&config, &schedule, after_outage).unwrap(),
+            vec![at("2026-03-01T12:00:00Z")]
+        );
+
+        schedule.catchup_policy = Some("run_all".to_string());
+        assert_eq!(
+            Scheduler::calculate_execution_times(&config, &schedule, after_outage).unwrap(),
+            vec![
+                at("2026-03-01T09:00:00Z"),
+                at("2026-03-01T10:00:00Z"),
+                at("2026-03-01T11:00:00Z"),
+                at("2026-03-01T12:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calculate_execution_times_with_fire_delay() {
+        let config = SchedulerConfig::default();
+        let mut schedule = create_test_cron_schedule("0 * * * *", "UTC");
+        schedule.fire_offset_secs = Some(90);
+        schedule.next_run_at = Some(UniversalTimestamp(at("2026-03-01T09:01:30Z")));
+        schedule.last_run_at = Some(UniversalTimestamp(at("2026-03-01T08:01:30Z")));
+
+        // The delayed run still reports its cron time as the scheduled time.
+        for policy in ["skip", "run_latest_only", "run_all"] {
+            schedule.catchup_policy = Some(policy.to_string());
+            assert_eq!(
+                Scheduler::calculate_execution_times(
+                    &config,
+                    &schedule,
+                    at("2026-03-01T09:01:31Z")
+                )
+                .unwrap(),
+                vec![at("2026-03-01T09:00:00Z")],
+                "{} on time",
+                policy
+            );
+        }
+
+        schedule.catchup_policy = Some("run_all".to_string());
+        assert_eq!(
+            Scheduler::calculate_execution_times(&config, &schedule, at("2026-03-01T11:01:00Z"))
+                .unwrap(),
+            vec![at("2026-03-01T09:00:00Z"), at("2026-03-01T10:00:00Z")]
+        );
+    }
+
+    #[test]
+    fn test_calculate_execution_times_for_interval() {
+        let config = SchedulerConfig::default();
+        let mut schedule = create_test_cron_schedule("0 * * * *", "UTC");
+        schedule.cron_expression = None;
+        schedule.interval_secs = Some(90);
+        schedule.interval_anchor = Some(UniversalTimestamp(at("2026-03-01T09:00:00Z")));
+        schedule.next_run_at = Some(UniversalTimestamp(at("2026-03-01T09:01:30Z")));
+        schedule.last_run_at = Some(UniversalTimestamp(at("2026-03-01T09:00:00Z")));
+        assert!(schedule.is_interval());
+
+        assert_eq!(
+            Scheduler::calculate_execution_times(&config, &schedule, at("2026-03-01T09:01:31Z"))
+                .unwrap(),
+            vec![at("2026-03-01T09:01:30Z")]
+        );
+
+        // Catchup walks the interval.
+        schedule.catchup_policy = Some("run_all".to_string());
+        assert_eq!(
+            Scheduler::calculate_execution_times(&config, &schedule, at("2026-03-01T09:05:00Z"))
+                .unwrap(),
+            vec![
+                at("2026-03-01T09:01:30Z"),
+                at("2026-03-01T09:03:00Z"),
+                at("2026-03-01T09:04:30Z")
+            ]
+        );
+        schedule.catchup_policy = Some("run_latest_only".to_string());
+        assert_eq!(
+            Scheduler::calculate_execution_times(&config, &schedule, at("2026-03-01T09:05:00Z"))
+                .unwrap(),
+            vec![at("2026-03-01T09:04:30Z")]
+        );
+    }
+
     #[test]
     fn test_catchup_policy_run_all() {
         let mut schedule = create_test_cron_schedule("0 * * * *", "UTC");
@@ -1597,6 +2566,7 @@ mod tests {
             reactor_poll_batch_limit: 25,
             reactor_firings_prune_interval: Duration::from_secs(120),
             reactor_firings_retention: Duration::from_secs(86_400),
+            trigger_lease_ttl: Some(Duration::from_secs(15)),
         };
         assert_eq!(config.cron_poll_interval, Duration::from_secs(60));
         assert_eq!(config.max_catchup_executions, 50);
@@ -1613,6 +2583,7 @@ mod tests {
             config.reactor_firings_retention,
             Duration::from_secs(86_400)
         );
+        assert_eq!(config.trigger_lease_ttl, Some(Duration::from_secs(15)));
     }
 
     #[test]
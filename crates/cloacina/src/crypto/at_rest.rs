@@ -0,0 +1,575 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Envelope encryption for data at rest.
+//!
+//! Execution contexts and registry package archives are written through an
+//! [`AtRestEncryption`] when one is attached to the
+//! [`Database`](crate::Database). Each record is sealed with AES-256-GCM under
+//! a data key (DEK); the DEK is wrapped by a key-encryption key (KEK) held by
+//! a [`KeyProvider`] and stored, wrapped, in the record header:
+//!
+//! ```text
+//! "CLAR" | 0x01 | kek_id_len (u8) | kek_id | wrapped_len (u16 BE) | wrapped DEK | nonce (12) | ciphertext || tag
+//! ```
+//!
+//! The header and a caller-supplied context (the row id, and chunk index for
+//! archives) are authenticated as associated data, so a record cannot be
+//! swapped into another row undetected.
+//!
+//! A DEK is reused for up to [`DATA_KEY_MAX_RECORDS`] records and unwrapped
+//! DEKs are cached, so a remote KMS is called once per data key rather than
+//! once per row. Rotating the KEK (a provider whose
+//! [`current_key_id`](KeyProvider::current_key_id) changes) starts a new DEK
+//! on the next write; records under older KEKs stay readable while the
+//! provider still knows those keys, and
+//! [`ContextDAL::reencrypt`](crate::dal::ContextDAL::reencrypt) /
+//! [`UnifiedRegistryStorage::reencrypt`](crate::dal::UnifiedRegistryStorage::reencrypt)
+//! rewrite them under the current one. Rows written before encryption was
+//! enabled are read as plaintext and encrypted by the same pass.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+use async_trait::async_trait;
+use base64::Engine as _;
+use rand::RngCore;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use super::{decrypt_bytes, encrypt_bytes};
+
+/// Environment variable read by [`StaticKeyProvider::from_env`]:
+/// comma-separated `id:key` pairs, current key first.
+pub const AT_REST_KEYS_ENV_VAR: &str = "CLOACINA_AT_REST_KEYS";
+
+/// Prefix of an encrypted record stored in a text column (contexts).
+pub const ENCRYPTED_TEXT_PREFIX: &str = "enc:v1:";
+
+/// Records sealed under one data key before a fresh one is generated. Well
+/// below the 2^32 random-nonce limit for AES-GCM.
+pub const DATA_KEY_MAX_RECORDS: u64 = 1 << 20;
+
+const MAGIC: &[u8; 4] = b"CLAR";
+const FORMAT_VERSION: u8 = 1;
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+/// Unwrapped data keys kept in memory.
+const DEK_CACHE_CAPACITY: usize = 1024;
+
+/// Errors from at-rest encryption.
+#[derive(Debug, Error)]
+pub enum AtRestError {
+    #[error("unknown key-encryption key '{0}'")]
+    UnknownKey(String),
+
+    #[error("key provider error: {0}")]
+    Provider(String),
+
+    #[error("invalid at-rest key configuration: {0}")]
+    Config(String),
+
+    #[error("malformed encrypted record: {0}")]
+    Malformed(&'static str),
+
+    #[error("encryption failed: {0}")]
+    Encrypt(String),
+
+    #[error("decryption failed: wrong key or tampered record")]
+    Decrypt,
+}
+
+/// A data key wrapped by a provider's KEK.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedKey {
+    pub kek_id: String,
+    pub wrapped: Vec<u8>,
+}
+
+/// Holds the key-encryption keys that wrap data keys.
+///
+/// Implementations may call out to a KMS; [`AtRestEncryption`] caches
+/// unwrapped keys so `unwrap_key` is not on the per-row path.
+#[async_trait]
+pub trait KeyProvider: Send + Sync {
+    /// KEK new data keys are wrapped with.
+    fn current_key_id(&self) -> String;
+
+    /// Wrap `dek` with the current KEK.
+    async fn wrap_key(&self, dek: &[u8]) -> Result<WrappedKey, AtRestError>;
+
+    /// Unwrap a data key wrapped by KEK `kek_id`.
+    async fn unwrap_key(&self, kek_id: &str, wrapped: &[u8]) -> Result<Vec<u8>, AtRestError>;
+}
+
+/// A [`KeyProvider`] holding KEKs in memory: the current key plus retired
+/// keys kept for reading older records.
+#[derive(Clone)]
+pub struct StaticKeyProvider {
+    current: String,
+    keys: HashMap<String, Vec<u8>>,
+}
+
+impl std::fmt::Debug for StaticKeyProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ids: Vec<&String> = self.keys.keys().collect();
+        ids.sort();
+        f.debug_struct("StaticKeyProvider")
+            .field("current", &self.current)
+            .field("key_ids", &ids)
+            .finish()
+    }
+}
+
+impl StaticKeyProvider {
+    /// A provider whose current KEK is `key` (32 bytes), named `key_id`.
+    pub fn new(key_id: impl Into<String>, key: Vec<u8>) -> Result<Self, AtRestError> {
+        let key_id = key_id.into();
+        let provider = Self {
+            current: key_id.clone(),
+            keys: HashMap::new(),
+        };
+        provider.with_retired_key(key_id, key)
+    }
+
+    /// Add a KEK that only unwraps: records sealed under it stay readable
+    /// until they are re-encrypted.
+    pub fn with_retired_key(
+        mut self,
+        key_id: impl Into<String>,
+        key: Vec<u8>,
+    ) -> Result<Self, AtRestError> {
+        let key_id = key_id.into();
+        if key_id.is_empty() || key_id.len() > u8::MAX as usize {
+            return Err(AtRestError::Config(format!(
+                "key id must be 1-255 bytes, got '{}'",
+                key_id
+            )));
+        }
+        if key.len() != KEY_LEN {
+            return Err(AtRestError::Config(format!(
+                "key '{}' must be 32 bytes, got {}",
+                key_id,
+                key.len()
+            )));
+        }
+        self.keys.insert(key_id, key);
+        Ok(self)
+    }
+
+    /// Parse `id:key[,id:key…]`; the first entry is the current KEK. Keys are
+    /// base64 or hex encodings of 32 bytes.
+    pub fn parse(spec: &str) -> Result<Self, AtRestError> {
+        let mut provider: Option<Self> = None;
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (id, raw) = entry.split_once(':').ok_or_else(|| {
+                AtRestError::Config("expected id:key, got an entry without ':'".to_string())
+            })?;
+            let key = parse_key(raw).ok_or_else(|| {
+                AtRestError::Config(format!(
+                    "key '{}' must be base64 or hex encoding of exactly 32 bytes",
+                    id
+                ))
+            })?;
+            provider = Some(match provider {
+                None => Self::new(id.trim(), key)?,
+                Some(p) => p.with_retired_key(id.trim(), key)?,
+            });
+        }
+        provider.ok_or_else(|| AtRestError::Config("no keys given".to_string()))
+    }
+
+    /// Read keys from `CLOACINA_AT_REST_KEYS`. `Ok(None)` when unset.
+    pub fn from_env() -> Result<Option<Self>, AtRestError> {
+        match std::env::var(AT_REST_KEYS_ENV_VAR) {
+            Err(_) => Ok(None),
+            Ok(spec) => Self::parse(&spec).map(Some),
+        }
+    }
+}
+
+fn parse_key(raw: &str) -> Option<Vec<u8>> {
+    let raw = raw.trim();
+    base64::engine::general_purpose::STANDARD
+        .decode(raw)
+        .ok()
+        .filter(|k| k.len() == KEY_LEN)
+        .or_else(|| hex::decode(raw).ok().filter(|k| k.len() == KEY_LEN))
+}
+
+#[async_trait]
+impl KeyProvider for StaticKeyProvider {
+    fn current_key_id(&self) -> String {
+        self.current.clone()
+    }
+
+    async fn wrap_key(&self, dek: &[u8]) -> Result<WrappedKey, AtRestError> {
+        let kek = &self.keys[&self.current];
+        let wrapped = encrypt_bytes(dek, kek).map_err(|e| AtRestError::Encrypt(e.to_string()))?;
+        Ok(WrappedKey {
+            kek_id: self.current.clone(),
+            wrapped,
+        })
+    }
+
+    async fn unwrap_key(&self, kek_id: &str, wrapped: &[u8]) -> Result<Vec<u8>, AtRestError> {
+        let kek = self
+            .keys
+            .get(kek_id)
+            .ok_or_else(|| AtRestError::UnknownKey(kek_id.to_string()))?;
+        decrypt_bytes(wrapped, kek).map_err(|_| AtRestError::Decrypt)
+    }
+}
+
+/// The data key new records are sealed with.
+struct ActiveDataKey {
+    key: [u8; KEY_LEN],
+    wrapped: WrappedKey,
+    records: u64,
+}
+
+/// Unwrapped data keys by `(kek_id, wrapped DEK)`.
+type UnwrappedKeys = HashMap<(String, Vec<u8>), [u8; KEY_LEN]>;
+
+/// Seals and opens at-rest records. See the [module docs](self).
+pub struct AtRestEncryption {
+    provider: Arc<dyn KeyProvider>,
+    active: Mutex<Option<ActiveDataKey>>,
+    unwrapped: StdMutex<UnwrappedKeys>,
+}
+
+impl std::fmt::Debug for AtRestEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AtRestEncryption")
+            .field("current_key_id", &self.provider.current_key_id())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Header fields of a sealed record.
+struct RecordHeader<'a> {
+    kek_id: &'a str,
+    wrapped: &'a [u8],
+    /// Length of the header, i.e. the offset of the nonce.
+    len: usize,
+}
+
+fn parse_header(record: &[u8]) -> Result<RecordHeader<'_>, AtRestError> {
+    if !AtRestEncryption::is_encrypted(record) {
+        return Err(AtRestError::Malformed("missing header"));
+    }
+    let mut at = MAGIC.len() + 1;
+    let id_len = *record.get(at).ok_or(AtRestError::Malformed("truncated"))? as usize;
+    at += 1;
+    let kek_id = record
+        .get(at..at + id_len)
+        .ok_or(AtRestError::Malformed("truncated key id"))?;
+    let kek_id = std::str::from_utf8(kek_id).map_err(|_| AtRestError::Malformed("key id"))?;
+    at += id_len;
+    let wrapped_len = record
+        .get(at..at + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+        .ok_or(AtRestError::Malformed("truncated"))?;
+    at += 2;
+    let wrapped = record
+        .get(at..at + wrapped_len)
+        .ok_or(AtRestError::Malformed("truncated data key"))?;
+    at += wrapped_len;
+    if record.len() < at + NONCE_LEN + 16 {
+        return Err(AtRestError::Malformed("truncated ciphertext"));
+    }
+    Ok(RecordHeader {
+        kek_id,
+        wrapped,
+        len: at,
+    })
+}
+
+impl AtRestEncryption {
+    pub fn new(provider: Arc<dyn KeyProvider>) -> Self {
+        Self {
+            provider,
+            active: Mutex::new(None),
+            unwrapped: StdMutex::new(HashMap::new()),
+        }
+    }
+
+    /// Encryption with a [`StaticKeyProvider`] from `CLOACINA_AT_REST_KEYS`,
+    /// or `None` when it is unset.
+    pub fn from_env() -> Result<Option<Arc<Self>>, AtRestError> {
+        Ok(StaticKeyProvider::from_env()?.map(|p| Arc::new(Self::new(Arc::new(p)))))
+    }
+
+    /// Id of the KEK new records are sealed under.
+    pub fn current_key_id(&self) -> String {
+        self.provider.current_key_id()
+    }
+
+    /// Whether `data` is a sealed record rather than legacy plaintext.
+    pub fn is_encrypted(data: &[u8]) -> bool {
+        data.len() > MAGIC.len() && data.starts_with(MAGIC) && data[MAGIC.len()] == FORMAT_VERSION
+    }
+
+    /// Whether a stored record should be rewritten: it is plaintext, or was
+    /// sealed under a KEK other than the current one.
+    pub fn needs_reencrypt(&self, data: &[u8]) -> bool {
+        match parse_header(data) {
+            Ok(header) => header.kek_id != self.provider.current_key_id(),
+            Err(_) => true,
+        }
+    }
+
+    /// Text-column form of [`needs_reencrypt`](Self::needs_reencrypt).
+    pub fn text_needs_reencrypt(&self, value: &str) -> bool {
+        match value.strip_prefix(ENCRYPTED_TEXT_PREFIX) {
+            Some(encoded) => base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map(|record| self.needs_reencrypt(&record))
+                .unwrap_or(true),
+            None => true,
+        }
+    }
+
+    /// The data key to seal the next record with, rotating it when the
+    /// provider's KEK changed or the key has sealed enough records.
+    async fn data_key(&self) -> Result<([u8; KEY_LEN], WrappedKey), AtRestError> {
+        let mut active = self.active.lock().await;
+        let current = self.provider.current_key_id();
+        let stale = match active.as_ref() {
+            Some(key) => key.wrapped.kek_id != current || key.records >= DATA_KEY_MAX_RECORDS,
+            None => true,
+        };
+        if stale {
+            let mut key = [0u8; KEY_LEN];
+            rand::thread_rng().fill_bytes(&mut key);
+            let wrapped = self.provider.wrap_key(&key).await?;
+            *active = Some(ActiveDataKey {
+                key,
+                wrapped,
+                records: 0,
+            });
+        }
+        let active = active.as_mut().expect("data key was just set");
+        active.records += 1;
+        Ok((active.key, active.wrapped.clone()))
+    }
+
+    async fn unwrap(&self, kek_id: &str, wrapped: &[u8]) -> Result<[u8; KEY_LEN], AtRestError> {
+        let cache_key = (kek_id.to_string(), wrapped.to_vec());
+        if let Some(key) = self.unwrapped.lock().unwrap().get(&cache_key) {
+            return Ok(*key);
+        }
+        let key: [u8; KEY_LEN] = self
+            .provider
+            .unwrap_key(kek_id, wrapped)
+            .await?
+            .try_into()
+            .map_err(|_| AtRestError::Malformed("data key length"))?;
+        let mut cache = self.unwrapped.lock().unwrap();
+        if cache.len() >= DEK_CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(cache_key, key);
+        Ok(key)
+    }
+
+    /// Seal `plaintext`, binding it to `aad` (e.g. the row id).
+    #[allow(deprecated)]
+    pub async fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, AtRestError> {
+        let (key, wrapped) = self.data_key().await?;
+        let wrapped_len = u16::try_from(wrapped.wrapped.len())
+            .map_err(|_| AtRestError::Encrypt("wrapped data key too long".to_string()))?;
+        let id_len = u8::try_from(wrapped.kek_id.len())
+            .map_err(|_| AtRestError::Encrypt("key id too long".to_string()))?;
+
+        let mut record = Vec::with_capacity(
+            MAGIC.len()
+                + 4
+                + wrapped.kek_id.len()
+                + wrapped.wrapped.len()
+                + NONCE_LEN
+                + 16
+                + plaintext.len(),
+        );
+        record.extend_from_slice(MAGIC);
+        record.push(FORMAT_VERSION);
+        record.push(id_len);
+        record.extend_from_slice(wrapped.kek_id.as_bytes());
+        record.extend_from_slice(&wrapped_len.to_be_bytes());
+        record.extend_from_slice(&wrapped.wrapped);
+        let header_len = record.len();
+
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let associated = [&record[..header_len], aad].concat();
+        let cipher =
+            Aes256Gcm::new_from_slice(&key).map_err(|e| AtRestError::Encrypt(e.to_string()))?;
+        let ciphertext = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext,
+                    aad: &associated,
+                },
+            )
+            .map_err(|e| AtRestError::Encrypt(e.to_string()))?;
+        record.extend_from_slice(&nonce);
+        record.extend_from_slice(&ciphertext);
+        Ok(record)
+    }
+
+    /// Open a record sealed by [`encrypt`](Self::encrypt) with the same
+    /// `aad`. Data without a record header is returned unchanged (rows
+    /// written before encryption was enabled).
+    #[allow(deprecated)]
+    pub async fn decrypt(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, AtRestError> {
+        if !Self::is_encrypted(data) {
+            return Ok(data.to_vec());
+        }
+        let header = parse_header(data)?;
+        let key = self.unwrap(header.kek_id, header.wrapped).await?;
+        let nonce = &data[header.len..header.len + NONCE_LEN];
+        let associated = [&data[..header.len], aad].concat();
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| AtRestError::Decrypt)?;
+        cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: &data[header.len + NONCE_LEN..],
+                    aad: &associated,
+                },
+            )
+            .map_err(|_| AtRestError::Decrypt)
+    }
+
+    /// [`encrypt`](Self::encrypt) for a text column: the record, base64
+    /// encoded, behind [`ENCRYPTED_TEXT_PREFIX`].
+    pub async fn encrypt_text(&self, plaintext: &str, aad: &[u8]) -> Result<String, AtRestError> {
+        let record = self.encrypt(plaintext.as_bytes(), aad).await?;
+        Ok(format!(
+            "{}{}",
+            ENCRYPTED_TEXT_PREFIX,
+            base64::engine::general_purpose::STANDARD.encode(record)
+        ))
+    }
+
+    /// Open a value written by [`encrypt_text`](Self::encrypt_text);
+    /// unprefixed values are returned unchanged.
+    pub async fn decrypt_text(&self, value: String, aad: &[u8]) -> Result<String, AtRestError> {
+        let Some(encoded) = value.strip_prefix(ENCRYPTED_TEXT_PREFIX) else {
+            return Ok(value);
+        };
+        let record = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|_| AtRestError::Malformed("base64"))?;
+        let plaintext = self.decrypt(&record, aad).await?;
+        String::from_utf8(plaintext).map_err(|_| AtRestError::Malformed("utf-8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider(id: &str, byte: u8) -> StaticKeyProvider {
+        StaticKeyProvider::new(id, vec![byte; 32]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn roundtrip_binds_aad_and_passes_plaintext_through() {
+        let enc = AtRestEncryption::new(Arc::new(provider("k1", 1)));
+        let sealed = enc.encrypt(b"archive bytes", b"row-1").await.unwrap();
+        assert!(AtRestEncryption::is_encrypted(&sealed));
+        assert_eq!(
+            enc.decrypt(&sealed, b"row-1").await.unwrap(),
+            b"archive bytes"
+        );
+        assert!(matches!(
+            enc.decrypt(&sealed, b"row-2").await,
+            Err(AtRestError::Decrypt)
+        ));
+        // Legacy rows.
+        assert_eq!(
+            enc.decrypt(b"PK\x03\x04", b"").await.unwrap(),
+            b"PK\x03\x04"
+        );
+
+        let text = enc.encrypt_text(r#"{"a":1}"#, b"ctx").await.unwrap();
+        assert!(text.starts_with(ENCRYPTED_TEXT_PREFIX));
+        assert_eq!(enc.decrypt_text(text, b"ctx").await.unwrap(), r#"{"a":1}"#);
+        assert_eq!(
+            enc.decrypt_text(r#"{"b":2}"#.to_string(), b"ctx")
+                .await
+                .unwrap(),
+            r#"{"b":2}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn rotation_keeps_old_records_readable_and_flags_them() {
+        let old = AtRestEncryption::new(Arc::new(provider("k1", 1)));
+        let sealed = old.encrypt(b"payload", b"").await.unwrap();
+
+        let rotated = StaticKeyProvider::new("k2", vec![2; 32])
+            .unwrap()
+            .with_retired_key("k1", vec![1; 32])
+            .unwrap();
+        let enc = AtRestEncryption::new(Arc::new(rotated));
+        assert!(enc.needs_reencrypt(&sealed));
+        assert!(enc.needs_reencrypt(b"plaintext"));
+        assert_eq!(enc.decrypt(&sealed, b"").await.unwrap(), b"payload");
+
+        let resealed = enc.encrypt(b"payload", b"").await.unwrap();
+        assert!(!enc.needs_reencrypt(&resealed));
+
+        // Without the retired key the old record is unreadable.
+        let forgotten = AtRestEncryption::new(Arc::new(provider("k2", 2)));
+        assert!(matches!(
+            forgotten.decrypt(&sealed, b"").await,
+            Err(AtRestError::UnknownKey(id)) if id == "k1"
+        ));
+    }
+
+    #[test]
+    fn parse_keyring_spec() {
+        let b64 = base64::engine::general_purpose::STANDARD.encode([7u8; 32]);
+        let hex = hex::encode([8u8; 32]);
+        let provider =
+            StaticKeyProvider::parse(&format!("2026-10:{},2026-01:{}", b64, hex)).unwrap();
+        assert_eq!(provider.current_key_id(), "2026-10");
+        assert_eq!(provider.keys.len(), 2);
+        assert!(!format!("{:?}", provider).contains(&b64));
+
+        assert!(StaticKeyProvider::parse("").is_err());
+        assert!(StaticKeyProvider::parse("k1:tooshort").is_err());
+        assert!(StaticKeyProvider::parse(&b64).is_err());
+    }
+
+    #[test]
+    fn truncated_records_are_rejected() {
+        let mut record = Vec::from(&MAGIC[..]);
+        record.push(FORMAT_VERSION);
+        record.push(10);
+        assert!(matches!(
+            parse_header(&record),
+            Err(AtRestError::Malformed(_))
+        ));
+    }
+}
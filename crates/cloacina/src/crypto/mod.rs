@@ -20,11 +20,14 @@
 //! - Ed25519 key generation and signing
 //! - AES-256-GCM encryption for private key storage at rest
 //! - Key fingerprint computation
+//! - Envelope encryption of contexts and package archives at rest
 
+pub mod at_rest;
 pub mod envelope;
 mod key_encryption;
 mod signing;
 
+pub use at_rest::{AtRestEncryption, AtRestError, KeyProvider, StaticKeyProvider, WrappedKey};
 pub use envelope::{
     generate_ephemeral_keypair, unwrap as envelope_unwrap, wrap as envelope_wrap, EnvelopeError,
     EphemeralKeypair, EphemeralPrivateKey,
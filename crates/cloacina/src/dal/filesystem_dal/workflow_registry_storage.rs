@@ -23,6 +23,7 @@
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
 use tokio::fs;
+use tokio::io::{AsyncRead, AsyncWrite};
 use uuid::Uuid;
 
 use crate::models::workflow_packages::StorageType;
@@ -213,6 +214,70 @@ impl RegistryStorage for FilesystemRegistryStorage {
         }
     }
 
+    async fn store_stream(
+        &mut self,
+        reader: &mut (dyn AsyncRead + Send + Unpin),
+    ) -> Result<String, StorageError> {
+        let id = Uuid::new_v4();
+        let file_path = self.file_path(&id.to_string());
+        let temp_path = file_path.with_extension("tmp");
+
+        // Copy into a temporary file, then move it into place atomically.
+        let copied = async {
+            let mut file = fs::File::create(&temp_path).await?;
+            tokio::io::copy(reader, &mut file).await?;
+            file.sync_all().await
+        }
+        .await;
+        if let Err(e) = copied {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(StorageError::Backend(format!(
+                "Failed to write file {}: {}",
+                file_path.display(),
+                e
+            )));
+        }
+        if let Err(e) = fs::rename(&temp_path, &file_path).await {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(StorageError::Backend(format!(
+                "Failed to move file to final location: {}",
+                e
+            )));
+        }
+        Ok(id.to_string())
+    }
+
+    async fn retrieve_to(
+        &self,
+        id: &str,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<bool, StorageError> {
+        if Uuid::parse_str(id).is_err() {
+            return Err(StorageError::InvalidId { id: id.to_string() });
+        }
+
+        let file_path = self.file_path(id);
+        let mut file = match fs::File::open(&file_path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => {
+                return Err(StorageError::Backend(format!(
+                    "Failed to read file {}: {}",
+                    file_path.display(),
+                    e
+                )))
+            }
+        };
+        tokio::io::copy(&mut file, writer).await.map_err(|e| {
+            StorageError::Backend(format!(
+                "Failed to read file {}: {}",
+                file_path.display(),
+                e
+            ))
+        })?;
+        Ok(true)
+    }
+
     async fn delete_binary(&mut self, id: &str) -> Result<(), StorageError> {
         // Validate UUID format
         if Uuid::parse_str(id).is_err() {
@@ -38,6 +38,12 @@ pub use unified::DAL;
 // Export ScheduleExecutionStats from the unified module
 pub use unified::ScheduleExecutionStats;
 
+// Export dashboard aggregates from the unified module
+pub use unified::{
+    DispatchLatencyStats, HourlyExecutionCount, QueueDepthSample, ReadyQueueStats,
+    TaskDurationStats, WorkflowFailureRate,
+};
+
 // Re-export filesystem DAL
 pub use filesystem_dal::FilesystemRegistryStorage;
 
@@ -0,0 +1,236 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Unified Audit Log DAL
+//!
+//! Append-only storage for the tamper-evident audit chain (see
+//! [`crate::security::audit_chain`]). Appends read the current head and
+//! insert the next sequence number in one transaction; the primary key on
+//! `sequence` turns a race between two writers into a retry, so the chain
+//! stays linear across server replicas.
+
+use super::models::{
+    NewUnifiedAuditAnchor, NewUnifiedAuditLogEntry, UnifiedAuditAnchor, UnifiedAuditLogEntry,
+};
+use super::DAL;
+use crate::database::schema::unified::{audit_anchors, audit_log};
+use crate::database::universal_types::UniversalTimestamp;
+use crate::error::ValidationError;
+use crate::security::audit_chain::{entry_hash, AuditAnchor, AuditEntry, GENESIS_HASH};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Attempts before an append that keeps losing the race gives up.
+const APPEND_ATTEMPTS: usize = 8;
+
+impl From<UnifiedAuditLogEntry> for AuditEntry {
+    fn from(row: UnifiedAuditLogEntry) -> Self {
+        AuditEntry {
+            sequence: row.sequence,
+            event_type: row.event_type,
+            payload: row.payload,
+            prev_hash: row.prev_hash,
+            entry_hash: row.entry_hash,
+            created_at: row.created_at.0,
+        }
+    }
+}
+
+impl From<UnifiedAuditAnchor> for AuditAnchor {
+    fn from(row: UnifiedAuditAnchor) -> Self {
+        AuditAnchor {
+            sequence: row.sequence,
+            entry_hash: row.entry_hash,
+            signature: row.signature,
+            anchored_at: row.anchored_at.0,
+        }
+    }
+}
+
+/// Data access layer for the audit chain.
+#[derive(Clone)]
+pub struct AuditLogDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> AuditLogDAL<'a> {
+    /// Creates a new AuditLogDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    /// Appends an event to the chain and returns the stored entry.
+    pub async fn append(
+        &self,
+        event_type: &str,
+        payload: &str,
+    ) -> Result<AuditEntry, ValidationError> {
+        for _ in 0..APPEND_ATTEMPTS {
+            let event_type = event_type.to_string();
+            let payload = payload.to_string();
+            // Truncated to what both backends store, so the hash survives
+            // a round trip.
+            let now = Utc::now();
+            let created_at = DateTime::from_timestamp_micros(now.timestamp_micros()).unwrap_or(now);
+
+            let appended: Option<UnifiedAuditLogEntry> =
+                crate::interact_on_backend!(self.dal, |conn| {
+                    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                        let head: Option<UnifiedAuditLogEntry> = audit_log::table
+                            .order(audit_log::sequence.desc())
+                            .first(conn)
+                            .optional()?;
+                        let (sequence, prev_hash) = match head {
+                            Some(h) => (h.sequence + 1, h.entry_hash),
+                            None => (1, GENESIS_HASH.to_string()),
+                        };
+                        let hash =
+                            entry_hash(&prev_hash, sequence, &created_at, &event_type, &payload);
+                        let row = NewUnifiedAuditLogEntry {
+                            sequence,
+                            event_type,
+                            payload,
+                            prev_hash,
+                            entry_hash: hash,
+                            created_at: UniversalTimestamp(created_at),
+                        };
+                        match diesel::insert_into(audit_log::table)
+                            .values(&row)
+                            .execute(conn)
+                        {
+                            Ok(_) => Ok(Some(UnifiedAuditLogEntry {
+                                sequence: row.sequence,
+                                event_type: row.event_type,
+                                payload: row.payload,
+                                prev_hash: row.prev_hash,
+                                entry_hash: row.entry_hash,
+                                created_at: row.created_at,
+                            })),
+                            Err(diesel::result::Error::DatabaseError(
+                                diesel::result::DatabaseErrorKind::UniqueViolation,
+                                _,
+                            )) => Ok(None),
+                            Err(e) => Err(e),
+                        }
+                    })
+                })?;
+
+            if let Some(entry) = appended {
+                return Ok(entry.into());
+            }
+        }
+        Err(ValidationError::ConnectionPool(format!(
+            "audit chain append lost the race {} times",
+            APPEND_ATTEMPTS
+        )))
+    }
+
+    /// The last entry in the chain.
+    pub async fn head(&self) -> Result<Option<AuditEntry>, ValidationError> {
+        let row: Option<UnifiedAuditLogEntry> = crate::interact_on_backend!(self.dal, |conn| {
+            audit_log::table
+                .order(audit_log::sequence.desc())
+                .first(conn)
+                .optional()
+        })?;
+        Ok(row.map(Into::into))
+    }
+
+    /// The entry at `sequence`.
+    pub async fn get(&self, sequence: i64) -> Result<Option<AuditEntry>, ValidationError> {
+        let row: Option<UnifiedAuditLogEntry> = crate::interact_on_backend!(self.dal, |conn| {
+            audit_log::table.find(sequence).first(conn).optional()
+        })?;
+        Ok(row.map(Into::into))
+    }
+
+    /// Up to `limit` entries from `from` (inclusive), optionally stopping at
+    /// `to` (inclusive), in sequence order.
+    pub async fn list(
+        &self,
+        from: i64,
+        to: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<AuditEntry>, ValidationError> {
+        let to = to.unwrap_or(i64::MAX);
+        let rows: Vec<UnifiedAuditLogEntry> = crate::interact_on_backend!(self.dal, |conn| {
+            audit_log::table
+                .filter(audit_log::sequence.ge(from))
+                .filter(audit_log::sequence.le(to))
+                .order(audit_log::sequence.asc())
+                .limit(limit)
+                .load(conn)
+        })?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Anchors at sequences `from..=to`.
+    pub async fn anchors(
+        &self,
+        from: i64,
+        to: Option<i64>,
+    ) -> Result<Vec<AuditAnchor>, ValidationError> {
+        let to = to.unwrap_or(i64::MAX);
+        let rows: Vec<UnifiedAuditAnchor> = crate::interact_on_backend!(self.dal, |conn| {
+            audit_anchors::table
+                .filter(audit_anchors::sequence.ge(from))
+                .filter(audit_anchors::sequence.le(to))
+                .order(audit_anchors::sequence.asc())
+                .load(conn)
+        })?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// The most recent anchor.
+    pub async fn latest_anchor(&self) -> Result<Option<AuditAnchor>, ValidationError> {
+        let row: Option<UnifiedAuditAnchor> = crate::interact_on_backend!(self.dal, |conn| {
+            audit_anchors::table
+                .order(audit_anchors::sequence.desc())
+                .first(conn)
+                .optional()
+        })?;
+        Ok(row.map(Into::into))
+    }
+
+    /// Records an anchor. Returns `None` when another writer already
+    /// anchored the same entry.
+    pub async fn insert_anchor(
+        &self,
+        sequence: i64,
+        entry_hash: &str,
+        signature: Option<String>,
+    ) -> Result<Option<AuditAnchor>, ValidationError> {
+        let row = NewUnifiedAuditAnchor {
+            sequence,
+            entry_hash: entry_hash.to_string(),
+            signature,
+            anchored_at: UniversalTimestamp::now(),
+        };
+        let anchor = AuditAnchor {
+            sequence: row.sequence,
+            entry_hash: row.entry_hash.clone(),
+            signature: row.signature.clone(),
+            anchored_at: row.anchored_at.0,
+        };
+        let inserted = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(audit_anchors::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(conn)
+        })?;
+        Ok((inserted > 0).then_some(anchor))
+    }
+}
@@ -22,9 +22,13 @@
 
 use super::DAL;
 use crate::context::Context;
-use crate::database::universal_types::UniversalUuid;
+use crate::context_format::ContextFormat;
+use crate::context_offload::ContextOffload;
+use crate::crypto::AtRestEncryption;
+use crate::database::universal_types::{UniversalBinary, UniversalTimestamp, UniversalUuid};
 use crate::error::ContextError;
 use diesel::prelude::*;
+use serde_json::{Map, Value};
 use tracing::warn;
 
 /// Data access layer for context operations with runtime backend selection.
@@ -41,8 +45,8 @@ impl<'a> ContextDAL<'a> {
 
     /// Create a new context in the database.
     ///
-    /// This method serializes the provided context data to JSON and stores it in the database.
-    /// Empty contexts (containing only whitespace or empty objects) are skipped.
+    /// This method stores the provided context data in the database's context format.
+    /// Empty contexts are skipped.
     ///
     /// # Arguments
     ///
@@ -63,15 +67,9 @@ impl<'a> ContextDAL<'a> {
         use crate::database::schema::unified::contexts;
         use crate::database::universal_types::UniversalTimestamp;
 
-        // Serialize the context data
-        let value = context.to_json()?;
-
-        // Skip insertion if context is empty or whitespace-only
-        let trimmed_value = value
-            .chars()
-            .filter(|c| !c.is_whitespace())
-            .collect::<String>();
-        if trimmed_value == "{}" {
+        // Skip insertion if context is empty
+        let data = context.to_map()?;
+        if data.is_empty() {
             warn!("Skipping insertion of empty context");
             return Ok(None);
         }
@@ -79,10 +77,12 @@ impl<'a> ContextDAL<'a> {
         // Generate ID and timestamps
         let id = UniversalUuid::new_v4();
         let now = UniversalTimestamp::now();
+        let (value, payload) = self.seal(data, id).await?;
 
         let new_context = NewUnifiedDbContext {
             id,
             value,
+            payload,
             created_at: now,
             updated_at: now,
         };
@@ -109,7 +109,10 @@ impl<'a> ContextDAL<'a> {
         let db_context: UnifiedDbContext =
             crate::interact_on_backend!(self.dal, |conn| contexts::table.find(id).first(conn))?;
 
-        Ok(Context::<T>::from_json(db_context.value)?)
+        let data = self
+            .open_map(db_context.value, db_context.payload.map(|p| p.0), id)
+            .await?;
+        Ok(Context::from_map(data)?)
     }
 
     /// Update an existing context in the database.
@@ -124,28 +127,455 @@ impl<'a> ContextDAL<'a> {
         use crate::database::schema::unified::contexts;
         use crate::database::universal_types::UniversalTimestamp;
 
-        let value = context.to_json()?;
+        let previous = self.stored_for_release(id).await?;
+        let (value, payload) = self.seal(context.to_map()?, id).await?;
         let now = UniversalTimestamp::now();
 
         crate::interact_on_backend!(self.dal, |conn| {
             diesel::update(contexts::table.find(id))
-                .set((contexts::value.eq(value), contexts::updated_at.eq(now)))
+                .set((
+                    contexts::value.eq(value),
+                    contexts::payload.eq(payload),
+                    contexts::updated_at.eq(now),
+                ))
                 .execute(conn)
         })?;
+        self.release(previous).await;
 
         Ok(())
     }
 
+    /// Prepare the context map for row `id`: offload its large values and
+    /// enforce the size limit when the database has context offloading,
+    /// then encode it in the database's context format (MessagePack for a
+    /// JSON database when binary values remain, see
+    /// [`ContextFormat::for_context`]) and encrypt it when it has at-rest
+    /// encryption. Returns the row's `value` and `payload`.
+    async fn seal(
+        &self,
+        data: Map<String, Value>,
+        id: UniversalUuid,
+    ) -> Result<(String, Option<UniversalBinary>), ContextError> {
+        let encryption = self.dal.database.at_rest_encryption();
+        let data = match self.dal.database.context_offload() {
+            Some(offload) => offload.offload(data, id, encryption.map(|e| &**e)).await?,
+            None => data,
+        };
+        let format = self.dal.database.context_format().for_context(&data);
+        self.encode(&data, id, format).await
+    }
+
+    /// Encode the context map of row `id` in `format` and encrypt it when
+    /// the database has at-rest encryption. JSON stays in `value`; binary
+    /// formats go to `payload`, with the format's name in `value`.
+    async fn encode(
+        &self,
+        data: &Map<String, Value>,
+        id: UniversalUuid,
+        format: ContextFormat,
+    ) -> Result<(String, Option<UniversalBinary>), ContextError> {
+        let encryption = self.dal.database.at_rest_encryption();
+        if !format.is_binary() {
+            let json = serde_json::to_string(data)?;
+            let value = match encryption {
+                Some(encryption) => encryption.encrypt_text(&json, id.0.as_bytes()).await?,
+                None => json,
+            };
+            return Ok((value, None));
+        }
+        let bytes = format.encode(data)?;
+        let bytes = match encryption {
+            Some(encryption) => encryption.encrypt(&bytes, id.0.as_bytes()).await?,
+            None => bytes,
+        };
+        Ok((format.as_str().to_string(), Some(UniversalBinary(bytes))))
+    }
+
+    /// Decrypt and decode the stored `value` and `payload` of row `id` back
+    /// to the context map, in whichever format the row was written.
+    async fn decode(
+        &self,
+        value: String,
+        payload: Option<Vec<u8>>,
+        id: UniversalUuid,
+    ) -> Result<Map<String, Value>, ContextError> {
+        let Some(bytes) = payload else {
+            return Ok(serde_json::from_str(&self.decrypt(value, id).await?)?);
+        };
+        let format: ContextFormat = value
+            .parse()
+            .map_err(|_| ContextError::UnknownFormat(value))?;
+        let bytes = if AtRestEncryption::is_encrypted(&bytes) {
+            self.encryption()?.decrypt(&bytes, id.0.as_bytes()).await?
+        } else {
+            bytes
+        };
+        format.decode(&bytes)
+    }
+
+    /// Decrypt and decode the stored value of row `id`, put back any values
+    /// offloaded from it, and return the context as JSON.
+    pub(crate) async fn open(
+        &self,
+        value: String,
+        payload: Option<Vec<u8>>,
+        id: UniversalUuid,
+    ) -> Result<String, ContextError> {
+        let data = self.open_map(value, payload, id).await?;
+        Ok(serde_json::to_string(&data)?)
+    }
+
+    /// Decrypt and decode the stored value of row `id` and put back any
+    /// values offloaded from it.
+    async fn open_map(
+        &self,
+        value: String,
+        payload: Option<Vec<u8>>,
+        id: UniversalUuid,
+    ) -> Result<Map<String, Value>, ContextError> {
+        let data = self.decode(value, payload, id).await?;
+        let encryption = self.dal.database.at_rest_encryption().map(|e| &**e);
+        match self.dal.database.context_offload() {
+            Some(offload) => offload.restore(data, id, encryption).await,
+            // Still fails clearly on a row written while offloading was on.
+            None => ContextOffload::new().restore(data, id, encryption).await,
+        }
+    }
+
+    /// The decoded stored value of row `id`, before it is overwritten or
+    /// deleted, when the database offloads context values.
+    async fn stored_for_release(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<Option<Map<String, Value>>, ContextError> {
+        use crate::database::schema::unified::contexts;
+
+        if self.dal.database.context_offload().is_none() {
+            return Ok(None);
+        }
+        let stored: Option<(String, Option<UniversalBinary>)> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                contexts::table
+                    .find(id)
+                    .select((contexts::value, contexts::payload))
+                    .first(conn)
+                    .optional()
+            })?;
+        match stored {
+            Some((value, payload)) => Ok(Some(self.decode(value, payload.map(|p| p.0), id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Delete the blobs a replaced or deleted context referred to.
+    async fn release(&self, stored: Option<Map<String, Value>>) {
+        if let (Some(offload), Some(data)) = (self.dal.database.context_offload(), stored) {
+            offload.release(&data).await;
+        }
+    }
+
+    /// Decrypt the stored value of row `id`. Plaintext rows pass through.
+    async fn decrypt(&self, value: String, id: UniversalUuid) -> Result<String, ContextError> {
+        if !value.starts_with(crate::crypto::at_rest::ENCRYPTED_TEXT_PREFIX) {
+            return Ok(value);
+        }
+        Ok(self
+            .encryption()?
+            .decrypt_text(value, id.0.as_bytes())
+            .await?)
+    }
+
+    /// The database's at-rest encryption, required to open a sealed row.
+    fn encryption(&self) -> Result<&AtRestEncryption, ContextError> {
+        Ok(self
+            .dal
+            .database
+            .at_rest_encryption()
+            .map(|e| &**e)
+            .ok_or_else(|| {
+                crate::crypto::AtRestError::Config(
+                    "context is encrypted but no at-rest keys are configured".to_string(),
+                )
+            })?)
+    }
+
+    /// Rewrite every context that is plaintext or sealed under a retired
+    /// key-encryption key under the current one, `batch_size` rows at a
+    /// time. Returns the number of rows rewritten; a no-op without at-rest
+    /// encryption. A row updated concurrently is left for the next pass.
+    pub async fn reencrypt(&self, batch_size: i64) -> Result<usize, ContextError> {
+        use crate::database::schema::unified::contexts;
+
+        let Some(encryption) = self.dal.database.at_rest_encryption().cloned() else {
+            return Ok(0);
+        };
+        let mut after = UniversalUuid(uuid::Uuid::nil());
+        let mut rewritten = 0;
+        loop {
+            let rows: Vec<(UniversalUuid, String, Option<UniversalBinary>)> =
+                crate::interact_on_backend!(self.dal, |conn| {
+                    contexts::table
+                        .filter(contexts::id.gt(after))
+                        .order(contexts::id.asc())
+                        .select((contexts::id, contexts::value, contexts::payload))
+                        .limit(batch_size)
+                        .load(conn)
+                })?;
+            let Some((last, _, _)) = rows.last() else {
+                break;
+            };
+            after = *last;
+            let full = rows.len() as i64 == batch_size;
+
+            for (id, stored, payload) in rows {
+                let updated = match payload {
+                    // Binary formats seal the payload; `value` is the format.
+                    Some(UniversalBinary(bytes)) => {
+                        if !encryption.needs_reencrypt(&bytes) {
+                            continue;
+                        }
+                        let plaintext = if AtRestEncryption::is_encrypted(&bytes) {
+                            encryption.decrypt(&bytes, id.0.as_bytes()).await?
+                        } else {
+                            bytes.clone()
+                        };
+                        let sealed = encryption.encrypt(&plaintext, id.0.as_bytes()).await?;
+                        crate::interact_on_backend!(self.dal, |conn| {
+                            diesel::update(
+                                contexts::table
+                                    .filter(contexts::id.eq(id))
+                                    .filter(contexts::payload.eq(UniversalBinary(bytes))),
+                            )
+                            .set(contexts::payload.eq(UniversalBinary(sealed)))
+                            .execute(conn)
+                        })?
+                    }
+                    None => {
+                        if !encryption.text_needs_reencrypt(&stored) {
+                            continue;
+                        }
+                        let plaintext = encryption
+                            .decrypt_text(stored.clone(), id.0.as_bytes())
+                            .await?;
+                        let sealed = encryption.encrypt_text(&plaintext, id.0.as_bytes()).await?;
+                        crate::interact_on_backend!(self.dal, |conn| {
+                            diesel::update(
+                                contexts::table
+                                    .filter(contexts::id.eq(id))
+                                    .filter(contexts::value.eq(stored)),
+                            )
+                            .set(contexts::value.eq(sealed))
+                            .execute(conn)
+                        })?
+                    }
+                };
+                rewritten += updated;
+            }
+            if !full {
+                break;
+            }
+        }
+        Ok(rewritten)
+    }
+
+    /// Rewrite every context stored in a format other than the one the
+    /// database's [`ContextFormat`] writes it in, `batch_size` rows at a
+    /// time. Under JSON that includes moving JSON rows holding base64 binary
+    /// values to MessagePack. Returns the number of rows rewritten. Offloaded
+    /// values stay where they are. A row updated concurrently is left for the
+    /// next pass.
+    pub async fn convert_format(&self, batch_size: i64) -> Result<usize, ContextError> {
+        use crate::database::schema::unified::contexts;
+
+        let format = self.dal.database.context_format();
+        let mut after = UniversalUuid(uuid::Uuid::nil());
+        let mut rewritten = 0;
+        loop {
+            let rows: Vec<(
+                UniversalUuid,
+                String,
+                Option<UniversalBinary>,
+                UniversalTimestamp,
+            )> = crate::interact_on_backend!(self.dal, |conn| {
+                contexts::table
+                    .filter(contexts::id.gt(after))
+                    .order(contexts::id.asc())
+                    .select((
+                        contexts::id,
+                        contexts::value,
+                        contexts::payload,
+                        contexts::updated_at,
+                    ))
+                    .limit(batch_size)
+                    .load(conn)
+            })?;
+            let Some((last, _, _, _)) = rows.last() else {
+                break;
+            };
+            after = *last;
+            let full = rows.len() as i64 == batch_size;
+
+            for (id, stored, payload, updated_at) in rows {
+                let stored_format = match payload {
+                    Some(_) => stored.parse().ok(),
+                    None => Some(ContextFormat::Json),
+                };
+                // Under JSON the target also depends on the row's values.
+                if stored_format == Some(format) && format.is_binary() {
+                    continue;
+                }
+                let data = self.decode(stored, payload.map(|p| p.0), id).await?;
+                let target = format.for_context(&data);
+                if stored_format == Some(target) {
+                    continue;
+                }
+                let (value, payload) = self.encode(&data, id, target).await?;
+                let updated = crate::interact_on_backend!(self.dal, |conn| {
+                    diesel::update(
+                        contexts::table
+                            .filter(contexts::id.eq(id))
+                            .filter(contexts::updated_at.eq(updated_at)),
+                    )
+                    .set((contexts::value.eq(value), contexts::payload.eq(payload)))
+                    .execute(conn)
+                })?;
+                rewritten += updated;
+            }
+            if !full {
+                break;
+            }
+        }
+        Ok(rewritten)
+    }
+
     /// Delete a context from the database.
     pub async fn delete(&self, id: UniversalUuid) -> Result<(), ContextError> {
         use crate::database::schema::unified::contexts;
 
+        let previous = self.stored_for_release(id).await?;
         crate::interact_on_backend!(self.dal, |conn| diesel::delete(contexts::table.find(id))
             .execute(conn))?;
+        self.release(previous).await;
 
         Ok(())
     }
 
+    /// Purge the context payloads of workflow executions that finished before
+    /// `cutoff`, keeping the executions themselves.
+    ///
+    /// Clears the context reference on each execution and on its tasks'
+    /// metadata, then deletes the context rows nothing references any more
+    /// and the executions' context lineage records. Status, timings, task rows and events are untouched, so the audit trail
+    /// outlives the payloads. Handles at most `limit` executions per call and
+    /// returns how many were purged; callers loop until it returns less than
+    /// `limit`.
+    pub async fn purge_for_executions_completed_before(
+        &self,
+        cutoff: UniversalTimestamp,
+        limit: i64,
+    ) -> Result<usize, ContextError> {
+        use crate::database::schema::unified::{
+            contexts, task_context_changes, task_execution_metadata, workflow_executions,
+        };
+        use diesel::connection::Connection;
+
+        // With offloading, the purged rows' blobs go too.
+        let collect_values = self.dal.database.context_offload().is_some();
+        let (purged, purged_values) = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                // Finished executions that still hold a context, either their
+                // own or one of their tasks'.
+                let executions: Vec<(UniversalUuid, Option<UniversalUuid>)> =
+                    workflow_executions::table
+                        .filter(workflow_executions::completed_at.lt(cutoff))
+                        .filter(
+                            workflow_executions::context_id.is_not_null().or(
+                                workflow_executions::id.eq_any(
+                                    task_execution_metadata::table
+                                        .filter(task_execution_metadata::context_id.is_not_null())
+                                        .select(task_execution_metadata::workflow_execution_id),
+                                ),
+                            ),
+                        )
+                        .select((workflow_executions::id, workflow_executions::context_id))
+                        .limit(limit)
+                        .load(conn)?;
+                if executions.is_empty() {
+                    return Ok((0, Vec::new()));
+                }
+
+                let execution_ids: Vec<UniversalUuid> =
+                    executions.iter().map(|(id, _)| *id).collect();
+                let mut context_ids: Vec<UniversalUuid> =
+                    executions.iter().filter_map(|(_, ctx)| *ctx).collect();
+                let task_context_ids: Vec<Option<UniversalUuid>> = task_execution_metadata::table
+                    .filter(
+                        task_execution_metadata::workflow_execution_id
+                            .eq_any(execution_ids.clone()),
+                    )
+                    .filter(task_execution_metadata::context_id.is_not_null())
+                    .select(task_execution_metadata::context_id)
+                    .load(conn)?;
+                context_ids.extend(task_context_ids.into_iter().flatten());
+
+                diesel::update(
+                    workflow_executions::table
+                        .filter(workflow_executions::id.eq_any(execution_ids.clone())),
+                )
+                .set(workflow_executions::context_id.eq(None::<UniversalUuid>))
+                .execute(conn)?;
+                diesel::update(task_execution_metadata::table.filter(
+                    task_execution_metadata::workflow_execution_id.eq_any(execution_ids.clone()),
+                ))
+                .set(task_execution_metadata::context_id.eq(None::<UniversalUuid>))
+                .execute(conn)?;
+                // Lineage records hold context values too.
+                diesel::delete(task_context_changes::table.filter(
+                    task_context_changes::workflow_execution_id.eq_any(execution_ids.clone()),
+                ))
+                .execute(conn)?;
+
+                // A context shared with a live execution stays.
+                let mut still_referenced: Vec<Option<UniversalUuid>> = workflow_executions::table
+                    .filter(workflow_executions::context_id.eq_any(context_ids.clone()))
+                    .select(workflow_executions::context_id)
+                    .load(conn)?;
+                still_referenced.extend(
+                    task_execution_metadata::table
+                        .filter(task_execution_metadata::context_id.eq_any(context_ids.clone()))
+                        .select(task_execution_metadata::context_id)
+                        .load::<Option<UniversalUuid>>(conn)?,
+                );
+                let still_referenced: std::collections::HashSet<UniversalUuid> =
+                    still_referenced.into_iter().flatten().collect();
+                context_ids.retain(|id| !still_referenced.contains(id));
+
+                let purged_values: Vec<(UniversalUuid, String, Option<UniversalBinary>)> =
+                    if collect_values {
+                        contexts::table
+                            .filter(contexts::id.eq_any(context_ids.clone()))
+                            .select((contexts::id, contexts::value, contexts::payload))
+                            .load(conn)?
+                    } else {
+                        Vec::new()
+                    };
+                diesel::delete(contexts::table.filter(contexts::id.eq_any(context_ids)))
+                    .execute(conn)?;
+
+                Ok((execution_ids.len(), purged_values))
+            })
+        })?;
+
+        for (id, value, payload) in purged_values {
+            match self.decode(value, payload.map(|p| p.0), id).await {
+                Ok(data) => self.release(Some(data)).await,
+                Err(e) => warn!("Cannot release offloaded values of context {}: {}", id, e),
+            }
+        }
+
+        Ok(purged)
+    }
+
     /// List contexts with pagination.
     ///
     /// Retrieves a paginated list of contexts from the database, ordered by creation date
@@ -167,8 +597,14 @@ impl<'a> ContextDAL<'a> {
 
         let mut results = Vec::new();
         for db_context in db_contexts {
-            let context = Context::<T>::from_json(db_context.value)?;
-            results.push(context);
+            let data = self
+                .open_map(
+                    db_context.value,
+                    db_context.payload.map(|p| p.0),
+                    db_context.id,
+                )
+                .await?;
+            results.push(Context::from_map(data)?);
         }
 
         Ok(results)
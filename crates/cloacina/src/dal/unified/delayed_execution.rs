@@ -0,0 +1,228 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Unified Delayed Execution DAL
+//!
+//! Stores one-shot workflow runs due at a future time. The scheduler sleeps
+//! until the earliest pending `run_at`, claims each due row with a
+//! conditional update (so only one runner hands it off) and records the
+//! execution it started. A claim with no recorded outcome goes stale after
+//! a timeout — the runner stopped mid hand-off — and can be claimed again;
+//! the started execution is keyed on the delayed execution's id (see
+//! [`delayed_run_id`]) so the re-claim can find it instead of starting a
+//! second one.
+
+use super::models::{NewUnifiedDelayedExecution, UnifiedDelayedExecution};
+use super::DAL;
+use crate::database::schema::unified::{delayed_executions, workflow_executions};
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::models::delayed_execution::{delayed_run_id, DelayedExecution};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+/// Data access layer for delayed executions.
+#[derive(Clone)]
+pub struct DelayedExecutionDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> DelayedExecutionDAL<'a> {
+    /// Creates a new DelayedExecutionDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    /// Records a run of `workflow_name` with `context` (JSON) due at `run_at`.
+    pub async fn create(
+        &self,
+        workflow_name: &str,
+        context: String,
+        run_at: DateTime<Utc>,
+    ) -> Result<DelayedExecution, ValidationError> {
+        let id = UniversalUuid::new_v4();
+        let new_row = NewUnifiedDelayedExecution {
+            id,
+            workflow_name: workflow_name.to_string(),
+            context,
+            run_at: UniversalTimestamp(run_at),
+            created_at: UniversalTimestamp::now(),
+        };
+
+        let row: UnifiedDelayedExecution = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(delayed_executions::table)
+                .values(&new_row)
+                .execute(conn)?;
+            delayed_executions::table.find(id).first(conn)
+        })?;
+
+        Ok(row.into())
+    }
+
+    /// The delayed execution with `id`, pending or not.
+    pub async fn get(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<Option<DelayedExecution>, ValidationError> {
+        let row: Option<UnifiedDelayedExecution> = crate::interact_on_backend!(self.dal, |conn| {
+            delayed_executions::table.find(id).first(conn).optional()
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    /// The workflow execution a hand-off of `delayed` already started, found
+    /// by its [`delayed_run_id`] whether or not the outcome was recorded.
+    pub async fn started_execution(
+        &self,
+        delayed: &DelayedExecution,
+    ) -> Result<Option<UniversalUuid>, ValidationError> {
+        let workflow_name = delayed.workflow_name.clone();
+        let run_id = delayed_run_id(&delayed.id.to_string());
+        let id: Option<UniversalUuid> = crate::interact_on_backend!(self.dal, |conn| {
+            workflow_executions::table
+                .filter(workflow_executions::workflow_name.eq(workflow_name))
+                .filter(workflow_executions::run_id.eq(run_id))
+                .select(workflow_executions::id)
+                .first(conn)
+                .optional()
+        })?;
+
+        Ok(id)
+    }
+
+    /// Pending delayed executions, soonest first.
+    pub async fn list_pending(&self) -> Result<Vec<DelayedExecution>, ValidationError> {
+        let rows: Vec<UnifiedDelayedExecution> = crate::interact_on_backend!(self.dal, |conn| {
+            delayed_executions::table
+                .filter(delayed_executions::claimed_at.is_null())
+                .order(delayed_executions::run_at.asc())
+                .load(conn)
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Delayed executions due at or before `now`, oldest first: pending ones,
+    /// and ones claimed before `stale_before` whose hand-off never recorded
+    /// an outcome.
+    pub async fn get_due(
+        &self,
+        now: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<DelayedExecution>, ValidationError> {
+        let now = UniversalTimestamp(now);
+        let stale_before = UniversalTimestamp(stale_before);
+        let rows: Vec<UnifiedDelayedExecution> = crate::interact_on_backend!(self.dal, |conn| {
+            delayed_executions::table
+                .filter(
+                    delayed_executions::claimed_at
+                        .is_null()
+                        .or(delayed_executions::claimed_at
+                            .lt(stale_before)
+                            .and(delayed_executions::workflow_execution_id.is_null())
+                            .and(delayed_executions::error.is_null())),
+                )
+                .filter(delayed_executions::run_at.le(now))
+                .order(delayed_executions::run_at.asc())
+                .limit(limit)
+                .load(conn)
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Earliest `run_at` over pending delayed executions, or `None` when
+    /// there are none.
+    pub async fn next_due_time(&self) -> Result<Option<DateTime<Utc>>, ValidationError> {
+        let earliest: Option<UniversalTimestamp> = crate::interact_on_backend!(self.dal, |conn| {
+            delayed_executions::table
+                .filter(delayed_executions::claimed_at.is_null())
+                .order(delayed_executions::run_at.asc())
+                .select(delayed_executions::run_at)
+                .first::<UniversalTimestamp>(conn)
+                .optional()
+        })?;
+
+        Ok(earliest.map(DateTime::<Utc>::from))
+    }
+
+    /// Claims a delayed execution for hand-off: a pending one, or one whose
+    /// claim from before `stale_before` never recorded an outcome. Returns
+    /// `false` when another runner claimed it first, it was handed off, or it
+    /// was cancelled.
+    pub async fn claim(
+        &self,
+        id: UniversalUuid,
+        now: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<bool, ValidationError> {
+        let now = UniversalTimestamp(now);
+        let stale_before = UniversalTimestamp(stale_before);
+        let updated = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(
+                delayed_executions::table.find(id).filter(
+                    delayed_executions::claimed_at
+                        .is_null()
+                        .or(delayed_executions::claimed_at
+                            .lt(stale_before)
+                            .and(delayed_executions::workflow_execution_id.is_null())
+                            .and(delayed_executions::error.is_null())),
+                ),
+            )
+            .set(delayed_executions::claimed_at.eq(Some(now)))
+            .execute(conn)
+        })?;
+
+        Ok(updated == 1)
+    }
+
+    /// Records the outcome of a claimed delayed execution's hand-off: the
+    /// workflow execution it started, or why it could not start.
+    pub async fn record_outcome(
+        &self,
+        id: UniversalUuid,
+        workflow_execution_id: Option<UniversalUuid>,
+        error: Option<String>,
+    ) -> Result<(), ValidationError> {
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(delayed_executions::table.find(id))
+                .set((
+                    delayed_executions::workflow_execution_id.eq(workflow_execution_id),
+                    delayed_executions::error.eq(error),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Cancels a pending delayed execution. Returns `false` when it does not
+    /// exist or has already been handed off.
+    pub async fn cancel(&self, id: UniversalUuid) -> Result<bool, ValidationError> {
+        let deleted = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::delete(
+                delayed_executions::table
+                    .find(id)
+                    .filter(delayed_executions::claimed_at.is_null()),
+            )
+            .execute(conn)
+        })?;
+
+        Ok(deleted == 1)
+    }
+}
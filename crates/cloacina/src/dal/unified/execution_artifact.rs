@@ -0,0 +1,198 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Unified Execution Artifact DAL
+//!
+//! Stores, lists and reads the run artifacts of workflow executions; see
+//! [`crate::artifacts`]. The bytes go to the database's artifact store, or
+//! to the registry storage tables when none is attached.
+
+use std::sync::Arc;
+
+use super::models::{NewUnifiedWorkflowExecutionArtifact, UnifiedWorkflowExecutionArtifact};
+use super::{UnifiedRegistryStorage, DAL};
+use crate::artifacts::{validate_artifact, ArtifactStore};
+use crate::database::schema::unified::{task_executions, workflow_execution_artifacts};
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::models::workflow_execution::ExecutionArtifact;
+use diesel::prelude::*;
+
+/// Data access layer for run artifacts.
+#[derive(Clone)]
+pub struct ExecutionArtifactDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> ExecutionArtifactDAL<'a> {
+    /// Creates a new ExecutionArtifactDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    fn store(&self) -> Arc<ArtifactStore> {
+        match self.dal.database.artifact_store() {
+            Some(store) => store.clone(),
+            None => Arc::new(ArtifactStore::new(Box::new(UnifiedRegistryStorage::new(
+                self.dal.database.clone(),
+            )))),
+        }
+    }
+
+    /// Store `bytes` as artifact `name` of the workflow execution task
+    /// execution `task_execution_id` belongs to. An artifact of the same
+    /// name is replaced.
+    pub async fn store_artifact(
+        &self,
+        task_execution_id: UniversalUuid,
+        name: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<ExecutionArtifact, ValidationError> {
+        validate_artifact(name, content_type)?;
+        let (workflow_execution_id, task_name): (UniversalUuid, String) =
+            crate::interact_on_backend!(self.dal, |conn| {
+                task_executions::table
+                    .find(task_execution_id)
+                    .select((
+                        task_executions::workflow_execution_id,
+                        task_executions::task_name,
+                    ))
+                    .first(conn)
+            })?;
+
+        let id = UniversalUuid::new_v4();
+        let size_bytes = bytes.len() as i64;
+        let store = self.store();
+        let encryption = self.dal.database.at_rest_encryption().map(|e| &**e);
+        let storage_id =
+            store
+                .put(bytes, id, encryption)
+                .await
+                .map_err(|e| ValidationError::DatabaseQuery {
+                    message: format!("failed to store artifact '{}': {}", name, e),
+                })?;
+
+        let row = NewUnifiedWorkflowExecutionArtifact {
+            id,
+            workflow_execution_id,
+            task_execution_id: Some(task_execution_id),
+            task_name,
+            name: name.to_string(),
+            content_type: content_type.to_string(),
+            size_bytes,
+            storage_id: storage_id.clone(),
+            created_at: UniversalTimestamp::now(),
+        };
+        let artifact_name = row.name.clone();
+        let result = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                let replaced: Option<String> = workflow_execution_artifacts::table
+                    .filter(
+                        workflow_execution_artifacts::workflow_execution_id
+                            .eq(workflow_execution_id),
+                    )
+                    .filter(workflow_execution_artifacts::name.eq(&artifact_name))
+                    .select(workflow_execution_artifacts::storage_id)
+                    .first(conn)
+                    .optional()?;
+                diesel::delete(
+                    workflow_execution_artifacts::table
+                        .filter(
+                            workflow_execution_artifacts::workflow_execution_id
+                                .eq(workflow_execution_id),
+                        )
+                        .filter(workflow_execution_artifacts::name.eq(&artifact_name)),
+                )
+                .execute(conn)?;
+                diesel::insert_into(workflow_execution_artifacts::table)
+                    .values(&row)
+                    .execute(conn)?;
+                let stored: UnifiedWorkflowExecutionArtifact =
+                    workflow_execution_artifacts::table.find(id).first(conn)?;
+                Ok((stored, replaced))
+            })
+        });
+
+        match result {
+            Ok((stored, replaced)) => {
+                if let Some(replaced) = replaced {
+                    store.discard(&replaced).await;
+                }
+                Ok(stored.into())
+            }
+            Err(e) => {
+                store.discard(&storage_id).await;
+                Err(e.into())
+            }
+        }
+    }
+
+    /// The artifacts of a workflow execution, oldest first.
+    pub async fn list(
+        &self,
+        workflow_execution_id: UniversalUuid,
+    ) -> Result<Vec<ExecutionArtifact>, ValidationError> {
+        let rows: Vec<UnifiedWorkflowExecutionArtifact> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_artifacts::table
+                    .filter(
+                        workflow_execution_artifacts::workflow_execution_id
+                            .eq(workflow_execution_id),
+                    )
+                    .order((
+                        workflow_execution_artifacts::created_at.asc(),
+                        workflow_execution_artifacts::name.asc(),
+                    ))
+                    .load(conn)
+            })?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Artifact `name` of a workflow execution with its bytes, or `None`
+    /// when the execution has no such artifact.
+    pub async fn read(
+        &self,
+        workflow_execution_id: UniversalUuid,
+        name: &str,
+    ) -> Result<Option<(ExecutionArtifact, Vec<u8>)>, ValidationError> {
+        let name = name.to_string();
+        let row: Option<UnifiedWorkflowExecutionArtifact> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_artifacts::table
+                    .filter(
+                        workflow_execution_artifacts::workflow_execution_id
+                            .eq(workflow_execution_id),
+                    )
+                    .filter(workflow_execution_artifacts::name.eq(&name))
+                    .first(conn)
+                    .optional()
+            })?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let encryption = self.dal.database.at_rest_encryption().map(|e| &**e);
+        let bytes = self
+            .store()
+            .get(&row.storage_id, row.id, encryption)
+            .await
+            .map_err(|e| ValidationError::DatabaseQuery {
+                message: format!("failed to read artifact '{}': {}", row.name, e),
+            })?;
+        Ok(Some((row.into(), bytes)))
+    }
+}
@@ -221,6 +221,24 @@ impl<'a> ExecutionEventDAL<'a> {
         Ok(results.into_iter().map(Into::into).collect())
     }
 
+    /// Gets the events of one workflow execution with `sequence_num > after`,
+    /// in sequence order. Live views of a single run tail it with this.
+    pub async fn list_by_workflow_after_sequence(
+        &self,
+        workflow_execution_id: UniversalUuid,
+        after: i64,
+    ) -> Result<Vec<ExecutionEvent>, ValidationError> {
+        let results: Vec<UnifiedExecutionEvent> = crate::interact_on_backend!(self.dal, |conn| {
+            execution_events::table
+                .filter(execution_events::workflow_execution_id.eq(workflow_execution_id))
+                .filter(execution_events::sequence_num.gt(after))
+                .order(execution_events::sequence_num.asc())
+                .load(conn)
+        })?;
+
+        Ok(results.into_iter().map(Into::into).collect())
+    }
+
     /// Gets all execution events for a specific task execution, ordered by sequence.
     pub(crate) async fn list_by_task(
         &self,
@@ -269,6 +287,57 @@ impl<'a> ExecutionEventDAL<'a> {
         Ok(results.into_iter().map(Into::into).collect())
     }
 
+    /// Gets up to `limit` events with `sequence_num > after`, in sequence order.
+    ///
+    /// `sequence_num` is table-wide, so this is a cursor over every event in the
+    /// system — the event-bus publisher tails the table with it.
+    pub async fn list_after_sequence(
+        &self,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<ExecutionEvent>, ValidationError> {
+        let results: Vec<UnifiedExecutionEvent> = crate::interact_on_backend!(self.dal, |conn| {
+            execution_events::table
+                .filter(execution_events::sequence_num.gt(after))
+                .order(execution_events::sequence_num.asc())
+                .limit(limit)
+                .load(conn)
+        })?;
+
+        Ok(results.into_iter().map(Into::into).collect())
+    }
+
+    /// Gets the events whose `sequence_num` is in `sequence_nums`, in sequence
+    /// order. The event-bus publisher uses it to re-check sequence gaps left
+    /// by transactions that had not committed when it read past them.
+    pub async fn list_by_sequences(
+        &self,
+        sequence_nums: Vec<i64>,
+    ) -> Result<Vec<ExecutionEvent>, ValidationError> {
+        if sequence_nums.is_empty() {
+            return Ok(Vec::new());
+        }
+        let results: Vec<UnifiedExecutionEvent> = crate::interact_on_backend!(self.dal, |conn| {
+            execution_events::table
+                .filter(execution_events::sequence_num.eq_any(sequence_nums))
+                .order(execution_events::sequence_num.asc())
+                .load(conn)
+        })?;
+
+        Ok(results.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the highest `sequence_num` currently stored, or 0 when empty.
+    pub async fn max_sequence(&self) -> Result<i64, ValidationError> {
+        let max: Option<i64> = crate::interact_on_backend!(self.dal, |conn| {
+            execution_events::table
+                .select(diesel::dsl::max(execution_events::sequence_num))
+                .first(conn)
+        })?;
+
+        Ok(max.unwrap_or(0))
+    }
+
     /// Deletes execution events older than the specified timestamp.
     ///
     /// Used for retention policy enforcement to prevent unbounded table growth.
@@ -0,0 +1,604 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Aggregate execution statistics for health dashboards.
+//!
+//! Each query loads the narrow columns it needs for the requested window and
+//! aggregates in memory, so the same code serves PostgreSQL and SQLite (hour
+//! truncation differs between the two). Windows are meant to be hours to
+//! days; they are not a substitute for a metrics store over months of data.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::DAL;
+use crate::database::schema::unified::{execution_events, task_executions, workflow_executions};
+use crate::database::universal_types::UniversalTimestamp;
+use crate::error::ValidationError;
+use crate::models::execution_event::ExecutionEventType;
+
+/// Workflow executions started in one hour, by outcome.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HourlyExecutionCount {
+    /// Start of the hour (UTC)
+    pub hour: DateTime<Utc>,
+    /// Executions started in the hour
+    pub started: i64,
+    /// Of those, how many have completed
+    pub completed: i64,
+    /// Of those, how many have failed
+    pub failed: i64,
+}
+
+/// Outcome counts for one workflow over a window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowFailureRate {
+    pub workflow_name: String,
+    /// Executions that reached Completed, Failed or Cancelled
+    pub finished: i64,
+    pub failed: i64,
+    /// `failed / finished` as a percentage; 0 when nothing finished
+    pub failure_rate: f64,
+}
+
+/// Run-time statistics for one task over a window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDurationStats {
+    /// Fully qualified task name
+    pub task_name: String,
+    /// Completed runs in the window
+    pub runs: i64,
+    pub avg_ms: i64,
+    pub max_ms: i64,
+}
+
+/// Number of tasks waiting to be claimed at one point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueDepthSample {
+    pub at: DateTime<Utc>,
+    pub depth: i64,
+}
+
+/// Tasks that are ready to run but not yet claimed by a worker.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadyQueueStats {
+    /// Tasks a worker could claim right now
+    pub ready: i64,
+    /// Ready tasks whose retry time has not arrived yet
+    pub backing_off: i64,
+    /// When the longest-waiting claimable task became claimable
+    pub oldest_ready_at: Option<DateTime<Utc>>,
+}
+
+/// Time between a task becoming claimable and a worker claiming it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DispatchLatencyStats {
+    /// Claims in the window
+    pub dispatched: i64,
+    pub avg_ms: Option<i64>,
+    pub p95_ms: Option<i64>,
+    pub max_ms: Option<i64>,
+}
+
+/// Data access layer for aggregate execution statistics.
+#[derive(Clone)]
+pub struct ExecutionStatsDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> ExecutionStatsDAL<'a> {
+    /// Creates a new ExecutionStatsDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    /// Workflow executions started since `since`, bucketed by hour, oldest
+    /// first. Hours with no executions are included with zero counts.
+    pub async fn executions_per_hour(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<HourlyExecutionCount>, ValidationError> {
+        let since_ts = UniversalTimestamp::from(since);
+        let rows: Vec<(UniversalTimestamp, String)> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_executions::table
+                    .filter(workflow_executions::started_at.ge(since_ts))
+                    .select((workflow_executions::started_at, workflow_executions::status))
+                    .load(conn)
+            })?;
+
+        Ok(bucket_by_hour(
+            since,
+            Utc::now(),
+            rows.into_iter().map(|(at, status)| (at.0, status)),
+        ))
+    }
+
+    /// Failure rate per workflow over executions finished since `since`,
+    /// highest rate first.
+    pub async fn failure_rates(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<WorkflowFailureRate>, ValidationError> {
+        let since_ts = UniversalTimestamp::from(since);
+        let rows: Vec<(String, String)> = crate::interact_on_backend!(self.dal, |conn| {
+            workflow_executions::table
+                .filter(workflow_executions::completed_at.ge(since_ts))
+                .filter(workflow_executions::status.eq_any(["Completed", "Failed", "Cancelled"]))
+                .select((
+                    workflow_executions::workflow_name,
+                    workflow_executions::status,
+                ))
+                .load(conn)
+        })?;
+
+        Ok(failure_rates_from(rows))
+    }
+
+    /// The `limit` tasks with the highest average run time among runs that
+    /// completed since `since`.
+    pub async fn slowest_tasks(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<TaskDurationStats>, ValidationError> {
+        let since_ts = UniversalTimestamp::from(since);
+        let rows: Vec<(
+            String,
+            Option<UniversalTimestamp>,
+            Option<UniversalTimestamp>,
+        )> = crate::interact_on_backend!(self.dal, |conn| {
+            task_executions::table
+                .filter(task_executions::status.eq("Completed"))
+                .filter(task_executions::completed_at.ge(since_ts))
+                .select((
+                    task_executions::task_name,
+                    task_executions::started_at,
+                    task_executions::completed_at,
+                ))
+                .load(conn)
+        })?;
+
+        let durations = rows
+            .into_iter()
+            .filter_map(|(name, start, end)| Some((name, (end?.0 - start?.0).num_milliseconds())));
+        Ok(slowest_from(durations, limit))
+    }
+
+    /// Tasks waiting to be claimed, sampled at the end of each hour since
+    /// `since` and at the current time.
+    ///
+    /// Reconstructed from execution events: a task joins the queue when it
+    /// is marked ready, scheduled for retry or reset, and leaves it when it
+    /// is claimed, skipped or abandoned. Tasks that were already queued at
+    /// `since` are not counted.
+    pub async fn queue_depth_history(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<QueueDepthSample>, ValidationError> {
+        let since_ts = UniversalTimestamp::from(since);
+        let types = QUEUE_EVENT_TYPES.map(|t| t.as_str());
+        let rows: Vec<(
+            Option<crate::database::universal_types::UniversalUuid>,
+            String,
+            UniversalTimestamp,
+        )> = crate::interact_on_backend!(self.dal, |conn| {
+            execution_events::table
+                .filter(execution_events::created_at.ge(since_ts))
+                .filter(execution_events::event_type.eq_any(types))
+                .order(execution_events::sequence_num.asc())
+                .select((
+                    execution_events::task_execution_id,
+                    execution_events::event_type,
+                    execution_events::created_at,
+                ))
+                .load(conn)
+        })?;
+
+        let transitions = rows.into_iter().filter_map(|(task, event_type, at)| {
+            let enqueued = matches!(
+                ExecutionEventType::from_str(&event_type)?,
+                ExecutionEventType::TaskMarkedReady
+                    | ExecutionEventType::TaskRetryScheduled
+                    | ExecutionEventType::TaskReset
+            );
+            Some((task?.0, enqueued, at.0))
+        });
+        Ok(sample_queue_depth(since, Utc::now(), transitions))
+    }
+
+    /// Tasks currently waiting in the claim queue.
+    pub async fn ready_queue(&self) -> Result<ReadyQueueStats, ValidationError> {
+        let rows: Vec<(Option<UniversalTimestamp>, UniversalTimestamp)> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                task_executions::table
+                    .filter(task_executions::status.eq("Ready"))
+                    .filter(task_executions::claimed_by.is_null())
+                    .select((task_executions::retry_at, task_executions::updated_at))
+                    .load(conn)
+            })?;
+
+        Ok(ready_queue_from(
+            Utc::now(),
+            rows.into_iter()
+                .map(|(retry_at, updated_at)| (retry_at.map(|t| t.0), updated_at.0)),
+        ))
+    }
+
+    /// How long tasks claimed since `since` waited in the claim queue.
+    ///
+    /// Each claim is paired with the enqueue event that preceded it for the
+    /// same task. Retries are enqueued at their retry time, so backoff does
+    /// not count as waiting.
+    pub async fn dispatch_latency(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<DispatchLatencyStats, ValidationError> {
+        let since_ts = UniversalTimestamp::from(since);
+        let types = [
+            ExecutionEventType::TaskMarkedReady,
+            ExecutionEventType::TaskRetryScheduled,
+            ExecutionEventType::TaskReset,
+            ExecutionEventType::TaskClaimed,
+        ]
+        .map(|t| t.as_str());
+        let rows: Vec<(
+            Option<crate::database::universal_types::UniversalUuid>,
+            String,
+            UniversalTimestamp,
+        )> = crate::interact_on_backend!(self.dal, |conn| {
+            execution_events::table
+                .filter(execution_events::created_at.ge(since_ts))
+                .filter(execution_events::event_type.eq_any(types))
+                .order(execution_events::sequence_num.asc())
+                .select((
+                    execution_events::task_execution_id,
+                    execution_events::event_type,
+                    execution_events::created_at,
+                ))
+                .load(conn)
+        })?;
+
+        let transitions = rows.into_iter().filter_map(|(task, event_type, at)| {
+            let enqueued =
+                ExecutionEventType::from_str(&event_type)? != ExecutionEventType::TaskClaimed;
+            Some((task?.0, enqueued, at.0))
+        });
+        Ok(dispatch_latency_from(transitions))
+    }
+}
+
+/// Events that move a task into or out of the claim queue.
+const QUEUE_EVENT_TYPES: [ExecutionEventType; 6] = [
+    ExecutionEventType::TaskMarkedReady,
+    ExecutionEventType::TaskRetryScheduled,
+    ExecutionEventType::TaskReset,
+    ExecutionEventType::TaskClaimed,
+    ExecutionEventType::TaskSkipped,
+    ExecutionEventType::TaskAbandoned,
+];
+
+fn truncate_to_hour(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.duration_trunc(Duration::hours(1)).unwrap_or(at)
+}
+
+fn bucket_by_hour(
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    rows: impl Iterator<Item = (DateTime<Utc>, String)>,
+) -> Vec<HourlyExecutionCount> {
+    let mut buckets: BTreeMap<DateTime<Utc>, HourlyExecutionCount> = BTreeMap::new();
+    let mut hour = truncate_to_hour(since);
+    while hour <= until {
+        buckets.insert(
+            hour,
+            HourlyExecutionCount {
+                hour,
+                ..Default::default()
+            },
+        );
+        hour += Duration::hours(1);
+    }
+
+    for (started_at, status) in rows {
+        let hour = truncate_to_hour(started_at);
+        let bucket = buckets.entry(hour).or_insert_with(|| HourlyExecutionCount {
+            hour,
+            ..Default::default()
+        });
+        bucket.started += 1;
+        match status.as_str() {
+            "Completed" => bucket.completed += 1,
+            "Failed" => bucket.failed += 1,
+            _ => {}
+        }
+    }
+    buckets.into_values().collect()
+}
+
+fn failure_rates_from(rows: Vec<(String, String)>) -> Vec<WorkflowFailureRate> {
+    let mut by_workflow: HashMap<String, (i64, i64)> = HashMap::new();
+    for (name, status) in rows {
+        let entry = by_workflow.entry(name).or_default();
+        entry.0 += 1;
+        if status == "Failed" {
+            entry.1 += 1;
+        }
+    }
+
+    let mut rates: Vec<WorkflowFailureRate> = by_workflow
+        .into_iter()
+        .map(|(workflow_name, (finished, failed))| WorkflowFailureRate {
+            workflow_name,
+            finished,
+            failed,
+            failure_rate: if finished > 0 {
+                (failed as f64 / finished as f64) * 100.0
+            } else {
+                0.0
+            },
+        })
+        .collect();
+    rates.sort_by(|a, b| {
+        b.failure_rate
+            .total_cmp(&a.failure_rate)
+            .then_with(|| a.workflow_name.cmp(&b.workflow_name))
+    });
+    rates
+}
+
+fn slowest_from(
+    durations: impl Iterator<Item = (String, i64)>,
+    limit: usize,
+) -> Vec<TaskDurationStats> {
+    let mut by_task: HashMap<String, (i64, i64, i64)> = HashMap::new();
+    for (name, ms) in durations {
+        let entry = by_task.entry(name).or_default();
+        entry.0 += 1;
+        entry.1 += ms;
+        entry.2 = entry.2.max(ms);
+    }
+
+    let mut stats: Vec<TaskDurationStats> = by_task
+        .into_iter()
+        .map(|(task_name, (runs, total, max_ms))| TaskDurationStats {
+            task_name,
+            runs,
+            avg_ms: total / runs,
+            max_ms,
+        })
+        .collect();
+    stats.sort_by(|a, b| {
+        b.avg_ms
+            .cmp(&a.avg_ms)
+            .then_with(|| a.task_name.cmp(&b.task_name))
+    });
+    stats.truncate(limit);
+    stats
+}
+
+fn sample_queue_depth(
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    transitions: impl Iterator<Item = (uuid::Uuid, bool, DateTime<Utc>)>,
+) -> Vec<QueueDepthSample> {
+    let mut sample_points = Vec::new();
+    let mut point = truncate_to_hour(since) + Duration::hours(1);
+    while point < until {
+        sample_points.push(point);
+        point += Duration::hours(1);
+    }
+    sample_points.push(until);
+
+    let mut queued: std::collections::HashSet<uuid::Uuid> = std::collections::HashSet::new();
+    let mut samples = Vec::with_capacity(sample_points.len());
+    let mut points = sample_points.into_iter().peekable();
+    for (task, enqueued, at) in transitions {
+        while let Some(point) = points.next_if(|p| *p < at) {
+            samples.push(QueueDepthSample {
+                at: point,
+                depth: queued.len() as i64,
+            });
+        }
+        if enqueued {
+            queued.insert(task);
+        } else {
+            queued.remove(&task);
+        }
+    }
+    for point in points {
+        samples.push(QueueDepthSample {
+            at: point,
+            depth: queued.len() as i64,
+        });
+    }
+    samples
+}
+
+fn ready_queue_from(
+    now: DateTime<Utc>,
+    rows: impl Iterator<Item = (Option<DateTime<Utc>>, DateTime<Utc>)>,
+) -> ReadyQueueStats {
+    let mut stats = ReadyQueueStats::default();
+    for (retry_at, updated_at) in rows {
+        match retry_at {
+            Some(retry_at) if retry_at > now => stats.backing_off += 1,
+            _ => {
+                stats.ready += 1;
+                let ready_at = retry_at.map_or(updated_at, |r| r.max(updated_at));
+                stats.oldest_ready_at = Some(
+                    stats
+                        .oldest_ready_at
+                        .map_or(ready_at, |oldest| oldest.min(ready_at)),
+                );
+            }
+        }
+    }
+    stats
+}
+
+fn dispatch_latency_from(
+    transitions: impl Iterator<Item = (uuid::Uuid, bool, DateTime<Utc>)>,
+) -> DispatchLatencyStats {
+    let mut enqueued_at: HashMap<uuid::Uuid, DateTime<Utc>> = HashMap::new();
+    let mut waits = Vec::new();
+    for (task, enqueued, at) in transitions {
+        if enqueued {
+            enqueued_at.insert(task, at);
+        } else if let Some(since) = enqueued_at.remove(&task) {
+            waits.push((at - since).num_milliseconds().max(0));
+        }
+    }
+    if waits.is_empty() {
+        return DispatchLatencyStats::default();
+    }
+
+    waits.sort_unstable();
+    let rank = (waits.len() * 95).div_ceil(100);
+    DispatchLatencyStats {
+        dispatched: waits.len() as i64,
+        avg_ms: Some(waits.iter().sum::<i64>() / waits.len() as i64),
+        p95_ms: Some(waits[rank.saturating_sub(1)]),
+        max_ms: waits.last().copied(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_bucket_by_hour_fills_gaps() {
+        let rows = vec![
+            (at(9, 5), "Completed".to_string()),
+            (at(9, 40), "Failed".to_string()),
+            (at(11, 0), "Running".to_string()),
+        ];
+        let buckets = bucket_by_hour(at(9, 30), at(11, 15), rows.into_iter());
+
+        let counts: Vec<_> = buckets
+            .iter()
+            .map(|b| (b.hour, b.started, b.completed, b.failed))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                (at(9, 0), 2, 1, 1),
+                (at(10, 0), 0, 0, 0),
+                (at(11, 0), 1, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failure_rates_sorted_by_rate() {
+        let rows = vec![
+            ("etl".to_string(), "Completed".to_string()),
+            ("etl".to_string(), "Failed".to_string()),
+            ("report".to_string(), "Completed".to_string()),
+            ("report".to_string(), "Cancelled".to_string()),
+        ];
+        let rates = failure_rates_from(rows);
+        assert_eq!(rates[0].workflow_name, "etl");
+        assert_eq!((rates[0].finished, rates[0].failed), (2, 1));
+        assert_eq!(rates[0].failure_rate, 50.0);
+        assert_eq!(rates[1].failure_rate, 0.0);
+    }
+
+    #[test]
+    fn test_slowest_tasks_by_average() {
+        let durations = vec![
+            ("fast".to_string(), 10),
+            ("slow".to_string(), 900),
+            ("slow".to_string(), 100),
+            ("medium".to_string(), 300),
+        ];
+        let stats = slowest_from(durations.into_iter(), 2);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            (
+                stats[0].task_name.as_str(),
+                stats[0].runs,
+                stats[0].avg_ms,
+                stats[0].max_ms
+            ),
+            ("slow", 2, 500, 900)
+        );
+        assert_eq!(stats[1].task_name, "medium");
+    }
+
+    #[test]
+    fn test_queue_depth_samples_reflect_claims() {
+        let a = uuid::Uuid::new_v4();
+        let b = uuid::Uuid::new_v4();
+        let transitions = vec![
+            (a, true, at(9, 10)),
+            (b, true, at(9, 20)),
+            (a, false, at(9, 50)),
+            (b, false, at(10, 30)),
+        ];
+        let samples = sample_queue_depth(at(9, 0), at(10, 45), transitions.into_iter());
+        let depths: Vec<_> = samples.iter().map(|s| (s.at, s.depth)).collect();
+        assert_eq!(depths, vec![(at(10, 0), 1), (at(10, 45), 0)]);
+    }
+
+    #[test]
+    fn test_ready_queue_separates_backoff() {
+        let rows = vec![
+            (None, at(9, 10)),
+            (Some(at(9, 30)), at(9, 0)),
+            (Some(at(11, 0)), at(9, 40)),
+        ];
+        let stats = ready_queue_from(at(10, 0), rows.into_iter());
+        assert_eq!(stats.ready, 2);
+        assert_eq!(stats.backing_off, 1);
+        assert_eq!(stats.oldest_ready_at, Some(at(9, 10)));
+    }
+
+    #[test]
+    fn test_dispatch_latency_pairs_enqueue_with_claim() {
+        let a = uuid::Uuid::new_v4();
+        let b = uuid::Uuid::new_v4();
+        let c = uuid::Uuid::new_v4();
+        let transitions = vec![
+            (a, true, at(9, 0)),
+            (b, true, at(9, 0)),
+            (a, false, at(9, 1)),
+            (b, false, at(9, 3)),
+            // Claimed without an enqueue in the window: ignored
+            (c, false, at(9, 4)),
+            // Retried and claimed again
+            (a, true, at(9, 10)),
+            (a, false, at(9, 12)),
+        ];
+        let stats = dispatch_latency_from(transitions.into_iter());
+        assert_eq!(stats.dispatched, 3);
+        assert_eq!(stats.avg_ms, Some(120_000));
+        assert_eq!(stats.max_ms, Some(180_000));
+        assert_eq!(
+            dispatch_latency_from(std::iter::empty()),
+            DispatchLatencyStats::default()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Unified Federation Chain Cursor DAL
+//!
+//! Persists how far each federation chain rule has forwarded, as the
+//! `(completed_at, id)` of the last source execution it handed to the peer
+//! region. The chain loop resumes from the cursor after a restart or a
+//! leadership change instead of re-reading a fixed window of recent rows.
+
+use super::models::{NewUnifiedFederationChainCursor, UnifiedFederationChainCursor};
+use super::DAL;
+use crate::database::schema::unified::federation_chain_cursors;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use diesel::prelude::*;
+
+/// Data access layer for federation chain cursors.
+#[derive(Clone)]
+pub struct FederationChainCursorDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> FederationChainCursorDAL<'a> {
+    /// Creates a new FederationChainCursorDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    /// The `(completed_at, id)` a chain rule last forwarded, or `None` when
+    /// it has not forwarded anything yet.
+    pub async fn get(
+        &self,
+        chain_key: &str,
+    ) -> Result<Option<(UniversalTimestamp, UniversalUuid)>, ValidationError> {
+        let chain_key = chain_key.to_string();
+        let row: Option<UnifiedFederationChainCursor> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                federation_chain_cursors::table
+                    .find(chain_key)
+                    .first(conn)
+                    .optional()
+            })?;
+
+        Ok(row.map(|r| (r.completed_at, r.execution_id)))
+    }
+
+    /// Moves a chain rule's cursor to `(completed_at, execution_id)`.
+    pub async fn advance(
+        &self,
+        chain_key: &str,
+        completed_at: UniversalTimestamp,
+        execution_id: UniversalUuid,
+    ) -> Result<(), ValidationError> {
+        let chain_key = chain_key.to_string();
+        let now = UniversalTimestamp::now();
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(federation_chain_cursors::table)
+                .values(&NewUnifiedFederationChainCursor {
+                    chain_key: chain_key.clone(),
+                    completed_at,
+                    execution_id,
+                    updated_at: now,
+                })
+                .on_conflict(federation_chain_cursors::chain_key)
+                .do_update()
+                .set((
+                    federation_chain_cursors::completed_at.eq(completed_at),
+                    federation_chain_cursors::execution_id.eq(execution_id),
+                    federation_chain_cursors::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+}
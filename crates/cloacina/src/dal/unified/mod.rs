@@ -49,10 +49,15 @@ pub mod agent_desired;
 pub mod agent_limits;
 #[cfg(feature = "postgres")]
 pub mod api_keys;
+pub mod audit_log;
 pub mod checkpoint;
 pub mod context;
+pub mod delayed_execution;
 pub mod delivery_outbox;
+pub mod execution_artifact;
 pub mod execution_event;
+pub mod execution_stats;
+pub mod federation_chain_cursor;
 #[cfg(feature = "postgres")]
 pub mod local_accounts;
 pub mod models;
@@ -62,11 +67,13 @@ pub mod oidc_login_flows;
 pub mod oidc_sessions;
 pub mod reactor_subscriptions;
 pub mod recovery_event;
+pub mod runner_instance;
 pub mod schedule;
 pub mod schedule_execution;
 pub mod task_execution;
 pub mod task_execution_metadata;
 pub mod task_outbox;
+pub mod workflow_deprecation;
 pub mod workflow_execution;
 pub mod workflow_packages;
 pub mod workflow_registry_storage;
@@ -78,10 +85,18 @@ pub use agent_desired::AgentDesiredDAL;
 pub use agent_limits::AgentLimitsDAL;
 #[cfg(feature = "postgres")]
 pub use api_keys::{ApiKeyDAL, ApiKeyInfo};
+pub use audit_log::AuditLogDAL;
 pub use checkpoint::CheckpointDAL;
 pub use context::ContextDAL;
+pub use delayed_execution::DelayedExecutionDAL;
 pub use delivery_outbox::DeliveryOutboxDAL;
+pub use execution_artifact::ExecutionArtifactDAL;
 pub use execution_event::ExecutionEventDAL;
+pub use execution_stats::{
+    DispatchLatencyStats, ExecutionStatsDAL, HourlyExecutionCount, QueueDepthSample,
+    ReadyQueueStats, TaskDurationStats, WorkflowFailureRate,
+};
+pub use federation_chain_cursor::FederationChainCursorDAL;
 #[cfg(feature = "postgres")]
 pub use local_accounts::{LocalAccount, LocalAccountDAL, LoginOutcome};
 #[cfg(feature = "postgres")]
@@ -90,11 +105,13 @@ pub use oidc_login_flows::OidcLoginFlowDAL;
 pub use oidc_sessions::{OidcSessionDAL, RefreshSession};
 pub use reactor_subscriptions::{ReactorFiring, ReactorSubscription, ReactorSubscriptionsDAL};
 pub use recovery_event::RecoveryEventDAL;
+pub use runner_instance::RunnerInstanceDAL;
 pub use schedule::ScheduleDAL;
 pub use schedule_execution::{ScheduleExecutionDAL, ScheduleExecutionStats};
-pub use task_execution::{ClaimResult, RetryStats, TaskExecutionDAL};
+pub use task_execution::{ClaimResult, ResolveResult, RetryStats, TaskExecutionDAL};
 pub use task_execution_metadata::TaskExecutionMetadataDAL;
 pub use task_outbox::TaskOutboxDAL;
+pub use workflow_deprecation::WorkflowDeprecationDAL;
 pub use workflow_execution::WorkflowExecutionDAL;
 pub use workflow_packages::WorkflowPackagesDAL;
 pub use workflow_registry_storage::UnifiedRegistryStorage;
@@ -184,6 +201,11 @@ impl DAL {
         OidcLoginFlowDAL::new(self)
     }
 
+    /// Returns an audit log DAL for the tamper-evident audit chain.
+    pub fn audit_log(&self) -> AuditLogDAL<'_> {
+        AuditLogDAL::new(self)
+    }
+
     /// Returns a checkpoint DAL for computation graph state persistence.
     pub fn checkpoint(&self) -> CheckpointDAL<'_> {
         CheckpointDAL::new(self)
@@ -194,6 +216,11 @@ impl DAL {
         ContextDAL::new(self)
     }
 
+    /// Returns a delayed execution DAL for one-shot runs due at a future time.
+    pub fn delayed_execution(&self) -> DelayedExecutionDAL<'_> {
+        DelayedExecutionDAL::new(self)
+    }
+
     /// Returns a workflow execution DAL for workflow execution operations.
     pub fn workflow_execution(&self) -> WorkflowExecutionDAL<'_> {
         WorkflowExecutionDAL::new(self)
@@ -225,11 +252,31 @@ impl DAL {
         RecoveryEventDAL::new(self)
     }
 
+    /// Returns a runner instance DAL for the blue/green handoff registry.
+    pub fn runner_instance(&self) -> RunnerInstanceDAL<'_> {
+        RunnerInstanceDAL::new(self)
+    }
+
+    /// Returns a federation chain cursor DAL for the chain loop's progress.
+    pub fn federation_chain_cursor(&self) -> FederationChainCursorDAL<'_> {
+        FederationChainCursorDAL::new(self)
+    }
+
+    /// Returns an execution artifact DAL for run artifacts.
+    pub fn execution_artifact(&self) -> ExecutionArtifactDAL<'_> {
+        ExecutionArtifactDAL::new(self)
+    }
+
     /// Returns an execution event DAL for execution event operations.
     pub fn execution_event(&self) -> ExecutionEventDAL<'_> {
         ExecutionEventDAL::new(self)
     }
 
+    /// Returns an execution stats DAL for dashboard aggregates.
+    pub fn execution_stats(&self) -> ExecutionStatsDAL<'_> {
+        ExecutionStatsDAL::new(self)
+    }
+
     /// Returns a unified schedule DAL for schedule operations.
     pub fn schedule(&self) -> ScheduleDAL<'_> {
         ScheduleDAL::new(self)
@@ -240,6 +287,11 @@ impl DAL {
         ScheduleExecutionDAL::new(self)
     }
 
+    /// Returns a workflow deprecation DAL for workflows marked for retirement.
+    pub fn workflow_deprecation(&self) -> WorkflowDeprecationDAL<'_> {
+        WorkflowDeprecationDAL::new(self)
+    }
+
     /// Returns a workflow packages DAL for package operations.
     pub fn workflow_packages(&self) -> WorkflowPackagesDAL<'_> {
         WorkflowPackagesDAL::new(self)
@@ -20,11 +20,15 @@
 //! SQL types that work with both PostgreSQL and SQLite backends.
 
 use crate::database::schema::unified::{
-    accumulator_boundaries, accumulator_checkpoints, contexts, delivery_outbox, execution_events,
+    accumulator_boundaries, accumulator_checkpoints, audit_anchors, audit_log, contexts,
+    delayed_executions, delivery_outbox, execution_events, federation_chain_cursors,
     key_trust_acls, package_artifacts, package_providers, package_signatures, reactor_state,
-    recovery_events, schedule_executions, schedules, secrets, signing_keys,
-    state_accumulator_buffers, task_execution_metadata, task_executions, task_outbox,
-    tenant_data_keys, trusted_keys, workflow_executions, workflow_packages, workflow_registry,
+    recovery_events, runner_instances, schedule_executions, schedules, secrets, signing_keys,
+    state_accumulator_buffers, task_approvals, task_context_access, task_context_changes,
+    task_execution_metadata, task_executions, task_outbox, tenant_data_keys, trusted_keys,
+    workflow_deprecations, workflow_execution_annotations, workflow_execution_artifacts,
+    workflow_execution_labels, workflow_execution_options, workflow_execution_params,
+    workflow_executions, workflow_packages, workflow_registry, workflow_registry_chunks,
 };
 use crate::database::universal_types::{
     UniversalBinary, UniversalBool, UniversalTimestamp, UniversalUuid,
@@ -41,6 +45,7 @@ use diesel::prelude::*;
 pub struct UnifiedDbContext {
     pub id: UniversalUuid,
     pub value: String,
+    pub payload: Option<UniversalBinary>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
 }
@@ -51,6 +56,7 @@ pub struct UnifiedDbContext {
 pub struct NewUnifiedDbContext {
     pub id: UniversalUuid,
     pub value: String,
+    pub payload: Option<UniversalBinary>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
 }
@@ -79,6 +85,11 @@ pub struct UnifiedWorkflowExecution {
     /// How this run was triggered (CLOACI-T-0776); `Some("manual")` for an
     /// operator REST run, `None` otherwise.
     pub trigger_origin: Option<String>,
+    pub run_id: Option<String>,
+    pub logical_date: Option<UniversalTimestamp>,
+    pub attempt: i32,
+    pub retry_at: Option<UniversalTimestamp>,
+    pub admitted_at: Option<UniversalTimestamp>,
 }
 
 #[derive(Debug, Insertable)]
@@ -92,12 +103,194 @@ pub struct NewUnifiedWorkflowExecution {
     pub started_at: UniversalTimestamp,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
+    pub run_id: Option<String>,
+    pub logical_date: Option<UniversalTimestamp>,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = workflow_execution_labels)]
+pub struct UnifiedWorkflowExecutionLabel {
+    pub workflow_execution_id: UniversalUuid,
+    pub label_key: String,
+    pub label_value: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_execution_labels)]
+pub struct NewUnifiedWorkflowExecutionLabel {
+    pub workflow_execution_id: UniversalUuid,
+    pub label_key: String,
+    pub label_value: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = workflow_execution_options)]
+pub struct UnifiedWorkflowExecutionOptions {
+    pub workflow_execution_id: UniversalUuid,
+    pub options: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_execution_options)]
+pub struct NewUnifiedWorkflowExecutionOptions {
+    pub workflow_execution_id: UniversalUuid,
+    pub options: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = workflow_execution_params)]
+pub struct UnifiedWorkflowExecutionParams {
+    pub workflow_execution_id: UniversalUuid,
+    pub params: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_execution_params)]
+pub struct NewUnifiedWorkflowExecutionParams {
+    pub workflow_execution_id: UniversalUuid,
+    pub params: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = workflow_execution_artifacts)]
+pub struct UnifiedWorkflowExecutionArtifact {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: Option<UniversalUuid>,
+    pub task_name: String,
+    pub name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_id: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_execution_artifacts)]
+pub struct NewUnifiedWorkflowExecutionArtifact {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: Option<UniversalUuid>,
+    pub task_name: String,
+    pub name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_id: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = workflow_execution_annotations)]
+pub struct UnifiedWorkflowExecutionAnnotation {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub author: String,
+    pub note: String,
+    pub resolution: Option<String>,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_execution_annotations)]
+pub struct NewUnifiedWorkflowExecutionAnnotation {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub author: String,
+    pub note: String,
+    pub resolution: Option<String>,
+    pub created_at: UniversalTimestamp,
 }
 
 // ============================================================================
 // Task Execution Models
 // ============================================================================
 
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = task_context_access)]
+pub struct UnifiedTaskContextAccess {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub attempt: i32,
+    /// JSON array of key names
+    pub reads: String,
+    /// JSON array of key names
+    pub writes: String,
+    pub recorded_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = task_context_access)]
+pub struct NewUnifiedTaskContextAccess {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub attempt: i32,
+    pub reads: String,
+    pub writes: String,
+    pub recorded_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = task_context_changes)]
+pub struct UnifiedTaskContextChanges {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub attempt: i32,
+    /// JSON array of changes, possibly sealed with the at-rest keys
+    pub changes: String,
+    pub recorded_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = task_context_changes)]
+pub struct NewUnifiedTaskContextChanges {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub attempt: i32,
+    pub changes: String,
+    pub recorded_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = task_approvals)]
+pub struct UnifiedTaskApproval {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    /// `approved` or `rejected`
+    pub decision: String,
+    pub approver: String,
+    pub comment: Option<String>,
+    pub decided_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = task_approvals)]
+pub struct NewUnifiedTaskApproval {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub decision: String,
+    pub approver: String,
+    pub comment: Option<String>,
+    pub decided_at: UniversalTimestamp,
+}
+
 #[derive(Debug, Clone, Queryable, Selectable)]
 #[diesel(table_name = task_executions)]
 pub struct UnifiedTaskExecution {
@@ -121,6 +314,8 @@ pub struct UnifiedTaskExecution {
     pub heartbeat_at: Option<UniversalTimestamp>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
+    /// `TaskErrorPayload` JSON of a structured task failure.
+    pub error_payload: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -196,6 +391,154 @@ pub struct NewUnifiedRecoveryEvent {
     pub updated_at: UniversalTimestamp,
 }
 
+// ============================================================================
+// Runner Instance Models
+// ============================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = runner_instances)]
+pub struct UnifiedRunnerInstance {
+    pub id: UniversalUuid,
+    pub name: Option<String>,
+    pub version: String,
+    pub state: String,
+    pub successor_id: Option<UniversalUuid>,
+    pub started_at: UniversalTimestamp,
+    pub heartbeat_at: UniversalTimestamp,
+    pub updated_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = runner_instances)]
+pub struct NewUnifiedRunnerInstance {
+    pub id: UniversalUuid,
+    pub name: Option<String>,
+    pub version: String,
+    pub state: String,
+    pub started_at: UniversalTimestamp,
+    pub heartbeat_at: UniversalTimestamp,
+    pub updated_at: UniversalTimestamp,
+}
+
+// ============================================================================
+// Federation Chain Cursor Models
+// ============================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = federation_chain_cursors)]
+pub struct UnifiedFederationChainCursor {
+    pub chain_key: String,
+    pub completed_at: UniversalTimestamp,
+    pub execution_id: UniversalUuid,
+    pub updated_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = federation_chain_cursors)]
+pub struct NewUnifiedFederationChainCursor {
+    pub chain_key: String,
+    pub completed_at: UniversalTimestamp,
+    pub execution_id: UniversalUuid,
+    pub updated_at: UniversalTimestamp,
+}
+
+// ============================================================================
+// Workflow Deprecation Models
+// ============================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = workflow_deprecations)]
+pub struct UnifiedWorkflowDeprecation {
+    pub workflow_name: String,
+    pub message: Option<String>,
+    pub sunset_at: Option<UniversalTimestamp>,
+    pub deprecated_at: UniversalTimestamp,
+    pub updated_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_deprecations)]
+pub struct NewUnifiedWorkflowDeprecation {
+    pub workflow_name: String,
+    pub message: Option<String>,
+    pub sunset_at: Option<UniversalTimestamp>,
+    pub deprecated_at: UniversalTimestamp,
+    pub updated_at: UniversalTimestamp,
+}
+
+// ============================================================================
+// Delayed Execution Models
+// ============================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = delayed_executions)]
+pub struct UnifiedDelayedExecution {
+    pub id: UniversalUuid,
+    pub workflow_name: String,
+    pub context: String,
+    pub run_at: UniversalTimestamp,
+    pub created_at: UniversalTimestamp,
+    pub claimed_at: Option<UniversalTimestamp>,
+    pub workflow_execution_id: Option<UniversalUuid>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = delayed_executions)]
+pub struct NewUnifiedDelayedExecution {
+    pub id: UniversalUuid,
+    pub workflow_name: String,
+    pub context: String,
+    pub run_at: UniversalTimestamp,
+    pub created_at: UniversalTimestamp,
+}
+
+// ============================================================================
+// Audit Chain Models
+// ============================================================================
+
+/// One hash-chained audit event. Append-only.
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = audit_log)]
+pub struct UnifiedAuditLogEntry {
+    pub sequence: i64,
+    pub event_type: String,
+    pub payload: String,
+    pub prev_hash: String,
+    pub entry_hash: String,
+    pub created_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = audit_log)]
+pub struct NewUnifiedAuditLogEntry {
+    pub sequence: i64,
+    pub event_type: String,
+    pub payload: String,
+    pub prev_hash: String,
+    pub entry_hash: String,
+    pub created_at: UniversalTimestamp,
+}
+
+/// A recorded chain head.
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = audit_anchors)]
+pub struct UnifiedAuditAnchor {
+    pub sequence: i64,
+    pub entry_hash: String,
+    pub signature: Option<String>,
+    pub anchored_at: UniversalTimestamp,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = audit_anchors)]
+pub struct NewUnifiedAuditAnchor {
+    pub sequence: i64,
+    pub entry_hash: String,
+    pub signature: Option<String>,
+    pub anchored_at: UniversalTimestamp,
+}
+
 // ============================================================================
 // Execution Event Models
 // ============================================================================
@@ -330,6 +673,36 @@ pub struct UnifiedSchedule {
     /// human instance name; both None for anonymous schedules.
     pub params: Option<String>,
     pub instance_name: Option<String>,
+    /// Operator-assigned tags (JSON array of strings); None when untagged.
+    pub tags: Option<String>,
+    /// Trigger dedup window (seconds) and hashed context keys (JSON array);
+    /// None = dedup against in-flight executions over the whole context.
+    pub dedup_window_secs: Option<i32>,
+    pub dedup_keys: Option<String>,
+    /// Runner currently polling this trigger and when its lease runs out;
+    /// None when unleased.
+    pub lease_owner: Option<UniversalUuid>,
+    pub lease_expires_at: Option<UniversalTimestamp>,
+    /// Cron fire offset and jitter window (seconds); None = fire on time.
+    pub fire_offset_secs: Option<i32>,
+    pub fire_jitter_secs: Option<i32>,
+    /// Interval (seconds) and its anchor for interval schedules; None for
+    /// cron-expression schedules.
+    pub interval_secs: Option<i32>,
+    pub interval_anchor: Option<UniversalTimestamp>,
+    /// Trigger throttling (milliseconds): least time between fires and the
+    /// debounce window; None = no limit / fire at once.
+    pub min_fire_interval_ms: Option<i32>,
+    pub debounce_window_ms: Option<i32>,
+    /// Declarative trigger condition (CEL) and its probes (JSON array);
+    /// None = fire on the registered trigger alone.
+    pub trigger_condition: Option<String>,
+    pub trigger_probes: Option<String>,
+    /// Pause audit: who paused the schedule and why; None when not paused.
+    pub paused_by: Option<String>,
+    pub pause_reason: Option<String>,
+    /// Cron DST policy; None = run_once.
+    pub dst_policy: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -353,6 +726,13 @@ pub struct NewUnifiedSchedule {
     // CLOACI-I-0116: named parameterized instance (None = anonymous).
     pub params: Option<String>,
     pub instance_name: Option<String>,
+    pub tags: Option<String>,
+    pub dedup_window_secs: Option<i32>,
+    pub dedup_keys: Option<String>,
+    pub fire_offset_secs: Option<i32>,
+    pub fire_jitter_secs: Option<i32>,
+    pub interval_secs: Option<i32>,
+    pub interval_anchor: Option<UniversalTimestamp>,
 }
 
 // ============================================================================
@@ -372,6 +752,10 @@ pub struct UnifiedScheduleExecution {
     pub completed_at: Option<UniversalTimestamp>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
+    /// Dedup decision for suppressed trigger firings ("active", "window" or
+    /// "rate_limited"); None for firings that executed.
+    pub suppressed_reason: Option<String>,
+    pub duplicate_of: Option<UniversalUuid>,
 }
 
 #[derive(Debug, Insertable)]
@@ -384,8 +768,11 @@ pub struct NewUnifiedScheduleExecution {
     pub claimed_at: Option<UniversalTimestamp>,
     pub context_hash: Option<String>,
     pub started_at: UniversalTimestamp,
+    pub completed_at: Option<UniversalTimestamp>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
+    pub suppressed_reason: Option<String>,
+    pub duplicate_of: Option<UniversalUuid>,
 }
 
 // ============================================================================
@@ -408,6 +795,14 @@ pub struct NewUnifiedWorkflowRegistryEntry {
     pub data: UniversalBinary,
 }
 
+#[derive(Debug, Insertable)]
+#[diesel(table_name = workflow_registry_chunks)]
+pub struct NewUnifiedWorkflowRegistryChunk {
+    pub registry_id: UniversalUuid,
+    pub chunk_index: i32,
+    pub data: UniversalBinary,
+}
+
 // ============================================================================
 // Workflow Packages Models
 // ============================================================================
@@ -700,16 +1095,23 @@ pub struct NewUnifiedPackageSignature {
 // models is straightforward - mostly just field-by-field mapping.
 
 use crate::models::context::DbContext;
+use crate::models::delayed_execution::DelayedExecution;
 use crate::models::execution_event::ExecutionEvent;
 use crate::models::key_trust_acl::KeyTrustAcl;
 use crate::models::package_signature::PackageSignature;
 use crate::models::recovery_event::RecoveryEvent;
+use crate::models::runner_instance::RunnerInstance;
 use crate::models::schedule::{Schedule, ScheduleExecution};
 use crate::models::signing_key::SigningKey;
-use crate::models::task_execution::TaskExecution;
+use crate::models::task_execution::{
+    ApprovalDecision, TaskApproval, TaskContextAccess, TaskExecution,
+};
 use crate::models::task_execution_metadata::TaskExecutionMetadata;
 use crate::models::trusted_key::TrustedKey;
-use crate::models::workflow_execution::WorkflowExecutionRecord;
+use crate::models::workflow_deprecation::WorkflowDeprecation;
+use crate::models::workflow_execution::{
+    ExecutionAnnotation, ExecutionArtifact, WorkflowExecutionRecord,
+};
 use crate::models::workflow_packages::WorkflowPackage;
 use crate::models::workflow_registry::WorkflowRegistryEntry;
 
@@ -742,6 +1144,112 @@ impl From<UnifiedWorkflowExecution> for WorkflowExecutionRecord {
             created_at: u.created_at,
             updated_at: u.updated_at,
             trigger_origin: u.trigger_origin,
+            run_id: u.run_id,
+            logical_date: u.logical_date,
+            attempt: u.attempt,
+            retry_at: u.retry_at,
+            admitted_at: u.admitted_at,
+        }
+    }
+}
+
+impl From<UnifiedTaskContextAccess> for TaskContextAccess {
+    fn from(u: UnifiedTaskContextAccess) -> Self {
+        TaskContextAccess {
+            id: u.id,
+            workflow_execution_id: u.workflow_execution_id,
+            task_execution_id: u.task_execution_id,
+            task_name: u.task_name,
+            attempt: u.attempt,
+            reads: serde_json::from_str(&u.reads).unwrap_or_default(),
+            writes: serde_json::from_str(&u.writes).unwrap_or_default(),
+            recorded_at: u.recorded_at,
+        }
+    }
+}
+
+impl From<UnifiedTaskApproval> for TaskApproval {
+    fn from(u: UnifiedTaskApproval) -> Self {
+        TaskApproval {
+            id: u.id,
+            workflow_execution_id: u.workflow_execution_id,
+            task_execution_id: u.task_execution_id,
+            task_name: u.task_name,
+            // The column is constrained to the two decisions.
+            decision: ApprovalDecision::from_str(&u.decision).unwrap_or(ApprovalDecision::Rejected),
+            approver: u.approver,
+            comment: u.comment,
+            decided_at: u.decided_at,
+        }
+    }
+}
+
+impl From<UnifiedWorkflowExecutionAnnotation> for ExecutionAnnotation {
+    fn from(u: UnifiedWorkflowExecutionAnnotation) -> Self {
+        ExecutionAnnotation {
+            id: u.id,
+            workflow_execution_id: u.workflow_execution_id,
+            author: u.author,
+            note: u.note,
+            resolution: u.resolution,
+            created_at: u.created_at,
+        }
+    }
+}
+
+impl From<UnifiedWorkflowExecutionArtifact> for ExecutionArtifact {
+    fn from(u: UnifiedWorkflowExecutionArtifact) -> Self {
+        ExecutionArtifact {
+            id: u.id,
+            workflow_execution_id: u.workflow_execution_id,
+            task_execution_id: u.task_execution_id,
+            task_name: u.task_name,
+            name: u.name,
+            content_type: u.content_type,
+            size_bytes: u.size_bytes,
+            created_at: u.created_at,
+        }
+    }
+}
+
+impl From<UnifiedRunnerInstance> for RunnerInstance {
+    fn from(u: UnifiedRunnerInstance) -> Self {
+        RunnerInstance {
+            id: u.id,
+            name: u.name,
+            version: u.version,
+            state: u.state,
+            successor_id: u.successor_id,
+            started_at: u.started_at,
+            heartbeat_at: u.heartbeat_at,
+            updated_at: u.updated_at,
+        }
+    }
+}
+
+impl From<UnifiedDelayedExecution> for DelayedExecution {
+    fn from(u: UnifiedDelayedExecution) -> Self {
+        DelayedExecution {
+            id: u.id,
+            workflow_name: u.workflow_name,
+            context: u.context,
+            run_at: u.run_at,
+            created_at: u.created_at,
+            claimed_at: u.claimed_at,
+            workflow_execution_id: u.workflow_execution_id,
+            error: u.error,
+        }
+    }
+}
+
+impl From<UnifiedWorkflowDeprecation> for WorkflowDeprecation {
+    fn from(u: UnifiedWorkflowDeprecation) -> Self {
+        WorkflowDeprecation {
+            workflow_name: u.workflow_name,
+            message: u.message,
+            sunset_at: u.sunset_at,
+            deprecated_at: u.deprecated_at,
+            updated_at: u.updated_at,
         }
     }
 }
@@ -769,6 +1277,9 @@ impl From<UnifiedTaskExecution> for TaskExecution {
             heartbeat_at: u.heartbeat_at,
             created_at: u.created_at,
             updated_at: u.updated_at,
+            error_payload: u
+                .error_payload
+                .and_then(|json| serde_json::from_str(&json).ok()),
         }
     }
 }
@@ -933,6 +1444,22 @@ impl From<UnifiedSchedule> for Schedule {
             paused_at: u.paused_at,
             params: u.params,
             instance_name: u.instance_name,
+            tags: u.tags,
+            dedup_window_secs: u.dedup_window_secs,
+            dedup_keys: u.dedup_keys,
+            lease_owner: u.lease_owner,
+            lease_expires_at: u.lease_expires_at,
+            fire_offset_secs: u.fire_offset_secs,
+            fire_jitter_secs: u.fire_jitter_secs,
+            interval_secs: u.interval_secs,
+            interval_anchor: u.interval_anchor,
+            min_fire_interval_ms: u.min_fire_interval_ms,
+            debounce_window_ms: u.debounce_window_ms,
+            trigger_condition: u.trigger_condition,
+            trigger_probes: u.trigger_probes,
+            paused_by: u.paused_by,
+            pause_reason: u.pause_reason,
+            dst_policy: u.dst_policy,
         }
     }
 }
@@ -950,6 +1477,8 @@ impl From<UnifiedScheduleExecution> for ScheduleExecution {
             completed_at: u.completed_at,
             created_at: u.created_at,
             updated_at: u.updated_at,
+            suppressed_reason: u.suppressed_reason,
+            duplicate_of: u.duplicate_of,
         }
     }
 }
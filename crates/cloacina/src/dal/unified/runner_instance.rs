@@ -0,0 +1,192 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Unified Runner Instance DAL
+//!
+//! Registry of live runners used for blue/green handoff. Runners register on
+//! start, heartbeat while they run, and read their own state back on every
+//! heartbeat so they notice when a successor has asked them to drain.
+
+use super::models::{NewUnifiedRunnerInstance, UnifiedRunnerInstance};
+use super::DAL;
+use crate::database::schema::unified::runner_instances;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::models::runner_instance::{RunnerInstance, RunnerInstanceState};
+use diesel::prelude::*;
+
+/// Data access layer for the runner instance registry.
+#[derive(Clone)]
+pub struct RunnerInstanceDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> RunnerInstanceDAL<'a> {
+    /// Creates a new RunnerInstanceDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    /// Registers a runner as `active`.
+    pub async fn register(
+        &self,
+        id: UniversalUuid,
+        name: Option<String>,
+        version: &str,
+    ) -> Result<RunnerInstance, ValidationError> {
+        let now = UniversalTimestamp::now();
+        let new_instance = NewUnifiedRunnerInstance {
+            id,
+            name,
+            version: version.to_string(),
+            state: RunnerInstanceState::Active.as_str().to_string(),
+            started_at: now,
+            heartbeat_at: now,
+            updated_at: now,
+        };
+
+        let row: UnifiedRunnerInstance = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(runner_instances::table)
+                .values(&new_instance)
+                .execute(conn)?;
+            runner_instances::table.find(id).first(conn)
+        })?;
+
+        Ok(row.into())
+    }
+
+    /// Retrieves a runner instance by ID.
+    pub async fn get(&self, id: UniversalUuid) -> Result<RunnerInstance, ValidationError> {
+        let row: UnifiedRunnerInstance = crate::interact_on_backend!(self.dal, |conn| {
+            runner_instances::table.find(id).first(conn)
+        })?;
+
+        Ok(row.into())
+    }
+
+    /// Lists every registered runner instance, most recently started first.
+    pub async fn list(&self) -> Result<Vec<RunnerInstance>, ValidationError> {
+        let rows: Vec<UnifiedRunnerInstance> = crate::interact_on_backend!(self.dal, |conn| {
+            runner_instances::table
+                .order(runner_instances::started_at.desc())
+                .load(conn)
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Refreshes a runner's heartbeat and returns its current row, so the
+    /// caller sees a drain request on the same round-trip.
+    pub async fn heartbeat(&self, id: UniversalUuid) -> Result<RunnerInstance, ValidationError> {
+        let now = UniversalTimestamp::now();
+        let row: UnifiedRunnerInstance = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(runner_instances::table.find(id))
+                .set(runner_instances::heartbeat_at.eq(now))
+                .execute(conn)?;
+            runner_instances::table.find(id).first(conn)
+        })?;
+
+        Ok(row.into())
+    }
+
+    /// Asks every other live `active` runner on a different version to drain
+    /// in favour of `successor`.
+    ///
+    /// A runner counts as live when it has heartbeated at or after
+    /// `live_since`; rows left behind by runners that crashed are not
+    /// touched. Peers already running `successor_version` are replicas of
+    /// the successor, not predecessors, and keep running. Returns the
+    /// runners that were moved to `draining`.
+    pub async fn request_handoff(
+        &self,
+        successor: UniversalUuid,
+        successor_version: &str,
+        live_since: UniversalTimestamp,
+    ) -> Result<Vec<RunnerInstance>, ValidationError> {
+        let successor_version = successor_version.to_string();
+        use diesel::connection::Connection;
+
+        let now = UniversalTimestamp::now();
+        let rows: Vec<UnifiedRunnerInstance> = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                let targets: Vec<UniversalUuid> = runner_instances::table
+                    .filter(runner_instances::state.eq(RunnerInstanceState::Active.as_str()))
+                    .filter(runner_instances::id.ne(successor))
+                    .filter(runner_instances::version.ne(&successor_version))
+                    .filter(runner_instances::heartbeat_at.ge(live_since))
+                    .select(runner_instances::id)
+                    .load(conn)?;
+                if targets.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                diesel::update(
+                    runner_instances::table.filter(runner_instances::id.eq_any(targets.clone())),
+                )
+                .set((
+                    runner_instances::state.eq(RunnerInstanceState::Draining.as_str()),
+                    runner_instances::successor_id.eq(Some(successor)),
+                    runner_instances::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+                runner_instances::table
+                    .filter(runner_instances::id.eq_any(targets))
+                    .load(conn)
+            })
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Marks a runner as `drained`. Called by the old runner once its
+    /// in-flight work is finished.
+    pub async fn mark_drained(&self, id: UniversalUuid) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(runner_instances::table.find(id))
+                .set((
+                    runner_instances::state.eq(RunnerInstanceState::Drained.as_str()),
+                    runner_instances::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Marks an `active` runner as `stopped` on an ordinary shutdown.
+    ///
+    /// Rows already `draining` or `drained` are left alone, so a shutdown
+    /// never passes for a completed handoff.
+    pub async fn mark_stopped(&self, id: UniversalUuid) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(
+                runner_instances::table
+                    .filter(runner_instances::id.eq(id))
+                    .filter(runner_instances::state.eq(RunnerInstanceState::Active.as_str())),
+            )
+            .set((
+                runner_instances::state.eq(RunnerInstanceState::Stopped.as_str()),
+                runner_instances::updated_at.eq(now),
+            ))
+            .execute(conn)
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Trigger leases (shared trigger polling across runners).
+//!
+//! With leasing enabled, each runner polls only the trigger schedules it
+//! holds a lease on. A lease names its owner and an expiry; the owner renews
+//! it while it runs, and once it expires any runner may take it over.
+//! `trigger_pollers` tracks the runners taking part so each can aim for a
+//! fair share of the triggers.
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use super::ScheduleDAL;
+use crate::database::schema::unified::{schedules, trigger_pollers};
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+
+impl<'a> ScheduleDAL<'a> {
+    /// Records that `poller` is polling triggers, forgets pollers that have
+    /// not been seen since `live_since`, and returns how many pollers are
+    /// live, this one included.
+    pub async fn heartbeat_trigger_poller(
+        &self,
+        poller: UniversalUuid,
+        live_since: DateTime<Utc>,
+    ) -> Result<usize, ValidationError> {
+        use diesel::connection::Connection;
+
+        let now = UniversalTimestamp::now();
+        let live_since = UniversalTimestamp::from(live_since);
+        let live: i64 = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<i64, diesel::result::Error, _>(|conn| {
+                let updated = diesel::update(trigger_pollers::table.find(poller))
+                    .set(trigger_pollers::heartbeat_at.eq(now))
+                    .execute(conn)?;
+                if updated == 0 {
+                    diesel::insert_into(trigger_pollers::table)
+                        .values((
+                            trigger_pollers::id.eq(poller),
+                            trigger_pollers::heartbeat_at.eq(now),
+                        ))
+                        .execute(conn)?;
+                }
+                diesel::delete(
+                    trigger_pollers::table.filter(trigger_pollers::heartbeat_at.lt(live_since)),
+                )
+                .execute(conn)?;
+                trigger_pollers::table.count().get_result(conn)
+            })
+        })?;
+
+        Ok(live as usize)
+    }
+
+    /// Removes `poller` from the trigger pollers and releases every lease it
+    /// holds, so the remaining runners take its triggers over at once.
+    pub async fn remove_trigger_poller(
+        &self,
+        poller: UniversalUuid,
+    ) -> Result<(), ValidationError> {
+        use diesel::connection::Connection;
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<(), diesel::result::Error, _>(|conn| {
+                diesel::update(schedules::table.filter(schedules::lease_owner.eq(Some(poller))))
+                    .set((
+                        schedules::lease_owner.eq(None::<UniversalUuid>),
+                        schedules::lease_expires_at.eq(None::<UniversalTimestamp>),
+                    ))
+                    .execute(conn)?;
+                diesel::delete(trigger_pollers::table.find(poller)).execute(conn)?;
+                Ok(())
+            })
+        })?;
+
+        Ok(())
+    }
+
+    /// Extends every trigger lease held by `owner` to `expires_at` and
+    /// returns the ids of the leased schedules.
+    pub async fn renew_trigger_leases(
+        &self,
+        owner: UniversalUuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Vec<UniversalUuid>, ValidationError> {
+        use diesel::connection::Connection;
+
+        let expires_at = UniversalTimestamp::from(expires_at);
+        let ids = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<Vec<UniversalUuid>, diesel::result::Error, _>(|conn| {
+                diesel::update(schedules::table.filter(schedules::lease_owner.eq(Some(owner))))
+                    .set(schedules::lease_expires_at.eq(Some(expires_at)))
+                    .execute(conn)?;
+                schedules::table
+                    .filter(schedules::lease_owner.eq(Some(owner)))
+                    .select(schedules::id)
+                    .load(conn)
+            })
+        })?;
+
+        Ok(ids)
+    }
+
+    /// Atomically leases trigger schedule `id` to `owner` until
+    /// `expires_at`. Succeeds when the schedule is unleased, already leased
+    /// to `owner`, or its lease expired before `now`.
+    pub async fn acquire_trigger_lease(
+        &self,
+        id: UniversalUuid,
+        owner: UniversalUuid,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, ValidationError> {
+        let now = UniversalTimestamp::from(now);
+        let expires_at = UniversalTimestamp::from(expires_at);
+        let updated = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(
+                schedules::table.find(id).filter(
+                    schedules::lease_owner
+                        .is_null()
+                        .or(schedules::lease_owner.eq(Some(owner)))
+                        .or(schedules::lease_expires_at.lt(Some(now))),
+                ),
+            )
+            .set((
+                schedules::lease_owner.eq(Some(owner)),
+                schedules::lease_expires_at.eq(Some(expires_at)),
+            ))
+            .execute(conn)
+        })?;
+
+        Ok(updated == 1)
+    }
+
+    /// Releases `owner`'s lease on trigger schedule `id`, if it still holds
+    /// it.
+    pub async fn release_trigger_lease(
+        &self,
+        id: UniversalUuid,
+        owner: UniversalUuid,
+    ) -> Result<(), ValidationError> {
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(
+                schedules::table
+                    .find(id)
+                    .filter(schedules::lease_owner.eq(Some(owner))),
+            )
+            .set((
+                schedules::lease_owner.eq(None::<UniversalUuid>),
+                schedules::lease_expires_at.eq(None::<UniversalTimestamp>),
+            ))
+            .execute(conn)
+        })?;
+
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@
 //! implementation at runtime based on the database connection type.
 
 mod crud;
+mod leases;
 
 use diesel::prelude::*;
 
@@ -70,6 +71,13 @@ impl<'a> ScheduleDAL<'a> {
             updated_at: now,
             params: new_schedule.params,
             instance_name: new_schedule.instance_name,
+            tags: new_schedule.tags,
+            dedup_window_secs: new_schedule.dedup_window_secs,
+            dedup_keys: new_schedule.dedup_keys,
+            fire_offset_secs: new_schedule.fire_offset_secs,
+            fire_jitter_secs: new_schedule.fire_jitter_secs,
+            interval_secs: new_schedule.interval_secs,
+            interval_anchor: new_schedule.interval_anchor,
         };
 
         crate::interact_on_backend!(self.dal, |conn| {
@@ -177,15 +185,26 @@ impl<'a> ScheduleDAL<'a> {
 
     /// Pauses a schedule (CLOACI-T-0749): the scheduler stops firing it until
     /// resumed. Distinct from `disable` — pause is transient operator state.
-    pub async fn pause(&self, id: UniversalUuid) -> Result<(), ValidationError> {
+    /// `paused_by` and `reason` are kept with `paused_at` for audit; pausing
+    /// an already paused schedule replaces them.
+    pub async fn pause(
+        &self,
+        id: UniversalUuid,
+        paused_by: Option<&str>,
+        reason: Option<&str>,
+    ) -> Result<(), ValidationError> {
         let now = UniversalTimestamp::now();
         let paused_true = UniversalBool::from(true);
+        let paused_by = paused_by.map(str::to_string);
+        let reason = reason.map(str::to_string);
 
         crate::interact_on_backend!(self.dal, |conn| {
             diesel::update(schedules::table.find(id))
                 .set((
                     schedules::paused.eq(paused_true),
                     schedules::paused_at.eq(Some(now)),
+                    schedules::paused_by.eq(paused_by),
+                    schedules::pause_reason.eq(reason),
                     schedules::updated_at.eq(now),
                 ))
                 .execute(conn)
@@ -194,9 +213,9 @@ impl<'a> ScheduleDAL<'a> {
         Ok(())
     }
 
-    /// Resumes a paused schedule (CLOACI-T-0749): clears the paused flag so the
-    /// scheduler fires it again on its normal schedule. Missed fires are not
-    /// caught up (skip policy).
+    /// Resumes a paused schedule (CLOACI-T-0749): clears the paused flag and
+    /// its audit fields so the scheduler fires it again on its normal
+    /// schedule. Missed fires are not caught up (skip policy).
     pub async fn resume(&self, id: UniversalUuid) -> Result<(), ValidationError> {
         let now = UniversalTimestamp::now();
         let paused_false = UniversalBool::from(false);
@@ -206,6 +225,120 @@ impl<'a> ScheduleDAL<'a> {
                 .set((
                     schedules::paused.eq(paused_false),
                     schedules::paused_at.eq(None::<UniversalTimestamp>),
+                    schedules::paused_by.eq(None::<String>),
+                    schedules::pause_reason.eq(None::<String>),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Replaces a schedule's tags. An empty set clears them (NULL column).
+    pub async fn set_tags(
+        &self,
+        id: UniversalUuid,
+        tags: &[String],
+    ) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        let encoded = crate::models::schedule::encode_tags(tags);
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.find(id))
+                .set((schedules::tags.eq(encoded), schedules::updated_at.eq(now)))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Replaces a trigger schedule's dedup configuration. `window_secs` of
+    /// `None` disables the window (only in-flight executions deduplicate);
+    /// an empty `keys` set hashes the whole context.
+    pub async fn set_dedup(
+        &self,
+        id: UniversalUuid,
+        window_secs: Option<i32>,
+        keys: &[String],
+    ) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        let encoded = crate::models::schedule::encode_tags(keys);
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.find(id))
+                .set((
+                    schedules::dedup_window_secs.eq(window_secs),
+                    schedules::dedup_keys.eq(encoded),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Replaces a trigger schedule's throttling: the least time between
+    /// fires and the debounce window, both in milliseconds. `None` disables
+    /// each.
+    pub async fn set_throttle(
+        &self,
+        id: UniversalUuid,
+        min_fire_interval_ms: Option<i32>,
+        debounce_window_ms: Option<i32>,
+    ) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.find(id))
+                .set((
+                    schedules::min_fire_interval_ms.eq(min_fire_interval_ms),
+                    schedules::debounce_window_ms.eq(debounce_window_ms),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Replaces a trigger schedule's declarative condition (a CEL
+    /// expression) and its probes (a JSON array of probe definitions).
+    /// `None` clears each. Callers validate both first.
+    pub async fn set_condition(
+        &self,
+        id: UniversalUuid,
+        condition: Option<String>,
+        probes: Option<String>,
+    ) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.find(id))
+                .set((
+                    schedules::trigger_condition.eq(condition),
+                    schedules::trigger_probes.eq(probes),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Sets a cron schedule's catchup policy for missed fires.
+    pub async fn set_catchup_policy(
+        &self,
+        id: UniversalUuid,
+        policy: crate::models::schedule::CatchupPolicy,
+    ) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        let policy = String::from(policy);
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.find(id))
+                .set((
+                    schedules::catchup_policy.eq(Some(policy)),
                     schedules::updated_at.eq(now),
                 ))
                 .execute(conn)
@@ -214,6 +347,118 @@ impl<'a> ScheduleDAL<'a> {
         Ok(())
     }
 
+    /// Sets a cron schedule's daylight-saving policy, with its next run time
+    /// recomputed under that policy by the caller.
+    pub async fn set_dst_policy(
+        &self,
+        id: UniversalUuid,
+        policy: crate::DstPolicy,
+        next_run: DateTime<Utc>,
+    ) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        let policy = policy.as_str().to_string();
+        let next_run_ts = UniversalTimestamp::from(next_run);
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.find(id))
+                .set((
+                    schedules::dst_policy.eq(Some(policy)),
+                    schedules::next_run_at.eq(Some(next_run_ts)),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Finds every schedule (cron or trigger) carrying `tag`.
+    ///
+    /// Tags are stored as a JSON array, so the match is done in Rust over the
+    /// tagged rows rather than with backend-specific JSON operators.
+    pub async fn find_by_tag(&self, tag: &str) -> Result<Vec<Schedule>, ValidationError> {
+        let results: Vec<UnifiedSchedule> = crate::interact_on_backend!(self.dal, |conn| {
+            schedules::table
+                .filter(schedules::tags.is_not_null())
+                .order(schedules::created_at.desc())
+                .load(conn)
+        })?;
+
+        Ok(results
+            .into_iter()
+            .map(Schedule::from)
+            .filter(|s| s.has_tag(tag))
+            .collect())
+    }
+
+    /// Pauses every not-yet-paused schedule carrying `tag` in one update.
+    /// Returns the IDs of the schedules that were paused, recorded with the
+    /// reason `paused with tag '<tag>'`; schedules already paused keep their
+    /// original `paused_at` and reason.
+    pub async fn pause_by_tag(&self, tag: &str) -> Result<Vec<UniversalUuid>, ValidationError> {
+        let ids: Vec<UniversalUuid> = self
+            .find_by_tag(tag)
+            .await?
+            .into_iter()
+            .filter(|s| !s.is_paused())
+            .map(|s| s.id)
+            .collect();
+        if ids.is_empty() {
+            return Ok(ids);
+        }
+
+        let now = UniversalTimestamp::now();
+        let paused_true = UniversalBool::from(true);
+        let pause_reason = Some(format!("paused with tag '{}'", tag));
+        let target = ids.clone();
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.filter(schedules::id.eq_any(&target)))
+                .set((
+                    schedules::paused.eq(paused_true),
+                    schedules::paused_at.eq(Some(now)),
+                    schedules::pause_reason.eq(pause_reason),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(ids)
+    }
+
+    /// Resumes every paused schedule carrying `tag` in one update. Returns the
+    /// IDs of the schedules that were resumed.
+    pub async fn resume_by_tag(&self, tag: &str) -> Result<Vec<UniversalUuid>, ValidationError> {
+        let ids: Vec<UniversalUuid> = self
+            .find_by_tag(tag)
+            .await?
+            .into_iter()
+            .filter(|s| s.is_paused())
+            .map(|s| s.id)
+            .collect();
+        if ids.is_empty() {
+            return Ok(ids);
+        }
+
+        let now = UniversalTimestamp::now();
+        let paused_false = UniversalBool::from(false);
+        let target = ids.clone();
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(schedules::table.filter(schedules::id.eq_any(&target)))
+                .set((
+                    schedules::paused.eq(paused_false),
+                    schedules::paused_at.eq(None::<UniversalTimestamp>),
+                    schedules::paused_by.eq(None::<String>),
+                    schedules::pause_reason.eq(None::<String>),
+                    schedules::updated_at.eq(now),
+                ))
+                .execute(conn)
+        })?;
+
+        Ok(ids)
+    }
+
     /// Deletes a schedule from the database.
     pub async fn delete(&self, id: UniversalUuid) -> Result<(), ValidationError> {
         crate::interact_on_backend!(self.dal, |conn| diesel::delete(schedules::table.find(id))
@@ -421,6 +666,13 @@ impl<'a> ScheduleDAL<'a> {
                 updated_at: now,
                 params: new_schedule.params,
                 instance_name: new_schedule.instance_name,
+                tags: new_schedule.tags,
+                dedup_window_secs: new_schedule.dedup_window_secs,
+                dedup_keys: new_schedule.dedup_keys,
+                fire_offset_secs: new_schedule.fire_offset_secs,
+                fire_jitter_secs: new_schedule.fire_jitter_secs,
+                interval_secs: new_schedule.interval_secs,
+                interval_anchor: new_schedule.interval_anchor,
             };
 
             crate::interact_on_backend!(self.dal, |conn| {
@@ -523,6 +775,13 @@ impl<'a> ScheduleDAL<'a> {
                 updated_at: now,
                 params: new_schedule.params,
                 instance_name: new_schedule.instance_name,
+                tags: new_schedule.tags,
+                dedup_window_secs: new_schedule.dedup_window_secs,
+                dedup_keys: new_schedule.dedup_keys,
+                fire_offset_secs: new_schedule.fire_offset_secs,
+                fire_jitter_secs: new_schedule.fire_jitter_secs,
+                interval_secs: new_schedule.interval_secs,
+                interval_anchor: new_schedule.interval_anchor,
             };
             crate::interact_on_backend!(self.dal, |conn| {
                 diesel::insert_into(schedules::table)
@@ -1146,12 +1405,17 @@ mod tests {
         assert!(!sched.is_paused());
 
         // Pausing removes it from the due set without disabling it.
-        dal.schedule().pause(sched.id).await.unwrap();
+        dal.schedule()
+            .pause(sched.id, Some("ops-key"), Some("warehouse migration"))
+            .await
+            .unwrap();
         let paused = dal.schedule().get_by_id(sched.id).await.unwrap();
         assert!(paused.is_paused());
         assert!(paused.is_enabled(), "pause must not flip enabled");
         assert!(!paused.is_active(), "paused schedule is not active");
         assert!(paused.paused_at.is_some());
+        assert_eq!(paused.paused_by.as_deref(), Some("ops-key"));
+        assert_eq!(paused.pause_reason.as_deref(), Some("warehouse migration"));
 
         let due = dal
             .schedule()
@@ -1174,6 +1438,8 @@ mod tests {
         let resumed = dal.schedule().get_by_id(sched.id).await.unwrap();
         assert!(!resumed.is_paused());
         assert!(resumed.paused_at.is_none());
+        assert!(resumed.paused_by.is_none());
+        assert!(resumed.pause_reason.is_none());
         let due = dal
             .schedule()
             .get_due_cron_schedules(Utc::now())
@@ -1192,7 +1458,7 @@ mod tests {
             .await
             .unwrap();
 
-        dal.schedule().pause(sched.id).await.unwrap();
+        dal.schedule().pause(sched.id, None, None).await.unwrap();
         let triggers = dal.schedule().get_enabled_triggers().await.unwrap();
         assert!(
             triggers.iter().all(|s| s.id != sched.id),
@@ -1204,6 +1470,207 @@ mod tests {
         assert!(triggers.iter().any(|s| s.id == sched.id));
     }
 
+    // ── tags / bulk pause by tag ────────────────────────────────────
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_pause_resume_by_tag() {
+        let dal = unique_dal().await;
+        let past = UniversalTimestamp::from(Utc::now() - chrono::Duration::hours(1));
+        let cron = dal
+            .schedule()
+            .create(NewSchedule::cron("load_wh", "0 * * * *", past).with_tags(["warehouse"]))
+            .await
+            .unwrap();
+        let trigger = dal
+            .schedule()
+            .create(NewSchedule::trigger(
+                "wh_files",
+                "ingest_wh",
+                Duration::from_secs(5),
+            ))
+            .await
+            .unwrap();
+        dal.schedule()
+            .set_tags(trigger.id, &["warehouse".to_string(), "ingest".to_string()])
+            .await
+            .unwrap();
+        let other = dal
+            .schedule()
+            .create(NewSchedule::cron("reports", "0 * * * *", past).with_tags(["reporting"]))
+            .await
+            .unwrap();
+
+        let tagged = dal.schedule().find_by_tag("warehouse").await.unwrap();
+        assert_eq!(tagged.len(), 2);
+
+        let paused = dal.schedule().pause_by_tag("warehouse").await.unwrap();
+        assert_eq!(paused.len(), 2);
+        assert!(dal.schedule().get_by_id(cron.id).await.unwrap().is_paused());
+        assert!(dal
+            .schedule()
+            .get_by_id(trigger.id)
+            .await
+            .unwrap()
+            .is_paused());
+        assert!(
+            !dal.schedule()
+                .get_by_id(other.id)
+                .await
+                .unwrap()
+                .is_paused(),
+            "schedules without the tag must be untouched"
+        );
+
+        // Idempotent: nothing left to pause.
+        assert!(dal
+            .schedule()
+            .pause_by_tag("warehouse")
+            .await
+            .unwrap()
+            .is_empty());
+
+        let resumed = dal.schedule().resume_by_tag("warehouse").await.unwrap();
+        assert_eq!(resumed.len(), 2);
+        assert!(!dal.schedule().get_by_id(cron.id).await.unwrap().is_paused());
+        assert!(!dal
+            .schedule()
+            .get_by_id(trigger.id)
+            .await
+            .unwrap()
+            .is_paused());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_set_tags_clears_with_empty_set() {
+        let dal = unique_dal().await;
+        let sched = dal
+            .schedule()
+            .create(
+                NewSchedule::cron("wf", "0 * * * *", UniversalTimestamp::now()).with_tags(["a"]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(sched.tag_list(), vec!["a".to_string()]);
+
+        dal.schedule().set_tags(sched.id, &[]).await.unwrap();
+        let cleared = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert!(cleared.tags.is_none());
+        assert!(dal.schedule().find_by_tag("a").await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_set_dedup_round_trip() {
+        let dal = unique_dal().await;
+        let sched = dal
+            .schedule()
+            .create(NewSchedule::trigger(
+                "file_watcher",
+                "process_files",
+                std::time::Duration::from_secs(5),
+            ))
+            .await
+            .unwrap();
+        assert!(sched.dedup_window().is_none());
+
+        dal.schedule()
+            .set_dedup(sched.id, Some(86_400), &["path".to_string()])
+            .await
+            .unwrap();
+        let configured = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert_eq!(
+            configured.dedup_window(),
+            Some(std::time::Duration::from_secs(86_400))
+        );
+        assert_eq!(configured.dedup_key_list(), vec!["path".to_string()]);
+
+        dal.schedule().set_dedup(sched.id, None, &[]).await.unwrap();
+        let cleared = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert!(cleared.dedup_window_secs.is_none());
+        assert!(cleared.dedup_keys.is_none());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_set_throttle_round_trip() {
+        let dal = unique_dal().await;
+        let sched = dal
+            .schedule()
+            .create(NewSchedule::trigger(
+                "file_watcher",
+                "process_files",
+                std::time::Duration::from_secs(5),
+            ))
+            .await
+            .unwrap();
+        assert!(sched.min_fire_interval().is_none());
+        assert!(sched.debounce_window().is_none());
+
+        dal.schedule()
+            .set_throttle(sched.id, Some(60_000), Some(500))
+            .await
+            .unwrap();
+        let configured = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert_eq!(
+            configured.min_fire_interval(),
+            Some(std::time::Duration::from_secs(60))
+        );
+        assert_eq!(
+            configured.debounce_window(),
+            Some(std::time::Duration::from_millis(500))
+        );
+
+        dal.schedule()
+            .set_throttle(sched.id, None, None)
+            .await
+            .unwrap();
+        let cleared = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert!(cleared.min_fire_interval_ms.is_none());
+        assert!(cleared.debounce_window_ms.is_none());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_set_condition_round_trip() {
+        let dal = unique_dal().await;
+        let sched = dal
+            .schedule()
+            .create(NewSchedule::trigger(
+                "disk_pressure",
+                "cleanup",
+                std::time::Duration::from_secs(30),
+            ))
+            .await
+            .unwrap();
+        assert!(sched.trigger_condition.is_none());
+
+        let probes = r#"[{"name":"flag","kind":"file","path":"/tmp/flag"}]"#;
+        dal.schedule()
+            .set_condition(
+                sched.id,
+                Some("probes.flag.exists".to_string()),
+                Some(probes.to_string()),
+            )
+            .await
+            .unwrap();
+        let configured = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert_eq!(
+            configured.trigger_condition.as_deref(),
+            Some("probes.flag.exists")
+        );
+        assert_eq!(configured.trigger_probes.as_deref(), Some(probes));
+
+        dal.schedule()
+            .set_condition(sched.id, None, None)
+            .await
+            .unwrap();
+        let cleared = dal.schedule().get_by_id(sched.id).await.unwrap();
+        assert!(cleared.trigger_condition.is_none());
+        assert!(cleared.trigger_probes.is_none());
+    }
+
     // ── update_cron_expression_and_timezone ──────────────────────────
 
     #[cfg(feature = "sqlite")]
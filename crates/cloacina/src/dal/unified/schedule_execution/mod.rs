@@ -28,7 +28,7 @@ use crate::dal::unified::models::{NewUnifiedScheduleExecution, UnifiedScheduleEx
 use crate::database::schema::unified::schedule_executions;
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
 use crate::error::ValidationError;
-use crate::models::schedule::{NewScheduleExecution, ScheduleExecution};
+use crate::models::schedule::{NewScheduleExecution, ScheduleExecution, SuppressionReason};
 use chrono::{DateTime, Duration, Utc};
 
 /// Statistics about schedule execution performance
@@ -72,8 +72,11 @@ impl<'a> ScheduleExecutionDAL<'a> {
             claimed_at: new_execution.claimed_at,
             context_hash: new_execution.context_hash,
             started_at: now,
+            completed_at: None,
             created_at: now,
             updated_at: now,
+            suppressed_reason: None,
+            duplicate_of: None,
         };
 
         crate::interact_on_backend!(self.dal, |conn| {
@@ -159,6 +162,154 @@ impl<'a> ScheduleExecutionDAL<'a> {
         Ok(count > 0)
     }
 
+    /// Finds the in-flight (uncompleted) firing for a schedule with the given
+    /// context hash, if any.
+    pub async fn find_active_by_hash(
+        &self,
+        schedule_id: UniversalUuid,
+        context_hash: &str,
+    ) -> Result<Option<ScheduleExecution>, ValidationError> {
+        let context_hash_owned = context_hash.to_string();
+
+        let result: Option<UnifiedScheduleExecution> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                schedule_executions::table
+                    .filter(schedule_executions::schedule_id.eq(schedule_id))
+                    .filter(schedule_executions::context_hash.eq(context_hash_owned))
+                    .filter(schedule_executions::completed_at.is_null())
+                    .order(schedule_executions::started_at.desc())
+                    .first(conn)
+                    .optional()
+            })?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    /// Finds the most recent firing (suppressed rows excluded) for a schedule
+    /// with the given context hash that started at or after `since`. Used for
+    /// window-based trigger deduplication.
+    pub async fn find_recent_by_hash(
+        &self,
+        schedule_id: UniversalUuid,
+        context_hash: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Option<ScheduleExecution>, ValidationError> {
+        let context_hash_owned = context_hash.to_string();
+        let since_ts = UniversalTimestamp::from(since);
+
+        let result: Option<UnifiedScheduleExecution> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                schedule_executions::table
+                    .filter(schedule_executions::schedule_id.eq(schedule_id))
+                    .filter(schedule_executions::context_hash.eq(context_hash_owned))
+                    .filter(schedule_executions::suppressed_reason.is_null())
+                    .filter(schedule_executions::started_at.ge(since_ts))
+                    .order(schedule_executions::started_at.desc())
+                    .first(conn)
+                    .optional()
+            })?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    /// Finds the most recent firing (suppressed rows excluded) for a schedule,
+    /// whatever its context hash, that started at or after `since`. Used for
+    /// trigger rate limiting.
+    pub async fn find_last_fired_since(
+        &self,
+        schedule_id: UniversalUuid,
+        since: DateTime<Utc>,
+    ) -> Result<Option<ScheduleExecution>, ValidationError> {
+        let since_ts = UniversalTimestamp::from(since);
+
+        let result: Option<UnifiedScheduleExecution> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                schedule_executions::table
+                    .filter(schedule_executions::schedule_id.eq(schedule_id))
+                    .filter(schedule_executions::suppressed_reason.is_null())
+                    .filter(schedule_executions::started_at.ge(since_ts))
+                    .order(schedule_executions::started_at.desc())
+                    .first(conn)
+                    .optional()
+            })?;
+
+        Ok(result.map(|r| r.into()))
+    }
+
+    /// Records a trigger firing that was suppressed as a duplicate of
+    /// `duplicate_of`, so the trigger history can explain it.
+    ///
+    /// The row is inserted already completed (it never runs, and must not
+    /// collide with the in-flight uniqueness index). Repeat suppressions of
+    /// the same firing for the same reason collapse into the existing row:
+    /// `started_at` keeps the first suppression and `updated_at` advances to
+    /// the latest, so a trigger that keeps firing while deduplicated does not
+    /// grow the history by one row per poll.
+    pub async fn record_suppressed(
+        &self,
+        schedule_id: UniversalUuid,
+        context_hash: &str,
+        reason: SuppressionReason,
+        duplicate_of: UniversalUuid,
+    ) -> Result<ScheduleExecution, ValidationError> {
+        let now = UniversalTimestamp::now();
+        let reason = reason.as_str().to_string();
+
+        let existing: Option<UnifiedScheduleExecution> = {
+            let context_hash = context_hash.to_string();
+            let reason = reason.clone();
+            crate::interact_on_backend!(self.dal, |conn| {
+                schedule_executions::table
+                    .filter(schedule_executions::schedule_id.eq(schedule_id))
+                    .filter(schedule_executions::context_hash.eq(context_hash))
+                    .filter(schedule_executions::suppressed_reason.eq(reason))
+                    .filter(schedule_executions::duplicate_of.eq(duplicate_of))
+                    .first(conn)
+                    .optional()
+            })?
+        };
+
+        let id = match existing {
+            Some(row) => {
+                let id = row.id;
+                crate::interact_on_backend!(self.dal, |conn| {
+                    diesel::update(schedule_executions::table.find(id))
+                        .set((
+                            schedule_executions::completed_at.eq(Some(now)),
+                            schedule_executions::updated_at.eq(now),
+                        ))
+                        .execute(conn)
+                })?;
+                id
+            }
+            None => {
+                let new_unified = NewUnifiedScheduleExecution {
+                    id: UniversalUuid::new_v4(),
+                    schedule_id,
+                    workflow_execution_id: None,
+                    scheduled_time: None,
+                    claimed_at: None,
+                    context_hash: Some(context_hash.to_string()),
+                    started_at: now,
+                    completed_at: Some(now),
+                    created_at: now,
+                    updated_at: now,
+                    suppressed_reason: Some(reason),
+                    duplicate_of: Some(duplicate_of),
+                };
+                let id = new_unified.id;
+                crate::interact_on_backend!(self.dal, |conn| {
+                    diesel::insert_into(schedule_executions::table)
+                        .values(&new_unified)
+                        .execute(conn)
+                })?;
+                id
+            }
+        };
+
+        self.get_by_id(id).await
+    }
+
     /// Updates the workflow execution ID for a schedule execution.
     pub async fn update_workflow_execution_id(
         &self,
@@ -228,8 +379,11 @@ impl<'a> ScheduleExecutionDAL<'a> {
 
         let (total_executions, successful_executions, lost_executions) =
             crate::interact_on_backend!(self.dal, |conn| {
+                // Suppressed (deduplicated) firings never ran, so they count
+                // toward neither the total nor the lost executions.
                 let total_executions: i64 = schedule_executions::table
                     .filter(schedule_executions::started_at.ge(since_ts))
+                    .filter(schedule_executions::suppressed_reason.is_null())
                     .count()
                     .first(conn)?;
 
@@ -245,6 +399,7 @@ impl<'a> ScheduleExecutionDAL<'a> {
                             .eq(workflow_executions::id.nullable())),
                     )
                     .filter(workflow_executions::id.is_null())
+                    .filter(schedule_executions::suppressed_reason.is_null())
                     .filter(schedule_executions::started_at.ge(since_ts))
                     .filter(schedule_executions::started_at.lt(lost_cutoff))
                     .count()
@@ -275,7 +430,7 @@ mod tests {
     use super::*;
     use crate::database::universal_types::UniversalTimestamp;
     use crate::database::Database;
-    use crate::models::schedule::{NewSchedule, NewScheduleExecution};
+    use crate::models::schedule::{NewSchedule, NewScheduleExecution, SuppressionReason};
 
     #[cfg(feature = "sqlite")]
     async fn unique_dal() -> DAL {
@@ -496,6 +651,100 @@ mod tests {
         assert!(!active);
     }
 
+    // ── dedup lookups + suppression ─────────────────────────────────
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_find_recent_by_hash_respects_window() {
+        let dal = unique_dal().await;
+        let sched_id = create_schedule(&dal).await;
+
+        let mut ne = new_exec(sched_id);
+        ne.context_hash = Some("file_a".to_string());
+        let exec = dal.schedule_execution().create(ne).await.unwrap();
+        dal.schedule_execution()
+            .complete(exec.id, Utc::now())
+            .await
+            .unwrap();
+
+        let within = dal
+            .schedule_execution()
+            .find_recent_by_hash(sched_id, "file_a", Utc::now() - Duration::hours(24))
+            .await
+            .unwrap();
+        assert_eq!(within.map(|e| e.id), Some(exec.id));
+
+        let outside = dal
+            .schedule_execution()
+            .find_recent_by_hash(sched_id, "file_a", Utc::now() + Duration::hours(1))
+            .await
+            .unwrap();
+        assert!(outside.is_none());
+
+        let other_hash = dal
+            .schedule_execution()
+            .find_recent_by_hash(sched_id, "file_b", Utc::now() - Duration::hours(24))
+            .await
+            .unwrap();
+        assert!(other_hash.is_none());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_record_suppressed_collapses_repeats() {
+        let dal = unique_dal().await;
+        let sched_id = create_schedule(&dal).await;
+
+        let mut ne = new_exec(sched_id);
+        ne.context_hash = Some("file_a".to_string());
+        let original = dal.schedule_execution().create(ne).await.unwrap();
+
+        let first = dal
+            .schedule_execution()
+            .record_suppressed(sched_id, "file_a", SuppressionReason::Active, original.id)
+            .await
+            .unwrap();
+        assert!(first.is_suppressed());
+        assert_eq!(first.suppressed_reason.as_deref(), Some("active"));
+        assert_eq!(first.duplicate_of, Some(original.id));
+        assert!(first.completed_at.is_some());
+
+        let again = dal
+            .schedule_execution()
+            .record_suppressed(sched_id, "file_a", SuppressionReason::Active, original.id)
+            .await
+            .unwrap();
+        assert_eq!(again.id, first.id);
+
+        // Suppressed rows neither count as active nor as a window match.
+        let active = dal
+            .schedule_execution()
+            .find_active_by_hash(sched_id, "file_a")
+            .await
+            .unwrap();
+        assert_eq!(active.map(|e| e.id), Some(original.id));
+        let recent = dal
+            .schedule_execution()
+            .find_recent_by_hash(sched_id, "file_a", Utc::now() - Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(recent.map(|e| e.id), Some(original.id));
+
+        let history = dal
+            .schedule_execution()
+            .list_by_schedule(sched_id, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+
+        let stats = dal
+            .schedule_execution()
+            .get_execution_stats(Utc::now() - Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(stats.total_executions, 1);
+    }
+
     // ── update_workflow_execution_id ────────────────────────────────
 
     #[cfg(feature = "sqlite")]
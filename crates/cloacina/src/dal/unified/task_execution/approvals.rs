@@ -0,0 +1,242 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Approval gates for task executions.
+//!
+//! A task that requires approval is parked in `AwaitingApproval` once its
+//! dependencies and trigger rules pass. Approving it returns it to
+//! `NotStarted` for the scheduler to mark ready; rejecting it fails it.
+
+use super::TaskExecutionDAL;
+use crate::dal::unified::models::{
+    NewUnifiedExecutionEvent, NewUnifiedTaskApproval, UnifiedTaskApproval, UnifiedTaskExecution,
+};
+use crate::database::schema::unified::{execution_events, task_approvals, task_executions};
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::{ErrorCategory, TaskErrorPayload, ValidationError, TASK_REJECTED_CODE};
+use crate::models::execution_event::ExecutionEventType;
+use crate::models::task_execution::{ApprovalDecision, TaskApproval, TaskExecution};
+use diesel::prelude::*;
+
+impl<'a> TaskExecutionDAL<'a> {
+    /// Parks a pending task until an approval decision is recorded.
+    ///
+    /// This operation is transactional: the status update and the
+    /// `TaskAwaitingApproval` event are written atomically. Returns `false`
+    /// if the task was no longer pending.
+    pub async fn park_for_approval(&self, task_id: UniversalUuid) -> Result<bool, ValidationError> {
+        use diesel::connection::Connection;
+
+        let parked = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<bool, diesel::result::Error, _>(|conn| {
+                let now = UniversalTimestamp::now();
+                let task: UnifiedTaskExecution =
+                    task_executions::table.find(task_id).first(conn)?;
+
+                let rows = diesel::update(
+                    task_executions::table
+                        .find(task_id)
+                        .filter(task_executions::status.eq_any(vec!["NotStarted", "Pending"])),
+                )
+                .set((
+                    task_executions::status.eq("AwaitingApproval"),
+                    task_executions::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+                if rows > 0 {
+                    let event = NewUnifiedExecutionEvent {
+                        id: UniversalUuid::new_v4(),
+                        workflow_execution_id: task.workflow_execution_id,
+                        task_execution_id: Some(task_id),
+                        event_type: ExecutionEventType::TaskAwaitingApproval
+                            .as_str()
+                            .to_string(),
+                        event_data: None,
+                        worker_id: None,
+                        created_at: now,
+                        request_id: None,
+                        runner_id: None,
+                        tenant_id: None,
+                    };
+                    diesel::insert_into(execution_events::table)
+                        .values(&event)
+                        .execute(conn)?;
+                }
+
+                Ok(rows > 0)
+            })
+        })?;
+
+        Ok(parked)
+    }
+
+    /// Records a decision on a task in `AwaitingApproval`.
+    ///
+    /// An approved task returns to `NotStarted` (with a `TaskApproved`
+    /// event) and is marked ready on the next scheduler tick. A rejected task
+    /// is marked `Failed` with a `TASK_REJECTED` error payload (with a
+    /// `TaskFailed` event) and is not retried. The decision, status update
+    /// and event are written in one transaction. Returns `None` if the task
+    /// was not awaiting approval.
+    pub async fn decide_approval(
+        &self,
+        task_id: UniversalUuid,
+        decision: ApprovalDecision,
+        approver: &str,
+        comment: Option<&str>,
+    ) -> Result<Option<TaskApproval>, ValidationError> {
+        use diesel::connection::Connection;
+
+        let message = match comment {
+            Some(comment) => format!("Rejected by {}: {}", approver, comment),
+            None => format!("Rejected by {}", approver),
+        };
+        let payload = serde_json::to_value(
+            TaskErrorPayload::new(TASK_REJECTED_CODE, message.clone())
+                .with_category(ErrorCategory::PermissionDenied),
+        )
+        .map_err(|e| ValidationError::Context(e.into()))?;
+        let approver = approver.to_string();
+        let comment = comment.map(str::to_string);
+        let row = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<Option<UnifiedTaskApproval>, diesel::result::Error, _>(|conn| {
+                let now = UniversalTimestamp::now();
+                let task: UnifiedTaskExecution =
+                    task_executions::table.find(task_id).first(conn)?;
+                let awaiting = task_executions::table
+                    .find(task_id)
+                    .filter(task_executions::status.eq("AwaitingApproval"));
+
+                let (rows, event_type, event_data) = match decision {
+                    ApprovalDecision::Approved => {
+                        let rows = diesel::update(awaiting)
+                            .set((
+                                task_executions::status.eq("NotStarted"),
+                                task_executions::updated_at.eq(now),
+                            ))
+                            .execute(conn)?;
+                        let data = serde_json::json!({
+                            "approver": approver,
+                            "comment": comment,
+                        });
+                        (rows, ExecutionEventType::TaskApproved, data)
+                    }
+                    ApprovalDecision::Rejected => {
+                        let rows = diesel::update(awaiting)
+                            .set((
+                                task_executions::status.eq("Failed"),
+                                task_executions::completed_at.eq(Some(now)),
+                                task_executions::last_error.eq(&message),
+                                task_executions::error_payload.eq(payload.to_string()),
+                                task_executions::updated_at.eq(now),
+                            ))
+                            .execute(conn)?;
+                        let data = serde_json::json!({
+                            "error": message,
+                            "payload": payload,
+                        });
+                        (rows, ExecutionEventType::TaskFailed, data)
+                    }
+                };
+                if rows == 0 {
+                    return Ok(None);
+                }
+
+                let approval = NewUnifiedTaskApproval {
+                    id: UniversalUuid::new_v4(),
+                    workflow_execution_id: task.workflow_execution_id,
+                    task_execution_id: task_id,
+                    task_name: task.task_name.clone(),
+                    decision: decision.as_str().to_string(),
+                    approver: approver.clone(),
+                    comment: comment.clone(),
+                    decided_at: now,
+                };
+                diesel::insert_into(task_approvals::table)
+                    .values(&approval)
+                    .execute(conn)?;
+
+                let event = NewUnifiedExecutionEvent {
+                    id: UniversalUuid::new_v4(),
+                    workflow_execution_id: task.workflow_execution_id,
+                    task_execution_id: Some(task_id),
+                    event_type: event_type.as_str().to_string(),
+                    event_data: Some(event_data.to_string()),
+                    worker_id: None,
+                    created_at: now,
+                    request_id: None,
+                    runner_id: None,
+                    tenant_id: None,
+                };
+                diesel::insert_into(execution_events::table)
+                    .values(&event)
+                    .execute(conn)?;
+
+                task_approvals::table
+                    .find(approval.id)
+                    .first(conn)
+                    .map(Some)
+            })
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    /// Lists the tasks awaiting approval across all workflow executions,
+    /// longest waiting first.
+    pub async fn get_awaiting_approval(&self) -> Result<Vec<TaskExecution>, ValidationError> {
+        let tasks: Vec<UnifiedTaskExecution> = crate::interact_on_backend!(self.dal, |conn| {
+            task_executions::table
+                .filter(task_executions::status.eq("AwaitingApproval"))
+                .order(task_executions::updated_at.asc())
+                .load(conn)
+        })?;
+
+        Ok(tasks.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the most recent approval decision for a task execution.
+    pub async fn latest_approval(
+        &self,
+        task_execution_id: UniversalUuid,
+    ) -> Result<Option<TaskApproval>, ValidationError> {
+        let row: Option<UnifiedTaskApproval> = crate::interact_on_backend!(self.dal, |conn| {
+            task_approvals::table
+                .filter(task_approvals::task_execution_id.eq(task_execution_id))
+                .order(task_approvals::decided_at.desc())
+                .first(conn)
+                .optional()
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    /// Lists the approval decisions of a workflow execution, oldest first.
+    pub async fn list_approvals(
+        &self,
+        workflow_execution_id: UniversalUuid,
+    ) -> Result<Vec<TaskApproval>, ValidationError> {
+        let rows: Vec<UnifiedTaskApproval> = crate::interact_on_backend!(self.dal, |conn| {
+            task_approvals::table
+                .filter(task_approvals::workflow_execution_id.eq(workflow_execution_id))
+                .order(task_approvals::decided_at.asc())
+                .load(conn)
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context access records for task executions.
+
+use super::TaskExecutionDAL;
+use crate::dal::unified::models::{NewUnifiedTaskContextAccess, UnifiedTaskContextAccess};
+use crate::database::schema::unified::task_context_access;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::models::task_execution::TaskContextAccess;
+use diesel::prelude::*;
+
+impl<'a> TaskExecutionDAL<'a> {
+    /// Records the context keys one task attempt read and wrote.
+    pub async fn record_context_access(
+        &self,
+        workflow_execution_id: UniversalUuid,
+        task_execution_id: UniversalUuid,
+        task_name: &str,
+        attempt: i32,
+        reads: &[String],
+        writes: &[String],
+    ) -> Result<(), ValidationError> {
+        let encode = |keys: &[String]| {
+            serde_json::to_string(keys).map_err(|e| ValidationError::DatabaseQuery {
+                message: format!("failed to encode context keys: {}", e),
+            })
+        };
+        let row = NewUnifiedTaskContextAccess {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id,
+            task_execution_id,
+            task_name: task_name.to_string(),
+            attempt,
+            reads: encode(reads)?,
+            writes: encode(writes)?,
+            recorded_at: UniversalTimestamp::now(),
+        };
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(task_context_access::table)
+                .values(&row)
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Lists the context access records of a workflow execution, oldest
+    /// first. Retried tasks have one record per traced attempt.
+    pub async fn list_context_access(
+        &self,
+        workflow_execution_id: UniversalUuid,
+    ) -> Result<Vec<TaskContextAccess>, ValidationError> {
+        let rows: Vec<UnifiedTaskContextAccess> = crate::interact_on_backend!(self.dal, |conn| {
+            task_context_access::table
+                .filter(task_context_access::workflow_execution_id.eq(workflow_execution_id))
+                .order(task_context_access::recorded_at.asc())
+                .load(conn)
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context lineage records for task executions.
+//!
+//! The records carry context values, so they are sealed with the database's
+//! at-rest keys like the contexts themselves.
+
+use super::TaskExecutionDAL;
+use crate::context_lineage::ContextChange;
+use crate::crypto::at_rest::ENCRYPTED_TEXT_PREFIX;
+use crate::dal::unified::models::{NewUnifiedTaskContextChanges, UnifiedTaskContextChanges};
+use crate::database::schema::unified::task_context_changes;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::models::task_execution::TaskContextChanges;
+use diesel::prelude::*;
+
+impl<'a> TaskExecutionDAL<'a> {
+    /// Records the context keys one successful task attempt changed.
+    pub async fn record_context_changes(
+        &self,
+        workflow_execution_id: UniversalUuid,
+        task_execution_id: UniversalUuid,
+        task_name: &str,
+        attempt: i32,
+        changes: &[ContextChange],
+    ) -> Result<(), ValidationError> {
+        let id = UniversalUuid::new_v4();
+        let encoded =
+            serde_json::to_string(changes).map_err(|e| ValidationError::DatabaseQuery {
+                message: format!("failed to encode context changes: {}", e),
+            })?;
+        let changes = match self.dal.database.at_rest_encryption() {
+            Some(encryption) => encryption
+                .encrypt_text(&encoded, id.0.as_bytes())
+                .await
+                .map_err(|e| ValidationError::DatabaseQuery {
+                    message: format!("failed to seal context changes: {}", e),
+                })?,
+            None => encoded,
+        };
+        let row = NewUnifiedTaskContextChanges {
+            id,
+            workflow_execution_id,
+            task_execution_id,
+            task_name: task_name.to_string(),
+            attempt,
+            changes,
+            recorded_at: UniversalTimestamp::now(),
+        };
+
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(task_context_changes::table)
+                .values(&row)
+                .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
+    /// Lists the context lineage records of a workflow execution, oldest
+    /// first. Retried tasks have one record per successful attempt.
+    pub async fn list_context_changes(
+        &self,
+        workflow_execution_id: UniversalUuid,
+    ) -> Result<Vec<TaskContextChanges>, ValidationError> {
+        let rows: Vec<UnifiedTaskContextChanges> = crate::interact_on_backend!(self.dal, |conn| {
+            task_context_changes::table
+                .filter(task_context_changes::workflow_execution_id.eq(workflow_execution_id))
+                .order(task_context_changes::recorded_at.asc())
+                .load(conn)
+        })?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let changes = if row.changes.starts_with(ENCRYPTED_TEXT_PREFIX) {
+                let encryption = self.dal.database.at_rest_encryption().ok_or_else(|| {
+                    ValidationError::DatabaseQuery {
+                        message: "context changes are encrypted but no at-rest keys are configured"
+                            .to_string(),
+                    }
+                })?;
+                encryption
+                    .decrypt_text(row.changes, row.id.0.as_bytes())
+                    .await
+                    .map_err(|e| ValidationError::DatabaseQuery {
+                        message: format!("failed to open context changes: {}", e),
+                    })?
+            } else {
+                row.changes
+            };
+            records.push(TaskContextChanges {
+                id: row.id,
+                workflow_execution_id: row.workflow_execution_id,
+                task_execution_id: row.task_execution_id,
+                task_name: row.task_name,
+                attempt: row.attempt,
+                changes: serde_json::from_str(&changes).map_err(|e| {
+                    ValidationError::DatabaseQuery {
+                        message: format!("invalid context changes: {}", e),
+                    }
+                })?,
+                recorded_at: row.recorded_at,
+            });
+        }
+        Ok(records)
+    }
+}
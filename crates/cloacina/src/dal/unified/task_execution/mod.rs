@@ -26,7 +26,10 @@
 //! - Atomic task claiming for distributed execution
 //! - Workflow completion and failure detection
 
+mod approvals;
 mod claiming;
+mod context_access;
+mod context_changes;
 mod crud;
 mod queries;
 mod recovery;
@@ -90,6 +93,15 @@ pub struct StaleClaim {
     pub heartbeat_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Changes made when a failed task is manually resolved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolveResult {
+    /// Skipped tasks returned to NotStarted so the scheduler re-evaluates them
+    pub reopened: Vec<UniversalUuid>,
+    /// Whether the execution had already failed and was set back to Running
+    pub execution_reopened: bool,
+}
+
 /// Data access layer for task execution operations with runtime backend selection.
 #[derive(Clone)]
 pub struct TaskExecutionDAL<'a> {
@@ -74,6 +74,38 @@ impl<'a> TaskExecutionDAL<'a> {
         Ok(n)
     }
 
+    /// Number of in-flight tasks (running, or claimed for dispatch) per
+    /// workflow execution. Executions with none are absent from the map.
+    pub async fn count_in_flight_for(
+        &self,
+        workflow_execution_ids: &[UniversalUuid],
+    ) -> Result<std::collections::HashMap<UniversalUuid, usize>, ValidationError> {
+        let mut counts = std::collections::HashMap::new();
+        if workflow_execution_ids.is_empty() {
+            return Ok(counts);
+        }
+
+        let ids = workflow_execution_ids.to_vec();
+        let rows: Vec<UniversalUuid> = crate::interact_on_backend!(self.dal, |conn| {
+            task_executions::table
+                .filter(task_executions::workflow_execution_id.eq_any(&ids))
+                .filter(
+                    task_executions::status
+                        .eq("Running")
+                        .or(task_executions::status
+                            .eq("Ready")
+                            .and(task_executions::claimed_by.is_not_null())),
+                )
+                .select(task_executions::workflow_execution_id)
+                .load(conn)
+        })?;
+
+        for id in rows {
+            *counts.entry(id).or_default() += 1;
+        }
+        Ok(counts)
+    }
+
     /// Checks if all tasks in a workflow execution have reached a terminal state.
     pub async fn check_workflow_completion(
         &self,
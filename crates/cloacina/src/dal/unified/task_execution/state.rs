@@ -19,16 +19,18 @@
 //! All state transitions are transactional: the status update and execution event
 //! are written atomically. If either fails, both are rolled back.
 
-use super::TaskExecutionDAL;
+use super::{ResolveResult, TaskExecutionDAL};
 use crate::dal::unified::models::{NewUnifiedExecutionEvent, UnifiedTaskExecution};
 // Only the sqlite mark_ready sets created_at explicitly (no column default on
 // sqlite); the postgres path now relies on DEFAULT CURRENT_TIMESTAMP, so this is
 // unused under postgres-only builds without the gate.
 #[cfg(feature = "sqlite")]
 use crate::dal::unified::models::NewUnifiedTaskOutbox;
-use crate::database::schema::unified::{execution_events, task_executions, task_outbox};
+use crate::database::schema::unified::{
+    execution_events, task_executions, task_outbox, workflow_executions,
+};
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
-use crate::error::ValidationError;
+use crate::error::{TaskErrorPayload, ValidationError};
 use crate::models::execution_event::ExecutionEventType;
 use diesel::prelude::*;
 
@@ -115,10 +117,30 @@ impl<'a> TaskExecutionDAL<'a> {
         task_id: UniversalUuid,
         error_message: &str,
         runner_id: Option<UniversalUuid>,
+    ) -> Result<bool, ValidationError> {
+        self.mark_failed_with_payload(task_id, error_message, None, runner_id)
+            .await
+    }
+
+    /// Like [`mark_failed`](Self::mark_failed), also storing the structured
+    /// payload of a `TaskError::Structured` failure in `error_payload` and in
+    /// the `TaskFailed` event. A `None` payload clears any payload left by an
+    /// earlier attempt.
+    pub async fn mark_failed_with_payload(
+        &self,
+        task_id: UniversalUuid,
+        error_message: &str,
+        payload: Option<&TaskErrorPayload>,
+        runner_id: Option<UniversalUuid>,
     ) -> Result<bool, ValidationError> {
         use diesel::connection::Connection;
 
         let error_message = error_message.to_string();
+        let payload_value = payload
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| ValidationError::Context(e.into()))?;
+        let payload_json = payload_value.as_ref().map(|v| v.to_string());
         let applied = crate::interact_on_backend!(self.dal, |conn| {
             conn.transaction::<bool, diesel::result::Error, _>(|conn| {
                 let now = UniversalTimestamp::now();
@@ -132,6 +154,7 @@ impl<'a> TaskExecutionDAL<'a> {
                     task_executions::status.eq("Failed"),
                     task_executions::completed_at.eq(Some(now)),
                     task_executions::last_error.eq(&error_message),
+                    task_executions::error_payload.eq(&payload_json),
                     task_executions::updated_at.eq(now),
                 );
                 let rows = if let Some(rid) = runner_id {
@@ -150,7 +173,11 @@ impl<'a> TaskExecutionDAL<'a> {
 
                 // Only insert event if the update was applied
                 if rows > 0 {
-                    let event_data = serde_json::json!({ "error": error_message }).to_string();
+                    let mut event_data = serde_json::json!({ "error": error_message });
+                    if let Some(payload) = &payload_value {
+                        event_data["payload"] = payload.clone();
+                    }
+                    let event_data = event_data.to_string();
                     let event = NewUnifiedExecutionEvent {
                         id: UniversalUuid::new_v4(),
                         workflow_execution_id: task.workflow_execution_id,
@@ -175,6 +202,88 @@ impl<'a> TaskExecutionDAL<'a> {
         Ok(applied)
     }
 
+    /// Fails every unfinished task of a workflow execution, e.g. when the
+    /// execution runs past its workflow timeout.
+    ///
+    /// Tasks that are not yet `Completed`, `Failed` or `Skipped` are marked
+    /// `Failed` with `error_message` and `payload`, their claims are released
+    /// (so a late result from a still-running attempt is dropped as a lost
+    /// claim), their outbox rows are removed, and a `TaskFailed` event is
+    /// written for each, all in one transaction. Returns how many tasks were
+    /// failed.
+    pub async fn fail_unfinished(
+        &self,
+        workflow_execution_id: UniversalUuid,
+        error_message: &str,
+        payload: &TaskErrorPayload,
+    ) -> Result<usize, ValidationError> {
+        use diesel::connection::Connection;
+
+        let error_message = error_message.to_string();
+        let payload_value =
+            serde_json::to_value(payload).map_err(|e| ValidationError::Context(e.into()))?;
+        let payload_json = payload_value.to_string();
+        let failed = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<usize, diesel::result::Error, _>(|conn| {
+                let now = UniversalTimestamp::now();
+
+                let task_ids: Vec<UniversalUuid> = task_executions::table
+                    .filter(task_executions::workflow_execution_id.eq(workflow_execution_id))
+                    .filter(task_executions::status.ne_all(vec!["Completed", "Failed", "Skipped"]))
+                    .select(task_executions::id)
+                    .load(conn)?;
+                if task_ids.is_empty() {
+                    return Ok(0);
+                }
+
+                diesel::delete(
+                    task_outbox::table.filter(task_outbox::task_execution_id.eq_any(&task_ids)),
+                )
+                .execute(conn)?;
+                diesel::update(
+                    task_executions::table.filter(task_executions::id.eq_any(&task_ids)),
+                )
+                .set((
+                    task_executions::status.eq("Failed"),
+                    task_executions::completed_at.eq(Some(now)),
+                    task_executions::last_error.eq(&error_message),
+                    task_executions::error_payload.eq(Some(&payload_json)),
+                    task_executions::claimed_by.eq(None::<UniversalUuid>),
+                    task_executions::heartbeat_at.eq(None::<UniversalTimestamp>),
+                    task_executions::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+                let event_data = serde_json::json!({
+                    "error": error_message,
+                    "payload": payload_value,
+                })
+                .to_string();
+                for task_id in &task_ids {
+                    let event = NewUnifiedExecutionEvent {
+                        id: UniversalUuid::new_v4(),
+                        workflow_execution_id,
+                        task_execution_id: Some(*task_id),
+                        event_type: ExecutionEventType::TaskFailed.as_str().to_string(),
+                        event_data: Some(event_data.clone()),
+                        worker_id: None,
+                        created_at: now,
+                        request_id: None,
+                        runner_id: None,
+                        tenant_id: None,
+                    };
+                    diesel::insert_into(execution_events::table)
+                        .values(&event)
+                        .execute(conn)?;
+                }
+
+                Ok(task_ids.len())
+            })
+        })?;
+
+        Ok(failed)
+    }
+
     /// Marks a task as ready for execution.
     ///
     /// This operation is transactional: the status update, execution event,
@@ -414,6 +523,129 @@ impl<'a> TaskExecutionDAL<'a> {
         Ok(())
     }
 
+    /// Marks a Failed task as Completed on an operator's say-so, for side
+    /// effects that were finished out-of-band.
+    ///
+    /// `reopen` lists tasks downstream of it; those that are Skipped go back
+    /// to NotStarted so the scheduler re-evaluates their trigger rules. An
+    /// execution that already failed is set back to Running. Returns `None`,
+    /// changing nothing, when the task is not Failed.
+    ///
+    /// This operation is transactional: the status updates and execution
+    /// events are written atomically.
+    pub async fn resolve_failed(
+        &self,
+        task_id: UniversalUuid,
+        reopen: Vec<UniversalUuid>,
+        note: &str,
+    ) -> Result<Option<ResolveResult>, ValidationError> {
+        use diesel::connection::Connection;
+
+        let note = note.to_string();
+        let result = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                let now = UniversalTimestamp::now();
+                let task: UnifiedTaskExecution =
+                    task_executions::table.find(task_id).first(conn)?;
+
+                let rows = diesel::update(
+                    task_executions::table
+                        .find(task_id)
+                        .filter(task_executions::status.eq("Failed")),
+                )
+                .set((
+                    task_executions::status.eq("Completed"),
+                    task_executions::completed_at.eq(Some(now)),
+                    task_executions::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+                if rows == 0 {
+                    return Ok(None);
+                }
+
+                let event = |task_execution_id, event_type: ExecutionEventType, data| {
+                    NewUnifiedExecutionEvent {
+                        id: UniversalUuid::new_v4(),
+                        workflow_execution_id: task.workflow_execution_id,
+                        task_execution_id,
+                        event_type: event_type.as_str().to_string(),
+                        event_data: Some(data),
+                        worker_id: None,
+                        created_at: now,
+                        request_id: None,
+                        runner_id: None,
+                        tenant_id: None,
+                    }
+                };
+                let mut events = vec![event(
+                    Some(task_id),
+                    ExecutionEventType::TaskResolved,
+                    serde_json::json!({ "note": note }).to_string(),
+                )];
+
+                let reopened: Vec<UniversalUuid> = task_executions::table
+                    .filter(task_executions::id.eq_any(&reopen))
+                    .filter(task_executions::workflow_execution_id.eq(task.workflow_execution_id))
+                    .filter(task_executions::status.eq("Skipped"))
+                    .select(task_executions::id)
+                    .load(conn)?;
+                if !reopened.is_empty() {
+                    diesel::update(
+                        task_executions::table.filter(task_executions::id.eq_any(&reopened)),
+                    )
+                    .set((
+                        task_executions::status.eq("NotStarted"),
+                        task_executions::error_details.eq(None::<String>),
+                        task_executions::completed_at.eq(None::<UniversalTimestamp>),
+                        task_executions::updated_at.eq(now),
+                    ))
+                    .execute(conn)?;
+                }
+                let reason = serde_json::json!({
+                    "reason": format!("upstream '{}' manually resolved", task.task_name)
+                })
+                .to_string();
+                for id in &reopened {
+                    events.push(event(
+                        Some(*id),
+                        ExecutionEventType::TaskReset,
+                        reason.clone(),
+                    ));
+                }
+
+                let execution_reopened = diesel::update(
+                    workflow_executions::table
+                        .find(task.workflow_execution_id)
+                        .filter(workflow_executions::status.eq("Failed")),
+                )
+                .set((
+                    workflow_executions::status.eq("Running"),
+                    workflow_executions::completed_at.eq(None::<UniversalTimestamp>),
+                    workflow_executions::error_details.eq(None::<String>),
+                    workflow_executions::updated_at.eq(now),
+                ))
+                .execute(conn)?
+                    > 0;
+                if execution_reopened {
+                    events.push(event(None, ExecutionEventType::WorkflowResumed, reason));
+                }
+
+                for event in &events {
+                    diesel::insert_into(execution_events::table)
+                        .values(event)
+                        .execute(conn)?;
+                }
+
+                Ok(Some(ResolveResult {
+                    reopened,
+                    execution_reopened,
+                }))
+            })
+        })?;
+
+        Ok(result)
+    }
+
     /// Marks a task as permanently abandoned after too many recovery attempts.
     ///
     /// This operation is transactional: the status update and execution event
@@ -23,7 +23,7 @@
 use super::models::{NewUnifiedTaskExecutionMetadata, UnifiedTaskExecutionMetadata};
 use super::DAL;
 use crate::database::schema::unified::{contexts, task_execution_metadata};
-use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::database::universal_types::{UniversalBinary, UniversalTimestamp, UniversalUuid};
 use crate::error::ValidationError;
 use crate::models::task_execution_metadata::{NewTaskExecutionMetadata, TaskExecutionMetadata};
 use crate::task::TaskNamespace;
@@ -311,23 +311,41 @@ impl<'a> TaskExecutionMetadataDAL<'a> {
             .map(|ns| ns.to_string())
             .collect();
 
-        let results: Vec<(UnifiedTaskExecutionMetadata, Option<String>)> =
-            crate::interact_on_backend!(self.dal, |conn| {
-                task_execution_metadata::table
-                    .left_join(
-                        contexts::table
-                            .on(task_execution_metadata::context_id.eq(contexts::id.nullable())),
-                    )
-                    .filter(task_execution_metadata::workflow_execution_id.eq(workflow_id))
-                    .filter(task_execution_metadata::task_name.eq_any(dependency_task_names))
-                    .select((
-                        task_execution_metadata::all_columns,
-                        contexts::value.nullable(),
-                    ))
-                    .load::<(UnifiedTaskExecutionMetadata, Option<String>)>(conn)
-            })?;
+        let results: Vec<(
+            UnifiedTaskExecutionMetadata,
+            Option<String>,
+            Option<UniversalBinary>,
+        )> = crate::interact_on_backend!(self.dal, |conn| {
+            task_execution_metadata::table
+                .left_join(
+                    contexts::table
+                        .on(task_execution_metadata::context_id.eq(contexts::id.nullable())),
+                )
+                .filter(task_execution_metadata::workflow_execution_id.eq(workflow_id))
+                .filter(task_execution_metadata::task_name.eq_any(dependency_task_names))
+                .select((
+                    task_execution_metadata::all_columns,
+                    contexts::value.nullable(),
+                    contexts::payload.nullable(),
+                ))
+                .load(conn)
+        })?;
 
-        Ok(results.into_iter().map(|(m, c)| (m.into(), c)).collect())
+        let contexts = self.dal.context();
+        let mut decoded = Vec::with_capacity(results.len());
+        for (metadata, value, payload) in results {
+            let value = match (value, metadata.context_id) {
+                (Some(value), Some(context_id)) => Some(
+                    contexts
+                        .open(value, payload.map(|p| p.0), context_id)
+                        .await
+                        .map_err(ValidationError::Context)?,
+                ),
+                (value, _) => value,
+            };
+            decoded.push((metadata.into(), value));
+        }
+        Ok(decoded)
     }
 }
 
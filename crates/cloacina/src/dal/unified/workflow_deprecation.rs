@@ -0,0 +1,122 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Unified Workflow Deprecation DAL
+//!
+//! Records which workflows are marked for retirement. The execution planner
+//! reads the record on every execution to log a warning, and the runner
+//! reads it before creating a schedule.
+
+use super::models::{NewUnifiedWorkflowDeprecation, UnifiedWorkflowDeprecation};
+use super::DAL;
+use crate::database::schema::unified::workflow_deprecations;
+use crate::database::universal_types::UniversalTimestamp;
+use crate::error::ValidationError;
+use crate::models::workflow_deprecation::WorkflowDeprecation;
+use diesel::prelude::*;
+
+/// Data access layer for workflow deprecations.
+#[derive(Clone)]
+pub struct WorkflowDeprecationDAL<'a> {
+    dal: &'a DAL,
+}
+
+impl<'a> WorkflowDeprecationDAL<'a> {
+    /// Creates a new WorkflowDeprecationDAL instance.
+    pub fn new(dal: &'a DAL) -> Self {
+        Self { dal }
+    }
+
+    /// Marks a workflow deprecated, or replaces the message and sunset date
+    /// of an existing deprecation. Re-deprecating keeps the original
+    /// `deprecated_at`.
+    pub async fn deprecate(
+        &self,
+        workflow_name: &str,
+        message: Option<String>,
+        sunset_at: Option<UniversalTimestamp>,
+    ) -> Result<WorkflowDeprecation, ValidationError> {
+        let workflow_name = workflow_name.to_string();
+        let now = UniversalTimestamp::now();
+
+        let row: UnifiedWorkflowDeprecation = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(workflow_deprecations::table)
+                .values(&NewUnifiedWorkflowDeprecation {
+                    workflow_name: workflow_name.clone(),
+                    message: message.clone(),
+                    sunset_at,
+                    deprecated_at: now,
+                    updated_at: now,
+                })
+                .on_conflict(workflow_deprecations::workflow_name)
+                .do_update()
+                .set((
+                    workflow_deprecations::message.eq(message.clone()),
+                    workflow_deprecations::sunset_at.eq(sunset_at),
+                    workflow_deprecations::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+            workflow_deprecations::table
+                .find(workflow_name.clone())
+                .first(conn)
+        })?;
+
+        Ok(row.into())
+    }
+
+    /// The deprecation of a workflow, or `None` when it is not deprecated.
+    pub async fn get(
+        &self,
+        workflow_name: &str,
+    ) -> Result<Option<WorkflowDeprecation>, ValidationError> {
+        let workflow_name = workflow_name.to_string();
+        let row: Option<UnifiedWorkflowDeprecation> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_deprecations::table
+                    .find(workflow_name)
+                    .first(conn)
+                    .optional()
+            })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    /// Every deprecated workflow, soonest sunset first; those without a
+    /// sunset date come last.
+    pub async fn list(&self) -> Result<Vec<WorkflowDeprecation>, ValidationError> {
+        let rows: Vec<UnifiedWorkflowDeprecation> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_deprecations::table
+                    .order(workflow_deprecations::workflow_name.asc())
+                    .load(conn)
+            })?;
+
+        let mut deprecations: Vec<WorkflowDeprecation> = rows.into_iter().map(Into::into).collect();
+        deprecations.sort_by_key(|d| (d.sunset_at.is_none(), d.sunset_at.map(|s| s.0)));
+        Ok(deprecations)
+    }
+
+    /// Clears a workflow's deprecation. Returns `false` if it was not
+    /// deprecated.
+    pub async fn remove(&self, workflow_name: &str) -> Result<bool, ValidationError> {
+        let workflow_name = workflow_name.to_string();
+        let deleted = crate::interact_on_backend!(self.dal, |conn| {
+            diesel::delete(workflow_deprecations::table.find(workflow_name)).execute(conn)
+        })?;
+
+        Ok(deleted > 0)
+    }
+}
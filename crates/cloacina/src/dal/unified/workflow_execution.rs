@@ -18,17 +18,35 @@
 //!
 //! All state transitions are transactional: the status update and execution event
 //! are written atomically. If either fails, both are rolled back.
+//!
+//! [`WorkflowExecutionDAL::get_snapshot`] reads the other way round: the
+//! execution row, its task rows and its contexts in one read-only transaction
+//! at snapshot isolation, so a reader never pairs a completed execution with
+//! tasks from before it completed.
 
 use super::models::{
-    NewUnifiedExecutionEvent, NewUnifiedWorkflowExecution, UnifiedWorkflowExecution,
+    NewUnifiedExecutionEvent, NewUnifiedWorkflowExecution, NewUnifiedWorkflowExecutionAnnotation,
+    NewUnifiedWorkflowExecutionLabel, UnifiedDbContext, UnifiedTaskExecution,
+    UnifiedWorkflowExecution, UnifiedWorkflowExecutionAnnotation, UnifiedWorkflowExecutionLabel,
+    UnifiedWorkflowExecutionOptions, UnifiedWorkflowExecutionParams,
 };
 use super::DAL;
-use crate::database::schema::unified::{execution_events, workflow_executions};
+use crate::context::Context;
+use crate::database::schema::unified::{
+    contexts, execution_events, task_execution_metadata, task_executions, task_outbox,
+    workflow_execution_annotations, workflow_execution_labels, workflow_execution_options,
+    workflow_execution_params, workflow_executions,
+};
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
-use crate::error::ValidationError;
+use crate::error::{ContextError, ValidationError};
 use crate::models::execution_event::ExecutionEventType;
-use crate::models::workflow_execution::{NewWorkflowExecution, WorkflowExecutionRecord};
+use crate::models::task_execution::TaskExecution;
+use crate::models::workflow_execution::{
+    default_run_id, validate_labels, ExecutionAnnotation, ExecutionLabels, ExecutionOptions,
+    ExecutionParams, NewExecutionAnnotation, NewWorkflowExecution, WorkflowExecutionRecord,
+};
 use diesel::prelude::*;
+use std::collections::HashMap;
 
 /// Filter for `WorkflowExecutionDAL::list_filtered`. CLOACI-T-0594 /
 /// API-02: closes the silent-filter-drop bug where the REST route's
@@ -40,6 +58,11 @@ pub struct ExecutionListFilter {
     pub status: Option<String>,
     /// Filter by exact workflow name. `None` means no name filter.
     pub workflow_name: Option<String>,
+    /// Filter by exact run id. `None` means no run id filter.
+    pub run_id: Option<String>,
+    /// Label selector: only executions carrying every `(key, value)` pair.
+    /// Empty means no label filter.
+    pub labels: Vec<(String, String)>,
     /// SQL `LIMIT`. Caller is responsible for bounding (route validates
     /// `limit <= 1000`); the DAL trusts whatever is passed.
     pub limit: i64,
@@ -47,6 +70,94 @@ pub struct ExecutionListFilter {
     pub offset: i64,
 }
 
+/// Order of [`ExecutionPageQuery`] results, by `(started_at, id)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExecutionSort {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+}
+
+/// Query for `WorkflowExecutionDAL::list_page`: filters plus a keyset
+/// position, so pages stay stable while new executions are inserted.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionPageQuery {
+    /// Exact workflow name. `None` means every workflow.
+    pub workflow_name: Option<String>,
+    /// Any of these statuses. Empty means every status.
+    pub statuses: Vec<String>,
+    /// Only executions started at or after this time.
+    pub started_from: Option<UniversalTimestamp>,
+    /// Only executions started strictly before this time.
+    pub started_until: Option<UniversalTimestamp>,
+    /// Exact run id.
+    pub run_id: Option<String>,
+    /// Label selector: only executions carrying every `(key, value)` pair.
+    pub labels: Vec<(String, String)>,
+    pub sort: ExecutionSort,
+    /// `(started_at, id)` of the last row of the previous page.
+    pub after: Option<(UniversalTimestamp, UniversalUuid)>,
+    /// SQL `LIMIT`; the caller bounds it.
+    pub limit: i64,
+}
+
+/// An execution's full state as of one instant, read by
+/// [`WorkflowExecutionDAL::get_snapshot`].
+#[derive(Debug)]
+pub struct ExecutionSnapshot {
+    pub execution: WorkflowExecutionRecord,
+    /// Every task row of the execution
+    pub tasks: Vec<TaskExecution>,
+    /// Output context id of each task that saved one, by task execution id
+    pub output_context_ids: HashMap<UniversalUuid, UniversalUuid>,
+    /// The execution's context and every task output context, by context id
+    pub contexts: HashMap<UniversalUuid, Context<serde_json::Value>>,
+}
+
+impl ExecutionSnapshot {
+    /// The context the execution started with, holding its final state once
+    /// it has completed.
+    pub fn execution_context(&self) -> Option<&Context<serde_json::Value>> {
+        self.contexts.get(self.execution.context_id.as_ref()?)
+    }
+
+    /// The context task execution `task_execution_id` saved as its output.
+    pub fn task_output(
+        &self,
+        task_execution_id: UniversalUuid,
+    ) -> Option<&Context<serde_json::Value>> {
+        self.contexts
+            .get(self.output_context_ids.get(&task_execution_id)?)
+    }
+}
+
+/// Makes the enclosing transaction read from one snapshot of the database.
+/// Must run before the transaction's first query.
+trait SnapshotIsolation {
+    fn begin_snapshot(&mut self) -> QueryResult<()>;
+}
+
+#[cfg(feature = "postgres")]
+impl SnapshotIsolation for diesel::PgConnection {
+    /// Postgres defaults to READ COMMITTED, where each statement sees the
+    /// rows committed before it started; REPEATABLE READ pins the first
+    /// statement's snapshot for the whole transaction.
+    fn begin_snapshot(&mut self) -> QueryResult<()> {
+        diesel::sql_query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(self)
+            .map(|_| ())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl SnapshotIsolation for diesel::SqliteConnection {
+    /// A SQLite transaction already reads from the snapshot taken by its
+    /// first read.
+    fn begin_snapshot(&mut self) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
 /// Data access layer for workflow execution operations with compile-time backend selection.
 #[derive(Clone)]
 pub struct WorkflowExecutionDAL<'a> {
@@ -82,6 +193,8 @@ impl<'a> WorkflowExecutionDAL<'a> {
                     started_at: now,
                     created_at: now,
                     updated_at: now,
+                    run_id: Some(default_run_id("manual", now.0)),
+                    logical_date: Some(now),
                 };
 
                 // Insert workflow record
@@ -128,6 +241,69 @@ impl<'a> WorkflowExecutionDAL<'a> {
         Ok(execution.into())
     }
 
+    /// Reads the execution row, its task rows and its contexts (the
+    /// execution's own and each task's output) in one read-only transaction
+    /// at snapshot isolation, so the parts always agree: status endpoints
+    /// built on it never show a completed execution with a task still
+    /// `Running`. Encrypted contexts are decrypted after the transaction.
+    pub async fn get_snapshot(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<ExecutionSnapshot, ValidationError> {
+        use diesel::connection::Connection;
+
+        let (execution, tasks, outputs, context_rows) =
+            crate::interact_on_backend!(self.dal, |conn| {
+                conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                    conn.begin_snapshot()?;
+                    let execution: UnifiedWorkflowExecution =
+                        workflow_executions::table.find(id).first(conn)?;
+                    let tasks: Vec<UnifiedTaskExecution> = task_executions::table
+                        .filter(task_executions::workflow_execution_id.eq(id))
+                        .load(conn)?;
+                    let outputs: Vec<(UniversalUuid, Option<UniversalUuid>)> =
+                        task_execution_metadata::table
+                            .filter(task_execution_metadata::workflow_execution_id.eq(id))
+                            .select((
+                                task_execution_metadata::task_execution_id,
+                                task_execution_metadata::context_id,
+                            ))
+                            .load(conn)?;
+                    let context_ids: Vec<UniversalUuid> = outputs
+                        .iter()
+                        .filter_map(|(_, context_id)| *context_id)
+                        .chain(execution.context_id)
+                        .collect();
+                    let context_rows: Vec<UnifiedDbContext> = contexts::table
+                        .filter(contexts::id.eq_any(context_ids))
+                        .load(conn)?;
+                    Ok((execution, tasks, outputs, context_rows))
+                })
+            })?;
+
+        let context_dal = self.dal.context();
+        let mut contexts = HashMap::with_capacity(context_rows.len());
+        for row in context_rows {
+            let value = context_dal
+                .open(row.value, row.payload.map(|p| p.0), row.id)
+                .await?;
+            contexts.insert(
+                row.id,
+                Context::from_json(value).map_err(ContextError::from)?,
+            );
+        }
+
+        Ok(ExecutionSnapshot {
+            execution: execution.into(),
+            tasks: tasks.into_iter().map(Into::into).collect(),
+            output_context_ids: outputs
+                .into_iter()
+                .filter_map(|(task_id, context_id)| Some((task_id, context_id?)))
+                .collect(),
+            contexts,
+        })
+    }
+
     pub async fn get_active_executions(
         &self,
     ) -> Result<Vec<WorkflowExecutionRecord>, ValidationError> {
@@ -160,6 +336,23 @@ impl<'a> WorkflowExecutionDAL<'a> {
         Ok(())
     }
 
+    /// Record that a queued execution was admitted past its workflow's
+    /// concurrency limit. Only the first admission of an attempt is kept.
+    pub async fn mark_admitted(&self, id: UniversalUuid) -> Result<(), ValidationError> {
+        let now = UniversalTimestamp::now();
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::update(
+                workflow_executions::table
+                    .find(id)
+                    .filter(workflow_executions::admitted_at.is_null()),
+            )
+            .set(workflow_executions::admitted_at.eq(Some(now)))
+            .execute(conn)
+        })?;
+
+        Ok(())
+    }
+
     /// Set a workflow execution's trigger origin (CLOACI-T-0776). Called right
     /// after creation by the REST execute endpoint to mark an operator run as
     /// `"manual"`. Best-effort metadata — failures are non-fatal to the run.
@@ -178,6 +371,237 @@ impl<'a> WorkflowExecutionDAL<'a> {
         Ok(())
     }
 
+    /// Attach labels to a workflow execution. A key the execution already
+    /// carries has its value replaced; other existing labels are kept. Labels
+    /// are validated first, so a malformed set writes nothing.
+    pub async fn set_labels(
+        &self,
+        id: UniversalUuid,
+        labels: &ExecutionLabels,
+    ) -> Result<(), ValidationError> {
+        use diesel::connection::Connection;
+
+        validate_labels(labels)?;
+        if labels.is_empty() {
+            return Ok(());
+        }
+        let labels = labels.clone();
+        crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                let now = UniversalTimestamp::now();
+                let keys: Vec<String> = labels.keys().cloned().collect();
+                diesel::delete(
+                    workflow_execution_labels::table
+                        .filter(workflow_execution_labels::workflow_execution_id.eq(id))
+                        .filter(workflow_execution_labels::label_key.eq_any(keys)),
+                )
+                .execute(conn)?;
+                let rows: Vec<NewUnifiedWorkflowExecutionLabel> = labels
+                    .into_iter()
+                    .map(
+                        |(label_key, label_value)| NewUnifiedWorkflowExecutionLabel {
+                            workflow_execution_id: id,
+                            label_key,
+                            label_value,
+                            created_at: now,
+                        },
+                    )
+                    .collect();
+                diesel::insert_into(workflow_execution_labels::table)
+                    .values(&rows)
+                    .execute(conn)
+            })
+        })?;
+
+        Ok(())
+    }
+
+    /// Labels attached to a workflow execution (empty when unlabelled).
+    pub async fn get_labels(&self, id: UniversalUuid) -> Result<ExecutionLabels, ValidationError> {
+        let rows: Vec<UnifiedWorkflowExecutionLabel> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_labels::table
+                    .filter(workflow_execution_labels::workflow_execution_id.eq(id))
+                    .load(conn)
+            })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.label_key, r.label_value))
+            .collect())
+    }
+
+    /// Labels for a batch of workflow executions, keyed by execution id.
+    /// Unlabelled executions are absent from the map.
+    pub async fn get_labels_for(
+        &self,
+        ids: &[UniversalUuid],
+    ) -> Result<HashMap<UniversalUuid, ExecutionLabels>, ValidationError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let ids = ids.to_vec();
+        let rows: Vec<UnifiedWorkflowExecutionLabel> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_labels::table
+                    .filter(workflow_execution_labels::workflow_execution_id.eq_any(ids))
+                    .load(conn)
+            })?;
+
+        let mut labels: HashMap<UniversalUuid, ExecutionLabels> = HashMap::new();
+        for r in rows {
+            labels
+                .entry(r.workflow_execution_id)
+                .or_default()
+                .insert(r.label_key, r.label_value);
+        }
+        Ok(labels)
+    }
+
+    /// Options a workflow execution was submitted with; default options
+    /// when it was submitted without overrides.
+    pub async fn get_options(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<ExecutionOptions, ValidationError> {
+        Ok(self
+            .get_options_for(&[id])
+            .await?
+            .remove(&id)
+            .unwrap_or_default())
+    }
+
+    /// Options for a batch of workflow executions, keyed by execution id.
+    /// Executions submitted without overrides are absent from the map.
+    pub async fn get_options_for(
+        &self,
+        ids: &[UniversalUuid],
+    ) -> Result<HashMap<UniversalUuid, ExecutionOptions>, ValidationError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let ids = ids.to_vec();
+        let rows: Vec<UnifiedWorkflowExecutionOptions> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_options::table
+                    .filter(workflow_execution_options::workflow_execution_id.eq_any(ids))
+                    .load(conn)
+            })?;
+
+        rows.into_iter()
+            .map(|r| {
+                let options = serde_json::from_str(&r.options).map_err(|e| {
+                    ValidationError::InvalidExecutionOptions(format!(
+                        "stored options of execution {} are unreadable: {}",
+                        r.workflow_execution_id, e
+                    ))
+                })?;
+                Ok((r.workflow_execution_id, options))
+            })
+            .collect()
+    }
+
+    /// Parameters a workflow execution was started with; empty when it was
+    /// started without any.
+    pub async fn get_params(&self, id: UniversalUuid) -> Result<ExecutionParams, ValidationError> {
+        let row: Option<UnifiedWorkflowExecutionParams> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_params::table
+                    .find(id)
+                    .first(conn)
+                    .optional()
+            })?;
+
+        match row {
+            Some(row) => serde_json::from_str(&row.params).map_err(|e| {
+                ValidationError::InvalidExecutionOptions(format!(
+                    "stored params of execution {} are unreadable: {}",
+                    id, e
+                ))
+            }),
+            None => Ok(ExecutionParams::new()),
+        }
+    }
+
+    /// Record an operator annotation on a workflow execution. The note is
+    /// validated first, so a malformed annotation writes nothing.
+    pub async fn add_annotation(
+        &self,
+        id: UniversalUuid,
+        annotation: NewExecutionAnnotation,
+    ) -> Result<ExecutionAnnotation, ValidationError> {
+        annotation.validate()?;
+        let row = NewUnifiedWorkflowExecutionAnnotation {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: id,
+            author: annotation.author,
+            note: annotation.note,
+            resolution: annotation.resolution.map(|r| r.as_str().to_string()),
+            created_at: UniversalTimestamp::now(),
+        };
+        let record = ExecutionAnnotation {
+            id: row.id,
+            workflow_execution_id: row.workflow_execution_id,
+            author: row.author.clone(),
+            note: row.note.clone(),
+            resolution: row.resolution.clone(),
+            created_at: row.created_at,
+        };
+        crate::interact_on_backend!(self.dal, |conn| {
+            diesel::insert_into(workflow_execution_annotations::table)
+                .values(&row)
+                .execute(conn)
+        })?;
+
+        Ok(record)
+    }
+
+    /// Annotations on a workflow execution, oldest first.
+    pub async fn list_annotations(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<Vec<ExecutionAnnotation>, ValidationError> {
+        let rows: Vec<UnifiedWorkflowExecutionAnnotation> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_annotations::table
+                    .filter(workflow_execution_annotations::workflow_execution_id.eq(id))
+                    .order(workflow_execution_annotations::created_at.asc())
+                    .load(conn)
+            })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Current resolution for a batch of workflow executions, keyed by
+    /// execution id: the resolution of each execution's most recent annotation
+    /// that set one. Executions without a resolution are absent from the map.
+    pub async fn get_resolutions_for(
+        &self,
+        ids: &[UniversalUuid],
+    ) -> Result<HashMap<UniversalUuid, String>, ValidationError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let ids = ids.to_vec();
+        let rows: Vec<UnifiedWorkflowExecutionAnnotation> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                workflow_execution_annotations::table
+                    .filter(workflow_execution_annotations::workflow_execution_id.eq_any(ids))
+                    .filter(workflow_execution_annotations::resolution.is_not_null())
+                    .order(workflow_execution_annotations::created_at.asc())
+                    .load(conn)
+            })?;
+
+        // Ascending order: later annotations overwrite earlier ones.
+        let mut resolutions = HashMap::new();
+        for r in rows {
+            if let Some(resolution) = r.resolution {
+                resolutions.insert(r.workflow_execution_id, resolution);
+            }
+        }
+        Ok(resolutions)
+    }
+
     /// Marks a workflow execution as completed.
     ///
     /// This operation is transactional: the status update and execution event
@@ -304,6 +728,111 @@ impl<'a> WorkflowExecutionDAL<'a> {
         Ok(())
     }
 
+    /// Re-runs a failed attempt of the whole workflow.
+    ///
+    /// Instead of marking the execution Failed, sets it back to Pending on
+    /// its next `attempt`, not to start before `retry_at`, and resets every
+    /// task to NotStarted on its first attempt. The tasks' recorded outputs
+    /// are dropped so no stale output feeds the next attempt. Returns
+    /// `false`, changing nothing, when the execution already finished.
+    ///
+    /// This operation is transactional: the resets and the execution event
+    /// are written atomically.
+    pub async fn schedule_retry(
+        &self,
+        id: UniversalUuid,
+        retry_at: UniversalTimestamp,
+        reason: &str,
+    ) -> Result<bool, ValidationError> {
+        use diesel::connection::Connection;
+
+        let reason = reason.to_string();
+        let scheduled = crate::interact_on_backend!(self.dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                let now = UniversalTimestamp::now();
+
+                let rows = diesel::update(workflow_executions::table.find(id).filter(
+                    workflow_executions::status.ne_all(vec!["Completed", "Failed", "Cancelled"]),
+                ))
+                .set((
+                    workflow_executions::status.eq("Pending"),
+                    workflow_executions::attempt.eq(workflow_executions::attempt + 1),
+                    workflow_executions::retry_at.eq(Some(retry_at)),
+                    workflow_executions::admitted_at.eq(None::<UniversalTimestamp>),
+                    workflow_executions::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+                if rows == 0 {
+                    return Ok(false);
+                }
+
+                let task_ids: Vec<UniversalUuid> = task_executions::table
+                    .filter(task_executions::workflow_execution_id.eq(id))
+                    .select(task_executions::id)
+                    .load(conn)?;
+                diesel::delete(
+                    task_outbox::table.filter(task_outbox::task_execution_id.eq_any(&task_ids)),
+                )
+                .execute(conn)?;
+                diesel::delete(
+                    task_execution_metadata::table
+                        .filter(task_execution_metadata::workflow_execution_id.eq(id)),
+                )
+                .execute(conn)?;
+                diesel::update(
+                    task_executions::table.filter(task_executions::workflow_execution_id.eq(id)),
+                )
+                .set((
+                    task_executions::status.eq("NotStarted"),
+                    task_executions::attempt.eq(1),
+                    task_executions::started_at.eq(None::<UniversalTimestamp>),
+                    task_executions::completed_at.eq(None::<UniversalTimestamp>),
+                    task_executions::error_details.eq(None::<String>),
+                    task_executions::retry_at.eq(None::<UniversalTimestamp>),
+                    task_executions::last_error.eq(None::<String>),
+                    task_executions::sub_status.eq(None::<String>),
+                    task_executions::claimed_by.eq(None::<UniversalUuid>),
+                    task_executions::heartbeat_at.eq(None::<UniversalTimestamp>),
+                    task_executions::error_payload.eq(None::<String>),
+                    task_executions::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+                let attempt: i32 = workflow_executions::table
+                    .find(id)
+                    .select(workflow_executions::attempt)
+                    .first(conn)?;
+                let event_data = serde_json::json!({
+                    "attempt": attempt,
+                    "retry_at": retry_at.0.to_rfc3339(),
+                    "reason": reason,
+                })
+                .to_string();
+                let event = NewUnifiedExecutionEvent {
+                    id: UniversalUuid::new_v4(),
+                    workflow_execution_id: id,
+                    task_execution_id: None,
+                    event_type: ExecutionEventType::WorkflowRetryScheduled
+                        .as_str()
+                        .to_string(),
+                    event_data: Some(event_data),
+                    worker_id: None,
+                    created_at: now,
+                    request_id: None,
+                    runner_id: None,
+                    tenant_id: None,
+                };
+                diesel::insert_into(execution_events::table)
+                    .values(&event)
+                    .execute(conn)?;
+
+                Ok(true)
+            })
+        })?;
+
+        Ok(scheduled)
+    }
+
     pub async fn cancel(&self, id: UniversalUuid) -> Result<(), ValidationError> {
         let now = UniversalTimestamp::now();
         crate::interact_on_backend!(self.dal, |conn| {
@@ -471,6 +1000,19 @@ impl<'a> WorkflowExecutionDAL<'a> {
                 if let Some(ref name) = filter.workflow_name {
                     query = query.filter(workflow_executions::workflow_name.eq(name.clone()));
                 }
+                if let Some(ref run_id) = filter.run_id {
+                    query = query.filter(workflow_executions::run_id.eq(run_id.clone()));
+                }
+                for (key, value) in &filter.labels {
+                    query = query.filter(
+                        workflow_executions::id.eq_any(
+                            workflow_execution_labels::table
+                                .filter(workflow_execution_labels::label_key.eq(key.clone()))
+                                .filter(workflow_execution_labels::label_value.eq(value.clone()))
+                                .select(workflow_execution_labels::workflow_execution_id),
+                        ),
+                    );
+                }
                 query
                     .order(workflow_executions::started_at.desc())
                     .limit(filter.limit)
@@ -480,4 +1022,120 @@ impl<'a> WorkflowExecutionDAL<'a> {
 
         Ok(executions.into_iter().map(Into::into).collect())
     }
+
+    /// One page of executions matching `query`, ordered by
+    /// `(started_at, id)` in the query's direction and starting strictly
+    /// after `query.after`. Paging with the last row's `(started_at, id)`
+    /// visits every matching execution exactly once.
+    pub async fn list_page(
+        &self,
+        query: ExecutionPageQuery,
+    ) -> Result<Vec<WorkflowExecutionRecord>, ValidationError> {
+        let executions: Vec<UnifiedWorkflowExecution> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                let mut q = workflow_executions::table.into_boxed();
+                if let Some(ref name) = query.workflow_name {
+                    q = q.filter(workflow_executions::workflow_name.eq(name.clone()));
+                }
+                if !query.statuses.is_empty() {
+                    q = q.filter(workflow_executions::status.eq_any(query.statuses.clone()));
+                }
+                if let Some(from) = query.started_from {
+                    q = q.filter(workflow_executions::started_at.ge(from));
+                }
+                if let Some(until) = query.started_until {
+                    q = q.filter(workflow_executions::started_at.lt(until));
+                }
+                if let Some(ref run_id) = query.run_id {
+                    q = q.filter(workflow_executions::run_id.eq(run_id.clone()));
+                }
+                for (key, value) in &query.labels {
+                    q = q.filter(
+                        workflow_executions::id.eq_any(
+                            workflow_execution_labels::table
+                                .filter(workflow_execution_labels::label_key.eq(key.clone()))
+                                .filter(workflow_execution_labels::label_value.eq(value.clone()))
+                                .select(workflow_execution_labels::workflow_execution_id),
+                        ),
+                    );
+                }
+                match query.sort {
+                    ExecutionSort::NewestFirst => {
+                        if let Some((started_at, id)) = query.after {
+                            q = q.filter(
+                                workflow_executions::started_at.lt(started_at).or(
+                                    workflow_executions::started_at
+                                        .eq(started_at)
+                                        .and(workflow_executions::id.lt(id)),
+                                ),
+                            );
+                        }
+                        q = q.order((
+                            workflow_executions::started_at.desc(),
+                            workflow_executions::id.desc(),
+                        ));
+                    }
+                    ExecutionSort::OldestFirst => {
+                        if let Some((started_at, id)) = query.after {
+                            q = q.filter(
+                                workflow_executions::started_at.gt(started_at).or(
+                                    workflow_executions::started_at
+                                        .eq(started_at)
+                                        .and(workflow_executions::id.gt(id)),
+                                ),
+                            );
+                        }
+                        q = q.order((
+                            workflow_executions::started_at.asc(),
+                            workflow_executions::id.asc(),
+                        ));
+                    }
+                }
+                q.limit(query.limit).load(conn)
+            })?;
+
+        Ok(executions.into_iter().map(Into::into).collect())
+    }
+
+    /// Completed executions of a workflow in `(completed_at, id)` order,
+    /// starting strictly after `after` and completed within `since..until`.
+    /// Paging with the last row's `(completed_at, id)` as the next `after`
+    /// visits every completion exactly once, however many share a timestamp.
+    pub async fn list_completed_after(
+        &self,
+        workflow_name: &str,
+        since: UniversalTimestamp,
+        until: UniversalTimestamp,
+        after: Option<(UniversalTimestamp, UniversalUuid)>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowExecutionRecord>, ValidationError> {
+        let workflow_name = workflow_name.to_string();
+        let executions: Vec<UnifiedWorkflowExecution> =
+            crate::interact_on_backend!(self.dal, |conn| {
+                let mut query = workflow_executions::table
+                    .filter(workflow_executions::workflow_name.eq(workflow_name.clone()))
+                    .filter(workflow_executions::status.eq("Completed"))
+                    .filter(workflow_executions::completed_at.ge(since))
+                    .filter(workflow_executions::completed_at.lt(until))
+                    .into_boxed();
+                if let Some((completed_at, id)) = after {
+                    query = query.filter(
+                        workflow_executions::completed_at.gt(completed_at).or(
+                            workflow_executions::completed_at
+                                .eq(completed_at)
+                                .and(workflow_executions::id.gt(id)),
+                        ),
+                    );
+                }
+                query
+                    .order((
+                        workflow_executions::completed_at.asc(),
+                        workflow_executions::id.asc(),
+                    ))
+                    .limit(limit)
+                    .load(conn)
+            })?;
+
+        Ok(executions.into_iter().map(Into::into).collect())
+    }
 }
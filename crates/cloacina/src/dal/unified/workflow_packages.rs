@@ -22,7 +22,7 @@
 
 use super::models::{NewUnifiedWorkflowPackage, UnifiedWorkflowPackage};
 use super::DAL;
-use crate::database::schema::unified::{workflow_packages, workflow_registry};
+use crate::database::schema::unified::workflow_packages;
 use crate::database::universal_types::{UniversalBool, UniversalTimestamp, UniversalUuid};
 use crate::models::workflow_packages::WorkflowPackage;
 use crate::registry::error::RegistryError;
@@ -731,7 +731,7 @@ impl<'a> WorkflowPackagesDAL<'a> {
     }
 
     /// Source `.cloacina` archive bytes for the package whose active build has
-    /// `content_hash == digest`. The registry (`workflow_registry.data`) holds
+    /// `content_hash == digest`. The registry (`workflow_registry`) holds
     /// the uploaded archive — for a Python package this is the importable
     /// `workflow/` + `vendor/` tree (`compiled_data` is empty). Served by
     /// `GET /v1/agent/source/{digest}` so a DB-less agent can fetch + import a
@@ -740,27 +740,26 @@ impl<'a> WorkflowPackagesDAL<'a> {
         &self,
         content_hash: &str,
     ) -> Result<Option<Vec<u8>>, RegistryError> {
-        use crate::database::universal_types::UniversalBinary;
+        use crate::registry::traits::RegistryStorage;
         let content_hash = content_hash.to_string();
-        // Two single-table lookups (package row → registry_id → archive),
-        // avoiding a cross-table join the unified schema doesn't wire up.
-        let result: Option<UniversalBinary> = crate::interact_on_backend!(self.dal, |conn| {
-            let pkg: Option<UnifiedWorkflowPackage> = workflow_packages::table
+        // Package row → registry_id, then the archive through registry
+        // storage, which reassembles archives stored in chunks.
+        let registry_id: Option<UniversalUuid> = crate::interact_on_backend!(self.dal, |conn| {
+            workflow_packages::table
                 .filter(workflow_packages::content_hash.eq(content_hash))
                 .filter(workflow_packages::build_status.eq("success"))
-                .first::<UnifiedWorkflowPackage>(conn)
-                .optional()?;
-            match pkg {
-                Some(p) => workflow_registry::table
-                    .filter(workflow_registry::id.eq(p.registry_id))
-                    .select(workflow_registry::data)
-                    .first::<UniversalBinary>(conn)
-                    .optional(),
-                None => Ok(None),
-            }
+                .select(workflow_packages::registry_id)
+                .first::<UniversalUuid>(conn)
+                .optional()
         })
         .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))?;
-        Ok(result.map(|b| b.into_inner()))
+        let Some(registry_id) = registry_id else {
+            return Ok(None);
+        };
+        super::UnifiedRegistryStorage::new(self.dal.database.clone())
+            .retrieve_binary(&registry_id.to_string())
+            .await
+            .map_err(RegistryError::from)
     }
 
     /// Resolve a successfully-built package's `(package_name, version)` from its
@@ -861,6 +860,8 @@ mod tests {
             declared_params: vec![],
             declared_surfaces: vec![],
             task_docs: Default::default(),
+            provenance: None,
+            config: None,
         }
     }
 
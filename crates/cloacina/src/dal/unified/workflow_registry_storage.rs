@@ -19,13 +19,25 @@
 //! This module provides binary storage operations that work with both
 //! PostgreSQL and SQLite backends, selecting the appropriate implementation
 //! at runtime based on the database connection type.
+//!
+//! Archives are written to `workflow_registry_chunks` in
+//! [`REGISTRY_CHUNK_SIZE`] pieces, one insert per chunk, and read back one
+//! chunk at a time, so a package is never held in memory whole on either
+//! side. Rows stored before chunking keep their bytes in
+//! `workflow_registry.data` and are still read from there.
+//!
+//! With at-rest encryption on the [`Database`], each chunk is sealed on its
+//! own, bound to its registry id and index, so streaming is unchanged.
 
 use async_trait::async_trait;
 use diesel::prelude::*;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use uuid::Uuid;
 
-use super::models::{NewUnifiedWorkflowRegistryEntry, UnifiedWorkflowRegistryEntry};
-use crate::database::schema::unified::workflow_registry;
+use super::models::{
+    NewUnifiedWorkflowRegistryChunk, NewUnifiedWorkflowRegistryEntry, UnifiedWorkflowRegistryEntry,
+};
+use crate::database::schema::unified::{workflow_registry, workflow_registry_chunks};
 use crate::database::universal_types::{UniversalBinary, UniversalTimestamp, UniversalUuid};
 use crate::database::Database;
 use crate::models::workflow_packages::StorageType;
@@ -50,19 +62,243 @@ impl UnifiedRegistryStorage {
     }
 }
 
+/// Size of one stored archive chunk.
+pub const REGISTRY_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Chunk index used as associated data for bytes stored inline on the
+/// parent row.
+const INLINE_CHUNK_INDEX: i32 = -1;
+
+/// Associated data binding an encrypted chunk to its place in an archive.
+fn chunk_aad(registry_id: UniversalUuid, chunk_index: i32) -> Vec<u8> {
+    [
+        &registry_id.0.as_bytes()[..],
+        &chunk_index.to_be_bytes()[..],
+    ]
+    .concat()
+}
+
+impl UnifiedRegistryStorage {
+    fn dal(&self) -> crate::dal::unified::DAL {
+        crate::dal::unified::DAL::new(self.database.clone())
+    }
+
+    /// Insert chunk `chunk_index` of `registry_id`.
+    async fn insert_chunk(
+        &self,
+        registry_id: UniversalUuid,
+        chunk_index: i32,
+        data: Vec<u8>,
+    ) -> Result<(), StorageError> {
+        let data = match self.database.at_rest_encryption() {
+            Some(encryption) => encryption
+                .encrypt(&data, &chunk_aad(registry_id, chunk_index))
+                .await
+                .map_err(|e| StorageError::Backend(format!("Encryption error: {}", e)))?,
+            None => data,
+        };
+        let chunk = NewUnifiedWorkflowRegistryChunk {
+            registry_id,
+            chunk_index,
+            data: UniversalBinary::from(data),
+        };
+        let dal = self.dal();
+        crate::interact_on_backend!(dal, |conn| {
+            diesel::insert_into(workflow_registry_chunks::table)
+                .values(&chunk)
+                .execute(conn)
+        })
+        .map_err(|e| StorageError::Backend(format!("Database error: {}", e)))?;
+        Ok(())
+    }
+
+    /// Decrypt stored chunk bytes. Plaintext (unencrypted) chunks pass through.
+    async fn open_chunk(
+        &self,
+        registry_id: UniversalUuid,
+        chunk_index: i32,
+        data: Vec<u8>,
+    ) -> Result<Vec<u8>, StorageError> {
+        if !crate::crypto::AtRestEncryption::is_encrypted(&data) {
+            return Ok(data);
+        }
+        let encryption = self.database.at_rest_encryption().ok_or_else(|| {
+            StorageError::Backend(
+                "Package archive is encrypted but no at-rest keys are configured".to_string(),
+            )
+        })?;
+        encryption
+            .decrypt(&data, &chunk_aad(registry_id, chunk_index))
+            .await
+            .map_err(|e| StorageError::DataCorruption {
+                id: registry_id.0.to_string(),
+                reason: format!("chunk {}: {}", chunk_index, e),
+            })
+    }
+
+    /// Rewrite every stored archive chunk that is plaintext or sealed under a
+    /// retired key-encryption key under the current one, scanning
+    /// `batch_size` archives at a time. Returns the number of chunks
+    /// rewritten; a no-op without at-rest encryption.
+    pub async fn reencrypt(&self, batch_size: i64) -> Result<usize, StorageError> {
+        let Some(encryption) = self.database.at_rest_encryption().cloned() else {
+            return Ok(0);
+        };
+        let db_err =
+            |e: diesel::result::Error| StorageError::Backend(format!("Database error: {}", e));
+        let dal = self.dal();
+        let mut after = UniversalUuid(Uuid::nil());
+        let mut rewritten = 0;
+        loop {
+            let ids: Vec<UniversalUuid> = crate::interact_on_backend!(dal, |conn| {
+                workflow_registry::table
+                    .filter(workflow_registry::id.gt(after))
+                    .order(workflow_registry::id.asc())
+                    .select(workflow_registry::id)
+                    .limit(batch_size)
+                    .load(conn)
+            })
+            .map_err(db_err)?;
+            let Some(last) = ids.last() else {
+                break;
+            };
+            after = *last;
+            let full = ids.len() as i64 == batch_size;
+
+            for registry_id in ids {
+                let inline: UniversalBinary = crate::interact_on_backend!(dal, |conn| {
+                    workflow_registry::table
+                        .filter(workflow_registry::id.eq(registry_id))
+                        .select(workflow_registry::data)
+                        .first::<UniversalBinary>(conn)
+                })
+                .map_err(db_err)?;
+                let inline = inline.into_inner();
+                if !inline.is_empty() && encryption.needs_reencrypt(&inline) {
+                    let plaintext = self
+                        .open_chunk(registry_id, INLINE_CHUNK_INDEX, inline)
+                        .await?;
+                    let sealed = encryption
+                        .encrypt(&plaintext, &chunk_aad(registry_id, INLINE_CHUNK_INDEX))
+                        .await
+                        .map_err(|e| StorageError::Backend(format!("Encryption error: {}", e)))?;
+                    let sealed = UniversalBinary::from(sealed);
+                    crate::interact_on_backend!(dal, |conn| {
+                        diesel::update(
+                            workflow_registry::table.filter(workflow_registry::id.eq(registry_id)),
+                        )
+                        .set(workflow_registry::data.eq(sealed))
+                        .execute(conn)
+                    })
+                    .map_err(db_err)?;
+                    rewritten += 1;
+                }
+
+                for chunk_index in 0i32.. {
+                    let chunk: Option<UniversalBinary> = crate::interact_on_backend!(dal, |conn| {
+                        workflow_registry_chunks::table
+                            .filter(workflow_registry_chunks::registry_id.eq(registry_id))
+                            .filter(workflow_registry_chunks::chunk_index.eq(chunk_index))
+                            .select(workflow_registry_chunks::data)
+                            .first::<UniversalBinary>(conn)
+                            .optional()
+                    })
+                    .map_err(db_err)?;
+                    let Some(chunk) = chunk else {
+                        break;
+                    };
+                    let chunk = chunk.into_inner();
+                    if !encryption.needs_reencrypt(&chunk) {
+                        continue;
+                    }
+                    let plaintext = self.open_chunk(registry_id, chunk_index, chunk).await?;
+                    let sealed = encryption
+                        .encrypt(&plaintext, &chunk_aad(registry_id, chunk_index))
+                        .await
+                        .map_err(|e| StorageError::Backend(format!("Encryption error: {}", e)))?;
+                    let sealed = UniversalBinary::from(sealed);
+                    crate::interact_on_backend!(dal, |conn| {
+                        diesel::update(
+                            workflow_registry_chunks::table
+                                .filter(workflow_registry_chunks::registry_id.eq(registry_id))
+                                .filter(workflow_registry_chunks::chunk_index.eq(chunk_index)),
+                        )
+                        .set(workflow_registry_chunks::data.eq(sealed))
+                        .execute(conn)
+                    })
+                    .map_err(db_err)?;
+                    rewritten += 1;
+                }
+            }
+            if !full {
+                break;
+            }
+        }
+        Ok(rewritten)
+    }
+
+    /// Read from `reader` into `buf` until it is full or the stream ends.
+    async fn fill_chunk(
+        reader: &mut (dyn AsyncRead + Send + Unpin),
+        buf: &mut Vec<u8>,
+    ) -> Result<(), StorageError> {
+        buf.clear();
+        let mut limited = (&mut *reader).take(REGISTRY_CHUNK_SIZE as u64);
+        limited
+            .read_to_end(buf)
+            .await
+            .map_err(|e| StorageError::Backend(format!("Failed to read package data: {}", e)))?;
+        Ok(())
+    }
+
+    /// Write every chunk of `registry_id` from `reader`, returning the number
+    /// of bytes stored.
+    async fn write_chunks(
+        &self,
+        registry_id: UniversalUuid,
+        reader: &mut (dyn AsyncRead + Send + Unpin),
+    ) -> Result<u64, StorageError> {
+        let mut total = 0u64;
+        let mut buf = Vec::with_capacity(REGISTRY_CHUNK_SIZE);
+        for chunk_index in 0.. {
+            Self::fill_chunk(reader, &mut buf).await?;
+            if buf.is_empty() {
+                break;
+            }
+            total += buf.len() as u64;
+            let full = buf.len() == REGISTRY_CHUNK_SIZE;
+            self.insert_chunk(registry_id, chunk_index, std::mem::take(&mut buf))
+                .await?;
+            if !full {
+                break;
+            }
+            buf.reserve(REGISTRY_CHUNK_SIZE);
+        }
+        Ok(total)
+    }
+}
+
 #[async_trait]
 impl RegistryStorage for UnifiedRegistryStorage {
     async fn store_binary(&mut self, data: Vec<u8>) -> Result<String, StorageError> {
+        self.store_stream(&mut data.as_slice()).await
+    }
+
+    async fn store_stream(
+        &mut self,
+        reader: &mut (dyn AsyncRead + Send + Unpin),
+    ) -> Result<String, StorageError> {
         let id = UniversalUuid::new_v4();
         let now = UniversalTimestamp::now();
 
+        // The parent row carries no bytes; the chunks reference it.
         let new_entry = NewUnifiedWorkflowRegistryEntry {
             id,
             created_at: now,
-            data: UniversalBinary::from(data),
+            data: UniversalBinary::from(Vec::new()),
         };
 
-        let dal = crate::dal::unified::DAL::new(self.database.clone());
+        let dal = self.dal();
         crate::interact_on_backend!(dal, |conn| {
             diesel::insert_into(workflow_registry::table)
                 .values(&new_entry)
@@ -70,15 +306,34 @@ impl RegistryStorage for UnifiedRegistryStorage {
         })
         .map_err(|e| StorageError::Backend(format!("Database error: {}", e)))?;
 
+        if let Err(e) = self.write_chunks(id, reader).await {
+            // Don't leave a truncated archive behind.
+            let _ = self.delete_binary(&id.0.to_string()).await;
+            return Err(e);
+        }
+
         Ok(id.0.to_string())
     }
 
     async fn retrieve_binary(&self, id: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let mut data = Vec::new();
+        if self.retrieve_to(id, &mut data).await? {
+            Ok(Some(data))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn retrieve_to(
+        &self,
+        id: &str,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<bool, StorageError> {
         let registry_uuid =
             Uuid::parse_str(id).map_err(|_| StorageError::InvalidId { id: id.to_string() })?;
         let registry_id = UniversalUuid(registry_uuid);
 
-        let dal = crate::dal::unified::DAL::new(self.database.clone());
+        let dal = self.dal();
         let entry: Option<UnifiedWorkflowRegistryEntry> =
             crate::interact_on_backend!(dal, |conn| {
                 workflow_registry::table
@@ -87,8 +342,44 @@ impl RegistryStorage for UnifiedRegistryStorage {
                     .optional()
             })
             .map_err(|e| StorageError::Backend(format!("Database error: {}", e)))?;
+        let Some(entry) = entry else {
+            return Ok(false);
+        };
+
+        let write_err = |e: std::io::Error| {
+            StorageError::Backend(format!("Failed to write package data: {}", e))
+        };
+        // Stored before chunking: the bytes live on the parent row.
+        let inline = entry.data.into_inner();
+        if !inline.is_empty() {
+            let inline = self
+                .open_chunk(registry_id, INLINE_CHUNK_INDEX, inline)
+                .await?;
+            writer.write_all(&inline).await.map_err(write_err)?;
+            return Ok(true);
+        }
+
+        for chunk_index in 0i32.. {
+            let chunk: Option<UniversalBinary> = crate::interact_on_backend!(dal, |conn| {
+                workflow_registry_chunks::table
+                    .filter(workflow_registry_chunks::registry_id.eq(registry_id))
+                    .filter(workflow_registry_chunks::chunk_index.eq(chunk_index))
+                    .select(workflow_registry_chunks::data)
+                    .first::<UniversalBinary>(conn)
+                    .optional()
+            })
+            .map_err(|e| StorageError::Backend(format!("Database error: {}", e)))?;
+            let Some(chunk) = chunk else {
+                break;
+            };
+            let chunk = self
+                .open_chunk(registry_id, chunk_index, chunk.into_inner())
+                .await?;
+            writer.write_all(&chunk).await.map_err(write_err)?;
+        }
+        writer.flush().await.map_err(write_err)?;
 
-        Ok(entry.map(|e| e.data.into_inner()))
+        Ok(true)
     }
 
     async fn delete_binary(&mut self, id: &str) -> Result<(), StorageError> {
@@ -96,8 +387,14 @@ impl RegistryStorage for UnifiedRegistryStorage {
             Uuid::parse_str(id).map_err(|_| StorageError::InvalidId { id: id.to_string() })?;
         let registry_id = UniversalUuid(registry_uuid);
 
-        let dal = crate::dal::unified::DAL::new(self.database.clone());
+        let dal = self.dal();
         crate::interact_on_backend!(dal, |conn| {
+            // Chunks first: SQLite only cascades with foreign keys enabled.
+            diesel::delete(
+                workflow_registry_chunks::table
+                    .filter(workflow_registry_chunks::registry_id.eq(registry_id)),
+            )
+            .execute(conn)?;
             diesel::delete(workflow_registry::table.filter(workflow_registry::id.eq(registry_id)))
                 .execute(conn)
         })
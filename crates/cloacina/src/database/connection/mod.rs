@@ -64,6 +64,8 @@ pub use backend::{DbConnection, DbConnectionManager, DbPool};
 #[cfg(all(feature = "sqlite", not(feature = "postgres")))]
 pub use backend::{DbConnection, DbPool};
 
+#[cfg(feature = "sqlite")]
+use std::sync::atomic::{AtomicU64, Ordering};
 #[cfg(feature = "sqlite")]
 use std::sync::Arc;
 #[cfg(feature = "sqlite")]
@@ -101,6 +103,11 @@ const SQLITE_POOL_SIZE: usize = 4;
 /// `pool timeout` error that names the checkout site instead of hanging.
 const POOL_WAIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
 
+/// `busy_timeout` applied to every SQLite connection until the SQLite
+/// maintenance service (see [`crate::sqlite_maintenance`]) retunes it.
+#[cfg(feature = "sqlite")]
+pub const SQLITE_DEFAULT_BUSY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 /// Errors that can occur during database operations.
 ///
 /// This error type covers connection pool creation, URL parsing,
@@ -202,6 +209,16 @@ pub struct Database {
     backend: BackendType,
     /// The PostgreSQL schema name for multi-tenant isolation (ignored for SQLite)
     schema: Option<String>,
+    /// Envelope encryption applied to contexts and registry archives written
+    /// through this database; `None` stores them in plaintext.
+    at_rest: Option<std::sync::Arc<crate::crypto::AtRestEncryption>>,
+    /// Size limit and large-value offloading applied to contexts written
+    /// through this database; `None` stores them whole, unbounded.
+    context_offload: Option<std::sync::Arc<crate::context_offload::ContextOffload>>,
+    /// Format contexts are written in; rows in any format are read.
+    context_format: crate::context_format::ContextFormat,
+    /// Blob store for run artifacts; `None` stores them in the database.
+    artifact_store: Option<std::sync::Arc<crate::artifacts::ArtifactStore>>,
     /// Backing tempfile when the user requested `:memory:` (or
     /// `sqlite://:memory:`). Held via Arc so every Database clone keeps the
     /// file alive; when the last clone drops, NamedTempFile::Drop deletes
@@ -209,6 +226,10 @@ pub struct Database {
     /// a real tempfile for in-memory requests.
     #[cfg(feature = "sqlite")]
     _memory_tempfile: Option<Arc<NamedTempFile>>,
+    /// `busy_timeout` (ms) applied on every SQLite checkout. Shared by all
+    /// clones so a retune reaches every pool user.
+    #[cfg(feature = "sqlite")]
+    sqlite_busy_timeout_ms: Arc<AtomicU64>,
 }
 
 impl std::fmt::Debug for Database {
@@ -216,6 +237,10 @@ impl std::fmt::Debug for Database {
         f.debug_struct("Database")
             .field("backend", &self.backend)
             .field("schema", &self.schema)
+            .field("at_rest_encryption", &self.at_rest.is_some())
+            .field("context_offload", &self.context_offload)
+            .field("context_format", &self.context_format)
+            .field("artifact_store", &self.artifact_store.is_some())
             .field("pool", &"<connection pool>")
             .finish()
     }
@@ -322,8 +347,14 @@ impl Database {
                     pool: AnyPool::Postgres(pool),
                     backend,
                     schema: validated_schema,
+                    at_rest: None,
+                    context_offload: None,
+                    context_format: Default::default(),
+                    artifact_store: None,
                     #[cfg(feature = "sqlite")]
                     _memory_tempfile: None,
+                    #[cfg(feature = "sqlite")]
+                    sqlite_busy_timeout_ms: Self::default_busy_timeout(),
                 })
             }
             BackendType::Sqlite => {
@@ -363,7 +394,12 @@ impl Database {
                     pool: AnyPool::Sqlite(pool),
                     backend,
                     schema: validated_schema,
+                    at_rest: None,
+                    context_offload: None,
+                    context_format: Default::default(),
+                    artifact_store: None,
                     _memory_tempfile: memory_tempfile,
+                    sqlite_busy_timeout_ms: Self::default_busy_timeout(),
                 })
             }
         }
@@ -394,8 +430,14 @@ impl Database {
                 pool,
                 backend: BackendType::Postgres,
                 schema: validated_schema,
+                at_rest: None,
+                context_offload: None,
+                context_format: Default::default(),
+                artifact_store: None,
                 #[cfg(feature = "sqlite")]
                 _memory_tempfile: None,
+                #[cfg(feature = "sqlite")]
+                sqlite_busy_timeout_ms: Self::default_busy_timeout(),
             });
         }
 
@@ -425,11 +467,38 @@ impl Database {
                 pool,
                 backend: BackendType::Sqlite,
                 schema: validated_schema,
+                at_rest: None,
+                context_offload: None,
+                context_format: Default::default(),
+                artifact_store: None,
                 _memory_tempfile: memory_tempfile,
+                sqlite_busy_timeout_ms: Self::default_busy_timeout(),
             });
         }
     }
 
+    #[cfg(feature = "sqlite")]
+    fn default_busy_timeout() -> Arc<AtomicU64> {
+        Arc::new(AtomicU64::new(
+            SQLITE_DEFAULT_BUSY_TIMEOUT.as_millis() as u64
+        ))
+    }
+
+    /// The `busy_timeout` currently applied to SQLite connections.
+    #[cfg(feature = "sqlite")]
+    pub fn sqlite_busy_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.sqlite_busy_timeout_ms.load(Ordering::Relaxed))
+    }
+
+    /// Sets the `busy_timeout` applied to SQLite connections. Takes effect
+    /// on each connection's next checkout, for every clone of this
+    /// `Database`. No-op for PostgreSQL.
+    #[cfg(feature = "sqlite")]
+    pub fn set_sqlite_busy_timeout(&self, timeout: std::time::Duration) {
+        self.sqlite_busy_timeout_ms
+            .store(timeout.as_millis() as u64, Ordering::Relaxed);
+    }
+
     /// Returns the detected backend type.
     pub fn backend(&self) -> BackendType {
         self.backend
@@ -440,6 +509,73 @@ impl Database {
         self.schema.as_deref()
     }
 
+    /// Encrypt contexts and registry archives written through this database
+    /// (and its clones made afterwards). Existing plaintext rows stay
+    /// readable; see [`crate::crypto::at_rest`].
+    pub fn with_at_rest_encryption(
+        mut self,
+        encryption: std::sync::Arc<crate::crypto::AtRestEncryption>,
+    ) -> Self {
+        self.at_rest = Some(encryption);
+        self
+    }
+
+    /// The at-rest encryption attached with
+    /// [`with_at_rest_encryption`](Self::with_at_rest_encryption), if any.
+    pub fn at_rest_encryption(&self) -> Option<&std::sync::Arc<crate::crypto::AtRestEncryption>> {
+        self.at_rest.as_ref()
+    }
+
+    /// Offload large context values to a blob store and cap context size for
+    /// contexts written through this database (and its clones made
+    /// afterwards); see [`crate::context_offload`].
+    pub fn with_context_offload(
+        mut self,
+        offload: std::sync::Arc<crate::context_offload::ContextOffload>,
+    ) -> Self {
+        self.context_offload = Some(offload);
+        self
+    }
+
+    /// The context offloading attached with
+    /// [`with_context_offload`](Self::with_context_offload), if any.
+    pub fn context_offload(
+        &self,
+    ) -> Option<&std::sync::Arc<crate::context_offload::ContextOffload>> {
+        self.context_offload.as_ref()
+    }
+
+    /// Write contexts through this database (and its clones made
+    /// afterwards) in `format`. Rows already stored in another format stay
+    /// readable; see [`crate::context_format`].
+    pub fn with_context_format(mut self, format: crate::context_format::ContextFormat) -> Self {
+        self.context_format = format;
+        self
+    }
+
+    /// The format contexts are written in (JSON unless set with
+    /// [`with_context_format`](Self::with_context_format)).
+    pub fn context_format(&self) -> crate::context_format::ContextFormat {
+        self.context_format
+    }
+
+    /// Write run artifacts through this database (and its clones made
+    /// afterwards) to `store` instead of the database; see
+    /// [`crate::artifacts`].
+    pub fn with_artifact_store(
+        mut self,
+        store: std::sync::Arc<crate::artifacts::ArtifactStore>,
+    ) -> Self {
+        self.artifact_store = Some(store);
+        self
+    }
+
+    /// The artifact store attached with
+    /// [`with_artifact_store`](Self::with_artifact_store), if any.
+    pub fn artifact_store(&self) -> Option<&std::sync::Arc<crate::artifacts::ArtifactStore>> {
+        self.artifact_store.as_ref()
+    }
+
     /// Returns a clone of the connection pool.
     pub fn pool(&self) -> AnyPool {
         self.pool.clone()
@@ -564,6 +700,13 @@ impl Database {
                 conn.interact(|conn| {
                     use diesel::prelude::*;
 
+                    // Incremental auto-vacuum lets the SQLite maintenance
+                    // service return freed pages to the filesystem. It can
+                    // only be switched on before the first table exists, so
+                    // this is a no-op for an already-initialised database.
+                    diesel::sql_query("PRAGMA auto_vacuum=INCREMENTAL;")
+                        .execute(conn)
+                        .map_err(|e| format!("Failed to set auto_vacuum: {}", e))?;
                     // Set SQLite pragmas for better concurrency before running migrations
                     // WAL mode allows concurrent reads during writes
                     diesel::sql_query("PRAGMA journal_mode=WAL;")
@@ -602,6 +745,9 @@ impl Database {
             conn.interact(|conn| {
                 use diesel::prelude::*;
 
+                diesel::sql_query("PRAGMA auto_vacuum=INCREMENTAL;")
+                    .execute(conn)
+                    .map_err(|e| format!("Failed to set auto_vacuum: {}", e))?;
                 diesel::sql_query("PRAGMA journal_mode=WAL;")
                     .execute(conn)
                     .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
@@ -866,11 +1012,14 @@ impl Database {
 
         let conn = pool.get().await?;
         // Ensure SQLite pragmas are set on every checkout — pragmas are per-connection
-        // and may be lost if the pool recycles the connection.
-        conn.interact(|conn| {
+        // and may be lost if the pool recycles the connection. The busy
+        // timeout is read each time so a maintenance retune applies here.
+        let busy_timeout_ms = self.sqlite_busy_timeout_ms.load(Ordering::Relaxed);
+        conn.interact(move |conn| {
             use diesel::prelude::*;
             let _ = diesel::sql_query("PRAGMA journal_mode=WAL;").execute(conn);
-            let _ = diesel::sql_query("PRAGMA busy_timeout=30000;").execute(conn);
+            let _ = diesel::sql_query(format!("PRAGMA busy_timeout={};", busy_timeout_ms))
+                .execute(conn);
         })
         .await
         .ok();
@@ -0,0 +1,2 @@
+-- Reverse schedule tags.
+ALTER TABLE schedules DROP COLUMN tags;
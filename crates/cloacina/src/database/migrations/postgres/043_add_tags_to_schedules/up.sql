@@ -0,0 +1,4 @@
+-- Schedule tags: operator-assigned labels (JSON array of strings) used to
+-- address a whole group of schedules at once, e.g. pausing everything tagged
+-- `warehouse` during an incident. NULL = untagged. ADD COLUMN only.
+ALTER TABLE schedules ADD COLUMN tags TEXT;
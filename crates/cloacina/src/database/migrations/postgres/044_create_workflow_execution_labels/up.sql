@@ -0,0 +1,14 @@
+-- Execution labels: arbitrary key/value pairs attached to a workflow execution
+-- at submission (source=api, customer=acme, backfill=true). One row per label
+-- so executions can be filtered by label through the (key, value) index
+-- instead of encoding the metadata in the context.
+CREATE TABLE workflow_execution_labels (
+    workflow_execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    label_key VARCHAR(63) NOT NULL,
+    label_value VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (workflow_execution_id, label_key)
+);
+
+CREATE INDEX idx_workflow_execution_labels_key_value
+    ON workflow_execution_labels(label_key, label_value);
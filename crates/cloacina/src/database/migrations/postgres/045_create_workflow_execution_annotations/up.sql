@@ -0,0 +1,15 @@
+-- Operator annotations: free-text notes attached to a workflow execution by
+-- an operator ("known outage, reran manually at 14:00"), optionally carrying a
+-- resolution status. Append-only; the latest annotation with a resolution is
+-- the execution's current resolution.
+CREATE TABLE workflow_execution_annotations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    workflow_execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    author VARCHAR(255) NOT NULL,
+    note TEXT NOT NULL,
+    resolution VARCHAR(32),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_workflow_execution_annotations_execution
+    ON workflow_execution_annotations(workflow_execution_id, created_at);
@@ -0,0 +1,2 @@
+-- Reverse runner instances.
+DROP TABLE runner_instances;
@@ -0,0 +1,17 @@
+-- Runner instances: one row per running DefaultRunner that takes part in
+-- blue/green handoff. A starting runner with takeover enabled moves every
+-- live `active` instance to `draining` and records itself as their successor;
+-- the old runner stops taking new work, finishes what it has in flight, and
+-- marks itself `drained`.
+CREATE TABLE runner_instances (
+    id UUID PRIMARY KEY,
+    name VARCHAR(255),
+    version VARCHAR(64) NOT NULL,
+    state VARCHAR(16) NOT NULL DEFAULT 'active',
+    successor_id UUID,
+    started_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    heartbeat_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_runner_instances_state ON runner_instances(state, heartbeat_at);
@@ -0,0 +1,4 @@
+-- Structured task errors: the `TaskErrorPayload` JSON (code, category,
+-- message, details) a task failed with via `TaskError::Structured`, kept
+-- verbatim. NULL = the task failed with a plain error. ADD COLUMN only.
+ALTER TABLE task_executions ADD COLUMN error_payload TEXT;
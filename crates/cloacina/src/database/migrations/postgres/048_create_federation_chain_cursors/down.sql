@@ -0,0 +1,3 @@
+-- Reverse federation chain cursors.
+DROP INDEX IF EXISTS idx_workflow_executions_completed;
+DROP TABLE federation_chain_cursors;
@@ -0,0 +1,13 @@
+-- Federation chain cursors: how far each chain rule has forwarded. The chain
+-- loop walks completed source executions in (completed_at, id) order and
+-- advances the cursor past each one it has handed to the peer region, so a
+-- restart or a burst of completions never skips or re-reads a page.
+CREATE TABLE federation_chain_cursors (
+    chain_key VARCHAR(512) PRIMARY KEY,
+    completed_at TIMESTAMP NOT NULL,
+    execution_id UUID NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_workflow_executions_completed
+    ON workflow_executions(workflow_name, status, completed_at, id);
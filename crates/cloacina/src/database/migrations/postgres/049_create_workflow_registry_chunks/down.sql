@@ -0,0 +1,2 @@
+-- Reverse workflow registry chunks.
+DROP TABLE workflow_registry_chunks;
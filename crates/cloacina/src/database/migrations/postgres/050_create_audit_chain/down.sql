@@ -0,0 +1,4 @@
+-- Reverse the audit chain tables.
+DROP TABLE audit_anchors;
+DROP INDEX IF EXISTS idx_audit_log_event_type;
+DROP TABLE audit_log;
@@ -0,0 +1,22 @@
+-- Tamper-evident audit chain. Every audit event is appended with the SHA-256
+-- of the previous entry, so editing or deleting any row breaks every hash
+-- after it. `sequence` is the chain position; its uniqueness is what keeps
+-- concurrent writers on one linear chain. Anchors periodically record (and
+-- optionally sign) the chain head so a rewrite of the whole tail is caught too.
+CREATE TABLE audit_log (
+    sequence BIGINT PRIMARY KEY,
+    event_type VARCHAR(255) NOT NULL,
+    payload TEXT NOT NULL,
+    prev_hash VARCHAR(64) NOT NULL,
+    entry_hash VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX idx_audit_log_event_type ON audit_log(event_type, sequence);
+
+CREATE TABLE audit_anchors (
+    sequence BIGINT PRIMARY KEY REFERENCES audit_log(sequence),
+    entry_hash VARCHAR(64) NOT NULL,
+    signature TEXT,
+    anchored_at TIMESTAMP NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Reverse execution options.
+DROP TABLE workflow_execution_options;
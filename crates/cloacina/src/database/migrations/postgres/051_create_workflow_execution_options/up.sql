@@ -0,0 +1,9 @@
+-- Execution options: per-execution overrides of runner configuration and
+-- task retry policies (max parallelism, priority, retry attempts and delays,
+-- timeouts), set at submission. Stored as one JSON document per execution;
+-- executions submitted without overrides have no row.
+CREATE TABLE workflow_execution_options (
+    workflow_execution_id UUID PRIMARY KEY REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    options TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_workflow_executions_logical_date;
+DROP INDEX IF EXISTS idx_workflow_executions_run_id;
+ALTER TABLE workflow_executions DROP COLUMN logical_date;
+ALTER TABLE workflow_executions DROP COLUMN run_id;
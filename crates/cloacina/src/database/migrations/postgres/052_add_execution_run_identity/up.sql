@@ -0,0 +1,8 @@
+-- Run identity: a stable run id and the logical (data-interval) date each
+-- execution processes, distinct from started_at, so backfills, reruns and
+-- partition naming have a canonical timestamp. NULL = pre-migration row.
+ALTER TABLE workflow_executions ADD COLUMN run_id TEXT;
+ALTER TABLE workflow_executions ADD COLUMN logical_date TIMESTAMP;
+
+CREATE INDEX idx_workflow_executions_run_id ON workflow_executions(workflow_name, run_id);
+CREATE INDEX idx_workflow_executions_logical_date ON workflow_executions(workflow_name, logical_date);
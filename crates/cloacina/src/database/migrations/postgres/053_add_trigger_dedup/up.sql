@@ -0,0 +1,11 @@
+-- Trigger dedup: per-schedule control over which firings count as duplicates.
+-- dedup_window_secs suppresses a firing whose context hash matched any firing
+-- within the window (NULL = only an in-flight execution suppresses);
+-- dedup_keys is a JSON array of top-level context keys to hash (NULL = the
+-- whole context). Suppressed firings are recorded as already-completed
+-- schedule_executions rows so the trigger history can explain them.
+ALTER TABLE schedules ADD COLUMN dedup_window_secs INTEGER;
+ALTER TABLE schedules ADD COLUMN dedup_keys TEXT;
+
+ALTER TABLE schedule_executions ADD COLUMN suppressed_reason VARCHAR;
+ALTER TABLE schedule_executions ADD COLUMN duplicate_of UUID;
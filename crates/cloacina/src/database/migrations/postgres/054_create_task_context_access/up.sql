@@ -0,0 +1,17 @@
+-- Context access tracing: the context keys each task attempt actually read
+-- and wrote at runtime (JSON arrays of key names), recorded by the executor
+-- when tracing is enabled. Compared against the workflow's dependency graph
+-- to surface hidden data couplings between tasks.
+CREATE TABLE task_context_access (
+    id UUID PRIMARY KEY,
+    workflow_execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id UUID NOT NULL REFERENCES task_executions(id) ON DELETE CASCADE,
+    task_name VARCHAR NOT NULL,
+    attempt INTEGER NOT NULL,
+    reads TEXT NOT NULL,
+    writes TEXT NOT NULL,
+    recorded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_task_context_access_execution
+    ON task_context_access(workflow_execution_id, recorded_at);
@@ -0,0 +1,4 @@
+-- Reverse approval gates.
+DROP INDEX IF EXISTS idx_task_approvals_task;
+DROP INDEX IF EXISTS idx_task_approvals_execution;
+DROP TABLE task_approvals;
@@ -0,0 +1,18 @@
+-- Approval gates: decisions recorded for tasks that require a human
+-- approval before they run. Append-only; the latest decision for a task
+-- execution is the one the scheduler honours.
+CREATE TABLE task_approvals (
+    id UUID PRIMARY KEY,
+    workflow_execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id UUID NOT NULL REFERENCES task_executions(id) ON DELETE CASCADE,
+    task_name VARCHAR NOT NULL,
+    decision VARCHAR NOT NULL CHECK (decision IN ('approved', 'rejected')),
+    approver VARCHAR NOT NULL,
+    comment TEXT,
+    decided_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_task_approvals_execution
+    ON task_approvals(workflow_execution_id, decided_at);
+CREATE INDEX idx_task_approvals_task
+    ON task_approvals(task_execution_id, decided_at);
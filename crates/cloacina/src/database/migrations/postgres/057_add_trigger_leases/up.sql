@@ -0,0 +1,14 @@
+-- Trigger leases: runners sharing a database split trigger polling by
+-- leasing individual trigger schedules. lease_owner polls the trigger until
+-- lease_expires_at; once the lease expires any runner may take it over.
+-- trigger_pollers lists the runners taking part, so each can hold a fair
+-- share of the leases.
+ALTER TABLE schedules ADD COLUMN lease_owner UUID;
+ALTER TABLE schedules ADD COLUMN lease_expires_at TIMESTAMP;
+
+CREATE INDEX idx_schedules_lease_owner ON schedules(lease_owner);
+
+CREATE TABLE trigger_pollers (
+    id UUID PRIMARY KEY,
+    heartbeat_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,11 @@
+-- Workflow deprecations: a workflow marked for retirement, with an optional
+-- sunset date and a note for its consumers. Every execution of a deprecated
+-- workflow logs a warning, and the runner refuses new schedules against it.
+-- Keyed by workflow name, so the deprecation survives new package versions.
+CREATE TABLE workflow_deprecations (
+    workflow_name VARCHAR(512) PRIMARY KEY,
+    message TEXT,
+    sunset_at TIMESTAMP,
+    deprecated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
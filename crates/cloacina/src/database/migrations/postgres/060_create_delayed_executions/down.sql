@@ -0,0 +1,2 @@
+-- Reverse delayed executions.
+DROP TABLE delayed_executions;
@@ -0,0 +1,16 @@
+-- Delayed executions: one-shot runs of a workflow at a future time, created
+-- with execute_at / execute_after. The scheduler claims a row once run_at
+-- has passed and hands it to the executor; pending rows survive restarts.
+CREATE TABLE delayed_executions (
+    id UUID PRIMARY KEY,
+    workflow_name VARCHAR NOT NULL,
+    context TEXT NOT NULL,
+    run_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    claimed_at TIMESTAMP,
+    workflow_execution_id UUID,
+    error TEXT
+);
+
+CREATE INDEX idx_delayed_executions_pending
+    ON delayed_executions(run_at) WHERE claimed_at IS NULL;
@@ -0,0 +1,6 @@
+-- Trigger throttling: a trigger schedule may require a minimum time between
+-- fires (firings sooner are suppressed as 'rate_limited') and may debounce
+-- firings, running only the latest once the trigger has been quiet for the
+-- window. Both in milliseconds; NULL = no limit / fire at once.
+ALTER TABLE schedules ADD COLUMN min_fire_interval_ms INTEGER;
+ALTER TABLE schedules ADD COLUMN debounce_window_ms INTEGER;
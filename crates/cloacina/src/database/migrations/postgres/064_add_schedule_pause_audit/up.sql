@@ -0,0 +1,3 @@
+-- Who paused a schedule and why, alongside paused_at. Cleared on resume.
+ALTER TABLE schedules ADD COLUMN paused_by TEXT;
+ALTER TABLE schedules ADD COLUMN pause_reason TEXT;
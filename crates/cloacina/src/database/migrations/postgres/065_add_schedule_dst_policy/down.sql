@@ -0,0 +1 @@
+ALTER TABLE schedules DROP COLUMN dst_policy;
@@ -0,0 +1,16 @@
+-- Context lineage: the top-level context keys each successful task attempt
+-- added, changed or removed, with previous and new values (a JSON array,
+-- sealed with the at-rest keys when configured), recorded by the executor
+-- when lineage tracking is enabled.
+CREATE TABLE task_context_changes (
+    id UUID PRIMARY KEY,
+    workflow_execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id UUID NOT NULL REFERENCES task_executions(id) ON DELETE CASCADE,
+    task_name VARCHAR NOT NULL,
+    attempt INTEGER NOT NULL,
+    changes TEXT NOT NULL,
+    recorded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_task_context_changes_execution
+    ON task_context_changes(workflow_execution_id, recorded_at);
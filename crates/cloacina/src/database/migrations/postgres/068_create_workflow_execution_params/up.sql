@@ -0,0 +1,9 @@
+-- Execution parameters: the run configuration an execution was started with
+-- (iteration counts, failure rates, backup types), kept apart from its
+-- context. Tasks read them but cannot change them. Stored as one JSON object
+-- per execution; executions started without parameters have no row.
+CREATE TABLE workflow_execution_params (
+    workflow_execution_id UUID PRIMARY KEY REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    params TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
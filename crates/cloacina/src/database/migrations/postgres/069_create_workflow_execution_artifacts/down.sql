@@ -0,0 +1,2 @@
+-- Reverse run artifacts.
+DROP TABLE workflow_execution_artifacts;
@@ -0,0 +1,17 @@
+-- Run artifacts: named files (reports, exports, images) a task stored for its
+-- workflow execution. The bytes live in the artifact store (the registry
+-- storage, a directory, or an extension's backend) under `storage_id`; this
+-- table records what each one is. A name is unique within an execution and
+-- storing it again replaces the earlier artifact.
+CREATE TABLE workflow_execution_artifacts (
+    id UUID PRIMARY KEY,
+    workflow_execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id UUID REFERENCES task_executions(id) ON DELETE SET NULL,
+    task_name VARCHAR NOT NULL,
+    name VARCHAR NOT NULL,
+    content_type VARCHAR NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    storage_id VARCHAR NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (workflow_execution_id, name)
+);
@@ -0,0 +1,5 @@
+-- Schedules on run_latest_only fall back to skip, the default policy.
+UPDATE schedules SET catchup_policy = 'skip' WHERE catchup_policy = 'run_latest_only';
+ALTER TABLE schedules DROP CONSTRAINT IF EXISTS schedules_catchup_policy_check;
+ALTER TABLE schedules ADD CONSTRAINT schedules_catchup_policy_check
+    CHECK (catchup_policy IS NULL OR catchup_policy IN ('skip', 'run_all'));
@@ -0,0 +1,4 @@
+-- Allow the run_latest_only cron catchup policy alongside skip and run_all.
+ALTER TABLE schedules DROP CONSTRAINT IF EXISTS schedules_catchup_policy_check;
+ALTER TABLE schedules ADD CONSTRAINT schedules_catchup_policy_check
+    CHECK (catchup_policy IS NULL OR catchup_policy IN ('skip', 'run_latest_only', 'run_all'));
@@ -0,0 +1,2 @@
+-- Fails while sealed or binary-format rows remain.
+ALTER TABLE contexts ADD CONSTRAINT contexts_value_check CHECK (value::json IS NOT NULL);
@@ -0,0 +1,3 @@
+-- Sealed contexts (at-rest encryption) and binary-format rows keep non-JSON
+-- text in value, so it can no longer be checked as JSON.
+ALTER TABLE contexts DROP CONSTRAINT IF EXISTS contexts_value_check;
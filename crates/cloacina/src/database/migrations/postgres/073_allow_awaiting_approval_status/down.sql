@@ -0,0 +1,5 @@
+-- Tasks still awaiting approval go back to waiting on their dependencies.
+UPDATE task_executions SET status = 'NotStarted' WHERE status = 'AwaitingApproval';
+ALTER TABLE task_executions DROP CONSTRAINT IF EXISTS task_executions_status_check;
+ALTER TABLE task_executions ADD CONSTRAINT task_executions_status_check
+    CHECK (status IN ('NotStarted', 'Ready', 'Running', 'Completed', 'Failed', 'Skipped'));
@@ -0,0 +1,4 @@
+-- Approval gates park a task in AwaitingApproval until it is decided.
+ALTER TABLE task_executions DROP CONSTRAINT IF EXISTS task_executions_status_check;
+ALTER TABLE task_executions ADD CONSTRAINT task_executions_status_check
+    CHECK (status IN ('NotStarted', 'Ready', 'Running', 'Completed', 'Failed', 'Skipped', 'AwaitingApproval'));
@@ -0,0 +1,2 @@
+-- Reverse execution labels.
+DROP TABLE workflow_execution_labels;
@@ -0,0 +1,15 @@
+-- Execution labels: arbitrary key/value pairs attached to a workflow execution
+-- at submission (source=api, customer=acme, backfill=true). One row per label
+-- so executions can be filtered by label through the (key, value) index
+-- instead of encoding the metadata in the context.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE workflow_execution_labels (
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    label_key TEXT NOT NULL,
+    label_value TEXT NOT NULL,
+    created_at TEXT NOT NULL,              -- RFC3339 format
+    PRIMARY KEY (workflow_execution_id, label_key)
+);
+
+CREATE INDEX idx_workflow_execution_labels_key_value
+    ON workflow_execution_labels(label_key, label_value);
@@ -0,0 +1,2 @@
+-- Reverse operator annotations.
+DROP TABLE workflow_execution_annotations;
@@ -0,0 +1,16 @@
+-- Operator annotations: free-text notes attached to a workflow execution by
+-- an operator ("known outage, reran manually at 14:00"), optionally carrying a
+-- resolution status. Append-only; the latest annotation with a resolution is
+-- the execution's current resolution.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE workflow_execution_annotations (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    author TEXT NOT NULL,
+    note TEXT NOT NULL,
+    resolution TEXT,
+    created_at TEXT NOT NULL              -- RFC3339 format
+);
+
+CREATE INDEX idx_workflow_execution_annotations_execution
+    ON workflow_execution_annotations(workflow_execution_id, created_at);
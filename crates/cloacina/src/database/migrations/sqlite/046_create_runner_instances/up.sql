@@ -0,0 +1,18 @@
+-- Runner instances: one row per running DefaultRunner that takes part in
+-- blue/green handoff. A starting runner with takeover enabled moves every
+-- live `active` instance to `draining` and records itself as their successor;
+-- the old runner stops taking new work, finishes what it has in flight, and
+-- marks itself `drained`.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE runner_instances (
+    id BLOB PRIMARY KEY NOT NULL,
+    name TEXT,
+    version TEXT NOT NULL,
+    state TEXT NOT NULL DEFAULT 'active',
+    successor_id BLOB,
+    started_at TEXT NOT NULL,             -- RFC3339 format
+    heartbeat_at TEXT NOT NULL,           -- RFC3339 format
+    updated_at TEXT NOT NULL              -- RFC3339 format
+);
+
+CREATE INDEX idx_runner_instances_state ON runner_instances(state, heartbeat_at);
@@ -0,0 +1,2 @@
+-- Reverse structured task errors.
+ALTER TABLE task_executions DROP COLUMN error_payload;
@@ -0,0 +1,14 @@
+-- Federation chain cursors: how far each chain rule has forwarded. The chain
+-- loop walks completed source executions in (completed_at, id) order and
+-- advances the cursor past each one it has handed to the peer region, so a
+-- restart or a burst of completions never skips or re-reads a page.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE federation_chain_cursors (
+    chain_key TEXT PRIMARY KEY NOT NULL,
+    completed_at TEXT NOT NULL,           -- RFC3339 format
+    execution_id BLOB NOT NULL,
+    updated_at TEXT NOT NULL              -- RFC3339 format
+);
+
+CREATE INDEX idx_workflow_executions_completed
+    ON workflow_executions(workflow_name, status, completed_at, id);
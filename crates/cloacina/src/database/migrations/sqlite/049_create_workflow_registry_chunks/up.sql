@@ -0,0 +1,11 @@
+-- Workflow registry chunks: package archives stored as ordered fixed-size
+-- chunks so registration and reconciliation stream them instead of holding a
+-- whole archive in memory. A chunked archive's workflow_registry row keeps an
+-- empty `data`; rows written before this migration keep their bytes inline.
+-- UUID stored as BLOB (16 bytes)
+CREATE TABLE workflow_registry_chunks (
+    registry_id BLOB NOT NULL REFERENCES workflow_registry(id) ON DELETE CASCADE,
+    chunk_index INTEGER NOT NULL,
+    data BLOB NOT NULL,
+    PRIMARY KEY (registry_id, chunk_index)
+);
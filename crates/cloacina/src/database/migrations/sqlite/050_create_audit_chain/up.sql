@@ -0,0 +1,23 @@
+-- Tamper-evident audit chain. Every audit event is appended with the SHA-256
+-- of the previous entry, so editing or deleting any row breaks every hash
+-- after it. `sequence` is the chain position; its uniqueness is what keeps
+-- concurrent writers on one linear chain. Anchors periodically record (and
+-- optionally sign) the chain head so a rewrite of the whole tail is caught too.
+-- TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE audit_log (
+    sequence INTEGER PRIMARY KEY NOT NULL,
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    prev_hash TEXT NOT NULL,
+    entry_hash TEXT NOT NULL,
+    created_at TEXT NOT NULL              -- RFC3339 format
+);
+
+CREATE INDEX idx_audit_log_event_type ON audit_log(event_type, sequence);
+
+CREATE TABLE audit_anchors (
+    sequence INTEGER PRIMARY KEY NOT NULL REFERENCES audit_log(sequence),
+    entry_hash TEXT NOT NULL,
+    signature TEXT,
+    anchored_at TEXT NOT NULL             -- RFC3339 format
+);
@@ -0,0 +1,10 @@
+-- Execution options: per-execution overrides of runner configuration and
+-- task retry policies (max parallelism, priority, retry attempts and delays,
+-- timeouts), set at submission. Stored as one JSON document per execution;
+-- executions submitted without overrides have no row.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE workflow_execution_options (
+    workflow_execution_id BLOB PRIMARY KEY REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    options TEXT NOT NULL,
+    created_at TEXT NOT NULL              -- RFC3339 format
+);
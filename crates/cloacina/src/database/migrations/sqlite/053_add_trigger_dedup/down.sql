@@ -0,0 +1,4 @@
+ALTER TABLE schedule_executions DROP COLUMN duplicate_of;
+ALTER TABLE schedule_executions DROP COLUMN suppressed_reason;
+ALTER TABLE schedules DROP COLUMN dedup_keys;
+ALTER TABLE schedules DROP COLUMN dedup_window_secs;
@@ -0,0 +1,3 @@
+-- Reverse context access tracing.
+DROP INDEX IF EXISTS idx_task_context_access_execution;
+DROP TABLE task_context_access;
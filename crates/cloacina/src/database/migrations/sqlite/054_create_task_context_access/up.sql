@@ -0,0 +1,18 @@
+-- Context access tracing: the context keys each task attempt actually read
+-- and wrote at runtime (JSON arrays of key names), recorded by the executor
+-- when tracing is enabled. Compared against the workflow's dependency graph
+-- to surface hidden data couplings between tasks.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE task_context_access (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id BLOB NOT NULL REFERENCES task_executions(id) ON DELETE CASCADE,
+    task_name TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    reads TEXT NOT NULL,
+    writes TEXT NOT NULL,
+    recorded_at TEXT NOT NULL             -- RFC3339 format
+);
+
+CREATE INDEX idx_task_context_access_execution
+    ON task_context_access(workflow_execution_id, recorded_at);
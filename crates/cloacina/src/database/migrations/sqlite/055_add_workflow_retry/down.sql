@@ -0,0 +1,2 @@
+ALTER TABLE workflow_executions DROP COLUMN retry_at;
+ALTER TABLE workflow_executions DROP COLUMN attempt;
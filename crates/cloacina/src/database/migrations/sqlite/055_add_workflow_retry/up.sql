@@ -0,0 +1,5 @@
+-- Workflow-level retry: which attempt of the whole workflow an execution is
+-- on, and when a retried execution may start again. Rows created before the
+-- column existed are on their first attempt.
+ALTER TABLE workflow_executions ADD COLUMN attempt INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE workflow_executions ADD COLUMN retry_at TEXT;  -- RFC3339 format
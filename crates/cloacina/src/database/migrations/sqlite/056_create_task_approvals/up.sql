@@ -0,0 +1,19 @@
+-- Approval gates: decisions recorded for tasks that require a human
+-- approval before they run. Append-only; the latest decision for a task
+-- execution is the one the scheduler honours.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE task_approvals (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id BLOB NOT NULL REFERENCES task_executions(id) ON DELETE CASCADE,
+    task_name TEXT NOT NULL,
+    decision TEXT NOT NULL CHECK (decision IN ('approved', 'rejected')),
+    approver TEXT NOT NULL,
+    comment TEXT,
+    decided_at TEXT NOT NULL              -- RFC3339 format
+);
+
+CREATE INDEX idx_task_approvals_execution
+    ON task_approvals(workflow_execution_id, decided_at);
+CREATE INDEX idx_task_approvals_task
+    ON task_approvals(task_execution_id, decided_at);
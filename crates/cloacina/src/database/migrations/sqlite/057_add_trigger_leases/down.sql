@@ -0,0 +1,4 @@
+DROP TABLE trigger_pollers;
+DROP INDEX IF EXISTS idx_schedules_lease_owner;
+ALTER TABLE schedules DROP COLUMN lease_expires_at;
+ALTER TABLE schedules DROP COLUMN lease_owner;
@@ -0,0 +1,15 @@
+-- Trigger leases: runners sharing a database split trigger polling by
+-- leasing individual trigger schedules. lease_owner polls the trigger until
+-- lease_expires_at; once the lease expires any runner may take it over.
+-- trigger_pollers lists the runners taking part, so each can hold a fair
+-- share of the leases.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+ALTER TABLE schedules ADD COLUMN lease_owner BLOB;
+ALTER TABLE schedules ADD COLUMN lease_expires_at TEXT;
+
+CREATE INDEX idx_schedules_lease_owner ON schedules(lease_owner);
+
+CREATE TABLE trigger_pollers (
+    id BLOB PRIMARY KEY NOT NULL,
+    heartbeat_at TEXT NOT NULL            -- RFC3339 format
+);
@@ -0,0 +1,2 @@
+-- Reverse workflow deprecations.
+DROP TABLE workflow_deprecations;
@@ -0,0 +1,12 @@
+-- Workflow deprecations: a workflow marked for retirement, with an optional
+-- sunset date and a note for its consumers. Every execution of a deprecated
+-- workflow logs a warning, and the runner refuses new schedules against it.
+-- Keyed by workflow name, so the deprecation survives new package versions.
+-- TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE workflow_deprecations (
+    workflow_name TEXT PRIMARY KEY NOT NULL,
+    message TEXT,
+    sunset_at TEXT,                       -- RFC3339 format
+    deprecated_at TEXT NOT NULL,          -- RFC3339 format
+    updated_at TEXT NOT NULL              -- RFC3339 format
+);
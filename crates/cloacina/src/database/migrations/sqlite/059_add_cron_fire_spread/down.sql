@@ -0,0 +1,2 @@
+ALTER TABLE schedules DROP COLUMN fire_jitter_secs;
+ALTER TABLE schedules DROP COLUMN fire_offset_secs;
@@ -0,0 +1,6 @@
+-- Cron fire spread: a cron schedule may run each fire a fixed offset plus a
+-- per-schedule jitter after its cron time, so schedules sharing an
+-- expression do not all hit the executor at the same instant. Both in
+-- seconds; NULL = no offset / no jitter. next_run_at holds the delayed time.
+ALTER TABLE schedules ADD COLUMN fire_offset_secs INTEGER;
+ALTER TABLE schedules ADD COLUMN fire_jitter_secs INTEGER;
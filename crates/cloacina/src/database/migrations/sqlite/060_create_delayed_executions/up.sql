@@ -0,0 +1,17 @@
+-- Delayed executions: one-shot runs of a workflow at a future time, created
+-- with execute_at / execute_after. The scheduler claims a row once run_at
+-- has passed and hands it to the executor; pending rows survive restarts.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE delayed_executions (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_name TEXT NOT NULL,
+    context TEXT NOT NULL,
+    run_at TEXT NOT NULL,                 -- RFC3339 format
+    created_at TEXT NOT NULL,             -- RFC3339 format
+    claimed_at TEXT,                      -- RFC3339 format
+    workflow_execution_id BLOB,
+    error TEXT
+);
+
+CREATE INDEX idx_delayed_executions_pending
+    ON delayed_executions(run_at) WHERE claimed_at IS NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE schedules DROP COLUMN interval_anchor;
+ALTER TABLE schedules DROP COLUMN interval_secs;
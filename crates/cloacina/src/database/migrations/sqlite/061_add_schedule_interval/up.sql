@@ -0,0 +1,5 @@
+-- Interval schedules: a cron-type schedule may fire every interval_secs
+-- seconds, counted from interval_anchor, instead of on a cron expression
+-- (cron_expression is NULL for these). NULL = an ordinary cron schedule.
+ALTER TABLE schedules ADD COLUMN interval_secs INTEGER;
+ALTER TABLE schedules ADD COLUMN interval_anchor TEXT; -- RFC3339 format
@@ -0,0 +1,2 @@
+ALTER TABLE schedules DROP COLUMN debounce_window_ms;
+ALTER TABLE schedules DROP COLUMN min_fire_interval_ms;
@@ -0,0 +1,2 @@
+ALTER TABLE schedules DROP COLUMN trigger_probes;
+ALTER TABLE schedules DROP COLUMN trigger_condition;
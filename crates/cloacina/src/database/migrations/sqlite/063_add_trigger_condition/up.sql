@@ -0,0 +1,6 @@
+-- Declarative trigger conditions: a CEL expression over the fired context and
+-- the results of the trigger's probes (a JSON array of probe definitions).
+-- A trigger schedule with a condition but no registered trigger fires
+-- whenever the condition holds, so operators can define triggers at runtime.
+ALTER TABLE schedules ADD COLUMN trigger_condition TEXT;
+ALTER TABLE schedules ADD COLUMN trigger_probes TEXT;
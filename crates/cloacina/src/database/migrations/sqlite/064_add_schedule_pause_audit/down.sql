@@ -0,0 +1,2 @@
+ALTER TABLE schedules DROP COLUMN pause_reason;
+ALTER TABLE schedules DROP COLUMN paused_by;
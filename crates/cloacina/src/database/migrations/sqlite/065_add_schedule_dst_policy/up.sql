@@ -0,0 +1,3 @@
+-- Per-schedule daylight-saving policy for cron schedules: skip, run_once or
+-- run_twice. NULL is run_once, the behavior before this column existed.
+ALTER TABLE schedules ADD COLUMN dst_policy TEXT;
@@ -0,0 +1,3 @@
+-- Reverse context lineage tracking.
+DROP INDEX IF EXISTS idx_task_context_changes_execution;
+DROP TABLE task_context_changes;
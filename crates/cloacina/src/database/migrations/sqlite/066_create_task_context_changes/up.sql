@@ -0,0 +1,17 @@
+-- Context lineage: the top-level context keys each successful task attempt
+-- added, changed or removed, with previous and new values (a JSON array,
+-- sealed with the at-rest keys when configured), recorded by the executor
+-- when lineage tracking is enabled.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE task_context_changes (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id BLOB NOT NULL REFERENCES task_executions(id) ON DELETE CASCADE,
+    task_name TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    changes TEXT NOT NULL,
+    recorded_at TEXT NOT NULL             -- RFC3339 format
+);
+
+CREATE INDEX idx_task_context_changes_execution
+    ON task_context_changes(workflow_execution_id, recorded_at);
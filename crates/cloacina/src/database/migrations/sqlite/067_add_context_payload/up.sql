@@ -0,0 +1,4 @@
+-- Binary context formats (MessagePack, CBOR): the encoded context goes in
+-- payload and value holds the format's name. NULL for JSON rows, where value
+-- is the context itself.
+ALTER TABLE contexts ADD COLUMN payload BLOB;
@@ -0,0 +1,2 @@
+-- Reverse execution parameters.
+DROP TABLE workflow_execution_params;
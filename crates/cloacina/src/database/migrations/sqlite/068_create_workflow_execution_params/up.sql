@@ -0,0 +1,10 @@
+-- Execution parameters: the run configuration an execution was started with
+-- (iteration counts, failure rates, backup types), kept apart from its
+-- context. Tasks read them but cannot change them. Stored as one JSON object
+-- per execution; executions started without parameters have no row.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE workflow_execution_params (
+    workflow_execution_id BLOB PRIMARY KEY REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    params TEXT NOT NULL,
+    created_at TEXT NOT NULL              -- RFC3339 format
+);
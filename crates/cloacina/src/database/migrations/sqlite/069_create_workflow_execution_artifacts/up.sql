@@ -0,0 +1,18 @@
+-- Run artifacts: named files (reports, exports, images) a task stored for its
+-- workflow execution. The bytes live in the artifact store (the registry
+-- storage, a directory, or an extension's backend) under `storage_id`; this
+-- table records what each one is. A name is unique within an execution and
+-- storing it again replaces the earlier artifact.
+-- UUID stored as BLOB (16 bytes), TIMESTAMP stored as TEXT (RFC3339 format)
+CREATE TABLE workflow_execution_artifacts (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    task_execution_id BLOB REFERENCES task_executions(id) ON DELETE SET NULL,
+    task_name TEXT NOT NULL,
+    name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    storage_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,             -- RFC3339 format
+    UNIQUE (workflow_execution_id, name)
+);
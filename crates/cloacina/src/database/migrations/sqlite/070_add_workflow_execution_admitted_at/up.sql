@@ -0,0 +1,4 @@
+-- When an execution queued behind a concurrency limit was admitted to run;
+-- its workflow timeout is measured from then. NULL for executions never held
+-- back by a limit.
+ALTER TABLE workflow_executions ADD COLUMN admitted_at TEXT;  -- RFC3339 format
@@ -0,0 +1,90 @@
+-- Revert to the skip and run_all catchup policies only.
+--
+-- SQLite doesn't support ALTER TABLE ... DROP CONSTRAINT, so we recreate the table
+
+-- Schedules on run_latest_only fall back to skip, the default policy.
+UPDATE schedules SET catchup_policy = 'skip' WHERE catchup_policy = 'run_latest_only';
+
+-- Create table with the original CHECK constraint
+CREATE TABLE schedules_new (
+    id BLOB PRIMARY KEY NOT NULL,
+    schedule_type TEXT NOT NULL CHECK (schedule_type IN ('cron', 'trigger')),
+    workflow_name TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1 CHECK (enabled IN (0, 1)),
+    cron_expression TEXT,
+    timezone TEXT DEFAULT 'UTC',
+    catchup_policy TEXT DEFAULT 'skip' CHECK (catchup_policy IS NULL OR catchup_policy IN ('skip', 'run_all')),
+    start_date TEXT,
+    end_date TEXT,
+    trigger_name TEXT UNIQUE,
+    poll_interval_ms INTEGER,
+    allow_concurrent INTEGER DEFAULT 0 CHECK (allow_concurrent IS NULL OR allow_concurrent IN (0, 1)),
+    next_run_at TEXT,
+    last_run_at TEXT,
+    last_poll_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    paused INTEGER NOT NULL DEFAULT 0 CHECK (paused IN (0, 1)),
+    paused_at TEXT,
+    params TEXT,
+    instance_name TEXT,
+    tags TEXT,
+    dedup_window_secs INTEGER,
+    dedup_keys TEXT,
+    lease_owner BLOB,
+    lease_expires_at TEXT,
+    fire_offset_secs INTEGER,
+    fire_jitter_secs INTEGER,
+    interval_secs INTEGER,
+    interval_anchor TEXT,
+    min_fire_interval_ms INTEGER,
+    debounce_window_ms INTEGER,
+    trigger_condition TEXT,
+    trigger_probes TEXT,
+    paused_by TEXT,
+    pause_reason TEXT,
+    dst_policy TEXT
+);
+
+-- Copy existing data
+INSERT INTO schedules_new (
+    id, schedule_type, workflow_name, enabled, cron_expression, timezone,
+    catchup_policy, start_date, end_date, trigger_name, poll_interval_ms,
+    allow_concurrent, next_run_at, last_run_at, last_poll_at, created_at,
+    updated_at, paused, paused_at, params, instance_name, tags,
+    dedup_window_secs, dedup_keys, lease_owner, lease_expires_at,
+    fire_offset_secs, fire_jitter_secs, interval_secs, interval_anchor,
+    min_fire_interval_ms, debounce_window_ms, trigger_condition,
+    trigger_probes, paused_by, pause_reason, dst_policy
+)
+SELECT
+    id, schedule_type, workflow_name, enabled, cron_expression, timezone,
+    catchup_policy, start_date, end_date, trigger_name, poll_interval_ms,
+    allow_concurrent, next_run_at, last_run_at, last_poll_at, created_at,
+    updated_at, paused, paused_at, params, instance_name, tags,
+    dedup_window_secs, dedup_keys, lease_owner, lease_expires_at,
+    fire_offset_secs, fire_jitter_secs, interval_secs, interval_anchor,
+    min_fire_interval_ms, debounce_window_ms, trigger_condition,
+    trigger_probes, paused_by, pause_reason, dst_policy
+FROM schedules;
+
+-- Drop old table
+DROP TABLE schedules;
+
+-- Rename new table
+ALTER TABLE schedules_new RENAME TO schedules;
+
+-- Recreate indexes
+CREATE INDEX idx_schedules_cron_polling
+ON schedules (enabled, next_run_at)
+WHERE enabled = 1 AND schedule_type = 'cron';
+CREATE INDEX idx_schedules_trigger_polling
+ON schedules (enabled, last_poll_at)
+WHERE enabled = 1 AND schedule_type = 'trigger';
+CREATE INDEX idx_schedules_workflow ON schedules (workflow_name, enabled);
+CREATE INDEX idx_schedules_type ON schedules (schedule_type);
+CREATE INDEX idx_schedules_paused ON schedules (paused) WHERE paused = 1;
+CREATE UNIQUE INDEX idx_schedules_instance_name
+    ON schedules (workflow_name, instance_name)
+    WHERE instance_name IS NOT NULL;
+CREATE INDEX idx_schedules_lease_owner ON schedules (lease_owner);
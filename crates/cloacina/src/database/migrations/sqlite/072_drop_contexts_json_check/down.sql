@@ -0,0 +1,27 @@
+-- Restore the JSON CHECK on contexts.value. Fails while sealed or
+-- binary-format rows remain.
+--
+-- SQLite doesn't support ALTER TABLE ... DROP CONSTRAINT, so we recreate the table
+
+-- Create new table with the JSON CHECK constraint
+CREATE TABLE contexts_new (
+    id BLOB PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL CHECK (json_valid(value)),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    payload BLOB
+);
+
+-- Copy existing data
+INSERT INTO contexts_new (id, value, created_at, updated_at, payload)
+SELECT id, value, created_at, updated_at, payload
+FROM contexts;
+
+-- Drop old table
+DROP TABLE contexts;
+
+-- Rename new table
+ALTER TABLE contexts_new RENAME TO contexts;
+
+-- Recreate indexes
+CREATE INDEX contexts_created_at_idx ON contexts(created_at DESC);
@@ -0,0 +1,58 @@
+-- Revert to the task statuses without AwaitingApproval.
+--
+-- SQLite doesn't support ALTER TABLE ... DROP CONSTRAINT, so we recreate the table
+
+-- Tasks still awaiting approval go back to waiting on their dependencies.
+UPDATE task_executions SET status = 'NotStarted' WHERE status = 'AwaitingApproval';
+
+-- Create new table with the updated CHECK constraint
+CREATE TABLE task_executions_new (
+    id BLOB PRIMARY KEY NOT NULL,
+    workflow_execution_id BLOB NOT NULL REFERENCES workflow_executions(id),
+    task_name TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('NotStarted', 'Ready', 'Running', 'Completed', 'Failed', 'Skipped')),
+    started_at TEXT,
+    completed_at TEXT,
+    attempt INTEGER DEFAULT 1,
+    max_attempts INTEGER DEFAULT 1,
+    error_details TEXT,
+    trigger_rules TEXT DEFAULT '{"type": "Always"}' CHECK (trigger_rules IS NULL OR json_valid(trigger_rules)),
+    task_configuration TEXT DEFAULT '{}' CHECK (task_configuration IS NULL OR json_valid(task_configuration)),
+    retry_at TEXT,
+    last_error TEXT,
+    recovery_attempts INTEGER DEFAULT 0 NOT NULL,
+    last_recovery_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    sub_status TEXT,
+    claimed_by BLOB,
+    heartbeat_at TEXT,
+    error_payload TEXT
+);
+
+-- Copy existing data
+INSERT INTO task_executions_new (
+    id, workflow_execution_id, task_name, status, started_at, completed_at,
+    attempt, max_attempts, error_details, trigger_rules, task_configuration,
+    retry_at, last_error, recovery_attempts, last_recovery_at, created_at,
+    updated_at, sub_status, claimed_by, heartbeat_at, error_payload
+)
+SELECT
+    id, workflow_execution_id, task_name, status, started_at, completed_at,
+    attempt, max_attempts, error_details, trigger_rules, task_configuration,
+    retry_at, last_error, recovery_attempts, last_recovery_at, created_at,
+    updated_at, sub_status, claimed_by, heartbeat_at, error_payload
+FROM task_executions;
+
+-- Drop old table
+DROP TABLE task_executions;
+
+-- Rename new table
+ALTER TABLE task_executions_new RENAME TO task_executions;
+
+-- Recreate indexes
+CREATE INDEX task_executions_status_idx ON task_executions(status);
+CREATE INDEX task_executions_pipeline_idx ON task_executions(workflow_execution_id);
+CREATE INDEX task_executions_task_name_idx ON task_executions(task_name);
+CREATE INDEX task_executions_running_idx ON task_executions(status) WHERE status = 'Running';
+CREATE INDEX idx_task_executions_claimed ON task_executions(claimed_by) WHERE claimed_by IS NOT NULL;
@@ -30,6 +30,7 @@ mod unified_schema {
         contexts (id) {
             id -> DbUuid,
             value -> Text,
+            payload -> Nullable<DbBinary>,
             created_at -> DbTimestamp,
             updated_at -> DbTimestamp,
         }
@@ -55,6 +56,229 @@ mod unified_schema {
             created_at -> DbTimestamp,
             updated_at -> DbTimestamp,
             trigger_origin -> Nullable<Text>,
+            run_id -> Nullable<Text>,
+            logical_date -> Nullable<DbTimestamp>,
+            attempt -> Integer,
+            retry_at -> Nullable<DbTimestamp>,
+            admitted_at -> Nullable<DbTimestamp>,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        workflow_execution_labels (workflow_execution_id, label_key) {
+            workflow_execution_id -> DbUuid,
+            label_key -> Text,
+            label_value -> Text,
+            created_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        workflow_execution_options (workflow_execution_id) {
+            workflow_execution_id -> DbUuid,
+            options -> Text,
+            created_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        workflow_execution_params (workflow_execution_id) {
+            workflow_execution_id -> DbUuid,
+            params -> Text,
+            created_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Named files tasks stored for their execution; the bytes live in
+        /// the artifact store under `storage_id`.
+        workflow_execution_artifacts (id) {
+            id -> DbUuid,
+            workflow_execution_id -> DbUuid,
+            task_execution_id -> Nullable<DbUuid>,
+            task_name -> Text,
+            name -> Text,
+            content_type -> Text,
+            size_bytes -> BigInt,
+            storage_id -> Text,
+            created_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        workflow_execution_annotations (id) {
+            id -> DbUuid,
+            workflow_execution_id -> DbUuid,
+            author -> Text,
+            note -> Text,
+            resolution -> Nullable<Text>,
+            created_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Context keys each task attempt read and wrote (JSON arrays).
+        task_context_access (id) {
+            id -> DbUuid,
+            workflow_execution_id -> DbUuid,
+            task_execution_id -> DbUuid,
+            task_name -> Text,
+            attempt -> Integer,
+            reads -> Text,
+            writes -> Text,
+            recorded_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Context keys each successful task attempt changed, with previous
+        /// and new values (JSON array, possibly sealed).
+        task_context_changes (id) {
+            id -> DbUuid,
+            workflow_execution_id -> DbUuid,
+            task_execution_id -> DbUuid,
+            task_name -> Text,
+            attempt -> Integer,
+            changes -> Text,
+            recorded_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Approval decisions for gated tasks (append-only).
+        task_approvals (id) {
+            id -> DbUuid,
+            workflow_execution_id -> DbUuid,
+            task_execution_id -> DbUuid,
+            task_name -> Text,
+            decision -> Text,
+            approver -> Text,
+            comment -> Nullable<Text>,
+            decided_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Per-rule position of the federation chain loop.
+        federation_chain_cursors (chain_key) {
+            chain_key -> Text,
+            completed_at -> DbTimestamp,
+            execution_id -> DbUuid,
+            updated_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Workflows marked for retirement, keyed by workflow name.
+        workflow_deprecations (workflow_name) {
+            workflow_name -> Text,
+            message -> Nullable<Text>,
+            sunset_at -> Nullable<DbTimestamp>,
+            deprecated_at -> DbTimestamp,
+            updated_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp};
+
+        /// One-shot workflow runs due at a future time.
+        delayed_executions (id) {
+            id -> DbUuid,
+            workflow_name -> Text,
+            context -> Text,
+            run_at -> DbTimestamp,
+            created_at -> DbTimestamp,
+            claimed_at -> Nullable<DbTimestamp>,
+            workflow_execution_id -> Nullable<DbUuid>,
+            error -> Nullable<Text>,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::DbTimestamp;
+
+        /// Hash-chained audit events; see `security::audit_chain`.
+        audit_log (sequence) {
+            sequence -> BigInt,
+            event_type -> Text,
+            payload -> Text,
+            prev_hash -> Text,
+            entry_hash -> Text,
+            created_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::DbTimestamp;
+
+        /// Recorded (optionally signed) audit chain heads.
+        audit_anchors (sequence) {
+            sequence -> BigInt,
+            entry_hash -> Text,
+            signature -> Nullable<Text>,
+            anchored_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Runners sharing trigger polling through leases.
+        trigger_pollers (id) {
+            id -> DbUuid,
+            heartbeat_at -> DbTimestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
+
+        /// Live runner registry used by blue/green handoff.
+        runner_instances (id) {
+            id -> DbUuid,
+            name -> Nullable<Text>,
+            version -> Text,
+            state -> Text,
+            successor_id -> Nullable<DbUuid>,
+            started_at -> DbTimestamp,
+            heartbeat_at -> DbTimestamp,
+            updated_at -> DbTimestamp,
         }
     }
 
@@ -83,6 +307,7 @@ mod unified_schema {
             heartbeat_at -> Nullable<DbTimestamp>,
             created_at -> DbTimestamp,
             updated_at -> DbTimestamp,
+            error_payload -> Nullable<Text>,
         }
     }
 
@@ -186,6 +411,17 @@ mod unified_schema {
         }
     }
 
+    diesel::table! {
+        use diesel::sql_types::*;
+        use crate::database::universal_types::{DbUuid, DbBinary};
+
+        workflow_registry_chunks (registry_id, chunk_index) {
+            registry_id -> DbUuid,
+            chunk_index -> Integer,
+            data -> DbBinary,
+        }
+    }
+
     diesel::table! {
         use diesel::sql_types::*;
         use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
@@ -263,6 +499,7 @@ mod unified_schema {
     // Unified Schedule Tables
     // =========================================================================
 
+    // More than 32 columns: needs diesel's `64-column-tables` feature.
     diesel::table! {
         use diesel::sql_types::*;
         use crate::database::universal_types::{DbUuid, DbTimestamp, DbBool, DbBinary};
@@ -289,6 +526,22 @@ mod unified_schema {
             paused_at -> Nullable<DbTimestamp>,
             params -> Nullable<Text>,
             instance_name -> Nullable<Text>,
+            tags -> Nullable<Text>,
+            dedup_window_secs -> Nullable<Integer>,
+            dedup_keys -> Nullable<Text>,
+            lease_owner -> Nullable<DbUuid>,
+            lease_expires_at -> Nullable<DbTimestamp>,
+            fire_offset_secs -> Nullable<Integer>,
+            fire_jitter_secs -> Nullable<Integer>,
+            interval_secs -> Nullable<Integer>,
+            interval_anchor -> Nullable<DbTimestamp>,
+            min_fire_interval_ms -> Nullable<Integer>,
+            debounce_window_ms -> Nullable<Integer>,
+            trigger_condition -> Nullable<Text>,
+            trigger_probes -> Nullable<Text>,
+            paused_by -> Nullable<Text>,
+            pause_reason -> Nullable<Text>,
+            dst_policy -> Nullable<Text>,
         }
     }
 
@@ -307,6 +560,8 @@ mod unified_schema {
             completed_at -> Nullable<DbTimestamp>,
             created_at -> DbTimestamp,
             updated_at -> DbTimestamp,
+            suppressed_reason -> Nullable<Text>,
+            duplicate_of -> Nullable<DbUuid>,
         }
     }
 
@@ -478,6 +733,14 @@ mod unified_schema {
     diesel::joinable!(task_outbox -> task_executions (task_execution_id));
     diesel::joinable!(schedule_executions -> schedules (schedule_id));
     diesel::joinable!(schedule_executions -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(workflow_execution_labels -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(workflow_execution_options -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(workflow_execution_params -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(workflow_execution_artifacts -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(workflow_execution_annotations -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(task_context_access -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(task_context_changes -> workflow_executions (workflow_execution_id));
+    diesel::joinable!(task_approvals -> workflow_executions (workflow_execution_id));
 
     diesel::table! {
         use diesel::sql_types::*;
@@ -522,27 +785,43 @@ mod unified_schema {
     diesel::allow_tables_to_appear_in_same_query!(
         accumulator_boundaries,
         accumulator_checkpoints,
+        audit_anchors,
+        audit_log,
         contexts,
+        delayed_executions,
         execution_events,
+        federation_chain_cursors,
         key_trust_acls,
         package_signatures,
         workflow_executions,
+        workflow_execution_labels,
+        workflow_execution_options,
+        workflow_execution_params,
+        workflow_execution_artifacts,
+        workflow_execution_annotations,
         reactor_firings,
         reactor_state,
         reactor_trigger_subscriptions,
         recovery_events,
+        runner_instances,
         schedule_executions,
         schedules,
         secrets,
         signing_keys,
         state_accumulator_buffers,
+        task_approvals,
+        task_context_access,
+        task_context_changes,
         task_executions,
         task_execution_metadata,
         task_outbox,
         tenant_data_keys,
+        trigger_pollers,
         trusted_keys,
+        workflow_deprecations,
         workflow_packages,
         workflow_registry,
+        workflow_registry_chunks,
     );
 }
 
@@ -559,6 +838,7 @@ mod postgres_schema {
         contexts (id) {
             id -> Uuid,
             value -> Varchar,
+            payload -> Nullable<Bytea>,
             created_at -> Timestamp,
             updated_at -> Timestamp,
         }
@@ -581,6 +861,11 @@ mod postgres_schema {
             created_at -> Timestamp,
             updated_at -> Timestamp,
             trigger_origin -> Nullable<Text>,
+            run_id -> Nullable<Text>,
+            logical_date -> Nullable<Timestamp>,
+            attempt -> Int4,
+            retry_at -> Nullable<Timestamp>,
+            admitted_at -> Nullable<Timestamp>,
         }
     }
 
@@ -1010,6 +1295,7 @@ mod sqlite_schema {
         contexts (id) {
             id -> Binary,
             value -> Text,
+            payload -> Nullable<Binary>,
             created_at -> Text,
             updated_at -> Text,
         }
@@ -122,7 +122,29 @@ use uuid::Uuid;
 
 // Re-export TaskError and CheckpointError from cloacina_workflow
 // This ensures type compatibility with macro-generated code
-pub use cloacina_workflow::{CheckpointError, TaskError};
+pub use cloacina_workflow::{CheckpointError, ErrorCategory, TaskError, TaskErrorPayload};
+
+/// Error payload code stored on a task that ran past its timeout.
+pub const TASK_TIMEOUT_CODE: &str = "TASK_TIMEOUT";
+
+/// Error payload code stored on tasks failed because their workflow
+/// execution ran past the workflow timeout.
+pub const WORKFLOW_TIMEOUT_CODE: &str = "WORKFLOW_TIMEOUT";
+
+/// Error payload code stored on tasks failed because their workflow
+/// execution ran out of time before its deadline.
+pub const DEADLINE_EXCEEDED_CODE: &str = "DEADLINE_EXCEEDED";
+
+/// Error payload code stored on a looping task whose `loop_until` condition
+/// still did not hold after `max_iterations` iterations.
+pub const LOOP_LIMIT_EXCEEDED_CODE: &str = "LOOP_LIMIT_EXCEEDED";
+
+/// Error payload code stored on a task whose output context is missing, or
+/// mistypes, one of the outputs it declares with `produces`.
+pub const OUTPUT_SCHEMA_VIOLATION_CODE: &str = "OUTPUT_SCHEMA_VIOLATION";
+
+/// Error payload code stored on a gated task whose approval was rejected.
+pub const TASK_REJECTED_CODE: &str = "TASK_REJECTED";
 
 /// Errors that can occur during context operations.
 ///
@@ -139,6 +161,14 @@ pub enum ContextError {
     #[error("Type mismatch for key {0}")]
     TypeMismatch(String),
 
+    #[error("Cannot read '{path}' as {type_name}: {message}")]
+    Deserialization {
+        key: String,
+        path: String,
+        type_name: String,
+        message: String,
+    },
+
     #[error("Key already exists: {0}")]
     KeyExists(String),
 
@@ -150,6 +180,33 @@ pub enum ContextError {
 
     #[error("Invalid execution scope: {0}")]
     InvalidScope(String),
+
+    /// A stored context could not be sealed or opened with the database's
+    /// at-rest encryption.
+    #[error("Context encryption error: {0}")]
+    Encryption(#[from] crate::crypto::AtRestError),
+
+    /// A context is larger, after offloading, than the database's
+    /// `max_context_bytes`.
+    #[error("Context is {size} bytes, over the {limit}-byte limit")]
+    TooLarge { size: usize, limit: usize },
+
+    /// An offloaded context value could not be written to or read from its
+    /// blob store.
+    #[error("Context offload error: {0}")]
+    Offload(#[from] crate::registry::StorageError),
+
+    /// A context could not be encoded in, or decoded from, its binary
+    /// storage format.
+    #[error("Context {format} encoding error: {message}")]
+    Encoding {
+        format: crate::context_format::ContextFormat,
+        message: String,
+    },
+
+    /// A stored context names a storage format this build does not know.
+    #[error("Context is stored in unknown format '{0}'")]
+    UnknownFormat(String),
 }
 
 impl From<cloacina_workflow::ContextError> for ContextError {
@@ -158,6 +215,17 @@ impl From<cloacina_workflow::ContextError> for ContextError {
             cloacina_workflow::ContextError::Serialization(e) => ContextError::Serialization(e),
             cloacina_workflow::ContextError::KeyNotFound(k) => ContextError::KeyNotFound(k),
             cloacina_workflow::ContextError::TypeMismatch(k) => ContextError::TypeMismatch(k),
+            cloacina_workflow::ContextError::Deserialization {
+                key,
+                path,
+                type_name,
+                message,
+            } => ContextError::Deserialization {
+                key,
+                path,
+                type_name,
+                message,
+            },
             cloacina_workflow::ContextError::KeyExists(k) => ContextError::KeyExists(k),
             cloacina_workflow::ContextError::Database(msg) => ContextError::ConnectionPool(msg),
             cloacina_workflow::ContextError::ConnectionPool(msg) => {
@@ -224,6 +292,26 @@ pub enum ValidationError {
     #[error("Invalid task name format: {0}")]
     InvalidTaskName(String),
 
+    /// An execution label or label selector is malformed.
+    #[error("Invalid execution label: {0}")]
+    InvalidLabel(String),
+
+    /// An execution annotation is malformed.
+    #[error("Invalid execution annotation: {0}")]
+    InvalidAnnotation(String),
+
+    /// Per-execution options are out of range.
+    #[error("Invalid execution options: {0}")]
+    InvalidExecutionOptions(String),
+
+    /// A run artifact's name or content type is malformed.
+    #[error("Invalid artifact: {0}")]
+    InvalidArtifact(String),
+
+    /// A task's declarative configuration failed to render.
+    #[error("Invalid configuration for task '{task_id}': {message}")]
+    InvalidTaskConfiguration { task_id: String, message: String },
+
     #[error("Context value evaluation failed: {key}")]
     ContextEvaluationFailed { key: String },
 
@@ -371,6 +459,17 @@ impl From<ContextError> for TaskError {
             ContextError::Serialization(e) => cloacina_workflow::ContextError::Serialization(e),
             ContextError::KeyNotFound(k) => cloacina_workflow::ContextError::KeyNotFound(k),
             ContextError::TypeMismatch(k) => cloacina_workflow::ContextError::TypeMismatch(k),
+            ContextError::Deserialization {
+                key,
+                path,
+                type_name,
+                message,
+            } => cloacina_workflow::ContextError::Deserialization {
+                key,
+                path,
+                type_name,
+                message,
+            },
             ContextError::KeyExists(k) => cloacina_workflow::ContextError::KeyExists(k),
             ContextError::Database(e) => {
                 cloacina_workflow::ContextError::Database(format!("{}", e))
@@ -381,6 +480,18 @@ impl From<ContextError> for TaskError {
             ContextError::InvalidScope(msg) => {
                 cloacina_workflow::ContextError::Database(format!("Invalid scope: {}", msg))
             }
+            ContextError::Encryption(e) => {
+                cloacina_workflow::ContextError::Database(format!("Encryption: {}", e))
+            }
+            e @ ContextError::TooLarge { .. } => {
+                cloacina_workflow::ContextError::Database(e.to_string())
+            }
+            ContextError::Offload(e) => {
+                cloacina_workflow::ContextError::Database(format!("Offload: {}", e))
+            }
+            e @ (ContextError::Encoding { .. } | ContextError::UnknownFormat(_)) => {
+                cloacina_workflow::ContextError::Database(e.to_string())
+            }
         };
         TaskError::ContextError {
             task_id: "unknown".to_string(),
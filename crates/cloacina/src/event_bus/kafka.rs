@@ -0,0 +1,104 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Kafka transport for the event publisher (`event-bus-kafka` feature).
+//!
+//! Publishes through an rdkafka [`FutureProducer`]. The message key is the
+//! Kafka record key, so every event of one workflow execution lands on the
+//! same partition in order; the content type travels as the `content-type`
+//! header. A publish completes once the broker acknowledges the record
+//! (`acks=all` unless overridden).
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+
+use super::{transport_error, BusMessage, EventBusError, EventBusTransport};
+
+/// Builder for a [`KafkaTransport`].
+pub struct KafkaTransportBuilder {
+    config: ClientConfig,
+    timeout: Duration,
+}
+
+impl KafkaTransportBuilder {
+    /// Set a librdkafka producer property, e.g. `security.protocol` or
+    /// `compression.type`.
+    pub fn set(mut self, key: &str, value: &str) -> Self {
+        self.config.set(key, value);
+        self
+    }
+
+    /// How long a publish may wait for room in the producer queue
+    /// (default: 5 seconds).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Create the producer.
+    pub fn build(self) -> Result<KafkaTransport, EventBusError> {
+        let producer: FutureProducer = self
+            .config
+            .create()
+            .map_err(|e| transport_error("cannot create kafka producer", e))?;
+        Ok(KafkaTransport {
+            producer,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Publishes [`BusMessage`]s to a Kafka cluster.
+pub struct KafkaTransport {
+    producer: FutureProducer,
+    timeout: Duration,
+}
+
+impl KafkaTransport {
+    /// Start building a transport for the cluster at `brokers`
+    /// (comma-separated `host:port` list).
+    pub fn builder(brokers: &str) -> KafkaTransportBuilder {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", brokers).set("acks", "all");
+        KafkaTransportBuilder {
+            config,
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+#[async_trait]
+impl EventBusTransport for KafkaTransport {
+    async fn publish(&self, message: &BusMessage) -> Result<(), EventBusError> {
+        let headers = OwnedHeaders::new().insert(Header {
+            key: "content-type",
+            value: Some(message.content_type),
+        });
+        let record = FutureRecord::to(&message.topic)
+            .key(&message.key)
+            .payload(&message.payload)
+            .headers(headers);
+        self.producer
+            .send(record, self.timeout)
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| transport_error("kafka publish failed", e))
+    }
+}
@@ -0,0 +1,684 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Execution event publishing to external message buses.
+//!
+//! The [`EventPublisher`] tails the append-only `execution_events` table by its
+//! table-wide `sequence_num` and forwards each event that passes an
+//! [`EventFilter`] to an [`EventBusTransport`], serialized per
+//! [`EventSerialization`]. Downstream stream processors subscribe to the bus
+//! topic instead of polling the database themselves.
+//!
+//! ## Transports
+//!
+//! Each bundled transport wraps the same client `cloacina-mq-triggers`
+//! consumes with and sits behind its own feature, so a build only links the
+//! broker client it publishes to:
+//!
+//! | Feature | Transport | Client |
+//! |---|---|---|
+//! | `event-bus-kafka` | `KafkaTransport` | `rdkafka` |
+//! | `event-bus-nats` | `NatsTransport` | `async-nats` |
+//! | `event-bus-amqp` | `AmqpTransport` | `lapin` |
+//!
+//! For any other bus, implement [`EventBusTransport::publish`] over its client
+//! and hand it to [`EventPublisher::new`].
+//!
+//! ## Delivery semantics
+//!
+//! At-least-once for the lifetime of the publisher. A transport error stops
+//! the current batch; the failed event is retried on the next tick and nothing
+//! after it is published first. The cursor is held in memory, so a restarted
+//! publisher starts from [`StartPosition`].
+//!
+//! Events are published in sequence order with one exception. On PostgreSQL,
+//! concurrent transactions can commit out of sequence order, so an event can
+//! become visible after the publisher has read past its number. The publisher
+//! remembers every number it skipped and re-checks them each tick for
+//! [`EventPublisherConfig::gap_timeout`]; an event that shows up in that window
+//! is published late, after higher-numbered ones. Numbers that never show up
+//! (rolled-back transactions) are dropped once the timeout passes, so an event
+//! whose transaction stays open longer than that is missed.
+
+#[cfg(feature = "event-bus-amqp")]
+mod amqp;
+#[cfg(feature = "event-bus-kafka")]
+mod kafka;
+#[cfg(feature = "event-bus-nats")]
+mod nats;
+
+#[cfg(feature = "event-bus-amqp")]
+pub use amqp::AmqpTransport;
+#[cfg(feature = "event-bus-kafka")]
+pub use kafka::{KafkaTransport, KafkaTransportBuilder};
+#[cfg(feature = "event-bus-nats")]
+pub use nats::NatsTransport;
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::sync::watch;
+use tracing::{debug, warn};
+
+use crate::dal::DAL;
+use crate::error::ValidationError;
+use crate::models::execution_event::ExecutionEvent;
+
+/// Errors from publishing execution events.
+#[derive(Debug, thiserror::Error)]
+pub enum EventBusError {
+    /// The transport rejected or failed to send a message.
+    #[error("event bus transport failed: {0}")]
+    Transport(String),
+
+    /// An event could not be serialized.
+    #[error("event serialization failed: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// Reading events from the database failed.
+    #[error("event read failed: {0}")]
+    Database(#[from] ValidationError),
+}
+
+/// Wraps a broker client error with what the transport was doing.
+#[cfg(any(
+    feature = "event-bus-amqp",
+    feature = "event-bus-kafka",
+    feature = "event-bus-nats"
+))]
+fn transport_error(context: &str, e: impl std::fmt::Display) -> EventBusError {
+    EventBusError::Transport(format!("{}: {}", context, e))
+}
+
+/// One message handed to a transport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusMessage {
+    /// Destination topic / subject / routing key.
+    pub topic: String,
+    /// Partition / ordering key: the workflow execution id, so all events of
+    /// one run land in order on a partitioned bus.
+    pub key: String,
+    /// Serialized event body.
+    pub payload: Vec<u8>,
+    /// MIME type of `payload`.
+    pub content_type: &'static str,
+}
+
+/// A message-bus client the publisher forwards events to.
+#[async_trait]
+pub trait EventBusTransport: Send + Sync {
+    /// Send one message. Returning an error makes the publisher retry it.
+    async fn publish(&self, message: &BusMessage) -> Result<(), EventBusError>;
+}
+
+/// Wire format for published events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventSerialization {
+    /// Plain JSON object of the event fields.
+    #[default]
+    Json,
+    /// A CloudEvents 1.0 structured-mode JSON envelope with the event fields as
+    /// `data`.
+    CloudEvents,
+}
+
+/// Which events get published. Empty lists match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    /// Event types to publish (e.g. `"workflow_completed"`).
+    pub event_types: Vec<String>,
+    /// Tenants to publish for. Events without a tenant only match an empty list.
+    pub tenants: Vec<String>,
+    /// Publish only workflow-level events (no `task_execution_id`).
+    pub workflow_events_only: bool,
+}
+
+impl EventFilter {
+    /// Whether `event` passes the filter.
+    pub fn matches(&self, event: &ExecutionEvent) -> bool {
+        if self.workflow_events_only && event.task_execution_id.is_some() {
+            return false;
+        }
+        if !self.event_types.is_empty() && !self.event_types.contains(&event.event_type) {
+            return false;
+        }
+        if !self.tenants.is_empty() {
+            match &event.tenant_id {
+                Some(t) if self.tenants.contains(t) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Where a new publisher starts reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StartPosition {
+    /// Only events written after the publisher starts.
+    #[default]
+    Latest,
+    /// Every event still in the table.
+    Beginning,
+    /// Events with `sequence_num` greater than this value.
+    After(i64),
+}
+
+/// Tunables for the [`EventPublisher`].
+#[derive(Debug, Clone)]
+pub struct EventPublisherConfig {
+    /// Topic template. `{event_type}` and `{tenant}` are substituted per event
+    /// (`{tenant}` becomes `public` for events without one).
+    pub topic: String,
+    /// Wire format.
+    pub serialization: EventSerialization,
+    /// Which events to publish.
+    pub filter: EventFilter,
+    /// How often the publisher checks for new events.
+    pub poll_interval: Duration,
+    /// Maximum events read per tick.
+    pub batch_limit: i64,
+    /// Where to start reading.
+    pub start: StartPosition,
+    /// How long a skipped sequence number is re-checked before the publisher
+    /// gives up on it.
+    pub gap_timeout: Duration,
+    /// Maximum skipped sequence numbers tracked at once; numbers past the cap
+    /// are not re-checked.
+    pub max_tracked_gaps: usize,
+}
+
+impl Default for EventPublisherConfig {
+    fn default() -> Self {
+        Self {
+            topic: "cloacina.events".to_string(),
+            serialization: EventSerialization::default(),
+            filter: EventFilter::default(),
+            poll_interval: Duration::from_secs(1),
+            batch_limit: 256,
+            start: StartPosition::default(),
+            gap_timeout: Duration::from_secs(60),
+            max_tracked_gaps: 1000,
+        }
+    }
+}
+
+/// Forwards execution events to an [`EventBusTransport`].
+pub struct EventPublisher {
+    dal: DAL,
+    transport: Arc<dyn EventBusTransport>,
+    config: EventPublisherConfig,
+    cursor: Option<i64>,
+    /// Sequence numbers read past while not yet visible, with when they were
+    /// first noticed.
+    gaps: BTreeMap<i64, Instant>,
+}
+
+impl EventPublisher {
+    /// Construct with [`EventPublisherConfig::default`].
+    pub fn new(dal: DAL, transport: Arc<dyn EventBusTransport>) -> Self {
+        Self::with_config(dal, transport, EventPublisherConfig::default())
+    }
+
+    pub fn with_config(
+        dal: DAL,
+        transport: Arc<dyn EventBusTransport>,
+        config: EventPublisherConfig,
+    ) -> Self {
+        Self {
+            dal,
+            transport,
+            config,
+            cursor: None,
+            gaps: BTreeMap::new(),
+        }
+    }
+
+    /// The `sequence_num` of the last event handled (published or filtered
+    /// out), once the publisher has started.
+    pub fn cursor(&self) -> Option<i64> {
+        self.cursor
+    }
+
+    /// Sequence numbers the publisher read past and is still waiting for.
+    pub fn pending_gaps(&self) -> Vec<i64> {
+        self.gaps.keys().copied().collect()
+    }
+
+    /// Run until `shutdown` flips to `true` (or the sender drops).
+    pub async fn run(mut self, mut shutdown: watch::Receiver<bool>) {
+        let mut ticker = tokio::time::interval(self.config.poll_interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    match self.publish_once().await {
+                        Ok(0) => {}
+                        Ok(n) => debug!(published = n, "event bus: published batch"),
+                        Err(e) => warn!(error = %e, "event bus: publish failed; will retry"),
+                    }
+                }
+                res = shutdown.changed() => {
+                    if res.is_err() || *shutdown.borrow() {
+                        break;
+                    }
+                }
+            }
+        }
+        debug!("event bus publisher stopped");
+    }
+
+    /// Publish one batch of new events, after any skipped events that have
+    /// since become visible. Returns the number published (events dropped by
+    /// the filter are skipped but not counted).
+    pub async fn publish_once(&mut self) -> Result<usize, EventBusError> {
+        let cursor = match self.cursor {
+            Some(c) => c,
+            None => {
+                let start = match self.config.start {
+                    StartPosition::Latest => self.dal.execution_event().max_sequence().await?,
+                    StartPosition::Beginning => 0,
+                    StartPosition::After(seq) => seq,
+                };
+                self.cursor = Some(start);
+                start
+            }
+        };
+
+        let mut published = self.publish_gaps().await?;
+
+        let events = self
+            .dal
+            .execution_event()
+            .list_after_sequence(cursor, self.config.batch_limit)
+            .await?;
+
+        let mut last = cursor;
+        for event in events {
+            if event.sequence_num > last + 1 {
+                self.track_gaps(last + 1, event.sequence_num);
+            }
+            if self.config.filter.matches(&event) {
+                let message = self.to_message(&event)?;
+                self.transport.publish(&message).await?;
+                published += 1;
+            }
+            last = event.sequence_num;
+            self.cursor = Some(last);
+        }
+        Ok(published)
+    }
+
+    /// Publish tracked gaps that have become visible and drop those that have
+    /// waited longer than `gap_timeout`.
+    async fn publish_gaps(&mut self) -> Result<usize, EventBusError> {
+        let timeout = self.config.gap_timeout;
+        self.gaps.retain(|seq, first_seen| {
+            let waiting = first_seen.elapsed() < timeout;
+            if !waiting {
+                debug!(sequence_num = *seq, "event bus: sequence gap never filled");
+            }
+            waiting
+        });
+        if self.gaps.is_empty() {
+            return Ok(0);
+        }
+
+        let events = self
+            .dal
+            .execution_event()
+            .list_by_sequences(self.pending_gaps())
+            .await?;
+
+        let mut published = 0usize;
+        for event in events {
+            if self.config.filter.matches(&event) {
+                let message = self.to_message(&event)?;
+                self.transport.publish(&message).await?;
+                published += 1;
+            }
+            self.gaps.remove(&event.sequence_num);
+        }
+        Ok(published)
+    }
+
+    /// Remember the sequence numbers in `from..to` as not yet visible.
+    fn track_gaps(&mut self, from: i64, to: i64) {
+        let now = Instant::now();
+        for seq in from..to {
+            if self.gaps.len() >= self.config.max_tracked_gaps && !self.gaps.contains_key(&seq) {
+                warn!(
+                    from = seq,
+                    to, "event bus: too many sequence gaps tracked; not waiting for the rest"
+                );
+                break;
+            }
+            self.gaps.entry(seq).or_insert(now);
+        }
+    }
+
+    fn to_message(&self, event: &ExecutionEvent) -> Result<BusMessage, EventBusError> {
+        let topic = self
+            .config
+            .topic
+            .replace("{event_type}", &event.event_type)
+            .replace("{tenant}", event.tenant_id.as_deref().unwrap_or("public"));
+        let (payload, content_type) = serialize_event(event, self.config.serialization)?;
+        Ok(BusMessage {
+            topic,
+            key: event.workflow_execution_id.0.to_string(),
+            payload,
+            content_type,
+        })
+    }
+}
+
+/// Serialize `event` in `format`, returning the bytes and their MIME type.
+pub fn serialize_event(
+    event: &ExecutionEvent,
+    format: EventSerialization,
+) -> Result<(Vec<u8>, &'static str), EventBusError> {
+    // event_data is stored as a JSON string; inline it when it parses.
+    let data = event.event_data.as_deref().map(|raw| {
+        serde_json::from_str::<serde_json::Value>(raw)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()))
+    });
+    let body = serde_json::json!({
+        "id": event.id.0.to_string(),
+        "sequence_num": event.sequence_num,
+        "workflow_execution_id": event.workflow_execution_id.0.to_string(),
+        "task_execution_id": event.task_execution_id.map(|u| u.0.to_string()),
+        "event_type": event.event_type,
+        "event_data": data,
+        "tenant_id": event.tenant_id,
+        "created_at": event.created_at.0.to_rfc3339(),
+    });
+    match format {
+        EventSerialization::Json => Ok((serde_json::to_vec(&body)?, "application/json")),
+        EventSerialization::CloudEvents => {
+            let envelope = serde_json::json!({
+                "specversion": "1.0",
+                "id": event.id.0.to_string(),
+                "source": "cloacina",
+                "type": format!("io.cloacina.{}", event.event_type),
+                "subject": event.workflow_execution_id.0.to_string(),
+                "time": event.created_at.0.to_rfc3339(),
+                "datacontenttype": "application/json",
+                "data": body,
+            });
+            Ok((
+                serde_json::to_vec(&envelope)?,
+                "application/cloudevents+json",
+            ))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use crate::models::execution_event::NewExecutionEvent;
+    use crate::models::task_execution::NewTaskExecution;
+    use crate::models::workflow_execution::NewWorkflowExecution;
+    use crate::UniversalUuid;
+    use std::sync::Mutex;
+
+    async fn unique_dal() -> DAL {
+        let url = format!(
+            "file:event_bus_test_{}?mode=memory&cache=shared",
+            uuid::Uuid::new_v4()
+        );
+        let db = Database::new(&url, "", 5);
+        db.run_migrations()
+            .await
+            .expect("migrations should succeed");
+        DAL::new(db)
+    }
+
+    #[derive(Default)]
+    struct CollectingTransport {
+        messages: Mutex<Vec<BusMessage>>,
+    }
+
+    #[async_trait]
+    impl EventBusTransport for CollectingTransport {
+        async fn publish(&self, message: &BusMessage) -> Result<(), EventBusError> {
+            self.messages.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    async fn emit(dal: &DAL, wf: UniversalUuid, event_type: &str, task: Option<UniversalUuid>) {
+        dal.execution_event()
+            .create(NewExecutionEvent {
+                workflow_execution_id: wf,
+                task_execution_id: task,
+                event_type: event_type.into(),
+                event_data: Some(r#"{"k":1}"#.into()),
+                worker_id: None,
+                request_id: None,
+                runner_id: None,
+                tenant_id: Some("acme".into()),
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn make_task_exec(dal: &DAL, wf: UniversalUuid) -> UniversalUuid {
+        dal.task_execution()
+            .create(NewTaskExecution {
+                workflow_execution_id: wf,
+                task_name: "alpha".into(),
+                status: "Running".into(),
+                attempt: 1,
+                max_attempts: 1,
+                trigger_rules: r#"{"type":"Always"}"#.into(),
+                task_configuration: "{}".into(),
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    /// Creating the workflow execution itself records an untenanted
+    /// `workflow_started` event.
+    async fn make_wf_exec(dal: &DAL) -> UniversalUuid {
+        dal.workflow_execution()
+            .create(NewWorkflowExecution {
+                workflow_name: "event-bus-test".into(),
+                workflow_version: "1.0".into(),
+                status: "Running".into(),
+                context_id: None,
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn publishes_filtered_events_in_order() {
+        let dal = unique_dal().await;
+        let wf = make_wf_exec(&dal).await;
+        let task = make_task_exec(&dal, wf).await;
+        emit(&dal, wf, "workflow_paused", None).await;
+        emit(&dal, wf, "task_started", Some(task)).await;
+        emit(&dal, wf, "workflow_resumed", None).await;
+
+        let transport = Arc::new(CollectingTransport::default());
+        let mut publisher = EventPublisher::with_config(
+            dal.clone(),
+            transport.clone(),
+            EventPublisherConfig {
+                topic: "cloacina.{tenant}.{event_type}".into(),
+                filter: EventFilter {
+                    tenants: vec!["acme".into()],
+                    workflow_events_only: true,
+                    ..Default::default()
+                },
+                start: StartPosition::Beginning,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(publisher.publish_once().await.unwrap(), 2);
+        let messages = transport.messages.lock().unwrap().clone();
+        let topics: Vec<&str> = messages.iter().map(|m| m.topic.as_str()).collect();
+        assert_eq!(
+            topics,
+            vec![
+                "cloacina.acme.workflow_paused",
+                "cloacina.acme.workflow_resumed"
+            ]
+        );
+        assert_eq!(messages[0].key, wf.0.to_string());
+
+        // Cursor moved past the filtered-out events; nothing is re-sent.
+        assert_eq!(publisher.publish_once().await.unwrap(), 0);
+    }
+
+    /// Move an event's sequence number, standing in for a transaction that
+    /// commits after a higher-numbered one.
+    async fn renumber(dal: &DAL, from: i64, to: i64) {
+        use diesel::prelude::*;
+        let conn = dal.database.get_sqlite_connection().await.unwrap();
+        conn.interact(move |conn| {
+            diesel::sql_query(format!(
+                "UPDATE execution_events SET sequence_num = {to} WHERE sequence_num = {from}"
+            ))
+            .execute(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn late_commits_fill_gaps() {
+        let dal = unique_dal().await;
+        let wf = make_wf_exec(&dal).await;
+        emit(&dal, wf, "workflow_paused", None).await;
+        emit(&dal, wf, "workflow_resumed", None).await;
+        let seqs: Vec<i64> = dal
+            .execution_event()
+            .list_after_sequence(0, 10)
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.sequence_num)
+            .collect();
+        let (paused, resumed) = (seqs[1], seqs[2]);
+
+        // `workflow_paused` is not visible yet when the publisher reads.
+        renumber(&dal, paused, -paused).await;
+
+        let transport = Arc::new(CollectingTransport::default());
+        let mut publisher = EventPublisher::with_config(
+            dal.clone(),
+            transport.clone(),
+            EventPublisherConfig {
+                topic: "{event_type}".into(),
+                start: StartPosition::Beginning,
+                ..Default::default()
+            },
+        );
+        assert_eq!(publisher.publish_once().await.unwrap(), 2);
+        assert_eq!(publisher.cursor(), Some(resumed));
+        assert_eq!(publisher.pending_gaps(), vec![paused]);
+
+        // It commits; the next tick publishes it even though the cursor is past.
+        renumber(&dal, -paused, paused).await;
+        assert_eq!(publisher.publish_once().await.unwrap(), 1);
+        assert!(publisher.pending_gaps().is_empty());
+        let topics: Vec<String> = transport
+            .messages
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|m| m.topic.clone())
+            .collect();
+        assert_eq!(
+            topics,
+            vec!["workflow_started", "workflow_resumed", "workflow_paused"]
+        );
+    }
+
+    #[tokio::test]
+    async fn unfilled_gaps_expire() {
+        let dal = unique_dal().await;
+        let wf = make_wf_exec(&dal).await;
+        emit(&dal, wf, "workflow_paused", None).await;
+        emit(&dal, wf, "workflow_resumed", None).await;
+        let paused = dal
+            .execution_event()
+            .list_after_sequence(0, 10)
+            .await
+            .unwrap()[1]
+            .sequence_num;
+        renumber(&dal, paused, -paused).await;
+
+        let transport = Arc::new(CollectingTransport::default());
+        let mut publisher = EventPublisher::with_config(
+            dal.clone(),
+            transport.clone(),
+            EventPublisherConfig {
+                start: StartPosition::Beginning,
+                gap_timeout: Duration::ZERO,
+                ..Default::default()
+            },
+        );
+        assert_eq!(publisher.publish_once().await.unwrap(), 2);
+        assert_eq!(publisher.pending_gaps(), vec![paused]);
+        assert_eq!(publisher.publish_once().await.unwrap(), 0);
+        assert!(publisher.pending_gaps().is_empty());
+    }
+
+    #[tokio::test]
+    async fn latest_start_skips_existing_events() {
+        let dal = unique_dal().await;
+        let wf = make_wf_exec(&dal).await;
+
+        let transport = Arc::new(CollectingTransport::default());
+        let mut publisher = EventPublisher::new(dal.clone(), transport.clone());
+        assert_eq!(publisher.publish_once().await.unwrap(), 0);
+
+        emit(&dal, wf, "workflow_completed", None).await;
+        assert_eq!(publisher.publish_once().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn cloud_events_envelope() {
+        let dal = unique_dal().await;
+        let wf = make_wf_exec(&dal).await;
+        emit(&dal, wf, "workflow_completed", None).await;
+        let event = dal
+            .execution_event()
+            .list_after_sequence(0, 10)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let (bytes, content_type) =
+            serialize_event(&event, EventSerialization::CloudEvents).unwrap();
+        assert_eq!(content_type, "application/cloudevents+json");
+        let v: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(v["specversion"], "1.0");
+        assert_eq!(v["type"], "io.cloacina.workflow_completed");
+        assert_eq!(v["data"]["event_data"]["k"], 1);
+    }
+}
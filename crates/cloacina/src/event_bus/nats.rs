@@ -0,0 +1,70 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! NATS transport for the event publisher (`event-bus-nats` feature).
+//!
+//! Publishes over an [`async_nats::Client`]. The message topic is the subject;
+//! the partition key and content type travel as the `Cloacina-Key` and
+//! `Content-Type` headers. Each publish is followed by a flush, so a message
+//! counts as sent once the client has written it to the server. This is core
+//! NATS, not JetStream: there is no server-side acknowledgement.
+
+use async_nats::HeaderMap;
+use async_trait::async_trait;
+
+use super::{transport_error, BusMessage, EventBusError, EventBusTransport};
+
+/// Publishes [`BusMessage`]s to a NATS server.
+pub struct NatsTransport {
+    client: async_nats::Client,
+}
+
+impl NatsTransport {
+    /// Connect to the server at `url`, e.g. `nats://localhost:4222`.
+    pub async fn connect(url: &str) -> Result<Self, EventBusError> {
+        let client = async_nats::connect(url)
+            .await
+            .map_err(|e| transport_error("cannot connect to nats", e))?;
+        Ok(Self::from_client(client))
+    }
+
+    /// Publish through an already connected client, e.g. one built with
+    /// [`async_nats::ConnectOptions`] for TLS or credentials.
+    pub fn from_client(client: async_nats::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl EventBusTransport for NatsTransport {
+    async fn publish(&self, message: &BusMessage) -> Result<(), EventBusError> {
+        let mut headers = HeaderMap::new();
+        headers.insert("Cloacina-Key", message.key.as_str());
+        headers.insert("Content-Type", message.content_type);
+        self.client
+            .publish_with_headers(
+                message.topic.clone(),
+                headers,
+                message.payload.clone().into(),
+            )
+            .await
+            .map_err(|e| transport_error("nats publish failed", e))?;
+        self.client
+            .flush()
+            .await
+            .map_err(|e| transport_error("nats flush failed", e))
+    }
+}
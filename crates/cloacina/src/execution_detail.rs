@@ -0,0 +1,534 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Full detail of one workflow execution.
+//!
+//! An [`ExecutionDetail`] joins the execution row, every task row, the
+//! execution's event log and its context references into one document: the
+//! task tree with per-attempt timings and errors, retry schedules, trigger
+//! rule outcomes and the ids of the contexts each task read and wrote. UIs
+//! and `cloacinactl execution show` read this instead of stitching the
+//! tables together themselves.
+//!
+//! The execution row, task rows and output context ids come from one
+//! [`get_snapshot`](crate::dal::unified::WorkflowExecutionDAL::get_snapshot) read, so
+//! the execution's status always agrees with its tasks'.
+//!
+//! Attempts are rebuilt from the event log: a `task_claimed` event opens an
+//! attempt and `task_completed`, `task_failed`, `task_abandoned` or
+//! `task_reset` closes it; `task_resolved` marks the failed last attempt as
+//! resolved by an operator; each `task_iterated` event of a looping task
+//! counts one iteration of the open attempt. Executions whose events were purged by retention
+//! keep their task rows but report no attempts.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::dal::DAL;
+use crate::error::{TaskErrorPayload, ValidationError};
+use crate::models::execution_event::{ExecutionEvent, ExecutionEventType};
+use crate::models::task_execution::TaskExecution;
+use crate::models::workflow_execution::ExecutionLabels;
+use crate::UniversalUuid;
+
+/// How a task attempt ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptOutcome {
+    /// Still running (or the runner died without recording an outcome)
+    Running,
+    Completed,
+    Failed,
+    Abandoned,
+    /// Reset by recovery after its runner went away
+    Reset,
+    /// Failed, then marked successful by an operator
+    Resolved,
+}
+
+impl AttemptOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttemptOutcome::Running => "running",
+            AttemptOutcome::Completed => "completed",
+            AttemptOutcome::Failed => "failed",
+            AttemptOutcome::Abandoned => "abandoned",
+            AttemptOutcome::Reset => "reset",
+            AttemptOutcome::Resolved => "resolved",
+        }
+    }
+}
+
+/// One claimed run of a task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskAttempt {
+    /// 1-based attempt number
+    pub attempt: i32,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+    pub outcome: AttemptOutcome,
+    /// Runner or worker that claimed the attempt
+    pub worker_id: Option<String>,
+    pub error: Option<String>,
+    /// When the retry that follows this attempt was scheduled to run
+    pub retry_at: Option<DateTime<Utc>>,
+    /// Loop iterations the attempt ran; `0` for tasks without `loop_until`
+    #[serde(default)]
+    pub iterations: u32,
+}
+
+/// Result of evaluating a task's trigger rules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriggerEvaluation {
+    /// `true` when the task was marked ready, `false` when it was skipped
+    pub satisfied: bool,
+    pub evaluated_at: DateTime<Utc>,
+    /// Skip reason, when not satisfied
+    pub reason: Option<String>,
+}
+
+/// One task of an execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDetail {
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub status: String,
+    pub sub_status: Option<String>,
+    /// Upstream task names. Only filled when the workflow definition is
+    /// known, e.g. by [`DefaultRunner::get_execution`](crate::DefaultRunner::get_execution).
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub attempt: i32,
+    pub max_attempts: i32,
+    pub retry_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub error_payload: Option<TaskErrorPayload>,
+    /// The task's trigger rules as stored
+    pub trigger_rules: Value,
+    pub trigger_evaluation: Option<TriggerEvaluation>,
+    pub attempts: Vec<TaskAttempt>,
+    /// Operator's note when the task failed and was manually resolved
+    pub resolution_note: Option<String>,
+    /// Context the task saved as its output
+    pub output_context_id: Option<UniversalUuid>,
+}
+
+/// Full detail of one workflow execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionDetail {
+    pub execution_id: UniversalUuid,
+    pub workflow_name: String,
+    pub workflow_version: String,
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+    pub error_details: Option<String>,
+    pub trigger_origin: Option<String>,
+    pub run_id: Option<String>,
+    /// Logical date the run processes
+    pub logical_date: Option<DateTime<Utc>>,
+    pub paused_at: Option<DateTime<Utc>>,
+    pub pause_reason: Option<String>,
+    pub recovery_attempts: i32,
+    /// Context the execution started with (and holds its final state)
+    pub context_id: Option<UniversalUuid>,
+    pub labels: ExecutionLabels,
+    /// Tasks in start order; tasks that never started sort last
+    pub tasks: Vec<TaskDetail>,
+}
+
+impl ExecutionDetail {
+    /// Read the detail of `execution_id` from the database.
+    pub async fn load(dal: &DAL, execution_id: UniversalUuid) -> Result<Self, ValidationError> {
+        let snapshot = dal.workflow_execution().get_snapshot(execution_id).await?;
+        let execution = snapshot.execution;
+        let labels = dal.workflow_execution().get_labels(execution_id).await?;
+        let mut tasks = snapshot.tasks;
+        tasks.sort_by_key(|t| {
+            (
+                t.started_at.is_none(),
+                t.started_at.map(|ts| ts.0),
+                t.created_at.0,
+            )
+        });
+        let events = dal.execution_event().list_by_workflow(execution_id).await?;
+        let mut events_by_task: HashMap<UniversalUuid, Vec<ExecutionEvent>> = HashMap::new();
+        for event in events {
+            if let Some(task_id) = event.task_execution_id {
+                events_by_task.entry(task_id).or_default().push(event);
+            }
+        }
+
+        let mut details = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let output_context_id = snapshot.output_context_ids.get(&task.id).copied();
+            let events = events_by_task.remove(&task.id).unwrap_or_default();
+            details.push(task_detail(task, &events, output_context_id));
+        }
+
+        Ok(Self {
+            execution_id: execution.id,
+            workflow_name: execution.workflow_name,
+            workflow_version: execution.workflow_version,
+            status: execution.status,
+            started_at: execution.started_at.0,
+            completed_at: execution.completed_at.map(|t| t.0),
+            duration_ms: execution
+                .completed_at
+                .map(|end| (end.0 - execution.started_at.0).num_milliseconds()),
+            error_details: execution.error_details,
+            trigger_origin: execution.trigger_origin,
+            run_id: execution.run_id,
+            logical_date: execution.logical_date.map(|t| t.0),
+            paused_at: execution.paused_at.map(|t| t.0),
+            pause_reason: execution.pause_reason,
+            recovery_attempts: execution.recovery_attempts,
+            context_id: execution.context_id,
+            labels,
+            tasks: details,
+        })
+    }
+
+    /// The task named `task_name`, if the execution has one.
+    pub fn task(&self, task_name: &str) -> Option<&TaskDetail> {
+        self.tasks.iter().find(|t| t.task_name == task_name)
+    }
+}
+
+/// Reads a string field from an event's JSON payload.
+fn event_field(event: &ExecutionEvent, key: &str) -> Option<String> {
+    let data: Value = serde_json::from_str(event.event_data.as_deref()?).ok()?;
+    data.get(key)?.as_str().map(str::to_string)
+}
+
+/// Closes the open attempt, if any, with `outcome` at the event's time.
+fn close_attempt(
+    attempts: &mut [TaskAttempt],
+    event: &ExecutionEvent,
+    outcome: AttemptOutcome,
+    error: Option<String>,
+) -> bool {
+    match attempts.last_mut() {
+        Some(open) if open.outcome == AttemptOutcome::Running => {
+            let ended_at = event.created_at.0;
+            open.ended_at = Some(ended_at);
+            open.duration_ms = Some((ended_at - open.started_at).num_milliseconds());
+            open.outcome = outcome;
+            open.error = error;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Builds a task's detail from its row and its events in sequence order.
+fn task_detail(
+    task: TaskExecution,
+    events: &[ExecutionEvent],
+    output_context_id: Option<UniversalUuid>,
+) -> TaskDetail {
+    let mut attempts: Vec<TaskAttempt> = Vec::new();
+    let mut trigger_evaluation = None;
+    let mut resolution_note = None;
+
+    for event in events {
+        let Some(event_type) = ExecutionEventType::from_str(&event.event_type) else {
+            continue;
+        };
+        match event_type {
+            ExecutionEventType::TaskClaimed => attempts.push(TaskAttempt {
+                attempt: attempts.len() as i32 + 1,
+                started_at: event.created_at.0,
+                ended_at: None,
+                duration_ms: None,
+                outcome: AttemptOutcome::Running,
+                worker_id: event
+                    .worker_id
+                    .clone()
+                    .or_else(|| event.runner_id.map(|id| id.to_string())),
+                error: None,
+                retry_at: None,
+                iterations: 0,
+            }),
+            ExecutionEventType::TaskCompleted => {
+                close_attempt(&mut attempts, event, AttemptOutcome::Completed, None);
+            }
+            ExecutionEventType::TaskFailed => {
+                let error = event_field(event, "error");
+                close_attempt(&mut attempts, event, AttemptOutcome::Failed, error);
+            }
+            ExecutionEventType::TaskAbandoned => {
+                let reason = event_field(event, "reason");
+                if !close_attempt(
+                    &mut attempts,
+                    event,
+                    AttemptOutcome::Abandoned,
+                    reason.clone(),
+                ) {
+                    // Abandoned after its last attempt already failed.
+                    if let Some(last) = attempts.last_mut() {
+                        last.outcome = AttemptOutcome::Abandoned;
+                        last.error = last.error.take().or(reason);
+                    }
+                }
+            }
+            ExecutionEventType::TaskReset => {
+                close_attempt(&mut attempts, event, AttemptOutcome::Reset, None);
+            }
+            ExecutionEventType::TaskResolved => {
+                if let Some(last) = attempts.last_mut().filter(|a| {
+                    matches!(
+                        a.outcome,
+                        AttemptOutcome::Failed | AttemptOutcome::Abandoned
+                    )
+                }) {
+                    last.outcome = AttemptOutcome::Resolved;
+                }
+                resolution_note = event_field(event, "note");
+            }
+            ExecutionEventType::TaskRetryScheduled => {
+                if let Some(last) = attempts.last_mut() {
+                    last.retry_at = event_field(event, "retry_at")
+                        .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+                        .map(|at| at.with_timezone(&Utc));
+                }
+            }
+            ExecutionEventType::TaskIterated => {
+                if let Some(open) = attempts
+                    .last_mut()
+                    .filter(|a| a.outcome == AttemptOutcome::Running)
+                {
+                    open.iterations += 1;
+                }
+            }
+            ExecutionEventType::TaskMarkedReady => {
+                trigger_evaluation = Some(TriggerEvaluation {
+                    satisfied: true,
+                    evaluated_at: event.created_at.0,
+                    reason: None,
+                });
+            }
+            ExecutionEventType::TaskSkipped => {
+                trigger_evaluation = Some(TriggerEvaluation {
+                    satisfied: false,
+                    evaluated_at: event.created_at.0,
+                    reason: event_field(event, "reason"),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    TaskDetail {
+        task_execution_id: task.id,
+        task_name: task.task_name,
+        status: task.status,
+        sub_status: task.sub_status,
+        dependencies: Vec::new(),
+        created_at: task.created_at.0,
+        started_at: task.started_at.map(|t| t.0),
+        completed_at: task.completed_at.map(|t| t.0),
+        attempt: task.attempt,
+        max_attempts: task.max_attempts,
+        retry_at: task.retry_at.map(|t| t.0),
+        last_error: task.last_error.or(task.error_details),
+        error_payload: task.error_payload,
+        trigger_rules: serde_json::from_str(&task.trigger_rules)
+            .unwrap_or(Value::String(task.trigger_rules)),
+        trigger_evaluation,
+        attempts,
+        resolution_note,
+        output_context_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UniversalTimestamp;
+
+    fn task() -> TaskExecution {
+        let now = UniversalTimestamp::now();
+        TaskExecution {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_name: "etl::load".to_string(),
+            status: "Completed".to_string(),
+            started_at: Some(now),
+            completed_at: Some(now),
+            attempt: 2,
+            max_attempts: 3,
+            error_details: None,
+            trigger_rules: r#"{"type":"Always"}"#.to_string(),
+            task_configuration: "{}".to_string(),
+            retry_at: None,
+            last_error: None,
+            recovery_attempts: 0,
+            last_recovery_at: None,
+            sub_status: None,
+            claimed_by: None,
+            heartbeat_at: None,
+            created_at: now,
+            updated_at: now,
+            error_payload: None,
+        }
+    }
+
+    fn event(event_type: ExecutionEventType, secs: i64, data: Option<&str>) -> ExecutionEvent {
+        let base = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        ExecutionEvent {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_execution_id: None,
+            event_type: event_type.as_str().to_string(),
+            event_data: data.map(str::to_string),
+            worker_id: Some("worker-1".to_string()),
+            created_at: UniversalTimestamp(base + chrono::Duration::seconds(secs)),
+            sequence_num: secs,
+            request_id: None,
+            runner_id: None,
+            tenant_id: None,
+        }
+    }
+
+    #[test]
+    fn test_attempts_rebuilt_from_events() {
+        let events = vec![
+            event(ExecutionEventType::TaskMarkedReady, 0, None),
+            event(ExecutionEventType::TaskClaimed, 1, None),
+            event(
+                ExecutionEventType::TaskFailed,
+                4,
+                Some(r#"{"error":"connection refused"}"#),
+            ),
+            event(
+                ExecutionEventType::TaskRetryScheduled,
+                4,
+                Some(r#"{"attempt":2,"retry_at":"2026-01-01T00:00:10+00:00"}"#),
+            ),
+            event(ExecutionEventType::TaskClaimed, 10, None),
+            event(ExecutionEventType::TaskCompleted, 12, None),
+        ];
+
+        let detail = task_detail(task(), &events, None);
+        assert_eq!(detail.attempts.len(), 2);
+
+        let first = &detail.attempts[0];
+        assert_eq!(first.outcome, AttemptOutcome::Failed);
+        assert_eq!(first.duration_ms, Some(3000));
+        assert_eq!(first.error.as_deref(), Some("connection refused"));
+        assert_eq!(first.worker_id.as_deref(), Some("worker-1"));
+        assert!(first.retry_at.is_some());
+
+        let second = &detail.attempts[1];
+        assert_eq!(second.attempt, 2);
+        assert_eq!(second.outcome, AttemptOutcome::Completed);
+        assert_eq!(second.duration_ms, Some(2000));
+        assert_eq!(second.iterations, 0);
+
+        assert!(detail.trigger_evaluation.unwrap().satisfied);
+        assert_eq!(detail.trigger_rules["type"], "Always");
+    }
+
+    #[test]
+    fn test_loop_iterations_counted_per_attempt() {
+        let events = vec![
+            event(ExecutionEventType::TaskClaimed, 0, None),
+            event(
+                ExecutionEventType::TaskIterated,
+                1,
+                Some(r#"{"iteration":1,"condition_met":false}"#),
+            ),
+            event(
+                ExecutionEventType::TaskIterated,
+                2,
+                Some(r#"{"iteration":2,"condition_met":true}"#),
+            ),
+            event(ExecutionEventType::TaskCompleted, 2, None),
+        ];
+
+        let detail = task_detail(task(), &events, None);
+        assert_eq!(detail.attempts.len(), 1);
+        assert_eq!(detail.attempts[0].iterations, 2);
+    }
+
+    #[test]
+    fn test_skip_and_abandon_are_reported() {
+        let skipped = vec![event(
+            ExecutionEventType::TaskSkipped,
+            0,
+            Some(r#"{"reason":"upstream failed"}"#),
+        )];
+        let detail = task_detail(task(), &skipped, None);
+        let evaluation = detail.trigger_evaluation.unwrap();
+        assert!(!evaluation.satisfied);
+        assert_eq!(evaluation.reason.as_deref(), Some("upstream failed"));
+        assert!(detail.attempts.is_empty());
+
+        let abandoned = vec![
+            event(ExecutionEventType::TaskClaimed, 0, None),
+            event(
+                ExecutionEventType::TaskFailed,
+                1,
+                Some(r#"{"error":"boom"}"#),
+            ),
+            event(
+                ExecutionEventType::TaskAbandoned,
+                1,
+                Some(r#"{"reason":"max retries exceeded"}"#),
+            ),
+        ];
+        let detail = task_detail(task(), &abandoned, None);
+        assert_eq!(detail.attempts.len(), 1);
+        assert_eq!(detail.attempts[0].outcome, AttemptOutcome::Abandoned);
+        assert_eq!(detail.attempts[0].error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn test_resolved_failure_is_reported() {
+        let resolved = vec![
+            event(ExecutionEventType::TaskClaimed, 0, None),
+            event(
+                ExecutionEventType::TaskFailed,
+                1,
+                Some(r#"{"error":"timeout"}"#),
+            ),
+            event(
+                ExecutionEventType::TaskResolved,
+                60,
+                Some(r#"{"note":"upload finished by hand"}"#),
+            ),
+        ];
+        let detail = task_detail(task(), &resolved, None);
+        assert_eq!(detail.attempts.len(), 1);
+        assert_eq!(detail.attempts[0].outcome, AttemptOutcome::Resolved);
+        assert_eq!(detail.attempts[0].error.as_deref(), Some("timeout"));
+        assert_eq!(
+            detail.resolution_note.as_deref(),
+            Some("upload finished by hand")
+        );
+    }
+}
@@ -67,8 +67,9 @@ impl<'a> ContextManager<'a> {
         let task_namespace = crate::task::TaskNamespace::from_string(&task_execution.task_name)
             .map_err(ValidationError::InvalidTaskName)?;
 
+        // Soft (ordering-only) dependencies contribute no context.
         let dependencies = workflow
-            .get_dependencies(&task_namespace)
+            .get_data_dependencies(&task_namespace)
             .map_err(|e| ValidationError::InvalidTaskName(e.to_string()))?;
 
         if dependencies.is_empty() {
@@ -138,7 +139,7 @@ impl<'a> ContextManager<'a> {
             }
         } else {
             // Multiple dependencies: merge their saved contexts
-            self.merge_dependency_contexts(task_execution, dependencies)
+            self.merge_dependency_contexts(task_execution, &dependencies)
                 .await
         }
     }
@@ -169,21 +170,32 @@ impl<'a> ContextManager<'a> {
                     {
                         sources.push(format!("{}({})", dep_task_name, dep_context.data().len()));
                         // Merge dependency context (later dependencies override earlier ones)
-                        for (key, value) in dep_context.data() {
-                            if merged_context.get(key).is_some() {
-                                merged_context
-                                    .update(key.clone(), value.clone())
-                                    .map_err(|e| ValidationError::ContextEvaluationFailed {
+                        let (data, bytes) = dep_context.into_parts();
+                        for (key, value) in data {
+                            if merged_context.get(&key).is_some()
+                                || merged_context.binary().contains_key(&key)
+                            {
+                                merged_context.update(key, value).map_err(|e| {
+                                    ValidationError::ContextEvaluationFailed {
                                         key: format!("merge_error:{}", e),
-                                    })?;
+                                    }
+                                })?;
                             } else {
-                                merged_context
-                                    .insert(key.clone(), value.clone())
-                                    .map_err(|e| ValidationError::ContextEvaluationFailed {
+                                merged_context.insert(key, value).map_err(|e| {
+                                    ValidationError::ContextEvaluationFailed {
                                         key: format!("merge_error:{}", e),
-                                    })?;
+                                    }
+                                })?;
                             }
                         }
+                        for (key, raw) in bytes {
+                            merged_context.remove(&key);
+                            merged_context.insert_bytes(key, raw).map_err(|e| {
+                                ValidationError::ContextEvaluationFailed {
+                                    key: format!("merge_error:{}", e),
+                                }
+                            })?;
+                        }
                     }
                 }
             }
@@ -0,0 +1,144 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Scheduler loop latency tracking.
+//!
+//! The scheduler loop records how long each tick takes; the most recent
+//! ticks are kept in memory so [`SchedulerLoopStats`] can report recent
+//! average, p95 and max tick durations next to the poll interval. A loop
+//! whose ticks take longer than its poll interval is falling behind: ready
+//! tasks wait at least one full tick before they are dispatched.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Ticks kept for the recent-duration statistics.
+const RECENT_TICKS: usize = 256;
+
+/// Scheduler loop latency as seen by this process.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchedulerLoopStats {
+    /// Configured time between ticks
+    pub poll_interval: Duration,
+    /// Ticks since the loop started
+    pub ticks: u64,
+    /// Of those, ticks that ended in an error
+    pub failed_ticks: u64,
+    /// When the last tick finished; `None` before the first tick
+    pub last_tick_at: Option<DateTime<Utc>>,
+    pub last_duration: Option<Duration>,
+    /// Over the most recent ticks (at most 256)
+    pub avg_duration: Option<Duration>,
+    pub p95_duration: Option<Duration>,
+    pub max_duration: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    ticks: u64,
+    failed_ticks: u64,
+    last_tick_at: Option<DateTime<Utc>>,
+    recent: VecDeque<Duration>,
+}
+
+/// Shared between a scheduler and the loop it runs.
+#[derive(Debug, Default)]
+pub(crate) struct LoopStatsRecorder {
+    inner: Mutex<Recorded>,
+}
+
+impl LoopStatsRecorder {
+    /// Records one tick that took `duration`.
+    pub(crate) fn record(&self, duration: Duration, ok: bool) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.ticks += 1;
+        if !ok {
+            inner.failed_ticks += 1;
+        }
+        inner.last_tick_at = Some(Utc::now());
+        if inner.recent.len() == RECENT_TICKS {
+            inner.recent.pop_front();
+        }
+        inner.recent.push_back(duration);
+    }
+
+    pub(crate) fn snapshot(&self, poll_interval: Duration) -> SchedulerLoopStats {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let mut sorted: Vec<Duration> = inner.recent.iter().copied().collect();
+        sorted.sort();
+        let avg_duration =
+            (!sorted.is_empty()).then(|| sorted.iter().sum::<Duration>() / sorted.len() as u32);
+        let p95_duration = (!sorted.is_empty()).then(|| {
+            let rank = (sorted.len() * 95).div_ceil(100);
+            sorted[rank.saturating_sub(1)]
+        });
+        SchedulerLoopStats {
+            poll_interval,
+            ticks: inner.ticks,
+            failed_ticks: inner.failed_ticks,
+            last_tick_at: inner.last_tick_at,
+            last_duration: inner.recent.back().copied(),
+            avg_duration,
+            p95_duration,
+            max_duration: sorted.last().copied(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_before_first_tick() {
+        let stats = LoopStatsRecorder::default().snapshot(Duration::from_millis(100));
+        assert_eq!(stats.ticks, 0);
+        assert_eq!(stats.poll_interval, Duration::from_millis(100));
+        assert!(stats.last_tick_at.is_none());
+        assert!(stats.avg_duration.is_none());
+    }
+
+    #[test]
+    fn test_snapshot_summarizes_recent_ticks() {
+        let recorder = LoopStatsRecorder::default();
+        for ms in 1..=100 {
+            recorder.record(Duration::from_millis(ms), ms % 10 != 0);
+        }
+        let stats = recorder.snapshot(Duration::from_millis(100));
+        assert_eq!(stats.ticks, 100);
+        assert_eq!(stats.failed_ticks, 10);
+        assert_eq!(stats.last_duration, Some(Duration::from_millis(100)));
+        assert_eq!(stats.avg_duration, Some(Duration::from_micros(50_500)));
+        assert_eq!(stats.p95_duration, Some(Duration::from_millis(95)));
+        assert_eq!(stats.max_duration, Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_only_recent_ticks_are_kept() {
+        let recorder = LoopStatsRecorder::default();
+        recorder.record(Duration::from_secs(10), true);
+        for _ in 0..RECENT_TICKS {
+            recorder.record(Duration::from_millis(5), true);
+        }
+        let stats = recorder.snapshot(Duration::from_millis(100));
+        assert_eq!(stats.ticks, RECENT_TICKS as u64 + 1);
+        assert_eq!(stats.max_duration, Some(Duration::from_millis(5)));
+    }
+}
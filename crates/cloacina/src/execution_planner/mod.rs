@@ -114,28 +114,41 @@
 //! ```
 
 mod context_manager;
+mod loop_stats;
 mod scheduler_loop;
 pub mod stale_claim_sweeper;
 mod state_manager;
 mod trigger_rules;
 
 // Re-export public types
+pub use loop_stats::SchedulerLoopStats;
 pub use trigger_rules::{TriggerCondition, TriggerRule, ValueOperator};
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use diesel::prelude::*;
 use diesel::Connection;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
-use crate::dal::unified::models::{NewUnifiedTaskExecution, NewUnifiedWorkflowExecution};
+use crate::dal::unified::models::{
+    NewUnifiedTaskExecution, NewUnifiedWorkflowExecution, NewUnifiedWorkflowExecutionLabel,
+    NewUnifiedWorkflowExecutionOptions, NewUnifiedWorkflowExecutionParams,
+};
 use crate::dal::DAL;
-use crate::database::schema::unified::{task_executions, workflow_executions};
+use crate::database::schema::unified::{
+    task_executions, workflow_execution_labels, workflow_execution_options,
+    workflow_execution_params, workflow_executions,
+};
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
 use crate::dispatcher::Dispatcher;
-use crate::error::ValidationError;
+use crate::error::{ContextError, ValidationError};
+use crate::models::delayed_execution::{delayed_run_id, DELAYED_EXECUTION_ID_KEY};
+use crate::models::workflow_execution::{
+    default_run_id, validate_labels, ExecutionLabels, ExecutionOptions,
+};
 use crate::task::TaskNamespace;
 use crate::Runtime;
 use crate::{Context, Database, Workflow};
@@ -190,6 +203,10 @@ pub struct TaskScheduler {
     dispatcher: Option<Arc<dyn Dispatcher>>,
     /// Shutdown signal for graceful termination of the scheduling loop.
     shutdown_rx: Option<tokio::sync::watch::Receiver<bool>>,
+    /// Tick durations recorded by the scheduling loop.
+    loop_stats: Arc<loop_stats::LoopStatsRecorder>,
+    /// Caps on concurrent executions, by workflow name.
+    execution_limits: HashMap<String, usize>,
 }
 
 impl TaskScheduler {
@@ -252,6 +269,8 @@ impl TaskScheduler {
             poll_interval,
             dispatcher: None,
             shutdown_rx: None,
+            loop_stats: Arc::default(),
+            execution_limits: HashMap::new(),
         }
     }
 
@@ -266,6 +285,14 @@ impl TaskScheduler {
         &self.runtime
     }
 
+    /// Caps how many executions of each named workflow run at once. Excess
+    /// executions stay queued as `Pending`; a workflow's own
+    /// `max_active_runs` applies only where no cap is given here.
+    pub fn with_execution_limits(mut self, execution_limits: HashMap<String, usize>) -> Self {
+        self.execution_limits = execution_limits;
+        self
+    }
+
     /// Sets the shutdown receiver for graceful termination of the scheduling loop.
     pub fn with_shutdown(mut self, shutdown_rx: tokio::sync::watch::Receiver<bool>) -> Self {
         self.shutdown_rx = Some(shutdown_rx);
@@ -294,6 +321,12 @@ impl TaskScheduler {
         self.dispatcher.as_ref()
     }
 
+    /// Latency of this scheduler's loop: tick count and recent tick
+    /// durations against the poll interval.
+    pub fn loop_stats(&self) -> SchedulerLoopStats {
+        self.loop_stats.snapshot(self.poll_interval)
+    }
+
     /// Schedules a new workflow execution with the provided input context.
     ///
     /// This method:
@@ -341,8 +374,77 @@ impl TaskScheduler {
         &self,
         workflow_name: &str,
         input_context: Context<serde_json::Value>,
+    ) -> Result<Uuid, ValidationError> {
+        self.schedule_workflow_execution_with_labels(
+            workflow_name,
+            input_context,
+            &ExecutionLabels::new(),
+        )
+        .await
+    }
+
+    /// Schedules a workflow execution with labels attached.
+    ///
+    /// Behaves like [`schedule_workflow_execution`](Self::schedule_workflow_execution),
+    /// but the label rows are inserted in the same transaction as the
+    /// execution row, so an execution is never visible without its labels.
+    /// Labels are validated first; a malformed set schedules nothing.
+    pub async fn schedule_workflow_execution_with_labels(
+        &self,
+        workflow_name: &str,
+        input_context: Context<serde_json::Value>,
+        labels: &ExecutionLabels,
+    ) -> Result<Uuid, ValidationError> {
+        self.schedule_workflow_execution_with_options(
+            workflow_name,
+            input_context,
+            labels,
+            &ExecutionOptions::default(),
+        )
+        .await
+    }
+
+    /// Schedules a workflow execution with labels and per-execution options.
+    ///
+    /// Behaves like
+    /// [`schedule_workflow_execution_with_labels`](Self::schedule_workflow_execution_with_labels).
+    /// The options are stored in the same transaction as the execution row,
+    /// and a `max_attempts` override is written to every task row. Options
+    /// are validated first; invalid options schedule nothing.
+    ///
+    /// Every execution gets a run identity, persisted on its row and stamped
+    /// into the context as `run_id` and `logical_date` (replacing any caller
+    /// values). The logical date is the options' `logical_date`, else the
+    /// cron `scheduled_time` or trigger `triggered_at` in the context, else
+    /// now; the run id is the options' `run_id`, else
+    /// `scheduled__`/`triggered__`/`manual__` followed by the logical date.
+    ///
+    /// The options' `params` are stored apart from the context, in the same
+    /// transaction, and handed read-only to every task.
+    pub async fn schedule_workflow_execution_with_options(
+        &self,
+        workflow_name: &str,
+        mut input_context: Context<serde_json::Value>,
+        labels: &ExecutionLabels,
+        options: &ExecutionOptions,
     ) -> Result<Uuid, ValidationError> {
         info!("Scheduling workflow execution: {}", workflow_name);
+        validate_labels(labels)?;
+        options.validate()?;
+        let overrides = options.overrides();
+        let stored_options = if overrides.is_default() {
+            None
+        } else {
+            Some(serde_json::to_string(&overrides).map_err(|e| {
+                ValidationError::InvalidExecutionOptions(format!("cannot serialize options: {}", e))
+            })?)
+        };
+        let stored_params = match options.params.as_ref().filter(|p| !p.is_empty()) {
+            Some(params) => Some(serde_json::to_string(params).map_err(|e| {
+                ValidationError::InvalidExecutionOptions(format!("cannot serialize params: {}", e))
+            })?),
+            None => None,
+        };
 
         // Look up workflow in scoped runtime registry
         let workflow = match self.runtime.get_workflow(workflow_name) {
@@ -366,20 +468,46 @@ impl TaskScheduler {
             );
         }
 
+        // A deprecated workflow still runs; the warning tells whoever
+        // started it to move off before the sunset date.
+        if let Some(deprecation) = self.dal.workflow_deprecation().get(workflow_name).await? {
+            warn!("{}", deprecation.warning(chrono::Utc::now()));
+        }
+
+        let (run_id, logical_date) = Self::run_identity(workflow_name, &input_context, options);
+        for (key, value) in [
+            ("run_id", serde_json::json!(run_id)),
+            (
+                "logical_date",
+                serde_json::json!(logical_date.0.to_rfc3339()),
+            ),
+        ] {
+            input_context.remove(key);
+            input_context
+                .insert(key, value)
+                .map_err(ContextError::from)?;
+        }
+
         // Store context first (separate operation - needed before main transaction)
         let stored_context = self.dal.context().create(&input_context).await?;
 
         // Build all task data BEFORE the transaction
         let task_ids = workflow.topological_sort()?;
         let mut task_data: Vec<(String, String, String, i32)> = Vec::with_capacity(task_ids.len());
+        let template_scope = crate::templating::TemplateScope::new(
+            &Self::fire_metadata(workflow_name, &input_context),
+            &input_context,
+        );
 
         for task_id in &task_ids {
             let trigger_rules = self.get_task_trigger_rules(&workflow, task_id);
-            let task_config = self.get_task_configuration(&workflow, task_id);
-            let max_attempts = workflow
-                .get_task(task_id)
-                .map(|t| t.retry_policy().max_attempts)
-                .unwrap_or(3);
+            let task_config = self.get_task_configuration(&workflow, task_id, &template_scope)?;
+            let max_attempts = options.max_attempts.unwrap_or_else(|| {
+                workflow
+                    .get_task(task_id)
+                    .map(|t| t.retry_policy().max_attempts)
+                    .unwrap_or(3)
+            });
 
             task_data.push((
                 task_id.to_string(),
@@ -406,6 +534,11 @@ impl TaskScheduler {
                 wf_version,
                 stored_context,
                 task_data,
+                labels.clone(),
+                stored_options,
+                stored_params,
+                run_id,
+                logical_date,
             )
             .await?,
             self.create_workflow_execution_sqlite(
@@ -415,6 +548,11 @@ impl TaskScheduler {
                 wf_version,
                 stored_context,
                 task_data,
+                labels.clone(),
+                stored_options,
+                stored_params,
+                run_id,
+                logical_date,
             )
             .await?
         );
@@ -428,8 +566,10 @@ impl TaskScheduler {
         Ok(workflow_execution_id.into())
     }
 
-    /// Creates workflow execution and tasks in PostgreSQL.
+    /// Creates workflow execution, tasks, labels, options and params in
+    /// PostgreSQL.
     #[cfg(feature = "postgres")]
+    #[allow(clippy::too_many_arguments)]
     async fn create_workflow_execution_postgres(
         &self,
         workflow_execution_id: UniversalUuid,
@@ -438,6 +578,11 @@ impl TaskScheduler {
         workflow_version: String,
         stored_context: Option<UniversalUuid>,
         task_data: Vec<(String, String, String, i32)>,
+        labels: ExecutionLabels,
+        options: Option<String>,
+        params: Option<String>,
+        run_id: String,
+        logical_date: UniversalTimestamp,
     ) -> Result<(), ValidationError> {
         let conn = self
             .dal
@@ -459,6 +604,8 @@ impl TaskScheduler {
                         started_at: now,
                         created_at: now,
                         updated_at: now,
+                        run_id: Some(run_id),
+                        logical_date: Some(logical_date),
                     })
                     .execute(conn)?;
 
@@ -480,6 +627,46 @@ impl TaskScheduler {
                         .execute(conn)?;
                 }
 
+                // Insert labels
+                if !labels.is_empty() {
+                    let rows: Vec<NewUnifiedWorkflowExecutionLabel> = labels
+                        .into_iter()
+                        .map(
+                            |(label_key, label_value)| NewUnifiedWorkflowExecutionLabel {
+                                workflow_execution_id,
+                                label_key,
+                                label_value,
+                                created_at: now,
+                            },
+                        )
+                        .collect();
+                    diesel::insert_into(workflow_execution_labels::table)
+                        .values(&rows)
+                        .execute(conn)?;
+                }
+
+                // Insert options
+                if let Some(options) = options {
+                    diesel::insert_into(workflow_execution_options::table)
+                        .values(&NewUnifiedWorkflowExecutionOptions {
+                            workflow_execution_id,
+                            options,
+                            created_at: now,
+                        })
+                        .execute(conn)?;
+                }
+
+                // Insert params
+                if let Some(params) = params {
+                    diesel::insert_into(workflow_execution_params::table)
+                        .values(&NewUnifiedWorkflowExecutionParams {
+                            workflow_execution_id,
+                            params,
+                            created_at: now,
+                        })
+                        .execute(conn)?;
+                }
+
                 Ok::<_, diesel::result::Error>(())
             })
         })
@@ -489,8 +676,10 @@ impl TaskScheduler {
         Ok(())
     }
 
-    /// Creates workflow execution and tasks in SQLite.
+    /// Creates workflow execution, tasks, labels, options and params in
+    /// SQLite.
     #[cfg(feature = "sqlite")]
+    #[allow(clippy::too_many_arguments)]
     async fn create_workflow_execution_sqlite(
         &self,
         workflow_execution_id: UniversalUuid,
@@ -499,6 +688,11 @@ impl TaskScheduler {
         workflow_version: String,
         stored_context: Option<UniversalUuid>,
         task_data: Vec<(String, String, String, i32)>,
+        labels: ExecutionLabels,
+        options: Option<String>,
+        params: Option<String>,
+        run_id: String,
+        logical_date: UniversalTimestamp,
     ) -> Result<(), ValidationError> {
         let conn = self
             .dal
@@ -520,6 +714,8 @@ impl TaskScheduler {
                         started_at: now,
                         created_at: now,
                         updated_at: now,
+                        run_id: Some(run_id),
+                        logical_date: Some(logical_date),
                     })
                     .execute(conn)?;
 
@@ -541,6 +737,46 @@ impl TaskScheduler {
                         .execute(conn)?;
                 }
 
+                // Insert labels
+                if !labels.is_empty() {
+                    let rows: Vec<NewUnifiedWorkflowExecutionLabel> = labels
+                        .into_iter()
+                        .map(
+                            |(label_key, label_value)| NewUnifiedWorkflowExecutionLabel {
+                                workflow_execution_id,
+                                label_key,
+                                label_value,
+                                created_at: now,
+                            },
+                        )
+                        .collect();
+                    diesel::insert_into(workflow_execution_labels::table)
+                        .values(&rows)
+                        .execute(conn)?;
+                }
+
+                // Insert options
+                if let Some(options) = options {
+                    diesel::insert_into(workflow_execution_options::table)
+                        .values(&NewUnifiedWorkflowExecutionOptions {
+                            workflow_execution_id,
+                            options,
+                            created_at: now,
+                        })
+                        .execute(conn)?;
+                }
+
+                // Insert params
+                if let Some(params) = params {
+                    diesel::insert_into(workflow_execution_params::table)
+                        .values(&NewUnifiedWorkflowExecutionParams {
+                            workflow_execution_id,
+                            params,
+                            created_at: now,
+                        })
+                        .execute(conn)?;
+                }
+
                 Ok::<_, diesel::result::Error>(())
             })
         })
@@ -595,7 +831,9 @@ impl TaskScheduler {
             self.instance_id,
             self.poll_interval,
             self.dispatcher.clone(),
-        );
+        )
+        .with_loop_stats(self.loop_stats.clone())
+        .with_execution_limits(self.execution_limits.clone());
         if let Some(ref shutdown_rx) = self.shutdown_rx {
             scheduler_loop = scheduler_loop.with_shutdown(shutdown_rx.clone());
         }
@@ -610,7 +848,8 @@ impl TaskScheduler {
             self.instance_id,
             self.poll_interval,
             self.dispatcher.clone(),
-        );
+        )
+        .with_execution_limits(self.execution_limits.clone());
         scheduler_loop.process_active_executions().await
     }
 
@@ -626,13 +865,85 @@ impl TaskScheduler {
             .unwrap_or_else(|_| serde_json::json!({"type": "Always"}))
     }
 
-    /// Gets task configuration (currently returns empty object).
+    /// Renders a task's declarative configuration for this execution.
+    ///
+    /// Tasks without a config get `{}`. A config that fails to render fails
+    /// the scheduling call, so no execution starts with a half-rendered value.
     fn get_task_configuration(
         &self,
-        _workflow: &Workflow,
-        _task_namespace: &TaskNamespace,
-    ) -> serde_json::Value {
-        // In the future, this could include task-specific configuration
-        serde_json::json!({})
+        workflow: &Workflow,
+        task_namespace: &TaskNamespace,
+        scope: &crate::templating::TemplateScope,
+    ) -> Result<serde_json::Value, ValidationError> {
+        let Some(config) = workflow
+            .get_task(task_namespace)
+            .ok()
+            .and_then(|task| task.configuration())
+        else {
+            return Ok(serde_json::json!({}));
+        };
+        crate::templating::render_config(&config, scope).map_err(|e| {
+            ValidationError::InvalidTaskConfiguration {
+                task_id: task_namespace.to_string(),
+                message: e.to_string(),
+            }
+        })
+    }
+
+    /// Fire metadata for config templates, read from the reserved keys the
+    /// cron and trigger schedulers stamp. Direct runs fire "now", in UTC.
+    /// Run id and logical date of an execution about to be scheduled (see
+    /// [`schedule_workflow_execution_with_options`](Self::schedule_workflow_execution_with_options)).
+    fn run_identity(
+        workflow_name: &str,
+        input_context: &Context<serde_json::Value>,
+        options: &ExecutionOptions,
+    ) -> (String, UniversalTimestamp) {
+        let fire = Self::fire_metadata(workflow_name, input_context);
+        let logical_date = options.logical_date.unwrap_or(fire.fire_time);
+        let delayed_id = input_context
+            .get(DELAYED_EXECUTION_ID_KEY)
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        let run_id = options.run_id.clone().unwrap_or_else(|| {
+            // A delayed run is keyed on its delayed execution, so a re-claim
+            // after an interrupted hand-off can find it.
+            if let Some(id) = &delayed_id {
+                return delayed_run_id(id);
+            }
+            let kind = if fire.schedule_id.is_some() {
+                "scheduled"
+            } else if fire.trigger_name.is_some() {
+                "triggered"
+            } else {
+                "manual"
+            };
+            default_run_id(kind, logical_date)
+        });
+        (run_id, UniversalTimestamp(logical_date))
+    }
+
+    fn fire_metadata(
+        workflow_name: &str,
+        input_context: &Context<serde_json::Value>,
+    ) -> crate::templating::FireMetadata {
+        let text = |key: &str| {
+            input_context
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+        let fire_time = text("scheduled_time")
+            .or_else(|| text("triggered_at"))
+            .and_then(|ts| chrono::DateTime::parse_from_rfc3339(&ts).ok())
+            .map(|ts| ts.with_timezone(&chrono::Utc))
+            .unwrap_or_else(chrono::Utc::now);
+        crate::templating::FireMetadata {
+            fire_time,
+            workflow_name: workflow_name.to_string(),
+            schedule_id: text("schedule_id"),
+            schedule_timezone: text("schedule_timezone"),
+            trigger_name: text("trigger_name"),
+        }
     }
 }
@@ -19,7 +19,7 @@
 //! This module contains the main scheduling loop that continuously processes
 //! active workflow executions and manages task readiness.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -28,14 +28,18 @@ use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::dal::DAL;
-use crate::database::universal_types::UniversalUuid;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
 use crate::database::BackendType;
 use crate::dispatcher::{DispatchError, Dispatcher, TaskReadyEvent};
-use crate::error::ValidationError;
+use crate::error::{
+    ErrorCategory, TaskErrorPayload, ValidationError, DEADLINE_EXCEEDED_CODE, WORKFLOW_TIMEOUT_CODE,
+};
 use crate::models::task_execution::TaskExecution;
-use crate::models::workflow_execution::WorkflowExecutionRecord;
+use crate::models::workflow_execution::{ExecutionOptions, WorkflowExecutionRecord};
+use crate::retry::RetryPolicy;
 use crate::Runtime;
 
+use super::loop_stats::LoopStatsRecorder;
 use super::state_manager::StateManager;
 
 /// Maximum backoff interval during sustained errors (30 seconds).
@@ -50,6 +54,119 @@ const MAX_BACKOFF: Duration = Duration::from_secs(30);
 /// tick. Set comfortably above typical fleet aggregate capacity.
 const MAX_CONCURRENT_DISPATCH: usize = 64;
 
+/// Sorts ready tasks by their execution's priority (stable, so equal
+/// priorities keep the DAL order) and drops tasks beyond an execution's free
+/// `max_parallelism` slots; they stay Ready for a later tick.
+fn plan_dispatch(
+    mut ready_tasks: Vec<TaskExecution>,
+    options: &HashMap<UniversalUuid, ExecutionOptions>,
+    in_flight: &HashMap<UniversalUuid, usize>,
+) -> Vec<TaskExecution> {
+    let priority = |task: &TaskExecution| {
+        options
+            .get(&task.workflow_execution_id)
+            .map_or(0, |o| o.priority)
+    };
+    ready_tasks.sort_by_key(|task| std::cmp::Reverse(priority(task)));
+
+    let mut free_slots: HashMap<UniversalUuid, usize> = options
+        .iter()
+        .filter_map(|(id, o)| {
+            let running = in_flight.get(id).copied().unwrap_or(0);
+            o.max_parallelism
+                .map(|max| (*id, max.saturating_sub(running)))
+        })
+        .collect();
+    ready_tasks.retain(
+        |task| match free_slots.get_mut(&task.workflow_execution_id) {
+            Some(0) => false,
+            Some(slots) => {
+                *slots -= 1;
+                true
+            }
+            None => true,
+        },
+    );
+    ready_tasks
+}
+
+/// Whether an active execution has started: it is `Running`, or the
+/// scheduler has moved one of its tasks past `NotStarted`.
+fn has_started(execution: &WorkflowExecutionRecord, statuses: &HashMap<String, String>) -> bool {
+    execution.status == "Running" || statuses.values().any(|s| s != "NotStarted")
+}
+
+/// Active executions held back by their workflow's concurrency limit.
+///
+/// Started executions fill a workflow's slots first; queued (not yet started)
+/// ones are admitted oldest first into what is left. Held-back executions stay
+/// `Pending` with their tasks untouched until a later tick frees a slot.
+fn held_back_executions(
+    executions: &[WorkflowExecutionRecord],
+    statuses: &HashMap<UniversalUuid, HashMap<String, String>>,
+    limits: &HashMap<String, usize>,
+) -> HashSet<UniversalUuid> {
+    let empty = HashMap::new();
+    let mut free_slots: HashMap<&str, usize> = limits
+        .iter()
+        .map(|(name, limit)| (name.as_str(), *limit))
+        .collect();
+    let mut queued = Vec::new();
+    for execution in executions {
+        let Some(slots) = free_slots.get_mut(execution.workflow_name.as_str()) else {
+            continue;
+        };
+        if has_started(execution, statuses.get(&execution.id).unwrap_or(&empty)) {
+            *slots = slots.saturating_sub(1);
+        } else {
+            queued.push(execution);
+        }
+    }
+
+    queued.sort_by_key(|e| (e.created_at.0, e.id.0));
+    let mut held_back = HashSet::new();
+    for execution in queued {
+        match free_slots.get_mut(execution.workflow_name.as_str()) {
+            Some(0) => {
+                held_back.insert(execution.id);
+            }
+            Some(slots) => *slots -= 1,
+            None => {}
+        }
+    }
+    held_back
+}
+
+/// When a failed `attempt` of a workflow under `policy` starts over, or
+/// `None` once the policy's `max_attempts` retries are spent.
+fn workflow_retry_at(
+    policy: &RetryPolicy,
+    attempt: i32,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    if attempt > policy.max_attempts {
+        return None;
+    }
+    let delay = chrono::Duration::from_std(policy.calculate_delay(attempt)).ok()?;
+    Some(now + delay)
+}
+
+/// When an execution under a workflow `timeout` runs out of time: `timeout`
+/// after it was created, after a workflow retry restarted it, or after it
+/// was admitted past a concurrency limit, whichever is latest. `None` when
+/// the deadline is out of range.
+fn timeout_deadline(
+    execution: &WorkflowExecutionRecord,
+    timeout: Duration,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    let start = [execution.retry_at, execution.admitted_at]
+        .into_iter()
+        .flatten()
+        .map(|at| at.0)
+        .fold(execution.started_at.0, |start, at| start.max(at));
+    start.checked_add_signed(chrono::Duration::from_std(timeout).ok()?)
+}
+
 /// Dispatch a single Ready task and log the outcome (CLOACI-T-0745). Shared by
 /// the postgres (spawned, concurrent) and sqlite (serial) dispatch paths.
 /// NoCapacity is expected backpressure (the task stays Ready, retried later);
@@ -96,6 +213,11 @@ pub struct SchedulerLoop<'a> {
     shutdown_rx: Option<tokio::sync::watch::Receiver<bool>>,
     /// Consecutive error count for circuit breaker / backoff.
     consecutive_errors: u32,
+    /// Where tick durations are recorded, when someone reads them.
+    loop_stats: Option<Arc<LoopStatsRecorder>>,
+    /// Runner-level caps on concurrent executions, by workflow name. These
+    /// take precedence over a workflow's own `max_active_runs`.
+    execution_limits: HashMap<String, usize>,
 }
 
 impl<'a> SchedulerLoop<'a> {
@@ -115,6 +237,8 @@ impl<'a> SchedulerLoop<'a> {
             dispatcher,
             shutdown_rx: None,
             consecutive_errors: 0,
+            loop_stats: None,
+            execution_limits: HashMap::new(),
         }
     }
 
@@ -124,6 +248,22 @@ impl<'a> SchedulerLoop<'a> {
         self
     }
 
+    /// Record every tick's duration in `loop_stats`.
+    pub(crate) fn with_loop_stats(mut self, loop_stats: Arc<LoopStatsRecorder>) -> Self {
+        self.loop_stats = Some(loop_stats);
+        self
+    }
+
+    /// Cap concurrent executions per workflow name, overriding each
+    /// workflow's own `max_active_runs`.
+    pub(crate) fn with_execution_limits(
+        mut self,
+        execution_limits: HashMap<String, usize>,
+    ) -> Self {
+        self.execution_limits = execution_limits;
+        self
+    }
+
     /// Runs the main scheduling loop that continuously processes active workflow executions.
     ///
     /// This loop:
@@ -151,7 +291,13 @@ impl<'a> SchedulerLoop<'a> {
                 interval.tick().await;
             }
 
-            match self.process_active_executions().await {
+            let tick_started = std::time::Instant::now();
+            let result = self.process_active_executions().await;
+            if let Some(ref loop_stats) = self.loop_stats {
+                loop_stats.record(tick_started.elapsed(), result.is_ok());
+            }
+
+            match result {
                 Ok(_) => {
                     if self.consecutive_errors > 0 {
                         info!(
@@ -267,6 +413,25 @@ impl<'a> SchedulerLoop<'a> {
                 .push(task);
         }
 
+        // Execution deadlines, from the options the executions were submitted
+        // with. Best-effort: without them, no execution is failed for its
+        // deadline this tick.
+        let deadlines: HashMap<UniversalUuid, chrono::DateTime<chrono::Utc>> = match self
+            .dal
+            .workflow_execution()
+            .get_options_for(&execution_ids)
+            .await
+        {
+            Ok(options) => options
+                .into_iter()
+                .filter_map(|(id, o)| o.deadline.map(|deadline| (id, deadline)))
+                .collect(),
+            Err(e) => {
+                warn!("Checking executions without their deadlines: {}", e);
+                HashMap::new()
+            }
+        };
+
         // CLOACI-T-0745: one query for the full task-status map per execution.
         // Dependency gating + status-based trigger conditions resolve from this
         // in memory, replacing the previous per-task `get_by_id` + per-task
@@ -278,13 +443,91 @@ impl<'a> SchedulerLoop<'a> {
             .await?;
         let empty_statuses: HashMap<String, String> = HashMap::new();
 
+        // Per-workflow run limits, looked up only for workflows with a queued
+        // execution that a limit could hold back.
+        let mut limits: HashMap<String, usize> = HashMap::new();
+        for execution in &active_executions {
+            let statuses = status_by_execution
+                .get(&execution.id)
+                .unwrap_or(&empty_statuses);
+            if has_started(execution, statuses) || limits.contains_key(&execution.workflow_name) {
+                continue;
+            }
+            if let Some(limit) = self
+                .execution_limits
+                .get(&execution.workflow_name)
+                .copied()
+                .or_else(|| {
+                    self.runtime
+                        .get_workflow(&execution.workflow_name)
+                        .and_then(|w| w.max_active_runs())
+                })
+            {
+                limits.insert(execution.workflow_name.clone(), limit);
+            }
+        }
+        let held_back = if limits.is_empty() {
+            HashSet::new()
+        } else {
+            held_back_executions(&active_executions, &status_by_execution, &limits)
+        };
+
+        // Per-workflow timeouts, looked up once per workflow name.
+        let mut timeouts: HashMap<&str, Option<Duration>> = HashMap::new();
+        for execution in &active_executions {
+            timeouts
+                .entry(execution.workflow_name.as_str())
+                .or_insert_with(|| {
+                    self.runtime
+                        .get_workflow(&execution.workflow_name)
+                        .and_then(|w| w.timeout())
+                });
+        }
+
         let state_manager = StateManager::new(self.dal, self.runtime.clone());
+        let now = chrono::Utc::now();
 
         // Process each workflow execution's tasks
         for execution in &active_executions {
+            if execution.retry_at.is_some_and(|retry_at| retry_at.0 > now) {
+                continue;
+            }
+            if let Some(&deadline) = deadlines.get(&execution.id) {
+                if now > deadline && self.miss_deadline(execution, deadline).await? {
+                    continue;
+                }
+            }
+            // A queued execution has not started running, so its workflow
+            // timeout does not run either until it is admitted.
+            if held_back.contains(&execution.id) {
+                debug!(
+                    "Workflow execution {} queued: '{}' is at its limit of {} concurrent executions",
+                    execution.id, execution.workflow_name, limits[&execution.workflow_name]
+                );
+                continue;
+            }
             let statuses = status_by_execution
                 .get(&execution.id)
                 .unwrap_or(&empty_statuses);
+            let admitted_now = limits.contains_key(&execution.workflow_name)
+                && execution.admitted_at.is_none()
+                && !has_started(execution, statuses);
+            if admitted_now {
+                self.dal
+                    .workflow_execution()
+                    .mark_admitted(execution.id)
+                    .await?;
+            } else if let Some(timeout) = timeouts
+                .get(execution.workflow_name.as_str())
+                .copied()
+                .flatten()
+            {
+                if timeout_deadline(execution, timeout).is_some_and(|deadline| now > deadline)
+                    && self.time_out_execution(execution, timeout).await?
+                {
+                    continue;
+                }
+            }
 
             match tasks_by_execution.get(&execution.id) {
                 // Has pending tasks → by definition NOT complete: update task
@@ -312,7 +555,7 @@ impl<'a> SchedulerLoop<'a> {
                         .check_workflow_completion(execution.id)
                         .await?
                     {
-                        self.complete_execution(execution).await?;
+                        self.complete_execution(execution, None).await?;
                     }
                 }
             }
@@ -343,6 +586,7 @@ impl<'a> SchedulerLoop<'a> {
             .increment(1);
             return Ok(());
         }
+        let ready_tasks = self.apply_execution_options(ready_tasks).await;
 
         // Backend-aware dispatch (CLOACI-T-0745).
         //
@@ -385,14 +629,115 @@ impl<'a> SchedulerLoop<'a> {
         Ok(())
     }
 
+    /// Orders and caps ready tasks by their executions' options: tasks of
+    /// higher-priority executions go first, and an execution with
+    /// `max_parallelism` gets only its free slots. Best-effort: when the
+    /// options cannot be read, the tasks are dispatched as they are.
+    async fn apply_execution_options(&self, ready_tasks: Vec<TaskExecution>) -> Vec<TaskExecution> {
+        let mut execution_ids: Vec<UniversalUuid> = ready_tasks
+            .iter()
+            .map(|t| t.workflow_execution_id)
+            .collect();
+        execution_ids.sort_by_key(|id| id.0);
+        execution_ids.dedup();
+
+        let options = match self
+            .dal
+            .workflow_execution()
+            .get_options_for(&execution_ids)
+            .await
+        {
+            Ok(options) if options.is_empty() => return ready_tasks,
+            Ok(options) => options,
+            Err(e) => {
+                warn!("Dispatching without execution options: {}", e);
+                return ready_tasks;
+            }
+        };
+        let capped: Vec<UniversalUuid> = options
+            .iter()
+            .filter(|(_, o)| o.max_parallelism.is_some())
+            .map(|(id, _)| *id)
+            .collect();
+        let in_flight = match self.dal.task_execution().count_in_flight_for(&capped).await {
+            Ok(in_flight) => in_flight,
+            Err(e) => {
+                warn!("Dispatching without parallelism limits: {}", e);
+                HashMap::new()
+            }
+        };
+        plan_dispatch(ready_tasks, &options, &in_flight)
+    }
+
+    /// Fails the unfinished tasks of an execution that ran past its workflow
+    /// `timeout`, then completes it as failed (or retries it). Returns whether
+    /// any task was still unfinished; when none was, the execution is left to
+    /// the normal completion path.
+    async fn time_out_execution(
+        &self,
+        execution: &WorkflowExecutionRecord,
+        timeout: Duration,
+    ) -> Result<bool, ValidationError> {
+        let message = format!(
+            "Workflow execution timed out after {}s",
+            timeout.as_secs_f64()
+        );
+        let payload = TaskErrorPayload::new(WORKFLOW_TIMEOUT_CODE, message.clone())
+            .with_category(ErrorCategory::Transient);
+        let failed = self
+            .dal
+            .task_execution()
+            .fail_unfinished(execution.id, &message, &payload)
+            .await?;
+        if failed == 0 {
+            return Ok(false);
+        }
+        warn!(
+            "Workflow execution {} (name: {}) timed out after {:?}; failed {} unfinished task(s)",
+            execution.id, execution.workflow_name, timeout, failed
+        );
+        self.complete_execution(execution, Some(timeout)).await?;
+        Ok(true)
+    }
+
+    /// Fails the unfinished tasks of an execution past its `deadline`, then
+    /// completes it as failed. Returns whether any task was still
+    /// unfinished; when none was, the execution is left to the normal
+    /// completion path.
+    async fn miss_deadline(
+        &self,
+        execution: &WorkflowExecutionRecord,
+        deadline: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool, ValidationError> {
+        let message = format!("Execution deadline {} has passed", deadline);
+        let payload = TaskErrorPayload::new(DEADLINE_EXCEEDED_CODE, message.clone());
+        let failed = self
+            .dal
+            .task_execution()
+            .fail_unfinished(execution.id, &message, &payload)
+            .await?;
+        if failed == 0 {
+            return Ok(false);
+        }
+        warn!(
+            "Workflow execution {} (name: {}) missed its deadline {}; failed {} unfinished task(s)",
+            execution.id, execution.workflow_name, deadline, failed
+        );
+        self.complete_execution(execution, None).await?;
+        Ok(true)
+    }
+
     /// Completes a workflow execution by updating its final context and marking it as completed.
     ///
     /// Guards against the race where two scheduler ticks both see the workflow execution
     /// as complete and both try to finalise it. Only the first caller (whose
     /// workflow execution is still "Running") will proceed; subsequent calls return early.
+    /// `timed_out` is the workflow timeout when the execution is being
+    /// completed because it ran past it.
     async fn complete_execution(
         &self,
         execution: &WorkflowExecutionRecord,
+        timed_out: Option<Duration>,
     ) -> Result<(), ValidationError> {
         // Guard: only proceed if the workflow execution is still running.
         // This prevents duplicate WorkflowCompleted events when two scheduler
@@ -433,19 +778,44 @@ impl<'a> SchedulerLoop<'a> {
 
         // Determine final workflow execution status based on task outcomes
         if failed_count > 0 {
-            let reason = format!(
+            let mut reason = format!(
                 "{} task(s) failed, {} completed, {} skipped",
                 failed_count, completed_count, skipped_count
             );
+            // Workflow-level failures are downstream of task failures, which
+            // a workflow timeout or a missed deadline forces.
+            let deadline_exceeded = all_tasks.iter().any(|t| {
+                t.error_payload
+                    .as_ref()
+                    .is_some_and(|p| p.code == DEADLINE_EXCEEDED_CODE)
+            });
+            let metric_reason = match timed_out {
+                Some(timeout) => {
+                    reason = format!("timed out after {}s; {}", timeout.as_secs_f64(), reason);
+                    "timeout"
+                }
+                None if deadline_exceeded => {
+                    reason = format!("deadline exceeded; {}", reason);
+                    "deadline_exceeded"
+                }
+                None => "dependency_failed",
+            };
+            // A retry would start after the deadline it already missed.
+            if !deadline_exceeded
+                && self
+                    .retry_execution(&current, &reason, metric_reason)
+                    .await?
+            {
+                return Ok(());
+            }
             self.dal
                 .workflow_execution()
                 .mark_failed(execution.id, &reason)
                 .await?;
-            // Workflow-level failures are always downstream of task failures.
             metrics::counter!(
                 "cloacina_workflows_total",
                 "status" => "failed",
-                "reason" => "dependency_failed",
+                "reason" => metric_reason,
             )
             .increment(1);
             info!(
@@ -481,6 +851,51 @@ impl<'a> SchedulerLoop<'a> {
         Ok(())
     }
 
+    /// Starts a failed execution over when its workflow has a retry policy
+    /// with attempts left. Returns whether the execution was reset.
+    async fn retry_execution(
+        &self,
+        execution: &WorkflowExecutionRecord,
+        reason: &str,
+        metric_reason: &'static str,
+    ) -> Result<bool, ValidationError> {
+        let Some(policy) = self
+            .runtime
+            .get_workflow(&execution.workflow_name)
+            .and_then(|w| w.retry_policy().cloned())
+        else {
+            return Ok(false);
+        };
+        let Some(retry_at) = workflow_retry_at(&policy, execution.attempt, chrono::Utc::now())
+        else {
+            return Ok(false);
+        };
+
+        if !self
+            .dal
+            .workflow_execution()
+            .schedule_retry(execution.id, UniversalTimestamp(retry_at), reason)
+            .await?
+        {
+            return Ok(false);
+        }
+        metrics::counter!(
+            "cloacina_workflows_total",
+            "status" => "retried",
+            "reason" => metric_reason,
+        )
+        .increment(1);
+        info!(
+            "Workflow execution {} (name: {}) attempt {} failed ({}); retrying at {}",
+            execution.id,
+            execution.workflow_name,
+            execution.attempt,
+            reason,
+            retry_at.to_rfc3339()
+        );
+        Ok(true)
+    }
+
     /// Updates the workflow execution's final context when it completes.
     ///
     /// This method finds the context from the final task(s) that produced output
@@ -552,3 +967,179 @@ impl<'a> SchedulerLoop<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ready(execution: UniversalUuid, name: &str) -> TaskExecution {
+        let now = UniversalTimestamp::now();
+        TaskExecution {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: execution,
+            task_name: name.to_string(),
+            status: "Ready".to_string(),
+            started_at: None,
+            completed_at: None,
+            attempt: 1,
+            max_attempts: 3,
+            error_details: None,
+            trigger_rules: "{}".to_string(),
+            task_configuration: "{}".to_string(),
+            retry_at: None,
+            last_error: None,
+            recovery_attempts: 0,
+            last_recovery_at: None,
+            sub_status: None,
+            claimed_by: None,
+            heartbeat_at: None,
+            created_at: now,
+            updated_at: now,
+            error_payload: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_dispatch_orders_by_priority_and_caps_parallelism() {
+        let (plain, urgent) = (UniversalUuid::new_v4(), UniversalUuid::new_v4());
+        let tasks = vec![
+            ready(plain, "a"),
+            ready(urgent, "b"),
+            ready(urgent, "c"),
+            ready(urgent, "d"),
+        ];
+        let options = HashMap::from([(
+            urgent,
+            ExecutionOptions {
+                priority: 10,
+                max_parallelism: Some(3),
+                ..Default::default()
+            },
+        )]);
+        let in_flight = HashMap::from([(urgent, 1)]);
+
+        let planned = plan_dispatch(tasks, &options, &in_flight);
+        let names: Vec<&str> = planned.iter().map(|t| t.task_name.as_str()).collect();
+        assert_eq!(names, vec!["b", "c", "a"]);
+    }
+
+    fn execution(name: &str, status: &str, created_secs_ago: i64) -> WorkflowExecutionRecord {
+        let created =
+            UniversalTimestamp(chrono::Utc::now() - chrono::Duration::seconds(created_secs_ago));
+        WorkflowExecutionRecord {
+            id: UniversalUuid::new_v4(),
+            workflow_name: name.to_string(),
+            workflow_version: "v1".to_string(),
+            status: status.to_string(),
+            context_id: None,
+            started_at: created,
+            completed_at: None,
+            error_details: None,
+            recovery_attempts: 0,
+            last_recovery_at: None,
+            paused_at: None,
+            pause_reason: None,
+            created_at: created,
+            updated_at: created,
+            trigger_origin: None,
+            run_id: None,
+            logical_date: None,
+            attempt: 1,
+            retry_at: None,
+            admitted_at: None,
+        }
+    }
+
+    fn task_statuses(statuses: &[&str]) -> HashMap<String, String> {
+        statuses
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (format!("t{}", i), s.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_held_back_executions_queues_over_max_active_runs() {
+        // backup allows one run; its oldest pending run has already had a
+        // task marked Ready, so both newer ones wait. etl is unlimited.
+        let started = execution("backup", "Pending", 30);
+        let older = execution("backup", "Pending", 20);
+        let newer = execution("backup", "Pending", 10);
+        let other = execution("etl", "Pending", 5);
+        let statuses = HashMap::from([
+            (started.id, task_statuses(&["Ready", "NotStarted"])),
+            (older.id, task_statuses(&["NotStarted"])),
+            (newer.id, task_statuses(&["NotStarted"])),
+            (other.id, task_statuses(&["NotStarted"])),
+        ]);
+        let limits = HashMap::from([("backup".to_string(), 1)]);
+        let executions = vec![newer.clone(), other, older.clone(), started];
+
+        let held = held_back_executions(&executions, &statuses, &limits);
+        assert_eq!(held, HashSet::from([older.id, newer.id]));
+    }
+
+    #[test]
+    fn test_held_back_executions_admits_oldest_into_free_slots() {
+        let running = execution("backup", "Running", 30);
+        let older = execution("backup", "Pending", 20);
+        let newer = execution("backup", "Pending", 10);
+        let statuses = HashMap::from([(running.id, task_statuses(&["Completed", "Running"]))]);
+        let limits = HashMap::from([("backup".to_string(), 2)]);
+        let executions = vec![newer.clone(), older, running];
+
+        let held = held_back_executions(&executions, &statuses, &limits);
+        assert_eq!(held, HashSet::from([newer.id]));
+    }
+
+    #[test]
+    fn test_workflow_retry_at_backs_off_until_attempts_are_spent() {
+        let policy = RetryPolicy::builder()
+            .max_attempts(2)
+            .initial_delay(Duration::from_secs(10))
+            .with_jitter(false)
+            .build();
+        let now = chrono::Utc::now();
+
+        assert_eq!(
+            workflow_retry_at(&policy, 1, now),
+            Some(now + chrono::Duration::seconds(10))
+        );
+        assert_eq!(
+            workflow_retry_at(&policy, 2, now),
+            Some(now + chrono::Duration::seconds(20))
+        );
+        assert_eq!(workflow_retry_at(&policy, 3, now), None);
+    }
+
+    #[test]
+    fn test_timeout_deadline_restarts_with_retries() {
+        let mut record = execution("etl", "Running", 120);
+        let timeout = Duration::from_secs(60);
+        assert_eq!(
+            timeout_deadline(&record, timeout),
+            Some(record.started_at.0 + chrono::Duration::seconds(60))
+        );
+
+        let retry_at = record.started_at.0 + chrono::Duration::seconds(90);
+        record.retry_at = Some(UniversalTimestamp(retry_at));
+        assert_eq!(
+            timeout_deadline(&record, timeout),
+            Some(retry_at + chrono::Duration::seconds(60))
+        );
+
+        assert_eq!(timeout_deadline(&record, Duration::MAX), None);
+    }
+
+    #[test]
+    fn test_timeout_deadline_starts_at_admission() {
+        let mut record = execution("etl", "Pending", 600);
+        let timeout = Duration::from_secs(60);
+        let admitted_at = chrono::Utc::now();
+        record.admitted_at = Some(UniversalTimestamp(admitted_at));
+        assert_eq!(
+            timeout_deadline(&record, timeout),
+            Some(admitted_at + chrono::Duration::seconds(60))
+        );
+    }
+}
@@ -26,14 +26,34 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::dal::DAL;
+use crate::database::universal_types::UniversalUuid;
 use crate::error::ValidationError;
-use crate::models::task_execution::TaskExecution;
+use crate::models::task_execution::{ApprovalDecision, TaskExecution};
 use crate::models::workflow_execution::WorkflowExecutionRecord;
 use crate::Runtime;
 
 use super::context_manager::ContextManager;
 use super::trigger_rules::{TriggerCondition, TriggerRule};
 
+/// Prefix of the skip reason recorded for short-circuited tasks; also how a
+/// short-circuit is recognised and propagated through Skipped dependencies.
+const SHORT_CIRCUIT_REASON_PREFIX: &str = "Short-circuited";
+
+/// Prefix of the skip reason recorded for tasks on a branch their decider did
+/// not select; also how such a skip is recognised and propagated downstream.
+const BRANCH_NOT_TAKEN_REASON_PREFIX: &str = "BranchNotTaken";
+
+/// Whether a short-circuit key's value counts as "no data".
+fn is_empty_output(value: Option<&serde_json::Value>) -> bool {
+    match value {
+        None | Some(serde_json::Value::Null) | Some(serde_json::Value::Bool(false)) => true,
+        Some(serde_json::Value::String(s)) => s.is_empty(),
+        Some(serde_json::Value::Array(a)) => a.is_empty(),
+        Some(serde_json::Value::Object(o)) => o.is_empty(),
+        Some(serde_json::Value::Number(_)) | Some(serde_json::Value::Bool(true)) => false,
+    }
+}
+
 /// State management operations for the scheduler.
 pub struct StateManager<'a> {
     dal: &'a DAL,
@@ -65,12 +85,61 @@ impl<'a> StateManager<'a> {
                 self.check_task_dependencies(task_execution, workflow_execution, statuses)?;
 
             if dependencies_satisfied {
+                // A short-circuiting ancestor with empty output skips the task
+                // outright, unless the task authored its own trigger rules.
+                if let Some(reason) = self
+                    .short_circuit_reason(task_execution, workflow_execution, statuses)
+                    .await?
+                {
+                    self.dal
+                        .task_execution()
+                        .mark_skipped(task_execution.id, &reason)
+                        .await?;
+                    info!(
+                        "Task skipped: {} (workflow execution: {}, {})",
+                        task_execution.task_name, workflow_execution_id, reason
+                    );
+                    continue;
+                }
+
+                // A branch its decider did not select is skipped, along with
+                // everything that only hangs off unselected branches.
+                if let Some(reason) = self
+                    .branch_not_taken_reason(task_execution, workflow_execution, statuses)
+                    .await?
+                {
+                    self.dal
+                        .task_execution()
+                        .mark_skipped(task_execution.id, &reason)
+                        .await?;
+                    info!(
+                        "Task skipped: {} (workflow execution: {}, {})",
+                        task_execution.task_name, workflow_execution_id, reason
+                    );
+                    continue;
+                }
+
                 // All dependencies are in terminal states, now evaluate trigger rules
                 let trigger_rules_satisfied = self
                     .evaluate_trigger_rules(task_execution, statuses)
                     .await?;
 
                 if trigger_rules_satisfied {
+                    if self
+                        .awaits_approval(task_execution, workflow_execution)
+                        .await?
+                    {
+                        self.dal
+                            .task_execution()
+                            .park_for_approval(task_execution.id)
+                            .await?;
+                        info!(
+                            "Task awaiting approval: {} (workflow execution: {})",
+                            task_execution.task_name, workflow_execution_id
+                        );
+                        continue;
+                    }
+
                     // Mark ready in database - dispatch is handled separately by scheduler_loop
                     self.dal
                         .task_execution()
@@ -145,6 +214,266 @@ impl<'a> StateManager<'a> {
         Ok(all_satisfied)
     }
 
+    /// Whether `task_execution` requires approval and has none yet.
+    ///
+    /// Only the latest decision counts: an approval carries over to a
+    /// workflow retry, a rejection does not.
+    async fn awaits_approval(
+        &self,
+        task_execution: &TaskExecution,
+        workflow_execution: &WorkflowExecutionRecord,
+    ) -> Result<bool, ValidationError> {
+        let Some(workflow) = self.runtime.get_workflow(&workflow_execution.workflow_name) else {
+            return Ok(false);
+        };
+        let task_namespace = crate::task::TaskNamespace::from_string(&task_execution.task_name)
+            .map_err(ValidationError::InvalidTaskName)?;
+        if !workflow
+            .get_task(&task_namespace)
+            .is_ok_and(|task| task.requires_approval())
+        {
+            return Ok(false);
+        }
+
+        let approval = self
+            .dal
+            .task_execution()
+            .latest_approval(task_execution.id)
+            .await?;
+        Ok(!approval.is_some_and(|a| a.decision == ApprovalDecision::Approved))
+    }
+
+    /// Returns the skip reason when an upstream short-circuit applies to
+    /// `task_execution`, or `None` when it should proceed to trigger rules.
+    ///
+    /// Only tasks on the default `Always` rule are short-circuited. The walk
+    /// looks at direct dependencies: a Completed dependency that declares
+    /// [`Task::short_circuit`](crate::task::Task::short_circuit) and produced an
+    /// empty value under that key triggers the skip, and a dependency that was
+    /// itself short-circuited passes its reason down so the whole subtree is
+    /// skipped.
+    async fn short_circuit_reason(
+        &self,
+        task_execution: &TaskExecution,
+        workflow_execution: &WorkflowExecutionRecord,
+        statuses: &HashMap<String, String>,
+    ) -> Result<Option<String>, ValidationError> {
+        let trigger_rule: TriggerRule = serde_json::from_str(&task_execution.trigger_rules)
+            .map_err(|e| ValidationError::InvalidTriggerRule(e.to_string()))?;
+        if !matches!(trigger_rule, TriggerRule::Always) {
+            return Ok(None);
+        }
+
+        let workflow = match self.runtime.get_workflow(&workflow_execution.workflow_name) {
+            Some(wf) => wf,
+            None => return Ok(None),
+        };
+        let task_namespace = crate::task::TaskNamespace::from_string(&task_execution.task_name)
+            .map_err(ValidationError::InvalidTaskName)?;
+        // A short-circuit never crosses a soft (ordering-only) edge.
+        let dependencies = workflow
+            .get_data_dependencies(&task_namespace)
+            .map_err(|e| ValidationError::InvalidTaskName(e.to_string()))?;
+
+        // Skip reasons of Skipped dependencies are loaded lazily, at most once.
+        let mut skipped_reasons: Option<HashMap<String, Option<String>>> = None;
+
+        for dependency in dependencies.iter() {
+            let dep_name = dependency.to_string();
+            match statuses.get(&dep_name).map(String::as_str) {
+                Some("Completed") => {
+                    let Some(key) = workflow
+                        .get_task(dependency)
+                        .ok()
+                        .and_then(|task| task.short_circuit())
+                    else {
+                        continue;
+                    };
+                    let Some(value) = self
+                        .dependency_output(task_execution.workflow_execution_id, dependency, &key)
+                        .await?
+                    else {
+                        continue;
+                    };
+                    if is_empty_output(value.as_ref()) {
+                        return Ok(Some(format!(
+                            "{}: '{}' produced no '{}'",
+                            SHORT_CIRCUIT_REASON_PREFIX, dependency.task_id, key
+                        )));
+                    }
+                }
+                Some("Skipped") => {
+                    if skipped_reasons.is_none() {
+                        skipped_reasons = Some(
+                            self.skip_reasons(task_execution.workflow_execution_id)
+                                .await?,
+                        );
+                    }
+                    if let Some(Some(reason)) = skipped_reasons
+                        .as_ref()
+                        .and_then(|reasons| reasons.get(&dep_name))
+                    {
+                        if reason.starts_with(SHORT_CIRCUIT_REASON_PREFIX) {
+                            return Ok(Some(reason.clone()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the skip reason when `task_execution` lies on a branch that was
+    /// not taken, or `None` when it should proceed.
+    ///
+    /// A task declaring [`Task::branch`](crate::task::Task::branch) is skipped
+    /// when a Completed direct dependency declaring
+    /// [`Task::branch_key`](crate::task::Task::branch_key) selected a different
+    /// branch (or none). A task on the default `Always` rule whose data
+    /// dependencies were all skipped as not taken inherits that skip, so the
+    /// whole unselected subgraph is skipped while a join after the branches
+    /// still runs.
+    async fn branch_not_taken_reason(
+        &self,
+        task_execution: &TaskExecution,
+        workflow_execution: &WorkflowExecutionRecord,
+        statuses: &HashMap<String, String>,
+    ) -> Result<Option<String>, ValidationError> {
+        let workflow = match self.runtime.get_workflow(&workflow_execution.workflow_name) {
+            Some(wf) => wf,
+            None => return Ok(None),
+        };
+        let task_namespace = crate::task::TaskNamespace::from_string(&task_execution.task_name)
+            .map_err(ValidationError::InvalidTaskName)?;
+        let dependencies = workflow
+            .get_data_dependencies(&task_namespace)
+            .map_err(|e| ValidationError::InvalidTaskName(e.to_string()))?;
+        if dependencies.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(branch) = workflow
+            .get_task(&task_namespace)
+            .ok()
+            .and_then(|task| task.branch())
+        {
+            for dependency in dependencies.iter() {
+                if statuses.get(&dependency.to_string()).map(String::as_str) != Some("Completed") {
+                    continue;
+                }
+                let Some(key) = workflow
+                    .get_task(dependency)
+                    .ok()
+                    .and_then(|task| task.branch_key())
+                else {
+                    continue;
+                };
+                let Some(value) = self
+                    .dependency_output(task_execution.workflow_execution_id, dependency, &key)
+                    .await?
+                else {
+                    continue;
+                };
+                match value.as_ref().and_then(serde_json::Value::as_str) {
+                    Some(selected) if selected == branch => {}
+                    Some(selected) => {
+                        return Ok(Some(format!(
+                            "{}: '{}' selected '{}', not '{}'",
+                            BRANCH_NOT_TAKEN_REASON_PREFIX, dependency.task_id, selected, branch
+                        )));
+                    }
+                    None => {
+                        return Ok(Some(format!(
+                            "{}: '{}' selected no branch under '{}', not '{}'",
+                            BRANCH_NOT_TAKEN_REASON_PREFIX, dependency.task_id, key, branch
+                        )));
+                    }
+                }
+            }
+        }
+
+        let trigger_rule: TriggerRule = serde_json::from_str(&task_execution.trigger_rules)
+            .map_err(|e| ValidationError::InvalidTriggerRule(e.to_string()))?;
+        if !matches!(trigger_rule, TriggerRule::Always)
+            || !dependencies.iter().all(|dependency| {
+                statuses.get(&dependency.to_string()).map(String::as_str) == Some("Skipped")
+            })
+        {
+            return Ok(None);
+        }
+
+        let reasons = self
+            .skip_reasons(task_execution.workflow_execution_id)
+            .await?;
+        let mut inherited = None;
+        for dependency in dependencies.iter() {
+            match reasons.get(&dependency.to_string()) {
+                Some(Some(reason)) if reason.starts_with(BRANCH_NOT_TAKEN_REASON_PREFIX) => {
+                    inherited.get_or_insert_with(|| reason.clone());
+                }
+                _ => return Ok(None),
+            }
+        }
+        Ok(inherited)
+    }
+
+    /// Reads the value `dependency` left under `key` in its output context.
+    ///
+    /// The outer `None` means the dependency has no execution metadata, so the
+    /// check is skipped; the inner one that the key is absent or the
+    /// dependency recorded no context.
+    async fn dependency_output(
+        &self,
+        workflow_execution_id: UniversalUuid,
+        dependency: &crate::task::TaskNamespace,
+        key: &str,
+    ) -> Result<Option<Option<serde_json::Value>>, ValidationError> {
+        let metadata = match self
+            .dal
+            .task_execution_metadata()
+            .get_by_workflow_and_task(workflow_execution_id, dependency)
+            .await
+        {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                warn!("No output metadata for '{}': {}", dependency, e);
+                return Ok(None);
+            }
+        };
+        match metadata.context_id {
+            Some(context_id) => Ok(Some(
+                self.dal
+                    .context()
+                    .read::<serde_json::Value>(context_id)
+                    .await
+                    .map_err(|e| ValidationError::ContextEvaluationFailed {
+                        key: format!("context_read_error:{}", e),
+                    })?
+                    .get(key)
+                    .cloned(),
+            )),
+            None => Ok(Some(None)),
+        }
+    }
+
+    /// Skip reasons of the execution's Skipped tasks, keyed by task name.
+    async fn skip_reasons(
+        &self,
+        workflow_execution_id: UniversalUuid,
+    ) -> Result<HashMap<String, Option<String>>, ValidationError> {
+        Ok(self
+            .dal
+            .task_execution()
+            .get_all_tasks_for_workflow(workflow_execution_id)
+            .await?
+            .into_iter()
+            .filter(|t| t.status == "Skipped")
+            .map(|t| (t.task_name, t.error_details))
+            .collect())
+    }
+
     /// Evaluates trigger rules for a task based on its configuration.
     pub async fn evaluate_trigger_rules(
         &self,
@@ -344,3 +673,26 @@ impl<'a> StateManager<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn empty_output_values() {
+        assert!(is_empty_output(None));
+        for v in [json!(null), json!(false), json!(""), json!([]), json!({})] {
+            assert!(is_empty_output(Some(&v)), "{v} should be empty");
+        }
+        for v in [
+            json!(0),
+            json!(true),
+            json!("x"),
+            json!([1]),
+            json!({"a": 1}),
+        ] {
+            assert!(!is_empty_output(Some(&v)), "{v} should not be empty");
+        }
+    }
+}
@@ -21,6 +21,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::context_manager::ContextManager;
+use crate::error::ValidationError;
+use crate::Context;
+
 /// Trigger rule definitions for conditional task execution.
 ///
 /// Trigger rules determine when a task should be executed based on various conditions.
@@ -94,6 +98,41 @@ pub enum TriggerRule {
     None { conditions: Vec<TriggerCondition> },
 }
 
+impl TriggerRule {
+    /// Evaluates a rule made only of context conditions against `context`,
+    /// as a task's `loop_until` condition is.
+    ///
+    /// Task status conditions cannot be answered from a context and are
+    /// rejected with [`ValidationError::InvalidTriggerRule`].
+    pub fn evaluate_on_context(
+        &self,
+        context: &Context<serde_json::Value>,
+    ) -> Result<bool, ValidationError> {
+        let evaluate = |conditions: &[TriggerCondition]| {
+            conditions
+                .iter()
+                .map(|condition| match condition {
+                    TriggerCondition::ContextValue {
+                        key,
+                        operator,
+                        value,
+                    } => ContextManager::evaluate_context_condition(context, key, operator, value),
+                    other => Err(ValidationError::InvalidTriggerRule(format!(
+                        "{:?} cannot be evaluated against a context",
+                        other
+                    ))),
+                })
+                .collect::<Result<Vec<bool>, _>>()
+        };
+        match self {
+            TriggerRule::Always => Ok(true),
+            TriggerRule::All { conditions } => Ok(evaluate(conditions)?.into_iter().all(|c| c)),
+            TriggerRule::Any { conditions } => Ok(evaluate(conditions)?.into_iter().any(|c| c)),
+            TriggerRule::None { conditions } => Ok(!evaluate(conditions)?.into_iter().any(|c| c)),
+        }
+    }
+}
+
 /// Individual conditions that can be evaluated for trigger rules.
 ///
 /// Conditions are the building blocks of trigger rules, allowing tasks to be
@@ -293,6 +332,39 @@ mod tests {
         }
     }
 
+    // ── Evaluation against a context ─────────────────────────────────
+
+    #[test]
+    fn evaluate_on_context_tests_context_conditions() {
+        let mut context = Context::new();
+        context.insert("converged", json!(false)).unwrap();
+        let rule = TriggerRule::All {
+            conditions: vec![TriggerCondition::ContextValue {
+                key: "converged".to_string(),
+                operator: ValueOperator::Equals,
+                value: json!(true),
+            }],
+        };
+        assert!(!rule.evaluate_on_context(&context).unwrap());
+
+        context.update("converged", json!(true)).unwrap();
+        assert!(rule.evaluate_on_context(&context).unwrap());
+        assert!(TriggerRule::Always.evaluate_on_context(&context).unwrap());
+    }
+
+    #[test]
+    fn evaluate_on_context_rejects_task_conditions() {
+        let rule = TriggerRule::Any {
+            conditions: vec![TriggerCondition::TaskSuccess {
+                task_name: "task1".to_string(),
+            }],
+        };
+        assert!(matches!(
+            rule.evaluate_on_context(&Context::new()),
+            Err(ValidationError::InvalidTriggerRule(_))
+        ));
+    }
+
     // ── TriggerCondition serialization ───────────────────────────────
 
     #[test]
@@ -31,6 +31,7 @@
 use std::sync::Arc;
 use tracing::{debug, error};
 
+use cloacina_workflow::context::TASK_CONFIG_KEY;
 use cloacina_workflow::secret::SecretResolver;
 
 use crate::context::Context;
@@ -73,10 +74,84 @@ impl TaskContextBuilder {
     ///   and smart-merge (latest-wins for primitives, recursive for objects,
     ///   dedup-concat for arrays). A dependency context that fails to parse is
     ///   a hard `ContextLoadFailed` (COR-11) — never a silent partial context.
+    ///
+    /// Either way the task's rendered declarative configuration, if it has
+    /// one, is set under [`TASK_CONFIG_KEY`], and the execution's params, if
+    /// it has any, are attached read-only (`context.params()`).
     pub async fn build(
         &self,
         claimed_task: &ClaimedTask,
         dependencies: &[TaskNamespace],
+    ) -> Result<Context<serde_json::Value>, ExecutorError> {
+        let mut context = self.build_merged(claimed_task, dependencies).await?;
+        self.apply_task_config(claimed_task, &mut context).await?;
+        self.apply_params(claimed_task, &mut context).await?;
+        Ok(context)
+    }
+
+    /// Attach the execution's params. They live outside the context data,
+    /// so nothing a task writes can change what the next task reads.
+    async fn apply_params(
+        &self,
+        claimed_task: &ClaimedTask,
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), ExecutorError> {
+        let params = self
+            .dal
+            .workflow_execution()
+            .get_params(claimed_task.workflow_execution_id)
+            .await
+            .map_err(|e| {
+                ExecutorError::ContextLoadFailed(format!(
+                    "execution params load failed for '{}': {}",
+                    claimed_task.task_name, e
+                ))
+            })?;
+        if !params.is_empty() {
+            context.set_params(params);
+        }
+        Ok(())
+    }
+
+    /// Set the task's rendered configuration (stored on its execution row by
+    /// the planner) under [`TASK_CONFIG_KEY`], replacing any stale value.
+    async fn apply_task_config(
+        &self,
+        claimed_task: &ClaimedTask,
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), ExecutorError> {
+        let _ = context.remove(TASK_CONFIG_KEY);
+
+        let task_execution = self
+            .dal
+            .task_execution()
+            .get_by_id(claimed_task.task_execution_id)
+            .await
+            .map_err(|e| {
+                ExecutorError::ContextLoadFailed(format!(
+                    "task configuration load failed for '{}': {}",
+                    claimed_task.task_name, e
+                ))
+            })?;
+        let config: serde_json::Value = serde_json::from_str(&task_execution.task_configuration)
+            .map_err(|e| {
+                ExecutorError::ContextLoadFailed(format!(
+                    "task configuration JSON parse failed for '{}': {}",
+                    claimed_task.task_name, e
+                ))
+            })?;
+
+        let empty = config.is_null() || config.as_object().is_some_and(|o| o.is_empty());
+        if !empty {
+            let _ = context.insert(TASK_CONFIG_KEY, config);
+        }
+        Ok(())
+    }
+
+    async fn build_merged(
+        &self,
+        claimed_task: &ClaimedTask,
+        dependencies: &[TaskNamespace],
     ) -> Result<Context<serde_json::Value>, ExecutorError> {
         debug!(
             "Building context for task '{}' with {} dependencies: {:?}",
@@ -110,13 +185,17 @@ impl TaskContextBuilder {
                         .read::<serde_json::Value>(context_id)
                         .await
                     {
-                        for (key, value) in initial_context.data() {
-                            let _ = context.insert(key, value.clone());
-                        }
                         debug!(
                             "Loaded initial workflow context with {} keys",
                             initial_context.data().len()
                         );
+                        let (data, bytes) = initial_context.into_parts();
+                        for (key, value) in data {
+                            let _ = context.insert(key, value);
+                        }
+                        for (key, raw) in bytes {
+                            let _ = context.insert_bytes(key, raw);
+                        }
                     }
                 }
             }
@@ -172,17 +251,17 @@ impl TaskContextBuilder {
                     dep_context.data().len(),
                     dep_context.data().keys().collect::<Vec<_>>()
                 );
-                for (key, value) in dep_context.data() {
-                    if let Some(existing_value) = context.get(key) {
-                        let merged_value = Self::merge_context_values(existing_value, value);
-                        if context.update(key, merged_value).is_err() {
-                            metrics::counter!(
-                                "cloacina_context_merge_failures_total",
-                                "kind" => "merge",
-                            )
-                            .increment(1);
+                let (data, bytes) = dep_context.into_parts();
+                for (key, value) in data {
+                    let merged = match context.get(&key) {
+                        Some(existing_value) => {
+                            let merged_value = Self::merge_context_values(existing_value, &value);
+                            context.update(key, merged_value)
                         }
-                    } else if context.insert(key, value.clone()).is_err() {
+                        None if context.binary().contains_key(&key) => context.update(key, value),
+                        None => context.insert(key, value),
+                    };
+                    if merged.is_err() {
                         metrics::counter!(
                             "cloacina_context_merge_failures_total",
                             "kind" => "merge",
@@ -190,6 +269,11 @@ impl TaskContextBuilder {
                         .increment(1);
                     }
                 }
+                // Binary values are replaced, never merged.
+                for (key, raw) in bytes {
+                    context.remove(&key);
+                    let _ = context.insert_bytes(key, raw);
+                }
             }
         }
 
@@ -38,7 +38,7 @@ use crate::context::Context;
 use crate::dal::DAL;
 use crate::database::universal_types::UniversalUuid;
 use crate::dispatcher::{ExecutionResult, TaskReadyEvent};
-use crate::error::ExecutorError;
+use crate::error::{ErrorCategory, ExecutorError, TaskError, TaskErrorPayload, TASK_TIMEOUT_CODE};
 use crate::executor::types::ClaimedTask;
 use crate::retry::{RetryCondition, RetryPolicy};
 
@@ -79,7 +79,9 @@ impl TaskResultHandler {
     /// - `claimed_task` is the identity the helpers below operate on.
     /// - `outcome` is `Ok(produced_context)` on success or `Err(executor_error)`
     ///   on failure.
-    /// - `retry_policy` is consulted only on the error branch.
+    /// - `retry_policy` is consulted only on the error branch, with the
+    ///   execution's [`ExecutionOptions`](crate::models::workflow_execution::ExecutionOptions)
+    ///   overrides applied.
     /// - `duration` is the wall-clock time the task closure consumed; used
     ///   for logs and embedded into the returned `ExecutionResult`.
     pub async fn handle_outcome(
@@ -120,13 +122,30 @@ impl TaskResultHandler {
                 }
             }
             Err(error) => {
+                // Retry overrides the execution was submitted with.
+                let retry_policy = match self
+                    .dal
+                    .workflow_execution()
+                    .get_options(claimed_task.workflow_execution_id)
+                    .await
+                {
+                    Ok(options) => options.apply_to_retry_policy(retry_policy),
+                    Err(e) => {
+                        warn!(
+                            task_id = %event.task_execution_id,
+                            error = %e,
+                            "Failed to load execution options; using the task's retry policy"
+                        );
+                        retry_policy.clone()
+                    }
+                };
                 let should_retry = self
-                    .should_retry_task(claimed_task, &error, retry_policy)
+                    .should_retry_task(claimed_task, &error, &retry_policy)
                     .await
                     .unwrap_or(false);
 
                 if should_retry {
-                    if let Err(e) = self.schedule_task_retry(claimed_task, retry_policy).await {
+                    if let Err(e) = self.schedule_task_retry(claimed_task, &retry_policy).await {
                         warn!(
                             task_id = %event.task_execution_id,
                             error = %e,
@@ -137,11 +156,18 @@ impl TaskResultHandler {
                     ExecutionResult::retry(event.task_execution_id, error.to_string(), duration)
                 } else {
                     self.total_failed.fetch_add(1, Ordering::SeqCst);
-                    // Mark failed in DB — executor owns all state transitions
+                    // Mark failed in DB — executor owns all state transitions.
+                    // A structured payload is stored verbatim next to the message.
+                    let payload = failure_payload(&error);
                     let _ = self
                         .dal
                         .task_execution()
-                        .mark_failed(event.task_execution_id, &error.to_string(), self.runner_id)
+                        .mark_failed_with_payload(
+                            event.task_execution_id,
+                            &error.to_string(),
+                            payload.as_ref(),
+                            self.runner_id,
+                        )
                         .await;
                     ExecutionResult::failure(event.task_execution_id, error.to_string(), duration)
                 }
@@ -200,10 +226,13 @@ impl TaskResultHandler {
     async fn save_task_context(
         &self,
         claimed_task: &ClaimedTask,
-        context: Context<serde_json::Value>,
+        mut context: Context<serde_json::Value>,
     ) -> Result<(), ExecutorError> {
         use crate::models::task_execution_metadata::NewTaskExecutionMetadata;
 
+        // The task's own config is input only; it must not flow downstream.
+        let _ = context.remove(cloacina_workflow::context::TASK_CONFIG_KEY);
+
         let context_id = self.dal.context().create(&context).await?;
 
         let task_metadata_record = NewTaskExecutionMetadata {
@@ -283,6 +312,9 @@ impl TaskResultHandler {
             ExecutorError::Database(_) => true,
             ExecutorError::ConnectionPool(_) => true,
             ExecutorError::TaskNotFound(_) => false,
+            ExecutorError::TaskExecution(TaskError::Structured { payload, .. }) => {
+                payload.category == ErrorCategory::Transient
+            }
             ExecutorError::TaskExecution(task_error) => {
                 let error_msg = task_error.to_string().to_lowercase();
                 error_msg.contains("timeout")
@@ -325,6 +357,20 @@ impl TaskResultHandler {
     }
 }
 
+/// The payload stored with a terminal failure: the task's own structured
+/// payload, or a `TASK_TIMEOUT` payload so timeouts stay distinguishable from
+/// task errors in the task results.
+fn failure_payload(error: &ExecutorError) -> Option<TaskErrorPayload> {
+    match error {
+        ExecutorError::TaskExecution(task_error) => task_error.payload().cloned(),
+        ExecutorError::TaskTimeout => Some(
+            TaskErrorPayload::new(TASK_TIMEOUT_CODE, error.to_string())
+                .with_category(ErrorCategory::Transient),
+        ),
+        _ => None,
+    }
+}
+
 #[cfg(all(test, feature = "sqlite"))]
 mod is_transient_tests {
     //! Moved from `thread_task_executor.rs` in T-0630 (the classification
@@ -400,4 +446,30 @@ mod is_transient_tests {
         };
         assert!(handler().is_transient_error(&ExecutorError::TaskExecution(task_err)));
     }
+
+    #[test]
+    fn test_is_transient_structured_error_uses_category() {
+        let transient = crate::error::TaskError::structured(
+            "test",
+            crate::error::TaskErrorPayload::new("UPSTREAM_DOWN", "upstream returned 503")
+                .with_category(ErrorCategory::Transient),
+        );
+        assert!(handler().is_transient_error(&ExecutorError::TaskExecution(transient)));
+
+        let permanent = crate::error::TaskError::structured(
+            "test",
+            crate::error::TaskErrorPayload::new("BAD_ROW", "connection field missing")
+                .with_category(ErrorCategory::Validation),
+        );
+        assert!(!handler().is_transient_error(&ExecutorError::TaskExecution(permanent)));
+    }
+
+    #[test]
+    fn test_failure_payload_marks_timeouts() {
+        let payload = failure_payload(&ExecutorError::TaskTimeout).unwrap();
+        assert_eq!(payload.code, TASK_TIMEOUT_CODE);
+        assert_eq!(payload.category, ErrorCategory::Transient);
+
+        assert!(failure_payload(&ExecutorError::TaskNotFound("missing".to_string())).is_none());
+    }
 }
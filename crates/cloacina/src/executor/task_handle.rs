@@ -19,7 +19,9 @@
 //! `TaskHandle` provides execution control capabilities to tasks that opt in
 //! by accepting it as a second parameter. The primary feature is `defer_until`,
 //! which allows a task to release its concurrency slot while polling an
-//! external condition.
+//! external condition. Tasks of an execution with a deadline can also read
+//! the time left before it with `remaining_time`, and any task can store
+//! named output files for its execution with `store_artifact`.
 //!
 //! # Example
 //!
@@ -43,12 +45,14 @@ use std::cell::RefCell;
 use std::future::Future;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use tracing::{debug, warn};
 
 use super::slot_token::SlotToken;
 use crate::dal::DAL;
 use crate::database::universal_types::UniversalUuid;
 use crate::error::ExecutorError;
+use crate::models::workflow_execution::ExecutionArtifact;
 
 tokio::task_local! {
     /// Task-local storage for passing a `TaskHandle` to macro-generated task code.
@@ -112,6 +116,7 @@ pub struct TaskHandle {
     task_execution_id: UniversalUuid,
     dal: Option<DAL>,
     cancel_rx: Option<tokio::sync::watch::Receiver<bool>>,
+    deadline: Option<DateTime<Utc>>,
 }
 
 impl TaskHandle {
@@ -124,6 +129,7 @@ impl TaskHandle {
             task_execution_id,
             dal: None,
             cancel_rx: None,
+            deadline: None,
         }
     }
 
@@ -143,9 +149,16 @@ impl TaskHandle {
             task_execution_id,
             dal: Some(dal),
             cancel_rx: Some(cancel_rx),
+            deadline: None,
         }
     }
 
+    /// Sets the deadline of the task's workflow execution, if it has one.
+    pub(crate) fn with_deadline(mut self, deadline: Option<DateTime<Utc>>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
     /// Release the concurrency slot while polling an external condition.
     ///
     /// This method:
@@ -234,11 +247,51 @@ impl TaskHandle {
         Ok(())
     }
 
+    /// Store `bytes` as run artifact `name` (`report.pdf`) of this task's
+    /// workflow execution, replacing an artifact of the same name; see
+    /// [`crate::artifacts`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name or content type is invalid or the bytes
+    /// cannot be stored.
+    pub async fn store_artifact(
+        &self,
+        name: &str,
+        bytes: impl Into<Vec<u8>>,
+        content_type: &str,
+    ) -> Result<ExecutionArtifact, ExecutorError> {
+        let dal = self.dal.as_ref().ok_or_else(|| {
+            ExecutorError::ConnectionPool(
+                "task handle has no database to store artifacts in".into(),
+            )
+        })?;
+        Ok(dal
+            .execution_artifact()
+            .store_artifact(self.task_execution_id, name, content_type, bytes.into())
+            .await?)
+    }
+
     /// Returns the task execution ID associated with this handle.
     pub fn task_execution_id(&self) -> UniversalUuid {
         self.task_execution_id
     }
 
+    /// Returns the deadline of the task's workflow execution, if it was
+    /// submitted with one.
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        self.deadline
+    }
+
+    /// Returns how long the task's workflow execution has left before its
+    /// deadline, or `None` when it has no deadline. Zero once the deadline
+    /// has passed; a long-running task can check this between steps and
+    /// stop early rather than run into the deadline.
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| (deadline - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+    }
+
     /// Returns whether the handle currently holds a concurrency slot.
     pub fn is_slot_held(&self) -> bool {
         self.slot_token.is_held()
@@ -455,6 +508,7 @@ mod tests {
             task_execution_id: UniversalUuid::new_v4(),
             dal: None,
             cancel_rx: Some(rx),
+            deadline: None,
         };
 
         assert!(!handle.is_cancelled(), "no signal → not cancelled");
@@ -478,6 +532,7 @@ mod tests {
             task_execution_id: UniversalUuid::new_v4(),
             dal: None,
             cancel_rx: Some(rx),
+            deadline: None,
         };
 
         // Before the signal, `cancelled()` should not resolve quickly.
@@ -509,6 +564,7 @@ mod tests {
             task_execution_id: UniversalUuid::new_v4(),
             dal: None,
             cancel_rx: Some(rx),
+            deadline: None,
         };
 
         drop(tx); // all senders gone without firing true
@@ -553,4 +609,19 @@ mod tests {
         assert_eq!(rh.task_execution_id(), original_id);
         assert!(rh.is_slot_held());
     }
+
+    #[tokio::test]
+    async fn test_remaining_time_counts_down_to_zero() {
+        let semaphore = Arc::new(Semaphore::new(1));
+        let handle = make_handle(&semaphore);
+        assert_eq!(handle.remaining_time(), None);
+
+        let handle = handle.with_deadline(Some(Utc::now() + chrono::Duration::minutes(10)));
+        let remaining = handle.remaining_time().expect("deadline is set");
+        assert!(remaining > Duration::from_secs(9 * 60));
+        assert!(remaining <= Duration::from_secs(10 * 60));
+
+        let handle = handle.with_deadline(Some(Utc::now() - chrono::Duration::seconds(1)));
+        assert_eq!(handle.remaining_time(), Some(Duration::ZERO));
+    }
 }
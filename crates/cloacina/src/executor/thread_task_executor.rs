@@ -21,6 +21,17 @@
 //!
 //! The executor is responsible for:
 //! - Executing tasks with proper timeout handling
+//! - Repeating the body of a looping task until its `loop_until` condition holds
+//! - Checking each successful task's output context against its declared
+//!   `produces` outputs
+//! - Showing each task only the context keys it `reads` and moving the
+//!   writes of `scoped` tasks under their ID
+//! - Recording the context keys each successful attempt changed, when
+//!   lineage tracking is enabled
+//! - Redacting the secret values a task resolved from its output and error
+//! - Applying per-workload-class concurrency limits and placing CPU-bound
+//!   tasks on the blocking thread pool
+//! - Limiting tasks that share a named resource to the capacity of its pool
 //! - Managing task retries and error handling
 //! - Maintaining task execution state
 //! - Handling task dependencies and context management
@@ -31,6 +42,8 @@
 //! with a dispatcher to receive task events directly. The dispatcher routes `TaskReadyEvent`s
 //! to the executor based on routing rules.
 
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
@@ -39,15 +52,26 @@ use tokio::sync::Semaphore;
 use super::slot_token::SlotToken;
 use super::task_handle::{with_task_handle, TaskHandle};
 use super::types::{ClaimedTask, ExecutorConfig};
+use crate::context_lineage::{diff_contexts, lineage_view};
+use crate::context_scope::TaskScope;
 use crate::dal::DAL;
 use crate::database::universal_types::UniversalUuid;
 use crate::dispatcher::{
     DispatchError, ExecutionResult, ExecutorMetrics, TaskExecutor, TaskReadyEvent,
 };
-use crate::error::ExecutorError;
+use crate::error::{
+    ExecutorError, TaskError, TaskErrorPayload, DEADLINE_EXCEEDED_CODE, LOOP_LIMIT_EXCEEDED_CODE,
+};
+use crate::execution_planner::TriggerRule;
+use crate::memory::{self, MemoryAccounting};
+use crate::models::execution_event::{ExecutionEventType, NewExecutionEvent};
+use crate::outputs::check_task_outputs;
+use crate::security::SecretRedactor;
+use crate::task::WorkloadClass;
 use crate::Runtime;
 use crate::{parse_namespace, Context, Database, Task, TaskRegistry};
 use async_trait::async_trait;
+use cloacina_workflow::context_access::ContextAccessLog;
 
 /// Bounded reason value for `cloacina_tasks_total{status="failed", reason=...}`.
 ///
@@ -79,6 +103,30 @@ fn failure_reason(err: &ExecutorError) -> &'static str {
     }
 }
 
+/// Why a task may not start under its execution's `deadline`: the deadline
+/// has passed, or less time is left than the task's `expected` duration.
+/// `None` when the task may start.
+fn deadline_refusal(
+    deadline: Option<chrono::DateTime<chrono::Utc>>,
+    expected: Option<std::time::Duration>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<String> {
+    let deadline = deadline?;
+    let remaining = (deadline - now).to_std().unwrap_or_default();
+    if remaining.is_zero() {
+        return Some(format!("Execution deadline {} has passed", deadline));
+    }
+    match expected {
+        Some(expected) if expected > remaining => Some(format!(
+            "Task expects to run {}s but only {}s are left before the execution deadline {}",
+            expected.as_secs_f64(),
+            remaining.as_secs_f64(),
+            deadline
+        )),
+        _ => None,
+    }
+}
+
 /// ThreadTaskExecutor is a thread-based implementation of task execution.
 ///
 /// This executor runs tasks in the current thread/process and manages:
@@ -110,6 +158,10 @@ pub struct ThreadTaskExecutor {
     config: ExecutorConfig,
     /// Semaphore controlling concurrent task execution slots
     semaphore: Arc<Semaphore>,
+    /// Per-class semaphores for the workload classes the config caps
+    workload_semaphores: Arc<HashMap<WorkloadClass, Arc<Semaphore>>>,
+    /// Per-pool semaphores for the resource pools the config defines
+    resource_semaphores: Arc<HashMap<String, Arc<Semaphore>>>,
     /// Metrics: total tasks executed. `Arc` so clones — and the shared
     /// [`crate::executor::TaskResultHandler`] (T-0630) — see the same counter.
     total_executed: Arc<AtomicU64>,
@@ -164,6 +216,16 @@ impl ThreadTaskExecutor {
         } else {
             None
         };
+        let workload_semaphores = config
+            .workload_limits
+            .iter()
+            .map(|(class, limit)| (*class, Arc::new(Semaphore::new(*limit))))
+            .collect();
+        let resource_semaphores = config
+            .resource_pools
+            .iter()
+            .map(|(name, capacity)| (name.clone(), Arc::new(Semaphore::new(*capacity))))
+            .collect();
         let result_handler = crate::executor::TaskResultHandler::new(
             dal.clone(),
             total_executed.clone(),
@@ -179,6 +241,8 @@ impl ThreadTaskExecutor {
             instance_id,
             config,
             semaphore: Arc::new(Semaphore::new(max_concurrent)),
+            workload_semaphores: Arc::new(workload_semaphores),
+            resource_semaphores: Arc::new(resource_semaphores),
             total_executed,
             total_failed,
             result_handler,
@@ -258,20 +322,47 @@ impl ThreadTaskExecutor {
         crate::executor::TaskContextBuilder::merge_context_values(existing, new)
     }
 
-    /// Executes a task with timeout protection.
+    /// Executes a task with timeout protection, under its memory limit if it
+    /// declares one (see [`crate::memory`]).
     ///
     /// # Arguments
     /// * `task` - The task implementation to execute
     /// * `context` - The execution context
+    /// * `timeout` - How long the task may run
     ///
     /// # Returns
     /// Result containing either the updated context or an error
     async fn execute_with_timeout(
         &self,
-        task: &dyn Task,
+        task: &Arc<dyn Task>,
         context: Context<serde_json::Value>,
+        timeout: std::time::Duration,
     ) -> Result<Context<serde_json::Value>, ExecutorError> {
-        match tokio::time::timeout(self.config.task_timeout, task.execute(context)).await {
+        let guarded = {
+            let task = Arc::clone(task);
+            async move {
+                memory::guard_task(
+                    task.id(),
+                    task.memory_limit(),
+                    MemoryAccounting::Allocations,
+                    task.execute(context),
+                )
+                .await
+            }
+        };
+        // CPU-bound tasks run on the blocking pool so they never stall the
+        // async workers. Tasks holding a `TaskHandle` stay on the async
+        // runtime: the handle lives in task-local storage of this future.
+        let blocking =
+            task.workload_class().is_some_and(|c| c.runs_blocking()) && !task.requires_handle();
+        let execution = async move {
+            if blocking {
+                run_on_blocking_pool(task.id(), guarded).await
+            } else {
+                guarded.await
+            }
+        };
+        match tokio::time::timeout(timeout, execution).await {
             Ok(result) => result.map_err(ExecutorError::TaskExecution),
             Err(_) => Err(ExecutorError::TaskTimeout),
         }
@@ -285,8 +376,9 @@ impl ThreadTaskExecutor {
     /// layered on top for tasks that need graceful cleanup.
     async fn execute_with_cancellation(
         &self,
-        task: &dyn Task,
+        task: &Arc<dyn Task>,
         context: Context<serde_json::Value>,
+        timeout: std::time::Duration,
         mut cancel_rx: tokio::sync::watch::Receiver<bool>,
     ) -> Result<Context<serde_json::Value>, ExecutorError> {
         // Convert the watch signal into a bool *before* entering the select!
@@ -303,7 +395,7 @@ impl ThreadTaskExecutor {
         // because its arm stays `Pending` while the cancel arm is ready.
         tokio::select! {
             biased;
-            r = self.execute_with_timeout(task, context) => r,
+            r = self.execute_with_timeout(task, context, timeout) => r,
             fired = wait_cancelled => {
                 if fired {
                     Err(ExecutorError::ClaimLost)
@@ -317,6 +409,85 @@ impl ThreadTaskExecutor {
         }
     }
 
+    /// Runs the task body, repeating it on its own output context while the
+    /// task's [`loop_until`](Task::loop_until) condition does not hold.
+    ///
+    /// Tasks without a loop run once, as [`execute_with_cancellation`] does.
+    /// Each iteration gets the full `timeout` and is recorded as a
+    /// `task_iterated` event; an attempt that runs `max_iterations` times
+    /// without meeting the condition fails with `LOOP_LIMIT_EXCEEDED`.
+    async fn execute_iterations(
+        &self,
+        task: &Arc<dyn Task>,
+        event: &TaskReadyEvent,
+        context: Context<serde_json::Value>,
+        timeout: std::time::Duration,
+        cancel_rx: tokio::sync::watch::Receiver<bool>,
+    ) -> Result<Context<serde_json::Value>, ExecutorError> {
+        let Some(rule) = task.loop_until() else {
+            return self
+                .execute_with_cancellation(task, context, timeout, cancel_rx)
+                .await;
+        };
+        let condition: TriggerRule =
+            serde_json::from_value(rule).map_err(|e| TaskError::ValidationFailed {
+                message: format!("Invalid loop_until condition: {}", e),
+            })?;
+        let max_iterations = task.max_iterations().unwrap_or(1);
+
+        let mut context = context;
+        for iteration in 1..=max_iterations {
+            context = self
+                .execute_with_cancellation(task, context, timeout, cancel_rx.clone())
+                .await?;
+            let condition_met = condition.evaluate_on_context(&context)?;
+
+            // Best-effort: the iteration record is diagnostic and never
+            // changes the task's outcome.
+            let record = NewExecutionEvent::task_event(
+                event.workflow_execution_id,
+                event.task_execution_id,
+                ExecutionEventType::TaskIterated,
+                Some(
+                    serde_json::json!({
+                        "iteration": iteration,
+                        "condition_met": condition_met,
+                    })
+                    .to_string(),
+                ),
+                None,
+            );
+            if let Err(e) = self.dal.execution_event().create(record).await {
+                tracing::warn!(
+                    task_id = %event.task_execution_id,
+                    error = %e,
+                    "Failed to record loop iteration"
+                );
+            }
+
+            if condition_met {
+                return Ok(context);
+            }
+            tracing::debug!(
+                task_name = %event.task_name,
+                iteration,
+                max_iterations,
+                "loop_until condition unmet; iterating"
+            );
+        }
+
+        let message = format!(
+            "loop_until condition still unmet after {} iterations",
+            max_iterations
+        );
+        Err(TaskError::structured(
+            task.id(),
+            TaskErrorPayload::new(LOOP_LIMIT_EXCEEDED_CODE, message)
+                .with_details(serde_json::json!({ "iterations": max_iterations })),
+        )
+        .into())
+    }
+
     // CLOACI-T-0630: the post-execution helpers that used to live here —
     // `save_task_context`, `complete_task_transaction`, `should_retry_task`,
     // `is_transient_error`, `schedule_task_retry` — moved to
@@ -325,6 +496,40 @@ impl ThreadTaskExecutor {
     // `result_handler.rs` for the (verbatim) implementations.
 }
 
+/// Drives `fut` to completion on tokio's blocking thread pool.
+///
+/// Dropping the returned future (a timeout or lost claim) signals the
+/// blocking thread, which drops `fut` at its next await point; a body that
+/// never yields keeps the thread until it returns. A panic in `fut` is
+/// resumed on the caller, as it would be had `fut` run inline.
+async fn run_on_blocking_pool<T, F>(task_id: &str, fut: F) -> Result<T, TaskError>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T, TaskError>> + Send + 'static,
+{
+    let (_abandon_tx, abandon_rx) = tokio::sync::oneshot::channel::<()>();
+    let handle = tokio::runtime::Handle::current();
+    let worker = tokio::task::spawn_blocking(move || {
+        handle.block_on(async move {
+            tokio::select! {
+                biased;
+                result = fut => Some(result),
+                _ = abandon_rx => None,
+            }
+        })
+    });
+    match worker.await {
+        Ok(Some(result)) => result,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        // `None` only comes back once this future was dropped; a cancelled
+        // worker means the runtime is shutting down.
+        Ok(None) | Err(_) => Err(TaskError::Unknown {
+            task_id: task_id.to_string(),
+            message: "blocking worker was cancelled".to_string(),
+        }),
+    }
+}
+
 impl Clone for ThreadTaskExecutor {
     fn clone(&self) -> Self {
         Self {
@@ -336,6 +541,8 @@ impl Clone for ThreadTaskExecutor {
             config: self.config.clone(),
             // Shared semaphore — clones coordinate on the same concurrency limit
             semaphore: Arc::clone(&self.semaphore),
+            workload_semaphores: Arc::clone(&self.workload_semaphores),
+            resource_semaphores: Arc::clone(&self.resource_semaphores),
             // Counters are now Arc<AtomicU64> so clones share the same
             // running totals; T-0630 also shares them with the
             // result_handler.
@@ -421,6 +628,115 @@ impl TaskExecutor for ThreadTaskExecutor {
             );
         }
 
+        // Compute runner_id for claim-guarded state transitions
+        let claim_runner_id = if self.config.enable_claiming {
+            Some(self.instance_id)
+        } else {
+            None
+        };
+
+        // Convert TaskReadyEvent to ClaimedTask format
+        let claimed_task = ClaimedTask {
+            task_execution_id: event.task_execution_id,
+            workflow_execution_id: event.workflow_execution_id,
+            task_name: event.task_name.clone(),
+            attempt: event.attempt,
+        };
+
+        // Resolve task from global registry
+        let namespace = match parse_namespace(&claimed_task.task_name) {
+            Ok(ns) => ns,
+            Err(e) => {
+                self.total_failed.fetch_add(1, Ordering::SeqCst);
+                let error_msg = format!("Invalid namespace: {}", e);
+                let _ = self
+                    .dal
+                    .task_execution()
+                    .mark_failed(event.task_execution_id, &error_msg, claim_runner_id)
+                    .await;
+                return Ok(ExecutionResult::failure(
+                    event.task_execution_id,
+                    error_msg,
+                    start.elapsed(),
+                ));
+            }
+        };
+
+        let task = match self.runtime.get_task(&namespace) {
+            Some(t) => t,
+            None => {
+                self.total_failed.fetch_add(1, Ordering::SeqCst);
+                let error_msg = format!("Task not found: {}", claimed_task.task_name);
+                let _ = self
+                    .dal
+                    .task_execution()
+                    .mark_failed(event.task_execution_id, &error_msg, claim_runner_id)
+                    .await;
+                return Ok(ExecutionResult::failure(
+                    event.task_execution_id,
+                    error_msg,
+                    start.elapsed(),
+                ));
+            }
+        };
+
+        // The execution may override the task timeout, which in turn
+        // overrides the configured default. Best-effort: the task's own
+        // timeout applies, and no deadline, when the options cannot be read.
+        let task_default = task.timeout().unwrap_or(self.config.task_timeout);
+        let (timeout, deadline) = match self
+            .dal
+            .workflow_execution()
+            .get_options(event.workflow_execution_id)
+            .await
+        {
+            Ok(options) => (
+                options.task_timeout.unwrap_or(task_default),
+                options.deadline,
+            ),
+            Err(e) => {
+                tracing::warn!(
+                    task_id = %event.task_execution_id,
+                    error = %e,
+                    "Failed to load execution options; using the task's default timeout"
+                );
+                (task_default, None)
+            }
+        };
+
+        // A task that cannot finish before the execution's deadline is not
+        // started: the whole execution fails, since its remaining tasks
+        // would miss the deadline too.
+        if let Some(error_msg) =
+            deadline_refusal(deadline, task.expected_duration(), chrono::Utc::now())
+        {
+            self.total_failed.fetch_add(1, Ordering::SeqCst);
+            let payload = TaskErrorPayload::new(DEADLINE_EXCEEDED_CODE, error_msg.clone());
+            if let Err(e) = self
+                .dal
+                .task_execution()
+                .fail_unfinished(event.workflow_execution_id, &error_msg, &payload)
+                .await
+            {
+                tracing::warn!(
+                    task_id = %event.task_execution_id,
+                    error = %e,
+                    "Failed to fail the execution past its deadline"
+                );
+            }
+            tracing::warn!(
+                task_id = %event.task_execution_id,
+                task_name = %event.task_name,
+                "{}; failing the execution",
+                error_msg
+            );
+            return Ok(ExecutionResult::failure(
+                event.task_execution_id,
+                error_msg,
+                start.elapsed(),
+            ));
+        }
+
         // Cancellation channel — the heartbeat loop flips this to `true` if
         // it detects `ClaimLost`. The execution future races against it via
         // `execute_with_cancellation` (Layer 1), and tasks holding a
@@ -468,6 +784,41 @@ impl TaskExecutor for ThreadTaskExecutor {
             None
         };
 
+        // A task of a capped workload class waits for a class slot first, so
+        // it queues without holding one of the runner's general slots. Held
+        // until the attempt finishes (a deferring `TaskHandle` keeps it).
+        let class_permit = match task
+            .workload_class()
+            .and_then(|class| self.workload_semaphores.get(&class))
+        {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|_| DispatchError::ExecutorNotFound("semaphore closed".into()))?,
+            ),
+            None => None,
+        };
+
+        // Likewise one slot of each resource pool the task uses, taken in
+        // name order so tasks sharing several pools cannot deadlock.
+        let mut resources = task.resources();
+        resources.sort();
+        resources.dedup();
+        let mut resource_permits = Vec::new();
+        for name in &resources {
+            if let Some(semaphore) = self.resource_semaphores.get(name) {
+                resource_permits.push(
+                    semaphore
+                        .clone()
+                        .acquire_owned()
+                        .await
+                        .map_err(|_| DispatchError::ExecutorNotFound("semaphore closed".into()))?,
+                );
+            }
+        }
+
         // Acquire a concurrency slot — held for the duration of execution.
         let permit = self
             .semaphore
@@ -495,61 +846,10 @@ impl TaskExecutor for ThreadTaskExecutor {
             );
         }
 
-        // Compute runner_id for claim-guarded state transitions
-        let claim_runner_id = if self.config.enable_claiming {
-            Some(self.instance_id)
-        } else {
-            None
-        };
-
-        // Convert TaskReadyEvent to ClaimedTask format
-        let claimed_task = ClaimedTask {
-            task_execution_id: event.task_execution_id,
-            workflow_execution_id: event.workflow_execution_id,
-            task_name: event.task_name.clone(),
-            attempt: event.attempt,
-        };
-
-        // Resolve task from global registry
-        let namespace = match parse_namespace(&claimed_task.task_name) {
-            Ok(ns) => ns,
-            Err(e) => {
-                self.total_failed.fetch_add(1, Ordering::SeqCst);
-                let error_msg = format!("Invalid namespace: {}", e);
-                let _ = self
-                    .dal
-                    .task_execution()
-                    .mark_failed(event.task_execution_id, &error_msg, claim_runner_id)
-                    .await;
-                return Ok(ExecutionResult::failure(
-                    event.task_execution_id,
-                    error_msg,
-                    start.elapsed(),
-                ));
-            }
-        };
-
-        let task = match self.runtime.get_task(&namespace) {
-            Some(t) => t,
-            None => {
-                self.total_failed.fetch_add(1, Ordering::SeqCst);
-                let error_msg = format!("Task not found: {}", claimed_task.task_name);
-                let _ = self
-                    .dal
-                    .task_execution()
-                    .mark_failed(event.task_execution_id, &error_msg, claim_runner_id)
-                    .await;
-                return Ok(ExecutionResult::failure(
-                    event.task_execution_id,
-                    error_msg,
-                    start.elapsed(),
-                ));
-            }
-        };
-
-        // Build context for execution
-        let dependencies = task.dependencies();
-        let context = match self.build_task_context(&claimed_task, dependencies).await {
+        // Build context for execution. Soft (ordering-only) dependencies
+        // contribute no context.
+        let dependencies = task.data_dependencies();
+        let mut context = match self.build_task_context(&claimed_task, &dependencies).await {
             Ok(ctx) => ctx,
             Err(e) => {
                 self.total_failed.fetch_add(1, Ordering::SeqCst);
@@ -567,6 +867,31 @@ impl TaskExecutor for ThreadTaskExecutor {
             }
         };
 
+        // Remember the secret values this attempt resolves, to strip them
+        // from its results.
+        let secret_redactor = self.secret_resolver.clone().map(|resolver| {
+            let redactor = Arc::new(SecretRedactor::new(resolver));
+            context.set_secret_resolver(redactor.clone());
+            redactor
+        });
+
+        // What the task was given, for lineage.
+        let lineage_input = self
+            .config
+            .track_context_lineage
+            .then(|| lineage_view(&context));
+
+        // Hide undeclared reads from the task before anything is traced.
+        let task_scope = TaskScope::enter(task.as_ref(), &mut context);
+
+        // Trace the keys the task body touches. Attached after the context
+        // is built so dependency merging isn't counted as task reads.
+        let access_log = self.config.trace_context_access.then(|| {
+            let log = Arc::new(ContextAccessLog::new());
+            context.set_access_log(log.clone());
+            log
+        });
+
         // `cloacina_active_tasks` is SQL-derived in the scheduler tick
         // (see `SchedulerLoop::process_active_executions`); no
         // increment/decrement here because a panic between the two would
@@ -574,14 +899,15 @@ impl TaskExecutor for ThreadTaskExecutor {
 
         // Execute the task — if it requires a handle, wrap execution with
         // task-local storage so the macro-generated code can access it.
-        let execution_result = if task.requires_handle() {
+        let mut execution_result = if task.requires_handle() {
             let slot_token = SlotToken::new(permit, self.semaphore.clone());
             let handle = TaskHandle::with_dal_and_cancel(
                 slot_token,
                 event.task_execution_id,
                 self.dal.clone(),
                 cancel_rx.clone(),
-            );
+            )
+            .with_deadline(deadline);
 
             // Set initial sub_status to Active
             if let Err(e) = self
@@ -599,7 +925,7 @@ impl TaskExecutor for ThreadTaskExecutor {
 
             let (result, _returned_handle) = with_task_handle(
                 handle,
-                self.execute_with_cancellation(task.as_ref(), context, cancel_rx.clone()),
+                self.execute_iterations(&task, &event, context, timeout, cancel_rx.clone()),
             )
             .await;
 
@@ -623,9 +949,11 @@ impl TaskExecutor for ThreadTaskExecutor {
         } else {
             // No handle needed — permit is held as _permit for the duration.
             let _permit = permit;
-            self.execute_with_cancellation(task.as_ref(), context, cancel_rx.clone())
+            self.execute_iterations(&task, &event, context, timeout, cancel_rx.clone())
                 .await
         };
+        drop(resource_permits);
+        drop(class_permit);
         // Drop the local cancel sender so that, once the heartbeat task's
         // clone is aborted, receivers can observe the channel close rather
         // than hang forever. (Not strictly required since the select! arm
@@ -637,6 +965,80 @@ impl TaskExecutor for ThreadTaskExecutor {
         // No `cloacina_active_tasks.decrement()` — SQL-derived in the
         // scheduler tick. See CLOACI-T-0589.
 
+        // Persist the traced keys. Best-effort: tracing is diagnostic and
+        // never changes the task's outcome.
+        if let Some(log) = access_log {
+            if let Ok(ctx) = execution_result.as_mut() {
+                ctx.take_access_log();
+            }
+            if let Err(e) = self
+                .dal
+                .task_execution()
+                .record_context_access(
+                    event.workflow_execution_id,
+                    event.task_execution_id,
+                    &event.task_name,
+                    event.attempt,
+                    &log.reads(),
+                    &log.writes(),
+                )
+                .await
+            {
+                tracing::warn!(
+                    task_id = %event.task_execution_id,
+                    error = %e,
+                    "Failed to record context access"
+                );
+            }
+        }
+
+        // A successful attempt must write the outputs the task declares.
+        // Checked after the access log is detached so the check's reads are
+        // not traced as the task's, and before scoping so declared keys are
+        // found where the task wrote them.
+        let execution_result = execution_result.and_then(|context| {
+            check_task_outputs(task.as_ref(), &context)?;
+            Ok(task_scope.leave(context)?)
+        });
+
+        // Redact resolved secret values before the result is stored, logged
+        // or recorded as lineage.
+        let execution_result = match &secret_redactor {
+            Some(redactor) => execution_result
+                .map(|context| redactor.redact_context(context))
+                .map_err(|e| match e {
+                    ExecutorError::TaskExecution(e) => {
+                        ExecutorError::TaskExecution(redactor.redact_error(e))
+                    }
+                    other => other,
+                }),
+            None => execution_result,
+        };
+
+        // Record which keys a successful attempt changed. Best-effort, like
+        // access tracing.
+        if let (Some(input), Ok(output)) = (&lineage_input, &execution_result) {
+            let changes = diff_contexts(input, &lineage_view(output));
+            if let Err(e) = self
+                .dal
+                .task_execution()
+                .record_context_changes(
+                    event.workflow_execution_id,
+                    event.task_execution_id,
+                    &event.task_name,
+                    event.attempt,
+                    &changes,
+                )
+                .await
+            {
+                tracing::warn!(
+                    task_id = %event.task_execution_id,
+                    error = %e,
+                    "Failed to record context lineage"
+                );
+            }
+        }
+
         // Stop heartbeat and release claim after execution (success or failure).
         // COR-08: actually wait for the heartbeat task to finish so the
         // synchronous-close contract holds. Without the bounded await,
@@ -879,6 +1281,47 @@ mod tests {
         assert_eq!(merged, json!(false));
     }
 
+    #[test]
+    fn test_deadline_refusal() {
+        let now = chrono::Utc::now();
+        let deadline = Some(now + chrono::Duration::seconds(60));
+        let minute = std::time::Duration::from_secs(60);
+
+        assert_eq!(deadline_refusal(None, Some(minute), now), None);
+        assert_eq!(deadline_refusal(deadline, None, now), None);
+        assert_eq!(deadline_refusal(deadline, Some(minute / 2), now), None);
+        assert!(deadline_refusal(deadline, Some(minute * 2), now)
+            .unwrap()
+            .contains("only 60s are left"));
+        assert!(deadline_refusal(Some(now), None, now)
+            .unwrap()
+            .contains("has passed"));
+    }
+
+    #[tokio::test]
+    async fn test_run_on_blocking_pool_leaves_the_calling_thread() {
+        let caller = std::thread::current().id();
+        let worker = run_on_blocking_pool("crunch", async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(std::thread::current().id())
+        })
+        .await
+        .unwrap();
+        assert_ne!(worker, caller);
+    }
+
+    #[tokio::test]
+    async fn test_run_on_blocking_pool_passes_task_errors_through() {
+        let err = run_on_blocking_pool::<(), _>("crunch", async {
+            Err(TaskError::ValidationFailed {
+                message: "bad input".to_string(),
+            })
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, TaskError::ValidationFailed { .. }));
+    }
+
     // -----------------------------------------------------------------------
     // Tests requiring SQLite (executor construction uses in-memory SQLite)
     // -----------------------------------------------------------------------
@@ -942,6 +1385,10 @@ mod tests {
                 task_timeout: std::time::Duration::from_secs(60),
                 enable_claiming: false,
                 heartbeat_interval: std::time::Duration::from_secs(5),
+                trace_context_access: false,
+                track_context_lineage: false,
+                workload_limits: std::collections::HashMap::new(),
+                resource_pools: std::collections::HashMap::new(),
             };
             let exec = ThreadTaskExecutor::new(db, registry, config);
             let metrics = exec.metrics();
@@ -24,6 +24,7 @@
 use crate::dal::DAL;
 use crate::database::UniversalUuid;
 use crate::error::ExecutorError;
+use crate::task::WorkloadClass;
 use crate::Database;
 use std::collections::HashMap;
 use tokio::sync::RwLock;
@@ -148,7 +149,12 @@ impl DependencyLoader {
 
         if let Some(context_id) = task_metadata.context_id {
             let context = dal.context().read::<serde_json::Value>(context_id).await?;
-            Ok(context.data().clone())
+            // Binary values come back as marker objects, like any JSON view.
+            Ok(context
+                .to_map()
+                .map_err(crate::error::ContextError::from)?
+                .into_iter()
+                .collect())
         } else {
             // Task has no output context
             Ok(HashMap::new())
@@ -171,6 +177,17 @@ pub struct ExecutorConfig {
     pub enable_claiming: bool,
     /// Heartbeat interval for claimed tasks (only used when claiming is enabled).
     pub heartbeat_interval: std::time::Duration,
+    /// Record the context keys each task reads and writes.
+    pub trace_context_access: bool,
+    /// Record the context keys each successful task attempt changes, with
+    /// their previous and new values.
+    pub track_context_lineage: bool,
+    /// Per-class caps on concurrently running tasks, within
+    /// `max_concurrent_tasks`.
+    pub workload_limits: HashMap<WorkloadClass, usize>,
+    /// Named resource pools and their capacities; a task holds a slot of
+    /// each pool named in its `resources`.
+    pub resource_pools: HashMap<String, usize>,
 }
 
 impl Default for ExecutorConfig {
@@ -181,12 +198,20 @@ impl Default for ExecutorConfig {
     /// * task_timeout: 5 minutes
     /// * enable_claiming: false (opt-in)
     /// * heartbeat_interval: 10 seconds
+    /// * trace_context_access: false
+    /// * track_context_lineage: false
+    /// * workload_limits: none
+    /// * resource_pools: none
     fn default() -> Self {
         Self {
             max_concurrent_tasks: 4,
             task_timeout: std::time::Duration::from_secs(300), // 5 minutes
             enable_claiming: true,
             heartbeat_interval: std::time::Duration::from_secs(10),
+            trace_context_access: false,
+            track_context_lineage: false,
+            workload_limits: HashMap::new(),
+            resource_pools: HashMap::new(),
         }
     }
 }
@@ -289,6 +314,10 @@ mod tests {
             task_timeout: std::time::Duration::from_secs(60),
             enable_claiming: false,
             heartbeat_interval: std::time::Duration::from_secs(5),
+            trace_context_access: false,
+            track_context_lineage: false,
+            workload_limits: HashMap::new(),
+            resource_pools: HashMap::new(),
         };
         assert_eq!(config.max_concurrent_tasks, 16);
         assert_eq!(config.task_timeout, std::time::Duration::from_secs(60));
@@ -303,6 +332,10 @@ mod tests {
             task_timeout: std::time::Duration::from_secs(120),
             enable_claiming: true,
             heartbeat_interval: std::time::Duration::from_secs(15),
+            trace_context_access: false,
+            track_context_lineage: false,
+            workload_limits: HashMap::new(),
+            resource_pools: HashMap::new(),
         };
         let cloned = config.clone();
         assert_eq!(cloned.max_concurrent_tasks, config.max_concurrent_tasks);
@@ -48,7 +48,10 @@ use chrono::{DateTime, Utc};
 use std::time::Duration;
 use uuid::Uuid;
 
-use crate::error::{ExecutorError, TaskError, ValidationError};
+use crate::error::{
+    ExecutorError, TaskError, TaskErrorPayload, ValidationError, DEADLINE_EXCEEDED_CODE,
+    WORKFLOW_TIMEOUT_CODE,
+};
 use crate::task::TaskState;
 use crate::Context;
 
@@ -85,6 +88,8 @@ pub struct TaskResult {
     pub attempt_count: i32,
     /// Error message if the task failed
     pub error_message: Option<String>,
+    /// Structured payload if the task failed with `TaskError::Structured`
+    pub error_payload: Option<TaskErrorPayload>,
 }
 
 /// Unified error type for workflow execution operations.
@@ -182,6 +187,53 @@ pub struct WorkflowExecutionResult {
     pub error_message: Option<String>,
 }
 
+impl WorkflowExecutionResult {
+    /// Extracts the workflow's declared outputs from the final context as a
+    /// typed struct.
+    ///
+    /// `T` is normally the `Outputs` struct generated inside a module annotated
+    /// with `#[workflow(outputs(...))]`:
+    ///
+    /// ```rust,ignore
+    /// let result = runner.execute("etl", Context::new()).await?;
+    /// let out = result.outputs::<etl::Outputs>()?;
+    /// println!("loaded {} rows", out.row_count);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OutputsError::Missing`](crate::outputs::OutputsError::Missing)
+    /// when a non-optional output was never written, and
+    /// [`OutputsError::InvalidType`](crate::outputs::OutputsError::InvalidType)
+    /// when a value doesn't deserialize into its declared type.
+    pub fn outputs<T: crate::outputs::WorkflowOutputs>(
+        &self,
+    ) -> Result<T, crate::outputs::OutputsError> {
+        T::from_context(&self.final_context)
+    }
+
+    /// Whether the execution failed because it missed its deadline rather
+    /// than because a task failed.
+    pub fn deadline_exceeded(&self) -> bool {
+        self.failed_with(DEADLINE_EXCEEDED_CODE)
+    }
+
+    /// Whether the execution failed because it ran past its workflow
+    /// timeout rather than because a task failed.
+    pub fn timed_out(&self) -> bool {
+        self.failed_with(WORKFLOW_TIMEOUT_CODE)
+    }
+
+    fn failed_with(&self, code: &str) -> bool {
+        self.status == WorkflowStatus::Failed
+            && self.task_results.iter().any(|t| {
+                t.error_payload
+                    .as_ref()
+                    .is_some_and(|payload| payload.code == code)
+            })
+    }
+}
+
 /// Handle for managing an asynchronous workflow execution.
 ///
 /// This struct provides methods to monitor and control a running workflow execution.
@@ -649,6 +701,7 @@ mod tests {
             duration: Some(Duration::from_secs(5)),
             attempt_count: 1,
             error_message: None,
+            error_payload: None,
         };
         assert_eq!(result.task_name, "extract");
         assert_eq!(result.attempt_count, 1);
@@ -668,6 +721,7 @@ mod tests {
             duration: None,
             attempt_count: 3,
             error_message: Some("division by zero".to_string()),
+            error_payload: None,
         };
         assert_eq!(result.error_message.as_deref(), Some("division by zero"));
         assert_eq!(result.attempt_count, 3);
@@ -683,6 +737,7 @@ mod tests {
             duration: None,
             attempt_count: 0,
             error_message: None,
+            error_payload: None,
         };
         let cloned = result.clone();
         assert_eq!(cloned.task_name, result.task_name);
@@ -723,6 +778,7 @@ mod tests {
             duration: Some(Duration::from_secs(2)),
             attempt_count: 1,
             error_message: None,
+            error_payload: None,
         };
         let task2 = TaskResult {
             task_name: "step_2".to_string(),
@@ -735,6 +791,7 @@ mod tests {
             duration: Some(Duration::from_secs(1)),
             attempt_count: 2,
             error_message: Some("oops".to_string()),
+            error_payload: None,
         };
         let result = WorkflowExecutionResult {
             execution_id: Uuid::new_v4(),
@@ -0,0 +1,385 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Runner extensions.
+//!
+//! An [`Extension`] lets a third-party crate plug into a
+//! [`DefaultRunner`](crate::DefaultRunner) without patching cloacina. It is
+//! handed to [`DefaultRunnerBuilder::extension`](crate::DefaultRunnerBuilder::extension)
+//! and, while the runner is built, registers what it contributes through an
+//! [`ExtensionRegistrar`]:
+//!
+//! - task executors ([`executor`](ExtensionRegistrar::executor)), selected
+//!   with the runner's `default_executor`
+//! - task types ([`task`](ExtensionRegistrar::task)) and trigger sources
+//!   ([`trigger`](ExtensionRegistrar::trigger)), added to the runner's
+//!   [`Runtime`]
+//! - stream backends ([`stream_backend`](ExtensionRegistrar::stream_backend))
+//!   for computation graph accumulators
+//! - registry storage ([`registry_storage`](ExtensionRegistrar::registry_storage)),
+//!   selected with the runner's `registry_storage_backend`
+//! - a secrets provider ([`secret_resolver`](ExtensionRegistrar::secret_resolver))
+//!   behind `context.secret(...)`
+//! - notifiers ([`notifier`](ExtensionRegistrar::notifier)), registered as
+//!   lifecycle hooks with [`DefaultRunner::on`](crate::DefaultRunner::on)
+//!
+//! Two extensions registering the same key is a build error, as is a key
+//! that names a built-in (executor `default`, registry storage `filesystem`,
+//! `sqlite`, `postgres`, `database`).
+//!
+//! ```rust,ignore
+//! struct S3Storage;
+//!
+//! impl Extension for S3Storage {
+//!     fn name(&self) -> &str {
+//!         "s3-storage"
+//!     }
+//!
+//!     fn register(&self, registrar: &mut ExtensionRegistrar) -> Result<(), ExtensionError> {
+//!         registrar.registry_storage("s3", |_database| {
+//!             Ok(Box::new(S3RegistryStorage::from_env()?) as Box<dyn RegistryStorage>)
+//!         })
+//!     }
+//! }
+//!
+//! let runner = DefaultRunner::builder()
+//!     .database_url(url)
+//!     .with_config(DefaultRunnerConfig::builder().registry_storage_backend("s3").build()?)
+//!     .extension(Arc::new(S3Storage))
+//!     .build()
+//!     .await?;
+//! assert_eq!(runner.extensions(), ["s3-storage"]);
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::computation_graph::stream_backend::{StreamBackendFuture, StreamConfig};
+use crate::dispatcher::TaskExecutor;
+use crate::models::execution_event::ExecutionEventType;
+use crate::registry::{RegistryStorage, StorageError};
+use crate::runner::{HookEvent, HookResult};
+use crate::runtime::Runtime;
+use crate::task::{Task, TaskNamespace};
+use crate::trigger::Trigger;
+use crate::Database;
+
+/// Executor keys the runner registers itself.
+const RESERVED_EXECUTORS: &[&str] = &["default"];
+
+/// Registry storage backends built into the runner.
+const RESERVED_STORAGE: &[&str] = &["filesystem", "sqlite", "postgres", "database"];
+
+/// Builds a registry storage backend for a runner's database.
+pub type RegistryStorageFactory =
+    Arc<dyn Fn(&Database) -> Result<Box<dyn RegistryStorage>, StorageError> + Send + Sync>;
+
+type TaskFactory = Box<dyn Fn() -> Arc<dyn Task> + Send + Sync>;
+type TriggerFactory = Box<dyn Fn() -> Arc<dyn Trigger> + Send + Sync>;
+type StreamFactory = Box<dyn Fn(StreamConfig) -> StreamBackendFuture + Send + Sync>;
+pub(crate) type NotifierFn =
+    Arc<dyn Fn(HookEvent) -> Pin<Box<dyn Future<Output = HookResult> + Send>> + Send + Sync>;
+
+/// Errors raised while installing extensions.
+#[derive(Debug, Error)]
+pub enum ExtensionError {
+    /// Two extensions share a name.
+    #[error("Extension '{name}' is installed more than once")]
+    DuplicateExtension { name: String },
+
+    /// Two extensions registered the same key.
+    #[error("Duplicate {kind} '{key}': registered by both '{first}' and '{second}'")]
+    Conflict {
+        kind: &'static str,
+        key: String,
+        first: String,
+        second: String,
+    },
+
+    /// An extension registered a key that names a built-in.
+    #[error("Extension '{extension}' cannot register built-in {kind} '{key}'")]
+    Reserved {
+        kind: &'static str,
+        key: String,
+        extension: String,
+    },
+
+    /// The extension itself failed to register.
+    #[error("Extension '{extension}' failed to register: {message}")]
+    Failed { extension: String, message: String },
+}
+
+/// A bundle of runner contributions from a third-party crate.
+pub trait Extension: Send + Sync {
+    /// Unique name, reported by [`DefaultRunner::extensions`](crate::DefaultRunner::extensions)
+    fn name(&self) -> &str;
+
+    /// Register this extension's contributions.
+    ///
+    /// Called once while the runner is built, before any background service
+    /// starts.
+    fn register(&self, registrar: &mut ExtensionRegistrar) -> Result<(), ExtensionError>;
+}
+
+/// Collects what extensions contribute to a runner being built.
+#[derive(Default)]
+pub struct ExtensionRegistrar {
+    /// Extension currently registering
+    current: String,
+    names: Vec<String>,
+    /// Which extension registered each `(kind, key)`, for conflict errors
+    owners: HashMap<(&'static str, String), String>,
+    pub(crate) executors: Vec<(String, Arc<dyn TaskExecutor>)>,
+    tasks: Vec<(TaskNamespace, TaskFactory)>,
+    triggers: Vec<(String, TriggerFactory)>,
+    stream_backends: Vec<(String, StreamFactory)>,
+    registry_storage: HashMap<String, RegistryStorageFactory>,
+    pub(crate) secret_resolver: Option<Arc<dyn cloacina_workflow::secret::SecretResolver>>,
+    pub(crate) notifiers: Vec<(ExecutionEventType, NotifierFn)>,
+}
+
+impl ExtensionRegistrar {
+    /// Install `extension`: record its name and let it register.
+    pub(crate) fn install(&mut self, extension: &dyn Extension) -> Result<(), ExtensionError> {
+        let name = extension.name().to_string();
+        if self.names.contains(&name) {
+            return Err(ExtensionError::DuplicateExtension { name });
+        }
+        self.current = name.clone();
+        self.names.push(name);
+        extension.register(self)
+    }
+
+    fn claim(
+        &mut self,
+        kind: &'static str,
+        key: &str,
+        reserved: &[&str],
+    ) -> Result<(), ExtensionError> {
+        if reserved.contains(&key) {
+            return Err(ExtensionError::Reserved {
+                kind,
+                key: key.to_string(),
+                extension: self.current.clone(),
+            });
+        }
+        if let Some(first) = self.owners.get(&(kind, key.to_string())) {
+            return Err(ExtensionError::Conflict {
+                kind,
+                key: key.to_string(),
+                first: first.clone(),
+                second: self.current.clone(),
+            });
+        }
+        self.owners
+            .insert((kind, key.to_string()), self.current.clone());
+        Ok(())
+    }
+
+    /// Register a task executor under `key`.
+    ///
+    /// Tasks go to it when the runner's `default_executor` is `key`.
+    pub fn executor(
+        &mut self,
+        key: &str,
+        executor: Arc<dyn TaskExecutor>,
+    ) -> Result<(), ExtensionError> {
+        self.claim("executor", key, RESERVED_EXECUTORS)?;
+        self.executors.push((key.to_string(), executor));
+        Ok(())
+    }
+
+    /// Register a task type under `namespace` in the runner's runtime.
+    pub fn task<F>(&mut self, namespace: TaskNamespace, factory: F) -> Result<(), ExtensionError>
+    where
+        F: Fn() -> Arc<dyn Task> + Send + Sync + 'static,
+    {
+        self.claim("task", &namespace.to_string(), &[])?;
+        self.tasks.push((namespace, Box::new(factory)));
+        Ok(())
+    }
+
+    /// Register a trigger source under `name` in the runner's runtime.
+    pub fn trigger<F>(&mut self, name: &str, factory: F) -> Result<(), ExtensionError>
+    where
+        F: Fn() -> Arc<dyn Trigger> + Send + Sync + 'static,
+    {
+        self.claim("trigger", name, &[])?;
+        self.triggers.push((name.to_string(), Box::new(factory)));
+        Ok(())
+    }
+
+    /// Register a stream backend for accumulators of `type_name`.
+    pub fn stream_backend<F>(&mut self, type_name: &str, factory: F) -> Result<(), ExtensionError>
+    where
+        F: Fn(StreamConfig) -> StreamBackendFuture + Send + Sync + 'static,
+    {
+        self.claim("stream backend", type_name, &[])?;
+        self.stream_backends
+            .push((type_name.to_string(), Box::new(factory)));
+        Ok(())
+    }
+
+    /// Register a registry storage backend, selected by setting the runner's
+    /// `registry_storage_backend` to `name`.
+    pub fn registry_storage<F>(&mut self, name: &str, factory: F) -> Result<(), ExtensionError>
+    where
+        F: Fn(&Database) -> Result<Box<dyn RegistryStorage>, StorageError> + Send + Sync + 'static,
+    {
+        self.claim("registry storage", name, RESERVED_STORAGE)?;
+        self.registry_storage
+            .insert(name.to_string(), Arc::new(factory));
+        Ok(())
+    }
+
+    /// Provide the runner's secrets backend.
+    ///
+    /// Only one extension may, and not together with
+    /// [`DefaultRunnerBuilder::secret_resolver`](crate::DefaultRunnerBuilder::secret_resolver).
+    pub fn secret_resolver(
+        &mut self,
+        resolver: Arc<dyn cloacina_workflow::secret::SecretResolver>,
+    ) -> Result<(), ExtensionError> {
+        self.claim("secret resolver", "secret_resolver", &[])?;
+        self.secret_resolver = Some(resolver);
+        Ok(())
+    }
+
+    /// Call `handler` for every `event_type` event, as a lifecycle hook.
+    pub fn notifier<F, Fut>(&mut self, event_type: ExecutionEventType, handler: F)
+    where
+        F: Fn(HookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HookResult> + Send + 'static,
+    {
+        self.notifiers
+            .push((event_type, Arc::new(move |event| Box::pin(handler(event)))));
+    }
+
+    /// Register the collected tasks, triggers and stream backends in
+    /// `runtime`, replacing entries with the same key.
+    pub(crate) fn apply_to_runtime(&mut self, runtime: &Runtime) {
+        for (namespace, factory) in self.tasks.drain(..) {
+            runtime.register_task(namespace, factory);
+        }
+        for (name, factory) in self.triggers.drain(..) {
+            runtime.register_trigger(name, factory);
+        }
+        for (type_name, factory) in self.stream_backends.drain(..) {
+            runtime.register_stream_backend(type_name, factory);
+        }
+    }
+
+    /// What the built runner keeps: extension names and storage backends.
+    pub(crate) fn installed(&mut self) -> InstalledExtensions {
+        InstalledExtensions {
+            names: std::mem::take(&mut self.names),
+            registry_storage: std::mem::take(&mut self.registry_storage),
+        }
+    }
+}
+
+/// Extensions installed on a runner, shared by its clones.
+#[derive(Default)]
+pub(crate) struct InstalledExtensions {
+    pub(crate) names: Vec<String>,
+    registry_storage: HashMap<String, RegistryStorageFactory>,
+}
+
+impl InstalledExtensions {
+    /// The extension storage backend registered as `name`.
+    pub(crate) fn registry_storage(&self, name: &str) -> Option<&RegistryStorageFactory> {
+        self.registry_storage.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestExtension {
+        name: &'static str,
+        storage: &'static str,
+    }
+
+    impl Extension for TestExtension {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn register(&self, registrar: &mut ExtensionRegistrar) -> Result<(), ExtensionError> {
+            registrar.registry_storage(self.storage, |_| {
+                Err(StorageError::Backend("unused".to_string()))
+            })
+        }
+    }
+
+    #[test]
+    fn test_conflicting_and_reserved_keys_are_rejected() {
+        let mut registrar = ExtensionRegistrar::default();
+        registrar
+            .install(&TestExtension {
+                name: "first",
+                storage: "s3",
+            })
+            .unwrap();
+
+        let err = registrar
+            .install(&TestExtension {
+                name: "second",
+                storage: "s3",
+            })
+            .unwrap_err();
+        assert!(
+            matches!(err, ExtensionError::Conflict { ref first, ref second, .. } if first == "first" && second == "second")
+        );
+
+        let err = registrar
+            .install(&TestExtension {
+                name: "third",
+                storage: "filesystem",
+            })
+            .unwrap_err();
+        assert!(matches!(err, ExtensionError::Reserved { .. }));
+
+        let err = registrar
+            .install(&TestExtension {
+                name: "first",
+                storage: "gcs",
+            })
+            .unwrap_err();
+        assert!(matches!(err, ExtensionError::DuplicateExtension { .. }));
+    }
+
+    #[test]
+    fn test_installed_keeps_names_and_storage() {
+        let mut registrar = ExtensionRegistrar::default();
+        registrar
+            .install(&TestExtension {
+                name: "s3-storage",
+                storage: "s3",
+            })
+            .unwrap();
+
+        let installed = registrar.installed();
+        assert_eq!(installed.names, vec!["s3-storage".to_string()]);
+        assert!(installed.registry_storage("s3").is_some());
+        assert!(installed.registry_storage("gcs").is_none());
+    }
+}
@@ -48,6 +48,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::TaskErrorPayload;
+use cloacina_workflow::CapabilityManifest;
+
 /// Wire-protocol version for the agent fleet. Bumped on backwards-incompatible
 /// changes. Every frame carries it so peers can negotiate (or refuse).
 pub const AGENT_PROTOCOL_VERSION: u32 = 1;
@@ -252,6 +255,17 @@ pub struct WorkPacket {
     /// secrets in one dispatch wrap to the SAME key (this execution's key).
     #[serde(default)]
     pub secret_key_id: Option<String>,
+    /// Capabilities the deployment policy granted the task's package, when it
+    /// is capability-gated. A sandboxing agent derives the execution's
+    /// Landlock/seccomp profile from them. Absent ⇒ not capability-gated (or
+    /// an older server).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<CapabilityManifest>,
+    /// The execution's read-only params, which the agent attaches to the
+    /// task's context apart from `context`. Absent ⇒ none (or an older
+    /// server).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 /// One at-rest secret resolved by the server and HPKE-wrapped to a single
@@ -365,6 +379,10 @@ pub enum AgentOutcome {
     Failure {
         message: String,
         classification: FailureClassification,
+        /// Payload of a `TaskError::Structured` failure, forwarded verbatim
+        /// so the server stores it exactly as the task returned it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<TaskErrorPayload>,
     },
     /// Agent refused to run the work packet (pre-execution). Server should
     /// treat as transient and reschedule onto a different agent.
@@ -406,6 +424,10 @@ pub enum RefusalReason {
     Shutdown,
     /// Server tried to route a packet whose tenant the agent isn't authorized for.
     TenantMismatch,
+    /// The agent enforces a syscall sandbox and the packet cannot run under
+    /// one: a Python package, or a package that carries no granted
+    /// capabilities to derive the profile from.
+    SandboxRequired,
 }
 
 #[cfg(test)]
@@ -431,6 +453,8 @@ mod tests {
             language: Some("rust".into()),
             wrapped_secrets: Vec::new(),
             secret_key_id: None,
+            capabilities: None,
+            params: None,
         };
         let json = serde_json::to_string(&p).unwrap();
         let back: WorkPacket = serde_json::from_str(&json).unwrap();
@@ -491,12 +515,74 @@ mod tests {
             language: None,
             wrapped_secrets: Vec::new(),
             secret_key_id: Some("key-42".into()),
+            capabilities: None,
+            params: None,
         };
         let json = serde_json::to_string(&p).unwrap();
         let back: WorkPacket = serde_json::from_str(&json).unwrap();
         assert_eq!(back.secret_key_id.as_deref(), Some("key-42"));
     }
 
+    #[test]
+    fn work_packet_capabilities_round_trip_and_are_optional() {
+        let granted = CapabilityManifest {
+            network: vec!["api.example.com:443".into()],
+            filesystem: vec!["rw:/var/lib/etl".into()],
+            ..Default::default()
+        };
+        let p = WorkPacket {
+            protocol_version: AGENT_PROTOCOL_VERSION,
+            task_execution_id: "t1".into(),
+            workflow_execution_id: "w1".into(),
+            task_name: "ns::task".into(),
+            attempt: 1,
+            context: serde_json::json!({}),
+            artifact: ArtifactRef {
+                digest: "d".into(),
+                fetch_url: "/x".into(),
+                build_target_triple: "x86_64-unknown-linux-gnu".into(),
+            },
+            timeout_seconds: 60,
+            tenant_id: None,
+            language: None,
+            wrapped_secrets: Vec::new(),
+            secret_key_id: None,
+            capabilities: Some(granted.clone()),
+            params: None,
+        };
+        let json = serde_json::to_value(&p).unwrap();
+        let back: WorkPacket = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(back.capabilities, Some(granted));
+
+        // An ungated packet (or one from an older server) omits the field.
+        let mut legacy = json;
+        legacy.as_object_mut().unwrap().remove("capabilities");
+        let back: WorkPacket = serde_json::from_value(legacy).unwrap();
+        assert_eq!(back.capabilities, None);
+    }
+
+    #[test]
+    fn failure_payload_round_trips_and_is_optional() {
+        let payload = TaskErrorPayload::new("CARD_DECLINED", "The card was declined")
+            .with_details(serde_json::json!({ "decline_code": "insufficient_funds" }));
+        let failure = AgentOutcome::Failure {
+            message: "declined".into(),
+            classification: FailureClassification::TaskError,
+            payload: Some(payload.clone()),
+        };
+        let json = serde_json::to_string(&failure).unwrap();
+        let back: AgentOutcome = serde_json::from_str(&json).unwrap();
+        match back {
+            AgentOutcome::Failure { payload: back, .. } => assert_eq!(back, Some(payload)),
+            other => panic!("expected Failure, got {:?}", other),
+        }
+
+        // Agents that predate structured errors send no `payload` key.
+        let legacy = r#"{"kind":"failure","message":"oops","classification":"task_error"}"#;
+        let back: AgentOutcome = serde_json::from_str(legacy).unwrap();
+        assert!(matches!(back, AgentOutcome::Failure { payload: None, .. }));
+    }
+
     #[test]
     fn outcome_variants_round_trip_with_snake_case_tags() {
         let success = AgentOutcome::Success {
@@ -510,6 +596,7 @@ mod tests {
         let failure = AgentOutcome::Failure {
             message: "oops".into(),
             classification: FailureClassification::Transient,
+            payload: None,
         };
         let json = serde_json::to_string(&failure).unwrap();
         assert!(json.contains("\"kind\":\"failure\""));
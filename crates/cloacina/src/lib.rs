@@ -463,11 +463,11 @@ pub extern crate cloacina_workflow_plugin;
 pub mod prelude {
     // Core types
     pub use crate::context::Context;
-    pub use crate::task::{Task, TaskRegistry, TaskState};
+    pub use crate::task::{Task, TaskRegistry, TaskState, WorkloadClass};
     pub use crate::workflow::{DependencyGraph, Workflow, WorkflowBuilder, WorkflowMetadata};
 
     // Trigger types
-    pub use crate::trigger::{Trigger, TriggerConfig, TriggerError, TriggerResult};
+    pub use crate::trigger::{Trigger, TriggerBatch, TriggerConfig, TriggerError, TriggerResult};
 
     // Error types
     pub use crate::error::{ExecutorError, TaskError, WorkflowError};
@@ -497,8 +497,16 @@ pub mod prelude {
 
 // #[cfg(feature = "auth")]
 // pub mod auth;
+pub mod artifacts;
+pub mod catalog;
 pub mod computation_graph;
 pub mod context;
+pub mod context_format;
+pub mod context_inheritance;
+pub mod context_lineage;
+pub mod context_offload;
+pub mod context_retention;
+pub mod context_scope;
 pub mod cron_evaluator;
 pub mod cron_recovery;
 /// Cron and event-trigger schedule management.
@@ -509,22 +517,30 @@ pub mod dal;
 pub mod database;
 pub mod delivery;
 pub mod dispatcher;
+pub mod doctor;
 pub mod error;
+pub mod event_bus;
+pub mod execution_detail;
 /// Task readiness evaluation, workflow processing, and stale claim sweeping.
 /// For cron and trigger scheduling, see [`cron_trigger_scheduler`].
 pub mod execution_planner;
 pub mod executor;
+pub mod extensions;
 pub mod fleet;
 pub mod graph;
 pub mod input_interface;
 pub mod inventory_entries;
 pub mod logging;
+pub mod memory;
 pub mod models;
+pub mod outputs;
 pub mod packaging;
 pub mod python_runtime;
 pub mod registry;
+pub mod replay;
 pub mod retry;
 pub mod runner;
+pub mod runner_handoff;
 pub mod runtime;
 pub mod workflow_instance;
 
@@ -536,9 +552,14 @@ pub use inventory;
 // don't need a direct serde_json dep.
 pub use serde_json;
 pub mod security;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_maintenance;
 pub mod task;
+pub mod templating;
 pub mod trigger;
 pub mod var;
+#[cfg(feature = "webhooks")]
+pub mod webhook;
 pub mod workflow;
 
 pub use logging::init_logging;
@@ -555,6 +576,8 @@ pub fn setup_test() {
 pub use database::connection::Database;
 
 // Re-export key types for convenience
+pub use artifacts::ArtifactStore;
+pub use catalog::{CatalogSource, CatalogTask, CatalogWorkflow, WorkflowCatalog};
 pub use cloacina_computation_graph::{
     Graph, ReactionMode as ComputationReactionMode, Reactor, ReactorConstructor,
     ReactorConstructorRef, ReactorRegistration,
@@ -562,10 +585,14 @@ pub use cloacina_computation_graph::{
 pub use computation_graph::ComputationGraphRegistration;
 pub use computation_graph::{TriggerlessGraph, TriggerlessGraphFn, TriggerlessGraphRegistration};
 pub use context::Context;
+pub use context_inheritance::{ContextInheritance, KeyPolicy};
 // CLOACI-T-0858: the secret resolution side channel (D-1). The trait + error
 // types live in cloacina-workflow (authoring surface); re-export for consumers.
 pub use cloacina_workflow::{SecretAccessError, SecretResolver, SecretResolverError};
-pub use cron_evaluator::{CronError, CronEvaluator};
+pub use context_format::ContextFormat;
+pub use context_offload::ContextOffload;
+pub use context_retention::{ContextRetentionConfig, ContextRetentionSweeper};
+pub use cron_evaluator::{CronError, CronEvaluator, DstPolicy};
 pub use cron_recovery::{CronRecoveryConfig, CronRecoveryService};
 pub use cron_trigger_scheduler::{Scheduler, SchedulerConfig};
 #[cfg(feature = "postgres")]
@@ -576,8 +603,11 @@ pub use dispatcher::{
     ExecutorMetrics, TaskExecutor, TaskReadyEvent,
 };
 pub use error::{
-    CheckpointError, ContextError, ExecutorError, RegistrationError, SubgraphError, TaskError,
-    ValidationError, WorkflowError,
+    CheckpointError, ContextError, ErrorCategory, ExecutorError, RegistrationError, SubgraphError,
+    TaskError, TaskErrorPayload, ValidationError, WorkflowError,
+};
+pub use execution_detail::{
+    AttemptOutcome, ExecutionDetail, TaskAttempt, TaskDetail, TriggerEvaluation,
 };
 pub use execution_planner::{TaskScheduler, TriggerCondition, TriggerRule, ValueOperator};
 pub use executor::{
@@ -585,6 +615,7 @@ pub use executor::{
     ThreadTaskExecutor, WorkflowExecution, WorkflowExecutionError, WorkflowExecutionResult,
     WorkflowExecutor, WorkflowStatus,
 };
+pub use extensions::{Extension, ExtensionError, ExtensionRegistrar, RegistryStorageFactory};
 pub use graph::{
     DependencyEdge, GraphEdge, GraphMetadata, GraphNode, TaskNode, WorkflowGraph, WorkflowGraphData,
 };
@@ -592,14 +623,20 @@ pub use inventory_entries::{
     ComputationGraphEntry, ReactorEntry, StreamBackendEntry, StreamBackendFactoryFn, TaskEntry,
     TriggerEntry, TriggerlessGraphEntry, WorkflowEntry,
 };
+pub use outputs::{OutputKind, OutputsError, ProducedOutput, WorkflowOutputs};
 pub use retry::{BackoffStrategy, RetryCondition, RetryPolicy, RetryPolicyBuilder};
 pub use runner::DefaultRunnerBuilder;
 pub use runner::{DefaultRunner, DefaultRunnerConfig};
+pub use runner_handoff::{RunnerHandoffConfig, RunnerHandoffMonitor};
 pub use runtime::Runtime;
+#[cfg(feature = "sqlite")]
+pub use sqlite_maintenance::{MaintenanceReport, SqliteMaintenance, SqliteMaintenanceConfig};
 pub use task::namespace::parse_namespace;
-pub use task::{Task, TaskNamespace, TaskRegistry, TaskState};
-pub use trigger::{Trigger, TriggerConfig, TriggerError, TriggerResult};
-pub use workflow::{DependencyGraph, Workflow, WorkflowBuilder, WorkflowMetadata};
+pub use task::{FnTask, Task, TaskNamespace, TaskRegistry, TaskState, WorkloadClass};
+pub use trigger::{Trigger, TriggerBatch, TriggerConfig, TriggerError, TriggerResult};
+pub use workflow::{
+    DependencyGraph, EmbeddedWorkflow, Workflow, WorkflowBuilder, WorkflowMetadata,
+};
 
 // Re-export the macros from cloacina-macros
 #[cfg(feature = "macros")]
@@ -0,0 +1,417 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Memory guardrails — per-task memory tracking and limits.
+//!
+//! A task declares a limit with `#[task(memory_limit_mb = N)]` (surfaced as
+//! [`Task::memory_limit`](crate::Task::memory_limit)). The executor runs the
+//! task future through [`guard_task`], which measures the task's memory in
+//! one of two ways:
+//!
+//! - [`MemoryAccounting::Allocations`] — for tasks that share a process with
+//!   other executions. [`TrackingAllocator`] attributes every allocation and
+//!   deallocation made while a guarded future is being polled to that task,
+//!   so co-located tasks do not count against each other. The figure is an
+//!   estimate: work the task hands to other threads (`spawn_blocking`,
+//!   `tokio::spawn`) and code in a packaged cdylib, which has its own
+//!   allocator, are not seen. The host binary must install the allocator:
+//!
+//!   ```rust,ignore
+//!   #[global_allocator]
+//!   static ALLOC: cloacina::memory::TrackingAllocator = cloacina::memory::TrackingAllocator::new();
+//!   ```
+//!
+//! - [`MemoryAccounting::ProcessRss`] — for process-isolated execution
+//!   (execution agents). The process's resident set size is sampled while the
+//!   task runs and its growth since the task started is the task's usage.
+//!   Linux only (`/proc/self/status`).
+//!
+//! A task over its limit fails with [`TaskError::MemoryLimitExceeded`]. The
+//! check runs each time the task yields (allocation accounting) or on every
+//! sample (RSS accounting), so a task that allocates far past its limit in a
+//! single synchronous step is stopped at its next `.await`, not mid-step.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Once};
+use std::task::{Context as PollContext, Poll};
+use std::time::Duration;
+
+use tracing::{debug, warn};
+
+use crate::error::TaskError;
+
+/// How often RSS accounting samples the process's resident set size.
+const RSS_SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
+
+static TRACKING_ALLOCATOR_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Usage counter of the guarded task currently being polled on this
+    /// thread, or null. Set by [`MemoryTracked::poll`] for the duration of
+    /// one poll of the task future.
+    static CURRENT_TASK: Cell<*const TaskMemory> = const { Cell::new(ptr::null()) };
+}
+
+/// Global allocator that attributes allocations to the guarded task being
+/// polled on the current thread. Delegates the actual allocation to
+/// [`System`].
+///
+/// Allocations made outside a guarded task cost one thread-local read.
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TrackingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+fn record(delta: i64) {
+    // `try_with` because allocations also happen while thread-locals are
+    // being torn down; those are simply not attributed.
+    let _ = CURRENT_TASK.try_with(|current| {
+        let task = current.get();
+        if !task.is_null() {
+            // SAFETY: the pointer is only set while the owning `MemoryTracked`
+            // future is being polled, and that future holds an `Arc` to the
+            // counter for its whole lifetime.
+            unsafe { (*task).add(delta) };
+        }
+    });
+}
+
+#[inline]
+fn mark_active() {
+    if !TRACKING_ALLOCATOR_ACTIVE.load(Ordering::Relaxed) {
+        TRACKING_ALLOCATOR_ACTIVE.store(true, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            mark_active();
+            record(layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            mark_active();
+            record(layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as i64));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+/// Returns `true` once [`TrackingAllocator`] is serving allocations in this
+/// process, i.e. it is installed as the `#[global_allocator]`.
+pub fn tracking_allocator_active() -> bool {
+    TRACKING_ALLOCATOR_ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Returns the resident set size of the current process in bytes, or `None`
+/// where it cannot be read (non-Linux platforms).
+pub fn process_rss_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let kib: u64 = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()?;
+        Some(kib * 1024)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// How [`guard_task`] measures a task's memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryAccounting {
+    /// Bytes allocated by the task future and not yet freed, as counted by
+    /// [`TrackingAllocator`].
+    Allocations,
+    /// Growth of the process's resident set size since the task started.
+    ProcessRss,
+}
+
+/// Live and peak usage of one guarded task.
+#[derive(Debug, Default)]
+struct TaskMemory {
+    current: AtomicI64,
+    peak: AtomicI64,
+}
+
+impl TaskMemory {
+    #[inline]
+    fn add(&self, delta: i64) {
+        let now = self.current.fetch_add(delta, Ordering::Relaxed) + delta;
+        if delta > 0 {
+            self.peak.fetch_max(now, Ordering::Relaxed);
+        }
+    }
+
+    fn peak_bytes(&self) -> u64 {
+        self.peak.load(Ordering::Relaxed).max(0) as u64
+    }
+}
+
+/// Future wrapper that attributes allocations made while polling `inner` to
+/// its own counter and fails once the peak passes `limit_bytes`.
+struct MemoryTracked<F> {
+    inner: Pin<Box<F>>,
+    usage: Arc<TaskMemory>,
+    limit_bytes: Option<u64>,
+}
+
+/// Outcome of a [`MemoryTracked`] future.
+enum Tracked<T> {
+    Completed(T),
+    OverLimit(u64),
+}
+
+impl<F: Future> Future for MemoryTracked<F> {
+    type Output = Tracked<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut PollContext<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let usage: *const TaskMemory = Arc::as_ptr(&this.usage);
+        let previous = CURRENT_TASK.with(|current| current.replace(usage));
+        let polled = this.inner.as_mut().poll(cx);
+        CURRENT_TASK.with(|current| current.set(previous));
+
+        let peak = this.usage.peak_bytes();
+        if let Some(limit) = this.limit_bytes {
+            if peak > limit {
+                return Poll::Ready(Tracked::OverLimit(peak));
+            }
+        }
+        polled.map(Tracked::Completed)
+    }
+}
+
+/// Runs a task future under its memory limit.
+///
+/// With [`MemoryAccounting::Allocations`] the task is always tracked when
+/// [`TrackingAllocator`] is installed (its peak feeds
+/// `cloacina_task_memory_peak_bytes`); without the allocator a declared limit
+/// cannot be enforced and a warning is logged once per process. With
+/// [`MemoryAccounting::ProcessRss`] the process is only sampled when the task
+/// declares a limit.
+pub async fn guard_task<F, T>(
+    task_id: &str,
+    limit_bytes: Option<u64>,
+    accounting: MemoryAccounting,
+    fut: F,
+) -> Result<T, TaskError>
+where
+    F: Future<Output = Result<T, TaskError>>,
+{
+    let observed = match accounting {
+        MemoryAccounting::Allocations => {
+            if !tracking_allocator_active() {
+                if limit_bytes.is_some() {
+                    warn_untracked(task_id);
+                }
+                return fut.await;
+            }
+            let usage = Arc::new(TaskMemory::default());
+            let tracked = MemoryTracked {
+                inner: Box::pin(fut),
+                usage: usage.clone(),
+                limit_bytes,
+            };
+            let outcome = tracked.await;
+            record_peak(task_id, usage.peak_bytes());
+            match outcome {
+                Tracked::Completed(result) => return result,
+                Tracked::OverLimit(peak) => peak,
+            }
+        }
+        MemoryAccounting::ProcessRss => {
+            let Some(limit) = limit_bytes else {
+                return fut.await;
+            };
+            let Some(baseline) = process_rss_bytes() else {
+                warn_untracked(task_id);
+                return fut.await;
+            };
+            let mut peak = 0u64;
+            let mut sampler = tokio::time::interval(RSS_SAMPLE_INTERVAL);
+            sampler.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            tokio::pin!(fut);
+            loop {
+                tokio::select! {
+                    biased;
+                    result = &mut fut => {
+                        record_peak(task_id, peak);
+                        return result;
+                    }
+                    _ = sampler.tick() => {
+                        let grown = process_rss_bytes()
+                            .unwrap_or(baseline)
+                            .saturating_sub(baseline);
+                        peak = peak.max(grown);
+                        if peak > limit {
+                            record_peak(task_id, peak);
+                            break peak;
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    let limit_bytes = limit_bytes.unwrap_or_default();
+    metrics::counter!("cloacina_task_memory_limit_exceeded_total").increment(1);
+    warn!(
+        task_id = %task_id,
+        limit_bytes,
+        observed_bytes = observed,
+        "Task exceeded its memory limit"
+    );
+    Err(TaskError::MemoryLimitExceeded {
+        task_id: task_id.to_string(),
+        limit_bytes,
+        observed_bytes: observed,
+    })
+}
+
+fn record_peak(task_id: &str, peak_bytes: u64) {
+    metrics::histogram!("cloacina_task_memory_peak_bytes").record(peak_bytes as f64);
+    debug!(task_id = %task_id, peak_bytes, "Task memory peak");
+}
+
+fn warn_untracked(task_id: &str) {
+    static WARNED: Once = Once::new();
+    WARNED.call_once(|| {
+        warn!(
+            task_id = %task_id,
+            "Task declares a memory limit but memory cannot be measured in this process \
+             (install cloacina::memory::TrackingAllocator as the global allocator, or run \
+             the task on an execution agent); memory limits are not enforced"
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_memory_tracks_peak_not_current() {
+        let usage = TaskMemory::default();
+        usage.add(1000);
+        usage.add(-600);
+        usage.add(200);
+        assert_eq!(usage.current.load(Ordering::Relaxed), 600);
+        assert_eq!(usage.peak_bytes(), 1000);
+    }
+
+    #[test]
+    fn test_task_memory_negative_balance_reports_zero_peak() {
+        // A task that only frees memory allocated before it started.
+        let usage = TaskMemory::default();
+        usage.add(-4096);
+        assert_eq!(usage.peak_bytes(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_memory_tracked_fails_over_limit() {
+        let usage = Arc::new(TaskMemory::default());
+        let counter = usage.clone();
+        let tracked = MemoryTracked {
+            inner: Box::pin(async move {
+                // Stand-in for allocations the tracking allocator would record.
+                counter.add(10 * 1024);
+                tokio::task::yield_now().await;
+                Ok::<_, TaskError>(())
+            }),
+            usage,
+            limit_bytes: Some(4 * 1024),
+        };
+        match tracked.await {
+            Tracked::OverLimit(peak) => assert_eq!(peak, 10 * 1024),
+            Tracked::Completed(_) => panic!("task over its limit should fail"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_tracked_completes_under_limit() {
+        let usage = Arc::new(TaskMemory::default());
+        let counter = usage.clone();
+        let tracked = MemoryTracked {
+            inner: Box::pin(async move {
+                counter.add(1024);
+                Ok::<_, TaskError>(7)
+            }),
+            usage,
+            limit_bytes: Some(4 * 1024),
+        };
+        match tracked.await {
+            Tracked::Completed(result) => assert_eq!(result.unwrap(), 7),
+            Tracked::OverLimit(_) => panic!("task under its limit should complete"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guard_task_without_limit_passes_through() {
+        let result = guard_task("t", None, MemoryAccounting::ProcessRss, async {
+            Ok::<_, TaskError>("done")
+        })
+        .await;
+        assert_eq!(result.unwrap(), "done");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_process_rss_is_readable_on_linux() {
+        assert!(process_rss_bytes().unwrap() > 0);
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Delayed Execution Model
+//!
+//! Domain type for a one-shot run of a workflow at a future time. The row is
+//! written when the run is requested and claimed by the scheduler once
+//! `run_at` has passed, so a pending run survives restarts.
+
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use serde::{Deserialize, Serialize};
+
+/// Context key the scheduler stamps with the delayed execution's id when it
+/// hands the run off.
+pub const DELAYED_EXECUTION_ID_KEY: &str = "delayed_execution_id";
+
+/// Run id of the workflow execution a delayed execution starts. It depends
+/// only on the delayed execution's id, so a re-claimed run can find the
+/// execution an interrupted hand-off already started.
+pub fn delayed_run_id(delayed_execution_id: &str) -> String {
+    format!("delayed__{}", delayed_execution_id)
+}
+
+/// A workflow run requested for a future time (domain type).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelayedExecution {
+    pub id: UniversalUuid,
+    pub workflow_name: String,
+    /// The run's input context, as JSON.
+    pub context: String,
+    /// When the run is due.
+    pub run_at: UniversalTimestamp,
+    pub created_at: UniversalTimestamp,
+    /// When the scheduler picked the run up; `None` while it is pending.
+    pub claimed_at: Option<UniversalTimestamp>,
+    /// The workflow execution the run started, once handed off.
+    pub workflow_execution_id: Option<UniversalUuid>,
+    /// Why the hand-off failed, e.g. an unknown workflow.
+    pub error: Option<String>,
+}
+
+impl DelayedExecution {
+    /// Whether the run is still waiting for its time.
+    pub fn is_pending(&self) -> bool {
+        self.claimed_at.is_none()
+    }
+}
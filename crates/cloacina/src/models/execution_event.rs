@@ -182,6 +182,14 @@ pub enum ExecutionEventType {
     TaskAbandoned,
     /// Task was reset by recovery process
     TaskReset,
+    /// Failed task was marked successful by an operator
+    TaskResolved,
+    /// Task that requires approval is waiting for a decision
+    TaskAwaitingApproval,
+    /// Task waiting for approval was approved
+    TaskApproved,
+    /// Looping task finished an iteration without meeting its condition
+    TaskIterated,
 
     // Workflow lifecycle events
     /// Workflow execution started
@@ -194,6 +202,8 @@ pub enum ExecutionEventType {
     WorkflowPaused,
     /// Paused workflow was resumed
     WorkflowResumed,
+    /// Failed workflow attempt was reset to run again
+    WorkflowRetryScheduled,
 }
 
 impl ExecutionEventType {
@@ -213,12 +223,17 @@ impl ExecutionEventType {
             ExecutionEventType::TaskSkipped => "task_skipped",
             ExecutionEventType::TaskAbandoned => "task_abandoned",
             ExecutionEventType::TaskReset => "task_reset",
+            ExecutionEventType::TaskResolved => "task_resolved",
+            ExecutionEventType::TaskAwaitingApproval => "task_awaiting_approval",
+            ExecutionEventType::TaskApproved => "task_approved",
+            ExecutionEventType::TaskIterated => "task_iterated",
             // Workflow events
             ExecutionEventType::WorkflowStarted => "workflow_started",
             ExecutionEventType::WorkflowCompleted => "workflow_completed",
             ExecutionEventType::WorkflowFailed => "workflow_failed",
             ExecutionEventType::WorkflowPaused => "workflow_paused",
             ExecutionEventType::WorkflowResumed => "workflow_resumed",
+            ExecutionEventType::WorkflowRetryScheduled => "workflow_retry_scheduled",
         }
     }
 
@@ -238,6 +253,10 @@ impl ExecutionEventType {
             "task_skipped" => Some(ExecutionEventType::TaskSkipped),
             "task_abandoned" => Some(ExecutionEventType::TaskAbandoned),
             "task_reset" => Some(ExecutionEventType::TaskReset),
+            "task_resolved" => Some(ExecutionEventType::TaskResolved),
+            "task_awaiting_approval" => Some(ExecutionEventType::TaskAwaitingApproval),
+            "task_approved" => Some(ExecutionEventType::TaskApproved),
+            "task_iterated" => Some(ExecutionEventType::TaskIterated),
             "workflow_started" | "pipeline_started" => Some(ExecutionEventType::WorkflowStarted),
             "workflow_completed" | "pipeline_completed" => {
                 Some(ExecutionEventType::WorkflowCompleted)
@@ -245,6 +264,7 @@ impl ExecutionEventType {
             "workflow_failed" | "pipeline_failed" => Some(ExecutionEventType::WorkflowFailed),
             "workflow_paused" | "pipeline_paused" => Some(ExecutionEventType::WorkflowPaused),
             "workflow_resumed" | "pipeline_resumed" => Some(ExecutionEventType::WorkflowResumed),
+            "workflow_retry_scheduled" => Some(ExecutionEventType::WorkflowRetryScheduled),
             _ => None,
         }
     }
@@ -265,6 +285,10 @@ impl ExecutionEventType {
                 | ExecutionEventType::TaskSkipped
                 | ExecutionEventType::TaskAbandoned
                 | ExecutionEventType::TaskReset
+                | ExecutionEventType::TaskResolved
+                | ExecutionEventType::TaskAwaitingApproval
+                | ExecutionEventType::TaskApproved
+                | ExecutionEventType::TaskIterated
         )
     }
 
@@ -277,6 +301,7 @@ impl ExecutionEventType {
                 | ExecutionEventType::WorkflowFailed
                 | ExecutionEventType::WorkflowPaused
                 | ExecutionEventType::WorkflowResumed
+                | ExecutionEventType::WorkflowRetryScheduled
         )
     }
 }
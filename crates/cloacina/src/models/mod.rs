@@ -23,11 +23,14 @@
 //! ## Models
 //!
 //! - [`context`]: Models for execution context storage
+//! - [`delayed_execution`]: Models for one-shot workflow runs due at a future time
 //! - [`schedule`]: Models for unified cron and trigger workflow scheduling
 //! - [`workflow_execution`]: Models for tracking workflow execution state and metadata
 //! - [`recovery_event`]: Models for recording system recovery events and state transitions
+//! - [`runner_instance`]: Models for the runner registry behind blue/green handoff
 //! - [`task_execution`]: Models for managing individual task execution records
 //! - [`task_execution_metadata`]: Models for storing task execution metadata and context references
+//! - [`workflow_deprecation`]: Models for workflows marked for retirement
 //! - [`workflow_registry`]: Models for binary workflow package storage
 //!
 //! ## Usage
@@ -69,13 +72,16 @@
 // #[cfg(feature = "auth")]
 // pub mod auth_tokens;
 pub mod context;
+pub mod delayed_execution;
 pub mod delivery_outbox;
 pub mod execution_event;
 pub mod recovery_event;
+pub mod runner_instance;
 pub mod schedule;
 pub mod task_execution;
 pub mod task_execution_metadata;
 pub mod task_outbox;
+pub mod workflow_deprecation;
 pub mod workflow_execution;
 pub mod workflow_packages;
 pub mod workflow_registry;
@@ -0,0 +1,108 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Runner Instance Model
+//!
+//! Domain types for the runner registry that backs blue/green handoff. Each
+//! runner with handoff enabled keeps one row alive by heartbeat; a successor
+//! taking over flips its predecessors to `draining`.
+
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle state of a registered runner instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunnerInstanceState {
+    /// Taking new work: firing schedules and claiming tasks.
+    Active,
+    /// A successor has asked this runner to stop taking new work and finish
+    /// what it has in flight.
+    Draining,
+    /// In-flight work is done (or the drain timed out) and the runner exited.
+    Drained,
+    /// The runner shut down without being asked to hand off.
+    Stopped,
+}
+
+impl RunnerInstanceState {
+    /// Returns the string stored in the `state` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RunnerInstanceState::Active => "active",
+            RunnerInstanceState::Draining => "draining",
+            RunnerInstanceState::Drained => "drained",
+            RunnerInstanceState::Stopped => "stopped",
+        }
+    }
+
+    /// Parses a state from its string representation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "active" => Some(RunnerInstanceState::Active),
+            "draining" => Some(RunnerInstanceState::Draining),
+            "drained" => Some(RunnerInstanceState::Drained),
+            "stopped" => Some(RunnerInstanceState::Stopped),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RunnerInstanceState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A registered runner instance (domain type).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunnerInstance {
+    pub id: UniversalUuid,
+    pub name: Option<String>,
+    /// Version of the cloacina build the runner is running.
+    pub version: String,
+    pub state: String,
+    /// The runner that asked this one to drain, if any.
+    pub successor_id: Option<UniversalUuid>,
+    pub started_at: UniversalTimestamp,
+    pub heartbeat_at: UniversalTimestamp,
+    pub updated_at: UniversalTimestamp,
+}
+
+impl RunnerInstance {
+    /// Parsed [`RunnerInstanceState`]; unknown values read as `None`.
+    pub fn state(&self) -> Option<RunnerInstanceState> {
+        RunnerInstanceState::from_str(&self.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_round_trips_through_str() {
+        for state in [
+            RunnerInstanceState::Active,
+            RunnerInstanceState::Draining,
+            RunnerInstanceState::Drained,
+            RunnerInstanceState::Stopped,
+        ] {
+            assert_eq!(RunnerInstanceState::from_str(state.as_str()), Some(state));
+        }
+        assert_eq!(RunnerInstanceState::from_str("retired"), None);
+    }
+}
@@ -20,32 +20,62 @@
 //! `schedule_executions` tables, replacing the separate cron and trigger models.
 
 use crate::database::universal_types::{UniversalBool, UniversalTimestamp, UniversalUuid};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::time::Duration;
 
-/// Enum representing the different catchup policies for missed cron executions.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// What a cron schedule does with fires it missed while no scheduler was
+/// running (or while it was lagging by more than a poll interval).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CatchupPolicy {
+    /// Drop missed fires; the schedule resumes with its next on-time fire.
     Skip,
+    /// Run every missed fire, oldest first, up to the scheduler's
+    /// `max_catchup_executions`.
     RunAll,
+    /// Run once, for the most recent missed fire.
+    RunLatestOnly,
+}
+
+impl CatchupPolicy {
+    /// Returns the string representation stored on the schedule.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatchupPolicy::Skip => "skip",
+            CatchupPolicy::RunAll => "run_all",
+            CatchupPolicy::RunLatestOnly => "run_latest_only",
+        }
+    }
+
+    /// Parses a policy from its string representation; unlike the `From`
+    /// conversions, unknown names are rejected instead of read as `Skip`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "skip" => Some(CatchupPolicy::Skip),
+            "run_all" => Some(CatchupPolicy::RunAll),
+            "run_latest_only" | "run_once" => Some(CatchupPolicy::RunLatestOnly),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CatchupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 impl From<CatchupPolicy> for String {
     fn from(policy: CatchupPolicy) -> Self {
-        match policy {
-            CatchupPolicy::Skip => "skip".to_string(),
-            CatchupPolicy::RunAll => "run_all".to_string(),
-        }
+        policy.as_str().to_string()
     }
 }
 
 impl From<String> for CatchupPolicy {
     fn from(s: String) -> Self {
-        match s.as_str() {
-            "run_all" => CatchupPolicy::RunAll,
-            "run_once" => CatchupPolicy::Skip,
-            _ => CatchupPolicy::Skip,
-        }
+        Self::from_str(&s).unwrap_or(CatchupPolicy::Skip)
     }
 }
 
@@ -129,6 +159,66 @@ pub struct Schedule {
     // unique per workflow.
     pub params: Option<String>,
     pub instance_name: Option<String>,
+
+    /// Operator-assigned tags, stored as a JSON array of strings. Tags group
+    /// schedules so they can be paused/resumed together (e.g. every schedule
+    /// touching the warehouse). `None` for untagged schedules.
+    pub tags: Option<String>,
+
+    /// Trigger dedup window in seconds. A firing whose context hash matches
+    /// any firing recorded within the window is suppressed. `None` keeps the
+    /// default behaviour: only an in-flight execution suppresses a duplicate.
+    pub dedup_window_secs: Option<i32>,
+    /// Top-level context keys that make up the dedup hash, stored as a JSON
+    /// array of strings. `None` hashes the whole context.
+    pub dedup_keys: Option<String>,
+
+    /// Runner polling this trigger under a lease, when the runners share
+    /// trigger polling. `None` when the trigger is unleased.
+    pub lease_owner: Option<UniversalUuid>,
+    /// When the current trigger lease runs out.
+    pub lease_expires_at: Option<UniversalTimestamp>,
+
+    /// Cron fire offset in seconds: each fire runs this long after its cron
+    /// time. `None` runs on time.
+    pub fire_offset_secs: Option<i32>,
+    /// Cron fire jitter window in seconds, added on top of the offset. See
+    /// [`cron_fire_delay`]. `None` adds no jitter.
+    pub fire_jitter_secs: Option<i32>,
+
+    /// Interval in seconds for an interval schedule, which fires every
+    /// interval from `interval_anchor` instead of on `cron_expression`.
+    /// `None` for cron-expression schedules.
+    pub interval_secs: Option<i32>,
+    /// The fire time the interval is counted from.
+    pub interval_anchor: Option<UniversalTimestamp>,
+
+    /// Least time between two fires of a trigger, in milliseconds. A firing
+    /// sooner after the previous fire is suppressed as rate limited. `None`
+    /// sets no limit.
+    pub min_fire_interval_ms: Option<i32>,
+    /// Trigger debounce window in milliseconds: a firing is held until the
+    /// trigger has not fired for this long, and only the latest held firing
+    /// runs. `None` fires at once.
+    pub debounce_window_ms: Option<i32>,
+
+    /// CEL condition a trigger firing must satisfy, over the fired `context`
+    /// and the `probes` results. A trigger schedule with a condition and no
+    /// registered trigger fires whenever it holds. See
+    /// [`crate::trigger::validate_trigger_condition`].
+    pub trigger_condition: Option<String>,
+    /// The trigger's probes as a JSON array of
+    /// [`TriggerProbe`](crate::trigger::TriggerProbe) definitions.
+    pub trigger_probes: Option<String>,
+
+    /// Who paused the schedule (e.g. the API key name), when paused.
+    pub paused_by: Option<String>,
+    /// Why the schedule was paused, when paused with a reason.
+    pub pause_reason: Option<String>,
+
+    /// Daylight-saving policy of a cron schedule (`skip`, `run_once`,
+    /// `run_twice`); `None` is `run_once`.
+    pub dst_policy: Option<String>,
 }
 
 impl Schedule {
@@ -164,6 +254,17 @@ impl Schedule {
         self.is_enabled() && !self.is_paused()
     }
 
+    /// Returns the schedule's tags. Untagged schedules (or an unreadable
+    /// `tags` column) yield an empty list.
+    pub fn tag_list(&self) -> Vec<String> {
+        parse_tags(self.tags.as_deref())
+    }
+
+    /// Returns true if the schedule carries `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tag_list().iter().any(|t| t == tag)
+    }
+
     /// Returns the poll interval as a Duration (trigger schedules only).
     pub fn poll_interval(&self) -> Option<Duration> {
         self.poll_interval_ms
@@ -177,6 +278,73 @@ impl Schedule {
             .map(|b| b.is_true())
             .unwrap_or(false)
     }
+
+    /// Returns the dedup window (trigger schedules only). `None` when no
+    /// window is configured or it is not positive.
+    pub fn dedup_window(&self) -> Option<Duration> {
+        self.dedup_window_secs
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::from_secs(secs as u64))
+    }
+
+    /// Returns the context keys the dedup hash covers. Empty means the whole
+    /// context is hashed.
+    pub fn dedup_key_list(&self) -> Vec<String> {
+        parse_tags(self.dedup_keys.as_deref())
+    }
+
+    /// Returns the interval the schedule fires on, for interval schedules.
+    /// `None` for cron-expression schedules and trigger schedules.
+    pub fn interval_schedule(&self) -> Option<IntervalSchedule> {
+        let secs = self.interval_secs.filter(|secs| *secs > 0)?;
+        let anchor = self
+            .interval_anchor
+            .map(|anchor| anchor.0)
+            .unwrap_or(self.created_at.0);
+        Some(IntervalSchedule::new(
+            Duration::from_secs(secs as u64),
+            anchor,
+        ))
+    }
+
+    /// Returns how fires in a daylight-saving transition are handled (cron
+    /// schedules only). Unset or unrecognised values are
+    /// [`DstPolicy::RunOnce`](crate::DstPolicy::RunOnce).
+    pub fn dst_policy(&self) -> crate::DstPolicy {
+        self.dst_policy
+            .as_deref()
+            .and_then(|policy| policy.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if this is a cron schedule firing on an interval rather
+    /// than a cron expression.
+    pub fn is_interval(&self) -> bool {
+        self.is_cron() && self.interval_schedule().is_some()
+    }
+
+    /// Returns the least time between two fires (trigger schedules only).
+    /// `None` when no limit is configured or it is not positive.
+    pub fn min_fire_interval(&self) -> Option<Duration> {
+        positive_millis(self.min_fire_interval_ms)
+    }
+
+    /// Returns the debounce window (trigger schedules only). `None` when
+    /// firings run at once.
+    pub fn debounce_window(&self) -> Option<Duration> {
+        positive_millis(self.debounce_window_ms)
+    }
+
+    /// Returns how long after each cron time the schedule runs (cron
+    /// schedules only). `next_run_at` already includes this delay.
+    pub fn fire_delay(&self) -> chrono::Duration {
+        cron_fire_delay(
+            &self.workflow_name,
+            self.instance_name.as_deref(),
+            self.fire_offset_secs,
+            self.fire_jitter_secs,
+        )
+    }
 }
 
 /// Structure for creating new schedule records.
@@ -204,6 +372,23 @@ pub struct NewSchedule {
     // CLOACI-I-0116: named parameterized instance (None = anonymous schedule).
     pub params: Option<String>,
     pub instance_name: Option<String>,
+
+    // Operator-assigned tags (JSON array); None = untagged.
+    pub tags: Option<String>,
+
+    // Trigger dedup: window in seconds and hashed context keys (JSON array).
+    // None = dedup against in-flight executions over the whole context.
+    pub dedup_window_secs: Option<i32>,
+    pub dedup_keys: Option<String>,
+
+    // Cron fire offset and jitter window in seconds; None = fire on time.
+    pub fire_offset_secs: Option<i32>,
+    pub fire_jitter_secs: Option<i32>,
+
+    // Interval schedules: interval in seconds and the fire time it counts
+    // from; None = fire on cron_expression.
+    pub interval_secs: Option<i32>,
+    pub interval_anchor: Option<UniversalTimestamp>,
 }
 
 impl NewSchedule {
@@ -228,6 +413,29 @@ impl NewSchedule {
             next_run_at: Some(next_run_at),
             params: None,
             instance_name: None,
+            tags: None,
+            dedup_window_secs: None,
+            dedup_keys: None,
+            fire_offset_secs: None,
+            fire_jitter_secs: None,
+            interval_secs: None,
+            interval_anchor: None,
+        }
+    }
+
+    /// Create a new interval schedule: a cron schedule without an expression
+    /// that fires every `interval.interval` from `interval.anchor`. The
+    /// interval is stored in whole seconds, partial seconds rounded up.
+    pub fn interval(
+        workflow_name: &str,
+        interval: &IntervalSchedule,
+        next_run_at: UniversalTimestamp,
+    ) -> Self {
+        Self {
+            cron_expression: None,
+            interval_secs: Some(saturating_secs(interval.interval)),
+            interval_anchor: Some(UniversalTimestamp(interval.anchor)),
+            ..Self::cron(workflow_name, "", next_run_at)
         }
     }
 
@@ -248,8 +456,196 @@ impl NewSchedule {
             next_run_at: None,
             params: None,
             instance_name: None,
+            tags: None,
+            dedup_window_secs: None,
+            dedup_keys: None,
+            fire_offset_secs: None,
+            fire_jitter_secs: None,
+            interval_secs: None,
+            interval_anchor: None,
         }
     }
+
+    /// Attach tags to the schedule being created. Tags are trimmed, empty
+    /// entries dropped, and duplicates removed; an empty set leaves the
+    /// schedule untagged.
+    pub fn with_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.tags = encode_tags(tags);
+        self
+    }
+
+    /// Configure trigger deduplication for the schedule being created.
+    /// `window` suppresses repeat firings of the same hash for that long;
+    /// `keys` restricts the hash to those top-level context keys (normalized
+    /// like tags; empty hashes the whole context).
+    pub fn with_dedup<I, S>(mut self, window: Option<Duration>, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.dedup_window_secs = window.filter(|w| !w.is_zero()).map(dedup_window_secs);
+        self.dedup_keys = encode_tags(keys);
+        self
+    }
+
+    /// Delay each fire of the cron schedule being created by `offset` plus a
+    /// jitter of up to `jitter` (whole seconds, partial seconds rounded up).
+    /// Zero durations leave the column NULL. `next_run_at` is not adjusted;
+    /// add [`fire_delay`](Self::fire_delay) to the cron time.
+    pub fn with_fire_spread(mut self, offset: Duration, jitter: Duration) -> Self {
+        self.fire_offset_secs = Some(saturating_secs(offset)).filter(|s| *s > 0);
+        self.fire_jitter_secs = Some(saturating_secs(jitter)).filter(|s| *s > 0);
+        self
+    }
+
+    /// Returns how long after each cron time the schedule will run.
+    pub fn fire_delay(&self) -> chrono::Duration {
+        cron_fire_delay(
+            &self.workflow_name,
+            self.instance_name.as_deref(),
+            self.fire_offset_secs,
+            self.fire_jitter_secs,
+        )
+    }
+}
+
+/// Delay of a cron schedule's runs after each cron time: `offset_secs` plus a
+/// jitter between zero and `jitter_secs`, in milliseconds.
+///
+/// The jitter is drawn from a hash of the workflow and instance name rather
+/// than per fire, so a schedule keeps a steady cadence, schedules sharing an
+/// expression spread across the window, and every runner computes the same
+/// delay for the same schedule.
+pub fn cron_fire_delay(
+    workflow_name: &str,
+    instance_name: Option<&str>,
+    offset_secs: Option<i32>,
+    jitter_secs: Option<i32>,
+) -> chrono::Duration {
+    let offset_ms = i64::from(offset_secs.unwrap_or(0).max(0)) * 1000;
+    let window_ms = jitter_secs.unwrap_or(0).max(0) as u64 * 1000;
+    let jitter_ms = if window_ms == 0 {
+        0
+    } else {
+        let digest = Sha256::new()
+            .chain_update(workflow_name.as_bytes())
+            .chain_update([0u8])
+            .chain_update(instance_name.unwrap_or("").as_bytes())
+            .finalize();
+        let mut seed = [0u8; 8];
+        seed.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(seed) % (window_ms + 1)
+    };
+    chrono::Duration::milliseconds(offset_ms + jitter_ms as i64)
+}
+
+/// Fire times of an interval schedule: every `interval`, counted from
+/// `anchor`. Fires before the anchor are never produced, so an anchor in the
+/// future delays the first fire until then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntervalSchedule {
+    pub interval: Duration,
+    pub anchor: DateTime<Utc>,
+}
+
+impl IntervalSchedule {
+    /// Create an interval schedule. A zero `interval` is treated as one
+    /// millisecond.
+    pub fn new(interval: Duration, anchor: DateTime<Utc>) -> Self {
+        Self { interval, anchor }
+    }
+
+    fn interval_ms(&self) -> i64 {
+        i64::try_from(self.interval.as_millis())
+            .unwrap_or(i64::MAX)
+            .max(1)
+    }
+
+    /// The first fire time strictly after `after`.
+    pub fn next_execution(&self, after: DateTime<Utc>) -> DateTime<Utc> {
+        if after < self.anchor {
+            return self.anchor;
+        }
+        let interval_ms = self.interval_ms();
+        let elapsed_ms = (after - self.anchor).num_milliseconds();
+        let fires = elapsed_ms / interval_ms + 1;
+        self.anchor + chrono::Duration::milliseconds(fires.saturating_mul(interval_ms))
+    }
+
+    /// Fire times strictly after `start` and before `end`, at most
+    /// `max_executions` of them.
+    pub fn executions_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        max_executions: usize,
+    ) -> Vec<DateTime<Utc>> {
+        let mut executions = Vec::new();
+        let mut next = self.next_execution(start);
+        while next < end && executions.len() < max_executions {
+            executions.push(next);
+            next = self.next_execution(next);
+        }
+        executions
+    }
+}
+
+/// Convert a dedup window into its stored whole-second form, saturating at
+/// `i32::MAX` and rounding partial seconds up.
+pub fn dedup_window_secs(window: Duration) -> i32 {
+    saturating_secs(window)
+}
+
+/// Convert a trigger throttle duration into its stored millisecond form,
+/// saturating at `i32::MAX`. Zero is stored as `None` (no throttle).
+pub fn throttle_millis(duration: Option<Duration>) -> Option<i32> {
+    duration
+        .filter(|d| !d.is_zero())
+        .map(|d| i32::try_from(d.as_millis()).unwrap_or(i32::MAX).max(1))
+}
+
+fn positive_millis(millis: Option<i32>) -> Option<Duration> {
+    millis
+        .filter(|ms| *ms > 0)
+        .map(|ms| Duration::from_millis(ms as u64))
+}
+
+fn saturating_secs(duration: Duration) -> i32 {
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    i32::try_from(secs).unwrap_or(i32::MAX)
+}
+
+/// Normalize a tag set into its stored form: a JSON array of trimmed,
+/// non-empty, de-duplicated tags (first occurrence wins). Returns `None`
+/// when nothing remains so untagged schedules keep a NULL column.
+pub fn encode_tags<I, S>(tags: I) -> Option<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.as_ref().trim();
+        if !tag.is_empty() && !normalized.iter().any(|t| t == tag) {
+            normalized.push(tag.to_string());
+        }
+    }
+    if normalized.is_empty() {
+        None
+    } else {
+        serde_json::to_string(&normalized).ok()
+    }
+}
+
+/// Decode the stored `tags` column. A NULL or malformed column is treated as
+/// untagged rather than an error — tags are advisory grouping metadata.
+pub fn parse_tags(raw: Option<&str>) -> Vec<String> {
+    raw.and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
+        .unwrap_or_default()
 }
 
 /// Represents a schedule execution record (domain type).
@@ -270,6 +666,47 @@ pub struct ScheduleExecution {
     pub completed_at: Option<UniversalTimestamp>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
+
+    /// Why this firing was deduplicated instead of executed (trigger
+    /// schedules only). `None` for firings that ran.
+    pub suppressed_reason: Option<String>,
+    /// The earlier firing this one duplicated, when suppressed.
+    pub duplicate_of: Option<UniversalUuid>,
+}
+
+impl ScheduleExecution {
+    /// Returns true if this row records a suppressed (deduplicated) firing.
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed_reason.is_some()
+    }
+}
+
+/// Why a trigger firing was suppressed as a duplicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuppressionReason {
+    /// An execution with the same context hash was still in flight.
+    Active,
+    /// A firing with the same context hash happened within the dedup window.
+    Window,
+    /// The trigger fired less than its minimum fire interval ago.
+    RateLimited,
+}
+
+impl SuppressionReason {
+    /// Stored form of the reason.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuppressionReason::Active => "active",
+            SuppressionReason::Window => "window",
+            SuppressionReason::RateLimited => "rate_limited",
+        }
+    }
+}
+
+impl std::fmt::Display for SuppressionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Structure for creating new schedule execution records.
@@ -296,6 +733,24 @@ mod tests {
         assert_eq!(ScheduleType::Trigger.to_string(), "trigger");
     }
 
+    #[test]
+    fn test_catchup_policy_conversions() {
+        for policy in [
+            CatchupPolicy::Skip,
+            CatchupPolicy::RunAll,
+            CatchupPolicy::RunLatestOnly,
+        ] {
+            assert_eq!(CatchupPolicy::from_str(policy.as_str()), Some(policy));
+            assert_eq!(CatchupPolicy::from(String::from(policy)), policy);
+        }
+        assert_eq!(
+            CatchupPolicy::from_str("run_once"),
+            Some(CatchupPolicy::RunLatestOnly)
+        );
+        assert_eq!(CatchupPolicy::from_str("sometimes"), None);
+        assert_eq!(CatchupPolicy::from("sometimes"), CatchupPolicy::Skip);
+    }
+
     #[test]
     fn test_new_cron_schedule() {
         let now = current_timestamp();
@@ -342,12 +797,135 @@ mod tests {
             paused_at: None,
             params: None,
             instance_name: None,
+            tags: None,
+            dedup_window_secs: None,
+            dedup_keys: None,
+            lease_owner: None,
+            lease_expires_at: None,
+            fire_offset_secs: None,
+            fire_jitter_secs: None,
+            interval_secs: None,
+            interval_anchor: None,
+            min_fire_interval_ms: None,
+            debounce_window_ms: None,
+            trigger_condition: None,
+            trigger_probes: None,
+            paused_by: None,
+            pause_reason: None,
+            dst_policy: None,
         };
 
         assert!(schedule.is_trigger());
         assert!(!schedule.is_cron());
+        assert!(schedule.tag_list().is_empty());
         assert!(schedule.is_enabled());
         assert_eq!(schedule.poll_interval(), Some(Duration::from_secs(5)));
         assert!(!schedule.allows_concurrent());
+        assert!(schedule.dedup_window().is_none());
+        assert!(schedule.dedup_key_list().is_empty());
+    }
+
+    #[test]
+    fn test_dedup_configuration() {
+        let schedule =
+            NewSchedule::trigger("file_watcher", "process_files", Duration::from_secs(5))
+                .with_dedup(Some(Duration::from_secs(86_400)), ["path", " path ", ""]);
+        assert_eq!(schedule.dedup_window_secs, Some(86_400));
+        assert_eq!(schedule.dedup_keys.as_deref(), Some(r#"["path"]"#));
+
+        assert_eq!(dedup_window_secs(Duration::from_millis(1500)), 2);
+        assert_eq!(dedup_window_secs(Duration::from_millis(1)), 1);
+        assert_eq!(dedup_window_secs(Duration::from_secs(u64::MAX)), i32::MAX);
+        assert_eq!(SuppressionReason::Window.to_string(), "window");
+        assert_eq!(SuppressionReason::RateLimited.to_string(), "rate_limited");
+    }
+
+    #[test]
+    fn test_cron_fire_delay() {
+        assert_eq!(
+            cron_fire_delay("etl", None, None, None),
+            chrono::Duration::zero()
+        );
+        assert_eq!(
+            cron_fire_delay("etl", None, Some(90), None),
+            chrono::Duration::seconds(90)
+        );
+
+        // The jitter stays inside the window, is stable for a schedule, and
+        // differs between schedules sharing an expression.
+        let delays: Vec<chrono::Duration> = (0..20)
+            .map(|i| cron_fire_delay(&format!("etl_{i}"), None, Some(5), Some(30)))
+            .collect();
+        for delay in &delays {
+            assert!(*delay >= chrono::Duration::seconds(5));
+            assert!(*delay <= chrono::Duration::seconds(35));
+        }
+        assert_eq!(cron_fire_delay("etl_0", None, Some(5), Some(30)), delays[0]);
+        assert!(delays.iter().any(|d| *d != delays[0]));
+        assert_ne!(
+            cron_fire_delay("etl", Some("eu"), None, Some(30)),
+            cron_fire_delay("etl", Some("us"), None, Some(30))
+        );
+
+        let schedule = NewSchedule::cron("etl", "*/5 * * * *", current_timestamp())
+            .with_fire_spread(Duration::from_millis(1500), Duration::ZERO);
+        assert_eq!(schedule.fire_offset_secs, Some(2));
+        assert_eq!(schedule.fire_jitter_secs, None);
+        assert_eq!(schedule.fire_delay(), chrono::Duration::seconds(2));
+    }
+
+    #[test]
+    fn test_interval_schedule() {
+        use chrono::TimeZone;
+
+        let anchor = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let every_90s = IntervalSchedule::new(Duration::from_secs(90), anchor);
+        let at = |secs: i64| anchor + chrono::Duration::seconds(secs);
+
+        // Before the anchor the first fire is the anchor itself; on or after
+        // it, the next multiple of the interval.
+        assert_eq!(every_90s.next_execution(at(-600)), anchor);
+        assert_eq!(every_90s.next_execution(anchor), at(90));
+        assert_eq!(every_90s.next_execution(at(100)), at(180));
+        assert_eq!(every_90s.next_execution(at(180)), at(270));
+
+        assert_eq!(
+            every_90s.executions_between(at(0), at(360), 10),
+            vec![at(90), at(180), at(270)]
+        );
+        assert_eq!(every_90s.executions_between(at(0), at(3600), 2).len(), 2);
+
+        let new_schedule = NewSchedule::interval(
+            "poller",
+            &IntervalSchedule::new(Duration::from_millis(1500), anchor),
+            UniversalTimestamp(anchor),
+        );
+        assert_eq!(new_schedule.schedule_type, "cron");
+        assert!(new_schedule.cron_expression.is_none());
+        assert_eq!(new_schedule.interval_secs, Some(2));
+        assert_eq!(
+            new_schedule.interval_anchor,
+            Some(UniversalTimestamp(anchor))
+        );
+    }
+
+    #[test]
+    fn test_tags_normalization() {
+        let now = current_timestamp();
+        let schedule = NewSchedule::cron("wf", "0 * * * *", now).with_tags([
+            " warehouse ",
+            "nightly",
+            "warehouse",
+            "",
+        ]);
+        assert_eq!(schedule.tags.as_deref(), Some(r#"["warehouse","nightly"]"#));
+        assert_eq!(
+            parse_tags(schedule.tags.as_deref()),
+            vec!["warehouse".to_string(), "nightly".to_string()]
+        );
+
+        let untagged = NewSchedule::cron("wf", "0 * * * *", now).with_tags(["  "]);
+        assert!(untagged.tags.is_none());
+        assert!(parse_tags(Some("not json")).is_empty());
     }
 }
@@ -19,7 +19,9 @@
 //! This module defines domain structures for tracking task executions.
 //! These are API-level types; backend-specific models handle database storage.
 
+use crate::context_lineage::ContextChange;
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::TaskErrorPayload;
 use serde::{Deserialize, Serialize};
 
 /// Represents a task execution record (domain type).
@@ -45,6 +47,9 @@ pub struct TaskExecution {
     pub heartbeat_at: Option<UniversalTimestamp>,
     pub created_at: UniversalTimestamp,
     pub updated_at: UniversalTimestamp,
+    /// Structured payload the task failed with, if it returned
+    /// `TaskError::Structured`.
+    pub error_payload: Option<TaskErrorPayload>,
 }
 
 /// Structure for creating new task executions (domain type).
@@ -58,3 +63,101 @@ pub struct NewTaskExecution {
     pub trigger_rules: String,
     pub task_configuration: String,
 }
+
+/// The context keys one task attempt read and wrote, recorded by the
+/// executor when context access tracing is enabled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskContextAccess {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub attempt: i32,
+    /// Keys the task read, sorted. Includes reads of keys that were absent.
+    pub reads: Vec<String>,
+    /// Keys the task inserted, updated or removed, sorted.
+    pub writes: Vec<String>,
+    pub recorded_at: UniversalTimestamp,
+}
+
+/// The top-level context keys one successful task attempt changed, recorded
+/// by the executor when context lineage tracking is enabled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskContextChanges {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub attempt: i32,
+    /// Changed keys, sorted
+    pub changes: Vec<ContextChange>,
+    pub recorded_at: UniversalTimestamp,
+}
+
+/// A decision on a task that requires approval before it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    /// The task may run
+    Approved,
+    /// The task fails without running
+    Rejected,
+}
+
+impl ApprovalDecision {
+    /// Returns the string representation of the decision.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalDecision::Approved => "approved",
+            ApprovalDecision::Rejected => "rejected",
+        }
+    }
+
+    /// Parses a decision from its string representation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "approved" => Some(ApprovalDecision::Approved),
+            "rejected" => Some(ApprovalDecision::Rejected),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ApprovalDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// An approval decision recorded for a gated task (domain type).
+///
+/// Decisions are append-only; a task whose workflow is retried after a
+/// rejection collects a new decision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskApproval {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    pub task_execution_id: UniversalUuid,
+    pub task_name: String,
+    pub decision: ApprovalDecision,
+    pub approver: String,
+    pub comment: Option<String>,
+    pub decided_at: UniversalTimestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_decision_round_trip() {
+        for decision in [ApprovalDecision::Approved, ApprovalDecision::Rejected] {
+            assert_eq!(
+                ApprovalDecision::from_str(decision.as_str()),
+                Some(decision)
+            );
+        }
+        assert_eq!(ApprovalDecision::from_str("pending"), None);
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Workflow Deprecation Model
+//!
+//! Domain type for a workflow marked for retirement. A deprecated workflow
+//! still runs, but every execution logs a warning and the runner refuses new
+//! schedules against it until the deprecation is cleared.
+
+use crate::database::universal_types::UniversalTimestamp;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A workflow marked for retirement (domain type).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowDeprecation {
+    pub workflow_name: String,
+    /// Note for consumers, e.g. which workflow replaces this one.
+    pub message: Option<String>,
+    /// When the workflow is due to be removed, if a date was set.
+    pub sunset_at: Option<UniversalTimestamp>,
+    pub deprecated_at: UniversalTimestamp,
+    pub updated_at: UniversalTimestamp,
+}
+
+impl WorkflowDeprecation {
+    /// Whether the sunset date has passed at `now`. Always `false` without a
+    /// sunset date.
+    pub fn is_past_sunset(&self, now: DateTime<Utc>) -> bool {
+        self.sunset_at.is_some_and(|sunset| sunset.0 <= now)
+    }
+
+    /// The warning logged for each execution of the workflow at `now`.
+    pub fn warning(&self, now: DateTime<Utc>) -> String {
+        let mut warning = format!("workflow '{}' is deprecated", self.workflow_name);
+        if let Some(sunset) = self.sunset_at {
+            let when = if self.is_past_sunset(now) {
+                "was sunset on"
+            } else {
+                "sunsets on"
+            };
+            warning.push_str(&format!(" and {} {}", when, sunset.0.to_rfc3339()));
+        }
+        if let Some(message) = &self.message {
+            warning.push_str(&format!(": {}", message));
+        }
+        warning
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn deprecation(sunset_at: Option<DateTime<Utc>>, message: Option<&str>) -> WorkflowDeprecation {
+        let deprecated_at = UniversalTimestamp(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+        WorkflowDeprecation {
+            workflow_name: "nightly_etl".to_string(),
+            message: message.map(str::to_string),
+            sunset_at: sunset_at.map(UniversalTimestamp),
+            deprecated_at,
+            updated_at: deprecated_at,
+        }
+    }
+
+    #[test]
+    fn warning_reports_sunset_and_message() {
+        let sunset = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        let before = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap();
+
+        let plain = deprecation(None, None);
+        assert!(!plain.is_past_sunset(after));
+        assert_eq!(plain.warning(after), "workflow 'nightly_etl' is deprecated");
+
+        let dated = deprecation(Some(sunset), Some("use nightly_etl_v2"));
+        assert!(!dated.is_past_sunset(before));
+        assert!(dated.is_past_sunset(after));
+        assert_eq!(
+            dated.warning(before),
+            "workflow 'nightly_etl' is deprecated and sunsets on \
+             2026-06-01T00:00:00+00:00: use nightly_etl_v2"
+        );
+        assert_eq!(
+            dated.warning(after),
+            "workflow 'nightly_etl' is deprecated and was sunset on \
+             2026-06-01T00:00:00+00:00: use nightly_etl_v2"
+        );
+    }
+}
@@ -20,7 +20,12 @@
 //! These are API-level types; backend-specific models handle database storage.
 
 use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::retry::RetryPolicy;
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
 
 /// Represents a workflow execution record (domain type).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +48,21 @@ pub struct WorkflowExecutionRecord {
     /// operator run via the REST execute endpoint; `None` for cron/trigger/
     /// reactor-driven runs (and rows created before the column existed).
     pub trigger_origin: Option<String>,
+    /// Stable identifier of the run (`scheduled__2026-01-01T00:00:00Z`);
+    /// `None` for rows created before the column existed.
+    pub run_id: Option<String>,
+    /// Logical (data-interval) date the run processes, distinct from
+    /// `started_at`; `None` for rows created before the column existed.
+    pub logical_date: Option<UniversalTimestamp>,
+    /// Attempt of the whole workflow this execution is on, starting at 1;
+    /// incremented each time a workflow retry policy re-runs it.
+    pub attempt: i32,
+    /// When a re-run execution may start again; `None` unless a workflow
+    /// retry is waiting out its delay.
+    pub retry_at: Option<UniversalTimestamp>,
+    /// When the execution, queued behind a concurrency limit, was admitted
+    /// to run; `None` unless a limit applies to its workflow.
+    pub admitted_at: Option<UniversalTimestamp>,
 }
 
 /// Structure for creating new workflow executions (domain type).
@@ -53,3 +73,499 @@ pub struct NewWorkflowExecution {
     pub status: String,
     pub context_id: Option<UniversalUuid>,
 }
+
+/// Read-only parameters of a workflow execution (`iterations`,
+/// `failure_rate`, `backup_type`), set at submission and kept apart from its
+/// context. Tasks read them with `context.params()`.
+pub type ExecutionParams = serde_json::Map<String, serde_json::Value>;
+
+/// Key/value labels attached to a workflow execution at submission
+/// (`source=api`, `customer=acme`, `backfill=true`). Stored one row per label
+/// so executions can be filtered by label.
+pub type ExecutionLabels = BTreeMap<String, String>;
+
+/// Maximum length of a label key.
+pub const MAX_LABEL_KEY_LEN: usize = 63;
+
+/// Maximum length of a label value.
+pub const MAX_LABEL_VALUE_LEN: usize = 255;
+
+/// Checks that a label can be stored and later matched by a selector.
+///
+/// Keys are 1–63 ASCII alphanumerics, `_`, `-`, `.` or `/`. Values are at most
+/// 255 characters and may not contain `,` or control characters, since `,`
+/// separates the terms of a label selector.
+pub fn validate_label(key: &str, value: &str) -> Result<(), ValidationError> {
+    if key.is_empty() || key.len() > MAX_LABEL_KEY_LEN {
+        return Err(ValidationError::InvalidLabel(format!(
+            "label key '{}' must be 1-{} characters",
+            key, MAX_LABEL_KEY_LEN
+        )));
+    }
+    if !key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/'))
+    {
+        return Err(ValidationError::InvalidLabel(format!(
+            "label key '{}' may only contain ASCII letters, digits, '_', '-', '.' and '/'",
+            key
+        )));
+    }
+    if value.chars().count() > MAX_LABEL_VALUE_LEN {
+        return Err(ValidationError::InvalidLabel(format!(
+            "value of label '{}' exceeds {} characters",
+            key, MAX_LABEL_VALUE_LEN
+        )));
+    }
+    if value.chars().any(|c| c == ',' || c.is_control()) {
+        return Err(ValidationError::InvalidLabel(format!(
+            "value of label '{}' may not contain ',' or control characters",
+            key
+        )));
+    }
+    Ok(())
+}
+
+/// Checks every label in a set (see [`validate_label`]).
+pub fn validate_labels(labels: &ExecutionLabels) -> Result<(), ValidationError> {
+    labels
+        .iter()
+        .try_for_each(|(key, value)| validate_label(key, value))
+}
+
+/// Parses a label selector of comma-separated `key=value` terms
+/// (`source=api,customer=acme`). An execution matches when it carries every
+/// term. Blank terms are ignored.
+pub fn parse_label_selector(raw: &str) -> Result<Vec<(String, String)>, ValidationError> {
+    let mut terms = Vec::new();
+    for term in raw.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let (key, value) = term.split_once('=').ok_or_else(|| {
+            ValidationError::InvalidLabel(format!(
+                "label selector term '{}' must be 'key=value'",
+                term
+            ))
+        })?;
+        let (key, value) = (key.trim(), value.trim());
+        validate_label(key, value)?;
+        terms.push((key.to_string(), value.to_string()));
+    }
+    Ok(terms)
+}
+
+/// Per-execution overrides of runner configuration and task retry policies,
+/// set at submission and stored with the execution.
+///
+/// Every field defaults to "no override": the runner's
+/// [`DefaultRunnerConfig`](crate::DefaultRunnerConfig) and each task's
+/// retry policy apply.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionOptions {
+    /// Most tasks of this execution dispatched at once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_parallelism: Option<usize>,
+    /// Dispatch order against other executions' ready tasks; higher first
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub priority: i32,
+    /// Replaces every task's `max_attempts`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<i32>,
+    /// Scales every task's retry delays (`0.1` retries ten times sooner);
+    /// delays stay capped at the task's `max_delay`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_delay_multiplier: Option<f64>,
+    /// Replaces the runner's `task_timeout` for this execution's tasks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_timeout: Option<Duration>,
+    /// Replaces the runner's `workflow_timeout` when waiting on this execution
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_timeout: Option<Duration>,
+    /// Time by which the execution must finish. Tasks read the time left
+    /// from their `TaskHandle`; a task whose expected duration no longer
+    /// fits is not started, and the execution fails with `DEADLINE_EXCEEDED`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<DateTime<Utc>>,
+    /// Run id of the execution; derived from the logical date when unset.
+    /// Persisted on the execution row, not with the options.
+    #[serde(skip)]
+    pub run_id: Option<String>,
+    /// Logical date of the execution (a backfilled partition, a rerun of a
+    /// past schedule); the schedule or trigger fire time, else now, when
+    /// unset. Persisted on the execution row, not with the options.
+    #[serde(skip)]
+    pub logical_date: Option<DateTime<Utc>>,
+    /// Parameters of the execution, readable but not writable by its tasks.
+    /// Persisted in their own table, not with the options.
+    #[serde(skip)]
+    pub params: Option<ExecutionParams>,
+}
+
+fn is_zero(value: &i32) -> bool {
+    *value == 0
+}
+
+impl ExecutionOptions {
+    /// Whether no field overrides anything.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Checks that every override is usable.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let invalid = |message: &str| Err(ValidationError::InvalidExecutionOptions(message.into()));
+        if self.max_parallelism == Some(0) {
+            return invalid("max_parallelism must be > 0");
+        }
+        if self.max_attempts.is_some_and(|n| n < 0) {
+            return invalid("max_attempts must be >= 0");
+        }
+        if self
+            .retry_delay_multiplier
+            .is_some_and(|m| !m.is_finite() || m < 0.0)
+        {
+            return invalid("retry_delay_multiplier must be a finite number >= 0");
+        }
+        if self.task_timeout == Some(Duration::ZERO) {
+            return invalid("task_timeout must be > 0");
+        }
+        if self.workflow_timeout == Some(Duration::ZERO) {
+            return invalid("workflow_timeout must be > 0");
+        }
+        if let Some(run_id) = &self.run_id {
+            validate_run_id(run_id)?;
+        }
+        Ok(())
+    }
+
+    /// The runtime overrides alone, without the run identity, which is
+    /// stored on the execution row instead, or the params, which are stored
+    /// on their own.
+    pub fn overrides(&self) -> Self {
+        Self {
+            run_id: None,
+            logical_date: None,
+            params: None,
+            ..self.clone()
+        }
+    }
+
+    /// `policy` with this execution's retry overrides applied.
+    pub fn apply_to_retry_policy(&self, policy: &RetryPolicy) -> RetryPolicy {
+        let mut policy = policy.clone();
+        if let Some(max_attempts) = self.max_attempts {
+            policy.max_attempts = max_attempts;
+        }
+        if let Some(multiplier) = self.retry_delay_multiplier {
+            // Every backoff strategy scales linearly with the initial delay.
+            policy.initial_delay = policy.initial_delay.mul_f64(multiplier);
+        }
+        policy
+    }
+}
+
+/// Maximum length of a run id.
+pub const MAX_RUN_ID_LEN: usize = 250;
+
+/// Checks that a run id is 1–250 ASCII alphanumerics, `_`, `-`, `.`, `:`
+/// or `+`, so it can name partitions and paths as is.
+pub fn validate_run_id(run_id: &str) -> Result<(), ValidationError> {
+    if run_id.is_empty() || run_id.len() > MAX_RUN_ID_LEN {
+        return Err(ValidationError::InvalidExecutionOptions(format!(
+            "run_id must be 1-{} characters",
+            MAX_RUN_ID_LEN
+        )));
+    }
+    if !run_id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '+'))
+    {
+        return Err(ValidationError::InvalidExecutionOptions(format!(
+            "run_id '{}' may only contain ASCII letters, digits, '_', '-', '.', ':' and '+'",
+            run_id
+        )));
+    }
+    Ok(())
+}
+
+/// Run id generated for an execution submitted without one:
+/// `<kind>__<logical date>`, where `kind` is `scheduled`, `triggered` or
+/// `manual`.
+pub fn default_run_id(kind: &str, logical_date: DateTime<Utc>) -> String {
+    format!(
+        "{}__{}",
+        kind,
+        logical_date.to_rfc3339_opts(SecondsFormat::Micros, true)
+    )
+}
+
+/// Resolution status an operator records on an execution through an
+/// annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExecutionResolution {
+    /// The failure is being looked into
+    Investigating,
+    /// The underlying problem was fixed; no further action needed
+    Resolved,
+    /// The work was re-run manually outside this execution
+    Rerun,
+    /// The failure is known and intentionally left alone
+    Ignored,
+}
+
+impl ExecutionResolution {
+    /// Returns the string representation of the resolution status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionResolution::Investigating => "investigating",
+            ExecutionResolution::Resolved => "resolved",
+            ExecutionResolution::Rerun => "rerun",
+            ExecutionResolution::Ignored => "ignored",
+        }
+    }
+
+    /// Parses a resolution status from its string representation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "investigating" => Some(ExecutionResolution::Investigating),
+            "resolved" => Some(ExecutionResolution::Resolved),
+            "rerun" => Some(ExecutionResolution::Rerun),
+            "ignored" => Some(ExecutionResolution::Ignored),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ExecutionResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Maximum length of an annotation note, in characters.
+pub const MAX_ANNOTATION_NOTE_LEN: usize = 4096;
+
+/// Maximum length of an annotation author, in characters.
+pub const MAX_ANNOTATION_AUTHOR_LEN: usize = 255;
+
+/// An operator note attached to a workflow execution (domain type).
+///
+/// Annotations are append-only. The most recent annotation carrying a
+/// resolution is the execution's current resolution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionAnnotation {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    /// Who wrote the note (the API key or account name on the server).
+    pub author: String,
+    pub note: String,
+    /// Resolution status set by this annotation, if any (see
+    /// [`ExecutionResolution::as_str`]).
+    pub resolution: Option<String>,
+    pub created_at: UniversalTimestamp,
+}
+
+/// A named file a task stored for its workflow execution (domain type);
+/// see [`crate::artifacts`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionArtifact {
+    pub id: UniversalUuid,
+    pub workflow_execution_id: UniversalUuid,
+    /// The task execution that stored it; `None` once that row is gone.
+    pub task_execution_id: Option<UniversalUuid>,
+    pub task_name: String,
+    /// File name, unique within the execution (`report.pdf`).
+    pub name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: UniversalTimestamp,
+}
+
+/// Structure for recording a new execution annotation (domain type).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewExecutionAnnotation {
+    pub author: String,
+    pub note: String,
+    pub resolution: Option<ExecutionResolution>,
+}
+
+impl NewExecutionAnnotation {
+    /// Creates an annotation with no resolution status.
+    pub fn new(author: impl Into<String>, note: impl Into<String>) -> Self {
+        Self {
+            author: author.into(),
+            note: note.into(),
+            resolution: None,
+        }
+    }
+
+    /// Builder-style: set the resolution status recorded with the note.
+    pub fn with_resolution(mut self, resolution: ExecutionResolution) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    /// Checks that the author and note are non-blank and within length limits.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.author.trim().is_empty() {
+            return Err(ValidationError::InvalidAnnotation(
+                "author may not be empty".to_string(),
+            ));
+        }
+        if self.author.chars().count() > MAX_ANNOTATION_AUTHOR_LEN {
+            return Err(ValidationError::InvalidAnnotation(format!(
+                "author exceeds {} characters",
+                MAX_ANNOTATION_AUTHOR_LEN
+            )));
+        }
+        if self.note.trim().is_empty() {
+            return Err(ValidationError::InvalidAnnotation(
+                "note may not be empty".to_string(),
+            ));
+        }
+        if self.note.chars().count() > MAX_ANNOTATION_NOTE_LEN {
+            return Err(ValidationError::InvalidAnnotation(format!(
+                "note exceeds {} characters",
+                MAX_ANNOTATION_NOTE_LEN
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_label() {
+        assert!(validate_label("source", "api").is_ok());
+        assert!(validate_label("team.io/owner", "data-eng").is_ok());
+        assert!(validate_label("backfill", "").is_ok());
+        assert!(validate_label("", "x").is_err());
+        assert!(validate_label("has space", "x").is_err());
+        assert!(validate_label(&"k".repeat(64), "x").is_err());
+        assert!(validate_label("k", "a,b").is_err());
+        assert!(validate_label("k", &"v".repeat(256)).is_err());
+    }
+
+    #[test]
+    fn test_parse_label_selector() {
+        let terms = parse_label_selector("source=api, customer=acme,").unwrap();
+        assert_eq!(
+            terms,
+            vec![
+                ("source".to_string(), "api".to_string()),
+                ("customer".to_string(), "acme".to_string()),
+            ]
+        );
+        assert!(parse_label_selector("").unwrap().is_empty());
+        assert!(parse_label_selector("source").is_err());
+    }
+
+    #[test]
+    fn test_execution_options_validate() {
+        assert!(ExecutionOptions::default().validate().is_ok());
+        let options = ExecutionOptions {
+            max_parallelism: Some(0),
+            ..Default::default()
+        };
+        assert!(options.validate().is_err());
+        let options = ExecutionOptions {
+            retry_delay_multiplier: Some(f64::NAN),
+            ..Default::default()
+        };
+        assert!(options.validate().is_err());
+        let options = ExecutionOptions {
+            task_timeout: Some(Duration::ZERO),
+            ..Default::default()
+        };
+        assert!(options.validate().is_err());
+    }
+
+    #[test]
+    fn test_execution_options_apply_to_retry_policy() {
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            ExecutionOptions::default().apply_to_retry_policy(&policy),
+            policy
+        );
+
+        let options = ExecutionOptions {
+            max_attempts: Some(7),
+            retry_delay_multiplier: Some(0.5),
+            ..Default::default()
+        };
+        let applied = options.apply_to_retry_policy(&policy);
+        assert_eq!(applied.max_attempts, 7);
+        assert_eq!(applied.initial_delay, policy.initial_delay / 2);
+        assert_eq!(applied.max_delay, policy.max_delay);
+    }
+
+    #[test]
+    fn test_default_execution_options_serialize_empty() {
+        assert_eq!(
+            serde_json::to_string(&ExecutionOptions::default()).unwrap(),
+            "{}"
+        );
+        let options = ExecutionOptions {
+            priority: 5,
+            deadline: Some(Utc::now()),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ExecutionOptions>(&json).unwrap(),
+            options
+        );
+    }
+
+    #[test]
+    fn test_run_id() {
+        let date = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let run_id = default_run_id("scheduled", date);
+        assert_eq!(run_id, "scheduled__2026-01-01T00:00:00.000000Z");
+        assert!(validate_run_id(&run_id).is_ok());
+        assert!(validate_run_id("backfill_2026-01+01").is_ok());
+        assert!(validate_run_id("").is_err());
+        assert!(validate_run_id("has space").is_err());
+        assert!(validate_run_id(&"r".repeat(MAX_RUN_ID_LEN + 1)).is_err());
+
+        let options = ExecutionOptions {
+            run_id: Some("backfill".to_string()),
+            logical_date: Some(date),
+            ..Default::default()
+        };
+        assert!(options.overrides().is_default());
+        assert_eq!(serde_json::to_string(&options).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_execution_resolution_round_trip() {
+        for r in [
+            ExecutionResolution::Investigating,
+            ExecutionResolution::Resolved,
+            ExecutionResolution::Rerun,
+            ExecutionResolution::Ignored,
+        ] {
+            assert_eq!(ExecutionResolution::from_str(r.as_str()), Some(r));
+        }
+        assert_eq!(ExecutionResolution::from_str("done"), None);
+    }
+
+    #[test]
+    fn test_new_execution_annotation_validate() {
+        assert!(NewExecutionAnnotation::new("ops", "known outage")
+            .with_resolution(ExecutionResolution::Rerun)
+            .validate()
+            .is_ok());
+        assert!(NewExecutionAnnotation::new("", "note").validate().is_err());
+        assert!(NewExecutionAnnotation::new("ops", "  ").validate().is_err());
+        assert!(
+            NewExecutionAnnotation::new("ops", "n".repeat(MAX_ANNOTATION_NOTE_LEN + 1))
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Declared workflow and task outputs — re-export of the canonical helpers.
+//!
+//! The implementation lives in `cloacina-workflow` because the
+//! `#[workflow(outputs(...))]` macro emits references to it into **packaged
+//! cdylibs**, which depend on `cloacina-workflow`, not core `cloacina`.
+//! Embedded workflows reach the same items through this re-export, and
+//! [`WorkflowExecutionResult::outputs`](crate::executor::WorkflowExecutionResult::outputs)
+//! is the host-side entry point. [`check_task_outputs`] is the executors'
+//! entry point for the per-task `produces` declarations.
+
+pub use cloacina_workflow::outputs::{
+    check_produced, extract_output, OutputContext, OutputKind, OutputViolation, OutputsError,
+    ProducedOutput, WorkflowOutputs, OUTPUTS_SURFACE_KIND,
+};
+
+use crate::error::{ErrorCategory, TaskError, TaskErrorPayload, OUTPUT_SCHEMA_VIOLATION_CODE};
+use crate::task::Task;
+
+/// Checks a task's output context against its declared
+/// [`produces`](Task::produces) outputs.
+///
+/// Fails with an `OUTPUT_SCHEMA_VIOLATION` payload (category `validation`)
+/// listing every unmet declaration under `details.violations`.
+pub fn check_task_outputs(task: &dyn Task, context: &OutputContext) -> Result<(), TaskError> {
+    let violations = check_produced(context, &task.produces());
+    if violations.is_empty() {
+        return Ok(());
+    }
+    let message = format!(
+        "output does not match declared outputs: {}",
+        violations
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join("; ")
+    );
+    Err(TaskError::structured(
+        task.id(),
+        TaskErrorPayload::new(OUTPUT_SCHEMA_VIOLATION_CODE, message)
+            .with_category(ErrorCategory::Validation)
+            .with_details(serde_json::json!({ "violations": violations })),
+    ))
+}
@@ -0,0 +1,190 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Prebuilt per-architecture binaries bundled inside a `.cloacina` archive.
+//!
+//! A Rust package may ship compiled cdylibs next to its source, one per
+//! target, under `targets/<triple>/`:
+//!
+//! ```text
+//! etl-1.0.0/
+//! ├── package.toml
+//! ├── Cargo.toml
+//! ├── src/
+//! └── targets/
+//!     ├── x86_64-linux/libetl.so
+//!     └── aarch64-linux/libetl.so
+//! ```
+//!
+//! `<triple>` uses the `{arch}-{os}` form of
+//! [`host_target_triple`](crate::fleet::protocol::host_target_triple), so a
+//! host looks up its own directory directly. The compiler uses the bundled
+//! binary for its target instead of running cargo, and the reconciler prefers
+//! the one for its host over a build made on a different architecture. One
+//! published archive therefore serves x86_64 and aarch64 runners alike.
+
+use std::path::{Path, PathBuf};
+
+/// Directory, relative to the package root, holding bundled binaries.
+pub const BUNDLED_TARGETS_DIR: &str = "targets";
+
+/// File extensions recognised as a bundled cdylib.
+const CDYLIB_EXTENSIONS: &[&str] = &["so", "dylib", "dll"];
+
+/// Normalise a target triple to the `{arch}-{os}` form used for bundled
+/// binaries. Accepts that form as-is or a full cargo triple
+/// (`aarch64-unknown-linux-gnu`, `x86_64-apple-darwin`, ...). Returns `None`
+/// for an architecture or OS this cannot identify.
+pub fn normalize_target_triple(triple: &str) -> Option<String> {
+    let mut parts = triple.split('-');
+    let arch = match parts.next()? {
+        "x86_64" | "amd64" => "x86_64",
+        "aarch64" | "arm64" => "aarch64",
+        _ => return None,
+    };
+    let os = parts.find_map(|part| match part {
+        "linux" => Some("linux"),
+        "darwin" | "macos" => Some("macos"),
+        "windows" => Some("windows"),
+        _ => None,
+    })?;
+    Some(format!("{arch}-{os}"))
+}
+
+/// Targets with a bundled binary in the unpacked package at `package_dir`,
+/// sorted. Empty when the package bundles none.
+pub fn bundled_targets(package_dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(package_dir.join(BUNDLED_TARGETS_DIR)) else {
+        return Vec::new();
+    };
+    let mut targets: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    targets.sort();
+    targets
+}
+
+/// Path of the binary bundled for `triple` in the unpacked package at
+/// `package_dir`, or `None` if it bundles none for that target.
+///
+/// Errors when the target directory holds more than one cdylib, since the
+/// choice would be ambiguous.
+pub fn find_bundled_binary(package_dir: &Path, triple: &str) -> Result<Option<PathBuf>, String> {
+    let dir = package_dir.join(BUNDLED_TARGETS_DIR).join(triple);
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(None);
+    };
+    let mut found: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| CDYLIB_EXTENSIONS.contains(&ext))
+        })
+        .collect();
+    match found.len() {
+        0 => Ok(None),
+        1 => Ok(found.pop()),
+        n => Err(format!(
+            "{} holds {} cdylibs; bundle exactly one per target",
+            dir.display(),
+            n
+        )),
+    }
+}
+
+/// Read the binary bundled for `triple`, if any.
+pub fn read_bundled_binary(package_dir: &Path, triple: &str) -> Result<Option<Vec<u8>>, String> {
+    match find_bundled_binary(package_dir, triple)? {
+        Some(path) => std::fs::read(&path)
+            .map(Some)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_cargo_triples_and_passes_through_short_form() {
+        assert_eq!(
+            normalize_target_triple("x86_64-unknown-linux-gnu").as_deref(),
+            Some("x86_64-linux")
+        );
+        assert_eq!(
+            normalize_target_triple("aarch64-apple-darwin").as_deref(),
+            Some("aarch64-macos")
+        );
+        assert_eq!(
+            normalize_target_triple("aarch64-linux").as_deref(),
+            Some("aarch64-linux")
+        );
+        assert_eq!(normalize_target_triple("riscv64gc-unknown-linux-gnu"), None);
+        assert_eq!(normalize_target_triple("x86_64-unknown-none"), None);
+    }
+
+    #[test]
+    fn host_triple_is_already_normalized() {
+        let host = crate::fleet::protocol::host_target_triple();
+        if let Some(normalized) = normalize_target_triple(&host) {
+            assert_eq!(normalized, host);
+        }
+    }
+
+    #[test]
+    fn finds_the_binary_for_a_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let x86 = dir.path().join("targets/x86_64-linux");
+        let arm = dir.path().join("targets/aarch64-linux");
+        std::fs::create_dir_all(&x86).unwrap();
+        std::fs::create_dir_all(&arm).unwrap();
+        std::fs::write(x86.join("libetl.so"), b"x86").unwrap();
+        std::fs::write(arm.join("libetl.so"), b"arm").unwrap();
+        std::fs::write(arm.join("README.md"), b"not a library").unwrap();
+
+        assert_eq!(
+            bundled_targets(dir.path()),
+            vec!["aarch64-linux".to_string(), "x86_64-linux".to_string()]
+        );
+        assert_eq!(
+            read_bundled_binary(dir.path(), "aarch64-linux").unwrap(),
+            Some(b"arm".to_vec())
+        );
+        assert_eq!(
+            read_bundled_binary(dir.path(), "x86_64-macos").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_ambiguous_target_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let x86 = dir.path().join("targets/x86_64-linux");
+        std::fs::create_dir_all(&x86).unwrap();
+        std::fs::write(x86.join("liba.so"), b"a").unwrap();
+        std::fs::write(x86.join("libb.so"), b"b").unwrap();
+
+        assert!(find_bundled_binary(dir.path(), "x86_64-linux").is_err());
+        assert!(bundled_targets(tempfile::tempdir().unwrap().path()).is_empty());
+    }
+}
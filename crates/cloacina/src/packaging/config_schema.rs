@@ -0,0 +1,366 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Package configuration schemas.
+//!
+//! A package names a JSON Schema document in `package.toml`
+//! (`[metadata] config_schema = "config.schema.json"`). Registering the
+//! package requires configuration values that validate against it; the
+//! registry stores the validated values (with schema defaults filled in) on
+//! the package metadata, and the runner hands them to the package's tasks as
+//! `context.package_config()`.
+//!
+//! Validation covers the JSON Schema keywords configuration documents use:
+//! `type`, `enum`, `const`, `properties`, `required`, `additionalProperties`,
+//! `items`, `minItems`/`maxItems`, `minLength`/`maxLength`, and
+//! `minimum`/`maximum`/`exclusiveMinimum`/`exclusiveMaximum`. Other keywords
+//! are ignored rather than rejected.
+
+use std::path::{Component, Path};
+
+use serde_json::{Map, Value};
+
+/// Read the configuration schema the package at `package_dir` declares at
+/// `schema_path` (relative to the package root). `None` when the package
+/// declares no schema. Errors when the path escapes the package, the file is
+/// missing, or it is not a JSON object.
+pub fn read_config_schema(
+    package_dir: &Path,
+    schema_path: Option<&str>,
+) -> Result<Option<Value>, String> {
+    let Some(schema_path) = schema_path else {
+        return Ok(None);
+    };
+    let relative = Path::new(schema_path);
+    if relative
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(format!(
+            "config_schema '{}' must be a relative path inside the package",
+            schema_path
+        ));
+    }
+    let path = package_dir.join(relative);
+    let json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read config_schema {}: {}", schema_path, e))?;
+    let schema: Value = serde_json::from_str(&json)
+        .map_err(|e| format!("invalid config_schema {}: {}", schema_path, e))?;
+    if !schema.is_object() {
+        return Err(format!(
+            "config_schema {} must be a JSON Schema object",
+            schema_path
+        ));
+    }
+    Ok(Some(schema))
+}
+
+/// Validate `config` against `schema` and return it with the defaults of
+/// omitted object properties filled in. A missing `config` is validated as
+/// an empty object, so a schema whose properties are all optional accepts a
+/// registration without values.
+///
+/// On failure, returns one message per violation, each prefixed with the
+/// JSON pointer of the offending value.
+pub fn validate_config(schema: &Value, config: Option<Value>) -> Result<Value, Vec<String>> {
+    let mut config = config.unwrap_or_else(|| Value::Object(Map::new()));
+    apply_defaults(schema, &mut config);
+    let mut errors = Vec::new();
+    check(schema, &config, "", &mut errors);
+    if errors.is_empty() {
+        Ok(config)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Fill in `default`s of properties absent from `value`, recursively.
+fn apply_defaults(schema: &Value, value: &mut Value) {
+    let (Some(properties), Some(object)) = (
+        schema.get("properties").and_then(Value::as_object),
+        value.as_object_mut(),
+    ) else {
+        return;
+    };
+    for (name, property) in properties {
+        match object.get_mut(name) {
+            Some(existing) => apply_defaults(property, existing),
+            None => {
+                if let Some(default) = property.get("default") {
+                    object.insert(name.clone(), default.clone());
+                }
+            }
+        }
+    }
+}
+
+fn check(schema: &Value, value: &Value, pointer: &str, errors: &mut Vec<String>) {
+    let at = if pointer.is_empty() { "/" } else { pointer };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| matches_type(value, t)) {
+            errors.push(format!(
+                "{}: expected {}, got {}",
+                at,
+                allowed.join(" or "),
+                type_name(value)
+            ));
+            // Further keywords assume the declared type.
+            return;
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            errors.push(format!(
+                "{}: must be one of {}",
+                at,
+                Value::from(options.clone())
+            ));
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            errors.push(format!("{}: must equal {}", at, constant));
+        }
+    }
+
+    match value {
+        Value::Object(object) => check_object(schema, object, pointer, errors),
+        Value::Array(items) => {
+            check_len(
+                schema,
+                items.len(),
+                "minItems",
+                "maxItems",
+                "items",
+                at,
+                errors,
+            );
+            if let Some(item_schema) = schema.get("items").filter(|s| s.is_object()) {
+                for (i, item) in items.iter().enumerate() {
+                    check(item_schema, item, &format!("{}/{}", pointer, i), errors);
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count();
+            check_len(
+                schema,
+                len,
+                "minLength",
+                "maxLength",
+                "characters",
+                at,
+                errors,
+            );
+        }
+        Value::Number(n) => {
+            if let Some(n) = n.as_f64() {
+                check_range(schema, n, at, errors);
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+}
+
+fn check_object(
+    schema: &Value,
+    object: &Map<String, Value>,
+    pointer: &str,
+    errors: &mut Vec<String>,
+) {
+    let at = if pointer.is_empty() { "/" } else { pointer };
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        for name in required.iter().filter_map(Value::as_str) {
+            if !object.contains_key(name) {
+                errors.push(format!("{}: missing required property '{}'", at, name));
+            }
+        }
+    }
+
+    for (name, value) in object {
+        let child = format!("{}/{}", pointer, name);
+        match properties.and_then(|p| p.get(name)) {
+            Some(property) => check(property, value, &child, errors),
+            None => match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => {
+                    errors.push(format!("{}: unknown property '{}'", at, name));
+                }
+                Some(extra @ Value::Object(_)) => check(extra, value, &child, errors),
+                _ => {}
+            },
+        }
+    }
+}
+
+fn check_len(
+    schema: &Value,
+    len: usize,
+    min_key: &str,
+    max_key: &str,
+    unit: &str,
+    at: &str,
+    errors: &mut Vec<String>,
+) {
+    if let Some(min) = schema.get(min_key).and_then(Value::as_u64) {
+        if (len as u64) < min {
+            errors.push(format!("{}: must have at least {} {}", at, min, unit));
+        }
+    }
+    if let Some(max) = schema.get(max_key).and_then(Value::as_u64) {
+        if (len as u64) > max {
+            errors.push(format!("{}: must have at most {} {}", at, max, unit));
+        }
+    }
+}
+
+fn check_range(schema: &Value, n: f64, at: &str, errors: &mut Vec<String>) {
+    let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+    if let Some(min) = bound("minimum") {
+        if n < min {
+            errors.push(format!("{}: must be >= {}", at, min));
+        }
+    }
+    if let Some(max) = bound("maximum") {
+        if n > max {
+            errors.push(format!("{}: must be <= {}", at, max));
+        }
+    }
+    if let Some(min) = bound("exclusiveMinimum") {
+        if n <= min {
+            errors.push(format!("{}: must be > {}", at, min));
+        }
+    }
+    if let Some(max) = bound("exclusiveMaximum") {
+        if n >= max {
+            errors.push(format!("{}: must be < {}", at, max));
+        }
+    }
+}
+
+fn matches_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        // Unknown type names are not ours to reject.
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["warehouse_url"],
+            "additionalProperties": false,
+            "properties": {
+                "warehouse_url": {"type": "string", "minLength": 1},
+                "batch_size": {"type": "integer", "minimum": 1, "maximum": 10000, "default": 500},
+                "mode": {"enum": ["full", "incremental"], "default": "incremental"},
+                "regions": {"type": "array", "items": {"type": "string"}, "maxItems": 3}
+            }
+        })
+    }
+
+    #[test]
+    fn valid_config_gets_defaults() {
+        let config = validate_config(&schema(), Some(json!({"warehouse_url": "pg://wh"}))).unwrap();
+        assert_eq!(
+            config,
+            json!({"warehouse_url": "pg://wh", "batch_size": 500, "mode": "incremental"})
+        );
+    }
+
+    #[test]
+    fn violations_are_reported_by_pointer() {
+        let errors = validate_config(
+            &schema(),
+            Some(json!({
+                "batch_size": 0,
+                "mode": "sometimes",
+                "regions": ["eu", 7],
+                "colour": "blue"
+            })),
+        )
+        .unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("missing required property 'warehouse_url'")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("/batch_size: must be >= 1")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("/mode: must be one of")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("/regions/1: expected string")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("unknown property 'colour'")));
+        assert_eq!(errors.len(), 5, "{errors:?}");
+    }
+
+    #[test]
+    fn missing_config_validates_as_empty_object() {
+        let optional =
+            json!({"type": "object", "properties": {"x": {"type": "integer", "default": 1}}});
+        assert_eq!(validate_config(&optional, None).unwrap(), json!({"x": 1}));
+        assert!(validate_config(&schema(), None).is_err());
+    }
+
+    #[test]
+    fn read_rejects_paths_outside_the_package() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("config.schema.json"), schema().to_string()).unwrap();
+        assert_eq!(
+            read_config_schema(dir.path(), Some("config.schema.json")).unwrap(),
+            Some(schema())
+        );
+        assert_eq!(read_config_schema(dir.path(), None).unwrap(), None);
+        assert!(read_config_schema(dir.path(), Some("../config.schema.json")).is_err());
+        assert!(read_config_schema(dir.path(), Some("/etc/passwd")).is_err());
+        assert!(read_config_schema(dir.path(), Some("missing.json")).is_err());
+    }
+}
@@ -0,0 +1,615 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Dry-run of a packaged workflow — the checks behind
+//! `cloacinactl package check`.
+//!
+//! Takes a `.cloacina` archive (or its unpacked directory) through the same
+//! steps the reconciler runs when it loads a Rust package: unpack, resolve
+//! `package.toml`, pick the cdylib for this host, read its task metadata over
+//! FFI, register the tasks, construct their namespaces and build the
+//! workflow. Nothing is written anywhere but a temp dir: tasks are registered
+//! into a private, empty [`Runtime`] that is dropped afterwards, no database
+//! is opened, and no task body, trigger or package health check is invoked.
+//!
+//! Like [`crate::doctor`], running the checks never fails. A step that
+//! cannot complete reports [`CheckStatus::Fail`] with a fix, and the steps
+//! that depend on it are reported as skipped. Python packages stop after the
+//! manifest: they carry no cdylib, and importing their module would run
+//! package code.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::Serialize;
+use tempfile::TempDir;
+
+use crate::doctor::CheckStatus;
+use crate::registry::loader::package_loader::PackageMetadata;
+use crate::registry::loader::{PackageLoader, TaskRegistrar};
+use crate::registry::reconciler::RegistryReconciler;
+use crate::task::TaskNamespace;
+use crate::Runtime;
+
+/// One dry-run step result.
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageCheck {
+    /// Stable step identifier (`unpack`, `manifest`, ...).
+    pub name: &'static str,
+    pub status: CheckStatus,
+    /// What was observed.
+    pub detail: String,
+    /// What to do about it, when the status is `Fail`.
+    pub fix: Option<String>,
+}
+
+impl PackageCheck {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn skip(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Skip,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// A task as the dry-run registered it.
+#[derive(Debug, Clone, Serialize)]
+pub struct DryRunTask {
+    /// Fully qualified task namespace.
+    pub namespace: String,
+    /// Fully qualified namespaces of the task's dependencies.
+    pub dependencies: Vec<String>,
+}
+
+/// Tunables for [`dry_run_package`].
+#[derive(Debug, Clone)]
+pub struct DryRunOptions {
+    /// Compiled cdylib to load instead of the binary the archive bundles for
+    /// this host (e.g. the output of `cloacinactl package build`).
+    pub library: Option<PathBuf>,
+    /// Tenant the task namespaces are constructed under (default `public`).
+    pub tenant_id: String,
+}
+
+impl Default for DryRunOptions {
+    fn default() -> Self {
+        Self {
+            library: None,
+            tenant_id: "public".to_string(),
+        }
+    }
+}
+
+/// Machine-readable result of a dry-run.
+#[derive(Debug, Clone, Serialize)]
+pub struct DryRunReport {
+    /// From `package.toml`; `None` when the manifest could not be read.
+    pub package_name: Option<String>,
+    pub version: Option<String>,
+    pub language: Option<String>,
+    /// Workflow the package registers, once its metadata was read.
+    pub workflow_name: Option<String>,
+    pub tenant_id: String,
+    /// Registered tasks, in package order.
+    pub tasks: Vec<DryRunTask>,
+    /// Tasks grouped by the level they would run at; empty unless the
+    /// workflow built.
+    pub execution_levels: Vec<Vec<String>>,
+    /// Step results, in the order they ran.
+    pub checks: Vec<PackageCheck>,
+}
+
+impl DryRunReport {
+    /// True when no step failed.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.status != CheckStatus::Fail)
+    }
+}
+
+/// Steps after `unpack`, in run order.
+const DRY_RUN_CHECKS: [&str; 6] = [
+    "manifest",
+    "library",
+    "metadata",
+    "task_registration",
+    "namespaces",
+    "workflow_build",
+];
+
+/// Load the package at `path` in check mode and report each step.
+pub async fn dry_run_package(path: &Path, options: &DryRunOptions) -> DryRunReport {
+    let mut report = DryRunReport {
+        package_name: None,
+        version: None,
+        language: None,
+        workflow_name: None,
+        tenant_id: options.tenant_id.clone(),
+        tasks: Vec::new(),
+        execution_levels: Vec::new(),
+        checks: Vec::new(),
+    };
+    run_checks(path, options, &mut report).await;
+
+    // Everything after the step that stopped the run is reported as skipped.
+    let ran: HashSet<&str> = report.checks.iter().map(|c| c.name).collect();
+    for name in DRY_RUN_CHECKS {
+        if !ran.contains(name) {
+            report.checks.push(PackageCheck::skip(
+                name,
+                "not run: an earlier step stopped the dry-run",
+            ));
+        }
+    }
+    report
+}
+
+async fn run_checks(path: &Path, options: &DryRunOptions, report: &mut DryRunReport) {
+    // --- unpack ---
+    // The TempDir is held until the end of the function so the extracted
+    // tree outlives the remaining steps.
+    let _staging: Option<TempDir>;
+    let dir = if path.is_dir() {
+        _staging = None;
+        report.checks.push(PackageCheck::ok(
+            "unpack",
+            "source directory, nothing to unpack",
+        ));
+        path.to_path_buf()
+    } else {
+        let tmp = match TempDir::new() {
+            Ok(tmp) => tmp,
+            Err(e) => {
+                report.checks.push(PackageCheck::fail(
+                    "unpack",
+                    format!("cannot create a temp dir: {}", e),
+                    "check that the system temp directory is writable",
+                ));
+                return;
+            }
+        };
+        let archive = path.to_path_buf();
+        let extract_dir = tmp.path().to_path_buf();
+        let unpacked = tokio::task::spawn_blocking(move || {
+            fidius_core::package::unpack_package(&archive, &extract_dir).map_err(|e| e.to_string())
+        })
+        .await
+        .unwrap_or_else(|e| Err(format!("unpack thread failed: {}", e)));
+        match unpacked {
+            Ok(dir) => {
+                _staging = Some(tmp);
+                report.checks.push(PackageCheck::ok(
+                    "unpack",
+                    format!("unpacked {}", path.display()),
+                ));
+                dir
+            }
+            Err(e) => {
+                report.checks.push(PackageCheck::fail(
+                    "unpack",
+                    format!("failed to unpack {}: {}", path.display(), e),
+                    "pass a .cloacina archive produced by `cloacinactl package pack`",
+                ));
+                return;
+            }
+        }
+    };
+
+    // --- manifest ---
+    let manifest = match cloacina_workflow_plugin::manifest::load_resolved_manifest(&dir) {
+        Ok(m) => m,
+        Err(e) => {
+            report.checks.push(PackageCheck::fail(
+                "manifest",
+                format!("package.toml: {}", e),
+                "run `cloacinactl package validate` for the full manifest diagnostics",
+            ));
+            return;
+        }
+    };
+    report.package_name = Some(manifest.package.name.clone());
+    report.version = Some(manifest.package.version.clone());
+    report.language = Some(manifest.metadata.language.clone());
+    report.checks.push(PackageCheck::ok(
+        "manifest",
+        format!(
+            "{} {} ({})",
+            manifest.package.name, manifest.package.version, manifest.metadata.language
+        ),
+    ));
+
+    if manifest.metadata.language != "rust" {
+        let detail = format!(
+            "{} packages carry no cdylib; loading one imports its module, which runs package code",
+            manifest.metadata.language
+        );
+        for name in DRY_RUN_CHECKS[1..].iter().copied() {
+            report.checks.push(PackageCheck::skip(name, detail.clone()));
+        }
+        return;
+    }
+
+    // --- library ---
+    let host_triple = crate::fleet::protocol::host_target_triple();
+    let library = match &options.library {
+        Some(lib) => std::fs::read(lib)
+            .map(|bytes| (bytes, lib.display().to_string()))
+            .map_err(|e| format!("failed to read {}: {}", lib.display(), e)),
+        None => match super::read_bundled_binary(&dir, &host_triple) {
+            Ok(Some(bytes)) => Ok((bytes, format!("bundled binary for '{}'", host_triple))),
+            Ok(None) => Err(format!(
+                "the archive bundles no binary for '{}' and no library was given",
+                host_triple
+            )),
+            Err(e) => Err(e),
+        },
+    };
+    let library_data = match library {
+        Ok((bytes, source)) => {
+            report.checks.push(PackageCheck::ok(
+                "library",
+                format!("{} ({} bytes)", source, bytes.len()),
+            ));
+            bytes
+        }
+        Err(e) => {
+            report.checks.push(PackageCheck::fail(
+                "library",
+                e,
+                "build the package with `cloacinactl package build` and pass the cdylib \
+                 with --library, or bundle one with `cloacinactl package pack --binary`",
+            ));
+            return;
+        }
+    };
+
+    // --- metadata ---
+    let loader = match PackageLoader::new() {
+        Ok(loader) => loader,
+        Err(e) => {
+            report.checks.push(PackageCheck::fail(
+                "metadata",
+                e.to_string(),
+                "check that the system temp directory is writable",
+            ));
+            return;
+        }
+    };
+    let metadata = match loader.extract_metadata(&library_data).await {
+        Ok(m) => m,
+        Err(e) => {
+            report.checks.push(PackageCheck::fail(
+                "metadata",
+                e.to_string(),
+                format!(
+                    "rebuild the cdylib for '{}' against this cloacina version",
+                    host_triple
+                ),
+            ));
+            return;
+        }
+    };
+    let workflow_name = if metadata.workflow_name.is_empty() {
+        metadata.package_name.clone()
+    } else {
+        metadata.workflow_name.clone()
+    };
+    report.workflow_name = Some(workflow_name.clone());
+    report.checks.push(PackageCheck::ok(
+        "metadata",
+        format!(
+            "workflow '{}' with {} task(s)",
+            workflow_name,
+            metadata.tasks.len()
+        ),
+    ));
+    if metadata.tasks.is_empty() {
+        for name in DRY_RUN_CHECKS[3..].iter().copied() {
+            report.checks.push(PackageCheck::skip(
+                name,
+                "the package declares no workflow tasks",
+            ));
+        }
+        return;
+    }
+
+    // --- task_registration ---
+    // A private runtime: nothing registered here is visible to any runner.
+    let runtime = Arc::new(Runtime::empty());
+    let registrar = match TaskRegistrar::with_handle_cache(loader.handle_cache()) {
+        Ok(registrar) => registrar,
+        Err(e) => {
+            report.checks.push(PackageCheck::fail(
+                "task_registration",
+                e.to_string(),
+                "check that the system temp directory is writable",
+            ));
+            return;
+        }
+    };
+    let package_id = format!("dry-run:{}", metadata.package_name);
+    let registered = registrar
+        .register_package_tasks(
+            &package_id,
+            &library_data,
+            &metadata,
+            Some(&options.tenant_id),
+            &runtime,
+        )
+        .await;
+    let namespaces = match registered {
+        Ok(namespaces) => {
+            report.checks.push(PackageCheck::ok(
+                "task_registration",
+                format!("registered {} task(s)", namespaces.len()),
+            ));
+            namespaces
+        }
+        Err(e) => {
+            report.checks.push(PackageCheck::fail(
+                "task_registration",
+                e.to_string(),
+                "fix the task declarations the error names and rebuild",
+            ));
+            return;
+        }
+    };
+    report.tasks = namespaces
+        .iter()
+        .map(|ns| DryRunTask {
+            namespace: ns.to_string(),
+            dependencies: runtime
+                .get_task(ns)
+                .map(|task| task.dependencies().iter().map(|d| d.to_string()).collect())
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    // --- namespaces ---
+    let problems = namespace_problems(&options.tenant_id, &metadata, &workflow_name, &namespaces);
+    if problems.is_empty() {
+        report.checks.push(PackageCheck::ok(
+            "namespaces",
+            format!(
+                "all tasks under {}::{}::{}",
+                options.tenant_id, metadata.package_name, workflow_name
+            ),
+        ));
+    } else {
+        report.checks.push(PackageCheck::fail(
+            "namespaces",
+            problems.join("; "),
+            "check the task ids and `dependencies` in the #[workflow] module",
+        ));
+        let _ = registrar.unregister_package_tasks(&package_id);
+        return;
+    }
+
+    // --- workflow_build ---
+    let built = RegistryReconciler::create_workflow_from_host_registry_static(
+        &runtime,
+        &metadata.package_name,
+        &workflow_name,
+        &options.tenant_id,
+    )
+    .map_err(|e| e.to_string())
+    .and_then(|workflow| {
+        workflow
+            .get_execution_levels()
+            .map_err(|e| format!("{:?}", e))
+    });
+    match built {
+        Ok(levels) => {
+            report.execution_levels = levels
+                .into_iter()
+                .map(|level| level.iter().map(|ns| ns.to_string()).collect())
+                .collect();
+            report.checks.push(PackageCheck::ok(
+                "workflow_build",
+                format!(
+                    "workflow '{}' builds: {} task(s) in {} level(s)",
+                    workflow_name,
+                    namespaces.len(),
+                    report.execution_levels.len()
+                ),
+            ));
+        }
+        Err(e) => report.checks.push(PackageCheck::fail(
+            "workflow_build",
+            e,
+            "fix the dependency graph (cycles, missing tasks) and rebuild",
+        )),
+    }
+
+    let _ = registrar.unregister_package_tasks(&package_id);
+}
+
+/// Mismatches between the tasks the package declares and the namespaces
+/// they were registered under.
+fn namespace_problems(
+    tenant_id: &str,
+    metadata: &PackageMetadata,
+    workflow_name: &str,
+    registered: &[TaskNamespace],
+) -> Vec<String> {
+    let registered_set: HashSet<&TaskNamespace> = registered.iter().collect();
+    let local_ids: HashSet<&str> = metadata.tasks.iter().map(|t| t.local_id.as_str()).collect();
+    let mut problems = Vec::new();
+
+    for ns in registered {
+        if ns.tenant_id != tenant_id
+            || ns.package_name != metadata.package_name
+            || ns.workflow_id != workflow_name
+        {
+            problems.push(format!("task registered under unexpected namespace {}", ns));
+        }
+    }
+    for task in &metadata.tasks {
+        let expected = TaskNamespace::new(
+            tenant_id,
+            &metadata.package_name,
+            workflow_name,
+            &task.local_id,
+        );
+        if !registered_set.contains(&expected) {
+            problems.push(format!(
+                "task '{}' was not registered as {}",
+                task.local_id, expected
+            ));
+        }
+        for dep in &task.dependencies {
+            let known = if dep.contains("::") {
+                let full = dep.replace("{tenant}", tenant_id);
+                registered.iter().any(|ns| ns.to_string() == full)
+            } else {
+                local_ids.contains(dep.as_str())
+            };
+            if !known {
+                problems.push(format!(
+                    "task '{}' depends on unknown task '{}'",
+                    task.local_id, dep
+                ));
+            }
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::loader::package_loader::TaskMetadata;
+
+    fn task(id: &str, deps: &[&str]) -> TaskMetadata {
+        TaskMetadata {
+            index: 0,
+            local_id: id.to_string(),
+            namespaced_id_template: format!("{{tenant}}::etl::daily::{}", id),
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            description: String::new(),
+            source_location: String::new(),
+            doc_what: None,
+            doc_why: None,
+        }
+    }
+
+    fn metadata(tasks: Vec<TaskMetadata>) -> PackageMetadata {
+        PackageMetadata {
+            package_name: "etl".to_string(),
+            workflow_name: "daily".to_string(),
+            version: "1.0.0".to_string(),
+            description: None,
+            author: None,
+            tasks,
+            graph_data: None,
+            architecture: "x86_64".to_string(),
+            symbols: Vec::new(),
+            workflow_triggers: Vec::new(),
+            declared_params: Vec::new(),
+            declared_surfaces: Vec::new(),
+            task_docs: Default::default(),
+            provenance: None,
+            config: None,
+        }
+    }
+
+    #[test]
+    fn namespaces_match_declared_tasks() {
+        let meta = metadata(vec![task("extract", &[]), task("load", &["extract"])]);
+        let registered = vec![
+            TaskNamespace::new("public", "etl", "daily", "extract"),
+            TaskNamespace::new("public", "etl", "daily", "load"),
+        ];
+        let problems = namespace_problems("public", &meta, "daily", &registered);
+        assert!(problems.is_empty(), "{:?}", problems);
+    }
+
+    #[test]
+    fn namespace_mismatches_are_reported() {
+        let meta = metadata(vec![task("extract", &[]), task("load", &["transform"])]);
+        let registered = vec![
+            TaskNamespace::new("public", "etl", "daily", "extract"),
+            TaskNamespace::new("public", "etl", "hourly", "load"),
+        ];
+        let problems = namespace_problems("public", &meta, "daily", &registered);
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+        assert!(problems[0].contains("public::etl::hourly::load"));
+        assert!(problems[1].contains("'load' was not registered"));
+        assert!(problems[2].contains("unknown task 'transform'"));
+    }
+
+    #[tokio::test]
+    async fn missing_archive_fails_and_skips_the_rest() {
+        let tmp = TempDir::new().unwrap();
+        let report =
+            dry_run_package(&tmp.path().join("nope.cloacina"), &DryRunOptions::default()).await;
+        assert!(!report.passed());
+        assert_eq!(report.checks[0].name, "unpack");
+        assert_eq!(report.checks[0].status, CheckStatus::Fail);
+        assert_eq!(report.checks.len(), 1 + DRY_RUN_CHECKS.len());
+        assert!(report.checks[1..]
+            .iter()
+            .all(|c| c.status == CheckStatus::Skip));
+    }
+
+    #[tokio::test]
+    async fn python_package_stops_after_the_manifest() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join("package.toml"),
+            r#"[package]
+name = "demo"
+version = "0.1.0"
+interface = "cloacina-workflow-plugin"
+interface_version = 1
+extension = "cloacina"
+
+[metadata]
+language = "python"
+workflow_name = "demo"
+entry_module = "demo.tasks"
+"#,
+        )
+        .unwrap();
+        let report = dry_run_package(tmp.path(), &DryRunOptions::default()).await;
+        assert!(report.passed());
+        assert_eq!(report.package_name.as_deref(), Some("demo"));
+        assert_eq!(report.language.as_deref(), Some("python"));
+        let library = report.checks.iter().find(|c| c.name == "library").unwrap();
+        assert_eq!(library.status, CheckStatus::Skip);
+        assert_eq!(report.checks.len(), 1 + DRY_RUN_CHECKS.len());
+    }
+}
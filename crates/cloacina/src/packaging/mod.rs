@@ -20,13 +20,17 @@
 //! into distributable fidius source archives. These functions can be used by CLI
 //! tools, tests, or other applications that need to package workflows.
 
+pub mod bundled;
+pub mod config_schema;
 /// Constructor **provider package** assembly + packing (CLOACI-T-0827).
 /// Default-OFF behind the `constructor-packaging` feature (serde-only contract
 /// crate; no wasm runtime).
 #[cfg(feature = "constructor-packaging")]
 pub mod constructor_provider;
+pub mod dry_run;
 pub mod manifest_schema;
 pub mod platform;
+pub mod provenance;
 /// Provider discovery + bundling for the packaged-constructor build side
 /// (CLOACI-T-0836). Default-OFF behind `constructor-packaging`.
 #[cfg(feature = "constructor-packaging")]
@@ -36,11 +40,20 @@ pub mod validation;
 
 #[cfg(test)]
 mod tests;
+pub use bundled::{
+    bundled_targets, find_bundled_binary, normalize_target_triple, read_bundled_binary,
+    BUNDLED_TARGETS_DIR,
+};
+pub use config_schema::{read_config_schema, validate_config};
+pub use dry_run::{dry_run_package, DryRunOptions, DryRunReport, DryRunTask, PackageCheck};
 pub use manifest_schema::{
     Manifest, ManifestValidationError, PackageInfo, PackageLanguage, PythonRuntime, RustRuntime,
     TaskDefinition, TriggerDefinition,
 };
 pub use platform::{detect_current_platform, SUPPORTED_TARGETS};
+pub use provenance::{
+    collect_provenance, read_provenance, write_provenance, BuildProvenance, PROVENANCE_FILE,
+};
 pub use types::{CargoToml, CompileOptions};
 
 use anyhow::{bail, Result};
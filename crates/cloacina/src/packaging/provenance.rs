@@ -0,0 +1,185 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Build provenance recorded inside a `.cloacina` archive.
+//!
+//! At pack time the packer writes `provenance.json` at the package root with
+//! the git commit the source came from, the pack timestamp, the `rustc`
+//! version, and a hash of the dependency lockfile. The registry reads it at
+//! upload and persists it with the package metadata, so a registered (and
+//! running) workflow can be traced back to the exact source it was built from.
+//!
+//! Collection is best-effort: a field the packing host cannot determine is
+//! left unset rather than failing the pack.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub use cloacina_api_types::BuildProvenance;
+
+/// File, relative to the package root, holding the provenance record.
+pub const PROVENANCE_FILE: &str = "provenance.json";
+
+/// Lockfiles hashed for Rust packages. `Cargo.lock` is searched for in the
+/// package directory and then its ancestors, since a package inside a cargo
+/// workspace shares the workspace's lockfile.
+const RUST_LOCKFILES: &[&str] = &["Cargo.lock"];
+
+/// Lockfiles hashed for Python packages, in order of preference.
+const PYTHON_LOCKFILES: &[&str] = &["uv.lock", "poetry.lock", "requirements.txt"];
+
+/// Collect provenance for the package source at `package_dir`.
+///
+/// `rust` selects the Rust toolchain and lockfile; Python packages record no
+/// `rustc_version` and hash the first of `uv.lock`, `poetry.lock`, or
+/// `requirements.txt` found in the package directory.
+pub fn collect_provenance(package_dir: &Path, rust: bool) -> BuildProvenance {
+    let git_commit = run_command(package_dir, "git", &["rev-parse", "HEAD"]);
+    let git_dirty = git_commit.is_some()
+        && run_command(package_dir, "git", &["status", "--porcelain", "--", "."])
+            .is_some_and(|status| !status.is_empty());
+    let rustc_version = if rust {
+        run_command(package_dir, "rustc", &["--version"])
+    } else {
+        None
+    };
+    let lockfile = if rust {
+        find_lockfile_upwards(package_dir, RUST_LOCKFILES)
+    } else {
+        PYTHON_LOCKFILES
+            .iter()
+            .map(|name| package_dir.join(name))
+            .find(|path| path.is_file())
+    };
+    let (lockfile, lockfile_sha256) = match lockfile.and_then(|path| {
+        let bytes = std::fs::read(&path).ok()?;
+        Some((path, bytes))
+    }) {
+        Some((path, bytes)) => {
+            use sha2::{Digest, Sha256};
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            (Some(name), Some(format!("{:x}", Sha256::digest(&bytes))))
+        }
+        None => (None, None),
+    };
+
+    BuildProvenance {
+        git_commit,
+        git_dirty,
+        built_at: Some(chrono::Utc::now().to_rfc3339()),
+        rustc_version,
+        lockfile,
+        lockfile_sha256,
+    }
+}
+
+/// Write `provenance` to `provenance.json` in `package_dir`.
+pub fn write_provenance(package_dir: &Path, provenance: &BuildProvenance) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(provenance)
+        .map_err(|e| format!("failed to serialize provenance: {}", e))?;
+    let path = package_dir.join(PROVENANCE_FILE);
+    std::fs::write(&path, json).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Read the provenance record from the unpacked package at `package_dir`, or
+/// `None` if the package carries none. Errors when the file exists but is
+/// not a valid record.
+pub fn read_provenance(package_dir: &Path) -> Result<Option<BuildProvenance>, String> {
+    let path = package_dir.join(PROVENANCE_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|e| format!("invalid {}: {}", PROVENANCE_FILE, e))
+}
+
+fn find_lockfile_upwards(start: &Path, names: &[&str]) -> Option<PathBuf> {
+    let start = start.canonicalize().ok()?;
+    start.ancestors().find_map(|dir| {
+        names
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+/// Run `program args...` in `dir`, returning its trimmed stdout when it exits
+/// successfully.
+fn run_command(dir: &Path, program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_the_package_dir() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        assert_eq!(read_provenance(tmp.path()).unwrap(), None);
+
+        let provenance = BuildProvenance {
+            git_commit: Some("0123abcd".to_string()),
+            built_at: Some("2026-10-18T12:00:00+00:00".to_string()),
+            ..Default::default()
+        };
+        write_provenance(tmp.path(), &provenance).unwrap();
+        assert_eq!(read_provenance(tmp.path()).unwrap(), Some(provenance));
+
+        std::fs::write(tmp.path().join(PROVENANCE_FILE), "not json").unwrap();
+        assert!(read_provenance(tmp.path()).is_err());
+    }
+
+    #[test]
+    fn hashes_the_nearest_cargo_lock() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("Cargo.lock"), "version = 3\n").unwrap();
+        let package_dir = tmp.path().join("workflows").join("etl");
+        std::fs::create_dir_all(&package_dir).unwrap();
+
+        let provenance = collect_provenance(&package_dir, true);
+        assert_eq!(provenance.lockfile.as_deref(), Some("Cargo.lock"));
+        assert_eq!(
+            provenance.lockfile_sha256.as_deref(),
+            Some("a6302849064e016e520e513a22aef99a2d874333e7fcbf0b2c2260cb6ffb42f6")
+        );
+        assert!(provenance.built_at.is_some());
+    }
+
+    #[test]
+    fn python_packages_hash_their_own_lockfile() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("requirements.txt"), "requests\n").unwrap();
+
+        let provenance = collect_provenance(tmp.path(), false);
+        assert_eq!(provenance.lockfile.as_deref(), Some("requirements.txt"));
+        assert_eq!(provenance.rustc_version, None);
+    }
+}
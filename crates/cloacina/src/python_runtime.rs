@@ -65,11 +65,12 @@ pub struct LoadedPythonWorkflow {
 /// interpreter as needed. Errors are flattened to `String` because the
 /// reconciler wraps them in `RegistryError::RegistrationFailed` anyway.
 pub trait PythonRuntime: Send + Sync {
-    /// Extract a `.cloacina` archive, import the entry module, and register
-    /// its tasks + triggers in the global registries for the given tenant.
+    /// Extract the `.cloacina` archive at `archive_path`, import the entry
+    /// module, and register its tasks + triggers in the global registries for
+    /// the given tenant.
     fn load_workflow_package(
         &self,
-        archive_data: &[u8],
+        archive_path: &Path,
         staging_dir: &Path,
         tenant_id: &str,
         runtime: &Arc<Runtime>,
@@ -83,7 +84,7 @@ pub trait PythonRuntime: Send + Sync {
     #[allow(clippy::too_many_arguments)]
     fn load_cg_package(
         &self,
-        archive_data: &[u8],
+        archive_path: &Path,
         staging_dir: &Path,
         tenant_id: &str,
         graph_name: &str,
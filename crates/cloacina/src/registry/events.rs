@@ -0,0 +1,158 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Package lifecycle events.
+//!
+//! [`WorkflowRegistryImpl`](super::WorkflowRegistryImpl) reports every change
+//! to the set of active packages as a [`RegistryEvent`] to the
+//! [`RegistryEventListener`]s attached with
+//! [`with_event_listener`](super::WorkflowRegistryImpl::with_event_listener).
+//! Events are emitted after the change has committed, so a listener never
+//! sees a change that was rolled back.
+//!
+//! [`RegistryEventChannel`] fans events out to in-process subscribers;
+//! the server's outbound webhooks are another listener.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// What happened to a package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistryEventKind {
+    /// The first version of a package name was registered.
+    Registered,
+    /// A new version (or new bytes, or new config) replaced the active one.
+    Updated,
+    /// The previously active version was re-activated.
+    RolledBack,
+    /// A package version was unregistered.
+    Removed,
+}
+
+impl RegistryEventKind {
+    /// Dotted event name, e.g. `package.rolled_back`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegistryEventKind::Registered => "package.registered",
+            RegistryEventKind::Updated => "package.updated",
+            RegistryEventKind::RolledBack => "package.rolled_back",
+            RegistryEventKind::Removed => "package.removed",
+        }
+    }
+}
+
+impl std::fmt::Display for RegistryEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A change to the registry's packages.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryEvent {
+    pub kind: RegistryEventKind,
+    /// Package row the event is about: the new active row for
+    /// `registered`/`updated`/`rolled_back`, the deleted row for `removed`.
+    pub package_id: Uuid,
+    pub package_name: String,
+    pub version: String,
+    /// Version that was active before an `updated` or `rolled_back` event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_version: Option<String>,
+    /// SHA-256 of the package archive, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Receives registry events.
+///
+/// Called inline by the registry operation that caused the event, so
+/// implementations must not block: hand slow work (network delivery) to a
+/// spawned task.
+pub trait RegistryEventListener: Send + Sync {
+    fn on_event(&self, event: &RegistryEvent);
+}
+
+/// Broadcasts registry events to in-process subscribers.
+///
+/// A subscriber that falls more than `capacity` events behind loses the
+/// oldest ones (see [`broadcast::error::RecvError::Lagged`]).
+#[derive(Debug, Clone)]
+pub struct RegistryEventChannel {
+    sender: broadcast::Sender<RegistryEvent>,
+}
+
+impl RegistryEventChannel {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<RegistryEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl RegistryEventListener for RegistryEventChannel {
+    fn on_event(&self, event: &RegistryEvent) {
+        // No subscribers is not an error.
+        let _ = self.sender.send(event.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_serializes_with_snake_case_kind() {
+        let event = RegistryEvent {
+            kind: RegistryEventKind::RolledBack,
+            package_id: Uuid::nil(),
+            package_name: "etl".to_string(),
+            version: "1.0.0".to_string(),
+            previous_version: Some("1.1.0".to_string()),
+            content_hash: None,
+            occurred_at: Utc::now(),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], "rolled_back");
+        assert_eq!(json["previous_version"], "1.1.0");
+        assert!(json.get("content_hash").is_none());
+        assert_eq!(event.kind.as_str(), "package.rolled_back");
+    }
+
+    #[test]
+    fn channel_delivers_to_subscribers() {
+        let channel = RegistryEventChannel::new(4);
+        let mut rx = channel.subscribe();
+        let event = RegistryEvent {
+            kind: RegistryEventKind::Registered,
+            package_id: Uuid::new_v4(),
+            package_name: "etl".to_string(),
+            version: "1.0.0".to_string(),
+            previous_version: None,
+            content_hash: Some("abc".to_string()),
+            occurred_at: Utc::now(),
+        };
+        channel.on_event(&event);
+        assert_eq!(rx.try_recv().unwrap(), event);
+    }
+}
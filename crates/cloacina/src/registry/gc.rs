@@ -0,0 +1,349 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Registry garbage collection — background service that deletes stale
+//! package versions.
+//!
+//! Every upload of a changed package supersedes the previous row for that
+//! name, but the superseded rows (and their source archives in registry
+//! storage, per-target artifacts and bundled providers) are kept forever.
+//! This sweeper applies a [`RegistryGcPolicy`] to them:
+//!
+//! - the **active** row of a package is never collected — it is the one the
+//!   reconciler loads and that schedules (which reference workflows by name)
+//!   run;
+//! - the newest `keep_last` rows per package, counting the active one, are
+//!   kept;
+//! - versions **pinned** in the policy are kept regardless of age;
+//! - everything else is collected.
+//!
+//! A dry run computes the same [`RegistryGcReport`] without deleting
+//! anything, so an operator can review a policy before enabling it.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::registry::traits::RegistryStorage;
+use crate::registry::workflow_registry::WorkflowRegistryImpl;
+
+/// Which package versions registry garbage collection keeps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryGcPolicy {
+    /// Rows kept per package, newest first, counting the active one
+    /// (default 5). Values below 1 behave as 1: the active row always stays.
+    pub keep_last: usize,
+    /// `(package_name, version)` pairs that are never collected.
+    pub pinned: Vec<(String, String)>,
+}
+
+impl Default for RegistryGcPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: 5,
+            pinned: Vec::new(),
+        }
+    }
+}
+
+impl RegistryGcPolicy {
+    /// Keep the newest `keep_last` rows per package.
+    pub fn keep_last(keep_last: usize) -> Self {
+        Self {
+            keep_last,
+            ..Self::default()
+        }
+    }
+
+    /// Never collect `version` of `package_name`.
+    pub fn pin(mut self, package_name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.pinned.push((package_name.into(), version.into()));
+        self
+    }
+
+    fn is_pinned(&self, package_name: &str, version: &str) -> bool {
+        self.pinned
+            .iter()
+            .any(|(name, v)| name == package_name && v == version)
+    }
+}
+
+/// One `workflow_packages` row as seen by garbage collection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryGcEntry {
+    /// Package row ID.
+    pub package_id: Uuid,
+    /// Package name.
+    pub package_name: String,
+    /// Package version.
+    pub version: String,
+    /// When the row was uploaded.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Outcome of one garbage-collection pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryGcReport {
+    /// Whether this was a dry run (nothing deleted).
+    pub dry_run: bool,
+    /// Rows deleted, or that would be deleted on a dry run, oldest first
+    /// within each package.
+    pub collected: Vec<RegistryGcEntry>,
+    /// Number of rows the policy keeps.
+    pub retained: usize,
+}
+
+/// A candidate row for [`select_collectable`]: the entry plus whether it is
+/// the package's active row.
+#[derive(Debug, Clone)]
+pub(crate) struct GcCandidate {
+    pub(crate) entry: RegistryGcEntry,
+    pub(crate) active: bool,
+}
+
+/// Split `rows` into (collected, retained count) under `policy`.
+pub(crate) fn select_collectable(
+    rows: Vec<GcCandidate>,
+    policy: &RegistryGcPolicy,
+) -> (Vec<RegistryGcEntry>, usize) {
+    let mut by_package: HashMap<String, Vec<GcCandidate>> = HashMap::new();
+    for row in rows {
+        by_package
+            .entry(row.entry.package_name.clone())
+            .or_default()
+            .push(row);
+    }
+
+    let keep_last = policy.keep_last.max(1);
+    let mut names: Vec<String> = by_package.keys().cloned().collect();
+    names.sort();
+
+    let mut collected = Vec::new();
+    let mut retained = 0;
+    for name in names {
+        let mut rows = by_package.remove(&name).unwrap_or_default();
+        // Active row first, then newest to oldest.
+        rows.sort_by(|a, b| {
+            b.active
+                .cmp(&a.active)
+                .then(b.entry.created_at.cmp(&a.entry.created_at))
+        });
+        let mut package_collected = Vec::new();
+        for (rank, row) in rows.into_iter().enumerate() {
+            if row.active
+                || rank < keep_last
+                || policy.is_pinned(&row.entry.package_name, &row.entry.version)
+            {
+                retained += 1;
+            } else {
+                package_collected.push(row.entry);
+            }
+        }
+        package_collected.reverse();
+        collected.extend(package_collected);
+    }
+    (collected, retained)
+}
+
+/// Configuration for the registry garbage-collection sweeper.
+#[derive(Debug, Clone)]
+pub struct RegistryGcConfig {
+    /// How often to run a pass (default 1h).
+    pub sweep_interval: Duration,
+    /// Which versions to keep.
+    pub policy: RegistryGcPolicy,
+    /// Only report what would be collected (default false).
+    pub dry_run: bool,
+}
+
+impl Default for RegistryGcConfig {
+    fn default() -> Self {
+        Self {
+            sweep_interval: Duration::from_secs(60 * 60),
+            policy: RegistryGcPolicy::default(),
+            dry_run: false,
+        }
+    }
+}
+
+/// Background service that garbage-collects stale package versions.
+pub struct RegistryGcSweeper<S: RegistryStorage> {
+    registry: WorkflowRegistryImpl<S>,
+    config: RegistryGcConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl<S: RegistryStorage + Send + Sync> RegistryGcSweeper<S> {
+    /// Create a new registry garbage-collection sweeper.
+    pub fn new(
+        registry: WorkflowRegistryImpl<S>,
+        config: RegistryGcConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            registry,
+            config,
+            shutdown_rx,
+        }
+    }
+
+    /// Run the sweep loop.
+    pub async fn run(&mut self) {
+        info!(
+            "Starting registry GC (interval: {}s, keep_last: {}, pinned: {}, dry_run: {})",
+            self.config.sweep_interval.as_secs(),
+            self.config.policy.keep_last,
+            self.config.policy.pinned.len(),
+            self.config.dry_run,
+        );
+
+        let mut interval = tokio::time::interval(self.config.sweep_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.sweep().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    if *self.shutdown_rx.borrow() {
+                        info!("Registry GC shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Perform a single pass. Returns `None` if the pass failed.
+    pub async fn sweep(&mut self) -> Option<RegistryGcReport> {
+        let report = match self
+            .registry
+            .collect_garbage(&self.config.policy, self.config.dry_run)
+            .await
+        {
+            Ok(report) => report,
+            Err(e) => {
+                warn!("Registry GC pass failed: {}", e);
+                return None;
+            }
+        };
+
+        if report.collected.is_empty() {
+            debug!(
+                "Registry GC: nothing to collect ({} version(s) retained)",
+                report.retained
+            );
+        } else if report.dry_run {
+            for entry in &report.collected {
+                info!(
+                    "Registry GC (dry run): would collect {} v{} ({}, uploaded {})",
+                    entry.package_name, entry.version, entry.package_id, entry.created_at
+                );
+            }
+            info!(
+                "Registry GC (dry run): {} version(s) would be collected, {} retained",
+                report.collected.len(),
+                report.retained
+            );
+        } else {
+            metrics::counter!("cloacina_registry_versions_collected_total")
+                .increment(report.collected.len() as u64);
+            info!(
+                "Registry GC: collected {} version(s), {} retained",
+                report.collected.len(),
+                report.retained
+            );
+        }
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, version: &str, age_days: i64, active: bool) -> GcCandidate {
+        GcCandidate {
+            entry: RegistryGcEntry {
+                package_id: Uuid::new_v4(),
+                package_name: name.to_string(),
+                version: version.to_string(),
+                created_at: Utc::now() - chrono::Duration::days(age_days),
+            },
+            active,
+        }
+    }
+
+    fn versions(collected: &[RegistryGcEntry]) -> Vec<(&str, &str)> {
+        collected
+            .iter()
+            .map(|e| (e.package_name.as_str(), e.version.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_newest_rows_per_package_and_collects_the_rest_oldest_first() {
+        let rows = vec![
+            row("etl", "1.0.0", 40, false),
+            row("etl", "1.1.0", 30, false),
+            row("etl", "1.2.0", 20, false),
+            row("etl", "1.3.0", 10, true),
+            row("report", "0.1.0", 5, true),
+        ];
+        let (collected, retained) = select_collectable(rows, &RegistryGcPolicy::keep_last(2));
+        assert_eq!(
+            versions(&collected),
+            vec![("etl", "1.0.0"), ("etl", "1.1.0")]
+        );
+        assert_eq!(retained, 3);
+    }
+
+    #[test]
+    fn active_row_is_kept_even_when_older_than_superseded_rows() {
+        // A re-activated older upload is still the one schedules run.
+        let rows = vec![row("etl", "2.0.0", 1, false), row("etl", "1.0.0", 50, true)];
+        let (collected, retained) = select_collectable(rows, &RegistryGcPolicy::keep_last(0));
+        assert_eq!(versions(&collected), vec![("etl", "2.0.0")]);
+        assert_eq!(retained, 1);
+    }
+
+    #[test]
+    fn pinned_versions_are_never_collected() {
+        let rows = vec![
+            row("etl", "1.0.0", 30, false),
+            row("etl", "1.1.0", 20, false),
+            row("etl", "1.2.0", 10, true),
+        ];
+        let policy = RegistryGcPolicy::keep_last(1).pin("etl", "1.0.0");
+        let (collected, retained) = select_collectable(rows, &policy);
+        assert_eq!(versions(&collected), vec![("etl", "1.1.0")]);
+        assert_eq!(retained, 2);
+    }
+
+    #[test]
+    fn config_defaults() {
+        let config = RegistryGcConfig::default();
+        assert_eq!(config.sweep_interval, Duration::from_secs(3600));
+        assert_eq!(config.policy.keep_last, 5);
+        assert!(config.policy.pinned.is_empty());
+        assert!(!config.dry_run);
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Package capability manifests vs. deployment policy.
+//!
+//! A package declares what it needs from the host in `[metadata.capabilities]`
+//! ([`CapabilityManifest`]). The runner's [`CapabilityPolicy`] says what this
+//! deployment is willing to grant. [`CapabilityPolicy::grant`] runs when the
+//! reconciler registers a package: any requested entry the policy does not
+//! allow rejects the package, and the result is the set the package's tasks
+//! receive at execution time.
+//!
+//! What "receive" means differs by kind. Secrets and environment variables are
+//! handed to a packaged task by the host, so they are enforced: a task can
+//! only resolve granted secrets, and `context.env_var(...)` only sees granted
+//! variables. Network and filesystem entries are checked at registration and
+//! enforced at run time only where executions are sandboxed (execution
+//! agents started with `--sandbox`; see `security::sandbox`). Elsewhere they
+//! are the package's declared contract, which the operator approves through
+//! the policy.
+
+use cloacina_workflow_plugin::CapabilityManifest;
+
+/// What this deployment allows packages to request, per capability kind.
+///
+/// Each kind is either unrestricted (`None`, the default — any request is
+/// allowed) or an allow-list of patterns. `*` matches any run of characters.
+/// Filesystem patterns are `ro:<path>` / `rw:<path>`; an `rw` grant also covers
+/// `ro` requests, and a path grant covers everything beneath it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapabilityPolicy {
+    network: Option<Vec<String>>,
+    filesystem: Option<Vec<String>>,
+    secrets: Option<Vec<String>>,
+    env: Option<Vec<String>>,
+}
+
+/// A package manifest that is malformed or asks for more than the policy
+/// allows. Lists every offending entry so the operator sees them all at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityError {
+    /// One line per rejected entry, e.g. `network 'api.example.com:443'`.
+    pub denied: Vec<String>,
+}
+
+impl std::fmt::Display for CapabilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "capabilities not granted by the deployment policy: {}",
+            self.denied.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CapabilityError {}
+
+impl CapabilityPolicy {
+    /// A policy that allows every request (the default).
+    pub fn unrestricted() -> Self {
+        Self::default()
+    }
+
+    /// A policy that allows nothing until `allow_*` calls open it up.
+    pub fn deny_all() -> Self {
+        Self {
+            network: Some(Vec::new()),
+            filesystem: Some(Vec::new()),
+            secrets: Some(Vec::new()),
+            env: Some(Vec::new()),
+        }
+    }
+
+    /// Allow network requests matching `pattern` (`host`, `host:port`, globs).
+    pub fn allow_network(mut self, pattern: impl Into<String>) -> Self {
+        self.network
+            .get_or_insert_with(Vec::new)
+            .push(pattern.into());
+        self
+    }
+
+    /// Allow filesystem requests matching `pattern` (`ro:<path>` / `rw:<path>`).
+    pub fn allow_filesystem(mut self, pattern: impl Into<String>) -> Self {
+        self.filesystem
+            .get_or_insert_with(Vec::new)
+            .push(pattern.into());
+        self
+    }
+
+    /// Allow packages to request secrets whose names match `pattern`.
+    pub fn allow_secret(mut self, pattern: impl Into<String>) -> Self {
+        self.secrets
+            .get_or_insert_with(Vec::new)
+            .push(pattern.into());
+        self
+    }
+
+    /// Allow packages to request environment variables matching `pattern`.
+    pub fn allow_env(mut self, pattern: impl Into<String>) -> Self {
+        self.env.get_or_insert_with(Vec::new).push(pattern.into());
+        self
+    }
+
+    /// Whether every kind is unrestricted.
+    pub fn is_unrestricted(&self) -> bool {
+        self.network.is_none()
+            && self.filesystem.is_none()
+            && self.secrets.is_none()
+            && self.env.is_none()
+    }
+
+    /// Validate a package's manifest against this policy and return what the
+    /// package is granted.
+    ///
+    /// `Ok(None)` means "no capability gating": the package declares no
+    /// `[metadata.capabilities]` table and the policy is unrestricted, so it
+    /// keeps the legacy behaviour. A package without a table under a
+    /// restrictive policy is treated as requesting nothing.
+    pub fn grant(
+        &self,
+        manifest: Option<&CapabilityManifest>,
+    ) -> Result<Option<CapabilityManifest>, CapabilityError> {
+        let manifest = match manifest {
+            Some(manifest) => manifest.clone(),
+            None if self.is_unrestricted() => return Ok(None),
+            None => CapabilityManifest::default(),
+        };
+
+        let mut denied = Vec::new();
+        for entry in &manifest.network {
+            if !allowed(&self.network, entry, glob_match) {
+                denied.push(format!("network '{}'", entry));
+            }
+        }
+        for entry in &manifest.filesystem {
+            match parse_fs(entry) {
+                Some(request) => {
+                    let granted = match &self.filesystem {
+                        None => true,
+                        Some(patterns) => patterns
+                            .iter()
+                            .filter_map(|p| parse_fs(p))
+                            .any(|grant| fs_covers(grant, request)),
+                    };
+                    if !granted {
+                        denied.push(format!("filesystem '{}'", entry));
+                    }
+                }
+                None => denied.push(format!(
+                    "filesystem '{}' (expected 'ro:<absolute path>' or 'rw:<absolute path>')",
+                    entry
+                )),
+            }
+        }
+        for entry in &manifest.secrets {
+            if !allowed(&self.secrets, entry, glob_match) {
+                denied.push(format!("secret '{}'", entry));
+            }
+        }
+        for entry in &manifest.env {
+            if entry.contains('*') {
+                denied.push(format!("env '{}' (variable names cannot be globs)", entry));
+            } else if !allowed(&self.env, entry, glob_match) {
+                denied.push(format!("env '{}'", entry));
+            }
+        }
+
+        if denied.is_empty() {
+            Ok(Some(manifest))
+        } else {
+            Err(CapabilityError { denied })
+        }
+    }
+}
+
+fn allowed(patterns: &Option<Vec<String>>, entry: &str, matches: fn(&str, &str) -> bool) -> bool {
+    match patterns {
+        None => true,
+        Some(patterns) => patterns.iter().any(|p| matches(p, entry)),
+    }
+}
+
+/// Split `ro:<path>` / `rw:<path>` into `(writable, path)`. Paths must be
+/// absolute.
+fn parse_fs(entry: &str) -> Option<(bool, &str)> {
+    let (mode, path) = entry.split_once(':')?;
+    let writable = match mode {
+        "ro" => false,
+        "rw" => true,
+        _ => return None,
+    };
+    path.starts_with('/').then_some((writable, path))
+}
+
+/// Whether `grant` covers `request`: `rw` covers `ro`, and a path covers
+/// itself, anything beneath it, and anything its glob matches.
+fn fs_covers(grant: (bool, &str), request: (bool, &str)) -> bool {
+    let (grant_rw, grant_path) = grant;
+    let (request_rw, request_path) = request;
+    if request_rw && !grant_rw {
+        return false;
+    }
+    let base = grant_path.trim_end_matches('/');
+    glob_match(grant_path, request_path)
+        || request_path == base
+        || request_path
+            .strip_prefix(base)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Minimal glob match supporting `*` (any run, including empty) anywhere in the
+/// pattern. Case-sensitive; no `?`/character classes (not needed for host/path).
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    // Classic two-pointer wildcard match.
+    let (p, t) = (pattern.as_bytes(), text.as_bytes());
+    let (mut pi, mut ti) = (0usize, 0usize);
+    let (mut star, mut mark) = (None, 0usize);
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == b'*') {
+            star = Some(pi);
+            mark = ti;
+            pi += 1;
+        } else if pi < p.len() && p[pi] == t[ti] {
+            pi += 1;
+            ti += 1;
+        } else if let Some(s) = star {
+            pi = s + 1;
+            mark += 1;
+            ti = mark;
+        } else {
+            return false;
+        }
+    }
+    while pi < p.len() && p[pi] == b'*' {
+        pi += 1;
+    }
+    pi == p.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> CapabilityManifest {
+        CapabilityManifest {
+            network: vec!["api.example.com:443".to_string()],
+            filesystem: vec!["ro:/etc/ssl/certs".to_string()],
+            secrets: vec!["warehouse_db".to_string()],
+            env: vec!["AWS_REGION".to_string()],
+        }
+    }
+
+    #[test]
+    fn unrestricted_policy_keeps_undeclared_packages_ungated() {
+        let policy = CapabilityPolicy::unrestricted();
+        assert_eq!(policy.grant(None).unwrap(), None);
+        assert_eq!(policy.grant(Some(&manifest())).unwrap(), Some(manifest()));
+    }
+
+    #[test]
+    fn restrictive_policy_treats_undeclared_packages_as_requesting_nothing() {
+        let policy = CapabilityPolicy::deny_all();
+        assert_eq!(
+            policy.grant(None).unwrap(),
+            Some(CapabilityManifest::default())
+        );
+    }
+
+    #[test]
+    fn policy_patterns_admit_matching_requests() {
+        let policy = CapabilityPolicy::deny_all()
+            .allow_network("*.example.com:443")
+            .allow_filesystem("rw:/etc/ssl")
+            .allow_secret("warehouse_*")
+            .allow_env("AWS_*");
+        assert_eq!(policy.grant(Some(&manifest())).unwrap(), Some(manifest()));
+    }
+
+    #[test]
+    fn every_denied_entry_is_reported() {
+        let policy = CapabilityPolicy::deny_all()
+            .allow_filesystem("ro:/etc/ssl/certs")
+            .allow_env("AWS_REGION");
+        let mut requested = manifest();
+        requested.filesystem.push("rw:/etc/ssl/certs".to_string());
+        requested.filesystem.push("/no/mode".to_string());
+        requested.env.push("AWS_*".to_string());
+
+        let err = policy.grant(Some(&requested)).unwrap_err();
+        assert_eq!(err.denied.len(), 5, "{:?}", err.denied);
+        assert!(err.denied[0].starts_with("network 'api.example.com:443'"));
+        assert!(err.denied[1].starts_with("filesystem 'rw:/etc/ssl/certs'"));
+        assert!(err.denied[2].starts_with("filesystem '/no/mode'"));
+        assert!(err.denied[3].starts_with("secret 'warehouse_db'"));
+        assert!(err.denied[4].starts_with("env 'AWS_*'"));
+        assert!(err.to_string().contains("deployment policy"));
+    }
+
+    #[test]
+    fn filesystem_grants_cover_subpaths_but_not_siblings() {
+        assert!(fs_covers((false, "/data"), (false, "/data/in")));
+        assert!(fs_covers((true, "/data/"), (true, "/data")));
+        assert!(!fs_covers((false, "/data"), (false, "/database")));
+        assert!(!fs_covers((false, "/data"), (true, "/data/in")));
+        assert!(fs_covers((false, "/srv/*/cache"), (false, "/srv/a/cache")));
+    }
+}
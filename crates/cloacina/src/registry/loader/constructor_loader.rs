@@ -102,6 +102,7 @@ use crate::computation_graph::reactor::ReactorFireDecider;
 use crate::computation_graph::types::InputCache;
 use crate::context::Context;
 use crate::error::TaskError;
+use crate::outputs::ProducedOutput;
 use crate::registry::error::LoaderError;
 use crate::runtime::Runtime;
 use crate::task::{Task, TaskNamespace};
@@ -1766,6 +1767,10 @@ impl Task for ConstructorNode {
         &self.dependencies
     }
 
+    fn is_soft_dependency(&self, dependency: &TaskNamespace) -> bool {
+        self.inner.is_soft_dependency(dependency)
+    }
+
     fn retry_policy(&self) -> crate::retry::RetryPolicy {
         self.inner.retry_policy()
     }
@@ -1774,6 +1779,66 @@ impl Task for ConstructorNode {
         self.inner.trigger_rules()
     }
 
+    fn short_circuit(&self) -> Option<String> {
+        self.inner.short_circuit()
+    }
+
+    fn branch_key(&self) -> Option<String> {
+        self.inner.branch_key()
+    }
+
+    fn branch(&self) -> Option<String> {
+        self.inner.branch()
+    }
+
+    fn loop_until(&self) -> Option<serde_json::Value> {
+        self.inner.loop_until()
+    }
+
+    fn max_iterations(&self) -> Option<u32> {
+        self.inner.max_iterations()
+    }
+
+    fn memory_limit(&self) -> Option<u64> {
+        self.inner.memory_limit()
+    }
+
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.inner.timeout()
+    }
+
+    fn expected_duration(&self) -> Option<std::time::Duration> {
+        self.inner.expected_duration()
+    }
+
+    fn requires_approval(&self) -> bool {
+        self.inner.requires_approval()
+    }
+
+    fn workload_class(&self) -> Option<crate::task::WorkloadClass> {
+        self.inner.workload_class()
+    }
+
+    fn resources(&self) -> Vec<String> {
+        self.inner.resources()
+    }
+
+    fn produces(&self) -> Vec<ProducedOutput> {
+        self.inner.produces()
+    }
+
+    fn scoped(&self) -> bool {
+        self.inner.scoped()
+    }
+
+    fn reads(&self) -> Option<Vec<String>> {
+        self.inner.reads()
+    }
+
+    fn configuration(&self) -> Option<serde_json::Value> {
+        self.inner.configuration()
+    }
+
     fn code_fingerprint(&self) -> Option<String> {
         self.inner.code_fingerprint()
     }
@@ -47,6 +47,8 @@ use std::sync::Arc;
 use fidius_host::http_types::request::Parts as HttpParts;
 use fidius_host::{EgressDenied, EgressPolicy};
 
+use super::capabilities::glob_match;
+
 /// The tenant's grants for one constructor instance, parsed from the
 /// `grants = { .. }` literal. Each list holds raw pattern strings in author order;
 /// validation happens in [`translate`]. An all-empty value (the [`Default`]) is the
@@ -418,38 +420,9 @@ impl EgressPolicy for GrantEgressPolicy {
 }
 
 // ===========================================================================
-// Glob + lint
+// Lint
 // ===========================================================================
 
-/// Minimal glob match supporting `*` (any run, including empty) anywhere in the
-/// pattern. Case-sensitive; no `?`/character classes (not needed for host/path).
-fn glob_match(pattern: &str, text: &str) -> bool {
-    // Classic two-pointer wildcard match.
-    let (p, t) = (pattern.as_bytes(), text.as_bytes());
-    let (mut pi, mut ti) = (0usize, 0usize);
-    let (mut star, mut mark) = (None, 0usize);
-    while ti < t.len() {
-        if pi < p.len() && (p[pi] == b'*') {
-            star = Some(pi);
-            mark = ti;
-            pi += 1;
-        } else if pi < p.len() && p[pi] == t[ti] {
-            pi += 1;
-            ti += 1;
-        } else if let Some(s) = star {
-            pi = s + 1;
-            mark += 1;
-            ti = mark;
-        } else {
-            return false;
-        }
-    }
-    while pi < p.len() && p[pi] == b'*' {
-        pi += 1;
-    }
-    pi == p.len()
-}
-
 /// Load-time capability lint (REQ-1.3.1): compare the package manifest's declared
 /// `[wasm].capabilities` (the author's stated intent) against the tenant's grants,
 /// and return a human-readable warning for each capability the component declares an
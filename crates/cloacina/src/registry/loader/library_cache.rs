@@ -0,0 +1,429 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Reference-counted cache of dlopened package libraries.
+//!
+//! Every fidius `PluginHandle` the loaders open for a package is filed under
+//! the [`LibraryKey`] of the library bytes it came from. A package
+//! registration *acquires* its key and deregistration *releases* it; task
+//! executions hold a [`LibraryLease`] while they call into the library.
+//! Once a library has no owners and no in-flight executions its handles
+//! are dropped, which dlcloses the library once the last task instance
+//! holding it goes away.
+//!
+//! Whether handles are really dropped is governed by [`UnloadPolicy`]. On
+//! macOS, dlclosing a cdylib corrupts the global `inventory` linked list
+//! that fidius registers into (the list nodes are unmapped while the head
+//! still points at them, and the next load SIGKILLs), so released handles
+//! are pinned for the process lifetime there instead.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use sha2::{Digest, Sha256};
+
+/// Identity of a package library: the SHA-256 of its bytes.
+///
+/// Keying by content means a package reloaded with identical bytes maps
+/// back onto the handles it already has open, while a replacement build
+/// gets a fresh entry that can be released independently of the old one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LibraryKey(String);
+
+impl LibraryKey {
+    /// Compute the key for a library's bytes.
+    pub fn of(library_data: &[u8]) -> Self {
+        Self(hex::encode(Sha256::digest(library_data)))
+    }
+
+    /// Hex digest backing this key.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for LibraryKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A 12-character prefix is plenty to tell libraries apart in logs.
+        f.write_str(&self.0[..self.0.len().min(12)])
+    }
+}
+
+/// What happens to a library's handles once it is fully released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnloadPolicy {
+    /// Drop the handles, so the library is dlclosed once the last task
+    /// instance referencing it is gone.
+    Release,
+    /// Keep the handles for the process lifetime. Used where dlclose is
+    /// unsafe (see the module docs).
+    Retain,
+}
+
+impl Default for UnloadPolicy {
+    fn default() -> Self {
+        if cfg!(target_os = "macos") {
+            UnloadPolicy::Retain
+        } else {
+            UnloadPolicy::Release
+        }
+    }
+}
+
+/// Outcome of [`PluginHandleCache::release`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseOutcome {
+    /// The library had no other owners or executions; its handles are gone
+    /// (or pinned, under [`UnloadPolicy::Retain`]).
+    Released,
+    /// No owners remain, but executions are still running. The handles go
+    /// when the last one finishes.
+    Draining { in_flight: usize },
+    /// Another registration still owns the same library.
+    StillOwned { owners: usize },
+    /// The cache holds nothing for this key.
+    NotLoaded,
+}
+
+/// Point-in-time counters for a [`PluginHandleCache`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryCacheStats {
+    /// Libraries currently tracked (loaded or draining).
+    pub libraries: usize,
+    /// Open plugin handles across those libraries.
+    pub handles: usize,
+    /// Task executions currently running inside a tracked library.
+    pub in_flight: usize,
+    /// Released libraries still waiting for in-flight executions.
+    pub draining: usize,
+    /// Handles kept alive after release because of [`UnloadPolicy::Retain`].
+    pub pinned_handles: usize,
+    /// Libraries fully released since the cache was created.
+    pub released_total: u64,
+}
+
+#[derive(Default)]
+struct CachedLibrary {
+    handles: Vec<Arc<fidius_host::PluginHandle>>,
+    owners: usize,
+    in_flight: usize,
+    retired: bool,
+}
+
+struct CacheState {
+    policy: UnloadPolicy,
+    libraries: HashMap<LibraryKey, CachedLibrary>,
+    pinned: Vec<Arc<fidius_host::PluginHandle>>,
+    released_total: u64,
+}
+
+impl CacheState {
+    /// Drop (or pin) a retired library with nothing left running in it.
+    fn finalize(&mut self, key: &LibraryKey) -> bool {
+        let ready = self
+            .libraries
+            .get(key)
+            .is_some_and(|lib| lib.retired && lib.in_flight == 0);
+        if !ready {
+            return false;
+        }
+        let library = self.libraries.remove(key).unwrap_or_default();
+        if self.policy == UnloadPolicy::Retain {
+            self.pinned.extend(library.handles);
+        }
+        self.released_total += 1;
+        tracing::debug!("Released package library {} ({:?})", key, self.policy);
+        true
+    }
+}
+
+/// Shared, reference-counted cache of plugin handles for package libraries.
+///
+/// Cloning is cheap and every clone sees the same state; the reconciler
+/// shares one cache between its `PackageLoader` and `TaskRegistrar`.
+#[derive(Clone)]
+pub struct PluginHandleCache {
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl PluginHandleCache {
+    /// Create an empty cache with the platform's default [`UnloadPolicy`].
+    pub fn new() -> Self {
+        Self::with_unload_policy(UnloadPolicy::default())
+    }
+
+    /// Create an empty cache with an explicit [`UnloadPolicy`].
+    pub fn with_unload_policy(policy: UnloadPolicy) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(CacheState {
+                policy,
+                libraries: HashMap::new(),
+                pinned: Vec::new(),
+                released_total: 0,
+            })),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The policy applied to libraries released from now on.
+    pub fn unload_policy(&self) -> UnloadPolicy {
+        self.lock().policy
+    }
+
+    /// Change the policy applied to libraries released from now on. Shared
+    /// by every clone of this cache.
+    pub fn set_unload_policy(&self, policy: UnloadPolicy) {
+        self.lock().policy = policy;
+    }
+
+    /// File an open handle under `key` and return a shared reference to it.
+    pub fn retain(
+        &self,
+        key: &LibraryKey,
+        handle: fidius_host::PluginHandle,
+    ) -> Arc<fidius_host::PluginHandle> {
+        let handle = Arc::new(handle);
+        self.lock()
+            .libraries
+            .entry(key.clone())
+            .or_default()
+            .handles
+            .push(handle.clone());
+        handle
+    }
+
+    /// Record a registration owning the library. Reacquiring a library that
+    /// is draining puts it back in service with its handles intact.
+    pub fn acquire(&self, key: &LibraryKey) {
+        let mut state = self.lock();
+        let library = state.libraries.entry(key.clone()).or_default();
+        library.owners += 1;
+        library.retired = false;
+    }
+
+    /// Drop one registration's ownership of the library. When no owners
+    /// remain the library is retired and released as soon as no executions
+    /// are running in it.
+    pub fn release(&self, key: &LibraryKey) -> ReleaseOutcome {
+        let mut state = self.lock();
+        let Some(library) = state.libraries.get_mut(key) else {
+            return ReleaseOutcome::NotLoaded;
+        };
+        library.owners = library.owners.saturating_sub(1);
+        if library.owners > 0 {
+            return ReleaseOutcome::StillOwned {
+                owners: library.owners,
+            };
+        }
+        library.retired = true;
+        let in_flight = library.in_flight;
+        if state.finalize(key) {
+            ReleaseOutcome::Released
+        } else {
+            ReleaseOutcome::Draining { in_flight }
+        }
+    }
+
+    /// Retire every library no registration owns — handles left behind by
+    /// metadata extraction, or by a load that failed before registering.
+    /// Returns how many libraries were released outright.
+    pub fn release_unowned(&self) -> usize {
+        let mut state = self.lock();
+        let unowned: Vec<LibraryKey> = state
+            .libraries
+            .iter_mut()
+            .filter(|(_, lib)| lib.owners == 0)
+            .map(|(key, lib)| {
+                lib.retired = true;
+                key.clone()
+            })
+            .collect();
+        unowned.iter().filter(|key| state.finalize(key)).count()
+    }
+
+    /// Mark an execution as running inside the library until the returned
+    /// lease is dropped. `None` when the library is no longer tracked.
+    pub fn lease(&self, key: &LibraryKey) -> Option<LibraryLease> {
+        let mut state = self.lock();
+        let library = state.libraries.get_mut(key)?;
+        library.in_flight += 1;
+        Some(LibraryLease {
+            cache: self.clone(),
+            key: key.clone(),
+        })
+    }
+
+    /// Current counters.
+    pub fn stats(&self) -> LibraryCacheStats {
+        let state = self.lock();
+        let mut stats = LibraryCacheStats {
+            libraries: state.libraries.len(),
+            pinned_handles: state.pinned.len(),
+            released_total: state.released_total,
+            ..Default::default()
+        };
+        for library in state.libraries.values() {
+            stats.handles += library.handles.len();
+            stats.in_flight += library.in_flight;
+            if library.retired {
+                stats.draining += 1;
+            }
+        }
+        stats
+    }
+}
+
+impl Default for PluginHandleCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for PluginHandleCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginHandleCache")
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+/// An execution running inside a cached library. Keeps a released library
+/// from being dropped until the execution finishes.
+pub struct LibraryLease {
+    cache: PluginHandleCache,
+    key: LibraryKey,
+}
+
+impl Drop for LibraryLease {
+    fn drop(&mut self) {
+        let mut state = self.cache.lock();
+        if let Some(library) = state.libraries.get_mut(&self.key) {
+            library.in_flight = library.in_flight.saturating_sub(1);
+        }
+        state.finalize(&self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn library_key_is_content_addressed() {
+        let a = LibraryKey::of(b"library one");
+        assert_eq!(a, LibraryKey::of(b"library one"));
+        assert_ne!(a, LibraryKey::of(b"library two"));
+        assert_eq!(a.as_str().len(), 64);
+        assert_eq!(a.to_string().len(), 12);
+    }
+
+    #[test]
+    fn release_without_executions_drops_the_library() {
+        let cache = PluginHandleCache::with_unload_policy(UnloadPolicy::Release);
+        let key = LibraryKey::of(b"pkg");
+        cache.acquire(&key);
+        assert_eq!(cache.stats().libraries, 1);
+
+        assert_eq!(cache.release(&key), ReleaseOutcome::Released);
+        let stats = cache.stats();
+        assert_eq!(stats.libraries, 0);
+        assert_eq!(stats.released_total, 1);
+        assert_eq!(cache.release(&key), ReleaseOutcome::NotLoaded);
+        assert!(cache.lease(&key).is_none());
+    }
+
+    #[test]
+    fn in_flight_executions_delay_release() {
+        let cache = PluginHandleCache::with_unload_policy(UnloadPolicy::Release);
+        let key = LibraryKey::of(b"pkg");
+        cache.acquire(&key);
+
+        let first = cache.lease(&key).unwrap();
+        let second = cache.lease(&key).unwrap();
+        assert_eq!(cache.stats().in_flight, 2);
+
+        assert_eq!(
+            cache.release(&key),
+            ReleaseOutcome::Draining { in_flight: 2 }
+        );
+        assert_eq!(cache.stats().draining, 1);
+
+        drop(first);
+        assert_eq!(cache.stats().libraries, 1);
+        drop(second);
+        let stats = cache.stats();
+        assert_eq!(stats.libraries, 0);
+        assert_eq!(stats.released_total, 1);
+    }
+
+    #[test]
+    fn shared_library_stays_until_last_owner_releases() {
+        let cache = PluginHandleCache::with_unload_policy(UnloadPolicy::Release);
+        let key = LibraryKey::of(b"pkg");
+        cache.acquire(&key);
+        cache.acquire(&key);
+
+        assert_eq!(
+            cache.release(&key),
+            ReleaseOutcome::StillOwned { owners: 1 }
+        );
+        assert_eq!(cache.release(&key), ReleaseOutcome::Released);
+    }
+
+    #[test]
+    fn reacquire_while_draining_keeps_the_library() {
+        let cache = PluginHandleCache::with_unload_policy(UnloadPolicy::Release);
+        let key = LibraryKey::of(b"pkg");
+        cache.acquire(&key);
+        let lease = cache.lease(&key).unwrap();
+        cache.release(&key);
+
+        cache.acquire(&key);
+        drop(lease);
+        let stats = cache.stats();
+        assert_eq!(stats.libraries, 1);
+        assert_eq!(stats.draining, 0);
+        assert_eq!(stats.released_total, 0);
+    }
+
+    #[test]
+    fn release_unowned_skips_owned_libraries() {
+        let cache = PluginHandleCache::with_unload_policy(UnloadPolicy::Release);
+        let owned = LibraryKey::of(b"owned");
+        let stray = LibraryKey::of(b"stray");
+        cache.acquire(&owned);
+        cache.acquire(&stray);
+        cache.release(&stray);
+        cache.acquire(&stray);
+        // Simulate extraction-only handles: tracked, never acquired.
+        cache.lock().libraries.get_mut(&stray).unwrap().owners = 0;
+
+        assert_eq!(cache.release_unowned(), 1);
+        assert_eq!(cache.stats().libraries, 1);
+        assert!(cache.lease(&owned).is_some());
+    }
+
+    #[test]
+    fn unload_policy_is_shared_between_clones() {
+        let cache = PluginHandleCache::with_unload_policy(UnloadPolicy::Release);
+        let clone = cache.clone();
+        clone.set_unload_policy(UnloadPolicy::Retain);
+        assert_eq!(cache.unload_policy(), UnloadPolicy::Retain);
+    }
+}
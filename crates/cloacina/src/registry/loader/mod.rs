@@ -20,6 +20,8 @@
 //! extract metadata, validate package integrity, and register tasks with the
 //! global task registry.
 
+/// Package capability manifests checked against deployment policy.
+pub mod capabilities;
 /// WASM task-constructor loader + executor adapter (CLOACI-I-0132 / T-0823).
 /// Default-OFF behind the `constructors-wasm` feature.
 #[cfg(feature = "constructors-wasm")]
@@ -30,9 +32,14 @@ pub mod ffi_triggerless_graph;
 /// Default-OFF behind the `constructors-wasm` feature.
 #[cfg(feature = "constructors-wasm")]
 pub mod grants;
+pub mod library_cache;
 pub mod package_loader;
 pub mod task_registrar;
 
+pub use capabilities::{CapabilityError, CapabilityPolicy};
+pub use library_cache::{
+    LibraryCacheStats, LibraryKey, LibraryLease, PluginHandleCache, ReleaseOutcome, UnloadPolicy,
+};
 pub use package_loader::PackageLoader;
 pub use task_registrar::TaskRegistrar;
 
@@ -24,6 +24,8 @@ use std::path::Path;
 use tempfile::TempDir;
 use tokio::fs;
 
+use super::library_cache::LibraryKey;
+pub use super::library_cache::PluginHandleCache;
 use crate::registry::error::LoaderError;
 
 /// Get the platform-specific dynamic library extension.
@@ -92,6 +94,18 @@ pub struct PackageMetadata {
     /// and metadata predating the field.
     #[serde(default)]
     pub task_docs: std::collections::HashMap<String, TaskDocs>,
+    /// Build provenance read from the archive's `provenance.json` at upload.
+    /// Identity data like `package_name`: kept when the build-success merge
+    /// rewrites the cdylib-derived fields. `None` for packages packed without
+    /// it.
+    #[serde(default)]
+    pub provenance: Option<crate::packaging::BuildProvenance>,
+    /// Configuration values supplied at registration, validated against the
+    /// package's `config_schema` with its defaults filled in. Kept through
+    /// the build-success merge like `provenance`. `None` when the package
+    /// declares no schema.
+    #[serde(default)]
+    pub config: Option<serde_json::Value>,
 }
 
 /// Individual task metadata.
@@ -135,24 +149,14 @@ pub struct TaskDocs {
 
 /// Package loader for extracting metadata from workflow library files.
 ///
-/// Loaded libraries are cached to prevent dlclose. The `inventory` crate
-/// (used by fidius) builds a global linked list via `__attribute__((constructor))`.
-/// If a cdylib is dlclosed, the linked-list nodes are unmapped but the head
-/// pointer still references them. Loading a second cdylib then crashes when
-/// its constructors traverse the corrupted list (macOS sends SIGKILL).
-/// Keeping libraries alive avoids this entirely.
-/// Shared cache of plugin handles kept alive to prevent dlclose.
-///
-/// The `inventory` crate (used by fidius) builds a global intrusive linked list
-/// via `__attribute__((constructor))`. If a cdylib is dlclosed, the list nodes
-/// are unmapped but the head pointer still references them. Loading a second
-/// cdylib then crashes when its constructors traverse the corrupted list
-/// (macOS sends SIGKILL). Keeping handles alive avoids dlclose entirely.
-pub type PluginHandleCache = std::sync::Arc<std::sync::Mutex<Vec<fidius_host::PluginHandle>>>;
-
+/// Every library opened for metadata extraction is filed in the shared
+/// [`PluginHandleCache`] under the [`LibraryKey`] of its bytes, so it is
+/// released together with the rest of the package when the package is
+/// deregistered (see [`super::library_cache`] for why dlclose is deferred
+/// and, on some platforms, skipped).
 pub struct PackageLoader {
     temp_dir: TempDir,
-    /// Shared cache — prevents dlclose of loaded libraries.
+    /// Shared cache owning the handles opened for each library.
     handle_cache: PluginHandleCache,
 }
 
@@ -165,7 +169,7 @@ impl PackageLoader {
 
         Ok(Self {
             temp_dir,
-            handle_cache: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+            handle_cache: PluginHandleCache::new(),
         })
     }
 
@@ -252,15 +256,18 @@ impl PackageLoader {
                 error: e.to_string(),
             })?;
 
-        self.extract_metadata_from_so(&temp_path).await
+        self.extract_metadata_from_so(&temp_path, &LibraryKey::of(package_data))
+            .await
     }
 
     /// Extract metadata from a library file using the fidius-host plugin API.
     ///
-    /// The loaded library is cached to prevent dlclose — see struct-level docs.
+    /// The loaded library is filed in the handle cache under `key` — see
+    /// struct-level docs.
     async fn extract_metadata_from_so(
         &self,
         library_path: &Path,
+        key: &LibraryKey,
     ) -> Result<PackageMetadata, LoaderError> {
         // Load via fidius-host — validates magic, ABI version, wire format, etc.
         let loaded = fidius_host::loader::load_library(library_path).map_err(
@@ -330,12 +337,9 @@ impl PackageLoader {
             }
         };
 
-        // Keep the handle alive — dropping it triggers dlclose which corrupts
-        // the inventory linked list (see struct-level docs).
-        // PluginHandle holds an Arc<Library> that keeps the dylib mapped.
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        // File the handle with the package's library so it is released with
+        // the package rather than dlclosed here (see struct-level docs).
+        self.handle_cache.retain(key, handle);
 
         let mut pkg = self.convert_plugin_metadata_to_rust(ffi_metadata)?;
         pkg.declared_params = declared_params;
@@ -414,6 +418,8 @@ impl PackageLoader {
             declared_surfaces: Vec::new(),
             // Docs come from the compiler parse at build success (T-0754).
             task_docs: Default::default(),
+            provenance: None,
+            config: None,
         })
     }
 
@@ -468,10 +474,8 @@ impl PackageLoader {
             }
         };
 
-        // Keep handle alive to prevent dlclose
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
 
         result
     }
@@ -521,9 +525,8 @@ impl PackageLoader {
         let result = crate::computation_graph::packaging_bridge::call_get_reactor_metadata(&handle)
             .map_err(|e| LoaderError::MetadataExtraction { reason: e });
 
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
 
         result
     }
@@ -576,9 +579,8 @@ impl PackageLoader {
             crate::computation_graph::packaging_bridge::call_get_constructor_metadata(&handle)
                 .map_err(|e| LoaderError::MetadataExtraction { reason: e });
 
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
 
         result
     }
@@ -626,13 +628,71 @@ impl PackageLoader {
         let result = crate::computation_graph::packaging_bridge::call_get_trigger_metadata(&handle)
             .map_err(|e| LoaderError::MetadataExtraction { reason: e });
 
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
 
         result
     }
 
+    /// Run the package's load-time health checks (method index 11).
+    ///
+    /// Each `#[workflow(health_check = ...)]` entry point runs inside the
+    /// cdylib under `timeout`. The FFI call blocks for as long as the checks
+    /// take, so it is driven from a blocking thread. An empty result means
+    /// the package declares no checks (or predates them).
+    pub async fn run_health_checks(
+        &self,
+        package_data: &[u8],
+        timeout: std::time::Duration,
+    ) -> Result<Vec<cloacina_workflow_plugin::HealthCheckReport>, LoaderError> {
+        let library_extension = get_library_extension();
+        let temp_path = self.temp_dir.path().join(format!(
+            "health_{}.{}",
+            uuid::Uuid::new_v4(),
+            library_extension
+        ));
+        fs::write(&temp_path, package_data)
+            .await
+            .map_err(|e| LoaderError::FileSystem {
+                path: temp_path.to_string_lossy().to_string(),
+                error: e.to_string(),
+            })?;
+
+        let loaded = fidius_host::loader::load_library(&temp_path).map_err(
+            |e: fidius_host::LoadError| LoaderError::LibraryLoad {
+                path: temp_path.to_string_lossy().to_string(),
+                error: e.to_string(),
+            },
+        )?;
+
+        let plugin =
+            loaded
+                .plugins
+                .into_iter()
+                .next()
+                .ok_or_else(|| LoaderError::MetadataExtraction {
+                    reason: "Plugin library contains no plugins".to_string(),
+                })?;
+
+        let handle = fidius_host::PluginHandle::from_loaded(plugin);
+
+        let (handle, result) = tokio::task::spawn_blocking(move || {
+            let result = crate::computation_graph::packaging_bridge::call_run_health_checks(
+                &handle, timeout,
+            );
+            (handle, result)
+        })
+        .await
+        .map_err(|e| LoaderError::MetadataExtraction {
+            reason: format!("health check thread failed: {}", e),
+        })?;
+
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
+
+        result.map_err(|e| LoaderError::MetadataExtraction { reason: e })
+    }
+
     /// Extract trigger-less computation graph metadata from compiled
     /// library bytes (T-0553 follow-up — Trigger-less CG FFI bridge).
     ///
@@ -697,9 +757,8 @@ impl PackageLoader {
             }),
         };
 
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
 
         out
     }
@@ -18,22 +18,45 @@
 //!
 //! The plugin library is loaded once during package registration and the handle
 //! is shared across all task instances from that package. No per-execution
-//! temp files or dlopen/dlclose cycles.
+//! temp files or dlopen/dlclose cycles. Each execution holds a lease on the
+//! package's library so an unregistered package is only released once its
+//! in-flight tasks finish. When the registrar runs with a sandbox mode and the
+//! package is capability-gated, each call runs on its own thread under the
+//! Landlock/seccomp profile derived from the granted capabilities (see
+//! `security::sandbox`).
 
 use chrono::Utc;
 use std::sync::Arc;
 
 use crate::context::Context;
 use crate::error::TaskError;
-use crate::task::{Task, TaskNamespace};
-use cloacina_workflow_plugin::{TaskExecutionRequest, TaskExecutionResult};
+use crate::outputs::ProducedOutput;
+use crate::registry::loader::library_cache::{LibraryKey, LibraryLease, PluginHandleCache};
+use crate::security::sandbox::{SandboxMode, SandboxProfile, SandboxStatus};
+use crate::task::{Task, TaskNamespace, WorkloadClass};
+use cloacina_workflow::context::value_bytes;
+use cloacina_workflow_plugin::{CapabilityManifest, TaskExecutionRequest, TaskExecutionResult};
 
 /// A persistent handle to a loaded workflow plugin library.
 ///
 /// Loaded once from compiled library bytes, kept alive for the lifetime of the
-/// package. All task instances from the same package share this handle.
+/// package. All task instances from the same package share this handle; the
+/// handle is also filed in the registrar's `PluginHandleCache`, which decides
+/// when the library may be dlclosed.
 pub(super) struct LoadedWorkflowPlugin {
-    handle: std::sync::Mutex<fidius_host::PluginHandle>,
+    handle: Arc<fidius_host::PluginHandle>,
+    /// Serializes calls into the plugin.
+    call_lock: std::sync::Mutex<()>,
+    cache: PluginHandleCache,
+    library_key: LibraryKey,
+    /// Capabilities the deployment policy granted this package at
+    /// registration. `None` when the package is not capability-gated.
+    capabilities: Option<CapabilityManifest>,
+    /// Whether calls into a capability-gated package run sandboxed.
+    sandbox: SandboxMode,
+    /// JSON-encoded configuration values the package was registered with,
+    /// sent with every execution request.
+    package_config_json: Option<String>,
     // Keep the temp dir alive so the dylib file isn't deleted while loaded
     _temp_dir: tempfile::TempDir,
 }
@@ -44,8 +67,13 @@ unsafe impl Send for LoadedWorkflowPlugin {}
 unsafe impl Sync for LoadedWorkflowPlugin {}
 
 impl LoadedWorkflowPlugin {
-    /// Load a workflow plugin from library bytes.
-    pub(super) fn load(library_data: &[u8], package_name: &str) -> Result<Self, TaskError> {
+    /// Load a workflow plugin from library bytes, filing its handle in `cache`.
+    pub(super) fn load(
+        library_data: &[u8],
+        package_name: &str,
+        cache: &PluginHandleCache,
+        library_key: &LibraryKey,
+    ) -> Result<Self, TaskError> {
         let temp_dir = tempfile::TempDir::new().map_err(|e| TaskError::ExecutionFailed {
             task_id: package_name.to_string(),
             message: format!("Failed to create temp dir: {}", e),
@@ -81,21 +109,54 @@ impl LoadedWorkflowPlugin {
                     timestamp: Utc::now(),
                 })?;
 
-        let handle = fidius_host::PluginHandle::from_loaded(plugin);
+        let handle = cache.retain(library_key, fidius_host::PluginHandle::from_loaded(plugin));
 
         Ok(Self {
-            handle: std::sync::Mutex::new(handle),
+            handle,
+            call_lock: std::sync::Mutex::new(()),
+            cache: cache.clone(),
+            library_key: library_key.clone(),
+            capabilities: None,
+            sandbox: SandboxMode::Off,
+            package_config_json: None,
             _temp_dir: temp_dir,
         })
     }
 
+    /// Gate this package's executions on the capabilities it was granted.
+    pub(super) fn with_capabilities(mut self, capabilities: Option<CapabilityManifest>) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Run this package's executions under the sandbox derived from its
+    /// granted capabilities. Has no effect on a package that is not
+    /// capability-gated.
+    pub(super) fn with_sandbox(mut self, mode: SandboxMode) -> Self {
+        self.sandbox = mode;
+        self
+    }
+
+    /// Hand this package's executions the configuration values it was
+    /// registered with.
+    pub(super) fn with_package_config(mut self, config: Option<&serde_json::Value>) -> Self {
+        self.package_config_json = config.map(|config| config.to_string());
+        self
+    }
+
+    /// Mark an execution as running in this library. `None` once the
+    /// package has been fully released.
+    fn lease(&self) -> Option<LibraryLease> {
+        self.cache.lease(&self.library_key)
+    }
+
     /// Call execute_task on the loaded plugin.
     fn execute_task(&self, request: TaskExecutionRequest) -> Result<TaskExecutionResult, String> {
-        let handle = self
-            .handle
+        let _guard = self
+            .call_lock
             .lock()
             .map_err(|e| format!("Plugin mutex poisoned: {}", e))?;
-        handle
+        self.handle
             .call_method(cloacina_workflow_plugin::METHOD_EXECUTE_TASK, &(request,))
             .map_err(|e| format!("execute_task FFI call failed: {}", e))
     }
@@ -123,6 +184,36 @@ pub(super) struct DynamicLibraryTask {
     /// FFI metadata (CLOACI-T-0721). Without this the host defaulted to `Always`
     /// and packaged workflows silently ignored conditional execution / skips.
     trigger_rules: serde_json::Value,
+    /// Output key whose emptiness skips descendants, from the FFI metadata.
+    short_circuit: Option<String>,
+    /// Output key holding the selected branch name, from the FFI metadata.
+    branch_key: Option<String>,
+    /// Branch the task heads, from the FFI metadata.
+    branch: Option<String>,
+    /// Condition ending the task's loop, from the FFI metadata.
+    loop_until: Option<serde_json::Value>,
+    /// Most iterations of the task's loop, from the FFI metadata.
+    max_iterations: Option<u32>,
+    /// Dependencies the task treats as soft, from the FFI metadata.
+    soft_dependencies: Vec<TaskNamespace>,
+    /// RSS growth limit in bytes, from the FFI metadata.
+    memory_limit: Option<u64>,
+    /// Execution timeout, from the FFI metadata.
+    timeout: Option<std::time::Duration>,
+    /// Whether the task waits for approval, from the FFI metadata.
+    requires_approval: bool,
+    /// Workload class, from the FFI metadata.
+    workload_class: Option<WorkloadClass>,
+    /// Named resources the task uses, from the FFI metadata.
+    resources: Vec<String>,
+    /// Declared outputs, from the FFI metadata.
+    produces: Vec<ProducedOutput>,
+    /// Whether the task's writes are scoped, from the FFI metadata.
+    scoped: bool,
+    /// Declared reads, from the FFI metadata.
+    reads: Option<Vec<String>>,
+    /// Declarative configuration, from the FFI metadata.
+    configuration: Option<serde_json::Value>,
 }
 
 impl DynamicLibraryTask {
@@ -130,8 +221,10 @@ impl DynamicLibraryTask {
     pub(super) fn load_plugin(
         library_data: &[u8],
         package_name: &str,
+        cache: &PluginHandleCache,
+        library_key: &LibraryKey,
     ) -> Result<LoadedWorkflowPlugin, TaskError> {
-        LoadedWorkflowPlugin::load(library_data, package_name)
+        LoadedWorkflowPlugin::load(library_data, package_name, cache, library_key)
     }
 
     /// Create a new dynamic library task with a shared plugin handle.
@@ -146,8 +239,106 @@ impl DynamicLibraryTask {
             task_name,
             dependencies,
             trigger_rules,
+            short_circuit: None,
+            branch_key: None,
+            branch: None,
+            loop_until: None,
+            max_iterations: None,
+            soft_dependencies: Vec::new(),
+            memory_limit: None,
+            timeout: None,
+            requires_approval: false,
+            workload_class: None,
+            resources: Vec::new(),
+            produces: Vec::new(),
+            scoped: false,
+            reads: None,
+            configuration: None,
         }
     }
+
+    /// Set the output key whose emptiness short-circuits descendants.
+    pub(super) fn with_short_circuit(mut self, short_circuit: Option<String>) -> Self {
+        self.short_circuit = short_circuit;
+        self
+    }
+
+    /// Set the decider output key and the branch the task heads.
+    pub(super) fn with_branch(
+        mut self,
+        branch_key: Option<String>,
+        branch: Option<String>,
+    ) -> Self {
+        self.branch_key = branch_key;
+        self.branch = branch;
+        self
+    }
+
+    /// Set the condition ending the task's loop and its iteration bound.
+    pub(super) fn with_loop(
+        mut self,
+        loop_until: Option<serde_json::Value>,
+        max_iterations: Option<u32>,
+    ) -> Self {
+        self.loop_until = loop_until;
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the dependencies the task treats as soft.
+    pub(super) fn with_soft_dependencies(mut self, soft_dependencies: Vec<TaskNamespace>) -> Self {
+        self.soft_dependencies = soft_dependencies;
+        self
+    }
+
+    /// Set the task's RSS growth limit in bytes.
+    pub(super) fn with_memory_limit(mut self, memory_limit: Option<u64>) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// Set the task's execution timeout.
+    pub(super) fn with_timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set whether the task waits for approval before it runs.
+    pub(super) fn with_requires_approval(mut self, requires_approval: bool) -> Self {
+        self.requires_approval = requires_approval;
+        self
+    }
+
+    /// Set the task's workload class.
+    pub(super) fn with_workload_class(mut self, workload_class: Option<WorkloadClass>) -> Self {
+        self.workload_class = workload_class;
+        self
+    }
+
+    /// Set the named resources the task uses.
+    pub(super) fn with_resources(mut self, resources: Vec<String>) -> Self {
+        self.resources = resources;
+        self
+    }
+
+    /// Set the task's declared outputs.
+    pub(super) fn with_produces(mut self, produces: Vec<ProducedOutput>) -> Self {
+        self.produces = produces;
+        self
+    }
+
+    /// Set whether the task's writes are scoped and the keys it reads.
+    pub(super) fn with_scoping(mut self, scoped: bool, reads: Option<Vec<String>>) -> Self {
+        self.scoped = scoped;
+        self.reads = reads;
+        self
+    }
+
+    /// Set the task's declarative configuration.
+    pub(super) fn with_configuration(mut self, configuration: Option<serde_json::Value>) -> Self {
+        self.configuration = configuration;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -158,13 +349,12 @@ impl Task for DynamicLibraryTask {
         context: Context<serde_json::Value>,
     ) -> Result<Context<serde_json::Value>, TaskError> {
         // Serialize current context for the request
-        let context_json =
-            serde_json::to_string(context.data()).map_err(|e| TaskError::ValidationFailed {
-                message: format!(
-                    "Failed to serialize context for task '{}': {}",
-                    self.task_name, e
-                ),
-            })?;
+        let context_json = context.to_json().map_err(|e| TaskError::ValidationFailed {
+            message: format!(
+                "Failed to serialize context for task '{}': {}",
+                self.task_name, e
+            ),
+        })?;
 
         tracing::debug!("Task '{}' input context: {}", self.task_name, context_json);
 
@@ -191,6 +381,20 @@ impl Task for DynamicLibraryTask {
             })
             .unwrap_or_default();
         for secret_name in referenced {
+            // A capability-gated package only reaches the secrets it declared
+            // and the deployment policy granted.
+            if let Some(granted) = &self.plugin.capabilities {
+                if !granted.secrets.contains(&secret_name) {
+                    return Err(TaskError::ExecutionFailed {
+                        task_id: self.task_name.clone(),
+                        message: format!(
+                            "secret '{}' is not among the package's granted capabilities",
+                            secret_name
+                        ),
+                        timestamp: Utc::now(),
+                    });
+                }
+            }
             let fields =
                 context
                     .secret(&secret_name)
@@ -207,24 +411,91 @@ impl Task for DynamicLibraryTask {
             task_name: self.task_name.clone(),
             context_json,
             resolved_secrets,
+            // Granted environment variables are read host-side and become the
+            // only ones `context.env_var(...)` sees inside the package.
+            granted_env: self.plugin.capabilities.as_ref().map(|granted| {
+                granted
+                    .env
+                    .iter()
+                    .filter_map(|name| std::env::var(name).ok().map(|v| (name.clone(), v)))
+                    .collect()
+            }),
+            package_config_json: self.plugin.package_config_json.clone(),
+            params_json: context
+                .params()
+                .map(|params| serde_json::Value::Object(params.clone()).to_string()),
         };
 
-        // Call via the shared plugin handle
+        // Call via the shared plugin handle, holding a lease on the library
+        // for the duration of the call.
         let plugin = self.plugin.clone();
         let task_name = self.task_name.clone();
 
-        let result = tokio::task::spawn_blocking(move || plugin.execute_task(request))
+        let lease = plugin.lease();
+
+        let sandbox = match (plugin.sandbox, &plugin.capabilities) {
+            (SandboxMode::Off, _) | (_, None) => None,
+            (mode, Some(granted)) => Some((mode, SandboxProfile::from_manifest(granted))),
+        };
+
+        let outcome = match sandbox {
+            None => tokio::task::spawn_blocking(move || {
+                let _lease = lease;
+                plugin.execute_task(request)
+            })
             .await
             .map_err(|e| TaskError::ExecutionFailed {
                 task_id: task_name.clone(),
                 message: format!("spawn_blocking panicked: {}", e),
                 timestamp: Utc::now(),
-            })?
-            .map_err(|e| TaskError::ExecutionFailed {
-                task_id: task_name.clone(),
-                message: format!("Plugin call failed for task '{}': {}", task_name, e),
-                timestamp: Utc::now(),
-            })?;
+            })?,
+            // The sandbox cannot be lifted from a thread once applied, so a
+            // sandboxed call gets a thread of its own that exits with it.
+            Some((mode, profile)) => {
+                let (tx, rx) = tokio::sync::oneshot::channel();
+                let thread_task_name = task_name.clone();
+                std::thread::Builder::new()
+                    .name("cloacina-sandboxed-task".to_string())
+                    .spawn(move || {
+                        let _lease = lease;
+                        let outcome = match profile.apply_to_current_thread(mode) {
+                            Ok(status) => {
+                                if status == SandboxStatus::FullyEnforced {
+                                    tracing::debug!(
+                                        "Task '{}' running under a fully enforced sandbox",
+                                        thread_task_name
+                                    );
+                                } else {
+                                    tracing::warn!(
+                                        "Task '{}' sandbox is {} on this kernel; running anyway (best-effort mode)",
+                                        thread_task_name,
+                                        status
+                                    );
+                                }
+                                plugin.execute_task(request)
+                            }
+                            Err(e) => Err(format!("refusing to run unsandboxed: {}", e)),
+                        };
+                        let _ = tx.send(outcome);
+                    })
+                    .map_err(|e| TaskError::ExecutionFailed {
+                        task_id: task_name.clone(),
+                        message: format!("failed to spawn sandboxed task thread: {}", e),
+                        timestamp: Utc::now(),
+                    })?;
+                rx.await.map_err(|_| TaskError::ExecutionFailed {
+                    task_id: task_name.clone(),
+                    message: "sandboxed task thread exited without a result".to_string(),
+                    timestamp: Utc::now(),
+                })?
+            }
+        };
+
+        let result = outcome.map_err(|e| TaskError::ExecutionFailed {
+            task_id: task_name.clone(),
+            message: format!("Plugin call failed for task '{}': {}", task_name, e),
+            timestamp: Utc::now(),
+        })?;
 
         if result.success {
             let mut result_context = context;
@@ -244,7 +515,18 @@ impl Task for DynamicLibraryTask {
 
                 if let serde_json::Value::Object(obj) = result_value {
                     for (key, value) in obj {
-                        if result_context.get(&key).is_some() {
+                        if let Some(raw) = value_bytes(&value) {
+                            result_context.remove(&key);
+                            result_context.insert_bytes(key, raw).map_err(|e| {
+                                TaskError::ExecutionFailed {
+                                    task_id: self.task_name.clone(),
+                                    message: format!("Failed to insert result: {}", e),
+                                    timestamp: Utc::now(),
+                                }
+                            })?;
+                        } else if result_context.get(&key).is_some()
+                            || result_context.binary().contains_key(&key)
+                        {
                             result_context.update(key, value).map_err(|e| {
                                 TaskError::ExecutionFailed {
                                     task_id: self.task_name.clone(),
@@ -293,6 +575,70 @@ impl Task for DynamicLibraryTask {
     fn trigger_rules(&self) -> serde_json::Value {
         self.trigger_rules.clone()
     }
+
+    fn is_soft_dependency(&self, dependency: &TaskNamespace) -> bool {
+        self.soft_dependencies.contains(dependency)
+    }
+
+    fn short_circuit(&self) -> Option<String> {
+        self.short_circuit.clone()
+    }
+
+    fn branch_key(&self) -> Option<String> {
+        self.branch_key.clone()
+    }
+
+    fn branch(&self) -> Option<String> {
+        self.branch.clone()
+    }
+
+    fn loop_until(&self) -> Option<serde_json::Value> {
+        self.loop_until.clone()
+    }
+
+    fn max_iterations(&self) -> Option<u32> {
+        self.max_iterations
+    }
+
+    fn memory_limit(&self) -> Option<u64> {
+        self.memory_limit
+    }
+
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.timeout
+    }
+
+    fn requires_approval(&self) -> bool {
+        self.requires_approval
+    }
+
+    fn workload_class(&self) -> Option<WorkloadClass> {
+        self.workload_class
+    }
+
+    fn resources(&self) -> Vec<String> {
+        self.resources.clone()
+    }
+
+    fn produces(&self) -> Vec<ProducedOutput> {
+        self.produces.clone()
+    }
+
+    fn scoped(&self) -> bool {
+        self.scoped
+    }
+
+    fn reads(&self) -> Option<Vec<String>> {
+        self.reads.clone()
+    }
+
+    fn configuration(&self) -> Option<serde_json::Value> {
+        self.configuration.clone()
+    }
+
+    fn capabilities(&self) -> Option<CapabilityManifest> {
+        self.plugin.capabilities.clone()
+    }
 }
 
 #[cfg(test)]
@@ -21,6 +21,7 @@ use tokio::fs;
 use super::types::{OwnedTaskMetadata, OwnedTaskMetadataCollection};
 use super::TaskRegistrar;
 use crate::registry::error::LoaderError;
+use crate::registry::loader::library_cache::LibraryKey;
 use crate::registry::loader::package_loader::get_library_extension;
 
 impl TaskRegistrar {
@@ -84,13 +85,28 @@ impl TaskRegistrar {
                 dependencies_json: serde_json::to_string(&t.dependencies)
                     .unwrap_or_else(|_| "[]".to_string()),
                 trigger_rules_json: t.trigger_rules,
+                short_circuit: t.short_circuit,
+                branch_key: t.branch_key,
+                branch: t.branch,
+                loop_until_json: t.loop_until,
+                max_iterations: t.max_iterations,
+                soft_dependencies: t.soft_dependencies,
+                memory_limit: t.memory_limit,
+                timeout_ms: t.timeout_ms,
+                requires_approval: t.requires_approval,
+                workload_class: t.workload_class,
+                resources: t.resources,
+                produces: t.produces,
+                scoped: t.scoped,
+                reads: t.reads,
+                configuration_json: t.configuration,
             })
             .collect();
 
-        // Keep handle alive to prevent dlclose — inventory linked-list corruption
-        if let Ok(mut cache) = self.handle_cache.lock() {
-            cache.push(handle);
-        }
+        // File the handle with the package's library; it is released when the
+        // package is unregistered (see `library_cache` for the dlclose rules).
+        self.handle_cache
+            .retain(&LibraryKey::of(package_data), handle);
 
         tracing::debug!(
             "Extracted metadata via fidius: package={}, workflow={}, task_count={}",
@@ -34,10 +34,14 @@ use tempfile::TempDir;
 
 use dynamic_task::DynamicLibraryTask;
 
+use crate::outputs::ProducedOutput;
 use crate::registry::error::LoaderError;
+use crate::registry::loader::library_cache::{LibraryKey, PluginHandleCache};
 use crate::registry::loader::package_loader::PackageMetadata;
-use crate::task::{Task, TaskNamespace};
+use crate::security::sandbox::SandboxMode;
+use crate::task::{Task, TaskNamespace, WorkloadClass};
 use crate::Runtime;
+use cloacina_workflow_plugin::CapabilityManifest;
 
 /// Task registrar for managing dynamically loaded package tasks.
 ///
@@ -49,11 +53,14 @@ pub struct TaskRegistrar {
     pub(super) temp_dir: TempDir,
     /// Map of package IDs to registered task namespaces for cleanup tracking
     registered_tasks: Arc<RwLock<HashMap<String, Vec<TaskNamespace>>>>,
-    /// Tracks which package IDs have been registered (for cleanup bookkeeping)
-    loaded_packages: Arc<RwLock<HashMap<String, ()>>>,
-    /// Shared cache — prevents dlclose of loaded libraries.
-    /// See `PackageLoader` docs for full explanation.
-    handle_cache: crate::registry::loader::package_loader::PluginHandleCache,
+    /// Library each registered package owns in the handle cache, released
+    /// again on unregistration
+    loaded_packages: Arc<RwLock<HashMap<String, LibraryKey>>>,
+    /// Shared cache owning the package library handles.
+    /// See `library_cache` for when libraries are dlclosed.
+    handle_cache: PluginHandleCache,
+    /// Sandbox applied to executions of capability-gated packages.
+    sandbox: SandboxMode,
 }
 
 impl TaskRegistrar {
@@ -67,14 +74,13 @@ impl TaskRegistrar {
             temp_dir,
             registered_tasks: Arc::new(RwLock::new(HashMap::new())),
             loaded_packages: Arc::new(RwLock::new(HashMap::new())),
-            handle_cache: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+            handle_cache: PluginHandleCache::new(),
+            sandbox: SandboxMode::Off,
         })
     }
 
     /// Create a task registrar with a shared handle cache.
-    pub fn with_handle_cache(
-        cache: crate::registry::loader::package_loader::PluginHandleCache,
-    ) -> Result<Self, LoaderError> {
+    pub fn with_handle_cache(cache: PluginHandleCache) -> Result<Self, LoaderError> {
         let temp_dir = TempDir::new().map_err(|e| LoaderError::TempDirectory {
             error: e.to_string(),
         })?;
@@ -84,9 +90,18 @@ impl TaskRegistrar {
             registered_tasks: Arc::new(RwLock::new(HashMap::new())),
             loaded_packages: Arc::new(RwLock::new(HashMap::new())),
             handle_cache: cache,
+            sandbox: SandboxMode::Off,
         })
     }
 
+    /// Run executions of capability-gated packages registered from here on
+    /// under the Landlock/seccomp sandbox derived from their granted
+    /// capabilities. Packages registered without capabilities are unaffected.
+    pub fn with_sandbox(mut self, mode: SandboxMode) -> Self {
+        self.sandbox = mode;
+        self
+    }
+
     /// Register package tasks with the global task registry using new host-managed approach.
     ///
     /// # Arguments
@@ -104,9 +119,33 @@ impl TaskRegistrar {
         &self,
         package_id: &str,
         package_data: &[u8],
-        _metadata: &PackageMetadata,
+        metadata: &PackageMetadata,
+        tenant_id: Option<&str>,
+        runtime: &Arc<Runtime>,
+    ) -> Result<Vec<TaskNamespace>, LoaderError> {
+        self.register_package_tasks_with_capabilities(
+            package_id,
+            package_data,
+            metadata,
+            tenant_id,
+            runtime,
+            None,
+        )
+        .await
+    }
+
+    /// Register package tasks whose executions are gated on the capabilities
+    /// the deployment policy granted the package. `None` registers them
+    /// ungated, like [`register_package_tasks`](Self::register_package_tasks).
+    /// Either way, the tasks see `metadata.config` as their package config.
+    pub async fn register_package_tasks_with_capabilities(
+        &self,
+        package_id: &str,
+        package_data: &[u8],
+        metadata: &PackageMetadata,
         tenant_id: Option<&str>,
         runtime: &Arc<Runtime>,
+        capabilities: Option<CapabilityManifest>,
     ) -> Result<Vec<TaskNamespace>, LoaderError> {
         let tenant_id = tenant_id.unwrap_or("public");
 
@@ -117,12 +156,20 @@ impl TaskRegistrar {
             .await?;
 
         // Load the plugin library once — all tasks from this package share the handle.
+        let library_key = LibraryKey::of(package_data);
         let plugin = Arc::new(
-            DynamicLibraryTask::load_plugin(package_data, &task_metadata.package_name).map_err(
-                |e| LoaderError::MetadataExtraction {
-                    reason: format!("Failed to load plugin for task execution: {}", e),
-                },
-            )?,
+            DynamicLibraryTask::load_plugin(
+                package_data,
+                &task_metadata.package_name,
+                &self.handle_cache,
+                &library_key,
+            )
+            .map_err(|e| LoaderError::MetadataExtraction {
+                reason: format!("Failed to load plugin for task execution: {}", e),
+            })?
+            .with_capabilities(capabilities)
+            .with_sandbox(self.sandbox)
+            .with_package_config(metadata.config.as_ref()),
         );
 
         // Register tasks in HOST global registry using metadata.
@@ -182,17 +229,88 @@ impl TaskRegistrar {
             let trigger_rules: serde_json::Value = serde_json::from_str(&task.trigger_rules_json)
                 .unwrap_or_else(|_| serde_json::json!({ "type": "Always" }));
 
+            // Short-circuit, branches, soft dependencies and memory limit travel
+            // the same way, so packaged tasks skip, tolerate and enforce like
+            // embedded ones.
+            let soft_dependencies: Vec<TaskNamespace> = dependency_namespaces
+                .iter()
+                .filter(|dep| task.soft_dependencies.contains(&dep.task_id))
+                .cloned()
+                .collect();
+
             let plugin = plugin.clone();
             let task_name = task_id.to_string();
             let deps = dependency_namespaces.clone();
+            let short_circuit = task.short_circuit.clone();
+            let branch_key = task.branch_key.clone();
+            let branch = task.branch.clone();
+            let loop_until = match &task.loop_until_json {
+                Some(json) => Some(serde_json::from_str::<serde_json::Value>(json).map_err(
+                    |e| LoaderError::MetadataExtraction {
+                        reason: format!("Invalid loop_until JSON for task '{}': {}", task_id, e),
+                    },
+                )?),
+                None => None,
+            };
+            let max_iterations = task.max_iterations;
+            let memory_limit = task.memory_limit;
+            let timeout = task.timeout_ms.map(std::time::Duration::from_millis);
+            let requires_approval = task.requires_approval;
+            let workload_class = match &task.workload_class {
+                Some(name) => Some(WorkloadClass::from_str(name).ok_or_else(|| {
+                    LoaderError::MetadataExtraction {
+                        reason: format!("Unknown workload class '{}' for task '{}'", name, task_id),
+                    }
+                })?),
+                None => None,
+            };
+            let resources = task.resources.clone();
+            let produces = task
+                .produces
+                .iter()
+                .map(|declaration| {
+                    ProducedOutput::parse(declaration).ok_or_else(|| {
+                        LoaderError::MetadataExtraction {
+                            reason: format!(
+                                "Invalid output declaration '{}' for task '{}'",
+                                declaration, task_id
+                            ),
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let scoped = task.scoped;
+            let reads = task.reads.clone();
+            let configuration = match &task.configuration_json {
+                Some(json) => Some(serde_json::from_str::<serde_json::Value>(json).map_err(
+                    |e| LoaderError::MetadataExtraction {
+                        reason: format!("Invalid configuration JSON for task '{}': {}", task_id, e),
+                    },
+                )?),
+                None => None,
+            };
 
             let constructor = Box::new(move || {
-                Arc::new(DynamicLibraryTask::new(
-                    plugin.clone(),
-                    task_name.clone(),
-                    deps.clone(),
-                    trigger_rules.clone(),
-                )) as Arc<dyn Task>
+                Arc::new(
+                    DynamicLibraryTask::new(
+                        plugin.clone(),
+                        task_name.clone(),
+                        deps.clone(),
+                        trigger_rules.clone(),
+                    )
+                    .with_short_circuit(short_circuit.clone())
+                    .with_branch(branch_key.clone(), branch.clone())
+                    .with_loop(loop_until.clone(), max_iterations)
+                    .with_soft_dependencies(soft_dependencies.clone())
+                    .with_memory_limit(memory_limit)
+                    .with_timeout(timeout)
+                    .with_requires_approval(requires_approval)
+                    .with_workload_class(workload_class)
+                    .with_resources(resources.clone())
+                    .with_produces(produces.clone())
+                    .with_scoping(scoped, reads.clone())
+                    .with_configuration(configuration.clone()),
+                ) as Arc<dyn Task>
             });
 
             runtime.register_task(namespace.clone(), constructor);
@@ -206,6 +324,17 @@ impl TaskRegistrar {
             registered.insert(package_id.to_string(), registered_namespaces.clone());
         }
 
+        // Own the package's library until it is unregistered. Re-registering
+        // the same package ID hands the previous library back first.
+        let previous = {
+            let mut packages = self.loaded_packages.write();
+            packages.insert(package_id.to_string(), library_key.clone())
+        };
+        self.handle_cache.acquire(&library_key);
+        if let Some(previous) = previous {
+            self.handle_cache.release(&previous);
+        }
+
         tracing::info!(
             "Successfully registered {} tasks for package {} using host-managed approach",
             registered_namespaces.len(),
@@ -243,10 +372,20 @@ impl TaskRegistrar {
             );
         }
 
-        // Remove package tracking entry
-        {
+        // Hand the package's library back. It is dropped once no other
+        // registration owns it and its in-flight executions have finished.
+        let library_key = {
             let mut packages = self.loaded_packages.write();
-            packages.remove(package_id);
+            packages.remove(package_id)
+        };
+        if let Some(library_key) = library_key {
+            let outcome = self.handle_cache.release(&library_key);
+            tracing::debug!(
+                "Released library {} for package '{}': {:?}",
+                library_key,
+                package_id,
+                outcome
+            );
         }
 
         Ok(())
@@ -270,6 +409,11 @@ impl TaskRegistrar {
         registered.values().map(|tasks| tasks.len()).sum()
     }
 
+    /// Get the shared library handle cache (load/unload counters live here).
+    pub fn handle_cache(&self) -> &PluginHandleCache {
+        &self.handle_cache
+    }
+
     /// Get the temporary directory path for manual operations.
     pub fn temp_dir(&self) -> &Path {
         self.temp_dir.path()
@@ -316,6 +460,8 @@ mod tests {
             declared_params: Vec::new(),
             declared_surfaces: Vec::new(),
             task_docs: Default::default(),
+            provenance: None,
+            config: None,
         }
     }
 
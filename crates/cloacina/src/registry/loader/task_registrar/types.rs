@@ -31,6 +31,36 @@ pub struct OwnedTaskMetadata {
     /// JSON string of the task's trigger rules (conditional execution).
     /// `{"type":"Always"}` when unconditional. (CLOACI-T-0721)
     pub trigger_rules_json: String,
+    /// Output key whose emptiness skips the task's descendants.
+    pub short_circuit: Option<String>,
+    /// Output key holding the branch name, if the task is a branch decider.
+    pub branch_key: Option<String>,
+    /// Branch the task heads, if any.
+    pub branch: Option<String>,
+    /// JSON string of the task's `loop_until` condition, if it loops.
+    pub loop_until_json: Option<String>,
+    /// Most iterations of the task's loop, if it loops.
+    pub max_iterations: Option<u32>,
+    /// Local IDs of dependencies the task treats as soft.
+    pub soft_dependencies: Vec<String>,
+    /// RSS growth limit in bytes, if the task declares one.
+    pub memory_limit: Option<u64>,
+    /// Execution timeout in milliseconds, if the task declares one.
+    pub timeout_ms: Option<u64>,
+    /// Whether the task waits for a human approval before it runs.
+    pub requires_approval: bool,
+    /// Workload class name (`cpu`, `io`, `memory`), if the task declares one.
+    pub workload_class: Option<String>,
+    /// Named resources the task uses.
+    pub resources: Vec<String>,
+    /// Declared outputs in `"key: kind"` form.
+    pub produces: Vec<String>,
+    /// Whether the task's writes move under its ID.
+    pub scoped: bool,
+    /// Context keys the task reads, if it declares them.
+    pub reads: Option<Vec<String>>,
+    /// JSON string of the task's declarative configuration, if any.
+    pub configuration_json: Option<String>,
 }
 
 /// Owned collection of task metadata — safe to use after library is unloaded.
@@ -34,6 +34,7 @@
 //! - [`traits`]: Core trait definitions for registry and storage
 //! - [`types`]: Data types for workflows, metadata, and errors
 //! - [`error`]: Error types for registry operations
+//! - [`events`]: Package lifecycle events and listeners
 //! - [`storage`]: Storage backend implementations (PostgreSQL, filesystem)
 //!
 //! ## Usage Example
@@ -64,6 +65,8 @@
 //! ```
 
 pub mod error;
+pub mod events;
+pub mod gc;
 pub mod loader;
 pub mod reconciler;
 pub mod storage;
@@ -73,14 +76,19 @@ pub mod workflow_registry;
 
 // Re-export commonly used types
 pub use error::{LoaderError, RegistryError, StorageError};
-pub use loader::{PackageLoader, TaskRegistrar};
+pub use events::{RegistryEvent, RegistryEventChannel, RegistryEventKind, RegistryEventListener};
+pub use gc::{
+    RegistryGcConfig, RegistryGcEntry, RegistryGcPolicy, RegistryGcReport, RegistryGcSweeper,
+};
+pub use loader::{CapabilityError, CapabilityPolicy, PackageLoader, TaskRegistrar};
 pub use reconciler::{
-    CronWorkflowRegistrar, PackageStatusDetail, ReconcileResult, ReconcilerConfig,
-    ReconcilerStatus, RegistryReconciler,
+    CronWorkflowRegistrar, PackageHealth, PackageStatusDetail, ReconcileResult,
+    ReconcileRunSummary, ReconcilerConfig, ReconcilerHandle, ReconcilerStatus, RegistryReconciler,
 };
 pub use traits::{RegistryStorage, WorkflowRegistry};
 pub use types::{
-    LoadedWorkflow, WorkflowMetadata, WorkflowPackage, WorkflowPackageId, WorkflowSourceFile,
+    LoadedWorkflow, PackageVersion, WorkflowMetadata, WorkflowPackage, WorkflowPackageId,
+    WorkflowSourceFile,
 };
 pub use workflow_registry::filesystem::FilesystemWorkflowRegistry;
 pub use workflow_registry::WorkflowRegistryImpl;
@@ -18,11 +18,12 @@
 
 use tracing::{debug, error, info, warn};
 
-use super::{PackageState, RegistryReconciler};
+use super::{PackageHealth, PackageState, RegistryReconciler};
 use crate::registry::error::RegistryError;
 use crate::registry::types::{WorkflowMetadata, WorkflowPackageId};
 use crate::task::TaskNamespace;
 use crate::Runtime;
+use cloacina_workflow_plugin::CapabilityManifest;
 use std::sync::Arc;
 
 /// Best-effort humantime parser for trigger metadata's poll_interval
@@ -98,6 +99,69 @@ fn load_plugin_handle_from_bytes(library_data: &[u8]) -> Result<fidius_host::Plu
 }
 
 impl RegistryReconciler {
+    /// Reject `metadata`'s package unless it is signed by a key trusted for
+    /// this reconciler's tenant. Verification runs against `trust_store`,
+    /// the database holding signatures and trusted keys.
+    async fn enforce_signature_policy(
+        &self,
+        metadata: &WorkflowMetadata,
+        archive_path: &std::path::Path,
+        trust_store: &crate::Database,
+    ) -> Result<(), RegistryError> {
+        let tenant = &self.config.default_tenant_id;
+        let policy = crate::security::SecurityConfig {
+            require_signatures: true,
+            verification_org_id: self.config.verification_org_id,
+            tenant_trust_orgs: self.config.tenant_trust_orgs.clone(),
+            ..crate::security::SecurityConfig::default()
+        };
+        let origin = format!(
+            "reconciler:tenant={}:{} v{}",
+            tenant, metadata.package_name, metadata.version
+        );
+        match crate::security::enforce_signature_policy(
+            &policy,
+            tenant,
+            archive_path,
+            trust_store,
+            &origin,
+        )
+        .await
+        {
+            Ok(result) => {
+                if let Some(result) = result {
+                    debug!(
+                        package_name = %metadata.package_name,
+                        package_version = %metadata.version,
+                        signer = %result.signer_fingerprint,
+                        "package signature verified"
+                    );
+                }
+                Ok(())
+            }
+            Err(e) => {
+                let msg = format!(
+                    "package {} v{} rejected by signature policy for tenant '{}' ({}): {}; \
+                     refusing to load (require_signatures=true)",
+                    metadata.package_name,
+                    metadata.version,
+                    tenant,
+                    e.code(),
+                    e
+                );
+                warn!(
+                    package_name = %metadata.package_name,
+                    package_version = %metadata.version,
+                    tenant = %tenant,
+                    reason = e.code(),
+                    "{}",
+                    msg
+                );
+                Err(RegistryError::RegistrationFailed { message: msg })
+            }
+        }
+    }
+
     /// Load a package into the global registries.
     ///
     /// Since the compiler service (CLOACI-I-0097) owns all `cargo build`
@@ -117,27 +181,60 @@ impl RegistryReconciler {
             metadata.package_name, metadata.version
         );
 
-        // Get the package archive data from the registry
+        // --- Step 1: copy the archive from the registry into a temp file ---
+        // Streamed from storage, so a large package is never held in memory.
+        let work_dir = tempfile::TempDir::new().map_err(|e| RegistryError::RegistrationFailed {
+            message: format!("Failed to create temp dir: {}", e),
+        })?;
+
+        let archive_path = work_dir.path().join(format!(
+            "{}-{}.cloacina",
+            metadata.package_name, metadata.version
+        ));
+
         let loaded_workflow = self
             .registry
-            .get_workflow(&metadata.package_name, &metadata.version)
+            .get_workflow_to_file(&metadata.package_name, &metadata.version, &archive_path)
             .await?
             .ok_or_else(|| RegistryError::PackageNotFound {
                 package_name: metadata.package_name.clone(),
                 version: metadata.version.clone(),
             })?;
 
-        // CLOACI-T-0571: defense-in-depth signature existence check.
-        // When `require_signatures` is on, refuse to load any package
-        // whose source bytes have no matching `package_signatures` row.
-        // The upload route is the strong verification gate (CLOACI-I-0103);
-        // this catches direct DB inserts that bypass it.
+        // Registry signature policy: with a trust store configured, the
+        // package must be signed by a key on its tenant's trust list — the
+        // same check the upload route applies — so a package inserted
+        // directly, or whose signer has since been revoked, never loads.
         if self.config.require_signatures {
+            if let Some(trust_store) = &self.config.signature_trust_store {
+                self.enforce_signature_policy(&metadata, &archive_path, trust_store)
+                    .await?;
+            }
+        }
+
+        // CLOACI-T-0571: defense-in-depth signature existence check.
+        // When `require_signatures` is on without a trust store, refuse to
+        // load any package whose source bytes have no matching
+        // `package_signatures` row. The upload route is the strong
+        // verification gate (CLOACI-I-0103); this catches direct DB inserts
+        // that bypass it.
+        if self.config.require_signatures && self.config.signature_trust_store.is_none() {
             let package_hash = {
-                use sha2::{Digest, Sha256};
-                let mut hasher = Sha256::new();
-                hasher.update(&loaded_workflow.package_data);
-                format!("{:x}", hasher.finalize())
+                let archive_path = archive_path.clone();
+                tokio::task::spawn_blocking(move || {
+                    use sha2::{Digest, Sha256};
+                    let mut hasher = Sha256::new();
+                    let mut file = std::fs::File::open(&archive_path)?;
+                    std::io::copy(&mut file, &mut hasher)?;
+                    Ok::<_, std::io::Error>(format!("{:x}", hasher.finalize()))
+                })
+                .await
+                .map_err(|e| RegistryError::RegistrationFailed {
+                    message: format!("spawn_blocking failed during archive hash: {}", e),
+                })?
+                .map_err(|e| RegistryError::RegistrationFailed {
+                    message: format!("Failed to hash package archive: {}", e),
+                })?
             };
             let has_sig = self
                 .registry
@@ -174,22 +271,6 @@ impl RegistryReconciler {
             }
         }
 
-        // --- Step 1: write archive to a temp file ---
-        let work_dir = tempfile::TempDir::new().map_err(|e| RegistryError::RegistrationFailed {
-            message: format!("Failed to create temp dir: {}", e),
-        })?;
-
-        let archive_path = work_dir.path().join(format!(
-            "{}-{}.cloacina",
-            metadata.package_name, metadata.version
-        ));
-
-        tokio::fs::write(&archive_path, &loaded_workflow.package_data)
-            .await
-            .map_err(|e| RegistryError::RegistrationFailed {
-                message: format!("Failed to write archive to temp file: {}", e),
-            })?;
-
         // --- Step 2: unpack archive ---
         let extract_dir = work_dir.path().join("source");
         tokio::fs::create_dir_all(&extract_dir).await.map_err(|e| {
@@ -257,6 +338,20 @@ impl RegistryReconciler {
             cloacina_manifest.metadata.language
         );
 
+        // Capability manifest vs. deployment policy: a package asking for
+        // more than this deployment grants is rejected before any code from
+        // it is loaded. The granted set gates its tasks at execution time.
+        let granted_capabilities = self
+            .config
+            .capability_policy
+            .grant(cloacina_manifest.metadata.capabilities.as_ref())
+            .map_err(|e| RegistryError::RegistrationFailed {
+                message: format!(
+                    "package {} v{} rejected by capability policy: {}",
+                    metadata.package_name, metadata.version, e
+                ),
+            })?;
+
         // T-E / I-0102: deprecation warnings removed; `[[triggers]]` and
         // `package_type` are now hard-errored at deserialization via
         // `#[serde(deny_unknown_fields)]` on `CloacinaMetadata`. The
@@ -271,28 +366,44 @@ impl RegistryReconciler {
         // over the primary build — the primary is whatever arch the compiler
         // host runs, which on a mixed fleet is not necessarily ours. Falls back
         // to the primary when no per-target artifact exists (single-arch
-        // deployments, where the primary IS the right arch). Hold onto the
-        // bytes so the computation-graph step below can reuse them without
+        // deployments, where the primary IS the right arch). Next in line is a
+        // prebuilt binary the archive bundles for this host under
+        // `targets/<triple>/` (multi-arch bundles), then the primary. Hold onto
+        // the bytes so the computation-graph step below can reuse them without
         // another DB round-trip.
         let host_triple = crate::fleet::protocol::host_target_triple();
         let per_target_cdylib = self
             .registry
             .get_compiled_data_for_target(&metadata.package_name, &metadata.version, &host_triple)
             .await?;
+        let bundled_cdylib = if per_target_cdylib.is_none() {
+            crate::packaging::read_bundled_binary(&source_dir, &host_triple).map_err(|e| {
+                RegistryError::RegistrationFailed {
+                    message: format!(
+                        "package {} v{}: {}",
+                        metadata.package_name, metadata.version, e
+                    ),
+                }
+            })?
+        } else {
+            None
+        };
         // Provenance string woven into load-failure messages so a wrong-arch
         // dlopen failure names WHICH artifact was tried (clear error, not a
         // silent wrong-arch load).
         let artifact_provenance = if per_target_cdylib.is_some() {
             format!("per-target artifact for '{host_triple}'")
+        } else if bundled_cdylib.is_some() {
+            format!("bundled binary for '{host_triple}'")
         } else {
             format!("primary build (no per-target artifact for '{host_triple}')")
         };
-        let rust_cdylib_bytes = match per_target_cdylib {
+        let rust_cdylib_bytes = match per_target_cdylib.or(bundled_cdylib) {
             Some(bytes) => {
                 info!(
-                    "Selected per-target cdylib ({} bytes, {}) for {} v{}",
+                    "Selected {} ({} bytes) for {} v{}",
+                    artifact_provenance,
                     bytes.len(),
-                    host_triple,
                     metadata.package_name,
                     metadata.version
                 );
@@ -364,6 +475,32 @@ impl RegistryReconciler {
             })?;
             rust_reactor_names = view.reactors.iter().map(|r| r.name.clone()).collect();
 
+            // Health gate: run the package's `#[workflow(health_check = ...)]`
+            // entry points before anything is registered. A failing package is
+            // tracked as Degraded with nothing wired up, so none of its
+            // workflows, triggers or schedules can fire.
+            if let Some(reason) = self.step_health_checks(&metadata, &library_data).await {
+                warn!(
+                    "Package {} v{} failed its health check and is degraded: {}",
+                    metadata.package_name, metadata.version, reason
+                );
+                self.loaded_packages.write().await.insert(
+                    metadata.id,
+                    PackageState {
+                        metadata: metadata.clone(),
+                        task_namespaces: Vec::new(),
+                        workflow_name: None,
+                        trigger_names: Vec::new(),
+                        graph_name: None,
+                        reactor_names: Vec::new(),
+                        cron_schedule_ids: Vec::new(),
+                        triggerless_graph_names: Vec::new(),
+                        health: PackageHealth::Degraded { reason },
+                    },
+                );
+                return Ok(());
+            }
+
             // Step 0 (CLOACI-T-0907): stage the package's BUNDLED constructor
             // providers before anything that may consume them spawns. This used
             // to happen only inside step_load_constructor_nodes (i.e. only for
@@ -416,8 +553,9 @@ impl RegistryReconciler {
                 .await?;
             // Step 6: workflows (tasks + workflow + trigger-subscription
             // validation).
-            let (task_namespaces, workflow_name) =
-                self.step_load_workflows(&metadata, &library_data).await?;
+            let (task_namespaces, workflow_name) = self
+                .step_load_workflows(&metadata, &library_data, granted_capabilities)
+                .await?;
 
             (
                 task_namespaces,
@@ -467,13 +605,13 @@ impl RegistryReconciler {
             self.stage_bundled_providers(&metadata).await?;
 
             let loaded = {
-                let archive_data = loaded_workflow.package_data.clone();
+                let archive_path = archive_path.clone();
                 let runtime = runtime.clone();
                 let cloacina_runtime = cloacina_runtime.clone();
                 tokio::task::spawn_blocking(move || {
                     runtime
                         .load_workflow_package(
-                            &archive_data,
+                            &archive_path,
                             &staging,
                             &tenant_id,
                             &cloacina_runtime,
@@ -616,7 +754,7 @@ impl RegistryReconciler {
                             ),
                             })?;
                     let maybe_decl = {
-                        let archive_data = loaded_workflow.package_data.clone();
+                        let archive_path = archive_path.clone();
                         let gn_inner = gn.clone();
                         let em_inner = em.clone();
                         let tenant_inner = tenant.clone();
@@ -625,7 +763,7 @@ impl RegistryReconciler {
                         tokio::task::spawn_blocking(move || {
                             runtime
                                 .load_cg_package(
-                                    &archive_data,
+                                    &archive_path,
                                     &staging,
                                     &tenant_inner,
                                     &gn_inner,
@@ -797,6 +935,7 @@ impl RegistryReconciler {
             reactor_names,
             cron_schedule_ids,
             triggerless_graph_names,
+            health: PackageHealth::Healthy,
         };
 
         let mut loaded_packages = self.loaded_packages.write().await;
@@ -993,11 +1132,13 @@ impl RegistryReconciler {
         Ok(())
     }
 
-    /// Register tasks from a package into the global task registry
+    /// Register tasks from a package into the global task registry, gated on
+    /// the capabilities the package was granted (`None` = ungated).
     pub(super) async fn register_package_tasks(
         &self,
         metadata: &WorkflowMetadata,
         package_data: &[u8],
+        capabilities: Option<CapabilityManifest>,
     ) -> Result<Vec<TaskNamespace>, RegistryError> {
         debug!(
             "Loading tasks for package: {} v{}",
@@ -1005,11 +1146,14 @@ impl RegistryReconciler {
         );
 
         // Extract metadata from the .so file using PackageLoader
-        let package_metadata = self
+        let mut package_metadata = self
             .package_loader
             .extract_metadata(package_data)
             .await
             .map_err(RegistryError::Loader)?;
+        // The library knows nothing of the values the package was registered
+        // with; they come from the registry row.
+        package_metadata.config = metadata.config.clone();
 
         debug!(
             "Package {} contains {} tasks",
@@ -1034,12 +1178,13 @@ impl RegistryReconciler {
 
         let task_namespaces = self
             .task_registrar
-            .register_package_tasks(
+            .register_package_tasks_with_capabilities(
                 &package_id,
                 package_data,
                 &package_metadata,
                 tenant_id,
                 runtime,
+                capabilities,
             )
             .await
             .map_err(RegistryError::Loader)?;
@@ -1230,7 +1375,7 @@ impl RegistryReconciler {
     }
 
     /// Static version of create_workflow_from_host_registry for use in closures.
-    pub(super) fn create_workflow_from_host_registry_static(
+    pub(crate) fn create_workflow_from_host_registry_static(
         runtime: &Arc<Runtime>,
         package_name: &str,
         workflow_name: &str,
@@ -1567,6 +1712,47 @@ impl RegistryReconciler {
         })
     }
 
+    /// Pipeline step 0a: load-time health checks.
+    ///
+    /// Returns `Some(reason)` when any declared check failed, or when the
+    /// checks could not be run at all; `None` when the package is healthy
+    /// (including packages that declare no checks). Each check is bounded by
+    /// `package_operation_timeout`.
+    pub(super) async fn step_health_checks(
+        &self,
+        metadata: &WorkflowMetadata,
+        library_data: &[u8],
+    ) -> Option<String> {
+        let reports = match self
+            .package_loader
+            .run_health_checks(library_data, self.config.package_operation_timeout)
+            .await
+        {
+            Ok(reports) => reports,
+            Err(e) => return Some(format!("health checks could not run: {}", e)),
+        };
+        let failures: Vec<String> = reports
+            .into_iter()
+            .filter(|r| !r.healthy)
+            .map(|r| {
+                format!(
+                    "workflow '{}': {}",
+                    r.workflow,
+                    r.message.as_deref().unwrap_or("health check failed")
+                )
+            })
+            .collect();
+        if failures.is_empty() {
+            debug!(
+                "Package {} v{} passed its health checks",
+                metadata.package_name, metadata.version
+            );
+            None
+        } else {
+            Some(failures.join("; "))
+        }
+    }
+
     /// Pipeline step 1: cron triggers (entries with `cron_expression.is_some()`).
     ///
     /// When a `CronWorkflowRegistrar` is attached, each cron entry is
@@ -2340,8 +2526,11 @@ impl RegistryReconciler {
         &self,
         metadata: &WorkflowMetadata,
         library_data: &[u8],
+        capabilities: Option<CapabilityManifest>,
     ) -> Result<(Vec<TaskNamespace>, Option<String>), RegistryError> {
-        let task_namespaces = self.register_package_tasks(metadata, library_data).await?;
+        let task_namespaces = self
+            .register_package_tasks(metadata, library_data, capabilities)
+            .await?;
         let workflow_name = self
             .register_package_workflows(metadata, library_data)
             .await?;
@@ -2417,6 +2606,8 @@ mod tests {
             declared_params: vec![],
             declared_surfaces: vec![],
             workflow_triggers: vec![],
+            provenance: None,
+            config: None,
         }
     }
 
@@ -2433,6 +2624,8 @@ mod tests {
             input_strategy: None,
             accumulators: Vec::new(),
             providers: Default::default(),
+            capabilities: None,
+            config_schema: None,
         }
     }
 
@@ -3067,6 +3260,7 @@ mod tests {
             reactor_names: vec!["owned_rx".to_string()],
             cron_schedule_ids: vec![],
             triggerless_graph_names: vec![],
+            health: PackageHealth::Healthy,
         };
         reconciler
             .loaded_packages
@@ -3140,6 +3334,7 @@ mod tests {
                 reactor_names: vec!["lone_rx".to_string()],
                 cron_schedule_ids: vec![],
                 triggerless_graph_names: vec![],
+                health: PackageHealth::Healthy,
             },
         );
 
@@ -3211,6 +3406,7 @@ mod tests {
                 reactor_names: vec!["ephemeral_rx".to_string()],
                 cron_schedule_ids: vec![],
                 triggerless_graph_names: vec![],
+                health: PackageHealth::Healthy,
             },
         );
 
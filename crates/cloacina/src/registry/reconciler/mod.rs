@@ -30,18 +30,22 @@
 //! - `ReconcilerConfig`: Configuration for reconciliation behavior
 //! - `ReconcileResult`: Result of a reconciliation operation
 //! - `PackageState`: Tracking loaded package state
+//! - `ReconcilerHandle`: Cloneable handle for reading status and requesting
+//!   an immediate reconciliation while the loop is running
 
 mod loading;
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::watch;
+use tokio::sync::{mpsc, oneshot, watch};
 use tokio::time::{interval, Interval};
 use tracing::{debug, error, info, warn};
 
 use crate::computation_graph::scheduler::ComputationGraphScheduler;
 use crate::registry::error::RegistryError;
+use crate::registry::loader::capabilities::CapabilityPolicy;
+use crate::registry::loader::library_cache::{LibraryCacheStats, PluginHandleCache, UnloadPolicy};
 use crate::registry::loader::package_loader::PackageLoader;
 use crate::registry::loader::task_registrar::TaskRegistrar;
 use crate::registry::traits::WorkflowRegistry;
@@ -77,6 +81,23 @@ pub struct ReconcilerConfig {
     /// `require_signatures` is false. Mirrors
     /// `cloacina-server`'s `--verification-org-id` CLI flag.
     pub verification_org_id: Option<crate::UniversalUuid>,
+
+    /// Per-tenant signature trust lists (tenant id → trusted org). Packages
+    /// are checked against the `default_tenant_id` entry, falling back to
+    /// `verification_org_id`.
+    pub tenant_trust_orgs: HashMap<String, crate::UniversalUuid>,
+
+    /// Database holding `package_signatures` and the trusted-key tables.
+    /// When set alongside `require_signatures`, every package is fully
+    /// verified against its tenant's trust list before it loads; when `None`
+    /// the reconciler only checks that a signature row exists.
+    pub signature_trust_store: Option<crate::Database>,
+
+    /// What packages may request in `[metadata.capabilities]`. A package
+    /// asking for anything outside this policy is rejected at registration;
+    /// the granted set is what its tasks can reach at execution time.
+    /// Unrestricted by default.
+    pub capability_policy: CapabilityPolicy,
 }
 
 impl Default for ReconcilerConfig {
@@ -89,6 +110,9 @@ impl Default for ReconcilerConfig {
             default_tenant_id: "public".to_string(),
             require_signatures: false,
             verification_org_id: None,
+            tenant_trust_orgs: HashMap::new(),
+            signature_trust_store: None,
+            capability_policy: CapabilityPolicy::default(),
         }
     }
 }
@@ -124,6 +148,30 @@ impl ReconcileResult {
     }
 }
 
+/// Load-time health of a tracked package
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PackageHealth {
+    /// Every declared `#[workflow(health_check = ...)]` passed (or none are
+    /// declared); the package's primitives are registered.
+    #[default]
+    Healthy,
+
+    /// A health check failed. Nothing from the package is registered, so its
+    /// workflows, triggers and schedules stay out of scheduling; the check is
+    /// re-run on every reconciliation pass until it passes.
+    Degraded {
+        /// Why the package is degraded, one line per failing check.
+        reason: String,
+    },
+}
+
+impl PackageHealth {
+    /// Whether the package failed its load-time health check.
+    pub fn is_degraded(&self) -> bool {
+        matches!(self, PackageHealth::Degraded { .. })
+    }
+}
+
 /// Tracks the state of loaded packages
 #[derive(Debug, Clone)]
 pub(super) struct PackageState {
@@ -165,6 +213,10 @@ pub(super) struct PackageState {
     /// empty for in-process / Python loads. `unload_package` drops
     /// each name from the runtime.
     pub(super) triggerless_graph_names: Vec<String>,
+
+    /// Load-time health. A `Degraded` entry registers nothing; it is tracked
+    /// so the status API can say why, and retried on the next pass.
+    pub(super) health: PackageHealth,
 }
 
 /// Trait the reconciler uses to register and unregister cron workflow
@@ -225,6 +277,96 @@ pub struct ReconcilerStatus {
 
     /// Details about each loaded package
     pub package_details: Vec<PackageStatusDetail>,
+
+    /// Summary of the most recent reconciliation pass, `None` until the
+    /// first pass finishes.
+    pub last_run: Option<ReconcileRunSummary>,
+
+    /// Number of reconciliation passes finished since the reconciler was
+    /// created (successful or not).
+    pub runs_completed: u64,
+
+    /// Counters for the dlopened package libraries (open, draining,
+    /// released).
+    pub libraries: LibraryCacheStats,
+}
+
+impl ReconcilerStatus {
+    /// Whether the most recent pass finished without an error or any
+    /// per-package failures. `false` before the first pass.
+    pub fn is_healthy(&self) -> bool {
+        self.last_run
+            .as_ref()
+            .is_some_and(|run| run.error.is_none() && run.packages_failed.is_empty())
+    }
+}
+
+/// Summary of a single finished reconciliation pass
+#[derive(Debug, Clone)]
+pub struct ReconcileRunSummary {
+    /// When the pass finished
+    pub finished_at: chrono::DateTime<chrono::Utc>,
+
+    /// How long the pass took
+    pub duration: Duration,
+
+    /// Number of packages loaded during the pass
+    pub packages_loaded: usize,
+
+    /// Number of packages unloaded during the pass
+    pub packages_unloaded: usize,
+
+    /// Packages that failed to load/unload, with the error message
+    pub packages_failed: Vec<(WorkflowPackageId, String)>,
+
+    /// Error that aborted the whole pass (e.g. the registry could not be
+    /// listed). Per-package failures land in `packages_failed` instead.
+    pub error: Option<String>,
+}
+
+/// Bookkeeping shared between the reconciler and its handles.
+#[derive(Debug, Default)]
+struct RunHistory {
+    last_run: Option<ReconcileRunSummary>,
+    runs_completed: u64,
+}
+
+/// Reply channel for a manual reconciliation request.
+type ReconcileReply = oneshot::Sender<Result<ReconcileResult, RegistryError>>;
+
+/// Cloneable handle onto a [`RegistryReconciler`] that outlives the move of
+/// the reconciler into its background loop. Used by `DefaultRunner` to
+/// report status and to trigger a reconciliation on demand instead of
+/// waiting for the next interval tick.
+#[derive(Clone)]
+pub struct ReconcilerHandle {
+    loaded_packages: Arc<tokio::sync::RwLock<HashMap<WorkflowPackageId, PackageState>>>,
+    library_cache: PluginHandleCache,
+    history: Arc<std::sync::Mutex<RunHistory>>,
+    manual_tx: mpsc::UnboundedSender<ReconcileReply>,
+}
+
+impl ReconcilerHandle {
+    /// Get the current reconciliation status
+    pub async fn status(&self) -> ReconcilerStatus {
+        build_status(&self.loaded_packages, &self.library_cache, &self.history).await
+    }
+
+    /// Ask the running reconciliation loop to reconcile immediately and
+    /// wait for the result. Requests are serialized with the periodic
+    /// passes, so a pass already in flight finishes first.
+    ///
+    /// Fails with [`RegistryError::Internal`] when the loop is not running
+    /// (never started, or already shut down).
+    pub async fn reconcile_now(&self) -> Result<ReconcileResult, RegistryError> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.manual_tx
+            .send(reply_tx)
+            .map_err(|_| RegistryError::Internal("registry reconciler is not running".into()))?;
+        reply_rx.await.map_err(|_| {
+            RegistryError::Internal("registry reconciler stopped before reconciling".into())
+        })?
+    }
 }
 
 /// Detailed status information about a loaded package
@@ -241,6 +383,9 @@ pub struct PackageStatusDetail {
 
     /// Whether a workflow was registered
     pub has_workflow: bool,
+
+    /// Load-time health; `Degraded` packages are excluded from scheduling
+    pub health: PackageHealth,
 }
 
 /// Registry Reconciler for synchronizing database state with in-memory registries
@@ -290,6 +435,14 @@ pub struct RegistryReconciler {
     /// itself is the stale side), we do not ping-pong the row back to
     /// `pending` forever.
     recompile_requested: std::sync::Mutex<std::collections::HashSet<WorkflowPackageId>>,
+
+    /// Outcome of the most recent pass plus a pass counter, shared with
+    /// every [`ReconcilerHandle`].
+    history: Arc<std::sync::Mutex<RunHistory>>,
+
+    /// Manual reconciliation requests from [`ReconcilerHandle::reconcile_now`].
+    manual_tx: mpsc::UnboundedSender<ReconcileReply>,
+    manual_rx: mpsc::UnboundedReceiver<ReconcileReply>,
 }
 
 impl RegistryReconciler {
@@ -306,6 +459,7 @@ impl RegistryReconciler {
 
         let task_registrar =
             TaskRegistrar::with_handle_cache(shared_cache).map_err(RegistryError::Loader)?;
+        let (manual_tx, manual_rx) = mpsc::unbounded_channel();
 
         Ok(Self {
             registry,
@@ -319,9 +473,23 @@ impl RegistryReconciler {
             graph_scheduler: Arc::new(tokio::sync::RwLock::new(None)),
             cron_registrar: None,
             recompile_requested: std::sync::Mutex::new(std::collections::HashSet::new()),
+            history: Arc::new(std::sync::Mutex::new(RunHistory::default())),
+            manual_tx,
+            manual_rx,
         })
     }
 
+    /// Get a handle for reading status and requesting reconciliations after
+    /// the reconciler has been moved into `start_reconciliation_loop`.
+    pub fn handle(&self) -> ReconcilerHandle {
+        ReconcilerHandle {
+            loaded_packages: self.loaded_packages.clone(),
+            library_cache: self.package_loader.handle_cache(),
+            history: self.history.clone(),
+            manual_tx: self.manual_tx.clone(),
+        }
+    }
+
     /// Attach a Runtime to this reconciler. Package load/unload operations
     /// will push registrations through the runtime so executors see the
     /// same view as the reconciler.
@@ -348,6 +516,14 @@ impl RegistryReconciler {
         self.graph_scheduler = slot;
     }
 
+    /// Override when unloaded package libraries are dlclosed. Defaults to
+    /// [`UnloadPolicy::Release`] except on macOS, where dlclose corrupts the
+    /// fidius `inventory` list and libraries are retained instead.
+    pub fn with_unload_policy(self, policy: UnloadPolicy) -> Self {
+        self.package_loader.handle_cache().set_unload_policy(policy);
+        self
+    }
+
     /// Attach a cron registrar that the reconciler will use to install
     /// cron schedules for each `#[trigger(cron = ...)]` declaration in
     /// loaded packages, and to drop them on unload. Builder-style
@@ -427,6 +603,15 @@ impl RegistryReconciler {
                         }
                     }
                 }
+                Some(reply) = self.manual_rx.recv() => {
+                    info!("Running requested reconciliation");
+                    let result = self.reconcile().await;
+                    if let Err(e) = &result {
+                        error!("Requested reconciliation failed: {}", e);
+                    }
+                    // The requester may have given up waiting; nothing to do then.
+                    let _ = reply.send(result);
+                }
                 _ = self.shutdown_rx.changed() => {
                     if *self.shutdown_rx.borrow() {
                         info!("Registry Reconciler shutdown requested");
@@ -446,7 +631,38 @@ impl RegistryReconciler {
     /// Perform a single reconciliation operation
     pub async fn reconcile(&self) -> Result<ReconcileResult, RegistryError> {
         let start_time = std::time::Instant::now();
+        let result = self.reconcile_once(start_time).await;
+
+        let summary = match &result {
+            Ok(r) => ReconcileRunSummary {
+                finished_at: chrono::Utc::now(),
+                duration: r.reconciliation_duration,
+                packages_loaded: r.packages_loaded.len(),
+                packages_unloaded: r.packages_unloaded.len(),
+                packages_failed: r.packages_failed.clone(),
+                error: None,
+            },
+            Err(e) => ReconcileRunSummary {
+                finished_at: chrono::Utc::now(),
+                duration: start_time.elapsed(),
+                packages_loaded: 0,
+                packages_unloaded: 0,
+                packages_failed: Vec::new(),
+                error: Some(e.to_string()),
+            },
+        };
+        let mut history = self.history.lock().unwrap();
+        history.last_run = Some(summary);
+        history.runs_completed += 1;
+        drop(history);
 
+        result
+    }
+
+    async fn reconcile_once(
+        &self,
+        start_time: std::time::Instant,
+    ) -> Result<ReconcileResult, RegistryError> {
         // Get all packages from the database
         let db_packages = self.registry.list_workflows().await?;
         let db_package_ids: HashSet<WorkflowPackageId> = db_packages.iter().map(|p| p.id).collect();
@@ -455,6 +671,14 @@ impl RegistryReconciler {
         let loaded_packages = self.loaded_packages.read().await;
         let loaded_package_ids: HashSet<WorkflowPackageId> =
             loaded_packages.keys().cloned().collect();
+        // Degraded packages registered nothing; treat them as not loaded so
+        // this pass re-runs their health checks (and they still unload
+        // normally when removed from the database).
+        let degraded_package_ids: HashSet<WorkflowPackageId> = loaded_packages
+            .iter()
+            .filter(|(_, state)| state.health.is_degraded())
+            .map(|(id, _)| *id)
+            .collect();
         drop(loaded_packages);
 
         // Determine what needs to be loaded and unloaded.
@@ -469,7 +693,8 @@ impl RegistryReconciler {
         // step pipeline (workflows → CGs → reactors → triggers → tasks)
         // by also reversing across packages.
         let mut packages_to_load: Vec<_> = db_package_ids
-            .difference(&loaded_package_ids)
+            .iter()
+            .filter(|id| !loaded_package_ids.contains(id) || degraded_package_ids.contains(id))
             .cloned()
             .collect();
         packages_to_load.sort_by_key(|id| {
@@ -550,6 +775,29 @@ impl RegistryReconciler {
                 );
                 match self.load_package(package_metadata.clone()).await {
                     Ok(()) => {
+                        let health = self
+                            .loaded_packages
+                            .read()
+                            .await
+                            .get(package_id)
+                            .map(|state| state.health.clone())
+                            .unwrap_or_default();
+                        if let PackageHealth::Degraded { reason } = health {
+                            // Reported as a failure so the pass (and the
+                            // status API) reads unhealthy, but it never aborts
+                            // the pass: the package is tracked, not broken.
+                            result.packages_failed.push((
+                                *package_id,
+                                format!(
+                                    "Package {} ({}:{}) is degraded: {}",
+                                    package_id,
+                                    package_metadata.package_name,
+                                    package_metadata.version,
+                                    reason
+                                ),
+                            ));
+                            continue;
+                        }
                         result.packages_loaded.push(*package_id);
                         info!(
                             "Loaded package: {} v{}",
@@ -608,6 +856,13 @@ impl RegistryReconciler {
             }
         }
 
+        // Drop library handles no loaded package owns — metadata-extraction
+        // opens and the leftovers of loads that failed part-way.
+        let released = self.package_loader.handle_cache().release_unowned();
+        if released > 0 {
+            debug!("Released {} unowned package librar(ies)", released);
+        }
+
         // Update total packages tracked
         let loaded_packages = self.loaded_packages.read().await;
         result.total_packages_tracked = loaded_packages.len();
@@ -643,20 +898,41 @@ impl RegistryReconciler {
 
     /// Get the current reconciliation status
     pub async fn get_status(&self) -> ReconcilerStatus {
-        let loaded_packages = self.loaded_packages.read().await;
+        build_status(
+            &self.loaded_packages,
+            &self.package_loader.handle_cache(),
+            &self.history,
+        )
+        .await
+    }
+}
 
-        ReconcilerStatus {
-            packages_loaded: loaded_packages.len(),
-            package_details: loaded_packages
-                .values()
-                .map(|state| PackageStatusDetail {
-                    package_name: state.metadata.package_name.clone(),
-                    version: state.metadata.version.clone(),
-                    task_count: state.task_namespaces.len(),
-                    has_workflow: state.workflow_name.is_some(),
-                })
-                .collect(),
-        }
+async fn build_status(
+    loaded_packages: &tokio::sync::RwLock<HashMap<WorkflowPackageId, PackageState>>,
+    library_cache: &PluginHandleCache,
+    history: &std::sync::Mutex<RunHistory>,
+) -> ReconcilerStatus {
+    let loaded_packages = loaded_packages.read().await;
+    let (last_run, runs_completed) = {
+        let history = history.lock().unwrap();
+        (history.last_run.clone(), history.runs_completed)
+    };
+
+    ReconcilerStatus {
+        packages_loaded: loaded_packages.len(),
+        package_details: loaded_packages
+            .values()
+            .map(|state| PackageStatusDetail {
+                package_name: state.metadata.package_name.clone(),
+                version: state.metadata.version.clone(),
+                task_count: state.task_namespaces.len(),
+                has_workflow: state.workflow_name.is_some(),
+                health: state.health.clone(),
+            })
+            .collect(),
+        last_run,
+        runs_completed,
+        libraries: library_cache.stats(),
     }
 }
 
@@ -737,14 +1013,19 @@ mod tests {
                     version: "1.0.0".to_string(),
                     task_count: 3,
                     has_workflow: true,
+                    health: PackageHealth::Healthy,
                 },
                 PackageStatusDetail {
                     package_name: "pkg2".to_string(),
                     version: "2.0.0".to_string(),
                     task_count: 1,
                     has_workflow: false,
+                    health: PackageHealth::Healthy,
                 },
             ],
+            last_run: None,
+            runs_completed: 0,
+            libraries: LibraryCacheStats::default(),
         };
 
         assert_eq!(status.packages_loaded, 2);
@@ -764,6 +1045,9 @@ mod tests {
             default_tenant_id: "tenant-42".to_string(),
             require_signatures: false,
             verification_org_id: None,
+            tenant_trust_orgs: HashMap::new(),
+            signature_trust_store: None,
+            capability_policy: CapabilityPolicy::deny_all(),
         };
 
         assert_eq!(config.reconcile_interval, Duration::from_secs(60));
@@ -826,6 +1110,7 @@ mod tests {
             version: "2.3.1".to_string(),
             task_count: 7,
             has_workflow: true,
+            health: PackageHealth::Healthy,
         };
 
         assert_eq!(detail.package_name, "my-workflow");
@@ -839,10 +1124,57 @@ mod tests {
         let status = ReconcilerStatus {
             packages_loaded: 0,
             package_details: vec![],
+            last_run: None,
+            runs_completed: 0,
+            libraries: LibraryCacheStats::default(),
         };
 
         assert_eq!(status.packages_loaded, 0);
         assert!(status.package_details.is_empty());
+        assert!(!status.is_healthy());
+    }
+
+    #[test]
+    fn test_reconciler_status_health_follows_last_run() {
+        let run = ReconcileRunSummary {
+            finished_at: chrono::Utc::now(),
+            duration: Duration::from_millis(10),
+            packages_loaded: 1,
+            packages_unloaded: 0,
+            packages_failed: vec![],
+            error: None,
+        };
+        let mut status = ReconcilerStatus {
+            packages_loaded: 1,
+            package_details: vec![],
+            last_run: Some(run.clone()),
+            runs_completed: 1,
+            libraries: LibraryCacheStats::default(),
+        };
+        assert!(status.is_healthy());
+
+        status.last_run = Some(ReconcileRunSummary {
+            packages_failed: vec![(Uuid::new_v4(), "boom".to_string())],
+            ..run.clone()
+        });
+        assert!(!status.is_healthy());
+
+        status.last_run = Some(ReconcileRunSummary {
+            error: Some("registry unavailable".to_string()),
+            ..run
+        });
+        assert!(!status.is_healthy());
+    }
+
+    #[test]
+    fn test_package_health_default_and_degraded() {
+        assert_eq!(PackageHealth::default(), PackageHealth::Healthy);
+        assert!(!PackageHealth::Healthy.is_degraded());
+
+        let degraded = PackageHealth::Degraded {
+            reason: "workflow 'etl': upstream unreachable".to_string(),
+        };
+        assert!(degraded.is_degraded());
     }
 
     #[test]
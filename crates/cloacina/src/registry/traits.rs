@@ -20,6 +20,8 @@
 //! backends and consistent registry operations across different implementations.
 
 use async_trait::async_trait;
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use crate::models::workflow_packages::StorageType;
 use crate::registry::error::{RegistryError, StorageError};
@@ -91,6 +93,49 @@ pub trait WorkflowRegistry: Send + Sync {
         package_data: Vec<u8>,
     ) -> Result<WorkflowPackageId, RegistryError>;
 
+    /// Register a workflow package from a `.cloacina` archive on disk.
+    ///
+    /// Same contract as [`register_workflow`](Self::register_workflow), for
+    /// callers that already hold the archive in a file (an upload spooled to
+    /// disk, a packed build). Registries override this to stream the file into
+    /// storage; the default reads it into memory and calls `register_workflow`.
+    async fn register_workflow_file(
+        &mut self,
+        archive_path: &Path,
+    ) -> Result<WorkflowPackageId, RegistryError> {
+        let package_data = tokio::fs::read(archive_path).await.map_err(|e| {
+            RegistryError::Internal(format!(
+                "Failed to read package {}: {}",
+                archive_path.display(),
+                e
+            ))
+        })?;
+        self.register_workflow(package_data).await
+    }
+
+    /// Register a workflow package from a `.cloacina` archive on disk along
+    /// with its configuration values.
+    ///
+    /// A package whose manifest names a `config_schema` is only registered
+    /// when `config` validates against it (a missing `config` is validated as
+    /// an empty object); the validated values are stored with the package and
+    /// reach its tasks as `context.package_config()`. Registering the same
+    /// archive again with different values supersedes the active version.
+    /// The default accepts no configuration and otherwise defers to
+    /// [`register_workflow_file`](Self::register_workflow_file).
+    async fn register_workflow_file_with_config(
+        &mut self,
+        archive_path: &Path,
+        config: Option<serde_json::Value>,
+    ) -> Result<WorkflowPackageId, RegistryError> {
+        if config.is_some() {
+            return Err(RegistryError::ValidationError {
+                reason: "this registry does not accept package configuration".to_string(),
+            });
+        }
+        self.register_workflow_file(archive_path).await
+    }
+
     /// Retrieve a specific workflow package by name and version.
     ///
     /// This operation:
@@ -114,6 +159,35 @@ pub trait WorkflowRegistry: Send + Sync {
         version: &str,
     ) -> Result<Option<LoadedWorkflow>, RegistryError>;
 
+    /// Retrieve a workflow package like [`get_workflow`](Self::get_workflow),
+    /// but write its source archive to `archive_path` instead of returning it.
+    ///
+    /// The returned [`LoadedWorkflow`] has an empty `package_data`. Registries
+    /// override this to copy the archive from storage in pieces, so loading a
+    /// large package never holds it in memory whole; the default calls
+    /// `get_workflow` and writes the bytes out.
+    async fn get_workflow_to_file(
+        &self,
+        package_name: &str,
+        version: &str,
+        archive_path: &Path,
+    ) -> Result<Option<LoadedWorkflow>, RegistryError> {
+        let Some(mut loaded) = self.get_workflow(package_name, version).await? else {
+            return Ok(None);
+        };
+        tokio::fs::write(archive_path, &loaded.package_data)
+            .await
+            .map_err(|e| {
+                RegistryError::Internal(format!(
+                    "Failed to write package to {}: {}",
+                    archive_path.display(),
+                    e
+                ))
+            })?;
+        loaded.package_data = Vec::new();
+        Ok(Some(loaded))
+    }
+
     /// List all registered workflows in the registry.
     ///
     /// Returns metadata for all workflows without loading binary data.
@@ -320,6 +394,53 @@ pub trait RegistryStorage: Send + Sync {
     /// * `Err(StorageError)` - If retrieval fails
     async fn retrieve_binary(&self, id: &str) -> Result<Option<Vec<u8>>, StorageError>;
 
+    /// Store binary data read from `reader` until end of stream.
+    ///
+    /// Backends override this to write the data as it arrives, so storing a
+    /// large package never holds it in memory whole. The default buffers the
+    /// stream and calls [`store_binary`](Self::store_binary).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - Unique identifier for retrieving the data
+    /// * `Err(StorageError)` - If reading or storage fails
+    async fn store_stream(
+        &mut self,
+        reader: &mut (dyn AsyncRead + Send + Unpin),
+    ) -> Result<String, StorageError> {
+        let mut data = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .await
+            .map_err(|e| StorageError::Backend(format!("Failed to read package data: {}", e)))?;
+        self.store_binary(data).await
+    }
+
+    /// Write the binary data stored under `id` to `writer`.
+    ///
+    /// Backends override this to copy the data through in pieces. The default
+    /// calls [`retrieve_binary`](Self::retrieve_binary) and writes the result.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - The data was written
+    /// * `Ok(false)` - If no data exists for the given ID (nothing is written)
+    /// * `Err(StorageError)` - If retrieval or writing fails
+    async fn retrieve_to(
+        &self,
+        id: &str,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<bool, StorageError> {
+        let Some(data) = self.retrieve_binary(id).await? else {
+            return Ok(false);
+        };
+        writer
+            .write_all(&data)
+            .await
+            .map_err(|e| StorageError::Backend(format!("Failed to write package data: {}", e)))?;
+        Ok(true)
+    }
+
     /// Delete binary workflow data from storage.
     ///
     /// # Arguments
@@ -348,3 +469,40 @@ pub trait RegistryStorage: Send + Sync {
     /// * `StorageType::Filesystem` - For filesystem-backed storage
     fn storage_type(&self) -> StorageType;
 }
+
+/// Boxed storage forwards to the backend it wraps, so a backend chosen at
+/// runtime (e.g. one contributed by an [`Extension`](crate::extensions::Extension))
+/// can back a [`WorkflowRegistryImpl`](crate::registry::WorkflowRegistryImpl).
+#[async_trait]
+impl RegistryStorage for Box<dyn RegistryStorage> {
+    async fn store_binary(&mut self, data: Vec<u8>) -> Result<String, StorageError> {
+        (**self).store_binary(data).await
+    }
+
+    async fn retrieve_binary(&self, id: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        (**self).retrieve_binary(id).await
+    }
+
+    async fn store_stream(
+        &mut self,
+        reader: &mut (dyn AsyncRead + Send + Unpin),
+    ) -> Result<String, StorageError> {
+        (**self).store_stream(reader).await
+    }
+
+    async fn retrieve_to(
+        &self,
+        id: &str,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<bool, StorageError> {
+        (**self).retrieve_to(id, writer).await
+    }
+
+    async fn delete_binary(&mut self, id: &str) -> Result<(), StorageError> {
+        (**self).delete_binary(id).await
+    }
+
+    fn storage_type(&self) -> StorageType {
+        (**self).storage_type()
+    }
+}
@@ -128,6 +128,50 @@ pub struct WorkflowMetadata {
     /// primary `on` workflow). Empty when the workflow subscribes to none.
     #[serde(default)]
     pub workflow_triggers: Vec<String>,
+
+    /// Where the package was built from (git commit, pack time, toolchain,
+    /// lockfile hash), as recorded at pack time. `None` for packages packed
+    /// without provenance.
+    #[serde(default)]
+    pub provenance: Option<crate::packaging::BuildProvenance>,
+
+    /// Configuration values the package was registered with, validated
+    /// against its `config_schema`. Handed to the package's tasks as
+    /// `context.package_config()`. `None` when the package declares no
+    /// schema.
+    #[serde(default)]
+    pub config: Option<serde_json::Value>,
+}
+
+impl WorkflowMetadata {
+    /// Declared outputs of this package's workflow, from
+    /// `#[workflow(outputs(...))]`. They travel as a `"workflow_outputs"` entry
+    /// in [`declared_surfaces`](Self::declared_surfaces); empty when none are
+    /// declared.
+    pub fn declared_outputs(&self) -> Vec<cloacina_api_types::InputSlot> {
+        self.declared_surfaces
+            .iter()
+            .find(|s| s.kind == crate::outputs::OUTPUTS_SURFACE_KIND)
+            .map(|s| s.slots.clone())
+            .unwrap_or_default()
+    }
+
+    /// JSON Schema document for the execution context this workflow accepts,
+    /// built from [`declared_params`](Self::declared_params).
+    pub fn params_json_schema(&self) -> serde_json::Value {
+        cloacina_api_types::slots_json_schema(
+            &format!("{} params", self.workflow_name),
+            &self.declared_params,
+        )
+    }
+
+    /// JSON Schema document for this workflow's declared outputs.
+    pub fn outputs_json_schema(&self) -> serde_json::Value {
+        cloacina_api_types::slots_json_schema(
+            &format!("{} outputs", self.workflow_name),
+            &self.declared_outputs(),
+        )
+    }
 }
 
 /// A single source file extracted from a package's retained `.cloacina`
@@ -145,6 +189,37 @@ pub struct WorkflowSourceFile {
     pub contents: String,
 }
 
+/// One registered version of a package, active or superseded.
+///
+/// Versions are listed newest first; at most one is active, and only
+/// versions with `build_status == "success"` are rollback targets.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PackageVersion {
+    /// Package row ID for this version.
+    pub id: Uuid,
+
+    /// Version string from the package manifest.
+    pub version: String,
+
+    /// SHA-256 of the uploaded archive.
+    pub content_hash: String,
+
+    /// Build state: `pending`, `building`, `success` or `failed`.
+    pub build_status: String,
+
+    /// Compiler error for a failed build.
+    pub build_error: Option<String>,
+
+    /// Whether this is the version the reconciler loads.
+    pub active: bool,
+
+    /// When this version was registered.
+    pub created_at: DateTime<Utc>,
+
+    /// When the build finished, if it has.
+    pub compiled_at: Option<DateTime<Utc>>,
+}
+
 /// One node in a workflow's task dependency graph (CLOACI-T-0663).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WorkflowTaskNode {
@@ -22,7 +22,7 @@ use uuid::Uuid;
 use super::WorkflowRegistryImpl;
 use crate::registry::error::RegistryError;
 use crate::registry::traits::RegistryStorage;
-use crate::registry::types::{WorkflowMetadata, WorkflowTaskNode};
+use crate::registry::types::{PackageVersion, WorkflowMetadata, WorkflowTaskNode};
 
 /// Result of inspecting a package — full metadata plus the raw build state.
 #[derive(Debug, Clone)]
@@ -60,7 +60,7 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
     /// `interact_on_backend!` macro (CLOACI-I-0135) can check out the concrete
     /// pooled connection for whichever backend is live. `DAL::new` only clones
     /// the `Database` (an `Arc`-backed pool handle), so this is cheap per call.
-    fn dal(&self) -> crate::dal::unified::DAL {
+    pub(super) fn dal(&self) -> crate::dal::unified::DAL {
         crate::dal::unified::DAL::new(self.database.clone())
     }
 
@@ -226,6 +226,8 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
                 declared_params: package_metadata.declared_params.clone(),
                 declared_surfaces: package_metadata.declared_surfaces.clone(),
                 workflow_triggers: package_metadata.workflow_triggers.clone(),
+                provenance: package_metadata.provenance.clone(),
+                config: package_metadata.config.clone(),
             });
         }
 
@@ -316,6 +318,8 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
                 declared_params: package_metadata.declared_params.clone(),
                 declared_surfaces: package_metadata.declared_surfaces.clone(),
                 workflow_triggers: package_metadata.workflow_triggers.clone(),
+                provenance: package_metadata.provenance.clone(),
+                config: package_metadata.config.clone(),
             };
 
             Ok(Some((
@@ -328,13 +332,14 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
         }
     }
 
-    /// Look up the active package row for `name`, returning (id, registry_id, content_hash).
+    /// Look up the active package row for `name`, returning
+    /// (id, registry_id, content_hash, version).
     ///
     /// Returns `Ok(None)` if no active row exists. Superseded rows are ignored.
     pub(super) async fn get_active_package_by_name(
         &self,
         package_name: &str,
-    ) -> Result<Option<(Uuid, String, String)>, RegistryError> {
+    ) -> Result<Option<(Uuid, String, String, String)>, RegistryError> {
         use crate::dal::unified::models::UnifiedWorkflowPackage;
         use crate::database::schema::unified::workflow_packages;
         use crate::database::universal_types::UniversalBool;
@@ -349,7 +354,41 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
                 .optional()
         })
         .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))?;
-        Ok(record.map(|r| (r.id.0, r.registry_id.0.to_string(), r.content_hash)))
+        Ok(record.map(|r| {
+            (
+                r.id.0,
+                r.registry_id.0.to_string(),
+                r.content_hash,
+                r.version,
+            )
+        }))
+    }
+
+    /// Configuration values stored with package `package_id`, or `None` when
+    /// it was registered without any (or the row does not exist).
+    pub(super) async fn get_package_config(
+        &self,
+        package_id: Uuid,
+    ) -> Result<Option<serde_json::Value>, RegistryError> {
+        use crate::database::schema::unified::workflow_packages;
+        use crate::database::universal_types::UniversalUuid;
+
+        let pid = UniversalUuid(package_id);
+        let dal = self.dal();
+        let metadata: Option<String> = crate::interact_on_backend!(dal, |conn| {
+            workflow_packages::table
+                .filter(workflow_packages::id.eq(pid))
+                .select(workflow_packages::metadata)
+                .first::<String>(conn)
+                .optional()
+        })
+        .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))?;
+        let Some(metadata) = metadata else {
+            return Ok(None);
+        };
+        let metadata: crate::registry::loader::package_loader::PackageMetadata =
+            serde_json::from_str(&metadata).map_err(RegistryError::Serialization)?;
+        Ok(metadata.config)
     }
 
     /// Supersede the current active row for `old_id` (if provided) and insert a new
@@ -465,6 +504,146 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
         })
     }
 
+    /// Re-activate the newest successfully-built version of `package_name`
+    /// registered before the active one, superseding the active row in the
+    /// same transaction. Repeated calls walk further back.
+    ///
+    /// Returns (restored id, restored version, restored content_hash,
+    /// replaced version), or `Ok(None)` when the name has no active row or no
+    /// earlier built version to return to.
+    pub(super) async fn rollback_active_package(
+        &self,
+        package_name: &str,
+    ) -> Result<Option<(Uuid, String, String, String)>, RegistryError> {
+        use crate::database::schema::unified::workflow_packages;
+        use crate::database::universal_types::{UniversalBool, UniversalTimestamp, UniversalUuid};
+
+        let name = package_name.to_string();
+        let dal = self.dal();
+        crate::interact_on_backend!(dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|tx| {
+                let Some((active_id, active_created, active_version)) = workflow_packages::table
+                    .filter(workflow_packages::package_name.eq(&name))
+                    .filter(workflow_packages::superseded.eq(UniversalBool(false)))
+                    .select((
+                        workflow_packages::id,
+                        workflow_packages::created_at,
+                        workflow_packages::version,
+                    ))
+                    .first::<(UniversalUuid, UniversalTimestamp, String)>(tx)
+                    .optional()?
+                else {
+                    return Ok(None);
+                };
+                let Some((previous_id, previous_version, previous_hash)) = workflow_packages::table
+                    .filter(workflow_packages::package_name.eq(&name))
+                    .filter(workflow_packages::superseded.eq(UniversalBool(true)))
+                    .filter(workflow_packages::build_status.eq("success"))
+                    .filter(workflow_packages::created_at.lt(active_created))
+                    .order(workflow_packages::created_at.desc())
+                    .select((
+                        workflow_packages::id,
+                        workflow_packages::version,
+                        workflow_packages::content_hash,
+                    ))
+                    .first::<(UniversalUuid, String, String)>(tx)
+                    .optional()?
+                else {
+                    return Ok(None);
+                };
+                // Supersede first: the partial unique index allows only one
+                // active row per name at any point in the transaction.
+                diesel::update(
+                    workflow_packages::table.filter(workflow_packages::id.eq(active_id)),
+                )
+                .set(workflow_packages::superseded.eq(UniversalBool(true)))
+                .execute(tx)?;
+                diesel::update(
+                    workflow_packages::table.filter(workflow_packages::id.eq(previous_id)),
+                )
+                .set(workflow_packages::superseded.eq(UniversalBool(false)))
+                .execute(tx)?;
+                Ok(Some((
+                    previous_id.0,
+                    previous_version,
+                    previous_hash,
+                    active_version,
+                )))
+            })
+        })
+        .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))
+    }
+
+    /// Every version row registered under `package_name`, newest first,
+    /// whatever its build state. Selects columns explicitly so compiled
+    /// artifacts are not loaded.
+    pub(super) async fn list_versions_by_name(
+        &self,
+        package_name: &str,
+    ) -> Result<Vec<PackageVersion>, RegistryError> {
+        use crate::database::schema::unified::workflow_packages;
+        use crate::database::universal_types::{UniversalBool, UniversalTimestamp, UniversalUuid};
+
+        /// The columns selected below, in order.
+        type VersionRow = (
+            UniversalUuid,
+            String,
+            String,
+            String,
+            Option<String>,
+            UniversalBool,
+            UniversalTimestamp,
+            Option<UniversalTimestamp>,
+        );
+
+        let name = package_name.to_string();
+        let dal = self.dal();
+        let rows: Vec<VersionRow> = crate::interact_on_backend!(dal, |conn| {
+            workflow_packages::table
+                .filter(workflow_packages::package_name.eq(&name))
+                .order(workflow_packages::created_at.desc())
+                .select((
+                    workflow_packages::id,
+                    workflow_packages::version,
+                    workflow_packages::content_hash,
+                    workflow_packages::build_status,
+                    workflow_packages::build_error,
+                    workflow_packages::superseded,
+                    workflow_packages::created_at,
+                    workflow_packages::compiled_at,
+                ))
+                .load(conn)
+        })
+        .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    id,
+                    version,
+                    content_hash,
+                    build_status,
+                    build_error,
+                    superseded,
+                    created_at,
+                    compiled_at,
+                )| {
+                    PackageVersion {
+                        id: id.0,
+                        version,
+                        content_hash,
+                        build_status,
+                        build_error,
+                        active: !superseded.is_true(),
+                        created_at: created_at.0,
+                        compiled_at: compiled_at.map(|t| t.0),
+                    }
+                },
+            )
+            .collect())
+    }
+
     /// Inspect a package by ID — returns metadata plus `build_status` /
     /// `build_error` regardless of build outcome. Unlike `get_package_metadata_by_id`
     /// this does not filter by `build_status = 'success'`, so operators can
@@ -525,6 +704,8 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
                 declared_params: package_metadata.declared_params.clone(),
                 declared_surfaces: package_metadata.declared_surfaces.clone(),
                 workflow_triggers: package_metadata.workflow_triggers.clone(),
+                provenance: package_metadata.provenance.clone(),
+                config: package_metadata.config.clone(),
             },
             build_status: record.build_status,
             build_error: record.build_error,
@@ -1385,6 +1566,8 @@ mod tests {
             declared_params: vec![],
             declared_surfaces: vec![],
             task_docs: Default::default(),
+            provenance: None,
+            config: None,
         }
     }
 
@@ -1569,6 +1752,48 @@ mod tests {
         assert!(undoc.doc_what.is_none() && undoc.doc_why.is_none());
     }
 
+    /// Provenance recorded at upload survives the build-success metadata
+    /// merge and surfaces through inspection.
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_provenance_survives_build_and_surfaces_on_inspect() {
+        let registry = create_test_registry().await;
+        let registry_id = Uuid::new_v4().to_string();
+        let provenance = crate::packaging::BuildProvenance {
+            git_commit: Some("9f1c2e7".to_string()),
+            git_dirty: true,
+            built_at: Some("2026-10-18T09:30:00+00:00".to_string()),
+            rustc_version: None,
+            lockfile: Some("uv.lock".to_string()),
+            lockfile_sha256: Some("ab".repeat(32)),
+        };
+        let mut meta = sample_metadata("prov-pkg", "1.0.0");
+        meta.provenance = Some(provenance.clone());
+        let pkg_id = registry
+            .store_package_metadata(&registry_id, &meta)
+            .await
+            .unwrap();
+        registry.claim_next_build().await.unwrap();
+
+        let mut docs = std::collections::HashMap::new();
+        docs.insert(
+            "my_task".to_string(),
+            crate::registry::loader::package_loader::TaskDocs::default(),
+        );
+        registry
+            .mark_build_success_with_docs(pkg_id, Vec::new(), docs, Vec::new(), Vec::new())
+            .await
+            .unwrap();
+
+        let inspected = registry
+            .inspect_package_by_id(pkg_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(inspected.build_status, "success");
+        assert_eq!(inspected.metadata.provenance, Some(provenance));
+    }
+
     #[cfg(feature = "sqlite")]
     #[tokio::test]
     async fn test_workflow_pause_resume() {
@@ -1877,6 +2102,77 @@ mod tests {
         assert_eq!(names[0].id, id_v2);
     }
 
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_rollback_reactivates_previous_version_and_emits_event() {
+        use crate::registry::events::{RegistryEventChannel, RegistryEventKind};
+
+        let channel = RegistryEventChannel::new(8);
+        let mut events = channel.subscribe();
+        let mut registry = create_test_registry()
+            .await
+            .with_event_listener(std::sync::Arc::new(channel));
+
+        let mut ids = Vec::new();
+        for version in ["1", "2"] {
+            let id = registry
+                .supersede_and_insert(
+                    ids.last().copied(),
+                    &Uuid::new_v4().to_string(),
+                    &sample_metadata("pkg-rb", version),
+                    &format!("hash-v{}", version),
+                )
+                .await
+                .unwrap();
+            registry.claim_next_build().await.unwrap();
+            registry.mark_build_success(id, Vec::new()).await.unwrap();
+            ids.push(id);
+            // Rollback orders versions by created_at.
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+
+        let restored = registry.rollback_workflow_package("pkg-rb").await.unwrap();
+        assert_eq!(restored.package_id, ids[0]);
+        let active = registry
+            .get_active_package_by_name("pkg-rb")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((active.0, active.3.as_str()), (ids[0], "1"));
+
+        let versions = registry.list_package_versions("pkg-rb").await.unwrap();
+        let listed: Vec<_> = versions
+            .iter()
+            .map(|v| (v.version.as_str(), v.active, v.build_status.as_str()))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![("2", false, "success"), ("1", true, "success")]
+        );
+
+        let event = events.try_recv().unwrap();
+        assert_eq!(event, restored);
+        assert_eq!(event.kind, RegistryEventKind::RolledBack);
+        assert_eq!(event.version, "1");
+        assert_eq!(event.previous_version.as_deref(), Some("2"));
+        assert_eq!(event.content_hash.as_deref(), Some("hash-v1"));
+
+        // Nothing older than v1: the rollback is refused and v1 stays active.
+        assert!(matches!(
+            registry.rollback_workflow_package("pkg-rb").await,
+            Err(RegistryError::ValidationError { .. })
+        ));
+        assert!(events.try_recv().is_err());
+
+        registry
+            .unregister_workflow_package_by_id(ids[0])
+            .await
+            .unwrap();
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.kind, RegistryEventKind::Removed);
+        assert_eq!(event.package_id, ids[0]);
+    }
+
     #[cfg(feature = "sqlite")]
     #[tokio::test]
     async fn test_partial_unique_rejects_second_active_for_same_name() {
@@ -2092,4 +2388,93 @@ mod tests {
             .unwrap();
         assert!(registry.claim_next_build().await.unwrap().is_some());
     }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_collect_garbage_keeps_active_and_newest_versions() {
+        use crate::registry::gc::RegistryGcPolicy;
+
+        let mut registry = create_test_registry().await;
+        let mut ids = Vec::new();
+        let mut registry_ids = Vec::new();
+        for version in ["1", "2", "3"] {
+            let registry_id = registry
+                .storage
+                .store_binary(format!("archive-{version}").into_bytes())
+                .await
+                .unwrap();
+            let id = registry
+                .supersede_and_insert(
+                    ids.last().copied(),
+                    &registry_id,
+                    &sample_metadata("pkg-gc", version),
+                    &format!("hash-{version}"),
+                )
+                .await
+                .unwrap();
+            ids.push(id);
+            registry_ids.push(registry_id);
+            // Distinct upload timestamps so "newest" is well defined.
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        let dal = crate::dal::DAL::new(registry.database.clone());
+        dal.workflow_packages()
+            .upsert_artifact("pkg-gc", "1", None, "aarch64-test", "art-1", vec![1])
+            .await
+            .unwrap();
+
+        let policy = RegistryGcPolicy::keep_last(2);
+
+        // Dry run reports the oldest version and deletes nothing.
+        let report = registry.collect_garbage(&policy, true).await.unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.retained, 2);
+        assert_eq!(report.collected.len(), 1);
+        assert_eq!(report.collected[0].package_id, ids[0]);
+        assert_eq!(report.collected[0].version, "1");
+        assert!(registry
+            .storage
+            .retrieve_binary(&registry_ids[0])
+            .await
+            .unwrap()
+            .is_some());
+
+        let report = registry.collect_garbage(&policy, false).await.unwrap();
+        assert!(!report.dry_run);
+        assert_eq!(report.collected.len(), 1);
+
+        // The collected row, its archive and its per-target artifact are gone.
+        assert!(registry
+            .storage
+            .retrieve_binary(&registry_ids[0])
+            .await
+            .unwrap()
+            .is_none());
+        assert!(dal
+            .workflow_packages()
+            .get_artifact_triples_for_package("pkg-gc")
+            .await
+            .unwrap()
+            .is_empty());
+        // The kept versions are untouched and the active one still resolves.
+        for registry_id in &registry_ids[1..] {
+            assert!(registry
+                .storage
+                .retrieve_binary(registry_id)
+                .await
+                .unwrap()
+                .is_some());
+        }
+        let active = registry
+            .get_active_package_by_name("pkg-gc")
+            .await
+            .unwrap()
+            .expect("active row survives GC");
+        assert_eq!(active.0, ids[2]);
+
+        // A second pass has nothing left to do.
+        let report = registry.collect_garbage(&policy, false).await.unwrap();
+        assert!(report.collected.is_empty());
+        assert_eq!(report.retained, 2);
+    }
 }
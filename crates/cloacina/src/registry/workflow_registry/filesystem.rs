@@ -149,6 +149,8 @@ impl FilesystemWorkflowRegistry {
                             declared_params: Vec::new(),
                             declared_surfaces: Vec::new(),
                             workflow_triggers: Vec::new(),
+                            provenance: None,
+                            config: None,
                         };
 
                         debug!(
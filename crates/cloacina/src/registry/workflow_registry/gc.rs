@@ -0,0 +1,180 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Garbage collection of superseded package versions. The policy and the
+//! background sweeper live in [`crate::registry::gc`].
+
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::WorkflowRegistryImpl;
+use crate::registry::error::RegistryError;
+use crate::registry::gc::{
+    select_collectable, GcCandidate, RegistryGcEntry, RegistryGcPolicy, RegistryGcReport,
+};
+use crate::registry::traits::RegistryStorage;
+
+impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
+    /// Apply `policy` to every package version in the registry and delete
+    /// the ones it does not keep: the `workflow_packages` row, its source
+    /// archive in registry storage, and the per-target artifacts and bundled
+    /// providers of a `(name, version)` no remaining row uses.
+    ///
+    /// With `dry_run` nothing is deleted; the report lists what would be.
+    pub async fn collect_garbage(
+        &mut self,
+        policy: &RegistryGcPolicy,
+        dry_run: bool,
+    ) -> Result<RegistryGcReport, RegistryError> {
+        let rows = self.list_gc_candidates().await?;
+        let registry_ids: Vec<(Uuid, Uuid)> = rows
+            .iter()
+            .map(|(row, registry_id)| (row.entry.package_id, *registry_id))
+            .collect();
+        let (collected, retained) =
+            select_collectable(rows.into_iter().map(|(row, _)| row).collect(), policy);
+
+        if !dry_run {
+            let mut remaining = registry_ids;
+            for entry in &collected {
+                remaining.retain(|(id, _)| *id != entry.package_id);
+                let registry_id = self.delete_collected_version(entry).await?;
+                // Re-uploads can share an archive; only drop it once no
+                // surviving row points at it.
+                if let Some(registry_id) = registry_id {
+                    if !remaining.iter().any(|(_, r)| *r == registry_id) {
+                        self.storage.delete_binary(&registry_id.to_string()).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(RegistryGcReport {
+            dry_run,
+            collected,
+            retained,
+        })
+    }
+
+    /// Every `workflow_packages` row (without its blobs), paired with its
+    /// registry storage ID.
+    async fn list_gc_candidates(&self) -> Result<Vec<(GcCandidate, Uuid)>, RegistryError> {
+        use crate::database::schema::unified::workflow_packages;
+        use crate::database::universal_types::{UniversalBool, UniversalTimestamp, UniversalUuid};
+
+        let dal = self.dal();
+        let rows: Vec<(
+            UniversalUuid,
+            UniversalUuid,
+            String,
+            String,
+            UniversalBool,
+            UniversalTimestamp,
+        )> = crate::interact_on_backend!(dal, |conn| {
+            workflow_packages::table
+                .select((
+                    workflow_packages::id,
+                    workflow_packages::registry_id,
+                    workflow_packages::package_name,
+                    workflow_packages::version,
+                    workflow_packages::superseded,
+                    workflow_packages::created_at,
+                ))
+                .load(conn)
+        })
+        .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, registry_id, package_name, version, superseded, created_at)| {
+                    (
+                        GcCandidate {
+                            entry: RegistryGcEntry {
+                                package_id: id.0,
+                                package_name,
+                                version,
+                                created_at: created_at.0,
+                            },
+                            active: !superseded.is_true(),
+                        },
+                        registry_id.0,
+                    )
+                },
+            )
+            .collect())
+    }
+
+    /// Delete one collected row, plus the per-target artifacts and bundled
+    /// providers of its `(name, version)` when no other row shares it.
+    /// Returns the row's registry storage ID, `None` if it was already gone.
+    async fn delete_collected_version(
+        &mut self,
+        entry: &RegistryGcEntry,
+    ) -> Result<Option<Uuid>, RegistryError> {
+        use crate::database::schema::unified::{
+            package_artifacts, package_providers, workflow_packages,
+        };
+        use crate::database::universal_types::UniversalUuid;
+
+        // A superseded row is not loaded, but clean up after one that is.
+        if self.loaded_packages.remove(&entry.package_id).is_some() {
+            self.registrar
+                .unregister_package_tasks(&entry.package_id.to_string())
+                .map_err(RegistryError::Loader)?;
+        }
+
+        let id = UniversalUuid(entry.package_id);
+        let name = entry.package_name.clone();
+        let version = entry.version.clone();
+        let dal = self.dal();
+        crate::interact_on_backend!(dal, |conn| {
+            conn.transaction::<_, diesel::result::Error, _>(|tx| {
+                let registry_id: Option<UniversalUuid> = workflow_packages::table
+                    .filter(workflow_packages::id.eq(id))
+                    .select(workflow_packages::registry_id)
+                    .first(tx)
+                    .optional()?;
+                if registry_id.is_none() {
+                    return Ok(None);
+                }
+                diesel::delete(workflow_packages::table.filter(workflow_packages::id.eq(id)))
+                    .execute(tx)?;
+                let still_used: i64 = workflow_packages::table
+                    .filter(workflow_packages::package_name.eq(&name))
+                    .filter(workflow_packages::version.eq(&version))
+                    .count()
+                    .get_result(tx)?;
+                if still_used == 0 {
+                    diesel::delete(
+                        package_artifacts::table
+                            .filter(package_artifacts::package_name.eq(&name))
+                            .filter(package_artifacts::version.eq(&version)),
+                    )
+                    .execute(tx)?;
+                    diesel::delete(
+                        package_providers::table
+                            .filter(package_providers::package_name.eq(&name))
+                            .filter(package_providers::version.eq(&version)),
+                    )
+                    .execute(tx)?;
+                }
+                Ok(registry_id.map(|r| r.0))
+            })
+        })
+        .map_err(|e| RegistryError::Database(format!("Database error: {}", e)))
+    }
+}
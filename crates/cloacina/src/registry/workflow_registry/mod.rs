@@ -22,6 +22,7 @@
 
 mod database;
 pub mod filesystem;
+mod gc;
 mod package;
 
 pub use database::{build_queue_stats, reconciler_stats, BuildQueueStats, ReconcilerStats};
@@ -29,14 +30,16 @@ pub use database::{build_queue_stats, reconciler_stats, BuildQueueStats, Reconci
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::database::Database;
 use crate::registry::error::RegistryError;
+use crate::registry::events::{RegistryEvent, RegistryEventKind, RegistryEventListener};
 use crate::registry::loader::{PackageLoader, TaskRegistrar};
 use crate::registry::traits::{RegistryStorage, WorkflowRegistry};
 use crate::registry::types::{
-    LoadedWorkflow, WorkflowMetadata, WorkflowPackageId, WorkflowSourceFile,
+    LoadedWorkflow, PackageVersion, WorkflowMetadata, WorkflowPackageId, WorkflowSourceFile,
 };
 use crate::task::TaskNamespace;
 
@@ -64,6 +67,8 @@ pub struct WorkflowRegistryImpl<S: RegistryStorage> {
     registrar: TaskRegistrar,
     /// Map of package IDs to registered task namespaces for cleanup tracking
     pub(super) loaded_packages: HashMap<Uuid, Vec<TaskNamespace>>,
+    /// Notified of every package lifecycle change this registry commits.
+    listeners: Vec<Arc<dyn RegistryEventListener>>,
 }
 
 impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
@@ -88,9 +93,43 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
             loader,
             registrar,
             loaded_packages: HashMap::new(),
+            listeners: Vec::new(),
         })
     }
 
+    /// Report package lifecycle events (register, update, rollback, remove)
+    /// to `listener`. May be called more than once.
+    pub fn with_event_listener(mut self, listener: Arc<dyn RegistryEventListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    fn emit(
+        &self,
+        kind: RegistryEventKind,
+        package_id: Uuid,
+        package_name: &str,
+        version: &str,
+        previous_version: Option<String>,
+        content_hash: Option<String>,
+    ) {
+        if self.listeners.is_empty() {
+            return;
+        }
+        let event = RegistryEvent {
+            kind,
+            package_id,
+            package_name: package_name.to_string(),
+            version: version.to_string(),
+            previous_version,
+            content_hash,
+            occurred_at: chrono::Utc::now(),
+        };
+        for listener in &self.listeners {
+            listener.on_event(&event);
+        }
+    }
+
     /// Get the number of currently loaded packages.
     pub fn loaded_package_count(&self) -> usize {
         self.loaded_packages.len()
@@ -157,6 +196,18 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
         WorkflowRegistry::register_workflow(self, package_data).await
     }
 
+    /// Register a workflow package from a `.cloacina` archive on disk with
+    /// its configuration values (alias for
+    /// `register_workflow_file_with_config` via the trait). The archive is
+    /// streamed into storage rather than read into memory.
+    pub async fn register_workflow_package_file(
+        &mut self,
+        archive_path: &Path,
+        config: Option<serde_json::Value>,
+    ) -> Result<Uuid, RegistryError> {
+        WorkflowRegistry::register_workflow_file_with_config(self, archive_path, config).await
+    }
+
     /// Get the source archive bytes for a package the compiler service has
     /// claimed. Unlike `get_workflow_package_by_id`, this does *not* filter
     /// by `build_status = 'success'` — the compiler needs to read source for
@@ -328,6 +379,11 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
         let workflows = self.list_workflows().await?;
         for w in workflows {
             for surface in w.declared_surfaces {
+                // Declared workflow outputs share the surface list but are not
+                // injectable boundaries.
+                if surface.kind == crate::outputs::OUTPUTS_SURFACE_KIND {
+                    continue;
+                }
                 if let Some(slot) = surface.slots.into_iter().find(|s| s.name == name) {
                     return Ok(Some(slot));
                 }
@@ -422,13 +478,22 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
         self.list_all_packages().await
     }
 
+    /// All registered versions of `package_name`, newest first, including
+    /// superseded and unbuilt ones. Empty when the name is unknown.
+    pub async fn list_package_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<Vec<PackageVersion>, RegistryError> {
+        self.list_versions_by_name(package_name).await
+    }
+
     /// Unregister a workflow package by ID.
     pub async fn unregister_workflow_package_by_id(
         &mut self,
         package_id: Uuid,
     ) -> Result<(), RegistryError> {
         // Get package metadata to find the registry_id for storage cleanup
-        let (registry_id, _metadata, _compiled) =
+        let (registry_id, metadata, _compiled) =
             match self.get_package_metadata_by_id(package_id).await? {
                 Some(data) => data,
                 None => return Ok(()), // Idempotent - already doesn't exist
@@ -447,9 +512,51 @@ impl<S: RegistryStorage> WorkflowRegistryImpl<S> {
         // Delete binary data from storage
         self.storage.delete_binary(&registry_id).await?;
 
+        self.emit(
+            RegistryEventKind::Removed,
+            package_id,
+            &metadata.package_name,
+            &metadata.version,
+            None,
+            None,
+        );
+
         Ok(())
     }
 
+    /// Roll `package_name` back to the newest successfully-built version
+    /// registered before the active one. The active version is superseded,
+    /// not deleted, so registering it again undoes the rollback. Returns the
+    /// `rolled_back` event describing the change, which listeners also get.
+    pub async fn rollback_workflow_package(
+        &mut self,
+        package_name: &str,
+    ) -> Result<RegistryEvent, RegistryError> {
+        let (package_id, version, content_hash, replaced_version) = self
+            .rollback_active_package(package_name)
+            .await?
+            .ok_or_else(|| RegistryError::ValidationError {
+                reason: format!(
+                    "package '{}' has no earlier built version to roll back to",
+                    package_name
+                ),
+            })?;
+
+        let event = RegistryEvent {
+            kind: RegistryEventKind::RolledBack,
+            package_id,
+            package_name: package_name.to_string(),
+            version,
+            previous_version: Some(replaced_version),
+            content_hash: Some(content_hash),
+            occurred_at: chrono::Utc::now(),
+        };
+        for listener in &self.listeners {
+            listener.on_event(&event);
+        }
+        Ok(event)
+    }
+
     /// Unregister a workflow package by name and version.
     pub async fn unregister_workflow_package_by_name(
         &mut self,
@@ -476,54 +583,106 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
         &mut self,
         package_data: Vec<u8>,
     ) -> Result<WorkflowPackageId, RegistryError> {
-        // 1. Require a bzip2 source archive (.cloacina)
         if !Self::is_cloacina_package(&package_data) {
-            return Err(RegistryError::ValidationError {
-                reason: "Package data is not a valid .cloacina bzip2 source archive. \
-                         Raw library registration is not supported."
-                    .to_string(),
-            });
+            return Err(not_a_cloacina_package());
         }
-
-        // 2. Read the manifest to extract package name/version for duplicate checking.
-        //    We do this by writing to a temp dir and calling unpack + load_manifest.
+        // Spool to disk and take the streaming path, so an in-memory upload
+        // and a file upload are registered identically.
         let work_dir = tempfile::TempDir::new()
             .map_err(|e| RegistryError::Internal(format!("Failed to create temp dir: {}", e)))?;
         let archive_path = work_dir.path().join("pkg.cloacina");
-        std::fs::write(&archive_path, &package_data)
+        tokio::fs::write(&archive_path, &package_data)
+            .await
             .map_err(|e| RegistryError::Internal(format!("Failed to write archive: {}", e)))?;
-        let extract_dir = work_dir.path().join("source");
-        std::fs::create_dir_all(&extract_dir)
-            .map_err(|e| RegistryError::Internal(format!("Failed to create extract dir: {}", e)))?;
+        drop(package_data);
+        self.register_workflow_file(&archive_path).await
+    }
 
-        let source_dir = fidius_core::package::unpack_package(&archive_path, &extract_dir)
-            .map_err(|e| RegistryError::ValidationError {
-                reason: format!("Failed to unpack source archive: {}", e),
-            })?;
+    async fn register_workflow_file(
+        &mut self,
+        archive_path: &Path,
+    ) -> Result<WorkflowPackageId, RegistryError> {
+        self.register_workflow_file_with_config(archive_path, None)
+            .await
+    }
 
-        let manifest = cloacina_workflow_plugin::manifest::load_resolved_manifest(&source_dir)
-            .map_err(|e| RegistryError::ValidationError {
-                reason: format!("Failed to load package.toml: {}", e),
-            })?;
+    async fn register_workflow_file_with_config(
+        &mut self,
+        archive_path: &Path,
+        config: Option<serde_json::Value>,
+    ) -> Result<WorkflowPackageId, RegistryError> {
+        // 1. Require a bzip2 source archive (.cloacina)
+        let mut magic = [0u8; 2];
+        let magic_len = {
+            use tokio::io::AsyncReadExt;
+            let mut file = tokio::fs::File::open(archive_path)
+                .await
+                .map_err(|e| RegistryError::Internal(format!("Failed to open archive: {}", e)))?;
+            file.read(&mut magic)
+                .await
+                .map_err(|e| RegistryError::Internal(format!("Failed to read archive: {}", e)))?
+        };
+        if !Self::is_cloacina_package(&magic[..magic_len]) {
+            return Err(not_a_cloacina_package());
+        }
+
+        // 2. Read the manifest to extract package name/version for duplicate
+        //    checking, and hash the archive for idempotency + audit of what's
+        //    installed. Both read the file in pieces; unpacking is blocking
+        //    work, so keep it off the async runtime's worker threads.
+        let work_dir = tempfile::TempDir::new()
+            .map_err(|e| RegistryError::Internal(format!("Failed to create temp dir: {}", e)))?;
+        let extract_dir = work_dir.path().join("source");
+        let archive = archive_path.to_path_buf();
+        let inspected =
+            tokio::task::spawn_blocking(move || inspect_archive(&archive, &extract_dir))
+                .await
+                .map_err(|e| {
+                    RegistryError::Internal(format!("archive inspection task panicked: {}", e))
+                })??;
+        let InspectedArchive {
+            manifest,
+            provenance,
+            config_schema,
+            content_hash,
+        } = inspected;
 
         let pkg_name = manifest.package.name.clone();
         let pkg_version = manifest.package.version.clone();
 
-        // 3. Content hash for idempotency + audit of what's installed.
-        let content_hash = {
-            use sha2::{Digest, Sha256};
-            let mut hasher = Sha256::new();
-            hasher.update(&package_data);
-            format!("{:x}", hasher.finalize())
+        // Configuration is only accepted by a package that declares a schema,
+        // and must validate against it.
+        let config = match config_schema {
+            Some(schema) => Some(crate::packaging::validate_config(&schema, config).map_err(
+                |errors| RegistryError::ValidationError {
+                    reason: format!(
+                        "package config does not match {}'s config_schema: {}",
+                        pkg_name,
+                        errors.join("; ")
+                    ),
+                },
+            )?),
+            None if config.is_some() => {
+                return Err(RegistryError::ValidationError {
+                    reason: format!(
+                        "package {} declares no config_schema; it takes no config",
+                        pkg_name
+                    ),
+                });
+            }
+            None => None,
         };
 
-        // 4. Look up the currently-active row for this package name.
+        // 3. Look up the currently-active row for this package name.
         //    - Same hash  → idempotent, return existing id (no storage churn, no supersede)
         //    - Different  → supersede old + insert new atomically
         //    - None       → insert new
         let active = self.get_active_package_by_name(&pkg_name).await?;
-        if let Some((existing_id, _, ref existing_hash)) = active {
-            if existing_hash == &content_hash {
+        if let Some((existing_id, _, ref existing_hash, _)) = active {
+            // Same bytes under different config is a new version of the package.
+            if existing_hash == &content_hash
+                && self.get_package_config(existing_id).await? == config
+            {
                 return Ok(existing_id);
             }
         }
@@ -548,11 +707,19 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
             declared_surfaces: vec![],
             // Filled at build success from the compiler doc parse (CLOACI-T-0754).
             task_docs: Default::default(),
+            provenance,
+            config,
         };
 
-        let registry_id = self.storage.store_binary(package_data).await?;
+        let mut file = tokio::fs::File::open(archive_path)
+            .await
+            .map_err(|e| RegistryError::Internal(format!("Failed to open archive: {}", e)))?;
+        let registry_id = self.storage.store_stream(&mut file).await?;
 
-        let old_id = active.map(|(id, _, _)| id);
+        let (old_id, previous_version) = match active {
+            Some((id, _, _, version)) => (Some(id), Some(version)),
+            None => (None, None),
+        };
 
         // Content-hash artifact reuse (T-0523): if an earlier row compiled the
         // same bytes successfully, skip the build queue and pre-populate the
@@ -570,6 +737,20 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
             )
             .await?;
 
+        let kind = if previous_version.is_some() {
+            RegistryEventKind::Updated
+        } else {
+            RegistryEventKind::Registered
+        };
+        self.emit(
+            kind,
+            package_id,
+            &package_metadata.package_name,
+            &package_metadata.version,
+            previous_version,
+            Some(content_hash),
+        );
+
         Ok(package_id)
     }
 
@@ -595,43 +776,46 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
             }
         };
 
-        // 3. Create loaded workflow
-        let workflow_metadata = WorkflowMetadata {
-            id: Uuid::new_v4(), // This should be the actual package ID from the database
-            registry_id: Uuid::parse_str(&registry_id).map_err(RegistryError::InvalidUuid)?,
-            workflow_name: if package_metadata.workflow_name.is_empty() {
-                package_metadata.package_name.clone()
-            } else {
-                package_metadata.workflow_name.clone()
-            },
-            package_name: package_metadata.package_name.clone(),
-            version: package_metadata.version.clone(),
-            description: package_metadata.description.clone(),
-            author: package_metadata.author.clone(),
-            tasks: package_metadata
-                .tasks
-                .iter()
-                .map(|t| t.local_id.clone())
-                .collect(),
-            task_graph: database::build_task_graph(&package_metadata),
-            schedules: Vec::new(),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-            // This load path is for execution, not the pause gate; the gate
-            // reads paused via the list/inspect paths. (CLOACI-T-0749)
-            paused: false,
-            declared_params: package_metadata.declared_params.clone(),
-            declared_surfaces: package_metadata.declared_surfaces.clone(),
-            workflow_triggers: package_metadata.workflow_triggers.clone(),
-        };
-
         Ok(Some(LoadedWorkflow {
-            metadata: workflow_metadata,
+            metadata: loaded_workflow_metadata(&registry_id, &package_metadata)?,
             package_data,
             compiled_data,
         }))
     }
 
+    async fn get_workflow_to_file(
+        &self,
+        package_name: &str,
+        version: &str,
+        archive_path: &Path,
+    ) -> Result<Option<LoadedWorkflow>, RegistryError> {
+        let (registry_id, package_metadata, compiled_data) =
+            match self.get_package_metadata(package_name, version).await? {
+                Some(data) => data,
+                None => return Ok(None),
+            };
+
+        // Copy the source archive from storage into the file piece by piece.
+        let mut file = tokio::fs::File::create(archive_path).await.map_err(|e| {
+            RegistryError::Internal(format!(
+                "Failed to create {}: {}",
+                archive_path.display(),
+                e
+            ))
+        })?;
+        if !self.storage.retrieve_to(&registry_id, &mut file).await? {
+            return Err(RegistryError::Internal(
+                "Package metadata exists but binary data is missing".to_string(),
+            ));
+        }
+
+        Ok(Some(LoadedWorkflow {
+            metadata: loaded_workflow_metadata(&registry_id, &package_metadata)?,
+            package_data: Vec::new(),
+            compiled_data,
+        }))
+    }
+
     async fn list_workflows(&self) -> Result<Vec<WorkflowMetadata>, RegistryError> {
         self.list_all_packages().await
     }
@@ -660,6 +844,12 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
 
         // 2. Find the package ID to unregister tasks
         let package_uuid = Uuid::parse_str(&registry_id).map_err(RegistryError::InvalidUuid)?;
+        // The row being deleted is the active one for this name; its id and
+        // hash go on the `removed` event.
+        let active = self
+            .get_active_package_by_name(package_name)
+            .await?
+            .filter(|(_, _, _, active_version)| active_version == version);
 
         // 3. Unregister tasks from global registry
         if let Some(_namespaces) = self.loaded_packages.remove(&package_uuid) {
@@ -674,6 +864,17 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
         // 5. Delete binary data from storage
         self.storage.delete_binary(&registry_id).await?;
 
+        if let Some((package_id, _, content_hash, _)) = active {
+            self.emit(
+                RegistryEventKind::Removed,
+                package_id,
+                package_name,
+                version,
+                None,
+                Some(content_hash),
+            );
+        }
+
         Ok(())
     }
 
@@ -753,6 +954,107 @@ impl<S: RegistryStorage + Send + Sync> WorkflowRegistry for WorkflowRegistryImpl
     }
 }
 
+/// The error for an upload that is not a bzip2 `.cloacina` archive.
+fn not_a_cloacina_package() -> RegistryError {
+    RegistryError::ValidationError {
+        reason: "Package data is not a valid .cloacina bzip2 source archive. \
+                 Raw library registration is not supported."
+            .to_string(),
+    }
+}
+
+/// What registration reads from an uploaded archive.
+struct InspectedArchive {
+    manifest: fidius_core::package::PackageManifest<cloacina_workflow_plugin::CloacinaMetadata>,
+    provenance: Option<crate::packaging::BuildProvenance>,
+    config_schema: Option<serde_json::Value>,
+    /// sha256 of the archive bytes.
+    content_hash: String,
+}
+
+/// Unpack the archive at `archive_path` into `extract_dir` and read what
+/// registration needs from it: the resolved manifest, the recorded build
+/// provenance, the declared config schema, and the sha256 of the archive
+/// bytes. Blocking.
+fn inspect_archive(
+    archive_path: &Path,
+    extract_dir: &Path,
+) -> Result<InspectedArchive, RegistryError> {
+    std::fs::create_dir_all(extract_dir)
+        .map_err(|e| RegistryError::Internal(format!("Failed to create extract dir: {}", e)))?;
+    let source_dir =
+        fidius_core::package::unpack_package(archive_path, extract_dir).map_err(|e| {
+            RegistryError::ValidationError {
+                reason: format!("Failed to unpack source archive: {}", e),
+            }
+        })?;
+    let manifest = cloacina_workflow_plugin::manifest::load_resolved_manifest(&source_dir)
+        .map_err(|e| RegistryError::ValidationError {
+            reason: format!("Failed to load package.toml: {}", e),
+        })?;
+    let provenance = crate::packaging::read_provenance(&source_dir)
+        .map_err(|reason| RegistryError::ValidationError { reason })?;
+    let config_schema = crate::packaging::read_config_schema(
+        &source_dir,
+        manifest.metadata.config_schema.as_deref(),
+    )
+    .map_err(|reason| RegistryError::ValidationError { reason })?;
+
+    let content_hash = {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        let mut file = std::fs::File::open(archive_path)
+            .map_err(|e| RegistryError::Internal(format!("Failed to open archive: {}", e)))?;
+        std::io::copy(&mut file, &mut hasher)
+            .map_err(|e| RegistryError::Internal(format!("Failed to hash archive: {}", e)))?;
+        format!("{:x}", hasher.finalize())
+    };
+    Ok(InspectedArchive {
+        manifest,
+        provenance,
+        config_schema,
+        content_hash,
+    })
+}
+
+/// The [`WorkflowMetadata`] handed to loaders for the package stored at
+/// `registry_id`.
+fn loaded_workflow_metadata(
+    registry_id: &str,
+    package_metadata: &crate::registry::loader::package_loader::PackageMetadata,
+) -> Result<WorkflowMetadata, RegistryError> {
+    Ok(WorkflowMetadata {
+        id: Uuid::new_v4(), // This should be the actual package ID from the database
+        registry_id: Uuid::parse_str(registry_id).map_err(RegistryError::InvalidUuid)?,
+        workflow_name: if package_metadata.workflow_name.is_empty() {
+            package_metadata.package_name.clone()
+        } else {
+            package_metadata.workflow_name.clone()
+        },
+        package_name: package_metadata.package_name.clone(),
+        version: package_metadata.version.clone(),
+        description: package_metadata.description.clone(),
+        author: package_metadata.author.clone(),
+        tasks: package_metadata
+            .tasks
+            .iter()
+            .map(|t| t.local_id.clone())
+            .collect(),
+        task_graph: database::build_task_graph(package_metadata),
+        schedules: Vec::new(),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+        // This load path is for execution, not the pause gate; the gate
+        // reads paused via the list/inspect paths. (CLOACI-T-0749)
+        paused: false,
+        declared_params: package_metadata.declared_params.clone(),
+        declared_surfaces: package_metadata.declared_surfaces.clone(),
+        workflow_triggers: package_metadata.workflow_triggers.clone(),
+        provenance: package_metadata.provenance.clone(),
+        config: package_metadata.config.clone(),
+    })
+}
+
 /// Unpack a `.cloacina` source archive in a temp dir and collect its UTF-8
 /// text files for display (CLOACI-T-0750). Binary, oversized, and unreadable
 /// files are skipped; the result is sorted by path. The temp dir is removed
@@ -0,0 +1,474 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Post-mortem replay of historical executions.
+//!
+//! Every task persists its output context, and every execution its initial
+//! context. An [`ExecutionRecording`] gathers both into one serializable
+//! snapshot of a past run; a [`ReplayRunner`] pairs that snapshot with a
+//! locally built [`Workflow`] so a developer can:
+//!
+//! - step through the run task by task, in the order tasks started;
+//! - see the exact input each task received, rebuilt the same way the
+//!   executors build it (initial context for roots, merged dependency
+//!   outputs otherwise), and what it changed;
+//! - re-execute a single task in-process — optionally with modified inputs —
+//!   and compare the result against what was recorded.
+//!
+//! Nothing a replay does touches the database: recordings are read once and
+//! re-executions run against the local task implementation only.
+//!
+//! ```rust,ignore
+//! use cloacina::replay::{ExecutionRecording, ReplayRunner};
+//!
+//! let recording = ExecutionRecording::load(&dal, execution_id).await?;
+//! let mut replay = ReplayRunner::new(recording, my_workflow());
+//! while let Some(step) = replay.current() {
+//!     println!("{} {}: {:?}", step.task_name, step.status, replay.diff(step.task_id())?);
+//!     replay.step_forward();
+//! }
+//! let outcome = replay
+//!     .rerun("transform", [("threshold".to_string(), serde_json::json!(0.5))])
+//!     .await?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::dal::DAL;
+use crate::database::universal_types::UniversalUuid;
+use crate::error::{ContextError, TaskError, ValidationError};
+use crate::executor::TaskContextBuilder;
+use crate::task::TaskNamespace;
+use crate::{Context, TaskErrorPayload, Workflow};
+
+/// Errors raised while recording or replaying an execution.
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    /// Loading the recording from the database failed.
+    #[error("failed to load execution recording: {0}")]
+    Load(#[from] ValidationError),
+
+    /// A persisted context could not be read or rebuilt.
+    #[error("context error: {0}")]
+    Context(#[from] ContextError),
+
+    /// A recording could not be parsed.
+    #[error("invalid recording: {0}")]
+    Invalid(#[from] serde_json::Error),
+
+    /// The local workflow has no task with this id.
+    #[error("task '{0}' is not part of the local workflow")]
+    UnknownTask(String),
+
+    /// The recording has no step for this task.
+    #[error("task '{0}' did not run in the recorded execution")]
+    NotRecorded(String),
+}
+
+/// One task of a recorded execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedStep {
+    /// Fully qualified task name (`tenant::package::workflow::task`).
+    pub task_name: String,
+    /// Final status of the task (`Completed`, `Failed`, `Skipped`, ...).
+    pub status: String,
+    /// Attempt the recording was taken from.
+    pub attempt: i32,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Error message of the last failed attempt, if any.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Structured error the task failed with, if any.
+    #[serde(default)]
+    pub error_payload: Option<TaskErrorPayload>,
+    /// Output context the task persisted; `None` for tasks that never
+    /// produced one (failed, skipped, or pruned by context retention).
+    #[serde(default)]
+    pub output: Option<BTreeMap<String, Value>>,
+}
+
+impl RecordedStep {
+    /// The task's id within its workflow — the last namespace segment.
+    pub fn task_id(&self) -> &str {
+        self.task_name
+            .rsplit("::")
+            .next()
+            .unwrap_or(&self.task_name)
+    }
+}
+
+/// Snapshot of a past execution: its initial context plus every task's
+/// status and output context, ordered by start time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionRecording {
+    pub execution_id: String,
+    pub workflow_name: String,
+    pub workflow_version: String,
+    pub status: String,
+    /// Context the execution was started with.
+    #[serde(default)]
+    pub initial_context: BTreeMap<String, Value>,
+    pub steps: Vec<RecordedStep>,
+}
+
+impl ExecutionRecording {
+    /// Read a recording of `execution_id` from the database.
+    pub async fn load(dal: &DAL, execution_id: UniversalUuid) -> Result<Self, ReplayError> {
+        let execution = dal.workflow_execution().get_by_id(execution_id).await?;
+        let initial_context = match execution.context_id {
+            Some(context_id) => to_map(&dal.context().read::<Value>(context_id).await?),
+            None => BTreeMap::new(),
+        };
+
+        let mut tasks = dal
+            .task_execution()
+            .get_all_tasks_for_workflow(execution_id)
+            .await?;
+        // Start order; tasks that never started sort last, in creation order.
+        tasks.sort_by_key(|t| {
+            (
+                t.started_at.is_none(),
+                t.started_at.map(|ts| ts.0),
+                t.created_at.0,
+            )
+        });
+
+        let mut steps = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let context_id = dal
+                .task_execution_metadata()
+                .get_by_task_execution(task.id)
+                .await
+                .ok()
+                .and_then(|m| m.context_id);
+            let output = match context_id {
+                Some(id) => Some(to_map(&dal.context().read::<Value>(id).await?)),
+                None => None,
+            };
+            steps.push(RecordedStep {
+                task_name: task.task_name,
+                status: task.status,
+                attempt: task.attempt,
+                started_at: task.started_at.map(|ts| ts.0),
+                completed_at: task.completed_at.map(|ts| ts.0),
+                error: task.last_error.or(task.error_details),
+                error_payload: task.error_payload,
+                output,
+            });
+        }
+
+        Ok(Self {
+            execution_id: execution.id.0.to_string(),
+            workflow_name: execution.workflow_name,
+            workflow_version: execution.workflow_version,
+            status: execution.status,
+            initial_context,
+            steps,
+        })
+    }
+
+    /// Parse a recording previously written with [`to_json`](Self::to_json)
+    /// or fetched from the server's `/executions/{id}/recording` endpoint.
+    pub fn from_json(json: &str) -> Result<Self, ReplayError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Serialize the recording, e.g. to replay it on another machine.
+    pub fn to_json(&self) -> Result<String, ReplayError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// The recorded step for `task_id`, matched on the task's id so a
+    /// recording taken under one tenant or package replays against a
+    /// workflow built under another.
+    pub fn step(&self, task_id: &str) -> Option<&RecordedStep> {
+        self.steps.iter().find(|s| s.task_id() == task_id)
+    }
+}
+
+/// Key-level difference between two contexts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContextDiff {
+    /// Keys present only in the later context.
+    pub added: BTreeMap<String, Value>,
+    /// Keys present only in the earlier context.
+    pub removed: BTreeMap<String, Value>,
+    /// Keys present in both with different values, as `(before, after)`.
+    pub changed: BTreeMap<String, (Value, Value)>,
+}
+
+impl ContextDiff {
+    /// Compare `before` against `after`.
+    pub fn between(before: &BTreeMap<String, Value>, after: &BTreeMap<String, Value>) -> Self {
+        let mut diff = Self::default();
+        for (key, old) in before {
+            match after.get(key) {
+                None => {
+                    diff.removed.insert(key.clone(), old.clone());
+                }
+                Some(new) if new != old => {
+                    diff.changed.insert(key.clone(), (old.clone(), new.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, new) in after {
+            if !before.contains_key(key) {
+                diff.added.insert(key.clone(), new.clone());
+            }
+        }
+        diff
+    }
+
+    /// Whether the two contexts were identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Result of re-executing one task with [`ReplayRunner::rerun`].
+#[derive(Debug)]
+pub struct RerunOutcome {
+    /// Context the task was given, after overrides.
+    pub input: BTreeMap<String, Value>,
+    /// What the task returned this time.
+    pub result: Result<BTreeMap<String, Value>, TaskError>,
+    /// Difference between the recorded output and this run's output, when
+    /// both exist.
+    pub diff_from_recorded: Option<ContextDiff>,
+}
+
+/// Steps through an [`ExecutionRecording`] and re-executes its tasks against
+/// a local [`Workflow`].
+pub struct ReplayRunner {
+    recording: ExecutionRecording,
+    workflow: Workflow,
+    cursor: usize,
+}
+
+impl ReplayRunner {
+    /// Replay `recording` with the task implementations and dependency graph
+    /// of `workflow`. The cursor starts on the first recorded step.
+    pub fn new(recording: ExecutionRecording, workflow: Workflow) -> Self {
+        Self {
+            recording,
+            workflow,
+            cursor: 0,
+        }
+    }
+
+    pub fn recording(&self) -> &ExecutionRecording {
+        &self.recording
+    }
+
+    /// Index of the step under the cursor.
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// The step under the cursor; `None` once the cursor has run past the
+    /// last step.
+    pub fn current(&self) -> Option<&RecordedStep> {
+        self.recording.steps.get(self.cursor)
+    }
+
+    /// Move to the next step and return it.
+    pub fn step_forward(&mut self) -> Option<&RecordedStep> {
+        if self.cursor < self.recording.steps.len() {
+            self.cursor += 1;
+        }
+        self.current()
+    }
+
+    /// Move to the previous step and return it; stays on the first step.
+    pub fn step_back(&mut self) -> Option<&RecordedStep> {
+        self.cursor = self.cursor.saturating_sub(1);
+        self.current()
+    }
+
+    /// Move the cursor to `task_id`'s step.
+    pub fn seek(&mut self, task_id: &str) -> Result<&RecordedStep, ReplayError> {
+        let index = self
+            .recording
+            .steps
+            .iter()
+            .position(|s| s.task_id() == task_id)
+            .ok_or_else(|| ReplayError::NotRecorded(task_id.to_string()))?;
+        self.cursor = index;
+        Ok(&self.recording.steps[index])
+    }
+
+    /// The context `task_id` received in the recorded run: the initial
+    /// context for a task without dependencies, otherwise its dependencies'
+    /// recorded outputs merged the way the executors merge them.
+    pub fn input(&self, task_id: &str) -> Result<BTreeMap<String, Value>, ReplayError> {
+        let namespace = self.namespace(task_id)?;
+        let dependencies = self
+            .workflow
+            .get_data_dependencies(&namespace)
+            .map_err(|_| ReplayError::UnknownTask(task_id.to_string()))?;
+
+        if dependencies.is_empty() {
+            return Ok(self.recording.initial_context.clone());
+        }
+
+        let mut input = BTreeMap::new();
+        for dependency in dependencies {
+            let Some(output) = self
+                .recording
+                .step(&dependency.task_id)
+                .and_then(|s| s.output.as_ref())
+            else {
+                continue;
+            };
+            for (key, value) in output {
+                let merged = match input.get(key) {
+                    Some(existing) => TaskContextBuilder::merge_context_values(existing, value),
+                    None => value.clone(),
+                };
+                input.insert(key.clone(), merged);
+            }
+        }
+        Ok(input)
+    }
+
+    /// What `task_id` changed: its reconstructed input against its recorded
+    /// output.
+    pub fn diff(&self, task_id: &str) -> Result<ContextDiff, ReplayError> {
+        let step = self
+            .recording
+            .step(task_id)
+            .ok_or_else(|| ReplayError::NotRecorded(task_id.to_string()))?;
+        let input = self.input(task_id)?;
+        Ok(ContextDiff::between(
+            &input,
+            step.output.as_ref().unwrap_or(&input),
+        ))
+    }
+
+    /// Re-execute `task_id` in-process on its recorded input with
+    /// `overrides` applied on top. Nothing is persisted; the task may have
+    /// side effects of its own, so point it at safe resources.
+    pub async fn rerun(
+        &self,
+        task_id: &str,
+        overrides: impl IntoIterator<Item = (String, Value)>,
+    ) -> Result<RerunOutcome, ReplayError> {
+        let namespace = self.namespace(task_id)?;
+        let task = self
+            .workflow
+            .get_task(&namespace)
+            .map_err(|_| ReplayError::UnknownTask(task_id.to_string()))?;
+
+        let mut input = self.input(task_id)?;
+        input.extend(overrides);
+
+        let mut context = Context::new();
+        for (key, value) in &input {
+            context
+                .insert(key.clone(), value.clone())
+                .map_err(ContextError::from)?;
+        }
+
+        let result = task.execute(context).await.map(|ctx| to_map(&ctx));
+        let diff_from_recorded = match (&result, self.recording.step(task_id)) {
+            (
+                Ok(output),
+                Some(RecordedStep {
+                    output: Some(recorded),
+                    ..
+                }),
+            ) => Some(ContextDiff::between(recorded, output)),
+            _ => None,
+        };
+
+        Ok(RerunOutcome {
+            input,
+            result,
+            diff_from_recorded,
+        })
+    }
+
+    fn namespace(&self, task_id: &str) -> Result<TaskNamespace, ReplayError> {
+        self.workflow
+            .get_task_ids()
+            .into_iter()
+            .find(|ns| ns.task_id == task_id)
+            .ok_or_else(|| ReplayError::UnknownTask(task_id.to_string()))
+    }
+}
+
+fn to_map(context: &Context<Value>) -> BTreeMap<String, Value> {
+    context
+        .data()
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn map(value: Value) -> BTreeMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_keys() {
+        let diff = ContextDiff::between(
+            &map(json!({"a": 1, "b": 2, "c": 3})),
+            &map(json!({"a": 1, "b": 20, "d": 4})),
+        );
+        assert_eq!(diff.added, map(json!({"d": 4})));
+        assert_eq!(diff.removed, map(json!({"c": 3})));
+        assert_eq!(diff.changed.get("b"), Some(&(json!(2), json!(20))));
+        assert!(!diff.is_empty());
+        assert!(ContextDiff::between(&map(json!({"a": 1})), &map(json!({"a": 1}))).is_empty());
+    }
+
+    #[test]
+    fn recording_round_trips_through_json() {
+        let recording = ExecutionRecording {
+            execution_id: "e1".into(),
+            workflow_name: "wf".into(),
+            workflow_version: "v1".into(),
+            status: "Completed".into(),
+            initial_context: map(json!({"seed": 1})),
+            steps: vec![RecordedStep {
+                task_name: "public::embedded::wf::load".into(),
+                status: "Completed".into(),
+                attempt: 1,
+                started_at: None,
+                completed_at: None,
+                error: None,
+                error_payload: None,
+                output: Some(map(json!({"seed": 1, "rows": 3}))),
+            }],
+        };
+        let parsed = ExecutionRecording::from_json(&recording.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, recording);
+        assert_eq!(parsed.steps[0].task_id(), "load");
+        assert!(parsed.step("load").is_some());
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Operator annotation API for the DefaultRunner.
+//!
+//! Annotations are free-text notes with an optional resolution status that
+//! operators attach to an execution ("known outage, reran manually at 14:00"),
+//! so incident context stays with the run.
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::workflow_execution::{ExecutionAnnotation, NewExecutionAnnotation};
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Record an operator annotation on an execution
+    ///
+    /// # Arguments
+    /// * `execution_id` - Execution to annotate
+    /// * `annotation` - Author, note, and optional resolution status
+    pub async fn annotate_execution(
+        &self,
+        execution_id: UniversalUuid,
+        annotation: NewExecutionAnnotation,
+    ) -> Result<ExecutionAnnotation, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_execution()
+            .add_annotation(execution_id, annotation)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to annotate execution: {}", e),
+            })
+    }
+
+    /// Annotations on an execution, oldest first
+    pub async fn get_execution_annotations(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<Vec<ExecutionAnnotation>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_execution()
+            .list_annotations(execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get execution annotations: {}", e),
+            })
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Approval gate API for the DefaultRunner.
+//!
+//! A task declared with `#[task(approval = true)]` (or
+//! [`FnTask::approval`](crate::FnTask::approval)) is parked as
+//! `AwaitingApproval` once its dependencies and trigger rules are satisfied,
+//! holding up everything downstream of it. [`DefaultRunner::approve_task`]
+//! lets it run; [`DefaultRunner::reject_task`] fails it without running.
+//! Every decision is persisted with its approver and comment.
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::task_execution::{ApprovalDecision, TaskApproval, TaskExecution};
+use crate::models::workflow_execution::{MAX_ANNOTATION_AUTHOR_LEN, MAX_ANNOTATION_NOTE_LEN};
+use crate::task::TaskNamespace;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Approve a task that is awaiting approval; it runs on the next
+    /// scheduler tick
+    ///
+    /// # Arguments
+    /// * `execution_id` - Execution the task belongs to
+    /// * `task` - Task id (`deploy`) or fully qualified task name
+    /// * `approver` - Who approved the task
+    /// * `comment` - Optional note recorded with the decision
+    pub async fn approve_task(
+        &self,
+        execution_id: UniversalUuid,
+        task: &str,
+        approver: &str,
+        comment: Option<&str>,
+    ) -> Result<TaskApproval, WorkflowExecutionError> {
+        self.decide_task(
+            execution_id,
+            task,
+            ApprovalDecision::Approved,
+            approver,
+            comment,
+        )
+        .await
+    }
+
+    /// Reject a task that is awaiting approval; it fails without running
+    /// and is not retried
+    ///
+    /// Arguments are as for [`DefaultRunner::approve_task`].
+    pub async fn reject_task(
+        &self,
+        execution_id: UniversalUuid,
+        task: &str,
+        approver: &str,
+        comment: Option<&str>,
+    ) -> Result<TaskApproval, WorkflowExecutionError> {
+        self.decide_task(
+            execution_id,
+            task,
+            ApprovalDecision::Rejected,
+            approver,
+            comment,
+        )
+        .await
+    }
+
+    /// Approval decisions recorded on an execution, oldest first
+    pub async fn get_task_approvals(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<Vec<TaskApproval>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.task_execution()
+            .list_approvals(execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get task approvals: {}", e),
+            })
+    }
+
+    /// Tasks currently awaiting approval across all executions, longest
+    /// waiting first
+    pub async fn get_pending_approvals(
+        &self,
+    ) -> Result<Vec<TaskExecution>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.task_execution()
+            .get_awaiting_approval()
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get pending approvals: {}", e),
+            })
+    }
+
+    async fn decide_task(
+        &self,
+        execution_id: UniversalUuid,
+        task: &str,
+        decision: ApprovalDecision,
+        approver: &str,
+        comment: Option<&str>,
+    ) -> Result<TaskApproval, WorkflowExecutionError> {
+        let invalid = |message: String| WorkflowExecutionError::Configuration { message };
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to record approval decision: {}", e),
+        };
+
+        if approver.trim().is_empty() {
+            return Err(invalid("approver may not be empty".to_string()));
+        }
+        if approver.chars().count() > MAX_ANNOTATION_AUTHOR_LEN {
+            return Err(invalid(format!(
+                "approver exceeds {} characters",
+                MAX_ANNOTATION_AUTHOR_LEN
+            )));
+        }
+        if comment.is_some_and(|c| c.chars().count() > MAX_ANNOTATION_NOTE_LEN) {
+            return Err(invalid(format!(
+                "comment exceeds {} characters",
+                MAX_ANNOTATION_NOTE_LEN
+            )));
+        }
+
+        let dal = DAL::new(self.database.clone());
+        let execution = dal
+            .workflow_execution()
+            .get_by_id(execution_id)
+            .await
+            .map_err(map_err)?;
+        if execution.status == "Cancelled" {
+            return Err(invalid(format!(
+                "Execution {} was cancelled; its tasks cannot be approved or rejected",
+                execution_id
+            )));
+        }
+
+        let tasks = dal
+            .task_execution()
+            .get_all_tasks_for_workflow(execution_id)
+            .await
+            .map_err(map_err)?;
+        let target = tasks
+            .iter()
+            .find(|t| {
+                t.task_name == task
+                    || TaskNamespace::from_string(&t.task_name).is_ok_and(|ns| ns.task_id == task)
+            })
+            .ok_or_else(|| invalid(format!("Execution has no task '{}'", task)))?;
+        if target.status != "AwaitingApproval" {
+            return Err(invalid(format!(
+                "Task '{}' is {}, not AwaitingApproval",
+                target.task_name, target.status
+            )));
+        }
+
+        dal.task_execution()
+            .decide_approval(target.id, decision, approver, comment)
+            .await
+            .map_err(map_err)?
+            .ok_or_else(|| {
+                invalid(format!(
+                    "Task '{}' is no longer awaiting approval",
+                    target.task_name
+                ))
+            })
+    }
+}
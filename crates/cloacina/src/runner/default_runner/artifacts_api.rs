@@ -0,0 +1,60 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Run artifact API for the DefaultRunner.
+//!
+//! Tasks store named output files for their execution with
+//! [`TaskHandle::store_artifact`](crate::executor::TaskHandle::store_artifact);
+//! these methods list and download them. See [`crate::artifacts`].
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::workflow_execution::ExecutionArtifact;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Artifacts stored by an execution's tasks, oldest first
+    pub async fn get_execution_artifacts(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<Vec<ExecutionArtifact>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.execution_artifact()
+            .list(execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get execution artifacts: {}", e),
+            })
+    }
+
+    /// An execution's artifact `name` with its bytes, or `None` when the
+    /// execution has no such artifact
+    pub async fn read_execution_artifact(
+        &self,
+        execution_id: UniversalUuid,
+        name: &str,
+    ) -> Result<Option<(ExecutionArtifact, Vec<u8>)>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.execution_artifact()
+            .read(execution_id, name)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to read execution artifact '{}': {}", name, e),
+            })
+    }
+}
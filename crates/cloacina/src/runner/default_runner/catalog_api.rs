@@ -0,0 +1,46 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Workflow catalog API for the DefaultRunner.
+//!
+//! [`DefaultRunner::workflow_catalog`] describes every workflow this
+//! runner's runtime can execute, including workflows loaded from packages
+//! by the registry reconciler.
+
+use crate::catalog::WorkflowCatalog;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Workflows this runner can execute, with their tasks, dependencies,
+    /// retry policies and declared params
+    ///
+    /// When the workflow registry is enabled, packaged workflows also carry
+    /// their package version, trigger subscriptions and pause state.
+    pub async fn workflow_catalog(&self) -> Result<WorkflowCatalog, WorkflowExecutionError> {
+        let mut catalog = WorkflowCatalog::from_runtime(&self.runtime);
+        if let Some(registry) = self.get_workflow_registry().await {
+            let packages = registry.list_workflows().await.map_err(|e| {
+                WorkflowExecutionError::ExecutionFailed {
+                    message: format!("Failed to list workflow packages: {}", e),
+                }
+            })?;
+            catalog.merge_packages(&packages);
+        }
+        Ok(catalog)
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Child workflow execution for the DefaultRunner.
+//!
+//! Runs one workflow on behalf of another, moving context between the two
+//! according to a [`ContextInheritance`] policy rather than copying it wholesale.
+
+use crate::context_inheritance::ContextInheritance;
+use crate::executor::workflow_executor::{
+    WorkflowExecutionError, WorkflowExecutionResult, WorkflowExecutor, WorkflowStatus,
+};
+use crate::Context;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Execute `workflow_name` as a child of `parent` and wait for it
+    ///
+    /// The child starts with the parent keys admitted by the policy's inbound
+    /// side. When the child completes, the keys admitted by the outbound side
+    /// are written back into `parent` (overwriting same-named keys). A failed
+    /// child leaves `parent` untouched.
+    ///
+    /// For chains, feed one run's `final_context` as the next run's `parent`.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the child workflow to execute
+    /// * `parent` - The parent context to draw from and merge back into
+    /// * `policy` - Which keys flow in each direction
+    pub async fn execute_child(
+        &self,
+        workflow_name: &str,
+        parent: &mut Context<serde_json::Value>,
+        policy: &ContextInheritance,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        let child_context =
+            policy
+                .child_context(parent)
+                .map_err(|e| WorkflowExecutionError::Configuration {
+                    message: format!(
+                        "Failed to build context for child workflow '{}': {}",
+                        workflow_name, e
+                    ),
+                })?;
+
+        let result = self.execute(workflow_name, child_context).await?;
+
+        if result.status == WorkflowStatus::Completed {
+            policy
+                .merge_back(&result.final_context, parent)
+                .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                    message: format!(
+                        "Failed to merge child workflow '{}' context into parent: {}",
+                        workflow_name, e
+                    ),
+                })?;
+        }
+
+        Ok(result)
+    }
+}
@@ -19,14 +19,24 @@
 //! This module contains the configuration structs and builders for
 //! configuring the DefaultRunner's behavior.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
 
+use crate::artifacts::ArtifactStore;
+use crate::context_format::ContextFormat;
+use crate::context_offload::ContextOffload;
+use crate::dal::{FilesystemRegistryStorage, UnifiedRegistryStorage};
 use crate::dispatcher::{DefaultDispatcher, Dispatcher, TaskExecutor};
 use crate::executor::types::ExecutorConfig;
 use crate::executor::workflow_executor::WorkflowExecutionError;
 use crate::executor::ThreadTaskExecutor;
+use crate::extensions::{Extension, ExtensionRegistrar, InstalledExtensions};
+use crate::registry::gc::RegistryGcPolicy;
+use crate::registry::loader::CapabilityPolicy;
+use crate::registry::RegistryStorage;
+use crate::task::WorkloadClass;
 use crate::Database;
 use crate::Runtime;
 use crate::TaskScheduler;
@@ -83,6 +93,7 @@ pub struct DefaultRunnerConfig {
     enable_trigger_scheduling: bool,
     trigger_base_poll_interval: Duration,
     trigger_poll_timeout: Duration,
+    trigger_lease_ttl: Option<Duration>,
     enable_registry_reconciler: bool,
     registry_reconcile_interval: Duration,
     registry_enable_startup_reconciliation: bool,
@@ -96,10 +107,70 @@ pub struct DefaultRunnerConfig {
     /// when the existence check fails. Mirrors `cloacina-server`'s
     /// `--verification-org-id`.
     verification_org_id: Option<crate::UniversalUuid>,
+    /// Per-tenant signature trust lists (tenant id → trusted org),
+    /// forwarded to the reconciler.
+    tenant_trust_orgs: HashMap<String, crate::UniversalUuid>,
+    /// Database holding package signatures and trusted keys that the
+    /// reconciler verifies packages against. `None` uses the runner's own
+    /// database.
+    signature_trust_store: Option<Database>,
+    /// What packages may request in `[metadata.capabilities]`; forwarded
+    /// to the reconciler.
+    capability_policy: CapabilityPolicy,
     enable_claiming: bool,
     heartbeat_interval: Duration,
     stale_claim_sweep_interval: Duration,
     stale_claim_threshold: Duration,
+    /// How long a finished execution keeps its context payloads. `None`
+    /// (the default) keeps them as long as the execution itself.
+    context_retention: Option<Duration>,
+    context_retention_sweep_interval: Duration,
+    /// Top-level context values larger than this many bytes (as JSON) are
+    /// written to `context_offload_backend` and replaced by a reference.
+    /// `None` (the default) keeps every value inline.
+    context_offload_threshold: Option<usize>,
+    context_offload_backend: String,
+    context_offload_path: Option<std::path::PathBuf>,
+    /// Largest context, after offloading, that may be stored. `None` (the
+    /// default) is unlimited.
+    max_context_bytes: Option<usize>,
+    /// Format new and updated contexts are stored in. JSON by default; rows
+    /// in other formats stay readable.
+    context_format: ContextFormat,
+    /// Blob store run artifacts are written to; `"database"` by default.
+    artifact_backend: String,
+    artifact_path: Option<std::path::PathBuf>,
+    /// Record the context keys each task reads and writes so hidden data
+    /// couplings between tasks can be surfaced. Off by default.
+    trace_context_access: bool,
+    /// Record which task changed each context key, and the previous value,
+    /// so overwrites can be traced. Off by default.
+    track_context_lineage: bool,
+    /// Per-class caps on concurrently running tasks, within
+    /// `max_concurrent_tasks`. Classes not listed are only bound by the
+    /// overall limit.
+    workload_limits: HashMap<WorkloadClass, usize>,
+    /// Named resource pools and their capacities. A task naming a pool in
+    /// its `resources` holds one of the pool's slots while it runs.
+    resource_pools: HashMap<String, usize>,
+    /// Per-workflow caps on concurrently running executions, by workflow
+    /// name. Overrides the workflow's own `max_active_runs`.
+    execution_limits: HashMap<String, usize>,
+    /// Which package versions registry garbage collection keeps. `None`
+    /// (the default) keeps every version.
+    registry_gc: Option<RegistryGcPolicy>,
+    registry_gc_interval: Duration,
+    registry_gc_dry_run: bool,
+    /// Run WAL checkpoints, `PRAGMA optimize`, incremental vacuum and
+    /// busy-timeout tuning on SQLite databases. Ignored on PostgreSQL.
+    enable_sqlite_maintenance: bool,
+    sqlite_maintenance_interval: Duration,
+    /// Register this runner in `runner_instances` and drain when a successor
+    /// asks it to (blue/green handoff).
+    enable_handoff: bool,
+    /// Ask every other live runner to drain on startup.
+    handoff_takeover: bool,
+    handoff_heartbeat_interval: Duration,
     runner_id: Option<String>,
     runner_name: Option<String>,
     /// Executor key every task is dispatched to (CLOACI-T-0640). `"default"`
@@ -205,6 +276,12 @@ impl DefaultRunnerConfig {
         self.trigger_poll_timeout
     }
 
+    /// Lease duration for sharing trigger polling with other runners
+    /// (`None` polls every trigger on this runner).
+    pub fn trigger_lease_ttl(&self) -> Option<Duration> {
+        self.trigger_lease_ttl
+    }
+
     /// Whether the registry reconciler is enabled.
     pub fn enable_registry_reconciler(&self) -> bool {
         self.enable_registry_reconciler
@@ -226,7 +303,7 @@ impl DefaultRunnerConfig {
     }
 
     /// CLOACI-T-0571: when true, the reconciler refuses to load packages
-    /// with no matching `package_signatures` row.
+    /// that are not signed by a key on the tenant's trust list.
     pub fn require_signatures(&self) -> bool {
         self.require_signatures
     }
@@ -248,6 +325,22 @@ impl DefaultRunnerConfig {
         self.verification_org_id
     }
 
+    /// Per-tenant signature trust lists forwarded to the reconciler.
+    pub fn tenant_trust_orgs(&self) -> &HashMap<String, crate::UniversalUuid> {
+        &self.tenant_trust_orgs
+    }
+
+    /// Database the reconciler verifies package signatures against.
+    pub fn signature_trust_store(&self) -> Option<&Database> {
+        self.signature_trust_store.as_ref()
+    }
+
+    /// Deployment policy that package capability manifests are validated
+    /// against at registration.
+    pub fn capability_policy(&self) -> &CapabilityPolicy {
+        &self.capability_policy
+    }
+
     /// Registry storage backend type.
     pub fn registry_storage_backend(&self) -> &str {
         &self.registry_storage_backend
@@ -273,6 +366,135 @@ impl DefaultRunnerConfig {
         self.stale_claim_threshold
     }
 
+    /// How long a finished execution keeps its contexts, if limited.
+    pub fn context_retention(&self) -> Option<Duration> {
+        self.context_retention
+    }
+
+    /// Interval for the context retention sweep (only when
+    /// `context_retention` is set).
+    pub fn context_retention_sweep_interval(&self) -> Duration {
+        self.context_retention_sweep_interval
+    }
+
+    /// Size above which context values are offloaded, if offloading is on.
+    pub fn context_offload_threshold(&self) -> Option<usize> {
+        self.context_offload_threshold
+    }
+
+    /// Blob store offloaded context values are written to.
+    pub fn context_offload_backend(&self) -> &str {
+        &self.context_offload_backend
+    }
+
+    /// Directory for offloaded context values (filesystem backend).
+    pub fn context_offload_path(&self) -> Option<&std::path::Path> {
+        self.context_offload_path.as_deref()
+    }
+
+    /// Largest context that may be stored, if limited.
+    pub fn max_context_bytes(&self) -> Option<usize> {
+        self.max_context_bytes
+    }
+
+    /// Format contexts are stored in.
+    pub fn context_format(&self) -> ContextFormat {
+        self.context_format
+    }
+
+    /// Blob store run artifacts are written to.
+    pub fn artifact_backend(&self) -> &str {
+        &self.artifact_backend
+    }
+
+    /// Directory for run artifacts (filesystem backend).
+    pub fn artifact_path(&self) -> Option<&std::path::Path> {
+        self.artifact_path.as_deref()
+    }
+
+    /// Whether the executor records the context keys each task reads and
+    /// writes.
+    pub fn trace_context_access(&self) -> bool {
+        self.trace_context_access
+    }
+
+    /// Whether the executor records the context keys each task changes.
+    pub fn track_context_lineage(&self) -> bool {
+        self.track_context_lineage
+    }
+
+    /// How many tasks of `class` may run at once, if capped.
+    pub fn workload_limit(&self, class: WorkloadClass) -> Option<usize> {
+        self.workload_limits.get(&class).copied()
+    }
+
+    /// Per-class concurrency caps.
+    pub fn workload_limits(&self) -> &HashMap<WorkloadClass, usize> {
+        &self.workload_limits
+    }
+
+    /// Capacity of the named resource pool, if defined.
+    pub fn resource_pool(&self, name: &str) -> Option<usize> {
+        self.resource_pools.get(name).copied()
+    }
+
+    /// Resource pools and their capacities.
+    pub fn resource_pools(&self) -> &HashMap<String, usize> {
+        &self.resource_pools
+    }
+
+    /// How many executions of `workflow_name` may run at once, if this
+    /// runner caps it.
+    pub fn max_concurrent_executions(&self, workflow_name: &str) -> Option<usize> {
+        self.execution_limits.get(workflow_name).copied()
+    }
+
+    /// Per-workflow execution caps, by workflow name.
+    pub fn execution_limits(&self) -> &HashMap<String, usize> {
+        &self.execution_limits
+    }
+
+    /// Registry garbage-collection policy, if enabled.
+    pub fn registry_gc(&self) -> Option<&RegistryGcPolicy> {
+        self.registry_gc.as_ref()
+    }
+
+    /// Interval between registry garbage-collection passes (only when
+    /// `registry_gc` is set).
+    pub fn registry_gc_interval(&self) -> Duration {
+        self.registry_gc_interval
+    }
+
+    /// Whether registry garbage collection only reports what it would delete.
+    pub fn registry_gc_dry_run(&self) -> bool {
+        self.registry_gc_dry_run
+    }
+
+    /// Whether the SQLite maintenance service runs (SQLite only).
+    pub fn enable_sqlite_maintenance(&self) -> bool {
+        self.enable_sqlite_maintenance
+    }
+
+    /// Interval between SQLite maintenance ticks.
+    pub fn sqlite_maintenance_interval(&self) -> Duration {
+        self.sqlite_maintenance_interval
+    }
+
+    /// Whether this runner takes part in blue/green handoff.
+    pub fn enable_handoff(&self) -> bool {
+        self.enable_handoff
+    }
+
+    /// Whether this runner asks live predecessors to drain on startup.
+    pub fn handoff_takeover(&self) -> bool {
+        self.handoff_takeover
+    }
+
+    /// How often the runner heartbeats its handoff registry row.
+    pub fn handoff_heartbeat_interval(&self) -> Duration {
+        self.handoff_heartbeat_interval
+    }
+
     /// Optional runner identifier for logging.
     pub fn runner_id(&self) -> Option<&str> {
         self.runner_id.as_deref()
@@ -325,6 +547,7 @@ impl Default for DefaultRunnerConfigBuilder {
                 enable_trigger_scheduling: true,
                 trigger_base_poll_interval: Duration::from_secs(1),
                 trigger_poll_timeout: Duration::from_secs(30),
+                trigger_lease_ttl: None,
                 enable_registry_reconciler: true,
                 registry_reconcile_interval: Duration::from_secs(5),
                 registry_enable_startup_reconciliation: true,
@@ -332,10 +555,35 @@ impl Default for DefaultRunnerConfigBuilder {
                 registry_storage_backend: "filesystem".to_string(),
                 require_signatures: false,
                 verification_org_id: None,
+                tenant_trust_orgs: HashMap::new(),
+                signature_trust_store: None,
+                capability_policy: CapabilityPolicy::default(),
                 enable_claiming: true,
                 heartbeat_interval: Duration::from_secs(10),
                 stale_claim_sweep_interval: Duration::from_secs(30),
                 stale_claim_threshold: Duration::from_secs(60),
+                context_retention: None,
+                context_retention_sweep_interval: Duration::from_secs(60 * 60),
+                context_offload_threshold: None,
+                context_offload_backend: "database".to_string(),
+                context_offload_path: None,
+                max_context_bytes: None,
+                context_format: ContextFormat::Json,
+                artifact_backend: "database".to_string(),
+                artifact_path: None,
+                trace_context_access: false,
+                track_context_lineage: false,
+                workload_limits: HashMap::new(),
+                resource_pools: HashMap::new(),
+                execution_limits: HashMap::new(),
+                registry_gc: None,
+                registry_gc_interval: Duration::from_secs(60 * 60),
+                registry_gc_dry_run: false,
+                enable_sqlite_maintenance: true,
+                sqlite_maintenance_interval: Duration::from_secs(60),
+                enable_handoff: false,
+                handoff_takeover: false,
+                handoff_heartbeat_interval: Duration::from_secs(10),
                 runner_id: None,
                 runner_name: None,
                 default_executor: "default".to_string(),
@@ -448,6 +696,15 @@ impl DefaultRunnerConfigBuilder {
         self
     }
 
+    /// Shares trigger polling with the other runners on the same database:
+    /// each trigger is polled by the runner holding its lease, and the
+    /// triggers of a runner that stops renewing move to the others once its
+    /// leases expire. `None` (the default) polls every trigger here.
+    pub fn trigger_lease_ttl(mut self, value: Option<Duration>) -> Self {
+        self.config.trigger_lease_ttl = value;
+        self
+    }
+
     /// Enables or disables the registry reconciler.
     pub fn enable_registry_reconciler(mut self, value: bool) -> Self {
         self.config.enable_registry_reconciler = value;
@@ -495,6 +752,32 @@ impl DefaultRunnerConfigBuilder {
         self
     }
 
+    /// Sets the per-tenant signature trust lists (tenant id → the org whose
+    /// trusted keys that tenant's packages must be signed by). Tenants not
+    /// listed use `verification_org_id`.
+    pub fn tenant_trust_orgs(mut self, value: HashMap<String, crate::UniversalUuid>) -> Self {
+        self.config.tenant_trust_orgs = value;
+        self
+    }
+
+    /// Sets the database holding package signatures and trusted keys. With
+    /// `require_signatures` on, the reconciler fully verifies every package
+    /// against it before loading; `None` (the default) uses the runner's own
+    /// database. The server points per-tenant runners at the admin database,
+    /// where signatures and trust lists live.
+    pub fn signature_trust_store(mut self, value: Option<Database>) -> Self {
+        self.config.signature_trust_store = value;
+        self
+    }
+
+    /// Sets the policy package capability manifests are validated against.
+    /// Packages requesting network hosts, filesystem paths, secrets or
+    /// environment variables outside it are rejected at registration.
+    pub fn capability_policy(mut self, value: CapabilityPolicy) -> Self {
+        self.config.capability_policy = value;
+        self
+    }
+
     /// Sets the runner identifier.
     pub fn runner_id(mut self, value: Option<String>) -> Self {
         self.config.runner_id = value;
@@ -542,6 +825,196 @@ impl DefaultRunnerConfigBuilder {
         self
     }
 
+    /// Sets how long a finished execution keeps its context payloads (its
+    /// input context and every task's output context). Past that, a
+    /// background sweeper deletes the payloads while the execution's status,
+    /// timings, task rows and events stay. `None` (the default) disables the
+    /// sweeper.
+    pub fn context_retention(mut self, value: Option<Duration>) -> Self {
+        self.config.context_retention = value;
+        self
+    }
+
+    /// Sets how often the context retention sweeper runs (default 1h).
+    pub fn context_retention_sweep_interval(mut self, value: Duration) -> Self {
+        self.config.context_retention_sweep_interval = value;
+        self
+    }
+
+    /// Sets the size, in bytes of JSON, above which a top-level context
+    /// value is written to the offload store instead of the `contexts`
+    /// table. The row keeps a reference and reads put the value back, so
+    /// tasks see no difference. `None` (the default) keeps every value
+    /// inline.
+    pub fn context_offload_threshold(mut self, value: Option<usize>) -> Self {
+        self.config.context_offload_threshold = value;
+        self
+    }
+
+    /// Sets where offloaded context values are stored: `"database"` (the
+    /// default; `"sqlite"` and `"postgres"` are aliases), `"filesystem"`, or
+    /// a registry storage backend registered by an extension.
+    pub fn context_offload_backend(mut self, value: impl Into<String>) -> Self {
+        self.config.context_offload_backend = value.into();
+        self
+    }
+
+    /// Sets the directory for the `"filesystem"` offload backend (default
+    /// `cloacina_context_offload` under the system temp directory).
+    pub fn context_offload_path(mut self, value: Option<std::path::PathBuf>) -> Self {
+        self.config.context_offload_path = value;
+        self
+    }
+
+    /// Sets the largest context, in bytes of JSON after offloading, that may
+    /// be stored. A task whose output is larger fails, as does starting an
+    /// execution with a larger input. `None` (the default) is unlimited.
+    pub fn max_context_bytes(mut self, value: Option<usize>) -> Self {
+        self.config.max_context_bytes = value;
+        self
+    }
+
+    /// Sets the format contexts are stored in: JSON (the default),
+    /// MessagePack or CBOR. The binary formats are smaller and faster to
+    /// parse for large numeric payloads. Existing rows keep their format and
+    /// stay readable; `cloacinactl admin convert-contexts` rewrites them.
+    pub fn context_format(mut self, value: ContextFormat) -> Self {
+        self.config.context_format = value;
+        self
+    }
+
+    /// Sets where run artifacts are stored: `"database"` (the default;
+    /// `"sqlite"` and `"postgres"` are aliases), `"filesystem"`, or a
+    /// registry storage backend registered by an extension.
+    pub fn artifact_backend(mut self, value: impl Into<String>) -> Self {
+        self.config.artifact_backend = value.into();
+        self
+    }
+
+    /// Sets the directory for the `"filesystem"` artifact backend (default
+    /// `cloacina_artifacts` under the system temp directory).
+    pub fn artifact_path(mut self, value: Option<std::path::PathBuf>) -> Self {
+        self.config.artifact_path = value;
+        self
+    }
+
+    /// Enables context access tracing. Each task attempt then records the
+    /// context keys it read and wrote, so
+    /// [`DefaultRunner::get_context_access`](super::DefaultRunner::get_context_access)
+    /// can compare them against the workflow's dependencies. Costs one extra
+    /// write per task attempt.
+    pub fn trace_context_access(mut self, value: bool) -> Self {
+        self.config.trace_context_access = value;
+        self
+    }
+
+    /// Enables context lineage tracking. Each successful task attempt then
+    /// records the top-level context keys it added, changed or removed, with
+    /// the previous and new values, so
+    /// [`DefaultRunner::get_context_lineage`](super::DefaultRunner::get_context_lineage)
+    /// can tell which task wrote each key. Costs one extra write per
+    /// successful attempt, holding the changed values.
+    pub fn track_context_lineage(mut self, value: bool) -> Self {
+        self.config.track_context_lineage = value;
+        self
+    }
+
+    /// Caps how many tasks of a [`WorkloadClass`] run at once. Tasks of the
+    /// class wait for a class slot before taking one of the runner's
+    /// `max_concurrent_tasks` slots, so a burst of CPU-bound work cannot
+    /// crowd out I/O-bound tasks. Unclassified tasks and classes without a
+    /// cap are only bound by `max_concurrent_tasks`.
+    pub fn workload_limit(mut self, class: WorkloadClass, limit: usize) -> Self {
+        self.config.workload_limits.insert(class, limit);
+        self
+    }
+
+    /// Defines a resource pool: at most `capacity` tasks that declare the
+    /// resource `name` (`#[task(resources = ["name"])]`) run at once, on top
+    /// of `max_concurrent_tasks`. Use it to keep tasks from overwhelming a
+    /// shared GPU, connection pool or rate-limited API. Resources without a
+    /// pool are not limited.
+    pub fn resource_pool(mut self, name: &str, capacity: usize) -> Self {
+        self.config
+            .resource_pools
+            .insert(name.to_string(), capacity);
+        self
+    }
+
+    /// Caps how many executions of the workflow named `workflow_name` run at
+    /// once, e.g. `1` so backups never overlap. Executions over the cap are
+    /// queued, not rejected: they stay `Pending` and start oldest first as
+    /// running ones finish. Overrides the workflow's own `max_active_runs`;
+    /// other workflows stay unrestricted.
+    pub fn max_concurrent_executions(mut self, workflow_name: &str, limit: usize) -> Self {
+        self.config
+            .execution_limits
+            .insert(workflow_name.to_string(), limit);
+        self
+    }
+
+    /// Sets the registry garbage-collection policy. A background job then
+    /// deletes superseded package versions the policy does not keep, along
+    /// with their stored archives and per-target artifacts. The active
+    /// version of a package is never collected. `None` (the default)
+    /// disables it.
+    pub fn registry_gc(mut self, value: Option<RegistryGcPolicy>) -> Self {
+        self.config.registry_gc = value;
+        self
+    }
+
+    /// Sets how often registry garbage collection runs (default 1h).
+    pub fn registry_gc_interval(mut self, value: Duration) -> Self {
+        self.config.registry_gc_interval = value;
+        self
+    }
+
+    /// Sets whether registry garbage collection only logs a report of what
+    /// it would delete (default false).
+    pub fn registry_gc_dry_run(mut self, value: bool) -> Self {
+        self.config.registry_gc_dry_run = value;
+        self
+    }
+
+    /// Sets whether the runner maintains a SQLite database in the background
+    /// (default true): WAL checkpoints sized to the write volume,
+    /// `PRAGMA optimize`, incremental vacuum, and a `busy_timeout` tuned to
+    /// the write rate. Has no effect on PostgreSQL.
+    pub fn enable_sqlite_maintenance(mut self, value: bool) -> Self {
+        self.config.enable_sqlite_maintenance = value;
+        self
+    }
+
+    /// Sets how often the SQLite maintenance service runs (default 60s).
+    pub fn sqlite_maintenance_interval(mut self, value: Duration) -> Self {
+        self.config.sqlite_maintenance_interval = value;
+        self
+    }
+
+    /// Sets whether the runner takes part in blue/green handoff. When
+    /// enabled it registers in `runner_instances`, heartbeats, and raises
+    /// [`DefaultRunner::handoff_requested`](super::DefaultRunner::handoff_requested)
+    /// once a successor asks it to drain.
+    pub fn enable_handoff(mut self, value: bool) -> Self {
+        self.config.enable_handoff = value;
+        self
+    }
+
+    /// Sets whether the runner asks every other live runner to drain when it
+    /// starts. Requires `enable_handoff`.
+    pub fn handoff_takeover(mut self, value: bool) -> Self {
+        self.config.handoff_takeover = value;
+        self
+    }
+
+    /// Sets how often the runner heartbeats and checks for a drain request
+    /// (default 10s). A successor treats a runner that missed three
+    /// heartbeats as gone.
+    pub fn handoff_heartbeat_interval(mut self, value: Duration) -> Self {
+        self.config.handoff_heartbeat_interval = value;
+        self
+    }
+
     /// Builds and validates the configuration.
     ///
     /// Returns an error if any configuration value is out of bounds.
@@ -565,6 +1038,87 @@ impl DefaultRunnerConfigBuilder {
                 self.config.stale_claim_threshold, self.config.heartbeat_interval
             )));
         }
+        if self.config.context_retention == Some(Duration::ZERO) {
+            return Err(ConfigError::Invalid(
+                "context_retention must be > 0 (use None to keep contexts)".into(),
+            ));
+        }
+        if self.config.context_retention_sweep_interval.is_zero() {
+            return Err(ConfigError::Invalid(
+                "context_retention_sweep_interval must be > 0".into(),
+            ));
+        }
+        if self.config.context_offload_threshold == Some(0) {
+            return Err(ConfigError::Invalid(
+                "context_offload_threshold must be > 0 (use None to keep values inline)".into(),
+            ));
+        }
+        if self.config.max_context_bytes == Some(0) {
+            return Err(ConfigError::Invalid(
+                "max_context_bytes must be > 0 (use None for no limit)".into(),
+            ));
+        }
+        if let Some((class, _)) = self
+            .config
+            .workload_limits
+            .iter()
+            .find(|(_, &limit)| limit == 0)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "workload limit for class '{}' must be > 0",
+                class
+            )));
+        }
+        if let Some((name, _)) = self
+            .config
+            .resource_pools
+            .iter()
+            .find(|(_, &capacity)| capacity == 0)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "capacity of resource pool '{}' must be > 0",
+                name
+            )));
+        }
+        if let Some((name, _)) = self
+            .config
+            .execution_limits
+            .iter()
+            .find(|(_, &limit)| limit == 0)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "max_concurrent_executions for workflow '{}' must be > 0",
+                name
+            )));
+        }
+        if let Some(ttl) = self.config.trigger_lease_ttl {
+            if ttl < self.config.trigger_base_poll_interval * 3 {
+                return Err(ConfigError::Invalid(format!(
+                    "trigger_lease_ttl ({:?}) must be at least 3x trigger_base_poll_interval ({:?})",
+                    ttl, self.config.trigger_base_poll_interval
+                )));
+            }
+        }
+        if self.config.registry_gc_interval.is_zero() {
+            return Err(ConfigError::Invalid(
+                "registry_gc_interval must be > 0".into(),
+            ));
+        }
+        if self.config.sqlite_maintenance_interval.is_zero() {
+            return Err(ConfigError::Invalid(
+                "sqlite_maintenance_interval must be > 0".into(),
+            ));
+        }
+        if self.config.handoff_takeover && !self.config.enable_handoff {
+            return Err(ConfigError::Invalid(
+                "handoff_takeover requires enable_handoff".into(),
+            ));
+        }
+        if self.config.handoff_heartbeat_interval.is_zero() {
+            return Err(ConfigError::Invalid(
+                "handoff_heartbeat_interval must be > 0".into(),
+            ));
+        }
         if self.config.cron_max_catchup_executions > 1000 {
             return Err(ConfigError::Invalid(format!(
                 "cron_max_catchup_executions ({}) must be <= 1000",
@@ -620,6 +1174,11 @@ pub struct DefaultRunnerBuilder {
     /// never printed. When set, the thread executor attaches it to every task
     /// context so `context.secret(...)` resolves.
     pub(super) secret_resolver: Option<Arc<dyn cloacina_workflow::secret::SecretResolver>>,
+    /// Envelope encryption for stored contexts and registry archives. Kept
+    /// off the config for the same reason as the secret resolver.
+    pub(super) at_rest_encryption: Option<Arc<crate::crypto::AtRestEncryption>>,
+    /// Extensions installed, in order, when the runner is built.
+    pub(super) extensions: Vec<Arc<dyn Extension>>,
 }
 
 impl Default for DefaultRunnerBuilder {
@@ -638,6 +1197,8 @@ impl DefaultRunnerBuilder {
             runtime: None,
             runtime_arc: None,
             secret_resolver: None,
+            at_rest_encryption: None,
+            extensions: Vec::new(),
         }
     }
 
@@ -657,6 +1218,28 @@ impl DefaultRunnerBuilder {
         self
     }
 
+    /// Encrypt execution contexts and registry package archives at rest.
+    ///
+    /// Build one from `CLOACINA_AT_REST_KEYS` with
+    /// [`AtRestEncryption::from_env`](crate::crypto::AtRestEncryption::from_env),
+    /// or around a custom [`KeyProvider`](crate::crypto::KeyProvider) for a
+    /// KMS. Rows written before it was enabled stay readable.
+    pub fn at_rest_encryption(mut self, encryption: Arc<crate::crypto::AtRestEncryption>) -> Self {
+        self.at_rest_encryption = Some(encryption);
+        self
+    }
+
+    /// Install an [`Extension`].
+    ///
+    /// Extensions register their executors, task types, trigger sources,
+    /// stream backends, registry storage, secrets provider and notifiers when
+    /// the runner is built, in the order they were added. A conflict between
+    /// two extensions fails [`build`](Self::build).
+    pub fn extension(mut self, extension: Arc<dyn Extension>) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
     /// Sets the database URL
     pub fn database_url(mut self, url: &str) -> Self {
         self.database_url = Some(url.to_string());
@@ -680,9 +1263,11 @@ impl DefaultRunnerBuilder {
 
     /// Sets a scoped [`Runtime`] for this runner.
     ///
-    /// When set, the runner (and all components it creates) will use this
-    /// runtime's registries instead of the process-global registries.
-    /// If not set, [`Runtime::from_global()`] is used as the default.
+    /// The runner (and every component it creates) resolves tasks,
+    /// workflows, triggers and graphs only through this runtime, so runners
+    /// built with separate runtimes never see each other's registrations.
+    /// Start from [`Runtime::empty`] to choose what is registered. If not set,
+    /// [`Runtime::new`] (seeded with every macro-registered entry) is used.
     pub fn runtime(mut self, runtime: Runtime) -> Self {
         self.runtime = Some(runtime);
         self
@@ -710,6 +1295,75 @@ impl DefaultRunnerBuilder {
         Ok(())
     }
 
+    /// The blob store named `backend`, chosen like the registry's: a
+    /// directory (`path`, else `default_dir` under the temp directory), the
+    /// database, or a backend an extension registered. `purpose` names the
+    /// store in errors.
+    fn blob_store(
+        backend: &str,
+        path: Option<&std::path::Path>,
+        default_dir: &str,
+        purpose: &str,
+        database: &Database,
+        extensions: &InstalledExtensions,
+    ) -> Result<Box<dyn RegistryStorage>, WorkflowExecutionError> {
+        match backend {
+            "filesystem" => {
+                let path = path
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| std::env::temp_dir().join(default_dir));
+                Ok(Box::new(FilesystemRegistryStorage::new(path).map_err(
+                    |e| WorkflowExecutionError::Configuration {
+                        message: format!("Failed to create {} storage: {}", purpose, e),
+                    },
+                )?))
+            }
+            "sqlite" | "postgres" | "database" => {
+                Ok(Box::new(UnifiedRegistryStorage::new(database.clone())))
+            }
+            backend => {
+                let factory = extensions.registry_storage(backend).ok_or_else(|| {
+                    WorkflowExecutionError::Configuration {
+                        message: format!(
+                            "Unknown {} backend: {}. Valid options: filesystem, sqlite, postgres, database, or one registered by an extension",
+                            purpose, backend
+                        ),
+                    }
+                })?;
+                factory(database).map_err(|e| WorkflowExecutionError::Configuration {
+                    message: format!("Failed to create '{}' {} storage: {}", backend, purpose, e),
+                })
+            }
+        }
+    }
+
+    /// The context offloading `config` asks for.
+    fn context_offload(
+        config: &DefaultRunnerConfig,
+        database: &Database,
+        extensions: &InstalledExtensions,
+    ) -> Result<Option<Arc<ContextOffload>>, WorkflowExecutionError> {
+        if config.context_offload_threshold().is_none() && config.max_context_bytes().is_none() {
+            return Ok(None);
+        }
+        let mut offload = ContextOffload::new();
+        if let Some(threshold) = config.context_offload_threshold() {
+            let store = Self::blob_store(
+                config.context_offload_backend(),
+                config.context_offload_path(),
+                "cloacina_context_offload",
+                "context offload",
+                database,
+                extensions,
+            )?;
+            offload = offload.with_store(store, threshold);
+        }
+        if let Some(limit) = config.max_context_bytes() {
+            offload = offload.with_max_context_bytes(limit);
+        }
+        Ok(Some(Arc::new(offload)))
+    }
+
     /// Builds the DefaultRunner
     pub async fn build(self) -> Result<DefaultRunner, WorkflowExecutionError> {
         let database_url =
@@ -734,12 +1388,15 @@ impl DefaultRunnerBuilder {
         }
 
         // Create the database with schema support
-        let database = Database::new_with_schema(
+        let mut database = Database::new_with_schema(
             &database_url,
             "cloacina",
             self.config.db_pool_size(),
             self.schema.as_deref(),
         );
+        if let Some(encryption) = self.at_rest_encryption.clone() {
+            database = database.with_at_rest_encryption(encryption);
+        }
 
         // Set up schema if specified (PostgreSQL only)
         #[cfg(feature = "postgres")]
@@ -775,6 +1432,44 @@ impl DefaultRunnerBuilder {
             .runtime_arc
             .unwrap_or_else(|| Arc::new(self.runtime.unwrap_or_default()));
 
+        let mut registrar = ExtensionRegistrar::default();
+        for extension in &self.extensions {
+            registrar.install(extension.as_ref()).map_err(|e| {
+                WorkflowExecutionError::Configuration {
+                    message: e.to_string(),
+                }
+            })?;
+        }
+        registrar.apply_to_runtime(&runtime);
+        let extension_resolver = registrar.secret_resolver.take();
+        if self.secret_resolver.is_some() && extension_resolver.is_some() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: "Set a secret resolver on the builder or in an extension, not both"
+                    .to_string(),
+            });
+        }
+        let secret_resolver = self.secret_resolver.clone().or(extension_resolver);
+        let installed = registrar.installed();
+        if let Some(offload) = Self::context_offload(&self.config, &database, &installed)? {
+            database = database.with_context_offload(offload);
+        }
+        database = database.with_context_format(self.config.context_format());
+        // Artifacts default to the database, which needs no store attached.
+        if !matches!(
+            self.config.artifact_backend(),
+            "sqlite" | "postgres" | "database"
+        ) {
+            let store = Self::blob_store(
+                self.config.artifact_backend(),
+                self.config.artifact_path(),
+                "cloacina_artifacts",
+                "artifact",
+                &database,
+                &installed,
+            )?;
+            database = database.with_artifact_store(Arc::new(ArtifactStore::new(store)));
+        }
+
         // Create scheduler with the scoped runtime
         let scheduler = TaskScheduler::with_poll_interval(
             database.clone(),
@@ -782,7 +1477,8 @@ impl DefaultRunnerBuilder {
         )
         .await
         .map_err(|e| WorkflowExecutionError::Executor(e.into()))?
-        .with_runtime(runtime.clone());
+        .with_runtime(runtime.clone())
+        .with_execution_limits(self.config.execution_limits().clone());
 
         // Create task executor
         let executor_config = ExecutorConfig {
@@ -790,6 +1486,10 @@ impl DefaultRunnerBuilder {
             task_timeout: self.config.task_timeout(),
             enable_claiming: self.config.enable_claiming(),
             heartbeat_interval: self.config.heartbeat_interval(),
+            trace_context_access: self.config.trace_context_access(),
+            track_context_lineage: self.config.track_context_lineage(),
+            workload_limits: self.config.workload_limits().clone(),
+            resource_pools: self.config.resource_pools().clone(),
         };
 
         // Create executor with the scoped runtime — skip with_global_registry() since
@@ -802,7 +1502,7 @@ impl DefaultRunnerBuilder {
         )
         // CLOACI-T-0858: thread the secret resolution side channel to the
         // executor so task contexts can resolve secrets in-process.
-        .with_secret_resolver(self.secret_resolver.clone());
+        .with_secret_resolver(secret_resolver);
 
         // Configure dispatcher for push-based task execution. Every task is sent
         // to the one configured executor key (CLOACI-T-0640).
@@ -810,7 +1510,11 @@ impl DefaultRunnerBuilder {
         let dispatcher = DefaultDispatcher::new(dal, self.config.default_executor());
 
         // Register the executor with the dispatcher
-        dispatcher.register_executor("default", Arc::new(executor) as Arc<dyn TaskExecutor>);
+        let local_executor = Arc::new(executor) as Arc<dyn TaskExecutor>;
+        dispatcher.register_executor("default", local_executor.clone());
+        for (key, executor) in registrar.executors.drain(..) {
+            dispatcher.register_executor(&key, executor);
+        }
 
         let scheduler = scheduler.with_dispatcher(Arc::new(dispatcher));
 
@@ -821,8 +1525,21 @@ impl DefaultRunnerBuilder {
             scheduler: Arc::new(scheduler),
             service_manager: Arc::new(RwLock::new(ServiceManager::new())),
             cron_change: Arc::new(tokio::sync::Notify::new()),
+            instance_id: crate::UniversalUuid::new_v4(),
+            handoff: Arc::new(tokio::sync::watch::channel(false).0),
+            local_executor,
+            hooks: Arc::new(super::hooks_api::LifecycleHooks::new()),
+            extensions: Arc::new(installed),
+            #[cfg(feature = "webhooks")]
+            webhooks: Arc::new(crate::webhook::WebhookRegistry::new()),
         };
 
+        for (event_type, notifier) in registrar.notifiers.drain(..) {
+            default_runner
+                .on(event_type, move |event| notifier(event))
+                .await?;
+        }
+
         // Start the background services immediately
         default_runner.start_background_services().await?;
 
@@ -900,6 +1617,252 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_context_retention_builder_setters() {
+        let def = DefaultRunnerConfig::default();
+        assert!(def.context_retention().is_none());
+        assert_eq!(
+            def.context_retention_sweep_interval(),
+            Duration::from_secs(3600)
+        );
+
+        let config = DefaultRunnerConfig::builder()
+            .context_retention(Some(Duration::from_secs(86400)))
+            .context_retention_sweep_interval(Duration::from_secs(600))
+            .build()
+            .unwrap();
+        assert_eq!(config.context_retention(), Some(Duration::from_secs(86400)));
+        assert_eq!(
+            config.context_retention_sweep_interval(),
+            Duration::from_secs(600)
+        );
+
+        assert!(DefaultRunnerConfig::builder()
+            .context_retention(Some(Duration::ZERO))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_context_offload_builder_setters() {
+        let def = DefaultRunnerConfig::default();
+        assert!(def.context_offload_threshold().is_none());
+        assert_eq!(def.context_offload_backend(), "database");
+        assert!(def.context_offload_path().is_none());
+        assert!(def.max_context_bytes().is_none());
+
+        let config = DefaultRunnerConfig::builder()
+            .context_offload_threshold(Some(64 * 1024))
+            .context_offload_backend("filesystem")
+            .context_offload_path(Some("/tmp/offload".into()))
+            .max_context_bytes(Some(1024 * 1024))
+            .build()
+            .unwrap();
+        assert_eq!(config.context_offload_threshold(), Some(64 * 1024));
+        assert_eq!(config.context_offload_backend(), "filesystem");
+        assert_eq!(
+            config.context_offload_path(),
+            Some(std::path::Path::new("/tmp/offload"))
+        );
+        assert_eq!(config.max_context_bytes(), Some(1024 * 1024));
+
+        assert!(DefaultRunnerConfig::builder()
+            .context_offload_threshold(Some(0))
+            .build()
+            .is_err());
+        assert!(DefaultRunnerConfig::builder()
+            .max_context_bytes(Some(0))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_context_format_builder_setter() {
+        assert_eq!(
+            DefaultRunnerConfig::default().context_format(),
+            ContextFormat::Json
+        );
+        let config = DefaultRunnerConfig::builder()
+            .context_format(ContextFormat::MessagePack)
+            .build()
+            .unwrap();
+        assert_eq!(config.context_format(), ContextFormat::MessagePack);
+    }
+
+    #[test]
+    fn test_artifact_builder_setters() {
+        let def = DefaultRunnerConfig::default();
+        assert_eq!(def.artifact_backend(), "database");
+        assert!(def.artifact_path().is_none());
+
+        let config = DefaultRunnerConfig::builder()
+            .artifact_backend("filesystem")
+            .artifact_path(Some("/tmp/artifacts".into()))
+            .build()
+            .unwrap();
+        assert_eq!(config.artifact_backend(), "filesystem");
+        assert_eq!(
+            config.artifact_path(),
+            Some(std::path::Path::new("/tmp/artifacts"))
+        );
+    }
+
+    #[test]
+    fn test_trace_context_access_builder_setter() {
+        assert!(!DefaultRunnerConfig::default().trace_context_access());
+        let config = DefaultRunnerConfig::builder()
+            .trace_context_access(true)
+            .build()
+            .unwrap();
+        assert!(config.trace_context_access());
+    }
+
+    #[test]
+    fn test_track_context_lineage_builder_setter() {
+        assert!(!DefaultRunnerConfig::default().track_context_lineage());
+        let config = DefaultRunnerConfig::builder()
+            .track_context_lineage(true)
+            .build()
+            .unwrap();
+        assert!(config.track_context_lineage());
+    }
+
+    #[test]
+    fn test_workload_limit_builder_setter() {
+        let def = DefaultRunnerConfig::default();
+        assert!(def.workload_limits().is_empty());
+        let config = DefaultRunnerConfig::builder()
+            .workload_limit(WorkloadClass::Cpu, 2)
+            .build()
+            .unwrap();
+        assert_eq!(config.workload_limit(WorkloadClass::Cpu), Some(2));
+        assert_eq!(config.workload_limit(WorkloadClass::Io), None);
+        assert!(DefaultRunnerConfig::builder()
+            .workload_limit(WorkloadClass::Memory, 0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_resource_pool_builder_setter() {
+        assert!(DefaultRunnerConfig::default().resource_pools().is_empty());
+        let config = DefaultRunnerConfig::builder()
+            .resource_pool("gpu", 1)
+            .resource_pool("db_connection", 4)
+            .build()
+            .unwrap();
+        assert_eq!(config.resource_pool("gpu"), Some(1));
+        assert_eq!(config.resource_pool("db_connection"), Some(4));
+        assert_eq!(config.resource_pool("api"), None);
+        assert!(DefaultRunnerConfig::builder()
+            .resource_pool("gpu", 0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_trigger_lease_ttl_builder_setter() {
+        assert_eq!(DefaultRunnerConfig::default().trigger_lease_ttl(), None);
+        let config = DefaultRunnerConfig::builder()
+            .trigger_lease_ttl(Some(Duration::from_secs(15)))
+            .build()
+            .unwrap();
+        assert_eq!(config.trigger_lease_ttl(), Some(Duration::from_secs(15)));
+        assert!(DefaultRunnerConfig::builder()
+            .trigger_base_poll_interval(Duration::from_secs(1))
+            .trigger_lease_ttl(Some(Duration::from_secs(2)))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_max_concurrent_executions_builder_setter() {
+        assert!(DefaultRunnerConfig::default().execution_limits().is_empty());
+        let config = DefaultRunnerConfig::builder()
+            .max_concurrent_executions("data_backup_workflow", 1)
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.max_concurrent_executions("data_backup_workflow"),
+            Some(1)
+        );
+        assert_eq!(config.max_concurrent_executions("etl"), None);
+        assert!(DefaultRunnerConfig::builder()
+            .max_concurrent_executions("data_backup_workflow", 0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_registry_gc_builder_setters() {
+        let def = DefaultRunnerConfig::default();
+        assert!(def.registry_gc().is_none());
+        assert_eq!(def.registry_gc_interval(), Duration::from_secs(3600));
+        assert!(!def.registry_gc_dry_run());
+
+        let policy = RegistryGcPolicy::keep_last(3).pin("etl", "1.0.0");
+        let config = DefaultRunnerConfig::builder()
+            .registry_gc(Some(policy.clone()))
+            .registry_gc_interval(Duration::from_secs(600))
+            .registry_gc_dry_run(true)
+            .build()
+            .unwrap();
+        assert_eq!(config.registry_gc(), Some(&policy));
+        assert_eq!(config.registry_gc_interval(), Duration::from_secs(600));
+        assert!(config.registry_gc_dry_run());
+
+        assert!(DefaultRunnerConfig::builder()
+            .registry_gc_interval(Duration::ZERO)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_sqlite_maintenance_builder_setters() {
+        let def = DefaultRunnerConfig::default();
+        assert!(def.enable_sqlite_maintenance());
+        assert_eq!(def.sqlite_maintenance_interval(), Duration::from_secs(60));
+
+        let config = DefaultRunnerConfig::builder()
+            .enable_sqlite_maintenance(false)
+            .sqlite_maintenance_interval(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        assert!(!config.enable_sqlite_maintenance());
+        assert_eq!(config.sqlite_maintenance_interval(), Duration::from_secs(5));
+
+        assert!(DefaultRunnerConfig::builder()
+            .sqlite_maintenance_interval(Duration::ZERO)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_handoff_builder_setters() {
+        let def = DefaultRunnerConfig::default();
+        assert!(!def.enable_handoff());
+        assert!(!def.handoff_takeover());
+        assert_eq!(def.handoff_heartbeat_interval(), Duration::from_secs(10));
+
+        let config = DefaultRunnerConfig::builder()
+            .enable_handoff(true)
+            .handoff_takeover(true)
+            .handoff_heartbeat_interval(Duration::from_secs(2))
+            .build()
+            .unwrap();
+        assert!(config.enable_handoff());
+        assert!(config.handoff_takeover());
+        assert_eq!(config.handoff_heartbeat_interval(), Duration::from_secs(2));
+
+        assert!(
+            DefaultRunnerConfig::builder()
+                .handoff_takeover(true)
+                .build()
+                .is_err(),
+            "takeover without handoff must be rejected"
+        );
+    }
+
     #[test]
     fn test_registry_storage_backend_configuration() {
         // Test filesystem backend (default)
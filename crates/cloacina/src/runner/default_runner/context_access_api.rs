@@ -0,0 +1,314 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context access report API for the DefaultRunner.
+//!
+//! With [`trace_context_access`](super::DefaultRunnerConfigBuilder::trace_context_access)
+//! enabled, the executor records the context keys each task attempt reads
+//! and writes. [`DefaultRunner::get_context_access`] returns those records
+//! for one execution together with findings from comparing them against the
+//! workflow's data dependencies: a task reading a key produced by a task it
+//! does not depend on is a hidden coupling that only works by accident of
+//! scheduling.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::task_execution::TaskContextAccess;
+use crate::task::TaskNamespace;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// Prefix of keys the runtime itself places in the context.
+const RESERVED_KEY_PREFIX: &str = "__cloacina";
+
+/// A mismatch between what tasks touched in the context and the workflow's
+/// declared dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ContextAccessFinding {
+    /// `task` read `key`, which `producer` wrote, but `producer` is not
+    /// upstream of `task` through data dependencies. Add the dependency.
+    MissingDependency {
+        task: String,
+        key: String,
+        producer: String,
+    },
+    /// `task` has a data dependency on `dependency` but read none of the keys
+    /// it wrote. The edge may only be needed for ordering.
+    UnusedDependency { task: String, dependency: String },
+}
+
+/// Context access records and findings for one execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextAccessReport {
+    pub execution_id: UniversalUuid,
+    pub workflow_name: String,
+    /// One record per traced task attempt, oldest first
+    pub tasks: Vec<TaskContextAccess>,
+    /// Findings from each task's latest traced attempt. Empty when the
+    /// runtime no longer knows the workflow.
+    pub findings: Vec<ContextAccessFinding>,
+}
+
+/// Compare traced accesses against data dependencies.
+///
+/// `data_dependencies` maps each task to the tasks whose output feeds it.
+/// Only the latest attempt of each task counts, reserved runtime keys are
+/// ignored, and a task reading its own write is not a coupling. Reads of
+/// keys no traced task wrote come from the execution's input and produce no
+/// finding.
+pub(crate) fn analyze_context_access(
+    accesses: &[TaskContextAccess],
+    data_dependencies: &HashMap<String, Vec<String>>,
+) -> Vec<ContextAccessFinding> {
+    let mut latest: BTreeMap<&str, &TaskContextAccess> = BTreeMap::new();
+    for access in accesses {
+        match latest.get(access.task_name.as_str()) {
+            Some(current) if current.attempt > access.attempt => {}
+            _ => {
+                latest.insert(&access.task_name, access);
+            }
+        }
+    }
+
+    let user_keys = |keys: &[String]| -> BTreeSet<String> {
+        keys.iter()
+            .filter(|k| !k.starts_with(RESERVED_KEY_PREFIX))
+            .cloned()
+            .collect()
+    };
+    let reads: BTreeMap<&str, BTreeSet<String>> = latest
+        .iter()
+        .map(|(task, access)| (*task, user_keys(&access.reads)))
+        .collect();
+    let writes: BTreeMap<&str, BTreeSet<String>> = latest
+        .iter()
+        .map(|(task, access)| (*task, user_keys(&access.writes)))
+        .collect();
+
+    let mut findings = Vec::new();
+    for (task, task_reads) in &reads {
+        let upstream = upstream_of(task, data_dependencies);
+        for key in task_reads {
+            if writes[task].contains(key) {
+                continue;
+            }
+            let producers: Vec<&str> = writes
+                .iter()
+                .filter(|(producer, keys)| producer != &task && keys.contains(key))
+                .map(|(producer, _)| *producer)
+                .collect();
+            if producers.iter().any(|p| upstream.contains(*p)) {
+                continue;
+            }
+            for producer in producers {
+                findings.push(ContextAccessFinding::MissingDependency {
+                    task: task.to_string(),
+                    key: key.clone(),
+                    producer: producer.to_string(),
+                });
+            }
+        }
+
+        for dependency in data_dependencies.get(*task).into_iter().flatten() {
+            let Some(dependency_writes) = writes.get(dependency.as_str()) else {
+                continue;
+            };
+            if !dependency_writes.is_empty() && dependency_writes.is_disjoint(task_reads) {
+                findings.push(ContextAccessFinding::UnusedDependency {
+                    task: task.to_string(),
+                    dependency: dependency.clone(),
+                });
+            }
+        }
+    }
+    findings
+}
+
+/// Every task reachable from `task` through data dependencies.
+fn upstream_of(task: &str, data_dependencies: &HashMap<String, Vec<String>>) -> BTreeSet<String> {
+    let mut seen = BTreeSet::new();
+    let mut stack = vec![task.to_string()];
+    while let Some(next) = stack.pop() {
+        for dependency in data_dependencies.get(&next).into_iter().flatten() {
+            if seen.insert(dependency.clone()) {
+                stack.push(dependency.clone());
+            }
+        }
+    }
+    seen
+}
+
+impl DefaultRunner {
+    /// Context keys each task of an execution read and wrote, with findings
+    ///
+    /// Records exist only for attempts run while context access tracing was
+    /// enabled. Findings compare them against the data dependencies of the
+    /// workflow registered in this runner's runtime.
+    pub async fn get_context_access(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ContextAccessReport, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to get context access: {}", e),
+        };
+
+        let execution = dal
+            .workflow_execution()
+            .get_by_id(execution_id)
+            .await
+            .map_err(map_err)?;
+        let tasks = dal
+            .task_execution()
+            .list_context_access(execution_id)
+            .await
+            .map_err(map_err)?;
+
+        let findings = match self.runtime().get_workflow(&execution.workflow_name) {
+            Some(workflow) => {
+                let data_dependencies: HashMap<String, Vec<String>> = workflow
+                    .get_task_ids()
+                    .into_iter()
+                    .map(|namespace| {
+                        let deps = workflow
+                            .get_data_dependencies(&namespace)
+                            .map(|deps| deps.iter().map(TaskNamespace::to_string).collect())
+                            .unwrap_or_default();
+                        (namespace.to_string(), deps)
+                    })
+                    .collect();
+                analyze_context_access(&tasks, &data_dependencies)
+            }
+            None => Vec::new(),
+        };
+
+        Ok(ContextAccessReport {
+            execution_id,
+            workflow_name: execution.workflow_name,
+            tasks,
+            findings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UniversalTimestamp;
+
+    fn access(task: &str, attempt: i32, reads: &[&str], writes: &[&str]) -> TaskContextAccess {
+        TaskContextAccess {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_execution_id: UniversalUuid::new_v4(),
+            task_name: task.to_string(),
+            attempt,
+            reads: reads.iter().map(|k| k.to_string()).collect(),
+            writes: writes.iter().map(|k| k.to_string()).collect(),
+            recorded_at: UniversalTimestamp::now(),
+        }
+    }
+
+    fn deps(edges: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        edges
+            .iter()
+            .map(|(task, deps)| {
+                (
+                    task.to_string(),
+                    deps.iter().map(|d| d.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_from_non_upstream_producer_is_missing_dependency() {
+        // process_data reads fetch_data's output but only depends on setup.
+        let accesses = vec![
+            access("setup", 1, &[], &["config"]),
+            access("fetch_data", 1, &["config"], &["raw"]),
+            access("process_data", 1, &["config", "raw"], &["result"]),
+        ];
+        let graph = deps(&[
+            ("setup", &[]),
+            ("fetch_data", &["setup"]),
+            ("process_data", &["setup"]),
+        ]);
+
+        let findings = analyze_context_access(&accesses, &graph);
+        assert_eq!(
+            findings,
+            vec![ContextAccessFinding::MissingDependency {
+                task: "process_data".to_string(),
+                key: "raw".to_string(),
+                producer: "fetch_data".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_transitive_upstream_and_input_reads_are_fine() {
+        let accesses = vec![
+            access("a", 1, &["input"], &["x"]),
+            access("b", 1, &["x"], &["y"]),
+            access(
+                "c",
+                1,
+                &["x", "y", "input", "__cloacina_task_config__"],
+                &[],
+            ),
+        ];
+        let graph = deps(&[("a", &[]), ("b", &["a"]), ("c", &["b"])]);
+
+        assert!(analyze_context_access(&accesses, &graph).is_empty());
+    }
+
+    #[test]
+    fn test_dependency_whose_output_is_never_read_is_unused() {
+        let accesses = vec![
+            access("a", 1, &[], &["x"]),
+            access("b", 1, &[], &["y"]),
+            access("c", 1, &["y"], &[]),
+        ];
+        let graph = deps(&[("a", &[]), ("b", &[]), ("c", &["a", "b"])]);
+
+        assert_eq!(
+            analyze_context_access(&accesses, &graph),
+            vec![ContextAccessFinding::UnusedDependency {
+                task: "c".to_string(),
+                dependency: "a".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_only_latest_attempt_counts() {
+        let accesses = vec![
+            access("a", 1, &[], &["x"]),
+            access("b", 1, &["x"], &[]),
+            access("b", 2, &[], &[]),
+        ];
+        let graph = deps(&[("a", &[]), ("b", &[])]);
+
+        assert!(analyze_context_access(&accesses, &graph).is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Context lineage API for the DefaultRunner.
+//!
+//! With [`track_context_lineage`](super::DefaultRunnerConfigBuilder::track_context_lineage)
+//! enabled, the executor records the context keys each successful task
+//! attempt changed. [`DefaultRunner::get_context_lineage`] returns those
+//! records for one execution, and the same changes grouped by key, to answer
+//! "which task overwrote `raw_data`, and what was there before".
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::context_lineage::ContextChangeKind;
+use crate::dal::DAL;
+use crate::database::universal_types::UniversalTimestamp;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::task_execution::TaskContextChanges;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// One write of a context key by a task attempt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyWrite {
+    pub task_name: String,
+    pub attempt: i32,
+    pub kind: ContextChangeKind,
+    /// Value before the task ran; `None` when added
+    pub previous: Option<Value>,
+    /// Value the task left; `None` when removed
+    pub value: Option<Value>,
+    pub recorded_at: UniversalTimestamp,
+}
+
+/// Context lineage records for one execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextLineage {
+    pub execution_id: UniversalUuid,
+    pub workflow_name: String,
+    /// One record per recorded task attempt, oldest first
+    pub tasks: Vec<TaskContextChanges>,
+    /// Every write of each key, oldest first. Keys no task wrote came from
+    /// the execution's input.
+    pub keys: BTreeMap<String, Vec<KeyWrite>>,
+}
+
+/// Group task attempts' changes by key, keeping record order.
+pub(crate) fn writes_by_key(tasks: &[TaskContextChanges]) -> BTreeMap<String, Vec<KeyWrite>> {
+    let mut keys: BTreeMap<String, Vec<KeyWrite>> = BTreeMap::new();
+    for task in tasks {
+        for change in &task.changes {
+            keys.entry(change.key.clone()).or_default().push(KeyWrite {
+                task_name: task.task_name.clone(),
+                attempt: task.attempt,
+                kind: change.kind,
+                previous: change.previous.clone(),
+                value: change.value.clone(),
+                recorded_at: task.recorded_at,
+            });
+        }
+    }
+    keys
+}
+
+impl DefaultRunner {
+    /// Which task changed each context key of an execution, with previous
+    /// values
+    ///
+    /// Records exist only for attempts run while context lineage tracking
+    /// was enabled.
+    pub async fn get_context_lineage(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ContextLineage, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to get context lineage: {}", e),
+        };
+
+        let execution = dal
+            .workflow_execution()
+            .get_by_id(execution_id)
+            .await
+            .map_err(map_err)?;
+        let tasks = dal
+            .task_execution()
+            .list_context_changes(execution_id)
+            .await
+            .map_err(map_err)?;
+
+        Ok(ContextLineage {
+            execution_id,
+            workflow_name: execution.workflow_name,
+            keys: writes_by_key(&tasks),
+            tasks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context_lineage::ContextChange;
+    use serde_json::json;
+
+    fn record(task: &str, changes: Vec<ContextChange>) -> TaskContextChanges {
+        TaskContextChanges {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_execution_id: UniversalUuid::new_v4(),
+            task_name: task.to_string(),
+            attempt: 1,
+            changes,
+            recorded_at: UniversalTimestamp::now(),
+        }
+    }
+
+    #[test]
+    fn test_writes_are_grouped_by_key_in_order() {
+        let tasks = vec![
+            record(
+                "extract",
+                vec![ContextChange {
+                    key: "raw_data".into(),
+                    kind: ContextChangeKind::Added,
+                    previous: None,
+                    value: Some(json!([1, 2])),
+                }],
+            ),
+            record(
+                "clean",
+                vec![ContextChange {
+                    key: "raw_data".into(),
+                    kind: ContextChangeKind::Modified,
+                    previous: Some(json!([1, 2])),
+                    value: Some(json!([])),
+                }],
+            ),
+        ];
+
+        let keys = writes_by_key(&tasks);
+        let writers: Vec<&str> = keys["raw_data"]
+            .iter()
+            .map(|w| w.task_name.as_str())
+            .collect();
+        assert_eq!(writers, vec!["extract", "clean"]);
+        assert_eq!(keys["raw_data"][1].previous, Some(json!([1, 2])));
+    }
+}
@@ -19,20 +19,39 @@
 //! This module provides methods for managing cron-scheduled workflow executions.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::dal::DAL;
 use crate::executor::workflow_executor::WorkflowExecutionError;
 use crate::registry::traits::WorkflowRegistry;
+use crate::registry::{ReconcileResult, ReconcilerStatus};
 use crate::UniversalUuid;
 
 use super::DefaultRunner;
 
+/// How a cron schedule spreads its runs around its cron times.
+///
+/// Each fire runs `offset` plus a per-schedule jitter of up to `jitter` after
+/// its cron time, so schedules registered on the same expression do not all
+/// start at the same instant. Both are stored in whole seconds. The fire's
+/// `scheduled_time` stays the cron time.
+#[derive(Debug, Clone, Default)]
+pub struct CronScheduleOptions {
+    /// Fixed delay after each cron time
+    pub offset: Duration,
+    /// Upper bound of the extra delay, drawn once per schedule
+    pub jitter: Duration,
+}
+
 impl DefaultRunner {
     /// Register a workflow to run on a cron schedule
     ///
     /// # Arguments
     /// * `workflow_name` - Name of the workflow to schedule
-    /// * `cron_expression` - Cron expression (e.g., "0 9 * * *" for daily at 9 AM)
+    /// * `cron_expression` - Cron expression (e.g., "0 9 * * *" for daily at 9 AM), a
+    ///   preset such as `@hourly`/`@daily`, or a phrase such as "every weekday at 9am".
+    ///   Presets and phrases are normalized and stored as canonical cron; ambiguous
+    ///   phrases are rejected (see [`normalize_cron_expression`](crate::cron_evaluator::normalize_cron_expression)).
     /// * `timezone` - Timezone for interpreting the cron expression (e.g., "UTC", "America/New_York")
     ///
     /// # Returns
@@ -56,13 +75,15 @@ impl DefaultRunner {
             });
         }
         let dal = DAL::new(self.database.clone());
+        self.ensure_not_deprecated(&dal, &instance.workflow_name)
+            .await?;
+        let cron_expression = &normalize_schedule_expression(cron_expression)?;
 
         use crate::CronEvaluator;
-        CronEvaluator::validate(cron_expression, timezone).map_err(|e| {
-            WorkflowExecutionError::Configuration {
+        crate::cron_evaluator::simulate_cron(cron_expression, timezone, chrono::Utc::now(), 1)
+            .map_err(|e| WorkflowExecutionError::Configuration {
                 message: format!("Invalid cron expression or timezone: {}", e),
-            }
-        })?;
+            })?;
         let evaluator = CronEvaluator::new(cron_expression, timezone).map_err(|e| {
             WorkflowExecutionError::Configuration {
                 message: format!("Failed to create cron evaluator: {}", e),
@@ -145,11 +166,45 @@ impl DefaultRunner {
         Ok(true)
     }
 
+    /// Register a workflow to run on a cron schedule, firing on its cron
+    /// times. See [`register_cron_workflow_with_options`](Self::register_cron_workflow_with_options).
     pub async fn register_cron_workflow(
         &self,
         workflow_name: &str,
         cron_expression: &str,
         timezone: &str,
+    ) -> Result<UniversalUuid, WorkflowExecutionError> {
+        self.register_cron_workflow_with_options(
+            workflow_name,
+            cron_expression,
+            timezone,
+            CronScheduleOptions::default(),
+        )
+        .await
+    }
+
+    /// Register a workflow to run on a cron schedule, with a fire offset and
+    /// jitter
+    ///
+    /// For example `*/5 * * * *` with a `jitter` of 30 seconds runs every
+    /// five minutes at a fixed point 0-30s past the cron time, and a second
+    /// schedule on the same expression most likely at a different point.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to schedule
+    /// * `cron_expression` - Cron expression, preset or phrase, as for
+    ///   [`register_cron_workflow`](Self::register_cron_workflow)
+    /// * `timezone` - Timezone for interpreting the cron expression
+    /// * `options` - Offset and jitter of each fire
+    ///
+    /// # Returns
+    /// * `Result<UniversalUuid, WorkflowExecutionError>` - The ID of the created schedule or an error
+    pub async fn register_cron_workflow_with_options(
+        &self,
+        workflow_name: &str,
+        cron_expression: &str,
+        timezone: &str,
+        options: CronScheduleOptions,
     ) -> Result<UniversalUuid, WorkflowExecutionError> {
         if !self.config.enable_cron_scheduling() {
             return Err(WorkflowExecutionError::Configuration {
@@ -159,14 +214,17 @@ impl DefaultRunner {
         }
 
         let dal = DAL::new(self.database.clone());
+        self.ensure_not_deprecated(&dal, workflow_name).await?;
+
+        // Presets and natural-language phrases are stored as canonical cron.
+        let cron_expression = &normalize_schedule_expression(cron_expression)?;
 
         // Validate cron expression and timezone
         use crate::CronEvaluator;
-        CronEvaluator::validate(cron_expression, timezone).map_err(|e| {
-            WorkflowExecutionError::Configuration {
+        crate::cron_evaluator::simulate_cron(cron_expression, timezone, chrono::Utc::now(), 1)
+            .map_err(|e| WorkflowExecutionError::Configuration {
                 message: format!("Invalid cron expression or timezone: {}", e),
-            }
-        })?;
+            })?;
 
         // Calculate initial next run time
         let evaluator = CronEvaluator::new(cron_expression, timezone).map_err(|e| {
@@ -175,22 +233,25 @@ impl DefaultRunner {
             }
         })?;
 
-        let now = chrono::Utc::now();
-        let next_run =
-            evaluator
-                .next_execution(now)
-                .map_err(|e| WorkflowExecutionError::Configuration {
-                    message: format!("Failed to calculate next execution: {}", e),
-                })?;
-
         // Create the schedule using unified NewSchedule
         use crate::database::universal_types::UniversalTimestamp;
         use crate::models::schedule::NewSchedule;
 
+        let now = chrono::Utc::now();
         let mut new_schedule =
-            NewSchedule::cron(workflow_name, cron_expression, UniversalTimestamp(next_run));
+            NewSchedule::cron(workflow_name, cron_expression, UniversalTimestamp(now))
+                .with_fire_spread(options.offset, options.jitter);
         new_schedule.timezone = Some(timezone.to_string());
 
+        // The first run is the first delayed cron time after now.
+        let delay = new_schedule.fire_delay();
+        let next_run = evaluator.next_execution(now - delay).map_err(|e| {
+            WorkflowExecutionError::Configuration {
+                message: format!("Failed to calculate next execution: {}", e),
+            }
+        })? + delay;
+        new_schedule.next_run_at = Some(UniversalTimestamp(next_run));
+
         let schedule = dal.schedule().create(new_schedule).await.map_err(|e| {
             WorkflowExecutionError::ExecutionFailed {
                 message: format!("Failed to create cron schedule: {}", e),
@@ -203,6 +264,66 @@ impl DefaultRunner {
         Ok(schedule.id)
     }
 
+    /// Register a workflow to run every `interval`
+    ///
+    /// For periods cron cannot express, such as every 90 seconds. Fires fall
+    /// on `anchor` plus whole multiples of `interval`; without an anchor the
+    /// interval counts from registration, and an anchor in the future holds
+    /// the first fire until then. The interval is kept in whole seconds.
+    ///
+    /// The schedule is a cron schedule without an expression, so it is
+    /// listed, enabled, paused, recovered and counted in the cron execution
+    /// statistics like any other, and follows its catchup policy.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to schedule
+    /// * `interval` - Time between fires, at least one second
+    /// * `anchor` - A fire time the interval is counted from
+    ///
+    /// # Returns
+    /// * `Result<UniversalUuid, WorkflowExecutionError>` - The ID of the created schedule or an error
+    pub async fn register_interval_workflow(
+        &self,
+        workflow_name: &str,
+        interval: Duration,
+        anchor: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<UniversalUuid, WorkflowExecutionError> {
+        if !self.config.enable_cron_scheduling() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: "Cron scheduling not enabled. Use enable_cron_scheduling(true) in config."
+                    .to_string(),
+            });
+        }
+        if interval < Duration::from_secs(1) {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!("Schedule interval must be at least 1s, got {:?}", interval),
+            });
+        }
+
+        let dal = DAL::new(self.database.clone());
+        self.ensure_not_deprecated(&dal, workflow_name).await?;
+
+        use crate::database::universal_types::UniversalTimestamp;
+        use crate::models::schedule::{IntervalSchedule, NewSchedule};
+
+        let now = chrono::Utc::now();
+        let interval = IntervalSchedule::new(interval, anchor.unwrap_or(now));
+        let next_run = interval.next_execution(now);
+        let new_schedule =
+            NewSchedule::interval(workflow_name, &interval, UniversalTimestamp(next_run));
+
+        let schedule = dal.schedule().create(new_schedule).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to create interval schedule: {}", e),
+            }
+        })?;
+
+        // Wake the timer-driven scheduler so the first fire is on time (T-0743).
+        self.cron_change.notify_one();
+
+        Ok(schedule.id)
+    }
+
     /// List all registered cron schedules
     ///
     /// # Arguments
@@ -343,12 +464,25 @@ impl DefaultRunner {
 
         let dal = DAL::new(self.database.clone());
 
+        let normalized = cron_expression
+            .map(normalize_schedule_expression)
+            .transpose()?;
+        let cron_expression = normalized.as_deref();
+
         // Get current schedule to fill in missing values
         let schedule = dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
             WorkflowExecutionError::ExecutionFailed {
                 message: format!("Failed to get cron schedule: {}", e),
             }
         })?;
+        if schedule.is_interval() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} fires on an interval and has no cron expression",
+                    schedule_id
+                ),
+            });
+        }
 
         let effective_expr = cron_expression
             .or(schedule.cron_expression.as_deref())
@@ -357,29 +491,32 @@ impl DefaultRunner {
 
         // Validate inputs if provided
         if cron_expression.is_some() || timezone.is_some() {
-            use crate::CronEvaluator;
-            CronEvaluator::validate(effective_expr, effective_tz).map_err(|e| {
-                WorkflowExecutionError::Configuration {
-                    message: format!("Invalid cron expression or timezone: {}", e),
-                }
+            crate::cron_evaluator::simulate_cron(
+                effective_expr,
+                effective_tz,
+                chrono::Utc::now(),
+                1,
+            )
+            .map_err(|e| WorkflowExecutionError::Configuration {
+                message: format!("Invalid cron expression or timezone: {}", e),
             })?;
         }
 
         // Calculate new next run time
         use crate::CronEvaluator;
-        let evaluator = CronEvaluator::new(effective_expr, effective_tz).map_err(|e| {
-            WorkflowExecutionError::Configuration {
+        let evaluator = CronEvaluator::new(effective_expr, effective_tz)
+            .map_err(|e| WorkflowExecutionError::Configuration {
                 message: format!("Failed to create cron evaluator: {}", e),
-            }
-        })?;
+            })?
+            .with_dst_policy(schedule.dst_policy());
 
         let now = chrono::Utc::now();
-        let next_run =
-            evaluator
-                .next_execution(now)
-                .map_err(|e| WorkflowExecutionError::Configuration {
-                    message: format!("Failed to calculate next execution: {}", e),
-                })?;
+        let delay = schedule.fire_delay();
+        let next_run = evaluator.next_execution(now - delay).map_err(|e| {
+            WorkflowExecutionError::Configuration {
+                message: format!("Failed to calculate next execution: {}", e),
+            }
+        })? + delay;
 
         dal.schedule()
             .update_cron_expression_and_timezone(schedule_id, cron_expression, timezone, next_run)
@@ -391,6 +528,262 @@ impl DefaultRunner {
         Ok(())
     }
 
+    /// Set what a cron schedule does with fires it missed
+    ///
+    /// [`CatchupPolicy::Skip`] (the default) drops them,
+    /// [`CatchupPolicy::RunLatestOnly`] runs once for the most recent one —
+    /// e.g. a nightly report — and [`CatchupPolicy::RunAll`] runs every
+    /// missed fire, up to `cron_max_catchup_executions` — e.g. an incremental
+    /// ingest.
+    ///
+    /// [`CatchupPolicy::Skip`]: crate::models::schedule::CatchupPolicy::Skip
+    /// [`CatchupPolicy::RunLatestOnly`]: crate::models::schedule::CatchupPolicy::RunLatestOnly
+    /// [`CatchupPolicy::RunAll`]: crate::models::schedule::CatchupPolicy::RunAll
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the cron schedule
+    /// * `policy` - The new catchup policy
+    pub async fn set_cron_catchup_policy(
+        &self,
+        schedule_id: UniversalUuid,
+        policy: crate::models::schedule::CatchupPolicy,
+    ) -> Result<(), WorkflowExecutionError> {
+        if !self.config.enable_cron_scheduling() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: "Cron scheduling not enabled.".to_string(),
+            });
+        }
+
+        let dal = DAL::new(self.database.clone());
+        let schedule = dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get cron schedule: {}", e),
+            }
+        })?;
+        if schedule.is_trigger() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} is a trigger schedule; catchup applies to cron schedules only",
+                    schedule_id
+                ),
+            });
+        }
+
+        dal.schedule()
+            .set_catchup_policy(schedule_id, policy)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set cron catchup policy: {}", e),
+            })
+    }
+
+    /// Set how a cron schedule handles daylight-saving transitions
+    ///
+    /// [`DstPolicy::Skip`] drops a fire whose wall-clock time does not exist
+    /// when clocks jump forward, [`DstPolicy::RunOnce`] (the default) moves
+    /// it to the first valid time after the gap, and both fire a repeated
+    /// wall-clock time once when clocks turn back; [`DstPolicy::RunTwice`]
+    /// fires a repeated time at both instants. The next run time is
+    /// recomputed under the new policy. Check the effect with
+    /// [`preview_schedule`](Self::preview_schedule).
+    ///
+    /// [`DstPolicy::Skip`]: crate::DstPolicy::Skip
+    /// [`DstPolicy::RunOnce`]: crate::DstPolicy::RunOnce
+    /// [`DstPolicy::RunTwice`]: crate::DstPolicy::RunTwice
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the cron schedule
+    /// * `policy` - The new DST policy
+    pub async fn set_cron_dst_policy(
+        &self,
+        schedule_id: UniversalUuid,
+        policy: crate::DstPolicy,
+    ) -> Result<(), WorkflowExecutionError> {
+        let schedule = self.get_cron_schedule(schedule_id).await?;
+        if schedule.is_trigger() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} is a trigger schedule; DST policies apply to cron schedules only",
+                    schedule_id
+                ),
+            });
+        }
+        if schedule.is_interval() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} fires on an interval and has no cron expression",
+                    schedule_id
+                ),
+            });
+        }
+
+        let expression = schedule.cron_expression.as_deref().unwrap_or("* * * * *");
+        let timezone = schedule.timezone.as_deref().unwrap_or("UTC");
+        let evaluator = crate::CronEvaluator::new(expression, timezone)
+            .map_err(|e| WorkflowExecutionError::Configuration {
+                message: format!("Failed to create cron evaluator: {}", e),
+            })?
+            .with_dst_policy(policy);
+        let delay = schedule.fire_delay();
+        let next_run = evaluator
+            .next_execution(chrono::Utc::now() - delay)
+            .map_err(|e| WorkflowExecutionError::Configuration {
+                message: format!("Failed to calculate next execution: {}", e),
+            })?
+            + delay;
+
+        let dal = DAL::new(self.database.clone());
+        dal.schedule()
+            .set_dst_policy(schedule_id, policy, next_run)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set cron DST policy: {}", e),
+            })?;
+        self.cron_change.notify_one();
+        Ok(())
+    }
+
+    /// Preview the next fire times of a cron expression without saving it
+    ///
+    /// Accepts the same presets and phrases as
+    /// [`register_cron_workflow`](Self::register_cron_workflow), so an editor
+    /// can show what an expression means before it is submitted.
+    ///
+    /// # Arguments
+    /// * `cron_expression` - Cron expression, preset, or phrase
+    /// * `timezone` - Timezone for interpreting the expression
+    /// * `count` - Number of fire times to return (at most 100)
+    pub fn preview_cron_fire_times(
+        &self,
+        cron_expression: &str,
+        timezone: &str,
+        count: usize,
+    ) -> Result<Vec<chrono::DateTime<chrono::Utc>>, WorkflowExecutionError> {
+        let expression = normalize_schedule_expression(cron_expression)?;
+        upcoming_fire_times(
+            &expression,
+            timezone,
+            crate::DstPolicy::default(),
+            chrono::Utc::now(),
+            None,
+            count,
+        )
+    }
+
+    /// Next fire times of a saved cron schedule
+    ///
+    /// Starts from now or the schedule's `start_date`, whichever is later,
+    /// and stops at its `end_date`. Disabled and paused schedules are
+    /// previewed as if they were active; check `enabled` and `paused` on the
+    /// schedule to tell whether they will actually fire.
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the schedule
+    /// * `count` - Number of fire times to return (at most 100)
+    pub async fn get_cron_schedule_fire_times(
+        &self,
+        schedule_id: UniversalUuid,
+        count: usize,
+    ) -> Result<Vec<chrono::DateTime<chrono::Utc>>, WorkflowExecutionError> {
+        let schedule = self.get_cron_schedule(schedule_id).await?;
+        let now = chrono::Utc::now();
+        let after = schedule
+            .start_date
+            .map(|start| start.0.max(now))
+            .unwrap_or(now);
+        if let Some(interval) = schedule.interval_schedule() {
+            let mut times = interval.executions_between(
+                after,
+                chrono::DateTime::<chrono::Utc>::MAX_UTC,
+                count.min(MAX_FIRE_TIME_PREVIEW),
+            );
+            if let Some(end) = schedule.end_date {
+                times.retain(|t| *t <= end.0);
+            }
+            return Ok(times);
+        }
+        let expression = schedule.cron_expression.as_deref().ok_or_else(|| {
+            WorkflowExecutionError::Configuration {
+                message: format!("Schedule {} is not a cron schedule", schedule_id),
+            }
+        })?;
+        let timezone = schedule.timezone.as_deref().unwrap_or("UTC");
+        upcoming_fire_times(
+            expression,
+            timezone,
+            schedule.dst_policy(),
+            after,
+            schedule.end_date.map(|end| end.0),
+            count,
+        )
+    }
+
+    /// Preview the next fire times of a saved schedule, with daylight-saving
+    /// detail
+    ///
+    /// Like [`get_cron_schedule_fire_times`](Self::get_cron_schedule_fire_times),
+    /// but each fire time also carries its wall-clock time in the schedule's
+    /// timezone and the [`DstEdge`](crate::cron_evaluator::DstEdge)s
+    /// affecting it, resolved under the schedule's DST policy — so a policy
+    /// can be checked before the schedule is enabled. Interval schedules
+    /// have no timezone and report UTC.
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the cron schedule
+    /// * `count` - Number of fire times to return (at most 100)
+    pub async fn preview_schedule(
+        &self,
+        schedule_id: UniversalUuid,
+        count: usize,
+    ) -> Result<Vec<crate::cron_evaluator::CronFireTime>, WorkflowExecutionError> {
+        use crate::cron_evaluator::CronFireTime;
+
+        let schedule = self.get_cron_schedule(schedule_id).await?;
+        if schedule.is_trigger() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} is a trigger schedule and has no fire times",
+                    schedule_id
+                ),
+            });
+        }
+        let now = chrono::Utc::now();
+        let after = schedule
+            .start_date
+            .map(|start| start.0.max(now))
+            .unwrap_or(now);
+        let count = count.min(MAX_FIRE_TIME_PREVIEW);
+
+        let mut fires = match schedule.interval_schedule() {
+            Some(interval) => interval
+                .executions_between(after, chrono::DateTime::<chrono::Utc>::MAX_UTC, count)
+                .into_iter()
+                .map(|at| CronFireTime {
+                    at,
+                    local: at.fixed_offset(),
+                    dst_edges: Vec::new(),
+                })
+                .collect(),
+            None => {
+                let expression = schedule.cron_expression.as_deref().unwrap_or("* * * * *");
+                let timezone = schedule.timezone.as_deref().unwrap_or("UTC");
+                crate::CronEvaluator::new(expression, timezone)
+                    .map_err(|e| WorkflowExecutionError::Configuration {
+                        message: format!("Invalid cron expression or timezone: {}", e),
+                    })?
+                    .with_dst_policy(schedule.dst_policy())
+                    .simulate(after, count)
+                    .map_err(|e| WorkflowExecutionError::Configuration {
+                        message: format!("Failed to calculate next execution: {}", e),
+                    })?
+            }
+        };
+        if let Some(end) = schedule.end_date {
+            fires.retain(|fire| fire.at <= end.0);
+        }
+        Ok(fires)
+    }
+
     /// Get execution history for a cron schedule
     ///
     /// # Arguments
@@ -460,6 +853,99 @@ impl DefaultRunner {
     pub fn is_registry_reconciler_enabled(&self) -> bool {
         self.config.enable_registry_reconciler()
     }
+
+    /// Get the registry reconciler's current status: loaded packages, the
+    /// outcome of the most recent pass and any per-package load errors.
+    ///
+    /// # Returns
+    /// * `Some(ReconcilerStatus)` - If the reconciler is enabled and initialized
+    /// * `None` - If the reconciler is not enabled or failed to initialize
+    pub async fn registry_status(&self) -> Option<ReconcilerStatus> {
+        let handle = self
+            .service_manager
+            .read()
+            .await
+            .registry_reconciler
+            .clone()?;
+        Some(handle.status().await)
+    }
+
+    /// Reconcile the workflow registry immediately instead of waiting for
+    /// the next interval tick, returning once the pass has finished. Use
+    /// this after uploading a package to make it runnable deterministically.
+    ///
+    /// Per-package load failures do not fail the call; they are reported in
+    /// `ReconcileResult::packages_failed` (and in [`registry_status`]).
+    ///
+    /// [`registry_status`]: Self::registry_status
+    ///
+    /// # Returns
+    /// * `Result<ReconcileResult, WorkflowExecutionError>` - What the pass loaded and unloaded
+    pub async fn reconcile_now(&self) -> Result<ReconcileResult, WorkflowExecutionError> {
+        let handle = self
+            .service_manager
+            .read()
+            .await
+            .registry_reconciler
+            .clone()
+            .ok_or_else(|| WorkflowExecutionError::Configuration {
+                message: "Registry reconciler not enabled.".to_string(),
+            })?;
+
+        handle
+            .reconcile_now()
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Registry reconciliation failed: {}", e),
+            })
+    }
+}
+
+/// Normalize a user-supplied schedule expression (cron, preset, or
+/// natural-language phrase) to canonical cron, surfacing failures as
+/// configuration errors.
+fn normalize_schedule_expression(expression: &str) -> Result<String, WorkflowExecutionError> {
+    let normalized = crate::cron_evaluator::normalize_cron_expression(expression).map_err(|e| {
+        WorkflowExecutionError::Configuration {
+            message: format!("Invalid schedule expression '{}': {}", expression, e),
+        }
+    })?;
+    if normalized != expression.trim() {
+        tracing::debug!(
+            "Normalized schedule expression '{}' to cron '{}'",
+            expression,
+            normalized
+        );
+    }
+    Ok(normalized)
+}
+
+/// Upper bound on fire-time previews, so a UI cannot ask for an unbounded walk.
+const MAX_FIRE_TIME_PREVIEW: usize = 100;
+
+/// Up to `count` fire times strictly after `after`, stopping at `end`.
+fn upcoming_fire_times(
+    cron_expression: &str,
+    timezone: &str,
+    dst_policy: crate::DstPolicy,
+    after: chrono::DateTime<chrono::Utc>,
+    end: Option<chrono::DateTime<chrono::Utc>>,
+    count: usize,
+) -> Result<Vec<chrono::DateTime<chrono::Utc>>, WorkflowExecutionError> {
+    let evaluator = crate::CronEvaluator::new(cron_expression, timezone)
+        .map_err(|e| WorkflowExecutionError::Configuration {
+            message: format!("Invalid cron expression or timezone: {}", e),
+        })?
+        .with_dst_policy(dst_policy);
+    let mut times = evaluator
+        .next_executions(after, count.min(MAX_FIRE_TIME_PREVIEW))
+        .map_err(|e| WorkflowExecutionError::Configuration {
+            message: format!("Failed to calculate next execution: {}", e),
+        })?;
+    if let Some(end) = end {
+        times.retain(|t| *t <= end);
+    }
+    Ok(times)
 }
 
 /// Adapter that lets the registry reconciler register/unregister cron
@@ -500,7 +986,7 @@ impl crate::registry::reconciler::CronWorkflowRegistrar for DalCronRegistrar {
         use crate::models::schedule::NewSchedule;
         use crate::CronEvaluator;
 
-        CronEvaluator::validate(cron_expression, timezone)
+        crate::cron_evaluator::simulate_cron(cron_expression, timezone, chrono::Utc::now(), 1)
             .map_err(|e| format!("Invalid cron expression or timezone: {}", e))?;
         let evaluator = CronEvaluator::new(cron_expression, timezone)
             .map_err(|e| format!("Failed to create cron evaluator: {}", e))?;
@@ -575,3 +1061,35 @@ impl crate::registry::reconciler::CronWorkflowRegistrar for DalCronRegistrar {
         Ok(schedule.id.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DstPolicy;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_upcoming_fire_times_caps_and_stops_at_end_date() {
+        let after = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+
+        let hourly =
+            upcoming_fire_times("0 * * * *", "UTC", DstPolicy::RunOnce, after, None, 500).unwrap();
+        assert_eq!(hourly.len(), MAX_FIRE_TIME_PREVIEW);
+        assert_eq!(
+            hourly[0],
+            Utc.with_ymd_and_hms(2026, 1, 1, 1, 0, 0).unwrap()
+        );
+
+        let end = Utc.with_ymd_and_hms(2026, 1, 1, 3, 0, 0).unwrap();
+        let bounded =
+            upcoming_fire_times("0 * * * *", "UTC", DstPolicy::RunOnce, after, Some(end), 10)
+                .unwrap();
+        assert_eq!(bounded.len(), 3);
+        assert_eq!(bounded.last(), Some(&end));
+
+        assert!(
+            upcoming_fire_times("0 * * * *", "Not/AZone", DstPolicy::RunOnce, after, None, 1)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Delayed execution API for the DefaultRunner.
+//!
+//! Runs a workflow once at a later time. The request is stored in the
+//! database, so it survives a restart, and the cron scheduler starts it when
+//! it falls due.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::delayed_execution::DelayedExecution;
+use crate::{Context, UniversalUuid};
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Run a workflow once at `run_at`
+    ///
+    /// A time in the past runs on the scheduler's next pass. Requires cron
+    /// scheduling, which dispatches delayed executions.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to run
+    /// * `context` - Initial context for the execution
+    /// * `run_at` - When to start the execution
+    ///
+    /// # Returns
+    /// * `Result<UniversalUuid, WorkflowExecutionError>` - ID of the delayed execution
+    pub async fn execute_at(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+        run_at: DateTime<Utc>,
+    ) -> Result<UniversalUuid, WorkflowExecutionError> {
+        if !self.config.enable_cron_scheduling() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: "Delayed executions are dispatched by the cron scheduler. \
+                          Use enable_cron_scheduling(true) in config."
+                    .to_string(),
+            });
+        }
+
+        let context = context
+            .to_json()
+            .map_err(|e| WorkflowExecutionError::Configuration {
+                message: format!("Failed to serialize context: {}", e),
+            })?;
+
+        let dal = DAL::new(self.database.clone());
+        let delayed = dal
+            .delayed_execution()
+            .create(workflow_name, context, run_at)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to create delayed execution: {}", e),
+            })?;
+
+        // Wake the scheduler in case this runs before anything it is waiting on.
+        self.cron_change.notify_one();
+        Ok(delayed.id)
+    }
+
+    /// Run a workflow once after `delay`
+    ///
+    /// Shorthand for [`execute_at`](Self::execute_at) with `now + delay`.
+    pub async fn execute_after(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+        delay: Duration,
+    ) -> Result<UniversalUuid, WorkflowExecutionError> {
+        let delay = chrono::Duration::from_std(delay).map_err(|e| {
+            WorkflowExecutionError::Configuration {
+                message: format!("Invalid delay: {}", e),
+            }
+        })?;
+        self.execute_at(workflow_name, context, Utc::now() + delay)
+            .await
+    }
+
+    /// A delayed execution by ID, pending or already started
+    pub async fn get_delayed_execution(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<Option<DelayedExecution>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.delayed_execution()
+            .get(id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get delayed execution: {}", e),
+            })
+    }
+
+    /// Delayed executions that have not started yet, soonest first
+    pub async fn list_delayed_executions(
+        &self,
+    ) -> Result<Vec<DelayedExecution>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.delayed_execution().list_pending().await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to list delayed executions: {}", e),
+            }
+        })
+    }
+
+    /// Cancel a delayed execution that has not started yet
+    ///
+    /// # Returns
+    /// * `Result<bool, WorkflowExecutionError>` - `false` if it does not exist or already started
+    pub async fn cancel_delayed_execution(
+        &self,
+        id: UniversalUuid,
+    ) -> Result<bool, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let cancelled = dal.delayed_execution().cancel(id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to cancel delayed execution: {}", e),
+            }
+        })?;
+        if cancelled {
+            self.cron_change.notify_one();
+        }
+        Ok(cancelled)
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Workflow deprecation API for the DefaultRunner.
+//!
+//! Retiring a workflow goes in three steps: mark it deprecated with a sunset
+//! date, move its consumers off while every execution logs a warning, and
+//! remove it once [`DefaultRunner::workflow_consumers`] comes back empty.
+//! New cron schedules against a deprecated workflow are refused.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::dal::DAL;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::schedule::Schedule;
+use crate::models::workflow_deprecation::WorkflowDeprecation;
+
+use super::DefaultRunner;
+
+/// What still starts a workflow, for deciding when it can be removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowConsumers {
+    pub workflow_name: String,
+    /// The workflow's deprecation, if it is deprecated
+    pub deprecation: Option<WorkflowDeprecation>,
+    /// Cron and trigger schedules that start the workflow
+    pub schedules: Vec<Schedule>,
+    /// Reactors whose firings start the workflow
+    pub reactors: Vec<String>,
+    /// Executions of the workflow that are still pending or running
+    pub active_executions: Vec<UniversalUuid>,
+}
+
+impl WorkflowConsumers {
+    /// Collects the consumers of `workflow_name` from the database.
+    pub async fn load(dal: &DAL, workflow_name: &str) -> Result<Self, ValidationError> {
+        let deprecation = dal.workflow_deprecation().get(workflow_name).await?;
+        let schedules = dal.schedule().find_by_workflow(workflow_name).await?;
+        let mut reactors: Vec<String> = dal
+            .reactor_subscriptions()
+            .list_all_enabled()
+            .await?
+            .into_iter()
+            .filter(|s| s.workflow_name == workflow_name)
+            .map(|s| s.reactor_name)
+            .collect();
+        reactors.sort();
+        reactors.dedup();
+        let active_executions = dal
+            .workflow_execution()
+            .get_active_executions()
+            .await?
+            .into_iter()
+            .filter(|e| e.workflow_name == workflow_name)
+            .map(|e| e.id)
+            .collect();
+
+        Ok(WorkflowConsumers {
+            workflow_name: workflow_name.to_string(),
+            deprecation,
+            schedules,
+            reactors,
+            active_executions,
+        })
+    }
+
+    /// Whether nothing starts the workflow any more and nothing is in flight.
+    pub fn is_empty(&self) -> bool {
+        self.schedules.is_empty() && self.reactors.is_empty() && self.active_executions.is_empty()
+    }
+}
+
+impl DefaultRunner {
+    /// Mark a workflow deprecated
+    ///
+    /// The workflow keeps running, but each execution logs a warning naming
+    /// the sunset date and `message`, and new cron schedules against it are
+    /// refused. Deprecating an already deprecated workflow replaces its
+    /// sunset date and message.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to deprecate
+    /// * `sunset_at` - When the workflow is due to be removed, if decided
+    /// * `message` - Note for consumers, e.g. the replacement workflow
+    pub async fn deprecate_workflow(
+        &self,
+        workflow_name: &str,
+        sunset_at: Option<DateTime<Utc>>,
+        message: Option<&str>,
+    ) -> Result<WorkflowDeprecation, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let deprecation = dal
+            .workflow_deprecation()
+            .deprecate(
+                workflow_name,
+                message.map(str::to_string),
+                sunset_at.map(UniversalTimestamp),
+            )
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to deprecate workflow '{}': {}", workflow_name, e),
+            })?;
+        tracing::info!("{}", deprecation.warning(Utc::now()));
+        Ok(deprecation)
+    }
+
+    /// Clear a workflow's deprecation
+    ///
+    /// # Returns
+    /// * `Result<bool, WorkflowExecutionError>` - `false` if the workflow was not deprecated
+    pub async fn undeprecate_workflow(
+        &self,
+        workflow_name: &str,
+    ) -> Result<bool, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_deprecation()
+            .remove(workflow_name)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to clear deprecation of '{}': {}", workflow_name, e),
+            })
+    }
+
+    /// The deprecation of a workflow, or `None` when it is not deprecated
+    pub async fn get_workflow_deprecation(
+        &self,
+        workflow_name: &str,
+    ) -> Result<Option<WorkflowDeprecation>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_deprecation()
+            .get(workflow_name)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to read deprecation of '{}': {}", workflow_name, e),
+            })
+    }
+
+    /// Every deprecated workflow, soonest sunset first
+    pub async fn list_workflow_deprecations(
+        &self,
+    ) -> Result<Vec<WorkflowDeprecation>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_deprecation().list().await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to list workflow deprecations: {}", e),
+            }
+        })
+    }
+
+    /// Report what still starts a workflow
+    ///
+    /// Lists the schedules and reactor subscriptions that start it and its
+    /// in-flight executions. When the report
+    /// [`is_empty`](WorkflowConsumers::is_empty) the workflow can be removed
+    /// without breaking anyone. Works for any workflow, deprecated or not.
+    pub async fn workflow_consumers(
+        &self,
+        workflow_name: &str,
+    ) -> Result<WorkflowConsumers, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        WorkflowConsumers::load(&dal, workflow_name)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to list consumers of '{}': {}", workflow_name, e),
+            })
+    }
+
+    /// Refuse a new schedule against a deprecated workflow.
+    pub(super) async fn ensure_not_deprecated(
+        &self,
+        dal: &DAL,
+        workflow_name: &str,
+    ) -> Result<(), WorkflowExecutionError> {
+        let deprecation = dal
+            .workflow_deprecation()
+            .get(workflow_name)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to read deprecation of '{}': {}", workflow_name, e),
+            })?;
+        match deprecation {
+            Some(deprecation) => Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "{}; new schedules are not accepted",
+                    deprecation.warning(Utc::now())
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+}
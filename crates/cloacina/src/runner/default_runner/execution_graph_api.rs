@@ -0,0 +1,615 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Live execution graph API for the DefaultRunner.
+//!
+//! A graph is the workflow's DAG overlaid with the state of one execution:
+//! per-node status, timings and retry counts. Callers take a snapshot with
+//! [`DefaultRunner::get_execution_graph`] or subscribe with
+//! [`DefaultRunner::watch_execution_graph`], which tails the execution's
+//! events and applies them to the snapshot instead of re-reading task rows.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::execution_event::{ExecutionEvent, ExecutionEventType};
+use crate::models::task_execution::TaskExecution;
+use crate::task::TaskNamespace;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// Buffered updates per watcher before the poller waits on the consumer.
+const WATCH_CHANNEL_CAPACITY: usize = 256;
+
+/// One task of an execution graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionGraphNode {
+    /// Fully qualified task name
+    pub task_name: String,
+    /// Task execution row, once the scheduler has created it
+    pub task_execution_id: Option<UniversalUuid>,
+    /// Task status (`NotStarted`, `Ready`, `Running`, `Completed`, `Failed`, `Skipped`)
+    pub status: String,
+    /// Sub-status of a running task (`Active` or `Deferred`)
+    pub sub_status: Option<String>,
+    /// Fully qualified names of the tasks this one depends on
+    pub dependencies: Vec<String>,
+    /// Current attempt, starting at 1
+    pub attempt: i32,
+    /// Attempts allowed before the task fails for good
+    pub max_attempts: i32,
+    /// When the current attempt started
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the current attempt finished
+    pub completed_at: Option<DateTime<Utc>>,
+    /// When a scheduled retry becomes eligible
+    pub retry_at: Option<DateTime<Utc>>,
+    /// Most recent error message
+    pub last_error: Option<String>,
+}
+
+impl ExecutionGraphNode {
+    fn from_task(task: &TaskExecution, dependencies: Vec<String>) -> Self {
+        Self {
+            task_name: task.task_name.clone(),
+            task_execution_id: Some(task.id),
+            status: task.status.clone(),
+            sub_status: task.sub_status.clone(),
+            dependencies,
+            attempt: task.attempt,
+            max_attempts: task.max_attempts,
+            started_at: task.started_at.map(|t| t.0),
+            completed_at: task.completed_at.map(|t| t.0),
+            retry_at: task.retry_at.map(|t| t.0),
+            last_error: task.last_error.clone(),
+        }
+    }
+
+    /// Wall-clock time of the current attempt, if it has finished
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        match (self.started_at, self.completed_at) {
+            (Some(start), Some(end)) => Some(end - start),
+            _ => None,
+        }
+    }
+}
+
+/// Snapshot of an execution overlaid on its workflow DAG.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionGraph {
+    pub execution_id: UniversalUuid,
+    pub workflow_name: String,
+    /// Workflow execution status
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Nodes sorted by task name
+    pub nodes: Vec<ExecutionGraphNode>,
+    /// Sequence number of the last event reflected in this snapshot
+    pub last_sequence: i64,
+}
+
+impl ExecutionGraph {
+    /// Node for a task, by fully qualified name
+    pub fn node(&self, task_name: &str) -> Option<&ExecutionGraphNode> {
+        self.nodes.iter().find(|n| n.task_name == task_name)
+    }
+
+    /// Whether the execution has reached a final status
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.status.as_str(), "Completed" | "Failed" | "Cancelled")
+    }
+
+    /// Apply one execution event to the snapshot.
+    ///
+    /// Returns the index of the node the event changed, if any. Events at or
+    /// below `last_sequence` and task events for nodes not in the graph are
+    /// ignored; callers add nodes for newly created tasks with
+    /// [`ExecutionGraph::upsert_task`] first.
+    pub fn apply(&mut self, event: &ExecutionEvent) -> Option<usize> {
+        if event.sequence_num <= self.last_sequence {
+            return None;
+        }
+        self.last_sequence = event.sequence_num;
+
+        let event_type = ExecutionEventType::from_str(&event.event_type)?;
+        let at = event.created_at.0;
+        let data: serde_json::Value = event
+            .event_data
+            .as_deref()
+            .and_then(|d| serde_json::from_str(d).ok())
+            .unwrap_or(serde_json::Value::Null);
+
+        match event_type {
+            ExecutionEventType::WorkflowStarted | ExecutionEventType::WorkflowResumed => {
+                self.status = "Running".to_string();
+                self.completed_at = None;
+                return None;
+            }
+            ExecutionEventType::WorkflowPaused => {
+                self.status = "Paused".to_string();
+                return None;
+            }
+            ExecutionEventType::WorkflowCompleted => {
+                self.status = "Completed".to_string();
+                self.completed_at = Some(at);
+                return None;
+            }
+            ExecutionEventType::WorkflowFailed => {
+                self.status = "Failed".to_string();
+                self.completed_at = Some(at);
+                return None;
+            }
+            ExecutionEventType::WorkflowRetryScheduled => {
+                // The whole workflow starts over: every task is reset.
+                self.status = "Pending".to_string();
+                self.completed_at = None;
+                for node in &mut self.nodes {
+                    node.status = "NotStarted".to_string();
+                    node.sub_status = None;
+                    node.attempt = 1;
+                    node.started_at = None;
+                    node.completed_at = None;
+                    node.retry_at = None;
+                    node.last_error = None;
+                }
+                return None;
+            }
+            _ => {}
+        }
+
+        let task_id = event.task_execution_id?;
+        let index = self
+            .nodes
+            .iter()
+            .position(|n| n.task_execution_id == Some(task_id))?;
+        let node = &mut self.nodes[index];
+
+        match event_type {
+            ExecutionEventType::TaskCreated => {
+                node.status = "NotStarted".to_string();
+            }
+            ExecutionEventType::TaskMarkedReady => {
+                node.status = "Ready".to_string();
+            }
+            ExecutionEventType::TaskClaimed | ExecutionEventType::TaskStarted => {
+                node.status = "Running".to_string();
+                node.retry_at = None;
+                if node.started_at.is_none() {
+                    node.started_at = Some(at);
+                }
+            }
+            ExecutionEventType::TaskDeferred => {
+                node.sub_status = Some("Deferred".to_string());
+            }
+            ExecutionEventType::TaskResumed => {
+                node.sub_status = Some("Active".to_string());
+            }
+            ExecutionEventType::TaskCompleted => {
+                node.status = "Completed".to_string();
+                node.completed_at = Some(at);
+            }
+            ExecutionEventType::TaskFailed => {
+                node.status = "Failed".to_string();
+                node.completed_at = Some(at);
+                if let Some(error) = data.get("error").and_then(|e| e.as_str()) {
+                    node.last_error = Some(error.to_string());
+                }
+            }
+            ExecutionEventType::TaskRetryScheduled => {
+                node.status = "Ready".to_string();
+                node.started_at = None;
+                node.completed_at = None;
+                if let Some(attempt) = data.get("attempt").and_then(|a| a.as_i64()) {
+                    node.attempt = attempt as i32;
+                }
+                node.retry_at = data
+                    .get("retry_at")
+                    .and_then(|r| r.as_str())
+                    .and_then(|r| DateTime::parse_from_rfc3339(r).ok())
+                    .map(|r| r.with_timezone(&Utc));
+            }
+            ExecutionEventType::TaskSkipped => {
+                node.status = "Skipped".to_string();
+                node.completed_at = Some(at);
+            }
+            ExecutionEventType::TaskAbandoned => {
+                node.status = "Failed".to_string();
+                node.completed_at = Some(at);
+                if let Some(reason) = data.get("reason").and_then(|r| r.as_str()) {
+                    node.last_error = Some(format!("ABANDONED: {}", reason));
+                }
+            }
+            ExecutionEventType::TaskResolved => {
+                node.status = "Completed".to_string();
+                node.completed_at = Some(at);
+            }
+            ExecutionEventType::TaskAwaitingApproval => {
+                node.status = "AwaitingApproval".to_string();
+            }
+            ExecutionEventType::TaskApproved => {
+                node.status = "NotStarted".to_string();
+            }
+            ExecutionEventType::TaskReset => {
+                node.status = "Ready".to_string();
+                node.attempt = 1;
+                node.started_at = None;
+                node.completed_at = None;
+                node.retry_at = None;
+                node.last_error = None;
+            }
+            _ => return None,
+        }
+        Some(index)
+    }
+
+    /// Insert or refresh the node for a task execution row, keeping its
+    /// dependencies. Returns the node's index.
+    pub fn upsert_task(&mut self, task: &TaskExecution) -> usize {
+        if let Some(index) = self
+            .nodes
+            .iter()
+            .position(|n| n.task_name == task.task_name)
+        {
+            let dependencies = std::mem::take(&mut self.nodes[index].dependencies);
+            self.nodes[index] = ExecutionGraphNode::from_task(task, dependencies);
+            index
+        } else {
+            self.nodes
+                .push(ExecutionGraphNode::from_task(task, Vec::new()));
+            self.nodes.len() - 1
+        }
+    }
+}
+
+/// A change delivered to an execution graph watcher.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionGraphUpdate {
+    /// Event that caused the change; `None` for status changes that emit no
+    /// event, such as cancellation
+    pub event: Option<ExecutionEvent>,
+    /// Node state after the event, for task events
+    pub node: Option<ExecutionGraphNode>,
+    /// Workflow execution status after the event
+    pub execution_status: String,
+}
+
+/// Initial snapshot plus a stream of updates for one execution.
+///
+/// The stream closes once the execution reaches a final status; dropping the
+/// receiver stops the poller.
+pub struct ExecutionGraphWatch {
+    pub graph: ExecutionGraph,
+    pub updates: mpsc::Receiver<ExecutionGraphUpdate>,
+}
+
+impl DefaultRunner {
+    /// Snapshot of an execution's DAG with per-task state
+    ///
+    /// Dependencies come from the workflow registered in this runner's
+    /// runtime; nodes for workflows the runtime no longer knows carry no
+    /// edges. Tasks the scheduler has not created rows for yet appear as
+    /// `NotStarted` without a `task_execution_id`.
+    pub async fn get_execution_graph(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ExecutionGraph, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to get execution graph: {}", e),
+        };
+
+        // Read the event high-water mark first so events racing the row
+        // reads are re-applied by a watcher rather than lost.
+        let last_sequence = dal
+            .execution_event()
+            .list_by_workflow_after_sequence(execution_id, 0)
+            .await
+            .map_err(map_err)?
+            .last()
+            .map(|e| e.sequence_num)
+            .unwrap_or(0);
+        let execution = dal
+            .workflow_execution()
+            .get_by_id(execution_id)
+            .await
+            .map_err(map_err)?;
+        let tasks = dal
+            .task_execution()
+            .get_all_tasks_for_workflow(execution_id)
+            .await
+            .map_err(map_err)?;
+
+        let workflow = self.runtime().get_workflow(&execution.workflow_name);
+        let mut nodes: Vec<ExecutionGraphNode> = Vec::new();
+        if let Some(workflow) = &workflow {
+            for namespace in workflow.get_task_ids() {
+                let dependencies = workflow
+                    .get_dependencies(&namespace)
+                    .map(|deps| deps.iter().map(TaskNamespace::to_string).collect())
+                    .unwrap_or_default();
+                nodes.push(ExecutionGraphNode {
+                    task_name: namespace.to_string(),
+                    task_execution_id: None,
+                    status: "NotStarted".to_string(),
+                    sub_status: None,
+                    dependencies,
+                    attempt: 1,
+                    max_attempts: 1,
+                    started_at: None,
+                    completed_at: None,
+                    retry_at: None,
+                    last_error: None,
+                });
+            }
+        }
+
+        let mut graph = ExecutionGraph {
+            execution_id,
+            workflow_name: execution.workflow_name,
+            status: execution.status,
+            started_at: execution.started_at.0,
+            completed_at: execution.completed_at.map(|t| t.0),
+            nodes,
+            last_sequence,
+        };
+        for task in &tasks {
+            graph.upsert_task(task);
+        }
+        graph.nodes.sort_by(|a, b| a.task_name.cmp(&b.task_name));
+        Ok(graph)
+    }
+
+    /// Snapshot of an execution's DAG plus a stream of changes to it
+    ///
+    /// # Arguments
+    /// * `execution_id` - Execution to watch
+    /// * `poll_interval` - How often to check for new events
+    pub async fn watch_execution_graph(
+        &self,
+        execution_id: UniversalUuid,
+        poll_interval: Duration,
+    ) -> Result<ExecutionGraphWatch, WorkflowExecutionError> {
+        let graph = self.get_execution_graph(execution_id).await?;
+        let (tx, rx) = mpsc::channel(WATCH_CHANNEL_CAPACITY);
+
+        if !graph.is_terminal() {
+            let dal = DAL::new(self.database.clone());
+            let mut state = graph.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(poll_interval);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                while !state.is_terminal() && !tx.is_closed() {
+                    interval.tick().await;
+                    let events = match dal
+                        .execution_event()
+                        .list_by_workflow_after_sequence(execution_id, state.last_sequence)
+                        .await
+                    {
+                        Ok(events) => events,
+                        Err(e) => {
+                            warn!("Execution graph poll for {} failed: {}", execution_id, e);
+                            continue;
+                        }
+                    };
+
+                    if events.is_empty() {
+                        // Cancellation writes no event; pick it up from the row.
+                        if let Ok(execution) =
+                            dal.workflow_execution().get_by_id(execution_id).await
+                        {
+                            if execution.status == "Cancelled" && state.status != "Cancelled" {
+                                state.status = execution.status;
+                                state.completed_at = execution.completed_at.map(|t| t.0);
+                                let update = ExecutionGraphUpdate {
+                                    event: None,
+                                    node: None,
+                                    execution_status: state.status.clone(),
+                                };
+                                if tx.send(update).await.is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                        continue;
+                    }
+
+                    for event in events {
+                        // Tasks created after the snapshot: load the row so the
+                        // node carries its limits before the event applies.
+                        if let Some(task_id) = event.task_execution_id {
+                            if !state
+                                .nodes
+                                .iter()
+                                .any(|n| n.task_execution_id == Some(task_id))
+                            {
+                                match dal.task_execution().get_by_id(task_id).await {
+                                    Ok(task) => {
+                                        state.upsert_task(&task);
+                                    }
+                                    Err(e) => {
+                                        debug!("Skipping event for unknown task {}: {}", task_id, e)
+                                    }
+                                }
+                            }
+                        }
+
+                        let node = state.apply(&event).map(|i| state.nodes[i].clone());
+                        let update = ExecutionGraphUpdate {
+                            event: Some(event),
+                            node,
+                            execution_status: state.status.clone(),
+                        };
+                        if tx.send(update).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+
+        Ok(ExecutionGraphWatch { graph, updates: rx })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UniversalTimestamp;
+
+    fn graph_with_task(task_id: UniversalUuid) -> ExecutionGraph {
+        ExecutionGraph {
+            execution_id: UniversalUuid::new_v4(),
+            workflow_name: "etl".to_string(),
+            status: "Running".to_string(),
+            started_at: Utc::now(),
+            completed_at: None,
+            nodes: vec![ExecutionGraphNode {
+                task_name: "public::embedded::etl::extract".to_string(),
+                task_execution_id: Some(task_id),
+                status: "Ready".to_string(),
+                sub_status: None,
+                dependencies: Vec::new(),
+                attempt: 1,
+                max_attempts: 3,
+                started_at: None,
+                completed_at: None,
+                retry_at: None,
+                last_error: None,
+            }],
+            last_sequence: 10,
+        }
+    }
+
+    fn event(
+        seq: i64,
+        task_id: Option<UniversalUuid>,
+        event_type: ExecutionEventType,
+        data: Option<serde_json::Value>,
+    ) -> ExecutionEvent {
+        ExecutionEvent {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_execution_id: task_id,
+            event_type: event_type.as_str().to_string(),
+            event_data: data.map(|d| d.to_string()),
+            worker_id: None,
+            created_at: UniversalTimestamp::now(),
+            sequence_num: seq,
+            request_id: None,
+            runner_id: None,
+            tenant_id: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_tracks_retry_and_completion() {
+        let task_id = UniversalUuid::new_v4();
+        let mut graph = graph_with_task(task_id);
+
+        graph.apply(&event(
+            11,
+            Some(task_id),
+            ExecutionEventType::TaskClaimed,
+            None,
+        ));
+        assert_eq!(graph.nodes[0].status, "Running");
+        assert!(graph.nodes[0].started_at.is_some());
+
+        graph.apply(&event(
+            12,
+            Some(task_id),
+            ExecutionEventType::TaskFailed,
+            Some(serde_json::json!({ "error": "connection reset" })),
+        ));
+        assert_eq!(graph.nodes[0].status, "Failed");
+        assert_eq!(
+            graph.nodes[0].last_error.as_deref(),
+            Some("connection reset")
+        );
+
+        graph.apply(&event(
+            13,
+            Some(task_id),
+            ExecutionEventType::TaskRetryScheduled,
+            Some(serde_json::json!({ "attempt": 2, "retry_at": "2026-01-01T00:00:00Z" })),
+        ));
+        assert_eq!(graph.nodes[0].status, "Ready");
+        assert_eq!(graph.nodes[0].attempt, 2);
+        assert!(graph.nodes[0].started_at.is_none());
+        assert!(graph.nodes[0].retry_at.is_some());
+
+        graph.apply(&event(
+            14,
+            Some(task_id),
+            ExecutionEventType::TaskStarted,
+            None,
+        ));
+        graph.apply(&event(
+            15,
+            Some(task_id),
+            ExecutionEventType::TaskCompleted,
+            None,
+        ));
+        assert_eq!(graph.nodes[0].status, "Completed");
+        assert!(graph.nodes[0].duration().is_some());
+
+        graph.apply(&event(
+            16,
+            None,
+            ExecutionEventType::WorkflowCompleted,
+            None,
+        ));
+        assert!(graph.is_terminal());
+        assert_eq!(graph.last_sequence, 16);
+    }
+
+    #[test]
+    fn test_apply_ignores_already_seen_and_unknown_events() {
+        let task_id = UniversalUuid::new_v4();
+        let mut graph = graph_with_task(task_id);
+
+        assert_eq!(
+            graph.apply(&event(
+                9,
+                Some(task_id),
+                ExecutionEventType::TaskCompleted,
+                None
+            )),
+            None
+        );
+        assert_eq!(graph.nodes[0].status, "Ready");
+
+        let other = UniversalUuid::new_v4();
+        assert_eq!(
+            graph.apply(&event(
+                11,
+                Some(other),
+                ExecutionEventType::TaskCompleted,
+                None
+            )),
+            None
+        );
+        assert_eq!(graph.last_sequence, 11);
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Per-execution configuration overrides for the DefaultRunner.
+//!
+//! [`ExecutionOptions`] override, for one execution, values otherwise fixed
+//! by [`DefaultRunnerConfig`](super::DefaultRunnerConfig) or the task macro
+//! attributes:
+//!
+//! - `max_parallelism` caps how many of the execution's tasks the scheduler
+//!   dispatches at once.
+//! - `priority` orders the execution's ready tasks ahead of (or behind)
+//!   other executions' when the scheduler dispatches.
+//! - `max_attempts` and `retry_delay_multiplier` adjust every task's retry
+//!   policy.
+//! - `task_timeout` replaces the thread executor's task timeout, and
+//!   `workflow_timeout` how long [`DefaultRunner::execute_with_options`]
+//!   waits.
+//! - `run_id` and `logical_date` set the execution's run identity, for
+//!   backfills and reruns; they are stored on the execution row and stamped
+//!   into its context.
+//! - `params` are the execution's read-only parameters (like Airflow's
+//!   `conf`): tasks read them with `context.params()` / `context.param(..)`
+//!   but cannot change them, and [`DefaultRunner::get_execution_params`]
+//!   returns them for any execution.
+//!
+//! The options are stored with the execution, so every runner sharing the
+//! database applies them.
+//!
+//! ```rust,ignore
+//! let options = ExecutionOptions {
+//!     max_parallelism: Some(2),
+//!     max_attempts: Some(1),
+//!     task_timeout: Some(Duration::from_secs(30)),
+//!     ..Default::default()
+//! };
+//! let result = runner.execute_with_options("etl", context, &options).await?;
+//! ```
+
+use crate::executor::workflow_executor::{
+    WorkflowExecution, WorkflowExecutionError, WorkflowExecutionResult,
+};
+use crate::models::workflow_execution::ExecutionLabels;
+pub use crate::models::workflow_execution::{ExecutionOptions, ExecutionParams};
+use crate::{Context, UniversalUuid};
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Executes a workflow with per-execution options and waits for it
+    ///
+    /// Waits up to the options' `workflow_timeout`, else the runner's.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to execute
+    /// * `context` - Initial context for the workflow
+    /// * `options` - Overrides for this execution
+    pub async fn execute_with_options(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+        options: &ExecutionOptions,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        let execution = self
+            .execute_async_with_options(workflow_name, context, &ExecutionLabels::new(), options)
+            .await?;
+        let timeout = options.workflow_timeout.or(self.config.workflow_timeout());
+        execution.wait_for_completion_with_timeout(timeout).await
+    }
+
+    /// Executes a workflow asynchronously with labels and per-execution
+    /// options
+    ///
+    /// Labels and options are validated before the execution is scheduled,
+    /// so invalid ones start nothing, and are written in the same
+    /// transaction as the execution row.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to execute
+    /// * `context` - Initial context for the workflow
+    /// * `labels` - Labels to attach to the execution
+    /// * `options` - Overrides for this execution
+    pub async fn execute_async_with_options(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+        labels: &ExecutionLabels,
+        options: &ExecutionOptions,
+    ) -> Result<WorkflowExecution, WorkflowExecutionError> {
+        options
+            .validate()
+            .map_err(|e| WorkflowExecutionError::Configuration {
+                message: e.to_string(),
+            })?;
+        let execution_id = self
+            .scheduler
+            .schedule_workflow_execution_with_options(workflow_name, context, labels, options)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to schedule workflow: {}", e),
+            })?;
+
+        Ok(WorkflowExecution::new(
+            execution_id,
+            workflow_name.to_string(),
+            self.clone(),
+        ))
+    }
+
+    /// Options an execution was submitted with; default options when it was
+    /// submitted without overrides. The run identity and params are not
+    /// included; read them from the execution record and
+    /// [`get_execution_params`](Self::get_execution_params).
+    pub async fn get_execution_options(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ExecutionOptions, WorkflowExecutionError> {
+        self.dal()
+            .workflow_execution()
+            .get_options(execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get options of execution {}: {}", execution_id, e),
+            })
+    }
+
+    /// Params an execution was submitted with; empty when it had none.
+    pub async fn get_execution_params(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ExecutionParams, WorkflowExecutionError> {
+        self.dal()
+            .workflow_execution()
+            .get_params(execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get params of execution {}: {}", execution_id, e),
+            })
+    }
+}
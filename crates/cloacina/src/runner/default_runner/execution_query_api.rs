@@ -0,0 +1,294 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Paginated execution query API for the DefaultRunner.
+//!
+//! [`DefaultRunner::query_executions`] filters executions by workflow,
+//! status, start time, run id, labels and tenant and returns summary rows a page at
+//! a time. Pages are keyset-paginated on `(started_at, id)`, so a cursor
+//! stays valid while new executions are inserted ahead of it.
+//! [`DefaultRunner::get_execution`] returns one execution in full: its task
+//! tree with per-attempt timings, errors, retries and trigger outcomes.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::dal::unified::workflow_execution::ExecutionPageQuery;
+pub use crate::dal::unified::workflow_execution::ExecutionSort;
+use crate::dal::DAL;
+use crate::execution_detail::ExecutionDetail;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::workflow_execution::{ExecutionLabels, WorkflowExecutionRecord};
+use crate::task::TaskNamespace;
+use crate::{UniversalTimestamp, UniversalUuid};
+
+use super::DefaultRunner;
+
+/// Page size when a filter does not set one.
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Largest page a filter may ask for.
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// Filters, order and position for [`DefaultRunner::query_executions`].
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionFilter {
+    /// Exact workflow name
+    pub workflow_name: Option<String>,
+    /// Any of these statuses (`Pending`, `Running`, `Completed`, ...)
+    pub statuses: Vec<String>,
+    /// Only executions started at or after this time
+    pub started_from: Option<DateTime<Utc>>,
+    /// Only executions started before this time
+    pub started_until: Option<DateTime<Utc>>,
+    /// Exact run id (`scheduled__2026-01-01T00:00:00.000000Z`)
+    pub run_id: Option<String>,
+    /// `(key, value)` pairs an execution must all carry
+    pub labels: Vec<(String, String)>,
+    /// Tenant (schema) the caller expects this runner to serve. A runner
+    /// reads only its own schema, so a different tenant is an error rather
+    /// than an empty page.
+    pub tenant: Option<String>,
+    pub sort: ExecutionSort,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    /// Page size; defaults to 50, at most 1000
+    pub limit: Option<usize>,
+}
+
+/// One execution in a query page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionSummary {
+    pub id: UniversalUuid,
+    pub workflow_name: String,
+    pub workflow_version: String,
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration: Option<Duration>,
+    pub error_details: Option<String>,
+    /// `manual` for operator runs; `None` for scheduled and triggered runs
+    pub trigger_origin: Option<String>,
+    pub run_id: Option<String>,
+    /// Logical date the run processes; `None` for executions created before
+    /// run identities existed
+    pub logical_date: Option<DateTime<Utc>>,
+    pub labels: ExecutionLabels,
+}
+
+impl ExecutionSummary {
+    fn from_record(record: WorkflowExecutionRecord, labels: ExecutionLabels) -> Self {
+        let duration = record
+            .completed_at
+            .and_then(|end| (end.0 - record.started_at.0).to_std().ok());
+        Self {
+            id: record.id,
+            workflow_name: record.workflow_name,
+            workflow_version: record.workflow_version,
+            status: record.status,
+            started_at: record.started_at.0,
+            completed_at: record.completed_at.map(|t| t.0),
+            duration,
+            error_details: record.error_details,
+            trigger_origin: record.trigger_origin,
+            run_id: record.run_id,
+            logical_date: record.logical_date.map(|t| t.0),
+            labels,
+        }
+    }
+}
+
+/// A page of executions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionPage {
+    pub items: Vec<ExecutionSummary>,
+    /// Pass as `ExecutionFilter::cursor` for the next page; `None` on the
+    /// last page
+    pub next_cursor: Option<String>,
+}
+
+/// Keyset position after one execution: its start time and id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ExecutionCursor {
+    started_at: DateTime<Utc>,
+    id: UniversalUuid,
+}
+
+impl fmt::Display for ExecutionCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.started_at.timestamp_nanos_opt().unwrap_or(i64::MAX);
+        write!(f, "{}:{}", nanos, self.id)
+    }
+}
+
+impl FromStr for ExecutionCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid execution cursor '{}'", s);
+        let (nanos, id) = s.split_once(':').ok_or_else(invalid)?;
+        let nanos: i64 = nanos.parse().map_err(|_| invalid())?;
+        let id = uuid::Uuid::parse_str(id).map_err(|_| invalid())?;
+        Ok(Self {
+            started_at: DateTime::from_timestamp_nanos(nanos),
+            id: UniversalUuid(id),
+        })
+    }
+}
+
+impl DefaultRunner {
+    /// One page of executions matching `filter`, as summary rows
+    ///
+    /// # Arguments
+    /// * `filter` - Filters, sort order, cursor and page size
+    pub async fn query_executions(
+        &self,
+        filter: ExecutionFilter,
+    ) -> Result<ExecutionPage, WorkflowExecutionError> {
+        if let Some(tenant) = &filter.tenant {
+            let own = self.database.schema().unwrap_or("public");
+            if tenant != own {
+                return Err(WorkflowExecutionError::Configuration {
+                    message: format!(
+                        "This runner serves tenant '{}', not '{}'; query through a runner for that tenant",
+                        own, tenant
+                    ),
+                });
+            }
+        }
+        let after = filter
+            .cursor
+            .as_deref()
+            .map(ExecutionCursor::from_str)
+            .transpose()
+            .map_err(|message| WorkflowExecutionError::Configuration { message })?;
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+
+        let dal = DAL::new(self.database.clone());
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to query executions: {}", e),
+        };
+        // One extra row tells whether another page follows.
+        let mut records = dal
+            .workflow_execution()
+            .list_page(ExecutionPageQuery {
+                workflow_name: filter.workflow_name,
+                statuses: filter.statuses,
+                started_from: filter.started_from.map(UniversalTimestamp),
+                started_until: filter.started_until.map(UniversalTimestamp),
+                run_id: filter.run_id,
+                labels: filter.labels,
+                sort: filter.sort,
+                after: after.map(|c| (UniversalTimestamp(c.started_at), c.id)),
+                limit: limit as i64 + 1,
+            })
+            .await
+            .map_err(map_err)?;
+
+        let has_more = records.len() > limit;
+        records.truncate(limit);
+        let next_cursor = match records.last() {
+            Some(last) if has_more => Some(
+                ExecutionCursor {
+                    started_at: last.started_at.0,
+                    id: last.id,
+                }
+                .to_string(),
+            ),
+            _ => None,
+        };
+
+        let ids: Vec<UniversalUuid> = records.iter().map(|r| r.id).collect();
+        let mut labels = dal
+            .workflow_execution()
+            .get_labels_for(&ids)
+            .await
+            .map_err(map_err)?;
+        let items = records
+            .into_iter()
+            .map(|record| {
+                let record_labels = labels.remove(&record.id).unwrap_or_default();
+                ExecutionSummary::from_record(record, record_labels)
+            })
+            .collect();
+
+        Ok(ExecutionPage { items, next_cursor })
+    }
+
+    /// Full detail of one execution: task tree, attempts and context ids
+    ///
+    /// Task dependencies come from the workflow registered in this runner's
+    /// runtime and are empty for workflows it no longer knows.
+    ///
+    /// # Arguments
+    /// * `execution_id` - Execution to describe
+    pub async fn get_execution(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ExecutionDetail, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let mut detail = ExecutionDetail::load(&dal, execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get execution {}: {}", execution_id, e),
+            })?;
+
+        if let Some(workflow) = self.runtime().get_workflow(&detail.workflow_name) {
+            for namespace in workflow.get_task_ids() {
+                let name = namespace.to_string();
+                let Some(task) = detail.tasks.iter_mut().find(|t| t.task_name == name) else {
+                    continue;
+                };
+                task.dependencies = workflow
+                    .get_dependencies(&namespace)
+                    .map(|deps| deps.iter().map(TaskNamespace::to_string).collect())
+                    .unwrap_or_default();
+            }
+        }
+        Ok(detail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trips() {
+        let cursor = ExecutionCursor {
+            started_at: DateTime::parse_from_rfc3339("2026-03-29T01:30:00.123456789Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            id: UniversalUuid::new_v4(),
+        };
+        let parsed: ExecutionCursor = cursor.to_string().parse().unwrap();
+        assert_eq!(parsed, cursor);
+    }
+
+    #[test]
+    fn test_malformed_cursor_is_rejected() {
+        assert!("".parse::<ExecutionCursor>().is_err());
+        assert!("123".parse::<ExecutionCursor>().is_err());
+        assert!("abc:not-a-uuid".parse::<ExecutionCursor>().is_err());
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Blue/green handoff API for the DefaultRunner.
+//!
+//! See [`crate::runner_handoff`] for the protocol. The host process waits on
+//! [`DefaultRunner::handoff_requested`] alongside its shutdown signals and
+//! calls [`DefaultRunner::drain`] instead of `shutdown()` when it fires.
+
+use std::time::Duration;
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// How often a drain re-checks the executor for in-flight tasks.
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+impl DefaultRunner {
+    /// This runner's ID in the `runner_instances` registry
+    pub fn instance_id(&self) -> UniversalUuid {
+        self.instance_id
+    }
+
+    /// Resolves once a successor runner has asked this one to drain
+    ///
+    /// Never resolves when handoff is disabled, so it can sit in a
+    /// `tokio::select!` next to the process's signal handlers.
+    pub async fn handoff_requested(&self) {
+        let mut rx = self.handoff.subscribe();
+        // `self` holds the sender, so the channel cannot close under us.
+        let _ = rx.wait_for(|requested| *requested).await;
+    }
+
+    /// Stops taking new work, waits for in-flight tasks, then shuts down
+    ///
+    /// Every background service stops first, so no more cron or trigger
+    /// firings and no more task dispatch. Tasks already running on the
+    /// in-process executor get up to `timeout` to finish; anything still
+    /// running after that is left to the successor's stale-claim sweeper.
+    /// With handoff enabled the runner is then marked `drained`.
+    ///
+    /// Returns `true` if every in-flight task finished within `timeout`.
+    pub async fn drain(&self, timeout: Duration) -> Result<bool, WorkflowExecutionError> {
+        self.service_manager.write().await.shutdown_all().await?;
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let drained = loop {
+            let in_flight = self.local_executor.metrics().active_tasks;
+            if in_flight == 0 {
+                break true;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                tracing::warn!(
+                    in_flight,
+                    "Drain timed out after {}s with tasks still running",
+                    timeout.as_secs()
+                );
+                break false;
+            }
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        };
+
+        if self.config.enable_handoff() {
+            let dal = DAL::new(self.database.clone());
+            if let Err(e) = dal.runner_instance().mark_drained(self.instance_id).await {
+                tracing::warn!("Failed to mark runner {} drained: {}", self.instance_id, e);
+            }
+        }
+        tracing::info!(instance_id = %self.instance_id, drained, "Runner drained");
+
+        self.database.close();
+        Ok(drained)
+    }
+}
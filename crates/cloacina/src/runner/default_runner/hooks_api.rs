@@ -0,0 +1,400 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Lifecycle hook registration for the DefaultRunner.
+//!
+//! [`DefaultRunner::on`] registers an async handler for one
+//! [`ExecutionEventType`]. Unlike the `on_success` / `on_failure` task macro
+//! callbacks, hooks are registered by the host application at runtime and
+//! see every workflow, so alerting and bookkeeping need no changes to
+//! workflow code.
+//!
+//! Hooks are fed by one dispatcher per runner that tails the
+//! `execution_events` table, started by the first registration:
+//!
+//! - Events are delivered in sequence order, starting with the first event
+//!   written after the dispatcher starts. Events of every runner sharing the
+//!   schema are delivered, not just this runner's.
+//! - The handlers of one event run one after another in registration order,
+//!   and the next event waits for them.
+//! - Each handler runs on its own task with a timeout. An error, panic or
+//!   timeout is logged and does not stop later handlers or the dispatcher.
+//! - Delivery is at most once and stops at [`DefaultRunner::shutdown`].
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use tokio::sync::watch;
+use tracing::{debug, warn};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::execution_event::{ExecutionEvent, ExecutionEventType};
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// How often the dispatcher checks for new events.
+const HOOK_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Maximum events read per poll.
+const HOOK_BATCH_LIMIT: i64 = 500;
+
+/// How long one handler may run before it is abandoned.
+const HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Result of a lifecycle hook.
+pub type HookResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+type HookFuture = Pin<Box<dyn Future<Output = HookResult> + Send>>;
+type HookFn = Arc<dyn Fn(HookEvent) -> HookFuture + Send + Sync>;
+
+/// Handle of a registered hook, for [`DefaultRunner::off`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HookId(u64);
+
+/// The event a hook is called with.
+#[derive(Debug, Clone)]
+pub struct HookEvent {
+    pub event_type: ExecutionEventType,
+    pub execution_id: UniversalUuid,
+    pub workflow_name: String,
+    pub task_execution_id: Option<UniversalUuid>,
+    /// Fully qualified task name, for task events
+    pub task_name: Option<String>,
+    /// The event's JSON payload (`error`, `reason`, `retry_at`, ...)
+    pub data: Option<serde_json::Value>,
+    pub occurred_at: DateTime<Utc>,
+    pub sequence_num: i64,
+}
+
+struct Registration {
+    id: HookId,
+    event_type: ExecutionEventType,
+    handler: HookFn,
+}
+
+/// Hooks registered on a runner, shared by its clones.
+pub(in crate::runner) struct LifecycleHooks {
+    registrations: RwLock<Vec<Registration>>,
+    next_id: AtomicU64,
+    /// `Some` once the dispatcher runs; sending `true` stops it.
+    stop: tokio::sync::Mutex<Option<watch::Sender<bool>>>,
+}
+
+impl LifecycleHooks {
+    pub(super) fn new() -> Self {
+        Self {
+            registrations: RwLock::new(Vec::new()),
+            next_id: AtomicU64::new(1),
+            stop: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    fn add(&self, event_type: ExecutionEventType, handler: HookFn) -> HookId {
+        let id = HookId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.registrations.write().push(Registration {
+            id,
+            event_type,
+            handler,
+        });
+        id
+    }
+
+    fn remove(&self, id: HookId) -> bool {
+        let mut registrations = self.registrations.write();
+        let before = registrations.len();
+        registrations.retain(|r| r.id != id);
+        registrations.len() != before
+    }
+
+    /// Handlers for `event_type`, in registration order.
+    fn handlers_for(&self, event_type: ExecutionEventType) -> Vec<HookFn> {
+        self.registrations
+            .read()
+            .iter()
+            .filter(|r| r.event_type == event_type)
+            .map(|r| r.handler.clone())
+            .collect()
+    }
+
+    /// Stop the dispatcher, if it runs.
+    pub(super) async fn stop(&self) {
+        if let Some(stop) = self.stop.lock().await.take() {
+            let _ = stop.send(true);
+        }
+    }
+}
+
+/// Runs `handlers` one after another, each isolated on its own task.
+async fn dispatch(handlers: Vec<HookFn>, event: HookEvent, timeout: Duration) {
+    for handler in handlers {
+        let hook_event = event.clone();
+        let mut task = tokio::spawn(async move { handler(hook_event).await });
+        match tokio::time::timeout(timeout, &mut task).await {
+            Ok(Ok(Ok(()))) => {}
+            Ok(Ok(Err(e))) => warn!(
+                "Lifecycle hook for {} of execution {} failed: {}",
+                event.event_type.as_str(),
+                event.execution_id,
+                e
+            ),
+            Ok(Err(e)) => warn!(
+                "Lifecycle hook for {} of execution {} panicked: {}",
+                event.event_type.as_str(),
+                event.execution_id,
+                e
+            ),
+            Err(_) => {
+                task.abort();
+                warn!(
+                    "Lifecycle hook for {} of execution {} timed out after {:?}",
+                    event.event_type.as_str(),
+                    event.execution_id,
+                    timeout
+                );
+            }
+        }
+    }
+}
+
+/// Resolves the names a [`HookEvent`] carries.
+async fn hook_event(
+    dal: &DAL,
+    event: &ExecutionEvent,
+    event_type: ExecutionEventType,
+) -> Option<HookEvent> {
+    let workflow_name = match dal
+        .workflow_execution()
+        .get_by_id(event.workflow_execution_id)
+        .await
+    {
+        Ok(execution) => execution.workflow_name,
+        Err(e) => {
+            debug!(
+                "Skipping hooks for unknown execution {}: {}",
+                event.workflow_execution_id, e
+            );
+            return None;
+        }
+    };
+    let task_name = match event.task_execution_id {
+        Some(task_id) => dal
+            .task_execution()
+            .get_by_id(task_id)
+            .await
+            .ok()
+            .map(|task| task.task_name),
+        None => None,
+    };
+    Some(HookEvent {
+        event_type,
+        execution_id: event.workflow_execution_id,
+        workflow_name,
+        task_execution_id: event.task_execution_id,
+        task_name,
+        data: event
+            .event_data
+            .as_deref()
+            .and_then(|data| serde_json::from_str(data).ok()),
+        occurred_at: event.created_at.0,
+        sequence_num: event.sequence_num,
+    })
+}
+
+impl DefaultRunner {
+    /// Register an async handler for one lifecycle event
+    ///
+    /// The first registration starts the runner's hook dispatcher; only
+    /// events written after that are delivered. See the module docs for
+    /// ordering and failure isolation.
+    ///
+    /// # Arguments
+    /// * `event_type` - Event to handle, e.g. `ExecutionEventType::TaskFailed`
+    /// * `handler` - Called with each matching event
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// runner
+    ///     .on(ExecutionEventType::WorkflowFailed, |event| async move {
+    ///         pager.alert(&event.workflow_name).await?;
+    ///         Ok(())
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn on<F, Fut>(
+        &self,
+        event_type: ExecutionEventType,
+        handler: F,
+    ) -> Result<HookId, WorkflowExecutionError>
+    where
+        F: Fn(HookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HookResult> + Send + 'static,
+    {
+        self.ensure_hook_dispatcher().await?;
+        let handler: HookFn = Arc::new(move |event| Box::pin(handler(event)));
+        Ok(self.hooks.add(event_type, handler))
+    }
+
+    /// Remove a hook registered with [`DefaultRunner::on`]
+    ///
+    /// Returns `false` when the hook was already removed. A handler that is
+    /// running finishes.
+    pub fn off(&self, hook: HookId) -> bool {
+        self.hooks.remove(hook)
+    }
+
+    async fn ensure_hook_dispatcher(&self) -> Result<(), WorkflowExecutionError> {
+        let mut stop = self.hooks.stop.lock().await;
+        if stop.is_some() {
+            return Ok(());
+        }
+
+        let dal = DAL::new(self.database.clone());
+        let mut cursor = dal.execution_event().max_sequence().await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to start lifecycle hook dispatcher: {}", e),
+            }
+        })?;
+        let (stop_tx, mut stop_rx) = watch::channel(false);
+        *stop = Some(stop_tx);
+
+        let hooks = self.hooks.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(HOOK_POLL_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    res = stop_rx.changed() => {
+                        if res.is_err() || *stop_rx.borrow() {
+                            break;
+                        }
+                    }
+                }
+
+                let events = match dal
+                    .execution_event()
+                    .list_after_sequence(cursor, HOOK_BATCH_LIMIT)
+                    .await
+                {
+                    Ok(events) => events,
+                    Err(e) => {
+                        warn!("Lifecycle hook poll failed: {}", e);
+                        continue;
+                    }
+                };
+
+                for event in events {
+                    cursor = event.sequence_num;
+                    let Some(event_type) = ExecutionEventType::from_str(&event.event_type) else {
+                        continue;
+                    };
+                    let handlers = hooks.handlers_for(event_type);
+                    if handlers.is_empty() {
+                        continue;
+                    }
+                    if let Some(hook_event) = hook_event(&dal, &event, event_type).await {
+                        dispatch(handlers, hook_event, HOOK_TIMEOUT).await;
+                    }
+                }
+            }
+            debug!("Lifecycle hook dispatcher stopped");
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn event() -> HookEvent {
+        HookEvent {
+            event_type: ExecutionEventType::TaskFailed,
+            execution_id: UniversalUuid::new_v4(),
+            workflow_name: "etl".to_string(),
+            task_execution_id: None,
+            task_name: Some("etl::load".to_string()),
+            data: None,
+            occurred_at: Utc::now(),
+            sequence_num: 1,
+        }
+    }
+
+    fn recorder(calls: &Arc<Mutex<Vec<&'static str>>>, name: &'static str) -> HookFn {
+        let calls = calls.clone();
+        Arc::new(move |_| {
+            calls.lock().unwrap().push(name);
+            Box::pin(async { Ok(()) })
+        })
+    }
+
+    #[test]
+    fn test_handlers_are_selected_in_registration_order() {
+        let hooks = LifecycleHooks::new();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        hooks.add(ExecutionEventType::TaskFailed, recorder(&calls, "first"));
+        let removed = hooks.add(ExecutionEventType::TaskFailed, recorder(&calls, "removed"));
+        hooks.add(
+            ExecutionEventType::WorkflowFailed,
+            recorder(&calls, "other"),
+        );
+        hooks.add(ExecutionEventType::TaskFailed, recorder(&calls, "second"));
+
+        assert!(hooks.remove(removed));
+        assert!(!hooks.remove(removed));
+        assert_eq!(hooks.handlers_for(ExecutionEventType::TaskFailed).len(), 2);
+        assert!(hooks
+            .handlers_for(ExecutionEventType::TaskCompleted)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failing_handlers_do_not_stop_later_ones() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let erroring: HookFn = Arc::new(|_| Box::pin(async { Err("pager down".into()) }));
+        let panicking: HookFn = Arc::new(|_| Box::pin(async { panic!("bug in hook") }));
+        let hanging: HookFn = Arc::new(|_| {
+            Box::pin(async {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(())
+            })
+        });
+
+        dispatch(
+            vec![
+                recorder(&calls, "first"),
+                erroring,
+                panicking,
+                hanging,
+                recorder(&calls, "last"),
+            ],
+            event(),
+            Duration::from_millis(50),
+        )
+        .await;
+
+        assert_eq!(*calls.lock().unwrap(), vec!["first", "last"]);
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Execution label API for the DefaultRunner.
+//!
+//! Labels are key/value metadata attached to an execution at submission
+//! (`source=api`, `customer=acme`) and queried without touching the context.
+
+use crate::dal::unified::workflow_execution::ExecutionListFilter;
+use crate::dal::DAL;
+use crate::executor::workflow_executor::{WorkflowExecution, WorkflowExecutionError};
+use crate::models::workflow_execution::{
+    validate_labels, ExecutionLabels, WorkflowExecutionRecord,
+};
+use crate::{Context, UniversalUuid};
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Executes a workflow asynchronously with labels attached
+    ///
+    /// Labels are validated before the execution is scheduled, so a malformed
+    /// label set starts nothing, and are written in the same transaction as
+    /// the execution row.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to execute
+    /// * `context` - Initial context for the workflow
+    /// * `labels` - Labels to attach to the execution
+    pub async fn execute_async_with_labels(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+        labels: &ExecutionLabels,
+    ) -> Result<WorkflowExecution, WorkflowExecutionError> {
+        validate_labels(labels).map_err(|e| WorkflowExecutionError::Configuration {
+            message: e.to_string(),
+        })?;
+        let execution_id = self
+            .scheduler
+            .schedule_workflow_execution_with_labels(workflow_name, context, labels)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to schedule workflow: {}", e),
+            })?;
+
+        Ok(WorkflowExecution::new(
+            execution_id,
+            workflow_name.to_string(),
+            self.clone(),
+        ))
+    }
+
+    /// Attach labels to an existing execution, replacing the values of keys
+    /// it already carries
+    pub async fn set_execution_labels(
+        &self,
+        execution_id: UniversalUuid,
+        labels: &ExecutionLabels,
+    ) -> Result<(), WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_execution()
+            .set_labels(execution_id, labels)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set execution labels: {}", e),
+            })
+    }
+
+    /// Labels attached to an execution
+    pub async fn get_execution_labels(
+        &self,
+        execution_id: UniversalUuid,
+    ) -> Result<ExecutionLabels, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_execution()
+            .get_labels(execution_id)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to get execution labels: {}", e),
+            })
+    }
+
+    /// List the most recent executions carrying every label in `labels`
+    ///
+    /// # Arguments
+    /// * `labels` - `(key, value)` pairs an execution must all carry
+    /// * `limit` - Maximum number of executions to return
+    pub async fn list_executions_by_labels(
+        &self,
+        labels: &[(String, String)],
+        limit: i64,
+    ) -> Result<Vec<WorkflowExecutionRecord>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.workflow_execution()
+            .list_filtered(ExecutionListFilter {
+                labels: labels.to_vec(),
+                limit,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to list executions by labels: {}", e),
+            })
+    }
+}
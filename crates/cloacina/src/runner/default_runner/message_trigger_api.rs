@@ -0,0 +1,159 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Message queue trigger registration for the DefaultRunner.
+//!
+//! See [`crate::trigger::MessageTrigger`] for the context layout and
+//! delivery guarantees.
+
+use std::sync::Arc;
+
+use tracing::{debug, info};
+
+use crate::dal::DAL;
+use crate::database::universal_types::UniversalBool;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::execution_event::ExecutionEventType;
+use crate::models::schedule::NewSchedule;
+use crate::trigger::{delivery_ids, MessageSource, MessageTrigger, Trigger};
+use crate::UniversalUuid;
+
+use super::{DefaultRunner, HookEvent, HookId, HookResult};
+
+/// A registered message trigger, for
+/// [`DefaultRunner::unregister_message_trigger`].
+#[derive(Debug, Clone)]
+pub struct MessageTriggerHandle {
+    pub name: String,
+    /// ID of the backing trigger schedule
+    pub schedule_id: UniversalUuid,
+    hooks: [HookId; 2],
+}
+
+impl DefaultRunner {
+    /// Register a message queue trigger that fires `workflow_name`
+    ///
+    /// Registers `trigger` with the runtime, creates (or refreshes) its
+    /// trigger schedule, and installs lifecycle hooks that settle each
+    /// message when the execution it started finishes: ack on
+    /// `WorkflowCompleted`, nack on `WorkflowFailed`.
+    ///
+    /// # Arguments
+    /// * `trigger` - The trigger, shared with the runtime
+    /// * `workflow_name` - Workflow to start for each message (or batch)
+    ///
+    /// # Returns
+    /// * `Result<MessageTriggerHandle, WorkflowExecutionError>` - Handle for unregistering
+    pub async fn register_message_trigger<S: MessageSource>(
+        &self,
+        trigger: Arc<MessageTrigger<S>>,
+        workflow_name: &str,
+    ) -> Result<MessageTriggerHandle, WorkflowExecutionError> {
+        let name = trigger.name().to_string();
+        let dal = DAL::new(self.database.clone());
+        self.ensure_not_deprecated(&dal, workflow_name).await?;
+
+        let mut new_schedule = NewSchedule::trigger(&name, workflow_name, trigger.poll_interval());
+        new_schedule.allow_concurrent = Some(UniversalBool::new(trigger.allow_concurrent()));
+        let schedule = dal
+            .schedule()
+            .upsert_trigger(new_schedule)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to register message trigger: {}", e),
+            })?;
+
+        let mut hooks = Vec::with_capacity(2);
+        for (event_type, succeeded) in [
+            (ExecutionEventType::WorkflowCompleted, true),
+            (ExecutionEventType::WorkflowFailed, false),
+        ] {
+            let trigger = trigger.clone();
+            let dal = dal.clone();
+            let hook = self
+                .on(event_type, move |event| {
+                    settle_messages(dal.clone(), trigger.clone(), event, succeeded)
+                })
+                .await?;
+            hooks.push(hook);
+        }
+
+        let registered = trigger.clone();
+        self.runtime
+            .register_trigger(name.clone(), move || registered.clone());
+
+        info!(
+            "Registered message trigger '{}' ({}) for workflow '{}'",
+            name,
+            trigger.source().describe(),
+            workflow_name
+        );
+        Ok(MessageTriggerHandle {
+            name,
+            schedule_id: schedule.id,
+            hooks: [hooks[0], hooks[1]],
+        })
+    }
+
+    /// Stop consuming for a message trigger
+    ///
+    /// Removes the trigger from the runtime and its settle hooks. Messages
+    /// still in flight are never acknowledged and will be redelivered by the
+    /// broker. The backing schedule and its history are kept.
+    pub fn unregister_message_trigger(&self, handle: &MessageTriggerHandle) {
+        self.runtime.unregister_trigger(&handle.name);
+        for hook in handle.hooks {
+            self.off(hook);
+        }
+    }
+}
+
+/// Settle the messages that started the finished execution, if any were
+/// received by `trigger`.
+async fn settle_messages<S: MessageSource>(
+    dal: DAL,
+    trigger: Arc<MessageTrigger<S>>,
+    event: HookEvent,
+    succeeded: bool,
+) -> HookResult {
+    if trigger.in_flight() == 0 {
+        return Ok(());
+    }
+    let execution = dal
+        .workflow_execution()
+        .get_by_id(event.execution_id)
+        .await?;
+    let Some(context_id) = execution.context_id else {
+        return Ok(());
+    };
+    let context = dal.context().read::<serde_json::Value>(context_id).await?;
+    let ids = delivery_ids(&context);
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let settled = trigger.settle(&ids, succeeded).await;
+    if settled > 0 {
+        debug!(
+            "Message trigger '{}' settled {} message(s) for execution {} ({})",
+            trigger.name(),
+            settled,
+            event.execution_id,
+            if succeeded { "ack" } else { "nack" }
+        );
+    }
+    Ok(())
+}
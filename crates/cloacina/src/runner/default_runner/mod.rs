@@ -26,15 +26,55 @@
 //! - `DefaultRunnerConfig`: Configuration options
 //! - `DefaultRunnerBuilder`: Builder for creating runners with custom settings
 
+mod annotations_api;
+mod approvals_api;
+mod artifacts_api;
+mod catalog_api;
+mod child_workflow_api;
 mod config;
+mod context_access_api;
+mod context_lineage_api;
 mod cron_api;
+mod delayed_execution_api;
+mod deprecation_api;
+mod execution_graph_api;
+mod execution_options_api;
+mod execution_query_api;
+mod handoff_api;
+mod hooks_api;
+mod labels_api;
+mod message_trigger_api;
+mod notifications_api;
+mod queue_stats_api;
 mod reactor_subscriptions_api;
+mod schedule_api;
 mod service_manager;
 mod services;
+mod streaming_api;
+mod task_resolution_api;
+#[cfg(feature = "webhooks")]
+mod webhook_api;
 mod workflow_executor_impl;
 mod workflow_result;
 
 pub use config::{DefaultRunnerBuilder, DefaultRunnerConfig, DefaultRunnerConfigBuilder};
+pub use context_access_api::{ContextAccessFinding, ContextAccessReport};
+pub use context_lineage_api::{ContextLineage, KeyWrite};
+pub use cron_api::CronScheduleOptions;
+pub use deprecation_api::WorkflowConsumers;
+pub use execution_graph_api::{
+    ExecutionGraph, ExecutionGraphNode, ExecutionGraphUpdate, ExecutionGraphWatch,
+};
+pub use execution_options_api::{ExecutionOptions, ExecutionParams};
+pub use execution_query_api::{ExecutionFilter, ExecutionPage, ExecutionSort, ExecutionSummary};
+pub use hooks_api::{HookEvent, HookId, HookResult};
+pub use message_trigger_api::MessageTriggerHandle;
+pub use notifications_api::{ExecutionNotification, NotificationConfig, NotificationKind};
+pub use queue_stats_api::QueueStats;
+pub use streaming_api::{
+    ExecutionStream, ExecutionUpdate, StreamingOptions, TaskOutcome, TaskUpdate,
+};
+pub use task_resolution_api::ResolvedTask;
 
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -79,6 +119,22 @@ pub struct DefaultRunner {
     /// mutating schedules so the scheduler re-arms its sleep immediately
     /// instead of waiting for the backstop.
     pub(super) cron_change: Arc<tokio::sync::Notify>,
+    /// This runner's row in `runner_instances` when handoff is enabled.
+    pub(super) instance_id: crate::UniversalUuid,
+    /// Raised by the handoff monitor once a successor asks this runner to
+    /// drain. Shared across clones.
+    pub(super) handoff: Arc<tokio::sync::watch::Sender<bool>>,
+    /// The in-process thread executor, kept so a drain can wait for its
+    /// in-flight tasks.
+    pub(super) local_executor: Arc<dyn TaskExecutor>,
+    /// Lifecycle hooks registered with `on()`, shared across clones.
+    pub(super) hooks: Arc<hooks_api::LifecycleHooks>,
+    /// Extensions installed by the builder, shared across clones.
+    pub(super) extensions: Arc<crate::extensions::InstalledExtensions>,
+    /// Webhook triggers registered with `register_webhook_trigger`, shared
+    /// across clones.
+    #[cfg(feature = "webhooks")]
+    pub(super) webhooks: Arc<crate::webhook::WebhookRegistry>,
 }
 
 impl DefaultRunner {
@@ -161,7 +217,8 @@ impl DefaultRunner {
             TaskScheduler::with_poll_interval(database.clone(), config.scheduler_poll_interval())
                 .await
                 .map_err(|e| WorkflowExecutionError::Executor(e.into()))?
-                .with_runtime(runtime.clone());
+                .with_runtime(runtime.clone())
+                .with_execution_limits(config.execution_limits().clone());
 
         // Create task executor
         let executor_config = ExecutorConfig {
@@ -169,6 +226,10 @@ impl DefaultRunner {
             task_timeout: config.task_timeout(),
             enable_claiming: config.enable_claiming(),
             heartbeat_interval: config.heartbeat_interval(),
+            trace_context_access: config.trace_context_access(),
+            track_context_lineage: config.track_context_lineage(),
+            workload_limits: config.workload_limits().clone(),
+            resource_pools: config.resource_pools().clone(),
         };
 
         let executor = ThreadTaskExecutor::with_runtime_and_registry(
@@ -186,7 +247,8 @@ impl DefaultRunner {
         let dal = DAL::new(database.clone());
         let dispatcher = DefaultDispatcher::new(dal, config.default_executor());
 
-        dispatcher.register_executor("default", Arc::new(executor) as Arc<dyn TaskExecutor>);
+        let local_executor = Arc::new(executor) as Arc<dyn TaskExecutor>;
+        dispatcher.register_executor("default", local_executor.clone());
 
         let scheduler = scheduler.with_dispatcher(Arc::new(dispatcher));
 
@@ -197,6 +259,13 @@ impl DefaultRunner {
             scheduler: Arc::new(scheduler),
             service_manager: Arc::new(RwLock::new(ServiceManager::new())),
             cron_change: Arc::new(tokio::sync::Notify::new()),
+            instance_id: crate::UniversalUuid::new_v4(),
+            handoff: Arc::new(tokio::sync::watch::channel(false).0),
+            local_executor,
+            hooks: Arc::new(hooks_api::LifecycleHooks::new()),
+            extensions: Arc::new(crate::extensions::InstalledExtensions::default()),
+            #[cfg(feature = "webhooks")]
+            webhooks: Arc::new(crate::webhook::WebhookRegistry::new()),
         };
 
         // Start the background services immediately
@@ -210,6 +279,12 @@ impl DefaultRunner {
         &self.database
     }
 
+    /// Names of the extensions installed with
+    /// [`DefaultRunnerBuilder::extension`], in installation order.
+    pub fn extensions(&self) -> &[String] {
+        &self.extensions.names
+    }
+
     /// Returns the DAL for database operations.
     pub fn dal(&self) -> DAL {
         DAL::new(self.database.clone())
@@ -266,7 +341,20 @@ impl DefaultRunner {
 
     /// Gracefully shuts down the executor and its background services.
     pub async fn shutdown(&self) -> Result<(), WorkflowExecutionError> {
+        self.hooks.stop().await;
+        #[cfg(feature = "webhooks")]
+        self.webhooks.stop();
         self.service_manager.write().await.shutdown_all().await?;
+        if self.config.enable_handoff() {
+            if let Err(e) = self
+                .dal()
+                .runner_instance()
+                .mark_stopped(self.instance_id)
+                .await
+            {
+                tracing::warn!("Failed to mark runner {} stopped: {}", self.instance_id, e);
+            }
+        }
         // Close the database connection pool to release all connections
         self.database.close();
         Ok(())
@@ -284,6 +372,13 @@ impl Clone for DefaultRunner {
             // Share the SAME Notify across clones so a cron-change signal from
             // any runner handle reaches the one scheduler loop (CLOACI-T-0743).
             cron_change: self.cron_change.clone(),
+            instance_id: self.instance_id,
+            handoff: self.handoff.clone(),
+            local_executor: self.local_executor.clone(),
+            hooks: self.hooks.clone(),
+            extensions: self.extensions.clone(),
+            #[cfg(feature = "webhooks")]
+            webhooks: self.webhooks.clone(),
         }
     }
 }
@@ -0,0 +1,446 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Failure and SLA notification API for the DefaultRunner.
+//!
+//! [`DefaultRunner::watch_notifications`] tails the execution event log for
+//! failures and checks running executions against per-workflow SLAs, so a
+//! desktop shell can raise OS notifications. Every notification carries the
+//! execution id, which is what a click-through needs to open the execution
+//! detail view ([`DefaultRunner::get_execution_graph`]).
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::execution_event::{ExecutionEvent, ExecutionEventType};
+use crate::models::workflow_execution::WorkflowExecutionRecord;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// Buffered notifications before the poller waits on the consumer.
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 256;
+
+/// Events read from the log per poll.
+const EVENT_BATCH_LIMIT: i64 = 500;
+
+/// What a notification reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// The workflow execution failed
+    WorkflowFailed,
+    /// A task attempt failed; a retry may still follow
+    TaskFailed,
+    /// A running execution exceeded its workflow's SLA
+    SlaMissed,
+}
+
+/// One notification for a watcher.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionNotification {
+    pub kind: NotificationKind,
+    /// Execution to open on click-through
+    pub execution_id: UniversalUuid,
+    pub workflow_name: String,
+    /// Failed task, for `TaskFailed`
+    pub task_name: Option<String>,
+    /// Failure reason or SLA breach description
+    pub message: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// What a notification watcher reports and how often it polls.
+#[derive(Debug, Clone)]
+pub struct NotificationConfig {
+    /// How often to read new events and check SLAs
+    pub poll_interval: Duration,
+    /// Report individual task failures as well as workflow failures
+    pub include_task_failures: bool,
+    /// Maximum run time per workflow name
+    pub workflow_slas: HashMap<String, Duration>,
+    /// Maximum run time for workflows without an entry in `workflow_slas`
+    pub default_sla: Option<Duration>,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(2),
+            include_task_failures: false,
+            workflow_slas: HashMap::new(),
+            default_sla: None,
+        }
+    }
+}
+
+impl NotificationConfig {
+    /// Sets the SLA for one workflow.
+    pub fn with_sla(mut self, workflow_name: impl Into<String>, max_duration: Duration) -> Self {
+        self.workflow_slas
+            .insert(workflow_name.into(), max_duration);
+        self
+    }
+
+    /// SLA that applies to `workflow_name`, if any.
+    pub fn sla_for(&self, workflow_name: &str) -> Option<Duration> {
+        self.workflow_slas
+            .get(workflow_name)
+            .copied()
+            .or(self.default_sla)
+    }
+
+    fn checks_slas(&self) -> bool {
+        self.default_sla.is_some() || !self.workflow_slas.is_empty()
+    }
+}
+
+/// Builds the notification for a failure event, or `None` for events the
+/// config does not report.
+fn notification_for_event(
+    config: &NotificationConfig,
+    event: &ExecutionEvent,
+    workflow_name: &str,
+    task_name: Option<String>,
+) -> Option<ExecutionNotification> {
+    let kind = match ExecutionEventType::from_str(&event.event_type)? {
+        ExecutionEventType::WorkflowFailed => NotificationKind::WorkflowFailed,
+        ExecutionEventType::TaskFailed if config.include_task_failures => {
+            NotificationKind::TaskFailed
+        }
+        _ => return None,
+    };
+
+    let reason = event
+        .event_data
+        .as_deref()
+        .and_then(|data| serde_json::from_str::<serde_json::Value>(data).ok())
+        .and_then(|data| {
+            ["reason", "error"]
+                .iter()
+                .find_map(|key| data.get(*key).and_then(|v| v.as_str()).map(str::to_string))
+        });
+    let message = match (&kind, &task_name) {
+        (NotificationKind::TaskFailed, Some(task)) => format!("Task '{}' failed", task),
+        (NotificationKind::TaskFailed, None) => "Task failed".to_string(),
+        _ => format!("Workflow '{}' failed", workflow_name),
+    };
+    let message = match reason {
+        Some(reason) => format!("{}: {}", message, reason),
+        None => message,
+    };
+
+    Some(ExecutionNotification {
+        kind,
+        execution_id: event.workflow_execution_id,
+        workflow_name: workflow_name.to_string(),
+        task_name,
+        message,
+        occurred_at: event.created_at.0,
+    })
+}
+
+/// SLA breaches among `active` executions not already in `notified`.
+///
+/// Each execution is reported once; ids that are no longer active are dropped
+/// from `notified` so the set does not grow with history.
+fn sla_breaches(
+    config: &NotificationConfig,
+    active: &[WorkflowExecutionRecord],
+    now: DateTime<Utc>,
+    notified: &mut HashSet<UniversalUuid>,
+) -> Vec<ExecutionNotification> {
+    notified.retain(|id| active.iter().any(|e| e.id == *id));
+
+    let mut breaches = Vec::new();
+    for execution in active {
+        let Some(sla) = config.sla_for(&execution.workflow_name) else {
+            continue;
+        };
+        let Ok(sla) = chrono::Duration::from_std(sla) else {
+            continue;
+        };
+        let deadline = execution.started_at.0 + sla;
+        if now <= deadline || notified.contains(&execution.id) {
+            continue;
+        }
+        notified.insert(execution.id);
+        let elapsed = (now - execution.started_at.0).num_seconds();
+        breaches.push(ExecutionNotification {
+            kind: NotificationKind::SlaMissed,
+            execution_id: execution.id,
+            workflow_name: execution.workflow_name.clone(),
+            task_name: None,
+            message: format!(
+                "Workflow '{}' has been running for {}s, over its {}s SLA",
+                execution.workflow_name,
+                elapsed,
+                sla.num_seconds()
+            ),
+            occurred_at: deadline,
+        });
+    }
+    breaches
+}
+
+impl DefaultRunner {
+    /// Stream of failure and SLA-miss notifications
+    ///
+    /// Only events written after the call are reported. The stream stays open
+    /// until the receiver is dropped.
+    ///
+    /// # Arguments
+    /// * `config` - What to report and the per-workflow SLAs
+    pub async fn watch_notifications(
+        &self,
+        config: NotificationConfig,
+    ) -> Result<mpsc::Receiver<ExecutionNotification>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let mut cursor = dal.execution_event().max_sequence().await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to start notification watch: {}", e),
+            }
+        })?;
+        let (tx, rx) = mpsc::channel(NOTIFICATION_CHANNEL_CAPACITY);
+
+        tokio::spawn(async move {
+            let mut workflow_names: HashMap<UniversalUuid, String> = HashMap::new();
+            let mut sla_notified: HashSet<UniversalUuid> = HashSet::new();
+            let mut interval = tokio::time::interval(config.poll_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            while !tx.is_closed() {
+                interval.tick().await;
+
+                let events = match dal
+                    .execution_event()
+                    .list_after_sequence(cursor, EVENT_BATCH_LIMIT)
+                    .await
+                {
+                    Ok(events) => events,
+                    Err(e) => {
+                        warn!("Notification poll failed: {}", e);
+                        continue;
+                    }
+                };
+
+                for event in events {
+                    cursor = event.sequence_num;
+                    let Some(event_type) = ExecutionEventType::from_str(&event.event_type) else {
+                        continue;
+                    };
+                    match event_type {
+                        ExecutionEventType::WorkflowFailed => {}
+                        ExecutionEventType::TaskFailed if config.include_task_failures => {}
+                        ExecutionEventType::WorkflowCompleted => {
+                            workflow_names.remove(&event.workflow_execution_id);
+                            continue;
+                        }
+                        _ => continue,
+                    }
+
+                    let workflow_name = match workflow_names.get(&event.workflow_execution_id) {
+                        Some(name) => name.clone(),
+                        None => match dal
+                            .workflow_execution()
+                            .get_by_id(event.workflow_execution_id)
+                            .await
+                        {
+                            Ok(execution) => {
+                                workflow_names.insert(
+                                    event.workflow_execution_id,
+                                    execution.workflow_name.clone(),
+                                );
+                                execution.workflow_name
+                            }
+                            Err(e) => {
+                                debug!(
+                                    "Skipping event for unknown execution {}: {}",
+                                    event.workflow_execution_id, e
+                                );
+                                continue;
+                            }
+                        },
+                    };
+                    let task_name = match event.task_execution_id {
+                        Some(task_id) => dal
+                            .task_execution()
+                            .get_by_id(task_id)
+                            .await
+                            .ok()
+                            .map(|task| task.task_name),
+                        None => None,
+                    };
+                    if event_type == ExecutionEventType::WorkflowFailed {
+                        workflow_names.remove(&event.workflow_execution_id);
+                    }
+
+                    if let Some(notification) =
+                        notification_for_event(&config, &event, &workflow_name, task_name)
+                    {
+                        if tx.send(notification).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                if config.checks_slas() {
+                    let active = match dal.workflow_execution().get_active_executions().await {
+                        Ok(active) => active,
+                        Err(e) => {
+                            warn!("SLA check failed: {}", e);
+                            continue;
+                        }
+                    };
+                    for notification in
+                        sla_breaches(&config, &active, Utc::now(), &mut sla_notified)
+                    {
+                        if tx.send(notification).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UniversalTimestamp;
+
+    fn event(event_type: ExecutionEventType, data: Option<&str>) -> ExecutionEvent {
+        ExecutionEvent {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_execution_id: None,
+            event_type: event_type.as_str().to_string(),
+            event_data: data.map(str::to_string),
+            worker_id: None,
+            created_at: UniversalTimestamp::now(),
+            sequence_num: 1,
+            request_id: None,
+            runner_id: None,
+            tenant_id: None,
+        }
+    }
+
+    fn running(workflow_name: &str, started_secs_ago: i64) -> WorkflowExecutionRecord {
+        let started = UniversalTimestamp(Utc::now() - chrono::Duration::seconds(started_secs_ago));
+        WorkflowExecutionRecord {
+            id: UniversalUuid::new_v4(),
+            workflow_name: workflow_name.to_string(),
+            workflow_version: "1.0.0".to_string(),
+            status: "Running".to_string(),
+            context_id: None,
+            started_at: started,
+            completed_at: None,
+            error_details: None,
+            recovery_attempts: 0,
+            last_recovery_at: None,
+            paused_at: None,
+            pause_reason: None,
+            created_at: started,
+            updated_at: started,
+            trigger_origin: None,
+            run_id: None,
+            logical_date: None,
+            attempt: 1,
+            retry_at: None,
+            admitted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_workflow_failure_carries_reason_and_execution() {
+        let config = NotificationConfig::default();
+        let failed = event(
+            ExecutionEventType::WorkflowFailed,
+            Some(r#"{"reason":"task extract abandoned"}"#),
+        );
+
+        let notification = notification_for_event(&config, &failed, "etl", None).unwrap();
+        assert_eq!(notification.kind, NotificationKind::WorkflowFailed);
+        assert_eq!(notification.execution_id, failed.workflow_execution_id);
+        assert_eq!(
+            notification.message,
+            "Workflow 'etl' failed: task extract abandoned"
+        );
+
+        let completed = event(ExecutionEventType::WorkflowCompleted, None);
+        assert!(notification_for_event(&config, &completed, "etl", None).is_none());
+    }
+
+    #[test]
+    fn test_task_failures_are_opt_in() {
+        let failed = event(ExecutionEventType::TaskFailed, None);
+        let task = Some("etl::extract".to_string());
+
+        let config = NotificationConfig::default();
+        assert!(notification_for_event(&config, &failed, "etl", task.clone()).is_none());
+
+        let config = NotificationConfig {
+            include_task_failures: true,
+            ..Default::default()
+        };
+        let notification = notification_for_event(&config, &failed, "etl", task).unwrap();
+        assert_eq!(notification.kind, NotificationKind::TaskFailed);
+        assert_eq!(notification.message, "Task 'etl::extract' failed");
+    }
+
+    #[test]
+    fn test_sla_breach_reported_once() {
+        let config = NotificationConfig::default().with_sla("etl", Duration::from_secs(60));
+        let late = running("etl", 120);
+        let on_time = running("etl", 10);
+        let no_sla = running("report", 3600);
+        let active = vec![late.clone(), on_time, no_sla];
+        let mut notified = HashSet::new();
+
+        let breaches = sla_breaches(&config, &active, Utc::now(), &mut notified);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].kind, NotificationKind::SlaMissed);
+        assert_eq!(breaches[0].execution_id, late.id);
+
+        assert!(sla_breaches(&config, &active, Utc::now(), &mut notified).is_empty());
+
+        // Finished executions are forgotten.
+        sla_breaches(&config, &[], Utc::now(), &mut notified);
+        assert!(notified.is_empty());
+    }
+
+    #[test]
+    fn test_default_sla_applies_to_unlisted_workflows() {
+        let config = NotificationConfig {
+            default_sla: Some(Duration::from_secs(30)),
+            ..Default::default()
+        }
+        .with_sla("etl", Duration::from_secs(600));
+
+        assert_eq!(config.sla_for("etl"), Some(Duration::from_secs(600)));
+        assert_eq!(config.sla_for("report"), Some(Duration::from_secs(30)));
+    }
+}
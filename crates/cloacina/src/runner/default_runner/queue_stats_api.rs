@@ -0,0 +1,69 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Queue introspection API for the DefaultRunner.
+//!
+//! [`DefaultRunner::queue_stats`] reports how many tasks are waiting to be
+//! claimed, how long claimed tasks waited, and how long this runner's
+//! scheduler loop takes per tick. The first two come from the database and
+//! cover every runner sharing it; loop latency is local to this process.
+
+use serde::{Deserialize, Serialize};
+
+use crate::dal::{DispatchLatencyStats, ReadyQueueStats, DAL};
+use crate::execution_planner::SchedulerLoopStats;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+
+use super::DefaultRunner;
+
+/// Claim queue and scheduler loop metrics for capacity planning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueStats {
+    /// Tasks waiting to be claimed right now
+    pub ready_queue: ReadyQueueStats,
+    /// Time from claimable to claimed over the requested window
+    pub dispatch_latency: DispatchLatencyStats,
+    pub scheduler_loop: SchedulerLoopStats,
+}
+
+impl DefaultRunner {
+    /// Current claim queue depth, time-to-dispatch over the last `window`,
+    /// and this runner's scheduler loop latency.
+    pub async fn queue_stats(
+        &self,
+        window: std::time::Duration,
+    ) -> Result<QueueStats, WorkflowExecutionError> {
+        let window = chrono::Duration::from_std(window).map_err(|_| {
+            WorkflowExecutionError::Configuration {
+                message: "Stats window is too large".to_string(),
+            }
+        })?;
+        let dal = DAL::new(self.database.clone());
+        let stats = dal.execution_stats();
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to query queue stats: {}", e),
+        };
+
+        Ok(QueueStats {
+            ready_queue: stats.ready_queue().await.map_err(map_err)?,
+            dispatch_latency: stats
+                .dispatch_latency(chrono::Utc::now() - window)
+                .await
+                .map_err(map_err)?,
+            scheduler_loop: self.scheduler.loop_stats(),
+        })
+    }
+}
@@ -0,0 +1,355 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Schedule management API for the DefaultRunner.
+//!
+//! Operations here apply to every schedule regardless of type (cron or
+//! trigger), as opposed to the cron-only surface in `cron_api`.
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Replace the tags on a schedule (cron or trigger)
+    ///
+    /// Tags group schedules so they can be paused and resumed together with
+    /// [`pause_schedules`](Self::pause_schedules) /
+    /// [`resume_schedules`](Self::resume_schedules). An empty slice clears them.
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the schedule to tag
+    /// * `tags` - The complete new tag set
+    pub async fn set_schedule_tags(
+        &self,
+        schedule_id: UniversalUuid,
+        tags: &[String],
+    ) -> Result<(), WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.schedule()
+            .set_tags(schedule_id, tags)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set schedule tags: {}", e),
+            })
+    }
+
+    /// List every schedule (cron or trigger) carrying `tag`
+    pub async fn list_schedules_by_tag(
+        &self,
+        tag: &str,
+    ) -> Result<Vec<crate::models::schedule::Schedule>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.schedule()
+            .find_by_tag(tag)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to list schedules by tag: {}", e),
+            })
+    }
+
+    /// Pause a single schedule (cron or trigger)
+    ///
+    /// The scheduler stops firing it until [`resume_schedule`](Self::resume_schedule);
+    /// other schedules, including others for the same workflow, keep
+    /// running. In-flight executions are unaffected. The pause time and
+    /// `reason` are stored on the schedule; pausing an already paused
+    /// schedule replaces the reason.
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the schedule to pause
+    /// * `reason` - Why it is paused, kept until it is resumed
+    pub async fn pause_schedule(
+        &self,
+        schedule_id: UniversalUuid,
+        reason: Option<&str>,
+    ) -> Result<(), WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to load schedule {}: {}", schedule_id, e),
+            }
+        })?;
+        dal.schedule()
+            .pause(schedule_id, None, reason)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to pause schedule {}: {}", schedule_id, e),
+            })?;
+        // Pausing shrinks the due set — re-arm the cron scheduler (T-0743).
+        self.cron_change.notify_one();
+        tracing::info!("Paused schedule {}", schedule_id);
+        Ok(())
+    }
+
+    /// Resume a single paused schedule
+    ///
+    /// It fires again on its next regular occurrence; fires missed while
+    /// paused are not caught up. Resuming a schedule that is not paused is a
+    /// no-op.
+    pub async fn resume_schedule(
+        &self,
+        schedule_id: UniversalUuid,
+    ) -> Result<(), WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to load schedule {}: {}", schedule_id, e),
+            }
+        })?;
+        dal.schedule().resume(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to resume schedule {}: {}", schedule_id, e),
+            }
+        })?;
+        self.cron_change.notify_one();
+        tracing::info!("Resumed schedule {}", schedule_id);
+        Ok(())
+    }
+
+    /// Pause every schedule carrying `tag` in one call
+    ///
+    /// Intended for incidents: pausing e.g. `"warehouse"` stops every cron
+    /// and trigger schedule touching the warehouse from firing. In-flight
+    /// executions are unaffected. Schedules that were already paused are left
+    /// as they are.
+    ///
+    /// # Returns
+    /// * `Result<Vec<UniversalUuid>, WorkflowExecutionError>` - IDs of the schedules that were paused
+    pub async fn pause_schedules(
+        &self,
+        tag: &str,
+    ) -> Result<Vec<UniversalUuid>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let paused = dal.schedule().pause_by_tag(tag).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to pause schedules tagged '{}': {}", tag, e),
+            }
+        })?;
+        // Pausing shrinks the due set — re-arm the cron scheduler (T-0743).
+        self.cron_change.notify_one();
+        tracing::info!("Paused {} schedule(s) tagged '{}'", paused.len(), tag);
+        Ok(paused)
+    }
+
+    /// Resume every paused schedule carrying `tag` in one call
+    ///
+    /// Resumed schedules fire on their next regular occurrence; fires missed
+    /// while paused are not caught up.
+    ///
+    /// # Returns
+    /// * `Result<Vec<UniversalUuid>, WorkflowExecutionError>` - IDs of the schedules that were resumed
+    pub async fn resume_schedules(
+        &self,
+        tag: &str,
+    ) -> Result<Vec<UniversalUuid>, WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let resumed = dal.schedule().resume_by_tag(tag).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to resume schedules tagged '{}': {}", tag, e),
+            }
+        })?;
+        self.cron_change.notify_one();
+        tracing::info!("Resumed {} schedule(s) tagged '{}'", resumed.len(), tag);
+        Ok(resumed)
+    }
+
+    /// Configure deduplication for a trigger schedule
+    ///
+    /// A firing is suppressed when an execution with the same context hash
+    /// is still in flight (unless the schedule allows concurrency) and, with
+    /// a `window`, when any firing with the same hash started within it.
+    /// `keys` restricts the hash to those top-level context keys, e.g.
+    /// `["path"]` with a 24h window fires once per file per day. Suppressed
+    /// firings are recorded in the schedule's execution history with the
+    /// reason and the firing they duplicated.
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the trigger schedule
+    /// * `window` - Dedup window; `None` only deduplicates against in-flight executions
+    /// * `keys` - Context keys to hash; empty hashes the whole context
+    pub async fn set_trigger_dedup(
+        &self,
+        schedule_id: UniversalUuid,
+        window: Option<std::time::Duration>,
+        keys: &[String],
+    ) -> Result<(), WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let schedule = dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to load schedule {}: {}", schedule_id, e),
+            }
+        })?;
+        if !schedule.is_trigger() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} is a cron schedule; dedup applies to triggers only",
+                    schedule_id
+                ),
+            });
+        }
+
+        let window_secs = window
+            .filter(|w| !w.is_zero())
+            .map(crate::models::schedule::dedup_window_secs);
+        dal.schedule()
+            .set_dedup(schedule_id, window_secs, keys)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set trigger dedup: {}", e),
+            })
+    }
+
+    /// Configure throttling for a trigger schedule
+    ///
+    /// With `min_interval_between_fires`, a firing sooner than that after the
+    /// previous fire is suppressed and recorded with reason `rate_limited`.
+    /// With `debounce_window`, a firing of an unbatched trigger is held and
+    /// replaced by any later firing, and only runs once the trigger has not
+    /// fired for the window. `None` (or zero) turns either off.
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the trigger schedule
+    /// * `min_interval_between_fires` - Minimum time between two fires
+    /// * `debounce_window` - Quiet time required before a held firing runs
+    pub async fn set_trigger_throttle(
+        &self,
+        schedule_id: UniversalUuid,
+        min_interval_between_fires: Option<std::time::Duration>,
+        debounce_window: Option<std::time::Duration>,
+    ) -> Result<(), WorkflowExecutionError> {
+        let dal = DAL::new(self.database.clone());
+        let schedule = dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to load schedule {}: {}", schedule_id, e),
+            }
+        })?;
+        if !schedule.is_trigger() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} is a cron schedule; throttling applies to triggers only",
+                    schedule_id
+                ),
+            });
+        }
+
+        use crate::models::schedule::throttle_millis;
+        dal.schedule()
+            .set_throttle(
+                schedule_id,
+                throttle_millis(min_interval_between_fires),
+                throttle_millis(debounce_window),
+            )
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set trigger throttle: {}", e),
+            })
+    }
+
+    /// Set or clear a trigger schedule's declarative condition
+    ///
+    /// Each poll runs `probes` and evaluates `condition` (CEL) over the fired
+    /// `context` and the `probes` results; a firing is launched only when it
+    /// holds. `None` clears the condition and the probes. See
+    /// [`crate::trigger::validate_trigger_condition`].
+    ///
+    /// # Arguments
+    /// * `schedule_id` - UUID of the trigger schedule
+    /// * `condition` - CEL expression, or `None` to clear
+    /// * `probes` - Probes the condition can read as `probes.<name>`
+    pub async fn set_trigger_condition(
+        &self,
+        schedule_id: UniversalUuid,
+        condition: Option<&str>,
+        probes: Vec<crate::trigger::TriggerProbe>,
+    ) -> Result<(), WorkflowExecutionError> {
+        use crate::trigger::{trigger_probes_json, validate_trigger_condition};
+
+        let dal = DAL::new(self.database.clone());
+        let schedule = dal.schedule().get_by_id(schedule_id).await.map_err(|e| {
+            WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to load schedule {}: {}", schedule_id, e),
+            }
+        })?;
+        if !schedule.is_trigger() {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!(
+                    "Schedule {} is a cron schedule; conditions apply to triggers only",
+                    schedule_id
+                ),
+            });
+        }
+
+        let (condition, probes) = match condition {
+            Some(condition) => {
+                validate_trigger_condition(condition, &probes).map_err(|e| {
+                    WorkflowExecutionError::Configuration {
+                        message: e.to_string(),
+                    }
+                })?;
+                (Some(condition.to_string()), trigger_probes_json(&probes))
+            }
+            None => (None, None),
+        };
+        dal.schedule()
+            .set_condition(schedule_id, condition, probes)
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to set trigger condition: {}", e),
+            })
+    }
+
+    /// Register a declarative trigger that fires `workflow_name`
+    ///
+    /// The trigger needs no Rust implementation: every `poll_interval` the
+    /// scheduler runs `probes` and fires when `condition` holds, with the
+    /// probe results in the context under `probes`. Re-registering a name
+    /// replaces its workflow, interval and condition.
+    ///
+    /// # Returns
+    /// * `Result<UniversalUuid, WorkflowExecutionError>` - ID of the trigger schedule
+    pub async fn register_condition_trigger(
+        &self,
+        name: &str,
+        workflow_name: &str,
+        poll_interval: std::time::Duration,
+        condition: &str,
+        probes: Vec<crate::trigger::TriggerProbe>,
+    ) -> Result<UniversalUuid, WorkflowExecutionError> {
+        use crate::models::schedule::NewSchedule;
+
+        crate::trigger::validate_trigger_condition(condition, &probes).map_err(|e| {
+            WorkflowExecutionError::Configuration {
+                message: e.to_string(),
+            }
+        })?;
+        let dal = DAL::new(self.database.clone());
+        self.ensure_not_deprecated(&dal, workflow_name).await?;
+
+        let schedule = dal
+            .schedule()
+            .upsert_trigger(NewSchedule::trigger(name, workflow_name, poll_interval))
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to register condition trigger: {}", e),
+            })?;
+        self.set_trigger_condition(schedule.id, Some(condition), probes)
+            .await?;
+        Ok(schedule.id)
+    }
+}
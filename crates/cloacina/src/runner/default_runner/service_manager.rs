@@ -29,9 +29,16 @@ use tokio::task::JoinHandle;
 use tracing::Instrument;
 
 use crate::computation_graph::scheduler::ComputationGraphScheduler;
+use crate::context_retention::ContextRetentionSweeper;
 use crate::execution_planner::stale_claim_sweeper::StaleClaimSweeper;
 use crate::executor::workflow_executor::WorkflowExecutionError;
-use crate::registry::{traits::WorkflowRegistry, RegistryReconciler};
+use crate::registry::traits::RegistryStorage;
+use crate::registry::{
+    traits::WorkflowRegistry, ReconcilerHandle, RegistryGcSweeper, RegistryReconciler,
+};
+use crate::runner_handoff::RunnerHandoffMonitor;
+#[cfg(feature = "sqlite")]
+use crate::sqlite_maintenance::SqliteMaintenance;
 use crate::{CronRecoveryService, Scheduler, TaskScheduler};
 
 /// A background service whose lifecycle is owned by the [`ServiceManager`].
@@ -63,6 +70,7 @@ pub(in crate::runner) struct ServiceManager {
     // a field-per-service. Filled as services are registered.
     pub(super) cron_recovery: Option<Arc<CronRecoveryService>>,
     pub(super) workflow_registry: Option<Arc<dyn WorkflowRegistry>>,
+    pub(super) registry_reconciler: Option<ReconcilerHandle>,
     pub(super) unified_scheduler: Option<Arc<Scheduler>>,
     /// Shared graph-scheduler slot — set by `DefaultRunner::set_graph_scheduler`
     /// and observed by the registry reconciler. The slot is shared via Arc so
@@ -79,6 +87,7 @@ impl ServiceManager {
             shutdown_sent: false,
             cron_recovery: None,
             workflow_registry: None,
+            registry_reconciler: None,
             unified_scheduler: None,
             graph_scheduler: Arc::new(RwLock::new(None)),
         }
@@ -227,6 +236,11 @@ impl BackgroundService for UnifiedSchedulerService {
                         let _ = inner_tx.send(true);
                     }
                 }
+                // The polling loop may have been dropped mid-tick; hand its
+                // trigger leases to the other runners now rather than on expiry.
+                if let Err(e) = scheduler_clone.release_trigger_leases().await {
+                    tracing::warn!("Failed to release trigger leases: {}", e);
+                }
             }
             .instrument(span),
         );
@@ -446,3 +460,274 @@ impl BackgroundService for StaleClaimSweeperService {
         Ok(())
     }
 }
+
+/// Wraps the context retention sweeper loop.
+pub(super) struct ContextRetentionService {
+    sweeper: Option<ContextRetentionSweeper>,
+    inner_shutdown_tx: watch::Sender<bool>,
+    span: tracing::Span,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ContextRetentionService {
+    pub(super) fn new(
+        sweeper: ContextRetentionSweeper,
+        inner_shutdown_tx: watch::Sender<bool>,
+        span: tracing::Span,
+    ) -> Self {
+        Self {
+            sweeper: Some(sweeper),
+            inner_shutdown_tx,
+            span,
+            handle: None,
+        }
+    }
+}
+
+#[async_trait]
+impl BackgroundService for ContextRetentionService {
+    fn name(&self) -> &'static str {
+        "context_retention"
+    }
+
+    async fn start(
+        &mut self,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) -> Result<(), WorkflowExecutionError> {
+        let mut sweeper =
+            self.sweeper
+                .take()
+                .ok_or_else(|| WorkflowExecutionError::Configuration {
+                    message: "context retention sweeper already started".to_string(),
+                })?;
+        let inner_tx = self.inner_shutdown_tx.clone();
+        let span = self.span.clone();
+        let handle = tokio::spawn(
+            async move {
+                tokio::select! {
+                    _ = sweeper.run() => {
+                        tracing::info!("Context retention sweeper completed");
+                    }
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Context retention sweeper shutdown requested");
+                        let _ = inner_tx.send(true);
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), WorkflowExecutionError> {
+        if let Some(h) = self.handle.take() {
+            let _ = h.await;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps the registry garbage-collection loop.
+pub(super) struct RegistryGcService<S: RegistryStorage> {
+    sweeper: Option<RegistryGcSweeper<S>>,
+    inner_shutdown_tx: watch::Sender<bool>,
+    span: tracing::Span,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl<S: RegistryStorage> RegistryGcService<S> {
+    pub(super) fn new(
+        sweeper: RegistryGcSweeper<S>,
+        inner_shutdown_tx: watch::Sender<bool>,
+        span: tracing::Span,
+    ) -> Self {
+        Self {
+            sweeper: Some(sweeper),
+            inner_shutdown_tx,
+            span,
+            handle: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: RegistryStorage + Send + Sync + 'static> BackgroundService for RegistryGcService<S> {
+    fn name(&self) -> &'static str {
+        "registry_gc"
+    }
+
+    async fn start(
+        &mut self,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) -> Result<(), WorkflowExecutionError> {
+        let mut sweeper =
+            self.sweeper
+                .take()
+                .ok_or_else(|| WorkflowExecutionError::Configuration {
+                    message: "registry GC sweeper already started".to_string(),
+                })?;
+        let inner_tx = self.inner_shutdown_tx.clone();
+        let span = self.span.clone();
+        let handle = tokio::spawn(
+            async move {
+                tokio::select! {
+                    _ = sweeper.run() => {
+                        tracing::info!("Registry GC completed");
+                    }
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Registry GC shutdown requested");
+                        let _ = inner_tx.send(true);
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), WorkflowExecutionError> {
+        if let Some(h) = self.handle.take() {
+            let _ = h.await;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps the SQLite maintenance loop.
+#[cfg(feature = "sqlite")]
+pub(super) struct SqliteMaintenanceService {
+    maintenance: Option<SqliteMaintenance>,
+    inner_shutdown_tx: watch::Sender<bool>,
+    span: tracing::Span,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteMaintenanceService {
+    pub(super) fn new(
+        maintenance: SqliteMaintenance,
+        inner_shutdown_tx: watch::Sender<bool>,
+        span: tracing::Span,
+    ) -> Self {
+        Self {
+            maintenance: Some(maintenance),
+            inner_shutdown_tx,
+            span,
+            handle: None,
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl BackgroundService for SqliteMaintenanceService {
+    fn name(&self) -> &'static str {
+        "sqlite_maintenance"
+    }
+
+    async fn start(
+        &mut self,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) -> Result<(), WorkflowExecutionError> {
+        let mut maintenance =
+            self.maintenance
+                .take()
+                .ok_or_else(|| WorkflowExecutionError::Configuration {
+                    message: "SQLite maintenance already started".to_string(),
+                })?;
+        let inner_tx = self.inner_shutdown_tx.clone();
+        let span = self.span.clone();
+        let handle = tokio::spawn(
+            async move {
+                tokio::select! {
+                    _ = maintenance.run() => {
+                        tracing::info!("SQLite maintenance completed");
+                    }
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("SQLite maintenance shutdown requested");
+                        let _ = inner_tx.send(true);
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), WorkflowExecutionError> {
+        if let Some(h) = self.handle.take() {
+            let _ = h.await;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps the runner handoff heartbeat loop.
+pub(super) struct RunnerHandoffService {
+    monitor: Option<RunnerHandoffMonitor>,
+    inner_shutdown_tx: watch::Sender<bool>,
+    span: tracing::Span,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RunnerHandoffService {
+    pub(super) fn new(
+        monitor: RunnerHandoffMonitor,
+        inner_shutdown_tx: watch::Sender<bool>,
+        span: tracing::Span,
+    ) -> Self {
+        Self {
+            monitor: Some(monitor),
+            inner_shutdown_tx,
+            span,
+            handle: None,
+        }
+    }
+}
+
+#[async_trait]
+impl BackgroundService for RunnerHandoffService {
+    fn name(&self) -> &'static str {
+        "runner_handoff"
+    }
+
+    async fn start(
+        &mut self,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) -> Result<(), WorkflowExecutionError> {
+        let mut monitor =
+            self.monitor
+                .take()
+                .ok_or_else(|| WorkflowExecutionError::Configuration {
+                    message: "runner handoff monitor already started".to_string(),
+                })?;
+        let inner_tx = self.inner_shutdown_tx.clone();
+        let span = self.span.clone();
+        let handle = tokio::spawn(
+            async move {
+                tokio::select! {
+                    _ = monitor.run() => {
+                        tracing::info!("Runner handoff monitor completed");
+                    }
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Runner handoff monitor shutdown requested");
+                        let _ = inner_tx.send(true);
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), WorkflowExecutionError> {
+        if let Some(h) = self.handle.take() {
+            let _ = h.await;
+        }
+        Ok(())
+    }
+}
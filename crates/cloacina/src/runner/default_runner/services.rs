@@ -29,12 +29,18 @@ use crate::dal::UnifiedRegistryStorage;
 use crate::dal::DAL;
 use crate::executor::workflow_executor::WorkflowExecutionError;
 use crate::registry::traits::WorkflowRegistry;
-use crate::registry::{ReconcilerConfig, RegistryReconciler, WorkflowRegistryImpl};
+use crate::registry::{
+    ReconcilerConfig, RegistryGcConfig, RegistryGcPolicy, RegistryGcSweeper, RegistryReconciler,
+    WorkflowRegistryImpl,
+};
 use crate::{Scheduler, SchedulerConfig};
 
+#[cfg(feature = "sqlite")]
+use super::service_manager::SqliteMaintenanceService;
 use super::service_manager::{
-    CronRecoveryServiceWrapper, RegistryReconcilerService, ServiceManager,
-    StaleClaimSweeperService, TaskSchedulerService, UnifiedSchedulerService,
+    ContextRetentionService, CronRecoveryServiceWrapper, RegistryGcService,
+    RegistryReconcilerService, RunnerHandoffService, ServiceManager, StaleClaimSweeperService,
+    TaskSchedulerService, UnifiedSchedulerService,
 };
 use super::DefaultRunner;
 
@@ -67,6 +73,12 @@ impl DefaultRunner {
 
         let mut manager = self.service_manager.write().await;
 
+        // Handoff registration goes first so a takeover asks the old runner
+        // to drain before this one starts firing schedules.
+        if self.config.enable_handoff() {
+            self.register_runner_handoff(&mut manager).await?;
+        }
+
         // Always: per-runner task scheduler.
         manager.register(Box::new(TaskSchedulerService::new(
             self.scheduler.clone(),
@@ -98,6 +110,29 @@ impl DefaultRunner {
             self.register_stale_claim_sweeper(&mut manager).await?;
         }
 
+        // Context retention expires context payloads of finished executions
+        // while their metadata stays; off unless a retention is configured.
+        if let Some(retention) = self.config.context_retention() {
+            self.register_context_retention(&mut manager, retention)
+                .await?;
+        }
+
+        // Registry GC deletes superseded package versions the policy does not
+        // keep; off unless a policy is configured.
+        if let Some(policy) = self.config.registry_gc() {
+            self.register_registry_gc(&mut manager, policy.clone())
+                .await?;
+        }
+
+        // SQLite has no autovacuum daemon or checkpointer of its own; the
+        // runner does that housekeeping for SQLite deployments.
+        #[cfg(feature = "sqlite")]
+        if self.config.enable_sqlite_maintenance()
+            && self.database.backend() == crate::database::BackendType::Sqlite
+        {
+            self.register_sqlite_maintenance(&mut manager).await?;
+        }
+
         manager.start_all().await?;
 
         Ok(())
@@ -126,6 +161,7 @@ impl DefaultRunner {
             // `SchedulerConfig` for tests / niche deployments.
             reactor_firings_prune_interval: Duration::from_secs(60 * 60),
             reactor_firings_retention: Duration::from_secs(7 * 24 * 60 * 60),
+            trigger_lease_ttl: self.config.trigger_lease_ttl(),
         };
 
         let dal = DAL::new(self.database.clone());
@@ -136,7 +172,8 @@ impl DefaultRunner {
             inner_rx,
             self.runtime.clone(),
             self.cron_change.clone(),
-        );
+        )
+        .with_lease_owner(self.instance_id);
         let unified_scheduler = Arc::new(unified_scheduler);
 
         manager.unified_scheduler = Some(unified_scheduler.clone());
@@ -206,6 +243,17 @@ impl DefaultRunner {
             // already plumbed via --require-signatures + --verification-org-id.
             require_signatures: self.config.require_signatures(),
             verification_org_id: self.config.verification_org_id(),
+            // Signatures are verified against this runner's own database
+            // unless a separate trust store (the server's admin database,
+            // for per-tenant runners) is configured.
+            tenant_trust_orgs: self.config.tenant_trust_orgs().clone(),
+            signature_trust_store: Some(
+                self.config
+                    .signature_trust_store()
+                    .cloned()
+                    .unwrap_or_else(|| self.database.clone()),
+            ),
+            capability_policy: self.config.capability_policy().clone(),
         };
 
         let workflow_registry_result = match self.config.registry_storage_backend() {
@@ -231,10 +279,21 @@ impl DefaultRunner {
                 let registry_dal = dal.workflow_registry(storage);
                 Ok(Arc::new(registry_dal) as Arc<dyn WorkflowRegistry>)
             }
-            backend => Err(format!(
-                "Unknown registry storage backend: {}. Valid options: filesystem, sqlite, postgres, database",
-                backend
-            )),
+            backend => match self.extensions.registry_storage(backend) {
+                Some(factory) => factory(&self.database)
+                    .map_err(|e| format!("Failed to create '{}' storage: {}", backend, e))
+                    .and_then(|storage| {
+                        WorkflowRegistryImpl::new(storage, self.database.clone())
+                            .map(|registry| Arc::new(registry) as Arc<dyn WorkflowRegistry>)
+                            .map_err(|e| {
+                                format!("Failed to create '{}' workflow registry: {}", backend, e)
+                            })
+                    }),
+                None => Err(format!(
+                    "Unknown registry storage backend: {}. Valid options: filesystem, sqlite, postgres, database, or one registered by an extension",
+                    backend
+                )),
+            },
         };
 
         let workflow_registry_arc = match workflow_registry_result {
@@ -273,6 +332,7 @@ impl DefaultRunner {
         }
 
         manager.workflow_registry = Some(workflow_registry_arc);
+        manager.registry_reconciler = Some(registry_reconciler.handle());
 
         manager.register(Box::new(RegistryReconcilerService::new(
             registry_reconciler,
@@ -311,4 +371,190 @@ impl DefaultRunner {
 
         Ok(())
     }
+
+    async fn register_context_retention(
+        &self,
+        manager: &mut ServiceManager,
+        retention: Duration,
+    ) -> Result<(), WorkflowExecutionError> {
+        use crate::context_retention::{ContextRetentionConfig, ContextRetentionSweeper};
+
+        tracing::info!("Registering context retention sweeper");
+
+        let (inner_tx, inner_rx) = watch::channel(false);
+
+        let retention_config = ContextRetentionConfig {
+            sweep_interval: self.config.context_retention_sweep_interval(),
+            retention,
+            ..Default::default()
+        };
+
+        let dal = DAL::new(self.database.clone());
+        let sweeper = ContextRetentionSweeper::new(Arc::new(dal), retention_config, inner_rx);
+
+        manager.register(Box::new(ContextRetentionService::new(
+            sweeper,
+            inner_tx,
+            self.create_runner_span("context_retention"),
+        )));
+
+        Ok(())
+    }
+
+    async fn register_registry_gc(
+        &self,
+        manager: &mut ServiceManager,
+        policy: RegistryGcPolicy,
+    ) -> Result<(), WorkflowExecutionError> {
+        tracing::info!("Registering registry GC");
+
+        let (inner_tx, inner_rx) = watch::channel(false);
+
+        let gc_config = RegistryGcConfig {
+            sweep_interval: self.config.registry_gc_interval(),
+            policy,
+            dry_run: self.config.registry_gc_dry_run(),
+        };
+        let span = self.create_runner_span("registry_gc");
+
+        // Same storage selection as the reconciler's registry.
+        match self.config.registry_storage_backend() {
+            "filesystem" => {
+                let storage_path = self
+                    .config
+                    .registry_storage_path()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| std::env::temp_dir().join("cloacina_registry"));
+                let storage = FilesystemRegistryStorage::new(storage_path).map_err(|e| {
+                    WorkflowExecutionError::Configuration {
+                        message: format!(
+                            "Failed to create filesystem storage for registry GC: {}",
+                            e
+                        ),
+                    }
+                })?;
+                let registry =
+                    WorkflowRegistryImpl::new(storage, self.database.clone()).map_err(|e| {
+                        WorkflowExecutionError::Configuration {
+                            message: format!("Failed to create registry for GC: {}", e),
+                        }
+                    })?;
+                manager.register(Box::new(RegistryGcService::new(
+                    RegistryGcSweeper::new(registry, gc_config, inner_rx),
+                    inner_tx,
+                    span,
+                )));
+            }
+            "sqlite" | "postgres" | "database" => {
+                let storage = UnifiedRegistryStorage::new(self.database.clone());
+                let registry =
+                    WorkflowRegistryImpl::new(storage, self.database.clone()).map_err(|e| {
+                        WorkflowExecutionError::Configuration {
+                            message: format!("Failed to create registry for GC: {}", e),
+                        }
+                    })?;
+                manager.register(Box::new(RegistryGcService::new(
+                    RegistryGcSweeper::new(registry, gc_config, inner_rx),
+                    inner_tx,
+                    span,
+                )));
+            }
+            backend => {
+                let Some(factory) = self.extensions.registry_storage(backend) else {
+                    tracing::error!(
+                        "Registry GC not started: unknown registry storage backend '{}'",
+                        backend
+                    );
+                    return Ok(());
+                };
+                let storage =
+                    factory(&self.database).map_err(|e| WorkflowExecutionError::Configuration {
+                        message: format!(
+                            "Failed to create '{}' storage for registry GC: {}",
+                            backend, e
+                        ),
+                    })?;
+                let registry =
+                    WorkflowRegistryImpl::new(storage, self.database.clone()).map_err(|e| {
+                        WorkflowExecutionError::Configuration {
+                            message: format!("Failed to create registry for GC: {}", e),
+                        }
+                    })?;
+                manager.register(Box::new(RegistryGcService::new(
+                    RegistryGcSweeper::new(registry, gc_config, inner_rx),
+                    inner_tx,
+                    span,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "sqlite")]
+    async fn register_sqlite_maintenance(
+        &self,
+        manager: &mut ServiceManager,
+    ) -> Result<(), WorkflowExecutionError> {
+        use crate::sqlite_maintenance::{SqliteMaintenance, SqliteMaintenanceConfig};
+
+        tracing::info!("Registering SQLite maintenance");
+
+        let (inner_tx, inner_rx) = watch::channel(false);
+
+        let maintenance_config = SqliteMaintenanceConfig {
+            interval: self.config.sqlite_maintenance_interval(),
+            ..Default::default()
+        };
+        let maintenance =
+            SqliteMaintenance::new(self.database.clone(), maintenance_config, inner_rx);
+
+        manager.register(Box::new(SqliteMaintenanceService::new(
+            maintenance,
+            inner_tx,
+            self.create_runner_span("sqlite_maintenance"),
+        )));
+
+        Ok(())
+    }
+
+    async fn register_runner_handoff(
+        &self,
+        manager: &mut ServiceManager,
+    ) -> Result<(), WorkflowExecutionError> {
+        use crate::runner_handoff::{RunnerHandoffConfig, RunnerHandoffMonitor};
+
+        tracing::info!("Registering runner handoff monitor");
+
+        let (inner_tx, inner_rx) = watch::channel(false);
+
+        let handoff_config = RunnerHandoffConfig {
+            instance_id: self.instance_id,
+            name: self.config.runner_name().map(str::to_string),
+            heartbeat_interval: self.config.handoff_heartbeat_interval(),
+            takeover: self.config.handoff_takeover(),
+        };
+
+        let dal = DAL::new(self.database.clone());
+        let monitor = RunnerHandoffMonitor::new(
+            Arc::new(dal),
+            handoff_config,
+            self.handoff.clone(),
+            inner_rx,
+        );
+        monitor
+            .register()
+            .await
+            .map_err(|e| WorkflowExecutionError::Configuration {
+                message: format!("Failed to register runner instance: {}", e),
+            })?;
+
+        manager.register(Box::new(RunnerHandoffService::new(
+            monitor,
+            inner_tx,
+            self.create_runner_span("runner_handoff"),
+        )));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,500 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Streaming execution API for the DefaultRunner.
+//!
+//! [`DefaultRunner::execute_streaming`] schedules a workflow like
+//! [`execute`](crate::executor::WorkflowExecutor::execute) but hands back a
+//! stream of per-task completion updates, built from the execution's event
+//! log, that ends with the same [`WorkflowExecutionResult`] `execute` returns.
+//! [`DefaultRunner::subscribe_execution`] streams the raw events of any
+//! execution instead.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::{
+    WorkflowExecutionError, WorkflowExecutionResult, WorkflowExecutor,
+};
+use crate::models::execution_event::{ExecutionEvent, ExecutionEventType};
+use crate::models::task_execution::TaskExecution;
+use crate::Context;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// Buffered updates per stream before the poller waits on the consumer.
+const STREAM_CHANNEL_CAPACITY: usize = 256;
+
+/// How often an execution subscription checks for new events.
+const SUBSCRIPTION_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How an execution stream polls and which task outputs it carries.
+#[derive(Debug, Clone)]
+pub struct StreamingOptions {
+    /// How often to check for new events
+    pub poll_interval: Duration,
+    /// Context keys copied from each completed task's output context
+    pub output_keys: Vec<String>,
+}
+
+impl Default for StreamingOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            output_keys: Vec::new(),
+        }
+    }
+}
+
+/// How a task attempt finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskOutcome {
+    Completed,
+    /// The attempt failed; check `attempt` against `max_attempts` for retries
+    Failed,
+    Skipped,
+    /// The task gave up after exhausting its retries or being cancelled
+    Abandoned,
+}
+
+/// One finished task attempt.
+#[derive(Debug, Clone)]
+pub struct TaskUpdate {
+    pub task_name: String,
+    pub task_execution_id: UniversalUuid,
+    pub outcome: TaskOutcome,
+    pub attempt: i32,
+    pub max_attempts: i32,
+    pub duration: Option<Duration>,
+    /// Failure or skip reason
+    pub message: Option<String>,
+    /// Requested `output_keys` present in the task's output context
+    pub outputs: HashMap<String, serde_json::Value>,
+}
+
+/// An item of an execution stream.
+#[derive(Debug)]
+pub enum ExecutionUpdate {
+    /// A task attempt finished
+    Task(TaskUpdate),
+    /// The execution reached a final status; always the last item
+    Finished(Box<WorkflowExecutionResult>),
+    /// Waiting for the execution failed, e.g. on the runner's workflow
+    /// timeout; always the last item
+    Error(WorkflowExecutionError),
+}
+
+/// A scheduled execution plus its update stream.
+///
+/// Dropping the receiver stops the poller; the execution keeps running.
+pub struct ExecutionStream {
+    pub execution_id: Uuid,
+    pub updates: mpsc::Receiver<ExecutionUpdate>,
+}
+
+/// Maps a task event to the outcome it reports, if any.
+fn task_outcome(event_type: ExecutionEventType) -> Option<TaskOutcome> {
+    match event_type {
+        // A failed task an operator marked successful
+        ExecutionEventType::TaskCompleted | ExecutionEventType::TaskResolved => {
+            Some(TaskOutcome::Completed)
+        }
+        ExecutionEventType::TaskFailed => Some(TaskOutcome::Failed),
+        ExecutionEventType::TaskSkipped => Some(TaskOutcome::Skipped),
+        ExecutionEventType::TaskAbandoned => Some(TaskOutcome::Abandoned),
+        _ => None,
+    }
+}
+
+/// Whether an event is the last one an execution writes.
+fn ends_execution(event_type: ExecutionEventType) -> bool {
+    matches!(
+        event_type,
+        ExecutionEventType::WorkflowCompleted | ExecutionEventType::WorkflowFailed
+    )
+}
+
+/// Copies the requested keys that exist in `context`.
+fn select_outputs(
+    context: &Context<serde_json::Value>,
+    keys: &[String],
+) -> HashMap<String, serde_json::Value> {
+    keys.iter()
+        .filter_map(|key| context.get(key).map(|value| (key.clone(), value.clone())))
+        .collect()
+}
+
+/// Builds the update for a task event from the task's row.
+fn task_update(
+    outcome: TaskOutcome,
+    event: &ExecutionEvent,
+    task: TaskExecution,
+    outputs: HashMap<String, serde_json::Value>,
+) -> TaskUpdate {
+    let duration = task
+        .completed_at
+        .zip(task.started_at)
+        .and_then(|(end, start)| (end.0 - start.0).to_std().ok());
+    let message = event
+        .event_data
+        .as_deref()
+        .and_then(|data| serde_json::from_str::<serde_json::Value>(data).ok())
+        .and_then(|data| {
+            ["error", "reason"]
+                .iter()
+                .find_map(|key| data.get(*key).and_then(|v| v.as_str()).map(str::to_string))
+        })
+        .or_else(|| match outcome {
+            TaskOutcome::Completed => None,
+            _ => task.error_details.clone(),
+        });
+
+    TaskUpdate {
+        task_name: task.task_name,
+        task_execution_id: task.id,
+        outcome,
+        attempt: task.attempt,
+        max_attempts: task.max_attempts,
+        duration,
+        message,
+        outputs,
+    }
+}
+
+impl DefaultRunner {
+    /// Executes a workflow and streams per-task updates while it runs
+    ///
+    /// The stream yields a [`ExecutionUpdate::Task`] for each finished task
+    /// attempt and ends with [`ExecutionUpdate::Finished`] carrying the full
+    /// result, or [`ExecutionUpdate::Error`] if waiting failed. The runner's
+    /// `workflow_timeout` applies as it does for `execute`.
+    ///
+    /// # Arguments
+    /// * `workflow_name` - Name of the workflow to execute
+    /// * `context` - Initial context for the workflow
+    /// * `options` - Poll interval and the task outputs to include
+    pub async fn execute_streaming(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+        options: StreamingOptions,
+    ) -> Result<ExecutionStream, WorkflowExecutionError> {
+        let execution_id = self
+            .execute_async(workflow_name, context)
+            .await?
+            .execution_id;
+        let (tx, rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+
+        let runner = self.clone();
+        tokio::spawn(async move {
+            let dal = DAL::new(runner.database.clone());
+            let id = UniversalUuid(execution_id);
+            let timeout = runner.config.workflow_timeout();
+            let start_time = std::time::Instant::now();
+            let mut cursor = 0;
+            let mut interval = tokio::time::interval(options.poll_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            while !tx.is_closed() {
+                interval.tick().await;
+                if let Some(timeout) = timeout {
+                    if start_time.elapsed() > timeout {
+                        let _ = tx
+                            .send(ExecutionUpdate::Error(WorkflowExecutionError::Timeout {
+                                timeout_seconds: timeout.as_secs(),
+                            }))
+                            .await;
+                        return;
+                    }
+                }
+
+                let events = match dal
+                    .execution_event()
+                    .list_by_workflow_after_sequence(id, cursor)
+                    .await
+                {
+                    Ok(events) => events,
+                    Err(e) => {
+                        warn!("Execution stream poll for {} failed: {}", execution_id, e);
+                        continue;
+                    }
+                };
+
+                let mut finished = false;
+                for event in events {
+                    cursor = event.sequence_num;
+                    let Some(event_type) = ExecutionEventType::from_str(&event.event_type) else {
+                        continue;
+                    };
+                    if ends_execution(event_type) {
+                        finished = true;
+                        continue;
+                    }
+                    let (Some(outcome), Some(task_id)) =
+                        (task_outcome(event_type), event.task_execution_id)
+                    else {
+                        continue;
+                    };
+
+                    let task = match dal.task_execution().get_by_id(task_id).await {
+                        Ok(task) => task,
+                        Err(e) => {
+                            debug!("Skipping event for unknown task {}: {}", task_id, e);
+                            continue;
+                        }
+                    };
+                    let outputs =
+                        if outcome == TaskOutcome::Completed && !options.output_keys.is_empty() {
+                            read_task_outputs(&dal, task_id, &options.output_keys).await
+                        } else {
+                            HashMap::new()
+                        };
+                    let update = task_update(outcome, &event, task, outputs);
+                    if tx.send(ExecutionUpdate::Task(update)).await.is_err() {
+                        return;
+                    }
+                }
+
+                if !finished {
+                    // Cancellation writes no event; pick it up from the row.
+                    finished = matches!(
+                        dal.workflow_execution().get_by_id(id).await,
+                        Ok(execution) if execution.status == "Cancelled"
+                    );
+                }
+                if finished {
+                    let update = match runner.build_workflow_result(execution_id).await {
+                        Ok(result) => ExecutionUpdate::Finished(Box::new(result)),
+                        Err(e) => ExecutionUpdate::Error(e),
+                    };
+                    let _ = tx.send(update).await;
+                    return;
+                }
+            }
+        });
+
+        Ok(ExecutionStream {
+            execution_id,
+            updates: rx,
+        })
+    }
+
+    /// Stream of an execution's events as they are written
+    ///
+    /// Replays the events already recorded for the execution, then yields
+    /// new ones in order: task started, completed, failed, retry scheduled,
+    /// skipped and the other [`ExecutionEventType`]s. The stream ends after
+    /// `workflow_completed` / `workflow_failed`, when the execution is
+    /// cancelled, or when it does not exist. Dropping the stream stops
+    /// polling; the execution keeps running.
+    ///
+    /// # Arguments
+    /// * `execution_id` - The workflow execution to follow
+    pub fn subscribe_execution(&self, execution_id: Uuid) -> impl Stream<Item = ExecutionEvent> {
+        let (tx, rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+        let dal = DAL::new(self.database.clone());
+
+        tokio::spawn(async move {
+            let id = UniversalUuid(execution_id);
+            let mut cursor = 0;
+            let mut interval = tokio::time::interval(SUBSCRIPTION_POLL_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            while !tx.is_closed() {
+                interval.tick().await;
+
+                // Status changes commit with their events, so once the row is
+                // final one more read drains everything left. Cancellation
+                // writes no event and ends the stream the same way.
+                let final_pass = match dal.workflow_execution().get_by_id(id).await {
+                    Ok(execution) => matches!(
+                        execution.status.as_str(),
+                        "Completed" | "Failed" | "Cancelled"
+                    ),
+                    Err(e) => {
+                        debug!("Ending subscription for execution {}: {}", execution_id, e);
+                        return;
+                    }
+                };
+
+                let events = match dal
+                    .execution_event()
+                    .list_by_workflow_after_sequence(id, cursor)
+                    .await
+                {
+                    Ok(events) => events,
+                    Err(e) => {
+                        warn!("Execution subscription for {} failed: {}", execution_id, e);
+                        continue;
+                    }
+                };
+                for event in events {
+                    cursor = event.sequence_num;
+                    let last =
+                        ExecutionEventType::from_str(&event.event_type).is_some_and(ends_execution);
+                    if tx.send(event).await.is_err() || last {
+                        return;
+                    }
+                }
+                if final_pass {
+                    return;
+                }
+            }
+        });
+
+        futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event| (event, rx))
+        })
+    }
+}
+
+/// Reads `keys` from the output context a task saved; empty if it saved none.
+async fn read_task_outputs(
+    dal: &DAL,
+    task_id: UniversalUuid,
+    keys: &[String],
+) -> HashMap<String, serde_json::Value> {
+    let context_id = match dal
+        .task_execution_metadata()
+        .get_by_task_execution(task_id)
+        .await
+    {
+        Ok(metadata) => metadata.context_id,
+        Err(e) => {
+            debug!("No output metadata for task {}: {}", task_id, e);
+            None
+        }
+    };
+    let Some(context_id) = context_id else {
+        return HashMap::new();
+    };
+    match dal.context().read::<serde_json::Value>(context_id).await {
+        Ok(context) => select_outputs(&context, keys),
+        Err(e) => {
+            warn!("Failed to read output context for task {}: {}", task_id, e);
+            HashMap::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UniversalTimestamp;
+
+    fn task(status: &str) -> TaskExecution {
+        let now = UniversalTimestamp::now();
+        TaskExecution {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_name: "etl::extract".to_string(),
+            status: status.to_string(),
+            started_at: Some(UniversalTimestamp(now.0 - chrono::Duration::seconds(3))),
+            completed_at: Some(now),
+            attempt: 2,
+            max_attempts: 3,
+            error_details: None,
+            trigger_rules: "{}".to_string(),
+            task_configuration: "{}".to_string(),
+            retry_at: None,
+            last_error: None,
+            recovery_attempts: 0,
+            last_recovery_at: None,
+            sub_status: None,
+            claimed_by: None,
+            heartbeat_at: None,
+            created_at: now,
+            updated_at: now,
+            error_payload: None,
+        }
+    }
+
+    fn event(event_type: ExecutionEventType, data: Option<&str>) -> ExecutionEvent {
+        ExecutionEvent {
+            id: UniversalUuid::new_v4(),
+            workflow_execution_id: UniversalUuid::new_v4(),
+            task_execution_id: Some(UniversalUuid::new_v4()),
+            event_type: event_type.as_str().to_string(),
+            event_data: data.map(str::to_string),
+            worker_id: None,
+            created_at: UniversalTimestamp::now(),
+            sequence_num: 7,
+            request_id: None,
+            runner_id: None,
+            tenant_id: None,
+        }
+    }
+
+    #[test]
+    fn test_only_task_terminal_events_produce_updates() {
+        assert_eq!(
+            task_outcome(ExecutionEventType::TaskCompleted),
+            Some(TaskOutcome::Completed)
+        );
+        assert_eq!(
+            task_outcome(ExecutionEventType::TaskAbandoned),
+            Some(TaskOutcome::Abandoned)
+        );
+        assert_eq!(
+            task_outcome(ExecutionEventType::TaskResolved),
+            Some(TaskOutcome::Completed)
+        );
+        assert_eq!(task_outcome(ExecutionEventType::TaskStarted), None);
+        assert_eq!(task_outcome(ExecutionEventType::WorkflowCompleted), None);
+    }
+
+    #[test]
+    fn test_only_workflow_terminal_events_end_a_subscription() {
+        assert!(ends_execution(ExecutionEventType::WorkflowCompleted));
+        assert!(ends_execution(ExecutionEventType::WorkflowFailed));
+        assert!(!ends_execution(ExecutionEventType::WorkflowRetryScheduled));
+        assert!(!ends_execution(ExecutionEventType::TaskFailed));
+    }
+
+    #[test]
+    fn test_failed_update_carries_error_and_attempts() {
+        let failed = event(
+            ExecutionEventType::TaskFailed,
+            Some(r#"{"error":"connection refused"}"#),
+        );
+        let update = task_update(TaskOutcome::Failed, &failed, task("Failed"), HashMap::new());
+
+        assert_eq!(update.task_name, "etl::extract");
+        assert_eq!(update.message.as_deref(), Some("connection refused"));
+        assert_eq!((update.attempt, update.max_attempts), (2, 3));
+        assert_eq!(update.duration, Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_select_outputs_keeps_requested_keys() {
+        let mut context = Context::<serde_json::Value>::new();
+        context.insert("rows", serde_json::json!(42)).unwrap();
+        context
+            .insert("raw", serde_json::json!("large blob"))
+            .unwrap();
+
+        let outputs = select_outputs(&context, &["rows".to_string(), "missing".to_string()]);
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs["rows"], serde_json::json!(42));
+    }
+}
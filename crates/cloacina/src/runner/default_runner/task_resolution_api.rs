@@ -0,0 +1,168 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Manual task resolution API for the DefaultRunner.
+//!
+//! When a task failed but its side effect was completed out-of-band (the
+//! upload finished, the row was fixed by hand), rerunning the workflow may
+//! repeat work that must not happen twice. [`DefaultRunner::resolve_failed_task`]
+//! marks the failed task successful instead: downstream tasks that were
+//! skipped because of the failure are re-evaluated, and an execution that
+//! already failed resumes. The resolved task saves no output context, so
+//! downstream tasks see nothing from it.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::models::workflow_execution::{
+    ExecutionAnnotation, ExecutionResolution, NewExecutionAnnotation,
+};
+use crate::task::TaskNamespace;
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+/// Outcome of [`DefaultRunner::resolve_failed_task`].
+#[derive(Debug, Clone)]
+pub struct ResolvedTask {
+    pub task_execution_id: UniversalUuid,
+    /// Fully qualified task name
+    pub task_name: String,
+    /// Skipped downstream tasks that the scheduler will re-evaluate
+    pub reopened_tasks: Vec<String>,
+    /// Whether the execution had already failed and is running again
+    pub execution_reopened: bool,
+    /// Annotation recording who resolved the task and why
+    pub annotation: ExecutionAnnotation,
+}
+
+impl DefaultRunner {
+    /// Mark a Failed task of an execution as successfully completed
+    ///
+    /// # Arguments
+    /// * `execution_id` - Execution the task belongs to
+    /// * `task` - Task id (`load_data`) or fully qualified task name
+    /// * `annotation` - Who resolved the task and why; recorded on the
+    ///   execution with resolution `resolved` unless another is given
+    pub async fn resolve_failed_task(
+        &self,
+        execution_id: UniversalUuid,
+        task: &str,
+        annotation: NewExecutionAnnotation,
+    ) -> Result<ResolvedTask, WorkflowExecutionError> {
+        let invalid = |message: String| WorkflowExecutionError::Configuration { message };
+        let map_err = |e: crate::error::ValidationError| WorkflowExecutionError::ExecutionFailed {
+            message: format!("Failed to resolve task: {}", e),
+        };
+
+        annotation.validate().map_err(|e| invalid(e.to_string()))?;
+        let note = annotation.note.clone();
+        let annotation = NewExecutionAnnotation {
+            note: format!("Marked task '{}' successful: {}", task, annotation.note),
+            resolution: annotation
+                .resolution
+                .or(Some(ExecutionResolution::Resolved)),
+            ..annotation
+        };
+        annotation.validate().map_err(|e| invalid(e.to_string()))?;
+
+        let dal = DAL::new(self.database.clone());
+        let execution = dal
+            .workflow_execution()
+            .get_by_id(execution_id)
+            .await
+            .map_err(map_err)?;
+        if execution.status == "Cancelled" {
+            return Err(invalid(format!(
+                "Execution {} was cancelled; its tasks cannot be resolved",
+                execution_id
+            )));
+        }
+        let workflow = self
+            .runtime
+            .get_workflow(&execution.workflow_name)
+            .ok_or_else(|| {
+                invalid(format!(
+                    "Workflow '{}' is not registered with this runner",
+                    execution.workflow_name
+                ))
+            })?;
+
+        let tasks = dal
+            .task_execution()
+            .get_all_tasks_for_workflow(execution_id)
+            .await
+            .map_err(map_err)?;
+        let target = tasks
+            .iter()
+            .find(|t| {
+                t.task_name == task
+                    || TaskNamespace::from_string(&t.task_name).is_ok_and(|ns| ns.task_id == task)
+            })
+            .ok_or_else(|| invalid(format!("Execution has no task '{}'", task)))?;
+        if target.status != "Failed" {
+            return Err(invalid(format!(
+                "Task '{}' is {}, not Failed",
+                target.task_name, target.status
+            )));
+        }
+
+        // Everything downstream of the task, direct or transitive.
+        let mut downstream = HashSet::new();
+        let mut queue = VecDeque::new();
+        if let Ok(ns) = TaskNamespace::from_string(&target.task_name) {
+            queue.push_back(ns);
+        }
+        while let Some(ns) = queue.pop_front() {
+            for dependent in workflow.get_dependents(&ns).unwrap_or_default() {
+                if downstream.insert(dependent.to_string()) {
+                    queue.push_back(dependent);
+                }
+            }
+        }
+        let reopen: Vec<UniversalUuid> = tasks
+            .iter()
+            .filter(|t| downstream.contains(&t.task_name))
+            .map(|t| t.id)
+            .collect();
+
+        let result = dal
+            .task_execution()
+            .resolve_failed(target.id, reopen, &note)
+            .await
+            .map_err(map_err)?
+            .ok_or_else(|| invalid(format!("Task '{}' is no longer Failed", target.task_name)))?;
+        let annotation = dal
+            .workflow_execution()
+            .add_annotation(execution_id, annotation)
+            .await
+            .map_err(map_err)?;
+
+        let reopened_tasks = tasks
+            .iter()
+            .filter(|t| result.reopened.contains(&t.id))
+            .map(|t| t.task_name.clone())
+            .collect();
+        Ok(ResolvedTask {
+            task_execution_id: target.id,
+            task_name: target.task_name.clone(),
+            reopened_tasks,
+            execution_reopened: result.execution_reopened,
+            annotation,
+        })
+    }
+}
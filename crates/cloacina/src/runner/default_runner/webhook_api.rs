@@ -0,0 +1,279 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Webhook trigger API for the DefaultRunner (`webhooks` feature).
+//!
+//! See [`crate::webhook`] for the request format and authentication.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use chrono::Utc;
+use tracing::{info, warn};
+
+use crate::dal::DAL;
+use crate::executor::workflow_executor::WorkflowExecutionError;
+use crate::executor::WorkflowExecutor;
+use crate::models::schedule::{NewSchedule, NewScheduleExecution};
+use crate::trigger::TriggerResult;
+use crate::webhook::{
+    webhook_context, WebhookAuth, WebhookError, WebhookFired, WebhookRegistration, WebhookTrigger,
+    WEBHOOK_POLL_INTERVAL,
+};
+use crate::UniversalUuid;
+
+use super::DefaultRunner;
+
+impl DefaultRunner {
+    /// Register a webhook trigger that fires `workflow_name`
+    ///
+    /// Requests to `POST /webhooks/{name}` on the webhook listener are
+    /// checked with `auth`, and their JSON body becomes the workflow's
+    /// context. The trigger is backed by a trigger schedule, which is created
+    /// (or refreshed) here and can be paused like any other. Re-registering a
+    /// name replaces its workflow and credentials.
+    ///
+    /// # Arguments
+    /// * `name` - Trigger name, the last segment of the webhook URL
+    /// * `workflow_name` - Workflow to start for each accepted request
+    /// * `auth` - How requests are authenticated
+    ///
+    /// # Returns
+    /// * `Result<UniversalUuid, WorkflowExecutionError>` - ID of the backing schedule
+    pub async fn register_webhook_trigger(
+        &self,
+        name: &str,
+        workflow_name: &str,
+        auth: WebhookAuth,
+    ) -> Result<UniversalUuid, WorkflowExecutionError> {
+        if name.is_empty() || name.contains('/') {
+            return Err(WorkflowExecutionError::Configuration {
+                message: format!("Invalid webhook trigger name '{}'", name),
+            });
+        }
+
+        let dal = DAL::new(self.database.clone());
+        self.ensure_not_deprecated(&dal, workflow_name).await?;
+
+        let schedule = dal
+            .schedule()
+            .upsert_trigger(NewSchedule::trigger(
+                name,
+                workflow_name,
+                WEBHOOK_POLL_INTERVAL,
+            ))
+            .await
+            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                message: format!("Failed to register webhook trigger: {}", e),
+            })?;
+
+        let trigger_name = name.to_string();
+        self.runtime.register_trigger(name.to_string(), move || {
+            Arc::new(WebhookTrigger {
+                name: trigger_name.clone(),
+            })
+        });
+        self.webhooks.insert(
+            name,
+            WebhookRegistration {
+                workflow_name: workflow_name.to_string(),
+                auth,
+            },
+        );
+
+        info!(
+            "Registered webhook trigger '{}' for workflow '{}'",
+            name, workflow_name
+        );
+        Ok(schedule.id)
+    }
+
+    /// Stop accepting requests for a webhook trigger
+    ///
+    /// The backing schedule and its history are kept; delete the schedule to
+    /// remove them. Returns whether the trigger was registered.
+    pub fn unregister_webhook_trigger(&self, name: &str) -> bool {
+        self.runtime.unregister_trigger(name);
+        self.webhooks.remove(name)
+    }
+
+    /// Router serving `POST /webhooks/{name}` for this runner's webhook
+    /// triggers, for mounting in an existing axum server.
+    pub fn webhook_router(&self) -> Router {
+        Router::new()
+            .route("/webhooks/{name}", post(receive_webhook))
+            .with_state(self.clone())
+    }
+
+    /// Start the webhook listener on `addr`
+    ///
+    /// Serves [`Self::webhook_router`] until [`Self::shutdown`]. Bind to port
+    /// 0 to pick a free port.
+    ///
+    /// # Returns
+    /// * `Result<SocketAddr, WorkflowExecutionError>` - The bound address
+    pub async fn serve_webhooks(
+        &self,
+        addr: SocketAddr,
+    ) -> Result<SocketAddr, WorkflowExecutionError> {
+        let listener = tokio::net::TcpListener::bind(addr).await.map_err(|e| {
+            WorkflowExecutionError::Configuration {
+                message: format!("Failed to bind webhook listener on {}: {}", addr, e),
+            }
+        })?;
+        let local_addr =
+            listener
+                .local_addr()
+                .map_err(|e| WorkflowExecutionError::ExecutionFailed {
+                    message: format!("Failed to read webhook listener address: {}", e),
+                })?;
+
+        let router = self.webhook_router();
+        let stopped = self.webhooks.stopped();
+        tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, router)
+                .with_graceful_shutdown(stopped)
+                .await
+            {
+                warn!("Webhook listener on {} stopped: {}", local_addr, e);
+            }
+        });
+
+        info!("Webhook listener serving on {}", local_addr);
+        Ok(local_addr)
+    }
+
+    /// Authenticate a webhook request, record it and start its workflow.
+    async fn fire_webhook(
+        &self,
+        name: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<WebhookFired, WebhookError> {
+        let registration = self
+            .webhooks
+            .get(name)
+            .ok_or_else(|| WebhookError::NotFound(name.to_string()))?;
+        registration.auth.verify(headers, body)?;
+        let mut context = webhook_context(body)?;
+
+        let dal = DAL::new(self.database.clone());
+        let schedule = dal
+            .schedule()
+            .get_by_trigger_name(name)
+            .await
+            .map_err(|e| WebhookError::Failed(e.to_string()))?
+            .ok_or_else(|| WebhookError::NotFound(name.to_string()))?;
+        if !schedule.is_enabled() || schedule.is_paused() {
+            return Err(WebhookError::Paused(name.to_string()));
+        }
+
+        // Hash before the trigger metadata is stamped, like a polled firing.
+        let context_hash = TriggerResult::Fire(Some(context.clone_data()))
+            .context_hash_for_keys(&schedule.dedup_key_list());
+        let event = dal
+            .schedule_execution()
+            .create(NewScheduleExecution {
+                schedule_id: schedule.id,
+                workflow_execution_id: None,
+                scheduled_time: None,
+                claimed_at: None,
+                context_hash: Some(context_hash),
+            })
+            .await
+            .map_err(|e| WebhookError::Failed(e.to_string()))?;
+
+        let stamp = [
+            ("trigger_name", serde_json::json!(name)),
+            ("triggered_at", serde_json::json!(Utc::now().to_rfc3339())),
+        ];
+        for (key, value) in stamp {
+            let _ = context.remove(key);
+            context
+                .insert(key, value)
+                .map_err(|e| WebhookError::Failed(e.to_string()))?;
+        }
+
+        let execution = match self
+            .execute_async(&registration.workflow_name, context)
+            .await
+        {
+            Ok(execution) => execution,
+            Err(e) => {
+                if let Err(e) = dal
+                    .schedule_execution()
+                    .complete(event.id, Utc::now())
+                    .await
+                {
+                    warn!("Failed to complete webhook event {}: {}", event.id, e);
+                }
+                return Err(WebhookError::Failed(e.to_string()));
+            }
+        };
+        let execution_id = UniversalUuid::from(execution.execution_id);
+        if let Err(e) = dal
+            .schedule_execution()
+            .update_workflow_execution_id(event.id, execution_id)
+            .await
+        {
+            warn!("Failed to link webhook event {}: {}", event.id, e);
+        }
+        if let Err(e) = dal
+            .workflow_execution()
+            .set_trigger_origin(execution_id, "webhook")
+            .await
+        {
+            warn!(
+                "Failed to mark execution {} as webhook-fired: {}",
+                execution_id, e
+            );
+        }
+
+        info!(
+            "Webhook trigger '{}' started workflow '{}' (execution: {})",
+            name, registration.workflow_name, execution_id
+        );
+        Ok(WebhookFired {
+            trigger: name.to_string(),
+            workflow_name: registration.workflow_name,
+            execution_id: execution_id.to_string(),
+            event_id: event.id.to_string(),
+        })
+    }
+}
+
+async fn receive_webhook(
+    State(runner): State<DefaultRunner>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    match runner.fire_webhook(&name, &headers, &body).await {
+        Ok(fired) => (StatusCode::ACCEPTED, Json(fired)).into_response(),
+        Err(e) => {
+            if matches!(e, WebhookError::Failed(_)) {
+                warn!("Webhook trigger '{}' failed: {}", name, e);
+            }
+            e.into_response()
+        }
+    }
+}
@@ -27,7 +27,6 @@ use crate::executor::workflow_executor::{
     TaskResult, WorkflowExecutionError, WorkflowExecutionResult, WorkflowStatus,
 };
 use crate::task::TaskState;
-use crate::Context;
 use crate::UniversalUuid;
 
 use super::DefaultRunner;
@@ -53,39 +52,28 @@ impl DefaultRunner {
     ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
         let dal = DAL::new(self.database.clone());
 
-        let workflow_execution = dal
+        // One snapshot, so a completed execution never comes back with a
+        // task still running
+        let mut snapshot = dal
             .workflow_execution()
-            .get_by_id(UniversalUuid(execution_id))
+            .get_snapshot(UniversalUuid(execution_id))
             .await
             .map_err(|e| WorkflowExecutionError::ExecutionFailed {
                 message: format!("Failed to get workflow execution: {}", e),
             })?;
-
-        let task_executions = dal
-            .task_execution()
-            .get_all_tasks_for_workflow(UniversalUuid(execution_id))
-            .await
-            .map_err(|e| WorkflowExecutionError::ExecutionFailed {
-                message: format!("Failed to get task executions: {}", e),
-            })?;
-
-        // Get final context using DAL
-        let final_context = if let Some(context_id) = workflow_execution.context_id {
-            dal.context().read(context_id).await.map_err(|e| {
-                WorkflowExecutionError::ExecutionFailed {
-                    message: format!("Failed to get context: {}", e),
-                }
-            })?
-        } else {
-            Context::new()
-        };
+        let workflow_execution = snapshot.execution;
+        let task_executions = snapshot.tasks;
+        let final_context = workflow_execution
+            .context_id
+            .and_then(|context_id| snapshot.contexts.remove(&context_id))
+            .unwrap_or_default();
 
         // Build task results
         let task_results: Vec<TaskResult> = task_executions
             .into_iter()
             .map(|task_exec| {
                 let status = match task_exec.status.as_str() {
-                    "Pending" => TaskState::Pending,
+                    "Pending" | "AwaitingApproval" => TaskState::Pending,
                     "Running" => TaskState::Running {
                         start_time: task_exec
                             .started_at
@@ -142,6 +130,7 @@ impl DefaultRunner {
                     duration,
                     attempt_count: task_exec.attempt,
                     error_message: task_exec.error_details,
+                    error_payload: task_exec.error_payload,
                 }
             })
             .collect();
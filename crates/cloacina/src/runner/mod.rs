@@ -24,4 +24,20 @@ pub mod default_runner;
 
 pub use default_runner::{DefaultRunner, DefaultRunnerConfig, DefaultRunnerConfigBuilder};
 
+pub use default_runner::CronScheduleOptions;
 pub use default_runner::DefaultRunnerBuilder;
+pub use default_runner::MessageTriggerHandle;
+pub use default_runner::QueueStats;
+pub use default_runner::ResolvedTask;
+pub use default_runner::WorkflowConsumers;
+pub use default_runner::{ContextAccessFinding, ContextAccessReport, ContextLineage, KeyWrite};
+pub use default_runner::{ExecutionFilter, ExecutionPage, ExecutionSort, ExecutionSummary};
+pub use default_runner::{
+    ExecutionGraph, ExecutionGraphNode, ExecutionGraphUpdate, ExecutionGraphWatch,
+};
+pub use default_runner::{ExecutionNotification, NotificationConfig, NotificationKind};
+pub use default_runner::{ExecutionOptions, ExecutionParams};
+pub use default_runner::{
+    ExecutionStream, ExecutionUpdate, StreamingOptions, TaskOutcome, TaskUpdate,
+};
+pub use default_runner::{HookEvent, HookId, HookResult};
@@ -0,0 +1,217 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Runner handoff — coordinated blue/green upgrades of long-running runners.
+//!
+//! Every runner with handoff enabled registers itself in `runner_instances`
+//! and heartbeats while it runs. A new runner started with takeover enabled
+//! moves every live `active` instance running a different version to
+//! `draining` and names itself as their successor. The old runner sees the change on its next heartbeat and raises
+//! its handoff signal; the host then calls
+//! [`DefaultRunner::drain`](crate::DefaultRunner::drain), which stops the old
+//! runner's schedulers, waits for its in-flight tasks, and marks it
+//! `drained`. A runner that shuts down without a handoff is marked `stopped`.
+//!
+//! Nothing is transferred explicitly. Cron firings are claimed atomically and
+//! ready tasks are claimed through `claimed_by`, so once the old runner stops
+//! taking new work its schedules and queued executions are simply picked up
+//! by the successor.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::dal::DAL;
+use crate::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use crate::error::ValidationError;
+use crate::models::runner_instance::{RunnerInstance, RunnerInstanceState};
+
+/// Heartbeats a runner may miss before a successor stops treating it as live.
+const LIVENESS_MISSES: u32 = 3;
+
+/// Configuration for the runner handoff monitor.
+#[derive(Debug, Clone)]
+pub struct RunnerHandoffConfig {
+    /// This runner's row in `runner_instances`.
+    pub instance_id: UniversalUuid,
+    /// Optional human-readable runner name, recorded for operators.
+    pub name: Option<String>,
+    /// How often the runner heartbeats and checks for a drain request
+    /// (default 10s).
+    pub heartbeat_interval: Duration,
+    /// Ask every live runner on another version to drain when this one
+    /// registers.
+    pub takeover: bool,
+}
+
+impl Default for RunnerHandoffConfig {
+    fn default() -> Self {
+        Self {
+            instance_id: UniversalUuid::new_v4(),
+            name: None,
+            heartbeat_interval: Duration::from_secs(10),
+            takeover: false,
+        }
+    }
+}
+
+/// Background service that keeps this runner's registry row alive and
+/// raises the handoff signal when a successor asks it to drain.
+pub struct RunnerHandoffMonitor {
+    dal: Arc<DAL>,
+    config: RunnerHandoffConfig,
+    handoff_tx: Arc<watch::Sender<bool>>,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl RunnerHandoffMonitor {
+    /// Create a new handoff monitor. `handoff_tx` is set to `true` once a
+    /// drain has been requested.
+    pub fn new(
+        dal: Arc<DAL>,
+        config: RunnerHandoffConfig,
+        handoff_tx: Arc<watch::Sender<bool>>,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            dal,
+            config,
+            handoff_tx,
+            shutdown_rx,
+        }
+    }
+
+    /// Register this runner and, with takeover enabled, ask its live
+    /// predecessors to drain. Returns the runners that were asked.
+    pub async fn register(&self) -> Result<Vec<RunnerInstance>, ValidationError> {
+        let registry = self.dal.runner_instance();
+        registry
+            .register(
+                self.config.instance_id,
+                self.config.name.clone(),
+                env!("CARGO_PKG_VERSION"),
+            )
+            .await?;
+        info!(
+            instance_id = %self.config.instance_id,
+            version = env!("CARGO_PKG_VERSION"),
+            "Registered runner instance"
+        );
+
+        if !self.config.takeover {
+            return Ok(Vec::new());
+        }
+
+        let window = chrono::Duration::from_std(self.config.heartbeat_interval * LIVENESS_MISSES)
+            .unwrap_or_default();
+        let live_since = UniversalTimestamp(chrono::Utc::now() - window);
+        let predecessors = registry
+            .request_handoff(
+                self.config.instance_id,
+                env!("CARGO_PKG_VERSION"),
+                live_since,
+            )
+            .await?;
+        if predecessors.is_empty() {
+            info!("Takeover requested but no live runner to hand off from");
+        }
+        for predecessor in &predecessors {
+            info!(
+                predecessor = %predecessor.id,
+                predecessor_version = %predecessor.version,
+                "Asked runner to drain for takeover"
+            );
+        }
+        Ok(predecessors)
+    }
+
+    /// Run the heartbeat loop.
+    pub async fn run(&mut self) {
+        let mut interval = tokio::time::interval(self.config.heartbeat_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.heartbeat().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    if *self.shutdown_rx.borrow() {
+                        info!("Runner handoff monitor shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Perform a single heartbeat. Returns `true` if a drain has been
+    /// requested for this runner.
+    pub async fn heartbeat(&self) -> bool {
+        let instance = match self
+            .dal
+            .runner_instance()
+            .heartbeat(self.config.instance_id)
+            .await
+        {
+            Ok(instance) => instance,
+            Err(e) => {
+                warn!("Runner heartbeat failed: {}", e);
+                return *self.handoff_tx.borrow();
+            }
+        };
+
+        match instance.state() {
+            Some(RunnerInstanceState::Active) | Some(RunnerInstanceState::Stopped) => {
+                debug!(instance_id = %instance.id, "Runner heartbeat");
+                false
+            }
+            Some(RunnerInstanceState::Draining) | Some(RunnerInstanceState::Drained) => {
+                if !*self.handoff_tx.borrow() {
+                    info!(
+                        instance_id = %instance.id,
+                        successor = ?instance.successor_id.map(|s| s.to_string()),
+                        "Drain requested by successor runner"
+                    );
+                    self.handoff_tx.send_replace(true);
+                }
+                true
+            }
+            None => {
+                warn!(
+                    "Runner instance {} has unknown state '{}'",
+                    instance.id, instance.state
+                );
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults() {
+        let config = RunnerHandoffConfig::default();
+        assert_eq!(config.heartbeat_interval, Duration::from_secs(10));
+        assert!(!config.takeover);
+        assert!(config.name.is_none());
+    }
+}
@@ -165,6 +165,39 @@ impl Runtime {
         }
     }
 
+    /// Register a single macro-registered workflow, and the tasks it uses,
+    /// from the `inventory` entries.
+    ///
+    /// Lets a runtime started from [`Runtime::empty`] pick the `#[workflow]`
+    /// definitions it should run instead of taking every one linked into the
+    /// binary. Triggers and graphs are not seeded. Returns `false` (and
+    /// registers nothing) when no workflow of that name was registered.
+    pub fn seed_workflow_from_inventory(&self, name: &str) -> bool {
+        use crate::inventory_entries::{TaskEntry, WorkflowEntry};
+
+        let Some(entry) = inventory::iter::<WorkflowEntry>
+            .into_iter()
+            .find(|e| e.name == name)
+        else {
+            return false;
+        };
+        let workflow = (entry.constructor)();
+
+        for namespace in workflow.get_task_ids() {
+            let macro_task = inventory::iter::<TaskEntry>
+                .into_iter()
+                .find(|e| (e.namespace)() == namespace);
+            if let Some(task_entry) = macro_task {
+                let ctor = task_entry.constructor;
+                self.register_task(namespace, move || ctor());
+            } else if let Ok(task) = workflow.get_task(&namespace) {
+                self.register_task(namespace, move || task.clone());
+            }
+        }
+        self.register_workflow(entry.name.to_string(), entry.constructor);
+        true
+    }
+
     // -----------------------------------------------------------------------
     // Task registry
     // -----------------------------------------------------------------------
@@ -186,8 +219,31 @@ impl Runtime {
     }
 
     /// Look up and instantiate a task by namespace.
+    ///
+    /// Tasks of a workflow that embeds other workflows resolve through that
+    /// workflow when needed: inlined tasks are not registered on their own,
+    /// and a task depending on an embed's prefix depends on its leaf tasks.
     pub fn get_task(&self, namespace: &TaskNamespace) -> Option<Arc<dyn Task>> {
-        self.inner.tasks.read().get(namespace).map(|ctor| ctor())
+        let registered = {
+            let tasks = self.inner.tasks.read();
+            tasks.get(namespace).map(|ctor| ctor()).filter(|task| {
+                task.dependencies()
+                    .iter()
+                    .all(|dependency| tasks.contains_key(dependency))
+            })
+        };
+        if registered.is_some() {
+            return registered;
+        }
+
+        let workflow = self.construct_workflow(&namespace.workflow_id);
+        match workflow {
+            Some(workflow) if !workflow.embedded_workflows().is_empty() => self
+                .inline_embedded_workflows(workflow, &mut vec![namespace.workflow_id.clone()])
+                .ok()
+                .and_then(|workflow| workflow.get_task(namespace).ok()),
+            _ => self.inner.tasks.read().get(namespace).map(|ctor| ctor()),
+        }
     }
 
     /// Check if a task is registered for the given namespace.
@@ -218,16 +274,78 @@ impl Runtime {
             .insert(name, Box::new(constructor));
     }
 
+    /// Register a workflow assembled at runtime together with each of its
+    /// tasks, so executions can resolve them by namespace. Replaces any
+    /// earlier registration under the same names.
+    pub fn register_built_workflow(&self, workflow: Workflow) {
+        for namespace in workflow.get_task_ids() {
+            if let Ok(task) = workflow.get_task(&namespace) {
+                self.register_task(namespace, move || task.clone());
+            }
+        }
+        self.register_workflow(workflow.name().to_string(), move || workflow.clone());
+    }
+
     /// Remove a workflow constructor. Returns true if the entry existed.
     pub fn unregister_workflow(&self, name: &str) -> bool {
         self.inner.workflows.write().remove(name).is_some()
     }
 
     /// Look up and instantiate a workflow by name.
+    ///
+    /// Workflows it embeds (see [`Workflow::embed_workflow`]) are looked up
+    /// here too and inlined into it. A workflow whose embedded workflows are
+    /// not all registered, or that embeds itself, is unavailable (`None`).
     pub fn get_workflow(&self, name: &str) -> Option<Workflow> {
+        let workflow = self.construct_workflow(name)?;
+        if workflow.embedded_workflows().is_empty() {
+            return Some(workflow);
+        }
+        match self.inline_embedded_workflows(workflow, &mut vec![name.to_string()]) {
+            Ok(workflow) => Some(workflow),
+            Err(e) => {
+                tracing::warn!("Workflow '{}' is unavailable: {}", name, e);
+                None
+            }
+        }
+    }
+
+    fn construct_workflow(&self, name: &str) -> Option<Workflow> {
         self.inner.workflows.read().get(name).map(|ctor| ctor())
     }
 
+    /// Inline every embedded workflow of `workflow`, resolving their own
+    /// embeds first. `stack` holds the embedding workflows being resolved,
+    /// to reject embed cycles.
+    fn inline_embedded_workflows(
+        &self,
+        mut workflow: Workflow,
+        stack: &mut Vec<String>,
+    ) -> Result<Workflow, String> {
+        for embed in workflow.embedded_workflows().to_vec() {
+            if stack.contains(&embed.workflow) {
+                return Err(format!(
+                    "embed '{}' of workflow '{}' is cyclic",
+                    embed.prefix, embed.workflow
+                ));
+            }
+            let embedded = self.construct_workflow(&embed.workflow).ok_or_else(|| {
+                format!(
+                    "embedded workflow '{}' ('{}') is not registered",
+                    embed.workflow, embed.prefix
+                )
+            })?;
+            stack.push(embed.workflow.clone());
+            let embedded = self.inline_embedded_workflows(embedded, stack);
+            stack.pop();
+            workflow
+                .inline_workflow(&embed.prefix, &embedded?)
+                .map_err(|e| e.to_string())?;
+        }
+        workflow.validate().map_err(|e| e.to_string())?;
+        Ok(workflow.finalize())
+    }
+
     /// Get all registered workflow names.
     pub fn workflow_names(&self) -> Vec<String> {
         self.inner.workflows.read().keys().cloned().collect()
@@ -485,6 +603,82 @@ mod tests {
         assert!(!rt.has_task(&ns));
     }
 
+    #[test]
+    fn register_built_workflow_registers_its_tasks() {
+        let rt = Runtime::empty();
+        let workflow = crate::workflow::Workflow::builder("built")
+            .task(crate::task::FnTask::new("extract", |context| async move {
+                Ok(context)
+            }))
+            .unwrap()
+            .task(
+                crate::task::FnTask::new("load", |context| async move { Ok(context) })
+                    .depends_on("extract"),
+            )
+            .unwrap()
+            .register(&rt)
+            .unwrap();
+
+        assert!(rt.get_workflow("built").is_some());
+        for namespace in workflow.get_task_ids() {
+            assert!(rt.has_task(&namespace));
+        }
+        let load = TaskNamespace::new("public", "embedded", "built", "load");
+        assert_eq!(
+            rt.get_task(&load).unwrap().dependencies(),
+            &[TaskNamespace::new("public", "embedded", "built", "extract")]
+        );
+    }
+
+    #[test]
+    fn get_workflow_inlines_embedded_workflows() {
+        let noop = |id: &str| crate::task::FnTask::new(id, |context| async move { Ok(context) });
+        let rt = Runtime::empty();
+        crate::workflow::Workflow::builder("vendor_ingest")
+            .package("vendor_pkg")
+            .task(noop("extract"))
+            .unwrap()
+            .task(noop("load").depends_on("extract"))
+            .unwrap()
+            .register(&rt)
+            .unwrap();
+        crate::workflow::Workflow::builder("etl")
+            .task(noop("prepare"))
+            .unwrap()
+            .embed_workflow("vendor", "vendor_ingest", &["prepare"])
+            .unwrap()
+            .task(noop("publish").depends_on("vendor"))
+            .unwrap()
+            .register(&rt)
+            .unwrap();
+
+        let ns = |id: &str| TaskNamespace::new("public", "embedded", "etl", id);
+        let workflow = rt.get_workflow("etl").unwrap();
+        assert!(workflow.embedded_workflows().is_empty());
+        assert_eq!(workflow.get_task_ids().len(), 4);
+        assert!(!rt.has_task(&ns("vendor.load")));
+        assert_eq!(
+            rt.get_task(&ns("vendor.extract")).unwrap().dependencies(),
+            &[ns("prepare")]
+        );
+        assert_eq!(
+            rt.get_task(&ns("publish")).unwrap().dependencies(),
+            &[ns("vendor.load")]
+        );
+
+        // Without the embedded workflow the embedding one is unavailable.
+        assert!(rt.unregister_workflow("vendor_ingest"));
+        assert!(rt.get_workflow("etl").is_none());
+    }
+
+    #[test]
+    fn seed_workflow_from_inventory_ignores_unknown_names() {
+        let rt = Runtime::empty();
+        assert!(!rt.seed_workflow_from_inventory("no_such_workflow"));
+        assert!(rt.workflow_names().is_empty());
+        assert!(rt.task_namespaces().is_empty());
+    }
+
     #[test]
     fn stream_backend_roundtrip_names_only() {
         let rt = Runtime::empty();
@@ -90,6 +90,16 @@ pub mod events {
     /// (CLOACI-T-0753). Like the reactor manual-fire, this bypasses the real
     /// event source, so the audit trail marks it operator-injected.
     pub const ACCUMULATOR_MANUAL_INJECT: &str = "accumulator.manual_inject";
+
+    /// An IdP-issued bearer token was accepted and mapped to a principal.
+    /// Emitted once per token, not per request.
+    pub const AUTH_TOKEN_ACCEPTED: &str = "auth.token.accepted";
+    /// A request presented a credential that was rejected.
+    pub const AUTH_REJECTED: &str = "auth.rejected";
+
+    /// The audit chain head was recorded as an anchor. Shipping this event to
+    /// an external log store is what makes a rewrite of the chain detectable.
+    pub const AUDIT_CHAIN_ANCHORED: &str = "audit.chain.anchored";
 }
 
 /// Log a signing key creation event.
@@ -439,6 +449,41 @@ pub fn log_accumulator_manual_inject(
     );
 }
 
+/// Log the first use of an IdP-issued bearer token. `principal` is the
+/// `oidc:<issuer>:<sub>` provenance; `tenant_id` and `role` are what the
+/// mapping policy granted it.
+pub fn log_token_authenticated(principal: &str, tenant_id: Option<&str>, role: &str) {
+    tracing::info!(
+        event_type = events::AUTH_TOKEN_ACCEPTED,
+        principal = %principal,
+        tenant_id = tenant_id.unwrap_or("<none>"),
+        role = %role,
+        "Bearer token authenticated"
+    );
+}
+
+/// Log a rejected credential. `method` is `api_key` or `oidc_jwt`; `reason`
+/// never contains the credential itself.
+pub fn log_authentication_failed(method: &str, reason: &str) {
+    tracing::warn!(
+        event_type = events::AUTH_REJECTED,
+        method = %method,
+        reason = %reason,
+        "Authentication rejected"
+    );
+}
+
+/// Log a new audit chain anchor.
+pub fn log_audit_chain_anchored(sequence: i64, entry_hash: &str, signed: bool) {
+    tracing::info!(
+        event_type = events::AUDIT_CHAIN_ANCHORED,
+        sequence = sequence,
+        entry_hash = %entry_hash,
+        signed = signed,
+        "Audit chain anchored"
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -879,4 +924,24 @@ mod tests {
         assert!(output.contains("101"));
         assert!(output.contains("unobtanium"));
     }
+
+    #[test]
+    fn test_log_token_authenticated() {
+        let output = with_captured_logs(|| {
+            log_token_authenticated("oidc:https://idp.example:sub-1", Some("acme"), "write");
+        });
+        assert!(output.contains(events::AUTH_TOKEN_ACCEPTED));
+        assert!(output.contains("oidc:https://idp.example:sub-1"));
+        assert!(output.contains("acme"));
+    }
+
+    #[test]
+    fn test_log_authentication_failed() {
+        let output = with_captured_logs(|| {
+            log_authentication_failed("oidc_jwt", "token expired");
+        });
+        assert!(output.contains(events::AUTH_REJECTED));
+        assert!(output.contains("oidc_jwt"));
+        assert!(output.contains("token expired"));
+    }
 }
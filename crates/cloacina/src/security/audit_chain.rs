@@ -0,0 +1,656 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Tamper-evident audit chain.
+//!
+//! Every `event_type`-tagged event emitted through [`super::audit`] can be
+//! captured by an [`AuditChainLayer`] and appended to the `audit_log` table.
+//! Each entry stores the SHA-256 of the previous entry, so editing, deleting
+//! or reordering a row breaks every hash after it.
+//!
+//! A chain on its own cannot catch someone who rewrites the whole tail from
+//! the edit onwards. Anchors close that gap: [`anchor_head`] periodically
+//! records the current head, signs it with an Ed25519 key held outside the
+//! database ([`AnchorSigner`]), and emits an `audit.chain.anchored` event so
+//! the head also reaches the log pipeline. [`verify_chain`] recomputes every
+//! hash in a range and checks each anchor against it.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+use crate::dal::DAL;
+use crate::database::Database;
+use crate::error::ValidationError;
+
+/// `prev_hash` of the first entry.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Environment variable holding the anchor signing key (32-byte Ed25519
+/// seed, base64 or hex).
+pub const ANCHOR_KEY_ENV_VAR: &str = "CLOACINA_AUDIT_ANCHOR_KEY";
+
+/// Entries read per query while verifying.
+const VERIFY_PAGE_SIZE: i64 = 1000;
+
+/// Errors configuring the audit chain.
+#[derive(Debug, thiserror::Error)]
+pub enum AuditChainError {
+    #[error("invalid anchor key: {0}")]
+    InvalidKey(String),
+}
+
+/// One chained audit entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditEntry {
+    pub sequence: i64,
+    pub event_type: String,
+    /// The event's fields as a JSON object.
+    pub payload: String,
+    pub prev_hash: String,
+    pub entry_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A recorded chain head.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditAnchor {
+    pub sequence: i64,
+    pub entry_hash: String,
+    /// Hex Ed25519 signature over [`anchor_message`]; `None` when no anchor
+    /// key was configured.
+    pub signature: Option<String>,
+    pub anchored_at: DateTime<Utc>,
+}
+
+/// Hash of an entry: SHA-256 over the previous hash, its position, its
+/// timestamp (microseconds), its event type and its payload.
+pub fn entry_hash(
+    prev_hash: &str,
+    sequence: i64,
+    created_at: &DateTime<Utc>,
+    event_type: &str,
+    payload: &str,
+) -> String {
+    let sequence = sequence.to_string();
+    let micros = created_at.timestamp_micros().to_string();
+    let mut hasher = Sha256::new();
+    for part in [prev_hash, sequence.as_str(), micros.as_str(), event_type] {
+        hasher.update(part.as_bytes());
+        hasher.update(b"\n");
+    }
+    hasher.update(payload.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// The bytes an anchor signature covers.
+pub fn anchor_message(sequence: i64, entry_hash: &str) -> Vec<u8> {
+    format!("cloacina-audit-anchor:{}:{}", sequence, entry_hash).into_bytes()
+}
+
+/// Signs anchors with an Ed25519 key. Give auditors the public key; the
+/// private key should not live anywhere the database's writers can read.
+pub struct AnchorSigner {
+    key: SigningKey,
+}
+
+impl std::fmt::Debug for AnchorSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnchorSigner")
+            .field("public_key", &self.public_key_hex())
+            .finish()
+    }
+}
+
+impl AnchorSigner {
+    /// A signer from a 32-byte Ed25519 seed.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, AuditChainError> {
+        let seed: [u8; 32] = seed.try_into().map_err(|_| {
+            AuditChainError::InvalidKey(format!("expected 32 bytes, got {}", seed.len()))
+        })?;
+        Ok(Self {
+            key: SigningKey::from_bytes(&seed),
+        })
+    }
+
+    /// The signer from `CLOACINA_AUDIT_ANCHOR_KEY`, or `None` when unset.
+    pub fn from_env() -> Result<Option<Self>, AuditChainError> {
+        use base64::Engine as _;
+        let Ok(raw) = std::env::var(ANCHOR_KEY_ENV_VAR) else {
+            return Ok(None);
+        };
+        let raw = raw.trim();
+        let seed = hex::decode(raw)
+            .ok()
+            .filter(|b| b.len() == 32)
+            .or_else(|| base64::engine::general_purpose::STANDARD.decode(raw).ok())
+            .ok_or_else(|| {
+                AuditChainError::InvalidKey(format!("{} is not hex or base64", ANCHOR_KEY_ENV_VAR))
+            })?;
+        Self::from_seed(&seed).map(Some)
+    }
+
+    /// Hex public key auditors verify anchors with.
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().to_bytes())
+    }
+
+    /// Raw public key bytes, for [`verify_anchor_signature`].
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        self.key.verifying_key().to_bytes().to_vec()
+    }
+
+    /// Hex signature over [`anchor_message`].
+    pub fn sign(&self, sequence: i64, entry_hash: &str) -> String {
+        hex::encode(
+            self.key
+                .sign(&anchor_message(sequence, entry_hash))
+                .to_bytes(),
+        )
+    }
+}
+
+/// Whether `signature` (hex) is `public_key`'s signature over the anchor.
+pub fn verify_anchor_signature(
+    public_key: &[u8],
+    sequence: i64,
+    entry_hash: &str,
+    signature: &str,
+) -> bool {
+    let Ok(key) = <[u8; 32]>::try_from(public_key) else {
+        return false;
+    };
+    let Ok(key) = VerifyingKey::from_bytes(&key) else {
+        return false;
+    };
+    let Some(sig) = hex::decode(signature)
+        .ok()
+        .and_then(|b| <[u8; 64]>::try_from(b.as_slice()).ok())
+    else {
+        return false;
+    };
+    key.verify(
+        &anchor_message(sequence, entry_hash),
+        &Signature::from_bytes(&sig),
+    )
+    .is_ok()
+}
+
+// ---------------------------------------------------------------------------
+// Capture — a tracing layer that forwards audit events to the chain writer
+// ---------------------------------------------------------------------------
+
+/// An audit event captured from tracing, waiting to be appended.
+#[derive(Debug, Clone)]
+pub struct CapturedAuditEvent {
+    pub event_type: String,
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Receiving end of an [`AuditChainLayer`]; hand it to
+/// [`spawn_audit_chain_writer`].
+pub type AuditEventReceiver = mpsc::UnboundedReceiver<CapturedAuditEvent>;
+
+/// Whether `metadata` is an audit event: an event carrying an `event_type`
+/// field, as every [`super::audit`] event does.
+pub fn is_audit_event(metadata: &tracing::Metadata<'_>) -> bool {
+    metadata.is_event() && metadata.fields().field("event_type").is_some()
+}
+
+/// Tracing layer forwarding every audit event (see [`is_audit_event`]) to
+/// the chain writer. Install it when the subscriber is built; events
+/// captured before the writer starts are queued. Give it its own
+/// [`is_audit_event`] filter rather than the log-level filter, so raising the
+/// log level cannot drop events from the chain.
+pub struct AuditChainLayer {
+    sender: mpsc::UnboundedSender<CapturedAuditEvent>,
+}
+
+/// Create a capture layer and the receiver its events arrive on.
+pub fn audit_chain_layer() -> (AuditChainLayer, AuditEventReceiver) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    (AuditChainLayer { sender }, receiver)
+}
+
+#[derive(Default)]
+struct FieldCollector {
+    event_type: Option<String>,
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Visit for FieldCollector {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "event_type" {
+            self.event_type = Some(value.to_string());
+        } else {
+            self.fields.insert(field.name().to_string(), value.into());
+        }
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let rendered = format!("{:?}", value);
+        if field.name() == "event_type" {
+            self.event_type = Some(rendered.trim_matches('"').to_string());
+        } else {
+            self.fields
+                .insert(field.name().to_string(), rendered.into());
+        }
+    }
+}
+
+impl<S: tracing::Subscriber> Layer<S> for AuditChainLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        if !is_audit_event(event.metadata()) {
+            return;
+        }
+        let mut collector = FieldCollector::default();
+        event.record(&mut collector);
+        if let Some(event_type) = collector.event_type {
+            let mut fields = collector.fields;
+            fields.insert(
+                "level".to_string(),
+                event.metadata().level().as_str().into(),
+            );
+            // A closed receiver means the writer is gone; nothing to do.
+            let _ = self.sender.send(CapturedAuditEvent { event_type, fields });
+        }
+    }
+}
+
+/// Append captured events to the chain until the layer is dropped.
+///
+/// Failures are logged without an `event_type` so they are not captured
+/// again, and counted in `cloacina_audit_chain_append_failures_total`.
+pub fn spawn_audit_chain_writer(
+    database: Database,
+    mut receiver: AuditEventReceiver,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let dal = DAL::new(database);
+        while let Some(event) = receiver.recv().await {
+            let payload = serde_json::Value::Object(event.fields).to_string();
+            if let Err(e) = dal.audit_log().append(&event.event_type, &payload).await {
+                metrics::counter!("cloacina_audit_chain_append_failures_total").increment(1);
+                tracing::error!(
+                    "Failed to append {} to the audit chain: {}",
+                    event.event_type,
+                    e
+                );
+            }
+        }
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Anchoring and verification
+// ---------------------------------------------------------------------------
+
+/// Record the current chain head as an anchor, unless it is already
+/// anchored. Returns the new anchor, if any.
+pub async fn anchor_head(
+    dal: &DAL,
+    signer: Option<&AnchorSigner>,
+) -> Result<Option<AuditAnchor>, ValidationError> {
+    let Some(head) = dal.audit_log().head().await? else {
+        return Ok(None);
+    };
+    if let Some(latest) = dal.audit_log().latest_anchor().await? {
+        if latest.sequence >= head.sequence {
+            return Ok(None);
+        }
+    }
+    let signature = signer.map(|s| s.sign(head.sequence, &head.entry_hash));
+    let anchor = dal
+        .audit_log()
+        .insert_anchor(head.sequence, &head.entry_hash, signature)
+        .await?;
+    if let Some(anchor) = &anchor {
+        super::audit::log_audit_chain_anchored(
+            anchor.sequence,
+            &anchor.entry_hash,
+            anchor.signature.is_some(),
+        );
+    }
+    Ok(anchor)
+}
+
+/// Where and why verification stopped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainBreak {
+    pub sequence: i64,
+    pub reason: String,
+}
+
+/// Result of [`verify_chain`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ChainVerification {
+    pub entries_checked: u64,
+    pub anchors_checked: u64,
+    pub first_sequence: Option<i64>,
+    pub last_sequence: Option<i64>,
+    /// Hash of the last verified entry.
+    pub head_hash: Option<String>,
+    /// The first inconsistency found; `None` when the range verified.
+    pub failure: Option<ChainBreak>,
+}
+
+impl ChainVerification {
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Incremental chain checker: feed entries in sequence order.
+pub struct ChainVerifier<'a> {
+    expected_sequence: i64,
+    expected_prev: String,
+    anchors: HashMap<i64, AuditAnchor>,
+    anchor_key: Option<&'a [u8]>,
+    result: ChainVerification,
+}
+
+impl<'a> ChainVerifier<'a> {
+    /// Start at `sequence`, whose `prev_hash` must be `prev_hash`.
+    pub fn new(
+        sequence: i64,
+        prev_hash: String,
+        anchors: Vec<AuditAnchor>,
+        anchor_key: Option<&'a [u8]>,
+    ) -> Self {
+        Self {
+            expected_sequence: sequence,
+            expected_prev: prev_hash,
+            anchors: anchors.into_iter().map(|a| (a.sequence, a)).collect(),
+            anchor_key,
+            result: ChainVerification::default(),
+        }
+    }
+
+    /// Check the next entry. Returns `false` once the chain is broken.
+    pub fn push(&mut self, entry: &AuditEntry) -> bool {
+        if self.result.failure.is_some() {
+            return false;
+        }
+        if let Err(reason) = self.check(entry) {
+            self.result.failure = Some(ChainBreak {
+                sequence: self.expected_sequence,
+                reason,
+            });
+            return false;
+        }
+        self.result.entries_checked += 1;
+        self.result.first_sequence.get_or_insert(entry.sequence);
+        self.result.last_sequence = Some(entry.sequence);
+        self.result.head_hash = Some(entry.entry_hash.clone());
+        self.expected_sequence += 1;
+        self.expected_prev = entry.entry_hash.clone();
+        true
+    }
+
+    fn check(&mut self, entry: &AuditEntry) -> Result<(), String> {
+        if entry.sequence != self.expected_sequence {
+            return Err(format!(
+                "entry missing: next stored entry is {}",
+                entry.sequence
+            ));
+        }
+        if entry.prev_hash != self.expected_prev {
+            return Err("prev_hash does not match the preceding entry".to_string());
+        }
+        let recomputed = entry_hash(
+            &entry.prev_hash,
+            entry.sequence,
+            &entry.created_at,
+            &entry.event_type,
+            &entry.payload,
+        );
+        if recomputed != entry.entry_hash {
+            return Err("entry contents do not match its hash".to_string());
+        }
+        if let Some(anchor) = self.anchors.remove(&entry.sequence) {
+            if anchor.entry_hash != entry.entry_hash {
+                return Err("entry does not match its anchor".to_string());
+            }
+            if let Some(key) = self.anchor_key {
+                match &anchor.signature {
+                    Some(sig)
+                        if verify_anchor_signature(
+                            key,
+                            anchor.sequence,
+                            &anchor.entry_hash,
+                            sig,
+                        ) => {}
+                    Some(_) => return Err("anchor signature is invalid".to_string()),
+                    None => return Err("anchor is unsigned".to_string()),
+                }
+            }
+            self.result.anchors_checked += 1;
+        }
+        Ok(())
+    }
+
+    /// Finish; anchors past the last entry mean the chain was truncated.
+    pub fn finish(mut self) -> ChainVerification {
+        if self.result.failure.is_none() {
+            if let Some(missing) = self.anchors.keys().min() {
+                self.result.failure = Some(ChainBreak {
+                    sequence: *missing,
+                    reason: "anchored entry is missing (chain truncated)".to_string(),
+                });
+            }
+        }
+        self.result
+    }
+}
+
+/// Verify entries `from..=to` (whole chain by default). A range that starts
+/// after the first entry trusts its predecessor's stored hash. With
+/// `anchor_key`, every anchor in range must carry a valid signature.
+pub async fn verify_chain(
+    dal: &DAL,
+    from: Option<i64>,
+    to: Option<i64>,
+    anchor_key: Option<&[u8]>,
+) -> Result<ChainVerification, ValidationError> {
+    let from = from.unwrap_or(1).max(1);
+    let prev_hash = if from == 1 {
+        GENESIS_HASH.to_string()
+    } else {
+        match dal.audit_log().get(from - 1).await? {
+            Some(prev) => prev.entry_hash,
+            None => {
+                return Ok(ChainVerification {
+                    failure: Some(ChainBreak {
+                        sequence: from - 1,
+                        reason: "entry missing before the requested range".to_string(),
+                    }),
+                    ..Default::default()
+                })
+            }
+        }
+    };
+    let anchors = dal.audit_log().anchors(from, to).await?;
+    let mut verifier = ChainVerifier::new(from, prev_hash, anchors, anchor_key);
+
+    let mut next = from;
+    loop {
+        let page = dal.audit_log().list(next, to, VERIFY_PAGE_SIZE).await?;
+        let Some(last) = page.last() else { break };
+        next = last.sequence + 1;
+        let full = page.len() as i64 == VERIFY_PAGE_SIZE;
+        for entry in &page {
+            if !verifier.push(entry) {
+                return Ok(verifier.finish());
+            }
+        }
+        if !full {
+            break;
+        }
+    }
+    Ok(verifier.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(n: i64) -> Vec<AuditEntry> {
+        let mut prev = GENESIS_HASH.to_string();
+        let created_at = DateTime::from_timestamp_micros(1_760_000_000_000_000).unwrap();
+        (1..=n)
+            .map(|sequence| {
+                let payload = format!(r#"{{"n":{}}}"#, sequence);
+                let hash = entry_hash(&prev, sequence, &created_at, "key.exported", &payload);
+                let entry = AuditEntry {
+                    sequence,
+                    event_type: "key.exported".to_string(),
+                    payload,
+                    prev_hash: prev.clone(),
+                    entry_hash: hash.clone(),
+                    created_at,
+                };
+                prev = hash;
+                entry
+            })
+            .collect()
+    }
+
+    fn verify(
+        entries: &[AuditEntry],
+        anchors: Vec<AuditAnchor>,
+        key: Option<&[u8]>,
+    ) -> ChainVerification {
+        let mut v = ChainVerifier::new(1, GENESIS_HASH.to_string(), anchors, key);
+        for e in entries {
+            if !v.push(e) {
+                break;
+            }
+        }
+        v.finish()
+    }
+
+    fn anchor(entry: &AuditEntry, signer: Option<&AnchorSigner>) -> AuditAnchor {
+        AuditAnchor {
+            sequence: entry.sequence,
+            entry_hash: entry.entry_hash.clone(),
+            signature: signer.map(|s| s.sign(entry.sequence, &entry.entry_hash)),
+            anchored_at: entry.created_at,
+        }
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let entries = chain(5);
+        let result = verify(&entries, vec![anchor(&entries[2], None)], None);
+        assert!(result.is_valid());
+        assert_eq!(result.entries_checked, 5);
+        assert_eq!(result.anchors_checked, 1);
+        assert_eq!(
+            result.head_hash.as_deref(),
+            Some(entries[4].entry_hash.as_str())
+        );
+    }
+
+    #[test]
+    fn edited_payload_breaks_the_chain() {
+        let mut entries = chain(5);
+        entries[2].payload = r#"{"n":99}"#.to_string();
+        let result = verify(&entries, vec![], None);
+        let failure = result.failure.unwrap();
+        assert_eq!(failure.sequence, 3);
+        assert_eq!(result.entries_checked, 2);
+    }
+
+    #[test]
+    fn deleted_entry_breaks_the_chain() {
+        let mut entries = chain(5);
+        entries.remove(1);
+        let failure = verify(&entries, vec![], None).failure.unwrap();
+        assert_eq!(failure.sequence, 2);
+    }
+
+    #[test]
+    fn rewritten_tail_is_caught_by_its_anchor() {
+        let original = chain(4);
+        let anchors = vec![anchor(&original[3], None)];
+        // Re-hash the whole chain after editing entry 2: links are
+        // consistent again, but entry 4 no longer matches its anchor.
+        let mut forged = original.clone();
+        forged[1].payload = r#"{"n":"forged"}"#.to_string();
+        let mut prev = forged[0].entry_hash.clone();
+        for e in forged.iter_mut().skip(1) {
+            e.prev_hash = prev.clone();
+            e.entry_hash = entry_hash(&prev, e.sequence, &e.created_at, &e.event_type, &e.payload);
+            prev = e.entry_hash.clone();
+        }
+        let failure = verify(&forged, anchors, None).failure.unwrap();
+        assert_eq!(failure.sequence, 4);
+    }
+
+    #[test]
+    fn truncation_below_an_anchor_is_caught() {
+        let entries = chain(4);
+        let anchors = vec![anchor(&entries[3], None)];
+        let failure = verify(&entries[..2], anchors, None).failure.unwrap();
+        assert_eq!(failure.sequence, 4);
+    }
+
+    #[test]
+    fn anchor_signatures_are_checked() {
+        let entries = chain(2);
+        let signer = AnchorSigner::from_seed(&[7u8; 32]).unwrap();
+        let public = hex::decode(signer.public_key_hex()).unwrap();
+        assert!(verify(
+            &entries,
+            vec![anchor(&entries[1], Some(&signer))],
+            Some(&public)
+        )
+        .is_valid());
+
+        let other = AnchorSigner::from_seed(&[8u8; 32]).unwrap();
+        let forged = verify(
+            &entries,
+            vec![anchor(&entries[1], Some(&other))],
+            Some(&public),
+        );
+        assert_eq!(
+            forged.failure.unwrap().reason,
+            "anchor signature is invalid"
+        );
+
+        let unsigned = verify(&entries, vec![anchor(&entries[1], None)], Some(&public));
+        assert_eq!(unsigned.failure.unwrap().reason, "anchor is unsigned");
+    }
+}
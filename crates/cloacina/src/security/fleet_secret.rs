@@ -619,6 +619,8 @@ mod tests {
             language: None,
             wrapped_secrets: vec![wrapped],
             secret_key_id: Some(key.key_id.clone()),
+            capabilities: None,
+            params: None,
         };
 
         // Wire assertion: the plaintext value must not appear anywhere in the
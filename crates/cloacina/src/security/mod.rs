@@ -21,13 +21,22 @@
 //! - [`DbKeyManager`] database-backed implementation
 //! - Key generation, encryption, and PEM export/import
 //! - Security audit logging for SIEM integration
+//! - [`audit_chain`]: hash-chained, anchored audit log with verification
+//! - [`secret_backends`]: Vault / AWS / GCP secrets manager, environment and
+//!   file resolvers, and short-lived cloud credentials
+//! - [`SecretRedactor`]: strips resolved secret values from task results
+//! - [`sandbox`]: Landlock / seccomp profiles for packaged task executions
 
 pub mod api_keys;
 pub mod audit;
+pub mod audit_chain;
 mod db_key_manager;
 pub mod fleet_secret;
 mod key_manager;
 mod package_signer;
+pub mod sandbox;
+pub mod secret_backends;
+mod secret_redaction;
 mod secret_resolver;
 mod secret_store;
 mod verification;
@@ -41,11 +50,17 @@ pub use key_manager::{KeyError, KeyManager, PublicKeyExport, SigningKeyInfo, Tru
 pub use package_signer::{
     DbPackageSigner, DetachedSignature, PackageSignError, PackageSignatureInfo, PackageSigner,
 };
+pub use sandbox::{SandboxError, SandboxMode, SandboxProfile, SandboxStatus};
+pub use secret_backends::{
+    database_url_with_credentials, secret_backend_from_env, CachedSecretResolver, CloudCredentials,
+    EnvSecretBackend, FileSecretBackend, SecretBackend,
+};
+pub use secret_redaction::{SecretRedactor, REDACTED_SECRET};
 pub use secret_resolver::{
     SecretAllow, SecretResolverConfigError, SecretStoreResolver, KEK_ENV_VAR,
 };
 pub use secret_store::{SecretError, SecretMetadata, SecretStore};
 pub use verification::{
-    verify_package, verify_package_bytes, verify_package_offline, SecurityConfig, SignatureSource,
-    VerificationError, VerificationResult,
+    enforce_signature_policy, parse_tenant_trust_orgs, verify_package, verify_package_bytes,
+    verify_package_offline, SecurityConfig, SignatureSource, VerificationError, VerificationResult,
 };
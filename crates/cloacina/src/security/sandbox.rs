@@ -0,0 +1,542 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Syscall sandboxing for packaged task executions (Linux).
+//!
+//! A [`SandboxProfile`] is derived from the capabilities a package was
+//! granted and applied to the thread that calls into the package:
+//!
+//! - **Landlock** limits the filesystem to the granted `ro:`/`rw:` paths plus
+//!   the read-only system paths a dynamically linked task needs (TLS roots,
+//!   resolver config, zoneinfo), and — on kernels with Landlock ABI v4 — TCP
+//!   `connect` to the granted ports.
+//! - **seccomp** denies syscalls no task needs (`ptrace`, `mount`, `bpf`,
+//!   namespace and module calls, cross-process memory access) and, when the
+//!   package was granted no network at all, IPv4/IPv6 socket creation.
+//!
+//! Both are per-thread and inherited by threads the task spawns; neither
+//! can be lifted once applied, so sandboxed calls run on a dedicated thread
+//! that exits with the call. Hostnames cannot be enforced by the kernel:
+//! a `host:port` grant opens the port to any host.
+//!
+//! The sandbox bounds what task code can reach through the kernel. It does
+//! not separate the task's memory from the process hosting it, which is why
+//! it is meant for execution agents, where that process runs nothing else.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use cloacina_workflow::CapabilityManifest;
+
+/// Read-only paths every sandboxed task may read, when they exist:
+/// shared libraries, TLS trust roots, resolver and timezone data.
+const SYSTEM_READ_PATHS: &[&str] = &[
+    "/usr",
+    "/lib",
+    "/lib64",
+    "/etc/ssl",
+    "/etc/pki",
+    "/etc/ca-certificates",
+    "/etc/resolv.conf",
+    "/etc/hosts",
+    "/etc/nsswitch.conf",
+    "/etc/localtime",
+    "/proc/self",
+    "/sys/devices/system/cpu",
+    "/dev/urandom",
+];
+
+/// Read-write paths every sandboxed task may use.
+const SYSTEM_WRITE_PATHS: &[&str] = &["/dev/null"];
+
+/// How an executor sandboxes packaged task executions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SandboxMode {
+    /// No sandbox (the default).
+    #[default]
+    Off,
+    /// Apply as much of the profile as the kernel supports; run the task
+    /// even if parts (or all) of it could not be enforced.
+    BestEffort,
+    /// Refuse to run a task unless its profile is fully enforced.
+    Enforce,
+}
+
+impl SandboxMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SandboxMode::Off => "off",
+            SandboxMode::BestEffort => "best-effort",
+            SandboxMode::Enforce => "enforce",
+        }
+    }
+}
+
+impl fmt::Display for SandboxMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SandboxMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "none" => Ok(SandboxMode::Off),
+            "best-effort" | "best_effort" => Ok(SandboxMode::BestEffort),
+            "enforce" => Ok(SandboxMode::Enforce),
+            other => Err(format!(
+                "unknown sandbox mode '{}' (expected off, best-effort or enforce)",
+                other
+            )),
+        }
+    }
+}
+
+/// How much of a profile the kernel enforced on the calling thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxStatus {
+    /// Every filesystem, network and syscall rule is in force.
+    FullyEnforced,
+    /// Some rules are in force; the kernel lacks support for the rest.
+    PartiallyEnforced,
+    /// Nothing could be applied.
+    NotEnforced,
+}
+
+impl SandboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SandboxStatus::FullyEnforced => "fully_enforced",
+            SandboxStatus::PartiallyEnforced => "partially_enforced",
+            SandboxStatus::NotEnforced => "not_enforced",
+        }
+    }
+}
+
+impl fmt::Display for SandboxStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Failure to sandbox the calling thread.
+#[derive(Debug, thiserror::Error)]
+pub enum SandboxError {
+    #[error("syscall sandboxing is not supported on this platform")]
+    Unsupported,
+
+    #[error("failed to apply sandbox: {0}")]
+    Apply(String),
+
+    #[error("sandbox is {0}, but enforce mode requires it fully enforced")]
+    NotFullyEnforced(SandboxStatus),
+}
+
+/// The kernel-level restrictions derived from a package's granted
+/// capabilities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SandboxProfile {
+    /// Paths the task may read (and execute) beneath.
+    pub read_paths: Vec<PathBuf>,
+    /// Paths the task may read and write beneath.
+    pub write_paths: Vec<PathBuf>,
+    /// TCP ports the task may connect to. `None` leaves TCP connect
+    /// unrestricted: a network grant with no port, or a `*` port, cannot be
+    /// expressed as a port rule.
+    pub connect_ports: Option<Vec<u16>>,
+    /// Deny creating IPv4/IPv6 sockets (the package was granted no network).
+    pub deny_inet_sockets: bool,
+}
+
+impl SandboxProfile {
+    /// Derive the profile for a package from the capabilities it was
+    /// granted. Entries that are not valid capabilities (the capability
+    /// policy rejects those at registration) grant nothing.
+    pub fn from_manifest(granted: &CapabilityManifest) -> Self {
+        let mut read_paths: Vec<PathBuf> = SYSTEM_READ_PATHS.iter().map(PathBuf::from).collect();
+        let mut write_paths: Vec<PathBuf> = SYSTEM_WRITE_PATHS.iter().map(PathBuf::from).collect();
+        for entry in &granted.filesystem {
+            if let Some(path) = entry.strip_prefix("ro:") {
+                if path.starts_with('/') {
+                    read_paths.push(PathBuf::from(path));
+                }
+            } else if let Some(path) = entry.strip_prefix("rw:") {
+                if path.starts_with('/') {
+                    write_paths.push(PathBuf::from(path));
+                }
+            }
+        }
+
+        let mut connect_ports = Some(Vec::new());
+        for entry in &granted.network {
+            let port = entry
+                .rsplit_once(':')
+                .and_then(|(_, port)| port.parse::<u16>().ok());
+            match (port, connect_ports.as_mut()) {
+                (Some(port), Some(ports)) => {
+                    if !ports.contains(&port) {
+                        ports.push(port);
+                    }
+                }
+                (None, _) => connect_ports = None,
+                (Some(_), None) => {}
+            }
+        }
+
+        Self {
+            read_paths,
+            write_paths,
+            connect_ports,
+            deny_inet_sockets: granted.network.is_empty(),
+        }
+    }
+
+    /// Apply the profile to the calling thread. It stays in force for the
+    /// rest of the thread's life and is inherited by threads it spawns.
+    ///
+    /// Under [`SandboxMode::Enforce`] anything short of full enforcement is
+    /// an error; under [`SandboxMode::BestEffort`] the achieved status is
+    /// returned for the caller to report. [`SandboxMode::Off`] applies
+    /// nothing.
+    pub fn apply_to_current_thread(
+        &self,
+        mode: SandboxMode,
+    ) -> Result<SandboxStatus, SandboxError> {
+        if mode == SandboxMode::Off {
+            return Ok(SandboxStatus::NotEnforced);
+        }
+        let status = self.apply_platform()?;
+        if mode == SandboxMode::Enforce && status != SandboxStatus::FullyEnforced {
+            return Err(SandboxError::NotFullyEnforced(status));
+        }
+        Ok(status)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn apply_platform(&self) -> Result<SandboxStatus, SandboxError> {
+        // Landlock sets no_new_privs itself, but seccomp needs it even when
+        // Landlock is unavailable.
+        linux::set_no_new_privs()?;
+        let landlock = linux::apply_landlock(self)?;
+        let seccomp = linux::apply_seccomp(self.deny_inet_sockets)?;
+        Ok(match (landlock, seccomp) {
+            (SandboxStatus::FullyEnforced, true) => SandboxStatus::FullyEnforced,
+            (SandboxStatus::NotEnforced, false) => SandboxStatus::NotEnforced,
+            _ => SandboxStatus::PartiallyEnforced,
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn apply_platform(&self) -> Result<SandboxStatus, SandboxError> {
+        Err(SandboxError::Unsupported)
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[cfg_attr(
+    not(any(target_arch = "x86_64", target_arch = "aarch64")),
+    allow(dead_code)
+)]
+mod linux {
+    use super::{SandboxError, SandboxProfile, SandboxStatus};
+    use landlock::{
+        path_beneath_rules, Access, AccessFs, AccessNet, NetPort, Ruleset, RulesetAttr,
+        RulesetCreatedAttr, RulesetStatus, ABI,
+    };
+
+    pub(super) fn set_no_new_privs() -> Result<(), SandboxError> {
+        // SAFETY: PR_SET_NO_NEW_PRIVS takes integer arguments only.
+        let rc = unsafe {
+            libc::prctl(
+                libc::PR_SET_NO_NEW_PRIVS,
+                1 as libc::c_ulong,
+                0 as libc::c_ulong,
+                0 as libc::c_ulong,
+                0 as libc::c_ulong,
+            )
+        };
+        if rc != 0 {
+            return Err(SandboxError::Apply(format!(
+                "prctl(PR_SET_NO_NEW_PRIVS): {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(())
+    }
+
+    pub(super) fn apply_landlock(profile: &SandboxProfile) -> Result<SandboxStatus, SandboxError> {
+        let abi = ABI::V4;
+        let err = |e: landlock::RulesetError| SandboxError::Apply(format!("landlock: {}", e));
+
+        let mut ruleset = Ruleset::default()
+            .handle_access(AccessFs::from_all(abi))
+            .map_err(err)?;
+        if profile.connect_ports.is_some() {
+            ruleset = ruleset
+                .handle_access(AccessNet::from_all(abi))
+                .map_err(err)?;
+        }
+        let existing = |paths: &[std::path::PathBuf]| -> Vec<std::path::PathBuf> {
+            paths.iter().filter(|p| p.exists()).cloned().collect()
+        };
+        let mut created = ruleset
+            .create()
+            .map_err(err)?
+            .add_rules(path_beneath_rules(
+                existing(&profile.read_paths),
+                AccessFs::from_read(abi),
+            ))
+            .map_err(err)?
+            .add_rules(path_beneath_rules(
+                existing(&profile.write_paths),
+                AccessFs::from_all(abi),
+            ))
+            .map_err(err)?;
+        for port in profile.connect_ports.iter().flatten() {
+            created = created
+                .add_rule(NetPort::new(*port, AccessNet::ConnectTcp))
+                .map_err(err)?;
+        }
+
+        let status = created.restrict_self().map_err(err)?;
+        Ok(match status.ruleset {
+            RulesetStatus::FullyEnforced => SandboxStatus::FullyEnforced,
+            RulesetStatus::PartiallyEnforced => SandboxStatus::PartiallyEnforced,
+            RulesetStatus::NotEnforced => SandboxStatus::NotEnforced,
+        })
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xC000_003E;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xC000_00B7;
+
+    /// Syscalls no task needs; denied with `EPERM`.
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    const DENIED_SYSCALLS: &[libc::c_long] = &[
+        libc::SYS_ptrace,
+        libc::SYS_mount,
+        libc::SYS_umount2,
+        libc::SYS_pivot_root,
+        libc::SYS_chroot,
+        libc::SYS_kexec_load,
+        libc::SYS_init_module,
+        libc::SYS_finit_module,
+        libc::SYS_delete_module,
+        libc::SYS_bpf,
+        libc::SYS_perf_event_open,
+        libc::SYS_unshare,
+        libc::SYS_setns,
+        libc::SYS_reboot,
+        libc::SYS_swapon,
+        libc::SYS_swapoff,
+        libc::SYS_keyctl,
+        libc::SYS_add_key,
+        libc::SYS_request_key,
+        libc::SYS_userfaultfd,
+        libc::SYS_process_vm_readv,
+        libc::SYS_process_vm_writev,
+    ];
+
+    // Classic BPF opcodes and `struct seccomp_data` offsets.
+    const BPF_LD_W_ABS: u16 = 0x20;
+    const BPF_JMP_JEQ_K: u16 = 0x15;
+    const BPF_JMP_JGE_K: u16 = 0x35;
+    const BPF_RET_K: u16 = 0x06;
+    const SECCOMP_DATA_NR: u32 = 0;
+    const SECCOMP_DATA_ARCH: u32 = 4;
+    const SECCOMP_DATA_ARG0: u32 = 16;
+    const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+    const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+    const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+    /// x32 syscalls on x86_64 carry this bit; the filter refuses them.
+    const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+    fn stmt(code: u16, k: u32) -> libc::sock_filter {
+        libc::sock_filter {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    fn jump(code: u16, k: u32, jt: u8, jf: u8) -> libc::sock_filter {
+        libc::sock_filter { code, jt, jf, k }
+    }
+
+    /// Build the seccomp filter program.
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    pub(super) fn filter(deny_inet_sockets: bool) -> Vec<libc::sock_filter> {
+        let eperm = SECCOMP_RET_ERRNO | libc::EPERM as u32;
+        let eacces = SECCOMP_RET_ERRNO | libc::EACCES as u32;
+
+        let mut prog = vec![
+            stmt(BPF_LD_W_ABS, SECCOMP_DATA_ARCH),
+            jump(BPF_JMP_JEQ_K, AUDIT_ARCH, 1, 0),
+            stmt(BPF_RET_K, SECCOMP_RET_KILL_PROCESS),
+            stmt(BPF_LD_W_ABS, SECCOMP_DATA_NR),
+            jump(BPF_JMP_JGE_K, X32_SYSCALL_BIT, 0, 1),
+            stmt(BPF_RET_K, eperm),
+        ];
+        for nr in DENIED_SYSCALLS {
+            prog.push(jump(BPF_JMP_JEQ_K, *nr as u32, 0, 1));
+            prog.push(stmt(BPF_RET_K, eperm));
+        }
+        if deny_inet_sockets {
+            // socket(AF_INET | AF_INET6, ...): the domain is the low word of
+            // arg0 on both supported (little-endian) architectures.
+            prog.push(jump(BPF_JMP_JEQ_K, libc::SYS_socket as u32, 0, 4));
+            prog.push(stmt(BPF_LD_W_ABS, SECCOMP_DATA_ARG0));
+            prog.push(jump(BPF_JMP_JEQ_K, libc::AF_INET as u32, 1, 0));
+            prog.push(jump(BPF_JMP_JEQ_K, libc::AF_INET6 as u32, 0, 1));
+            prog.push(stmt(BPF_RET_K, eacces));
+        }
+        prog.push(stmt(BPF_RET_K, SECCOMP_RET_ALLOW));
+        prog
+    }
+
+    /// Install the seccomp filter on the calling thread. `Ok(false)` on
+    /// architectures the filter does not cover.
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    pub(super) fn apply_seccomp(deny_inet_sockets: bool) -> Result<bool, SandboxError> {
+        let mut prog = filter(deny_inet_sockets);
+        let fprog = libc::sock_fprog {
+            len: prog.len() as libc::c_ushort,
+            filter: prog.as_mut_ptr(),
+        };
+        // SAFETY: `fprog` points at a valid filter program that outlives the
+        // call; the kernel copies it before returning.
+        let rc = unsafe {
+            libc::prctl(
+                libc::PR_SET_SECCOMP,
+                libc::SECCOMP_MODE_FILTER as libc::c_ulong,
+                &fprog as *const libc::sock_fprog,
+            )
+        };
+        if rc != 0 {
+            return Err(SandboxError::Apply(format!(
+                "prctl(PR_SET_SECCOMP): {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(true)
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub(super) fn apply_seccomp(_deny_inet_sockets: bool) -> Result<bool, SandboxError> {
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(network: &[&str], filesystem: &[&str]) -> CapabilityManifest {
+        CapabilityManifest {
+            network: network.iter().map(|s| s.to_string()).collect(),
+            filesystem: filesystem.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mode_parses() {
+        assert_eq!("off".parse::<SandboxMode>().unwrap(), SandboxMode::Off);
+        assert_eq!(
+            "best-effort".parse::<SandboxMode>().unwrap(),
+            SandboxMode::BestEffort
+        );
+        assert_eq!(
+            "ENFORCE".parse::<SandboxMode>().unwrap(),
+            SandboxMode::Enforce
+        );
+        assert!("strict".parse::<SandboxMode>().is_err());
+    }
+
+    #[test]
+    fn test_profile_splits_filesystem_grants() {
+        let profile = SandboxProfile::from_manifest(&manifest(
+            &[],
+            &[
+                "ro:/etc/pipeline",
+                "rw:/var/lib/etl",
+                "rw:relative",
+                "x:/tmp",
+            ],
+        ));
+        assert!(profile.read_paths.contains(&PathBuf::from("/etc/pipeline")));
+        assert!(profile.read_paths.contains(&PathBuf::from("/usr")));
+        assert!(profile.write_paths.contains(&PathBuf::from("/var/lib/etl")));
+        assert!(!profile.write_paths.contains(&PathBuf::from("relative")));
+        assert!(!profile.read_paths.contains(&PathBuf::from("/tmp")));
+        assert!(!profile.write_paths.contains(&PathBuf::from("/tmp")));
+    }
+
+    #[test]
+    fn test_profile_no_network_denies_sockets() {
+        let profile = SandboxProfile::from_manifest(&CapabilityManifest::default());
+        assert_eq!(profile.connect_ports, Some(Vec::new()));
+        assert!(profile.deny_inet_sockets);
+    }
+
+    #[test]
+    fn test_profile_collects_granted_ports() {
+        let profile = SandboxProfile::from_manifest(&manifest(
+            &[
+                "api.example.com:443",
+                "db.internal:5432",
+                "mirror.example.com:443",
+            ],
+            &[],
+        ));
+        assert_eq!(profile.connect_ports, Some(vec![443, 5432]));
+        assert!(!profile.deny_inet_sockets);
+    }
+
+    #[test]
+    fn test_profile_portless_grant_leaves_connect_open() {
+        for entry in ["api.example.com", "*.example.com:*"] {
+            let profile =
+                SandboxProfile::from_manifest(&manifest(&["db.internal:5432", entry], &[]));
+            assert_eq!(profile.connect_ports, None, "{}", entry);
+        }
+    }
+
+    #[test]
+    fn test_off_applies_nothing() {
+        let profile = SandboxProfile::from_manifest(&CapabilityManifest::default());
+        assert_eq!(
+            profile.apply_to_current_thread(SandboxMode::Off).unwrap(),
+            SandboxStatus::NotEnforced
+        );
+    }
+
+    #[cfg(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    #[test]
+    fn test_seccomp_filter_denies_inet_sockets_only_without_network() {
+        let open = linux::filter(false);
+        let closed = linux::filter(true);
+        assert_eq!(closed.len(), open.len() + 5);
+    }
+}
@@ -0,0 +1,390 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! AWS Secrets Manager backend.
+//!
+//! Calls `GetSecretValue` directly over HTTPS, signed with Signature Version
+//! 4, so the core crate does not pull in the AWS SDK. A secret name is a
+//! Secrets Manager `SecretId` (name or ARN). `SecretString` payloads that are
+//! JSON objects become fields; anything else (and `SecretBinary`) is exposed
+//! as the single field `value`. `VersionId` is the rotation version.
+//!
+//! Credentials are static: the standard `AWS_ACCESS_KEY_ID` /
+//! `AWS_SECRET_ACCESS_KEY` / `AWS_SESSION_TOKEN` variables. Instance-profile
+//! and web-identity credentials are not fetched; export them (e.g. with
+//! `aws configure export-credentials --format env`) before starting.
+
+use async_trait::async_trait;
+use base64::Engine as _;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+use cloacina_workflow::secret::SecretResolverError;
+
+use super::{payload_fields, FetchedSecret, SecretBackend};
+use crate::security::SecretResolverConfigError;
+
+const SERVICE: &str = "secretsmanager";
+const TARGET: &str = "secretsmanager.GetSecretValue";
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+/// Static AWS credentials.
+#[derive(Clone)]
+pub struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+impl std::fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// Connection settings for [`AwsSecretsManagerBackend`].
+#[derive(Debug, Clone)]
+pub struct AwsSecretsManagerConfig {
+    pub region: String,
+    pub credentials: AwsCredentials,
+    /// Override for `https://secretsmanager.{region}.amazonaws.com`
+    /// (VPC endpoints, LocalStack).
+    pub endpoint: Option<String>,
+}
+
+impl AwsSecretsManagerConfig {
+    /// Read `AWS_REGION` (or `AWS_DEFAULT_REGION`), `AWS_ACCESS_KEY_ID`,
+    /// `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN` and
+    /// `CLOACINA_AWS_SECRETS_ENDPOINT`.
+    pub fn from_env() -> Result<Self, SecretResolverConfigError> {
+        let region = std::env::var("AWS_REGION")
+            .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+            .map_err(|_| SecretResolverConfigError::MissingEnv("AWS_REGION"))?;
+        let access_key_id = std::env::var("AWS_ACCESS_KEY_ID")
+            .map_err(|_| SecretResolverConfigError::MissingEnv("AWS_ACCESS_KEY_ID"))?;
+        let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY")
+            .map_err(|_| SecretResolverConfigError::MissingEnv("AWS_SECRET_ACCESS_KEY"))?;
+        Ok(Self {
+            region,
+            credentials: AwsCredentials {
+                access_key_id,
+                secret_access_key,
+                session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+            },
+            endpoint: std::env::var("CLOACINA_AWS_SECRETS_ENDPOINT").ok(),
+        })
+    }
+}
+
+/// Reads secrets from AWS Secrets Manager.
+pub struct AwsSecretsManagerBackend {
+    config: AwsSecretsManagerConfig,
+    endpoint: reqwest::Url,
+    http: reqwest::Client,
+}
+
+impl AwsSecretsManagerBackend {
+    pub fn new(config: AwsSecretsManagerConfig) -> Result<Self, SecretResolverConfigError> {
+        let endpoint = config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://{}.{}.amazonaws.com/", SERVICE, config.region));
+        let endpoint = reqwest::Url::parse(&endpoint).map_err(|e| {
+            SecretResolverConfigError::InvalidBackendConfig(format!(
+                "secrets manager endpoint '{}': {}",
+                endpoint, e
+            ))
+        })?;
+        if endpoint.host_str().is_none() {
+            return Err(SecretResolverConfigError::InvalidBackendConfig(format!(
+                "secrets manager endpoint '{}' has no host",
+                endpoint
+            )));
+        }
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| SecretResolverConfigError::InvalidBackendConfig(e.to_string()))?;
+        Ok(Self {
+            config,
+            endpoint,
+            http,
+        })
+    }
+
+    fn host(&self) -> String {
+        let host = self.endpoint.host_str().unwrap_or_default();
+        match self.endpoint.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetSecretValueResponse {
+    secret_string: Option<String>,
+    secret_binary: Option<String>,
+    version_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AwsErrorBody {
+    #[serde(rename = "__type", default)]
+    kind: String,
+    #[serde(alias = "Message", default)]
+    message: String,
+}
+
+#[async_trait]
+impl SecretBackend for AwsSecretsManagerBackend {
+    fn kind(&self) -> &'static str {
+        "aws"
+    }
+
+    async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+        let body = serde_json::to_vec(&serde_json::json!({ "SecretId": name }))
+            .map_err(|e| SecretResolverError::Backend(e.to_string()))?;
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let host = self.host();
+        let credentials = &self.config.credentials;
+
+        let mut headers = vec![
+            ("content-type", CONTENT_TYPE.to_string()),
+            ("host", host),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        headers.push(("x-amz-target", TARGET.to_string()));
+
+        let authorization = sign(
+            &SigningRequest {
+                method: "POST",
+                path: self.endpoint.path(),
+                headers: &headers,
+                body: &body,
+            },
+            credentials,
+            &self.config.region,
+            SERVICE,
+            &amz_date,
+        );
+
+        let mut request = self
+            .http
+            .post(self.endpoint.clone())
+            .header("Authorization", authorization)
+            .body(body);
+        for (name, value) in &headers {
+            // reqwest derives `host` from the URL.
+            if *name != "host" {
+                request = request.header(*name, value);
+            }
+        }
+        let response = request.send().await.map_err(|e| {
+            SecretResolverError::Backend(format!("secrets manager request failed: {}", e))
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error: AwsErrorBody = response.json().await.unwrap_or(AwsErrorBody {
+                kind: String::new(),
+                message: String::new(),
+            });
+            if error.kind.ends_with("ResourceNotFoundException") {
+                return Err(SecretResolverError::NotFound(name.to_string()));
+            }
+            return Err(SecretResolverError::Backend(format!(
+                "secrets manager returned {} for '{}': {} {}",
+                status, name, error.kind, error.message
+            )));
+        }
+
+        let value: GetSecretValueResponse = response.json().await.map_err(|e| {
+            SecretResolverError::Backend(format!("invalid secrets manager response: {}", e))
+        })?;
+        let payload = match (value.secret_string, value.secret_binary) {
+            (Some(s), _) => s,
+            (None, Some(b)) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(b)
+                    .map_err(|e| {
+                        SecretResolverError::Backend(format!("invalid SecretBinary: {}", e))
+                    })?;
+                String::from_utf8(bytes).map_err(|_| {
+                    SecretResolverError::Backend(format!("SecretBinary of '{}' is not UTF-8", name))
+                })?
+            }
+            (None, None) => {
+                return Err(SecretResolverError::Backend(format!(
+                    "secret '{}' has no value",
+                    name
+                )))
+            }
+        };
+        Ok(FetchedSecret {
+            fields: payload_fields(&payload),
+            version: value.version_id,
+            lease: None,
+        })
+    }
+}
+
+/// The parts of an HTTP request SigV4 covers. `headers` are lowercase and
+/// sorted by name; all of them are signed.
+pub(super) struct SigningRequest<'a> {
+    pub(super) method: &'a str,
+    pub(super) path: &'a str,
+    pub(super) headers: &'a [(&'a str, String)],
+    pub(super) body: &'a [u8],
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Build the SigV4 `Authorization` header value for a query-less request.
+pub(super) fn sign(
+    request: &SigningRequest<'_>,
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    amz_date: &str,
+) -> String {
+    let date = &amz_date[..8];
+    let canonical_headers: String = request
+        .headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect();
+    let signed_headers = request
+        .headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_request = format!(
+        "{}\n{}\n\n{}\n{}\n{}",
+        request.method,
+        if request.path.is_empty() {
+            "/"
+        } else {
+            request.path
+        },
+        canonical_headers,
+        signed_headers,
+        hex::encode(Sha256::digest(request.body))
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = hmac_sha256(
+        format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+        date.as_bytes(),
+    );
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    let key = hmac_sha256(&key, b"aws4_request");
+    let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        credentials.access_key_id, scope, signed_headers, signature
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_aws_get_vanilla_test_vector() {
+        // `get-vanilla` from the AWS SigV4 test suite.
+        let credentials = AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+        };
+        let headers = [
+            ("host", "example.amazonaws.com".to_string()),
+            ("x-amz-date", "20150830T123600Z".to_string()),
+        ];
+        let authorization = sign(
+            &SigningRequest {
+                method: "GET",
+                path: "/",
+                headers: &headers,
+                body: b"",
+            },
+            &credentials,
+            "us-east-1",
+            "service",
+            "20150830T123600Z",
+        );
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn endpoint_defaults_to_the_regional_host() {
+        let backend = AwsSecretsManagerBackend::new(AwsSecretsManagerConfig {
+            region: "eu-west-1".to_string(),
+            credentials: AwsCredentials {
+                access_key_id: "AKID".to_string(),
+                secret_access_key: "secret".to_string(),
+                session_token: Some("token".to_string()),
+            },
+            endpoint: None,
+        })
+        .unwrap();
+        assert_eq!(backend.host(), "secretsmanager.eu-west-1.amazonaws.com");
+        let debug = format!("{:?}", backend.config);
+        assert!(!debug.contains("secret\""));
+        assert!(!debug.contains("token\""));
+
+        let local = AwsSecretsManagerBackend::new(AwsSecretsManagerConfig {
+            endpoint: Some("http://localhost:4566".to_string()),
+            ..backend.config.clone()
+        })
+        .unwrap();
+        assert_eq!(local.host(), "localhost:4566");
+    }
+}
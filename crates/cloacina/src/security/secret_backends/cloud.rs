@@ -0,0 +1,389 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Short-lived cloud credentials resolved as secrets.
+//!
+//! Instead of storing cloud keys in a context or a secret, a task names the
+//! identity it runs as and receives a session minted for it:
+//!
+//! | Secret name | Issuer | Feature | Fields |
+//! |---|---|---|---|
+//! | `aws-role:<role ARN>` | `AwsAssumeRoleBackend` (STS) | `secrets-aws` | `access_key_id`, `secret_access_key`, `session_token`, `expiration`, `region` |
+//! | `gcp-sa:<service account email>` | `GcpImpersonationBackend` (IAM Credentials) | `secrets-gcp` | `access_token`, `token_type`, `expiration`, `service_account` |
+//!
+//! A task reads them like any other secret — `context.secret("aws-role:…")`
+//! directly (per task), or through a `{"$secret": "aws-role:…"}` instance
+//! binding so each workflow instance picks its own role (per workflow).
+//! Sessions are cached until two thirds of their lifetime, so a task always
+//! receives credentials with a third of their lifetime left.
+//!
+//! [`CloudCredentials`] routes those names to the issuers and everything
+//! else to the regular secrets resolver. On a multi-tenant server a tenant
+//! may only assume the identities granted to it in
+//! `CLOACINA_CLOUD_IDENTITY_MAP`; an embedded host (one trusted tenant) uses
+//! the ungated [`CloudCredentials::resolver`].
+
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use cloacina_workflow::secret::{SecretResolver, SecretResolverError};
+
+#[cfg(feature = "secrets-aws")]
+use super::{AwsAssumeRoleBackend, AwsAssumeRoleConfig};
+use super::{CachedSecretResolver, SecretBackend};
+#[cfg(feature = "secrets-gcp")]
+use super::{GcpImpersonationBackend, GcpImpersonationConfig};
+use crate::security::{SecretAllow, SecretResolverConfigError};
+
+/// Environment variable listing the enabled issuers (`aws`, `gcp`).
+pub const CLOUD_CREDENTIALS_ENV_VAR: &str = "CLOACINA_CLOUD_CREDENTIALS";
+/// Environment variable mapping tenants to the identities they may assume.
+pub const CLOUD_IDENTITY_MAP_ENV_VAR: &str = "CLOACINA_CLOUD_IDENTITY_MAP";
+/// Environment variable overriding the requested session lifetime, seconds.
+pub const CLOUD_CREDENTIALS_TTL_ENV_VAR: &str = "CLOACINA_CLOUD_CREDENTIALS_TTL_S";
+/// Session lifetime requested when the TTL variable is unset (the STS
+/// minimum).
+pub const DEFAULT_CLOUD_CREDENTIAL_LIFETIME: Duration = Duration::from_secs(900);
+
+/// Secret-name prefix for an AWS IAM role to assume.
+pub const AWS_ROLE_PREFIX: &str = "aws-role:";
+/// Secret-name prefix for a GCP service account to impersonate.
+pub const GCP_SERVICE_ACCOUNT_PREFIX: &str = "gcp-sa:";
+
+/// Credential issuers keyed by secret-name prefix, plus the per-tenant
+/// identity grants. See the [module docs](self).
+#[derive(Default)]
+pub struct CloudCredentials {
+    issuers: Vec<(String, Arc<CachedSecretResolver>)>,
+    tenant_grants: HashMap<String, HashSet<String>>,
+}
+
+impl std::fmt::Debug for CloudCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CloudCredentials")
+            .field(
+                "issuers",
+                &self.issuers.iter().map(|(p, _)| p).collect::<Vec<_>>(),
+            )
+            .field("tenant_grants", &self.tenant_grants)
+            .finish()
+    }
+}
+
+/// The prefix and backend of the built-in issuer `kind`, configured from the
+/// environment. Without an issuer feature every kind is rejected.
+fn issuer_from_env(
+    kind: &str,
+) -> Result<(&'static str, Arc<dyn SecretBackend>), SecretResolverConfigError> {
+    match kind {
+        #[cfg(feature = "secrets-aws")]
+        "aws" => Ok((
+            AWS_ROLE_PREFIX,
+            Arc::new(AwsAssumeRoleBackend::new(AwsAssumeRoleConfig::from_env()?)?),
+        )),
+        #[cfg(feature = "secrets-gcp")]
+        "gcp" => Ok((
+            GCP_SERVICE_ACCOUNT_PREFIX,
+            Arc::new(GcpImpersonationBackend::new(
+                GcpImpersonationConfig::from_env()?,
+            )?),
+        )),
+        other => Err(SecretResolverConfigError::InvalidBackendConfig(format!(
+            "unsupported cloud credential issuer '{}' (built-in: aws, gcp behind the \
+             secrets-aws / secrets-gcp features)",
+            other
+        ))),
+    }
+}
+
+impl CloudCredentials {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve names starting with `prefix` through `backend`, which receives
+    /// the name with the prefix removed. Issued credentials are cached.
+    pub fn with_issuer(
+        mut self,
+        prefix: impl Into<String>,
+        backend: Arc<dyn SecretBackend>,
+    ) -> Self {
+        self.issuers
+            .push((prefix.into(), Arc::new(CachedSecretResolver::new(backend))));
+        self
+    }
+
+    /// Allow `tenant` to resolve the credential secret `name` (prefix
+    /// included) through [`tenant_resolver`](Self::tenant_resolver).
+    pub fn grant(mut self, tenant: impl Into<String>, name: impl Into<String>) -> Self {
+        self.tenant_grants
+            .entry(tenant.into())
+            .or_default()
+            .insert(name.into());
+        self
+    }
+
+    /// Build the issuers listed in `CLOACINA_CLOUD_CREDENTIALS` and the
+    /// grants in `CLOACINA_CLOUD_IDENTITY_MAP`. `Ok(None)` when the issuer
+    /// list is unset or empty.
+    pub fn from_env() -> Result<Option<Self>, SecretResolverConfigError> {
+        let Ok(kinds) = std::env::var(CLOUD_CREDENTIALS_ENV_VAR) else {
+            return Ok(None);
+        };
+        let mut credentials = Self::new();
+        for kind in kinds.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+            let (prefix, backend) = issuer_from_env(kind)?;
+            credentials = credentials.with_issuer(prefix, backend);
+        }
+        if credentials.issuers.is_empty() {
+            return Ok(None);
+        }
+        if let Ok(spec) = std::env::var(CLOUD_IDENTITY_MAP_ENV_VAR) {
+            credentials.tenant_grants = parse_identity_map(&spec)?;
+        }
+        Ok(Some(credentials))
+    }
+
+    /// Whether `name` is a credential secret handled by one of the issuers.
+    pub fn handles(&self, name: &str) -> bool {
+        self.issuer_for(name).is_some()
+    }
+
+    fn issuer_for<'a>(&self, name: &'a str) -> Option<(&'a str, &Arc<CachedSecretResolver>)> {
+        self.issuers.iter().find_map(|(prefix, issuer)| {
+            name.strip_prefix(prefix.as_str())
+                .map(|identity| (identity, issuer))
+        })
+    }
+
+    /// A **trusted, ungated** resolver: any configured identity may be
+    /// assumed. For an embedded host, which owns the credentials anyway.
+    /// Names the issuers do not handle go to `fallback`.
+    pub fn resolver(
+        self: &Arc<Self>,
+        fallback: Option<Arc<dyn SecretResolver>>,
+    ) -> Arc<dyn SecretResolver> {
+        Arc::new(CloudCredentialResolver {
+            credentials: self.clone(),
+            allow: SecretAllow::All,
+            fallback,
+        })
+    }
+
+    /// A resolver for `tenant` that may only assume the identities granted
+    /// to it (fail-closed: a tenant with no grants assumes none). Names the
+    /// issuers do not handle go to `fallback`.
+    pub fn tenant_resolver(
+        self: &Arc<Self>,
+        tenant: &str,
+        fallback: Option<Arc<dyn SecretResolver>>,
+    ) -> Arc<dyn SecretResolver> {
+        Arc::new(CloudCredentialResolver {
+            credentials: self.clone(),
+            allow: SecretAllow::List(self.tenant_grants.get(tenant).cloned().unwrap_or_default()),
+            fallback,
+        })
+    }
+}
+
+/// Parse `CLOACINA_CLOUD_IDENTITY_MAP`: `;`-separated
+/// `<tenant>=<name>[,<name>…]` rules, e.g.
+/// `acme=aws-role:arn:aws:iam::123456789012:role/acme-etl;beta=gcp-sa:etl@beta.iam.gserviceaccount.com`.
+/// Repeating a tenant adds to its grants.
+pub fn parse_identity_map(
+    spec: &str,
+) -> Result<HashMap<String, HashSet<String>>, SecretResolverConfigError> {
+    let mut grants: HashMap<String, HashSet<String>> = HashMap::new();
+    for rule in spec.split(';').map(str::trim).filter(|r| !r.is_empty()) {
+        let (tenant, names) = rule.split_once('=').ok_or_else(|| {
+            SecretResolverConfigError::InvalidBackendConfig(format!(
+                "{} rule '{}' is not <tenant>=<name>[,<name>]",
+                CLOUD_IDENTITY_MAP_ENV_VAR, rule
+            ))
+        })?;
+        let tenant = tenant.trim();
+        if tenant.is_empty() {
+            return Err(SecretResolverConfigError::InvalidBackendConfig(format!(
+                "{} rule '{}' has no tenant",
+                CLOUD_IDENTITY_MAP_ENV_VAR, rule
+            )));
+        }
+        grants.entry(tenant.to_string()).or_default().extend(
+            names
+                .split(',')
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(str::to_string),
+        );
+    }
+    Ok(grants)
+}
+
+/// Read `CLOACINA_CLOUD_CREDENTIALS_TTL_S`, defaulting to
+/// [`DEFAULT_CLOUD_CREDENTIAL_LIFETIME`].
+#[cfg_attr(
+    not(any(feature = "secrets-aws", feature = "secrets-gcp")),
+    allow(dead_code)
+)]
+pub(super) fn credential_lifetime_from_env() -> Result<Duration, SecretResolverConfigError> {
+    match std::env::var(CLOUD_CREDENTIALS_TTL_ENV_VAR) {
+        Err(_) => Ok(DEFAULT_CLOUD_CREDENTIAL_LIFETIME),
+        Ok(v) => match v.trim().parse::<u64>() {
+            Ok(secs) if secs > 0 => Ok(Duration::from_secs(secs)),
+            _ => Err(SecretResolverConfigError::InvalidBackendConfig(format!(
+                "{} must be a positive number of seconds, got '{}'",
+                CLOUD_CREDENTIALS_TTL_ENV_VAR, v
+            ))),
+        },
+    }
+}
+
+/// Routes credential names to their issuer, gated by `allow`, and every
+/// other name to `fallback`.
+struct CloudCredentialResolver {
+    credentials: Arc<CloudCredentials>,
+    allow: SecretAllow,
+    fallback: Option<Arc<dyn SecretResolver>>,
+}
+
+#[async_trait]
+impl SecretResolver for CloudCredentialResolver {
+    async fn resolve(&self, name: &str) -> Result<BTreeMap<String, String>, SecretResolverError> {
+        let Some((identity, issuer)) = self.credentials.issuer_for(name) else {
+            return match &self.fallback {
+                Some(fallback) => fallback.resolve(name).await,
+                None => Err(SecretResolverError::Backend(format!(
+                    "no secrets backend configured for '{}'",
+                    name
+                ))),
+            };
+        };
+        if !self.allow.permits(name) {
+            return Err(SecretResolverError::NotGranted(name.to_string()));
+        }
+        issuer.resolve(identity).await.map_err(|e| match e {
+            SecretResolverError::NotFound(_) => SecretResolverError::NotFound(name.to_string()),
+            other => other,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{FetchedSecret, SecretLease};
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Issuer echoing the identity it was asked for, counting calls.
+    #[derive(Default)]
+    struct EchoIssuer {
+        issued: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SecretBackend for EchoIssuer {
+        fn kind(&self) -> &'static str {
+            "echo"
+        }
+
+        async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+            let n = self.issued.fetch_add(1, Ordering::SeqCst);
+            Ok(FetchedSecret {
+                fields: BTreeMap::from([
+                    ("identity".to_string(), name.to_string()),
+                    ("session".to_string(), n.to_string()),
+                ]),
+                version: None,
+                lease: Some(SecretLease {
+                    id: name.to_string(),
+                    duration: Duration::from_secs(900),
+                    renewable: false,
+                }),
+            })
+        }
+    }
+
+    struct StaticResolver;
+
+    #[async_trait]
+    impl SecretResolver for StaticResolver {
+        async fn resolve(
+            &self,
+            name: &str,
+        ) -> Result<BTreeMap<String, String>, SecretResolverError> {
+            Ok(BTreeMap::from([("stored".to_string(), name.to_string())]))
+        }
+    }
+
+    const ROLE: &str = "aws-role:arn:aws:iam::123456789012:role/etl";
+
+    #[tokio::test]
+    async fn tenants_assume_only_granted_identities() {
+        let issuer = Arc::new(EchoIssuer::default());
+        let credentials = Arc::new(
+            CloudCredentials::new()
+                .with_issuer(AWS_ROLE_PREFIX, issuer.clone())
+                .grant("acme", ROLE),
+        );
+        assert!(credentials.handles(ROLE));
+        assert!(!credentials.handles("warehouse_db"));
+
+        let acme = credentials.tenant_resolver("acme", Some(Arc::new(StaticResolver)));
+        let fields = acme.resolve(ROLE).await.unwrap();
+        assert_eq!(fields["identity"], "arn:aws:iam::123456789012:role/etl");
+        // Cached: a second task gets the same session.
+        assert_eq!(acme.resolve(ROLE).await.unwrap()["session"], "0");
+        assert_eq!(issuer.issued.load(Ordering::SeqCst), 1);
+        // Other names still reach the tenant's secret store.
+        assert_eq!(
+            acme.resolve("warehouse_db").await.unwrap()["stored"],
+            "warehouse_db"
+        );
+
+        let beta = credentials.tenant_resolver("beta", None);
+        assert!(matches!(
+            beta.resolve(ROLE).await,
+            Err(SecretResolverError::NotGranted(_))
+        ));
+        assert!(matches!(
+            beta.resolve("warehouse_db").await,
+            Err(SecretResolverError::Backend(_))
+        ));
+
+        let trusted = credentials.resolver(None);
+        assert!(trusted
+            .resolve("aws-role:arn:aws:iam::123456789012:role/other")
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    fn identity_map_parses_rules() {
+        let grants = parse_identity_map(
+            "acme=aws-role:arn:aws:iam::1:role/a, gcp-sa:etl@acme.iam.gserviceaccount.com; \
+             beta=aws-role:arn:aws:iam::2:role/b;acme=aws-role:arn:aws:iam::1:role/c",
+        )
+        .unwrap();
+        assert_eq!(grants["acme"].len(), 3);
+        assert!(grants["acme"].contains("gcp-sa:etl@acme.iam.gserviceaccount.com"));
+        assert!(grants["beta"].contains("aws-role:arn:aws:iam::2:role/b"));
+
+        assert!(parse_identity_map("acme").is_err());
+        assert!(parse_identity_map("=aws-role:x").is_err());
+    }
+}
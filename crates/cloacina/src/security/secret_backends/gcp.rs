@@ -0,0 +1,292 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! GCP Secret Manager backend.
+//!
+//! A secret name is a Secret Manager secret id in the configured project;
+//! the `latest` version is read. Payloads that are JSON objects become
+//! fields; anything else is exposed as the single field `value`. The numeric
+//! version is the rotation version.
+//!
+//! Authentication uses `GOOGLE_OAUTH_ACCESS_TOKEN` when set (e.g. from
+//! `gcloud auth print-access-token`); otherwise the service account's token
+//! is fetched from the GCE/GKE metadata server and cached until shortly
+//! before it expires.
+
+use async_trait::async_trait;
+use base64::Engine as _;
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+use cloacina_workflow::secret::SecretResolverError;
+
+use super::{payload_fields, FetchedSecret, SecretBackend};
+use crate::security::SecretResolverConfigError;
+
+const DEFAULT_ENDPOINT: &str = "https://secretmanager.googleapis.com";
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+/// Refresh metadata-server tokens this long before they expire.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Connection settings for [`GcpSecretManagerBackend`].
+#[derive(Clone)]
+pub struct GcpSecretManagerConfig {
+    pub project: String,
+    /// Static OAuth access token; `None` uses the metadata server.
+    pub access_token: Option<String>,
+    /// Override for `https://secretmanager.googleapis.com`.
+    pub endpoint: Option<String>,
+}
+
+impl std::fmt::Debug for GcpSecretManagerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpSecretManagerConfig")
+            .field("project", &self.project)
+            .field(
+                "access_token",
+                &self.access_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("endpoint", &self.endpoint)
+            .finish()
+    }
+}
+
+impl GcpSecretManagerConfig {
+    /// Read `GOOGLE_CLOUD_PROJECT`, `GOOGLE_OAUTH_ACCESS_TOKEN` and
+    /// `CLOACINA_GCP_SECRETS_ENDPOINT`.
+    pub fn from_env() -> Result<Self, SecretResolverConfigError> {
+        let project = std::env::var("GOOGLE_CLOUD_PROJECT")
+            .map_err(|_| SecretResolverConfigError::MissingEnv("GOOGLE_CLOUD_PROJECT"))?;
+        Ok(Self {
+            project,
+            access_token: std::env::var("GOOGLE_OAUTH_ACCESS_TOKEN").ok(),
+            endpoint: std::env::var("CLOACINA_GCP_SECRETS_ENDPOINT").ok(),
+        })
+    }
+}
+
+struct CachedToken {
+    token: String,
+    refresh_at: Instant,
+}
+
+/// The caller's own OAuth token: a static one, or the metadata server's
+/// (cached until shortly before it expires). Shared with the impersonation
+/// issuer in `gcp_iam`.
+pub(super) struct GcpTokenSource {
+    static_token: Option<String>,
+    http: reqwest::Client,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+impl GcpTokenSource {
+    pub(super) fn new(static_token: Option<String>, http: reqwest::Client) -> Self {
+        Self {
+            static_token,
+            http,
+            cached: Mutex::new(None),
+        }
+    }
+
+    pub(super) async fn token(&self) -> Result<String, SecretResolverError> {
+        if let Some(token) = &self.static_token {
+            return Ok(token.clone());
+        }
+        let mut cached = self.cached.lock().await;
+        if let Some(token) = cached.as_ref() {
+            if Instant::now() < token.refresh_at {
+                return Ok(token.token.clone());
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct MetadataToken {
+            access_token: String,
+            expires_in: u64,
+        }
+        let response = self
+            .http
+            .get(METADATA_TOKEN_URL)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await
+            .map_err(|e| {
+                SecretResolverError::Backend(format!("metadata server token request failed: {}", e))
+            })?;
+        if !response.status().is_success() {
+            return Err(SecretResolverError::Backend(format!(
+                "metadata server returned {} for the access token",
+                response.status()
+            )));
+        }
+        let token: MetadataToken = response.json().await.map_err(|e| {
+            SecretResolverError::Backend(format!("invalid metadata server token: {}", e))
+        })?;
+        let lifetime = Duration::from_secs(token.expires_in).saturating_sub(TOKEN_REFRESH_MARGIN);
+        *cached = Some(CachedToken {
+            token: token.access_token.clone(),
+            refresh_at: Instant::now() + lifetime,
+        });
+        Ok(token.access_token)
+    }
+}
+
+/// Reads secrets from GCP Secret Manager.
+pub struct GcpSecretManagerBackend {
+    config: GcpSecretManagerConfig,
+    endpoint: String,
+    http: reqwest::Client,
+    token: GcpTokenSource,
+}
+
+impl GcpSecretManagerBackend {
+    pub fn new(config: GcpSecretManagerConfig) -> Result<Self, SecretResolverConfigError> {
+        let endpoint = config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+        reqwest::Url::parse(&endpoint).map_err(|e| {
+            SecretResolverConfigError::InvalidBackendConfig(format!(
+                "secret manager endpoint '{}': {}",
+                endpoint, e
+            ))
+        })?;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| SecretResolverConfigError::InvalidBackendConfig(e.to_string()))?;
+        Ok(Self {
+            token: GcpTokenSource::new(config.access_token.clone(), http.clone()),
+            config,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            http,
+        })
+    }
+
+    fn access_url(&self, name: &str) -> String {
+        format!(
+            "{}/v1/projects/{}/secrets/{}/versions/latest:access",
+            self.endpoint,
+            urlencoding::encode(&self.config.project),
+            urlencoding::encode(name)
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct AccessResponse {
+    name: String,
+    payload: AccessPayload,
+}
+
+#[derive(Deserialize)]
+struct AccessPayload {
+    data: String,
+}
+
+/// Decode an `:access` response into a secret.
+fn parse_access(
+    name: &str,
+    response: AccessResponse,
+) -> Result<FetchedSecret, SecretResolverError> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(&response.payload.data)
+        .map_err(|e| {
+            SecretResolverError::Backend(format!("invalid payload for '{}': {}", name, e))
+        })?;
+    let payload = String::from_utf8(bytes)
+        .map_err(|_| SecretResolverError::Backend(format!("payload of '{}' is not UTF-8", name)))?;
+    // `projects/{number}/secrets/{id}/versions/{version}`
+    let version = response.name.rsplit('/').next().map(str::to_string);
+    Ok(FetchedSecret {
+        fields: payload_fields(&payload),
+        version,
+        lease: None,
+    })
+}
+
+#[async_trait]
+impl SecretBackend for GcpSecretManagerBackend {
+    fn kind(&self) -> &'static str {
+        "gcp"
+    }
+
+    async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+        let token = self.token.token().await?;
+        let response = self
+            .http
+            .get(self.access_url(name))
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(|e| {
+                SecretResolverError::Backend(format!("secret manager request failed: {}", e))
+            })?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(SecretResolverError::NotFound(name.to_string()));
+        }
+        if !status.is_success() {
+            let detail = response.text().await.unwrap_or_default();
+            return Err(SecretResolverError::Backend(format!(
+                "secret manager returned {} for '{}': {}",
+                status, name, detail
+            )));
+        }
+        let body: AccessResponse = response.json().await.map_err(|e| {
+            SecretResolverError::Backend(format!("invalid secret manager response: {}", e))
+        })?;
+        parse_access(name, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_responses_decode_payload_and_version() {
+        let response: AccessResponse = serde_json::from_value(serde_json::json!({
+            "name": "projects/123/secrets/warehouse/versions/7",
+            "payload": {
+                "data": base64::engine::general_purpose::STANDARD
+                    .encode(r#"{"username":"etl","password":"pw"}"#),
+                "dataCrc32c": "0"
+            }
+        }))
+        .unwrap();
+        let secret = parse_access("warehouse", response).unwrap();
+        assert_eq!(secret.fields["username"], "etl");
+        assert_eq!(secret.version.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn access_url_escapes_components() {
+        let backend = GcpSecretManagerBackend::new(GcpSecretManagerConfig {
+            project: "acme-prod".to_string(),
+            access_token: Some("ya29.token".to_string()),
+            endpoint: None,
+        })
+        .unwrap();
+        assert_eq!(
+            backend.access_url("db/creds"),
+            "https://secretmanager.googleapis.com/v1/projects/acme-prod/secrets/db%2Fcreds/versions/latest:access"
+        );
+        assert!(!format!("{:?}", backend.config).contains("ya29"));
+    }
+}
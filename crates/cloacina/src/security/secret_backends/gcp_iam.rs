@@ -0,0 +1,251 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! GCP service account impersonation issuer.
+//!
+//! A secret name is a service account email; resolving it calls the IAM
+//! Credentials `generateAccessToken` API and returns a short-lived OAuth
+//! access token for that account. The caller authenticates with its own
+//! identity — `GOOGLE_OAUTH_ACCESS_TOKEN`, or the metadata server, which on
+//! GKE with workload identity is the Kubernetes service account's Google
+//! identity — and needs `roles/iam.serviceAccountTokenCreator` on each
+//! account it impersonates.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+
+use cloacina_workflow::secret::SecretResolverError;
+
+use super::cloud::credential_lifetime_from_env;
+use super::gcp::GcpTokenSource;
+use super::{FetchedSecret, SecretBackend, SecretLease};
+use crate::security::SecretResolverConfigError;
+
+const DEFAULT_ENDPOINT: &str = "https://iamcredentials.googleapis.com";
+const DEFAULT_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+/// Settings for [`GcpImpersonationBackend`].
+#[derive(Clone)]
+pub struct GcpImpersonationConfig {
+    /// The caller's own static OAuth token; `None` uses the metadata server.
+    pub access_token: Option<String>,
+    /// OAuth scopes requested for each impersonated token.
+    pub scopes: Vec<String>,
+    /// Requested token lifetime (at most one hour unless the organization
+    /// allows longer).
+    pub lifetime: Duration,
+    /// Override for `https://iamcredentials.googleapis.com`.
+    pub endpoint: Option<String>,
+}
+
+impl std::fmt::Debug for GcpImpersonationConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpImpersonationConfig")
+            .field(
+                "access_token",
+                &self.access_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("scopes", &self.scopes)
+            .field("lifetime", &self.lifetime)
+            .field("endpoint", &self.endpoint)
+            .finish()
+    }
+}
+
+impl GcpImpersonationConfig {
+    /// Read `GOOGLE_OAUTH_ACCESS_TOKEN`, `CLOACINA_GCP_CREDENTIALS_SCOPES`
+    /// (comma-separated, default `cloud-platform`),
+    /// `CLOACINA_CLOUD_CREDENTIALS_TTL_S` and
+    /// `CLOACINA_GCP_IAM_CREDENTIALS_ENDPOINT`.
+    pub fn from_env() -> Result<Self, SecretResolverConfigError> {
+        let scopes = std::env::var("CLOACINA_GCP_CREDENTIALS_SCOPES")
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            access_token: std::env::var("GOOGLE_OAUTH_ACCESS_TOKEN").ok(),
+            scopes: if scopes.is_empty() {
+                vec![DEFAULT_SCOPE.to_string()]
+            } else {
+                scopes
+            },
+            lifetime: credential_lifetime_from_env()?,
+            endpoint: std::env::var("CLOACINA_GCP_IAM_CREDENTIALS_ENDPOINT").ok(),
+        })
+    }
+}
+
+/// Issues short-lived access tokens for GCP service accounts.
+pub struct GcpImpersonationBackend {
+    config: GcpImpersonationConfig,
+    endpoint: String,
+    http: reqwest::Client,
+    token: GcpTokenSource,
+}
+
+impl GcpImpersonationBackend {
+    pub fn new(config: GcpImpersonationConfig) -> Result<Self, SecretResolverConfigError> {
+        let endpoint = config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+        reqwest::Url::parse(&endpoint).map_err(|e| {
+            SecretResolverConfigError::InvalidBackendConfig(format!(
+                "IAM credentials endpoint '{}': {}",
+                endpoint, e
+            ))
+        })?;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| SecretResolverConfigError::InvalidBackendConfig(e.to_string()))?;
+        Ok(Self {
+            token: GcpTokenSource::new(config.access_token.clone(), http.clone()),
+            config,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            http,
+        })
+    }
+
+    fn generate_url(&self, service_account: &str) -> String {
+        format!(
+            "{}/v1/projects/-/serviceAccounts/{}:generateAccessToken",
+            self.endpoint,
+            urlencoding::encode(service_account)
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateAccessTokenResponse {
+    access_token: String,
+    expire_time: String,
+}
+
+/// Turn a `generateAccessToken` response into token fields with a lease that
+/// runs until the token expires.
+fn parse_token(
+    service_account: &str,
+    response: GenerateAccessTokenResponse,
+) -> Result<FetchedSecret, SecretResolverError> {
+    let expires_at = DateTime::parse_from_rfc3339(&response.expire_time).map_err(|e| {
+        SecretResolverError::Backend(format!(
+            "invalid token expiry '{}': {}",
+            response.expire_time, e
+        ))
+    })?;
+    let remaining = (expires_at.with_timezone(&Utc) - Utc::now())
+        .to_std()
+        .unwrap_or_default();
+    Ok(FetchedSecret {
+        fields: [
+            ("access_token", response.access_token),
+            ("token_type", "Bearer".to_string()),
+            ("expiration", response.expire_time),
+            ("service_account", service_account.to_string()),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect(),
+        version: None,
+        lease: Some(SecretLease {
+            id: service_account.to_string(),
+            duration: remaining,
+            renewable: false,
+        }),
+    })
+}
+
+#[async_trait]
+impl SecretBackend for GcpImpersonationBackend {
+    fn kind(&self) -> &'static str {
+        "gcp-iam"
+    }
+
+    async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+        let token = self.token.token().await?;
+        let response = self
+            .http
+            .post(self.generate_url(name))
+            .bearer_auth(token)
+            .json(&serde_json::json!({
+                "scope": self.config.scopes,
+                "lifetime": format!("{}s", self.config.lifetime.as_secs()),
+            }))
+            .send()
+            .await
+            .map_err(|e| {
+                SecretResolverError::Backend(format!("IAM credentials request failed: {}", e))
+            })?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(SecretResolverError::NotFound(name.to_string()));
+        }
+        if !status.is_success() {
+            let detail = response.text().await.unwrap_or_default();
+            return Err(SecretResolverError::Backend(format!(
+                "IAM credentials returned {} impersonating '{}': {}",
+                status, name, detail
+            )));
+        }
+        let body: GenerateAccessTokenResponse = response.json().await.map_err(|e| {
+            SecretResolverError::Backend(format!("invalid IAM credentials response: {}", e))
+        })?;
+        parse_token(name, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_carry_a_lease_until_expiry() {
+        let response: GenerateAccessTokenResponse = serde_json::from_value(serde_json::json!({
+            "accessToken": "ya29.impersonated",
+            "expireTime": "2099-10-02T15:01:23Z"
+        }))
+        .unwrap();
+        let secret = parse_token("etl@acme-prod.iam.gserviceaccount.com", response).unwrap();
+        assert_eq!(secret.fields["access_token"], "ya29.impersonated");
+        assert_eq!(secret.fields["token_type"], "Bearer");
+        assert!(!secret.lease.unwrap().renewable);
+    }
+
+    #[test]
+    fn generate_url_escapes_the_account() {
+        let backend = GcpImpersonationBackend::new(GcpImpersonationConfig {
+            access_token: Some("ya29.caller".to_string()),
+            scopes: vec![DEFAULT_SCOPE.to_string()],
+            lifetime: Duration::from_secs(900),
+            endpoint: None,
+        })
+        .unwrap();
+        assert_eq!(
+            backend.generate_url("etl@acme-prod.iam.gserviceaccount.com"),
+            "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/etl%40acme-prod.iam.gserviceaccount.com:generateAccessToken"
+        );
+        assert!(!format!("{:?}", backend.config).contains("ya29"));
+    }
+}
@@ -0,0 +1,225 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Environment-variable and file backends.
+//!
+//! Neither needs a secrets manager, so both are always built:
+//!
+//! - [`EnvSecretBackend`] reads secret `api_key` from `SECRET_API_KEY` (the
+//!   prefix plus the name upper-cased, other characters as `_`).
+//! - [`FileSecretBackend`] reads secret `api_key` from `<dir>/api_key`. When
+//!   that is a directory, as a mounted Kubernetes secret is, each file in it
+//!   is a field.
+//!
+//! A value that is a JSON object maps its keys to fields; anything else is
+//! the single field `value`.
+
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use cloacina_workflow::secret::SecretResolverError;
+
+use super::{payload_fields, FetchedSecret, SecretBackend};
+use crate::security::SecretResolverConfigError;
+
+/// Environment variable overriding [`EnvSecretBackend`]'s prefix.
+pub const SECRETS_ENV_PREFIX_ENV_VAR: &str = "CLOACINA_SECRETS_ENV_PREFIX";
+
+/// Default prefix of the variables [`EnvSecretBackend`] reads.
+pub const DEFAULT_SECRETS_ENV_PREFIX: &str = "SECRET_";
+
+/// Environment variable naming [`FileSecretBackend`]'s directory.
+pub const SECRETS_DIR_ENV_VAR: &str = "CLOACINA_SECRETS_DIR";
+
+/// Reads secrets from the process environment.
+#[derive(Debug, Clone)]
+pub struct EnvSecretBackend {
+    prefix: String,
+}
+
+impl EnvSecretBackend {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Use `CLOACINA_SECRETS_ENV_PREFIX`, or `SECRET_` when unset.
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var(SECRETS_ENV_PREFIX_ENV_VAR)
+                .unwrap_or_else(|_| DEFAULT_SECRETS_ENV_PREFIX.to_string()),
+        )
+    }
+
+    /// The variable holding secret `name`.
+    pub fn variable(&self, name: &str) -> String {
+        let suffix: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{}{}", self.prefix, suffix)
+    }
+}
+
+#[async_trait]
+impl SecretBackend for EnvSecretBackend {
+    fn kind(&self) -> &'static str {
+        "env"
+    }
+
+    async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+        let payload = std::env::var(self.variable(name))
+            .map_err(|_| SecretResolverError::NotFound(name.to_string()))?;
+        Ok(FetchedSecret {
+            fields: payload_fields(&payload),
+            version: None,
+            lease: None,
+        })
+    }
+}
+
+/// Reads secrets from files under a directory.
+#[derive(Debug, Clone)]
+pub struct FileSecretBackend {
+    dir: PathBuf,
+}
+
+impl FileSecretBackend {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Use the directory named by `CLOACINA_SECRETS_DIR`.
+    pub fn from_env() -> Result<Self, SecretResolverConfigError> {
+        let dir = std::env::var(SECRETS_DIR_ENV_VAR)
+            .map_err(|_| SecretResolverConfigError::MissingEnv(SECRETS_DIR_ENV_VAR))?;
+        let dir = PathBuf::from(dir);
+        if !dir.is_dir() {
+            return Err(SecretResolverConfigError::InvalidBackendConfig(format!(
+                "{} '{}' is not a directory",
+                SECRETS_DIR_ENV_VAR,
+                dir.display()
+            )));
+        }
+        Ok(Self::new(dir))
+    }
+}
+
+/// File contents without the trailing newline editors and `echo` add.
+fn file_value(bytes: Vec<u8>, name: &str) -> Result<String, SecretResolverError> {
+    let text = String::from_utf8(bytes)
+        .map_err(|_| SecretResolverError::Backend(format!("secret '{}' is not UTF-8", name)))?;
+    Ok(text
+        .strip_suffix('\n')
+        .map(|t| t.strip_suffix('\r').unwrap_or(t).to_string())
+        .unwrap_or(text))
+}
+
+#[async_trait]
+impl SecretBackend for FileSecretBackend {
+    fn kind(&self) -> &'static str {
+        "file"
+    }
+
+    async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+        // A name is one path component, so it cannot reach outside the
+        // directory.
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+            return Err(SecretResolverError::NotFound(name.to_string()));
+        }
+        let path = self.dir.join(name);
+        let backend_error =
+            |e: std::io::Error| SecretResolverError::Backend(format!("reading '{}': {}", name, e));
+
+        let metadata = match tokio::fs::metadata(&path).await {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(SecretResolverError::NotFound(name.to_string()))
+            }
+            Err(e) => return Err(backend_error(e)),
+        };
+        let fields = if metadata.is_dir() {
+            let mut fields = BTreeMap::new();
+            let mut entries = tokio::fs::read_dir(&path).await.map_err(backend_error)?;
+            while let Some(entry) = entries.next_entry().await.map_err(backend_error)? {
+                let field = entry.file_name().to_string_lossy().into_owned();
+                // Kubernetes keeps its bookkeeping in hidden entries.
+                if field.starts_with('.') || !entry.path().is_file() {
+                    continue;
+                }
+                let bytes = tokio::fs::read(entry.path()).await.map_err(backend_error)?;
+                fields.insert(field, file_value(bytes, name)?);
+            }
+            fields
+        } else {
+            let bytes = tokio::fs::read(&path).await.map_err(backend_error)?;
+            payload_fields(&file_value(bytes, name)?)
+        };
+        Ok(FetchedSecret {
+            fields,
+            version: None,
+            lease: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_variable_names_are_normalized() {
+        let backend = EnvSecretBackend::new("SECRET_");
+        assert_eq!(backend.variable("api_key"), "SECRET_API_KEY");
+        assert_eq!(
+            backend.variable("db.primary-creds"),
+            "SECRET_DB_PRIMARY_CREDS"
+        );
+    }
+
+    #[tokio::test]
+    async fn files_and_directories_resolve_to_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("api_key"), "abc123\n").unwrap();
+        std::fs::create_dir(dir.path().join("db")).unwrap();
+        std::fs::write(dir.path().join("db/username"), "app").unwrap();
+        std::fs::write(dir.path().join("db/password"), "hunter22\n").unwrap();
+        std::fs::write(dir.path().join("db/.hidden"), "skip").unwrap();
+        let backend = FileSecretBackend::new(dir.path());
+
+        let api_key = backend.fetch("api_key").await.unwrap();
+        assert_eq!(api_key.fields["value"], "abc123");
+
+        let db = backend.fetch("db").await.unwrap();
+        assert_eq!(db.fields.len(), 2);
+        assert_eq!(db.fields["password"], "hunter22");
+
+        for name in ["missing", "../api_key", ".hidden", "db/username"] {
+            assert!(matches!(
+                backend.fetch(name).await,
+                Err(SecretResolverError::NotFound(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,284 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! HashiCorp Vault backend.
+//!
+//! A secret name is a Vault API path under `/v1/`, after the optional
+//! `path_prefix`: with prefix `secret/data/`, the name `etl` reads the KV v2
+//! secret `secret/etl`; without a prefix, `database/creds/app` reads dynamic
+//! database credentials. KV v2 responses are unwrapped to their `data` and
+//! versioned by `metadata.version`; any other response's `data` is the secret,
+//! and its `lease_id` / `lease_duration` / `renewable` become the lease.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::Duration;
+
+use cloacina_workflow::secret::SecretResolverError;
+
+use super::{json_fields, FetchedSecret, SecretBackend, SecretLease};
+use crate::security::SecretResolverConfigError;
+
+/// Connection settings for [`VaultSecretBackend`].
+#[derive(Clone)]
+pub struct VaultConfig {
+    /// Vault address, e.g. `https://vault.internal:8200`.
+    pub address: String,
+    pub token: String,
+    /// Enterprise namespace, sent as `X-Vault-Namespace`.
+    pub namespace: Option<String>,
+    /// Prepended to every secret name, e.g. `secret/data/`.
+    pub path_prefix: String,
+}
+
+impl std::fmt::Debug for VaultConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultConfig")
+            .field("address", &self.address)
+            .field("token", &"<redacted>")
+            .field("namespace", &self.namespace)
+            .field("path_prefix", &self.path_prefix)
+            .finish()
+    }
+}
+
+impl VaultConfig {
+    /// Read `VAULT_ADDR`, `VAULT_TOKEN`, `VAULT_NAMESPACE` and
+    /// `CLOACINA_VAULT_PATH_PREFIX`.
+    pub fn from_env() -> Result<Self, SecretResolverConfigError> {
+        let address = std::env::var("VAULT_ADDR")
+            .map_err(|_| SecretResolverConfigError::MissingEnv("VAULT_ADDR"))?;
+        let token = std::env::var("VAULT_TOKEN")
+            .map_err(|_| SecretResolverConfigError::MissingEnv("VAULT_TOKEN"))?;
+        Ok(Self {
+            address,
+            token,
+            namespace: std::env::var("VAULT_NAMESPACE").ok(),
+            path_prefix: std::env::var("CLOACINA_VAULT_PATH_PREFIX").unwrap_or_default(),
+        })
+    }
+}
+
+/// Reads secrets from HashiCorp Vault and renews their leases.
+pub struct VaultSecretBackend {
+    config: VaultConfig,
+    http: reqwest::Client,
+}
+
+impl VaultSecretBackend {
+    pub fn new(config: VaultConfig) -> Result<Self, SecretResolverConfigError> {
+        reqwest::Url::parse(&config.address).map_err(|e| {
+            SecretResolverConfigError::InvalidBackendConfig(format!(
+                "VAULT_ADDR '{}': {}",
+                config.address, e
+            ))
+        })?;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| SecretResolverConfigError::InvalidBackendConfig(e.to_string()))?;
+        Ok(Self { config, http })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/v1/{}",
+            self.config.address.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let mut request = self
+            .http
+            .request(method, self.url(path))
+            .header("X-Vault-Token", &self.config.token);
+        if let Some(namespace) = &self.config.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+        request
+    }
+}
+
+#[derive(Deserialize)]
+struct VaultResponse {
+    #[serde(default)]
+    lease_id: String,
+    #[serde(default)]
+    lease_duration: u64,
+    #[serde(default)]
+    renewable: bool,
+    #[serde(default)]
+    data: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl VaultResponse {
+    fn lease(&self) -> Option<SecretLease> {
+        (!self.lease_id.is_empty()).then(|| SecretLease {
+            id: self.lease_id.clone(),
+            duration: Duration::from_secs(self.lease_duration),
+            renewable: self.renewable,
+        })
+    }
+}
+
+/// Turn a read response into a secret, unwrapping KV v2's `data.data`.
+fn parse_read(response: VaultResponse) -> FetchedSecret {
+    let lease = response.lease();
+    let mut data = response.data.unwrap_or_default();
+    let kv2 = matches!(
+        (data.get("data"), data.get("metadata")),
+        (
+            Some(serde_json::Value::Object(_)),
+            Some(serde_json::Value::Object(_))
+        )
+    );
+    if kv2 {
+        let version = data
+            .get("metadata")
+            .and_then(|m| m.get("version"))
+            .map(|v| v.to_string());
+        let fields = match data.remove("data") {
+            Some(serde_json::Value::Object(fields)) => json_fields(fields),
+            _ => Default::default(),
+        };
+        FetchedSecret {
+            fields,
+            version,
+            lease,
+        }
+    } else {
+        FetchedSecret {
+            fields: json_fields(data),
+            // Each dynamic lease is a distinct credential.
+            version: lease.as_ref().map(|l| l.id.clone()),
+            lease,
+        }
+    }
+}
+
+async fn error_for(name: &str, response: reqwest::Response) -> SecretResolverError {
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return SecretResolverError::NotFound(name.to_string());
+    }
+    // Vault error bodies are `{"errors": [...]}` and never carry secret data.
+    let detail = response.text().await.unwrap_or_default();
+    SecretResolverError::Backend(format!(
+        "vault returned {} for '{}': {}",
+        status, name, detail
+    ))
+}
+
+#[async_trait]
+impl SecretBackend for VaultSecretBackend {
+    fn kind(&self) -> &'static str {
+        "vault"
+    }
+
+    async fn fetch(&self, name: &str) -> Result<FetchedSecret, SecretResolverError> {
+        let path = format!("{}{}", self.config.path_prefix, name);
+        let response = self
+            .request(reqwest::Method::GET, &path)
+            .send()
+            .await
+            .map_err(|e| SecretResolverError::Backend(format!("vault request failed: {}", e)))?;
+        if !response.status().is_success() {
+            return Err(error_for(name, response).await);
+        }
+        let body: VaultResponse = response
+            .json()
+            .await
+            .map_err(|e| SecretResolverError::Backend(format!("invalid vault response: {}", e)))?;
+        Ok(parse_read(body))
+    }
+
+    async fn renew(&self, lease: &SecretLease) -> Result<SecretLease, SecretResolverError> {
+        let response = self
+            .request(reqwest::Method::PUT, "sys/leases/renew")
+            .json(&serde_json::json!({ "lease_id": lease.id }))
+            .send()
+            .await
+            .map_err(|e| SecretResolverError::Backend(format!("vault request failed: {}", e)))?;
+        if !response.status().is_success() {
+            return Err(error_for(&lease.id, response).await);
+        }
+        let body: VaultResponse = response
+            .json()
+            .await
+            .map_err(|e| SecretResolverError::Backend(format!("invalid vault response: {}", e)))?;
+        body.lease().ok_or_else(|| {
+            SecretResolverError::Backend(format!("vault returned no lease renewing {}", lease.id))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kv2_reads_are_unwrapped_and_versioned() {
+        let response: VaultResponse = serde_json::from_value(serde_json::json!({
+            "lease_id": "",
+            "lease_duration": 0,
+            "renewable": false,
+            "data": {
+                "data": {"password": "s3cret", "port": 5432},
+                "metadata": {"version": 4, "created_time": "2026-01-01T00:00:00Z"}
+            }
+        }))
+        .unwrap();
+        let secret = parse_read(response);
+        assert_eq!(secret.fields["password"], "s3cret");
+        assert_eq!(secret.fields["port"], "5432");
+        assert_eq!(secret.version.as_deref(), Some("4"));
+        assert!(secret.lease.is_none());
+    }
+
+    #[test]
+    fn dynamic_reads_carry_their_lease() {
+        let response: VaultResponse = serde_json::from_value(serde_json::json!({
+            "lease_id": "database/creds/app/AbC",
+            "lease_duration": 3600,
+            "renewable": true,
+            "data": {"username": "v-app-1", "password": "generated"}
+        }))
+        .unwrap();
+        let secret = parse_read(response);
+        assert_eq!(secret.fields["username"], "v-app-1");
+        let lease = secret.lease.unwrap();
+        assert_eq!(lease.duration, Duration::from_secs(3600));
+        assert!(lease.renewable);
+        assert_eq!(secret.version.as_deref(), Some("database/creds/app/AbC"));
+    }
+
+    #[test]
+    fn urls_join_address_and_path() {
+        let backend = VaultSecretBackend::new(VaultConfig {
+            address: "https://vault.internal:8200/".to_string(),
+            token: "t".to_string(),
+            namespace: None,
+            path_prefix: "secret/data/".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            backend.url("secret/data/etl"),
+            "https://vault.internal:8200/v1/secret/data/etl"
+        );
+        assert!(format!("{:?}", backend.config).contains("<redacted>"));
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Redaction of resolved secret values from task results.
+//!
+//! `context.secret(...)` returns plaintext to the task and never writes it
+//! into the context, but the task itself can: copying a token into an output
+//! key, or into an error message. The executor wraps its resolver in a
+//! [`SecretRedactor`] for each task attempt. The redactor remembers every
+//! value the attempt resolved and replaces it with [`REDACTED_SECRET`] in the
+//! attempt's output context and error before either is stored or logged.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use cloacina_workflow::secret::{SecretResolver, SecretResolverError};
+use cloacina_workflow::{Context, TaskError};
+
+/// What a resolved secret value is replaced with.
+pub const REDACTED_SECRET: &str = "[REDACTED]";
+
+/// Values shorter than this are left alone: redacting every `1` or `on` would
+/// mangle unrelated output without protecting anything.
+const MIN_REDACTED_LEN: usize = 4;
+
+/// A [`SecretResolver`] that records what it resolves, for redaction.
+pub struct SecretRedactor {
+    inner: Arc<dyn SecretResolver>,
+    revealed: Mutex<BTreeSet<String>>,
+}
+
+// Revealed values must never appear in logs.
+impl std::fmt::Debug for SecretRedactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretRedactor")
+            .field("revealed", &self.revealed.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl SecretRedactor {
+    pub fn new(inner: Arc<dyn SecretResolver>) -> Self {
+        Self {
+            inner,
+            revealed: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    /// `text` with every revealed value replaced, or `None` if it holds none.
+    pub fn redact_str(&self, text: &str) -> Option<String> {
+        let revealed = self.revealed.lock().unwrap();
+        // Longest first, so a value containing another is replaced whole.
+        let mut values: Vec<&String> = revealed
+            .iter()
+            .filter(|v| text.contains(v.as_str()))
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by_key(|v| std::cmp::Reverse(v.len()));
+        Some(
+            values
+                .into_iter()
+                .fold(text.to_string(), |text, v| text.replace(v, REDACTED_SECRET)),
+        )
+    }
+
+    /// Redact every string in `value`, recursively.
+    pub fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => {
+                if let Some(redacted) = self.redact_str(text) {
+                    *text = redacted;
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| self.redact_value(v)),
+            Value::Object(map) => map.values_mut().for_each(|v| self.redact_value(v)),
+            _ => {}
+        }
+    }
+
+    /// Redact the values of `context`.
+    pub fn redact_context(&self, context: Context<Value>) -> Context<Value> {
+        if self.revealed.lock().unwrap().is_empty() {
+            return context;
+        }
+        let (mut data, bytes) = context.into_parts();
+        data.values_mut().for_each(|v| self.redact_value(v));
+        Context::from_parts(data, bytes)
+    }
+
+    /// Redact the messages `error` carries.
+    pub fn redact_error(&self, error: TaskError) -> TaskError {
+        let redact = |message: String| self.redact_str(&message).unwrap_or(message);
+        match error {
+            TaskError::ExecutionFailed {
+                message,
+                task_id,
+                timestamp,
+            } => TaskError::ExecutionFailed {
+                message: redact(message),
+                task_id,
+                timestamp,
+            },
+            TaskError::ValidationFailed { message } => TaskError::ValidationFailed {
+                message: redact(message),
+            },
+            TaskError::Unknown { task_id, message } => TaskError::Unknown {
+                task_id,
+                message: redact(message),
+            },
+            TaskError::Structured {
+                task_id,
+                mut payload,
+            } => {
+                payload.message = redact(payload.message);
+                self.redact_value(&mut payload.details);
+                TaskError::Structured { task_id, payload }
+            }
+            other => other,
+        }
+    }
+}
+
+#[async_trait]
+impl SecretResolver for SecretRedactor {
+    async fn resolve(&self, name: &str) -> Result<BTreeMap<String, String>, SecretResolverError> {
+        let fields = self.inner.resolve(name).await?;
+        self.revealed.lock().unwrap().extend(
+            fields
+                .values()
+                .filter(|v| v.len() >= MIN_REDACTED_LEN)
+                .cloned(),
+        );
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cloacina_workflow::secret::MapSecretResolver;
+    use serde_json::json;
+
+    fn redactor() -> SecretRedactor {
+        SecretRedactor::new(Arc::new(MapSecretResolver::new(BTreeMap::from([(
+            "api".to_string(),
+            BTreeMap::from([
+                ("token".to_string(), "tok-123456".to_string()),
+                ("region".to_string(), "eu".to_string()),
+            ]),
+        )]))))
+    }
+
+    #[tokio::test]
+    async fn resolved_values_are_redacted_from_outputs_and_errors() {
+        let redactor = redactor();
+        let mut context = Context::new();
+        context
+            .insert(
+                "auth",
+                json!({ "header": "Bearer tok-123456", "region": "eu" }),
+            )
+            .unwrap();
+
+        // Nothing resolved yet, nothing to redact.
+        let context = redactor.redact_context(context);
+        assert_eq!(context.get("auth").unwrap()["header"], "Bearer tok-123456");
+
+        redactor.resolve("api").await.unwrap();
+        let context = redactor.redact_context(context);
+        assert_eq!(context.get("auth").unwrap()["header"], "Bearer [REDACTED]");
+        // Too short to redact.
+        assert_eq!(context.get("auth").unwrap()["region"], "eu");
+
+        let error = redactor.redact_error(TaskError::ValidationFailed {
+            message: "rejected tok-123456".to_string(),
+        });
+        assert!(matches!(
+            error,
+            TaskError::ValidationFailed { message } if message == "rejected [REDACTED]"
+        ));
+    }
+}
@@ -88,7 +88,7 @@ impl SecretAllow {
     }
 
     /// Whether `name` may be resolved under this policy.
-    fn permits(&self, name: &str) -> bool {
+    pub(crate) fn permits(&self, name: &str) -> bool {
         match self {
             SecretAllow::All => true,
             SecretAllow::List(set) => set.contains(name),
@@ -104,6 +104,15 @@ pub enum SecretResolverConfigError {
 
     #[error("{0} must be base64 or hex encoding of exactly 32 bytes")]
     InvalidKek(&'static str),
+
+    /// `CLOACINA_SECRETS_BACKEND` names a backend this build does not include
+    /// (unknown, or its `secrets-*` feature is off).
+    #[error("unsupported secrets backend '{0}' (built-in: env, file; vault, aws, gcp behind the secrets-vault / secrets-aws / secrets-gcp features)")]
+    UnsupportedBackend(String),
+
+    /// A secrets backend's configuration is incomplete or malformed.
+    #[error("invalid secrets backend configuration: {0}")]
+    InvalidBackendConfig(String),
 }
 
 /// Resolves secrets by decrypting against a tenant-scoped [`SecretStore`].
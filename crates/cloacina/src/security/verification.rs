@@ -21,10 +21,12 @@
 //! - [`VerificationError`] for specific failure types
 //! - [`SignatureSource`] for specifying where to find signatures
 //! - [`verify_and_load_package`] for verified package loading
+//! - [`enforce_signature_policy`] for the registry's per-tenant signature gate
 
 use super::audit;
 use crate::crypto::verify_signature;
 use crate::database::universal_types::UniversalUuid;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -56,6 +58,11 @@ pub struct SecurityConfig {
     /// all uploads). Set this to the server's default-org UUID when
     /// signature verification is enabled. (T-0557 Bug 2)
     pub verification_org_id: Option<UniversalUuid>,
+
+    /// Per-tenant trust lists: tenant id → the organization whose trusted
+    /// keys that tenant's packages must be signed by. Tenants not listed
+    /// fall back to `verification_org_id`.
+    pub tenant_trust_orgs: HashMap<String, UniversalUuid>,
 }
 
 impl SecurityConfig {
@@ -65,6 +72,7 @@ impl SecurityConfig {
             require_signatures: true,
             key_encryption_key: None,
             verification_org_id: None,
+            tenant_trust_orgs: HashMap::new(),
         }
     }
 
@@ -78,6 +86,53 @@ impl SecurityConfig {
         self.key_encryption_key = Some(key);
         self
     }
+
+    /// Verify `tenant`'s packages against `org_id`'s trusted keys instead of
+    /// the default `verification_org_id`.
+    pub fn with_tenant_trust_org(
+        mut self,
+        tenant: impl Into<String>,
+        org_id: UniversalUuid,
+    ) -> Self {
+        self.tenant_trust_orgs.insert(tenant.into(), org_id);
+        self
+    }
+
+    /// The organization whose trusted keys `tenant`'s packages are checked
+    /// against, or `None` when the tenant has no trust list.
+    pub fn trust_org_for(&self, tenant: &str) -> Option<UniversalUuid> {
+        self.tenant_trust_orgs
+            .get(tenant)
+            .copied()
+            .or(self.verification_org_id)
+    }
+}
+
+/// Parse `TENANT=ORG_UUID` trust list entries (as given to
+/// `--tenant-trust-org`) into a tenant → organization map.
+pub fn parse_tenant_trust_orgs<S: AsRef<str>>(
+    entries: &[S],
+) -> Result<HashMap<String, UniversalUuid>, String> {
+    let mut orgs = HashMap::new();
+    for entry in entries {
+        let entry = entry.as_ref().trim();
+        let (tenant, org) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("expected TENANT=ORG_UUID, got '{}'", entry))?;
+        let tenant = tenant.trim();
+        if tenant.is_empty() {
+            return Err(format!("missing tenant in '{}'", entry));
+        }
+        let org = uuid::Uuid::parse_str(org.trim())
+            .map_err(|e| format!("invalid organization id in '{}': {}", entry, e))?;
+        if orgs
+            .insert(tenant.to_string(), UniversalUuid::from(org))
+            .is_some()
+        {
+            return Err(format!("tenant '{}' has more than one trust org", tenant));
+        }
+    }
+    Ok(orgs)
 }
 
 /// Errors that occur during package verification.
@@ -137,6 +192,31 @@ pub enum VerificationError {
         /// The underlying key manager error
         error: String,
     },
+
+    #[error("No signature trust list configured for tenant '{tenant}'")]
+    NoTrustList {
+        /// Tenant whose packages could not be checked
+        tenant: String,
+    },
+}
+
+impl VerificationError {
+    /// Stable machine-readable code for this failure, used as the API error
+    /// code on rejected uploads and as the audit-log failure reason.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TamperedPackage { .. } => "package_tampered",
+            Self::UntrustedSigner { .. } => "untrusted_signer",
+            Self::InvalidSignature => "invalid_signature",
+            Self::SignatureNotFound { .. } => "signature_not_found",
+            Self::NoTrustList { .. } => "signature_verification_unconfigured",
+            Self::MalformedSignature { .. }
+            | Self::FileReadError { .. }
+            | Self::HashError { .. }
+            | Self::DatabaseError { .. }
+            | Self::KeyManagerError { .. } => "signature_verification_error",
+        }
+    }
 }
 
 /// Where to find the signature for a package.
@@ -195,13 +275,8 @@ pub async fn verify_package<P: AsRef<Path>>(
 ) -> Result<VerificationResult, VerificationError> {
     let package_path = package_path.as_ref();
 
-    // 1. Compute package hash
-    let package_data =
-        std::fs::read(package_path).map_err(|e| VerificationError::FileReadError {
-            error: e.to_string(),
-        })?;
-
-    let package_hash = compute_package_hash(&package_data)?;
+    // 1. Compute package hash, reading the file in pieces
+    let package_hash = compute_file_hash(package_path)?;
 
     // 2. Load signature based on source
     let signature = match signature_source {
@@ -300,6 +375,63 @@ pub async fn verify_package<P: AsRef<Path>>(
     })
 }
 
+/// Apply the registry signature policy to a package owned by `tenant`.
+///
+/// With `require_signatures` off this is a no-op returning `Ok(None)`.
+/// Otherwise the package must carry a signature (looked up by hash in
+/// `database`) from a key trusted by the tenant's trust org — see
+/// [`SecurityConfig::trust_org_for`]. Both outcomes are audit-logged under
+/// `origin` (e.g. `upload:tenant=acme`), so the upload route and the
+/// reconciler produce the same record for the same package.
+pub async fn enforce_signature_policy(
+    config: &SecurityConfig,
+    tenant: &str,
+    package_path: &Path,
+    database: &crate::Database,
+    origin: &str,
+) -> Result<Option<VerificationResult>, VerificationError> {
+    if !config.require_signatures {
+        return Ok(None);
+    }
+    let Some(org_id) = config.trust_org_for(tenant) else {
+        tracing::warn!(
+            tenant = %tenant,
+            origin = %origin,
+            "package rejected: signatures are required but the tenant has no trust list"
+        );
+        return Err(VerificationError::NoTrustList {
+            tenant: tenant.to_string(),
+        });
+    };
+    let dal = crate::dal::DAL::new(database.clone());
+    let package_signer = DbPackageSigner::new(dal.clone());
+    let key_manager = DbKeyManager::new(dal);
+    match verify_package(
+        package_path,
+        org_id,
+        SignatureSource::Database,
+        &package_signer,
+        &key_manager,
+    )
+    .await
+    {
+        Ok(result) => {
+            audit::log_package_load_success(
+                org_id,
+                origin,
+                &result.package_hash,
+                Some(&result.signer_fingerprint),
+                /* signature_verified */ true,
+            );
+            Ok(Some(result))
+        }
+        Err(e) => {
+            audit::log_package_load_failure(org_id, origin, &e.to_string(), e.code());
+            Err(e)
+        }
+    }
+}
+
 /// Verify a package using only a detached signature and public key (offline mode).
 ///
 /// This is useful when the database is not available or for offline verification.
@@ -475,6 +607,19 @@ fn compute_package_hash(data: &[u8]) -> Result<String, VerificationError> {
     Ok(hex::encode(hasher.finalize()))
 }
 
+/// Compute SHA256 hash of the file at `path` without reading it into memory.
+fn compute_file_hash(path: &Path) -> Result<String, VerificationError> {
+    use sha2::{Digest, Sha256};
+
+    let read_error = |e: std::io::Error| VerificationError::FileReadError {
+        error: e.to_string(),
+    };
+    let mut file = std::fs::File::open(path).map_err(read_error)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(read_error)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
 /// Load signature from database.
 async fn load_signature_from_db(
     package_hash: &str,
@@ -514,6 +659,36 @@ mod tests {
         assert!(config.key_encryption_key.is_none());
     }
 
+    #[test]
+    fn tenant_trust_orgs_override_the_default_org() {
+        let default_org = UniversalUuid::new_v4();
+        let acme_org = UniversalUuid::new_v4();
+        let config = SecurityConfig {
+            verification_org_id: Some(default_org),
+            ..SecurityConfig::require_signatures()
+        }
+        .with_tenant_trust_org("acme", acme_org);
+        assert_eq!(config.trust_org_for("acme"), Some(acme_org));
+        assert_eq!(config.trust_org_for("beta"), Some(default_org));
+
+        let tenant_only =
+            SecurityConfig::require_signatures().with_tenant_trust_org("acme", acme_org);
+        assert_eq!(tenant_only.trust_org_for("beta"), None);
+    }
+
+    #[test]
+    fn parse_tenant_trust_orgs_rejects_malformed_entries() {
+        let org = uuid::Uuid::new_v4();
+        let orgs = parse_tenant_trust_orgs(&[format!("acme={}", org)]).unwrap();
+        assert_eq!(orgs["acme"], UniversalUuid::from(org));
+
+        assert!(parse_tenant_trust_orgs(&["acme"]).is_err());
+        assert!(parse_tenant_trust_orgs(&["acme=not-a-uuid"]).is_err());
+        assert!(parse_tenant_trust_orgs(&[format!("={}", org)]).is_err());
+        let dup = format!("acme={}", org);
+        assert!(parse_tenant_trust_orgs(&[dup.clone(), dup]).is_err());
+    }
+
     #[test]
     fn test_security_config_require_signatures() {
         let config = SecurityConfig::require_signatures();
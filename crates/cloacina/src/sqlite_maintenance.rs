@@ -0,0 +1,447 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! SQLite maintenance — background housekeeping for SQLite deployments.
+//!
+//! SQLite needs periodic care that PostgreSQL does for itself: the WAL grows
+//! until something checkpoints it, the query planner's statistics go stale
+//! as tables fill, freed pages stay in the file, and a fixed `busy_timeout`
+//! is either too short under heavy writes or needlessly long when idle. Each
+//! tick this service:
+//!
+//! 1. runs a `PASSIVE` WAL checkpoint and uses the WAL size it reports to
+//!    estimate how many pages were written since the last tick;
+//! 2. runs a `TRUNCATE` checkpoint once the WAL passes `truncate_wal_pages`,
+//!    so the `-wal` file shrinks back instead of staying at its high-water
+//!    mark;
+//! 3. runs `PRAGMA optimize` (SQLite's incremental `ANALYZE`) after
+//!    `optimize_after_pages` pages have been written;
+//! 4. runs `PRAGMA incremental_vacuum` when the free list passes
+//!    `vacuum_free_pages` — only on databases created with incremental
+//!    auto-vacuum, which `Database::run_migrations` enables for new files;
+//! 5. raises the connection `busy_timeout` while the write rate is high (or
+//!    a checkpoint reported contention), and lowers it back to
+//!    `busy_timeout_min` once things are quiet.
+//!
+//! Every step is best-effort: a failure is logged and the next tick tries
+//! again.
+
+use std::time::Duration;
+
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::database::connection::SQLITE_DEFAULT_BUSY_TIMEOUT;
+use crate::database::Database;
+
+/// Configuration for the SQLite maintenance service.
+#[derive(Debug, Clone)]
+pub struct SqliteMaintenanceConfig {
+    /// How often the maintenance tick runs (default 60s).
+    pub interval: Duration,
+    /// WAL size, in pages, past which the WAL is checkpointed with
+    /// `TRUNCATE` (default 4096 — 16 MiB at the default page size).
+    pub truncate_wal_pages: i64,
+    /// Pages written between `PRAGMA optimize` runs (default 10 000).
+    pub optimize_after_pages: u64,
+    /// Free-list size, in pages, that triggers an incremental vacuum
+    /// (default 1024).
+    pub vacuum_free_pages: i64,
+    /// Pages released per incremental vacuum (default 512).
+    pub vacuum_batch_pages: i64,
+    /// Lowest `busy_timeout` the tuner sets (default 30s).
+    pub busy_timeout_min: Duration,
+    /// Highest `busy_timeout` the tuner sets (default 120s).
+    pub busy_timeout_max: Duration,
+    /// Write rate, in WAL pages per second, above which the busy timeout is
+    /// raised (default 100). Below a quarter of it the timeout is lowered.
+    pub high_write_pages_per_sec: f64,
+}
+
+impl Default for SqliteMaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            truncate_wal_pages: 4096,
+            optimize_after_pages: 10_000,
+            vacuum_free_pages: 1024,
+            vacuum_batch_pages: 512,
+            busy_timeout_min: SQLITE_DEFAULT_BUSY_TIMEOUT,
+            busy_timeout_max: Duration::from_secs(120),
+            high_write_pages_per_sec: 100.0,
+        }
+    }
+}
+
+/// What one maintenance tick observed and did.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MaintenanceReport {
+    /// WAL pages written since the previous tick (estimated).
+    pub pages_written: u64,
+    /// Whether the passive checkpoint was blocked by a reader or writer.
+    pub checkpoint_busy: bool,
+    /// Whether the WAL was truncated.
+    pub truncated: bool,
+    /// Whether `PRAGMA optimize` ran.
+    pub optimized: bool,
+    /// Whether an incremental vacuum ran.
+    pub vacuumed: bool,
+    /// The busy timeout in effect after the tick.
+    pub busy_timeout: Duration,
+}
+
+#[derive(QueryableByName)]
+struct CheckpointRow {
+    #[diesel(sql_type = BigInt)]
+    busy: i64,
+    #[diesel(sql_type = BigInt)]
+    log: i64,
+}
+
+#[derive(QueryableByName)]
+struct FreelistRow {
+    #[diesel(sql_type = BigInt)]
+    freelist_count: i64,
+}
+
+#[derive(QueryableByName)]
+struct AutoVacuumRow {
+    #[diesel(sql_type = BigInt)]
+    auto_vacuum: i64,
+}
+
+/// `PRAGMA auto_vacuum` value for incremental mode.
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+
+/// Result of the blocking part of a tick, run on a pooled connection.
+struct TickOutcome {
+    checkpoint_busy: bool,
+    wal_pages: i64,
+    truncated: bool,
+    optimized: bool,
+    vacuumed: bool,
+    incremental_vacuum: bool,
+}
+
+/// Background service that keeps a SQLite database in shape.
+pub struct SqliteMaintenance {
+    database: Database,
+    config: SqliteMaintenanceConfig,
+    shutdown_rx: watch::Receiver<bool>,
+    /// WAL size seen by the previous tick.
+    last_wal_pages: i64,
+    /// Pages written since the last `PRAGMA optimize`.
+    pages_since_optimize: u64,
+    /// Logged once when the database cannot be vacuumed incrementally.
+    warned_no_incremental_vacuum: bool,
+}
+
+impl SqliteMaintenance {
+    /// Create a new SQLite maintenance service.
+    pub fn new(
+        database: Database,
+        config: SqliteMaintenanceConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            shutdown_rx,
+            last_wal_pages: 0,
+            pages_since_optimize: 0,
+            warned_no_incremental_vacuum: false,
+        }
+    }
+
+    /// Run the maintenance loop.
+    pub async fn run(&mut self) {
+        info!(
+            "Starting SQLite maintenance (interval: {}s)",
+            self.config.interval.as_secs()
+        );
+
+        let mut interval = tokio::time::interval(self.config.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.tick().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    if *self.shutdown_rx.borrow() {
+                        info!("SQLite maintenance shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Perform a single maintenance tick.
+    pub async fn tick(&mut self) -> MaintenanceReport {
+        let conn = match self.database.get_sqlite_connection().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!("SQLite maintenance skipped: no connection: {}", e);
+                return self.report_unchanged();
+            }
+        };
+
+        let config = self.config.clone();
+        let pages_since_optimize = self.pages_since_optimize;
+        let last_wal_pages = self.last_wal_pages;
+        let outcome = conn
+            .interact(move |conn| run_tick(conn, &config, last_wal_pages, pages_since_optimize))
+            .await;
+        let outcome = match outcome {
+            Ok(Ok(outcome)) => outcome,
+            Ok(Err(e)) => {
+                warn!("SQLite maintenance checkpoint failed: {}", e);
+                return self.report_unchanged();
+            }
+            Err(e) => {
+                warn!("SQLite maintenance failed: {}", e);
+                return self.report_unchanged();
+            }
+        };
+
+        let pages_written = pages_written(self.last_wal_pages, outcome.wal_pages);
+        self.last_wal_pages = if outcome.truncated {
+            0
+        } else {
+            outcome.wal_pages
+        };
+        self.pages_since_optimize = if outcome.optimized {
+            0
+        } else {
+            self.pages_since_optimize + pages_written
+        };
+        if !outcome.incremental_vacuum && !self.warned_no_incremental_vacuum {
+            self.warned_no_incremental_vacuum = true;
+            info!(
+                "SQLite database was created without incremental auto-vacuum; freed pages \
+                 are reused but never returned to the filesystem (run `PRAGMA \
+                 auto_vacuum=INCREMENTAL; VACUUM;` once, offline, to convert it)"
+            );
+        }
+
+        let current = self.database.sqlite_busy_timeout();
+        let rate = pages_written as f64 / self.config.interval.as_secs_f64().max(1.0);
+        let busy_timeout = tune_busy_timeout(current, rate, outcome.checkpoint_busy, &self.config);
+        if busy_timeout != current {
+            info!(
+                "SQLite busy_timeout {}ms -> {}ms ({:.1} pages/s, checkpoint busy: {})",
+                current.as_millis(),
+                busy_timeout.as_millis(),
+                rate,
+                outcome.checkpoint_busy
+            );
+            self.database.set_sqlite_busy_timeout(busy_timeout);
+        }
+
+        record_metrics(&outcome, busy_timeout);
+        let report = MaintenanceReport {
+            pages_written,
+            checkpoint_busy: outcome.checkpoint_busy,
+            truncated: outcome.truncated,
+            optimized: outcome.optimized,
+            vacuumed: outcome.vacuumed,
+            busy_timeout,
+        };
+        debug!("SQLite maintenance tick: {:?}", report);
+        report
+    }
+
+    fn report_unchanged(&self) -> MaintenanceReport {
+        MaintenanceReport {
+            busy_timeout: self.database.sqlite_busy_timeout(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The blocking part of a tick. Only the passive checkpoint is fatal to the
+/// tick; the follow-up steps log and carry on.
+fn run_tick(
+    conn: &mut diesel::SqliteConnection,
+    config: &SqliteMaintenanceConfig,
+    last_wal_pages: i64,
+    pages_since_optimize: u64,
+) -> Result<TickOutcome, diesel::result::Error> {
+    let checkpoint =
+        diesel::sql_query("PRAGMA wal_checkpoint(PASSIVE);").get_result::<CheckpointRow>(conn)?;
+    let checkpoint_busy = checkpoint.busy != 0;
+    // `log` is -1 when the database is not in WAL mode.
+    let wal_pages = checkpoint.log.max(0);
+
+    let mut truncated = false;
+    if wal_pages >= config.truncate_wal_pages && !checkpoint_busy {
+        match diesel::sql_query("PRAGMA wal_checkpoint(TRUNCATE);")
+            .get_result::<CheckpointRow>(conn)
+        {
+            Ok(row) => truncated = row.busy == 0,
+            Err(e) => warn!("SQLite WAL truncate failed: {}", e),
+        }
+    }
+
+    let mut optimized = false;
+    if pages_since_optimize + pages_written(last_wal_pages, wal_pages)
+        >= config.optimize_after_pages
+    {
+        match diesel::sql_query("PRAGMA optimize;").execute(conn) {
+            Ok(_) => optimized = true,
+            Err(e) => warn!("SQLite optimize failed: {}", e),
+        }
+    }
+
+    let incremental_vacuum = diesel::sql_query("PRAGMA auto_vacuum;")
+        .get_result::<AutoVacuumRow>(conn)
+        .map(|row| row.auto_vacuum == AUTO_VACUUM_INCREMENTAL)
+        .unwrap_or(false);
+    let mut vacuumed = false;
+    if incremental_vacuum {
+        let free = diesel::sql_query("PRAGMA freelist_count;")
+            .get_result::<FreelistRow>(conn)
+            .map(|row| row.freelist_count)
+            .unwrap_or(0);
+        if free >= config.vacuum_free_pages {
+            match diesel::sql_query(format!(
+                "PRAGMA incremental_vacuum({});",
+                config.vacuum_batch_pages
+            ))
+            .execute(conn)
+            {
+                Ok(_) => vacuumed = true,
+                Err(e) => warn!("SQLite incremental vacuum failed: {}", e),
+            }
+        }
+    }
+
+    Ok(TickOutcome {
+        checkpoint_busy,
+        wal_pages,
+        truncated,
+        optimized,
+        vacuumed,
+        incremental_vacuum,
+    })
+}
+
+/// Pages written between two WAL size readings. The WAL restarts from the
+/// beginning after a complete checkpoint, so a smaller reading means the
+/// whole current WAL is new.
+fn pages_written(previous: i64, current: i64) -> u64 {
+    if current >= previous {
+        (current - previous) as u64
+    } else {
+        current as u64
+    }
+}
+
+/// Next busy timeout: doubled (up to the max) under a high write rate or a
+/// contended checkpoint, halved (down to the min) when the rate drops below
+/// a quarter of the threshold, unchanged in between.
+fn tune_busy_timeout(
+    current: Duration,
+    pages_per_sec: f64,
+    checkpoint_busy: bool,
+    config: &SqliteMaintenanceConfig,
+) -> Duration {
+    let next = if checkpoint_busy || pages_per_sec >= config.high_write_pages_per_sec {
+        current.saturating_mul(2)
+    } else if pages_per_sec < config.high_write_pages_per_sec / 4.0 {
+        current / 2
+    } else {
+        current
+    };
+    next.clamp(config.busy_timeout_min, config.busy_timeout_max)
+}
+
+fn record_metrics(outcome: &TickOutcome, busy_timeout: Duration) {
+    metrics::counter!("cloacina_sqlite_maintenance_total", "action" => "checkpoint").increment(1);
+    if outcome.truncated {
+        metrics::counter!("cloacina_sqlite_maintenance_total", "action" => "truncate").increment(1);
+    }
+    if outcome.optimized {
+        metrics::counter!("cloacina_sqlite_maintenance_total", "action" => "optimize").increment(1);
+    }
+    if outcome.vacuumed {
+        metrics::counter!("cloacina_sqlite_maintenance_total", "action" => "incremental_vacuum")
+            .increment(1);
+    }
+    metrics::gauge!("cloacina_sqlite_wal_pages").set(outcome.wal_pages as f64);
+    metrics::gauge!("cloacina_sqlite_busy_timeout_seconds").set(busy_timeout.as_secs_f64());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults() {
+        let config = SqliteMaintenanceConfig::default();
+        assert_eq!(config.interval, Duration::from_secs(60));
+        assert_eq!(config.busy_timeout_min, SQLITE_DEFAULT_BUSY_TIMEOUT);
+        assert!(config.busy_timeout_max > config.busy_timeout_min);
+    }
+
+    #[test]
+    fn pages_written_handles_wal_restart() {
+        assert_eq!(pages_written(100, 250), 150);
+        assert_eq!(pages_written(250, 40), 40);
+        assert_eq!(pages_written(0, 0), 0);
+    }
+
+    #[test]
+    fn busy_timeout_tracks_write_rate() {
+        let config = SqliteMaintenanceConfig::default();
+        let min = config.busy_timeout_min;
+        let max = config.busy_timeout_max;
+
+        // Heavy writes double it, capped at the max.
+        assert_eq!(tune_busy_timeout(min, 500.0, false, &config), min * 2);
+        assert_eq!(tune_busy_timeout(max, 500.0, false, &config), max);
+        // A contended checkpoint raises it even at a low rate.
+        assert_eq!(tune_busy_timeout(min, 0.0, true, &config), min * 2);
+        // Quiet halves it, floored at the min.
+        assert_eq!(tune_busy_timeout(max, 1.0, false, &config), max / 2);
+        assert_eq!(tune_busy_timeout(min, 1.0, false, &config), min);
+        // In between it holds.
+        assert_eq!(tune_busy_timeout(min * 2, 50.0, false, &config), min * 2);
+    }
+
+    #[tokio::test]
+    async fn tick_checkpoints_and_reports_on_sqlite() {
+        let database = Database::new("sqlite://:memory:", "", 4);
+        database.run_migrations().await.unwrap();
+        let (_tx, rx) = watch::channel(false);
+        let config = SqliteMaintenanceConfig {
+            optimize_after_pages: 0,
+            ..Default::default()
+        };
+        let mut maintenance = SqliteMaintenance::new(database.clone(), config, rx);
+
+        let report = maintenance.tick().await;
+        assert!(report.optimized);
+        assert_eq!(report.busy_timeout, database.sqlite_busy_timeout());
+
+        // A fresh file gets incremental auto-vacuum from run_migrations.
+        assert!(!maintenance.warned_no_incremental_vacuum);
+    }
+}
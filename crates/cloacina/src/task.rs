@@ -333,6 +333,7 @@
 //!
 //! Tasks track their execution state for monitoring and recovery:
 
+pub mod fn_task;
 pub mod namespace;
 
 use crate::error::{RegistrationError, ValidationError};
@@ -341,7 +342,8 @@ use std::sync::Arc;
 
 // Re-export core task types from cloacina_workflow
 // This ensures type compatibility between macro-generated code and runtime
-pub use cloacina_workflow::{Task, TaskState};
+pub use cloacina_workflow::{Task, TaskState, WorkloadClass};
+pub use fn_task::FnTask;
 pub use namespace::{parse_namespace, TaskNamespace};
 
 // The Task trait is now re-exported from cloacina_workflow above.
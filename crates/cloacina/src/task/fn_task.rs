@@ -0,0 +1,456 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Closure-backed tasks for workflows assembled at runtime.
+//!
+//! [`FnTask`] is the programmatic counterpart of `#[task]`: everything the
+//! macro attributes express (dependencies, soft dependencies, retry policy,
+//! trigger rules, short-circuit key, memory limit, timeout, expected duration,
+//! approval gate, workload class, resources, produced outputs, config) is a builder method, so a
+//! DAG can be generated from configuration instead of code. Add one to a workflow with [`WorkflowBuilder::task`],
+//! which resolves its dependency IDs against the workflow's namespace.
+//!
+//! [`WorkflowBuilder::task`]: crate::workflow::WorkflowBuilder::task
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+
+use crate::context::Context;
+use crate::error::TaskError;
+use crate::execution_planner::TriggerRule;
+use crate::outputs::{OutputKind, ProducedOutput};
+use crate::retry::RetryPolicy;
+
+use super::{Task, TaskNamespace, WorkloadClass};
+
+type TaskBody = dyn Fn(
+        Context<serde_json::Value>,
+    ) -> BoxFuture<'static, Result<Context<serde_json::Value>, TaskError>>
+    + Send
+    + Sync;
+
+/// A task whose body is an async closure.
+///
+/// The closure takes the task's input context and returns its output
+/// context, like [`Task::execute`].
+///
+/// ```rust,ignore
+/// use cloacina::{FnTask, Workflow};
+///
+/// let workflow = Workflow::builder("etl")
+///     .task(FnTask::new("extract", |mut context| async move {
+///         context.insert("rows", serde_json::json!(42))?;
+///         Ok(context)
+///     }))?
+///     .task(
+///         FnTask::new("load", |context| async move { Ok(context) })
+///             .depends_on("extract")
+///             .timeout(std::time::Duration::from_secs(60)),
+///     )?
+///     .build()?;
+/// ```
+#[derive(Clone)]
+pub struct FnTask {
+    id: String,
+    body: Arc<TaskBody>,
+    dependency_ids: Vec<String>,
+    soft_dependency_ids: Vec<String>,
+    dependencies: Vec<TaskNamespace>,
+    retry_policy: RetryPolicy,
+    trigger_rules: serde_json::Value,
+    short_circuit: Option<String>,
+    branch_key: Option<String>,
+    branch: Option<String>,
+    loop_until: Option<serde_json::Value>,
+    max_iterations: Option<u32>,
+    memory_limit: Option<u64>,
+    timeout: Option<Duration>,
+    expected_duration: Option<Duration>,
+    requires_approval: bool,
+    workload_class: Option<WorkloadClass>,
+    resources: Vec<String>,
+    produces: Vec<ProducedOutput>,
+    scoped: bool,
+    reads: Option<Vec<String>>,
+    configuration: Option<serde_json::Value>,
+}
+
+impl FnTask {
+    /// Create a task with the given ID and body.
+    pub fn new<F, Fut>(id: &str, body: F) -> Self
+    where
+        F: Fn(Context<serde_json::Value>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Context<serde_json::Value>, TaskError>> + Send + 'static,
+    {
+        Self {
+            id: id.to_string(),
+            body: Arc::new(move |context| Box::pin(body(context))),
+            dependency_ids: Vec::new(),
+            soft_dependency_ids: Vec::new(),
+            dependencies: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            trigger_rules: serde_json::json!({"type": "Always"}),
+            short_circuit: None,
+            branch_key: None,
+            branch: None,
+            loop_until: None,
+            max_iterations: None,
+            memory_limit: None,
+            timeout: None,
+            expected_duration: None,
+            requires_approval: false,
+            workload_class: None,
+            resources: Vec::new(),
+            produces: Vec::new(),
+            scoped: false,
+            reads: None,
+            configuration: None,
+        }
+    }
+
+    /// Run after the task with local ID `id`, receiving its output context
+    pub fn depends_on(mut self, id: &str) -> Self {
+        self.dependency_ids.push(id.to_string());
+        self
+    }
+
+    /// Run after the task with local ID `id`, regardless of its outcome and
+    /// without receiving its output (see [`Task::is_soft_dependency`])
+    pub fn soft_depends_on(mut self, id: &str) -> Self {
+        self.dependency_ids.push(id.to_string());
+        self.soft_dependency_ids.push(id.to_string());
+        self
+    }
+
+    /// Set the task's retry policy
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set the condition under which the task runs once its dependencies are
+    /// done
+    pub fn trigger_rule(mut self, rule: TriggerRule) -> Self {
+        self.trigger_rules =
+            serde_json::to_value(rule).expect("trigger rules always serialize to JSON");
+        self
+    }
+
+    /// Skip the task's descendants when `key` is empty in its output (see
+    /// [`Task::short_circuit`])
+    pub fn short_circuit(mut self, key: &str) -> Self {
+        self.short_circuit = Some(key.to_string());
+        self
+    }
+
+    /// Make the task a branch decider that writes the selected branch name
+    /// under `key` (see [`Task::branch_key`])
+    pub fn branch_key(mut self, key: &str) -> Self {
+        self.branch_key = Some(key.to_string());
+        self
+    }
+
+    /// Run the task only when its decider selects branch `name` (see
+    /// [`Task::branch`])
+    pub fn branch(mut self, name: &str) -> Self {
+        self.branch = Some(name.to_string());
+        self
+    }
+
+    /// Run the body again on its own output until `rule`'s context
+    /// conditions hold, at most `max_iterations` times (see
+    /// [`Task::loop_until`])
+    pub fn loop_until(mut self, rule: TriggerRule, max_iterations: u32) -> Self {
+        self.loop_until =
+            Some(serde_json::to_value(rule).expect("trigger rules always serialize to JSON"));
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Fail the task once it uses more than `mb` MiB (see
+    /// [`Task::memory_limit`])
+    pub fn memory_limit_mb(mut self, mb: u64) -> Self {
+        self.memory_limit = Some(mb * 1024 * 1024);
+        self
+    }
+
+    /// Fail an attempt that runs longer than `timeout` (see [`Task::timeout`])
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Expect an attempt to take about `duration`, so it is not started
+    /// too close to an execution deadline (see [`Task::expected_duration`])
+    pub fn expected_duration(mut self, duration: Duration) -> Self {
+        self.expected_duration = Some(duration);
+        self
+    }
+
+    /// Wait for a human approval before running (see
+    /// [`Task::requires_approval`])
+    pub fn approval(mut self, required: bool) -> Self {
+        self.requires_approval = required;
+        self
+    }
+
+    /// Classify the task's workload for per-class concurrency limits and
+    /// placement (see [`Task::workload_class`])
+    pub fn workload(mut self, class: WorkloadClass) -> Self {
+        self.workload_class = Some(class);
+        self
+    }
+
+    /// Declare a named resource the task uses, limited by the runner's pool
+    /// of that name (see [`Task::resources`]); call once per resource
+    pub fn resource(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !self.resources.contains(&name) {
+            self.resources.push(name);
+        }
+        self
+    }
+
+    /// Declare a context key the task writes and its JSON type, checked
+    /// after each successful attempt (see [`Task::produces`]); call once per
+    /// output
+    pub fn produces(mut self, key: impl Into<String>, kind: OutputKind) -> Self {
+        self.produces.push(ProducedOutput::new(key, kind));
+        self
+    }
+
+    /// Move the keys the task adds or changes under its ID (see
+    /// [`Task::scoped`])
+    pub fn scoped(mut self, scoped: bool) -> Self {
+        self.scoped = scoped;
+        self
+    }
+
+    /// Show the task only these context keys; the rest pass through to its
+    /// output (see [`Task::reads`])
+    pub fn reads<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.reads = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the task's declarative config; strings may embed `{{ … }}`
+    /// templates rendered at schedule time (see [`Task::configuration`])
+    pub fn config(mut self, config: serde_json::Value) -> Self {
+        self.configuration = Some(config);
+        self
+    }
+
+    /// Resolve the dependency IDs into namespaces of the workflow the task is
+    /// added to.
+    pub(crate) fn resolve(mut self, tenant: &str, package: &str, workflow: &str) -> Self {
+        self.dependencies = self
+            .dependency_ids
+            .iter()
+            .map(|id| TaskNamespace::new(tenant, package, workflow, id))
+            .collect();
+        self
+    }
+}
+
+impl std::fmt::Debug for FnTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnTask")
+            .field("id", &self.id)
+            .field("dependencies", &self.dependency_ids)
+            .field("soft_dependencies", &self.soft_dependency_ids)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Task for FnTask {
+    async fn execute(
+        &self,
+        context: Context<serde_json::Value>,
+    ) -> Result<Context<serde_json::Value>, TaskError> {
+        (self.body)(context).await
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn dependencies(&self) -> &[TaskNamespace] {
+        &self.dependencies
+    }
+
+    fn is_soft_dependency(&self, dependency: &TaskNamespace) -> bool {
+        self.soft_dependency_ids.contains(&dependency.task_id)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy.clone()
+    }
+
+    fn trigger_rules(&self) -> serde_json::Value {
+        self.trigger_rules.clone()
+    }
+
+    fn short_circuit(&self) -> Option<String> {
+        self.short_circuit.clone()
+    }
+
+    fn branch_key(&self) -> Option<String> {
+        self.branch_key.clone()
+    }
+
+    fn branch(&self) -> Option<String> {
+        self.branch.clone()
+    }
+
+    fn loop_until(&self) -> Option<serde_json::Value> {
+        self.loop_until.clone()
+    }
+
+    fn max_iterations(&self) -> Option<u32> {
+        self.max_iterations
+    }
+
+    fn memory_limit(&self) -> Option<u64> {
+        self.memory_limit
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn expected_duration(&self) -> Option<Duration> {
+        self.expected_duration
+    }
+
+    fn requires_approval(&self) -> bool {
+        self.requires_approval
+    }
+
+    fn workload_class(&self) -> Option<WorkloadClass> {
+        self.workload_class
+    }
+
+    fn resources(&self) -> Vec<String> {
+        self.resources.clone()
+    }
+
+    fn produces(&self) -> Vec<ProducedOutput> {
+        self.produces.clone()
+    }
+
+    fn scoped(&self) -> bool {
+        self.scoped
+    }
+
+    fn reads(&self) -> Option<Vec<String>> {
+        self.reads.clone()
+    }
+
+    fn configuration(&self) -> Option<serde_json::Value> {
+        self.configuration.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution_planner::{TriggerCondition, ValueOperator};
+
+    #[tokio::test]
+    async fn test_fn_task_runs_body_and_exposes_attributes() {
+        let task = FnTask::new("load", |mut context| async move {
+            context.insert("loaded", serde_json::json!(true))?;
+            Ok(context)
+        })
+        .depends_on("extract")
+        .soft_depends_on("cleanup")
+        .retry_policy(RetryPolicy::builder().max_attempts(5).build())
+        .trigger_rule(TriggerRule::All {
+            conditions: vec![TriggerCondition::TaskSuccess {
+                task_name: "extract".to_string(),
+            }],
+        })
+        .short_circuit("rows")
+        .branch_key("route")
+        .branch("fast")
+        .loop_until(
+            TriggerRule::All {
+                conditions: vec![TriggerCondition::ContextValue {
+                    key: "converged".to_string(),
+                    operator: ValueOperator::Equals,
+                    value: serde_json::json!(true),
+                }],
+            },
+            10,
+        )
+        .memory_limit_mb(2)
+        .timeout(Duration::from_secs(30))
+        .expected_duration(Duration::from_secs(10))
+        .approval(true)
+        .workload(WorkloadClass::Cpu)
+        .resource("gpu")
+        .resource("gpu")
+        .produces("loaded", OutputKind::Boolean)
+        .scoped(true)
+        .reads(["rows", "route"])
+        .config(serde_json::json!({"bucket": "{{ ds }}"}))
+        .resolve("public", "embedded", "etl");
+
+        let extract = TaskNamespace::new("public", "embedded", "etl", "extract");
+        let cleanup = TaskNamespace::new("public", "embedded", "etl", "cleanup");
+        assert_eq!(task.dependencies(), &[extract.clone(), cleanup.clone()]);
+        assert!(task.is_soft_dependency(&cleanup));
+        assert_eq!(task.data_dependencies(), vec![extract]);
+        assert_eq!(Task::retry_policy(&task).max_attempts, 5);
+        assert_eq!(task.trigger_rules()["type"], "All");
+        assert_eq!(Task::short_circuit(&task).as_deref(), Some("rows"));
+        assert_eq!(Task::branch_key(&task).as_deref(), Some("route"));
+        assert_eq!(Task::branch(&task).as_deref(), Some("fast"));
+        assert_eq!(Task::loop_until(&task).unwrap()["type"], "All");
+        assert_eq!(task.max_iterations(), Some(10));
+        assert_eq!(task.memory_limit(), Some(2 * 1024 * 1024));
+        assert_eq!(Task::timeout(&task), Some(Duration::from_secs(30)));
+        assert_eq!(
+            Task::expected_duration(&task),
+            Some(Duration::from_secs(10))
+        );
+        assert!(task.requires_approval());
+        assert_eq!(task.workload_class(), Some(WorkloadClass::Cpu));
+        assert_eq!(task.resources(), vec!["gpu".to_string()]);
+        assert_eq!(
+            Task::produces(&task),
+            vec![ProducedOutput::new("loaded", OutputKind::Boolean)]
+        );
+        assert!(Task::scoped(&task));
+        assert_eq!(
+            Task::reads(&task),
+            Some(vec!["rows".to_string(), "route".to_string()])
+        );
+        assert!(task.configuration().is_some());
+
+        let output = task.execute(Context::new()).await.unwrap();
+        assert_eq!(output.get("loaded"), Some(&serde_json::json!(true)));
+    }
+}
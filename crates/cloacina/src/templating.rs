@@ -0,0 +1,608 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Templated values for scheduled fires and declarative task configs.
+//!
+//! Rendering is opt-in. A schedule opts a bound instance param in by binding
+//! it as a `{"$template": "…"}` marker, and a task opts in by declaring a
+//! `config` (see [`Task::configuration`](crate::Task::configuration)). The
+//! template string may embed `{{ … }}` expressions:
+//!
+//! ```text
+//! {"$template": "s3://bucket/{{ ds }}/{{ workflow.name }}.parquet"}
+//! {"$template": "{{ context.region | upper }}"}
+//! {"$template": "{{ context.label | default('unlabelled') }}"}
+//! ```
+//!
+//! Nothing else is rendered: plain params, trigger payloads and task outputs
+//! pass through verbatim, so a `{{` inside data that arrived from outside can
+//! never be evaluated.
+//!
+//! ## Variables
+//!
+//! | Name | Value |
+//! |---|---|
+//! | `ds` / `ds_nodash` | Fire date in the schedule's timezone, `YYYY-MM-DD` / `YYYYMMDD` |
+//! | `ts` | Fire time in the schedule's timezone, RFC 3339 |
+//! | `workflow.name` | Target workflow |
+//! | `schedule.id`, `schedule.timezone` | The firing schedule |
+//! | `trigger.name` | Trigger name (trigger fires only) |
+//! | `context.<key>[.<field>…]` | A context value, with dotted access into objects |
+//!
+//! Any other name is an unknown variable.
+//!
+//! ## Filters
+//!
+//! Built in: `upper`, `lower`, `trim`, `default(value)`, `replace(from, to)`,
+//! `json`. Register more with [`register_filter`]; arguments are string or
+//! number literals.
+//!
+//! ## Rendering rules
+//!
+//! - A string that is exactly one expression keeps the value's JSON type
+//!   (`"{{ context.count }}"` renders to a number, not `"3"`).
+//! - `\{{` is a literal `{{`.
+//! - A template that fails to render (unknown variable or filter, bad syntax)
+//!   is an error: the fire fails rather than running with a half-rendered
+//!   value.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+use tracing::warn;
+
+use crate::Context;
+
+/// Errors from rendering a template.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    #[error("unterminated '{{{{' in template")]
+    Unterminated,
+    #[error("empty expression")]
+    EmptyExpression,
+    #[error("unknown variable '{0}'")]
+    UnknownVariable(String),
+    #[error("unknown filter '{0}'")]
+    UnknownFilter(String),
+    #[error("malformed filter '{0}'")]
+    MalformedFilter(String),
+    #[error("filter '{name}' failed: {message}")]
+    FilterFailed { name: String, message: String },
+    #[error("malformed template marker: {0}")]
+    MalformedMarker(String),
+    #[error("'{key}': {source}")]
+    Key {
+        key: String,
+        #[source]
+        source: Box<TemplateError>,
+    },
+}
+
+impl TemplateError {
+    fn at(self, key: &str) -> Self {
+        TemplateError::Key {
+            key: key.to_string(),
+            source: Box::new(self),
+        }
+    }
+}
+
+/// The marker key that opts a bound instance param into rendering: the param
+/// is bound as `{"$template": "<template string>"}`.
+pub const TEMPLATE_MARKER: &str = "$template";
+
+/// Classify a value as a template marker.
+///
+/// Returns `Ok(Some(template))` when `value` is exactly
+/// `{"$template": "<string>"}`, `Ok(None)` for any value without the marker
+/// key, and `Err` when the marker key is present but the object is malformed.
+pub fn template_source(value: &Value) -> Result<Option<&str>, TemplateError> {
+    let Value::Object(map) = value else {
+        return Ok(None);
+    };
+    if !map.contains_key(TEMPLATE_MARKER) {
+        return Ok(None);
+    }
+    if map.len() != 1 {
+        return Err(TemplateError::MalformedMarker(format!(
+            "a '{}' object must contain only the '{}' key",
+            TEMPLATE_MARKER, TEMPLATE_MARKER
+        )));
+    }
+    match map.get(TEMPLATE_MARKER) {
+        Some(Value::String(template)) => Ok(Some(template)),
+        _ => Err(TemplateError::MalformedMarker(format!(
+            "'{}' must map to a template string",
+            TEMPLATE_MARKER
+        ))),
+    }
+}
+
+/// A template filter: receives the piped value and the literal arguments.
+pub type TemplateFilter = Arc<dyn Fn(&Value, &[Value]) -> Result<Value, String> + Send + Sync>;
+
+static CUSTOM_FILTERS: Lazy<RwLock<HashMap<String, TemplateFilter>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Register a custom filter, replacing any earlier filter of the same name.
+/// Custom filters shadow the built-ins.
+///
+/// ```rust,ignore
+/// cloacina::templating::register_filter("slug", |v, _args| {
+///     Ok(serde_json::json!(v.as_str().unwrap_or_default().replace(' ', "-")))
+/// });
+/// ```
+pub fn register_filter<F>(name: impl Into<String>, filter: F)
+where
+    F: Fn(&Value, &[Value]) -> Result<Value, String> + Send + Sync + 'static,
+{
+    if let Ok(mut filters) = CUSTOM_FILTERS.write() {
+        filters.insert(name.into(), Arc::new(filter));
+    }
+}
+
+/// Execution metadata available to templates at fire time.
+#[derive(Debug, Clone)]
+pub struct FireMetadata {
+    pub fire_time: DateTime<Utc>,
+    pub workflow_name: String,
+    pub schedule_id: Option<String>,
+    pub schedule_timezone: Option<String>,
+    pub trigger_name: Option<String>,
+}
+
+/// Variable scope a template is rendered against.
+pub struct TemplateScope {
+    root: Value,
+}
+
+impl TemplateScope {
+    /// Build the scope from fire metadata and the context templates may read.
+    ///
+    /// Date variables are computed in the schedule's timezone; an unknown or
+    /// missing timezone falls back to UTC.
+    pub fn new(meta: &FireMetadata, context: &Context<Value>) -> Self {
+        let ctx: Map<String, Value> = context
+            .data()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        let tz = match meta.schedule_timezone.as_deref() {
+            None => Tz::UTC,
+            Some(name) => name.parse::<Tz>().unwrap_or_else(|_| {
+                warn!(
+                    timezone = name,
+                    "unknown schedule timezone; templating in UTC"
+                );
+                Tz::UTC
+            }),
+        };
+        let local = meta.fire_time.with_timezone(&tz);
+        let root = serde_json::json!({
+            "ds": local.format("%Y-%m-%d").to_string(),
+            "ds_nodash": local.format("%Y%m%d").to_string(),
+            "ts": local.to_rfc3339(),
+            "workflow": { "name": meta.workflow_name },
+            "schedule": {
+                "id": meta.schedule_id,
+                "timezone": meta.schedule_timezone,
+            },
+            "trigger": { "name": meta.trigger_name },
+            "context": Value::Object(ctx),
+        });
+        Self { root }
+    }
+
+    fn lookup(&self, path: &str) -> Option<&Value> {
+        let mut segments = path.split('.');
+        let head = segments.next()?;
+        let mut current = self.root.get(head)?;
+        for seg in segments {
+            current = current.get(seg)?;
+        }
+        Some(current)
+    }
+}
+
+/// Render one template string. A string that is exactly one expression keeps
+/// the expression's JSON type; anything else renders to a string.
+pub fn render_value(template: &str, scope: &TemplateScope) -> Result<Value, TemplateError> {
+    let trimmed = template.trim();
+    if trimmed.starts_with("{{") && trimmed.ends_with("}}") && trimmed.matches("{{").count() == 1 {
+        return eval_expression(&trimmed[2..trimmed.len() - 2], scope);
+    }
+    render_str(template, scope).map(Value::String)
+}
+
+/// Render every `{{ … }}` in `template` to a string.
+pub fn render_str(template: &str, scope: &TemplateScope) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find("{{") {
+        if pos > 0 && rest.as_bytes()[pos - 1] == b'\\' {
+            out.push_str(&rest[..pos - 1]);
+            out.push_str("{{");
+            rest = &rest[pos + 2..];
+            continue;
+        }
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 2..];
+        let end = after.find("}}").ok_or(TemplateError::Unterminated)?;
+        let value = eval_expression(&after[..end], scope)?;
+        out.push_str(&display(&value));
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Render the `{"$template": …}` markers in a schedule's bound instance
+/// params (the JSON object stored on the schedule row), returning the params
+/// with each marker replaced by its rendered value.
+///
+/// Templates read `context.*` from `context` (the trigger payload, if any)
+/// overlaid with the schedule's plain params. Other params are returned
+/// untouched, and nothing from the context is ever rendered itself.
+pub fn render_params(
+    params_json: &str,
+    meta: &FireMetadata,
+    context: &Context<Value>,
+) -> Result<String, TemplateError> {
+    let mut params: Map<String, Value> = serde_json::from_str(params_json).map_err(|e| {
+        TemplateError::MalformedMarker(format!("params are not a JSON object: {e}"))
+    })?;
+
+    let mut templates = Vec::new();
+    for (key, value) in &params {
+        if let Some(template) = template_source(value).map_err(|e| e.at(key))? {
+            templates.push((key.clone(), template.to_string()));
+        }
+    }
+    if templates.is_empty() {
+        return Ok(params_json.to_string());
+    }
+
+    let mut visible = context.data().clone();
+    for (key, value) in &params {
+        if !templates.iter().any(|(k, _)| k == key) {
+            visible.insert(key.clone(), value.clone());
+        }
+    }
+    let scope = TemplateScope::new(meta, &Context::from_data(visible));
+    for (key, template) in templates {
+        let rendered = render_value(&template, &scope).map_err(|e| e.at(&key))?;
+        params.insert(key, rendered);
+    }
+    Ok(Value::Object(params).to_string())
+}
+
+/// Render every string in a declarative task config (recursing into arrays
+/// and objects). Unlike instance params, the whole config is a template: it
+/// is authored with the task, never supplied at runtime.
+pub fn render_config(config: &Value, scope: &TemplateScope) -> Result<Value, TemplateError> {
+    match config {
+        Value::String(s) if s.contains("{{") => render_value(s, scope),
+        Value::Array(items) => items
+            .iter()
+            .map(|v| render_config(v, scope))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| {
+                render_config(v, scope)
+                    .map(|v| (k.clone(), v))
+                    .map_err(|e| e.at(k))
+            })
+            .collect::<Result<Map<_, _>, _>>()
+            .map(Value::Object),
+        other => Ok(other.clone()),
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn eval_expression(expr: &str, scope: &TemplateScope) -> Result<Value, TemplateError> {
+    let mut parts = split_pipes(expr).into_iter();
+    let var = parts.next().unwrap_or_default();
+    let var = var.trim();
+    if var.is_empty() {
+        return Err(TemplateError::EmptyExpression);
+    }
+    // An unknown variable is Null when a `default` filter follows it.
+    let filters: Vec<String> = parts.collect();
+    let has_default = filters
+        .iter()
+        .any(|f| f.trim_start().starts_with("default"));
+    let mut value = match scope.lookup(var) {
+        Some(v) => v.clone(),
+        None if has_default => Value::Null,
+        None => return Err(TemplateError::UnknownVariable(var.to_string())),
+    };
+    for filter in filters {
+        let (name, args) = parse_filter(filter.trim())?;
+        value = apply_filter(&name, &value, &args)?;
+    }
+    Ok(value)
+}
+
+/// Split on `|` outside quotes.
+fn split_pipes(expr: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for c in expr.chars() {
+        match (c, quote) {
+            ('\'' | '"', None) => {
+                quote = Some(c);
+                current.push(c);
+            }
+            (c, Some(q)) if c == q => {
+                quote = None;
+                current.push(c);
+            }
+            ('|', None) => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
+fn parse_filter(spec: &str) -> Result<(String, Vec<Value>), TemplateError> {
+    let Some(open) = spec.find('(') else {
+        return Ok((spec.to_string(), Vec::new()));
+    };
+    if !spec.ends_with(')') {
+        return Err(TemplateError::MalformedFilter(spec.to_string()));
+    }
+    let name = spec[..open].trim().to_string();
+    let inner = &spec[open + 1..spec.len() - 1];
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for c in inner.chars() {
+        match (c, quote) {
+            ('\'' | '"', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (',', None) => args.push(parse_literal(&std::mem::take(&mut current), spec)?),
+            _ => current.push(c),
+        }
+    }
+    if quote.is_some() {
+        return Err(TemplateError::MalformedFilter(spec.to_string()));
+    }
+    if !current.trim().is_empty() || !args.is_empty() {
+        args.push(parse_literal(&current, spec)?);
+    }
+    Ok((name, args))
+}
+
+fn parse_literal(raw: &str, spec: &str) -> Result<Value, TemplateError> {
+    // Quotes were stripped by the caller; numbers stay numbers.
+    let trimmed = raw.trim();
+    if let Ok(n) = trimmed.parse::<i64>() {
+        return Ok(Value::from(n));
+    }
+    if let Ok(f) = trimmed.parse::<f64>() {
+        return Ok(Value::from(f));
+    }
+    if raw.is_empty() {
+        return Err(TemplateError::MalformedFilter(spec.to_string()));
+    }
+    Ok(Value::String(raw.to_string()))
+}
+
+fn apply_filter(name: &str, value: &Value, args: &[Value]) -> Result<Value, TemplateError> {
+    let custom = CUSTOM_FILTERS
+        .read()
+        .ok()
+        .and_then(|filters| filters.get(name).cloned());
+    if let Some(filter) = custom {
+        return filter(value, args).map_err(|message| TemplateError::FilterFailed {
+            name: name.to_string(),
+            message,
+        });
+    }
+
+    let text = || display(value);
+    let arg_str = |i: usize| args.get(i).map(display);
+    match name {
+        "upper" => Ok(Value::String(text().to_uppercase())),
+        "lower" => Ok(Value::String(text().to_lowercase())),
+        "trim" => Ok(Value::String(text().trim().to_string())),
+        "json" => Ok(Value::String(value.to_string())),
+        "default" => Ok(match value {
+            Value::Null => args.first().cloned().unwrap_or(Value::Null),
+            Value::String(s) if s.is_empty() => args.first().cloned().unwrap_or(Value::Null),
+            other => other.clone(),
+        }),
+        "replace" => match (arg_str(0), arg_str(1)) {
+            (Some(from), Some(to)) => Ok(Value::String(text().replace(&from, &to))),
+            _ => Err(TemplateError::FilterFailed {
+                name: name.to_string(),
+                message: "expects two arguments".to_string(),
+            }),
+        },
+        other => Err(TemplateError::UnknownFilter(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn meta() -> FireMetadata {
+        FireMetadata {
+            fire_time: Utc.with_ymd_and_hms(2026, 3, 9, 6, 30, 0).unwrap(),
+            workflow_name: "nightly_export".into(),
+            schedule_id: Some("sched-1".into()),
+            schedule_timezone: Some("UTC".into()),
+            trigger_name: None,
+        }
+    }
+
+    fn ctx(pairs: &[(&str, Value)]) -> Context<Value> {
+        let mut c = Context::new();
+        for (k, v) in pairs {
+            c.insert(*k, v.clone()).unwrap();
+        }
+        c
+    }
+
+    #[test]
+    fn renders_metadata_and_context() {
+        let c = ctx(&[("region", json!("eu"))]);
+        let scope = TemplateScope::new(&meta(), &c);
+        assert_eq!(
+            render_str(
+                "s3://bucket/{{ ds }}/{{ workflow.name }}-{{ context.region | upper }}.parquet",
+                &scope
+            )
+            .unwrap(),
+            "s3://bucket/2026-03-09/nightly_export-EU.parquet"
+        );
+    }
+
+    #[test]
+    fn single_expression_keeps_type() {
+        let c = ctx(&[("limits", json!({"rows": 500}))]);
+        let scope = TemplateScope::new(&meta(), &c);
+        assert_eq!(
+            render_value("{{ context.limits.rows }}", &scope).unwrap(),
+            json!(500)
+        );
+    }
+
+    #[test]
+    fn default_and_escape() {
+        let scope = TemplateScope::new(&meta(), &ctx(&[]));
+        assert_eq!(
+            render_str("{{ context.label | default('none') }}", &scope).unwrap(),
+            "none"
+        );
+        assert_eq!(render_str(r"\{{ ds }}", &scope).unwrap(), "{{ ds }}");
+    }
+
+    #[test]
+    fn unknown_variable_is_an_error() {
+        let scope = TemplateScope::new(&meta(), &ctx(&[]));
+        assert_eq!(
+            render_str("{{ nope }}", &scope),
+            Err(TemplateError::UnknownVariable("nope".into()))
+        );
+    }
+
+    #[test]
+    fn custom_filter() {
+        register_filter("test_slug", |v, _| {
+            Ok(json!(v.as_str().unwrap_or_default().replace(' ', "-")))
+        });
+        let c = ctx(&[("title", json!("big report"))]);
+        let scope = TemplateScope::new(&meta(), &c);
+        assert_eq!(
+            render_str("{{ context.title | test_slug }}", &scope).unwrap(),
+            "big-report"
+        );
+    }
+
+    #[test]
+    fn bare_names_do_not_reach_the_context() {
+        let scope = TemplateScope::new(&meta(), &ctx(&[("region", json!("eu"))]));
+        assert_eq!(
+            render_str("{{ region }}", &scope),
+            Err(TemplateError::UnknownVariable("region".into()))
+        );
+    }
+
+    #[test]
+    fn dates_use_the_schedule_timezone() {
+        // 06:30 UTC on the 9th is still the 8th in Los Angeles.
+        let mut m = meta();
+        m.schedule_timezone = Some("America/Los_Angeles".into());
+        let scope = TemplateScope::new(&m, &ctx(&[]));
+        assert_eq!(render_str("{{ ds }}", &scope).unwrap(), "2026-03-08");
+        assert_eq!(render_str("{{ ds_nodash }}", &scope).unwrap(), "20260308");
+        assert_eq!(
+            render_str("{{ ts }}", &scope).unwrap(),
+            "2026-03-08T23:30:00-07:00"
+        );
+    }
+
+    #[test]
+    fn render_params_only_renders_markers() {
+        let params = json!({
+            "path": {"$template": "s3://bucket/{{ ds_nodash }}/{{ context.region }}"},
+            "region": "eu",
+            "literal": "{{ ds }}",
+        })
+        .to_string();
+        let payload = ctx(&[("note", json!("{{ ds }}"))]);
+        let rendered: Value =
+            serde_json::from_str(&render_params(&params, &meta(), &payload).unwrap()).unwrap();
+        assert_eq!(rendered["path"], json!("s3://bucket/20260309/eu"));
+        assert_eq!(
+            rendered["literal"],
+            json!("{{ ds }}"),
+            "plain params are not rendered"
+        );
+    }
+
+    #[test]
+    fn payload_values_are_inserted_verbatim() {
+        let params = json!({"echo": {"$template": "{{ context.note }}"}}).to_string();
+        let payload = ctx(&[("note", json!("{{ schedule.id }}"))]);
+        let rendered: Value =
+            serde_json::from_str(&render_params(&params, &meta(), &payload).unwrap()).unwrap();
+        assert_eq!(rendered["echo"], json!("{{ schedule.id }}"));
+    }
+
+    #[test]
+    fn render_params_fails_on_bad_templates() {
+        let params = json!({"path": {"$template": "{{ not_a_var }}"}}).to_string();
+        assert_eq!(
+            render_params(&params, &meta(), &ctx(&[])),
+            Err(TemplateError::UnknownVariable("not_a_var".into()).at("path"))
+        );
+        let malformed = json!({"path": {"$template": 3}}).to_string();
+        assert!(matches!(
+            render_params(&malformed, &meta(), &ctx(&[])),
+            Err(TemplateError::Key { .. })
+        ));
+    }
+
+    #[test]
+    fn render_config_renders_nested_strings() {
+        let config = json!({"target": {"paths": ["{{ ds }}", 7]}, "name": "static"});
+        let scope = TemplateScope::new(&meta(), &ctx(&[]));
+        assert_eq!(
+            render_config(&config, &scope).unwrap(),
+            json!({"target": {"paths": ["2026-03-09", 7]}, "name": "static"})
+        );
+    }
+}
@@ -0,0 +1,389 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Declarative trigger conditions.
+//!
+//! A trigger schedule can carry a CEL condition and a list of probes, stored
+//! in the `schedules` row so they can be changed at runtime. Each poll runs
+//! the probes and evaluates the condition with these variables:
+//!
+//! | Variable | Value |
+//! |---|---|
+//! | `context` | The registered trigger's fired context (empty without one) |
+//! | `probes` | Map of probe name to probe result |
+//! | `trigger` | The trigger name |
+//!
+//! With a registered trigger, the condition gates its firings. Without one,
+//! the schedule is a declarative trigger that fires whenever the condition
+//! holds. Either way the probe results are added to the fired context under
+//! `probes`.
+//!
+//! Probes never fail a poll; a probe that cannot run reports the reason in
+//! its result (`error`).
+//!
+//! | Kind | Definition | Result |
+//! |---|---|---|
+//! | `file` | `path` | `exists`, `size_bytes`, `modified_at`, `age_secs` |
+//! | `env` | `var` | `set`, `value` |
+//! | `last_execution` | `workflow`, optional `status` | `found`, `status`, `started_at`, `completed_at`, `age_secs` |
+//! | `http` | `url`, optional `timeout_ms` | `ok`, `status`, `body` (`trigger-http-probes` feature) |
+//!
+//! ```text
+//! condition: probes.export.exists && probes.export.age_secs > 60
+//!            && probes.upstream.status == "Completed"
+//! probes:    [{"name": "export", "kind": "file", "path": "/data/export.csv"},
+//!             {"name": "upstream", "kind": "last_execution", "workflow": "extract"}]
+//! ```
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use super::TriggerError;
+use crate::dal::unified::workflow_execution::ExecutionListFilter;
+use crate::dal::DAL;
+use crate::Context;
+
+/// Context key holding probe results in a conditioned firing.
+pub const PROBES_KEY: &str = "probes";
+
+/// Default timeout of an `http` probe.
+pub const DEFAULT_HTTP_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A named probe whose result a trigger condition can read as
+/// `probes.<name>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TriggerProbe {
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: ProbeKind,
+}
+
+/// What a [`TriggerProbe`] checks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProbeKind {
+    /// Metadata of a file on the scheduler's host.
+    File { path: String },
+    /// An environment variable of the scheduler process.
+    Env { var: String },
+    /// The most recent execution of a workflow, optionally only those with
+    /// `status` (e.g. `Completed`).
+    LastExecution {
+        workflow: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        status: Option<String>,
+    },
+    /// An HTTP GET (`trigger-http-probes` feature).
+    Http {
+        url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+    },
+}
+
+fn invalid(message: impl Into<String>) -> TriggerError {
+    TriggerError::InvalidCondition {
+        message: message.into(),
+    }
+}
+
+/// Check that `expression` compiles and `probes` are well formed: named,
+/// uniquely, and of a kind this build can run.
+pub fn validate_trigger_condition(
+    expression: &str,
+    probes: &[TriggerProbe],
+) -> Result<(), TriggerError> {
+    if expression.trim().is_empty() {
+        return Err(invalid("condition is empty"));
+    }
+    // The CEL parser panics on some malformed input (e.g. a trailing `&&`)
+    // instead of returning an error, so treat a panic as a compile failure.
+    std::panic::catch_unwind(|| cel_interpreter::Program::compile(expression))
+        .map_err(|_| invalid("condition does not compile"))?
+        .map_err(|e| invalid(format!("condition does not compile: {}", e)))?;
+
+    let mut names = HashSet::new();
+    for probe in probes {
+        if probe.name.is_empty() {
+            return Err(invalid("probe name is empty"));
+        }
+        if !names.insert(probe.name.as_str()) {
+            return Err(invalid(format!("duplicate probe '{}'", probe.name)));
+        }
+        if matches!(probe.kind, ProbeKind::Http { .. }) && !cfg!(feature = "trigger-http-probes") {
+            return Err(invalid(format!(
+                "probe '{}': http probes need the `trigger-http-probes` feature",
+                probe.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Parse stored probes (a JSON array); `None` is no probes.
+pub fn parse_trigger_probes(json: Option<&str>) -> Result<Vec<TriggerProbe>, TriggerError> {
+    match json {
+        None => Ok(Vec::new()),
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| invalid(format!("invalid probe definitions: {}", e))),
+    }
+}
+
+/// Storage form of `probes`; `None` when there are none.
+pub fn trigger_probes_json(probes: &[TriggerProbe]) -> Option<String> {
+    if probes.is_empty() {
+        return None;
+    }
+    serde_json::to_string(probes).ok()
+}
+
+/// Run every probe, returning results by probe name.
+pub(crate) async fn run_probes(dal: &DAL, probes: &[TriggerProbe]) -> Map<String, Value> {
+    let mut results = Map::new();
+    for probe in probes {
+        let result = match &probe.kind {
+            ProbeKind::File { path } => file_probe(path).await,
+            ProbeKind::Env { var } => match std::env::var(var) {
+                Ok(value) => json!({ "set": true, "value": value }),
+                Err(_) => json!({ "set": false }),
+            },
+            ProbeKind::LastExecution { workflow, status } => {
+                last_execution_probe(dal, workflow, status.as_deref()).await
+            }
+            ProbeKind::Http { url, timeout_ms } => {
+                let timeout = timeout_ms
+                    .map(Duration::from_millis)
+                    .unwrap_or(DEFAULT_HTTP_PROBE_TIMEOUT);
+                http_probe(url, timeout).await
+            }
+        };
+        results.insert(probe.name.clone(), result);
+    }
+    results
+}
+
+fn age_secs(at: DateTime<Utc>) -> i64 {
+    (Utc::now() - at).num_seconds()
+}
+
+async fn file_probe(path: &str) -> Value {
+    match tokio::fs::metadata(path).await {
+        Ok(metadata) => {
+            let mut result = json!({ "exists": true, "size_bytes": metadata.len() });
+            if let Ok(modified) = metadata.modified() {
+                let modified: DateTime<Utc> = modified.into();
+                result["modified_at"] = json!(modified.to_rfc3339());
+                result["age_secs"] = json!(age_secs(modified));
+            }
+            result
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => json!({ "exists": false }),
+        Err(e) => json!({ "exists": false, "error": e.to_string() }),
+    }
+}
+
+async fn last_execution_probe(dal: &DAL, workflow: &str, status: Option<&str>) -> Value {
+    let latest = dal
+        .workflow_execution()
+        .list_filtered(ExecutionListFilter {
+            status: status.map(str::to_string),
+            workflow_name: Some(workflow.to_string()),
+            limit: 1,
+            ..Default::default()
+        })
+        .await;
+    match latest {
+        Ok(executions) => match executions.into_iter().next() {
+            Some(execution) => {
+                let finished = execution.completed_at.unwrap_or(execution.started_at);
+                json!({
+                    "found": true,
+                    "status": execution.status,
+                    "started_at": execution.started_at.0.to_rfc3339(),
+                    "completed_at": execution.completed_at.map(|at| at.0.to_rfc3339()),
+                    "age_secs": age_secs(finished.0),
+                })
+            }
+            None => json!({ "found": false }),
+        },
+        Err(e) => json!({ "found": false, "error": e.to_string() }),
+    }
+}
+
+#[cfg(feature = "trigger-http-probes")]
+async fn http_probe(url: &str, timeout: Duration) -> Value {
+    /// Longest body kept in the result.
+    const MAX_BODY_BYTES: usize = 64 * 1024;
+
+    let client = match reqwest::Client::builder().timeout(timeout).build() {
+        Ok(client) => client,
+        Err(e) => return json!({ "ok": false, "error": e.to_string() }),
+    };
+    match client.get(url).send().await {
+        Ok(response) => {
+            let status = response.status();
+            let body = match response.bytes().await {
+                Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|_| {
+                    let end = bytes.len().min(MAX_BODY_BYTES);
+                    Value::String(String::from_utf8_lossy(&bytes[..end]).into_owned())
+                }),
+                Err(_) => Value::Null,
+            };
+            json!({ "ok": status.is_success(), "status": status.as_u16(), "body": body })
+        }
+        Err(e) => json!({ "ok": false, "error": e.to_string() }),
+    }
+}
+
+#[cfg(not(feature = "trigger-http-probes"))]
+async fn http_probe(_url: &str, _timeout: Duration) -> Value {
+    json!({ "ok": false, "error": "http probes need the `trigger-http-probes` feature" })
+}
+
+/// Evaluate a compiled condition against a fired context and probe results.
+pub(crate) fn eval_trigger_condition(
+    program: &cel_interpreter::Program,
+    trigger_name: &str,
+    context: &Context<Value>,
+    probes: &Map<String, Value>,
+) -> Result<bool, String> {
+    use cel_interpreter::{Context as CelContext, Value as CelValue};
+
+    let mut cel_ctx = CelContext::default();
+    let fired: Map<String, Value> = context
+        .data()
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    cel_ctx
+        .add_variable("context", Value::Object(fired))
+        .map_err(|e| format!("cel add_variable(context): {}", e))?;
+    cel_ctx
+        .add_variable(PROBES_KEY, Value::Object(probes.clone()))
+        .map_err(|e| format!("cel add_variable(probes): {}", e))?;
+    cel_ctx
+        .add_variable("trigger", trigger_name)
+        .map_err(|e| format!("cel add_variable(trigger): {}", e))?;
+
+    match program.execute(&cel_ctx) {
+        Ok(CelValue::Bool(b)) => Ok(b),
+        Ok(other) => Err(format!("condition must evaluate to bool, got {:?}", other)),
+        Err(e) => Err(format!("eval error: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(name: &str, kind: ProbeKind) -> TriggerProbe {
+        TriggerProbe {
+            name: name.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_probe_definitions_round_trip() {
+        let json = r#"[{"name":"flag","kind":"file","path":"/tmp/flag"},
+            {"name":"up","kind":"last_execution","workflow":"extract","status":"Completed"}]"#;
+        let probes = parse_trigger_probes(Some(json)).unwrap();
+        assert_eq!(
+            probes,
+            vec![
+                probe(
+                    "flag",
+                    ProbeKind::File {
+                        path: "/tmp/flag".into()
+                    }
+                ),
+                probe(
+                    "up",
+                    ProbeKind::LastExecution {
+                        workflow: "extract".into(),
+                        status: Some("Completed".into()),
+                    }
+                ),
+            ]
+        );
+        let stored = trigger_probes_json(&probes).unwrap();
+        assert_eq!(parse_trigger_probes(Some(&stored)).unwrap(), probes);
+        assert!(trigger_probes_json(&[]).is_none());
+        assert!(parse_trigger_probes(Some(r#"[{"name":"x","kind":"ftp"}]"#)).is_err());
+    }
+
+    #[test]
+    fn test_validation_rejects_bad_conditions_and_probes() {
+        let env = |name: &str| probe(name, ProbeKind::Env { var: "HOME".into() });
+        assert!(validate_trigger_condition("probes.a.set", &[env("a")]).is_ok());
+        assert!(validate_trigger_condition("  ", &[]).is_err());
+        assert!(validate_trigger_condition("probes.a.set &&", &[env("a")]).is_err());
+        assert!(validate_trigger_condition("true", &[env("a"), env("a")]).is_err());
+        assert!(validate_trigger_condition("true", &[env("")]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_condition_reads_context_and_file_probe() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ready.flag");
+        std::fs::write(&path, b"ok").unwrap();
+        let probes = [
+            probe(
+                "ready",
+                ProbeKind::File {
+                    path: path.to_string_lossy().into_owned(),
+                },
+            ),
+            probe(
+                "missing",
+                ProbeKind::File {
+                    path: dir.path().join("nope").to_string_lossy().into_owned(),
+                },
+            ),
+        ];
+        // File probes need no database.
+        let mut results = Map::new();
+        for p in &probes {
+            if let ProbeKind::File { path } = &p.kind {
+                results.insert(p.name.clone(), file_probe(path).await);
+            }
+        }
+        assert_eq!(results["ready"]["size_bytes"], json!(2));
+
+        let mut context = Context::new();
+        context.insert("rows", json!(12)).unwrap();
+        let program = cel_interpreter::Program::compile(
+            "probes.ready.exists && !probes.missing.exists && context.rows > 10 \
+             && trigger == 'ingest'",
+        )
+        .unwrap();
+        assert_eq!(
+            eval_trigger_condition(&program, "ingest", &context, &results),
+            Ok(true)
+        );
+        assert_eq!(
+            eval_trigger_condition(&program, "other", &context, &results),
+            Ok(false)
+        );
+
+        let not_bool = cel_interpreter::Program::compile("context.rows").unwrap();
+        assert!(eval_trigger_condition(&not_bool, "ingest", &context, &results).is_err());
+    }
+}
@@ -0,0 +1,451 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Built-in filesystem watch trigger (`file-watch` feature).
+//!
+//! [`FileWatchTrigger`] subscribes to the OS's file change notifications
+//! (inotify, FSEvents, kqueue or ReadDirectoryChangesW via `notify`) instead
+//! of listing a directory on every poll. Changes are queued as they arrive;
+//! each poll fires for the oldest queued file whose path matches one of the
+//! trigger's glob patterns. Repeated changes to a queued file are coalesced
+//! into one firing.
+//!
+//! The fired context carries:
+//!
+//! | Key | Value |
+//! |---|---|
+//! | `path` | Absolute path of the file |
+//! | `relative_path` | Path relative to the watched directory |
+//! | `file_name` | Final path component |
+//! | `event` | `created`, `modified` or `removed` |
+//! | `size_bytes` | File size (not for `removed`) |
+//! | `modified_at` | RFC 3339 modification time (not for `removed`) |
+//!
+//! ```rust,ignore
+//! use cloacina::trigger::{FileChange, FileWatchTrigger};
+//!
+//! let trigger = Arc::new(
+//!     FileWatchTrigger::builder("inbox_watcher", "/data/inbox")
+//!         .pattern("**/*.csv")
+//!         .recursive(true)
+//!         .build()?,
+//! );
+//! let registered = trigger.clone();
+//! runner.runtime().register_trigger("inbox_watcher".into(), move || registered.clone());
+//! scheduler.register_trigger(trigger.as_ref(), "ingest_csv").await?;
+//! ```
+//!
+//! Changes made while no process is watching are not seen. Set dedup keys to
+//! `["path"]` to fire at most once per file.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use glob::{MatchOptions, Pattern};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use parking_lot::Mutex;
+use tracing::{debug, warn};
+
+use super::{Trigger, TriggerBatch, TriggerError, TriggerResult};
+use crate::Context;
+
+/// Default poll interval of a [`FileWatchTrigger`].
+pub const DEFAULT_FILE_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Kind of change a [`FileWatchTrigger`] fires for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileChange {
+    Created,
+    Modified,
+    Removed,
+}
+
+impl FileChange {
+    /// The value of the fired context's `event` key.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileChange::Created => "created",
+            FileChange::Modified => "modified",
+            FileChange::Removed => "removed",
+        }
+    }
+
+    fn from_kind(kind: &EventKind) -> Option<Self> {
+        match kind {
+            EventKind::Create(_) => Some(FileChange::Created),
+            EventKind::Modify(_) => Some(FileChange::Modified),
+            EventKind::Remove(_) => Some(FileChange::Removed),
+            _ => None,
+        }
+    }
+}
+
+/// Queued changes, oldest first, one entry per path.
+#[derive(Debug, Default)]
+struct PendingChanges {
+    changes: VecDeque<(PathBuf, FileChange)>,
+}
+
+impl PendingChanges {
+    /// Queue a change, folding it into an already queued change of the same
+    /// path: a file created and then written is still `created`; a removal
+    /// replaces whatever was queued.
+    fn push(&mut self, path: PathBuf, change: FileChange) {
+        if let Some((_, queued)) = self.changes.iter_mut().find(|(p, _)| *p == path) {
+            *queued = match (*queued, change) {
+                (_, FileChange::Removed) => FileChange::Removed,
+                (FileChange::Removed, _) => FileChange::Created,
+                (queued, _) => queued,
+            };
+            return;
+        }
+        self.changes.push_back((path, change));
+    }
+
+    fn pop(&mut self) -> Option<(PathBuf, FileChange)> {
+        self.changes.pop_front()
+    }
+}
+
+/// Builder for a [`FileWatchTrigger`].
+#[derive(Debug)]
+pub struct FileWatchTriggerBuilder {
+    name: String,
+    root: PathBuf,
+    patterns: Vec<String>,
+    changes: Vec<FileChange>,
+    recursive: bool,
+    poll_interval: Duration,
+    allow_concurrent: bool,
+    batch: Option<TriggerBatch>,
+}
+
+impl FileWatchTriggerBuilder {
+    /// Only fire for paths (relative to the watched directory) matching this
+    /// glob, e.g. `*.csv` or `**/*.json`. Repeatable; with none, every file
+    /// matches. `*` does not cross directory separators; `**` does.
+    pub fn pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    /// Kinds of change to fire for. Default: created and modified.
+    pub fn changes(mut self, changes: &[FileChange]) -> Self {
+        self.changes = changes.to_vec();
+        self
+    }
+
+    /// Also watch subdirectories. Default: `false`.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// How often the scheduler takes the next queued change. Default: 1s.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Allow a firing while an execution with the same context hash runs.
+    /// Default: `false`.
+    pub fn allow_concurrent(mut self, allow: bool) -> Self {
+        self.allow_concurrent = allow;
+        self
+    }
+
+    /// Gather changes into one execution per batch (see [`TriggerBatch`]).
+    pub fn batch(mut self, batch: TriggerBatch) -> Self {
+        self.batch = Some(batch);
+        self
+    }
+
+    /// Validate the patterns and start watching the directory.
+    pub fn build(self) -> Result<FileWatchTrigger, TriggerError> {
+        let patterns = self
+            .patterns
+            .iter()
+            .map(|p| {
+                Pattern::new(p).map_err(|e| TriggerError::PollError {
+                    message: format!("invalid glob pattern '{}': {}", p, e),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let root = std::fs::canonicalize(&self.root).map_err(|e| TriggerError::PollError {
+            message: format!("cannot watch '{}': {}", self.root.display(), e),
+        })?;
+
+        let pending = Arc::new(Mutex::new(PendingChanges::default()));
+        let sink = pending.clone();
+        let trigger_name = self.name.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |res: Result<Event, notify::Error>| match res {
+                Ok(event) => {
+                    if let Some(change) = FileChange::from_kind(&event.kind) {
+                        let mut pending = sink.lock();
+                        for path in event.paths {
+                            pending.push(path, change);
+                        }
+                    }
+                }
+                Err(e) => warn!("File watch trigger '{}' error: {}", trigger_name, e),
+            })
+            .map_err(|e| TriggerError::PollError {
+                message: format!("cannot start file watcher: {}", e),
+            })?;
+        let mode = if self.recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        watcher
+            .watch(&root, mode)
+            .map_err(|e| TriggerError::PollError {
+                message: format!("cannot watch '{}': {}", root.display(), e),
+            })?;
+
+        Ok(FileWatchTrigger {
+            name: self.name,
+            root,
+            patterns,
+            changes: self.changes,
+            poll_interval: self.poll_interval,
+            allow_concurrent: self.allow_concurrent,
+            batch: self.batch,
+            pending,
+            _watcher: watcher,
+        })
+    }
+}
+
+/// Trigger that fires when files matching a glob change in a directory.
+///
+/// The watch starts at [`FileWatchTriggerBuilder::build`] and stops when the
+/// trigger is dropped, so register one shared instance (an `Arc`) rather
+/// than building a new one per runtime lookup.
+pub struct FileWatchTrigger {
+    name: String,
+    root: PathBuf,
+    patterns: Vec<Pattern>,
+    changes: Vec<FileChange>,
+    poll_interval: Duration,
+    allow_concurrent: bool,
+    batch: Option<TriggerBatch>,
+    pending: Arc<Mutex<PendingChanges>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatchTrigger {
+    /// Start building a trigger named `name` that watches the directory
+    /// `root`.
+    pub fn builder(name: impl Into<String>, root: impl Into<PathBuf>) -> FileWatchTriggerBuilder {
+        FileWatchTriggerBuilder {
+            name: name.into(),
+            root: root.into(),
+            patterns: Vec::new(),
+            changes: vec![FileChange::Created, FileChange::Modified],
+            recursive: false,
+            poll_interval: DEFAULT_FILE_WATCH_POLL_INTERVAL,
+            allow_concurrent: false,
+            batch: None,
+        }
+    }
+
+    /// The watched directory (canonicalized).
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Whether a change to `path` fires this trigger.
+    fn matches(&self, path: &Path, change: FileChange) -> bool {
+        if !self.changes.contains(&change) {
+            return false;
+        }
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        self.patterns.is_empty()
+            || self
+                .patterns
+                .iter()
+                .any(|p| p.matches_path_with(relative, options))
+    }
+
+    /// Context for a change, or `None` when the file is gone or is a
+    /// directory by the time the change is taken.
+    fn change_context(
+        &self,
+        path: &Path,
+        change: FileChange,
+    ) -> Result<Option<Context<serde_json::Value>>, cloacina_workflow::TriggerError> {
+        let mut ctx = Context::new();
+        if change != FileChange::Removed {
+            let Ok(metadata) = std::fs::metadata(path) else {
+                return Ok(None);
+            };
+            if metadata.is_dir() {
+                return Ok(None);
+            }
+            ctx.insert("size_bytes", serde_json::json!(metadata.len()))?;
+            if let Ok(modified) = metadata.modified() {
+                let modified: DateTime<Utc> = modified.into();
+                ctx.insert("modified_at", serde_json::json!(modified.to_rfc3339()))?;
+            }
+        }
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        ctx.insert("path", serde_json::json!(path.to_string_lossy()))?;
+        ctx.insert(
+            "relative_path",
+            serde_json::json!(relative.to_string_lossy()),
+        )?;
+        ctx.insert(
+            "file_name",
+            serde_json::json!(path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default()),
+        )?;
+        ctx.insert("event", serde_json::json!(change.as_str()))?;
+        Ok(Some(ctx))
+    }
+}
+
+impl fmt::Debug for FileWatchTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileWatchTrigger")
+            .field("name", &self.name)
+            .field("root", &self.root)
+            .field("patterns", &self.patterns)
+            .field("changes", &self.changes)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Trigger for FileWatchTrigger {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    fn allow_concurrent(&self) -> bool {
+        self.allow_concurrent
+    }
+
+    fn batch(&self) -> Option<TriggerBatch> {
+        self.batch
+    }
+
+    async fn poll(&self) -> Result<TriggerResult, cloacina_workflow::TriggerError> {
+        loop {
+            let Some((path, change)) = self.pending.lock().pop() else {
+                return Ok(TriggerResult::Skip);
+            };
+            if !self.matches(&path, change) {
+                continue;
+            }
+            if let Some(ctx) = self.change_context(&path, change)? {
+                debug!(
+                    "File watch trigger '{}': {} {}",
+                    self.name,
+                    change.as_str(),
+                    path.display()
+                );
+                return Ok(TriggerResult::Fire(Some(ctx)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_changes_coalesce_per_path() {
+        let mut pending = PendingChanges::default();
+        pending.push("/in/a.csv".into(), FileChange::Created);
+        pending.push("/in/b.csv".into(), FileChange::Modified);
+        pending.push("/in/a.csv".into(), FileChange::Modified);
+        pending.push("/in/b.csv".into(), FileChange::Removed);
+
+        assert_eq!(
+            pending.pop(),
+            Some(("/in/a.csv".into(), FileChange::Created))
+        );
+        assert_eq!(
+            pending.pop(),
+            Some(("/in/b.csv".into(), FileChange::Removed))
+        );
+        assert_eq!(pending.pop(), None);
+    }
+
+    #[tokio::test]
+    async fn test_fires_for_matching_files_with_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let trigger = FileWatchTrigger::builder("inbox", dir.path())
+            .pattern("*.csv")
+            .build()
+            .unwrap();
+
+        std::fs::write(dir.path().join("skip.txt"), b"x").unwrap();
+        std::fs::write(dir.path().join("data.csv"), b"a,b\n1,2\n").unwrap();
+
+        let mut fired = None;
+        for _ in 0..50 {
+            if let TriggerResult::Fire(ctx) = trigger.poll().await.unwrap() {
+                fired = ctx;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        let ctx = fired.expect("trigger should fire for data.csv");
+        assert_eq!(ctx.get("file_name"), Some(&serde_json::json!("data.csv")));
+        assert_eq!(
+            ctx.get("relative_path"),
+            Some(&serde_json::json!("data.csv"))
+        );
+        assert_eq!(ctx.get("size_bytes"), Some(&serde_json::json!(8)));
+        assert!(ctx.get("modified_at").is_some());
+
+        // Later writes to data.csv may still fire; the .txt change never does.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        while let TriggerResult::Fire(Some(ctx)) = trigger.poll().await.unwrap() {
+            assert_eq!(ctx.get("file_name"), Some(&serde_json::json!("data.csv")));
+        }
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(FileWatchTrigger::builder("bad", dir.path())
+            .pattern("[")
+            .build()
+            .is_err());
+    }
+}
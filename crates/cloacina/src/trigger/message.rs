@@ -0,0 +1,586 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Message queue triggers.
+//!
+//! [`MessageTrigger`] fires a workflow for messages pulled from a
+//! [`MessageSource`]. Core carries no broker clients; the Kafka, NATS
+//! JetStream and AMQP sources live in the `cloacina-mq-triggers` crate, each
+//! behind its own cargo feature, and any other broker can implement
+//! [`MessageSource`] directly.
+//!
+//! A message is only acknowledged once the workflow it started finishes:
+//! register the trigger with
+//! [`DefaultRunner::register_message_trigger`](crate::runner::DefaultRunner::register_message_trigger),
+//! which acks on `WorkflowCompleted` and nacks (for redelivery) on
+//! `WorkflowFailed`. A firing that never becomes an execution — the
+//! schedule's condition is false, debouncing replaces it, it is suppressed
+//! as a duplicate or rate limited, the handoff fails — is nacked when the
+//! scheduler discards it. Messages whose outcome is never seen because the
+//! process stops stay unacknowledged and are redelivered by the broker, so
+//! delivery is at least once. The delivery ids are read
+//! back from the execution's context, so tasks must not remove `message` or
+//! `messages`.
+//!
+//! One message fires one execution. The payload is parsed as JSON: an
+//! object's keys become context keys (except `message` and the keys the
+//! scheduler and runtime reserve, which are dropped), anything else is stored under
+//! `payload` (bytes that are not JSON as a UTF-8 string). The message
+//! metadata is stored under `message`:
+//!
+//! | Key | Value |
+//! |---|---|
+//! | `message.delivery_id` | Id used to settle the message |
+//! | `message.source` | Topic, subject or queue it came from |
+//! | `message.key` | Message key, if any |
+//! | `message.position` | Broker position (`partition:offset`, stream sequence, ...) |
+//! | `message.headers` | Header map, if any |
+//!
+//! With [`MessageTriggerBuilder::batch_size`] above 1, a poll takes up to
+//! that many waiting messages and fires once with `messages` (a list of
+//! `{payload, message}` objects) and `message_count`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use thiserror::Error;
+use tracing::{debug, warn};
+
+use super::{Trigger, TriggerResult};
+use crate::workflow_instance::is_reserved_payload_key;
+use crate::Context;
+
+/// Default poll interval of a [`MessageTrigger`].
+pub const DEFAULT_MESSAGE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Context key holding a message's metadata.
+pub const MESSAGE_KEY: &str = "message";
+
+/// Context key holding the messages of a batched firing.
+pub const MESSAGES_KEY: &str = "messages";
+
+/// Error reported by a [`MessageSource`].
+#[derive(Debug, Error)]
+#[error("message source error: {0}")]
+pub struct MessageSourceError(pub String);
+
+/// A message received from a broker, before it is settled.
+#[derive(Debug, Clone, Default)]
+pub struct MessageEnvelope {
+    pub payload: Vec<u8>,
+    /// Topic, subject or queue the message came from
+    pub source: String,
+    pub key: Option<String>,
+    /// Broker position, e.g. `partition:offset`
+    pub position: Option<String>,
+    pub headers: BTreeMap<String, String>,
+}
+
+/// A broker connection a [`MessageTrigger`] pulls messages from.
+///
+/// Implementations must not acknowledge on receipt: each message is settled
+/// exactly once through [`ack`](Self::ack) or [`nack`](Self::nack) with the
+/// handle returned alongside it.
+#[async_trait]
+pub trait MessageSource: Send + Sync + 'static {
+    /// Broker handle needed to settle one message.
+    type Delivery: Send + 'static;
+
+    /// Receive up to `max` messages, waiting at most `wait` for the first.
+    /// An empty result means none arrived.
+    async fn receive(
+        &self,
+        max: usize,
+        wait: Duration,
+    ) -> Result<Vec<(MessageEnvelope, Self::Delivery)>, MessageSourceError>;
+
+    /// Mark a message processed.
+    async fn ack(&self, delivery: Self::Delivery) -> Result<(), MessageSourceError>;
+
+    /// Hand a message back for redelivery.
+    async fn nack(&self, delivery: Self::Delivery) -> Result<(), MessageSourceError>;
+
+    /// Short description for logs, e.g. `kafka orders@group`.
+    fn describe(&self) -> String;
+}
+
+/// Builder for a [`MessageTrigger`].
+pub struct MessageTriggerBuilder<S: MessageSource> {
+    name: String,
+    source: S,
+    batch_size: usize,
+    receive_wait: Duration,
+    poll_interval: Duration,
+    ack_on_failure: bool,
+}
+
+impl<S: MessageSource> MessageTriggerBuilder<S> {
+    /// Take up to `size` waiting messages per firing. Default: 1, one
+    /// execution per message.
+    pub fn batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size.max(1);
+        self
+    }
+
+    /// How long a poll waits for a message to arrive. Default: 50ms.
+    pub fn receive_wait(mut self, wait: Duration) -> Self {
+        self.receive_wait = wait;
+        self
+    }
+
+    /// How often the scheduler polls the source. Default: 100ms.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Acknowledge messages whose workflow failed instead of handing them
+    /// back for redelivery. Default: `false`.
+    pub fn ack_on_failure(mut self, ack: bool) -> Self {
+        self.ack_on_failure = ack;
+        self
+    }
+
+    pub fn build(self) -> MessageTrigger<S> {
+        MessageTrigger {
+            name: self.name,
+            source: self.source,
+            batch_size: self.batch_size,
+            receive_wait: self.receive_wait,
+            poll_interval: self.poll_interval,
+            ack_on_failure: self.ack_on_failure,
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// Trigger that fires a workflow for messages from a [`MessageSource`].
+///
+/// Received messages are held unacknowledged until
+/// [`settle`](Self::settle) is called with their delivery ids, so register
+/// one shared instance (an `Arc`) through
+/// [`DefaultRunner::register_message_trigger`](crate::runner::DefaultRunner::register_message_trigger).
+pub struct MessageTrigger<S: MessageSource> {
+    name: String,
+    source: S,
+    batch_size: usize,
+    receive_wait: Duration,
+    poll_interval: Duration,
+    ack_on_failure: bool,
+    in_flight: Mutex<HashMap<String, S::Delivery>>,
+}
+
+impl<S: MessageSource> MessageTrigger<S> {
+    /// Start building a trigger named `name` that consumes from `source`.
+    pub fn builder(name: impl Into<String>, source: S) -> MessageTriggerBuilder<S> {
+        MessageTriggerBuilder {
+            name: name.into(),
+            source,
+            batch_size: 1,
+            receive_wait: Duration::from_millis(50),
+            poll_interval: DEFAULT_MESSAGE_POLL_INTERVAL,
+            ack_on_failure: false,
+        }
+    }
+
+    /// The underlying source.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Number of received messages not yet settled.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().len()
+    }
+
+    /// Settle the messages behind `delivery_ids` once their workflow
+    /// finished: ack on success, nack on failure (unless
+    /// [`MessageTriggerBuilder::ack_on_failure`] is set). Ids this trigger
+    /// does not hold are ignored. Returns how many messages were settled.
+    pub async fn settle(&self, delivery_ids: &[String], succeeded: bool) -> usize {
+        let deliveries = self.take_in_flight(delivery_ids);
+        let settled = deliveries.len();
+        for delivery in deliveries {
+            let result = if succeeded || self.ack_on_failure {
+                self.source.ack(delivery).await
+            } else {
+                self.source.nack(delivery).await
+            };
+            if let Err(e) = result {
+                warn!("Message trigger '{}' failed to settle: {}", self.name, e);
+            }
+        }
+        settled
+    }
+
+    /// Nack the messages behind `delivery_ids`, whatever
+    /// [`MessageTriggerBuilder::ack_on_failure`] says: their firing never ran,
+    /// so the broker should redeliver them. Returns how many were released.
+    async fn release(&self, delivery_ids: &[String]) -> usize {
+        let deliveries = self.take_in_flight(delivery_ids);
+        let released = deliveries.len();
+        for delivery in deliveries {
+            if let Err(e) = self.source.nack(delivery).await {
+                warn!("Message trigger '{}' failed to release: {}", self.name, e);
+            }
+        }
+        released
+    }
+
+    /// Remove the deliveries behind `delivery_ids` from the in-flight set.
+    fn take_in_flight(&self, delivery_ids: &[String]) -> Vec<S::Delivery> {
+        let mut in_flight = self.in_flight.lock();
+        delivery_ids
+            .iter()
+            .filter_map(|id| in_flight.remove(id))
+            .collect()
+    }
+}
+
+impl<S: MessageSource> fmt::Debug for MessageTrigger<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageTrigger")
+            .field("name", &self.name)
+            .field("source", &self.source.describe())
+            .field("batch_size", &self.batch_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The JSON value of a message payload: parsed JSON, else the payload as a
+/// UTF-8 string (lossy), and `null` for an empty payload.
+fn payload_value(payload: &[u8]) -> serde_json::Value {
+    if payload.is_empty() {
+        return serde_json::Value::Null;
+    }
+    serde_json::from_slice(payload).unwrap_or_else(|_| {
+        serde_json::Value::String(String::from_utf8_lossy(payload).into_owned())
+    })
+}
+
+fn metadata_value(envelope: &MessageEnvelope, delivery_id: &str) -> serde_json::Value {
+    let mut metadata = serde_json::json!({
+        "delivery_id": delivery_id,
+        "source": envelope.source,
+    });
+    if let Some(key) = &envelope.key {
+        metadata["key"] = serde_json::json!(key);
+    }
+    if let Some(position) = &envelope.position {
+        metadata["position"] = serde_json::json!(position);
+    }
+    if !envelope.headers.is_empty() {
+        metadata["headers"] = serde_json::json!(envelope.headers);
+    }
+    metadata
+}
+
+/// Context for a single message.
+fn message_context(
+    envelope: &MessageEnvelope,
+    delivery_id: &str,
+) -> Result<Context<serde_json::Value>, cloacina_workflow::TriggerError> {
+    let mut ctx = Context::new();
+    match payload_value(&envelope.payload) {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields {
+                if key == MESSAGE_KEY || is_reserved_payload_key(&key) {
+                    debug!("Dropping reserved key '{}' from message payload", key);
+                    continue;
+                }
+                ctx.insert(key, value)?;
+            }
+        }
+        serde_json::Value::Null => {}
+        other => ctx.insert("payload", other)?,
+    }
+    ctx.insert(MESSAGE_KEY, metadata_value(envelope, delivery_id))?;
+    Ok(ctx)
+}
+
+/// Context for a batch of messages.
+fn batch_context(
+    messages: &[(MessageEnvelope, String)],
+) -> Result<Context<serde_json::Value>, cloacina_workflow::TriggerError> {
+    let entries: Vec<serde_json::Value> = messages
+        .iter()
+        .map(|(envelope, delivery_id)| {
+            serde_json::json!({
+                "payload": payload_value(&envelope.payload),
+                "message": metadata_value(envelope, delivery_id),
+            })
+        })
+        .collect();
+    let mut ctx = Context::new();
+    ctx.insert("message_count", serde_json::json!(entries.len()))?;
+    ctx.insert(MESSAGES_KEY, serde_json::Value::Array(entries))?;
+    Ok(ctx)
+}
+
+/// Delivery ids recorded in a fired context, single or batched.
+pub fn delivery_ids(context: &Context<serde_json::Value>) -> Vec<String> {
+    let id_of = |metadata: &serde_json::Value| {
+        metadata
+            .get("delivery_id")
+            .and_then(|id| id.as_str())
+            .map(str::to_string)
+    };
+    let mut ids: Vec<String> = context
+        .get(MESSAGE_KEY)
+        .and_then(id_of)
+        .into_iter()
+        .collect();
+    if let Some(serde_json::Value::Array(messages)) = context.get(MESSAGES_KEY) {
+        ids.extend(
+            messages
+                .iter()
+                .filter_map(|m| m.get(MESSAGE_KEY).and_then(id_of)),
+        );
+    }
+    ids
+}
+
+#[async_trait]
+impl<S: MessageSource> Trigger for MessageTrigger<S> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// Each message fires its own execution, even when two payloads are
+    /// identical.
+    fn allow_concurrent(&self) -> bool {
+        true
+    }
+
+    async fn poll(&self) -> Result<TriggerResult, cloacina_workflow::TriggerError> {
+        let received = self
+            .source
+            .receive(self.batch_size, self.receive_wait)
+            .await
+            .map_err(|e| cloacina_workflow::TriggerError::PollError {
+                message: e.to_string(),
+            })?;
+        if received.is_empty() {
+            return Ok(TriggerResult::Skip);
+        }
+
+        let mut messages = Vec::with_capacity(received.len());
+        {
+            let mut in_flight = self.in_flight.lock();
+            for (envelope, delivery) in received {
+                let delivery_id = uuid::Uuid::new_v4().to_string();
+                in_flight.insert(delivery_id.clone(), delivery);
+                messages.push((envelope, delivery_id));
+            }
+        }
+        debug!(
+            "Message trigger '{}' received {} message(s) from {}",
+            self.name,
+            messages.len(),
+            self.source.describe()
+        );
+
+        let ctx = if self.batch_size == 1 {
+            let (envelope, delivery_id) = &messages[0];
+            message_context(envelope, delivery_id)
+        } else {
+            batch_context(&messages)
+        };
+        match ctx {
+            Ok(ctx) => Ok(TriggerResult::Fire(Some(ctx))),
+            Err(e) => {
+                let ids: Vec<String> = messages.into_iter().map(|(_, id)| id).collect();
+                self.release(&ids).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Nacks the messages of a firing that will not run, so they do not
+    /// stay in flight and the broker redelivers them.
+    async fn discard(&self, context: &Context<serde_json::Value>) {
+        let released = self.release(&delivery_ids(context)).await;
+        if released > 0 {
+            debug!(
+                "Message trigger '{}' released {} message(s) of a discarded firing",
+                self.name, released
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// In-memory source recording how messages were settled.
+    #[derive(Default)]
+    struct MemorySource {
+        queue: Mutex<VecDeque<MessageEnvelope>>,
+        settled: Mutex<Vec<(String, bool)>>,
+    }
+
+    impl MemorySource {
+        fn with(payloads: &[&str]) -> Self {
+            let source = Self::default();
+            for (i, payload) in payloads.iter().enumerate() {
+                source.queue.lock().push_back(MessageEnvelope {
+                    payload: payload.as_bytes().to_vec(),
+                    source: "orders".into(),
+                    position: Some(format!("0:{}", i)),
+                    ..Default::default()
+                });
+            }
+            source
+        }
+    }
+
+    #[async_trait]
+    impl MessageSource for MemorySource {
+        type Delivery = String;
+
+        async fn receive(
+            &self,
+            max: usize,
+            _wait: Duration,
+        ) -> Result<Vec<(MessageEnvelope, String)>, MessageSourceError> {
+            let mut queue = self.queue.lock();
+            let n = max.min(queue.len());
+            Ok(queue
+                .drain(..n)
+                .map(|m| {
+                    let position = m.position.clone().unwrap_or_default();
+                    (m, position)
+                })
+                .collect())
+        }
+
+        async fn ack(&self, delivery: String) -> Result<(), MessageSourceError> {
+            self.settled.lock().push((delivery, true));
+            Ok(())
+        }
+
+        async fn nack(&self, delivery: String) -> Result<(), MessageSourceError> {
+            self.settled.lock().push((delivery, false));
+            Ok(())
+        }
+
+        fn describe(&self) -> String {
+            "memory".into()
+        }
+    }
+
+    fn fired(result: TriggerResult) -> Context<serde_json::Value> {
+        match result {
+            TriggerResult::Fire(Some(ctx)) => ctx,
+            _ => panic!("expected a firing with context"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fires_per_message_and_acks_on_success() {
+        let trigger =
+            MessageTrigger::builder("orders", MemorySource::with(&[r#"{"id": 7}"#, "plain"]))
+                .build();
+
+        let first = fired(trigger.poll().await.unwrap());
+        assert_eq!(first.get("id"), Some(&serde_json::json!(7)));
+        assert_eq!(
+            first.get("message").unwrap()["position"],
+            serde_json::json!("0:0")
+        );
+        let second = fired(trigger.poll().await.unwrap());
+        assert_eq!(second.get("payload"), Some(&serde_json::json!("plain")));
+        assert!(matches!(trigger.poll().await.unwrap(), TriggerResult::Skip));
+        assert_eq!(trigger.in_flight(), 2);
+
+        assert_eq!(trigger.settle(&delivery_ids(&first), true).await, 1);
+        assert_eq!(trigger.settle(&delivery_ids(&second), false).await, 1);
+        // Settling twice is a no-op.
+        assert_eq!(trigger.settle(&delivery_ids(&first), true).await, 0);
+        assert_eq!(
+            *trigger.source().settled.lock(),
+            vec![("0:0".to_string(), true), ("0:1".to_string(), false)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batches_messages_into_one_firing() {
+        let trigger = MessageTrigger::builder("orders", MemorySource::with(&["1", "2", "3"]))
+            .batch_size(2)
+            .ack_on_failure(true)
+            .build();
+
+        let batch = fired(trigger.poll().await.unwrap());
+        assert_eq!(batch.get("message_count"), Some(&serde_json::json!(2)));
+        assert_eq!(
+            batch.get("messages").unwrap()[1]["payload"],
+            serde_json::json!(2)
+        );
+
+        let ids = delivery_ids(&batch);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(trigger.settle(&ids, false).await, 2);
+        assert!(trigger
+            .source()
+            .settled
+            .lock()
+            .iter()
+            .all(|(_, acked)| *acked));
+        assert_eq!(trigger.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_discarded_firing_is_nacked() {
+        let trigger = MessageTrigger::builder("orders", MemorySource::with(&["1", "2"]))
+            .ack_on_failure(true)
+            .build();
+
+        let first = fired(trigger.poll().await.unwrap());
+        let _second = fired(trigger.poll().await.unwrap());
+        assert_eq!(trigger.in_flight(), 2);
+
+        trigger.discard(&first).await;
+        assert_eq!(trigger.in_flight(), 1);
+        assert_eq!(
+            *trigger.source().settled.lock(),
+            vec![("0:0".to_string(), false)]
+        );
+        // The workflow hooks find nothing left to settle.
+        assert_eq!(trigger.settle(&delivery_ids(&first), true).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_payload_cannot_set_reserved_keys() {
+        let payload = r#"{"id": 1, "scheduled_time": "x", "trigger_name": "x", "__cloacina_secret_refs__": {}, "message": "x"}"#;
+        let trigger = MessageTrigger::builder("orders", MemorySource::with(&[payload])).build();
+
+        let ctx = fired(trigger.poll().await.unwrap());
+        assert_eq!(ctx.get("id"), Some(&serde_json::json!(1)));
+        assert!(ctx.get("scheduled_time").is_none());
+        assert!(ctx.get("trigger_name").is_none());
+        assert!(ctx.get("__cloacina_secret_refs__").is_none());
+        assert_eq!(delivery_ids(&ctx).len(), 1);
+    }
+}
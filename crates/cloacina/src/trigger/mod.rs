@@ -27,6 +27,9 @@
 //! - Firing workflows with optional context when conditions are met
 //! - Deduplicating concurrent executions based on context hash
 //!
+//! With the `file-watch` feature, [`FileWatchTrigger`] is a built-in trigger
+//! that fires on filesystem change notifications.
+//!
 //! ## Example
 //!
 //! ```rust,ignore
@@ -49,9 +52,30 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
+use crate::Context;
+
+mod condition;
+#[cfg(feature = "file-watch")]
+mod file_watch;
+mod message;
+pub(crate) use condition::{eval_trigger_condition, run_probes};
+pub use condition::{
+    parse_trigger_probes, trigger_probes_json, validate_trigger_condition, ProbeKind, TriggerProbe,
+    DEFAULT_HTTP_PROBE_TIMEOUT, PROBES_KEY,
+};
+#[cfg(feature = "file-watch")]
+pub use file_watch::{
+    FileChange, FileWatchTrigger, FileWatchTriggerBuilder, DEFAULT_FILE_WATCH_POLL_INTERVAL,
+};
+pub use message::{
+    delivery_ids, MessageEnvelope, MessageSource, MessageSourceError, MessageTrigger,
+    MessageTriggerBuilder, DEFAULT_MESSAGE_POLL_INTERVAL, MESSAGES_KEY, MESSAGE_KEY,
+};
+
 /// Errors that can occur during trigger operations.
 #[derive(Debug, Error)]
 pub enum TriggerError {
@@ -78,6 +102,10 @@ pub enum TriggerError {
     /// Workflow scheduling failed
     #[error("Failed to schedule workflow '{workflow}': {message}")]
     WorkflowSchedulingFailed { workflow: String, message: String },
+
+    /// A declarative trigger condition or probe definition is invalid
+    #[error("Invalid trigger condition: {message}")]
+    InvalidCondition { message: String },
 }
 
 impl From<deadpool::managed::PoolError<deadpool_diesel::Error>> for TriggerError {
@@ -155,12 +183,157 @@ impl TriggerConfig {
 // T-0552 / I-0102 follow-up: Trigger trait relocated to cloacina-workflow
 // so packaged cdylibs can collect TriggerEntry inventory entries. Engine
 // paths re-export.
+pub use cloacina_workflow::trigger::{BATCH_COUNT_KEY, BATCH_EVENTS_KEY};
 pub use cloacina_workflow::Trigger;
+pub use cloacina_workflow::TriggerBatch;
+
+/// Buffered payloads of one batching trigger, awaiting a flush.
+///
+/// Held in scheduler memory: events buffered when the process stops are not
+/// replayed. The window is checked whenever the trigger is polled, so a
+/// window flush lands up to one `poll_interval` late.
+#[derive(Debug, Clone, Default)]
+pub struct PendingBatch {
+    events: Vec<serde_json::Value>,
+    opened_at: Option<Instant>,
+}
+
+impl PendingBatch {
+    /// Buffer one fired payload (`None` is buffered as an empty object).
+    pub fn push(&mut self, context: Option<Context<serde_json::Value>>, now: Instant) {
+        let payload = context
+            .map(|ctx| serde_json::Value::Object(ctx.to_map().unwrap_or_default()))
+            .unwrap_or_else(|| serde_json::Value::Object(Default::default()));
+        self.events.push(payload);
+        self.opened_at.get_or_insert(now);
+    }
+
+    /// Number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether nothing is buffered.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Whether `policy` says the buffer should be flushed at `now`.
+    pub fn is_ready(&self, policy: &TriggerBatch, now: Instant) -> bool {
+        if self.events.is_empty() {
+            return false;
+        }
+        let full = policy
+            .max_events()
+            .is_some_and(|max| self.events.len() >= max);
+        let expired = match (policy.window_duration(), self.opened_at) {
+            (Some(window), Some(opened)) => now.duration_since(opened) >= window,
+            _ => false,
+        };
+        full || expired
+    }
+
+    /// Build a single execution context carrying the buffered payload list
+    /// and its count. The buffer is left intact; call [`Self::clear`] once
+    /// the execution has been handed off so a failed handoff retries the
+    /// same events on the next poll.
+    pub fn to_context(&self) -> Result<Context<serde_json::Value>, crate::error::ContextError> {
+        let mut ctx = Context::new();
+        ctx.insert(BATCH_COUNT_KEY, serde_json::json!(self.events.len()))?;
+        ctx.insert(
+            BATCH_EVENTS_KEY,
+            serde_json::Value::Array(self.events.clone()),
+        )?;
+        Ok(ctx)
+    }
+
+    /// Remove the buffered events, each as the context it was fired with,
+    /// when the batch is dropped without running.
+    pub fn drain(&mut self) -> Vec<Context<serde_json::Value>> {
+        self.opened_at = None;
+        self.events
+            .drain(..)
+            .map(|event| match event {
+                serde_json::Value::Object(fields) => Context::from_map(fields).unwrap_or_default(),
+                _ => Context::new(),
+            })
+            .collect()
+    }
+
+    /// Discard the buffered events after a successful flush.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.opened_at = None;
+    }
+}
+
+/// The latest firing of a debounced trigger, held until the trigger has not
+/// fired for the schedule's debounce window.
+///
+/// Held in scheduler memory like [`PendingBatch`]. Quiet time is measured
+/// between polls, so the held firing runs on the first poll at least one
+/// window after the last fire.
+#[derive(Debug, Clone, Default)]
+pub struct PendingDebounce {
+    held: Option<Option<HashMap<String, serde_json::Value>>>,
+    last_fired_at: Option<Instant>,
+}
+
+impl PendingDebounce {
+    /// Hold a firing in place of any held earlier and restart the window.
+    /// Returns the context of the firing it replaced, if any.
+    pub fn hold(
+        &mut self,
+        context: Option<Context<serde_json::Value>>,
+        now: Instant,
+    ) -> Option<Context<serde_json::Value>> {
+        self.last_fired_at = Some(now);
+        self.held
+            .replace(context.map(Context::into_data))
+            .flatten()
+            .map(Context::from_data)
+    }
+
+    /// Whether no firing is held.
+    pub fn is_empty(&self) -> bool {
+        self.held.is_none()
+    }
+
+    /// Whether a firing is held and the trigger has not fired for `window`
+    /// at `now`.
+    pub fn is_settled(&self, window: Duration, now: Instant) -> bool {
+        self.held.is_some()
+            && self
+                .last_fired_at
+                .is_some_and(|at| now.duration_since(at) >= window)
+    }
+
+    /// The held firing as a trigger result (`Skip` when nothing is held).
+    /// The firing stays held; call [`Self::clear`] once it has been handed
+    /// off so a failed handoff retries it on the next poll.
+    pub fn to_result(&self) -> TriggerResult {
+        match &self.held {
+            Some(data) => TriggerResult::Fire(data.clone().map(Context::from_data)),
+            None => TriggerResult::Skip,
+        }
+    }
+
+    /// Remove the held firing's context when it is dropped without running.
+    pub fn take(&mut self) -> Option<Context<serde_json::Value>> {
+        self.last_fired_at = None;
+        self.held.take().flatten().map(Context::from_data)
+    }
+
+    /// Drop the held firing.
+    pub fn clear(&mut self) {
+        self.held = None;
+        self.last_fired_at = None;
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Context;
     use async_trait::async_trait;
 
     #[derive(Debug)]
@@ -236,6 +409,119 @@ mod tests {
         assert_eq!(hash1, hash3);
     }
 
+    #[test]
+    fn test_trigger_result_context_hash_for_keys() {
+        let fire = |path: &str, mtime: i64| {
+            let mut ctx = Context::new();
+            ctx.insert("path", serde_json::json!(path)).unwrap();
+            ctx.insert("mtime", serde_json::json!(mtime)).unwrap();
+            TriggerResult::Fire(Some(ctx))
+        };
+        let keys = vec!["path".to_string()];
+
+        // Only the selected keys feed the hash
+        assert_eq!(
+            fire("a.csv", 1).context_hash_for_keys(&keys),
+            fire("a.csv", 2).context_hash_for_keys(&keys)
+        );
+        assert_ne!(
+            fire("a.csv", 1).context_hash_for_keys(&keys),
+            fire("b.csv", 1).context_hash_for_keys(&keys)
+        );
+
+        // No keys falls back to the whole-context hash
+        assert_eq!(
+            fire("a.csv", 1).context_hash_for_keys(&[]),
+            fire("a.csv", 1).context_hash()
+        );
+        assert_eq!(
+            TriggerResult::Fire(None).context_hash_for_keys(&keys),
+            "fire_no_context"
+        );
+    }
+
+    #[test]
+    fn test_pending_batch_flushes_on_count() {
+        let policy = TriggerBatch::events(2);
+        let now = Instant::now();
+        let mut batch = PendingBatch::default();
+        assert!(!batch.is_ready(&policy, now));
+
+        let mut ctx = Context::new();
+        ctx.insert("file", serde_json::json!("a.csv")).unwrap();
+        batch.push(Some(ctx), now);
+        assert!(!batch.is_ready(&policy, now));
+        batch.push(None, now);
+        assert!(batch.is_ready(&policy, now));
+
+        let ctx = batch.to_context().unwrap();
+        assert_eq!(ctx.get(BATCH_COUNT_KEY), Some(&serde_json::json!(2)));
+        assert_eq!(
+            ctx.get(BATCH_EVENTS_KEY),
+            Some(&serde_json::json!([{"file": "a.csv"}, {}]))
+        );
+        assert_eq!(batch.len(), 2);
+        batch.clear();
+        assert!(batch.is_empty());
+        assert!(!batch.is_ready(&policy, now));
+    }
+
+    #[test]
+    fn test_pending_batch_flushes_on_window() {
+        let policy = TriggerBatch::window(Duration::from_secs(10));
+        let start = Instant::now();
+        let mut batch = PendingBatch::default();
+        batch.push(None, start);
+        batch.push(None, start + Duration::from_secs(5));
+        assert!(!batch.is_ready(&policy, start + Duration::from_secs(9)));
+        assert!(batch.is_ready(&policy, start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_trigger_batch_zero_count_flushes_every_event() {
+        let policy = TriggerBatch::events(0);
+        assert_eq!(policy.max_events(), Some(1));
+        assert_eq!(policy.window_duration(), None);
+
+        let now = Instant::now();
+        let mut batch = PendingBatch::default();
+        batch.push(None, now);
+        assert!(batch.is_ready(&policy, now));
+    }
+
+    #[test]
+    fn test_pending_debounce_keeps_latest_until_quiet() {
+        let window = Duration::from_millis(500);
+        let start = Instant::now();
+        let mut debounce = PendingDebounce::default();
+        assert!(debounce.is_empty());
+        assert!(!debounce.to_result().should_fire());
+
+        let fire = |n: i64| {
+            let mut ctx = Context::new();
+            ctx.insert("n", serde_json::json!(n)).unwrap();
+            Some(ctx)
+        };
+        assert!(debounce.hold(fire(1), start).is_none());
+        // The replaced firing is handed back so its trigger can release it.
+        let replaced = debounce
+            .hold(fire(2), start + Duration::from_millis(300))
+            .unwrap();
+        assert_eq!(replaced.get("n"), Some(&serde_json::json!(1)));
+
+        // Each fire restarts the window.
+        assert!(!debounce.is_settled(window, start + Duration::from_millis(600)));
+        assert!(debounce.is_settled(window, start + Duration::from_millis(800)));
+
+        // Only the latest firing is kept, and it survives until cleared.
+        let held = debounce.to_result().into_context().unwrap();
+        assert_eq!(held.get("n"), Some(&serde_json::json!(2)));
+        assert!(!debounce.is_empty());
+        debounce.clear();
+        assert!(debounce.is_empty());
+        assert!(!debounce.is_settled(window, start + Duration::from_secs(10)));
+    }
+
     #[test]
     fn test_trigger_config() {
         let config = TriggerConfig::new("test", "my_workflow", Duration::from_secs(5));
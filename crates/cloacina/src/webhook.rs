@@ -0,0 +1,415 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Webhook triggers (`webhooks` feature).
+//!
+//! A webhook trigger fires a workflow when an HTTP request arrives instead of
+//! when a poll says so. [`DefaultRunner::register_webhook_trigger`] registers
+//! one; the runner's webhook listener ([`DefaultRunner::serve_webhooks`], or
+//! [`DefaultRunner::webhook_router`] to mount it in an existing server) then
+//! accepts `POST /webhooks/{name}`:
+//!
+//! 1. The request is authenticated with the trigger's [`WebhookAuth`]: a
+//!    shared secret header, or an HMAC-SHA256 signature of the raw body
+//!    (`sha256=<hex>`, the format GitHub and most providers send).
+//! 2. The body is mapped into the workflow context by [`webhook_context`]: a
+//!    JSON object's top-level keys become context keys; any other JSON value
+//!    is stored under `payload`. An empty body fires with an empty context.
+//!    Keys the scheduler stamps on a firing (`scheduled_time`, `run_id`, …)
+//!    and runtime keys (`__cloacina…`) are dropped, so a sender cannot pick
+//!    the run's identity or logical date.
+//! 3. The event is recorded as an execution of the trigger's schedule, so it
+//!    shows up in the trigger's history with its context hash, and the
+//!    workflow is started with `trigger_name`, `triggered_at` and
+//!    `trigger_origin = "webhook"`.
+//!
+//! The trigger is backed by an ordinary trigger schedule, so pausing it
+//! rejects requests with `409`. Registrations (and their secrets) are held
+//! in memory only; re-register webhook triggers when the process starts.
+//!
+//! [`DefaultRunner::register_webhook_trigger`]: crate::runner::DefaultRunner::register_webhook_trigger
+//! [`DefaultRunner::serve_webhooks`]: crate::runner::DefaultRunner::serve_webhooks
+//! [`DefaultRunner::webhook_router`]: crate::runner::DefaultRunner::webhook_router
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use hmac::{Hmac, Mac};
+use parking_lot::RwLock;
+use serde::Serialize;
+use sha2::Sha256;
+use tokio::sync::watch;
+use tracing::debug;
+
+use crate::context::Context;
+use crate::trigger::{Trigger, TriggerResult};
+use crate::workflow_instance::is_reserved_payload_key;
+
+/// Header carrying the shared secret, unless overridden.
+pub const DEFAULT_SECRET_HEADER: &str = "x-cloacina-webhook-secret";
+/// Header carrying the HMAC-SHA256 signature, unless overridden.
+pub const DEFAULT_SIGNATURE_HEADER: &str = "x-cloacina-signature";
+/// Context key for a request body that is not a JSON object.
+pub const PAYLOAD_KEY: &str = "payload";
+
+/// Poll interval of the schedule backing a webhook trigger. The poll never
+/// fires; it only keeps the scheduler from treating the trigger as missing.
+pub(crate) const WEBHOOK_POLL_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// How a webhook request proves it comes from the expected sender.
+#[derive(Clone)]
+pub enum WebhookAuth {
+    /// Accept every request. Only for listeners on a trusted network.
+    None,
+    /// The `header` must equal `secret`.
+    SharedSecret { header: String, secret: String },
+    /// The `header` must hold `sha256=<hex>` (or the bare hex digest) of the
+    /// HMAC-SHA256 of the raw body keyed with `secret`.
+    HmacSha256 { header: String, secret: String },
+}
+
+impl WebhookAuth {
+    /// Shared secret sent in the `X-Cloacina-Webhook-Secret` header.
+    pub fn shared_secret(secret: impl Into<String>) -> Self {
+        Self::SharedSecret {
+            header: DEFAULT_SECRET_HEADER.to_string(),
+            secret: secret.into(),
+        }
+    }
+
+    /// HMAC-SHA256 body signature sent in the `X-Cloacina-Signature` header.
+    pub fn hmac_sha256(secret: impl Into<String>) -> Self {
+        Self::HmacSha256 {
+            header: DEFAULT_SIGNATURE_HEADER.to_string(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Read the secret or signature from `header` instead, e.g.
+    /// `X-Hub-Signature-256` for GitHub. No effect on [`WebhookAuth::None`].
+    pub fn with_header(mut self, name: impl Into<String>) -> Self {
+        match &mut self {
+            Self::None => {}
+            Self::SharedSecret { header, .. } | Self::HmacSha256 { header, .. } => {
+                *header = name.into().to_ascii_lowercase();
+            }
+        }
+        self
+    }
+
+    /// Check a request's headers and raw body against this scheme.
+    pub fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), WebhookError> {
+        match self {
+            Self::None => Ok(()),
+            Self::SharedSecret { header, secret } => {
+                let presented = header_value(headers, header)?;
+                if constant_time_eq(presented.as_bytes(), secret.as_bytes()) {
+                    Ok(())
+                } else {
+                    Err(WebhookError::Unauthorized)
+                }
+            }
+            Self::HmacSha256 { header, secret } => {
+                let presented = header_value(headers, header)?;
+                let hex_digest = presented.strip_prefix("sha256=").unwrap_or(presented);
+                let signature =
+                    hex::decode(hex_digest.trim()).map_err(|_| WebhookError::Unauthorized)?;
+                let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+                    .expect("HMAC accepts keys of any length");
+                mac.update(body);
+                mac.verify_slice(&signature)
+                    .map_err(|_| WebhookError::Unauthorized)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WebhookAuth {
+    // Secrets stay out of logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::SharedSecret { header, .. } => f
+                .debug_struct("SharedSecret")
+                .field("header", header)
+                .finish_non_exhaustive(),
+            Self::HmacSha256 { header, .. } => f
+                .debug_struct("HmacSha256")
+                .field("header", header)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+fn header_value<'a>(headers: &'a HeaderMap, name: &str) -> Result<&'a str, WebhookError> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .ok_or(WebhookError::Unauthorized)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Map a webhook request body into a workflow context.
+///
+/// A JSON object's top-level keys become context keys; any other JSON value
+/// is stored under [`PAYLOAD_KEY`]. An empty body yields an empty context.
+/// Keys in [`RESERVED_FIRE_KEYS`](crate::workflow_instance::RESERVED_FIRE_KEYS)
+/// and runtime keys (`__cloacina…`) are dropped: the scheduler owns them.
+pub fn webhook_context(body: &[u8]) -> Result<Context<serde_json::Value>, WebhookError> {
+    let mut context = Context::new();
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(context);
+    }
+    let value: serde_json::Value = serde_json::from_slice(body)
+        .map_err(|e| WebhookError::InvalidBody(format!("body is not JSON: {}", e)))?;
+    let entries: Vec<(String, serde_json::Value)> = match value {
+        serde_json::Value::Object(obj) => obj.into_iter().collect(),
+        other => vec![(PAYLOAD_KEY.to_string(), other)],
+    };
+    for (key, value) in entries {
+        if is_reserved_payload_key(&key) {
+            debug!("Dropping reserved key '{}' from webhook body", key);
+            continue;
+        }
+        context
+            .insert(key, value)
+            .map_err(|e| WebhookError::InvalidBody(e.to_string()))?;
+    }
+    Ok(context)
+}
+
+/// Why a webhook request did not fire its workflow.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("webhook trigger '{0}' not found")]
+    NotFound(String),
+    #[error("invalid or missing webhook credentials")]
+    Unauthorized,
+    #[error("invalid webhook body: {0}")]
+    InvalidBody(String),
+    #[error("webhook trigger '{0}' is paused or disabled")]
+    Paused(String),
+    #[error("failed to fire webhook trigger: {0}")]
+    Failed(String),
+}
+
+impl WebhookError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::InvalidBody(_) => StatusCode::BAD_REQUEST,
+            Self::Paused(_) => StatusCode::CONFLICT,
+            Self::Failed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        (
+            status,
+            Json(serde_json::json!({ "error": self.to_string() })),
+        )
+            .into_response()
+    }
+}
+
+/// `202 Accepted` body for a webhook request that started its workflow.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookFired {
+    pub trigger: String,
+    pub workflow_name: String,
+    /// The started workflow execution.
+    pub execution_id: String,
+    /// The schedule execution recording this event.
+    pub event_id: String,
+}
+
+/// A registered webhook trigger.
+#[derive(Debug, Clone)]
+pub(crate) struct WebhookRegistration {
+    pub(crate) workflow_name: String,
+    pub(crate) auth: WebhookAuth,
+}
+
+/// Webhook triggers registered with a runner, shared across its clones.
+pub(crate) struct WebhookRegistry {
+    triggers: RwLock<HashMap<String, WebhookRegistration>>,
+    shutdown: watch::Sender<bool>,
+}
+
+impl WebhookRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            triggers: RwLock::new(HashMap::new()),
+            shutdown: watch::channel(false).0,
+        }
+    }
+
+    pub(crate) fn insert(&self, name: &str, registration: WebhookRegistration) {
+        self.triggers.write().insert(name.to_string(), registration);
+    }
+
+    pub(crate) fn remove(&self, name: &str) -> bool {
+        self.triggers.write().remove(name).is_some()
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<WebhookRegistration> {
+        self.triggers.read().get(name).cloned()
+    }
+
+    /// Resolves once [`Self::stop`] has been called.
+    pub(crate) fn stopped(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let mut rx = self.shutdown.subscribe();
+        async move {
+            let _ = rx.wait_for(|stopped| *stopped).await;
+        }
+    }
+
+    /// Stop every listener started by `serve_webhooks`.
+    pub(crate) fn stop(&self) {
+        self.shutdown.send_replace(true);
+    }
+}
+
+/// Placeholder registered in the runtime for a webhook trigger's schedule.
+/// Webhook triggers fire on request, so the poll never fires.
+#[derive(Debug)]
+pub(crate) struct WebhookTrigger {
+    pub(crate) name: String,
+}
+
+#[async_trait]
+impl Trigger for WebhookTrigger {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn poll_interval(&self) -> Duration {
+        WEBHOOK_POLL_INTERVAL
+    }
+
+    fn allow_concurrent(&self) -> bool {
+        true
+    }
+
+    async fn poll(&self) -> Result<TriggerResult, cloacina_workflow::TriggerError> {
+        Ok(TriggerResult::Skip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(name: &str, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+            HeaderValue::from_str(value).unwrap(),
+        );
+        headers
+    }
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn test_shared_secret_must_match() {
+        let auth = WebhookAuth::shared_secret("s3cret");
+        assert!(auth
+            .verify(&headers(DEFAULT_SECRET_HEADER, "s3cret"), b"{}")
+            .is_ok());
+        assert!(auth
+            .verify(&headers(DEFAULT_SECRET_HEADER, "wrong"), b"{}")
+            .is_err());
+        assert!(auth.verify(&HeaderMap::new(), b"{}").is_err());
+        assert!(WebhookAuth::None.verify(&HeaderMap::new(), b"{}").is_ok());
+    }
+
+    #[test]
+    fn test_hmac_signature_covers_body() {
+        let body = br#"{"ref":"refs/heads/main"}"#;
+        let auth = WebhookAuth::hmac_sha256("hunter2").with_header("X-Hub-Signature-256");
+        let signature = sign("hunter2", body);
+
+        assert!(auth
+            .verify(&headers("x-hub-signature-256", &signature), body)
+            .is_ok());
+        // Bare hex digest without the `sha256=` prefix.
+        assert!(auth
+            .verify(
+                &headers("x-hub-signature-256", &signature["sha256=".len()..]),
+                body
+            )
+            .is_ok());
+        assert!(auth
+            .verify(&headers("x-hub-signature-256", &signature), b"{}")
+            .is_err());
+        assert!(auth
+            .verify(&headers("x-hub-signature-256", "sha256=zz"), body)
+            .is_err());
+        assert!(!format!("{:?}", auth).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_webhook_context_maps_body() {
+        let ctx = webhook_context(br#"{"ref":"main","commits":[1,2]}"#).unwrap();
+        assert_eq!(ctx.get("ref"), Some(&serde_json::json!("main")));
+        assert_eq!(ctx.get("commits"), Some(&serde_json::json!([1, 2])));
+
+        let ctx = webhook_context(b"[1,2]").unwrap();
+        assert_eq!(ctx.get(PAYLOAD_KEY), Some(&serde_json::json!([1, 2])));
+
+        assert!(webhook_context(b"  ").unwrap().get(PAYLOAD_KEY).is_none());
+
+        let ctx = webhook_context(
+            br#"{"ref":"main","scheduled_time":"1999-01-01T00:00:00Z","schedule_id":"x","is_recovery":true,"__cloacina_secret_refs__":{"k":"db"}}"#,
+        )
+        .unwrap();
+        assert_eq!(ctx.get("ref"), Some(&serde_json::json!("main")));
+        for key in [
+            "scheduled_time",
+            "schedule_id",
+            "is_recovery",
+            "__cloacina_secret_refs__",
+        ] {
+            assert!(
+                ctx.get(key).is_none(),
+                "{} must not come from the body",
+                key
+            );
+        }
+        assert!(matches!(
+            webhook_context(b"not json"),
+            Err(WebhookError::InvalidBody(_))
+        ));
+    }
+}
@@ -22,7 +22,9 @@
 use std::sync::Arc;
 
 use crate::error::{ValidationError, WorkflowError};
-use crate::task::Task;
+use crate::retry::RetryPolicy;
+use crate::task::{FnTask, Task};
+use crate::Runtime;
 
 use super::Workflow;
 
@@ -111,6 +113,33 @@ impl WorkflowBuilder {
         self
     }
 
+    /// Set the workflow package (`"embedded"` by default)
+    pub fn package(mut self, package: &str) -> Self {
+        self.workflow.set_package(package);
+        self
+    }
+
+    /// Limit how many executions of the workflow may run at once (see
+    /// [`Workflow::set_max_active_runs`])
+    pub fn max_active_runs(mut self, limit: usize) -> Self {
+        self.workflow.set_max_active_runs(Some(limit));
+        self
+    }
+
+    /// Re-run the whole workflow after a failed execution (see
+    /// [`Workflow::set_retry_policy`])
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.workflow.set_retry_policy(Some(policy));
+        self
+    }
+
+    /// Fail executions of the workflow that run longer than `timeout` (see
+    /// [`Workflow::set_timeout`])
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.workflow.set_timeout(Some(timeout));
+        self
+    }
+
     /// Add a tag to the workflow metadata
     pub fn tag(mut self, key: &str, value: &str) -> Self {
         self.workflow.add_tag(key, value);
@@ -123,6 +152,35 @@ impl WorkflowBuilder {
         Ok(self)
     }
 
+    /// Embed the registered workflow `workflow` as a sub-DAG under `prefix`,
+    /// after the tasks in `dependencies` (see [`Workflow::embed_workflow`]).
+    /// Tasks depend on the whole sub-DAG through `prefix`.
+    pub fn embed_workflow(
+        mut self,
+        prefix: &str,
+        workflow: &str,
+        dependencies: &[&str],
+    ) -> Result<Self, WorkflowError> {
+        let dependencies = dependencies
+            .iter()
+            .map(|id| self.workflow.embed_namespace(id))
+            .collect();
+        self.workflow
+            .embed_workflow(prefix, workflow, dependencies)?;
+        Ok(self)
+    }
+
+    /// Add a closure-backed task, resolving its dependency IDs within this
+    /// workflow. Set the tenant and package before adding tasks.
+    pub fn task(self, task: FnTask) -> Result<Self, WorkflowError> {
+        let task = task.resolve(
+            self.workflow.tenant(),
+            self.workflow.package(),
+            self.workflow.name(),
+        );
+        self.add_task(Arc::new(task))
+    }
+
     /// Validate the workflow structure
     pub fn validate(self) -> Result<Self, ValidationError> {
         self.workflow.validate()?;
@@ -135,4 +193,12 @@ impl WorkflowBuilder {
         // Auto-calculate version when building
         Ok(self.workflow.finalize())
     }
+
+    /// Build the workflow and register it, with its tasks, on `runtime` so
+    /// it can be executed by name (see [`Runtime::register_built_workflow`])
+    pub fn register(self, runtime: &Runtime) -> Result<Workflow, ValidationError> {
+        let workflow = self.build()?;
+        runtime.register_built_workflow(workflow.clone());
+        Ok(workflow)
+    }
 }
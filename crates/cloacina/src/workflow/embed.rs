@@ -0,0 +1,505 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Embedded workflows: another workflow's tasks inlined as a sub-DAG.
+//!
+//! A workflow declares an embed with [`Workflow::embed_workflow`] (or
+//! `embed_workflow!(…)` inside a `#[workflow]` module), naming the workflow
+//! to embed and a prefix. Until the embed is resolved, the prefix stands in
+//! for the whole sub-DAG: tasks may depend on it like on any task.
+//!
+//! [`Workflow::inline_workflow`] resolves an embed. Every task of the
+//! embedded workflow is added as `<prefix>.<task id>` under the embedding
+//! workflow's namespace; its root tasks depend on the embed's dependencies,
+//! and the tasks that depended on the prefix depend on its leaf tasks
+//! instead. The [`Runtime`](crate::Runtime) does this whenever it hands out
+//! a workflow that declares embeds, so an embedded packaged workflow is
+//! picked up (or dropped) as its package is loaded and unloaded.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cloacina_workflow::CapabilityManifest;
+
+use super::Workflow;
+use crate::context::Context;
+use crate::error::{CheckpointError, TaskError, WorkflowError};
+use crate::outputs::ProducedOutput;
+use crate::retry::RetryPolicy;
+use crate::task::{Task, TaskNamespace, WorkloadClass};
+
+/// A workflow embedded in another one as a sub-DAG, not yet inlined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedWorkflow {
+    /// Prefix the embedded tasks are inlined under; dependents of the whole
+    /// sub-DAG depend on this id.
+    pub prefix: String,
+    /// Name of the workflow to embed, as registered in the runtime.
+    pub workflow: String,
+    /// Tasks the sub-DAG's root tasks wait for.
+    pub dependencies: Vec<TaskNamespace>,
+}
+
+impl Workflow {
+    /// Declare `workflow` as a sub-DAG of this workflow, inlined under
+    /// `prefix` once it is resolved (see [`Workflow::inline_workflow`]).
+    ///
+    /// Until then `prefix` is a valid dependency for this workflow's tasks,
+    /// standing for every task of the embedded workflow.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `prefix` is empty, contains `::` or `.`, or is already used
+    /// by a task or another embed.
+    pub fn embed_workflow(
+        &mut self,
+        prefix: &str,
+        workflow: &str,
+        dependencies: Vec<TaskNamespace>,
+    ) -> Result<(), WorkflowError> {
+        if prefix.is_empty() || prefix.contains("::") || prefix.contains('.') {
+            return Err(WorkflowError::ValidationError(format!(
+                "invalid embed prefix '{}': must be non-empty and contain no '::' or '.'",
+                prefix
+            )));
+        }
+        let handle = self.embed_namespace(prefix);
+        if self.tasks.contains_key(&handle) || self.embeds.iter().any(|e| e.prefix == prefix) {
+            return Err(WorkflowError::DuplicateTask(handle.to_string()));
+        }
+        self.embeds.push(EmbeddedWorkflow {
+            prefix: prefix.to_string(),
+            workflow: workflow.to_string(),
+            dependencies,
+        });
+        Ok(())
+    }
+
+    /// Embedded workflows declared on this workflow and not yet inlined.
+    pub fn embedded_workflows(&self) -> &[EmbeddedWorkflow] {
+        &self.embeds
+    }
+
+    /// Namespace standing for the sub-DAG embedded under `prefix`.
+    pub fn embed_namespace(&self, prefix: &str) -> TaskNamespace {
+        TaskNamespace::new(&self.tenant, &self.package, &self.name, prefix)
+    }
+
+    /// Inline `embedded` as the sub-DAG declared under `prefix`.
+    ///
+    /// Each task of `embedded` is added as `<prefix>.<task id>` with its
+    /// dependencies and trigger-rule task references renamed to match. Its
+    /// root tasks wait for the embed's dependencies, and the tasks depending
+    /// on `prefix` wait for its leaf tasks instead.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no embed is declared under `prefix`, if `embedded` still has
+    /// embeds of its own, or if an inlined task id is already taken.
+    pub fn inline_workflow(
+        &mut self,
+        prefix: &str,
+        embedded: &Workflow,
+    ) -> Result<(), WorkflowError> {
+        let position = self
+            .embeds
+            .iter()
+            .position(|e| e.prefix == prefix)
+            .ok_or_else(|| {
+                WorkflowError::InvalidDependency(format!(
+                    "workflow '{}' declares no embed '{}'",
+                    self.name, prefix
+                ))
+            })?;
+        if !embedded.embeds.is_empty() {
+            return Err(WorkflowError::ValidationError(format!(
+                "embedded workflow '{}' has unresolved embeds",
+                embedded.name
+            )));
+        }
+        let embed = self.embeds.remove(position);
+        let handle = self.embed_namespace(prefix);
+
+        let renamed: HashMap<TaskNamespace, TaskNamespace> = embedded
+            .tasks
+            .keys()
+            .map(|ns| {
+                let id = format!("{}.{}", prefix, ns.task_id);
+                (ns.clone(), self.embed_namespace(&id))
+            })
+            .collect();
+        let rename = |ns: &TaskNamespace| renamed.get(ns).cloned().unwrap_or_else(|| ns.clone());
+        let rule_names: HashMap<String, String> = renamed
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect();
+
+        let root_dependencies: Vec<TaskNamespace> = embed
+            .dependencies
+            .iter()
+            .flat_map(|dep| self.resolve_embed_dependency(dep))
+            .collect();
+        for (ns, task) in &embedded.tasks {
+            let mut dependencies: Vec<TaskNamespace> =
+                task.dependencies().iter().map(rename).collect();
+            if dependencies.is_empty() {
+                dependencies = root_dependencies.clone();
+            }
+            let soft_dependencies = task
+                .dependencies()
+                .iter()
+                .filter(|dep| task.is_soft_dependency(dep))
+                .map(rename)
+                .collect();
+            let mut trigger_rules = task.trigger_rules();
+            rename_rule_task_names(&mut trigger_rules, &rule_names);
+            self.add_task(Arc::new(InlinedTask {
+                inner: task.clone(),
+                id: renamed[ns].task_id.clone(),
+                dependencies,
+                soft_dependencies,
+                trigger_rules,
+            }))?;
+        }
+
+        let leaves: Vec<TaskNamespace> = embedded.get_leaves().iter().map(rename).collect();
+        let dependents: Vec<TaskNamespace> = self
+            .tasks
+            .iter()
+            .filter(|(_, task)| task.dependencies().contains(&handle))
+            .map(|(ns, _)| ns.clone())
+            .collect();
+        for ns in dependents {
+            let task = self.tasks[&ns].clone();
+            let mut dependencies = Vec::new();
+            for dep in task.dependencies() {
+                if *dep == handle {
+                    dependencies.extend(leaves.iter().cloned());
+                } else {
+                    dependencies.push(dep.clone());
+                }
+            }
+            let mut soft_dependencies: Vec<TaskNamespace> = task
+                .dependencies()
+                .iter()
+                .filter(|dep| **dep != handle && task.is_soft_dependency(dep))
+                .cloned()
+                .collect();
+            if task.is_soft_dependency(&handle) {
+                soft_dependencies.extend(leaves.iter().cloned());
+            }
+
+            self.dependency_graph.remove_edge(&ns, &handle);
+            for leaf in &leaves {
+                self.dependency_graph.add_edge(ns.clone(), leaf.clone());
+            }
+            let trigger_rules = task.trigger_rules();
+            self.tasks.insert(
+                ns.clone(),
+                Arc::new(InlinedTask {
+                    id: ns.task_id.clone(),
+                    inner: task,
+                    dependencies,
+                    soft_dependencies,
+                    trigger_rules,
+                }),
+            );
+        }
+        self.dependency_graph.remove_node(&handle);
+
+        Ok(())
+    }
+
+    /// The tasks a dependency on `dep` means: the leaf tasks of an already
+    /// inlined embed when `dep` names one, `dep` itself otherwise.
+    fn resolve_embed_dependency(&self, dep: &TaskNamespace) -> Vec<TaskNamespace> {
+        if self.tasks.contains_key(dep) || self.embeds.iter().any(|e| e.prefix == dep.task_id) {
+            return vec![dep.clone()];
+        }
+        let prefix = format!("{}.", dep.task_id);
+        let inlined: Vec<&TaskNamespace> = self
+            .tasks
+            .keys()
+            .filter(|ns| ns.task_id.starts_with(&prefix))
+            .collect();
+        if inlined.is_empty() {
+            return vec![dep.clone()];
+        }
+        inlined
+            .iter()
+            .filter(|ns| {
+                !inlined
+                    .iter()
+                    .any(|other| self.tasks[*other].dependencies().contains(ns))
+            })
+            .map(|ns| (*ns).clone())
+            .collect()
+    }
+}
+
+/// Rewrites the `task_name` of task-outcome conditions in trigger rules.
+fn rename_rule_task_names(value: &mut serde_json::Value, names: &HashMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if let Some(serde_json::Value::String(task_name)) = map.get_mut("task_name") {
+                if let Some(renamed) = names.get(task_name.as_str()) {
+                    *task_name = renamed.clone();
+                }
+            }
+            for (_, v) in map.iter_mut() {
+                rename_rule_task_names(v, names);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items.iter_mut() {
+                rename_rule_task_names(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A task placed in another workflow's DAG: same behavior, new id and
+/// dependencies.
+struct InlinedTask {
+    inner: Arc<dyn Task>,
+    id: String,
+    dependencies: Vec<TaskNamespace>,
+    soft_dependencies: Vec<TaskNamespace>,
+    trigger_rules: serde_json::Value,
+}
+
+#[async_trait]
+impl Task for InlinedTask {
+    async fn execute(
+        &self,
+        context: Context<serde_json::Value>,
+    ) -> Result<Context<serde_json::Value>, TaskError> {
+        self.inner.execute(context).await
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn dependencies(&self) -> &[TaskNamespace] {
+        &self.dependencies
+    }
+
+    fn is_soft_dependency(&self, dependency: &TaskNamespace) -> bool {
+        self.soft_dependencies.contains(dependency)
+    }
+
+    fn checkpoint(&self, context: &Context<serde_json::Value>) -> Result<(), CheckpointError> {
+        self.inner.checkpoint(context)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.inner.retry_policy()
+    }
+
+    fn trigger_rules(&self) -> serde_json::Value {
+        self.trigger_rules.clone()
+    }
+
+    fn short_circuit(&self) -> Option<String> {
+        self.inner.short_circuit()
+    }
+
+    fn branch_key(&self) -> Option<String> {
+        self.inner.branch_key()
+    }
+
+    fn branch(&self) -> Option<String> {
+        self.inner.branch()
+    }
+
+    fn loop_until(&self) -> Option<serde_json::Value> {
+        self.inner.loop_until()
+    }
+
+    fn max_iterations(&self) -> Option<u32> {
+        self.inner.max_iterations()
+    }
+
+    fn memory_limit(&self) -> Option<u64> {
+        self.inner.memory_limit()
+    }
+
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.inner.timeout()
+    }
+
+    fn expected_duration(&self) -> Option<std::time::Duration> {
+        self.inner.expected_duration()
+    }
+
+    fn requires_approval(&self) -> bool {
+        self.inner.requires_approval()
+    }
+
+    fn workload_class(&self) -> Option<WorkloadClass> {
+        self.inner.workload_class()
+    }
+
+    fn resources(&self) -> Vec<String> {
+        self.inner.resources()
+    }
+
+    fn produces(&self) -> Vec<ProducedOutput> {
+        self.inner.produces()
+    }
+
+    fn scoped(&self) -> bool {
+        self.inner.scoped()
+    }
+
+    fn reads(&self) -> Option<Vec<String>> {
+        self.inner.reads()
+    }
+
+    fn capabilities(&self) -> Option<CapabilityManifest> {
+        self.inner.capabilities()
+    }
+
+    fn configuration(&self) -> Option<serde_json::Value> {
+        self.inner.configuration()
+    }
+
+    fn code_fingerprint(&self) -> Option<String> {
+        self.inner.code_fingerprint()
+    }
+
+    fn requires_handle(&self) -> bool {
+        self.inner.requires_handle()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubTask {
+        id: String,
+        dependencies: Vec<TaskNamespace>,
+    }
+
+    #[async_trait]
+    impl Task for StubTask {
+        async fn execute(
+            &self,
+            context: Context<serde_json::Value>,
+        ) -> Result<Context<serde_json::Value>, TaskError> {
+            Ok(context)
+        }
+
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn dependencies(&self) -> &[TaskNamespace] {
+            &self.dependencies
+        }
+    }
+
+    fn add(workflow: &mut Workflow, id: &str, dependencies: &[&str]) {
+        let dependencies = dependencies
+            .iter()
+            .map(|dep| workflow.embed_namespace(dep))
+            .collect();
+        workflow
+            .add_task(Arc::new(StubTask {
+                id: id.to_string(),
+                dependencies,
+            }))
+            .unwrap();
+    }
+
+    /// vendor_ingest: extract -> load, embedded between prepare and publish.
+    fn composed() -> Workflow {
+        let mut vendor = Workflow::new("vendor_ingest");
+        vendor.set_package("vendor_pkg");
+        add(&mut vendor, "extract", &[]);
+        add(&mut vendor, "load", &["extract"]);
+
+        let mut workflow = Workflow::new("etl");
+        add(&mut workflow, "prepare", &[]);
+        add(&mut workflow, "publish", &["vendor"]);
+        let prepare = workflow.embed_namespace("prepare");
+        workflow
+            .embed_workflow("vendor", "vendor_ingest", vec![prepare])
+            .unwrap();
+        workflow.validate().unwrap();
+        workflow.inline_workflow("vendor", &vendor).unwrap();
+        workflow
+    }
+
+    #[test]
+    fn test_inline_workflow_wires_sub_dag_between_tasks() {
+        let workflow = composed();
+        let ns = |id: &str| workflow.embed_namespace(id);
+
+        assert!(workflow.embedded_workflows().is_empty());
+        assert_eq!(workflow.get_task_ids().len(), 4);
+        assert_eq!(
+            workflow.get_dependencies(&ns("vendor.extract")).unwrap(),
+            &[ns("prepare")]
+        );
+        assert_eq!(
+            workflow.get_dependencies(&ns("vendor.load")).unwrap(),
+            &[ns("vendor.extract")]
+        );
+        assert_eq!(
+            workflow.get_dependencies(&ns("publish")).unwrap(),
+            &[ns("vendor.load")]
+        );
+        let order = workflow.topological_sort().unwrap();
+        let position = |id: &str| order.iter().position(|n| *n == ns(id)).unwrap();
+        assert!(position("prepare") < position("vendor.extract"));
+        assert!(position("vendor.load") < position("publish"));
+    }
+
+    #[test]
+    fn test_embed_workflow_rejects_bad_prefixes() {
+        let mut workflow = Workflow::new("etl");
+        add(&mut workflow, "prepare", &[]);
+        assert!(workflow.embed_workflow("", "vendor", vec![]).is_err());
+        assert!(workflow.embed_workflow("a.b", "vendor", vec![]).is_err());
+        assert!(workflow
+            .embed_workflow("prepare", "vendor", vec![])
+            .is_err());
+        workflow.embed_workflow("vendor", "vendor", vec![]).unwrap();
+        assert!(workflow.embed_workflow("vendor", "other", vec![]).is_err());
+        assert!(workflow
+            .inline_workflow("missing", &Workflow::new("vendor"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_rename_rule_task_names() {
+        let names = HashMap::from([(
+            "a::b::c::extract".to_string(),
+            "a::b::d::v.extract".to_string(),
+        )]);
+        let mut rules = serde_json::json!({
+            "type": "All",
+            "conditions": [{"type": "TaskSuccess", "task_name": "a::b::c::extract"}],
+        });
+        rename_rule_task_names(&mut rules, &names);
+        assert_eq!(rules["conditions"][0]["task_name"], "a::b::d::v.extract");
+    }
+}
@@ -68,12 +68,14 @@
 //! constructors are owned by [`crate::Runtime`] and seeded from `inventory`.
 
 mod builder;
+mod embed;
 mod graph;
 mod metadata;
 mod registry;
 
 // Re-export public types
 pub use builder::WorkflowBuilder;
+pub use embed::EmbeddedWorkflow;
 pub use graph::DependencyGraph;
 pub use metadata::WorkflowMetadata;
 pub use registry::WorkflowConstructor;
@@ -84,6 +86,7 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::error::{SubgraphError, ValidationError, WorkflowError};
+use crate::retry::RetryPolicy;
 use crate::task::{Task, TaskNamespace};
 
 /// Main Workflow structure for representing and managing task graphs.
@@ -141,6 +144,10 @@ pub struct Workflow {
     tasks: HashMap<TaskNamespace, Arc<dyn Task>>,
     dependency_graph: DependencyGraph,
     metadata: WorkflowMetadata,
+    max_active_runs: Option<usize>,
+    retry_policy: Option<RetryPolicy>,
+    timeout: Option<std::time::Duration>,
+    embeds: Vec<EmbeddedWorkflow>,
 }
 
 impl std::fmt::Debug for Workflow {
@@ -152,6 +159,10 @@ impl std::fmt::Debug for Workflow {
             .field("task_count", &self.tasks.len())
             .field("dependency_graph", &self.dependency_graph)
             .field("metadata", &self.metadata)
+            .field("max_active_runs", &self.max_active_runs)
+            .field("retry_policy", &self.retry_policy)
+            .field("timeout", &self.timeout)
+            .field("embeds", &self.embeds)
             .finish()
     }
 }
@@ -181,6 +192,10 @@ impl Workflow {
             tasks: HashMap::new(),
             dependency_graph: DependencyGraph::new(),
             metadata: WorkflowMetadata::default(),
+            max_active_runs: None,
+            retry_policy: None,
+            timeout: None,
+            embeds: Vec::new(),
         }
     }
 
@@ -250,6 +265,56 @@ impl Workflow {
         self.metadata.version = version.to_string();
     }
 
+    /// Get the maximum number of executions of this workflow that may run at
+    /// once, if limited
+    pub fn max_active_runs(&self) -> Option<usize> {
+        self.max_active_runs
+    }
+
+    /// Limit how many executions of this workflow may run at once
+    ///
+    /// Executions over the limit stay `Pending` until a running one finishes;
+    /// the scheduler starts them oldest first. `None` (or `Some(0)`) removes
+    /// the limit.
+    pub fn set_max_active_runs(&mut self, limit: Option<usize>) {
+        self.max_active_runs = limit.filter(|n| *n > 0);
+    }
+
+    /// Get the policy for re-running the whole workflow after it fails, if
+    /// any
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Re-run the whole workflow when an execution fails
+    ///
+    /// After a failed attempt the scheduler resets every task of the
+    /// execution and starts it again once the policy's delay for that attempt
+    /// has passed, up to `max_attempts` times; only the last attempt's
+    /// failure marks the execution `Failed`. Task-level retries still apply
+    /// within each attempt. Retry conditions are not consulted: any failure
+    /// is retried. `None` (or `max_attempts <= 0`) disables workflow retries.
+    pub fn set_retry_policy(&mut self, policy: Option<RetryPolicy>) {
+        self.retry_policy = policy.filter(|p| p.max_attempts > 0);
+    }
+
+    /// Get how long an execution of this workflow may run, if limited
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        self.timeout
+    }
+
+    /// Fail executions of this workflow that run longer than `timeout`
+    ///
+    /// The clock starts when the execution is created, when a workflow retry
+    /// restarts it, or when it is admitted past `max_active_runs`, so time
+    /// spent queued behind the limit does not count. Once it runs out the scheduler fails every unfinished
+    /// task and then the execution, which the retry policy may re-run. Tasks
+    /// already running are not interrupted; their late results are ignored.
+    /// `None` (or a zero duration) removes the limit.
+    pub fn set_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.timeout = timeout.filter(|t| !t.is_zero());
+    }
+
     /// Set the Workflow description
     pub fn set_description(&mut self, description: &str) {
         self.metadata.description = Some(description.to_string());
@@ -416,7 +481,8 @@ impl Workflow {
     ///
     /// Checks for:
     /// - Empty workflows
-    /// - Missing dependencies
+    /// - Missing dependencies (a dependency on the prefix of an embedded
+    ///   workflow not yet inlined counts as present)
     /// - Circular dependencies
     ///
     /// # Returns
@@ -443,7 +509,11 @@ impl Workflow {
         // Check for missing dependencies
         for (task_namespace, task) in &self.tasks {
             for dependency in task.dependencies() {
-                if !self.tasks.contains_key(dependency) {
+                let embedded = self
+                    .embeds
+                    .iter()
+                    .any(|e| *dependency == self.embed_namespace(&e.prefix));
+                if !self.tasks.contains_key(dependency) && !embedded {
                     return Err(ValidationError::MissingDependency {
                         task: task_namespace.to_string(),
                         dependency: dependency.to_string(),
@@ -452,6 +522,26 @@ impl Workflow {
             }
         }
 
+        // A branch task must hang directly off the decider that selects it
+        for (task_namespace, task) in &self.tasks {
+            let Some(branch) = task.branch() else {
+                continue;
+            };
+            let has_decider = task.data_dependencies().iter().any(|dependency| {
+                self.tasks
+                    .get(dependency)
+                    .is_some_and(|dep| dep.branch_key().is_some())
+            });
+            if !has_decider {
+                return Err(ValidationError::InvalidGraph {
+                    message: format!(
+                        "task '{}' declares branch '{}' but depends on no branch decider",
+                        task_namespace, branch
+                    ),
+                });
+            }
+        }
+
         // Check for cycles
         if self.dependency_graph.has_cycles() {
             let cycle = self
@@ -527,6 +617,27 @@ impl Workflow {
             .ok_or_else(|| WorkflowError::TaskNotFound(namespace.to_string()))
     }
 
+    /// Get the data dependencies of a task: its dependencies minus those
+    /// declared ordering-only (see [`Task::is_soft_dependency`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - Task namespace to get data dependencies for
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TaskNamespace>)` - Dependencies whose output feeds the task
+    /// * `Err(WorkflowError)` - If the task doesn't exist
+    pub fn get_data_dependencies(
+        &self,
+        namespace: &TaskNamespace,
+    ) -> Result<Vec<TaskNamespace>, WorkflowError> {
+        self.tasks
+            .get(namespace)
+            .map(|task| task.data_dependencies())
+            .ok_or_else(|| WorkflowError::TaskNotFound(namespace.to_string()))
+    }
+
     /// Get dependents of a task
     ///
     /// Returns tasks that depend on the given task.
@@ -587,6 +698,9 @@ impl Workflow {
         // Create new Workflow with subset of tasks
         let mut workflow = Workflow::new(&format!("{}-subgraph", self.name));
         workflow.metadata = self.metadata.clone();
+        workflow.max_active_runs = self.max_active_runs;
+        workflow.retry_policy = self.retry_policy.clone();
+        workflow.timeout = self.timeout;
 
         for task_namespace in &subgraph_tasks {
             if let Some(task) = self.tasks.get(task_namespace) {
@@ -1019,6 +1133,51 @@ mod tests {
         assert!(workflow.validate().is_ok());
     }
 
+    #[test]
+    fn test_workflow_branch_requires_decider() {
+        init_test_logging();
+
+        let body = |context: Context<serde_json::Value>| async move { Ok::<_, TaskError>(context) };
+        let mut workflow = Workflow::new("test-workflow");
+        workflow
+            .add_task(Arc::new(crate::FnTask::new("route", body).resolve(
+                "public",
+                "embedded",
+                "test-workflow",
+            )))
+            .unwrap();
+        workflow
+            .add_task(Arc::new(
+                crate::FnTask::new("fast", body)
+                    .depends_on("route")
+                    .branch("fast")
+                    .resolve("public", "embedded", "test-workflow"),
+            ))
+            .unwrap();
+        assert!(matches!(
+            workflow.validate(),
+            Err(ValidationError::InvalidGraph { .. })
+        ));
+
+        let mut workflow = Workflow::new("test-workflow");
+        workflow
+            .add_task(Arc::new(
+                crate::FnTask::new("route", body)
+                    .branch_key("path")
+                    .resolve("public", "embedded", "test-workflow"),
+            ))
+            .unwrap();
+        workflow
+            .add_task(Arc::new(
+                crate::FnTask::new("fast", body)
+                    .depends_on("route")
+                    .branch("fast")
+                    .resolve("public", "embedded", "test-workflow"),
+            ))
+            .unwrap();
+        assert!(workflow.validate().is_ok());
+    }
+
     #[test]
     fn test_workflow_cycle_detection() {
         init_test_logging();
@@ -1629,6 +1788,72 @@ mod tests {
         assert!(!ids.contains(&ns3));
     }
 
+    #[test]
+    fn test_max_active_runs() {
+        init_test_logging();
+
+        let workflow = Workflow::builder("test-workflow")
+            .max_active_runs(1)
+            .add_task(Arc::new(TestTask::new("task1", vec![])))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(workflow.max_active_runs(), Some(1));
+
+        let ns1 = TaskNamespace::new("public", "embedded", "test-workflow", "task1");
+        assert_eq!(
+            workflow.subgraph(&[&ns1]).unwrap().max_active_runs(),
+            Some(1)
+        );
+
+        let mut workflow = workflow;
+        workflow.set_max_active_runs(Some(0));
+        assert_eq!(workflow.max_active_runs(), None);
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        init_test_logging();
+
+        let workflow = Workflow::builder("test-workflow")
+            .retry_policy(RetryPolicy::builder().max_attempts(2).build())
+            .add_task(Arc::new(TestTask::new("task1", vec![])))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(workflow.retry_policy().map(|p| p.max_attempts), Some(2));
+
+        let ns1 = TaskNamespace::new("public", "embedded", "test-workflow", "task1");
+        assert!(workflow.subgraph(&[&ns1]).unwrap().retry_policy().is_some());
+
+        let mut workflow = workflow;
+        workflow.set_retry_policy(Some(RetryPolicy::builder().max_attempts(0).build()));
+        assert!(workflow.retry_policy().is_none());
+    }
+
+    #[test]
+    fn test_timeout() {
+        init_test_logging();
+
+        let workflow = Workflow::builder("test-workflow")
+            .timeout(std::time::Duration::from_secs(60))
+            .add_task(Arc::new(TestTask::new("task1", vec![])))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(workflow.timeout(), Some(std::time::Duration::from_secs(60)));
+
+        let ns1 = TaskNamespace::new("public", "embedded", "test-workflow", "task1");
+        assert_eq!(
+            workflow.subgraph(&[&ns1]).unwrap().timeout(),
+            Some(std::time::Duration::from_secs(60))
+        );
+
+        let mut workflow = workflow;
+        workflow.set_timeout(Some(std::time::Duration::ZERO));
+        assert_eq!(workflow.timeout(), None);
+    }
+
     #[test]
     fn test_subgraph_task_not_found() {
         init_test_logging();
@@ -27,24 +27,38 @@
 //! Params are delivered as FLAT top-level context keys — the same mapping the
 //! server's validated execute path uses — with the scheduler's reserved keys
 //! (`scheduled_time`, `schedule_id`, `schedule_timezone`,
-//! `schedule_expression`, `trigger_name`, `triggered_at`) always winning on
-//! conflict so a binding can never spoof them.
+//! `schedule_expression`, `is_recovery`, `trigger_name`, `triggered_at`, and
+//! the planner's `run_id` and `logical_date`) always winning on conflict so a
+//! binding can never spoof them.
 
 use serde::{Deserialize, Serialize};
 
 use cloacina_api_types::InputSlot;
 
-/// Context keys the cron/trigger schedulers own; instance params can never
-/// override these (they are stamped after the params merge).
+/// Context keys the cron/trigger schedulers and the execution planner own;
+/// instance params can never override these (they are stamped after the
+/// params merge).
 pub const RESERVED_FIRE_KEYS: &[&str] = &[
     "scheduled_time",
     "schedule_id",
     "schedule_timezone",
     "schedule_expression",
+    "is_recovery",
     "trigger_name",
     "triggered_at",
+    "run_id",
+    "logical_date",
 ];
 
+/// Prefix of context keys the runtime reserves (`__cloacina_secret_refs__`, ...).
+const RESERVED_KEY_PREFIX: &str = "__cloacina";
+
+/// Whether an externally supplied payload (a webhook body, a message) must
+/// not set `key`: it is a [`RESERVED_FIRE_KEYS`] entry or a runtime key.
+pub fn is_reserved_payload_key(key: &str) -> bool {
+    RESERVED_FIRE_KEYS.contains(&key) || key.starts_with(RESERVED_KEY_PREFIX)
+}
+
 /// Errors from building or using a workflow instance.
 #[derive(Debug, thiserror::Error)]
 pub enum WorkflowInstanceError {
@@ -212,6 +226,14 @@ impl WorkflowInstanceBuilder {
                             slot.name.clone(),
                         ));
                     }
+                    // A `{"$template": …}` binding is rendered at fire time;
+                    // reject a malformed marker now rather than on every fire.
+                    crate::templating::template_source(v).map_err(|e| {
+                        WorkflowInstanceError::InvalidParam {
+                            name: slot.name.clone(),
+                            message: e.to_string(),
+                        }
+                    })?;
                     resolved.insert(slot.name.clone(), v.clone());
                 }
                 None => match &slot.default {
@@ -237,7 +259,9 @@ impl WorkflowInstanceBuilder {
 /// top-level keys, SKIPPING the reserved scheduler keys (reserved always
 /// wins) and — via `Context::update` semantics on the caller side — letting
 /// bound params override a trigger-produced payload (OQ-3). Shared by the
-/// cron and trigger fire paths.
+/// cron and trigger fire paths, which render `{"$template": …}` params with
+/// [`crate::templating::render_params`] first; an unrendered marker is an
+/// error.
 pub fn merge_instance_params(
     context: &mut crate::Context<serde_json::Value>,
     params_json: &str,
@@ -263,6 +287,16 @@ pub fn merge_instance_params(
                 k
             ));
         }
+        if crate::templating::template_source(&v)
+            .map(|t| t.is_some())
+            .unwrap_or(true)
+        {
+            return Err(format!(
+                "instance param '{}' is a '{}' binding, which only a schedule fire renders",
+                k,
+                crate::templating::TEMPLATE_MARKER
+            ));
+        }
 
         match secret_ref_target(&v).map_err(|m| {
             format!(
@@ -381,6 +415,27 @@ mod tests {
         InputSlot::secret(name)
     }
 
+    #[test]
+    fn template_bindings_are_validated_and_never_merged_raw() {
+        let declared = vec![slot("path", true, None)];
+        let instance = WorkflowInstance::builder("export")
+            .param("path", serde_json::json!({"$template": "s3://b/{{ ds }}"}))
+            .unwrap()
+            .build(&declared)
+            .unwrap();
+
+        let err = WorkflowInstance::builder("export")
+            .param("path", serde_json::json!({"$template": 1}))
+            .unwrap()
+            .build(&declared)
+            .unwrap_err();
+        assert!(matches!(err, WorkflowInstanceError::InvalidParam { .. }));
+
+        // Merging without rendering first is refused.
+        let mut ctx = crate::Context::new();
+        assert!(merge_instance_params(&mut ctx, &instance.params_json().unwrap()).is_err());
+    }
+
     #[test]
     fn secret_ref_target_classifies_values() {
         // A well-formed reference.
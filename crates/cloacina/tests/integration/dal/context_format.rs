@@ -0,0 +1,232 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for context storage formats.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use std::sync::Arc;
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::context::Context;
+use cloacina::crypto::{AtRestEncryption, StaticKeyProvider};
+use cloacina::dal::DAL;
+use cloacina::ContextFormat;
+use serde_json::json;
+
+fn numeric_context(seed: i64) -> Context<serde_json::Value> {
+    let mut context = Context::new();
+    context
+        .insert(
+            "readings",
+            json!((0..500).map(|i| seed * 1000 + i).collect::<Vec<_>>()),
+        )
+        .unwrap();
+    context.insert("sensor", json!("north")).unwrap();
+    context
+}
+
+#[tokio::test]
+async fn test_rows_in_every_format_are_readable_and_convertible() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_rows_in_every_format_are_readable_and_convertible on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let json_dal = DAL::new(database.clone());
+        let json_id = json_dal
+            .context()
+            .create(&numeric_context(1))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Switching formats leaves the existing row readable.
+        let msgpack_dal = DAL::new(
+            database
+                .clone()
+                .with_context_format(ContextFormat::MessagePack),
+        );
+        let msgpack_id = msgpack_dal
+            .context()
+            .create(&numeric_context(2))
+            .await
+            .unwrap()
+            .unwrap();
+        for (dal, name) in [(&json_dal, "json"), (&msgpack_dal, "msgpack")] {
+            for (id, seed) in [(json_id, 1), (msgpack_id, 2)] {
+                let read = dal.context().read::<serde_json::Value>(id).await.unwrap();
+                assert_eq!(
+                    read.get("readings"),
+                    numeric_context(seed).get("readings"),
+                    "[{}] row {} read through the {} database",
+                    backend,
+                    seed,
+                    name
+                );
+            }
+        }
+
+        // Conversion rewrites only the JSON row, and is idempotent.
+        assert_eq!(
+            msgpack_dal.context().convert_format(1).await.unwrap(),
+            1,
+            "[{}]",
+            backend
+        );
+        assert_eq!(
+            msgpack_dal.context().convert_format(1).await.unwrap(),
+            0,
+            "[{}]",
+            backend
+        );
+
+        // Updating a row writes it in the current format.
+        let cbor_dal = DAL::new(database.clone().with_context_format(ContextFormat::Cbor));
+        let mut updated = numeric_context(3);
+        updated.insert("extra", json!(true)).unwrap();
+        cbor_dal.context().update(json_id, &updated).await.unwrap();
+
+        let read = json_dal
+            .context()
+            .read::<serde_json::Value>(json_id)
+            .await
+            .unwrap();
+        assert_eq!(read.get("extra"), Some(&json!(true)), "[{}]", backend);
+        assert_eq!(
+            json_dal.context().convert_format(10).await.unwrap(),
+            2,
+            "[{}] both binary rows go back to JSON",
+            backend
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_binary_values_are_stored_as_raw_bytes() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_binary_values_are_stored_as_raw_bytes on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let json_dal = DAL::new(database.clone());
+        let image: Vec<u8> = (0..4096).map(|i| (i % 251) as u8).collect();
+        let mut context = numeric_context(5);
+        context.insert_bytes("image", &image).unwrap();
+        let id = json_dal.context().create(&context).await.unwrap().unwrap();
+
+        let read = json_dal
+            .context()
+            .read::<serde_json::Value>(id)
+            .await
+            .unwrap();
+        assert_eq!(read.get_bytes("image").unwrap(), image, "[{}]", backend);
+
+        // The row went to MessagePack, not base64 JSON: both a JSON and a
+        // MessagePack database find it already in place.
+        assert_eq!(
+            json_dal.context().convert_format(10).await.unwrap(),
+            0,
+            "[{}]",
+            backend
+        );
+        let msgpack_dal = DAL::new(
+            database
+                .clone()
+                .with_context_format(ContextFormat::MessagePack),
+        );
+        assert_eq!(
+            msgpack_dal.context().convert_format(10).await.unwrap(),
+            0,
+            "[{}]",
+            backend
+        );
+
+        // Without binary values the row goes back to JSON.
+        json_dal
+            .context()
+            .update(id, &numeric_context(5))
+            .await
+            .unwrap();
+        assert_eq!(
+            msgpack_dal.context().convert_format(10).await.unwrap(),
+            1,
+            "[{}]",
+            backend
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_binary_contexts_are_encrypted_at_rest() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_binary_contexts_are_encrypted_at_rest on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let encryption = Arc::new(AtRestEncryption::new(Arc::new(
+            StaticKeyProvider::new("k1", vec![7; 32]).unwrap(),
+        )));
+        let database = guard.get_database();
+        let sealed = DAL::new(
+            database
+                .clone()
+                .with_context_format(ContextFormat::Cbor)
+                .with_at_rest_encryption(encryption),
+        );
+        let id = sealed
+            .context()
+            .create(&numeric_context(4))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let read = sealed
+            .context()
+            .read::<serde_json::Value>(id)
+            .await
+            .unwrap();
+        assert_eq!(read.get("sensor"), Some(&json!("north")), "[{}]", backend);
+
+        // Without the keys the payload cannot be opened.
+        assert!(
+            DAL::new(database)
+                .context()
+                .read::<serde_json::Value>(id)
+                .await
+                .is_err(),
+            "[{}]",
+            backend
+        );
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for context size limits and large-value offloading.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use std::sync::Arc;
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::context::Context;
+use cloacina::dal::{UnifiedRegistryStorage, DAL};
+use cloacina::error::ContextError;
+use cloacina::ContextOffload;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_large_values_are_offloaded_and_size_limit_enforced() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_large_values_are_offloaded_and_size_limit_enforced on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let offload = ContextOffload::new()
+            .with_store(
+                Box::new(UnifiedRegistryStorage::new(database.clone())),
+                1024,
+            )
+            .with_max_context_bytes(4096);
+        let dal = DAL::new(database.clone().with_context_offload(Arc::new(offload)));
+
+        // Far over the limit in total, but the product list goes to the
+        // blob store and only a reference stays in the row.
+        let products: Vec<_> = (0..10_000)
+            .map(|i| json!({"id": i, "name": format!("Product {}", i)}))
+            .collect();
+        let mut context = Context::<serde_json::Value>::new();
+        context.insert("products", json!(products)).unwrap();
+        context.insert("count", json!(10_000)).unwrap();
+
+        let id = dal
+            .context()
+            .create(&context)
+            .await
+            .expect("offloaded context fits the limit")
+            .unwrap();
+        let read = dal.context().read::<serde_json::Value>(id).await.unwrap();
+        assert_eq!(read.get("count"), Some(&json!(10_000)), "[{}]", backend);
+        assert_eq!(
+            read.get("products"),
+            Some(&json!(products)),
+            "[{}] offloaded value is restored on read",
+            backend
+        );
+
+        // Without the offload store the row only holds a reference.
+        let plain = DAL::new(database.clone());
+        assert!(
+            matches!(
+                plain.context().read::<serde_json::Value>(id).await,
+                Err(ContextError::Offload(_))
+            ),
+            "[{}] row keeps a reference, not the value",
+            backend
+        );
+
+        // Many values under the threshold stay inline and count against
+        // the limit.
+        let mut wide = Context::<serde_json::Value>::new();
+        for i in 0..20 {
+            wide.insert(format!("key_{}", i), json!("x".repeat(512)))
+                .unwrap();
+        }
+        assert!(
+            matches!(
+                dal.context().create(&wide).await,
+                Err(ContextError::TooLarge { limit: 4096, .. })
+            ),
+            "[{}] inline values over the limit are rejected",
+            backend
+        );
+
+        dal.context().delete(id).await.unwrap();
+    }
+}
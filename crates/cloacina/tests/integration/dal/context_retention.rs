@@ -0,0 +1,174 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for context retention.
+//!
+//! Verifies that purging drops the workflow and task-output contexts of
+//! finished executions while the execution rows themselves stay, and that
+//! unfinished executions and recently-finished ones are left alone.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::context::Context;
+use cloacina::dal::DAL;
+use cloacina::database::universal_types::UniversalTimestamp;
+use cloacina::models::task_execution::NewTaskExecution;
+use cloacina::models::task_execution_metadata::NewTaskExecutionMetadata;
+use cloacina::models::workflow_execution::NewWorkflowExecution;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_context_purge_keeps_execution_metadata() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_context_purge_keeps_execution_metadata on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let dal = DAL::new(database.clone());
+
+        let mut input = Context::<serde_json::Value>::new();
+        input.insert("payload", json!("x".repeat(64))).unwrap();
+
+        // A finished execution with an input context and one task output.
+        let finished_ctx = dal.context().create(&input).await.unwrap().unwrap();
+        let finished = dal
+            .workflow_execution()
+            .create(NewWorkflowExecution {
+                workflow_name: "retention-test".to_string(),
+                workflow_version: "1.0".to_string(),
+                status: "Running".to_string(),
+                context_id: Some(finished_ctx),
+            })
+            .await
+            .expect("Failed to create workflow execution");
+        let task = dal
+            .task_execution()
+            .create(NewTaskExecution {
+                workflow_execution_id: finished.id,
+                task_name: "retention-task".to_string(),
+                status: "Completed".to_string(),
+                attempt: 1,
+                max_attempts: 1,
+                trigger_rules: json!({"type": "Always"}).to_string(),
+                task_configuration: json!({}).to_string(),
+            })
+            .await
+            .expect("Failed to create task");
+        let output_ctx = dal.context().create(&input).await.unwrap().unwrap();
+        dal.task_execution_metadata()
+            .create(NewTaskExecutionMetadata {
+                task_execution_id: task.id,
+                workflow_execution_id: finished.id,
+                task_name: "retention-task".to_string(),
+                context_id: Some(output_ctx),
+            })
+            .await
+            .expect("Failed to create task metadata");
+        dal.workflow_execution()
+            .mark_completed(finished.id)
+            .await
+            .expect("Failed to complete execution");
+
+        // A still-running execution with its own context.
+        let running_ctx = dal.context().create(&input).await.unwrap().unwrap();
+        let running = dal
+            .workflow_execution()
+            .create(NewWorkflowExecution {
+                workflow_name: "retention-test".to_string(),
+                workflow_version: "1.0".to_string(),
+                status: "Running".to_string(),
+                context_id: Some(running_ctx),
+            })
+            .await
+            .expect("Failed to create workflow execution");
+
+        // A cutoff before the execution finished purges nothing.
+        let past = UniversalTimestamp(chrono::Utc::now() - chrono::Duration::hours(1));
+        let purged = dal
+            .context()
+            .purge_for_executions_completed_before(past, 100)
+            .await
+            .expect("Failed to purge contexts");
+        assert_eq!(purged, 0, "[{}] nothing finished before cutoff", backend);
+
+        let future = UniversalTimestamp(chrono::Utc::now() + chrono::Duration::hours(1));
+        let purged = dal
+            .context()
+            .purge_for_executions_completed_before(future, 100)
+            .await
+            .expect("Failed to purge contexts");
+        assert_eq!(purged, 1, "[{}] only the finished execution", backend);
+
+        let after = dal
+            .workflow_execution()
+            .get_by_id(finished.id)
+            .await
+            .expect("Execution metadata must survive the purge");
+        assert_eq!(after.status, "Completed", "[{}]", backend);
+        assert!(after.completed_at.is_some(), "[{}]", backend);
+        assert_eq!(after.context_id, None, "[{}]", backend);
+
+        let metadata = dal
+            .task_execution_metadata()
+            .get_by_task_execution(task.id)
+            .await
+            .expect("Task metadata must survive the purge");
+        assert_eq!(metadata.context_id, None, "[{}]", backend);
+
+        assert!(
+            dal.context()
+                .read::<serde_json::Value>(finished_ctx)
+                .await
+                .is_err(),
+            "[{}] workflow context should be deleted",
+            backend
+        );
+        assert!(
+            dal.context()
+                .read::<serde_json::Value>(output_ctx)
+                .await
+                .is_err(),
+            "[{}] task output context should be deleted",
+            backend
+        );
+
+        let untouched = dal
+            .workflow_execution()
+            .get_by_id(running.id)
+            .await
+            .unwrap();
+        assert_eq!(untouched.context_id, Some(running_ctx), "[{}]", backend);
+        dal.context()
+            .read::<serde_json::Value>(running_ctx)
+            .await
+            .expect("Running execution keeps its context");
+
+        // A second pass has nothing left to do.
+        let purged = dal
+            .context()
+            .purge_for_executions_completed_before(future, 100)
+            .await
+            .expect("Failed to purge contexts");
+        assert_eq!(purged, 0, "[{}] purge is idempotent", backend);
+    }
+}
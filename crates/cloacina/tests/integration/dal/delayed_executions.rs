@@ -0,0 +1,131 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for delayed executions.
+//!
+//! Verifies that only due rows are handed out, that a row can be claimed
+//! once, and that only pending rows can be cancelled.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use chrono::SubsecRound;
+use cloacina::dal::DAL;
+use cloacina::database::universal_types::UniversalUuid;
+
+#[tokio::test]
+async fn test_delayed_execution_lifecycle() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!("Running test_delayed_execution_lifecycle on {}", backend);
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let dal = DAL::new(guard.get_database());
+        let delayed = dal.delayed_execution();
+        // Whole seconds, so the values survive each backend's precision.
+        let now = chrono::Utc::now().trunc_subsecs(0);
+
+        assert!(delayed.next_due_time().await.unwrap().is_none());
+
+        let due = delayed
+            .create(
+                "report",
+                "{\"day\":1}".to_string(),
+                now - chrono::Duration::minutes(1),
+            )
+            .await
+            .unwrap();
+        let later = delayed
+            .create("report", "{}".to_string(), now + chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert!(due.is_pending());
+        assert_eq!(due.context, "{\"day\":1}");
+
+        assert_eq!(
+            delayed.next_due_time().await.unwrap(),
+            Some(now - chrono::Duration::minutes(1))
+        );
+        let pending: Vec<_> = delayed
+            .list_pending()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|d| d.id)
+            .collect();
+        assert_eq!(pending, vec![due.id, later.id]);
+
+        let stale_before = now - chrono::Duration::minutes(5);
+        let ready = delayed.get_due(now, stale_before, 10).await.unwrap();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].id, due.id);
+
+        // Only the first claim wins.
+        assert!(delayed.claim(due.id, now, stale_before).await.unwrap());
+        assert!(!delayed.claim(due.id, now, stale_before).await.unwrap());
+        assert!(delayed
+            .get_due(now, stale_before, 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // A claim that never recorded an outcome goes stale and can be
+        // claimed again, once.
+        let later_now = now + chrono::Duration::minutes(10);
+        let later_stale_before = later_now - chrono::Duration::minutes(5);
+        let stranded = delayed
+            .get_due(later_now, later_stale_before, 10)
+            .await
+            .unwrap();
+        assert_eq!(stranded.len(), 1);
+        assert_eq!(stranded[0].id, due.id);
+        assert!(delayed
+            .claim(due.id, later_now, later_stale_before)
+            .await
+            .unwrap());
+        assert!(!delayed
+            .claim(due.id, later_now, later_stale_before)
+            .await
+            .unwrap());
+
+        let execution_id = UniversalUuid::new_v4();
+        delayed
+            .record_outcome(due.id, Some(execution_id), None)
+            .await
+            .unwrap();
+        let started = delayed.get(due.id).await.unwrap().unwrap();
+        assert!(!started.is_pending());
+        assert_eq!(started.workflow_execution_id, Some(execution_id));
+        assert!(started.error.is_none());
+
+        // Once handed off, the claim never goes stale.
+        let much_later = now + chrono::Duration::hours(1);
+        assert!(delayed
+            .get_due(much_later - chrono::Duration::minutes(30), much_later, 10)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(!delayed.claim(due.id, much_later, much_later).await.unwrap());
+
+        // A started run can no longer be cancelled; a pending one can.
+        assert!(!delayed.cancel(due.id).await.unwrap());
+        assert!(delayed.cancel(later.id).await.unwrap());
+        assert!(delayed.get(later.id).await.unwrap().is_none());
+        assert!(delayed.next_due_time().await.unwrap().is_none());
+    }
+}
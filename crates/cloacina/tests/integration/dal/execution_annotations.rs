@@ -0,0 +1,122 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for operator annotations on executions.
+//!
+//! Verifies that annotations are stored with author and timestamp, listed
+//! oldest first, and that the latest annotation carrying a resolution wins.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::DAL;
+use cloacina::models::workflow_execution::{
+    ExecutionResolution, NewExecutionAnnotation, NewWorkflowExecution,
+};
+
+#[tokio::test]
+async fn test_execution_annotations_record_and_resolve() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_execution_annotations_record_and_resolve on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let dal = DAL::new(database.clone());
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let exec = dal
+                .workflow_execution()
+                .create(NewWorkflowExecution {
+                    workflow_name: "annotations-test".to_string(),
+                    workflow_version: "1.0".to_string(),
+                    status: "Failed".to_string(),
+                    context_id: None,
+                })
+                .await
+                .expect("Failed to create workflow execution");
+            ids.push(exec.id);
+        }
+
+        let first = dal
+            .workflow_execution()
+            .add_annotation(
+                ids[0],
+                NewExecutionAnnotation::new("oncall", "upstream outage, looking")
+                    .with_resolution(ExecutionResolution::Investigating),
+            )
+            .await
+            .expect("Failed to annotate execution");
+        assert_eq!(first.author, "oncall");
+        assert_eq!(first.resolution.as_deref(), Some("investigating"));
+
+        dal.workflow_execution()
+            .add_annotation(
+                ids[0],
+                NewExecutionAnnotation::new("oncall", "known outage, reran manually at 14:00")
+                    .with_resolution(ExecutionResolution::Rerun),
+            )
+            .await
+            .expect("Failed to annotate execution");
+        // A plain note does not clear the resolution.
+        dal.workflow_execution()
+            .add_annotation(ids[0], NewExecutionAnnotation::new("lead", "ack"))
+            .await
+            .expect("Failed to annotate execution");
+
+        // Malformed annotations are rejected without writing anything.
+        assert!(dal
+            .workflow_execution()
+            .add_annotation(ids[1], NewExecutionAnnotation::new("oncall", " "))
+            .await
+            .is_err());
+
+        let annotations = dal
+            .workflow_execution()
+            .list_annotations(ids[0])
+            .await
+            .expect("Failed to list annotations");
+        let notes: Vec<&str> = annotations.iter().map(|a| a.note.as_str()).collect();
+        assert_eq!(
+            notes,
+            vec![
+                "upstream outage, looking",
+                "known outage, reran manually at 14:00",
+                "ack"
+            ]
+        );
+        assert!(dal
+            .workflow_execution()
+            .list_annotations(ids[1])
+            .await
+            .unwrap()
+            .is_empty());
+
+        let resolutions = dal
+            .workflow_execution()
+            .get_resolutions_for(&ids)
+            .await
+            .unwrap();
+        assert_eq!(resolutions.get(&ids[0]).map(String::as_str), Some("rerun"));
+        assert!(!resolutions.contains_key(&ids[1]));
+    }
+}
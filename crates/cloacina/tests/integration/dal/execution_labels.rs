@@ -0,0 +1,145 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for execution labels.
+//!
+//! Verifies that labels are stored per execution, that re-labelling replaces
+//! values key by key, and that `list_filtered` narrows by label selector.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::unified::workflow_execution::ExecutionListFilter;
+use cloacina::dal::DAL;
+use cloacina::models::workflow_execution::{ExecutionLabels, NewWorkflowExecution};
+
+fn labels(pairs: &[(&str, &str)]) -> ExecutionLabels {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_execution_labels_set_get_and_filter() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_execution_labels_set_get_and_filter on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let dal = DAL::new(database.clone());
+
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let exec = dal
+                .workflow_execution()
+                .create(NewWorkflowExecution {
+                    workflow_name: "labels-test".to_string(),
+                    workflow_version: "1.0".to_string(),
+                    status: "Pending".to_string(),
+                    context_id: None,
+                })
+                .await
+                .expect("Failed to create workflow execution");
+            ids.push(exec.id);
+        }
+
+        dal.workflow_execution()
+            .set_labels(ids[0], &labels(&[("source", "api"), ("customer", "acme")]))
+            .await
+            .expect("Failed to label execution");
+        dal.workflow_execution()
+            .set_labels(
+                ids[1],
+                &labels(&[("source", "api"), ("customer", "globex")]),
+            )
+            .await
+            .expect("Failed to label execution");
+
+        // Re-labelling replaces the value of an existing key and keeps the rest.
+        dal.workflow_execution()
+            .set_labels(
+                ids[1],
+                &labels(&[("customer", "acme"), ("backfill", "true")]),
+            )
+            .await
+            .expect("Failed to relabel execution");
+        let stored = dal
+            .workflow_execution()
+            .get_labels(ids[1])
+            .await
+            .expect("Failed to get labels");
+        assert_eq!(
+            stored,
+            labels(&[
+                ("source", "api"),
+                ("customer", "acme"),
+                ("backfill", "true")
+            ])
+        );
+
+        // Unlabelled executions report no labels.
+        let none = dal.workflow_execution().get_labels(ids[2]).await.unwrap();
+        assert!(none.is_empty());
+
+        // Malformed labels are rejected without writing anything.
+        assert!(dal
+            .workflow_execution()
+            .set_labels(ids[2], &labels(&[("bad key", "x")]))
+            .await
+            .is_err());
+        assert!(dal
+            .workflow_execution()
+            .get_labels(ids[2])
+            .await
+            .unwrap()
+            .is_empty());
+
+        let filter = |terms: &[(&str, &str)]| ExecutionListFilter {
+            labels: terms
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            limit: 10,
+            ..Default::default()
+        };
+
+        let acme = dal
+            .workflow_execution()
+            .list_filtered(filter(&[("customer", "acme")]))
+            .await
+            .unwrap();
+        assert_eq!(acme.len(), 2);
+
+        let backfill = dal
+            .workflow_execution()
+            .list_filtered(filter(&[("customer", "acme"), ("backfill", "true")]))
+            .await
+            .unwrap();
+        assert_eq!(backfill.len(), 1);
+        assert_eq!(backfill[0].id, ids[1]);
+
+        let batch = dal.workflow_execution().get_labels_for(&ids).await.unwrap();
+        assert_eq!(batch.len(), 2);
+        assert!(!batch.contains_key(&ids[2]));
+    }
+}
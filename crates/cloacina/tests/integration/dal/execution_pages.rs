@@ -0,0 +1,136 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for keyset-paginated execution queries.
+//!
+//! Verifies that `list_page` visits every matching execution exactly once in
+//! both sort orders and applies its status and start-time filters.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::unified::workflow_execution::{ExecutionPageQuery, ExecutionSort};
+use cloacina::dal::DAL;
+use cloacina::models::workflow_execution::NewWorkflowExecution;
+use cloacina::UniversalUuid;
+
+async fn collect_pages(dal: &DAL, query: ExecutionPageQuery) -> Vec<UniversalUuid> {
+    let mut seen = Vec::new();
+    let mut after = None;
+    loop {
+        let page = dal
+            .workflow_execution()
+            .list_page(ExecutionPageQuery {
+                after,
+                ..query.clone()
+            })
+            .await
+            .expect("Failed to list page");
+        let Some(last) = page.last() else {
+            break;
+        };
+        after = Some((last.started_at, last.id));
+        seen.extend(page.iter().map(|e| e.id));
+    }
+    seen
+}
+
+#[tokio::test]
+async fn test_execution_pages_visit_each_execution_once() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_execution_pages_visit_each_execution_once on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let dal = DAL::new(database.clone());
+
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            let exec = dal
+                .workflow_execution()
+                .create(NewWorkflowExecution {
+                    workflow_name: "pages-test".to_string(),
+                    workflow_version: "1.0".to_string(),
+                    status: if i % 2 == 0 { "Completed" } else { "Failed" }.to_string(),
+                    context_id: None,
+                })
+                .await
+                .expect("Failed to create workflow execution");
+            ids.push(exec.id);
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+
+        let query = ExecutionPageQuery {
+            workflow_name: Some("pages-test".to_string()),
+            limit: 2,
+            ..Default::default()
+        };
+
+        let newest_first = collect_pages(&dal, query.clone()).await;
+        let mut expected = ids.clone();
+        expected.reverse();
+        assert_eq!(newest_first, expected);
+
+        let oldest_first = collect_pages(
+            &dal,
+            ExecutionPageQuery {
+                sort: ExecutionSort::OldestFirst,
+                ..query.clone()
+            },
+        )
+        .await;
+        assert_eq!(oldest_first, ids);
+
+        let failed = collect_pages(
+            &dal,
+            ExecutionPageQuery {
+                statuses: vec!["Failed".to_string()],
+                ..query.clone()
+            },
+        )
+        .await;
+        assert_eq!(failed, vec![ids[3], ids[1]]);
+
+        let third = dal.workflow_execution().get_by_id(ids[2]).await.unwrap();
+        let from_third = collect_pages(
+            &dal,
+            ExecutionPageQuery {
+                started_from: Some(third.started_at),
+                sort: ExecutionSort::OldestFirst,
+                ..query.clone()
+            },
+        )
+        .await;
+        assert_eq!(from_third, ids[2..].to_vec());
+
+        assert!(third.logical_date.is_some());
+        let by_run_id = collect_pages(
+            &dal,
+            ExecutionPageQuery {
+                run_id: third.run_id.clone(),
+                ..query
+            },
+        )
+        .await;
+        assert_eq!(by_run_id, vec![ids[2]]);
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for snapshot reads of an execution's state.
+//!
+//! Verifies that `WorkflowExecutionDAL::get_snapshot` returns the execution
+//! row, its task rows and its contexts together, and reflects a completed
+//! execution and its completed tasks at once.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::DAL;
+use cloacina::models::task_execution::NewTaskExecution;
+use cloacina::models::task_execution_metadata::NewTaskExecutionMetadata;
+use cloacina::models::workflow_execution::NewWorkflowExecution;
+use cloacina::Context;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_execution_snapshot_reads_execution_tasks_and_contexts() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_execution_snapshot_reads_execution_tasks_and_contexts on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let dal = DAL::new(database.clone());
+
+        let mut input = Context::new();
+        input.insert("day", json!("2026-03-01")).unwrap();
+        let input_id = dal
+            .context()
+            .create(&input)
+            .await
+            .expect("Failed to create context");
+
+        let wf_exec = dal
+            .workflow_execution()
+            .create(NewWorkflowExecution {
+                workflow_name: "snapshot-test".to_string(),
+                workflow_version: "1.0".to_string(),
+                status: "Running".to_string(),
+                context_id: input_id,
+            })
+            .await
+            .expect("Failed to create workflow execution");
+
+        let mut task_ids = Vec::new();
+        for name in ["extract", "load"] {
+            let task = dal
+                .task_execution()
+                .create(NewTaskExecution {
+                    workflow_execution_id: wf_exec.id,
+                    task_name: name.to_string(),
+                    status: "Running".to_string(),
+                    attempt: 1,
+                    max_attempts: 1,
+                    trigger_rules: json!({"type": "Always"}).to_string(),
+                    task_configuration: json!({}).to_string(),
+                })
+                .await
+                .expect("Failed to create task");
+            task_ids.push(task.id);
+        }
+
+        let mut output = Context::new();
+        output.insert("rows", json!(42)).unwrap();
+        let output_id = dal
+            .context()
+            .create(&output)
+            .await
+            .expect("Failed to create context");
+        dal.task_execution_metadata()
+            .upsert_task_execution_metadata(NewTaskExecutionMetadata {
+                task_execution_id: task_ids[0],
+                workflow_execution_id: wf_exec.id,
+                task_name: "extract".to_string(),
+                context_id: output_id,
+            })
+            .await
+            .expect("Failed to save task metadata");
+
+        let running = dal
+            .workflow_execution()
+            .get_snapshot(wf_exec.id)
+            .await
+            .expect("Failed to read snapshot");
+        assert_eq!(running.execution.status, "Running", "[{}]", backend);
+        assert_eq!(running.tasks.len(), 2, "[{}]", backend);
+        assert_eq!(
+            running.execution_context().and_then(|c| c.get("day")),
+            Some(&json!("2026-03-01")),
+            "[{}]",
+            backend
+        );
+        assert_eq!(
+            running.task_output(task_ids[0]).and_then(|c| c.get("rows")),
+            Some(&json!(42)),
+            "[{}]",
+            backend
+        );
+        assert!(running.task_output(task_ids[1]).is_none(), "[{}]", backend);
+
+        for task_id in &task_ids {
+            dal.task_execution()
+                .mark_completed(*task_id, None)
+                .await
+                .expect("Failed to complete task");
+        }
+        dal.workflow_execution()
+            .mark_completed(wf_exec.id)
+            .await
+            .expect("Failed to complete execution");
+
+        let completed = dal
+            .workflow_execution()
+            .get_snapshot(wf_exec.id)
+            .await
+            .expect("Failed to read snapshot");
+        assert_eq!(completed.execution.status, "Completed", "[{}]", backend);
+        assert!(
+            completed.tasks.iter().all(|t| t.status == "Completed"),
+            "[{}]",
+            backend
+        );
+
+        assert!(
+            dal.workflow_execution()
+                .get_snapshot(cloacina::UniversalUuid::new_v4())
+                .await
+                .is_err(),
+            "[{}]",
+            backend
+        );
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for the federation chain cursor.
+//!
+//! Verifies that completed executions page in `(completed_at, id)` order from
+//! a cursor without skipping or repeating rows, and that the cursor persists.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::DAL;
+use cloacina::database::universal_types::UniversalTimestamp;
+use cloacina::models::workflow_execution::NewWorkflowExecution;
+
+#[tokio::test]
+async fn test_completed_executions_page_from_a_persisted_cursor() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_completed_executions_page_from_a_persisted_cursor on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let dal = DAL::new(guard.get_database());
+        let since = UniversalTimestamp(chrono::Utc::now() - chrono::Duration::hours(1));
+
+        let mut completed = Vec::new();
+        for i in 0..5 {
+            let exec = dal
+                .workflow_execution()
+                .create(NewWorkflowExecution {
+                    workflow_name: "chain-source".to_string(),
+                    workflow_version: "1.0".to_string(),
+                    status: "Pending".to_string(),
+                    context_id: None,
+                })
+                .await
+                .unwrap();
+            // Leave one execution running; it must never be paged.
+            if i != 2 {
+                dal.workflow_execution()
+                    .mark_completed(exec.id)
+                    .await
+                    .unwrap();
+                completed.push(exec.id);
+            }
+        }
+        let until = UniversalTimestamp(chrono::Utc::now() + chrono::Duration::minutes(1));
+
+        // Page two at a time, persisting the cursor after every row.
+        let cursors = dal.federation_chain_cursor();
+        assert!(cursors
+            .get("chain-source->eu/load")
+            .await
+            .unwrap()
+            .is_none());
+        let mut seen = Vec::new();
+        loop {
+            let after = cursors.get("chain-source->eu/load").await.unwrap();
+            let page = dal
+                .workflow_execution()
+                .list_completed_after("chain-source", since, until, after, 2)
+                .await
+                .unwrap();
+            if page.is_empty() {
+                break;
+            }
+            for execution in page {
+                cursors
+                    .advance(
+                        "chain-source->eu/load",
+                        execution.completed_at.unwrap(),
+                        execution.id,
+                    )
+                    .await
+                    .unwrap();
+                seen.push(execution.id);
+            }
+        }
+
+        let mut expected = completed.clone();
+        expected.sort_by_key(|id| id.0);
+        let mut visited = seen.clone();
+        visited.sort_by_key(|id| id.0);
+        assert_eq!(
+            visited, expected,
+            "every completion is visited exactly once"
+        );
+        assert_eq!(
+            cursors
+                .get("chain-source->eu/load")
+                .await
+                .unwrap()
+                .unwrap()
+                .1,
+            *seen.last().unwrap()
+        );
+
+        // Other rules keep their own position.
+        assert!(cursors
+            .get("chain-source->us/load")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -16,13 +16,25 @@
 
 pub mod api_keys;
 pub mod context;
+pub mod context_format;
+pub mod context_offload;
+pub mod context_retention;
+pub mod delayed_executions;
 #[cfg(feature = "postgres")]
 pub mod delivery_relay;
+pub mod execution_annotations;
 pub mod execution_events;
+pub mod execution_labels;
+pub mod execution_pages;
+pub mod execution_snapshot;
+pub mod federation_chain_cursors;
 pub mod reactor_subscriptions;
 pub mod reconciler_e2e_load;
+pub mod runner_instances;
 pub mod sub_status;
 pub mod task_claiming;
+pub mod task_error_payload;
+pub mod workflow_deprecations;
 pub mod workflow_packages;
 pub mod workflow_registry;
 pub mod workflow_registry_reconciler_integration;
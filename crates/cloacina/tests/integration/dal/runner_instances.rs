@@ -0,0 +1,117 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for the runner instance registry behind blue/green
+//! handoff.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::DAL;
+use cloacina::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use cloacina::models::runner_instance::RunnerInstanceState;
+
+#[tokio::test]
+async fn test_runner_instance_handoff_lifecycle() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!(
+            "Running test_runner_instance_handoff_lifecycle on {}",
+            backend
+        );
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let database = guard.get_database();
+        let dal = DAL::new(database.clone());
+        let registry = dal.runner_instance();
+
+        let blue = UniversalUuid::new_v4();
+        let green = UniversalUuid::new_v4();
+        let registered = registry
+            .register(blue, Some("blue".to_string()), "1.0.0")
+            .await
+            .expect("Failed to register runner");
+        assert_eq!(registered.state(), Some(RunnerInstanceState::Active));
+        registry
+            .register(green, Some("green".to_string()), "1.1.0")
+            .await
+            .expect("Failed to register runner");
+        // A replica already on the successor's version is not a predecessor.
+        let teal = UniversalUuid::new_v4();
+        registry
+            .register(teal, Some("teal".to_string()), "1.1.0")
+            .await
+            .expect("Failed to register runner");
+
+        // A runner that has not heartbeated since the liveness cutoff is
+        // treated as gone and left alone.
+        let future = UniversalTimestamp(chrono::Utc::now() + chrono::Duration::hours(1));
+        let asked = registry
+            .request_handoff(green, "1.1.0", future)
+            .await
+            .expect("Failed to request handoff");
+        assert!(asked.is_empty(), "[{}] stale runners are skipped", backend);
+
+        let past = UniversalTimestamp(chrono::Utc::now() - chrono::Duration::minutes(1));
+        let asked = registry
+            .request_handoff(green, "1.1.0", past)
+            .await
+            .expect("Failed to request handoff");
+        assert_eq!(asked.len(), 1, "[{}]", backend);
+        assert_eq!(asked[0].id, blue, "[{}]", backend);
+        assert_eq!(asked[0].successor_id, Some(green), "[{}]", backend);
+
+        // The old runner learns about it on its next heartbeat.
+        let seen = registry.heartbeat(blue).await.expect("Failed to heartbeat");
+        assert_eq!(seen.state(), Some(RunnerInstanceState::Draining));
+
+        // A second request does not re-target a runner already draining.
+        let asked = registry
+            .request_handoff(green, "1.1.0", past)
+            .await
+            .expect("Failed to request handoff");
+        assert!(asked.is_empty(), "[{}]", backend);
+
+        registry
+            .mark_drained(blue)
+            .await
+            .expect("Failed to mark drained");
+        let drained = registry.get(blue).await.unwrap();
+        assert_eq!(drained.state(), Some(RunnerInstanceState::Drained));
+
+        // An ordinary shutdown only touches active rows.
+        registry
+            .mark_stopped(blue)
+            .await
+            .expect("Failed to mark stopped");
+        let drained = registry.get(blue).await.unwrap();
+        assert_eq!(drained.state(), Some(RunnerInstanceState::Drained));
+        registry
+            .mark_stopped(teal)
+            .await
+            .expect("Failed to mark stopped");
+        let stopped = registry.get(teal).await.unwrap();
+        assert_eq!(stopped.state(), Some(RunnerInstanceState::Stopped));
+
+        let all = registry.list().await.unwrap();
+        assert_eq!(all.len(), 3, "[{}]", backend);
+        let green_row = all.iter().find(|r| r.id == green).unwrap();
+        assert_eq!(green_row.state(), Some(RunnerInstanceState::Active));
+        assert_eq!(green_row.version, "1.1.0");
+    }
+}
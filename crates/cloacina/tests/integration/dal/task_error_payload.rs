@@ -0,0 +1,118 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for the `error_payload` column on task executions.
+//!
+//! Verifies that a structured `TaskErrorPayload` passed to
+//! `mark_failed_with_payload()` round-trips verbatim through the task row and
+//! the `TaskFailed` event, and that a plain `mark_failed()` clears it.
+
+use crate::fixtures::get_all_fixtures;
+use cloacina::dal::DAL;
+use cloacina::models::task_execution::NewTaskExecution;
+use cloacina::models::workflow_execution::NewWorkflowExecution;
+use cloacina::{ErrorCategory, TaskErrorPayload};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_task_error_payload_round_trip() {
+    for (backend, fixture) in get_all_fixtures().await {
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let dal = DAL::new(guard.get_database());
+
+        let wf_exec = dal
+            .workflow_execution()
+            .create(NewWorkflowExecution {
+                workflow_name: "error-payload-test".to_string(),
+                workflow_version: "1.0".to_string(),
+                status: "Running".to_string(),
+                context_id: None,
+            })
+            .await
+            .expect("Failed to create workflow execution");
+
+        let task = dal
+            .task_execution()
+            .create(NewTaskExecution {
+                workflow_execution_id: wf_exec.id,
+                task_name: "charge_card".to_string(),
+                status: "Running".to_string(),
+                attempt: 1,
+                max_attempts: 1,
+                trigger_rules: json!({"type": "Always"}).to_string(),
+                task_configuration: json!({}).to_string(),
+            })
+            .await
+            .expect("Failed to create task");
+
+        let payload = TaskErrorPayload::new("CARD_DECLINED", "The card was declined")
+            .with_category(ErrorCategory::External)
+            .with_details(json!({ "decline_code": "insufficient_funds", "retry_after": null }));
+
+        dal.task_execution()
+            .mark_failed_with_payload(task.id, "declined", Some(&payload), None)
+            .await
+            .expect("Failed to mark task failed");
+
+        let failed = dal
+            .task_execution()
+            .get_by_id(task.id)
+            .await
+            .expect("Failed to get task");
+        assert_eq!(failed.status, "Failed", "[{}]", backend);
+        assert_eq!(
+            failed.last_error.as_deref(),
+            Some("declined"),
+            "[{}]",
+            backend
+        );
+        assert_eq!(failed.error_payload, Some(payload.clone()), "[{}]", backend);
+
+        let events = dal
+            .execution_event()
+            .list_by_workflow(wf_exec.id)
+            .await
+            .expect("Failed to list events");
+        let failed_event = events
+            .iter()
+            .find(|e| e.event_type == "task_failed")
+            .expect("TaskFailed event");
+        let data: serde_json::Value =
+            serde_json::from_str(failed_event.event_data.as_deref().unwrap()).unwrap();
+        assert_eq!(data["payload"]["code"], "CARD_DECLINED", "[{}]", backend);
+        assert_eq!(data["payload"]["category"], "external", "[{}]", backend);
+        assert_eq!(
+            data["payload"]["details"]["decline_code"], "insufficient_funds",
+            "[{}]",
+            backend
+        );
+
+        // A later plain failure clears the payload.
+        dal.task_execution()
+            .mark_failed(task.id, "plain", None)
+            .await
+            .expect("Failed to mark task failed");
+        let plain = dal
+            .task_execution()
+            .get_by_id(task.id)
+            .await
+            .expect("Failed to get task");
+        assert_eq!(plain.error_payload, None, "[{}]", backend);
+    }
+}
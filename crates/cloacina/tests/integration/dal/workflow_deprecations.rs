@@ -0,0 +1,87 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for workflow deprecations.
+//!
+//! Verifies that re-deprecating keeps the original `deprecated_at`, that
+//! deprecations list soonest sunset first, and that clearing one removes it.
+//!
+//! Tests run on all enabled backends (SQLite, PostgreSQL) using `get_all_fixtures()`.
+
+use crate::fixtures::get_all_fixtures;
+use chrono::SubsecRound;
+use cloacina::dal::DAL;
+use cloacina::database::universal_types::UniversalTimestamp;
+
+#[tokio::test]
+async fn test_workflow_deprecation_lifecycle() {
+    for (backend, fixture) in get_all_fixtures().await {
+        tracing::info!("Running test_workflow_deprecation_lifecycle on {}", backend);
+
+        let mut guard = fixture.lock().unwrap_or_else(|e| e.into_inner());
+        guard.reset_database().await;
+        guard.initialize().await;
+
+        let dal = DAL::new(guard.get_database());
+        let deprecations = dal.workflow_deprecation();
+        // Whole seconds, so the values survive each backend's precision.
+        let now = chrono::Utc::now().trunc_subsecs(0);
+        let soon = UniversalTimestamp(now + chrono::Duration::days(7));
+        let later = UniversalTimestamp(now + chrono::Duration::days(90));
+
+        assert!(deprecations.get("old_etl").await.unwrap().is_none());
+
+        let first = deprecations
+            .deprecate("old_etl", None, Some(later))
+            .await
+            .unwrap();
+        assert_eq!(first.sunset_at, Some(later));
+        assert!(first.message.is_none());
+
+        // Re-deprecating replaces the sunset and message but not when the
+        // workflow was first deprecated.
+        let updated = deprecations
+            .deprecate("old_etl", Some("use etl_v2".to_string()), Some(soon))
+            .await
+            .unwrap();
+        assert_eq!(updated.sunset_at, Some(soon));
+        assert_eq!(updated.message.as_deref(), Some("use etl_v2"));
+        assert_eq!(updated.deprecated_at, first.deprecated_at);
+
+        deprecations
+            .deprecate("old_report", None, None)
+            .await
+            .unwrap();
+        deprecations
+            .deprecate("old_ingest", None, Some(later))
+            .await
+            .unwrap();
+
+        let listed: Vec<String> = deprecations
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|d| d.workflow_name)
+            .collect();
+        assert_eq!(listed, vec!["old_etl", "old_ingest", "old_report"]);
+
+        assert!(deprecations.remove("old_etl").await.unwrap());
+        assert!(!deprecations.remove("old_etl").await.unwrap());
+        assert!(deprecations.get("old_etl").await.unwrap().is_none());
+        assert_eq!(deprecations.list().await.unwrap().len(), 2);
+    }
+}
@@ -47,6 +47,8 @@ async fn test_store_and_get_package_metadata() {
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     };
 
     // Create a corresponding workflow_registry entry first
@@ -109,6 +111,8 @@ async fn test_store_duplicate_package_metadata() {
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     };
 
     // Create a corresponding workflow_registry entry first
@@ -190,6 +194,8 @@ async fn test_list_all_packages() {
             declared_params: vec![],
             declared_surfaces: vec![],
             task_docs: Default::default(),
+            provenance: None,
+            config: None,
         };
 
         package_names.push(test_metadata.package_name.clone());
@@ -243,6 +249,8 @@ async fn test_delete_package_metadata() {
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     };
 
     // Create a corresponding workflow_registry entry first
@@ -380,6 +388,8 @@ async fn test_store_package_with_complex_metadata() {
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     };
 
     // Create a corresponding workflow_registry entry first
@@ -457,6 +467,8 @@ async fn test_store_package_with_invalid_uuid() {
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     };
 
     // Try to store with invalid UUID
@@ -521,6 +533,8 @@ async fn test_package_versioning() {
         declared_params: vec![],
         declared_surfaces: vec![],
         task_docs: Default::default(),
+        provenance: None,
+        config: None,
     };
     workflow_packages_dal
         .store_package_metadata(&registry_id, &meta_v1, storage_type, None)
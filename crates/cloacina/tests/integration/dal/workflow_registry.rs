@@ -179,6 +179,97 @@ async fn test_register_and_get_workflow_package_with_fs_storage() {
 /// the compiler must still be persisted onto the stored metadata at build
 /// success. Drives the empty-artifact path of `mark_build_success_with_docs`
 /// with a declared-params list and asserts it lands on the stored metadata.
+/// Copy `from` into `to` recursively, skipping build output.
+fn copy_package_source(from: &std::path::Path, to: &std::path::Path) {
+    std::fs::create_dir_all(to).expect("create package dir");
+    for entry in std::fs::read_dir(from).expect("read package dir") {
+        let entry = entry.expect("dir entry");
+        let path = entry.path();
+        if entry.file_name() == "target" {
+            continue;
+        }
+        if path.is_dir() {
+            copy_package_source(&path, &to.join(entry.file_name()));
+        } else {
+            std::fs::copy(&path, to.join(entry.file_name())).expect("copy package file");
+        }
+    }
+}
+
+/// Registration config is validated against the package's `config_schema`,
+/// has the schema's defaults filled in, and is stored on the package
+/// metadata.
+#[tokio::test]
+#[serial]
+async fn test_register_with_config_stores_validated_config() {
+    let workspace_root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .expect("workspace root");
+    let work_dir = tempfile::TempDir::new().expect("temp dir");
+    let source = work_dir.path().join("source");
+    copy_package_source(
+        &workspace_root.join("examples/features/workflows/packaged-workflows"),
+        &source,
+    );
+    let manifest = std::fs::read_to_string(source.join("package.toml")).expect("package.toml");
+    std::fs::write(
+        source.join("package.toml"),
+        manifest.replace(
+            "[metadata]\n",
+            "[metadata]\nconfig_schema = \"config.schema.json\"\n",
+        ),
+    )
+    .expect("write package.toml");
+    std::fs::write(
+        source.join("config.schema.json"),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "batch_size": {"type": "integer", "minimum": 1},
+                "region": {"type": "string", "default": "us-east-1"}
+            },
+            "required": ["batch_size"]
+        })
+        .to_string(),
+    )
+    .expect("write config schema");
+    let archive = work_dir.path().join("configured.cloacina");
+    fidius_core::package::pack_package(&source, Some(&archive)).expect("pack package");
+
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let dal = fixture.get_dal();
+    let storage = fixture.create_storage();
+    let mut registry_dal = dal.workflow_registry(storage);
+
+    let invalid = registry_dal
+        .register_workflow_package_file(&archive, Some(serde_json::json!({"batch_size": 0})))
+        .await;
+    assert!(invalid.is_err(), "config violating the schema is rejected");
+
+    let package_id = registry_dal
+        .register_workflow_package_file(&archive, Some(serde_json::json!({"batch_size": 50})))
+        .await
+        .expect("register with config");
+    drive_to_success(&registry_dal, package_id).await;
+
+    let (metadata, _) = registry_dal
+        .get_workflow_package_by_id(package_id)
+        .await
+        .expect("get_workflow_package_by_id")
+        .expect("package present");
+    assert_eq!(
+        metadata.config,
+        Some(serde_json::json!({"batch_size": 50, "region": "us-east-1"}))
+    );
+}
+
 #[tokio::test]
 #[serial]
 async fn test_python_declared_params_persisted_on_empty_artifact_build() {
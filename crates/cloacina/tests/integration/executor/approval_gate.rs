@@ -0,0 +1,227 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Approval gate tasks, end to end through the executor.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::models::task_execution::ApprovalDecision;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[task(id = "ag_build", dependencies = [])]
+async fn ag_build(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("built", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "ag_release", dependencies = ["ag_build"], approval = true)]
+async fn ag_release(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("released", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "ag_announce", dependencies = ["ag_release"])]
+async fn ag_announce(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("announced", json!(true))?;
+    Ok(())
+}
+
+/// Registers build -> release (gated) -> announce and starts a runner.
+async fn gated_runner(database_url: &str, schema: &str, workflow_name: &str) -> DefaultRunner {
+    let ns = |id: &str| TaskNamespace::new("public", "embedded", workflow_name, id);
+    let workflow = Workflow::builder(workflow_name)
+        .description("Approval gate test workflow")
+        .add_task(Arc::new(ag_build_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            ag_release_task().with_dependencies(vec![ns("ag_build")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            ag_announce_task().with_dependencies(vec![ns("ag_release")]),
+        ))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let runtime = cloacina::Runtime::empty();
+    runtime.register_built_workflow(workflow);
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(1)
+        .build()
+        .unwrap();
+    DefaultRunner::builder()
+        .database_url(database_url)
+        .schema(schema)
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap()
+}
+
+/// Polls until the execution has a task awaiting approval.
+async fn wait_for_gate(runner: &DefaultRunner, execution_id: UniversalUuid) {
+    for _ in 0..100 {
+        let pending = runner.get_pending_approvals().await.unwrap();
+        if pending
+            .iter()
+            .any(|t| t.workflow_execution_id == execution_id)
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("task never reached AwaitingApproval");
+}
+
+fn unique_name(prefix: &str) -> String {
+    format!(
+        "{}_{}",
+        prefix,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    )
+}
+
+async fn statuses(
+    dal: &cloacina::dal::DAL,
+    execution_id: UniversalUuid,
+) -> HashMap<String, cloacina::models::task_execution::TaskExecution> {
+    dal.task_execution()
+        .get_all_tasks_for_workflow(execution_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| (t.task_name.rsplit("::").next().unwrap().to_string(), t))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_approved_task_runs_and_unblocks_downstream() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = unique_name("approval_gate_approve");
+    let runner = gated_runner(
+        &fixture.get_database_url(),
+        &fixture.get_schema(),
+        &workflow_name,
+    )
+    .await;
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+
+    let execution = runner
+        .execute_async(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    let exec_id = UniversalUuid(execution.execution_id);
+    wait_for_gate(&runner, exec_id).await;
+
+    let tasks = statuses(&dal, exec_id).await;
+    assert_eq!(tasks["ag_build"].status, "Completed");
+    assert_eq!(tasks["ag_release"].status, "AwaitingApproval");
+    assert_eq!(tasks["ag_announce"].status, "NotStarted");
+
+    let approval = runner
+        .approve_task(exec_id, "ag_release", "release-manager", Some("CAB-42"))
+        .await
+        .unwrap();
+    assert_eq!(approval.decision, ApprovalDecision::Approved);
+    assert!(
+        runner
+            .approve_task(exec_id, "ag_release", "release-manager", None)
+            .await
+            .is_err(),
+        "a task is decided once"
+    );
+
+    execution
+        .wait_for_completion_with_timeout(Some(Duration::from_secs(30)))
+        .await
+        .unwrap();
+    let approvals = runner.get_task_approvals(exec_id).await.unwrap();
+    runner.shutdown().await.unwrap();
+
+    let tasks = statuses(&dal, exec_id).await;
+    assert_eq!(tasks["ag_release"].status, "Completed");
+    assert_eq!(tasks["ag_announce"].status, "Completed");
+    assert_eq!(approvals.len(), 1);
+    assert_eq!(approvals[0].approver, "release-manager");
+    assert_eq!(approvals[0].comment.as_deref(), Some("CAB-42"));
+    assert!(approvals[0].task_name.ends_with("::ag_release"));
+}
+
+#[tokio::test]
+async fn test_rejected_task_fails_without_running() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = unique_name("approval_gate_reject");
+    let runner = gated_runner(
+        &fixture.get_database_url(),
+        &fixture.get_schema(),
+        &workflow_name,
+    )
+    .await;
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+
+    let execution = runner
+        .execute_async(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    let exec_id = UniversalUuid(execution.execution_id);
+    wait_for_gate(&runner, exec_id).await;
+
+    assert!(runner
+        .reject_task(exec_id, "ag_release", "  ", None)
+        .await
+        .is_err());
+    let rejection = runner
+        .reject_task(exec_id, "ag_release", "release-manager", Some("freeze"))
+        .await
+        .unwrap();
+    assert_eq!(rejection.decision, ApprovalDecision::Rejected);
+
+    let _ = execution
+        .wait_for_completion_with_timeout(Some(Duration::from_secs(30)))
+        .await;
+    runner.shutdown().await.unwrap();
+
+    let tasks = statuses(&dal, exec_id).await;
+    let release = &tasks["ag_release"];
+    assert_eq!(release.status, "Failed");
+    assert!(release.started_at.is_none(), "a rejected task never runs");
+    assert_eq!(
+        release.error_payload.as_ref().map(|p| p.code.as_str()),
+        Some("TASK_REJECTED")
+    );
+}
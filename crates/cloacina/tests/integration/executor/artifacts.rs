@@ -0,0 +1,136 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Run artifacts, end to end: a task stores files through its `TaskHandle`,
+//! a second store under the same name replaces the first, and the runner
+//! lists and reads them back for the execution.
+
+use cloacina::database::universal_types::UniversalUuid;
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::DefaultRunner;
+use cloacina::*;
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::fixtures::get_or_init_fixture;
+
+fn artifact_error(e: impl std::fmt::Display) -> TaskError {
+    TaskError::ExecutionFailed {
+        message: format!("store_artifact failed: {e}"),
+        task_id: "report_task".into(),
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+/// Stores a draft report, replaces it, stores a data file, and records
+/// whether a path-like name was refused.
+#[task(id = "report_task", dependencies = [])]
+async fn report_task(
+    context: &mut Context<Value>,
+    handle: &mut TaskHandle,
+) -> Result<(), TaskError> {
+    handle
+        .store_artifact("report.txt", b"draft".to_vec(), "text/plain")
+        .await
+        .map_err(artifact_error)?;
+    handle
+        .store_artifact("report.txt", b"final report".to_vec(), "text/plain")
+        .await
+        .map_err(artifact_error)?;
+    handle
+        .store_artifact("rows.csv", "id,value\n1,42\n", "text/csv")
+        .await
+        .map_err(artifact_error)?;
+
+    let rejected = handle
+        .store_artifact("../escape.txt", b"nope".to_vec(), "text/plain")
+        .await
+        .is_err();
+    context.insert("path_name_rejected", Value::Bool(rejected))?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_task_stored_artifacts_are_listed_and_read_back() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow = Workflow::builder("artifact_pipeline")
+        .add_task(Arc::new(report_task_task()))
+        .unwrap()
+        .build()
+        .unwrap();
+    let runtime = cloacina::Runtime::empty();
+    let namespace = TaskNamespace::new(
+        workflow.tenant(),
+        workflow.package(),
+        workflow.name(),
+        "report_task",
+    );
+    runtime.register_task(namespace, || {
+        Arc::new(report_task_task()) as Arc<dyn cloacina::Task>
+    });
+    runtime.register_workflow("artifact_pipeline".to_string(), {
+        let wf = workflow.clone();
+        move || wf.clone()
+    });
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let result = runner
+        .execute("artifact_pipeline", Context::new())
+        .await
+        .unwrap();
+    let execution_id = UniversalUuid(result.execution_id);
+    let artifacts = runner.get_execution_artifacts(execution_id).await.unwrap();
+    let report = runner
+        .read_execution_artifact(execution_id, "report.txt")
+        .await
+        .unwrap();
+    let missing = runner
+        .read_execution_artifact(execution_id, "escape.txt")
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Completed);
+    assert_eq!(
+        result.final_context.get("path_name_rejected"),
+        Some(&Value::Bool(true))
+    );
+
+    let mut names: Vec<&str> = artifacts.iter().map(|a| a.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["report.txt", "rows.csv"]);
+    assert!(artifacts
+        .iter()
+        .all(|a| a.task_name.ends_with("report_task")));
+
+    let (artifact, bytes) = report.expect("report.txt should be stored");
+    assert_eq!(bytes, b"final report");
+    assert_eq!(artifact.size_bytes, bytes.len() as i64);
+    assert_eq!(artifact.content_type, "text/plain");
+    assert!(missing.is_none());
+}
@@ -0,0 +1,173 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Conditional branches, end to end through the executor.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::fixtures::get_or_init_fixture;
+
+/// Decider: selects the `fast` branch.
+#[task(id = "br_route", dependencies = [], branch_key = "path")]
+async fn br_route(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("path", json!("fast"))?;
+    Ok(())
+}
+
+#[task(id = "br_fast", dependencies = ["br_route"], branch = "fast")]
+async fn br_fast(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("fast", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "br_fast_load", dependencies = ["br_fast"])]
+async fn br_fast_load(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("fast_loaded", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "br_slow", dependencies = ["br_route"], branch = "slow")]
+async fn br_slow(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("slow", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "br_slow_load", dependencies = ["br_slow"])]
+async fn br_slow_load(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("slow_loaded", json!(true))?;
+    Ok(())
+}
+
+/// Join after both branches: runs once the taken branch finishes.
+#[task(id = "br_join", dependencies = ["br_fast_load", "br_slow_load"])]
+async fn br_join(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("joined", json!(true))?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_branch_skips_unselected_subgraph() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let database_url = fixture.get_database_url();
+    let database = fixture.get_database();
+
+    let workflow_name = format!(
+        "branch_test_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let ns = |id: &str| TaskNamespace::new("public", "embedded", &workflow_name, id);
+    let workflow = Workflow::builder(&workflow_name)
+        .description("Branch test workflow")
+        .add_task(Arc::new(br_route_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            br_fast_task().with_dependencies(vec![ns("br_route")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            br_fast_load_task().with_dependencies(vec![ns("br_fast")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            br_slow_task().with_dependencies(vec![ns("br_route")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            br_slow_load_task().with_dependencies(vec![ns("br_slow")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            br_join_task().with_dependencies(vec![ns("br_fast_load"), ns("br_slow_load")]),
+        ))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let runtime = cloacina::Runtime::empty();
+    for task_ns in workflow.get_task_ids() {
+        let task = workflow.get_task(&task_ns).unwrap();
+        runtime.register_task(task_ns, move || task.clone());
+    }
+    runtime.register_workflow(workflow_name.clone(), {
+        let workflow = workflow.clone();
+        move || workflow.clone()
+    });
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(1)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::builder()
+        .database_url(&database_url)
+        .schema(&fixture.get_schema())
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let execution = runner
+        .execute_async(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    let exec_id = execution.execution_id;
+    execution.wait_for_completion().await.unwrap();
+    runner.shutdown().await.unwrap();
+
+    let dal = cloacina::dal::DAL::new(database);
+    let tasks: HashMap<String, (String, Option<String>)> = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(UniversalUuid(exec_id))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| {
+            (
+                t.task_name.rsplit("::").next().unwrap().to_string(),
+                (t.status, t.error_details),
+            )
+        })
+        .collect();
+
+    assert_eq!(tasks["br_route"].0, "Completed");
+    assert_eq!(tasks["br_fast"].0, "Completed");
+    assert_eq!(tasks["br_fast_load"].0, "Completed");
+    assert_eq!(
+        tasks["br_join"].0, "Completed",
+        "the join follows the taken branch"
+    );
+    for id in ["br_slow", "br_slow_load"] {
+        assert_eq!(tasks[id].0, "Skipped", "{id} is on the unselected branch");
+        let reason = tasks[id].1.as_deref().unwrap_or_default();
+        assert!(
+            reason.starts_with("BranchNotTaken: 'br_route' selected 'fast'"),
+            "{id}: {reason}"
+        );
+    }
+}
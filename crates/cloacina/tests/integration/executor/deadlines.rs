@@ -0,0 +1,113 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Execution deadlines, end to end: a task expected to outlast the deadline
+//! is not started and the execution fails with `DEADLINE_EXCEEDED`.
+
+use cloacina::database::universal_types::UniversalUuid;
+use cloacina::runner::{DefaultRunner, ExecutionOptions};
+use cloacina::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[tokio::test]
+async fn test_task_expected_past_deadline_fails_execution() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = format!(
+        "deadlines_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let load_ran = Arc::new(AtomicBool::new(false));
+    let ran = load_ran.clone();
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder(&workflow_name)
+        .task(FnTask::new("extract", |context| async move { Ok(context) }))
+        .unwrap()
+        .task(
+            FnTask::new("load", move |context| {
+                let ran = ran.clone();
+                async move {
+                    ran.store(true, Ordering::SeqCst);
+                    Ok(context)
+                }
+            })
+            .depends_on("extract")
+            .expected_duration(Duration::from_secs(3600)),
+        )
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let options = ExecutionOptions {
+        deadline: Some(chrono::Utc::now() + chrono::Duration::seconds(60)),
+        workflow_timeout: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let result = runner
+        .execute_with_options(&workflow_name, Context::new(), &options)
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Failed);
+    assert!(result.deadline_exceeded());
+    assert!(!result.timed_out());
+    assert!(result
+        .error_message
+        .as_deref()
+        .is_some_and(|m| m.starts_with("deadline exceeded")));
+    assert!(!load_ran.load(Ordering::SeqCst), "load must not start");
+
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+    let tasks = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(UniversalUuid(result.execution_id))
+        .await
+        .unwrap();
+    let load = tasks
+        .iter()
+        .find(|t| t.task_name.ends_with("::load"))
+        .unwrap();
+    assert_eq!(load.status, "Failed");
+    assert_eq!(
+        load.error_payload.as_ref().map(|p| p.code.as_str()),
+        Some(cloacina::error::DEADLINE_EXCEEDED_CODE)
+    );
+    let extract = tasks
+        .iter()
+        .find(|t| t.task_name.ends_with("::extract"))
+        .unwrap();
+    assert_eq!(extract.status, "Completed");
+}
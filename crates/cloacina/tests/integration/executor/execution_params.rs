@@ -0,0 +1,96 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Execution params, end to end: every task reads the params the execution
+//! was submitted with, they never enter the context, and the runner returns
+//! them for the execution.
+
+use cloacina::database::universal_types::UniversalUuid;
+use cloacina::runner::{DefaultRunner, ExecutionOptions};
+use cloacina::*;
+use serde_json::json;
+use std::time::Duration;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[tokio::test]
+async fn test_tasks_read_params_kept_out_of_context() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = format!(
+        "execution_params_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder(&workflow_name)
+        .task(FnTask::new("generate", |mut context| async move {
+            let iterations: u32 = context.param("iterations")?;
+            context.insert("rows", json!(iterations * 10))?;
+            Ok(context)
+        }))
+        .unwrap()
+        .task(
+            FnTask::new("backup", |mut context| async move {
+                let backup_type: String = context.param("backup_type")?;
+                context.insert("backup", json!(backup_type))?;
+                Ok(context)
+            })
+            .depends_on("generate"),
+        )
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let params = json!({ "iterations": 3, "backup_type": "full" })
+        .as_object()
+        .cloned()
+        .unwrap();
+    let options = ExecutionOptions {
+        params: Some(params.clone()),
+        workflow_timeout: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let result = runner
+        .execute_with_options(&workflow_name, Context::new(), &options)
+        .await
+        .unwrap();
+    let stored = runner
+        .get_execution_params(UniversalUuid(result.execution_id))
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Completed);
+    assert_eq!(result.final_context.get("rows"), Some(&json!(30)));
+    assert_eq!(result.final_context.get("backup"), Some(&json!("full")));
+    assert!(result.final_context.get("iterations").is_none());
+    assert_eq!(stored, params);
+}
@@ -0,0 +1,160 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Looping tasks, end to end: the body repeats on its own output until its
+//! `loop_until` condition holds, and gives up after `max_iterations`.
+
+use cloacina::database::universal_types::UniversalUuid;
+use cloacina::execution_planner::{TriggerCondition, TriggerRule, ValueOperator};
+use cloacina::executor::WorkflowExecutor;
+use cloacina::models::execution_event::ExecutionEventType;
+use cloacina::retry::RetryPolicy;
+use cloacina::runner::DefaultRunner;
+use cloacina::*;
+use serde_json::json;
+
+use crate::fixtures::get_or_init_fixture;
+
+fn until_converged() -> TriggerRule {
+    TriggerRule::All {
+        conditions: vec![TriggerCondition::ContextValue {
+            key: "converged".to_string(),
+            operator: ValueOperator::Equals,
+            value: json!(true),
+        }],
+    }
+}
+
+/// Counts up by one per iteration; converges once the count reaches `target`.
+async fn refine(
+    mut context: Context<serde_json::Value>,
+    target: i64,
+) -> Result<Context<serde_json::Value>, TaskError> {
+    let count = context.get("count").and_then(|c| c.as_i64()).unwrap_or(0) + 1;
+    if context.get("count").is_some() {
+        context.update("count", json!(count))?;
+        context.update("converged", json!(count >= target))?;
+    } else {
+        context.insert("count", json!(count))?;
+        context.insert("converged", json!(count >= target))?;
+    }
+    Ok(context)
+}
+
+fn unique_name(prefix: &str) -> String {
+    format!(
+        "{}_{}",
+        prefix,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    )
+}
+
+#[tokio::test]
+async fn test_loop_repeats_until_condition_holds() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = unique_name("loops");
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder(&workflow_name)
+        .task(FnTask::new("refine", |context| refine(context, 3)).loop_until(until_converged(), 10))
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+    let result = runner
+        .execute(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Completed);
+    assert_eq!(result.final_context.get("count"), Some(&json!(3)));
+
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+    let iterations: Vec<serde_json::Value> = dal
+        .execution_event()
+        .list_by_workflow(UniversalUuid(result.execution_id))
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|e| e.event_type == ExecutionEventType::TaskIterated.as_str())
+        .map(|e| serde_json::from_str(e.event_data.as_deref().unwrap()).unwrap())
+        .collect();
+    assert_eq!(iterations.len(), 3, "every iteration is recorded");
+    assert_eq!(iterations[2]["iteration"], 3);
+    assert_eq!(iterations[2]["condition_met"], true);
+}
+
+#[tokio::test]
+async fn test_loop_fails_after_max_iterations() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = unique_name("loops_limit");
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder(&workflow_name)
+        .task(
+            FnTask::new("refine", |context| refine(context, 100))
+                .loop_until(until_converged(), 2)
+                .retry_policy(RetryPolicy::builder().max_attempts(1).build()),
+        )
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+    let result = runner
+        .execute(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Failed);
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+    let tasks = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(UniversalUuid(result.execution_id))
+        .await
+        .unwrap();
+    assert_eq!(tasks[0].status, "Failed");
+    assert_eq!(
+        tasks[0].error_payload.as_ref().map(|p| p.code.as_str()),
+        Some(cloacina::error::LOOP_LIMIT_EXCEEDED_CODE)
+    );
+}
@@ -14,11 +14,25 @@
  *  limitations under the License.
  */
 
+pub mod approval_gate;
+pub mod artifacts;
+pub mod branches;
 pub mod claim_loss_cancellation;
 pub mod context_merging;
+pub mod deadlines;
 pub mod defer_until;
+pub mod execution_params;
+pub mod loops;
 pub mod multi_tenant;
+pub mod output_schema;
 pub mod pause_resume;
+pub mod replay;
+pub mod resource_pools;
 pub mod retry_condition;
 pub mod secret_no_leak;
+pub mod short_circuit;
+pub mod soft_dependencies;
+pub mod task_config;
 pub mod task_execution;
+pub mod workflow_timeout;
+pub mod workload_classes;
@@ -0,0 +1,117 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Declared task outputs, end to end: a task whose output context misses or
+//! mistypes a `produces` key fails itself with a schema violation naming
+//! every unmet declaration, instead of a dependent failing on the missing key.
+
+use cloacina::database::universal_types::UniversalUuid;
+use cloacina::executor::WorkflowExecutor;
+use cloacina::retry::RetryPolicy;
+use cloacina::runner::DefaultRunner;
+use cloacina::*;
+use serde_json::json;
+
+use crate::fixtures::get_or_init_fixture;
+
+fn unique_name(prefix: &str) -> String {
+    format!(
+        "{}_{}",
+        prefix,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    )
+}
+
+/// Registers `produce`, which writes `count` as `count_value` and declares it
+/// a number, and `consume`, which depends on it.
+fn register(runtime: &cloacina::Runtime, workflow_name: &str, count_value: serde_json::Value) {
+    Workflow::builder(workflow_name)
+        .task(
+            FnTask::new("produce", move |mut context| {
+                let count_value = count_value.clone();
+                async move {
+                    context.insert("count", count_value)?;
+                    Ok(context)
+                }
+            })
+            .produces("count", OutputKind::Number)
+            .produces("processed_data", OutputKind::Any)
+            .retry_policy(RetryPolicy::builder().max_attempts(1).build()),
+        )
+        .unwrap()
+        .task(
+            FnTask::new("consume", |mut context| async move {
+                context.insert("consumed", json!(true))?;
+                Ok(context)
+            })
+            .depends_on("produce"),
+        )
+        .unwrap()
+        .register(runtime)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_mistyped_or_missing_output_fails_producing_task() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = unique_name("output_schema");
+    let runtime = cloacina::Runtime::empty();
+    register(&runtime, &workflow_name, json!("three"));
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+    let result = runner
+        .execute(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Failed);
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+    let tasks = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(UniversalUuid(result.execution_id))
+        .await
+        .unwrap();
+    let produce = tasks
+        .iter()
+        .find(|t| t.task_name.ends_with("::produce"))
+        .unwrap();
+    assert_eq!(produce.status, "Failed");
+    let payload = produce.error_payload.as_ref().unwrap();
+    assert_eq!(payload.code, cloacina::error::OUTPUT_SCHEMA_VIOLATION_CODE);
+    assert_eq!(payload.category, ErrorCategory::Validation);
+    let violations = payload.details["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 2, "every unmet declaration is listed");
+    assert_eq!(violations[0]["key"], "count");
+    assert_eq!(violations[0]["expected"], "number");
+    assert_eq!(violations[0]["found"], "string");
+    assert_eq!(violations[1]["key"], "processed_data");
+    assert!(violations[1]["found"].is_null());
+}
@@ -0,0 +1,152 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Recording a finished execution and replaying it locally.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::replay::{ExecutionRecording, ReplayRunner};
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[task(id = "replay_load", dependencies = [])]
+async fn replay_load(context: &mut Context<Value>) -> Result<(), TaskError> {
+    let seed = context.get("seed").and_then(|v| v.as_i64()).unwrap_or(0);
+    context.insert("loaded", json!(seed + 1))?;
+    Ok(())
+}
+
+#[task(id = "replay_scale", dependencies = ["replay_load"])]
+async fn replay_scale(context: &mut Context<Value>) -> Result<(), TaskError> {
+    let loaded = context.get("loaded").and_then(|v| v.as_i64()).unwrap_or(0);
+    let factor = context.get("factor").and_then(|v| v.as_i64()).unwrap_or(2);
+    context.insert("scaled", json!(loaded * factor))?;
+    Ok(())
+}
+
+fn replay_workflow(name: &str) -> Workflow {
+    let load_ns = TaskNamespace::new("public", "embedded", name, "replay_load");
+    Workflow::builder(name)
+        .description("Replay test workflow")
+        .add_task(Arc::new(replay_load_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            replay_scale_task().with_dependencies(vec![load_ns]),
+        ))
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_replay_steps_through_and_reruns_a_recorded_execution() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let database_url = fixture.get_database_url();
+    let database = fixture.get_database();
+
+    let workflow_name = format!(
+        "replay_pipeline_test_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let workflow = replay_workflow(&workflow_name);
+
+    let runtime = cloacina::Runtime::empty();
+    for task_ns in workflow.get_task_ids() {
+        let task = workflow.get_task(&task_ns).unwrap();
+        runtime.register_task(task_ns, move || task.clone());
+    }
+    runtime.register_workflow(workflow_name.clone(), {
+        let workflow = workflow.clone();
+        move || workflow.clone()
+    });
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(1)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::builder()
+        .database_url(&database_url)
+        .schema(&fixture.get_schema())
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let mut input_context = Context::new();
+    input_context.insert("seed", json!(4)).unwrap();
+    let execution = runner
+        .execute_async(&workflow_name, input_context)
+        .await
+        .unwrap();
+    let exec_id = execution.execution_id;
+    execution.wait_for_completion().await.unwrap();
+    runner.shutdown().await.unwrap();
+
+    let dal = cloacina::dal::DAL::new(database.clone());
+    let recording = ExecutionRecording::load(&dal, UniversalUuid(exec_id))
+        .await
+        .unwrap();
+    assert_eq!(recording.initial_context.get("seed"), Some(&json!(4)));
+    assert_eq!(recording.steps.len(), 2);
+
+    // A recording survives a trip through a file.
+    let recording = ExecutionRecording::from_json(&recording.to_json().unwrap()).unwrap();
+    let mut replay = ReplayRunner::new(recording, replay_workflow(&workflow_name));
+
+    // Steps come back in execution order.
+    assert_eq!(replay.current().unwrap().task_id(), "replay_load");
+    assert_eq!(replay.step_forward().unwrap().task_id(), "replay_scale");
+    assert!(replay.step_forward().is_none());
+    assert_eq!(replay.step_back().unwrap().task_id(), "replay_scale");
+
+    // Inputs are rebuilt from the recorded contexts.
+    let input = replay.input("replay_scale").unwrap();
+    assert_eq!(input.get("loaded"), Some(&json!(5)));
+    let diff = replay.diff("replay_scale").unwrap();
+    assert_eq!(diff.added.get("scaled"), Some(&json!(10)));
+    assert!(diff.removed.is_empty() && diff.changed.is_empty());
+
+    // An unmodified re-run reproduces the recorded output...
+    let outcome = replay.rerun("replay_scale", []).await.unwrap();
+    assert!(outcome.diff_from_recorded.unwrap().is_empty());
+
+    // ...and a modified input shows up as a diff against it.
+    let outcome = replay
+        .rerun("replay_scale", [("factor".to_string(), json!(3))])
+        .await
+        .unwrap();
+    assert_eq!(outcome.input.get("factor"), Some(&json!(3)));
+    let diff = outcome.diff_from_recorded.unwrap();
+    assert_eq!(diff.changed.get("scaled"), Some(&(json!(10), json!(15))));
+    assert_eq!(diff.added.get("factor"), Some(&json!(3)));
+
+    assert!(matches!(
+        replay.seek("missing"),
+        Err(cloacina::replay::ReplayError::NotRecorded(_))
+    ));
+}
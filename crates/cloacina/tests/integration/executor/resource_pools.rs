@@ -0,0 +1,104 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Resource pool concurrency limits, end to end.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::fixtures::get_or_init_fixture;
+
+/// Tracks how many probe tasks run at once.
+#[derive(Default)]
+struct Probe {
+    running: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+fn probe_task(id: &str, probe: Arc<Probe>, resource: &str) -> FnTask {
+    FnTask::new(id, move |context| {
+        let probe = probe.clone();
+        async move {
+            let now = probe.running.fetch_add(1, Ordering::SeqCst) + 1;
+            probe.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            probe.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(context)
+        }
+    })
+    .resource(resource)
+}
+
+#[tokio::test]
+async fn test_resource_pool_caps_concurrent_tasks() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = format!(
+        "resource_pools_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let api_probe = Arc::new(Probe::default());
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder(&workflow_name)
+        .task(probe_task("call_a", api_probe.clone(), "partner_api"))
+        .unwrap()
+        .task(probe_task("call_b", api_probe.clone(), "partner_api"))
+        .unwrap()
+        .task(probe_task("call_c", api_probe.clone(), "partner_api"))
+        .unwrap()
+        .task(probe_task(
+            "warm_cache",
+            Arc::new(Probe::default()),
+            "unpooled",
+        ))
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(4)
+        .resource_pool("partner_api", 1)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let result = runner
+        .execute(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(result.status, WorkflowStatus::Completed);
+    assert_eq!(api_probe.peak.load(Ordering::SeqCst), 1);
+}
@@ -242,3 +242,90 @@ async fn test_secret_resolution_does_not_leak_plaintext_sqlite() {
     // Keep seed_db alive until the very end so the shared in-memory DB persists.
     drop(seed_db);
 }
+
+/// A task that copies a resolved secret straight into its output context.
+#[task(id = "careless_secret_task", dependencies = [])]
+async fn careless_secret_task(context: &mut Context<Value>) -> Result<(), TaskError> {
+    let api_key = context
+        .get_secret("api_key")
+        .await
+        .map_err(|e| TaskError::Unknown {
+            task_id: "careless_secret_task".to_string(),
+            message: format!("secret resolution failed: {e}"),
+        })?;
+    context.insert(
+        "auth_header",
+        serde_json::json!(format!("Bearer {api_key}")),
+    )?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_secret_written_into_context_is_redacted_sqlite() {
+    let url = format!(
+        "file:secret_redact_{}?mode=memory&cache=shared",
+        uuid::Uuid::new_v4()
+    );
+    let resolver: Arc<dyn SecretResolver> = Arc::new(
+        cloacina_workflow::secret::MapSecretResolver::new(std::collections::BTreeMap::from([(
+            "api_key".to_string(),
+            std::collections::BTreeMap::from([("value".to_string(), SECRET_PASSWORD.to_string())]),
+        )])),
+    );
+
+    let workflow_name = format!("secret_redact_wf_{}", uuid::Uuid::new_v4().simple());
+    let workflow = Workflow::builder(&workflow_name)
+        .add_task(Arc::new(careless_secret_task_task()))
+        .unwrap()
+        .build()
+        .unwrap();
+    let runtime = cloacina::Runtime::empty();
+    let namespace = TaskNamespace::new(
+        workflow.tenant(),
+        workflow.package(),
+        workflow.name(),
+        "careless_secret_task",
+    );
+    runtime.register_task(namespace, || {
+        Arc::new(careless_secret_task_task()) as Arc<dyn cloacina::Task>
+    });
+    runtime.register_workflow(workflow_name.clone(), {
+        let workflow = workflow.clone();
+        move || workflow.clone()
+    });
+
+    let runner = DefaultRunner::builder()
+        .database_url(&url)
+        .with_config(
+            DefaultRunnerConfig::builder()
+                .max_concurrent_tasks(1)
+                .build()
+                .unwrap(),
+        )
+        .runtime(runtime)
+        .secret_resolver(resolver)
+        .build()
+        .await
+        .unwrap();
+
+    let result = runner
+        .execute_async(&workflow_name, Context::new())
+        .await
+        .unwrap()
+        .wait_for_completion()
+        .await
+        .unwrap();
+    assert_eq!(result.status, WorkflowStatus::Completed);
+    assert_eq!(
+        result.final_context.get("auth_header"),
+        Some(&serde_json::json!("Bearer [REDACTED]"))
+    );
+    for row in dump_contexts(runner.database()).await {
+        assert!(
+            !row.contains(SECRET_PASSWORD),
+            "LEAK: secret plaintext found in a durable contexts row: {row}"
+        );
+    }
+
+    runner.shutdown().await.unwrap();
+}
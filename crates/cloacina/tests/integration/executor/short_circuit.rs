@@ -0,0 +1,151 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Short-circuiting on empty task results, end to end through the executor.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::fixtures::get_or_init_fixture;
+
+/// Finds nothing new: `rows` is empty, so every descendant is skipped.
+#[task(id = "sc_fetch", dependencies = [], short_circuit = "rows")]
+async fn sc_fetch(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("rows", json!([]))?;
+    Ok(())
+}
+
+#[task(id = "sc_transform", dependencies = ["sc_fetch"])]
+async fn sc_transform(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("transformed", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "sc_load", dependencies = ["sc_transform"])]
+async fn sc_load(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("loaded", json!(true))?;
+    Ok(())
+}
+
+/// Independent branch with a non-empty short-circuit key; it must not skip.
+#[task(id = "sc_heartbeat", dependencies = [], short_circuit = "beat")]
+async fn sc_heartbeat(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("beat", json!(1))?;
+    Ok(())
+}
+
+#[task(id = "sc_notify", dependencies = ["sc_heartbeat"])]
+async fn sc_notify(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("notified", json!(true))?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_short_circuit_skips_all_descendants() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let database_url = fixture.get_database_url();
+    let database = fixture.get_database();
+
+    let workflow_name = format!(
+        "short_circuit_test_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let ns = |id: &str| TaskNamespace::new("public", "embedded", &workflow_name, id);
+    let workflow = Workflow::builder(&workflow_name)
+        .description("Short-circuit test workflow")
+        .add_task(Arc::new(sc_fetch_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            sc_transform_task().with_dependencies(vec![ns("sc_fetch")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            sc_load_task().with_dependencies(vec![ns("sc_transform")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(sc_heartbeat_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            sc_notify_task().with_dependencies(vec![ns("sc_heartbeat")]),
+        ))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let runtime = cloacina::Runtime::empty();
+    for task_ns in workflow.get_task_ids() {
+        let task = workflow.get_task(&task_ns).unwrap();
+        runtime.register_task(task_ns, move || task.clone());
+    }
+    runtime.register_workflow(workflow_name.clone(), {
+        let workflow = workflow.clone();
+        move || workflow.clone()
+    });
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(1)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::builder()
+        .database_url(&database_url)
+        .schema(&fixture.get_schema())
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let execution = runner
+        .execute_async(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    let exec_id = execution.execution_id;
+    execution.wait_for_completion().await.unwrap();
+    runner.shutdown().await.unwrap();
+
+    let dal = cloacina::dal::DAL::new(database);
+    let statuses: HashMap<String, String> = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(UniversalUuid(exec_id))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| {
+            (
+                t.task_name.rsplit("::").next().unwrap().to_string(),
+                t.status,
+            )
+        })
+        .collect();
+
+    assert_eq!(statuses["sc_fetch"], "Completed");
+    assert_eq!(statuses["sc_transform"], "Skipped");
+    assert_eq!(statuses["sc_load"], "Skipped", "skips reach grandchildren");
+    assert_eq!(statuses["sc_heartbeat"], "Completed");
+    assert_eq!(statuses["sc_notify"], "Completed");
+}
@@ -0,0 +1,173 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Soft (ordering-only) dependency edges.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[task(id = "soft_extract", dependencies = [])]
+async fn soft_extract(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("rows", json!(3))?;
+    Ok(())
+}
+
+/// Completes without the `cleaned` key, so it short-circuits its data
+/// dependents.
+#[task(id = "soft_cleanup", dependencies = ["soft_extract"], short_circuit = "cleaned")]
+async fn soft_cleanup(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("cleanup_marker", json!(true))?;
+    Ok(())
+}
+
+#[task(id = "soft_report", dependencies = ["soft_extract", soft("soft_cleanup")])]
+async fn soft_report(context: &mut Context<Value>) -> Result<(), TaskError> {
+    let saw_cleanup = context.get("cleanup_marker").is_some();
+    context.insert("report_saw_cleanup", json!(saw_cleanup))?;
+    Ok(())
+}
+
+#[task(id = "soft_audit", dependencies = ["soft_cleanup"])]
+async fn soft_audit(context: &mut Context<Value>) -> Result<(), TaskError> {
+    context.insert("audited", json!(true))?;
+    Ok(())
+}
+
+#[test]
+fn test_soft_dependency_macro_metadata() {
+    assert_eq!(
+        SoftReportTask::dependency_task_ids(),
+        &["soft_extract", "soft_cleanup"]
+    );
+    assert_eq!(
+        SoftReportTask::soft_dependency_task_ids(),
+        &["soft_cleanup"]
+    );
+
+    let extract = TaskNamespace::new("public", "embedded", "wf", "soft_extract");
+    let cleanup = TaskNamespace::new("public", "embedded", "wf", "soft_cleanup");
+    let task = soft_report_task().with_dependencies(vec![extract.clone(), cleanup.clone()]);
+    assert!(task.is_soft_dependency(&cleanup));
+    assert!(!task.is_soft_dependency(&extract));
+    assert_eq!(task.data_dependencies(), vec![extract]);
+}
+
+#[tokio::test]
+async fn test_soft_dependency_orders_without_data_or_skip_propagation() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let database_url = fixture.get_database_url();
+    let database = fixture.get_database();
+
+    let workflow_name = format!(
+        "soft_deps_test_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let ns = |id: &str| TaskNamespace::new("public", "embedded", &workflow_name, id);
+    let workflow = Workflow::builder(&workflow_name)
+        .description("Soft dependency test workflow")
+        .add_task(Arc::new(soft_extract_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            soft_cleanup_task().with_dependencies(vec![ns("soft_extract")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            soft_report_task().with_dependencies(vec![ns("soft_extract"), ns("soft_cleanup")]),
+        ))
+        .unwrap()
+        .add_task(Arc::new(
+            soft_audit_task().with_dependencies(vec![ns("soft_cleanup")]),
+        ))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let runtime = cloacina::Runtime::empty();
+    for task_ns in workflow.get_task_ids() {
+        let task = workflow.get_task(&task_ns).unwrap();
+        runtime.register_task(task_ns, move || task.clone());
+    }
+    runtime.register_workflow(workflow_name.clone(), {
+        let workflow = workflow.clone();
+        move || workflow.clone()
+    });
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(1)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::builder()
+        .database_url(&database_url)
+        .schema(&fixture.get_schema())
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    let execution = runner
+        .execute_async(&workflow_name, Context::new())
+        .await
+        .unwrap();
+    let exec_id = execution.execution_id;
+    execution.wait_for_completion().await.unwrap();
+    runner.shutdown().await.unwrap();
+
+    let dal = cloacina::dal::DAL::new(database.clone());
+    let tasks: HashMap<String, _> = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(UniversalUuid(exec_id))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| (t.task_name.rsplit("::").next().unwrap().to_string(), t))
+        .collect();
+
+    // The data dependent of the short-circuiting task is skipped...
+    assert_eq!(tasks["soft_audit"].status, "Skipped");
+    // ...but the soft dependent still runs, after it.
+    let report = &tasks["soft_report"];
+    assert_eq!(report.status, "Completed");
+    assert!(report.started_at.unwrap().0 >= tasks["soft_cleanup"].completed_at.unwrap().0);
+
+    // And it received only its data dependency's context.
+    let metadata = dal
+        .task_execution_metadata()
+        .get_by_task_execution(report.id)
+        .await
+        .unwrap();
+    let output = dal
+        .context()
+        .read::<Value>(metadata.context_id.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(output.get("rows"), Some(&json!(3)));
+    assert_eq!(output.get("report_saw_cleanup"), Some(&json!(false)));
+}
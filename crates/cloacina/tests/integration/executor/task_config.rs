@@ -0,0 +1,120 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Declarative task configs, rendered at schedule time and delivered to the task.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::*;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[task(
+    id = "tc_export",
+    dependencies = [],
+    config = r#"{"target": "s3://bucket/{{ ds }}/{{ context.region | upper }}.parquet", "rows": "{{ context.rows }}"}"#
+)]
+async fn tc_export(context: &mut Context<Value>) -> Result<(), TaskError> {
+    let config: Value = context.task_config()?.unwrap_or_default();
+    context.insert("seen_config", config)?;
+    Ok(())
+}
+
+#[task(id = "tc_report", dependencies = ["tc_export"])]
+async fn tc_report(context: &mut Context<Value>) -> Result<(), TaskError> {
+    let inherited = context.task_config::<Value>()?.is_some();
+    context.insert("report_saw_config", json!(inherited))?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_task_config_is_rendered_and_scoped_to_its_task() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let database_url = fixture.get_database_url();
+
+    let workflow_name = format!(
+        "task_config_test_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let ns = |id: &str| TaskNamespace::new("public", "embedded", &workflow_name, id);
+    let workflow = Workflow::builder(&workflow_name)
+        .add_task(Arc::new(tc_export_task()))
+        .unwrap()
+        .add_task(Arc::new(
+            tc_report_task().with_dependencies(vec![ns("tc_export")]),
+        ))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let runtime = cloacina::Runtime::empty();
+    for task_ns in workflow.get_task_ids() {
+        let task = workflow.get_task(&task_ns).unwrap();
+        runtime.register_task(task_ns, move || task.clone());
+    }
+    runtime.register_workflow(workflow_name.clone(), {
+        let workflow = workflow.clone();
+        move || workflow.clone()
+    });
+
+    let config = DefaultRunnerConfig::builder()
+        .max_concurrent_tasks(1)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::builder()
+        .database_url(&database_url)
+        .schema(&fixture.get_schema())
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    // A cron-shaped fire: `ds` follows the schedule's timezone.
+    let mut input = Context::new();
+    input.insert("region", json!("eu")).unwrap();
+    input.insert("rows", json!(250)).unwrap();
+    input
+        .insert("scheduled_time", json!("2026-03-09T06:30:00+00:00"))
+        .unwrap();
+    input
+        .insert("schedule_timezone", json!("America/Los_Angeles"))
+        .unwrap();
+
+    let execution = runner.execute_async(&workflow_name, input).await.unwrap();
+    let result = execution.wait_for_completion().await.unwrap();
+    runner.shutdown().await.unwrap();
+
+    let final_context = result.final_context;
+    assert_eq!(
+        final_context.get("seen_config"),
+        Some(&json!({"target": "s3://bucket/2026-03-08/EU.parquet", "rows": 250}))
+    );
+    assert_eq!(final_context.get("report_saw_config"), Some(&json!(false)));
+    assert!(final_context
+        .get(cloacina_workflow::context::TASK_CONFIG_KEY)
+        .is_none());
+}
@@ -1112,6 +1112,18 @@ async fn run_workflow_and_get_status(
     workflow_name: &str,
     task_defs: Vec<(&str, TaskCtor)>,
     dep_map: Vec<(&str, Vec<&str>)>,
+) -> String {
+    run_workflow(workflow_name, task_defs, dep_map, None).await
+}
+
+/// Like [`run_workflow_and_get_status`], but once the execution ends, marks
+/// `resolve_task` as manually resolved and waits for the execution to end
+/// again.
+async fn run_workflow(
+    workflow_name: &str,
+    task_defs: Vec<(&str, TaskCtor)>,
+    dep_map: Vec<(&str, Vec<&str>)>,
+    resolve_task: Option<&str>,
 ) -> String {
     let fixture = get_or_init_fixture().await;
     let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
@@ -1192,6 +1204,36 @@ async fn run_workflow_and_get_status(
 
     // Poll until workflow execution reaches a terminal state
     let dal = cloacina::dal::DAL::new(database.clone());
+    wait_for_terminal(&dal, exec_id).await;
+
+    if let Some(task) = resolve_task {
+        let resolved = runner
+            .resolve_failed_task(
+                UniversalUuid(exec_id),
+                task,
+                cloacina::models::workflow_execution::NewExecutionAnnotation::new(
+                    "oncall",
+                    "side effect completed by hand",
+                ),
+            )
+            .await
+            .unwrap();
+        assert!(resolved.execution_reopened);
+        assert_eq!(resolved.annotation.resolution.as_deref(), Some("resolved"));
+        wait_for_terminal(&dal, exec_id).await;
+    }
+
+    let exec = dal
+        .workflow_execution()
+        .get_by_id(UniversalUuid(exec_id))
+        .await
+        .unwrap();
+
+    runner.shutdown().await.unwrap();
+    exec.status
+}
+
+async fn wait_for_terminal(dal: &cloacina::dal::DAL, exec_id: uuid::Uuid) {
     crate::fixtures::poll_until(
         Duration::from_secs(15),
         Duration::from_millis(100),
@@ -1212,15 +1254,6 @@ async fn run_workflow_and_get_status(
         },
     )
     .await;
-
-    let exec = dal
-        .workflow_execution()
-        .get_by_id(UniversalUuid(exec_id))
-        .await
-        .unwrap();
-
-    runner.shutdown().await.unwrap();
-    exec.status
 }
 
 /// COR-01: Workflow where all tasks succeed must be marked "Completed".
@@ -1320,3 +1353,76 @@ async fn test_workflow_skipped_downstream_marked_failed() {
         "Workflow with failed task + skipped dependents must be Failed"
     );
 }
+
+/// A failed task marked as manually resolved lets the execution finish as
+/// "Completed".
+#[tokio::test]
+#[serial_test::serial]
+async fn test_resolved_failed_task_completes_workflow() {
+    let status = run_workflow(
+        "resolve_failed_task",
+        vec![
+            (
+                "always_fails_task",
+                Box::new(|| Arc::new(always_fails_task_task())),
+            ),
+            (
+                "downstream_of_failure",
+                Box::new(|| Arc::new(downstream_of_failure_task())),
+            ),
+        ],
+        vec![
+            ("always_fails_task", vec![]),
+            ("downstream_of_failure", vec!["always_fails_task"]),
+        ],
+        Some("always_fails_task"),
+    )
+    .await;
+
+    assert_eq!(
+        status, "Completed",
+        "Workflow whose only failure was resolved must complete"
+    );
+}
+
+/// A task with a declared memory limit.
+#[task(id = "memory_capped_task", dependencies = [], memory_limit_mb = 64)]
+async fn memory_capped_task(_context: &mut Context<Value>) -> Result<(), TaskError> {
+    Ok(())
+}
+
+#[test]
+fn test_task_macro_memory_limit() {
+    assert_eq!(
+        memory_capped_task_task().memory_limit(),
+        Some(64 * 1024 * 1024)
+    );
+    assert_eq!(always_succeeds_task_task().memory_limit(), None);
+}
+
+/// A task that outlives its declared timeout.
+#[task(id = "slow_task", dependencies = [], retry_attempts = 0, timeout_ms = 100)]
+async fn slow_task(_context: &mut Context<Value>) -> Result<(), TaskError> {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    Ok(())
+}
+
+#[test]
+fn test_task_macro_timeout() {
+    assert_eq!(slow_task_task().timeout(), Some(Duration::from_millis(100)));
+    assert_eq!(always_succeeds_task_task().timeout(), None);
+}
+
+/// A task that runs past its own timeout fails the workflow.
+#[tokio::test]
+#[serial_test::serial]
+async fn test_task_timeout_fails_workflow() {
+    let status = run_workflow_and_get_status(
+        "task_timeout",
+        vec![("slow_task", Box::new(|| Arc::new(slow_task_task())))],
+        vec![("slow_task", vec![])],
+    )
+    .await;
+
+    assert_eq!(status, "Failed", "A timed-out task must fail the workflow");
+}
@@ -0,0 +1,93 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Workflow timeouts and concurrency limits: time an execution spends
+//! queued behind `max_active_runs` does not count against its timeout.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::DefaultRunner;
+use cloacina::*;
+use std::time::Duration;
+
+use crate::fixtures::get_or_init_fixture;
+
+#[tokio::test]
+async fn test_queued_execution_does_not_time_out_before_admission() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let workflow_name = format!(
+        "queued_timeout_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder(&workflow_name)
+        .max_active_runs(1)
+        .timeout(Duration::from_secs(3))
+        .task(FnTask::new("work", |context| async move {
+            tokio::time::sleep(Duration::from_millis(1500)).await;
+            Ok(context)
+        }))
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+
+    // The third execution waits behind the other two for longer than the
+    // timeout, but each run on its own finishes well inside it.
+    let mut executions = Vec::new();
+    for _ in 0..3 {
+        executions.push(
+            runner
+                .execute_async(&workflow_name, Context::new())
+                .await
+                .unwrap(),
+        );
+    }
+    let mut results = Vec::new();
+    for execution in executions {
+        results.push(
+            execution
+                .wait_for_completion_with_timeout(Some(Duration::from_secs(30)))
+                .await
+                .unwrap(),
+        );
+    }
+    runner.shutdown().await.unwrap();
+
+    for result in &results {
+        assert_eq!(
+            result.status,
+            WorkflowStatus::Completed,
+            "execution {} failed: {:?}",
+            result.execution_id,
+            result.error_message
+        );
+    }
+}
@@ -167,6 +167,9 @@ fn test_task_execution_fidelity() {
         task_name: "extract_data".to_string(),
         context_json: "{}".to_string(),
         resolved_secrets: Default::default(),
+        granted_env: None,
+        package_config_json: None,
+        params_json: None,
     };
 
     // Method index 1 = execute_task (fidius 0.0.5 tuple encoding: single-arg = (T,))
@@ -210,6 +213,9 @@ fn test_unknown_task_returns_error() {
         task_name: "nonexistent_task".to_string(),
         context_json: "{}".to_string(),
         resolved_secrets: Default::default(),
+        granted_env: None,
+        package_config_json: None,
+        params_json: None,
     };
 
     // fidius 0.0.5 tuple encoding: single-arg = (T,)
@@ -317,3 +323,29 @@ fn test_input_interface_populated() {
     assert_eq!(batch_size["schema"]["type"], serde_json::json!("integer"));
     assert_eq!(batch_size["default"], serde_json::json!(500));
 }
+
+#[test]
+fn test_run_health_checks_without_declared_checks() {
+    let dylib_path = match find_packaged_workflow_dylib() {
+        Some(p) => p,
+        None => {
+            eprintln!("Skipping: packaged-workflows example not built");
+            return;
+        }
+    };
+
+    let loaded =
+        fidius_host::loader::load_library(&dylib_path).expect("Failed to load plugin library");
+    let plugin = loaded.plugins.into_iter().next().unwrap();
+    let handle = fidius_host::PluginHandle::from_loaded(plugin);
+
+    // Method index 11 = run_health_checks (optional since interface v6). The
+    // example declares no checks; artifacts built before v6 report
+    // NotImplemented, which the bridge also maps to "no checks".
+    let reports = cloacina::computation_graph::packaging_bridge::call_run_health_checks(
+        &handle,
+        std::time::Duration::from_secs(5),
+    )
+    .expect("run_health_checks should succeed");
+    assert!(reports.is_empty(), "unexpected reports: {:?}", reports);
+}
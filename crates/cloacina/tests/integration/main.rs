@@ -44,6 +44,7 @@ pub mod registry_simple_functional_test;
 pub mod registry_storage_tests;
 pub mod registry_workflow_registry_tests;
 pub mod runner_configurable_registry_tests;
+pub mod runner_handoff;
 pub mod scheduler;
 pub mod signing;
 pub mod task;
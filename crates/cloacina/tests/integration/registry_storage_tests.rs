@@ -151,6 +151,28 @@ mod storage_tests {
 
         assert_eq!(retrieved, Some(binary_data));
     }
+
+    /// Test streamed store and retrieve across several chunks, including a
+    /// size that ends exactly on a chunk boundary
+    pub async fn test_streamed_round_trip_impl<S: RegistryStorage>(mut storage: S) {
+        for size in [9 * 1024 * 1024 + 123, 8 * 1024 * 1024] {
+            let data = create_test_workflow_data(size);
+            let id = storage
+                .store_stream(&mut data.as_slice())
+                .await
+                .expect("Failed to stream binary data");
+
+            let mut streamed = Vec::new();
+            assert!(storage.retrieve_to(&id, &mut streamed).await.unwrap());
+            assert_eq!(streamed, data);
+            assert_eq!(storage.retrieve_binary(&id).await.unwrap(), Some(data));
+
+            storage.delete_binary(&id).await.unwrap();
+            let mut gone = Vec::new();
+            assert!(!storage.retrieve_to(&id, &mut gone).await.unwrap());
+            assert!(gone.is_empty());
+        }
+    }
 }
 
 // Filesystem backend tests
@@ -211,6 +233,12 @@ mod filesystem_tests {
         let (storage, _temp_dir) = create_filesystem_storage();
         storage_tests::test_binary_data_integrity_impl(storage).await;
     }
+
+    #[tokio::test]
+    async fn test_streamed_round_trip() {
+        let (storage, _temp_dir) = create_filesystem_storage();
+        storage_tests::test_streamed_round_trip_impl(storage).await;
+    }
 }
 
 // Database backend tests (PostgreSQL/SQLite)
@@ -280,4 +308,11 @@ mod database_tests {
         let storage = create_database_storage().await;
         storage_tests::test_binary_data_integrity_impl(storage).await;
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_streamed_round_trip() {
+        let storage = create_database_storage().await;
+        storage_tests::test_streamed_round_trip_impl(storage).await;
+    }
 }
@@ -184,6 +184,37 @@ mod filesystem_tests {
         registry_tests::test_runner_shutdown_impl(runner).await;
     }
 
+    #[tokio::test]
+    async fn test_filesystem_reconcile_now_updates_status() {
+        let (runner, _temp_dir) = create_filesystem_runner().await;
+
+        // Startup reconciliation is disabled, so nothing has run yet.
+        let status = runner
+            .registry_status()
+            .await
+            .expect("Status should be available when reconciler is enabled");
+        assert!(status.last_run.is_none());
+
+        let result = runner
+            .reconcile_now()
+            .await
+            .expect("Manual reconciliation should succeed");
+        assert!(!result.has_failures());
+        assert_eq!(result.total_packages_tracked, 0);
+
+        let status = runner.registry_status().await.unwrap();
+        let last_run = status.last_run.as_ref().expect("last run recorded");
+        assert!(last_run.error.is_none());
+        assert!(status.runs_completed >= 1);
+        assert!(status.is_healthy());
+        assert_eq!(status.packages_loaded, 0);
+
+        runner
+            .shutdown()
+            .await
+            .expect("Runner should shut down cleanly");
+    }
+
     #[tokio::test]
     async fn test_filesystem_custom_path() {
         let temp_dir = TempDir::new().expect("Failed to create temp directory");
@@ -364,6 +395,8 @@ mod error_tests {
             !runner.is_registry_reconciler_enabled(),
             "Registry reconciler should be disabled"
         );
+        assert!(runner.registry_status().await.is_none());
+        assert!(runner.reconcile_now().await.is_err());
 
         runner
             .shutdown()
@@ -0,0 +1,153 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Integration tests for blue/green runner handoff.
+//!
+//! Two runners share one database: the second starts with takeover enabled,
+//! the first sees the drain request on its next heartbeat and drains.
+
+use std::time::Duration;
+
+use cloacina::models::runner_instance::RunnerInstanceState;
+use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+#[cfg(feature = "sqlite")]
+use cloacina::UniversalUuid;
+
+fn handoff_config(takeover: bool) -> DefaultRunnerConfig {
+    DefaultRunnerConfig::builder()
+        .enable_registry_reconciler(false)
+        .enable_cron_scheduling(false)
+        .enable_trigger_scheduling(false)
+        .enable_handoff(true)
+        .handoff_takeover(takeover)
+        .handoff_heartbeat_interval(Duration::from_millis(100))
+        .build()
+        .unwrap()
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn test_takeover_drains_previous_runner() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_url = format!(
+        "sqlite://{}?mode=rwc",
+        dir.path().join("handoff.db").display()
+    );
+
+    let blue = DefaultRunner::with_config(&db_url, handoff_config(false))
+        .await
+        .expect("Failed to create blue runner");
+    // Both runners are built from this crate, so pose blue as the old release.
+    set_version(&blue, blue.instance_id(), "0.0.1").await;
+    let green = DefaultRunner::with_config(&db_url, handoff_config(true))
+        .await
+        .expect("Failed to create green runner");
+
+    tokio::time::timeout(Duration::from_secs(5), blue.handoff_requested())
+        .await
+        .expect("blue runner never saw the drain request");
+
+    let record = green
+        .dal()
+        .runner_instance()
+        .get(blue.instance_id())
+        .await
+        .unwrap();
+    assert_eq!(record.state(), Some(RunnerInstanceState::Draining));
+    assert_eq!(record.successor_id, Some(green.instance_id()));
+
+    let drained = blue.drain(Duration::from_secs(5)).await.unwrap();
+    assert!(drained, "idle runner should drain immediately");
+
+    let record = green
+        .dal()
+        .runner_instance()
+        .get(blue.instance_id())
+        .await
+        .unwrap();
+    assert_eq!(record.state(), Some(RunnerInstanceState::Drained));
+
+    // The successor itself is untouched and never asked to drain.
+    let record = green
+        .dal()
+        .runner_instance()
+        .get(green.instance_id())
+        .await
+        .unwrap();
+    assert_eq!(record.state(), Some(RunnerInstanceState::Active));
+    assert!(
+        tokio::time::timeout(Duration::from_millis(300), green.handoff_requested())
+            .await
+            .is_err(),
+        "successor must not be asked to drain"
+    );
+
+    green.shutdown().await.unwrap();
+}
+
+/// Overwrites a runner's recorded version, standing in for an older build.
+#[cfg(feature = "sqlite")]
+async fn set_version(runner: &DefaultRunner, id: UniversalUuid, version: &str) {
+    use cloacina::database::schema::unified::runner_instances;
+    use diesel::prelude::*;
+
+    let version = version.to_string();
+    let conn = runner
+        .database()
+        .get_sqlite_connection()
+        .await
+        .expect("sqlite connection");
+    conn.interact(move |conn| {
+        diesel::update(runner_instances::table.find(id))
+            .set(runner_instances::version.eq(version))
+            .execute(conn)
+    })
+    .await
+    .expect("interact")
+    .expect("update");
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn test_takeover_leaves_same_version_replicas_running() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_url = format!(
+        "sqlite://{}?mode=rwc",
+        dir.path().join("replicas.db").display()
+    );
+
+    let first = DefaultRunner::with_config(&db_url, handoff_config(false))
+        .await
+        .expect("Failed to create first runner");
+    let second = DefaultRunner::with_config(&db_url, handoff_config(true))
+        .await
+        .expect("Failed to create second runner");
+
+    assert!(
+        tokio::time::timeout(Duration::from_millis(300), first.handoff_requested())
+            .await
+            .is_err(),
+        "a replica on the successor's version must not be drained"
+    );
+
+    // An ordinary shutdown is recorded as `stopped`, not as a handoff.
+    let first_id = first.instance_id();
+    first.shutdown().await.unwrap();
+    let record = second.dal().runner_instance().get(first_id).await.unwrap();
+    assert_eq!(record.state(), Some(RunnerInstanceState::Stopped));
+
+    second.shutdown().await.unwrap();
+}
@@ -16,7 +16,9 @@
 
 use crate::fixtures::get_or_init_fixture;
 use async_trait::async_trait;
+use cloacina::dal::unified::workflow_execution::ExecutionListFilter;
 use cloacina::execution_planner::TaskScheduler;
+use cloacina::models::workflow_execution::{ExecutionLabels, ExecutionOptions};
 use cloacina::*;
 use serial_test::serial;
 use std::sync::Arc;
@@ -97,6 +99,159 @@ async fn test_schedule_workflow_execution() {
     assert_eq!(wf_exec.status, "Pending");
 }
 
+#[tokio::test]
+#[serial]
+async fn test_schedule_workflow_execution_with_labels() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database = fixture.get_database();
+
+    let workflow = Workflow::builder("labelled-workflow")
+        .add_task(Arc::new(SimpleTask {
+            id: "test-task".to_string(),
+        }))
+        .expect("Failed to add task")
+        .build()
+        .expect("Failed to build workflow");
+    let runtime = Arc::new(cloacina::Runtime::empty());
+    runtime.register_workflow("labelled-workflow".to_string(), move || workflow.clone());
+
+    let scheduler = TaskScheduler::new(database.clone())
+        .await
+        .expect("Failed to create scheduler")
+        .with_runtime(runtime.clone());
+
+    let labels: ExecutionLabels = [("source", "api"), ("customer", "acme")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let execution_id = scheduler
+        .schedule_workflow_execution_with_labels("labelled-workflow", Context::new(), &labels)
+        .await
+        .expect("Failed to schedule workflow execution");
+
+    let dal = fixture.get_dal();
+    let stored = dal
+        .workflow_execution()
+        .get_labels(UniversalUuid(execution_id))
+        .await
+        .expect("Failed to get labels");
+    assert_eq!(stored, labels);
+
+    // A malformed label set schedules nothing.
+    let bad: ExecutionLabels = [("bad key".to_string(), "x".to_string())].into();
+    assert!(scheduler
+        .schedule_workflow_execution_with_labels("labelled-workflow", Context::new(), &bad)
+        .await
+        .is_err());
+    let executions = dal
+        .workflow_execution()
+        .list_filtered(ExecutionListFilter {
+            status: None,
+            workflow_name: Some("labelled-workflow".to_string()),
+            run_id: None,
+            labels: Vec::new(),
+            limit: 100,
+            offset: 0,
+        })
+        .await
+        .expect("Failed to list executions");
+    assert_eq!(executions.len(), 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_schedule_workflow_execution_run_identity() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database = fixture.get_database();
+
+    let workflow = Workflow::builder("dated-workflow")
+        .add_task(Arc::new(SimpleTask {
+            id: "test-task".to_string(),
+        }))
+        .expect("Failed to add task")
+        .build()
+        .expect("Failed to build workflow");
+    let runtime = Arc::new(cloacina::Runtime::empty());
+    runtime.register_workflow("dated-workflow".to_string(), move || workflow.clone());
+
+    let scheduler = TaskScheduler::new(database.clone())
+        .await
+        .expect("Failed to create scheduler")
+        .with_runtime(runtime.clone());
+    let dal = fixture.get_dal();
+
+    let logical_date = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let options = ExecutionOptions {
+        run_id: Some("backfill__2026-01-01".to_string()),
+        logical_date: Some(logical_date),
+        ..Default::default()
+    };
+    let mut context = Context::new();
+    context
+        .insert("run_id", serde_json::json!("spoofed"))
+        .unwrap();
+    let execution_id = scheduler
+        .schedule_workflow_execution_with_options(
+            "dated-workflow",
+            context,
+            &ExecutionLabels::new(),
+            &options,
+        )
+        .await
+        .expect("Failed to schedule workflow execution");
+
+    let execution = dal
+        .workflow_execution()
+        .get_by_id(UniversalUuid(execution_id))
+        .await
+        .expect("Failed to get execution");
+    assert_eq!(execution.run_id.as_deref(), Some("backfill__2026-01-01"));
+    assert_eq!(execution.logical_date.map(|t| t.0), Some(logical_date));
+    let context: Context<serde_json::Value> = dal
+        .context()
+        .read(execution.context_id.expect("context stored"))
+        .await
+        .expect("Failed to read context");
+    assert_eq!(
+        context.get("run_id"),
+        Some(&serde_json::json!("backfill__2026-01-01"))
+    );
+    assert_eq!(
+        context.get("logical_date"),
+        Some(&serde_json::json!(logical_date.to_rfc3339()))
+    );
+    // Run identities are not execution overrides.
+    assert!(dal
+        .workflow_execution()
+        .get_options(UniversalUuid(execution_id))
+        .await
+        .expect("Failed to get options")
+        .is_default());
+
+    let execution_id = scheduler
+        .schedule_workflow_execution("dated-workflow", Context::new())
+        .await
+        .expect("Failed to schedule workflow execution");
+    let execution = dal
+        .workflow_execution()
+        .get_by_id(UniversalUuid(execution_id))
+        .await
+        .expect("Failed to get execution");
+    assert!(execution
+        .run_id
+        .as_deref()
+        .is_some_and(|id| id.starts_with("manual__")));
+    assert!(execution.logical_date.is_some());
+}
+
 #[tokio::test]
 #[serial]
 async fn test_schedule_nonexistent_workflow() {
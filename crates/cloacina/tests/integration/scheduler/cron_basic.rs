@@ -15,13 +15,21 @@
  */
 
 use crate::fixtures::get_or_init_fixture;
+use async_trait::async_trait;
 use chrono::Utc;
 use cloacina::cron_evaluator::CronEvaluator;
-use cloacina::database::universal_types::UniversalTimestamp;
-use cloacina::models::schedule::{NewSchedule, NewScheduleExecution};
-use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::cron_trigger_scheduler::Scheduler;
+use cloacina::dal::unified::workflow_execution::ExecutionListFilter;
+use cloacina::database::universal_types::{UniversalTimestamp, UniversalUuid};
+use cloacina::execution_planner::TaskScheduler;
+use cloacina::models::delayed_execution::{delayed_run_id, DELAYED_EXECUTION_ID_KEY};
+use cloacina::models::schedule::{CatchupPolicy, NewSchedule, NewScheduleExecution};
+use cloacina::runner::{CronScheduleOptions, DefaultRunner, DefaultRunnerConfig};
+use cloacina::{Context, Runtime, Task, TaskError, TaskNamespace, Workflow};
 use serial_test::serial;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::watch;
 use tokio::time::sleep;
 
 #[tokio::test]
@@ -58,6 +66,38 @@ async fn test_cron_schedule_creation() {
     assert!(!created_schedule.id.to_string().is_empty());
 }
 
+#[tokio::test]
+#[serial]
+async fn test_every_catchup_policy_round_trips() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.initialize().await;
+    let dal = fixture.get_dal();
+
+    let schedule = dal
+        .schedule()
+        .create(NewSchedule::cron(
+            "catchup-policy-workflow",
+            "0 0 * * *",
+            UniversalTimestamp(Utc::now()),
+        ))
+        .await
+        .unwrap();
+
+    for policy in [
+        CatchupPolicy::Skip,
+        CatchupPolicy::RunAll,
+        CatchupPolicy::RunLatestOnly,
+    ] {
+        dal.schedule()
+            .set_catchup_policy(schedule.id, policy)
+            .await
+            .unwrap_or_else(|e| panic!("store {}: {}", policy, e));
+        let stored = dal.schedule().get_by_id(schedule.id).await.unwrap();
+        assert_eq!(stored.catchup_policy.map(CatchupPolicy::from), Some(policy));
+    }
+}
+
 #[tokio::test]
 #[serial]
 async fn test_default_runner_cron_integration() {
@@ -375,3 +415,346 @@ async fn test_workflow_instance_register_roundtrip() {
 
     runner.shutdown().await.unwrap();
 }
+
+#[tokio::test]
+#[serial]
+async fn test_deprecated_workflow_refuses_new_cron_schedules() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database_url = fixture.get_database_url();
+
+    let config = DefaultRunnerConfig::builder()
+        .enable_cron_scheduling(true)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::with_config(&database_url, config)
+        .await
+        .unwrap();
+
+    let existing = runner
+        .register_cron_workflow("legacy-report", "0 * * * *", "UTC")
+        .await
+        .unwrap();
+
+    let sunset = Utc::now() + chrono::Duration::days(30);
+    runner
+        .deprecate_workflow("legacy-report", Some(sunset), Some("use report-v2"))
+        .await
+        .unwrap();
+
+    let err = runner
+        .register_cron_workflow("legacy-report", "30 * * * *", "UTC")
+        .await
+        .expect_err("a deprecated workflow must not get new schedules");
+    assert!(err.to_string().contains("use report-v2"), "{}", err);
+
+    // The schedule created before the deprecation is a remaining consumer.
+    let consumers = runner.workflow_consumers("legacy-report").await.unwrap();
+    assert!(consumers.deprecation.is_some());
+    assert_eq!(consumers.schedules.len(), 1);
+    assert_eq!(consumers.schedules[0].id, existing);
+    assert!(!consumers.is_empty());
+
+    runner.delete_cron_schedule(existing).await.unwrap();
+    assert!(runner
+        .workflow_consumers("legacy-report")
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert!(runner.undeprecate_workflow("legacy-report").await.unwrap());
+    assert!(!runner.undeprecate_workflow("legacy-report").await.unwrap());
+    let renewed = runner
+        .register_cron_workflow("legacy-report", "30 * * * *", "UTC")
+        .await
+        .expect("schedules are accepted again once the deprecation is cleared");
+    runner.delete_cron_schedule(renewed).await.unwrap();
+
+    runner.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_cron_fire_spread_delays_next_run() {
+    use chrono::Timelike;
+
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database_url = fixture.get_database_url();
+
+    let config = DefaultRunnerConfig::builder()
+        .enable_cron_scheduling(true)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::with_config(&database_url, config)
+        .await
+        .unwrap();
+
+    let registered_at = Utc::now();
+    let spread = runner
+        .register_cron_workflow_with_options(
+            "spread-report",
+            "*/5 * * * *",
+            "UTC",
+            CronScheduleOptions {
+                offset: Duration::from_secs(60),
+                jitter: Duration::from_secs(30),
+            },
+        )
+        .await
+        .unwrap();
+    let schedule = runner.get_cron_schedule(spread).await.unwrap();
+    assert_eq!(schedule.fire_offset_secs, Some(60));
+    assert_eq!(schedule.fire_jitter_secs, Some(30));
+
+    // next_run_at is a cron time pushed back by the offset plus jitter.
+    let delay = schedule.fire_delay();
+    assert!(delay >= chrono::Duration::seconds(60));
+    assert!(delay <= chrono::Duration::seconds(90));
+    let next_run = schedule.next_run_at.unwrap().0;
+    assert!(next_run > registered_at);
+    let cron_time = next_run - delay;
+    assert_eq!(cron_time.minute() % 5, 0);
+    assert_eq!(cron_time.second(), 0);
+    assert_eq!(cron_time.nanosecond(), 0);
+
+    let plain = runner
+        .register_cron_workflow("plain-report", "*/5 * * * *", "UTC")
+        .await
+        .unwrap();
+    let plain = runner.get_cron_schedule(plain).await.unwrap();
+    assert_eq!(plain.fire_delay(), chrono::Duration::zero());
+    assert_eq!(plain.next_run_at.unwrap().0.second(), 0);
+
+    runner.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_interval_schedule_registration() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database_url = fixture.get_database_url();
+
+    let config = DefaultRunnerConfig::builder()
+        .enable_cron_scheduling(true)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::with_config(&database_url, config)
+        .await
+        .unwrap();
+
+    assert!(runner
+        .register_interval_workflow("poller", Duration::from_millis(500), None)
+        .await
+        .is_err());
+
+    let anchor = Utc::now() - chrono::Duration::seconds(10);
+    let id = runner
+        .register_interval_workflow("poller", Duration::from_secs(90), Some(anchor))
+        .await
+        .unwrap();
+    let schedule = runner.get_cron_schedule(id).await.unwrap();
+    assert!(schedule.is_cron());
+    assert!(schedule.is_interval());
+    assert!(schedule.cron_expression.is_none());
+    assert_eq!(schedule.interval_secs, Some(90));
+
+    // The first fire is the anchor plus one interval, and later fires follow
+    // every 90 seconds.
+    let first = anchor + chrono::Duration::seconds(90);
+    let next_run = schedule.next_run_at.unwrap().0;
+    assert!((next_run - first).num_milliseconds().abs() < 1000);
+    let fires = runner.get_cron_schedule_fire_times(id, 3).await.unwrap();
+    assert_eq!(fires.len(), 3);
+    assert_eq!(fires[1] - fires[0], chrono::Duration::seconds(90));
+    assert_eq!(fires[2] - fires[1], chrono::Duration::seconds(90));
+
+    // Interval schedules list with the cron schedules but have no
+    // expression to update.
+    let listed = runner.list_cron_schedules(false, 10, 0).await.unwrap();
+    assert!(listed.iter().any(|s| s.id == id));
+    assert!(runner
+        .update_cron_schedule(id, None, Some("Europe/London"))
+        .await
+        .is_err());
+
+    runner.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_delayed_execution_dispatch_and_cancel() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database_url = fixture.get_database_url();
+
+    let config = DefaultRunnerConfig::builder()
+        .enable_cron_scheduling(true)
+        .build()
+        .unwrap();
+    let runner = DefaultRunner::with_config(&database_url, config)
+        .await
+        .unwrap();
+
+    // A far-off run stays pending until cancelled.
+    let far = runner
+        .execute_at(
+            "nightly-report",
+            Context::new(),
+            Utc::now() + chrono::Duration::days(1),
+        )
+        .await
+        .unwrap();
+    let pending = runner.list_delayed_executions().await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].id, far);
+    assert!(runner.cancel_delayed_execution(far).await.unwrap());
+    assert!(!runner.cancel_delayed_execution(far).await.unwrap());
+    assert!(runner.list_delayed_executions().await.unwrap().is_empty());
+
+    // A due run is claimed by the scheduler; the workflow is not registered,
+    // so the hand-off records why it could not start.
+    let soon = runner
+        .execute_after(
+            "missing-workflow",
+            Context::new(),
+            Duration::from_millis(200),
+        )
+        .await
+        .unwrap();
+    sleep(Duration::from_secs(3)).await;
+
+    let delayed = runner.get_delayed_execution(soon).await.unwrap().unwrap();
+    assert!(!delayed.is_pending());
+    assert!(delayed.workflow_execution_id.is_none());
+    assert!(delayed.error.is_some());
+
+    runner.shutdown().await.unwrap();
+}
+
+#[derive(Clone)]
+struct NoopTask;
+
+#[async_trait]
+impl Task for NoopTask {
+    async fn execute(
+        &self,
+        context: Context<serde_json::Value>,
+    ) -> Result<Context<serde_json::Value>, TaskError> {
+        Ok(context)
+    }
+
+    fn id(&self) -> &str {
+        "noop"
+    }
+
+    fn dependencies(&self) -> &[TaskNamespace] {
+        &[]
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_stale_delayed_claim_reuses_started_execution() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+    let database = fixture.get_database();
+    let dal = fixture.get_dal();
+
+    let now = Utc::now();
+    let delayed = dal
+        .delayed_execution()
+        .create(
+            "delayed-workflow",
+            "{}".to_string(),
+            now - chrono::Duration::hours(1),
+        )
+        .await
+        .unwrap();
+
+    // A runner claimed the run an hour ago and started its execution, then
+    // stopped before recording the outcome.
+    let claimed_at = now - chrono::Duration::hours(1);
+    assert!(dal
+        .delayed_execution()
+        .claim(delayed.id, claimed_at, claimed_at)
+        .await
+        .unwrap());
+    let workflow = Workflow::builder("delayed-workflow")
+        .add_task(Arc::new(NoopTask))
+        .unwrap()
+        .build()
+        .unwrap();
+    let runtime = Arc::new(Runtime::empty());
+    runtime.register_workflow("delayed-workflow".to_string(), move || workflow.clone());
+    let planner = TaskScheduler::new(database.clone())
+        .await
+        .unwrap()
+        .with_runtime(runtime);
+    let mut context = Context::new();
+    context
+        .insert(
+            DELAYED_EXECUTION_ID_KEY,
+            serde_json::json!(delayed.id.to_string()),
+        )
+        .unwrap();
+    let started = planner
+        .schedule_workflow_execution("delayed-workflow", context)
+        .await
+        .unwrap();
+
+    // The claim is stale, so the next pass claims it again and finds the
+    // execution instead of starting a second one.
+    let runner = DefaultRunner::with_config(
+        &fixture.get_database_url(),
+        DefaultRunnerConfig::builder()
+            .enable_cron_scheduling(false)
+            .build()
+            .unwrap(),
+    )
+    .await
+    .unwrap();
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let scheduler = Scheduler::with_defaults(
+        Arc::new(dal.clone()),
+        Arc::new(runner.clone()),
+        shutdown_rx,
+        Arc::new(Runtime::empty()),
+    );
+    scheduler.dispatch_delayed_executions_once().await.unwrap();
+
+    let delayed = dal
+        .delayed_execution()
+        .get(delayed.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(delayed.workflow_execution_id, Some(UniversalUuid(started)));
+    assert!(delayed.error.is_none());
+    let executions = dal
+        .workflow_execution()
+        .list_filtered(ExecutionListFilter {
+            status: None,
+            workflow_name: Some("delayed-workflow".to_string()),
+            run_id: Some(delayed_run_id(&delayed.id.to_string())),
+            labels: Vec::new(),
+            limit: 10,
+            offset: 0,
+        })
+        .await
+        .unwrap();
+    assert_eq!(executions.len(), 1);
+
+    runner.shutdown().await.unwrap();
+}
@@ -20,4 +20,7 @@ mod cron_basic;
 mod dependency_resolution;
 mod reactor_predicate;
 mod stale_claims;
+mod trigger_batch;
+mod trigger_condition;
+mod trigger_leases;
 mod trigger_rules;
@@ -218,6 +218,7 @@ async fn test_predicate_filters_dispatch_and_advances_watermark_for_skips() {
             reactor_poll_batch_limit: 100,
             reactor_firings_prune_interval: Duration::from_secs(3600),
             reactor_firings_retention: Duration::from_secs(86400),
+            trigger_lease_ttl: None,
         },
         shutdown_rx,
         runtime,
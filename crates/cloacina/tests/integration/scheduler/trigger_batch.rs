@@ -0,0 +1,223 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Batching triggers must not lose buffered events when the handoff to the
+//! workflow executor fails: the batch stays buffered and is retried on the
+//! next poll, and is only cleared once an execution has been launched.
+
+use crate::fixtures::get_or_init_fixture;
+use async_trait::async_trait;
+use chrono::Utc;
+use cloacina::context::Context;
+use cloacina::cron_trigger_scheduler::{Scheduler, SchedulerConfig};
+use cloacina::executor::{
+    StatusCallback, WorkflowExecution, WorkflowExecutionError, WorkflowExecutionResult,
+    WorkflowExecutor, WorkflowStatus,
+};
+use cloacina::trigger::{Trigger, TriggerBatch, TriggerResult, BATCH_EVENTS_KEY};
+use cloacina::Runtime;
+use serial_test::serial;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+const TRIGGER_NAME: &str = "batch_handoff_failure";
+const WORKFLOW_NAME: &str = "wf_batch_handoff_failure";
+
+/// Fires on every poll with an increasing sequence number, batching two
+/// events per execution.
+#[derive(Debug, Default)]
+struct CountingTrigger {
+    fired: AtomicUsize,
+}
+
+#[async_trait]
+impl Trigger for CountingTrigger {
+    fn name(&self) -> &str {
+        TRIGGER_NAME
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_millis(1)
+    }
+
+    fn allow_concurrent(&self) -> bool {
+        false
+    }
+
+    async fn poll(&self) -> Result<TriggerResult, cloacina_workflow::TriggerError> {
+        let n = self.fired.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut ctx = Context::new();
+        ctx.insert("n", serde_json::json!(n))?;
+        Ok(TriggerResult::Fire(Some(ctx)))
+    }
+
+    fn batch(&self) -> Option<TriggerBatch> {
+        Some(TriggerBatch::events(2))
+    }
+}
+
+/// Executor whose first `execute` call fails; every call records the
+/// batched events it was handed.
+#[derive(Default)]
+struct FlakyExecutor {
+    failed_once: AtomicBool,
+    calls: Mutex<Vec<(bool, serde_json::Value)>>,
+}
+
+#[async_trait]
+impl WorkflowExecutor for FlakyExecutor {
+    async fn execute(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        let events = context
+            .get(BATCH_EVENTS_KEY)
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
+        let fail = !self.failed_once.swap(true, Ordering::SeqCst);
+        self.calls.lock().unwrap().push((!fail, events));
+        if fail {
+            return Err(WorkflowExecutionError::ExecutionFailed {
+                message: "executor unavailable".to_string(),
+            });
+        }
+        Ok(WorkflowExecutionResult {
+            execution_id: Uuid::new_v4(),
+            workflow_name: workflow_name.to_string(),
+            status: WorkflowStatus::Pending,
+            start_time: Utc::now(),
+            end_time: None,
+            duration: None,
+            final_context: context,
+            task_results: Vec::new(),
+            error_message: None,
+        })
+    }
+
+    async fn execute_async(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+    ) -> Result<WorkflowExecution, WorkflowExecutionError> {
+        unreachable!("trigger path uses execute only")
+    }
+    async fn get_execution_status(
+        &self,
+        _execution_id: Uuid,
+    ) -> Result<WorkflowStatus, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn get_execution_result(
+        &self,
+        _execution_id: Uuid,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn cancel_execution(&self, _execution_id: Uuid) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn pause_execution(
+        &self,
+        _execution_id: Uuid,
+        _reason: Option<&str>,
+    ) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn resume_execution(&self, _execution_id: Uuid) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn execute_with_callback(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+        _callback: Box<dyn StatusCallback>,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn list_executions(
+        &self,
+    ) -> Result<Vec<WorkflowExecutionResult>, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn shutdown(&self) -> Result<(), WorkflowExecutionError> {
+        Ok(())
+    }
+}
+
+/// Poll 1 buffers one event; poll 2 fills the batch but the handoff fails;
+/// poll 3 adds a third event and launches all three together.
+#[tokio::test]
+#[serial]
+async fn test_failed_batch_handoff_keeps_events_for_next_poll() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let trigger = Arc::new(CountingTrigger::default());
+    let runtime = Arc::new(Runtime::empty());
+    let registered = trigger.clone();
+    runtime.register_trigger(TRIGGER_NAME.to_string(), move || {
+        registered.clone() as Arc<dyn Trigger>
+    });
+
+    let executor = Arc::new(FlakyExecutor::default());
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mut scheduler = Scheduler::new(
+        Arc::new(cloacina::dal::DAL::new(fixture.get_database())),
+        executor.clone(),
+        SchedulerConfig {
+            trigger_base_poll_interval: Duration::from_millis(1),
+            ..SchedulerConfig::default()
+        },
+        shutdown_rx,
+        runtime,
+        Arc::new(tokio::sync::Notify::new()),
+    );
+    scheduler
+        .register_trigger(trigger.as_ref(), WORKFLOW_NAME)
+        .await
+        .expect("register trigger");
+
+    for _ in 0..3 {
+        scheduler.poll_triggers_once().await.expect("poll triggers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let calls = executor.calls.lock().unwrap().clone();
+    assert_eq!(
+        calls.len(),
+        2,
+        "expected one failed and one retried handoff"
+    );
+    assert!(!calls[0].0, "first handoff should have failed");
+    assert_eq!(calls[0].1, serde_json::json!([{"n": 1}, {"n": 2}]));
+    assert!(calls[1].0, "retried handoff should have succeeded");
+    assert_eq!(
+        calls[1].1,
+        serde_json::json!([{"n": 1}, {"n": 2}, {"n": 3}]),
+        "the failed batch must be retried, not dropped"
+    );
+
+    // The successful flush cleared the buffer: the next event starts a new
+    // batch and does not launch anything on its own.
+    scheduler.poll_triggers_once().await.expect("poll triggers");
+    assert_eq!(executor.calls.lock().unwrap().len(), 2);
+}
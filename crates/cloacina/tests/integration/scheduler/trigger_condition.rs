@@ -0,0 +1,284 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! A trigger schedule with a declarative condition and no registered
+//! trigger fires whenever the condition holds over its probe results, and
+//! carries the results in the fired context.
+
+use crate::fixtures::get_or_init_fixture;
+use async_trait::async_trait;
+use chrono::Utc;
+use cloacina::context::Context;
+use cloacina::cron_trigger_scheduler::{Scheduler, SchedulerConfig};
+use cloacina::executor::{
+    StatusCallback, WorkflowExecution, WorkflowExecutionError, WorkflowExecutionResult,
+    WorkflowExecutor, WorkflowStatus,
+};
+use cloacina::models::schedule::NewSchedule;
+use cloacina::trigger::{trigger_probes_json, ProbeKind, Trigger, TriggerProbe, TriggerResult};
+use cloacina::Runtime;
+use serial_test::serial;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+const TRIGGER_NAME: &str = "condition_flag_file";
+const WORKFLOW_NAME: &str = "wf_condition_flag_file";
+const POLLED_TRIGGER_NAME: &str = "condition_polled_rows";
+
+/// Trigger firing every poll with a context that already has a `probes` key.
+#[derive(Debug)]
+struct RowsTrigger;
+
+#[async_trait]
+impl Trigger for RowsTrigger {
+    fn name(&self) -> &str {
+        POLLED_TRIGGER_NAME
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_millis(1)
+    }
+
+    fn allow_concurrent(&self) -> bool {
+        true
+    }
+
+    async fn poll(&self) -> Result<TriggerResult, cloacina_workflow::TriggerError> {
+        let mut ctx = Context::new();
+        ctx.insert("rows", serde_json::json!(3))?;
+        ctx.insert("probes", serde_json::json!("set by poll"))?;
+        Ok(TriggerResult::Fire(Some(ctx)))
+    }
+}
+
+/// Executor recording the context of every launched workflow.
+#[derive(Default)]
+struct RecordingExecutor {
+    contexts: Mutex<Vec<serde_json::Value>>,
+}
+
+#[async_trait]
+impl WorkflowExecutor for RecordingExecutor {
+    async fn execute(
+        &self,
+        workflow_name: &str,
+        context: Context<serde_json::Value>,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        self.contexts
+            .lock()
+            .unwrap()
+            .push(serde_json::to_value(context.data()).unwrap());
+        Ok(WorkflowExecutionResult {
+            execution_id: Uuid::new_v4(),
+            workflow_name: workflow_name.to_string(),
+            status: WorkflowStatus::Pending,
+            start_time: Utc::now(),
+            end_time: None,
+            duration: None,
+            final_context: context,
+            task_results: Vec::new(),
+            error_message: None,
+        })
+    }
+
+    async fn execute_async(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+    ) -> Result<WorkflowExecution, WorkflowExecutionError> {
+        unreachable!("trigger path uses execute only")
+    }
+    async fn get_execution_status(
+        &self,
+        _execution_id: Uuid,
+    ) -> Result<WorkflowStatus, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn get_execution_result(
+        &self,
+        _execution_id: Uuid,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn cancel_execution(&self, _execution_id: Uuid) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn pause_execution(
+        &self,
+        _execution_id: Uuid,
+        _reason: Option<&str>,
+    ) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn resume_execution(&self, _execution_id: Uuid) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn execute_with_callback(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+        _callback: Box<dyn StatusCallback>,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn list_executions(
+        &self,
+    ) -> Result<Vec<WorkflowExecutionResult>, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn shutdown(&self) -> Result<(), WorkflowExecutionError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_declarative_trigger_fires_when_condition_holds() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let flag = dir.path().join("ready.flag");
+    let dal = Arc::new(cloacina::dal::DAL::new(fixture.get_database()));
+    let schedule = dal
+        .schedule()
+        .upsert_trigger(NewSchedule::trigger(
+            TRIGGER_NAME,
+            WORKFLOW_NAME,
+            Duration::from_millis(1),
+        ))
+        .await
+        .expect("create trigger schedule");
+    let probes = [TriggerProbe {
+        name: "flag".to_string(),
+        kind: ProbeKind::File {
+            path: flag.to_string_lossy().into_owned(),
+        },
+    }];
+    dal.schedule()
+        .set_condition(
+            schedule.id,
+            Some("probes.flag.exists".to_string()),
+            trigger_probes_json(&probes),
+        )
+        .await
+        .expect("set condition");
+
+    let executor = Arc::new(RecordingExecutor::default());
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mut scheduler = Scheduler::new(
+        dal,
+        executor.clone(),
+        SchedulerConfig {
+            trigger_base_poll_interval: Duration::from_millis(1),
+            ..SchedulerConfig::default()
+        },
+        shutdown_rx,
+        Arc::new(Runtime::empty()),
+        Arc::new(tokio::sync::Notify::new()),
+    );
+
+    scheduler.poll_triggers_once().await.expect("poll triggers");
+    assert!(
+        executor.contexts.lock().unwrap().is_empty(),
+        "the condition is false until the flag file exists"
+    );
+
+    std::fs::write(&flag, b"go").unwrap();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    scheduler.poll_triggers_once().await.expect("poll triggers");
+
+    let contexts = executor.contexts.lock().unwrap().clone();
+    assert_eq!(contexts.len(), 1);
+    assert_eq!(
+        contexts[0]["probes"]["flag"]["exists"],
+        serde_json::json!(true)
+    );
+    assert_eq!(
+        contexts[0]["probes"]["flag"]["size_bytes"],
+        serde_json::json!(2)
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_polled_trigger_condition_replaces_probes_in_context() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let flag = dir.path().join("ready.flag");
+    std::fs::write(&flag, b"go").unwrap();
+    let dal = Arc::new(cloacina::dal::DAL::new(fixture.get_database()));
+    let schedule = dal
+        .schedule()
+        .upsert_trigger(NewSchedule::trigger(
+            POLLED_TRIGGER_NAME,
+            WORKFLOW_NAME,
+            Duration::from_millis(1),
+        ))
+        .await
+        .expect("create trigger schedule");
+    let probes = [TriggerProbe {
+        name: "flag".to_string(),
+        kind: ProbeKind::File {
+            path: flag.to_string_lossy().into_owned(),
+        },
+    }];
+    dal.schedule()
+        .set_condition(
+            schedule.id,
+            Some("context.rows > 0 && probes.flag.exists".to_string()),
+            trigger_probes_json(&probes),
+        )
+        .await
+        .expect("set condition");
+
+    let runtime = Arc::new(Runtime::empty());
+    runtime.register_trigger(POLLED_TRIGGER_NAME.to_string(), || {
+        Arc::new(RowsTrigger) as Arc<dyn Trigger>
+    });
+    let executor = Arc::new(RecordingExecutor::default());
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mut scheduler = Scheduler::new(
+        dal,
+        executor.clone(),
+        SchedulerConfig {
+            trigger_base_poll_interval: Duration::from_millis(1),
+            ..SchedulerConfig::default()
+        },
+        shutdown_rx,
+        runtime,
+        Arc::new(tokio::sync::Notify::new()),
+    );
+
+    scheduler.poll_triggers_once().await.expect("poll triggers");
+
+    let contexts = executor.contexts.lock().unwrap().clone();
+    assert_eq!(contexts.len(), 1);
+    assert_eq!(contexts[0]["rows"], serde_json::json!(3));
+    assert_eq!(
+        contexts[0]["probes"]["flag"]["exists"],
+        serde_json::json!(true),
+        "probe results replace the poll's own `probes` value"
+    );
+}
@@ -0,0 +1,218 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Trigger leases split trigger polling between schedulers sharing a
+//! database, and a scheduler that leaves hands its triggers to the others.
+
+use crate::fixtures::get_or_init_fixture;
+use async_trait::async_trait;
+use cloacina::context::Context;
+use cloacina::cron_trigger_scheduler::{Scheduler, SchedulerConfig};
+use cloacina::executor::{
+    StatusCallback, WorkflowExecution, WorkflowExecutionError, WorkflowExecutionResult,
+    WorkflowExecutor, WorkflowStatus,
+};
+use cloacina::trigger::{Trigger, TriggerResult};
+use cloacina::{Runtime, UniversalUuid};
+use serial_test::serial;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+const LEASE_TTL: Duration = Duration::from_millis(300);
+
+/// Never fires; the test only cares about who polls it.
+#[derive(Debug)]
+struct IdleTrigger {
+    name: &'static str,
+}
+
+#[async_trait]
+impl Trigger for IdleTrigger {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_millis(1)
+    }
+
+    fn allow_concurrent(&self) -> bool {
+        false
+    }
+
+    async fn poll(&self) -> Result<TriggerResult, cloacina_workflow::TriggerError> {
+        Ok(TriggerResult::Skip)
+    }
+}
+
+struct NoopExecutor;
+
+#[async_trait]
+impl WorkflowExecutor for NoopExecutor {
+    async fn execute(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!("lease test triggers never fire")
+    }
+
+    async fn execute_async(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+    ) -> Result<WorkflowExecution, WorkflowExecutionError> {
+        unreachable!("trigger path uses execute only")
+    }
+    async fn get_execution_status(
+        &self,
+        _execution_id: Uuid,
+    ) -> Result<WorkflowStatus, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn get_execution_result(
+        &self,
+        _execution_id: Uuid,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn cancel_execution(&self, _execution_id: Uuid) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn pause_execution(
+        &self,
+        _execution_id: Uuid,
+        _reason: Option<&str>,
+    ) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn resume_execution(&self, _execution_id: Uuid) -> Result<(), WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn execute_with_callback(
+        &self,
+        _workflow_name: &str,
+        _context: Context<serde_json::Value>,
+        _callback: Box<dyn StatusCallback>,
+    ) -> Result<WorkflowExecutionResult, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn list_executions(
+        &self,
+    ) -> Result<Vec<WorkflowExecutionResult>, WorkflowExecutionError> {
+        unreachable!()
+    }
+    async fn shutdown(&self) -> Result<(), WorkflowExecutionError> {
+        Ok(())
+    }
+}
+
+fn leasing_scheduler(
+    dal: &Arc<cloacina::dal::DAL>,
+    runtime: &Arc<Runtime>,
+    owner: UniversalUuid,
+) -> Scheduler {
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    Scheduler::new(
+        dal.clone(),
+        Arc::new(NoopExecutor),
+        SchedulerConfig {
+            trigger_base_poll_interval: Duration::from_millis(1),
+            trigger_lease_ttl: Some(LEASE_TTL),
+            ..SchedulerConfig::default()
+        },
+        shutdown_rx,
+        runtime.clone(),
+        Arc::new(tokio::sync::Notify::new()),
+    )
+    .with_lease_owner(owner)
+}
+
+/// Lease owner of each enabled trigger, by trigger name.
+async fn lease_owners(dal: &cloacina::dal::DAL) -> HashMap<String, Option<UniversalUuid>> {
+    dal.schedule()
+        .get_enabled_triggers()
+        .await
+        .expect("list triggers")
+        .into_iter()
+        .map(|s| (s.trigger_name.unwrap_or_default(), s.lease_owner))
+        .collect()
+}
+
+/// Two schedulers end up with one trigger each; when one releases its
+/// leases the other takes both triggers over.
+#[tokio::test]
+#[serial]
+async fn test_trigger_leases_balance_and_fail_over() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let runtime = Arc::new(Runtime::empty());
+    let dal = Arc::new(cloacina::dal::DAL::new(fixture.get_database()));
+    let owner_a = UniversalUuid::new_v4();
+    let owner_b = UniversalUuid::new_v4();
+    let mut a = leasing_scheduler(&dal, &runtime, owner_a);
+    let mut b = leasing_scheduler(&dal, &runtime, owner_b);
+
+    for name in ["lease_trigger_one", "lease_trigger_two"] {
+        let trigger = Arc::new(IdleTrigger { name });
+        let registered = trigger.clone();
+        runtime.register_trigger(name.to_string(), move || {
+            registered.clone() as Arc<dyn Trigger>
+        });
+        a.register_trigger(trigger.as_ref(), &format!("wf_{}", name))
+            .await
+            .expect("register trigger");
+    }
+
+    // Each round is longer than the renewal period, so every poll renews
+    // and rebalances.
+    for _ in 0..4 {
+        a.poll_triggers_once().await.expect("poll triggers");
+        b.poll_triggers_once().await.expect("poll triggers");
+        tokio::time::sleep(LEASE_TTL / 2).await;
+    }
+
+    let owners = lease_owners(&dal).await;
+    assert_eq!(owners.len(), 2);
+    assert_eq!(
+        owners.values().filter(|o| **o == Some(owner_a)).count(),
+        1,
+        "each scheduler should hold one lease: {:?}",
+        owners
+    );
+    assert_eq!(
+        owners.values().filter(|o| **o == Some(owner_b)).count(),
+        1,
+        "each scheduler should hold one lease: {:?}",
+        owners
+    );
+
+    a.release_trigger_leases().await.expect("release leases");
+    b.poll_triggers_once().await.expect("poll triggers");
+
+    let owners = lease_owners(&dal).await;
+    assert!(
+        owners.values().all(|o| *o == Some(owner_b)),
+        "the remaining scheduler should take over every trigger: {:?}",
+        owners
+    );
+}
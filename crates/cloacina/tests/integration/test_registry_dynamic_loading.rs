@@ -158,6 +158,9 @@ fn test_reconciler_config() {
         default_tenant_id: "custom".to_string(),
         require_signatures: false,
         verification_org_id: None,
+        tenant_trust_orgs: Default::default(),
+        signature_trust_store: None,
+        capability_policy: Default::default(),
     };
 
     assert_eq!(custom_config.reconcile_interval.as_secs(), 60);
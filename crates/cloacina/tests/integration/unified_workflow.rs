@@ -78,6 +78,54 @@ async fn test_workflow_executes_sqlite() {
     runner.shutdown().await.expect("Shutdown failed");
 }
 
+#[test]
+fn test_scoped_runtime_seeds_only_selected_workflow() {
+    let runtime = cloacina::Runtime::empty();
+    assert!(runtime.seed_workflow_from_inventory("unified_test_workflow"));
+
+    assert_eq!(runtime.workflow_names(), vec!["unified_test_workflow"]);
+    let workflow = runtime.get_workflow("unified_test_workflow").unwrap();
+    let task_ids = workflow.get_task_ids();
+    assert_eq!(runtime.task_namespaces().len(), task_ids.len());
+    for namespace in &task_ids {
+        assert!(runtime.get_task(namespace).is_some());
+    }
+    assert!(
+        runtime.trigger_names().is_empty(),
+        "triggers are not seeded with the workflow"
+    );
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn test_workflow_executes_on_scoped_runtime_sqlite() {
+    let runtime = cloacina::Runtime::empty();
+    runtime.seed_workflow_from_inventory("unified_test_workflow");
+    let config = DefaultRunnerConfig::builder()
+        .enable_registry_reconciler(false)
+        .build()
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(":memory:")
+        .with_config(config)
+        .runtime(runtime)
+        .build()
+        .await
+        .expect("Failed to create runner");
+
+    let result = runner
+        .execute("unified_test_workflow", Context::new())
+        .await
+        .expect("Workflow execution failed");
+    assert_eq!(
+        result.final_context.get("step_two_done"),
+        Some(&json!(true))
+    );
+
+    runner.shutdown().await.expect("Shutdown failed");
+}
+
 // --- Trigger macro tests ---
 
 use cloacina_workflow::TriggerResult;
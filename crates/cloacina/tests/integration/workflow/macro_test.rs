@@ -166,6 +166,43 @@ fn test_workflow_secrets_ride_manifest_metadata_as_encrypted_slots() {
     }
 }
 
+// `#[workflow(health_check = ...)]` names a (private) async fn inside the
+// module; the descriptor entry carries a wrapper the registry runs after load.
+#[workflow(name = "health_checked_workflow", health_check = check_upstream)]
+pub mod health_checked_workflow {
+    use super::*;
+
+    async fn check_upstream() -> Result<(), String> {
+        Err("upstream unreachable".to_string())
+    }
+
+    #[task(id = "fetch", dependencies = [])]
+    pub async fn fetch(_context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_workflow_health_check_rides_descriptor_entry() {
+    let entries: Vec<_> =
+        inventory::iter::<cloacina::cloacina_workflow_plugin::WorkflowDescriptorEntry>
+            .into_iter()
+            .collect();
+
+    let check = entries
+        .iter()
+        .find(|e| e.name == "health_checked_workflow")
+        .and_then(|e| e.health_check)
+        .expect("health_checked_workflow should carry its health check");
+    assert_eq!(check().await, Err("upstream unreachable".to_string()));
+
+    let undeclared = entries
+        .iter()
+        .find(|e| e.name == "parallel_execution")
+        .expect("descriptor entry for parallel_execution should exist");
+    assert!(undeclared.health_check.is_none());
+}
+
 #[test]
 fn test_workflow_macro_emits_inventory_entries() {
     // Smoke test for T-0505: confirm that `#[workflow]` and `#[task]` emit
@@ -228,3 +265,50 @@ fn test_workflow_execution_levels() {
     // Level 1: task_c (depends on both task_a and task_b)
     assert_eq!(execution_levels[1].len(), 1);
 }
+
+// `#[workflow(outputs(...))]` generates a typed `Outputs` struct and publishes
+// one slot per output; `Option<T>` outputs are optional slots.
+#[workflow(
+    name = "output_declaring_workflow",
+    outputs(row_count: u64, summary: Option<String>)
+)]
+pub mod output_declaring_workflow {
+    use super::*;
+
+    #[task(id = "count_rows", dependencies = [])]
+    pub async fn count_rows(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {
+        context.insert("row_count", serde_json::json!(3))?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_workflow_outputs_mark_option_outputs_optional() {
+    use cloacina::input_interface::InputSlot;
+    use cloacina::outputs::WorkflowOutputs;
+
+    let outputs_json =
+        inventory::iter::<cloacina::cloacina_workflow_plugin::WorkflowDescriptorEntry>
+            .into_iter()
+            .find(|e| e.name == "output_declaring_workflow")
+            .map(|e| (e.outputs)())
+            .expect("descriptor entry for output_declaring_workflow should exist");
+    let slots: Vec<InputSlot> =
+        serde_json::from_str(&outputs_json).expect("outputs JSON should parse into InputSlots");
+
+    let row_count = slots.iter().find(|s| s.name == "row_count").unwrap();
+    assert!(row_count.required);
+    let summary = slots.iter().find(|s| s.name == "summary").unwrap();
+    assert!(!summary.required, "Option<_> outputs must not be required");
+    assert_eq!(summary.schema["type"], "string");
+
+    assert_eq!(
+        output_declaring_workflow::Outputs::KEYS,
+        &["row_count", "summary"]
+    );
+    let mut context = Context::new();
+    context.insert("row_count", serde_json::json!(3)).unwrap();
+    let out = output_declaring_workflow::Outputs::from_context(&context).unwrap();
+    assert_eq!(out.row_count, 3);
+    assert_eq!(out.summary, None);
+}
@@ -17,4 +17,5 @@
 pub mod basic;
 pub mod callback_test;
 pub mod macro_test;
+pub mod runtime_builder;
 pub mod subgraph;
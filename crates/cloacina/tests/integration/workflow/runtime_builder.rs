@@ -0,0 +1,200 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Workflows assembled at runtime from closure-backed tasks run like
+//! macro-defined ones.
+
+use cloacina::executor::WorkflowExecutor;
+use cloacina::runner::DefaultRunner;
+use cloacina::{Context, FnTask, UniversalUuid, Workflow};
+use serde_json::json;
+use std::time::Duration;
+
+use crate::fixtures::get_or_init_fixture;
+
+/// Build a fan-in DAG from a list of dataset names, the way a config-driven
+/// catalog would.
+fn catalog_workflow(name: &str, datasets: &[&str]) -> cloacina::WorkflowBuilder {
+    let mut builder = Workflow::builder(name).description("Generated from a dataset catalog");
+    let mut report = FnTask::new("report", |mut context| async move {
+        context.insert("reported", json!(true))?;
+        Ok(context)
+    });
+    for dataset in datasets {
+        let key = dataset.to_string();
+        builder = builder
+            .task(FnTask::new(dataset, move |mut context| {
+                let key = key.clone();
+                async move {
+                    context.insert(key, json!("loaded"))?;
+                    Ok(context)
+                }
+            }))
+            .unwrap();
+        report = report.depends_on(dataset);
+    }
+    builder.task(report).unwrap()
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn test_runtime_built_workflow_executes() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let runtime = cloacina::Runtime::new();
+    let workflow = catalog_workflow("runtime_built_catalog", &["orders", "customers"])
+        .register(&runtime)
+        .unwrap();
+    assert_eq!(workflow.get_task_ids().len(), 3);
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+    let execution = runner
+        .execute_async("runtime_built_catalog", Context::new())
+        .await
+        .unwrap();
+
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+    let exec_id = UniversalUuid(execution.execution_id);
+    crate::fixtures::poll_until(
+        Duration::from_secs(15),
+        Duration::from_millis(100),
+        "runtime-built workflow should complete",
+        || {
+            let dal = dal.clone();
+            async move {
+                dal.workflow_execution()
+                    .get_by_id(exec_id)
+                    .await
+                    .is_ok_and(|exec| exec.status == "Completed" || exec.status == "Failed")
+            }
+        },
+    )
+    .await;
+
+    let exec = dal.workflow_execution().get_by_id(exec_id).await.unwrap();
+    runner.shutdown().await.unwrap();
+    assert_eq!(exec.status, "Completed");
+}
+
+/// A workflow embedding another one runs the embedded tasks inline, between
+/// its own tasks.
+#[tokio::test]
+#[serial_test::serial]
+async fn test_embedded_workflow_runs_inline() {
+    let fixture = get_or_init_fixture().await;
+    let mut fixture = fixture.lock().unwrap_or_else(|e| e.into_inner());
+    fixture.reset_database().await;
+    fixture.initialize().await;
+
+    let step = |id: &str| {
+        let key = id.to_string();
+        FnTask::new(id, move |mut context| {
+            let key = key.clone();
+            async move {
+                context.insert(key, json!(true))?;
+                Ok(context)
+            }
+        })
+    };
+    let runtime = cloacina::Runtime::empty();
+    Workflow::builder("runtime_built_vendor")
+        .package("vendor_pkg")
+        .task(step("extract"))
+        .unwrap()
+        .task(step("load").depends_on("extract"))
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+    Workflow::builder("runtime_built_composed")
+        .task(step("prepare"))
+        .unwrap()
+        .embed_workflow("vendor", "runtime_built_vendor", &["prepare"])
+        .unwrap()
+        .task(step("publish").depends_on("vendor"))
+        .unwrap()
+        .register(&runtime)
+        .unwrap();
+
+    let runner = DefaultRunner::builder()
+        .database_url(&fixture.get_database_url())
+        .schema(&fixture.get_schema())
+        .runtime(runtime)
+        .build()
+        .await
+        .unwrap();
+    let execution = runner
+        .execute_async("runtime_built_composed", Context::new())
+        .await
+        .unwrap();
+
+    let dal = cloacina::dal::DAL::new(fixture.get_database());
+    let exec_id = UniversalUuid(execution.execution_id);
+    crate::fixtures::poll_until(
+        Duration::from_secs(15),
+        Duration::from_millis(100),
+        "composed workflow should complete",
+        || {
+            let dal = dal.clone();
+            async move {
+                dal.workflow_execution()
+                    .get_by_id(exec_id)
+                    .await
+                    .is_ok_and(|exec| exec.status == "Completed" || exec.status == "Failed")
+            }
+        },
+    )
+    .await;
+
+    let exec = dal.workflow_execution().get_by_id(exec_id).await.unwrap();
+    let tasks = dal
+        .task_execution()
+        .get_all_tasks_for_workflow(exec_id)
+        .await
+        .unwrap();
+    runner.shutdown().await.unwrap();
+
+    assert_eq!(exec.status, "Completed");
+    let mut names: Vec<String> = tasks
+        .iter()
+        .map(|t| t.task_name.rsplit("::").next().unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        ["prepare", "publish", "vendor.extract", "vendor.load"]
+    );
+    let publish = tasks
+        .iter()
+        .find(|t| t.task_name.ends_with("::publish"))
+        .unwrap();
+    let load = tasks
+        .iter()
+        .find(|t| t.task_name.ends_with("::vendor.load"))
+        .unwrap();
+    assert!(
+        load.completed_at.unwrap().0 <= publish.started_at.unwrap().0,
+        "publish waits for the embedded sub-DAG"
+    );
+}
@@ -0,0 +1,95 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Per-task memory limits under allocation accounting.
+//!
+//! Lives in its own test binary because it installs
+//! [`TrackingAllocator`] as the global allocator.
+
+use cloacina::memory::{
+    guard_task, tracking_allocator_active, MemoryAccounting, TrackingAllocator,
+};
+use cloacina::TaskError;
+
+#[global_allocator]
+static ALLOC: TrackingAllocator = TrackingAllocator::new();
+
+const MIB: u64 = 1024 * 1024;
+
+#[tokio::test]
+async fn task_over_its_limit_fails_with_memory_limit_exceeded() {
+    assert!(tracking_allocator_active());
+
+    let result = guard_task("leaky", Some(MIB), MemoryAccounting::Allocations, async {
+        let mut retained = Vec::new();
+        for _ in 0..8 {
+            retained.push(vec![1u8; 512 * 1024]);
+            tokio::task::yield_now().await;
+        }
+        Ok::<_, TaskError>(retained.len())
+    })
+    .await;
+
+    match result {
+        Err(TaskError::MemoryLimitExceeded {
+            task_id,
+            limit_bytes,
+            observed_bytes,
+        }) => {
+            assert_eq!(task_id, "leaky");
+            assert_eq!(limit_bytes, MIB);
+            assert!(observed_bytes > MIB);
+        }
+        other => panic!("expected MemoryLimitExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn freed_memory_does_not_count_towards_the_limit() {
+    let result = guard_task("churn", Some(MIB), MemoryAccounting::Allocations, async {
+        for _ in 0..8 {
+            let scratch = vec![1u8; 512 * 1024];
+            drop(scratch);
+            tokio::task::yield_now().await;
+        }
+        Ok::<_, TaskError>(())
+    })
+    .await;
+
+    assert!(result.is_ok(), "got {:?}", result);
+}
+
+#[tokio::test]
+async fn concurrent_tasks_are_accounted_separately() {
+    let leaky = guard_task("leaky", Some(MIB), MemoryAccounting::Allocations, async {
+        let mut retained = Vec::new();
+        for _ in 0..8 {
+            retained.push(vec![1u8; 512 * 1024]);
+            tokio::task::yield_now().await;
+        }
+        Ok::<_, TaskError>(())
+    });
+    let frugal = guard_task("frugal", Some(MIB), MemoryAccounting::Allocations, async {
+        for _ in 0..8 {
+            tokio::task::yield_now().await;
+        }
+        Ok::<_, TaskError>(())
+    });
+
+    let (leaky, frugal) = tokio::join!(leaky, frugal);
+    assert!(matches!(leaky, Err(TaskError::MemoryLimitExceeded { .. })));
+    assert!(frugal.is_ok(), "got {:?}", frugal);
+}
@@ -17,6 +17,9 @@
 //! Implementation of the `admin cleanup-events` command.
 //!
 //! Cleans up old execution events from the database based on a retention policy.
+//! When a server is targeted (`--server`, `--profile`, or a default profile
+//! with no database URL configured) the cleanup runs remotely through
+//! `POST /v1/maintenance/cleanup-events`, so no database credentials are needed.
 
 use anyhow::{anyhow, Context, Result};
 use chrono::{Duration, Utc};
@@ -25,6 +28,13 @@ use cloacina::database::universal_types::UniversalTimestamp;
 use cloacina::Database;
 use tracing::info;
 
+use crate::commands::config::CloacinaConfig;
+use crate::shared::client::CliClient;
+use crate::shared::client_ctx::ClientContext;
+use crate::shared::error::CliError;
+use crate::shared::render;
+use crate::GlobalOpts;
+
 /// Parse a duration string like "90d", "30d", "7d", "24h", "1h30m" into a chrono::Duration.
 ///
 /// Supported units:
@@ -37,7 +47,7 @@ use tracing::info;
 /// - "90d" -> 90 days
 /// - "24h" -> 24 hours
 /// - "7d12h" -> 7 days and 12 hours
-fn parse_duration(s: &str) -> Result<Duration> {
+pub(crate) fn parse_duration(s: &str) -> Result<Duration> {
     let s = s.trim().to_lowercase();
     if s.is_empty() {
         return Err(anyhow!("Duration string cannot be empty"));
@@ -150,6 +160,51 @@ pub async fn run(database_url: &str, older_than: &str, dry_run: bool) -> Result<
     Ok(())
 }
 
+/// Whether the cleanup should go through the server instead of the database.
+///
+/// An explicit `--database-url` (or `DATABASE_URL`) always wins; an explicit
+/// `--server`/`--profile` selects the server; otherwise the server is used only
+/// when a default profile exists and no `database_url` is configured.
+pub fn targets_server(
+    globals: &GlobalOpts,
+    database_url: Option<&str>,
+    config: &CloacinaConfig,
+) -> bool {
+    if database_url.is_some() {
+        return false;
+    }
+    if globals.server.is_some() || globals.profile.is_some() {
+        return true;
+    }
+    config.database_url.is_none() && config.default_profile.is_some()
+}
+
+/// Run the cleanup-events command against a remote server.
+///
+/// Requires a god-mode (platform admin) API key.
+pub async fn run_remote(
+    globals: &GlobalOpts,
+    older_than: &str,
+    dry_run: bool,
+) -> Result<(), CliError> {
+    let duration = parse_duration(older_than)
+        .with_context(|| format!("Invalid duration: '{}'", older_than))
+        .map_err(|e| CliError::UserError(format!("{e:#}")))?;
+
+    let config = CloacinaConfig::load(&globals.home.join("config.toml"));
+    let ctx = ClientContext::resolve(globals, &config).map_err(CliError::Other)?;
+    let output = ctx.output;
+    let client = CliClient::new(ctx)?;
+
+    let resp = client
+        .inner()
+        .cleanup_events(duration.num_seconds() as u64, dry_run)
+        .await
+        .map_err(CliError::from)?;
+    let body = serde_json::to_value(&resp).map_err(|e| CliError::Other(e.into()))?;
+    render::object(&body, output)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +273,39 @@ mod tests {
     fn test_parse_duration_zero() {
         assert!(parse_duration("0d").is_err());
     }
+
+    fn globals() -> GlobalOpts {
+        GlobalOpts {
+            verbose: false,
+            home: std::path::PathBuf::from("."),
+            profile: None,
+            server: None,
+            api_key: None,
+            tenant: None,
+            json: false,
+            output: None,
+            no_color: false,
+        }
+    }
+
+    #[test]
+    fn test_targets_server_precedence() {
+        let mut config = CloacinaConfig::default();
+        let mut opts = globals();
+        assert!(!targets_server(&opts, None, &config));
+
+        config.default_profile = Some("prod".into());
+        assert!(targets_server(&opts, None, &config));
+
+        config.database_url = Some("postgres://localhost/cloacina".into());
+        assert!(!targets_server(&opts, None, &config));
+
+        opts.server = Some("https://cloacina.example.com".into());
+        assert!(targets_server(&opts, None, &config));
+        assert!(!targets_server(
+            &opts,
+            Some("postgres://localhost/cloacina"),
+            &config
+        ));
+    }
 }
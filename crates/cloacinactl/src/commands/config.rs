@@ -67,6 +67,11 @@ pub struct Profile {
     /// API key. Accepts raw literal, `env:VAR`, or `file:PATH`. `keyring:NAME`
     /// is rejected in v1 (deferred).
     pub api_key: String,
+    /// TLS for a server behind a private CA or requiring client certificates
+    /// (`[profiles.<name>.tls]`: `ca_cert`, `client_cert`, `client_key`).
+    /// Unset fields fall back to the `CLOACINA_TLS_*` env vars.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<cloacina_client::TlsOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +94,26 @@ pub struct DaemonSection {
     pub cron_recovery_interval_s: u64,
     /// Cron lost task threshold in minutes.
     pub cron_lost_threshold_min: u64,
+    /// Hours after an execution finishes before its contexts are purged
+    /// (None = keep forever). Execution metadata is kept either way.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_retention_h: Option<u64>,
+    /// Superseded package versions to keep per package, counting the active
+    /// one (None = keep every version).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_gc_keep_last: Option<usize>,
+    /// Only log which package versions registry GC would delete.
+    pub registry_gc_dry_run: bool,
+    /// Record the context keys each task reads and writes.
+    pub trace_context_access: bool,
+    /// Record which task changed each context key, with previous values.
+    pub track_context_lineage: bool,
+    /// Format contexts are stored in: `json`, `msgpack` or `cbor`.
+    pub context_format: cloacina::ContextFormat,
+    /// Concurrent executions allowed per workflow name
+    /// (`[daemon.max_concurrent_executions]`); excess runs are queued.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_concurrent_executions: BTreeMap<String, usize>,
 }
 
 impl Default for DaemonSection {
@@ -102,6 +127,13 @@ impl Default for DaemonSection {
             cron_max_catchup: None, // None = unlimited (run_all)
             cron_recovery_interval_s: 300,
             cron_lost_threshold_min: 10,
+            context_retention_h: None,
+            registry_gc_keep_last: None,
+            registry_gc_dry_run: false,
+            trace_context_access: false,
+            track_context_lineage: false,
+            context_format: cloacina::ContextFormat::Json,
+            max_concurrent_executions: BTreeMap::new(),
         }
     }
 }
@@ -384,11 +416,14 @@ pub fn run_profile_set(
     default: bool,
 ) -> Result<()> {
     let mut config = CloacinaConfig::load(config_path);
+    // Re-pointing a profile keeps its TLS settings.
+    let tls = config.profiles.get(name).and_then(|p| p.tls.clone());
     config.profiles.insert(
         name.to_string(),
         Profile {
             server: server.to_string(),
             api_key: api_key.to_string(),
+            tls,
         },
     );
     if default {
@@ -508,6 +543,13 @@ mod tests {
         assert!(config.daemon.cron_max_catchup.is_none());
         assert_eq!(config.daemon.cron_recovery_interval_s, 300);
         assert_eq!(config.daemon.cron_lost_threshold_min, 10);
+        assert_eq!(config.daemon.context_retention_h, None);
+        assert_eq!(config.daemon.registry_gc_keep_last, None);
+        assert!(!config.daemon.registry_gc_dry_run);
+        assert!(!config.daemon.trace_context_access);
+        assert!(!config.daemon.track_context_lineage);
+        assert_eq!(config.daemon.context_format, cloacina::ContextFormat::Json);
+        assert!(config.daemon.max_concurrent_executions.is_empty());
         assert!(config.watch.directories.is_empty());
         assert_eq!(config.server.default_executor, "default");
     }
@@ -548,6 +590,9 @@ database_url = "postgres://localhost/test"
 poll_interval_ms = 1000
 shutdown_timeout_s = 60
 
+[daemon.max_concurrent_executions]
+data_backup_workflow = 1
+
 [watch]
 directories = ["/extra/dir1", "~/workflows"]
 "#,
@@ -563,6 +608,13 @@ directories = ["/extra/dir1", "~/workflows"]
         assert_eq!(config.daemon.shutdown_timeout_s, 60);
         // Unspecified fields should still have defaults
         assert_eq!(config.daemon.log_level, "info");
+        assert_eq!(
+            config
+                .daemon
+                .max_concurrent_executions
+                .get("data_backup_workflow"),
+            Some(&1)
+        );
         assert_eq!(config.watch.directories.len(), 2);
     }
 
@@ -0,0 +1,72 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Implementation of the `admin convert-contexts` command.
+//!
+//! Rewrites every stored context into one storage format. Rows in other
+//! formats stay readable, so this is only needed to shrink existing rows
+//! after switching a runner's `context_format`, or to move back to JSON.
+
+use anyhow::{anyhow, Context, Result};
+use cloacina::crypto::AtRestEncryption;
+use cloacina::dal::DAL;
+use cloacina::{ContextFormat, Database};
+use tracing::info;
+
+/// Run the convert-contexts command.
+///
+/// # Arguments
+///
+/// * `database_url` - The database connection URL
+/// * `schema` - Tenant schema to process (`None` for the default schema)
+/// * `format` - Format to rewrite the contexts in
+/// * `batch_size` - Rows read per query
+pub async fn run(
+    database_url: &str,
+    schema: Option<&str>,
+    format: ContextFormat,
+    batch_size: i64,
+) -> Result<()> {
+    if batch_size <= 0 {
+        return Err(anyhow!("--batch-size must be greater than zero"));
+    }
+
+    info!(
+        %format,
+        schema = schema.unwrap_or("public"),
+        "Converting contexts"
+    );
+
+    // Use a default pool size of 4 for CLI operations
+    let mut database = Database::try_new_with_schema(database_url, "", 4, schema)
+        .context("Failed to connect to database")?
+        .with_context_format(format);
+    // Sealed rows are opened and sealed again with the same keys.
+    if let Some(encryption) =
+        AtRestEncryption::from_env().context("Invalid CLOACINA_AT_REST_KEYS")?
+    {
+        database = database.with_at_rest_encryption(encryption);
+    }
+
+    let converted = DAL::new(database)
+        .context()
+        .convert_format(batch_size)
+        .await
+        .context("Failed to convert contexts")?;
+    info!("Converted {} context(s) to {}", converted, format);
+
+    Ok(())
+}
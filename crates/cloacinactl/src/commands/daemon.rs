@@ -190,7 +190,7 @@ pub async fn run(
 
     // 3. Create/open SQLite database
     let db_path = home.join("cloacina.db");
-    let db_url = format!("sqlite://{}?mode=rwc&_journal_mode=WAL", db_path.display());
+    let db_url = format!("sqlite://{}?mode=rwc", db_path.display());
     info!("Database: {}", db_path.display());
 
     // 4. Create DefaultRunner with SQLite backend and configured poll intervals
@@ -201,6 +201,24 @@ pub async fn run(
     if let Some(max_catchup) = daemon_cfg.cron_max_catchup {
         config_builder = config_builder.cron_max_catchup_executions(max_catchup as usize);
     }
+    if let Some(hours) = daemon_cfg.context_retention_h {
+        config_builder =
+            config_builder.context_retention(Some(Duration::from_secs(hours * 60 * 60)));
+    }
+    if let Some(keep_last) = daemon_cfg.registry_gc_keep_last {
+        config_builder = config_builder
+            .registry_gc(Some(cloacina::registry::RegistryGcPolicy::keep_last(
+                keep_last,
+            )))
+            .registry_gc_dry_run(daemon_cfg.registry_gc_dry_run);
+    }
+    config_builder = config_builder
+        .trace_context_access(daemon_cfg.trace_context_access)
+        .track_context_lineage(daemon_cfg.track_context_lineage)
+        .context_format(daemon_cfg.context_format);
+    for (workflow_name, limit) in &daemon_cfg.max_concurrent_executions {
+        config_builder = config_builder.max_concurrent_executions(workflow_name, *limit);
+    }
     let runner_config = config_builder
         .build()
         .context("Invalid runner configuration")?;
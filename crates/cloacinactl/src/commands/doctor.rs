@@ -0,0 +1,126 @@
+/*
+ *  Copyright 2025-2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Implementation of the top-level `doctor` command.
+//!
+//! Runs [`cloacina::doctor`]'s environment checks against the configured
+//! database and prints each result with its suggested fix. Exits non-zero
+//! when any check fails; warnings alone still exit 0.
+
+use anyhow::{Context, Result};
+use cloacina::doctor::{run_doctor, CheckStatus, DoctorOptions, DoctorReport};
+
+use crate::commands::cleanup_events::parse_duration;
+use crate::shared::error::CliError;
+use crate::OutputFormat;
+
+/// Build [`DoctorOptions`] from the CLI's duration strings (`5s`, `10m`, ...).
+pub fn options(
+    schema: Option<String>,
+    max_clock_skew: &str,
+    stale_after: &str,
+) -> Result<DoctorOptions> {
+    let max_clock_skew = parse_duration(max_clock_skew)
+        .and_then(|d| Ok(d.to_std()?))
+        .with_context(|| format!("Invalid --max-clock-skew: '{}'", max_clock_skew))?;
+    let stale_after = parse_duration(stale_after)
+        .and_then(|d| Ok(d.to_std()?))
+        .with_context(|| format!("Invalid --stale-after: '{}'", stale_after))?;
+    Ok(DoctorOptions {
+        schema,
+        max_clock_skew,
+        stale_after,
+    })
+}
+
+pub async fn run(
+    database_url: &str,
+    options: &DoctorOptions,
+    output: OutputFormat,
+) -> Result<(), CliError> {
+    let report = run_doctor(database_url, options).await;
+    print_report(&report, output)?;
+
+    let failed = report
+        .checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    if failed > 0 {
+        return Err(CliError::UserError(format!(
+            "doctor: {failed} check(s) failed"
+        )));
+    }
+    Ok(())
+}
+
+fn print_report(report: &DoctorReport, output: OutputFormat) -> Result<(), CliError> {
+    match output {
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(report).map_err(|e| CliError::UserError(e.to_string()))?
+        ),
+        OutputFormat::Yaml => print!(
+            "{}",
+            serde_yaml::to_string(report).map_err(|e| CliError::UserError(e.to_string()))?
+        ),
+        // Just the checks that need attention, one per line.
+        OutputFormat::Id => {
+            for check in &report.checks {
+                if matches!(check.status, CheckStatus::Warn | CheckStatus::Fail) {
+                    println!("{}", check.name);
+                }
+            }
+        }
+        OutputFormat::Table => {
+            for check in &report.checks {
+                println!(
+                    "[{}] {:<20} {}",
+                    label(check.status),
+                    check.name,
+                    check.detail
+                );
+                if let Some(fix) = &check.fix {
+                    println!("       {:<20} fix: {}", "", fix);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn label(status: CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Ok => " ok ",
+        CheckStatus::Skip => "skip",
+        CheckStatus::Warn => "warn",
+        CheckStatus::Fail => "FAIL",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_parse_durations() {
+        let opts = options(Some("tenant_a".into()), "2s", "1h").unwrap();
+        assert_eq!(opts.schema.as_deref(), Some("tenant_a"));
+        assert_eq!(opts.max_clock_skew, std::time::Duration::from_secs(2));
+        assert_eq!(opts.stale_after, std::time::Duration::from_secs(3600));
+        assert!(options(None, "soon", "1h").is_err());
+    }
+}
@@ -18,7 +18,10 @@
 
 pub mod cleanup_events;
 pub mod config;
+pub mod convert_contexts;
 pub mod daemon;
+pub mod doctor;
 pub mod health;
+pub mod reencrypt;
 pub mod status;
 pub mod watcher;
@@ -0,0 +1,70 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! Implementation of the `admin reencrypt` command.
+//!
+//! Seals plaintext contexts and package archives, and re-wraps records sealed
+//! under a retired key, with the keys in `CLOACINA_AT_REST_KEYS`. Run it after
+//! enabling encryption or rotating the key; the retired key can be dropped
+//! once it completes.
+
+use anyhow::{anyhow, Context, Result};
+use cloacina::crypto::AtRestEncryption;
+use cloacina::dal::{UnifiedRegistryStorage, DAL};
+use cloacina::Database;
+use tracing::info;
+
+/// Run the reencrypt command.
+///
+/// # Arguments
+///
+/// * `database_url` - The database connection URL
+/// * `schema` - Tenant schema to process (`None` for the default schema)
+/// * `batch_size` - Rows read per query
+pub async fn run(database_url: &str, schema: Option<&str>, batch_size: i64) -> Result<()> {
+    if batch_size <= 0 {
+        return Err(anyhow!("--batch-size must be greater than zero"));
+    }
+    let encryption = AtRestEncryption::from_env()
+        .context("Invalid CLOACINA_AT_REST_KEYS")?
+        .ok_or_else(|| anyhow!("CLOACINA_AT_REST_KEYS must be set to re-encrypt"))?;
+
+    info!(
+        key_id = %encryption.current_key_id(),
+        schema = schema.unwrap_or("public"),
+        "Re-encrypting contexts and package archives"
+    );
+
+    // Use a default pool size of 4 for CLI operations
+    let database = Database::try_new_with_schema(database_url, "", 4, schema)
+        .context("Failed to connect to database")?
+        .with_at_rest_encryption(encryption);
+
+    let contexts = DAL::new(database.clone())
+        .context()
+        .reencrypt(batch_size)
+        .await
+        .context("Failed to re-encrypt contexts")?;
+    info!("Re-encrypted {} context(s)", contexts);
+
+    let records = UnifiedRegistryStorage::new(database)
+        .reencrypt(batch_size)
+        .await
+        .context("Failed to re-encrypt package archives")?;
+    info!("Re-encrypted {} package archive record(s)", records);
+
+    Ok(())
+}
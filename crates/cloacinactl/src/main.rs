@@ -33,8 +33,8 @@ mod shared;
 use shared::error::CliError;
 
 use nouns::{
-    accumulator, compiler, constructor, daemon, execution, graph, key, package, reactor, secret,
-    server, tenant, trigger, workflow,
+    accumulator, audit, bundle, compiler, constructor, cron, daemon, execution, graph, key,
+    package, reactor, secret, server, tenant, trigger, workflow,
 };
 
 /// cloacinactl — Cloacina task orchestration engine
@@ -146,15 +146,45 @@ enum Commands {
     /// Key — API key lifecycle
     Key(key::KeyCmd),
 
+    /// Audit — read and verify the tamper-evident audit chain (admin key)
+    Audit(audit::AuditCmd),
+
     /// Secret — tenant secrets (encrypted; metadata-only reads)
     Secret(secret::SecretCmd),
 
     /// Trigger — registered event triggers
     Trigger(trigger::TriggerCmd),
 
+    /// Cron — offline cron expression tools (simulate upcoming fire times)
+    Cron(cron::CronCmd),
+
+    /// Bundle — export/import packages, version pins, and schedule state
+    /// between servers (promote staging → production)
+    Bundle(bundle::BundleCmd),
+
     /// Composite status: daemon + server side by side.
     Status,
 
+    /// Diagnose the environment: database connectivity, migrations, schema
+    /// permissions, SQLite pragmas, registry storage, clock skew, and
+    /// orphaned executions
+    Doctor {
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: Option<String>,
+
+        /// Tenant schema to inspect (PostgreSQL); defaults to the public schema
+        #[arg(long)]
+        schema: Option<String>,
+
+        /// Database clock difference that counts as skew
+        #[arg(long, default_value = "5s")]
+        max_clock_skew: String,
+
+        /// Idle time after which executions, task claims, and builds count as orphaned
+        #[arg(long, default_value = "10m")]
+        stale_after: String,
+    },
+
     /// Manage configuration (get/set/list values in ~/.cloacina/config.toml)
     Config {
         #[command(subcommand)]
@@ -220,7 +250,8 @@ enum ProfileCommands {
 
 #[derive(Subcommand)]
 enum AdminCommands {
-    /// Clean up old execution events from the database
+    /// Clean up old execution events — directly in the database, or through
+    /// the server when one is targeted (`--server`/`--profile`)
     CleanupEvents {
         #[arg(long, env = "DATABASE_URL")]
         database_url: Option<String>,
@@ -231,6 +262,36 @@ enum AdminCommands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Encrypt stored contexts and package archives with the current
+    /// at-rest key (CLOACINA_AT_REST_KEYS)
+    Reencrypt {
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: Option<String>,
+
+        /// Tenant schema to process; defaults to the public schema
+        #[arg(long)]
+        schema: Option<String>,
+
+        #[arg(long, default_value_t = 500)]
+        batch_size: i64,
+    },
+    /// Rewrite stored contexts in one storage format (json, msgpack or
+    /// cbor)
+    ConvertContexts {
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: Option<String>,
+
+        /// Tenant schema to process; defaults to the public schema
+        #[arg(long)]
+        schema: Option<String>,
+
+        /// Format to store the contexts in
+        #[arg(long)]
+        format: cloacina::ContextFormat,
+
+        #[arg(long, default_value_t = 500)]
+        batch_size: i64,
+    },
 }
 
 fn default_home() -> PathBuf {
@@ -267,9 +328,23 @@ async fn run() -> std::result::Result<(), CliError> {
         Commands::Execution(cmd) => return cmd.run(&cli.globals).await,
         Commands::Tenant(cmd) => return cmd.run(&cli.globals).await,
         Commands::Key(cmd) => return cmd.run(&cli.globals).await,
+        Commands::Audit(cmd) => return cmd.run(&cli.globals).await,
         Commands::Secret(cmd) => return cmd.run(&cli.globals).await,
         Commands::Trigger(cmd) => return cmd.run(&cli.globals).await,
+        Commands::Cron(cmd) => return cmd.run(&cli.globals).await,
+        Commands::Bundle(cmd) => return cmd.run(&cli.globals).await,
         Commands::Status => nouns::top_level_status(&cli.globals).await,
+        Commands::Doctor {
+            database_url,
+            schema,
+            max_clock_skew,
+            stale_after,
+        } => {
+            let db_url =
+                commands::config::resolve_database_url(database_url.as_deref(), &config_path)?;
+            let options = commands::doctor::options(schema, &max_clock_skew, &stale_after)?;
+            return commands::doctor::run(&db_url, &options, cli.globals.effective_output()).await;
+        }
 
         Commands::Config { command } => match command {
             ConfigCommands::Get { key } => commands::config::run_get(&config_path, &key),
@@ -325,12 +400,49 @@ async fn run() -> std::result::Result<(), CliError> {
                     older_than,
                     dry_run,
                 } => {
+                    let config = commands::config::CloacinaConfig::load(&config_path);
+                    if commands::cleanup_events::targets_server(
+                        &cli.globals,
+                        database_url.as_deref(),
+                        &config,
+                    ) {
+                        return commands::cleanup_events::run_remote(
+                            &cli.globals,
+                            &older_than,
+                            dry_run,
+                        )
+                        .await;
+                    }
                     let db_url = commands::config::resolve_database_url(
                         database_url.as_deref(),
                         &config_path,
                     )?;
                     commands::cleanup_events::run(&db_url, &older_than, dry_run).await
                 }
+                AdminCommands::Reencrypt {
+                    database_url,
+                    schema,
+                    batch_size,
+                } => {
+                    let db_url = commands::config::resolve_database_url(
+                        database_url.as_deref(),
+                        &config_path,
+                    )?;
+                    commands::reencrypt::run(&db_url, schema.as_deref(), batch_size).await
+                }
+                AdminCommands::ConvertContexts {
+                    database_url,
+                    schema,
+                    format,
+                    batch_size,
+                } => {
+                    let db_url = commands::config::resolve_database_url(
+                        database_url.as_deref(),
+                        &config_path,
+                    )?;
+                    commands::convert_contexts::run(&db_url, schema.as_deref(), format, batch_size)
+                        .await
+                }
             }
         }
     })
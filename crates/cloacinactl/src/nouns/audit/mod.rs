@@ -0,0 +1,105 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+use clap::{Args, Subcommand};
+
+use crate::commands::config::CloacinaConfig;
+use crate::shared::client::CliClient;
+use crate::shared::client_ctx::ClientContext;
+use crate::shared::error::CliError;
+use crate::shared::render;
+use crate::{GlobalOpts, OutputFormat};
+
+#[derive(Args)]
+pub struct AuditCmd {
+    #[command(subcommand)]
+    verb: AuditVerb,
+}
+
+#[derive(Subcommand)]
+enum AuditVerb {
+    /// List audit chain entries in sequence order.
+    List {
+        /// Only entries with a sequence greater than this.
+        #[arg(long, default_value_t = 0)]
+        after: i64,
+        #[arg(long, default_value_t = 100)]
+        limit: i64,
+    },
+    /// Verify the hash chain and its anchors. Exits non-zero when broken.
+    Verify {
+        #[arg(long)]
+        from: Option<i64>,
+        #[arg(long)]
+        to: Option<i64>,
+    },
+}
+
+impl AuditCmd {
+    pub async fn run(self, globals: &GlobalOpts) -> Result<(), CliError> {
+        let config = CloacinaConfig::load(&globals.home.join("config.toml"));
+        let ctx = ClientContext::resolve(globals, &config).map_err(CliError::Other)?;
+        let output = ctx.output;
+        let client = CliClient::new(ctx)?;
+        match self.verb {
+            AuditVerb::List { after, limit } => {
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/audit/entries?after={after}&limit={limit}"))
+                    .await?;
+                render::list(&body, output)
+            }
+            AuditVerb::Verify { from, to } => {
+                let mut path = "/v1/audit/verify".to_string();
+                let mut sep = '?';
+                for (name, value) in [("from", from), ("to", to)] {
+                    if let Some(v) = value {
+                        path.push_str(&format!("{sep}{name}={v}"));
+                        sep = '&';
+                    }
+                }
+                let body: serde_json::Value = client.get(&path).await?;
+                let valid = body.get("valid").and_then(|v| v.as_bool()) == Some(true);
+                if matches!(output, OutputFormat::Table) {
+                    let checked = body.get("entries_checked").cloned().unwrap_or_default();
+                    let anchors = body.get("anchors_checked").cloned().unwrap_or_default();
+                    if valid {
+                        println!("audit chain intact: {checked} entries, {anchors} anchors");
+                        if body.get("signatures_checked").and_then(|v| v.as_bool()) != Some(true) {
+                            println!(
+                                "NOTE: no anchor key configured; anchor signatures not checked"
+                            );
+                        }
+                    }
+                } else {
+                    render::object(&body, output)?;
+                }
+                if valid {
+                    Ok(())
+                } else {
+                    let failure = body.get("failure").cloned().unwrap_or_default();
+                    Err(CliError::UserError(format!(
+                        "audit chain broken at sequence {}: {}",
+                        failure.get("sequence").cloned().unwrap_or_default(),
+                        failure
+                            .get("reason")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("unknown")
+                    )))
+                }
+            }
+        }
+    }
+}
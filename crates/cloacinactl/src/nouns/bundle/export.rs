@@ -0,0 +1,125 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl bundle export`.
+
+use std::path::Path;
+
+use base64::Engine as _;
+use cloacina_client::types::TriggerScheduleSummary;
+use serde_json::json;
+
+use super::{Bundle, BundleSource, BundledSchedule, BundledWorkflow, BUNDLE_FORMAT_VERSION};
+use crate::shared::client::CliClient;
+use crate::shared::error::CliError;
+use crate::shared::render;
+
+/// Server-side cap on `GET /triggers` page size.
+const TRIGGER_PAGE: i64 = 1000;
+
+pub async fn run(client: &CliClient, out: &Path, only: &[String]) -> Result<(), CliError> {
+    let api = client.inner();
+
+    let mut summaries = api.list_workflows(None).await?.items;
+    if !only.is_empty() {
+        for name in only {
+            if !summaries.iter().any(|w| &w.package_name == name) {
+                return Err(CliError::NotFound {
+                    resource: "workflow".into(),
+                    key: name.clone(),
+                });
+            }
+        }
+        summaries.retain(|w| only.contains(&w.package_name));
+    }
+
+    let mut workflows = Vec::with_capacity(summaries.len());
+    for summary in summaries {
+        let versions = api
+            .list_workflow_versions(&summary.package_name, None)
+            .await?;
+        let content_hash = versions
+            .versions
+            .into_iter()
+            .find(|v| v.active)
+            .map(|v| v.content_hash);
+        let archive = api
+            .download_workflow_archive(&summary.package_name, None)
+            .await?;
+        workflows.push(BundledWorkflow {
+            package_name: summary.package_name,
+            workflow_name: summary.workflow_name,
+            version: summary.version,
+            content_hash,
+            paused: summary.paused,
+            archive: base64::engine::general_purpose::STANDARD.encode(archive),
+        });
+    }
+
+    let schedules: Vec<BundledSchedule> = list_all_triggers(client)
+        .await?
+        .into_iter()
+        .filter(|s| workflows.iter().any(|w| w.workflow_name == s.workflow_name))
+        .map(|s| BundledSchedule {
+            schedule_type: s.schedule_type,
+            workflow_name: s.workflow_name,
+            cron_expression: s.cron_expression,
+            trigger_name: s.trigger_name,
+            paused: s.paused,
+        })
+        .collect();
+
+    let bundle = Bundle {
+        format_version: BUNDLE_FORMAT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        source: BundleSource {
+            server: client.ctx().server.clone(),
+            tenant: client.ctx().tenant_segment().to_string(),
+        },
+        workflows,
+        schedules,
+    };
+    let raw = serde_json::to_vec_pretty(&bundle).map_err(|e| CliError::Other(e.into()))?;
+    std::fs::write(out, raw).map_err(CliError::Io)?;
+
+    render::object(
+        &json!({
+            "file": out.display().to_string(),
+            "workflows": bundle.workflows.len(),
+            "schedules": bundle.schedules.len(),
+        }),
+        client.ctx().output,
+    )
+}
+
+/// Every schedule in the tenant, following the server's page cap.
+pub(super) async fn list_all_triggers(
+    client: &CliClient,
+) -> Result<Vec<TriggerScheduleSummary>, CliError> {
+    let mut all = Vec::new();
+    loop {
+        let page = client
+            .inner()
+            .list_triggers(Some(TRIGGER_PAGE), Some(all.len() as i64), None)
+            .await?
+            .items;
+        let done = (page.len() as i64) < TRIGGER_PAGE;
+        all.extend(page);
+        if done {
+            return Ok(all);
+        }
+    }
+}
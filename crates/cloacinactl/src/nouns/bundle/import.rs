@@ -0,0 +1,130 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl bundle import`.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use cloacina_client::ClientError;
+use serde_json::{json, Value};
+
+use super::export::list_all_triggers;
+use super::read_bundle;
+use crate::shared::client::CliClient;
+use crate::shared::error::CliError;
+use crate::shared::render;
+
+pub async fn run(client: &CliClient, file: &Path, dry_run: bool) -> Result<(), CliError> {
+    let bundle = read_bundle(file)?;
+    let api = client.inner();
+    let mut rows: Vec<Value> = Vec::new();
+
+    // 1. Packages. Uploading bytes identical to the active version is a no-op
+    //    on the server, so "unchanged" is decided client-side only to keep the
+    //    report (and dry runs) accurate.
+    for wf in &bundle.workflows {
+        let archive = wf.archive_bytes()?;
+        let active_hash = match api.list_workflow_versions(&wf.package_name, None).await {
+            Ok(resp) => resp
+                .versions
+                .into_iter()
+                .find(|v| v.active)
+                .map(|v| v.content_hash),
+            Err(ClientError::NotFound(_)) => None,
+            Err(e) => return Err(e.into()),
+        };
+        let unchanged = active_hash.is_some() && active_hash == wf.content_hash;
+        let action = if unchanged {
+            "unchanged"
+        } else if dry_run {
+            "would upload"
+        } else {
+            api.upload_workflow(archive, None).await?;
+            "uploaded"
+        };
+        rows.push(row("workflow", &wf.package_name, &wf.version, action));
+    }
+
+    // 2. Workflow pause state.
+    let current: HashMap<String, bool> = api
+        .list_workflows(None)
+        .await?
+        .items
+        .into_iter()
+        .map(|w| (w.package_name, w.paused))
+        .collect();
+    for wf in &bundle.workflows {
+        let Some(&paused) = current.get(&wf.package_name) else {
+            // Only reachable on a dry run: the package isn't uploaded yet.
+            continue;
+        };
+        if paused == wf.paused {
+            continue;
+        }
+        let action = match (wf.paused, dry_run) {
+            (true, true) => "would pause",
+            (false, true) => "would resume",
+            (true, false) => {
+                api.pause_workflow(&wf.package_name, None).await?;
+                "paused"
+            }
+            (false, false) => {
+                api.resume_workflow(&wf.package_name, None).await?;
+                "resumed"
+            }
+        };
+        rows.push(row("workflow", &wf.package_name, &wf.version, action));
+    }
+
+    // 3. Schedule pause state. Schedules appear once the target has built the
+    //    package; any not there yet are reported as pending so the import can
+    //    simply be re-run.
+    let triggers = list_all_triggers(client).await?;
+    for sched in &bundle.schedules {
+        let target = triggers.iter().find(|t| {
+            t.schedule_type == sched.schedule_type
+                && t.trigger_name.as_deref().unwrap_or(&t.workflow_name) == sched.key()
+        });
+        let detail = sched
+            .cron_expression
+            .as_deref()
+            .unwrap_or(&sched.schedule_type);
+        let action = match target {
+            None => "pending",
+            Some(t) if t.paused == sched.paused => "unchanged",
+            Some(_) => match (sched.paused, dry_run) {
+                (true, true) => "would pause",
+                (false, true) => "would resume",
+                (true, false) => {
+                    api.pause_trigger(sched.key(), None).await?;
+                    "paused"
+                }
+                (false, false) => {
+                    api.resume_trigger(sched.key(), None).await?;
+                    "resumed"
+                }
+            },
+        };
+        rows.push(row("schedule", sched.key(), detail, action));
+    }
+
+    render::list(&json!({ "items": rows }), client.ctx().output)
+}
+
+fn row(kind: &str, name: &str, detail: &str, action: &str) -> Value {
+    json!({ "kind": kind, "name": name, "detail": detail, "action": action })
+}
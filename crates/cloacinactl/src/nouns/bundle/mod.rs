@@ -0,0 +1,216 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl bundle <verb>` — export a tenant's workflow packages, their
+//! pinned versions, and schedule state to a single file, and apply it to
+//! another server or tenant (promote staging → production).
+//!
+//! A bundle is a versioned JSON document. Packages travel as the exact
+//! `.cloacina` bytes that were uploaded (base64), so importing one re-uploads
+//! identical content — the registry treats a same-hash upload as a no-op,
+//! which makes re-importing a bundle idempotent. Cron and trigger schedules
+//! are derived from the packages themselves; the bundle carries their pause
+//! state so it can be reapplied once the target has built them.
+
+use std::path::PathBuf;
+
+use base64::Engine as _;
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::config::CloacinaConfig;
+use crate::shared::client::CliClient;
+use crate::shared::client_ctx::ClientContext;
+use crate::shared::error::CliError;
+use crate::GlobalOpts;
+
+pub mod export;
+pub mod import;
+
+/// Bundle format understood by this build. Bumped on incompatible changes;
+/// `bundle import` refuses bundles newer than this.
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+#[derive(Args)]
+pub struct BundleCmd {
+    #[command(subcommand)]
+    verb: BundleVerb,
+}
+
+#[derive(Subcommand)]
+enum BundleVerb {
+    /// Capture workflow packages, version pins, and schedule state.
+    Export {
+        /// Output file.
+        #[arg(long, default_value = "cloacina-bundle.json")]
+        out: PathBuf,
+        /// Export only these packages (repeatable). Default: all.
+        #[arg(long = "workflow")]
+        workflows: Vec<String>,
+    },
+    /// Apply a bundle to the targeted server/tenant.
+    Import {
+        file: PathBuf,
+        /// Report what would change without uploading or pausing anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+impl BundleCmd {
+    pub async fn run(self, globals: &GlobalOpts) -> Result<(), CliError> {
+        let config = CloacinaConfig::load(&globals.home.join("config.toml"));
+        let ctx = ClientContext::resolve(globals, &config).map_err(CliError::Other)?;
+        let client = CliClient::new(ctx)?;
+        match self.verb {
+            BundleVerb::Export { out, workflows } => export::run(&client, &out, &workflows).await,
+            BundleVerb::Import { file, dry_run } => import::run(&client, &file, dry_run).await,
+        }
+    }
+}
+
+/// The on-disk bundle document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub format_version: u32,
+    /// RFC 3339 timestamp of the export.
+    pub exported_at: String,
+    pub source: BundleSource,
+    pub workflows: Vec<BundledWorkflow>,
+    #[serde(default)]
+    pub schedules: Vec<BundledSchedule>,
+}
+
+/// Where a bundle was exported from (informational).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleSource {
+    pub server: String,
+    pub tenant: String,
+}
+
+/// One workflow package, pinned to the version active at export time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledWorkflow {
+    pub package_name: String,
+    pub workflow_name: String,
+    pub version: String,
+    /// SHA-256 of `archive`, as reported by the source registry.
+    pub content_hash: Option<String>,
+    #[serde(default)]
+    pub paused: bool,
+    /// The `.cloacina` archive, base64-encoded.
+    pub archive: String,
+}
+
+impl BundledWorkflow {
+    pub fn archive_bytes(&self) -> Result<Vec<u8>, CliError> {
+        base64::engine::general_purpose::STANDARD
+            .decode(&self.archive)
+            .map_err(|e| {
+                CliError::UserError(format!(
+                    "bundle archive for '{}' is not valid base64: {e}",
+                    self.package_name
+                ))
+            })
+    }
+}
+
+/// Pause state of one cron or trigger schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledSchedule {
+    /// `cron` or `trigger`.
+    pub schedule_type: String,
+    pub workflow_name: String,
+    #[serde(default)]
+    pub cron_expression: Option<String>,
+    #[serde(default)]
+    pub trigger_name: Option<String>,
+    #[serde(default)]
+    pub paused: bool,
+}
+
+impl BundledSchedule {
+    /// The name the trigger pause/resume routes resolve: the trigger name for
+    /// trigger schedules, the workflow name for cron schedules.
+    pub fn key(&self) -> &str {
+        self.trigger_name.as_deref().unwrap_or(&self.workflow_name)
+    }
+}
+
+/// Read and version-check a bundle file.
+pub fn read_bundle(path: &std::path::Path) -> Result<Bundle, CliError> {
+    let raw = std::fs::read_to_string(path).map_err(CliError::Io)?;
+    parse_bundle(&raw)
+}
+
+fn parse_bundle(raw: &str) -> Result<Bundle, CliError> {
+    let bundle: Bundle = serde_json::from_str(raw)
+        .map_err(|e| CliError::UserError(format!("not a cloacina bundle: {e}")))?;
+    if bundle.format_version == 0 || bundle.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(CliError::UserError(format!(
+            "unsupported bundle format version {} (this cloacinactl reads up to {})",
+            bundle.format_version, BUNDLE_FORMAT_VERSION
+        )));
+    }
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Bundle {
+        Bundle {
+            format_version: BUNDLE_FORMAT_VERSION,
+            exported_at: "2026-10-18T00:00:00+00:00".into(),
+            source: BundleSource {
+                server: "https://staging.example.com".into(),
+                tenant: "public".into(),
+            },
+            workflows: vec![BundledWorkflow {
+                package_name: "etl".into(),
+                workflow_name: "etl_workflow".into(),
+                version: "1.2.0".into(),
+                content_hash: Some("abc".into()),
+                paused: false,
+                archive: base64::engine::general_purpose::STANDARD.encode(b"archive-bytes"),
+            }],
+            schedules: vec![BundledSchedule {
+                schedule_type: "cron".into(),
+                workflow_name: "etl_workflow".into(),
+                cron_expression: Some("0 * * * *".into()),
+                trigger_name: None,
+                paused: true,
+            }],
+        }
+    }
+
+    #[test]
+    fn bundle_round_trips() {
+        let raw = serde_json::to_string(&sample()).unwrap();
+        let back = parse_bundle(&raw).unwrap();
+        assert_eq!(back.workflows[0].archive_bytes().unwrap(), b"archive-bytes");
+        assert_eq!(back.schedules[0].key(), "etl_workflow");
+    }
+
+    #[test]
+    fn newer_format_is_rejected() {
+        let mut bundle = sample();
+        bundle.format_version = BUNDLE_FORMAT_VERSION + 1;
+        let raw = serde_json::to_string(&bundle).unwrap();
+        assert!(matches!(parse_bundle(&raw), Err(CliError::UserError(_))));
+    }
+}
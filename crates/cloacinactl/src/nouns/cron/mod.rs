@@ -0,0 +1,176 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl cron` — offline cron expression tools. Runs the same
+//! evaluator schedule registration validates with; no server or database.
+
+use chrono::{DateTime, Utc};
+use clap::{Args, Subcommand};
+use serde_json::json;
+
+use cloacina::cron_evaluator::{
+    normalize_cron_expression, simulate_cron, CronEvaluator, CronFireTime, DstEdge, DstPolicy,
+};
+
+use crate::shared::error::CliError;
+use crate::shared::render;
+use crate::{GlobalOpts, OutputFormat};
+
+#[derive(Args)]
+pub struct CronCmd {
+    #[command(subcommand)]
+    verb: CronVerb,
+}
+
+#[derive(Subcommand)]
+enum CronVerb {
+    /// Validate a cron expression (or preset/phrase) and print its upcoming
+    /// fire times, flagging daylight-saving edges.
+    Simulate {
+        /// Cron expression, preset (`@daily`), or phrase (`every weekday at 9am`).
+        expression: String,
+        /// IANA timezone the expression is evaluated in.
+        #[arg(long, default_value = "UTC")]
+        tz: String,
+        /// Number of fire times to print.
+        #[arg(long, default_value_t = 10)]
+        count: usize,
+        /// Simulate from this RFC 3339 instant instead of now.
+        #[arg(long)]
+        from: Option<String>,
+        /// Daylight-saving policy: `skip`, `run_once` or `run_twice`.
+        #[arg(long, default_value = "run_once")]
+        dst_policy: String,
+    },
+}
+
+impl CronCmd {
+    pub async fn run(self, globals: &GlobalOpts) -> Result<(), CliError> {
+        match self.verb {
+            CronVerb::Simulate {
+                expression,
+                tz,
+                count,
+                from,
+                dst_policy,
+            } => simulate(
+                &expression,
+                &tz,
+                count,
+                from.as_deref(),
+                &dst_policy,
+                globals.effective_output(),
+            ),
+        }
+    }
+}
+
+fn simulate(
+    expression: &str,
+    tz: &str,
+    count: usize,
+    from: Option<&str>,
+    dst_policy: &str,
+    output: OutputFormat,
+) -> Result<(), CliError> {
+    let policy: DstPolicy = dst_policy
+        .parse()
+        .map_err(|e| CliError::UserError(format!("invalid --dst-policy: {e}")))?;
+    let after = match from {
+        Some(from) => DateTime::parse_from_rfc3339(from)
+            .map_err(|e| CliError::UserError(format!("invalid --from '{from}': {e}")))?
+            .with_timezone(&Utc),
+        None => Utc::now(),
+    };
+    let cron = normalize_cron_expression(expression)
+        .map_err(|e| CliError::UserError(format!("invalid schedule '{expression}': {e}")))?;
+    let invalid = |e| CliError::UserError(format!("invalid schedule '{expression}': {e}"));
+    // Validate as registration does, then simulate under the policy.
+    simulate_cron(&cron, tz, after, 1).map_err(invalid)?;
+    let fires = CronEvaluator::new(&cron, tz)
+        .and_then(|evaluator| evaluator.with_dst_policy(policy).simulate(after, count))
+        .map_err(invalid)?;
+
+    if matches!(output, OutputFormat::Table) {
+        println!("{cron} ({tz}, dst {policy})");
+    }
+    let items: Vec<serde_json::Value> = fires.iter().map(|fire| row(fire, policy)).collect();
+    render::list(&json!({ "items": items }), output)
+}
+
+fn row(fire: &CronFireTime, policy: DstPolicy) -> serde_json::Value {
+    json!({
+        "utc": fire.at.to_rfc3339(),
+        "local": fire.local.format("%Y-%m-%d %H:%M:%S %:z").to_string(),
+        "dst": describe_edges(&fire.dst_edges, policy),
+    })
+}
+
+fn describe_edges(edges: &[DstEdge], policy: DstPolicy) -> String {
+    edges
+        .iter()
+        .map(|edge| match edge {
+            DstEdge::Shifted => "wall time skipped; fires after the gap".to_string(),
+            DstEdge::Ambiguous if policy == DstPolicy::RunTwice => {
+                "wall time repeats; fires at both instants".to_string()
+            }
+            DstEdge::Ambiguous => "wall time repeats; fires once".to_string(),
+            DstEdge::OffsetChanged {
+                from_offset_seconds,
+                to_offset_seconds,
+            } => format!(
+                "offset {} -> {}",
+                format_offset(*from_offset_seconds),
+                format_offset(*to_offset_seconds)
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+    format!("{sign}{:02}:{:02}", seconds / 3600, seconds % 3600 / 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_dst_edges() {
+        assert_eq!(describe_edges(&[], DstPolicy::RunOnce), "");
+        assert_eq!(
+            describe_edges(
+                &[
+                    DstEdge::Shifted,
+                    DstEdge::OffsetChanged {
+                        from_offset_seconds: 3600,
+                        to_offset_seconds: 7200,
+                    },
+                ],
+                DstPolicy::RunOnce
+            ),
+            "wall time skipped; fires after the gap; offset +01:00 -> +02:00"
+        );
+        assert_eq!(
+            describe_edges(&[DstEdge::Ambiguous], DstPolicy::RunTwice),
+            "wall time repeats; fires at both instants"
+        );
+        assert_eq!(format_offset(-16200), "-04:30");
+    }
+}
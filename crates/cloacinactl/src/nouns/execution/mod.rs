@@ -40,6 +40,13 @@ enum ExecutionVerb {
         workflow: Option<String>,
         #[arg(long)]
         status: Option<String>,
+        /// Only executions carrying this label, as `key=value`. Repeatable;
+        /// every label must match.
+        #[arg(long = "label")]
+        labels: Vec<String>,
+        /// Only the execution(s) with this run id.
+        #[arg(long)]
+        run_id: Option<String>,
         /// Maximum number of rows to return (server-side cap: 1000).
         /// CLOACI-T-0596 / API-10.
         #[arg(long, default_value = "50")]
@@ -47,9 +54,70 @@ enum ExecutionVerb {
         /// Offset into the result set for pagination.
         #[arg(long, default_value = "0")]
         offset: u32,
+        /// List executions from every region of the server's federation,
+        /// tagged by region. `--limit` and `--offset` apply per region.
+        #[arg(long)]
+        all_regions: bool,
     },
     /// Current state of a single execution.
     Status { id: String },
+    /// Full detail of an execution: every task with its attempts, timings,
+    /// errors, retries and trigger outcome.
+    Show { id: String },
+    /// Attach an operator note to an execution.
+    Annotate {
+        id: String,
+        /// Free-text note (e.g. "known outage, reran manually at 14:00").
+        note: String,
+        /// Resolution status: investigating, resolved, rerun, or ignored.
+        #[arg(long)]
+        resolution: Option<String>,
+    },
+    /// Operator notes on an execution, oldest first.
+    Annotations { id: String },
+    /// Mark a failed task successful without re-running it. Skipped
+    /// downstream tasks run and the note is kept as a `resolved` annotation.
+    Resolve {
+        id: String,
+        /// Task name, local or fully qualified.
+        task: String,
+        /// Why the failure can be treated as a success.
+        note: String,
+    },
+    /// Context keys each task read and wrote, with missing or unused
+    /// dependencies they imply. Needs a server run with
+    /// `--trace-context-access`.
+    ContextAccess { id: String },
+    /// Which task changed each context key, with previous and new values.
+    /// Needs a server run with `--track-context-lineage`.
+    ContextLineage { id: String },
+    /// Files the execution's tasks stored.
+    Artifacts { id: String },
+    /// Download one artifact of an execution.
+    Artifact {
+        id: String,
+        /// Artifact name, as listed by `execution artifacts`.
+        name: String,
+        /// Write the artifact to this file (default: its name, in the
+        /// current directory).
+        #[arg(long, short = 'o')]
+        out: Option<std::path::PathBuf>,
+    },
+    /// Replayable snapshot of an execution (initial context plus every
+    /// task's output context), for stepping through locally with
+    /// `cloacina::replay::ReplayRunner`.
+    Recording {
+        id: String,
+        /// Write the recording to this file instead of stdout.
+        #[arg(long, short = 'o')]
+        out: Option<std::path::PathBuf>,
+    },
+    /// Claim queue depth, time-to-dispatch and scheduler loop latency.
+    Queue {
+        /// Window for dispatch latency, in seconds (server default: 3600).
+        #[arg(long)]
+        window_secs: Option<u64>,
+    },
     /// Event trail for an execution.
     Events {
         id: String,
@@ -73,8 +141,11 @@ impl ExecutionCmd {
             ExecutionVerb::List {
                 workflow,
                 status,
+                labels,
+                run_id,
                 limit,
                 offset,
+                all_regions,
             } => {
                 let mut query = format!("?limit={limit}&offset={offset}");
                 if let Some(w) = workflow {
@@ -83,9 +154,32 @@ impl ExecutionCmd {
                 if let Some(s) = status {
                     query.push_str(&format!("&status={s}"));
                 }
+                if !labels.is_empty() {
+                    let selector = labels.join(",");
+                    query.push_str(&format!("&label={}", urlencoding::encode(&selector)));
+                }
+                if let Some(r) = run_id {
+                    query.push_str(&format!("&run_id={}", urlencoding::encode(&r)));
+                }
+                let route = if all_regions {
+                    "federation/executions"
+                } else {
+                    "executions"
+                };
                 let body: serde_json::Value = client
-                    .get(&format!("/v1/tenants/{tenant}/executions{query}"))
+                    .get(&format!("/v1/tenants/{tenant}/{route}{query}"))
                     .await?;
+                // A federated list is partial when a peer is down; say so
+                // without polluting the listed rows.
+                if let Some(down) = body.get("unreachable").and_then(|u| u.as_array()) {
+                    for region in down {
+                        eprintln!(
+                            "warning: region {} unreachable: {}",
+                            region["region"].as_str().unwrap_or("?"),
+                            region["error"].as_str().unwrap_or("unknown error")
+                        );
+                    }
+                }
                 render::list(&body, output)
             }
             ExecutionVerb::Status { id } => {
@@ -94,6 +188,159 @@ impl ExecutionCmd {
                     .await?;
                 render::object(&body, output)
             }
+            ExecutionVerb::Show { id } => {
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/tenants/{tenant}/executions/{id}/tree"))
+                    .await?;
+                if !matches!(output, OutputFormat::Table) {
+                    return render::object(&body, output);
+                }
+                let mut header = body.clone();
+                if let Some(map) = header.as_object_mut() {
+                    map.remove("tasks");
+                }
+                render::object(&header, output)?;
+                println!();
+                render::list(&attempt_rows(&body), output)
+            }
+            ExecutionVerb::Annotate {
+                id,
+                note,
+                resolution,
+            } => {
+                let body = serde_json::json!({
+                    "note": note,
+                    "resolution": resolution,
+                });
+                let resp: serde_json::Value = client
+                    .post(
+                        &format!("/v1/tenants/{tenant}/executions/{id}/annotations"),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            ExecutionVerb::Annotations { id } => {
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/tenants/{tenant}/executions/{id}/annotations"))
+                    .await?;
+                let annotations = body
+                    .get("annotations")
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Array(Vec::new()));
+                render::list(&annotations, output)
+            }
+            ExecutionVerb::Resolve { id, task, note } => {
+                let body = serde_json::json!({ "note": note });
+                let resp: serde_json::Value = client
+                    .post(
+                        &format!(
+                            "/v1/tenants/{tenant}/executions/{id}/tasks/{}/resolve",
+                            urlencoding::encode(&task)
+                        ),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            ExecutionVerb::ContextAccess { id } => {
+                let body: serde_json::Value = client
+                    .get(&format!(
+                        "/v1/tenants/{tenant}/executions/{id}/context-access"
+                    ))
+                    .await?;
+                if !matches!(output, OutputFormat::Table) {
+                    return render::object(&body, output);
+                }
+                let empty = serde_json::Value::Array(Vec::new());
+                render::list(body.get("tasks").unwrap_or(&empty), output)?;
+                println!();
+                render::list(body.get("findings").unwrap_or(&empty), output)
+            }
+            ExecutionVerb::ContextLineage { id } => {
+                let body: serde_json::Value = client
+                    .get(&format!(
+                        "/v1/tenants/{tenant}/executions/{id}/context-lineage"
+                    ))
+                    .await?;
+                if !matches!(output, OutputFormat::Table) {
+                    return render::object(&body, output);
+                }
+                // One row per write, grouped by key.
+                let rows: Vec<serde_json::Value> = body
+                    .get("keys")
+                    .and_then(|keys| keys.as_object())
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|(key, writes)| {
+                        writes.as_array().into_iter().flatten().map(move |w| {
+                            serde_json::json!({
+                                "key": key,
+                                "task_name": w["task_name"],
+                                "attempt": w["attempt"],
+                                "kind": w["kind"],
+                                "previous": w.get("previous").cloned().unwrap_or_default(),
+                                "value": w.get("value").cloned().unwrap_or_default(),
+                            })
+                        })
+                    })
+                    .collect();
+                render::list(&serde_json::Value::Array(rows), output)
+            }
+            ExecutionVerb::Artifacts { id } => {
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/tenants/{tenant}/executions/{id}/artifacts"))
+                    .await?;
+                let artifacts = body
+                    .get("artifacts")
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Array(Vec::new()));
+                render::list(&artifacts, output)
+            }
+            ExecutionVerb::Artifact { id, name, out } => {
+                let bytes = client
+                    .inner()
+                    .download_execution_artifact(&id, &name, Some(&tenant))
+                    .await?;
+                let path = out.unwrap_or_else(|| std::path::PathBuf::from(&name));
+                std::fs::write(&path, &bytes)
+                    .map_err(|e| CliError::UserError(format!("write {}: {e}", path.display())))?;
+                eprintln!(
+                    "wrote {} bytes of {name} to {}",
+                    bytes.len(),
+                    path.display()
+                );
+                Ok(())
+            }
+            ExecutionVerb::Recording { id, out } => {
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/tenants/{tenant}/executions/{id}/recording"))
+                    .await?;
+                match out {
+                    Some(path) => {
+                        std::fs::write(
+                            &path,
+                            serde_json::to_string_pretty(&body)
+                                .map_err(|e| CliError::UserError(format!("serialize: {e}")))?,
+                        )
+                        .map_err(|e| {
+                            CliError::UserError(format!("write {}: {e}", path.display()))
+                        })?;
+                        eprintln!("wrote recording of {id} to {}", path.display());
+                        Ok(())
+                    }
+                    None => render::object(&body, output),
+                }
+            }
+            ExecutionVerb::Queue { window_secs } => {
+                let query = window_secs
+                    .map(|w| format!("?window_secs={w}"))
+                    .unwrap_or_default();
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/tenants/{tenant}/stats/queue{query}"))
+                    .await?;
+                render::object(&body, output)
+            }
             ExecutionVerb::Events { id, follow, since } => {
                 if follow {
                     // CLOACI-T-0629: live event streaming over the interservice
@@ -122,6 +369,46 @@ impl ExecutionCmd {
     }
 }
 
+/// One table row per task attempt of an execution tree; tasks that never
+/// ran get a single row with an empty attempt.
+fn attempt_rows(tree: &serde_json::Value) -> serde_json::Value {
+    let str_of = |v: &serde_json::Value, key: &str| v[key].as_str().unwrap_or("").to_string();
+    let mut rows = Vec::new();
+    for task in tree["tasks"].as_array().into_iter().flatten() {
+        let attempts = task["attempts"].as_array().cloned().unwrap_or_default();
+        if attempts.is_empty() {
+            let note = match task["trigger_evaluation"]["reason"].as_str() {
+                Some(reason) => format!("skipped: {reason}"),
+                None => str_of(task, "last_error"),
+            };
+            rows.push(serde_json::json!({
+                "task": str_of(task, "task_name"),
+                "attempt": "",
+                "outcome": str_of(task, "status"),
+                "started": "",
+                "duration": "",
+                "error": note,
+            }));
+            continue;
+        }
+        for attempt in attempts {
+            let duration = attempt["duration_ms"]
+                .as_i64()
+                .map(|ms| format!("{}ms", ms))
+                .unwrap_or_default();
+            rows.push(serde_json::json!({
+                "task": str_of(task, "task_name"),
+                "attempt": format!("{}/{}", attempt["attempt"], task["max_attempts"]),
+                "outcome": str_of(&attempt, "outcome"),
+                "started": str_of(&attempt, "started_at"),
+                "duration": duration,
+                "error": str_of(&attempt, "error"),
+            }));
+        }
+    }
+    serde_json::Value::Array(rows)
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // CLOACI-T-0629: --follow over the interservice communication substrate.
 // ────────────────────────────────────────────────────────────────────────────
@@ -152,3 +439,40 @@ async fn follow_execution_events(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attempt_rows_cover_retries_and_skips() {
+        let tree = serde_json::json!({
+            "tasks": [
+                {
+                    "task_name": "etl::load",
+                    "status": "Completed",
+                    "max_attempts": 3,
+                    "attempts": [
+                        {"attempt": 1, "outcome": "failed", "started_at": "t0", "duration_ms": 40, "error": "timeout"},
+                        {"attempt": 2, "outcome": "completed", "started_at": "t1", "duration_ms": 12, "error": null}
+                    ]
+                },
+                {
+                    "task_name": "etl::notify",
+                    "status": "Skipped",
+                    "max_attempts": 1,
+                    "attempts": [],
+                    "trigger_evaluation": {"satisfied": false, "reason": "upstream failed"}
+                }
+            ]
+        });
+        let rows = attempt_rows(&tree);
+        let rows = rows.as_array().unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0]["attempt"], "1/3");
+        assert_eq!(rows[0]["duration"], "40ms");
+        assert_eq!(rows[0]["error"], "timeout");
+        assert_eq!(rows[1]["outcome"], "completed");
+        assert_eq!(rows[2]["error"], "skipped: upstream failed");
+    }
+}
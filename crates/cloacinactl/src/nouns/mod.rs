@@ -22,8 +22,11 @@ use anyhow::Result;
 use crate::GlobalOpts;
 
 pub mod accumulator;
+pub mod audit;
+pub mod bundle;
 pub mod compiler;
 pub mod constructor;
+pub mod cron;
 pub mod daemon;
 pub mod execution;
 pub mod graph;
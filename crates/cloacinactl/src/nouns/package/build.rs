@@ -20,7 +20,7 @@ use std::process::Command;
 use super::manifest::{self, PackageLanguage};
 use crate::shared::error::CliError;
 
-pub fn run(dir: &Path, release: bool) -> Result<(), CliError> {
+pub fn run(dir: &Path, release: bool, targets: &[String]) -> Result<(), CliError> {
     // CLOACI-T-0665: Python packages have nothing to compile — `pack` archives
     // the source tree directly. Detect the language from `[metadata].language`
     // and no-op for Python so `package build` (and `publish`, which calls this)
@@ -45,6 +45,9 @@ pub fn run(dir: &Path, release: bool) -> Result<(), CliError> {
     if release {
         cmd.arg("--release");
     }
+    for target in targets {
+        cmd.arg("--target").arg(target);
+    }
 
     let status = cmd
         .status()
@@ -57,6 +60,15 @@ pub fn run(dir: &Path, release: bool) -> Result<(), CliError> {
     }
 
     let profile = if release { "release" } else { "debug" };
-    println!("built {} in {} profile", dir.display(), profile);
+    if targets.is_empty() {
+        println!("built {} in {} profile", dir.display(), profile);
+    } else {
+        println!(
+            "built {} in {} profile for {}",
+            dir.display(),
+            profile,
+            targets.join(", ")
+        );
+    }
     Ok(())
 }
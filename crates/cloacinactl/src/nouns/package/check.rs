@@ -0,0 +1,111 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl package check <path>` — load a package in dry-run mode.
+//!
+//! Runs [`cloacina::packaging::dry_run_package`] locally: the cdylib is
+//! loaded, its tasks registered into a throwaway runtime and the workflow
+//! built, without running any task. Exits non-zero when a step fails.
+
+use std::path::{Path, PathBuf};
+
+use cloacina::doctor::CheckStatus;
+use cloacina::packaging::{dry_run_package, DryRunOptions, DryRunReport};
+
+use crate::shared::error::CliError;
+use crate::OutputFormat;
+
+pub async fn run(
+    path: &Path,
+    library: Option<PathBuf>,
+    tenant: &str,
+    output: OutputFormat,
+) -> Result<(), CliError> {
+    if !path.exists() {
+        return Err(CliError::UserError(format!(
+            "{} does not exist",
+            path.display()
+        )));
+    }
+    let options = DryRunOptions {
+        library,
+        tenant_id: tenant.to_string(),
+    };
+    let report = dry_run_package(path, &options).await;
+    print_report(&report, output)?;
+
+    if !report.passed() {
+        return Err(CliError::UserError(format!(
+            "package check: {} failed",
+            report
+                .checks
+                .iter()
+                .filter(|c| c.status == CheckStatus::Fail)
+                .map(|c| c.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    Ok(())
+}
+
+fn print_report(report: &DryRunReport, output: OutputFormat) -> Result<(), CliError> {
+    match output {
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(report).map_err(|e| CliError::UserError(e.to_string()))?
+        ),
+        OutputFormat::Yaml => print!(
+            "{}",
+            serde_yaml::to_string(report).map_err(|e| CliError::UserError(e.to_string()))?
+        ),
+        // The registered task namespaces, one per line.
+        OutputFormat::Id => {
+            for task in &report.tasks {
+                println!("{}", task.namespace);
+            }
+        }
+        OutputFormat::Table => {
+            for check in &report.checks {
+                println!(
+                    "[{}] {:<18} {}",
+                    label(check.status),
+                    check.name,
+                    check.detail
+                );
+                if let Some(fix) = &check.fix {
+                    println!("       {:<18} fix: {}", "", fix);
+                }
+            }
+            for (i, level) in report.execution_levels.iter().enumerate() {
+                if i == 0 {
+                    println!();
+                }
+                println!("level {}: {}", i, level.join(", "));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn label(status: CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Ok => " ok ",
+        CheckStatus::Skip => "skip",
+        CheckStatus::Warn => "warn",
+        CheckStatus::Fail => "FAIL",
+    }
+}
@@ -63,6 +63,24 @@ pub async fn run(globals: &GlobalOpts, id: &str) -> Result<(), CliError> {
             if let Some(err) = body.get("build_error").and_then(|v| v.as_str()) {
                 println!("Build error:  {}", err);
             }
+            match body.get("provenance").filter(|p| !p.is_null()) {
+                Some(p) => {
+                    let dirty = p.get("git_dirty").and_then(|v| v.as_bool()) == Some(true);
+                    println!(
+                        "Commit:       {}{}",
+                        json_str(p, "git_commit"),
+                        if dirty { " (dirty)" } else { "" }
+                    );
+                    println!("Packed:       {}", json_str(p, "built_at"));
+                    println!("Rustc:        {}", json_str(p, "rustc_version"));
+                    println!(
+                        "Lockfile:     {} {}",
+                        json_str(p, "lockfile"),
+                        json_str(p, "lockfile_sha256")
+                    );
+                }
+                None => println!("Provenance:   none recorded"),
+            }
         }
     }
     Ok(())
@@ -377,6 +377,8 @@ mod tests {
             input_strategy: None,
             accumulators: Vec::new(),
             providers: Default::default(),
+            capabilities: None,
+            config_schema: None,
         }
     }
 
@@ -460,6 +462,8 @@ mod tests {
             input_strategy: None,
             accumulators: Vec::new(),
             providers: Default::default(),
+            capabilities: None,
+            config_schema: None,
         }
     }
 
@@ -14,8 +14,8 @@
  *  limitations under the License.
  */
 
-//! `cloacinactl package <verb>` — build / pack / publish / upload / list /
-//! inspect / delete.
+//! `cloacinactl package <verb>` — build / check / pack / publish / upload /
+//! list / inspect / versions / rollback / delete.
 
 use clap::{Args, Subcommand};
 use std::path::PathBuf;
@@ -24,6 +24,7 @@ use crate::shared::error::CliError;
 use crate::GlobalOpts;
 
 pub mod build;
+pub mod check;
 pub mod delete;
 pub mod inspect;
 pub mod list;
@@ -31,6 +32,8 @@ pub mod manifest;
 pub mod new;
 pub mod pack;
 pub mod publish;
+pub mod rollback;
+pub mod template;
 pub mod upload;
 pub mod validate;
 
@@ -52,6 +55,10 @@ enum PackageVerb {
         /// Package shape: workflow (default), graph, or cron (cron is Rust-only).
         #[arg(long, value_enum, default_value_t = new::ScaffoldKind::Workflow)]
         kind: new::ScaffoldKind,
+        /// Scaffold a Rust project from a built-in template (workflow,
+        /// trigger and tests) instead of a minimal `--kind` package.
+        #[arg(long, value_enum, conflicts_with_all = ["lang", "kind"])]
+        template: Option<template::ScaffoldTemplate>,
         /// Directory to create (default: ./<name>).
         #[arg(long)]
         path: Option<PathBuf>,
@@ -62,6 +69,10 @@ enum PackageVerb {
         /// Build in release profile (default is debug).
         #[arg(long)]
         release: bool,
+        /// Cross-compile for this cargo target triple (repeatable). The
+        /// outputs land under `target/<triple>/` for `pack --binary`.
+        #[arg(long = "target")]
+        targets: Vec<String>,
     },
     /// Validate a package (source dir or .cloacina archive) against the
     /// canonical format without uploading.
@@ -69,6 +80,16 @@ enum PackageVerb {
         /// Package source directory or .cloacina archive.
         path: PathBuf,
     },
+    /// Dry-run a package (.cloacina archive or source dir): load its cdylib,
+    /// register its tasks and build the workflow without running any task.
+    Check {
+        /// Package source directory or .cloacina archive.
+        path: PathBuf,
+        /// Compiled cdylib to load instead of the binary the archive bundles
+        /// for this host.
+        #[arg(long)]
+        library: Option<PathBuf>,
+    },
     /// fidius-pack the source directory into a .cloacina archive.
     Pack {
         dir: PathBuf,
@@ -78,6 +99,11 @@ enum PackageVerb {
         /// Sign the archive with this Ed25519 key file.
         #[arg(long)]
         sign: Option<PathBuf>,
+        /// Bundle a prebuilt cdylib for a target, as `<triple>=<path>`
+        /// (repeatable). Rust packages only; see `targets/` in the package
+        /// format.
+        #[arg(long = "binary", value_parser = pack::parse_binary_spec)]
+        binaries: Vec<(String, PathBuf)>,
     },
     /// build + pack + upload in one shot.
     Publish {
@@ -88,7 +114,13 @@ enum PackageVerb {
         sign: Option<PathBuf>,
     },
     /// Upload a pre-packed .cloacina archive.
-    Upload { file: PathBuf },
+    Upload {
+        file: PathBuf,
+        /// JSON file of configuration values, for a package that declares a
+        /// `config_schema`.
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
     /// List installed packages.
     List {
         #[arg(long)]
@@ -96,6 +128,10 @@ enum PackageVerb {
     },
     /// Fetch metadata for a single package.
     Inspect { id: String },
+    /// List every registered version of a package, newest first.
+    Versions { name: String },
+    /// Re-activate the version registered before the active one.
+    Rollback { name: String },
     /// Uninstall a package.
     Delete {
         id: String,
@@ -111,19 +147,35 @@ impl PackageCmd {
                 name,
                 lang,
                 kind,
+                template,
                 path,
-            } => new::run(&name, lang, kind, path.as_deref()),
+            } => new::run(&name, lang, kind, template, path.as_deref()),
             PackageVerb::Validate { path } => validate::run(&path),
-            PackageVerb::Build { dir, release } => build::run(&dir, release),
-            PackageVerb::Pack { dir, out, sign } => {
-                pack::run(&dir, out.as_deref(), sign.as_deref())
+            PackageVerb::Check { path, library } => {
+                let tenant = globals.tenant.as_deref().unwrap_or("public");
+                check::run(&path, library, tenant, globals.effective_output()).await
             }
+            PackageVerb::Build {
+                dir,
+                release,
+                targets,
+            } => build::run(&dir, release, &targets),
+            PackageVerb::Pack {
+                dir,
+                out,
+                sign,
+                binaries,
+            } => pack::run(&dir, out.as_deref(), sign.as_deref(), &binaries),
             PackageVerb::Publish { dir, release, sign } => {
                 publish::run(globals, &dir, release, sign.as_deref()).await
             }
-            PackageVerb::Upload { file } => upload::run(globals, &file).await,
+            PackageVerb::Upload { file, config } => {
+                upload::run(globals, &file, config.as_deref()).await
+            }
             PackageVerb::List { filter } => list::run(globals, filter.as_deref()).await,
             PackageVerb::Inspect { id } => inspect::run(globals, &id).await,
+            PackageVerb::Versions { name } => rollback::versions(globals, &name).await,
+            PackageVerb::Rollback { name } => rollback::run(globals, &name).await,
             PackageVerb::Delete { id, force } => delete::run(globals, &id, force).await,
         }
     }
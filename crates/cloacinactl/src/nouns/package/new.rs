@@ -24,6 +24,9 @@
 //! - `cron` — a workflow fired by a cron `#[trigger(on, cron)]` (Rust only;
 //!   Python has no cron trigger — it uses poll `@cloaca.trigger`).
 //!
+//! `--template` instead scaffolds a fuller Rust project (workflow, trigger and
+//! a `tests/` suite) from a built-in template — see [`super::template`].
+//!
 //! Python packages use bare decorators (the loader builds the workflow/graph
 //! context from `workflow_name`/`graph_name`); Rust packages depend on the
 //! published `cloacina-*` crates, not the in-repo path deps.
@@ -33,10 +36,11 @@ use std::path::{Path, PathBuf};
 
 use clap::ValueEnum;
 
+use super::template::{self, ScaffoldTemplate};
 use crate::shared::error::CliError;
 
 /// Version pin for the generated Rust package's `cloacina-*` dependencies.
-const CLOACINA_CRATE_VERSION: &str = "0.10";
+pub(super) const CLOACINA_CRATE_VERSION: &str = "0.10";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum ScaffoldLang {
@@ -55,6 +59,7 @@ pub fn run(
     name: &str,
     lang: ScaffoldLang,
     kind: ScaffoldKind,
+    template: Option<ScaffoldTemplate>,
     path: Option<&Path>,
 ) -> Result<(), CliError> {
     let name = name.trim();
@@ -91,6 +96,18 @@ pub fn run(
         )));
     }
 
+    if let Some(template) = template {
+        template::scaffold(&dir, name, &module, template)?;
+        println!(
+            "created rust package from the {} template at {}",
+            template::label(template),
+            dir.display()
+        );
+        println!("next: cd {} && cargo test", dir.display());
+        println!("then: cloacinactl package validate {}", dir.display());
+        return Ok(());
+    }
+
     match lang {
         ScaffoldLang::Python => scaffold_python(&dir, name, &module, kind)?,
         ScaffoldLang::Rust => scaffold_rust(&dir, name, &module, kind)?,
@@ -136,7 +153,7 @@ fn pascal_case(module: &str) -> String {
         .collect()
 }
 
-fn write(path: &Path, contents: &str) -> Result<(), CliError> {
+pub(super) fn write(path: &Path, contents: &str) -> Result<(), CliError> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).map_err(CliError::Io)?;
     }
@@ -509,6 +526,7 @@ mod tests {
             "data-pipeline",
             ScaffoldLang::Python,
             ScaffoldKind::Workflow,
+            None,
             Some(&dir),
         )
         .unwrap();
@@ -531,6 +549,7 @@ mod tests {
             "my-graph",
             ScaffoldLang::Python,
             ScaffoldKind::Graph,
+            None,
             Some(&dir),
         )
         .unwrap();
@@ -554,6 +573,7 @@ mod tests {
             "my-workflow",
             ScaffoldLang::Rust,
             ScaffoldKind::Workflow,
+            None,
             Some(&dir),
         )
         .unwrap();
@@ -576,6 +596,7 @@ mod tests {
             "nightly",
             ScaffoldLang::Rust,
             ScaffoldKind::Cron,
+            None,
             Some(&dir),
         )
         .unwrap();
@@ -594,6 +615,7 @@ mod tests {
             "my-graph",
             ScaffoldLang::Rust,
             ScaffoldKind::Graph,
+            None,
             Some(&dir),
         )
         .unwrap();
@@ -615,6 +637,7 @@ mod tests {
             "x",
             ScaffoldLang::Python,
             ScaffoldKind::Cron,
+            None,
             Some(tmp.path()),
         )
         .unwrap_err();
@@ -632,6 +655,7 @@ mod tests {
             "taken",
             ScaffoldLang::Python,
             ScaffoldKind::Workflow,
+            None,
             Some(&dir),
         )
         .unwrap_err();
@@ -645,6 +669,7 @@ mod tests {
             "  ",
             ScaffoldLang::Python,
             ScaffoldKind::Workflow,
+            None,
             Some(tmp.path()),
         )
         .unwrap_err();
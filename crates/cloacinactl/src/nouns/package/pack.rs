@@ -19,7 +19,12 @@ use std::path::{Path, PathBuf};
 use super::manifest::{self, PackageLanguage};
 use crate::shared::error::CliError;
 
-pub fn run(dir: &Path, out: Option<&Path>, sign: Option<&Path>) -> Result<(), CliError> {
+pub fn run(
+    dir: &Path,
+    out: Option<&Path>,
+    sign: Option<&Path>,
+    binaries: &[(String, PathBuf)],
+) -> Result<(), CliError> {
     // CLOACI-T-0596 / API-05: `--sign` is part of the public surface but
     // the CLI doesn't yet drive `cloacina::security::package_signer`.
     // Previously this silently ignored the flag with an `eprintln!` —
@@ -34,7 +39,7 @@ pub fn run(dir: &Path, out: Option<&Path>, sign: Option<&Path>) -> Result<(), Cl
         )));
     }
 
-    let produced = pack_to(dir, out)?;
+    let produced = pack_to(dir, out, binaries)?;
     println!("{}", produced.display());
     Ok(())
 }
@@ -46,19 +51,33 @@ pub fn run(dir: &Path, out: Option<&Path>, sign: Option<&Path>) -> Result<(), Cl
 /// validated for the server-expected `workflow/` layout up front so a
 /// mis-laid-out module fails here rather than at upload. (Reading the manifest
 /// through `CloacinaMetadata` also rejects `package_type` / `[[metadata.triggers]]`.)
-pub fn pack_to(dir: &Path, out: Option<&Path>) -> Result<PathBuf, CliError> {
+///
+/// `binaries` are `(triple, cdylib path)` pairs bundled under
+/// `targets/<triple>/` so one archive serves several architectures. Every
+/// archive records the source's build provenance in `provenance.json`.
+pub fn pack_to(
+    dir: &Path,
+    out: Option<&Path>,
+    binaries: &[(String, PathBuf)],
+) -> Result<PathBuf, CliError> {
     let meta = manifest::read_metadata(dir)?;
     let lang = manifest::language(&meta)?;
     match lang {
         PackageLanguage::Python => manifest::validate_python_layout(dir, &meta)?,
         PackageLanguage::Rust => manifest::validate_rust_layout(dir)?,
     }
+    if !binaries.is_empty() && lang == PackageLanguage::Python {
+        return Err(CliError::UserError(
+            "--binary only applies to Rust packages; python packages have nothing to compile"
+                .to_string(),
+        ));
+    }
     manifest::lint_footguns(dir, lang, &meta)?;
 
     // CLOACI-T-0735: fidius's pack re-parses the manifest with its strict
     // header schema, and the archive carries package.toml verbatim. When the
-    // on-disk manifest is MINIMAL (resolver-defaulted), stage a copy with the
-    // RESOLVED manifest so (a) fidius accepts it and (b) every produced
+    // on-disk manifest is MINIMAL (resolver-defaulted), the staged copy gets
+    // the RESOLVED manifest so (a) fidius accepts it and (b) every produced
     // archive carries the fully-resolved form — consumers never depend on
     // resolution having happened.
     let raw = std::fs::read_to_string(dir.join("package.toml"))
@@ -68,33 +87,82 @@ pub fn pack_to(dir: &Path, out: Option<&Path>) -> Result<PathBuf, CliError> {
     let raw_value: toml::Value = toml::from_str(&raw)
         .map_err(|e| CliError::UserError(format!("invalid package.toml: {e}")))?;
 
-    let produced = if raw_value == resolved {
-        fidius_core::package::pack_package(dir, out)
-            .map_err(|e| CliError::UserError(format!("pack_package failed: {e}")))?
-    } else {
-        let stage =
-            tempfile::tempdir().map_err(|e| CliError::UserError(format!("staging dir: {e}")))?;
-        let stage_dir = stage.path().join(
-            dir.file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| "package".to_string()),
-        );
-        copy_package_tree(dir, &stage_dir)?;
+    // Always pack from a staged copy: the archive gains a provenance.json
+    // (and possibly a resolved manifest and bundled binaries) that must not
+    // be written into the author's source tree.
+    let stage =
+        tempfile::tempdir().map_err(|e| CliError::UserError(format!("staging dir: {e}")))?;
+    let stage_dir = stage.path().join(
+        dir.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "package".to_string()),
+    );
+    copy_package_tree(dir, &stage_dir)?;
+    if raw_value != resolved {
         std::fs::write(
             stage_dir.join("package.toml"),
             toml::to_string_pretty(&resolved)
                 .map_err(|e| CliError::UserError(format!("serialize manifest: {e}")))?,
         )
         .map_err(|e| CliError::UserError(format!("write resolved manifest: {e}")))?;
-        // Default output is the CURRENT directory (same as the unstaged
-        // path), so staging doesn't change where the archive lands.
-        fidius_core::package::pack_package(&stage_dir, out)
-            .map_err(|e| CliError::UserError(format!("pack_package failed: {e}")))?
-    };
+    }
+    stage_binaries(&stage_dir, binaries)?;
+    // Provenance is taken from the SOURCE dir — the staged copy has no .git
+    // and, for workspace members, no Cargo.lock above it.
+    let provenance = cloacina::packaging::collect_provenance(dir, lang == PackageLanguage::Rust);
+    cloacina::packaging::write_provenance(&stage_dir, &provenance).map_err(CliError::UserError)?;
+    // Default output is the CURRENT directory, same as packing `dir` in
+    // place, so staging doesn't change where the archive lands.
+    let produced = fidius_core::package::pack_package(&stage_dir, out)
+        .map_err(|e| CliError::UserError(format!("pack_package failed: {e}")))?;
 
     Ok(produced.path)
 }
 
+/// Parse a `--binary <triple>=<path>` value. The triple may be a cargo triple
+/// (`aarch64-unknown-linux-gnu`) or the short `{arch}-{os}` form; it is stored
+/// in the short form the loader looks up.
+pub fn parse_binary_spec(spec: &str) -> Result<(String, PathBuf), String> {
+    let (triple, path) = spec
+        .split_once('=')
+        .ok_or_else(|| format!("expected <triple>=<path>, got '{spec}'"))?;
+    let normalized = cloacina::packaging::normalize_target_triple(triple)
+        .ok_or_else(|| format!("unrecognised target triple '{triple}'"))?;
+    Ok((normalized, PathBuf::from(path)))
+}
+
+/// Copy each bundled binary into `<stage>/targets/<triple>/`, replacing any
+/// binary the source tree already bundles for that triple.
+fn stage_binaries(stage_dir: &Path, binaries: &[(String, PathBuf)]) -> Result<(), CliError> {
+    let mut seen = std::collections::HashSet::new();
+    for (triple, path) in binaries {
+        if !seen.insert(triple) {
+            return Err(CliError::UserError(format!(
+                "--binary given twice for target '{triple}'"
+            )));
+        }
+        let file_name = path.file_name().ok_or_else(|| {
+            CliError::UserError(format!(
+                "--binary path '{}' has no file name",
+                path.display()
+            ))
+        })?;
+        let target_dir = stage_dir
+            .join(cloacina::packaging::BUNDLED_TARGETS_DIR)
+            .join(triple);
+        if target_dir.exists() {
+            std::fs::remove_dir_all(&target_dir)
+                .map_err(|e| CliError::UserError(format!("staging {triple}: {e}")))?;
+        }
+        std::fs::create_dir_all(&target_dir)
+            .map_err(|e| CliError::UserError(format!("staging {triple}: {e}")))?;
+        std::fs::copy(path, target_dir.join(file_name)).map_err(|e| {
+            CliError::UserError(format!("bundle {} for {triple}: {e}", path.display()))
+        })?;
+    }
+    Ok(())
+}
+
 /// Recursive copy of a package source tree into `dst`, skipping build
 /// output/VCS dirs that don't belong in an archive (`target`, `.git`,
 /// `node_modules`, `__pycache__`) and any prior archives.
@@ -124,3 +192,106 @@ fn copy_package_tree(src: &Path, dst: &Path) -> Result<(), CliError> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nouns::package::new::{self, ScaffoldKind, ScaffoldLang};
+    use tempfile::TempDir;
+
+    #[test]
+    fn binary_spec_normalizes_the_triple() {
+        let (triple, path) = parse_binary_spec("aarch64-unknown-linux-gnu=out/libetl.so").unwrap();
+        assert_eq!(triple, "aarch64-linux");
+        assert_eq!(path, PathBuf::from("out/libetl.so"));
+        assert!(parse_binary_spec("out/libetl.so").is_err());
+        assert!(parse_binary_spec("sparc-sun-solaris=libetl.so").is_err());
+    }
+
+    #[test]
+    fn rust_pack_bundles_binaries_per_target() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("etl");
+        new::run(
+            "etl",
+            ScaffoldLang::Rust,
+            ScaffoldKind::Workflow,
+            None,
+            Some(&dir),
+        )
+        .unwrap();
+        let x86 = tmp.path().join("libetl-x86.so");
+        let arm = tmp.path().join("libetl.so");
+        std::fs::write(&x86, b"x86").unwrap();
+        std::fs::write(&arm, b"arm").unwrap();
+
+        let archive = pack_to(
+            &dir,
+            Some(&tmp.path().join("etl.cloacina")),
+            &[
+                ("x86_64-linux".to_string(), x86),
+                ("aarch64-linux".to_string(), arm),
+            ],
+        )
+        .unwrap();
+
+        let unpacked = tmp.path().join("unpacked");
+        let root = fidius_core::package::unpack_package(&archive, &unpacked).unwrap();
+        assert_eq!(
+            cloacina::packaging::bundled_targets(&root),
+            vec!["aarch64-linux".to_string(), "x86_64-linux".to_string()]
+        );
+        assert_eq!(
+            cloacina::packaging::read_bundled_binary(&root, "aarch64-linux").unwrap(),
+            Some(b"arm".to_vec())
+        );
+    }
+
+    #[test]
+    fn pack_records_provenance_without_touching_the_source() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("etl");
+        new::run(
+            "etl",
+            ScaffoldLang::Rust,
+            ScaffoldKind::Workflow,
+            None,
+            Some(&dir),
+        )
+        .unwrap();
+        std::fs::write(dir.join("Cargo.lock"), "version = 3\n").unwrap();
+
+        let archive = pack_to(&dir, Some(&tmp.path().join("etl.cloacina")), &[]).unwrap();
+        assert!(!dir.join(cloacina::packaging::PROVENANCE_FILE).exists());
+
+        let unpacked = tmp.path().join("unpacked");
+        let root = fidius_core::package::unpack_package(&archive, &unpacked).unwrap();
+        let provenance = cloacina::packaging::read_provenance(&root)
+            .unwrap()
+            .expect("archive carries provenance.json");
+        assert!(provenance.built_at.is_some());
+        assert_eq!(provenance.lockfile.as_deref(), Some("Cargo.lock"));
+        assert_eq!(provenance.lockfile_sha256.map(|h| h.len()), Some(64));
+    }
+
+    #[test]
+    fn python_pack_rejects_binaries() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("pipeline");
+        new::run(
+            "pipeline",
+            ScaffoldLang::Python,
+            ScaffoldKind::Workflow,
+            None,
+            Some(&dir),
+        )
+        .unwrap();
+        let err = pack_to(
+            &dir,
+            Some(&tmp.path().join("pipeline.cloacina")),
+            &[("x86_64-linux".to_string(), tmp.path().join("lib.so"))],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("only applies to Rust"));
+    }
+}
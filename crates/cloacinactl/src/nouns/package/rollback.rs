@@ -0,0 +1,56 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl package versions|rollback` — version history and rollback.
+
+use serde_json::Value;
+
+use crate::commands::config::CloacinaConfig;
+use crate::shared::client::CliClient;
+use crate::shared::client_ctx::ClientContext;
+use crate::shared::error::CliError;
+use crate::shared::render;
+use crate::GlobalOpts;
+
+pub async fn versions(globals: &GlobalOpts, name: &str) -> Result<(), CliError> {
+    let config = CloacinaConfig::load(&globals.home.join("config.toml"));
+    let ctx = ClientContext::resolve(globals, &config).map_err(CliError::Other)?;
+    let output = ctx.output;
+    let client = CliClient::new(ctx)?;
+
+    let tenant = client.ctx().tenant_segment().to_string();
+    let body: Value = client
+        .get(&format!("/v1/tenants/{tenant}/workflows/{name}/versions"))
+        .await?;
+    let versions = body.get("versions").cloned().unwrap_or(Value::Null);
+    render::list(&versions, output)
+}
+
+pub async fn run(globals: &GlobalOpts, name: &str) -> Result<(), CliError> {
+    let config = CloacinaConfig::load(&globals.home.join("config.toml"));
+    let ctx = ClientContext::resolve(globals, &config).map_err(CliError::Other)?;
+    let output = ctx.output;
+    let client = CliClient::new(ctx)?;
+
+    let tenant = client.ctx().tenant_segment().to_string();
+    let body: Value = client
+        .post(
+            &format!("/v1/tenants/{tenant}/workflows/{name}/rollback"),
+            &Value::Null,
+        )
+        .await?;
+    render::object(&body, output)
+}
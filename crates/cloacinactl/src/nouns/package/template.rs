@@ -0,0 +1,589 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl package new <name> --template <template>` — scaffold a Rust
+//! package from a built-in project template.
+//!
+//! Where `--kind` emits the smallest package of a given shape, a template is
+//! a realistic starting point: a multi-task workflow with typed context
+//! reads/writes, the trigger that drives it, and a `tests/` suite that runs
+//! the tasks in-process with `cargo test`. Templates are Rust-only — the
+//! tests call the task functions directly, which the Python loader has no
+//! equivalent for.
+//!
+//! - `etl` — extract → transform → load, started on demand.
+//! - `event-driven` — a poll `#[trigger]` that fires the workflow with the
+//!   events it found, and a workflow that handles them.
+//! - `cron-report` — a cron `#[trigger]` that builds and publishes a
+//!   periodic report.
+
+use std::path::Path;
+
+use clap::ValueEnum;
+
+use super::new::{write, CLOACINA_CRATE_VERSION};
+use crate::shared::error::CliError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ScaffoldTemplate {
+    Etl,
+    EventDriven,
+    CronReport,
+}
+
+pub fn label(template: ScaffoldTemplate) -> &'static str {
+    match template {
+        ScaffoldTemplate::Etl => "etl",
+        ScaffoldTemplate::EventDriven => "event-driven",
+        ScaffoldTemplate::CronReport => "cron-report",
+    }
+}
+
+pub fn scaffold(
+    dir: &Path,
+    name: &str,
+    module: &str,
+    template: ScaffoldTemplate,
+) -> Result<(), CliError> {
+    let (description, lib, tests) = match template {
+        ScaffoldTemplate::Etl => (
+            format!("{name} ETL workflow"),
+            etl_lib(name, module),
+            etl_tests(module),
+        ),
+        ScaffoldTemplate::EventDriven => (
+            format!("{name} event-driven workflow"),
+            event_driven_lib(name, module),
+            event_driven_tests(module),
+        ),
+        ScaffoldTemplate::CronReport => (
+            format!("{name} scheduled report"),
+            cron_report_lib(name, module),
+            cron_report_tests(module),
+        ),
+    };
+
+    write(&dir.join("Cargo.toml"), &cargo_toml(name))?;
+    write(
+        &dir.join("package.toml"),
+        &package_toml(name, module, &description),
+    )?;
+    write(&dir.join("src/lib.rs"), &lib)?;
+    write(&dir.join(format!("tests/{module}.rs")), &tests)?;
+    write(&dir.join(".gitignore"), "/target\n*.cloacina\n")?;
+    Ok(())
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+# The compiler injects the cdylib crate-type at build. `packaged` is declared
+# (and on by default) so the macros expand the same way under a local
+# `cargo test` as they do in the compiler, which leaves explicit values alone.
+[features]
+default = ["packaged"]
+packaged = []
+
+[dependencies]
+cloacina-workflow = {{ version = "{ver}", features = ["packaged", "macros"] }}
+cloacina-workflow-plugin = "{ver}"
+serde = {{ version = "1.0", features = ["derive"] }}
+serde_json = "1.0"
+
+[dev-dependencies]
+tokio = {{ version = "1", features = ["macros", "rt"] }}
+"#,
+        ver = CLOACINA_CRATE_VERSION
+    )
+}
+
+fn package_toml(name: &str, module: &str, description: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+
+[metadata]
+workflow_name = "{module}"
+description = "{description}"
+"#
+    )
+}
+
+// ---------------------------------------------------------------------------
+// etl
+// ---------------------------------------------------------------------------
+
+fn etl_lib(name: &str, module: &str) -> String {
+    format!(
+        r#"//! {name} — extract → transform → load.
+//!
+//! Start it on demand (`cloacinactl workflow run {module}`), optionally with
+//! a `source` key in the input context. Replace the bodies of `extract` and
+//! `load` with calls to your real source and sink.
+
+use cloacina_workflow::{{task, workflow}};
+use cloacina_workflow::{{Context, TaskError}};
+use serde::{{Deserialize, Serialize}};
+
+cloacina_workflow_plugin::package!();
+
+/// One row moving through the pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {{
+    pub id: u64,
+    pub name: String,
+    pub amount_cents: i64,
+}}
+
+/// Drop rows without a name, normalise names, and skip non-positive amounts.
+pub fn transform_records(records: Vec<Record>) -> Vec<Record> {{
+    records
+        .into_iter()
+        .filter(|r| !r.name.trim().is_empty() && r.amount_cents > 0)
+        .map(|r| Record {{
+            name: r.name.trim().to_lowercase(),
+            ..r
+        }})
+        .collect()
+}}
+
+#[workflow(name = "{module}", description = "{name} ETL workflow")]
+pub mod {module}_wf {{
+    use super::*;
+
+    #[task(id = "extract", dependencies = [])]
+    pub async fn extract(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {{
+        let source: String = context
+            .get_as("source")?
+            .unwrap_or_else(|| "sample".to_string());
+        // Replace with a read from `source` (database, object store, API).
+        let records = vec![
+            Record {{ id: 1, name: " Ada ".to_string(), amount_cents: 1200 }},
+            Record {{ id: 2, name: "".to_string(), amount_cents: 500 }},
+            Record {{ id: 3, name: "Grace".to_string(), amount_cents: -40 }},
+            Record {{ id: 4, name: "Linus".to_string(), amount_cents: 800 }},
+        ];
+        context.insert_as("source", source)?;
+        context.insert_as("raw_records", records)?;
+        Ok(())
+    }}
+
+    #[task(id = "transform", dependencies = ["extract"])]
+    pub async fn transform(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {{
+        let raw: Vec<Record> = context.get_required("raw_records")?;
+        let extracted = raw.len();
+        let clean = transform_records(raw);
+        context.insert_as("rejected_count", extracted - clean.len())?;
+        context.insert_as("clean_records", clean)?;
+        Ok(())
+    }}
+
+    #[task(id = "load", dependencies = ["transform"])]
+    pub async fn load(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {{
+        let clean: Vec<Record> = context.get_required("clean_records")?;
+        // Replace with a write to your sink; record what was written.
+        let total: i64 = clean.iter().map(|r| r.amount_cents).sum();
+        context.insert_as("loaded_count", clean.len())?;
+        context.insert_as("loaded_total_cents", total)?;
+        Ok(())
+    }}
+}}
+"#
+    )
+}
+
+fn etl_tests(module: &str) -> String {
+    format!(
+        r#"//! Runs the {module} tasks in order, in-process.
+
+use cloacina_workflow::Context;
+use {module}::{module}_wf::{{extract, load, transform}};
+use {module}::{{transform_records, Record}};
+
+#[tokio::test]
+async fn pipeline_loads_only_clean_records() {{
+    let mut context = Context::new();
+    extract(&mut context).await.unwrap();
+    transform(&mut context).await.unwrap();
+    load(&mut context).await.unwrap();
+
+    assert_eq!(context.get_required::<usize>("loaded_count").unwrap(), 2);
+    assert_eq!(context.get_required::<usize>("rejected_count").unwrap(), 2);
+    assert_eq!(
+        context.get_required::<i64>("loaded_total_cents").unwrap(),
+        2000
+    );
+}}
+
+#[tokio::test]
+async fn transform_requires_extracted_records() {{
+    let mut context = Context::new();
+    assert!(transform(&mut context).await.is_err());
+}}
+
+#[test]
+fn transform_normalises_names() {{
+    let out = transform_records(vec![Record {{
+        id: 1,
+        name: "  Ada ".to_string(),
+        amount_cents: 1,
+    }}]);
+    assert_eq!(out[0].name, "ada");
+}}
+"#
+    )
+}
+
+// ---------------------------------------------------------------------------
+// event-driven
+// ---------------------------------------------------------------------------
+
+fn event_driven_lib(name: &str, module: &str) -> String {
+    format!(
+        r#"//! {name} — fire a workflow when new events appear.
+//!
+//! A poll trigger asks `pending_events` for work every 30 seconds and fires
+//! `{module}` with the events in its context; it skips when there are none.
+//! Replace `pending_events` with a read from your queue, bucket, or API.
+
+use cloacina_workflow::{{task, trigger, workflow}};
+use cloacina_workflow::{{Context, TaskError, TriggerError, TriggerResult}};
+use serde::{{Deserialize, Serialize}};
+
+cloacina_workflow_plugin::package!();
+
+/// An event picked up by the trigger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {{
+    pub id: String,
+    pub kind: String,
+    pub payload: serde_json::Value,
+}}
+
+/// Events waiting to be handled. Replace with a real source.
+pub async fn pending_events() -> Result<Vec<Event>, TriggerError> {{
+    Ok(Vec::new())
+}}
+
+/// Fire with the events in the `events` key, or skip when there are none.
+pub fn fire_for(events: Vec<Event>) -> Result<TriggerResult, TriggerError> {{
+    if events.is_empty() {{
+        return Ok(TriggerResult::Skip);
+    }}
+    let mut context = Context::new();
+    context
+        .insert_as("events", events)
+        .map_err(|e| TriggerError::PollError {{
+            message: e.to_string(),
+        }})?;
+    Ok(TriggerResult::Fire(Some(context)))
+}}
+
+// Binds to the workflow via `on`; it is not listed in `#[workflow(triggers)]`.
+#[trigger(on = "{module}", poll_interval = "30s")]
+pub async fn {module}_events() -> Result<TriggerResult, TriggerError> {{
+    fire_for(pending_events().await?)
+}}
+
+#[workflow(name = "{module}", description = "{name} event-driven workflow")]
+pub mod {module}_wf {{
+    use super::*;
+
+    #[task(id = "validate_events", dependencies = [])]
+    pub async fn validate_events(
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), TaskError> {{
+        let events: Vec<Event> = context.get_required("events")?;
+        let (valid, invalid): (Vec<Event>, Vec<Event>) =
+            events.into_iter().partition(|e| !e.kind.is_empty());
+        let invalid_ids: Vec<String> = invalid.into_iter().map(|e| e.id).collect();
+        context.insert_as("valid_events", valid)?;
+        context.insert_as("invalid_event_ids", invalid_ids)?;
+        Ok(())
+    }}
+
+    #[task(id = "handle_events", dependencies = ["validate_events"])]
+    pub async fn handle_events(
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), TaskError> {{
+        let events: Vec<Event> = context.get_required("valid_events")?;
+        // Replace with the real side effect for each event.
+        let handled: Vec<String> = events.into_iter().map(|e| e.id).collect();
+        context.insert_as("handled_event_ids", handled)?;
+        Ok(())
+    }}
+}}
+"#
+    )
+}
+
+fn event_driven_tests(module: &str) -> String {
+    format!(
+        r#"//! Exercises the {module} trigger decision and tasks in-process.
+
+use cloacina_workflow::Context;
+use {module}::{module}_wf::{{handle_events, validate_events}};
+use {module}::{{fire_for, Event}};
+
+fn event(id: &str, kind: &str) -> Event {{
+    Event {{
+        id: id.to_string(),
+        kind: kind.to_string(),
+        payload: serde_json::json!({{}}),
+    }}
+}}
+
+#[test]
+fn trigger_skips_without_events() {{
+    assert!(!fire_for(Vec::new()).unwrap().should_fire());
+}}
+
+#[test]
+fn trigger_fires_with_events_in_context() {{
+    let result = fire_for(vec![event("e1", "created")]).unwrap();
+    assert!(result.should_fire());
+    let context = result.into_context().expect("fire carries a context");
+    let events: Vec<Event> = context.get_required("events").unwrap();
+    assert_eq!(events.len(), 1);
+}}
+
+#[tokio::test]
+async fn workflow_handles_only_valid_events() {{
+    let mut context = fire_for(vec![event("e1", "created"), event("e2", "")])
+        .unwrap()
+        .into_context()
+        .unwrap();
+    validate_events(&mut context).await.unwrap();
+    handle_events(&mut context).await.unwrap();
+
+    let handled: Vec<String> = context.get_required("handled_event_ids").unwrap();
+    assert_eq!(handled, vec!["e1".to_string()]);
+    let invalid: Vec<String> = context.get_required("invalid_event_ids").unwrap();
+    assert_eq!(invalid, vec!["e2".to_string()]);
+}}
+
+#[tokio::test]
+async fn workflow_requires_events() {{
+    let mut context = Context::new();
+    assert!(validate_events(&mut context).await.is_err());
+}}
+"#
+    )
+}
+
+// ---------------------------------------------------------------------------
+// cron-report
+// ---------------------------------------------------------------------------
+
+fn cron_report_lib(name: &str, module: &str) -> String {
+    format!(
+        r#"//! {name} — build and publish a report on a schedule.
+//!
+//! A cron trigger fires `{module}` every day at 06:00 UTC. Replace the body of
+//! `gather_metrics` with your real queries and `publish_report` with your
+//! delivery channel (email, chat, object store).
+
+use cloacina_workflow::{{task, trigger, workflow}};
+use cloacina_workflow::{{Context, TaskError}};
+use serde::{{Deserialize, Serialize}};
+
+cloacina_workflow_plugin::package!();
+
+/// One named figure in the report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metric {{
+    pub name: String,
+    pub value: f64,
+}}
+
+/// Render metrics as a plain-text report, one line per metric.
+pub fn render(title: &str, metrics: &[Metric]) -> String {{
+    let mut out = format!("{{title}}\n");
+    for m in metrics {{
+        out.push_str(&format!("- {{}}: {{}}\n", m.name, m.value));
+    }}
+    out
+}}
+
+// 6-field cron with leading seconds: 06:00:00 every day. The trigger binds to
+// the workflow via `on`; it is not listed in `#[workflow(triggers)]`.
+#[trigger(on = "{module}", cron = "0 0 6 * * *")]
+pub async fn {module}_schedule() {{}}
+
+#[workflow(name = "{module}", description = "{name} scheduled report")]
+pub mod {module}_wf {{
+    use super::*;
+
+    #[task(id = "gather_metrics", dependencies = [])]
+    pub async fn gather_metrics(
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), TaskError> {{
+        // Replace with real queries.
+        let metrics = vec![
+            Metric {{ name: "orders".to_string(), value: 42.0 }},
+            Metric {{ name: "revenue".to_string(), value: 1234.5 }},
+        ];
+        context.insert_as("metrics", metrics)?;
+        Ok(())
+    }}
+
+    #[task(id = "render_report", dependencies = ["gather_metrics"])]
+    pub async fn render_report(
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), TaskError> {{
+        let metrics: Vec<Metric> = context.get_required("metrics")?;
+        context.insert_as("report", render("{name} report", &metrics))?;
+        Ok(())
+    }}
+
+    #[task(id = "publish_report", dependencies = ["render_report"])]
+    pub async fn publish_report(
+        context: &mut Context<serde_json::Value>,
+    ) -> Result<(), TaskError> {{
+        let report: String = context.get_required("report")?;
+        // Replace with delivery; record where the report went.
+        context.insert_as("published_bytes", report.len())?;
+        Ok(())
+    }}
+}}
+"#
+    )
+}
+
+fn cron_report_tests(module: &str) -> String {
+    format!(
+        r#"//! Runs the {module} tasks in order, in-process.
+
+use cloacina_workflow::Context;
+use {module}::{module}_wf::{{gather_metrics, publish_report, render_report}};
+use {module}::{{render, Metric}};
+
+#[tokio::test]
+async fn report_is_rendered_and_published() {{
+    let mut context = Context::new();
+    gather_metrics(&mut context).await.unwrap();
+    render_report(&mut context).await.unwrap();
+    publish_report(&mut context).await.unwrap();
+
+    let report: String = context.get_required("report").unwrap();
+    assert!(report.contains("- orders: 42"));
+    assert!(context.get_required::<usize>("published_bytes").unwrap() > 0);
+}}
+
+#[test]
+fn render_lists_every_metric() {{
+    let out = render(
+        "t",
+        &[Metric {{
+            name: "a".to_string(),
+            value: 1.0,
+        }}],
+    );
+    assert_eq!(out, "t\n- a: 1\n");
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nouns::package::new::{run, ScaffoldKind, ScaffoldLang};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn scaffold_into(tmp: &TempDir, name: &str, template: ScaffoldTemplate) -> std::path::PathBuf {
+        let dir = tmp.path().join(name);
+        run(
+            name,
+            ScaffoldLang::Python,
+            ScaffoldKind::Workflow,
+            Some(template),
+            Some(&dir),
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn etl_template_emits_rust_project_with_tests() {
+        let tmp = TempDir::new().unwrap();
+        let dir = scaffold_into(&tmp, "orders-etl", ScaffoldTemplate::Etl);
+
+        // A template is always Rust, whatever the `--lang` default says.
+        assert!(!dir.join("workflow").exists());
+        let cargo = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("[dev-dependencies]"));
+        assert!(!cargo.contains("path ="));
+
+        let manifest = fs::read_to_string(dir.join("package.toml")).unwrap();
+        assert!(manifest.contains("workflow_name = \"orders_etl\""));
+
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("cloacina_workflow_plugin::package!()"));
+        assert!(lib.contains("pub mod orders_etl_wf"));
+        assert!(lib.contains("dependencies = [\"transform\"]"));
+
+        let tests = fs::read_to_string(dir.join("tests/orders_etl.rs")).unwrap();
+        assert!(tests.contains("use orders_etl::orders_etl_wf::{extract, load, transform};"));
+    }
+
+    #[test]
+    fn event_driven_template_uses_a_poll_trigger() {
+        let tmp = TempDir::new().unwrap();
+        let dir = scaffold_into(&tmp, "inbox", ScaffoldTemplate::EventDriven);
+
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("#[trigger(on = \"inbox\", poll_interval = \"30s\")]"));
+        assert!(!lib.contains("triggers = ["));
+        assert!(dir.join("tests/inbox.rs").exists());
+    }
+
+    #[test]
+    fn cron_report_template_binds_cron_via_on() {
+        let tmp = TempDir::new().unwrap();
+        let dir = scaffold_into(&tmp, "daily-report", ScaffoldTemplate::CronReport);
+
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("#[trigger(on = \"daily_report\", cron = \"0 0 6 * * *\")]"));
+        assert!(!lib.contains("triggers = ["));
+        let tests = fs::read_to_string(dir.join("tests/daily_report.rs")).unwrap();
+        assert!(tests.contains("publish_report(&mut context)"));
+    }
+
+    #[test]
+    fn template_refuses_non_empty_existing_dir() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("taken");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("something"), b"x").unwrap();
+
+        let err = run(
+            "taken",
+            ScaffoldLang::Python,
+            ScaffoldKind::Workflow,
+            Some(ScaffoldTemplate::Etl),
+            Some(&dir),
+        )
+        .unwrap_err();
+        assert!(format!("{err:?}").contains("not empty"));
+    }
+}
@@ -23,7 +23,11 @@ use crate::shared::client_ctx::ClientContext;
 use crate::shared::error::CliError;
 use crate::GlobalOpts;
 
-pub async fn run(globals: &GlobalOpts, file: &Path) -> Result<(), CliError> {
+pub async fn run(
+    globals: &GlobalOpts,
+    file: &Path,
+    config_file: Option<&Path>,
+) -> Result<(), CliError> {
     let config = CloacinaConfig::load(&globals.home.join("config.toml"));
     let ctx = ClientContext::resolve(globals, &config).map_err(CliError::Other)?;
 
@@ -37,7 +41,11 @@ pub async fn run(globals: &GlobalOpts, file: &Path) -> Result<(), CliError> {
         .file_name(filename)
         .mime_str("application/octet-stream")
         .map_err(CliError::from_reqwest)?;
-    let form = multipart::Form::new().part("file", part);
+    let mut form = multipart::Form::new().part("file", part);
+    if let Some(config_file) = config_file {
+        let config = std::fs::read_to_string(config_file).map_err(CliError::Io)?;
+        form = form.text("config", config);
+    }
 
     let http = reqwest::Client::new();
     let tenant = ctx.tenant_segment();
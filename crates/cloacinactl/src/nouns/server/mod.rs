@@ -23,6 +23,7 @@ use std::net::SocketAddr;
 use crate::GlobalOpts;
 
 pub mod health;
+pub mod regions;
 pub mod start;
 pub mod status;
 pub mod stop;
@@ -65,6 +66,8 @@ enum ServerVerb {
     Status,
     /// Terse HTTP /health probe — exit 0 if up, 2 otherwise.
     Health,
+    /// Federation regions and whether each peer is reachable.
+    Regions,
 }
 
 impl ServerCmd {
@@ -90,6 +93,7 @@ impl ServerCmd {
             ServerVerb::Stop { force } => stop::run(globals, force).await,
             ServerVerb::Status => status::run(globals).await,
             ServerVerb::Health => health::run(globals).await,
+            ServerVerb::Regions => regions::run(globals).await,
         }
     }
 }
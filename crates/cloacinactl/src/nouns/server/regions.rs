@@ -0,0 +1,39 @@
+/*
+ *  Copyright 2026 Colliery Software
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ */
+
+//! `cloacinactl server regions` — the server's federation roster.
+
+use anyhow::Result;
+
+use crate::commands::config::CloacinaConfig;
+use crate::shared::client::CliClient;
+use crate::shared::client_ctx::ClientContext;
+use crate::shared::render;
+use crate::GlobalOpts;
+
+pub async fn run(globals: &GlobalOpts) -> Result<()> {
+    let config = CloacinaConfig::load(&globals.home.join("config.toml"));
+    let ctx = ClientContext::resolve(globals, &config)?;
+    let output = ctx.output;
+    let client = CliClient::new(ctx)?;
+    let body: serde_json::Value = client.get("/v1/federation/regions").await?;
+    let regions = body
+        .get("regions")
+        .cloned()
+        .unwrap_or(serde_json::Value::Array(Vec::new()));
+    render::list(&regions, output)?;
+    Ok(())
+}
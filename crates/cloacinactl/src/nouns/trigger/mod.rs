@@ -43,6 +43,80 @@ enum TriggerVerb {
     Inspect {
         name: String,
     },
+    /// Configure how a trigger deduplicates firings. Suppressed firings show
+    /// up in `trigger inspect` with their reason.
+    Dedup {
+        name: String,
+        /// Suppress a firing whose hash matches any firing within this many
+        /// seconds. Omit (or 0) to dedup only against in-flight executions.
+        #[arg(long)]
+        window_secs: Option<i64>,
+        /// Context key to hash (repeatable). Omit to hash the whole context.
+        #[arg(long = "key", short = 'k')]
+        keys: Vec<String>,
+    },
+    /// Set what a cron schedule does with fires it missed while no
+    /// scheduler was running.
+    Catchup {
+        name: String,
+        /// `skip` drops missed fires, `run_latest_only` runs once for the
+        /// most recent one, `run_all` runs every missed fire.
+        #[arg(long)]
+        policy: String,
+    },
+    /// Set how a cron schedule handles daylight-saving transitions.
+    Dst {
+        name: String,
+        /// `skip` drops a fire whose local time does not exist,
+        /// `run_once` moves it past the gap, `run_twice` also fires a
+        /// repeated local time at both instants.
+        #[arg(long)]
+        policy: String,
+    },
+    /// Rate-limit and debounce a trigger. Rate-limited firings show up in
+    /// `trigger inspect` with reason `rate_limited`.
+    Throttle {
+        name: String,
+        /// Suppress a firing sooner than this many milliseconds after the
+        /// previous fire. Omit (or 0) for no rate limit.
+        #[arg(long)]
+        min_interval_ms: Option<i64>,
+        /// Hold a firing until the trigger has not fired for this many
+        /// milliseconds, keeping only the latest. Omit (or 0) to fire
+        /// immediately.
+        #[arg(long)]
+        debounce_ms: Option<i64>,
+    },
+    /// Gate a trigger's firings on a CEL condition. Omit `--when` to clear
+    /// the condition and its probes.
+    Condition {
+        name: String,
+        /// CEL expression over `context`, `probes` and `trigger`, e.g.
+        /// `probes.flag.exists`.
+        #[arg(long)]
+        when: Option<String>,
+        /// Probe definition as JSON (repeatable), e.g.
+        /// `{"name":"flag","kind":"file","path":"/data/ready"}`.
+        #[arg(long = "probe")]
+        probes: Vec<String>,
+    },
+    /// Create a declarative trigger that fires a workflow whenever its
+    /// condition holds.
+    Create {
+        name: String,
+        /// Workflow to fire.
+        #[arg(long)]
+        workflow: String,
+        /// How often to evaluate the condition, in milliseconds.
+        #[arg(long, default_value = "30000")]
+        poll_interval_ms: i64,
+        /// CEL expression over `probes` and `trigger`.
+        #[arg(long)]
+        when: String,
+        /// Probe definition as JSON (repeatable).
+        #[arg(long = "probe")]
+        probes: Vec<String>,
+    },
 }
 
 impl TriggerCmd {
@@ -67,6 +141,97 @@ impl TriggerCmd {
                     .await?;
                 render::object(&body, output)
             }
+            TriggerVerb::Dedup {
+                name,
+                window_secs,
+                keys,
+            } => {
+                let body = serde_json::json!({ "window_secs": window_secs, "keys": keys });
+                let resp: serde_json::Value = client
+                    .put(
+                        &format!("/v1/tenants/{tenant}/triggers/{name}/dedup"),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            TriggerVerb::Catchup { name, policy } => {
+                let body = serde_json::json!({ "policy": policy });
+                let resp: serde_json::Value = client
+                    .put(
+                        &format!("/v1/tenants/{tenant}/triggers/{name}/catchup"),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            TriggerVerb::Dst { name, policy } => {
+                let body = serde_json::json!({ "policy": policy });
+                let resp: serde_json::Value = client
+                    .put(&format!("/v1/tenants/{tenant}/triggers/{name}/dst"), &body)
+                    .await?;
+                render::object(&resp, output)
+            }
+            TriggerVerb::Throttle {
+                name,
+                min_interval_ms,
+                debounce_ms,
+            } => {
+                let body = serde_json::json!({
+                    "min_interval_between_fires_ms": min_interval_ms,
+                    "debounce_window_ms": debounce_ms,
+                });
+                let resp: serde_json::Value = client
+                    .put(
+                        &format!("/v1/tenants/{tenant}/triggers/{name}/throttle"),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            TriggerVerb::Condition { name, when, probes } => {
+                let body = serde_json::json!({
+                    "condition": when,
+                    "probes": parse_probes(&probes)?,
+                });
+                let resp: serde_json::Value = client
+                    .put(
+                        &format!("/v1/tenants/{tenant}/triggers/{name}/condition"),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            TriggerVerb::Create {
+                name,
+                workflow,
+                poll_interval_ms,
+                when,
+                probes,
+            } => {
+                let body = serde_json::json!({
+                    "name": name,
+                    "workflow_name": workflow,
+                    "poll_interval_ms": poll_interval_ms,
+                    "condition": when,
+                    "probes": parse_probes(&probes)?,
+                });
+                let resp: serde_json::Value = client
+                    .post(&format!("/v1/tenants/{tenant}/triggers"), &body)
+                    .await?;
+                render::object(&resp, output)
+            }
         }
     }
 }
+
+/// Parse `--probe` JSON arguments.
+fn parse_probes(probes: &[String]) -> Result<Vec<serde_json::Value>, CliError> {
+    probes
+        .iter()
+        .map(|p| {
+            serde_json::from_str(p)
+                .map_err(|e| CliError::UserError(format!("invalid --probe '{p}': {e}")))
+        })
+        .collect()
+}
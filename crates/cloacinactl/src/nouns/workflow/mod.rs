@@ -47,7 +47,53 @@ enum WorkflowVerb {
         /// Context JSON file path, or `-` for stdin.
         #[arg(long)]
         context: Option<String>,
+        /// Label to attach to the execution, as `key=value`. Repeatable.
+        #[arg(long = "label", value_parser = parse_label)]
+        labels: Vec<(String, String)>,
+        /// Read-only parameter of this run, as `key=value`. The value is
+        /// parsed as JSON (`iterations=3`), else taken as a string.
+        /// Repeatable.
+        #[arg(long = "param", value_parser = parse_param)]
+        params: Vec<(String, serde_json::Value)>,
+        /// Most tasks of this run dispatched at once.
+        #[arg(long)]
+        max_parallelism: Option<u32>,
+        /// Dispatch priority against other runs; higher goes first.
+        #[arg(long)]
+        priority: Option<i32>,
+        /// Replaces every task's max attempts for this run.
+        #[arg(long)]
+        max_attempts: Option<i32>,
+        /// Scales every task's retry delays for this run.
+        #[arg(long)]
+        retry_delay_multiplier: Option<f64>,
+        /// Timeout in seconds for each task of this run.
+        #[arg(long)]
+        task_timeout_secs: Option<u64>,
+        /// RFC 3339 time by which this run must finish.
+        #[arg(long)]
+        deadline: Option<String>,
+        /// Run id of this run; derived from the logical date when unset.
+        #[arg(long)]
+        run_id: Option<String>,
+        /// RFC 3339 logical date this run processes (backfills, reruns).
+        #[arg(long)]
+        logical_date: Option<String>,
     },
+    /// Mark a workflow deprecated; new cron schedules against it are refused.
+    Deprecate {
+        name: String,
+        /// RFC 3339 date the workflow is due to be removed.
+        #[arg(long)]
+        sunset: Option<String>,
+        /// Note for consumers, e.g. the replacement workflow.
+        #[arg(long)]
+        message: Option<String>,
+    },
+    /// Clear a workflow's deprecation.
+    Undeprecate { name: String },
+    /// List the schedules, reactors, and executions that still use a workflow.
+    Consumers { name: String },
 }
 
 impl WorkflowCmd {
@@ -93,13 +139,46 @@ impl WorkflowCmd {
                     .await?;
                 render::object(&body, output)
             }
-            WorkflowVerb::Run { name, context } => {
+            WorkflowVerb::Run {
+                name,
+                context,
+                labels,
+                params,
+                max_parallelism,
+                priority,
+                max_attempts,
+                retry_delay_multiplier,
+                task_timeout_secs,
+                deadline,
+                run_id,
+                logical_date,
+            } => {
                 // The execute endpoint expects the context nested under a
                 // `context` key (`ExecuteRequest { context: Option<Value> }`);
                 // posting the bare file JSON makes the server silently drop it
                 // ("Skipping insertion of empty context"). Wrap it here.
                 let ctx = load_context(context.as_deref())?;
-                let body = serde_json::json!({ "context": ctx });
+                let labels: serde_json::Map<String, serde_json::Value> = labels
+                    .into_iter()
+                    .map(|(k, v)| (k, serde_json::Value::String(v)))
+                    .collect();
+                let params: serde_json::Map<String, serde_json::Value> =
+                    params.into_iter().collect();
+                let mut body =
+                    serde_json::json!({ "context": ctx, "labels": labels, "params": params });
+                let options = run_options(
+                    max_parallelism,
+                    priority,
+                    max_attempts,
+                    retry_delay_multiplier,
+                    task_timeout_secs,
+                    deadline,
+                    run_id,
+                    logical_date,
+                );
+                if !options.is_empty() {
+                    body["options"] = serde_json::Value::Object(options);
+                }
                 let resp: serde_json::Value = client
                     .post(
                         &format!("/v1/tenants/{tenant}/workflows/{name}/execute"),
@@ -117,10 +196,88 @@ impl WorkflowCmd {
                 }
                 Ok(())
             }
+            WorkflowVerb::Deprecate {
+                name,
+                sunset,
+                message,
+            } => {
+                let body = serde_json::json!({ "sunset_at": sunset, "message": message });
+                let resp: serde_json::Value = client
+                    .put(
+                        &format!("/v1/tenants/{tenant}/workflows/{name}/deprecation"),
+                        &body,
+                    )
+                    .await?;
+                render::object(&resp, output)
+            }
+            WorkflowVerb::Undeprecate { name } => {
+                client
+                    .delete(&format!(
+                        "/v1/tenants/{tenant}/workflows/{name}/deprecation"
+                    ))
+                    .await?;
+                println!("cleared deprecation of workflow {name}");
+                Ok(())
+            }
+            WorkflowVerb::Consumers { name } => {
+                let body: serde_json::Value = client
+                    .get(&format!("/v1/tenants/{tenant}/workflows/{name}/consumers"))
+                    .await?;
+                render::object(&body, output)
+            }
         }
     }
 }
 
+/// The `options` object of an execute request, holding only the flags that
+/// were given.
+#[allow(clippy::too_many_arguments)]
+fn run_options(
+    max_parallelism: Option<u32>,
+    priority: Option<i32>,
+    max_attempts: Option<i32>,
+    retry_delay_multiplier: Option<f64>,
+    task_timeout_secs: Option<u64>,
+    deadline: Option<String>,
+    run_id: Option<String>,
+    logical_date: Option<String>,
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut options = serde_json::Map::new();
+    let mut set = |key: &str, value: Option<serde_json::Value>| {
+        if let Some(value) = value {
+            options.insert(key.to_string(), value);
+        }
+    };
+    set("max_parallelism", max_parallelism.map(Into::into));
+    set("priority", priority.map(Into::into));
+    set("max_attempts", max_attempts.map(Into::into));
+    set(
+        "retry_delay_multiplier",
+        retry_delay_multiplier.map(Into::into),
+    );
+    set("task_timeout_secs", task_timeout_secs.map(Into::into));
+    set("deadline", deadline.map(Into::into));
+    set("run_id", run_id.map(Into::into));
+    set("logical_date", logical_date.map(Into::into));
+    options
+}
+
+fn parse_param(raw: &str) -> Result<(String, serde_json::Value), String> {
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("param '{raw}' must be key=value"))?;
+    let value = value.trim();
+    let value = serde_json::from_str(value)
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+    Ok((key.trim().to_string(), value))
+}
+
+fn parse_label(raw: &str) -> Result<(String, String), String> {
+    raw.split_once('=')
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .ok_or_else(|| format!("label '{raw}' must be key=value"))
+}
+
 fn load_context(source: Option<&str>) -> Result<serde_json::Value, CliError> {
     match source {
         None => Ok(serde_json::json!({})),
@@ -59,7 +59,9 @@ pub fn confirm_destructive(action: &str) -> Result<(), CliError> {
 
 impl CliClient {
     pub fn new(ctx: ClientContext) -> Result<Arc<Self>, CliError> {
-        let mut builder = cloacina_client::ClientBuilder::new(&ctx.server).api_key(&ctx.api_key);
+        let mut builder = cloacina_client::ClientBuilder::new(&ctx.server)
+            .api_key(&ctx.api_key)
+            .tls(ctx.tls.clone());
         if let Some(tenant) = &ctx.tenant {
             builder = builder.tenant(tenant);
         }
@@ -34,6 +34,8 @@ pub struct ClientContext {
     /// consumer reads it (pending wire-up in the renderer layer).
     #[allow(dead_code)]
     pub no_color: bool,
+    /// Profile TLS settings merged over the `CLOACINA_TLS_*` env vars.
+    pub tls: cloacina_client::TlsOptions,
 }
 
 impl ClientContext {
@@ -70,12 +72,18 @@ impl ClientContext {
 
         let api_key = resolve_api_key_scheme(&api_key_raw)?;
 
+        let tls = profile
+            .and_then(|p| p.tls.clone())
+            .unwrap_or_default()
+            .or(cloacina_client::TlsOptions::from_env());
+
         Ok(Self {
             server,
             api_key,
             tenant: opts.tenant.clone(),
             output: opts.effective_output(),
             no_color: opts.no_color,
+            tls,
         })
     }
 
@@ -128,6 +136,7 @@ mod tests {
             Profile {
                 server: "http://from-profile".into(),
                 api_key: "profile-key".into(),
+                tls: None,
             },
         );
         let o = opts(|o| {
@@ -150,6 +159,7 @@ mod tests {
             Profile {
                 server: "http://a".into(),
                 api_key: "k-a".into(),
+                tls: None,
             },
         );
         config.profiles.insert(
@@ -157,6 +167,7 @@ mod tests {
             Profile {
                 server: "http://b".into(),
                 api_key: "k-b".into(),
+                tls: None,
             },
         );
         let o = opts(|o| o.profile = Some("b".into()));
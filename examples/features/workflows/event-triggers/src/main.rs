@@ -30,7 +30,8 @@
 //!
 //! ## Triggers Demonstrated
 //!
-//! 1. **File Watcher** - Polls for new files and triggers processing workflow
+//! 1. **File Watcher** - The built-in `FileWatchTrigger` fires the processing
+//!    workflow for each CSV file dropped into `event_triggers_inbox/`
 //! 2. **Queue Monitor** - Fires when queue depth exceeds threshold
 //! 3. **Health Check** - Triggers recovery workflow on service failures
 //!
@@ -42,7 +43,9 @@
 //! ```
 
 use cloacina::runner::{DefaultRunner, DefaultRunnerConfig};
+use cloacina::trigger::FileWatchTrigger;
 use cloacina::{task, workflow, Context, TaskError};
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::info;
 
@@ -68,7 +71,7 @@ pub mod file_processing_workflow {
     pub async fn validate_file(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {
         // Clone string value to avoid borrow issues
         let filename = context
-            .get("filename")
+            .get("file_name")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown")
             .to_string();
@@ -79,7 +82,11 @@ pub mod file_processing_workflow {
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
         context.insert("validated", serde_json::json!(true))?;
-        context.insert("file_size_bytes", serde_json::json!(1024))?;
+        let size_bytes = context
+            .get("size_bytes")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+        context.insert("file_size_bytes", serde_json::json!(size_bytes))?;
         context.insert("record_count", serde_json::json!(100))?;
 
         info!("File '{}' validated successfully", filename);
@@ -90,7 +97,7 @@ pub mod file_processing_workflow {
     #[task(dependencies = ["validate_file"])]
     pub async fn process_file(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {
         let filename = context
-            .get("filename")
+            .get("file_name")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown")
             .to_string();
@@ -115,7 +122,7 @@ pub mod file_processing_workflow {
     #[task(dependencies = ["process_file"])]
     pub async fn archive_file(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {
         let filename = context
-            .get("filename")
+            .get("file_name")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown")
             .to_string();
@@ -374,10 +381,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // still has to wire by hand for an in-process demo; packaged
     // workflows go through the reconciler instead.
     register_trigger_schedules(&runner).await?;
+    register_file_watcher(&runner).await?;
+
+    // Drop a CSV into the inbox every 10s so the file watcher has work.
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(Duration::from_secs(10));
+        loop {
+            interval.tick().await;
+            let path = format!(
+                "{}/data_file_{}.csv",
+                INBOX_DIR,
+                chrono::Utc::now().timestamp()
+            );
+            if let Err(e) = tokio::fs::write(&path, "id,amount\n1,10\n2,20\n").await {
+                tracing::warn!("could not write {}: {}", path, e);
+            }
+        }
+    });
 
     info!("");
     info!("Event triggers are now active!");
-    info!("- File Watcher: polls every 2s for new files");
+    info!(
+        "- File Watcher: fires for each CSV written to {}/",
+        INBOX_DIR
+    );
     info!("- Queue Monitor: polls every 3s, fires when depth > 10");
     info!("- Health Check: polls every 2s, fires after 3 failures");
     info!("");
@@ -432,7 +459,6 @@ async fn register_trigger_schedules(
     // `name = "..."` attribute on the #[trigger] macro; the workflow name
     // mirrors the `on = "..."` attribute.
     let bindings = [
-        ("file_watcher", "file_processing_workflow"),
         ("queue_monitor", "queue_processing_workflow"),
         ("service_health", "service_recovery_workflow"),
     ];
@@ -452,3 +478,40 @@ async fn register_trigger_schedules(
 
     Ok(())
 }
+
+/// Directory the built-in file watcher watches.
+const INBOX_DIR: &str = "event_triggers_inbox";
+
+/// Register the built-in `FileWatchTrigger` for `*.csv` files in
+/// [`INBOX_DIR`] and persist its schedule row.
+///
+/// Unlike the `#[trigger]` triggers, it is built at runtime, so it is
+/// registered with the runtime by hand. The runtime factory hands out the
+/// one shared instance: the OS watch lives as long as the trigger does.
+async fn register_file_watcher(runner: &DefaultRunner) -> Result<(), Box<dyn std::error::Error>> {
+    let _ = std::fs::remove_dir_all(INBOX_DIR);
+    std::fs::create_dir_all(INBOX_DIR)?;
+
+    let trigger = Arc::new(
+        FileWatchTrigger::builder("file_watcher", INBOX_DIR)
+            .pattern("*.csv")
+            .build()?,
+    );
+    let registered = trigger.clone();
+    runner
+        .runtime()
+        .register_trigger("file_watcher".to_string(), move || registered.clone());
+
+    let scheduler = runner
+        .unified_scheduler()
+        .await
+        .ok_or("unified scheduler not enabled — check enable_trigger_scheduling()")?;
+    let schedule = scheduler
+        .register_trigger(trigger.as_ref(), "file_processing_workflow")
+        .await?;
+    info!(
+        "Registered schedule: file_watcher -> file_processing_workflow (ID: {})",
+        schedule.id
+    );
+    Ok(())
+}
@@ -29,12 +29,13 @@
 //!
 //! ## Triggers demonstrated
 //!
-//! 1. **`file_watcher`** — every fifth poll, simulate a new file landing
-//!    on disk and fire `process_file_workflow`.
-//! 2. **`queue_monitor`** — fire `process_queue_workflow` whenever the
+//! 1. **`queue_monitor`** — fire `process_queue_workflow` whenever the
 //!    synthetic queue depth crosses a threshold.
-//! 3. **`service_health`** — fire `alert_workflow` after three
+//! 2. **`service_health`** — fire `alert_workflow` after three
 //!    consecutive simulated failures.
+//!
+//! The file watcher is not hand-rolled: `main.rs` registers the built-in
+//! `FileWatchTrigger`, which reacts to real filesystem events.
 
 use cloacina::trigger;
 use cloacina_workflow::Context;
@@ -50,9 +51,8 @@ use tracing::{debug, info};
 // instance state from, so polling state lives here as plain atomics.
 // ----------------------------------------------------------------------
 
-/// Pseudo-counter for the file watcher's poll loop. We pretend a new
-/// file lands on disk every fifth poll.
-static FILE_POLL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+/// Tick counter driving the synthetic service-health pattern.
+static HEALTH_TICK: AtomicUsize = AtomicUsize::new(0);
 
 /// Pseudo-counter for the queue depth simulation.
 static QUEUE_TICK: AtomicUsize = AtomicUsize::new(0);
@@ -85,54 +85,7 @@ const QUEUE_THRESHOLD: usize = 10;
 const HEALTH_FAILURE_THRESHOLD: usize = 3;
 
 // ============================================================================
-// 1. File-watcher trigger
-// ============================================================================
-//
-// Pretend a new file lands in `/data/inbox` every fifth poll. Real
-// production code would `std::fs::read_dir` or call an object-storage
-// API; the macro form is identical either way.
-
-#[trigger(
-    on = "file_processing_workflow",
-    poll_interval = "2s",
-    allow_concurrent = false,
-    name = "file_watcher"
-)]
-async fn file_watcher() -> Result<TriggerResult, TriggerError> {
-    let count = FILE_POLL_COUNTER.fetch_add(1, Ordering::SeqCst);
-    if count % 5 != 4 {
-        debug!("file_watcher: no new files (poll #{})", count);
-        return Ok(TriggerResult::Skip);
-    }
-
-    let filename = format!("data_file_{}.csv", chrono::Utc::now().timestamp());
-    info!(
-        "file_watcher: found new file '{}' in /data/inbox",
-        filename
-    );
-
-    let mut ctx = Context::new();
-    ctx.insert("filename", serde_json::json!(filename))
-        .map_err(|e| TriggerError::PollError {
-            message: format!("insert filename: {}", e),
-        })?;
-    ctx.insert("watch_path", serde_json::json!("/data/inbox"))
-        .map_err(|e| TriggerError::PollError {
-            message: format!("insert watch_path: {}", e),
-        })?;
-    ctx.insert(
-        "discovered_at",
-        serde_json::json!(chrono::Utc::now().to_rfc3339()),
-    )
-    .map_err(|e| TriggerError::PollError {
-        message: format!("insert discovered_at: {}", e),
-    })?;
-
-    Ok(TriggerResult::Fire(Some(ctx)))
-}
-
-// ============================================================================
-// 2. Queue-depth trigger
+// 1. Queue-depth trigger
 // ============================================================================
 //
 // Synthesizes a depth that oscillates between 0 and 20. Whenever the
@@ -181,7 +134,7 @@ async fn queue_monitor() -> Result<TriggerResult, TriggerError> {
 }
 
 // ============================================================================
-// 3. Health-check trigger
+// 2. Health-check trigger
 // ============================================================================
 //
 // Polls a synthetic service. Service goes unhealthy for 5 ticks every 15.
@@ -195,10 +148,8 @@ async fn queue_monitor() -> Result<TriggerResult, TriggerError> {
     name = "service_health"
 )]
 async fn service_health() -> Result<TriggerResult, TriggerError> {
-    // Reuse file_watcher's tick so the health pattern lines up with the
-    // demo's overall timeline; in production each trigger would have its
-    // own clock or call out to a real probe.
-    let tick = FILE_POLL_COUNTER.load(Ordering::SeqCst);
+    // In production this would call out to a real probe.
+    let tick = HEALTH_TICK.fetch_add(1, Ordering::SeqCst);
     let healthy = tick % 15 < 10;
     SERVICE_HEALTHY.store(healthy, Ordering::SeqCst);
 
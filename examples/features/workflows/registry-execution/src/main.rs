@@ -170,9 +170,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("⏳ Waiting for registry reconciler to load workflow...");
     let workflow_name = "data_processing"; // Use the workflow name from simple-packaged-demo
 
-    // Give the reconciler some time to complete startup reconciliation and register tasks
-    println!("   Waiting for reconciler startup and task registration (30 seconds)...");
-    tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
+    // Reconcile now rather than sleeping until the next interval tick
+    let reconcile = runner.reconcile_now().await?;
+    for (package_id, error) in &reconcile.packages_failed {
+        println!("   ❌ Package {} failed to load: {}", package_id, error);
+    }
+    if let Some(status) = runner.registry_status().await {
+        println!(
+            "   Reconciler has {} package(s) loaded",
+            status.packages_loaded
+        );
+    }
 
     // Test if workflow is available before trying to execute
     println!("🔍 Checking if workflow is available for execution...");
@@ -77,9 +77,7 @@ pub mod parallel_processing {
     use super::*;
 
     /// Generate a large dataset of products
-    #[task(
-        retry_attempts = 2
-    )]
+    #[task(retry_attempts = 2)]
     pub async fn generate_data(context: &mut Context<serde_json::Value>) -> Result<(), TaskError> {
         info!("Generating product dataset");
 
@@ -185,21 +183,7 @@ pub mod parallel_processing {
     pub async fn process_partition_2(
         context: &mut Context<serde_json::Value>,
     ) -> Result<(), TaskError> {
-        let products: Vec<Product> = context
-            .get("partition_2")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing partition_2 in context".to_string(),
-            })?
-            .as_array()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Partition_2 is not an array".to_string(),
-            })?
-            .iter()
-            .map(|v| serde_json::from_value(v.clone()))
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize partition_2: {}", e),
-            })?;
+        let products: Vec<Product> = context.get_typed("partition_2")?;
 
         info!("Processing partition 2: {} products", products.len());
 
@@ -242,21 +226,7 @@ pub mod parallel_processing {
     pub async fn process_partition_3(
         context: &mut Context<serde_json::Value>,
     ) -> Result<(), TaskError> {
-        let products: Vec<Product> = context
-            .get("partition_3")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing partition_3 in context".to_string(),
-            })?
-            .as_array()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Partition_3 is not an array".to_string(),
-            })?
-            .iter()
-            .map(|v| serde_json::from_value(v.clone()))
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize partition_3: {}", e),
-            })?;
+        let products: Vec<Product> = context.get_typed("partition_3")?;
 
         info!("Processing partition 3: {} products", products.len());
 
@@ -300,53 +270,11 @@ pub mod parallel_processing {
     ) -> Result<(), TaskError> {
         info!("Combining results from parallel processing");
 
-        let stats_1: HashMap<String, CategoryStats> = context
-            .get("stats_1")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing stats_1 in context".to_string(),
-            })?
-            .as_object()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "stats_1 is not an object".to_string(),
-            })?
-            .iter()
-            .map(|(k, v)| serde_json::from_value(v.clone()).map(|v| (k.clone(), v)))
-            .collect::<Result<HashMap<_, _>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize stats_1: {}", e),
-            })?;
+        let stats_1: HashMap<String, CategoryStats> = context.get_typed("stats_1")?;
 
-        let stats_2: HashMap<String, CategoryStats> = context
-            .get("stats_2")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing stats_2 in context".to_string(),
-            })?
-            .as_object()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "stats_2 is not an object".to_string(),
-            })?
-            .iter()
-            .map(|(k, v)| serde_json::from_value(v.clone()).map(|v| (k.clone(), v)))
-            .collect::<Result<HashMap<_, _>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize stats_2: {}", e),
-            })?;
+        let stats_2: HashMap<String, CategoryStats> = context.get_typed("stats_2")?;
 
-        let stats_3: HashMap<String, CategoryStats> = context
-            .get("stats_3")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing stats_3 in context".to_string(),
-            })?
-            .as_object()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "stats_3 is not an object".to_string(),
-            })?
-            .iter()
-            .map(|(k, v)| serde_json::from_value(v.clone()).map(|v| (k.clone(), v)))
-            .collect::<Result<HashMap<_, _>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize stats_3: {}", e),
-            })?;
+        let stats_3: HashMap<String, CategoryStats> = context.get_typed("stats_3")?;
 
         let processing_time_1: u64 = context
             .get("processing_time_1")
@@ -435,21 +363,7 @@ pub mod parallel_processing {
     ) -> Result<(), TaskError> {
         info!("Generating processing report");
 
-        let final_stats: HashMap<String, CategoryStats> = context
-            .get("final_stats")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing final_stats in context".to_string(),
-            })?
-            .as_object()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "final_stats is not an object".to_string(),
-            })?
-            .iter()
-            .map(|(k, v)| serde_json::from_value(v.clone()).map(|v| (k.clone(), v)))
-            .collect::<Result<HashMap<_, _>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize final_stats: {}", e),
-            })?;
+        let final_stats: HashMap<String, CategoryStats> = context.get_typed("final_stats")?;
 
         let report = final_stats
             .iter()
@@ -477,21 +391,7 @@ pub mod parallel_processing {
     ) -> Result<(), TaskError> {
         info!("Sending completion notifications");
 
-        let final_stats: HashMap<String, CategoryStats> = context
-            .get("final_stats")
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "Missing final_stats in context".to_string(),
-            })?
-            .as_object()
-            .ok_or_else(|| TaskError::ValidationFailed {
-                message: "final_stats is not an object".to_string(),
-            })?
-            .iter()
-            .map(|(k, v)| serde_json::from_value(v.clone()).map(|v| (k.clone(), v)))
-            .collect::<Result<HashMap<_, _>, _>>()
-            .map_err(|e| TaskError::ValidationFailed {
-                message: format!("Failed to deserialize final_stats: {}", e),
-            })?;
+        let final_stats: HashMap<String, CategoryStats> = context.get_typed("final_stats")?;
 
         let total_products: u32 = final_stats.values().map(|s| s.product_count).sum();
 
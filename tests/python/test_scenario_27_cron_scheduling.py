@@ -90,6 +90,14 @@ class TestCronScheduling:
         assert updated_schedule["timezone"] == "America/New_York"
         print("✓ Cron schedule update works correctly")
 
+        # Next fire times follow the updated expression: noon New York time
+        fire_times = shared_runner.get_cron_schedule_fire_times(schedule_id, 3)
+        assert len(fire_times) == 3
+        parsed = [datetime.fromisoformat(t) for t in fire_times]
+        assert parsed == sorted(parsed)
+        assert all(t.minute == 0 and t.hour in (16, 17) for t in parsed)
+        print("✓ Cron schedule fire-time preview works correctly")
+
         # Test 5: Enable/disable cron schedule
         print("Testing cron schedule enable/disable...")
 